@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::Parser;
+use zksync_commitment_generator::CommitmentGenerator;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::{url::SensitiveUrl, L1BatchNumber, L2ChainId};
+
+use crate::{
+    outputs::BatchOutputs,
+    replay::{replay, ReplayOutput},
+    source::{load_from_database, BatchDump},
+};
+
+mod outputs;
+mod replay;
+mod source;
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "Deterministic replay of sealed L1 batches",
+    long_about = None
+)]
+struct Cli {
+    /// Path to a batch dump (produced by this tool with `--save-dump`) to load the batch from.
+    #[arg(
+        long,
+        required_unless_present = "database_url",
+        conflicts_with = "database_url"
+    )]
+    dump: Option<PathBuf>,
+    /// Postgres URL of the node database to load the batch from.
+    #[arg(long, requires = "l1_batch_number")]
+    database_url: Option<SensitiveUrl>,
+    /// L2 chain ID; only used when loading the batch from the database.
+    #[arg(long, default_value = "270")]
+    chain_id: L2ChainId,
+    /// Number of the replayed L1 batch; only used when loading the batch from the database.
+    #[arg(long)]
+    l1_batch_number: Option<L1BatchNumber>,
+    /// Path to save a self-contained batch dump to, so that the batch can be replayed without database access.
+    #[arg(long, requires = "database_url")]
+    save_dump: Option<PathBuf>,
+}
+
+/// Prints the replay summary and fails if the replay diverges from the persisted batch.
+fn report(
+    l1_batch_number: L1BatchNumber,
+    output: &ReplayOutput,
+    divergences: &[String],
+) -> anyhow::Result<()> {
+    println!(
+        "Replayed L1 batch #{l1_batch_number}: {} transactions, {} storage writes",
+        output.tx_count,
+        output
+            .batch
+            .final_execution_state
+            .deduplicated_storage_logs
+            .iter()
+            .filter(|log| log.is_write())
+            .count()
+    );
+    if divergences.is_empty() {
+        println!("Replayed batch matches the persisted one");
+        return Ok(());
+    }
+    for divergence in divergences {
+        println!("- {divergence}");
+    }
+    anyhow::bail!(
+        "replayed L1 batch #{l1_batch_number} diverges from the persisted one in {} place(s)",
+        divergences.len()
+    )
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let Some(database_url) = cli.database_url else {
+        let path = cli.dump.context("batch dump path is not specified")?;
+        let (input, expected_outputs) = BatchDump::load(&path)?.into_input();
+        let l1_batch_number = input.l1_batch_env.number;
+        let output = replay(input, false)?;
+        let divergences = BatchOutputs::new(&output.batch).diff(&expected_outputs);
+        println!("Batch commitment is only checked when replaying from the database");
+        return report(l1_batch_number, &output, &divergences);
+    };
+
+    let l1_batch_number = cli
+        .l1_batch_number
+        .context("L1 batch number is not specified")?;
+    let pool = ConnectionPool::<Core>::singleton(database_url)
+        .build()
+        .await
+        .context("failed connecting to Postgres")?;
+    let mut conn = pool.connection_tagged("batch_replayer").await?;
+    let expected_outputs = BatchOutputs::load(&mut conn, l1_batch_number).await?;
+    drop(conn);
+
+    let input = load_from_database(&pool, cli.chain_id, l1_batch_number).await?;
+    let commitment_mode = input.pubdata_params.pubdata_type;
+    let pubdata_params = input.pubdata_params;
+    let capture_dump = cli.save_dump.is_some();
+    // Postgres storage blocks on async calls internally, so the VM must run on a blocking thread.
+    let mut output = tokio::task::spawn_blocking(move || replay(input, capture_dump)).await??;
+    let mut divergences = BatchOutputs::new(&output.batch).diff(&expected_outputs);
+
+    if let (Some(path), Some(vm_dump)) = (&cli.save_dump, output.dump.take()) {
+        let dump = BatchDump {
+            vm: vm_dump,
+            pubdata_params,
+            expected_outputs,
+        };
+        dump.save(path)?;
+        println!("Saved batch dump to {}", path.display());
+    }
+
+    // The commitment depends on the Merkle tree root hash, which is loaded from Postgres.
+    let commitment_generator = CommitmentGenerator::new(pool.clone(), commitment_mode);
+    let recomputed_commitment = commitment_generator
+        .recompute_commitment(l1_batch_number, output.execution_outputs())
+        .await;
+    let mut conn = pool.connection_tagged("batch_replayer").await?;
+    let expected_commitment = conn
+        .blocks_dal()
+        .get_l1_batch_metadata(l1_batch_number)
+        .await?
+        .map(|batch| batch.metadata.commitment);
+    drop(conn);
+    match (recomputed_commitment, expected_commitment) {
+        (Ok(Some(commitment)), Some(expected)) if commitment != expected => {
+            divergences.push(format!(
+                "batch commitment: persisted {expected:?}, recomputed {commitment:?}"
+            ));
+        }
+        (Ok(Some(_)), Some(_)) => println!("Batch commitment matches the persisted one"),
+        (Ok(None), _) => println!("Batch commitment is not checked for pre-boojum batches"),
+        (Ok(Some(_)), None) => println!("Batch commitment is not checked: it's not persisted yet"),
+        (Err(err), _) => println!("Batch commitment is not checked: {err:#}"),
+    }
+    report(l1_batch_number, &output, &divergences)
+}
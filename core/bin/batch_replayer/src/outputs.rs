@@ -0,0 +1,191 @@
+//! Comparing outputs of the replayed L1 batch with the persisted ones.
+
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_multivm::interface::FinishedL1Batch;
+use zksync_types::{
+    l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
+    L1BatchNumber, H256,
+};
+
+/// Outputs of an L1 batch execution that are compared between the replay and the batch persisted by the node.
+/// The Merkle tree root hash isn't produced by the VM; instead, the storage writes it's computed from are compared.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BatchOutputs {
+    /// Final values of the storage slots written to in the batch, keyed by the hashed storage key.
+    pub storage_writes: BTreeMap<H256, H256>,
+    pub user_l2_to_l1_logs: Vec<UserL2ToL1Log>,
+    pub system_logs: Vec<SystemL2ToL1Log>,
+    /// Pubdata published by the batch. Can be `None` for old batches.
+    pub pubdata_input: Option<Vec<u8>>,
+}
+
+impl BatchOutputs {
+    pub fn new(batch: &FinishedL1Batch) -> Self {
+        let execution_state = &batch.final_execution_state;
+        let storage_writes = execution_state
+            .deduplicated_storage_logs
+            .iter()
+            .filter(|log| log.is_write())
+            .map(|log| (log.key.hashed_key(), log.value))
+            .collect();
+        Self {
+            storage_writes,
+            user_l2_to_l1_logs: execution_state.user_l2_to_l1_logs.clone(),
+            system_logs: execution_state.system_logs.clone(),
+            pubdata_input: batch.pubdata_input.clone(),
+        }
+    }
+
+    /// Loads outputs persisted for the specified L1 batch.
+    pub async fn load(
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Self> {
+        let header = conn
+            .blocks_dal()
+            .get_l1_batch_header(l1_batch_number)
+            .await?
+            .with_context(|| format!("L1 batch #{l1_batch_number} is not sealed"))?;
+        let storage_writes = conn
+            .storage_logs_dal()
+            .get_touched_slots_for_l1_batch(l1_batch_number)
+            .await?;
+        Ok(Self {
+            storage_writes: storage_writes.into_iter().collect(),
+            user_l2_to_l1_logs: header.l2_to_l1_logs,
+            system_logs: header.system_logs,
+            pubdata_input: header.pubdata_input,
+        })
+    }
+
+    /// Returns human-readable descriptions of all divergences of these (replayed) outputs from the `expected` ones.
+    pub fn diff(&self, expected: &Self) -> Vec<String> {
+        let mut divergences = vec![];
+        for (key, expected_value) in &expected.storage_writes {
+            match self.storage_writes.get(key) {
+                Some(value) if value == expected_value => { /* Values match */ }
+                Some(value) => divergences.push(format!(
+                    "storage slot {key:?}: persisted value {expected_value:?}, replayed {value:?}"
+                )),
+                None => divergences.push(format!(
+                    "storage slot {key:?} is written to with {expected_value:?} in the persisted batch, but not on replay"
+                )),
+            }
+        }
+        for (key, value) in &self.storage_writes {
+            if !expected.storage_writes.contains_key(key) {
+                divergences.push(format!(
+                    "storage slot {key:?} is only written to on replay, with {value:?}"
+                ));
+            }
+        }
+
+        diff_sequences(
+            "user L2-to-L1 log",
+            &expected.user_l2_to_l1_logs,
+            &self.user_l2_to_l1_logs,
+            &mut divergences,
+        );
+        diff_sequences(
+            "system log",
+            &expected.system_logs,
+            &self.system_logs,
+            &mut divergences,
+        );
+        if let (Some(expected_pubdata), Some(pubdata)) =
+            (&expected.pubdata_input, &self.pubdata_input)
+        {
+            if expected_pubdata != pubdata {
+                let first_diff = expected_pubdata
+                    .iter()
+                    .zip(pubdata)
+                    .position(|(expected, actual)| expected != actual)
+                    .unwrap_or(expected_pubdata.len().min(pubdata.len()));
+                divergences.push(format!(
+                    "pubdata differs starting from byte {first_diff}: {} bytes persisted, {} bytes replayed",
+                    expected_pubdata.len(),
+                    pubdata.len()
+                ));
+            }
+        }
+        divergences
+    }
+}
+
+/// Reports the length mismatch and the first mismatching item for two sequences.
+fn diff_sequences<T: PartialEq + fmt::Debug>(
+    name: &str,
+    expected: &[T],
+    actual: &[T],
+    divergences: &mut Vec<String>,
+) {
+    if expected.len() != actual.len() {
+        divergences.push(format!(
+            "{name} count: {} persisted, {} replayed",
+            expected.len(),
+            actual.len()
+        ));
+    }
+    let first_diff = expected
+        .iter()
+        .zip(actual)
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual);
+    if let Some((i, (expected, actual))) = first_diff {
+        divergences.push(format!(
+            "{name} #{i}: persisted {expected:?}, replayed {actual:?}"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::l2_to_l1_log::L2ToL1Log;
+
+    use super::*;
+
+    fn mock_outputs() -> BatchOutputs {
+        BatchOutputs {
+            storage_writes: BTreeMap::from([
+                (H256::repeat_byte(1), H256::repeat_byte(0xff)),
+                (H256::repeat_byte(2), H256::zero()),
+            ]),
+            user_l2_to_l1_logs: vec![],
+            system_logs: vec![SystemL2ToL1Log(L2ToL1Log::default())],
+            pubdata_input: Some(vec![1, 2, 3]),
+        }
+    }
+
+    #[test]
+    fn diffing_equal_outputs() {
+        let outputs = mock_outputs();
+        assert!(outputs.diff(&outputs.clone()).is_empty());
+    }
+
+    #[test]
+    fn diffing_diverging_outputs() {
+        let expected = mock_outputs();
+        let mut replayed = expected.clone();
+        replayed
+            .storage_writes
+            .insert(H256::repeat_byte(1), H256::repeat_byte(0xfe));
+        replayed.storage_writes.remove(&H256::repeat_byte(2));
+        replayed
+            .storage_writes
+            .insert(H256::repeat_byte(3), H256::repeat_byte(3));
+        replayed.system_logs[0].0.tx_number_in_block = 1;
+        replayed.pubdata_input = Some(vec![1, 2, 4, 5]);
+
+        let divergences = replayed.diff(&expected);
+        assert_eq!(divergences.len(), 5, "{divergences:#?}");
+        assert!(divergences[0].contains("persisted value"));
+        assert!(divergences[1].contains("not on replay"));
+        assert!(divergences[2].contains("only written to on replay"));
+        assert!(divergences[3].starts_with("system log #0"));
+        assert!(divergences[4].contains("starting from byte 2"));
+    }
+}
@@ -0,0 +1,107 @@
+//! Re-executing the L1 batch in the VM.
+
+use anyhow::Context as _;
+use zksync_commitment_generator::L1BatchExecutionOutputs;
+use zksync_multivm::{
+    interface::{
+        storage::{ReadStorage, StorageSnapshot, StorageView},
+        utils::VmDump,
+        ExecutionResult, FinishedL1Batch, L2BlockEnv, VmFactory, VmInterface, VmInterfaceExt,
+    },
+    pubdata_builders::pubdata_params_to_builder,
+    vm_latest::HistoryEnabled,
+    LegacyVmInstance,
+};
+use zksync_types::u256_to_h256;
+
+use crate::source::ReplayInput;
+
+/// Outputs of replaying an L1 batch.
+#[derive(Debug)]
+pub(crate) struct ReplayOutput {
+    pub batch: FinishedL1Batch,
+    /// Total number of transactions in the batch.
+    pub tx_count: usize,
+    /// VM dump covering the entire batch; only captured if requested.
+    pub dump: Option<VmDump>,
+}
+
+impl ReplayOutput {
+    pub fn execution_outputs(&self) -> L1BatchExecutionOutputs {
+        let execution_state = &self.batch.final_execution_state;
+        L1BatchExecutionOutputs {
+            l2_to_l1_logs: execution_state.user_l2_to_l1_logs.clone(),
+            system_logs: execution_state.system_logs.clone(),
+            events: execution_state.events.clone(),
+            state_diffs: self.batch.state_diffs.clone().unwrap_or_default(),
+            initial_bootloader_contents: self
+                .batch
+                .final_bootloader_memory
+                .clone()
+                .unwrap_or_default(),
+            pubdata_input: self.batch.pubdata_input.clone(),
+        }
+    }
+}
+
+/// Re-executes all transactions in the batch and finishes it using the VM version matching the batch protocol version.
+/// This method is blocking.
+pub(crate) fn replay<S: ReadStorage>(
+    input: ReplayInput<S>,
+    capture_dump: bool,
+) -> anyhow::Result<ReplayOutput> {
+    let storage = StorageView::new(input.storage).to_rc_ptr();
+    let mut vm: LegacyVmInstance<_, HistoryEnabled> = LegacyVmInstance::new(
+        input.l1_batch_env.clone(),
+        input.system_env.clone(),
+        storage.clone(),
+    );
+
+    let mut tx_count = 0;
+    for (i, l2_block) in input.l2_blocks.iter().enumerate() {
+        if i > 0 {
+            // First block is already set.
+            vm.start_new_l2_block(L2BlockEnv::from_l2_block_data(l2_block));
+        }
+
+        for tx in &l2_block.txs {
+            let tx_hash = tx.hash();
+            let (compression_result, tx_result) =
+                vm.execute_transaction_with_bytecode_compression(tx.clone(), true);
+            compression_result.with_context(|| {
+                format!("failed compressing bytecodes for transaction {tx_hash:?}")
+            })?;
+            // Halted transactions are never included into batches by the state keeper.
+            if let ExecutionResult::Halt { reason } = &tx_result.result {
+                anyhow::bail!(
+                    "transaction {tx_hash:?} in L2 block #{} is halted on replay: {reason}",
+                    l2_block.number
+                );
+            }
+            tx_count += 1;
+        }
+    }
+    let batch = vm.finish_batch(pubdata_params_to_builder(input.pubdata_params));
+
+    let dump = capture_dump.then(|| {
+        let used_contract_hashes = batch
+            .final_execution_state
+            .used_contract_hashes
+            .iter()
+            .map(|&hash| u256_to_h256(hash));
+        VmDump {
+            l1_batch_env: input.l1_batch_env,
+            system_env: input.system_env,
+            l2_blocks: input.l2_blocks,
+            storage: StorageSnapshot::from_storage_view(
+                &mut storage.borrow_mut(),
+                used_contract_hashes,
+            ),
+        }
+    });
+    Ok(ReplayOutput {
+        batch,
+        tx_count,
+        dump,
+    })
+}
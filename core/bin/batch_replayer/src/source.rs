@@ -0,0 +1,100 @@
+//! Loading inputs for the replayed L1 batch.
+
+use std::path::Path;
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Handle;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_multivm::interface::{storage::StorageSnapshot, utils::VmDump, L1BatchEnv, SystemEnv};
+use zksync_state::PostgresStorage;
+use zksync_types::{
+    block::L2BlockExecutionData, commitment::PubdataParams, L1BatchNumber, L2ChainId,
+};
+use zksync_vm_executor::storage::L1BatchParamsProvider;
+
+use crate::outputs::BatchOutputs;
+
+/// Inputs necessary to re-execute an L1 batch.
+#[derive(Debug)]
+pub(crate) struct ReplayInput<S> {
+    pub storage: S,
+    pub l1_batch_env: L1BatchEnv,
+    pub system_env: SystemEnv,
+    pub pubdata_params: PubdataParams,
+    pub l2_blocks: Vec<L2BlockExecutionData>,
+}
+
+/// Self-contained dump of an L1 batch allowing to replay it without access to the node database.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BatchDump {
+    /// VM inputs. The storage snapshot covers all slots accessed during the batch execution, including finishing the batch.
+    pub vm: VmDump,
+    pub pubdata_params: PubdataParams,
+    /// Outputs persisted for the batch by the node.
+    pub expected_outputs: BatchOutputs,
+}
+
+impl BatchDump {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed reading batch dump from {}", path.display()))?;
+        serde_json::from_str(&contents).context("failed deserializing batch dump")
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string(self).context("failed serializing batch dump")?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("failed writing batch dump to {}", path.display()))
+    }
+
+    pub fn into_input(self) -> (ReplayInput<StorageSnapshot>, BatchOutputs) {
+        let input = ReplayInput {
+            storage: self.vm.storage,
+            l1_batch_env: self.vm.l1_batch_env,
+            system_env: self.vm.system_env,
+            pubdata_params: self.pubdata_params,
+            l2_blocks: self.vm.l2_blocks,
+        };
+        (input, self.expected_outputs)
+    }
+}
+
+/// Loads the L1 batch from the node database. The returned storage reflects the state before the batch.
+pub(crate) async fn load_from_database(
+    pool: &ConnectionPool<Core>,
+    chain_id: L2ChainId,
+    l1_batch_number: L1BatchNumber,
+) -> anyhow::Result<ReplayInput<PostgresStorage<'static>>> {
+    anyhow::ensure!(
+        l1_batch_number > L1BatchNumber(0),
+        "genesis L1 batch cannot be replayed"
+    );
+    let mut conn = pool.connection_tagged("batch_replayer").await?;
+
+    let params_provider = L1BatchParamsProvider::new(&mut conn).await?;
+    let (system_env, l1_batch_env, pubdata_params) = params_provider
+        .load_l1_batch_env(&mut conn, l1_batch_number, u32::MAX, chain_id)
+        .await?
+        .with_context(|| format!("L1 batch #{l1_batch_number} is not found"))?;
+    let l2_blocks = conn
+        .transactions_dal()
+        .get_l2_blocks_to_execute_for_l1_batch(l1_batch_number)
+        .await?;
+    let (_, last_l2_block_in_prev_batch) = conn
+        .blocks_dal()
+        .get_l2_block_range_of_l1_batch(l1_batch_number - 1)
+        .await?
+        .with_context(|| format!("L1 batch #{} is not found", l1_batch_number - 1))?;
+
+    let storage =
+        PostgresStorage::new_async(Handle::current(), conn, last_l2_block_in_prev_batch, true)
+            .await?;
+    Ok(ReplayInput {
+        storage,
+        l1_batch_env,
+        system_env,
+        pubdata_params,
+        l2_blocks,
+    })
+}
@@ -14,25 +14,32 @@ use zksync_config::{
         api::{MaxResponseSize, MaxResponseSizeOverrides},
         consensus::{ConsensusConfig, ConsensusSecrets},
         en_config::ENConfig,
-        GeneralConfig, Secrets,
+        CustomSystemContract, GeneralConfig, Secrets,
     },
     ObjectStoreConfig,
 };
 use zksync_consensus_crypto::TextFmt;
 use zksync_consensus_roles as roles;
-use zksync_core_leftovers::temp_config_store::read_yaml_repr;
+use zksync_core_leftovers::temp_config_store::{
+    read_yaml_repr, read_yaml_repr_with_overrides, ConfigOverrides,
+};
 #[cfg(test)]
 use zksync_dal::{ConnectionPool, Core};
 use zksync_metadata_calculator::MetadataCalculatorRecoveryConfig;
 use zksync_node_api_server::{
     tx_sender::{TimestampAsserterParams, TxSenderConfig},
-    web3::{state::InternalApiConfig, Namespace},
+    web3::{
+        state::{InternalApiConfig, NodeMetadata},
+        Namespace,
+    },
 };
-use zksync_protobuf_config::proto;
+use zksync_protobuf_config::{encode_json_repr, proto};
 use zksync_snapshots_applier::SnapshotsApplierConfig;
 use zksync_types::{
-    api::BridgeAddresses, commitment::L1BatchCommitmentMode, url::SensitiveUrl, Address,
-    L1BatchNumber, L1ChainId, L2ChainId, SLChainId, ETHEREUM_ADDRESS,
+    api::{self, BridgeAddresses},
+    commitment::L1BatchCommitmentMode,
+    url::SensitiveUrl,
+    Address, L1BatchNumber, L1ChainId, L2ChainId, SLChainId, ETHEREUM_ADDRESS,
 };
 use zksync_web3_decl::{
     client::{DynClient, L2},
@@ -41,7 +48,7 @@ use zksync_web3_decl::{
     namespaces::{EnNamespaceClient, ZksNamespaceClient},
 };
 
-use crate::config::observability::ObservabilityENConfig;
+use crate::{config::observability::ObservabilityENConfig, metadata::SERVER_VERSION};
 
 pub(crate) mod observability;
 #[cfg(test)]
@@ -126,6 +133,7 @@ pub(crate) struct RemoteENConfig {
     pub base_token_addr: Address,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
     pub dummy_verifier: bool,
+    pub custom_system_contracts: Vec<CustomSystemContract>,
 }
 
 impl RemoteENConfig {
@@ -205,6 +213,9 @@ impl RemoteENConfig {
                 .as_ref()
                 .map(|a| a.dummy_verifier)
                 .unwrap_or_default(),
+            custom_system_contracts: genesis
+                .map(|a| a.custom_system_contracts)
+                .unwrap_or_default(),
             l2_timestamp_asserter_addr: timestamp_asserter_address,
         })
     }
@@ -227,6 +238,7 @@ impl RemoteENConfig {
             l2_legacy_shared_bridge_addr: Some(Address::repeat_byte(7)),
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
             dummy_verifier: true,
+            custom_system_contracts: vec![],
             l2_timestamp_asserter_addr: None,
         }
     }
@@ -281,6 +293,12 @@ pub(crate) struct OptionalENConfig {
     /// Max number of cache misses during one VM execution. If the number of cache misses exceeds this value, the API server panics.
     /// This is a temporary solution to mitigate API request resulting in thousands of DB queries.
     pub vm_execution_cache_misses_limit: Option<usize>,
+    /// Max number of VM cycles for a single call or gas estimation step. Execution exceeding this limit is interrupted
+    /// and results in an error returned to the caller. If not set, execution is only bounded by the gas limit.
+    pub vm_execution_max_cycles: Option<u64>,
+    /// Timeout for a single VM execution during calls and gas estimation, in milliseconds. For gas estimation,
+    /// the timeout applies to the entire estimation rather than to individual steps. If not set, execution time is not limited.
+    pub vm_execution_timeout_ms: Option<u64>,
     /// Limit for fee history block range.
     #[serde(default = "OptionalENConfig::default_fee_history_limit")]
     pub fee_history_limit: u64,
@@ -308,6 +326,14 @@ pub(crate) struct OptionalENConfig {
     /// This option can be tweaked down if the API server is running out of memory.
     #[serde(default = "OptionalENConfig::default_vm_concurrency_limit")]
     pub vm_concurrency_limit: usize,
+    /// Max number of VM instances concurrently spawned for `eth_call`s. If set, calls use a dedicated pool of VM permits.
+    pub call_vm_concurrency_limit: Option<usize>,
+    /// Max number of VM instances concurrently spawned for gas estimation. If set, gas estimation uses a dedicated pool
+    /// of VM permits.
+    pub estimate_vm_concurrency_limit: Option<usize>,
+    /// Max number of VM instances concurrently spawned for tracing calls. If set, tracing uses a dedicated pool
+    /// of VM permits.
+    pub trace_vm_concurrency_limit: Option<usize>,
     /// Smart contract bytecode cache size for the API server. Default value is 128 MiB.
     #[serde(default = "OptionalENConfig::default_factory_deps_cache_size_mb")]
     factory_deps_cache_size_mb: usize,
@@ -318,6 +344,10 @@ pub(crate) struct OptionalENConfig {
     /// values cache will be disabled.
     #[serde(default = "OptionalENConfig::default_latest_values_cache_size_mb")]
     latest_values_cache_size_mb: usize,
+    /// Size of the cache of storage data shared by VM executions against the same L2 block, in MiBs.
+    /// The default value is 32 MiB. If set to 0, the cache will be disabled.
+    #[serde(default = "OptionalENConfig::default_block_state_cache_size_mb")]
+    block_state_cache_size_mb: usize,
     /// Enabled JSON RPC API namespaces.
     api_namespaces: Option<Vec<Namespace>>,
     /// Whether to support HTTP methods that install filters and query filter changes.
@@ -352,6 +382,10 @@ pub(crate) struct OptionalENConfig {
     /// Time limit in milliseconds to abort a health check and return "not ready" status for the corresponding component.
     /// If not specified, the default value in the health check crate will be used.
     healthcheck_hard_time_limit_ms: Option<u64>,
+    /// Whether to expose the effective node configuration (with secrets redacted) on the `/config` endpoint
+    /// of the healthcheck server. Only supported if the node is configured using YAML config files.
+    #[serde(default)]
+    pub healthcheck_expose_config: bool,
 
     // Gas estimation config
     /// The factor by which to scale the gas limit.
@@ -411,6 +445,17 @@ pub(crate) struct OptionalENConfig {
     /// Enables the stale keys repair task for the Merkle tree.
     #[serde(default)]
     pub merkle_tree_repair_stale_keys: bool,
+    /// Number of the latest L1 batches to retain in the Merkle tree. If set, older tree versions are pruned
+    /// regardless of Postgres pruning.
+    pub merkle_tree_retained_l1_batches: Option<u64>,
+    /// Start of the daily maintenance window for the Merkle tree (hour in UTC, 0..=23). If set together with
+    /// the end hour, retention-based pruning and manual RocksDB compaction are only performed inside the window.
+    pub merkle_tree_maintenance_window_start_hour: Option<u8>,
+    /// End of the daily maintenance window for the Merkle tree (hour in UTC, exclusive).
+    pub merkle_tree_maintenance_window_end_hour: Option<u8>,
+    /// Minimum number of L1 batches pruned from the Merkle tree since the last manual RocksDB compaction
+    /// to trigger the next compaction.
+    pub merkle_tree_maintenance_compaction_min_pruned_l1_batches: Option<u64>,
 
     // Postgres config (new parameters)
     /// Threshold in milliseconds for the DB connection lifetime to denote it as long-living and log its details.
@@ -444,6 +489,15 @@ pub(crate) struct OptionalENConfig {
 
     #[serde(default)]
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    /// First L1 batches committed after each switch of the chain between rollup and validium modes. Each transition
+    /// flips the commitment mode; batches after the last transition are committed using
+    /// `l1_batch_commit_data_generator_mode`. Used by the consistency checker to verify batches committed in older modes.
+    #[serde(default)]
+    pub commitment_mode_transition_batches: Vec<L1BatchNumber>,
+    /// Inclusion deadline for priority operations measured in L1 batches. If set, the consistency checker verifies
+    /// that each priority operation is included into one of the first `forced_inclusion_deadline_batches` L1 batches
+    /// opened after the operation was submitted on L1.
+    pub forced_inclusion_deadline_batches: Option<u32>,
     /// Enables application-level snapshot recovery. Required to start a node that was recovered from a snapshot,
     /// or to initialize a node from a snapshot. Has no effect if a node that was initialized from a Postgres dump
     /// or was synced from genesis.
@@ -456,6 +510,10 @@ pub(crate) struct OptionalENConfig {
     /// if I/O capacity of your infra is high, you may increase concurrency to speed up Postgres recovery.
     #[serde(default = "OptionalENConfig::default_snapshots_recovery_postgres_max_concurrency")]
     pub snapshots_recovery_postgres_max_concurrency: NonZeroUsize,
+    /// Maximum number of storage log chunks downloaded during snapshot recovery, but not yet persisted in Postgres.
+    /// Increasing this value allows downloading chunks ahead of Postgres persistence at the cost of higher RAM usage.
+    #[serde(default)]
+    pub snapshots_recovery_postgres_max_chunks_in_memory: Option<NonZeroUsize>,
 
     #[serde(default)]
     pub snapshots_recovery_object_store: Option<ObjectStoreConfig>,
@@ -478,6 +536,11 @@ pub(crate) struct OptionalENConfig {
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 7 days.
     #[serde(default = "OptionalENConfig::default_pruning_data_retention_sec")]
     pruning_data_retention_sec: u64,
+    /// Addresses for which full history (events, call traces, transaction data and overwritten storage values)
+    /// is retained when pruning. This allows running a node in a selective archive mode, e.g. retaining history
+    /// of protocol contracts or major dapps while pruning everything else. Has no effect if pruning is disabled.
+    #[serde(default)]
+    pub pruning_retained_addresses: Vec<Address>,
     /// Gateway RPC URL, needed for operating during migration.
     #[allow(dead_code)]
     pub gateway_url: Option<SensitiveUrl>,
@@ -519,6 +582,14 @@ impl OptionalENConfig {
                 general_config.api_config,
                 web3_json_rpc.vm_execution_cache_misses_limit
             ),
+            vm_execution_max_cycles: load_config!(
+                general_config.api_config,
+                web3_json_rpc.vm_execution_max_cycles
+            ),
+            vm_execution_timeout_ms: load_config!(
+                general_config.api_config,
+                web3_json_rpc.vm_execution_timeout_ms
+            ),
             fee_history_limit: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.fee_history_limit,
@@ -554,6 +625,18 @@ impl OptionalENConfig {
                 web3_json_rpc.vm_concurrency_limit,
                 default_vm_concurrency_limit
             ),
+            call_vm_concurrency_limit: load_config!(
+                general_config.api_config,
+                web3_json_rpc.call_vm_concurrency_limit
+            ),
+            estimate_vm_concurrency_limit: load_config!(
+                general_config.api_config,
+                web3_json_rpc.estimate_vm_concurrency_limit
+            ),
+            trace_vm_concurrency_limit: load_config!(
+                general_config.api_config,
+                web3_json_rpc.trace_vm_concurrency_limit
+            ),
             factory_deps_cache_size_mb: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.factory_deps_cache_size_mb,
@@ -569,6 +652,11 @@ impl OptionalENConfig {
                 web3_json_rpc.latest_values_cache_size_mb,
                 default_latest_values_cache_size_mb
             ),
+            block_state_cache_size_mb: load_optional_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.block_state_cache_size_mb,
+                default_block_state_cache_size_mb
+            ),
             filters_disabled: general_config
                 .api_config
                 .as_ref()
@@ -593,6 +681,10 @@ impl OptionalENConfig {
                 general_config.api_config,
                 healthcheck.hard_time_limit_ms
             ),
+            healthcheck_expose_config: general_config
+                .api_config
+                .as_ref()
+                .map_or(false, |config| config.healthcheck.expose_config),
             estimate_gas_scale_factor: load_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.estimate_gas_scale_factor,
@@ -648,6 +740,22 @@ impl OptionalENConfig {
                 .map_or(false, |config| {
                     config.experimental.merkle_tree_repair_stale_keys
                 }),
+            merkle_tree_retained_l1_batches: load_config!(
+                general_config.db_config,
+                merkle_tree.retained_l1_batches
+            ),
+            merkle_tree_maintenance_window_start_hour: load_config!(
+                general_config.db_config,
+                merkle_tree.maintenance_window_start_hour
+            ),
+            merkle_tree_maintenance_window_end_hour: load_config!(
+                general_config.db_config,
+                merkle_tree.maintenance_window_end_hour
+            ),
+            merkle_tree_maintenance_compaction_min_pruned_l1_batches: load_config!(
+                general_config.db_config,
+                merkle_tree.maintenance_compaction_min_pruned_l1_batches
+            ),
             database_long_connection_threshold_ms: load_config!(
                 general_config.postgres_config,
                 long_connection_threshold_ms
@@ -662,6 +770,8 @@ impl OptionalENConfig {
                 default_l2_block_seal_queue_capacity
             ),
             l1_batch_commit_data_generator_mode: enconfig.l1_batch_commit_data_generator_mode,
+            commitment_mode_transition_batches: enconfig.commitment_mode_transition_batches.clone(),
+            forced_inclusion_deadline_batches: enconfig.forced_inclusion_deadline_batches,
             snapshots_recovery_enabled: general_config
                 .snapshot_recovery
                 .as_ref()
@@ -672,6 +782,10 @@ impl OptionalENConfig {
                 postgres.max_concurrency,
                 default_snapshots_recovery_postgres_max_concurrency
             ),
+            snapshots_recovery_postgres_max_chunks_in_memory: load_config!(
+                general_config.snapshot_recovery,
+                postgres.max_chunks_in_memory
+            ),
             pruning_enabled: general_config
                 .pruning
                 .as_ref()
@@ -696,6 +810,11 @@ impl OptionalENConfig {
                 data_retention_sec,
                 default_pruning_data_retention_sec
             ),
+            pruning_retained_addresses: general_config
+                .pruning
+                .as_ref()
+                .map(|a| a.retained_addresses.clone())
+                .unwrap_or_default(),
             protective_reads_persistence_enabled: general_config
                 .db_config
                 .as_ref()
@@ -794,6 +913,10 @@ impl OptionalENConfig {
         128
     }
 
+    const fn default_block_state_cache_size_mb() -> usize {
+        32
+    }
+
     const fn default_merkle_tree_multi_get_chunk_size() -> usize {
         500
     }
@@ -897,6 +1020,11 @@ impl OptionalENConfig {
         self.latest_values_cache_size_mb * BYTES_IN_MEGABYTE
     }
 
+    /// Returns the size of the block state cache in bytes.
+    pub fn block_state_cache_size(&self) -> usize {
+        self.block_state_cache_size_mb * BYTES_IN_MEGABYTE
+    }
+
     /// Returns the size of block cache for Merkle tree in bytes.
     pub fn merkle_tree_block_cache_size(&self) -> usize {
         self.merkle_tree_block_cache_size_mb * BYTES_IN_MEGABYTE
@@ -936,6 +1064,10 @@ impl OptionalENConfig {
         }
     }
 
+    pub fn vm_execution_timeout(&self) -> Option<Duration> {
+        self.vm_execution_timeout_ms.map(Duration::from_millis)
+    }
+
     pub fn healthcheck_slow_time_limit(&self) -> Option<Duration> {
         self.healthcheck_slow_time_limit_ms
             .map(Duration::from_millis)
@@ -1289,6 +1421,9 @@ pub(crate) struct ExternalNodeConfig<R = RemoteENConfig> {
     pub consensus_secrets: Option<ConsensusSecrets>,
     pub api_component: ApiComponentConfig,
     pub tree_component: TreeComponentConfig,
+    /// General config with secrets redacted, exposed by the healthcheck server if
+    /// [`OptionalENConfig::healthcheck_expose_config`] is set. Only available for YAML-based configuration.
+    pub effective_config: Option<serde_json::Value>,
     pub remote: R,
 }
 
@@ -1307,6 +1442,7 @@ impl ExternalNodeConfig<()> {
             api_component: envy::prefixed("EN_API_")
                 .from_env::<ApiComponentConfig>()
                 .context("could not load external node config (API component params)")?,
+            effective_config: None,
             tree_component: envy::prefixed("EN_TREE_")
                 .from_env::<TreeComponentConfig>()
                 .context("could not load external node config (tree component params)")?,
@@ -1321,17 +1457,35 @@ impl ExternalNodeConfig<()> {
         external_node_config_path: PathBuf,
         secrets_configs_path: PathBuf,
         consensus_config_path: Option<PathBuf>,
+        overrides: &ConfigOverrides,
     ) -> anyhow::Result<Self> {
-        let general_config = read_yaml_repr::<proto::general::GeneralConfig>(&general_config_path)
-            .context("failed decoding general YAML config")?;
-        let external_node_config =
-            read_yaml_repr::<proto::en::ExternalNode>(&external_node_config_path)
-                .context("failed decoding external node YAML config")?;
-        let secrets_config = read_yaml_repr::<proto::secrets::Secrets>(&secrets_configs_path)
-            .context("failed decoding secrets YAML config")?;
+        let general_config = read_yaml_repr_with_overrides::<proto::general::GeneralConfig>(
+            &general_config_path,
+            "general",
+            overrides,
+        )
+        .context("failed decoding general YAML config")?;
+        let external_node_config = read_yaml_repr_with_overrides::<proto::en::ExternalNode>(
+            &external_node_config_path,
+            "external_node",
+            overrides,
+        )
+        .context("failed decoding external node YAML config")?;
+        let secrets_config = read_yaml_repr_with_overrides::<proto::secrets::Secrets>(
+            &secrets_configs_path,
+            "secrets",
+            overrides,
+        )
+        .context("failed decoding secrets YAML config")?;
 
         let consensus = consensus_config_path
-            .map(|path| read_yaml_repr::<proto::consensus::Config>(&path))
+            .map(|path| {
+                read_yaml_repr_with_overrides::<proto::consensus::Config>(
+                    &path,
+                    "consensus",
+                    overrides,
+                )
+            })
             .transpose()
             .context("failed decoding consensus YAML config")?;
         let consensus_secrets = secrets_config.consensus.clone();
@@ -1360,6 +1514,15 @@ impl ExternalNodeConfig<()> {
 
         let api_component = ApiComponentConfig::from_configs(&general_config);
         let tree_component = TreeComponentConfig::from_configs(&general_config);
+        let effective_config = if optional.healthcheck_expose_config {
+            let mut general_config = general_config;
+            general_config.redact_secrets();
+            let encoded = encode_json_repr::<proto::general::GeneralConfig>(&general_config)
+                .context("failed encoding effective config")?;
+            Some(encoded)
+        } else {
+            None
+        };
 
         Ok(Self {
             required,
@@ -1370,6 +1533,7 @@ impl ExternalNodeConfig<()> {
             consensus,
             api_component,
             tree_component,
+            effective_config,
             consensus_secrets,
             remote: (),
         })
@@ -1404,6 +1568,7 @@ impl ExternalNodeConfig<()> {
             experimental: self.experimental,
             consensus: self.consensus,
             tree_component: self.tree_component,
+            effective_config: self.effective_config,
             api_component: self.api_component,
             consensus_secrets: self.consensus_secrets,
             remote,
@@ -1427,6 +1592,7 @@ impl ExternalNodeConfig {
                 tree_api_remote_url: None,
             },
             tree_component: TreeComponentConfig { api_port: None },
+            effective_config: None,
         }
     }
 
@@ -1445,6 +1611,7 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
     fn from(config: &ExternalNodeConfig) -> Self {
         Self {
             l1_chain_id: config.required.l1_chain_id,
+            sl_chain_id: config.required.settlement_layer_id(),
             l2_chain_id: config.required.l2_chain_id,
             max_tx_size: config.optional.max_tx_size_bytes,
             estimate_gas_scale_factor: config.optional.estimate_gas_scale_factor,
@@ -1472,7 +1639,20 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
             filters_disabled: config.optional.filters_disabled,
             dummy_verifier: config.remote.dummy_verifier,
             l1_batch_commit_data_generator_mode: config.remote.l1_batch_commit_data_generator_mode,
+            custom_system_contracts: config.remote.custom_system_contracts.clone(),
             timestamp_asserter_address: config.remote.l2_timestamp_asserter_addr,
+            node_metadata: NodeMetadata {
+                role: api::NodeRole::External,
+                version: SERVER_VERSION.to_owned(),
+                components: vec![],
+                pruning: api::NodePruningSettings {
+                    enabled: config.optional.pruning_enabled,
+                    data_retention_sec: config
+                        .optional
+                        .pruning_enabled
+                        .then(|| config.optional.pruning_data_retention().as_secs()),
+                },
+            },
         }
     }
 }
@@ -1491,6 +1671,8 @@ impl From<&ExternalNodeConfig> for TxSenderConfig {
             // We set these values to the maximum since we don't know the actual values
             // and they will be enforced by the main node anyway.
             max_allowed_l2_tx_gas_limit: u64::MAX,
+            max_l2_tx_size_bytes: None,
+            max_l2_tx_calldata_bytes: None,
             validation_computational_gas_limit: u32::MAX,
             chain_id: config.required.l2_chain_id,
             // Does not matter for EN.
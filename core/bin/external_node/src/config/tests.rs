@@ -84,8 +84,12 @@ fn parsing_optional_config_from_empty_env() {
     assert_eq!(config.max_nonce_ahead, 50);
     assert_eq!(config.estimate_gas_scale_factor, 1.2);
     assert_eq!(config.vm_concurrency_limit, 2_048);
+    assert_eq!(config.trace_vm_concurrency_limit, None);
+    assert_eq!(config.vm_execution_max_cycles, None);
+    assert_eq!(config.vm_execution_timeout(), None);
     assert_eq!(config.factory_deps_cache_size(), 128 * BYTES_IN_MEGABYTE);
     assert_eq!(config.latest_values_cache_size(), 128 * BYTES_IN_MEGABYTE);
+    assert_eq!(config.block_state_cache_size(), 32 * BYTES_IN_MEGABYTE);
     assert_eq!(config.merkle_tree_multi_get_chunk_size, 500);
     assert_eq!(
         config.merkle_tree_block_cache_size(),
@@ -118,16 +122,25 @@ fn parsing_optional_config_from_env() {
         ("EN_MAX_NONCE_AHEAD", "100"),
         ("EN_ESTIMATE_GAS_SCALE_FACTOR", "1.5"),
         ("EN_VM_CONCURRENCY_LIMIT", "1000"),
+        ("EN_TRACE_VM_CONCURRENCY_LIMIT", "16"),
+        ("EN_VM_EXECUTION_MAX_CYCLES", "100000000"),
+        ("EN_VM_EXECUTION_TIMEOUT_MS", "5000"),
         ("EN_FACTORY_DEPS_CACHE_SIZE_MB", "64"),
         ("EN_LATEST_VALUES_CACHE_SIZE_MB", "50"),
+        ("EN_BLOCK_STATE_CACHE_SIZE_MB", "0"),
         ("EN_MERKLE_TREE_MULTI_GET_CHUNK_SIZE", "1000"),
         ("EN_MERKLE_TREE_BLOCK_CACHE_SIZE_MB", "32"),
+        ("EN_MERKLE_TREE_RETAINED_L1_BATCHES", "10000"),
+        ("EN_MERKLE_TREE_MAINTENANCE_WINDOW_START_HOUR", "22"),
+        ("EN_MERKLE_TREE_MAINTENANCE_WINDOW_END_HOUR", "4"),
         ("EN_MAX_RESPONSE_BODY_SIZE_MB", "1"),
         (
             "EN_MAX_RESPONSE_BODY_SIZE_OVERRIDES_MB",
             "zks_getProof=100,eth_call=2",
         ),
         ("EN_L1_BATCH_COMMIT_DATA_GENERATOR_MODE", "Validium"),
+        ("EN_COMMITMENT_MODE_TRANSITION_BATCHES", "1000,2000"),
+        ("EN_FORCED_INCLUSION_DEADLINE_BATCHES", "5"),
         ("EN_TIMESTAMP_ASSERTER_MIN_TIME_TILL_END_SEC", "2"),
     ];
     let env_vars = env_vars
@@ -148,13 +161,25 @@ fn parsing_optional_config_from_env() {
     assert_eq!(config.max_nonce_ahead, 100);
     assert_eq!(config.estimate_gas_scale_factor, 1.5);
     assert_eq!(config.vm_concurrency_limit, 1_000);
+    assert_eq!(config.call_vm_concurrency_limit, None);
+    assert_eq!(config.trace_vm_concurrency_limit, Some(16));
+    assert_eq!(config.vm_execution_max_cycles, Some(100_000_000));
+    assert_eq!(config.vm_execution_timeout(), Some(Duration::from_secs(5)));
     assert_eq!(config.factory_deps_cache_size(), 64 * BYTES_IN_MEGABYTE);
     assert_eq!(config.latest_values_cache_size(), 50 * BYTES_IN_MEGABYTE);
+    assert_eq!(config.block_state_cache_size(), 0);
     assert_eq!(config.merkle_tree_multi_get_chunk_size, 1_000);
     assert_eq!(
         config.merkle_tree_block_cache_size(),
         32 * BYTES_IN_MEGABYTE
     );
+    assert_eq!(config.merkle_tree_retained_l1_batches, Some(10_000));
+    assert_eq!(config.merkle_tree_maintenance_window_start_hour, Some(22));
+    assert_eq!(config.merkle_tree_maintenance_window_end_hour, Some(4));
+    assert_eq!(
+        config.merkle_tree_maintenance_compaction_min_pruned_l1_batches,
+        None
+    );
     let max_response_size = config.max_response_body_size();
     assert_eq!(max_response_size.global, BYTES_IN_MEGABYTE);
     assert_eq!(
@@ -174,6 +199,11 @@ fn parsing_optional_config_from_env() {
         config.l1_batch_commit_data_generator_mode,
         L1BatchCommitmentMode::Validium
     );
+    assert_eq!(
+        config.commitment_mode_transition_batches,
+        [L1BatchNumber(1_000), L1BatchNumber(2_000)]
+    );
+    assert_eq!(config.forced_inclusion_deadline_batches, Some(5));
 }
 
 #[test]
@@ -3,6 +3,7 @@ use std::{collections::HashSet, str::FromStr};
 use anyhow::Context as _;
 use clap::Parser;
 use node_builder::ExternalNodeBuilder;
+use zksync_core_leftovers::temp_config_store::{ConfigOverride, ConfigOverrides};
 use zksync_web3_decl::client::{Client, DynClient, L2};
 
 use crate::config::{generate_consensus_secrets, ExternalNodeConfig};
@@ -58,6 +59,12 @@ struct Cli {
         requires = "enable_consensus"
     )]
     consensus_path: Option<std::path::PathBuf>,
+    /// Overrides a single key of a YAML config in the `<file>.<path>=<value>` format, e.g.
+    /// `external_node.main_node_url=http://localhost:3050`. Supported files are `general`, `secrets`,
+    /// `external_node` and `consensus`; requires configs to be loaded from YAML files. Can be specified
+    /// multiple times. Takes precedence over `ZKSYNC_CONFIG_OVERRIDE__*` env vars.
+    #[arg(long = "config-override", value_name = "KEY=VALUE")]
+    config_overrides: Vec<ConfigOverride>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
@@ -128,6 +135,8 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    let config_overrides = ConfigOverrides::new(opt.config_overrides.clone())
+        .context("failed parsing config overrides")?;
     let mut config = if let Some(config_path) = opt.config_path.clone() {
         let secrets_path = opt.secrets_path.clone().unwrap();
         let external_node_config_path = opt.external_node_config_path.clone().unwrap();
@@ -137,13 +146,21 @@ fn main() -> anyhow::Result<()> {
                 "if --config-path and --enable-consensus are specified, then --consensus-path should be used to specify the location of the consensus config"
             );
         }
+        let mut yaml_files = vec!["general", "secrets", "external_node"];
+        if opt.consensus_path.is_some() {
+            yaml_files.push("consensus");
+        }
+        config_overrides.ensure_files(&yaml_files)?;
         ExternalNodeConfig::from_files(
             config_path,
             external_node_config_path,
             secrets_path,
             opt.consensus_path.clone(),
+            &config_overrides,
         )?
     } else {
+        // Overrides are only supported for YAML configs.
+        config_overrides.ensure_files(&[])?;
         ExternalNodeConfig::new().context("Failed to load node configuration")?
     };
 
@@ -156,6 +173,14 @@ fn main() -> anyhow::Result<()> {
         let _rt_guard = runtime.enter();
         config.observability.build_observability()?
     };
+    for config_override in config_overrides.effective() {
+        tracing::info!(
+            "Config override from {}: {}={}",
+            config_override.source,
+            config_override.key(),
+            config_override.display_value()
+        );
+    }
 
     // Build L1 and L2 clients.
     let main_node_url = &config.required.main_node_url;
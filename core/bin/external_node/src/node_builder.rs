@@ -6,15 +6,20 @@ use zksync_block_reverter::NodeRole;
 use zksync_config::{
     configs::{
         api::{HealthCheckConfig, MerkleTreeApiConfig},
-        database::MerkleTreeMode,
+        database::{MerkleTreeConfig, MerkleTreeMode},
         DatabaseSecrets,
     },
     PostgresConfig,
 };
 use zksync_metadata_calculator::{
-    MerkleTreeReaderConfig, MetadataCalculatorConfig, MetadataCalculatorRecoveryConfig,
+    MerkleTreeMaintenanceConfig, MerkleTreeReaderConfig, MetadataCalculatorConfig,
+    MetadataCalculatorRecoveryConfig,
+};
+use zksync_node_api_server::{
+    execution_sandbox::VmWorkloadLimits,
+    tx_sender::ExecutionLimits,
+    web3::{state::InternalApiConfig, Namespace},
 };
-use zksync_node_api_server::web3::Namespace;
 use zksync_node_framework::{
     implementations::layers::{
         batch_status_updater::BatchStatusUpdaterLayer,
@@ -163,8 +168,19 @@ impl ExternalNodeBuilder {
                 .optional
                 .healthcheck_hard_time_limit()
                 .map(|d| d.as_millis() as u64),
+            expose_config: self.config.optional.healthcheck_expose_config,
         };
-        self.node.add_layer(HealthCheckLayer(healthcheck_config));
+        let mut layer = HealthCheckLayer::new(healthcheck_config);
+        if self.config.optional.healthcheck_expose_config {
+            if let Some(effective_config) = self.config.effective_config.clone() {
+                layer = layer.with_effective_config(effective_config);
+            } else {
+                tracing::warn!(
+                    "Exposing effective config is only supported for YAML-based configuration; ignoring"
+                );
+            }
+        }
+        self.node.add_layer(layer);
         Ok(self)
     }
 
@@ -268,7 +284,8 @@ impl ExternalNodeBuilder {
                 self.config.optional.pruning_removal_delay(),
                 self.config.optional.pruning_chunk_size,
                 self.config.optional.pruning_data_retention(),
-            );
+            )
+            .with_retained_addresses(self.config.optional.pruning_retained_addresses.clone());
             self.node.add_layer(layer);
         } else {
             tracing::info!("Pruning is disabled");
@@ -300,7 +317,14 @@ impl ExternalNodeBuilder {
             self.config.diamond_proxy_address(),
             max_batches_to_recheck,
             self.config.optional.l1_batch_commit_data_generator_mode,
-        );
+        )
+        .with_commitment_mode_transitions(
+            self.config
+                .optional
+                .commitment_mode_transition_batches
+                .clone(),
+        )
+        .with_forced_inclusion_deadline(self.config.optional.forced_inclusion_deadline_batches);
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -388,6 +412,26 @@ impl ExternalNodeBuilder {
             layer = layer.with_pruning_config(self.config.optional.pruning_removal_delay());
         }
 
+        // Add retention-based tree pruning and scheduled compaction if configured.
+        let maintenance_config = MerkleTreeMaintenanceConfig::new(&MerkleTreeConfig {
+            retained_l1_batches: self.config.optional.merkle_tree_retained_l1_batches,
+            maintenance_window_start_hour: self
+                .config
+                .optional
+                .merkle_tree_maintenance_window_start_hour,
+            maintenance_window_end_hour: self
+                .config
+                .optional
+                .merkle_tree_maintenance_window_end_hour,
+            maintenance_compaction_min_pruned_l1_batches: self
+                .config
+                .optional
+                .merkle_tree_maintenance_compaction_min_pruned_l1_batches,
+            ..MerkleTreeConfig::default()
+        })
+        .context("invalid Merkle tree maintenance config")?;
+        layer = layer.with_maintenance_config(maintenance_config);
+
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -421,13 +465,24 @@ impl ExternalNodeBuilder {
             initial_writes_cache_size: self.config.optional.initial_writes_cache_size() as u64,
             latest_values_cache_size: self.config.optional.latest_values_cache_size() as u64,
             latest_values_max_block_lag: 20, // reasonable default
+            block_state_cache_size: self.config.optional.block_state_cache_size() as u64,
         };
         let max_vm_concurrency = self.config.optional.vm_concurrency_limit;
+        let vm_workload_limits = VmWorkloadLimits {
+            call: self.config.optional.call_vm_concurrency_limit,
+            estimate: self.config.optional.estimate_vm_concurrency_limit,
+            trace: self.config.optional.trace_vm_concurrency_limit,
+        };
         let tx_sender_layer = TxSenderLayer::new(
             (&self.config).into(),
             postgres_storage_config,
             max_vm_concurrency,
         )
+        .with_vm_workload_limits(vm_workload_limits)
+        .with_execution_limits(ExecutionLimits {
+            max_cycles: self.config.optional.vm_execution_max_cycles,
+            timeout: self.config.optional.vm_execution_timeout(),
+        })
         .with_whitelisted_tokens_for_aa_cache(true);
 
         self.node.add_layer(ProxySinkLayer);
@@ -483,23 +538,33 @@ impl ExternalNodeBuilder {
         }
     }
 
-    fn add_http_web3_api_layer(mut self) -> anyhow::Result<Self> {
+    fn add_http_web3_api_layer(mut self, components: &[Component]) -> anyhow::Result<Self> {
         let optional_config = self.web3_api_optional_config();
         self.node.add_layer(Web3ServerLayer::http(
             self.config.required.http_port,
-            (&self.config).into(),
+            InternalApiConfig::from(&self.config).with_components(
+                components
+                    .iter()
+                    .map(|component| format!("{component:?}"))
+                    .collect(),
+            ),
             optional_config,
         ));
 
         Ok(self)
     }
 
-    fn add_ws_web3_api_layer(mut self) -> anyhow::Result<Self> {
+    fn add_ws_web3_api_layer(mut self, components: &[Component]) -> anyhow::Result<Self> {
         // TODO: Support websocket requests per minute limit
         let optional_config = self.web3_api_optional_config();
         self.node.add_layer(Web3ServerLayer::ws(
             self.config.required.ws_port,
-            (&self.config).into(),
+            InternalApiConfig::from(&self.config).with_components(
+                components
+                    .iter()
+                    .map(|component| format!("{component:?}"))
+                    .collect(),
+            ),
             optional_config,
         ));
 
@@ -547,6 +612,9 @@ impl ExternalNodeBuilder {
                     drop_storage_key_preimages: config
                         .experimental
                         .snapshots_recovery_drop_storage_key_preimages,
+                    max_chunks_in_memory: config
+                        .optional
+                        .snapshots_recovery_postgres_max_chunks_in_memory,
                     object_store_config: config.optional.snapshots_recovery_object_store.clone(),
                 });
         self.node.add_layer(ExternalNodeInitStrategyLayer {
@@ -614,7 +682,7 @@ impl ExternalNodeBuilder {
                         .add_tree_api_client_layer()?
                         .add_main_node_fee_params_fetcher_layer()?
                         .add_tx_sender_layer()?
-                        .add_http_web3_api_layer()?;
+                        .add_http_web3_api_layer(&components)?;
                 }
                 Component::WsApi => {
                     self = self
@@ -623,7 +691,7 @@ impl ExternalNodeBuilder {
                         .add_tree_api_client_layer()?
                         .add_main_node_fee_params_fetcher_layer()?
                         .add_tx_sender_layer()?
-                        .add_ws_web3_api_layer()?;
+                        .add_ws_web3_api_layer(&components)?;
                 }
                 Component::Tree => {
                     // Right now, distributed mode for EN is not fully supported, e.g. there are some
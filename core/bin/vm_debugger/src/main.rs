@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::Parser;
+use zksync_multivm::{
+    interface::{
+        storage::{ReadStorage, StorageView},
+        InspectExecutionMode, L2BlockEnv, VmFactory, VmInterface, VmInterfaceExt,
+    },
+    tracers::stepping::{Breakpoint, StepTracer},
+    vm_latest::{self, HistoryEnabled, ToTracerPointer},
+};
+use zksync_types::{url::SensitiveUrl, L2ChainId, H256};
+
+use crate::{
+    repl::Repl,
+    source::{load_dump, load_from_database, DebugInput},
+};
+
+mod repl;
+mod source;
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "Interactive step debugger for VM transactions",
+    long_about = None
+)]
+struct Cli {
+    /// Path to a VM dump (e.g., produced by the VM playground or a VM fuzzing artifact) to load the transaction from.
+    #[arg(
+        long,
+        required_unless_present = "database_url",
+        conflicts_with = "database_url"
+    )]
+    dump: Option<PathBuf>,
+    /// Postgres URL of the node database to load the transaction from.
+    #[arg(long, requires = "tx_hash")]
+    database_url: Option<SensitiveUrl>,
+    /// L2 chain ID; only used when loading the transaction from the database.
+    #[arg(long, default_value = "270")]
+    chain_id: L2ChainId,
+    /// Hash of the debugged transaction. If loading from a VM dump, defaults to the last transaction in the dump.
+    #[arg(long)]
+    tx_hash: Option<H256>,
+    /// Breakpoints in the `opcode:<name>`, `address:<address>` or `storage:<key>` format. If not specified,
+    /// the execution is paused before the first instruction of the transaction.
+    #[arg(long = "break", short = 'b')]
+    breakpoints: Vec<Breakpoint>,
+}
+
+/// Re-executes transactions preceding the debugged one, and then executes the debugged transaction with the stepping tracer.
+fn debug<S: ReadStorage + 'static>(
+    input: DebugInput<S>,
+    breakpoints: Vec<Breakpoint>,
+) -> anyhow::Result<()> {
+    let storage = StorageView::new(input.storage).to_rc_ptr();
+    let mut vm: vm_latest::Vm<_, HistoryEnabled> =
+        vm_latest::Vm::new(input.l1_batch_env, input.system_env, storage);
+
+    for (i, l2_block) in input.l2_blocks.into_iter().enumerate() {
+        if i > 0 {
+            // First block is already set.
+            vm.start_new_l2_block(L2BlockEnv {
+                number: l2_block.number.0,
+                timestamp: l2_block.timestamp,
+                prev_block_hash: l2_block.prev_block_hash,
+                max_virtual_blocks_to_create: l2_block.virtual_blocks,
+            });
+        }
+
+        for tx in l2_block.txs {
+            let tx_hash = tx.hash();
+            if tx_hash != input.tx_hash {
+                let (compression_result, _) =
+                    vm.execute_transaction_with_bytecode_compression(tx, true);
+                compression_result.with_context(|| {
+                    format!("failed compressing bytecodes for transaction {tx_hash:?}")
+                })?;
+                continue;
+            }
+
+            println!("Debugging transaction {tx_hash:?}; type `help` for the list of commands");
+            let start_paused = breakpoints.is_empty();
+            let tracer = StepTracer::new(Repl::default(), breakpoints, start_paused);
+            vm.push_transaction(tx);
+            let result = vm.inspect(
+                &mut tracer.into_tracer_pointer().into(),
+                InspectExecutionMode::OneTx,
+            );
+            println!("Execution result: {:?}", result.result);
+            return Ok(());
+        }
+    }
+    anyhow::bail!(
+        "transaction {:?} is not found in the loaded L1 batch",
+        input.tx_hash
+    )
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    if let Some(database_url) = cli.database_url {
+        let tx_hash = cli.tx_hash.context("transaction hash is not specified")?;
+        let input = load_from_database(database_url, cli.chain_id, tx_hash).await?;
+        // Postgres storage blocks on async calls internally, so the VM must run on a blocking thread.
+        tokio::task::spawn_blocking(move || debug(input, cli.breakpoints)).await?
+    } else {
+        let path = cli.dump.context("VM dump path is not specified")?;
+        debug(load_dump(&path, cli.tx_hash)?, cli.breakpoints)
+    }
+}
@@ -0,0 +1,289 @@
+//! Interactive front-end for the stepping tracer.
+
+use std::io::{self, BufRead, Write};
+
+use anyhow::Context as _;
+use zksync_multivm::tracers::stepping::{
+    Breakpoint, FrameMemory, Register, StepAction, StepDebugger, VmStep,
+};
+
+const HELP: &str = "\
+Commands:
+  s, step [N]           execute N instructions (default: 1); an empty line steps once
+  c, continue           run until the next breakpoint
+  b, break <BP>         add a breakpoint: opcode:<name>, address:<address> or storage:<key>
+  d, delete <INDEX>     remove a breakpoint
+  l, breakpoints        list breakpoints
+  r, regs               show registers
+  st, stack [N]         show top N stack slots of the current frame (default: 8)
+  m, mem <OFFSET> [LEN] show heap bytes of the current frame (default length: 128)
+  aux <OFFSET> [LEN]    show auxiliary heap bytes of the current frame
+  i, info               show the current instruction
+  q, quit               abort the execution
+  h, help               show this help";
+
+const DEFAULT_STACK_SLOTS: u32 = 8;
+const DEFAULT_MEMORY_LEN: u32 = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Step(u64),
+    Continue,
+    Break(Breakpoint),
+    Delete(usize),
+    ListBreakpoints,
+    Registers,
+    Stack(u32),
+    Heap { offset: u32, len: u32 },
+    AuxHeap { offset: u32, len: u32 },
+    Info,
+    Quit,
+    Help,
+}
+
+impl Command {
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let mut parts = line.split_whitespace();
+        let Some(name) = parts.next() else {
+            return Ok(Self::Step(1));
+        };
+        let args: Vec<_> = parts.collect();
+        let arg = |idx: usize| args.get(idx).copied();
+
+        let command = match name {
+            "s" | "step" => Self::Step(arg(0).map(parse_number).transpose()?.unwrap_or(1)),
+            "c" | "continue" => Self::Continue,
+            "b" | "break" => Self::Break(arg(0).context("breakpoint is not specified")?.parse()?),
+            "d" | "delete" => {
+                let index = arg(0).context("breakpoint index is not specified")?;
+                Self::Delete(index.parse().context("invalid breakpoint index")?)
+            }
+            "l" | "breakpoints" => Self::ListBreakpoints,
+            "r" | "regs" => Self::Registers,
+            "st" | "stack" => {
+                let slots = arg(0).map(parse_number).transpose()?;
+                Self::Stack(slots.unwrap_or(DEFAULT_STACK_SLOTS.into()).try_into()?)
+            }
+            "m" | "mem" | "aux" => {
+                let offset = parse_number(arg(0).context("memory offset is not specified")?)?;
+                let len = arg(1).map(parse_number).transpose()?;
+                let offset = offset.try_into().context("memory offset is too large")?;
+                let len = len
+                    .unwrap_or(DEFAULT_MEMORY_LEN.into())
+                    .try_into()
+                    .context("memory length is too large")?;
+                if name == "aux" {
+                    Self::AuxHeap { offset, len }
+                } else {
+                    Self::Heap { offset, len }
+                }
+            }
+            "i" | "info" => Self::Info,
+            "q" | "quit" => Self::Quit,
+            "h" | "help" => Self::Help,
+            _ => anyhow::bail!("unknown command `{name}`; type `help` for the list of commands"),
+        };
+        Ok(command)
+    }
+}
+
+/// Parses a hex (`0x`-prefixed) or decimal number.
+fn parse_number(s: &str) -> anyhow::Result<u64> {
+    let number = match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    number.with_context(|| format!("invalid number `{s}`"))
+}
+
+/// Debugger reading commands from stdin.
+#[derive(Debug, Default)]
+pub(crate) struct Repl {
+    /// Number of instructions to execute before prompting again.
+    remaining_steps: u64,
+}
+
+impl Repl {
+    fn print_step(step: &VmStep) {
+        println!(
+            "[cycle {}] depth {} | pc {} | sp {} | ergs {}",
+            step.cycle, step.depth, step.pc, step.sp, step.ergs_remaining
+        );
+        println!(
+            "  contract {:?} (code {:?}), sender {:?}",
+            step.this_address, step.code_address, step.msg_sender
+        );
+        print!("  > {}", step.opcode);
+        if let Some(access) = &step.storage_access {
+            let kind = if access.is_transient {
+                "transient "
+            } else {
+                ""
+            };
+            print!(" | {kind}slot {:#x} of {:?}", access.key, access.address);
+            if let Some(value) = access.value {
+                print!(" = {value:#x}");
+            }
+            if let Some(value) = access.written_value {
+                print!(" <- {value:#x}");
+            }
+        }
+        println!();
+    }
+
+    fn format_register(register: &Register) -> String {
+        let tag = if register.is_pointer { " (ptr)" } else { "" };
+        format!("{:#066x}{tag}", register.value)
+    }
+
+    fn print_memory(offset: u32, bytes: &[u8]) {
+        for (i, chunk) in bytes.chunks(32).enumerate() {
+            let chunk_offset = offset as usize + i * 32;
+            println!("  {chunk_offset:#08x}: {}", hex::encode(chunk));
+        }
+    }
+
+    fn execute(
+        &mut self,
+        command: Command,
+        step: &VmStep,
+        memory: &dyn FrameMemory,
+        breakpoints: &mut Vec<Breakpoint>,
+    ) -> Option<StepAction> {
+        match command {
+            Command::Step(0) => {}
+            Command::Step(count) => {
+                self.remaining_steps = count - 1;
+                return Some(StepAction::Step);
+            }
+            Command::Continue => return Some(StepAction::Continue),
+            Command::Quit => return Some(StepAction::Abort),
+            Command::Break(breakpoint) => {
+                println!("Breakpoint #{}: {breakpoint}", breakpoints.len());
+                breakpoints.push(breakpoint);
+            }
+            Command::Delete(index) => {
+                if index < breakpoints.len() {
+                    let breakpoint = breakpoints.remove(index);
+                    println!("Removed breakpoint #{index}: {breakpoint}");
+                } else {
+                    println!("No breakpoint #{index}");
+                }
+            }
+            Command::ListBreakpoints => {
+                if breakpoints.is_empty() {
+                    println!("No breakpoints");
+                }
+                for (i, breakpoint) in breakpoints.iter().enumerate() {
+                    println!("  #{i}: {breakpoint}");
+                }
+            }
+            Command::Registers => {
+                for (i, register) in step.registers.iter().enumerate() {
+                    println!("  r{:<2} {}", i + 1, Self::format_register(register));
+                }
+            }
+            Command::Stack(slots) => {
+                let sp = u32::try_from(step.sp).unwrap_or(u32::MAX);
+                if sp == 0 {
+                    println!("Stack is empty");
+                }
+                for slot in (sp.saturating_sub(slots)..sp).rev() {
+                    let register = memory.read_stack(slot);
+                    println!("  [{slot}] {}", Self::format_register(&register));
+                }
+            }
+            Command::Heap { offset, len } => {
+                Self::print_memory(offset, &memory.read_heap(offset, len));
+            }
+            Command::AuxHeap { offset, len } => {
+                Self::print_memory(offset, &memory.read_aux_heap(offset, len));
+            }
+            Command::Info => Self::print_step(step),
+            Command::Help => println!("{HELP}"),
+        }
+        None
+    }
+}
+
+impl StepDebugger for Repl {
+    fn on_pause(
+        &mut self,
+        step: &VmStep,
+        breakpoint: Option<usize>,
+        memory: &dyn FrameMemory,
+        breakpoints: &mut Vec<Breakpoint>,
+    ) -> StepAction {
+        if let Some(index) = breakpoint {
+            println!("Hit breakpoint #{index}: {}", breakpoints[index]);
+            self.remaining_steps = 0;
+        } else if self.remaining_steps > 0 {
+            self.remaining_steps -= 1;
+            return StepAction::Step;
+        }
+        Self::print_step(step);
+
+        let mut stdin = io::stdin().lock();
+        loop {
+            print!("(debug) ");
+            io::stdout().flush().ok();
+            let mut line = String::new();
+            match stdin.read_line(&mut line) {
+                Ok(0) | Err(_) => return StepAction::Abort, // EOF
+                Ok(_) => {}
+            }
+            let command = match Command::parse(&line) {
+                Ok(command) => command,
+                Err(err) => {
+                    println!("Error: {err:#}");
+                    continue;
+                }
+            };
+            if let Some(action) = self.execute(command, step, memory, breakpoints) {
+                return action;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{Address, U256};
+
+    use super::*;
+
+    #[test]
+    fn parsing_commands() {
+        assert_eq!(Command::parse("").unwrap(), Command::Step(1));
+        assert_eq!(Command::parse("  \n").unwrap(), Command::Step(1));
+        assert_eq!(Command::parse("step 10").unwrap(), Command::Step(10));
+        assert_eq!(Command::parse("s 0x10").unwrap(), Command::Step(16));
+        assert_eq!(Command::parse("c").unwrap(), Command::Continue);
+        assert_eq!(
+            Command::parse("b address:0x0000000000000000000000000000000000008006").unwrap(),
+            Command::Break(Breakpoint::Address(Address::from_low_u64_be(0x8006)))
+        );
+        assert_eq!(
+            Command::parse("break storage:0x1").unwrap(),
+            Command::Break(Breakpoint::StorageKey(U256::one()))
+        );
+        assert_eq!(Command::parse("d 2").unwrap(), Command::Delete(2));
+        assert_eq!(Command::parse("stack").unwrap(), Command::Stack(8));
+        assert_eq!(
+            Command::parse("mem 0x40").unwrap(),
+            Command::Heap {
+                offset: 64,
+                len: 128
+            }
+        );
+        assert_eq!(
+            Command::parse("aux 0 32").unwrap(),
+            Command::AuxHeap { offset: 0, len: 32 }
+        );
+
+        Command::parse("b").unwrap_err();
+        Command::parse("b sload").unwrap_err();
+        Command::parse("mem").unwrap_err();
+        Command::parse("jump 10").unwrap_err();
+    }
+}
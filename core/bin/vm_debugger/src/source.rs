@@ -0,0 +1,111 @@
+//! Loading inputs for the debugged transaction.
+
+use std::path::Path;
+
+use anyhow::Context as _;
+use tokio::runtime::Handle;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_multivm::interface::{storage::StorageSnapshot, utils::VmDump, L1BatchEnv, SystemEnv};
+use zksync_state::PostgresStorage;
+use zksync_types::{
+    block::L2BlockExecutionData, url::SensitiveUrl, L1BatchNumber, L2ChainId, H256,
+};
+use zksync_vm_executor::storage::L1BatchParamsProvider;
+
+/// Inputs necessary to re-execute an L1 batch up to (and including) the debugged transaction.
+#[derive(Debug)]
+pub(crate) struct DebugInput<S> {
+    pub storage: S,
+    pub l1_batch_env: L1BatchEnv,
+    pub system_env: SystemEnv,
+    pub l2_blocks: Vec<L2BlockExecutionData>,
+    pub tx_hash: H256,
+}
+
+/// Loads a VM dump from a file. Besides plain dumps (e.g., produced by the VM playground or shadow VM),
+/// VM fuzzing artifacts are supported. If `tx_hash` is not specified, the last transaction in the dump is debugged.
+pub(crate) fn load_dump(
+    path: &Path,
+    tx_hash: Option<H256>,
+) -> anyhow::Result<DebugInput<StorageSnapshot>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed reading VM dump from {}", path.display()))?;
+    let value: serde_json::Value =
+        serde_json::from_str(&contents).context("VM dump is not valid JSON")?;
+    let dump = value.pointer("/failure/dump").cloned().unwrap_or(value);
+    let dump: VmDump = serde_json::from_value(dump).context("failed deserializing VM dump")?;
+
+    let tx_hash = match tx_hash {
+        Some(hash) => hash,
+        None => dump
+            .l2_blocks
+            .iter()
+            .flat_map(|block| &block.txs)
+            .last()
+            .context("VM dump has no transactions")?
+            .hash(),
+    };
+    Ok(DebugInput {
+        storage: dump.storage,
+        l1_batch_env: dump.l1_batch_env,
+        system_env: dump.system_env,
+        l2_blocks: dump.l2_blocks,
+        tx_hash,
+    })
+}
+
+/// Loads the L1 batch containing the specified transaction from the node database. The returned storage
+/// reflects the state before the batch, so the batch is re-executed from its start.
+pub(crate) async fn load_from_database(
+    database_url: SensitiveUrl,
+    chain_id: L2ChainId,
+    tx_hash: H256,
+) -> anyhow::Result<DebugInput<PostgresStorage<'static>>> {
+    let pool = ConnectionPool::<Core>::singleton(database_url)
+        .build()
+        .await
+        .context("failed connecting to Postgres")?;
+    let mut conn = pool.connection_tagged("vm_debugger").await?;
+
+    let receipt = conn
+        .transactions_web3_dal()
+        .get_transaction_receipts(&[tx_hash])
+        .await?
+        .into_iter()
+        .next()
+        .with_context(|| format!("transaction {tx_hash:?} is not found"))?;
+    let l1_batch_number = receipt
+        .l1_batch_number
+        .with_context(|| format!("transaction {tx_hash:?} is not included into an L1 batch yet"))?;
+    let l1_batch_number = L1BatchNumber(l1_batch_number.as_u32());
+    anyhow::ensure!(
+        l1_batch_number > L1BatchNumber(0),
+        "transactions in the genesis L1 batch cannot be debugged"
+    );
+
+    let params_provider = L1BatchParamsProvider::new(&mut conn).await?;
+    let (system_env, l1_batch_env, _) = params_provider
+        .load_l1_batch_env(&mut conn, l1_batch_number, u32::MAX, chain_id)
+        .await?
+        .with_context(|| format!("L1 batch #{l1_batch_number} is not found"))?;
+    let l2_blocks = conn
+        .transactions_dal()
+        .get_l2_blocks_to_execute_for_l1_batch(l1_batch_number)
+        .await?;
+    let (_, last_l2_block_in_prev_batch) = conn
+        .blocks_dal()
+        .get_l2_block_range_of_l1_batch(l1_batch_number - 1)
+        .await?
+        .with_context(|| format!("L1 batch #{} is not found", l1_batch_number - 1))?;
+
+    let storage =
+        PostgresStorage::new_async(Handle::current(), conn, last_l2_block_in_prev_batch, true)
+            .await?;
+    Ok(DebugInput {
+        storage,
+        l1_batch_env,
+        system_env,
+        l2_blocks,
+        tx_hash,
+    })
+}
@@ -11,21 +11,25 @@ use zksync_config::{
         },
         fri_prover_group::FriProverGroupConfig,
         house_keeper::HouseKeeperConfig,
-        BasicWitnessInputProducerConfig, ContractsConfig, DataAvailabilitySecrets, DatabaseSecrets,
-        ExperimentalVmConfig, ExternalPriceApiClientConfig, FriProofCompressorConfig,
-        FriProverConfig, FriProverGatewayConfig, FriWitnessGeneratorConfig,
-        FriWitnessVectorGeneratorConfig, L1Secrets, ObservabilityConfig, PrometheusConfig,
-        ProofDataHandlerConfig, ProtectiveReadsWriterConfig, Secrets,
+        BasicWitnessInputProducerConfig, BatchVerifierConfig, ContractsConfig,
+        DataAvailabilitySecrets, DatabaseSecrets, ExperimentalVmConfig, ExternalBlockBuilderConfig,
+        ExternalPriceApiClientConfig, FriProofCompressorConfig, FriProverConfig,
+        FriProverGatewayConfig, FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig,
+        L1Secrets, ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig,
+        ProtectiveReadsWriterConfig, Secrets,
     },
     ApiConfig, BaseTokenAdjusterConfig, ContractVerifierConfig, DAClientConfig, DADispatcherConfig,
     DBConfig, EthConfig, EthWatchConfig, ExternalProofIntegrationApiConfig, GasAdjusterConfig,
     GenesisConfig, ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
 };
 use zksync_core_leftovers::{
-    temp_config_store::{read_yaml_repr, TempConfigStore},
+    temp_config_store::{
+        read_yaml_repr_with_overrides, ConfigOverride, ConfigOverrides, TempConfigStore,
+    },
     Component, Components,
 };
 use zksync_env_config::FromEnv;
+use zksync_protobuf_config::proto;
 
 use crate::node_builder::MainNodeBuilder;
 
@@ -62,6 +66,12 @@ struct Cli {
     /// Path to the yaml with genesis. If set, it will be used instead of env vars.
     #[arg(long)]
     genesis_path: Option<std::path::PathBuf>,
+    /// Overrides a single key of a YAML config in the `<file>.<path>=<value>` format, e.g.
+    /// `general.api.web3_json_rpc.http_port=3050`. Supported files are `general`, `secrets`, `contracts`,
+    /// `wallets` and `genesis`; the corresponding config must be loaded from a YAML file. Can be specified
+    /// multiple times. Takes precedence over `ZKSYNC_CONFIG_OVERRIDE__*` env vars.
+    #[arg(long = "config-override", value_name = "KEY=VALUE")]
+    config_overrides: Vec<ConfigOverride>,
     /// Used to enable node framework.
     /// Now the node framework is used by default and this argument is left for backward compatibility.
     #[arg(long)]
@@ -90,6 +100,21 @@ fn main() -> anyhow::Result<()> {
     // Load env config and use it if file config is not provided
     let tmp_config = load_env_config()?;
 
+    let config_overrides =
+        ConfigOverrides::new(opt.config_overrides).context("failed parsing config overrides")?;
+    let yaml_files = [
+        ("general", opt.config_path.is_some()),
+        ("secrets", opt.secrets_path.is_some()),
+        ("contracts", opt.contracts_config_path.is_some()),
+        ("wallets", opt.wallets_path.is_some()),
+        ("genesis", opt.genesis_path.is_some()),
+    ];
+    let yaml_files: Vec<_> = yaml_files
+        .into_iter()
+        .filter_map(|(file, is_loaded)| is_loaded.then_some(file))
+        .collect();
+    config_overrides.ensure_files(&yaml_files)?;
+
     let configs = match opt.config_path {
         None => {
             let mut configs = tmp_config.general();
@@ -97,21 +122,31 @@ fn main() -> anyhow::Result<()> {
                 config::read_consensus_config().context("read_consensus_config()")?;
             configs
         }
-        Some(path) => {
-            read_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&path)
-                .context("failed decoding general YAML config")?
-        }
+        Some(path) => read_yaml_repr_with_overrides::<proto::general::GeneralConfig>(
+            &path,
+            "general",
+            &config_overrides,
+        )
+        .context("failed decoding general YAML config")?,
     };
 
     let wallets = match opt.wallets_path {
         None => tmp_config.wallets(),
-        Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::wallets::Wallets>(&path)
-            .context("failed decoding wallets YAML config")?,
+        Some(path) => read_yaml_repr_with_overrides::<proto::wallets::Wallets>(
+            &path,
+            "wallets",
+            &config_overrides,
+        )
+        .context("failed decoding wallets YAML config")?,
     };
 
     let secrets: Secrets = match opt.secrets_path {
-        Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::secrets::Secrets>(&path)
-            .context("failed decoding secrets YAML config")?,
+        Some(path) => read_yaml_repr_with_overrides::<proto::secrets::Secrets>(
+            &path,
+            "secrets",
+            &config_overrides,
+        )
+        .context("failed decoding secrets YAML config")?,
         None => Secrets {
             consensus: config::read_consensus_secrets().context("read_consensus_secrets()")?,
             database: DatabaseSecrets::from_env().ok(),
@@ -122,14 +157,22 @@ fn main() -> anyhow::Result<()> {
 
     let contracts_config = match opt.contracts_config_path {
         None => ContractsConfig::from_env().context("contracts_config")?,
-        Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::contracts::Contracts>(&path)
-            .context("failed decoding contracts YAML config")?,
+        Some(path) => read_yaml_repr_with_overrides::<proto::contracts::Contracts>(
+            &path,
+            "contracts",
+            &config_overrides,
+        )
+        .context("failed decoding contracts YAML config")?,
     };
 
     let genesis = match opt.genesis_path {
         None => GenesisConfig::from_env().context("Genesis config")?,
-        Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::genesis::Genesis>(&path)
-            .context("failed decoding genesis YAML config")?,
+        Some(path) => read_yaml_repr_with_overrides::<proto::genesis::Genesis>(
+            &path,
+            "genesis",
+            &config_overrides,
+        )
+        .context("failed decoding genesis YAML config")?,
     };
     let observability_config = configs
         .observability
@@ -143,6 +186,14 @@ fn main() -> anyhow::Result<()> {
         let _context_guard = node.runtime_handle().enter();
         observability_config.install()?
     };
+    for config_override in config_overrides.effective() {
+        tracing::info!(
+            "Config override from {}: {}={}",
+            config_override.source,
+            config_override.key(),
+            config_override.display_value()
+        );
+    }
 
     if opt.genesis {
         // If genesis is requested, we don't need to run the node.
@@ -186,6 +237,7 @@ fn load_env_config() -> anyhow::Result<TempConfigStore> {
         da_dispatcher_config: DADispatcherConfig::from_env().ok(),
         protective_reads_writer_config: ProtectiveReadsWriterConfig::from_env().ok(),
         basic_witness_input_producer_config: BasicWitnessInputProducerConfig::from_env().ok(),
+        batch_verifier_config: BatchVerifierConfig::from_env().ok(),
         core_object_store: ObjectStoreConfig::from_env().ok(),
         base_token_adjuster_config: BaseTokenAdjusterConfig::from_env().ok(),
         commitment_generator: None,
@@ -196,5 +248,7 @@ fn load_env_config() -> anyhow::Result<TempConfigStore> {
         experimental_vm_config: ExperimentalVmConfig::from_env().ok(),
         prover_job_monitor_config: None,
         timestamp_asserter_config: TimestampAsserterConfig::from_env().ok(),
+        external_block_builder_config: ExternalBlockBuilderConfig::from_env().ok(),
+        token_price_config: None,
     })
 }
@@ -12,9 +12,10 @@ use zksync_config::{
     ContractsConfig, GenesisConfig,
 };
 use zksync_core_leftovers::Component;
-use zksync_metadata_calculator::MetadataCalculatorConfig;
+use zksync_metadata_calculator::{MerkleTreeMaintenanceConfig, MetadataCalculatorConfig};
 use zksync_node_api_server::{
-    tx_sender::{TimestampAsserterParams, TxSenderConfig},
+    execution_sandbox::VmWorkloadLimits,
+    tx_sender::{ExecutionLimits, TimestampAsserterParams, TxSenderConfig},
     web3::{state::InternalApiConfig, Namespace},
 };
 use zksync_node_framework::{
@@ -34,6 +35,7 @@ use zksync_node_framework::{
         da_dispatcher::DataAvailabilityDispatcherLayer,
         eth_sender::{EthTxAggregatorLayer, EthTxManagerLayer},
         eth_watch::EthWatchLayer,
+        etherscan_api::EtherscanApiLayer,
         external_proof_integration_api::ExternalProofIntegrationApiLayer,
         gas_adjuster::GasAdjusterLayer,
         healtcheck_server::HealthCheckLayer,
@@ -57,13 +59,15 @@ use zksync_node_framework::{
             main_batch_executor::MainBatchExecutorLayer, mempool_io::MempoolIOLayer,
             output_handler::OutputHandlerLayer, RocksdbStorageOptions, StateKeeperLayer,
         },
+        transaction_decoder::TransactionDecoderLayer,
         vm_runner::{
-            bwip::BasicWitnessInputProducerLayer, playground::VmPlaygroundLayer,
-            protective_reads::ProtectiveReadsWriterLayer,
+            batch_verifier::BatchVerifierLayer, bwip::BasicWitnessInputProducerLayer,
+            playground::VmPlaygroundLayer, protective_reads::ProtectiveReadsWriterLayer,
         },
         web3_api::{
             caches::MempoolCacheLayer,
             server::{Web3ServerLayer, Web3ServerOptionalConfig},
+            token_prices::TokenPriceCacheLayer,
             tree_api_client::TreeApiClientLayer,
             tx_sender::{PostgresStorageCachesConfig, TxSenderLayer},
             tx_sink::MasterPoolSinkLayer,
@@ -71,6 +75,7 @@ use zksync_node_framework::{
     },
     service::{ZkStackService, ZkStackServiceBuilder},
 };
+use zksync_protobuf_config::{encode_json_repr, proto};
 use zksync_types::{
     pubdata_da::PubdataSendingMode, settlement::SettlementMode, SHARED_BRIDGE_ETHER_TOKEN_ADDRESS,
 };
@@ -224,7 +229,9 @@ impl MainNodeBuilder {
             &operations_manager_env_config,
             &state_keeper_env_config,
         );
-        let mut layer = MetadataCalculatorLayer::new(metadata_calculator_config);
+        let maintenance_config = MerkleTreeMaintenanceConfig::new(&merkle_tree_env_config)?;
+        let mut layer = MetadataCalculatorLayer::new(metadata_calculator_config)
+            .with_maintenance_config(maintenance_config);
         if with_tree_api {
             let merkle_tree_api_config = try_load_config!(self.configs.api_config).merkle_tree;
             layer = layer.with_tree_api_config(merkle_tree_api_config);
@@ -244,7 +251,7 @@ impl MainNodeBuilder {
             sk_config.l2_block_seal_queue_capacity,
         )
         .with_protective_reads_persistence_enabled(sk_config.protective_reads_persistence_enabled);
-        let mempool_io_layer = MempoolIOLayer::new(
+        let mut mempool_io_layer = MempoolIOLayer::new(
             self.genesis_config.l2_chain_id,
             sk_config.clone(),
             try_load_config!(self.configs.mempool_config),
@@ -252,6 +259,9 @@ impl MainNodeBuilder {
             self.contracts_config.l2_da_validator_addr,
             self.genesis_config.l1_batch_commit_data_generator_mode,
         );
+        if let Some(config) = self.configs.external_block_builder_config.clone() {
+            mempool_io_layer = mempool_io_layer.with_external_block_builder(config);
+        }
         let db_config = try_load_config!(self.configs.db_config);
         let experimental_vm_config = self
             .configs
@@ -288,17 +298,34 @@ impl MainNodeBuilder {
     }
 
     fn add_proof_data_handler_layer(mut self) -> anyhow::Result<Self> {
-        self.node.add_layer(ProofDataHandlerLayer::new(
-            try_load_config!(self.configs.proof_data_handler_config),
+        let proof_data_handler_config = try_load_config!(self.configs.proof_data_handler_config);
+        let mut layer = ProofDataHandlerLayer::new(
+            proof_data_handler_config.clone(),
             self.genesis_config.l1_batch_commit_data_generator_mode,
             self.genesis_config.l2_chain_id,
-        ));
+        );
+        if proof_data_handler_config
+            .marketplace_config
+            .marketplace_support
+        {
+            layer = layer.with_proof_verifier(self.contracts_config.diamond_proxy_addr);
+        }
+        self.node.add_layer(layer);
         Ok(self)
     }
 
     fn add_healthcheck_layer(mut self) -> anyhow::Result<Self> {
         let healthcheck_config = try_load_config!(self.configs.api_config).healthcheck;
-        self.node.add_layer(HealthCheckLayer(healthcheck_config));
+        let mut layer = HealthCheckLayer::new(healthcheck_config.clone());
+        if healthcheck_config.expose_config {
+            let mut effective_config = self.configs.clone();
+            effective_config.redact_secrets();
+            let effective_config =
+                encode_json_repr::<proto::general::GeneralConfig>(&effective_config)
+                    .context("failed encoding effective config")?;
+            layer = layer.with_effective_config(effective_config);
+        }
+        self.node.add_layer(layer);
         Ok(self)
     }
 
@@ -324,6 +351,7 @@ impl MainNodeBuilder {
             initial_writes_cache_size: rpc_config.initial_writes_cache_size() as u64,
             latest_values_cache_size: rpc_config.latest_values_cache_size() as u64,
             latest_values_max_block_lag: rpc_config.latest_values_max_block_lag(),
+            block_state_cache_size: rpc_config.block_state_cache_size() as u64,
         };
         let vm_config = self
             .configs
@@ -347,7 +375,17 @@ impl MainNodeBuilder {
             postgres_storage_caches_config,
             rpc_config.vm_concurrency_limit(),
         );
-        let layer = layer.with_vm_mode(vm_config.api_fast_vm_mode);
+        let layer = layer
+            .with_vm_mode(vm_config.api_fast_vm_mode)
+            .with_vm_workload_limits(VmWorkloadLimits {
+                call: rpc_config.call_vm_concurrency_limit,
+                estimate: rpc_config.estimate_vm_concurrency_limit,
+                trace: rpc_config.trace_vm_concurrency_limit,
+            })
+            .with_execution_limits(ExecutionLimits {
+                max_cycles: rpc_config.vm_execution_max_cycles,
+                timeout: rpc_config.vm_execution_timeout(),
+            });
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -361,6 +399,13 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_token_price_cache_layer(mut self) -> anyhow::Result<Self> {
+        if let Some(config) = self.configs.token_price_config.clone() {
+            self.node.add_layer(TokenPriceCacheLayer::new(config));
+        }
+        Ok(self)
+    }
+
     fn add_tree_api_client_layer(mut self) -> anyhow::Result<Self> {
         let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
         self.node
@@ -368,7 +413,7 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
-    fn add_http_web3_api_layer(mut self) -> anyhow::Result<Self> {
+    fn add_http_web3_api_layer(mut self, components: &[Component]) -> anyhow::Result<Self> {
         let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
         let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
         let with_debug_namespace = state_keeper_config.save_call_traces;
@@ -397,14 +442,20 @@ impl MainNodeBuilder {
         };
         self.node.add_layer(Web3ServerLayer::http(
             rpc_config.http_port,
-            InternalApiConfig::new(&rpc_config, &self.contracts_config, &self.genesis_config),
+            InternalApiConfig::new(&rpc_config, &self.contracts_config, &self.genesis_config)
+                .with_components(
+                    components
+                        .iter()
+                        .map(|component| format!("{component:?}"))
+                        .collect(),
+                ),
             optional_config,
         ));
 
         Ok(self)
     }
 
-    fn add_ws_web3_api_layer(mut self) -> anyhow::Result<Self> {
+    fn add_ws_web3_api_layer(mut self, components: &[Component]) -> anyhow::Result<Self> {
         let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
         let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
         let circuit_breaker_config = try_load_config!(self.configs.circuit_breaker_config);
@@ -438,7 +489,13 @@ impl MainNodeBuilder {
         };
         self.node.add_layer(Web3ServerLayer::ws(
             rpc_config.ws_port,
-            InternalApiConfig::new(&rpc_config, &self.contracts_config, &self.genesis_config),
+            InternalApiConfig::new(&rpc_config, &self.contracts_config, &self.genesis_config)
+                .with_components(
+                    components
+                        .iter()
+                        .map(|component| format!("{component:?}"))
+                        .collect(),
+                ),
             optional_config,
         ));
 
@@ -503,6 +560,15 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_etherscan_api_layer(mut self) -> anyhow::Result<Self> {
+        let config = try_load_config!(self.configs.api_config).etherscan;
+        self.node.add_layer(EtherscanApiLayer::new(
+            config,
+            self.genesis_config.l2_chain_id,
+        ));
+        Ok(self)
+    }
+
     fn add_consensus_layer(mut self) -> anyhow::Result<Self> {
         self.node.add_layer(MainNodeConsensusLayer {
             config: self
@@ -600,6 +666,17 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_vm_runner_batch_verifier_layer(mut self) -> anyhow::Result<Self> {
+        let batch_verifier_config = try_load_config!(self.configs.batch_verifier_config);
+        self.node.add_layer(BatchVerifierLayer::new(
+            batch_verifier_config,
+            self.genesis_config.l2_chain_id,
+            self.genesis_config.l1_batch_commit_data_generator_mode,
+        ));
+
+        Ok(self)
+    }
+
     fn add_vm_playground_layer(mut self) -> anyhow::Result<Self> {
         let vm_config = self
             .configs
@@ -645,6 +722,12 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_transaction_decoder_layer(mut self) -> anyhow::Result<Self> {
+        self.node.add_layer(TransactionDecoderLayer);
+
+        Ok(self)
+    }
+
     /// This layer will make sure that the database is initialized correctly,
     /// e.g. genesis will be performed if it's required.
     ///
@@ -724,7 +807,8 @@ impl MainNodeBuilder {
                         .add_tx_sender_layer()?
                         .add_tree_api_client_layer()?
                         .add_api_caches_layer()?
-                        .add_http_web3_api_layer()?;
+                        .add_token_price_cache_layer()?
+                        .add_http_web3_api_layer(&components)?;
                 }
                 Component::WsApi => {
                     self = self
@@ -732,7 +816,8 @@ impl MainNodeBuilder {
                         .add_tx_sender_layer()?
                         .add_tree_api_client_layer()?
                         .add_api_caches_layer()?
-                        .add_ws_web3_api_layer()?;
+                        .add_token_price_cache_layer()?
+                        .add_ws_web3_api_layer(&components)?;
                 }
                 Component::ContractVerificationApi => {
                     self = self.add_contract_verification_api_layer()?;
@@ -791,9 +876,18 @@ impl MainNodeBuilder {
                 Component::VmPlayground => {
                     self = self.add_vm_playground_layer()?;
                 }
+                Component::VmRunnerBatchVerifier => {
+                    self = self.add_vm_runner_batch_verifier_layer()?;
+                }
                 Component::ExternalProofIntegrationApi => {
                     self = self.add_external_proof_integration_api_layer()?;
                 }
+                Component::TransactionDecoder => {
+                    self = self.add_transaction_decoder_layer()?;
+                }
+                Component::EtherscanApi => {
+                    self = self.add_etherscan_api_layer()?;
+                }
             }
         }
         Ok(self.node.build())
@@ -207,6 +207,43 @@ impl FromStr for AggregationRound {
     }
 }
 
+/// Aggregation round together with its polling weight, parsed from `<round>[:<weight>]`
+/// (e.g., `basic_circuits:4`). The weight defaults to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightedRound {
+    pub round: AggregationRound,
+    pub weight: usize,
+}
+
+impl std::fmt::Display for WeightedRound {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}:{}", self.round, self.weight)
+    }
+}
+
+impl FromStr for WeightedRound {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (round, weight) = match s.split_once(':') {
+            Some((round, weight)) => {
+                let weight = weight
+                    .parse()
+                    .map_err(|err| format!("invalid weight for round `{round}`: {err}"))?;
+                (round, weight)
+            }
+            None => (s, 1),
+        };
+        if weight == 0 {
+            return Err(format!("weight for round `{round}` must be positive"));
+        }
+        Ok(Self {
+            round: round.parse()?,
+            weight,
+        })
+    }
+}
+
 impl TryFrom<i32> for AggregationRound {
     type Error = ();
 
@@ -381,6 +418,21 @@ impl JobIdentifiers {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parsing_weighted_round() {
+        let round: WeightedRound = "basic_circuits:3".parse().unwrap();
+        assert_eq!(round.round, AggregationRound::BasicCircuits);
+        assert_eq!(round.weight, 3);
+        assert_eq!(round.to_string(), "basic_circuits:3");
+
+        let round: WeightedRound = "scheduler".parse().unwrap();
+        assert_eq!(round.round, AggregationRound::Scheduler);
+        assert_eq!(round.weight, 1);
+
+        "scheduler:0".parse::<WeightedRound>().unwrap_err();
+        "unknown:1".parse::<WeightedRound>().unwrap_err();
+    }
+
     #[test]
     fn test_eip_4844_blobs_empty_pubdata() {
         let payload = vec![];
@@ -463,3 +463,48 @@ pub struct ProofGenerationTime {
     pub time_taken: NaiveTime,
     pub created_at: NaiveDateTime,
 }
+
+/// Stage in the end-to-end proving pipeline of an L1 batch. Timestamps of reaching each stage are recorded
+/// by the server (for server-side stages) and reported by the prover subsystem (for witness generation,
+/// proving and compression), so that proof latency can be tracked in a single place.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    EnumString,
+    Display,
+    strum::AsRefStr,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ProvingStage {
+    /// The batch was queued for proof generation on the server.
+    Queued,
+    BasicCircuitsStarted,
+    BasicCircuitsFinished,
+    LeafAggregationStarted,
+    LeafAggregationFinished,
+    NodeAggregationStarted,
+    NodeAggregationFinished,
+    RecursionTipStarted,
+    RecursionTipFinished,
+    SchedulerStarted,
+    SchedulerFinished,
+    /// The first prover job for the batch was picked.
+    ProvingStarted,
+    /// The last prover job for the batch (i.e., the scheduler proof) has finished.
+    ProvingFinished,
+    CompressionStarted,
+    CompressionFinished,
+    /// The final proof was submitted to the server.
+    Submitted,
+    /// The proof was verified on L1, i.e. the prove transaction was confirmed.
+    Verified,
+}
@@ -24,6 +24,9 @@ pub struct ApiConfig {
     pub healthcheck: HealthCheckConfig,
     /// Configuration options for Merkle tree API.
     pub merkle_tree: MerkleTreeApiConfig,
+    /// Configuration options for the Etherscan-compatible API.
+    #[serde(default)]
+    pub etherscan: EtherscanApiConfig,
 }
 
 /// Response size limits for specific RPC methods.
@@ -178,10 +181,25 @@ pub struct Web3JsonRpcConfig {
     /// Max number of cache misses during one VM execution. If the number of cache misses exceeds this value, the API server panics.
     /// This is a temporary solution to mitigate API request resulting in thousands of DB queries.
     pub vm_execution_cache_misses_limit: Option<usize>,
+    /// Max number of VM cycles for a single call or gas estimation step. Execution exceeding this limit is interrupted
+    /// and results in an error returned to the caller. If not set, execution is only bounded by the gas limit.
+    pub vm_execution_max_cycles: Option<u64>,
+    /// Timeout for a single VM execution during calls and gas estimation, in milliseconds. For gas estimation,
+    /// the timeout applies to the entire estimation rather than to individual steps. If not set, execution time is not limited.
+    pub vm_execution_timeout_ms: Option<u64>,
     /// Max number of VM instances to be concurrently spawned by the API server.
     /// This option can be tweaked down if the API server is running out of memory.
     /// If not set, the VM concurrency limit will be efficiently disabled.
     pub vm_concurrency_limit: Option<usize>,
+    /// Max number of VM instances concurrently spawned for `eth_call`s and dry runs of submitted transactions.
+    /// If set, these requests use a dedicated pool of VM permits instead of the pool limited by `vm_concurrency_limit`.
+    pub call_vm_concurrency_limit: Option<usize>,
+    /// Max number of VM instances concurrently spawned for gas estimation. If set, gas estimation uses
+    /// a dedicated pool of VM permits instead of the pool limited by `vm_concurrency_limit`.
+    pub estimate_vm_concurrency_limit: Option<usize>,
+    /// Max number of VM instances concurrently spawned for tracing calls (`debug_traceCall`). If set, tracing uses
+    /// a dedicated pool of VM permits instead of the pool limited by `vm_concurrency_limit`.
+    pub trace_vm_concurrency_limit: Option<usize>,
     /// Smart contract cache size in MiBs. The default value is 128 MiB.
     pub factory_deps_cache_size_mb: Option<usize>,
     /// Initial writes cache size in MiBs. The default value is 32 MiB.
@@ -193,6 +211,9 @@ pub struct Web3JsonRpcConfig {
     /// lead to increased the cache update latency, i.e., less storage queries being processed by the cache. OTOH, smaller values
     /// can lead to spurious resets when Postgres lags for whatever reason (e.g., when sealing L1 batches).
     pub latest_values_max_block_lag: Option<NonZeroU32>,
+    /// Size of the cache of storage data shared by VM executions against the same L2 block, in MiBs. The default value
+    /// is 32 MiB. If set to 0, the cache will be disabled.
+    pub block_state_cache_size_mb: Option<usize>,
     /// Limit for fee history block range.
     pub fee_history_limit: Option<u64>,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
@@ -248,11 +269,17 @@ impl Web3JsonRpcConfig {
             estimate_gas_optimize_search: false,
             max_tx_size: 1000000,
             vm_execution_cache_misses_limit: None,
+            vm_execution_max_cycles: None,
+            vm_execution_timeout_ms: None,
             vm_concurrency_limit: None,
+            call_vm_concurrency_limit: None,
+            estimate_vm_concurrency_limit: None,
+            trace_vm_concurrency_limit: None,
             factory_deps_cache_size_mb: None,
             initial_writes_cache_size_mb: None,
             latest_values_cache_size_mb: None,
             latest_values_max_block_lag: None,
+            block_state_cache_size_mb: None,
             fee_history_limit: None,
             max_batch_request_size: None,
             max_response_body_size_mb: None,
@@ -291,6 +318,10 @@ impl Web3JsonRpcConfig {
         Duration::from_millis(self.pubsub_polling_interval.unwrap_or(200))
     }
 
+    pub fn vm_execution_timeout(&self) -> Option<Duration> {
+        self.vm_execution_timeout_ms.map(Duration::from_millis)
+    }
+
     pub fn vm_concurrency_limit(&self) -> usize {
         // The default limit is large so that it does not create a bottleneck on its own.
         // VM execution can still be limited by Tokio runtime parallelism and/or the number
@@ -318,6 +349,11 @@ impl Web3JsonRpcConfig {
         self.latest_values_max_block_lag.map_or(20, NonZeroU32::get)
     }
 
+    /// Returns the size of the block state cache in bytes.
+    pub fn block_state_cache_size(&self) -> usize {
+        self.block_state_cache_size_mb.unwrap_or(32) * super::BYTES_IN_MEGABYTE
+    }
+
     pub fn fee_history_limit(&self) -> u64 {
         self.fee_history_limit.unwrap_or(1024)
     }
@@ -364,6 +400,10 @@ pub struct HealthCheckConfig {
     /// Time limit in milliseconds to abort a health check and return "not ready" status for the corresponding component.
     /// If not specified, the default value in the health check crate will be used.
     pub hard_time_limit_ms: Option<u64>,
+    /// Whether to expose the effective node configuration (with secrets redacted) on the `/config` endpoint.
+    /// Disabled by default since the config may contain data that shouldn't be publicly available.
+    #[serde(default)]
+    pub expose_config: bool,
 }
 
 impl HealthCheckConfig {
@@ -408,6 +448,40 @@ impl MerkleTreeApiConfig {
     }
 }
 
+/// Configuration for the Etherscan-compatible REST API.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EtherscanApiConfig {
+    /// Port to bind the Etherscan-compatible API server to.
+    #[serde(default = "EtherscanApiConfig::default_port")]
+    pub port: u16,
+    /// Maximum number of records returned by list actions (e.g., `txlist`). Mirrors the page size limit of Etherscan.
+    #[serde(default = "EtherscanApiConfig::default_max_page_size")]
+    pub max_page_size: usize,
+}
+
+impl Default for EtherscanApiConfig {
+    fn default() -> Self {
+        Self {
+            port: Self::default_port(),
+            max_page_size: Self::default_max_page_size(),
+        }
+    }
+}
+
+impl EtherscanApiConfig {
+    const fn default_port() -> u16 {
+        3_075
+    }
+
+    const fn default_max_page_size() -> usize {
+        10_000
+    }
+
+    pub fn bind_addr(&self) -> SocketAddr {
+        SocketAddr::new("0.0.0.0".parse().unwrap(), self.port)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
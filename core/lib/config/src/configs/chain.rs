@@ -132,6 +132,36 @@ pub struct StateKeeperConfig {
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
 
+    /// Target utilization of `max_gas_per_batch` for the dynamic L2 base fee. If set, the minimal L2 gas price
+    /// is multiplied by a factor adjusted after each sealed L1 batch in an EIP-1559-like manner: the factor grows
+    /// if the batch gas usage exceeds the target, and decreases otherwise. If not set, the minimal L2 gas price is constant.
+    #[serde(default)]
+    pub l2_base_fee_target_utilization: Option<f64>,
+    /// Maximum relative change of the dynamic L2 base fee factor per L1 batch. Defaults to 0.125, as in EIP-1559.
+    #[serde(default)]
+    pub l2_base_fee_max_change_rate: Option<f64>,
+    /// Cap for the dynamic L2 base fee factor; its floor is 1, i.e. the L2 gas price never drops below `minimal_l2_gas_price`.
+    /// Defaults to 10.
+    #[serde(default)]
+    pub l2_base_fee_max_multiplier: Option<f64>,
+
+    /// Inclusion deadline for priority operations, measured in L1 batches. If set, a priority operation must be included
+    /// into one of the first `forced_inclusion_deadline_batches` L1 batches opened after the timestamp of the L1 block
+    /// it was submitted in. In the last of these batches, the state keeper executes only priority operations and doesn't
+    /// seal the batch by timeout until all due operations are executed.
+    #[serde(default)]
+    pub forced_inclusion_deadline_batches: Option<u32>,
+
+    /// Maximum size of a raw (signed and encoded) L2 transaction in bytes. If set, larger transactions are rejected
+    /// both by the main node API and by the state keeper. Note that the API additionally applies
+    /// `api.web3_json_rpc.max_tx_size` to incoming transactions, so it may need to be raised as well.
+    #[serde(default)]
+    pub max_l2_tx_size_bytes: Option<usize>,
+    /// Maximum calldata size of an L2 transaction in bytes. If set, transactions with larger calldata are rejected
+    /// both by the main node API and by the state keeper.
+    #[serde(default)]
+    pub max_l2_tx_calldata_bytes: Option<usize>,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -146,6 +176,19 @@ pub struct StateKeeperConfig {
 }
 
 impl StateKeeperConfig {
+    const DEFAULT_L2_BASE_FEE_MAX_CHANGE_RATE: f64 = 0.125;
+    const DEFAULT_L2_BASE_FEE_MAX_MULTIPLIER: f64 = 10.0;
+
+    pub fn l2_base_fee_max_change_rate(&self) -> f64 {
+        self.l2_base_fee_max_change_rate
+            .unwrap_or(Self::DEFAULT_L2_BASE_FEE_MAX_CHANGE_RATE)
+    }
+
+    pub fn l2_base_fee_max_multiplier(&self) -> f64 {
+        self.l2_base_fee_max_multiplier
+            .unwrap_or(Self::DEFAULT_L2_BASE_FEE_MAX_MULTIPLIER)
+    }
+
     /// Creates a config object suitable for use in unit tests.
     /// Values mostly repeat the values used in the localhost environment.
     pub fn for_tests() -> Self {
@@ -178,6 +221,12 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            l2_base_fee_target_utilization: None,
+            l2_base_fee_max_change_rate: None,
+            l2_base_fee_max_multiplier: None,
+            forced_inclusion_deadline_batches: None,
+            max_l2_tx_size_bytes: None,
+            max_l2_tx_calldata_bytes: None,
             bootloader_hash: None,
             default_aa_hash: None,
             evm_emulator_hash: None,
@@ -221,6 +270,24 @@ impl CircuitBreakerConfig {
     }
 }
 
+/// Policy used to order L2 transactions of different accounts in the mempool.
+///
+/// - `Timestamp` orders transactions by their arrival time, skipping transactions that don't match the current
+///   fee requirements.
+/// - `Fifo` orders transactions strictly by their arrival time; a transaction is never overtaken by later transactions,
+///   even if it doesn't match the current fee requirements.
+/// - `PriorityFee` orders transactions by `max_priority_fee_per_gas`, and then by their arrival time.
+/// - `Custom` uses an operator-defined policy that must be provided when building the node.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MempoolOrderingPolicy {
+    #[default]
+    Timestamp,
+    Fifo,
+    PriorityFee,
+    Custom,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct MempoolConfig {
     pub sync_interval_ms: u64,
@@ -229,6 +296,17 @@ pub struct MempoolConfig {
     pub stuck_tx_timeout: u64,
     pub remove_stuck_txs: bool,
     pub delay_interval: u64,
+    /// Maximum number of transactions pre-executed concurrently by the admission dry-run when they are
+    /// loaded into the mempool. If not set, the admission dry-run is disabled.
+    #[serde(default)]
+    pub admission_dry_run_concurrency: Option<usize>,
+    /// Whether to reject transactions that halted during the admission dry-run and don't conflict
+    /// with other pre-executed transactions, instead of executing them in the state keeper.
+    #[serde(default)]
+    pub admission_reject_halted_txs: bool,
+    /// Policy used to order L2 transactions in the mempool.
+    #[serde(default)]
+    pub ordering_policy: MempoolOrderingPolicy,
 }
 
 impl MempoolConfig {
@@ -6,6 +6,7 @@ pub const DEFAULT_POLLING_INTERVAL_MS: u32 = 5000;
 pub const DEFAULT_MAX_ROWS_TO_DISPATCH: u32 = 100;
 pub const DEFAULT_MAX_RETRIES: u16 = 5;
 pub const DEFAULT_USE_DUMMY_INCLUSION_DATA: bool = false;
+pub const DEFAULT_MAX_CONCURRENT_DISPATCHES: u32 = 1;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DADispatcherConfig {
@@ -19,6 +20,10 @@ pub struct DADispatcherConfig {
     // TODO: run a verification task to check if the L1 contract expects the inclusion proofs to
     // avoid the scenario where contracts expect real proofs, and server is using dummy proofs.
     pub use_dummy_inclusion_data: Option<bool>,
+    /// The maximum number of blobs dispatched to the data availability layer concurrently. Dispatch results
+    /// are still persisted in the L1 batch order. Should be kept at 1 for DA clients that cannot submit
+    /// several transactions at once (e.g., because they rely on sequential account nonces).
+    pub max_concurrent_dispatches: Option<u32>,
 }
 
 impl DADispatcherConfig {
@@ -28,6 +33,7 @@ impl DADispatcherConfig {
             max_rows_to_dispatch: Some(DEFAULT_MAX_ROWS_TO_DISPATCH),
             max_retries: Some(DEFAULT_MAX_RETRIES),
             use_dummy_inclusion_data: Some(DEFAULT_USE_DUMMY_INCLUSION_DATA),
+            max_concurrent_dispatches: Some(DEFAULT_MAX_CONCURRENT_DISPATCHES),
         }
     }
 
@@ -51,4 +57,10 @@ impl DADispatcherConfig {
         self.use_dummy_inclusion_data
             .unwrap_or(DEFAULT_USE_DUMMY_INCLUSION_DATA)
     }
+
+    pub fn max_concurrent_dispatches(&self) -> u32 {
+        self.max_concurrent_dispatches
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_DISPATCHES)
+            .max(1)
+    }
 }
@@ -46,6 +46,23 @@ pub struct MerkleTreeConfig {
     /// Maximum number of L1 batches to be processed by the Merkle tree at a time.
     #[serde(default = "MerkleTreeConfig::default_max_l1_batches_per_iter")]
     pub max_l1_batches_per_iter: usize,
+    /// Number of the latest L1 batches to retain in the tree. If set, older tree versions are pruned regardless
+    /// of Postgres pruning. The tree cannot be rolled back beyond the retained L1 batches, and Merkle proofs
+    /// for older batches become unavailable.
+    #[serde(default)]
+    pub retained_l1_batches: Option<u64>,
+    /// Start of the daily maintenance window (hour in UTC, 0..=23). If set together with the end hour,
+    /// retention-based pruning and manual RocksDB compaction are only performed inside the window.
+    #[serde(default)]
+    pub maintenance_window_start_hour: Option<u8>,
+    /// End of the daily maintenance window (hour in UTC, exclusive). May be less than the start hour
+    /// for windows spanning midnight.
+    #[serde(default)]
+    pub maintenance_window_end_hour: Option<u8>,
+    /// Minimum number of L1 batches pruned from the tree since the last manual RocksDB compaction to trigger
+    /// the next compaction inside the maintenance window. If not set, a default of 1,000 L1 batches is used.
+    #[serde(default)]
+    pub maintenance_compaction_min_pruned_l1_batches: Option<u64>,
 }
 
 impl Default for MerkleTreeConfig {
@@ -58,6 +75,10 @@ impl Default for MerkleTreeConfig {
             memtable_capacity_mb: Self::default_memtable_capacity_mb(),
             stalled_writes_timeout_sec: Self::default_stalled_writes_timeout_sec(),
             max_l1_batches_per_iter: Self::default_max_l1_batches_per_iter(),
+            retained_l1_batches: None,
+            maintenance_window_start_hour: None,
+            maintenance_window_end_hour: None,
+            maintenance_compaction_min_pruned_l1_batches: None,
         }
     }
 }
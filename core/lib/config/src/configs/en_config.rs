@@ -2,7 +2,8 @@ use std::num::{NonZeroU64, NonZeroUsize};
 
 use serde::Deserialize;
 use zksync_basic_types::{
-    commitment::L1BatchCommitmentMode, url::SensitiveUrl, L1ChainId, L2ChainId, SLChainId,
+    commitment::L1BatchCommitmentMode, url::SensitiveUrl, L1BatchNumber, L1ChainId, L2ChainId,
+    SLChainId,
 };
 
 /// Temporary config for initializing external node, will be completely replaced by consensus config later
@@ -13,6 +14,12 @@ pub struct ENConfig {
     pub sl_chain_id: Option<SLChainId>,
     pub l1_chain_id: L1ChainId,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    /// First L1 batches committed after each switch of the chain between rollup and validium. Each transition flips
+    /// the commitment mode; batches after the last one are committed using `l1_batch_commit_data_generator_mode`.
+    #[serde(default)]
+    pub commitment_mode_transition_batches: Vec<L1BatchNumber>,
+    /// Inclusion deadline for priority operations in L1 batches verified by the consistency checker.
+    pub forced_inclusion_deadline_batches: Option<u32>,
 
     // Main node configuration
     pub main_node_url: SensitiveUrl,
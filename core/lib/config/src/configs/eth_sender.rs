@@ -43,6 +43,7 @@ impl EthConfig {
                 tx_aggregation_paused: false,
                 tx_aggregation_only_prove_and_execute: false,
                 time_in_mempool_in_l1_blocks_cap: 1800,
+                wait_for_batch_verification: false,
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -123,6 +124,10 @@ pub struct SenderConfig {
     /// Cap of time in mempool for price calculations
     #[serde(default = "SenderConfig::default_time_in_mempool_in_l1_blocks_cap")]
     pub time_in_mempool_in_l1_blocks_cap: u32,
+    /// If set, batches are only committed after their commitments are independently recomputed
+    /// by the batch verifier (a VM runner component) and match the persisted ones.
+    #[serde(default)]
+    pub wait_for_batch_verification: bool,
 }
 
 impl SenderConfig {
@@ -173,6 +178,26 @@ impl SenderConfig {
     }
 }
 
+/// Fee market of the settlement layer, which determines how the gas adjuster samples fees and which transaction
+/// types are used by the eth sender.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum L1FeeMarket {
+    /// EIP-1559 fee market with blob transactions (EIP-4844) support, e.g. Ethereum mainnet.
+    #[default]
+    Eip4844,
+    /// EIP-1559 fee market without blob transactions support.
+    Eip1559,
+    /// Legacy fee market. Fees are sampled using `eth_gasPrice`, and legacy (type 0) transactions are sent.
+    Legacy,
+}
+
+impl L1FeeMarket {
+    pub fn supports_blobs(self) -> bool {
+        matches!(self, Self::Eip4844)
+    }
+}
+
 #[derive(Debug, Deserialize, Copy, Clone, PartialEq, Default)]
 pub struct GasAdjusterConfig {
     /// Priority Fee to be used by GasAdjuster
@@ -207,6 +232,9 @@ pub struct GasAdjusterConfig {
     /// It offers a runtime check for correctly provided values.
     #[serde(default)]
     pub settlement_mode: SettlementMode,
+    /// Fee market of the L1 the chain settles to.
+    #[serde(default)]
+    pub l1_fee_market: L1FeeMarket,
 }
 
 impl GasAdjusterConfig {
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration for the experimental API allowing an external block builder to propose transaction ordering
+/// for L2 blocks.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ExternalBlockBuilderConfig {
+    /// Port to bind the API server to.
+    pub http_port: u16,
+    /// Bearer token that the builder must provide in the `Authorization` header.
+    pub auth_token: String,
+    /// Maximum time the state keeper waits for a proposal when opening an L2 block before falling back
+    /// to local transaction ordering.
+    #[serde(default = "ExternalBlockBuilderConfig::default_proposal_timeout_ms")]
+    pub proposal_timeout_ms: u64,
+    /// Maximum number of transactions in a single proposal.
+    #[serde(default = "ExternalBlockBuilderConfig::default_max_transactions_per_proposal")]
+    pub max_transactions_per_proposal: usize,
+}
+
+impl ExternalBlockBuilderConfig {
+    pub fn default_proposal_timeout_ms() -> u64 {
+        200
+    }
+
+    pub fn default_max_transactions_per_proposal() -> usize {
+        1_000
+    }
+
+    pub fn proposal_timeout(&self) -> Duration {
+        Duration::from_millis(self.proposal_timeout_ms)
+    }
+}
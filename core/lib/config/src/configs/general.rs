@@ -13,11 +13,14 @@ use crate::{
         prover_job_monitor::ProverJobMonitorConfig,
         pruning::PruningConfig,
         snapshot_recovery::SnapshotRecoveryConfig,
-        vm_runner::{BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig},
-        CommitmentGeneratorConfig, ExperimentalVmConfig, ExternalPriceApiClientConfig,
-        FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
-        FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig, ObservabilityConfig,
-        PrometheusConfig, ProofDataHandlerConfig,
+        token_price::TokenPriceProviderConfig,
+        vm_runner::{
+            BasicWitnessInputProducerConfig, BatchVerifierConfig, ProtectiveReadsWriterConfig,
+        },
+        CommitmentGeneratorConfig, ExperimentalVmConfig, ExternalBlockBuilderConfig,
+        ExternalPriceApiClientConfig, FriProofCompressorConfig, FriProverConfig,
+        FriProverGatewayConfig, FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig,
+        ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig, TokenPriceConfig,
     },
     ApiConfig, ContractVerifierConfig, DBConfig, EthConfig, ExternalProofIntegrationApiConfig,
     ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
@@ -49,6 +52,7 @@ pub struct GeneralConfig {
     pub da_dispatcher_config: Option<DADispatcherConfig>,
     pub protective_reads_writer_config: Option<ProtectiveReadsWriterConfig>,
     pub basic_witness_input_producer_config: Option<BasicWitnessInputProducerConfig>,
+    pub batch_verifier_config: Option<BatchVerifierConfig>,
     pub commitment_generator: Option<CommitmentGeneratorConfig>,
     pub snapshot_recovery: Option<SnapshotRecoveryConfig>,
     pub pruning: Option<PruningConfig>,
@@ -60,4 +64,57 @@ pub struct GeneralConfig {
     pub experimental_vm_config: Option<ExperimentalVmConfig>,
     pub prover_job_monitor_config: Option<ProverJobMonitorConfig>,
     pub timestamp_asserter_config: Option<TimestampAsserterConfig>,
+    pub external_block_builder_config: Option<ExternalBlockBuilderConfig>,
+    pub token_price_config: Option<TokenPriceConfig>,
+}
+
+impl GeneralConfig {
+    /// Placeholder replacing sensitive values by [`Self::redact_secrets()`].
+    pub const REDACTED: &'static str = "<redacted>";
+
+    /// Replaces sensitive values (API keys, auth tokens and URLs that may embed credentials) with [`Self::REDACTED`],
+    /// so that the config can be exposed outside the node, e.g. to detect drift between the running node
+    /// and on-disk configs.
+    pub fn redact_secrets(&mut self) {
+        if let Some(config) = &mut self.observability {
+            // Sentry DSNs include the client key.
+            if let Some(sentry_url) = &mut config.sentry_url {
+                *sentry_url = Self::REDACTED.to_owned();
+            }
+            if let Some(opentelemetry) = &mut config.opentelemetry {
+                opentelemetry.endpoint = Self::REDACTED.to_owned();
+                if let Some(logs_endpoint) = &mut opentelemetry.logs_endpoint {
+                    *logs_endpoint = Self::REDACTED.to_owned();
+                }
+            }
+        }
+        if let Some(pushgateway_url) = self
+            .prometheus_config
+            .as_mut()
+            .and_then(|config| config.pushgateway_url.as_mut())
+        {
+            *pushgateway_url = Self::REDACTED.to_owned();
+        }
+        if let Some(api_key) = self
+            .external_price_api_client_config
+            .as_mut()
+            .and_then(|config| config.api_key.as_mut())
+        {
+            *api_key = Self::REDACTED.to_owned();
+        }
+        if let Some(config) = &mut self.external_block_builder_config {
+            config.auth_token = Self::REDACTED.to_owned();
+        }
+        for provider in self
+            .token_price_config
+            .iter_mut()
+            .flat_map(|config| &mut config.providers)
+        {
+            if let TokenPriceProviderConfig::CoinGecko(config) = provider {
+                if let Some(api_key) = &mut config.api_key {
+                    *api_key = Self::REDACTED.to_owned();
+                }
+            }
+        }
+    }
 }
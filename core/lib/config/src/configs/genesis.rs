@@ -5,6 +5,17 @@ use zksync_basic_types::{
     Address, L1ChainId, L2ChainId, SLChainId, H256,
 };
 
+/// Additional system contract deployed at genesis in a specific chain.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomSystemContract {
+    /// Address of the contract. Must be in the kernel space so that the contract is treated as a system one by the VM.
+    pub address: Address,
+    /// Versioned hash of the contract bytecode.
+    pub bytecode_hash: H256,
+    /// Path to the compiled contract artifact relative to the workspace root.
+    pub source: String,
+}
+
 /// This config represents the genesis state of the chain.
 /// Each chain has this config immutable and we update it only during the protocol upgrade
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -32,6 +43,9 @@ pub struct GenesisConfig {
     pub fee_account: Address,
     pub dummy_verifier: bool,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    /// Chain-specific system contracts deployed at genesis in addition to the ones from the system contracts repo.
+    #[serde(default)]
+    pub custom_system_contracts: Vec<CustomSystemContract>,
 }
 
 impl GenesisConfig {
@@ -60,6 +74,7 @@ impl GenesisConfig {
             l2_chain_id: L2ChainId::default(),
             dummy_verifier: false,
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
+            custom_system_contracts: vec![],
         }
     }
 }
@@ -11,6 +11,7 @@ pub use self::{
     eth_sender::{EthConfig, GasAdjusterConfig},
     eth_watch::EthWatchConfig,
     experimental::{ExperimentalDBConfig, ExperimentalVmConfig, ExperimentalVmPlaygroundConfig},
+    external_block_builder::ExternalBlockBuilderConfig,
     external_price_api_client::ExternalPriceApiClientConfig,
     external_proof_integration_api::ExternalProofIntegrationApiConfig,
     fri_proof_compressor::FriProofCompressorConfig,
@@ -19,17 +20,20 @@ pub use self::{
     fri_witness_generator::FriWitnessGeneratorConfig,
     fri_witness_vector_generator::FriWitnessVectorGeneratorConfig,
     general::GeneralConfig,
-    genesis::GenesisConfig,
+    genesis::{CustomSystemContract, GenesisConfig},
     object_store::ObjectStoreConfig,
     observability::{ObservabilityConfig, OpentelemetryConfig},
-    proof_data_handler::{ProofDataHandlerConfig, TeeConfig},
+    proof_data_handler::{ProofDataHandlerConfig, ProofMarketplaceConfig, TeeConfig},
     prover_job_monitor::ProverJobMonitorConfig,
     pruning::PruningConfig,
     secrets::{DataAvailabilitySecrets, DatabaseSecrets, L1Secrets, Secrets},
     snapshot_recovery::SnapshotRecoveryConfig,
     snapshots_creator::SnapshotsCreatorConfig,
+    token_price::TokenPriceConfig,
     utils::PrometheusConfig,
-    vm_runner::{BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig},
+    vm_runner::{
+        BasicWitnessInputProducerConfig, BatchVerifierConfig, ProtectiveReadsWriterConfig,
+    },
 };
 
 pub mod api;
@@ -46,6 +50,7 @@ pub mod en_config;
 pub mod eth_sender;
 pub mod eth_watch;
 mod experimental;
+pub mod external_block_builder;
 pub mod external_price_api_client;
 pub mod external_proof_integration_api;
 pub mod fri_proof_compressor;
@@ -65,6 +70,7 @@ pub mod pruning;
 pub mod secrets;
 pub mod snapshot_recovery;
 pub mod snapshots_creator;
+pub mod token_price;
 pub mod utils;
 pub mod vm_runner;
 pub mod wallets;
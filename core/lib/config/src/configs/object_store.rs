@@ -16,6 +16,16 @@ pub struct ObjectStoreConfig {
     /// **Important.** Mirroring logic assumes that objects in the underlying store are immutable. If this is not the case,
     /// the mirrored objects may become stale.
     pub local_mirror_path: Option<String>,
+    /// Maximum size of the local mirror in megabytes. If set, the least recently used objects are evicted from the mirror
+    /// once its size exceeds the limit, which makes the mirror usable as a persistent cache (e.g., for witness inputs
+    /// on prover hosts). Recency and mirror size are tracked via the mirror directory contents, so the directory can be shared
+    /// by co-located processes; objects left in the mirror by previous runs are accounted for as well.
+    /// If not set, the mirror size is unlimited.
+    pub local_mirror_max_size_mb: Option<u64>,
+    /// Replication of objects to mirror stores. If not specified, objects are only stored in the primary store
+    /// specified by [`Self::mode`].
+    #[serde(default)]
+    pub replication: Option<ObjectStoreReplicationConfig>,
 }
 
 impl ObjectStoreConfig {
@@ -41,3 +51,38 @@ pub enum ObjectStoreMode {
         file_backed_base_path: String,
     },
 }
+
+/// Configuration of object replication from the primary object store to mirror stores.
+///
+/// Objects are put into the primary store synchronously and then replicated to mirrors in the background,
+/// so that writes don't pay the latency of mirror stores. Reads are served by the stores in the order
+/// specified by [`Self::read_preference`], falling back to the next store if an object is missing
+/// (e.g., because it wasn't replicated yet) or a store returns an error. This allows, for example, to put
+/// a mirror bucket in the same region as a GPU prover fleet to avoid cross-region latency and egress costs.
+///
+/// Failed replication operations are retried with exponential backoff for several minutes, and operations
+/// on the same object are applied to each mirror in order. Replication is still best-effort: if a mirror
+/// is unavailable for longer, objects are not replicated to it, and reads fall back to other stores.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectStoreReplicationConfig {
+    /// Mirror stores. Names must be unique and must not be equal to [`Self::PRIMARY_STORE_NAME`].
+    pub mirrors: Vec<ObjectStoreMirrorConfig>,
+    /// Names of stores in the order they should be queried on reads; the primary store is referred to as
+    /// [`Self::PRIMARY_STORE_NAME`]. Stores not mentioned are queried afterwards, starting from the primary store.
+    #[serde(default)]
+    pub read_preference: Vec<String>,
+}
+
+impl ObjectStoreReplicationConfig {
+    /// Name used to refer to the primary store in [`Self::read_preference`].
+    pub const PRIMARY_STORE_NAME: &'static str = "primary";
+}
+
+/// Mirror object store configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectStoreMirrorConfig {
+    /// Name of the mirror used in [`ObjectStoreReplicationConfig::read_preference`], logs and metrics.
+    pub name: String,
+    #[serde(flatten)]
+    pub mode: ObjectStoreMode,
+}
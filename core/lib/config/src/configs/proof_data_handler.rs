@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use serde::Deserialize;
-use zksync_basic_types::L1BatchNumber;
+use zksync_basic_types::{L1BatchNumber, H256};
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct TeeConfig {
@@ -43,6 +43,25 @@ impl TeeConfig {
     }
 }
 
+/// Configuration of the API allowing third-party provers (e.g., proving marketplaces) to claim batches
+/// and submit proofs for them.
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
+pub struct ProofMarketplaceConfig {
+    /// If true, the marketplace API is enabled. Proofs submitted via the API are verified
+    /// with the L1 verifier contract before being saved.
+    #[serde(default)]
+    pub marketplace_support: bool,
+    /// Keccak-256 hashes of the API keys accepted from provers. Keys are passed in the
+    /// `Authorization: Bearer <key>` header of the claim and submit requests.
+    #[serde(default)]
+    pub marketplace_api_key_hashes: Vec<H256>,
+    /// Reward token advertised to provers, e.g. a token symbol or an address. Not interpreted by the server.
+    pub marketplace_reward_token: Option<String>,
+    /// Reward per proven batch advertised to provers, as a decimal amount of `marketplace_reward_token`.
+    /// Not interpreted by the server.
+    pub marketplace_reward_per_batch: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct ProofDataHandlerConfig {
     pub http_port: u16,
@@ -51,6 +70,9 @@ pub struct ProofDataHandlerConfig {
     // ^ Filled in separately in `Self::from_env()`. We cannot use `serde(flatten)` because it
     // doesn't work with `envy`: https://github.com/softprops/envy/issues/26
     pub tee_config: TeeConfig,
+    #[serde(skip)]
+    // ^ Filled in separately in `Self::from_env()`, same as `tee_config`.
+    pub marketplace_config: ProofMarketplaceConfig,
 }
 
 impl ProofDataHandlerConfig {
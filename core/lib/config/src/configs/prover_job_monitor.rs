@@ -61,6 +61,13 @@ pub struct ProverJobMonitorConfig {
     /// The interval between runs for Witness Job Queuer.
     #[serde(default = "ProverJobMonitorConfig::default_witness_job_queuer_run_interval_ms")]
     pub witness_job_queuer_run_interval_ms: u64,
+    /// The interval between runs for Artifacts Garbage Collector.
+    #[serde(default = "ProverJobMonitorConfig::default_artifacts_gc_run_interval_ms")]
+    pub artifacts_gc_run_interval_ms: u64,
+    /// The amount of time after a batch proof was sent to the server after which intermediate artifacts of the batch
+    /// can be removed, provided that the batch is proven on L1. If not set, artifacts are never removed.
+    #[serde(default)]
+    pub artifacts_gc_retention_ms: Option<u64>,
     /// HTTP port of the ProverJobMonitor to send requests to.
     pub http_port: u16,
 }
@@ -184,4 +191,19 @@ impl ProverJobMonitorConfig {
     pub fn default_witness_job_queuer_run_interval_ms() -> u64 {
         10_000
     }
+
+    /// The interval between runs for Artifacts Garbage Collector.
+    pub fn artifacts_gc_run_interval(&self) -> Duration {
+        Duration::from_millis(self.artifacts_gc_run_interval_ms)
+    }
+
+    /// Default artifacts_gc_run_interval_ms -- 1 hour
+    pub fn default_artifacts_gc_run_interval_ms() -> u64 {
+        3_600_000
+    }
+
+    /// The amount of time after which artifacts of batches proven on L1 can be removed.
+    pub fn artifacts_gc_retention(&self) -> Option<Duration> {
+        self.artifacts_gc_retention_ms.map(Duration::from_millis)
+    }
 }
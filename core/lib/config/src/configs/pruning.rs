@@ -1,6 +1,7 @@
 use std::num::NonZeroU64;
 
 use serde::Deserialize;
+use zksync_basic_types::Address;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct PruningConfig {
@@ -16,4 +17,9 @@ pub struct PruningConfig {
     /// the retention period greater than that implicitly imposed by other criteria (e.g., 7 or 30 days).
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 1 hour.
     pub data_retention_sec: Option<u64>,
+    /// Addresses for which full history (events, call traces, transaction data and overwritten storage values)
+    /// is retained when the corresponding L1 batches are pruned. Useful to keep app-specific history for
+    /// protocol contracts or major dapps while pruning the rest of the state.
+    #[serde(default)]
+    pub retained_addresses: Vec<Address>,
 }
@@ -26,6 +26,9 @@ pub struct PostgresRecoveryConfig {
     /// reduce this factor to about 5 if snapshot recovery overloads I/O capacity of the node. Conversely,
     /// if I/O capacity of your infra is high, you may increase concurrency to speed up Postgres recovery.
     pub max_concurrency: Option<NonZeroUsize>,
+    /// Maximum number of storage log chunks held in memory during recovery, i.e., downloaded from the object store
+    /// but not yet persisted in Postgres. Larger values allow downloading chunks ahead of Postgres at the cost of RAM usage.
+    pub max_chunks_in_memory: Option<NonZeroUsize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
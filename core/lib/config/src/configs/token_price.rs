@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use zksync_basic_types::Address;
+
+/// Configuration for token prices served by the `zks_getTokenPrice` API method.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TokenPriceConfig {
+    /// Price providers queried in the specified order; the first provider returning a price for a token is used.
+    pub providers: Vec<TokenPriceProviderConfig>,
+    /// Time during which a fetched price is served from the cache without querying providers.
+    #[serde(default = "TokenPriceConfig::default_cache_ttl_ms")]
+    pub cache_ttl_ms: u64,
+    /// Age after which a cached price is marked as stale. Stale prices are only returned if all providers fail.
+    #[serde(default = "TokenPriceConfig::default_stale_after_ms")]
+    pub stale_after_ms: u64,
+}
+
+impl TokenPriceConfig {
+    pub fn default_cache_ttl_ms() -> u64 {
+        60_000
+    }
+
+    pub fn default_stale_after_ms() -> u64 {
+        600_000
+    }
+
+    pub fn cache_ttl(&self) -> Duration {
+        Duration::from_millis(self.cache_ttl_ms)
+    }
+
+    pub fn stale_after(&self) -> Duration {
+        Duration::from_millis(self.stale_after_ms)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "source")]
+pub enum TokenPriceProviderConfig {
+    CoinGecko(CoinGeckoTokenPriceConfig),
+    UniswapV3Twap(UniswapV3TwapConfig),
+    Static(StaticTokenPriceConfig),
+}
+
+impl TokenPriceProviderConfig {
+    /// Returns whether the provider needs access to L1.
+    pub fn requires_l1_client(&self) -> bool {
+        matches!(self, Self::UniswapV3Twap(_))
+    }
+}
+
+/// Fetches USD prices from the CoinGecko API.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CoinGeckoTokenPriceConfig {
+    pub base_url: Option<String>,
+    pub api_key: Option<String>,
+    #[serde(default = "CoinGeckoTokenPriceConfig::default_client_timeout_ms")]
+    pub client_timeout_ms: u64,
+}
+
+impl CoinGeckoTokenPriceConfig {
+    pub fn default_client_timeout_ms() -> u64 {
+        10_000
+    }
+}
+
+/// Computes prices as time-weighted average prices from Uniswap V3 pools on L1. Each pool must pair the token
+/// with a USD-pegged stablecoin.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UniswapV3TwapConfig {
+    pub pools: Vec<UniswapV3PoolConfig>,
+    /// Averaging window for the TWAP.
+    #[serde(default = "UniswapV3TwapConfig::default_window_sec")]
+    pub window_sec: u32,
+}
+
+impl UniswapV3TwapConfig {
+    pub fn default_window_sec() -> u32 {
+        1_800
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UniswapV3PoolConfig {
+    /// L1 address of the priced token.
+    pub token: Address,
+    /// Address of the Uniswap V3 pool pairing the token with a USD-pegged stablecoin.
+    pub pool: Address,
+}
+
+/// Serves fixed prices; useful for testnets and tokens without market prices.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StaticTokenPriceConfig {
+    pub prices: Vec<StaticTokenPrice>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StaticTokenPrice {
+    /// L1 address of the token.
+    pub token: Address,
+    pub price_usd: f64,
+}
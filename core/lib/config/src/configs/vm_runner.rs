@@ -34,3 +34,21 @@ impl BasicWitnessInputProducerConfig {
         "./db/basic_witness_input_producer".to_owned()
     }
 }
+
+/// Configuration for the batch verifier, which re-executes batches with the fast VM and recomputes their commitments.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BatchVerifierConfig {
+    /// Path to the RocksDB data directory that serves state cache.
+    #[serde(default = "BatchVerifierConfig::default_db_path")]
+    pub db_path: String,
+    /// How many max batches should be processed at the same time.
+    pub window_size: u32,
+    /// All batches before this one (inclusive) are always considered to be processed.
+    pub first_processed_batch: L1BatchNumber,
+}
+
+impl BatchVerifierConfig {
+    fn default_db_path() -> String {
+        "./db/batch_verifier".to_owned()
+    }
+}
@@ -62,6 +62,14 @@ impl Wallet {
 pub struct EthSender {
     pub operator: Wallet,
     pub blob_operator: Option<Wallet>,
+    /// Operator sending prove transactions. If not set, they are sent by the main operator.
+    ///
+    /// The operator can only be changed or removed once all its transactions are confirmed;
+    /// otherwise, eth_sender returns an error on start.
+    pub prove_operator: Option<Wallet>,
+    /// Operator sending execute transactions. If not set, they are sent by the main operator.
+    /// The same restrictions on changing the operator as for `prove_operator` apply.
+    pub execute_operator: Option<Wallet>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -89,6 +97,8 @@ impl Wallets {
                 blob_operator: Some(
                     Wallet::from_private_key_bytes(H256::repeat_byte(0x2), None).unwrap(),
                 ),
+                prove_operator: None,
+                execute_operator: None,
             }),
             state_keeper: Some(StateKeeper {
                 fee_account: AddressWallet::from_address(H160::repeat_byte(0x3)),
@@ -58,6 +58,18 @@ impl Distribution<configs::chain::FeeModelVersion> for EncodeDist {
     }
 }
 
+impl Distribution<configs::chain::MempoolOrderingPolicy> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::chain::MempoolOrderingPolicy {
+        type T = configs::chain::MempoolOrderingPolicy;
+        match rng.gen_range(0..4) {
+            0 => T::Timestamp,
+            1 => T::Fifo,
+            2 => T::PriorityFee,
+            _ => T::Custom,
+        }
+    }
+}
+
 impl Distribution<configs::ApiConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::ApiConfig {
         configs::ApiConfig {
@@ -65,6 +77,7 @@ impl Distribution<configs::ApiConfig> for EncodeDist {
             prometheus: self.sample(rng),
             healthcheck: self.sample(rng),
             merkle_tree: self.sample(rng),
+            etherscan: self.sample(rng),
         }
     }
 }
@@ -88,11 +101,17 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             estimate_gas_optimize_search: self.sample(rng),
             max_tx_size: self.sample(rng),
             vm_execution_cache_misses_limit: self.sample(rng),
+            vm_execution_max_cycles: self.sample(rng),
+            vm_execution_timeout_ms: self.sample(rng),
             vm_concurrency_limit: self.sample(rng),
+            call_vm_concurrency_limit: self.sample(rng),
+            estimate_vm_concurrency_limit: self.sample(rng),
+            trace_vm_concurrency_limit: self.sample(rng),
             factory_deps_cache_size_mb: self.sample(rng),
             initial_writes_cache_size_mb: self.sample(rng),
             latest_values_cache_size_mb: self.sample(rng),
             latest_values_max_block_lag: self.sample(rng),
+            block_state_cache_size_mb: self.sample(rng),
             fee_history_limit: self.sample(rng),
             max_batch_request_size: self.sample(rng),
             max_response_body_size_mb: self.sample(rng),
@@ -126,6 +145,7 @@ impl Distribution<configs::api::HealthCheckConfig> for EncodeDist {
             port: self.sample(rng),
             slow_time_limit_ms: self.sample(rng),
             hard_time_limit_ms: self.sample(rng),
+            expose_config: self.sample(rng),
         }
     }
 }
@@ -147,6 +167,15 @@ impl Distribution<configs::api::MerkleTreeApiConfig> for EncodeDist {
     }
 }
 
+impl Distribution<configs::api::EtherscanApiConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::api::EtherscanApiConfig {
+        configs::api::EtherscanApiConfig {
+            port: self.sample(rng),
+            max_page_size: self.sample(rng),
+        }
+    }
+}
+
 impl Distribution<configs::PrometheusConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::PrometheusConfig {
         configs::PrometheusConfig {
@@ -195,6 +224,12 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            l2_base_fee_target_utilization: self.sample(rng),
+            l2_base_fee_max_change_rate: self.sample(rng),
+            l2_base_fee_max_multiplier: self.sample(rng),
+            forced_inclusion_deadline_batches: self.sample(rng),
+            max_l2_tx_size_bytes: self.sample(rng),
+            max_l2_tx_calldata_bytes: self.sample(rng),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -233,6 +268,9 @@ impl Distribution<configs::chain::MempoolConfig> for EncodeDist {
             stuck_tx_timeout: self.sample(rng),
             remove_stuck_txs: self.sample(rng),
             delay_interval: self.sample(rng),
+            admission_dry_run_concurrency: self.sample(rng),
+            admission_reject_halted_txs: self.sample(rng),
+            ordering_policy: self.sample(rng),
         }
     }
 }
@@ -293,6 +331,10 @@ impl Distribution<configs::database::MerkleTreeConfig> for EncodeDist {
             memtable_capacity_mb: self.sample(rng),
             stalled_writes_timeout_sec: self.sample(rng),
             max_l1_batches_per_iter: self.sample(rng),
+            retained_l1_batches: self.sample(rng),
+            maintenance_window_start_hour: self.sample_opt(|| rng.gen_range(0..24)),
+            maintenance_window_end_hour: self.sample_opt(|| rng.gen_range(0..24)),
+            maintenance_compaction_min_pruned_l1_batches: self.sample(rng),
         }
     }
 }
@@ -386,6 +428,17 @@ impl Distribution<configs::eth_sender::ProofSendingMode> for EncodeDist {
     }
 }
 
+impl Distribution<configs::eth_sender::L1FeeMarket> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::L1FeeMarket {
+        type T = configs::eth_sender::L1FeeMarket;
+        match rng.gen_range(0..3) {
+            0 => T::Eip4844,
+            1 => T::Eip1559,
+            _ => T::Legacy,
+        }
+    }
+}
+
 impl Distribution<configs::eth_sender::ProofLoadingMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::ProofLoadingMode {
         type T = configs::eth_sender::ProofLoadingMode;
@@ -419,6 +472,7 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             tx_aggregation_paused: false,
             tx_aggregation_only_prove_and_execute: false,
             time_in_mempool_in_l1_blocks_cap: self.sample(rng),
+            wait_for_batch_verification: self.sample(rng),
         }
     }
 }
@@ -440,6 +494,7 @@ impl Distribution<configs::eth_sender::GasAdjusterConfig> for EncodeDist {
             max_blob_base_fee: self.sample(rng),
             // TODO(EVM-676): generate it randomly once this value is used
             settlement_mode: Default::default(),
+            l1_fee_market: self.sample(rng),
         }
     }
 }
@@ -668,6 +723,32 @@ impl Distribution<configs::ObjectStoreConfig> for EncodeDist {
             mode: self.sample(rng),
             max_retries: self.sample(rng),
             local_mirror_path: self.sample(rng),
+            local_mirror_max_size_mb: self.sample(rng),
+            replication: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::object_store::ObjectStoreReplicationConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::object_store::ObjectStoreReplicationConfig {
+        configs::object_store::ObjectStoreReplicationConfig {
+            mirrors: self.sample_collect(rng),
+            read_preference: self.sample_collect(rng),
+        }
+    }
+}
+
+impl Distribution<configs::object_store::ObjectStoreMirrorConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::object_store::ObjectStoreMirrorConfig {
+        configs::object_store::ObjectStoreMirrorConfig {
+            name: self.sample(rng),
+            mode: self.sample(rng),
         }
     }
 }
@@ -682,6 +763,12 @@ impl Distribution<configs::ProofDataHandlerConfig> for EncodeDist {
                 first_tee_processed_batch: L1BatchNumber(rng.gen()),
                 tee_proof_generation_timeout_in_secs: self.sample(rng),
             },
+            marketplace_config: configs::ProofMarketplaceConfig {
+                marketplace_support: self.sample(rng),
+                marketplace_api_key_hashes: self.sample_range(rng).map(|_| rng.gen()).collect(),
+                marketplace_reward_token: self.sample(rng),
+                marketplace_reward_per_batch: self.sample(rng),
+            },
         }
     }
 }
@@ -746,6 +833,17 @@ impl Distribution<configs::GenesisConfig> for EncodeDist {
                 0 => L1BatchCommitmentMode::Rollup,
                 _ => L1BatchCommitmentMode::Validium,
             },
+            custom_system_contracts: self.sample_collect(rng),
+        }
+    }
+}
+
+impl Distribution<configs::CustomSystemContract> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::CustomSystemContract {
+        configs::CustomSystemContract {
+            address: rng.gen(),
+            bytecode_hash: rng.gen(),
+            source: self.sample(rng),
         }
     }
 }
@@ -903,6 +1001,8 @@ impl Distribution<configs::wallets::EthSender> for EncodeDist {
         configs::wallets::EthSender {
             operator: self.sample(rng),
             blob_operator: self.sample_opt(|| self.sample(rng)),
+            prove_operator: self.sample_opt(|| self.sample(rng)),
+            execute_operator: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -936,6 +1036,11 @@ impl Distribution<configs::en_config::ENConfig> for EncodeDist {
                 0 => L1BatchCommitmentMode::Rollup,
                 _ => L1BatchCommitmentMode::Validium,
             },
+            commitment_mode_transition_batches: self
+                .sample_range(rng)
+                .map(|_| L1BatchNumber(rng.gen()))
+                .collect(),
+            forced_inclusion_deadline_batches: self.sample(rng),
             main_node_rate_limit_rps: self.sample_opt(|| rng.gen()),
             gateway_url: self
                 .sample_opt(|| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap()),
@@ -973,6 +1078,7 @@ impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
             max_rows_to_dispatch: self.sample(rng),
             max_retries: self.sample(rng),
             use_dummy_inclusion_data: self.sample(rng),
+            max_concurrent_dispatches: self.sample(rng),
         }
     }
 }
@@ -1003,6 +1109,16 @@ impl Distribution<configs::vm_runner::BasicWitnessInputProducerConfig> for Encod
     }
 }
 
+impl Distribution<configs::vm_runner::BatchVerifierConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::vm_runner::BatchVerifierConfig {
+        configs::vm_runner::BatchVerifierConfig {
+            db_path: self.sample(rng),
+            window_size: self.sample(rng),
+            first_processed_batch: L1BatchNumber(rng.gen()),
+        }
+    }
+}
+
 impl Distribution<configs::CommitmentGeneratorConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::CommitmentGeneratorConfig {
         configs::CommitmentGeneratorConfig {
@@ -1030,6 +1146,7 @@ impl Distribution<configs::snapshot_recovery::PostgresRecoveryConfig> for Encode
     ) -> configs::snapshot_recovery::PostgresRecoveryConfig {
         configs::snapshot_recovery::PostgresRecoveryConfig {
             max_concurrency: self.sample_opt(|| rng.gen()),
+            max_chunks_in_memory: self.sample_opt(|| rng.gen()),
         }
     }
 }
@@ -1059,6 +1176,7 @@ impl Distribution<configs::pruning::PruningConfig> for EncodeDist {
             chunk_size: self.sample(rng),
             removal_delay_sec: self.sample_opt(|| rng.gen()),
             data_retention_sec: self.sample(rng),
+            retained_addresses: self.sample_range(rng).map(|_| rng.gen()).collect(),
         }
     }
 }
@@ -1099,6 +1217,20 @@ impl Distribution<configs::external_proof_integration_api::ExternalProofIntegrat
     }
 }
 
+impl Distribution<configs::external_block_builder::ExternalBlockBuilderConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::external_block_builder::ExternalBlockBuilderConfig {
+        configs::external_block_builder::ExternalBlockBuilderConfig {
+            http_port: self.sample(rng),
+            auth_token: self.sample(rng),
+            proposal_timeout_ms: self.sample(rng),
+            max_transactions_per_proposal: self.sample(rng),
+        }
+    }
+}
+
 impl Distribution<configs::external_price_api_client::ExternalPriceApiClientConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(
         &self,
@@ -1139,6 +1271,8 @@ impl Distribution<configs::prover_job_monitor::ProverJobMonitorConfig> for Encod
             prover_queue_reporter_run_interval_ms: self.sample(rng),
             witness_generator_queue_reporter_run_interval_ms: self.sample(rng),
             witness_job_queuer_run_interval_ms: self.sample(rng),
+            artifacts_gc_run_interval_ms: self.sample(rng),
+            artifacts_gc_retention_ms: self.sample(rng),
             http_port: self.sample(rng),
         }
     }
@@ -1171,6 +1305,7 @@ impl Distribution<configs::GeneralConfig> for EncodeDist {
             da_dispatcher_config: self.sample(rng),
             protective_reads_writer_config: self.sample(rng),
             basic_witness_input_producer_config: self.sample(rng),
+            batch_verifier_config: self.sample(rng),
             commitment_generator: self.sample(rng),
             snapshot_recovery: self.sample(rng),
             pruning: self.sample(rng),
@@ -1182,6 +1317,58 @@ impl Distribution<configs::GeneralConfig> for EncodeDist {
             experimental_vm_config: self.sample(rng),
             prover_job_monitor_config: self.sample(rng),
             timestamp_asserter_config: self.sample(rng),
+            external_block_builder_config: self.sample(rng),
+            token_price_config: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::token_price::TokenPriceProviderConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::token_price::TokenPriceProviderConfig {
+        use configs::token_price::{
+            CoinGeckoTokenPriceConfig, StaticTokenPrice, StaticTokenPriceConfig,
+            TokenPriceProviderConfig as T, UniswapV3PoolConfig, UniswapV3TwapConfig,
+        };
+
+        match rng.gen_range(0..3) {
+            0 => T::CoinGecko(CoinGeckoTokenPriceConfig {
+                base_url: self.sample(rng),
+                api_key: self.sample(rng),
+                client_timeout_ms: self.sample(rng),
+            }),
+            1 => T::UniswapV3Twap(UniswapV3TwapConfig {
+                pools: self
+                    .sample_range(rng)
+                    .map(|_| UniswapV3PoolConfig {
+                        token: rng.gen(),
+                        pool: rng.gen(),
+                    })
+                    .collect(),
+                window_sec: self.sample(rng),
+            }),
+            _ => T::Static(StaticTokenPriceConfig {
+                prices: self
+                    .sample_range(rng)
+                    .map(|_| StaticTokenPrice {
+                        token: rng.gen(),
+                        // Use prices exactly representable in decimal so that they survive JSON roundtrips.
+                        price_usd: f64::from(rng.gen::<u16>()) / 100.0,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+impl Distribution<configs::token_price::TokenPriceConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::token_price::TokenPriceConfig {
+        configs::token_price::TokenPriceConfig {
+            providers: self.sample_collect(rng),
+            cache_ttl_ms: self.sample(rng),
+            stale_after_ms: self.sample(rng),
         }
     }
 }
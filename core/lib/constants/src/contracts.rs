@@ -165,6 +165,13 @@ pub const L2_MESSAGE_ROOT_ADDRESS: Address = H160([
     0x00, 0x01, 0x00, 0x05,
 ]);
 
+/// Maximum address in the kernel space. Contracts deployed at addresses up to this one (inclusive) are executed
+/// by the VM in the kernel mode, i.e. they can perform system calls and use privileged opcodes.
+pub const MAX_KERNEL_SPACE_ADDRESS: Address = H160([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xff, 0xff,
+]);
+
 pub const ERC20_TRANSFER_TOPIC: H256 = H256([
     221, 242, 82, 173, 27, 226, 200, 155, 105, 194, 176, 104, 252, 55, 141, 170, 149, 43, 167, 241,
     99, 196, 161, 22, 40, 245, 90, 77, 245, 35, 179, 239,
@@ -15,8 +15,8 @@ use zksync_queued_job_processor::{async_trait, JobProcessor};
 use zksync_types::{
     bytecode::{trim_padded_evm_bytecode, BytecodeMarker},
     contract_verification_api::{
-        self as api, CompilationArtifacts, VerificationIncomingRequest, VerificationInfo,
-        VerificationRequest,
+        self as api, CompilationArtifacts, PartialMatch, VerificationIncomingRequest,
+        VerificationInfo, VerificationRequest,
     },
     Address, CONTRACT_DEPLOYER_ADDRESS,
 };
@@ -31,6 +31,7 @@ use crate::{
 mod compilers;
 pub mod error;
 mod metrics;
+mod partial_match;
 mod resolver;
 #[cfg(test)]
 mod tests;
@@ -509,21 +510,110 @@ impl ContractVerifier {
         Ok(ConstructorArgs::Check(args.to_vec()))
     }
 
+    /// Searches for verified contracts with the deployed bytecode similar to the bytecode of the contract
+    /// at the specified address.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn find_partial_matches(&self, address: Address) -> anyhow::Result<Vec<PartialMatch>> {
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("contract_verifier")
+            .await?;
+        let Some(deployed_contract) = storage
+            .contract_verification_dal()
+            .get_contract_info_for_verification(address)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+        let bytecode_marker = BytecodeMarker::new(deployed_contract.bytecode_hash)
+            .context("unknown bytecode kind")?;
+        let deployed_bytecode = match bytecode_marker {
+            BytecodeMarker::EraVm => deployed_contract.bytecode.as_slice(),
+            BytecodeMarker::Evm => trim_padded_evm_bytecode(&deployed_contract.bytecode)
+                .context("invalid stored EVM bytecode")?,
+        };
+
+        let skeleton_hash = partial_match::code_skeleton_hash(bytecode_marker, deployed_bytecode);
+        let candidates = storage
+            .contract_verification_dal()
+            .get_partial_match_candidates(skeleton_hash, address, partial_match::MAX_CANDIDATES)
+            .await?;
+        drop(storage);
+
+        let candidates_count = candidates.len();
+        let partial_matches: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|(candidate_address, info)| {
+                let req = info.request.req;
+                let candidate_marker =
+                    VersionedCompiler::from(req.compiler_versions.clone()).expected_bytecode_kind();
+                if candidate_marker != bytecode_marker {
+                    return None;
+                }
+                let differences = partial_match::compare_bytecodes(
+                    bytecode_marker,
+                    deployed_bytecode,
+                    info.artifacts.deployed_bytecode(),
+                )?;
+                Some(PartialMatch {
+                    address: candidate_address,
+                    source_code_data: req.source_code_data,
+                    contract_name: req.contract_name,
+                    compiler_versions: req.compiler_versions,
+                    optimization_used: req.optimization_used,
+                    optimizer_mode: req.optimizer_mode,
+                    metadata_differs: differences.metadata,
+                    immutables_differ: differences.immutables,
+                })
+            })
+            .take(partial_match::MAX_PARTIAL_MATCHES)
+            .collect();
+        tracing::debug!(
+            candidates_count,
+            partial_matches_count = partial_matches.len(),
+            "Finished searching for partial matches"
+        );
+        Ok(partial_matches)
+    }
+
+    /// Processes the verification result. If `partial_matches_address` is set and verification has failed,
+    /// partial matches for the contract at this address are searched for and saved together with the error.
     #[tracing::instrument(level = "debug", skip_all, err, fields(id = request_id))]
     async fn process_result(
         &self,
         request_id: usize,
+        partial_matches_address: Option<Address>,
         verification_result: Result<VerificationInfo, ContractVerifierError>,
     ) -> anyhow::Result<()> {
+        let mut partial_matches = vec![];
+        if let (Some(address), Err(err)) = (partial_matches_address, &verification_result) {
+            if !matches!(err, ContractVerifierError::Internal(_)) {
+                partial_matches = self
+                    .find_partial_matches(address)
+                    .await
+                    .unwrap_or_else(|err| {
+                        tracing::warn!(request_id, "failed searching for partial matches: {err:#}");
+                        vec![]
+                    });
+            }
+        }
+
         let mut storage = self
             .connection_pool
             .connection_tagged("contract_verifier")
             .await?;
         match verification_result {
             Ok(info) => {
+                let bytecode_marker =
+                    VersionedCompiler::from(info.request.req.compiler_versions.clone())
+                        .expected_bytecode_kind();
+                let skeleton_hash = partial_match::code_skeleton_hash(
+                    bytecode_marker,
+                    info.artifacts.deployed_bytecode(),
+                );
                 storage
                     .contract_verification_dal()
-                    .save_verification_info(info)
+                    .save_verification_info(info, Some(skeleton_hash))
                     .await?;
                 tracing::info!("Successfully processed request with id = {request_id}");
             }
@@ -544,7 +634,13 @@ impl ContractVerifier {
                 };
                 storage
                     .contract_verification_dal()
-                    .save_verification_error(request_id, &error_message, &compilation_errors, None)
+                    .save_verification_error(
+                        request_id,
+                        &error_message,
+                        &compilation_errors,
+                        None,
+                        &partial_matches,
+                    )
                     .await?;
                 tracing::info!("Request with id = {request_id} was failed");
             }
@@ -594,6 +690,7 @@ impl JobProcessor for ContractVerifier {
                 "Internal error",
                 &serde_json::Value::Array(Vec::new()),
                 Some(&error),
+                &[],
             )
             .await
             .unwrap();
@@ -611,8 +708,13 @@ impl JobProcessor for ContractVerifier {
             tracing::info!("Started to process request with id = {}", job.id);
 
             let job_id = job.id;
+            let partial_matches_address = job
+                .req
+                .search_partial_matches
+                .then_some(job.req.contract_address);
             let verification_result = this.verify(job).await;
-            this.process_result(job_id, verification_result).await?;
+            this.process_result(job_id, partial_matches_address, verification_result)
+                .await?;
 
             API_CONTRACT_VERIFIER_METRICS
                 .request_processing_time
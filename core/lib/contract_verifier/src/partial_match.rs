@@ -0,0 +1,257 @@
+//! Search for partial matches, i.e., verified contracts with the deployed bytecode similar to the bytecode
+//! of a contract being verified. Similar bytecodes are identical up to the compiler metadata appended to the bytecode
+//! and (for EVM) values of immutable variables.
+
+use std::ops;
+
+use zksync_types::{bytecode::BytecodeMarker, web3::keccak256, H256};
+
+/// Maximum number of candidates loaded from the storage for a single request.
+pub(crate) const MAX_CANDIDATES: usize = 50;
+/// Maximum number of partial matches returned for a single request.
+pub(crate) const MAX_PARTIAL_MATCHES: usize = 10;
+
+const PUSH1_OPCODE: u8 = 0x60;
+const PUSH32_OPCODE: u8 = 0x7f;
+/// Length of the metadata hash appended by `zksolc` / `zkvyper` to EraVM bytecodes.
+const ERA_VM_METADATA_LEN: usize = 32;
+
+/// Differences between two similar bytecodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BytecodeDifferences {
+    pub metadata: bool,
+    pub immutables: bool,
+}
+
+/// Splits the bytecode into the code and metadata parts. If the metadata cannot be recognized,
+/// the metadata part is empty.
+fn split_metadata(marker: BytecodeMarker, bytecode: &[u8]) -> (&[u8], &[u8]) {
+    match marker {
+        BytecodeMarker::EraVm => {
+            if bytecode.len() > ERA_VM_METADATA_LEN && bytecode.len() % 32 == 0 {
+                bytecode.split_at(bytecode.len() - ERA_VM_METADATA_LEN)
+            } else {
+                (bytecode, &[])
+            }
+        }
+        BytecodeMarker::Evm => {
+            // `solc` and `vyper` append CBOR-encoded metadata followed by its length as a 2-byte big-endian integer.
+            let Some(len_start) = bytecode.len().checked_sub(2) else {
+                return (bytecode, &[]);
+            };
+            let metadata_len = u16::from_be_bytes([bytecode[len_start], bytecode[len_start + 1]]);
+            let Some(metadata_start) = len_start.checked_sub(metadata_len.into()) else {
+                return (bytecode, &[]);
+            };
+            // The metadata must be a CBOR map (major type 5) with a small number of entries.
+            if metadata_len > 0 && (0xa0..=0xb7).contains(&bytecode[metadata_start]) {
+                bytecode.split_at(metadata_start)
+            } else {
+                (bytecode, &[])
+            }
+        }
+    }
+}
+
+/// Iterates over ranges of `PUSH32` arguments in the EVM code. Immutable variables are always
+/// inserted into the code as `PUSH32` arguments.
+fn push32_args(code: &[u8]) -> impl Iterator<Item = ops::Range<usize>> + '_ {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos < code.len() {
+            let opcode = code[pos];
+            pos += 1;
+            if (PUSH1_OPCODE..=PUSH32_OPCODE).contains(&opcode) {
+                let arg_len = usize::from(opcode - PUSH1_OPCODE) + 1;
+                let arg_start = pos;
+                pos = (pos + arg_len).min(code.len());
+                if opcode == PUSH32_OPCODE {
+                    return Some(arg_start..pos);
+                }
+            }
+        }
+        None
+    })
+}
+
+/// Computes the hash of the deployed bytecode with metadata and (for EVM) `PUSH32` arguments erased.
+/// Similar bytecodes have the same skeleton hash.
+pub(crate) fn code_skeleton_hash(marker: BytecodeMarker, deployed_bytecode: &[u8]) -> H256 {
+    let (code, _) = split_metadata(marker, deployed_bytecode);
+    match marker {
+        BytecodeMarker::EraVm => H256(keccak256(code)),
+        BytecodeMarker::Evm => {
+            let mut skeleton = code.to_vec();
+            for arg_range in push32_args(code) {
+                skeleton[arg_range].fill(0);
+            }
+            H256(keccak256(&skeleton))
+        }
+    }
+}
+
+/// Compares the deployed bytecode with a bytecode of a verified contract. Returns `None` if the bytecodes
+/// are not similar. Immutables are only allowed to differ if they are zeroed in the `candidate` bytecode,
+/// as is the case for bytecodes produced by the compiler.
+pub(crate) fn compare_bytecodes(
+    marker: BytecodeMarker,
+    deployed_bytecode: &[u8],
+    candidate: &[u8],
+) -> Option<BytecodeDifferences> {
+    let (deployed_code, deployed_metadata) = split_metadata(marker, deployed_bytecode);
+    let (candidate_code, candidate_metadata) = split_metadata(marker, candidate);
+    if deployed_code.len() != candidate_code.len() {
+        return None;
+    }
+    let mut differences = BytecodeDifferences {
+        metadata: deployed_metadata != candidate_metadata,
+        immutables: false,
+    };
+
+    match marker {
+        BytecodeMarker::EraVm => {
+            // Immutables are not stored in EraVM bytecodes.
+            (deployed_code == candidate_code).then_some(differences)
+        }
+        BytecodeMarker::Evm => {
+            let mut pos = 0;
+            for arg_range in push32_args(candidate_code) {
+                if deployed_code[pos..arg_range.start] != candidate_code[pos..arg_range.start] {
+                    return None;
+                }
+                let deployed_arg = &deployed_code[arg_range.clone()];
+                let candidate_arg = &candidate_code[arg_range.clone()];
+                if deployed_arg != candidate_arg {
+                    if candidate_arg.iter().any(|&byte| byte != 0) {
+                        return None;
+                    }
+                    differences.immutables = true;
+                }
+                pos = arg_range.end;
+            }
+            (deployed_code[pos..] == candidate_code[pos..]).then_some(differences)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `PUSH1 0x80 PUSH1 0x40 MSTORE PUSH32 <immutable> POP STOP`
+    fn evm_code(immutable: [u8; 32]) -> Vec<u8> {
+        let mut code = vec![0x60, 0x80, 0x60, 0x40, 0x52, PUSH32_OPCODE];
+        code.extend_from_slice(&immutable);
+        code.extend_from_slice(&[0x50, 0x00]);
+        code
+    }
+
+    fn with_evm_metadata(mut code: Vec<u8>, metadata_byte: u8) -> Vec<u8> {
+        // `{"ipfs": <34 bytes>}` encoded in CBOR.
+        let mut metadata = vec![0xa1, 0x64];
+        metadata.extend_from_slice(b"ipfs");
+        metadata.extend_from_slice(&[0x58, 0x22]);
+        metadata.extend_from_slice(&[metadata_byte; 34]);
+        let metadata_len = u16::try_from(metadata.len()).unwrap();
+        code.extend_from_slice(&metadata);
+        code.extend_from_slice(&metadata_len.to_be_bytes());
+        code
+    }
+
+    #[test]
+    fn splitting_evm_metadata() {
+        let code = evm_code([0; 32]);
+        let bytecode = with_evm_metadata(code.clone(), 1);
+        let (split_code, metadata) = split_metadata(BytecodeMarker::Evm, &bytecode);
+        assert_eq!(split_code, code);
+        assert_eq!(metadata.len(), 2 + 4 + 2 + 34 + 2);
+
+        let (split_code, metadata) = split_metadata(BytecodeMarker::Evm, &code);
+        assert_eq!(split_code, code);
+        assert!(metadata.is_empty());
+    }
+
+    #[test]
+    fn iterating_over_push32_args() {
+        let code = evm_code([1; 32]);
+        let args: Vec<_> = push32_args(&code).collect();
+        assert_eq!(args, [6..38]);
+
+        // `PUSH2` argument containing the `PUSH32` opcode must be skipped.
+        let code = [0x61, PUSH32_OPCODE, PUSH32_OPCODE, 0x00];
+        assert_eq!(push32_args(&code).count(), 0);
+        // Truncated `PUSH32` argument.
+        let code = [0x00, PUSH32_OPCODE, 0x01];
+        let args: Vec<_> = push32_args(&code).collect();
+        assert_eq!(args, [2..3]);
+    }
+
+    #[test]
+    fn comparing_similar_evm_bytecodes() {
+        let compiled = with_evm_metadata(evm_code([0; 32]), 1);
+        let deployed = with_evm_metadata(evm_code([0xff; 32]), 2);
+        assert_eq!(
+            code_skeleton_hash(BytecodeMarker::Evm, &compiled),
+            code_skeleton_hash(BytecodeMarker::Evm, &deployed)
+        );
+        let differences = compare_bytecodes(BytecodeMarker::Evm, &deployed, &compiled).unwrap();
+        assert_eq!(
+            differences,
+            BytecodeDifferences {
+                metadata: true,
+                immutables: true,
+            }
+        );
+
+        let deployed = with_evm_metadata(evm_code([0; 32]), 1);
+        let differences = compare_bytecodes(BytecodeMarker::Evm, &deployed, &compiled).unwrap();
+        assert_eq!(
+            differences,
+            BytecodeDifferences {
+                metadata: false,
+                immutables: false,
+            }
+        );
+    }
+
+    #[test]
+    fn comparing_different_evm_bytecodes() {
+        // Non-zero `PUSH32` argument in the candidate is a constant rather than an immutable.
+        let compiled = with_evm_metadata(evm_code([1; 32]), 1);
+        let deployed = with_evm_metadata(evm_code([2; 32]), 1);
+        assert!(compare_bytecodes(BytecodeMarker::Evm, &deployed, &compiled).is_none());
+
+        let compiled = with_evm_metadata(evm_code([0; 32]), 1);
+        let mut code = evm_code([0; 32]);
+        code[1] = 0x60;
+        let deployed = with_evm_metadata(code, 1);
+        assert_ne!(
+            code_skeleton_hash(BytecodeMarker::Evm, &compiled),
+            code_skeleton_hash(BytecodeMarker::Evm, &deployed)
+        );
+        assert!(compare_bytecodes(BytecodeMarker::Evm, &deployed, &compiled).is_none());
+    }
+
+    #[test]
+    fn comparing_era_vm_bytecodes() {
+        let mut compiled = vec![1; 64];
+        compiled.extend_from_slice(&[2; ERA_VM_METADATA_LEN]);
+        let mut deployed = vec![1; 64];
+        deployed.extend_from_slice(&[3; ERA_VM_METADATA_LEN]);
+        assert_eq!(
+            code_skeleton_hash(BytecodeMarker::EraVm, &compiled),
+            code_skeleton_hash(BytecodeMarker::EraVm, &deployed)
+        );
+        let differences = compare_bytecodes(BytecodeMarker::EraVm, &deployed, &compiled).unwrap();
+        assert_eq!(
+            differences,
+            BytecodeDifferences {
+                metadata: true,
+                immutables: false,
+            }
+        );
+
+        deployed[0] = 0;
+        assert!(compare_bytecodes(BytecodeMarker::EraVm, &deployed, &compiled).is_none());
+    }
+}
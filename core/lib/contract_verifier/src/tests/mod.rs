@@ -357,6 +357,7 @@ fn test_request(address: Address, source: &str) -> VerificationIncomingRequest {
         constructor_arguments: Default::default(),
         is_system: false,
         force_evmla: false,
+        search_partial_matches: false,
     }
 }
 
@@ -609,6 +610,90 @@ async fn bytecode_mismatch_error() {
     assert_eq!(err, ContractVerifierError::BytecodeMismatch.to_string());
 }
 
+#[tokio::test]
+async fn partial_matches_for_bytecode_mismatch() {
+    let pool = ConnectionPool::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    // Bytecodes only differ in the last word, which corresponds to the metadata hash.
+    let verified_bytecode = [[1; 64].as_slice(), &[2; 32]].concat();
+    let deployed_bytecode = [[1; 64].as_slice(), &[3; 32]].concat();
+    let verified_address = Address::repeat_byte(1);
+    mock_deployment(
+        &mut storage,
+        verified_address,
+        verified_bytecode.clone(),
+        &[],
+    )
+    .await;
+    let address = Address::repeat_byte(2);
+    mock_deployment(&mut storage, address, deployed_bytecode, &[]).await;
+
+    let req = test_request(verified_address, COUNTER_CONTRACT);
+    let verified_request_id = storage
+        .contract_verification_dal()
+        .add_contract_verification_request(&req)
+        .await
+        .unwrap();
+
+    let mock_resolver = MockCompilerResolver::zksolc(move |_| CompilationArtifacts {
+        bytecode: verified_bytecode.clone(),
+        deployed_bytecode: None,
+        abi: counter_contract_abi(),
+    });
+    let verifier = ContractVerifier::with_resolver(
+        Duration::from_secs(60),
+        pool.clone(),
+        Arc::new(mock_resolver),
+    )
+    .await
+    .unwrap();
+
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    verifier
+        .clone()
+        .run(stop_receiver.clone(), Some(1))
+        .await
+        .unwrap();
+    let status = storage
+        .contract_verification_dal()
+        .get_verification_request_status(verified_request_id)
+        .await
+        .unwrap()
+        .expect("no status");
+    assert_eq!(status.status, "successful");
+
+    let mut req = test_request(address, COUNTER_CONTRACT);
+    req.search_partial_matches = true;
+    let request_id = storage
+        .contract_verification_dal()
+        .add_contract_verification_request(&req)
+        .await
+        .unwrap();
+    verifier.run(stop_receiver, Some(1)).await.unwrap();
+
+    let status = storage
+        .contract_verification_dal()
+        .get_verification_request_status(request_id)
+        .await
+        .unwrap()
+        .expect("no status");
+    assert_eq!(status.status, "failed");
+    assert_eq!(
+        status.error.unwrap(),
+        ContractVerifierError::BytecodeMismatch.to_string()
+    );
+    let partial_matches = status.partial_matches.expect("no partial matches");
+    assert_eq!(partial_matches.len(), 1);
+    let partial_match = &partial_matches[0];
+    assert_eq!(partial_match.address, verified_address);
+    assert_eq!(partial_match.contract_name, req.contract_name);
+    assert_eq!(partial_match.compiler_versions, req.compiler_versions);
+    assert!(partial_match.metadata_differs);
+    assert!(!partial_match.immutables_differ);
+}
+
 #[test_casing(4, Product((TestContract::ALL, BYTECODE_KINDS)))]
 #[tokio::test]
 async fn args_mismatch_error(contract: TestContract, bytecode_kind: BytecodeMarker) {
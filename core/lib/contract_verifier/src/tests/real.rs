@@ -221,6 +221,7 @@ async fn using_zksolc_with_abstract_contract(specify_contract_file: bool) {
         source_code_data,
         contract_name: contract_name.to_owned(),
         force_evmla: false,
+        search_partial_matches: false,
     };
 
     let input = ZkSolc::build_input(req).unwrap();
@@ -242,6 +243,7 @@ fn test_yul_request(compiler_versions: CompilerVersions) -> VerificationIncoming
         constructor_arguments: Default::default(),
         is_system: false,
         force_evmla: false,
+        search_partial_matches: false,
     }
 }
 
@@ -297,6 +299,7 @@ fn test_vyper_request(
         constructor_arguments: Default::default(),
         is_system: false,
         force_evmla: false,
+        search_partial_matches: false,
     }
 }
 
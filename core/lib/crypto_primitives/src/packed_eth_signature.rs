@@ -1,3 +1,4 @@
+use rayon::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 use zksync_basic_types::{serde_wrappers::ZeroPrefixHexSerde, web3::keccak256, Address, H256};
@@ -10,6 +11,10 @@ use crate::{
     eip712_signature::typed_structure::{EIP712TypedStructure, Eip712Domain},
 };
 
+/// Minimum number of signatures in a batch for which signers are recovered in parallel. For smaller batches,
+/// distributing work among threads costs more than it saves.
+const MIN_PARALLEL_RECOVERY_BATCH_SIZE: usize = 8;
+
 /// Struct used for working with Ethereum signatures created using eth_sign (using geth, ethers.js, etc)
 /// message is serialized as 65 bytes long `0x` prefixed string.
 ///
@@ -110,6 +115,21 @@ impl PackedEthSignature {
         Ok(Address::from(address.0))
     }
 
+    /// Recovers signers for a batch of `(signature, signed_bytes)` pairs. Large batches are processed
+    /// in parallel on the global `rayon` thread pool. Results are returned in the same order as inputs.
+    pub fn batch_recover_signers(
+        batch: &[(Self, H256)],
+    ) -> Vec<Result<Address, ParityCryptoError>> {
+        let recover = |(signature, signed_bytes): &(Self, H256)| {
+            signature.signature_recover_signer(signed_bytes)
+        };
+        if batch.len() < MIN_PARALLEL_RECOVERY_BATCH_SIZE {
+            batch.iter().map(recover).collect()
+        } else {
+            batch.par_iter().map(recover).collect()
+        }
+    }
+
     pub fn from_rsv(r: &H256, s: &H256, v: u8) -> Self {
         let r = H256::from_slice(&r.0);
         let s = H256::from_slice(&s.0);
@@ -216,4 +236,34 @@ mod tests {
     fn unpack_v_1_with_chain_id_270() {
         assert_eq!(PackedEthSignature::unpack_v(576).unwrap(), (1, Some(270)));
     }
+
+    #[test]
+    fn batch_recovering_signers() {
+        for batch_size in [1, MIN_PARALLEL_RECOVERY_BATCH_SIZE, 50] {
+            let keys: Vec<_> = (0..batch_size).map(|_| K256PrivateKey::random()).collect();
+            let mut batch: Vec<_> = keys
+                .iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    let signed_bytes =
+                        PackedEthSignature::message_to_signed_bytes(&i.to_be_bytes());
+                    let signature = PackedEthSignature::sign_raw(key, &signed_bytes).unwrap();
+                    (signature, signed_bytes)
+                })
+                .collect();
+            // Corrupt the last signature; it should not affect other signatures in the batch.
+            let (_, last_signed_bytes) = batch.last_mut().unwrap();
+            *last_signed_bytes = H256::repeat_byte(0xff);
+
+            let signers = PackedEthSignature::batch_recover_signers(&batch);
+            assert_eq!(signers.len(), batch_size);
+            for (i, (signer, key)) in signers.into_iter().zip(&keys).enumerate() {
+                if i + 1 == batch_size {
+                    assert_ne!(signer.ok(), Some(key.address()));
+                } else {
+                    assert_eq!(signer.unwrap(), key.address());
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS decoded_transactions (
+    tx_hash BYTEA PRIMARY KEY,
+    miniblock_number BIGINT NOT NULL REFERENCES miniblocks (number) ON DELETE CASCADE,
+    decoded JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS decoded_transactions_miniblock_number_idx ON decoded_transactions (miniblock_number);
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS tokens_well_known_l2_address_idx;
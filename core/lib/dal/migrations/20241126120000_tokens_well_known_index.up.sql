@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS tokens_well_known_l2_address_idx ON tokens (l2_address) WHERE well_known = TRUE;
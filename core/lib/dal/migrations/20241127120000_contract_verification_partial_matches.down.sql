@@ -0,0 +1,5 @@
+ALTER TABLE contract_verification_requests DROP COLUMN IF EXISTS partial_matches;
+ALTER TABLE contract_verification_requests DROP COLUMN IF EXISTS search_partial_matches;
+
+DROP INDEX IF EXISTS contracts_verification_info_code_skeleton_hash_idx;
+ALTER TABLE contracts_verification_info DROP COLUMN IF EXISTS code_skeleton_hash;
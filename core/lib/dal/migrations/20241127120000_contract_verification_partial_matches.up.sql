@@ -0,0 +1,7 @@
+ALTER TABLE contracts_verification_info ADD COLUMN IF NOT EXISTS code_skeleton_hash BYTEA;
+CREATE INDEX IF NOT EXISTS contracts_verification_info_code_skeleton_hash_idx
+    ON contracts_verification_info (code_skeleton_hash);
+
+ALTER TABLE contract_verification_requests
+    ADD COLUMN IF NOT EXISTS search_partial_matches BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE contract_verification_requests ADD COLUMN IF NOT EXISTS partial_matches JSONB;
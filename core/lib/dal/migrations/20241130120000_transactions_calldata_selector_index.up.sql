@@ -0,0 +1,6 @@
+-- no-transaction
+-- `transactions` is a hot table; build the index concurrently so that it doesn't block writes.
+-- `CONCURRENTLY` cannot run inside a transaction block, hence the single statement in this migration.
+CREATE INDEX CONCURRENTLY IF NOT EXISTS transactions_calldata_selector_idx
+    ON transactions (SUBSTRING(data ->> 'calldata' FROM 1 FOR 10), miniblock_number, index_in_block)
+    WHERE miniblock_number IS NOT NULL;
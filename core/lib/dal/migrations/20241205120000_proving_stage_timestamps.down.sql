@@ -0,0 +1 @@
+DROP TABLE IF EXISTS proving_stage_timestamps;
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS proving_stage_timestamps (
+    l1_batch_number BIGINT NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    stage TEXT NOT NULL,
+    recorded_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, stage)
+);
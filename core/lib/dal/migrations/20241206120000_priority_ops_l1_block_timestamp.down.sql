@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS transactions_unincluded_priority_ops_idx;
+ALTER TABLE transactions DROP COLUMN IF EXISTS l1_block_timestamp;
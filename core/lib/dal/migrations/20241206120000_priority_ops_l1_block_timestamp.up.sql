@@ -0,0 +1,4 @@
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS l1_block_timestamp BIGINT;
+CREATE INDEX IF NOT EXISTS transactions_unincluded_priority_ops_idx
+    ON transactions (priority_op_id)
+    WHERE is_priority = TRUE AND l1_batch_number IS NULL;
@@ -16,12 +16,13 @@ use zksync_db_connection::{
 };
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
+    api,
     block::{
         BlockGasCount, L1BatchHeader, L1BatchStatistics, L1BatchTreeData, L2BlockHeader,
         StorageOracleInfo, UnsealedL1BatchHeader,
     },
     commitment::{L1BatchCommitmentArtifacts, L1BatchWithMetadata},
-    fee_model::BatchFeeInput,
+    fee_model::{BatchFeeInput, FeeParams},
     l2_to_l1_log::UserL2ToL1Log,
     writes::TreeWrite,
     Address, Bloom, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256, U256,
@@ -86,6 +87,30 @@ impl BlocksDal<'_, '_> {
         Ok(())
     }
 
+    /// Returns the timestamp of the specified L1 batch. Unlike other getters, also returns timestamps
+    /// of unsealed batches.
+    pub async fn get_l1_batch_timestamp(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<u64>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                timestamp
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0)
+        )
+        .instrument("get_l1_batch_timestamp")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| row.timestamp as u64))
+    }
+
     pub async fn is_genesis_needed(&mut self) -> DalResult<bool> {
         let count = sqlx::query!(
             r#"
@@ -785,6 +810,140 @@ impl BlocksDal<'_, '_> {
         Ok(())
     }
 
+    /// Saves execution statistics for an L1 batch. The statistics are exposed in batch reports via the API.
+    pub async fn save_l1_batch_execution_stats(
+        &mut self,
+        number: L1BatchNumber,
+        stats: &api::L1BatchExecutionStats,
+    ) -> DalResult<()> {
+        let instrumentation =
+            Instrumented::new("save_l1_batch_execution_stats").with_arg("number", &number);
+        let stats =
+            serde_json::to_value(stats).map_err(|err| instrumentation.arg_error("stats", err))?;
+        let query = sqlx::query!(
+            r#"
+            UPDATE l1_batches
+            SET
+                execution_stats = $2,
+                updated_at = NOW()
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0),
+            stats
+        );
+        let result = instrumentation
+            .clone()
+            .with(query)
+            .execute(self.storage)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            let err = instrumentation.constraint_error(anyhow::anyhow!("L1 batch is not present"));
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Returns execution stats saved for the specified L1 batch by [`Self::save_l1_batch_execution_stats()`].
+    pub async fn get_l1_batch_execution_stats(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<api::L1BatchExecutionStats>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                execution_stats
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0)
+        )
+        .try_map(|row| {
+            row.execution_stats
+                .map(|stats| serde_json::from_value(stats).decode_column("execution_stats"))
+                .transpose()
+        })
+        .instrument("get_l1_batch_execution_stats")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .flatten())
+    }
+
+    /// Saves fee model parameters the fee input of the specified L1 batch was derived from.
+    pub async fn save_l1_batch_fee_params(
+        &mut self,
+        number: L1BatchNumber,
+        fee_params: &FeeParams,
+    ) -> DalResult<()> {
+        let instrumentation =
+            Instrumented::new("save_l1_batch_fee_params").with_arg("number", &number);
+        let fee_params = serde_json::to_value(fee_params)
+            .map_err(|err| instrumentation.arg_error("fee_params", err))?;
+        let query = sqlx::query!(
+            r#"
+            UPDATE l1_batches
+            SET
+                fee_params = $2,
+                updated_at = NOW()
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0),
+            fee_params
+        );
+        let result = instrumentation
+            .clone()
+            .with(query)
+            .execute(self.storage)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            let err = instrumentation.constraint_error(anyhow::anyhow!("L1 batch is not present"));
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Returns fee model parameters for the specified L1 batch. Returns `None` if the batch is not present,
+    /// or if parameters were not recorded for it (e.g., on external nodes, or for batches created before parameters were persisted).
+    pub async fn get_l1_batch_fee_params(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<FeeParams>> {
+        let instrumentation =
+            Instrumented::new("get_l1_batch_fee_params").with_arg("number", &number);
+        let query = sqlx::query!(
+            r#"
+            SELECT
+                fee_params
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0)
+        );
+        let row = instrumentation
+            .clone()
+            .with(query)
+            .fetch_optional(self.storage)
+            .await?;
+
+        let Some(fee_params) = row.and_then(|row| row.fee_params) else {
+            return Ok(None);
+        };
+        let fee_params = serde_json::from_value(fee_params).map_err(|err| {
+            instrumentation.constraint_error(
+                anyhow::Error::from(err).context("invalid fee params stored for L1 batch"),
+            )
+        })?;
+        Ok(Some(fee_params))
+    }
+
     pub async fn get_unsealed_l1_batch(&mut self) -> DalResult<Option<UnsealedL1BatchHeader>> {
         Self::get_unsealed_l1_batch_inner(self.storage).await
     }
@@ -2984,6 +3143,47 @@ mod tests {
             .is_err());
     }
 
+    #[tokio::test]
+    async fn persisting_l1_batch_fee_params() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let header = mock_l1_batch_header();
+        assert!(conn
+            .blocks_dal()
+            .save_l1_batch_fee_params(header.number, &FeeParams::sensible_v1_default())
+            .await
+            .is_err());
+
+        insert_mock_l1_batch_header(&mut conn, &header).await;
+        let fee_params = conn
+            .blocks_dal()
+            .get_l1_batch_fee_params(header.number)
+            .await
+            .unwrap();
+        assert!(fee_params.is_none());
+
+        conn.blocks_dal()
+            .save_l1_batch_fee_params(header.number, &FeeParams::sensible_v1_default())
+            .await
+            .unwrap();
+        let fee_params = conn
+            .blocks_dal()
+            .get_l1_batch_fee_params(header.number)
+            .await
+            .unwrap()
+            .expect("no fee params");
+        let FeeParams::V1(fee_params) = fee_params else {
+            panic!("unexpected fee params: {fee_params:?}");
+        };
+        assert_eq!(fee_params.l1_gas_price, 1_000_000_000);
+        assert_eq!(fee_params.config.minimal_l2_gas_price, 100_000_000);
+    }
+
     #[tokio::test]
     async fn persisting_evm_emulator_hash() {
         let pool = ConnectionPool::<Core>::test_pool().await;
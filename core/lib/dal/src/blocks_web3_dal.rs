@@ -1,9 +1,12 @@
+use std::{ops, str::FromStr};
+
 use zksync_db_connection::{
     connection::Connection, error::DalResult, instrument::InstrumentExt, interpolate_query,
     match_query_as,
 };
 use zksync_system_constants::EMPTY_UNCLES_HASH;
 use zksync_types::{
+    aggregated_operations::AggregatedActionType,
     api,
     debug_flat_call::CallTraceMeta,
     fee_model::BatchFeeInput,
@@ -18,13 +21,22 @@ use crate::{
         bigdecimal_to_u256, parse_protocol_version,
         storage_block::{
             ResolvedL1BatchForL2Block, StorageBlockDetails, StorageL1BatchDetails,
-            LEGACY_BLOCK_GAS_LIMIT,
+            StorageL1BatchOperationCost, StorageL1BatchReportData, LEGACY_BLOCK_GAS_LIMIT,
         },
         storage_transaction::CallTrace,
     },
     Core, CoreDal,
 };
 
+/// Fee paid by a transaction used to compute rewards in `eth_feeHistory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionPriorityFee {
+    pub l2_block_number: L2BlockNumber,
+    /// Effective priority fee per gas, i.e. the difference between the effective gas price and the block base fee.
+    pub priority_fee_per_gas: U256,
+    pub gas_used: u64,
+}
+
 #[derive(Debug)]
 pub struct BlocksWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -653,6 +665,55 @@ impl BlocksWeb3Dal<'_, '_> {
         Ok((base_fee_per_gas, effective_pubdata_price))
     }
 
+    /// Returns priority fees paid by transactions in the specified range of L2 blocks, ordered by the L2 block number
+    /// and the transaction index in the block.
+    pub async fn get_transaction_priority_fees(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<TransactionPriorityFee>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                transactions.miniblock_number AS "miniblock_number!",
+                transactions.effective_gas_price AS "effective_gas_price!",
+                transactions.gas_limit AS "gas_limit!",
+                transactions.refunded_gas,
+                miniblocks.base_fee_per_gas
+            FROM
+                transactions
+            INNER JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+            WHERE
+                transactions.miniblock_number BETWEEN $1 AND $2
+                AND transactions.effective_gas_price IS NOT NULL
+                AND transactions.gas_limit IS NOT NULL
+            ORDER BY
+                transactions.miniblock_number,
+                transactions.index_in_block
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .instrument("get_transaction_priority_fees")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let effective_gas_price = bigdecimal_to_u256(row.effective_gas_price);
+                let base_fee_per_gas = bigdecimal_to_u256(row.base_fee_per_gas);
+                let gas_limit =
+                    u64::try_from(bigdecimal_to_u256(row.gas_limit)).unwrap_or(u64::MAX);
+                TransactionPriorityFee {
+                    l2_block_number: L2BlockNumber(row.miniblock_number as u32),
+                    priority_fee_per_gas: effective_gas_price.saturating_sub(base_fee_per_gas),
+                    gas_used: gas_limit.saturating_sub(row.refunded_gas as u64),
+                }
+            })
+            .collect())
+    }
+
     pub async fn get_block_details(
         &mut self,
         block_number: L2BlockNumber,
@@ -793,12 +854,158 @@ impl BlocksWeb3Dal<'_, '_> {
 
         Ok(l1_batch_details.map(Into::into))
     }
+
+    /// Returns L1 batches with numbers greater than `after` that have reached the specified `stage`
+    /// (i.e., the corresponding L1 transaction is confirmed), ordered by number.
+    pub async fn get_l1_batch_stage_updates(
+        &mut self,
+        stage: api::L1BatchStage,
+        after: L1BatchNumber,
+    ) -> DalResult<Vec<api::L1BatchStageUpdate>> {
+        let stage_str = match stage {
+            api::L1BatchStage::Committed => "committed",
+            api::L1BatchStage::Proven => "proven",
+            api::L1BatchStage::Executed => "executed",
+        };
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batches.number,
+                eth_tx.tx_hash,
+                eth_tx.confirmed_at AS "confirmed_at!"
+            FROM
+                l1_batches
+            INNER JOIN eth_txs_history AS eth_tx
+                ON (
+                    eth_tx.eth_tx_id = (
+                        CASE $1::TEXT
+                            WHEN 'committed' THEN l1_batches.eth_commit_tx_id
+                            WHEN 'proven' THEN l1_batches.eth_prove_tx_id
+                            ELSE l1_batches.eth_execute_tx_id
+                        END
+                    )
+                    AND eth_tx.confirmed_at IS NOT NULL
+                )
+            WHERE
+                l1_batches.number > $2
+            ORDER BY
+                l1_batches.number
+            "#,
+            stage_str,
+            i64::from(after.0)
+        )
+        .instrument("get_l1_batch_stage_updates")
+        .with_arg("stage", &stage)
+        .with_arg("after", &after)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| api::L1BatchStageUpdate {
+                number: L1BatchNumber(row.number as u32),
+                stage,
+                tx_hash: H256::from_str(&row.tx_hash).expect("Incorrect L1 tx hash"),
+                confirmed_at: row.confirmed_at.and_utc(),
+            })
+            .collect())
+    }
+
+    pub async fn get_l1_batch_report(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Option<api::L1BatchReport>> {
+        let Some(details) = self.get_l1_batch_details(l1_batch_number).await? else {
+            return Ok(None);
+        };
+
+        let report_data = sqlx::query_as!(
+            StorageL1BatchReportData,
+            r#"
+            SELECT
+                l1_batches.sealed_at,
+                l1_batches.execution_stats,
+                l1_batches.predicted_circuits_by_type,
+                l1_batches.predicted_commit_gas_cost,
+                l1_batches.predicted_prove_gas_cost,
+                l1_batches.predicted_execute_gas_cost,
+                proof_generation_details.created_at AS "proof_inputs_ready_at?",
+                proof_generation_details.prover_taken_at AS "proof_picked_at?",
+                proof_generation_details.updated_at AS "proof_updated_at?",
+                proof_generation_details.status AS "proof_status?"
+            FROM
+                l1_batches
+            LEFT JOIN proof_generation_details
+                ON proof_generation_details.l1_batch_number = l1_batches.number
+            WHERE
+                l1_batches.number = $1
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_l1_batch_report")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+
+        let costs = sqlx::query_as!(
+            StorageL1BatchOperationCost,
+            r#"
+            SELECT
+                eth_txs.tx_type,
+                eth_txs_history.tx_hash,
+                eth_txs.gas_used,
+                eth_txs_history.base_fee_per_gas,
+                eth_txs_history.priority_fee_per_gas,
+                eth_txs_history.blob_base_fee_per_gas,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        l1_batches AS processed_batches
+                    WHERE
+                        processed_batches.eth_commit_tx_id = eth_txs.id
+                        OR processed_batches.eth_prove_tx_id = eth_txs.id
+                        OR processed_batches.eth_execute_tx_id = eth_txs.id
+                ) AS "batch_count!"
+            FROM
+                l1_batches
+            INNER JOIN eth_txs
+                ON eth_txs.id IN (
+                    l1_batches.eth_commit_tx_id,
+                    l1_batches.eth_prove_tx_id,
+                    l1_batches.eth_execute_tx_id
+                )
+            INNER JOIN eth_txs_history
+                ON eth_txs_history.id = eth_txs.confirmed_eth_tx_history_id
+            WHERE
+                l1_batches.number = $1
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_l1_batch_report#costs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        let mut l1_costs = api::L1BatchL1Costs::default();
+        for cost in costs {
+            let slot = match cost.tx_type.parse() {
+                Ok(AggregatedActionType::Commit) => &mut l1_costs.commit,
+                Ok(AggregatedActionType::PublishProofOnchain) => &mut l1_costs.prove,
+                Ok(AggregatedActionType::Execute) => &mut l1_costs.execute,
+                Err(_) => continue,
+            };
+            *slot = Some(cost.into());
+        }
+        Ok(Some(report_data.into_report(details, l1_costs)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use zksync_types::{
-        aggregated_operations::AggregatedActionType,
         block::{L2BlockHasher, L2BlockHeader},
         Address, L2BlockNumber, ProtocolVersion, ProtocolVersionId,
     };
@@ -1042,6 +1249,115 @@ mod tests {
         assert_eq!(resolved_l2_block_number, Some(l2_block_header.number));
     }
 
+    #[tokio::test]
+    async fn getting_l1_batch_report() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+        let l1_batch_header = create_l1_batch_header(1);
+        let l1_batch_number = l1_batch_header.number;
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&l1_batch_header)
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .mark_l2_blocks_as_executed_in_l1_batch(l1_batch_number)
+            .await
+            .unwrap();
+
+        let report = conn
+            .blocks_web3_dal()
+            .get_l1_batch_report(l1_batch_number)
+            .await
+            .unwrap()
+            .expect("no report");
+        assert_eq!(report.details.number, l1_batch_number);
+        assert_eq!(report.execution, None);
+        assert_eq!(report.l1_costs, api::L1BatchL1Costs::default());
+        assert_eq!(report.proof, None);
+
+        let stats = api::L1BatchExecutionStats {
+            gas_used: 1_000_000,
+            computational_gas_used: 500_000,
+            events_count: 10,
+            initial_writes_count: Some(3),
+            repeated_writes_count: Some(5),
+            pubdata: api::PubdataComposition {
+                total: 1_000,
+                l2_to_l1_logs: 88,
+                l2_to_l1_messages: 96,
+                published_bytecodes: 0,
+                state_diffs: 816,
+            },
+        };
+        conn.blocks_dal()
+            .save_l1_batch_execution_stats(l1_batch_number, &stats)
+            .await
+            .unwrap();
+
+        let commit_tx = conn
+            .eth_sender_dal()
+            .save_eth_tx(
+                0,
+                vec![],
+                AggregatedActionType::Commit,
+                Address::default(),
+                0,
+                None,
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        let tx_hash = H256::random();
+        conn.eth_sender_dal()
+            .insert_tx_history(commit_tx.id, 10, 2, None, tx_hash, &[], 0)
+            .await
+            .unwrap();
+        conn.eth_sender_dal()
+            .confirm_tx(tx_hash, 100_000.into())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .set_eth_tx_id(
+                l1_batch_number..=l1_batch_number,
+                commit_tx.id,
+                AggregatedActionType::Commit,
+            )
+            .await
+            .unwrap();
+
+        let report = conn
+            .blocks_web3_dal()
+            .get_l1_batch_report(l1_batch_number)
+            .await
+            .unwrap()
+            .expect("no report");
+        assert_eq!(report.execution, Some(stats));
+        let commit_cost = report.l1_costs.commit.expect("no commit cost");
+        assert_eq!(commit_cost.tx_hash, tx_hash);
+        assert_eq!(commit_cost.gas_used, Some(100_000));
+        assert_eq!(commit_cost.max_fee_per_gas, 12);
+        assert_eq!(commit_cost.batch_count, 1);
+        assert_eq!(commit_cost.max_cost, Some(1_200_000.into()));
+        assert_eq!(report.l1_costs.prove, None);
+        assert_eq!(report.l1_costs.execute, None);
+
+        let report = conn
+            .blocks_web3_dal()
+            .get_l1_batch_report(L1BatchNumber(2))
+            .await
+            .unwrap();
+        assert!(report.is_none());
+    }
+
     #[tokio::test]
     async fn resolving_block_by_hash() {
         let connection_pool = ConnectionPool::<Core>::test_pool().await;
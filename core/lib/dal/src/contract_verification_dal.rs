@@ -10,7 +10,7 @@ use zksync_db_connection::{error::SqlxContext, instrument::InstrumentExt};
 use zksync_types::{
     address_to_h256,
     contract_verification_api::{
-        VerificationIncomingRequest, VerificationInfo, VerificationRequest,
+        PartialMatch, VerificationIncomingRequest, VerificationInfo, VerificationRequest,
         VerificationRequestStatus,
     },
     web3, Address, CONTRACT_DEPLOYER_ADDRESS, H256,
@@ -92,12 +92,13 @@ impl ContractVerificationDal<'_, '_> {
                 constructor_arguments,
                 is_system,
                 force_evmla,
+                search_partial_matches,
                 status,
                 created_at,
                 updated_at
             )
             VALUES
-            ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 'queued', NOW(), NOW())
+            ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 'queued', NOW(), NOW())
             RETURNING
             id
             "#,
@@ -112,6 +113,7 @@ impl ContractVerificationDal<'_, '_> {
             query.constructor_arguments.0.as_slice(),
             query.is_system,
             query.force_evmla,
+            query.search_partial_matches,
         )
         .instrument("add_contract_verification_request")
         .with_arg("address", &query.contract_address)
@@ -172,7 +174,8 @@ impl ContractVerificationDal<'_, '_> {
             optimizer_mode,
             constructor_arguments,
             is_system,
-            force_evmla
+            force_evmla,
+            search_partial_matches
             "#,
             &processing_timeout
         )
@@ -185,9 +188,12 @@ impl ContractVerificationDal<'_, '_> {
     }
 
     /// Updates the verification request status and inserts the verification info upon successful verification.
+    /// `code_skeleton_hash` is the hash of the deployed bytecode with metadata and immutables stripped; it is used
+    /// to search for partial matches (see [`Self::get_partial_match_candidates()`]).
     pub async fn save_verification_info(
         &mut self,
         verification_info: VerificationInfo,
+        code_skeleton_hash: Option<H256>,
     ) -> DalResult<()> {
         let mut transaction = self.storage.start_transaction().await?;
         let id = verification_info.request.id;
@@ -216,16 +222,18 @@ impl ContractVerificationDal<'_, '_> {
         sqlx::query!(
             r#"
             INSERT INTO
-            contracts_verification_info (address, verification_info)
+            contracts_verification_info (address, verification_info, code_skeleton_hash)
             VALUES
-            ($1, $2)
+            ($1, $2, $3)
             ON CONFLICT (address) DO
             UPDATE
             SET
-            verification_info = $2
+            verification_info = $2,
+            code_skeleton_hash = $3
             "#,
             address.as_bytes(),
-            &verification_info_json
+            &verification_info_json,
+            code_skeleton_hash.as_ref().map(H256::as_bytes)
         )
         .instrument("save_verification_info#insert")
         .with_arg("id", &id)
@@ -236,13 +244,20 @@ impl ContractVerificationDal<'_, '_> {
         transaction.commit().await
     }
 
+    /// Marks the verification request as failed. If `partial_matches` are non-empty, they are persisted
+    /// and returned in the request status.
     pub async fn save_verification_error(
         &mut self,
         id: usize,
         error: &str,
         compilation_errors: &serde_json::Value,
         panic_message: Option<&str>,
+        partial_matches: &[PartialMatch],
     ) -> DalResult<()> {
+        // Serialization should always succeed.
+        let partial_matches = (!partial_matches.is_empty()).then(|| {
+            serde_json::to_value(partial_matches).expect("failed serializing partial matches")
+        });
         sqlx::query!(
             r#"
             UPDATE contract_verification_requests
@@ -251,14 +266,16 @@ impl ContractVerificationDal<'_, '_> {
                 updated_at = NOW(),
                 error = $2,
                 compilation_errors = $3,
-                panic_message = $4
+                panic_message = $4,
+                partial_matches = $5
             WHERE
                 id = $1
             "#,
             id as i64,
             error,
             compilation_errors,
-            panic_message
+            panic_message,
+            partial_matches
         )
         .instrument("save_verification_error")
         .with_arg("id", &id)
@@ -277,7 +294,8 @@ impl ContractVerificationDal<'_, '_> {
             SELECT
                 status,
                 error,
-                compilation_errors
+                compilation_errors,
+                partial_matches
             FROM
                 contract_verification_requests
             WHERE
@@ -301,10 +319,16 @@ impl ContractVerificationDal<'_, '_> {
                 }
             }
 
+            let partial_matches = row
+                .partial_matches
+                .map(|matches| serde_json::from_value(matches).decode_column("partial_matches"))
+                .transpose()?;
+
             Ok(VerificationRequestStatus {
                 status: row.status,
                 error: row.error,
                 compilation_errors: (!compilation_errors.is_empty()).then_some(compilation_errors),
+                partial_matches,
             })
         })
         .instrument("get_verification_request_status")
@@ -516,7 +540,8 @@ impl ContractVerificationDal<'_, '_> {
                 optimizer_mode,
                 constructor_arguments,
                 is_system,
-                force_evmla
+                force_evmla,
+                search_partial_matches
             FROM
                 contract_verification_requests
             WHERE
@@ -560,15 +585,57 @@ impl ContractVerificationDal<'_, '_> {
         .await?
         .flatten())
     }
+
+    /// Returns verification info for verified contracts with the specified code skeleton hash (i.e., candidates
+    /// for a partial match), excluding the contract at `excluded_address`. The returned contracts are ordered
+    /// by the verification time, most recent first.
+    pub async fn get_partial_match_candidates(
+        &mut self,
+        code_skeleton_hash: H256,
+        excluded_address: Address,
+        limit: usize,
+    ) -> DalResult<Vec<(Address, VerificationInfo)>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                address,
+                verification_info AS "verification_info!"
+            FROM
+                contracts_verification_info
+            WHERE
+                code_skeleton_hash = $1
+                AND address != $2
+                AND verification_info IS NOT NULL
+            ORDER BY
+                verification_info ->> 'verifiedAt' DESC
+            LIMIT
+                $3
+            "#,
+            code_skeleton_hash.as_bytes(),
+            excluded_address.as_bytes(),
+            limit as i64
+        )
+        .try_map(|row| {
+            let info =
+                serde_json::from_value(row.verification_info).decode_column("verification_info")?;
+            Ok((Address::from_slice(&row.address), info))
+        })
+        .instrument("get_partial_match_candidates")
+        .with_arg("code_skeleton_hash", &code_skeleton_hash)
+        .with_arg("excluded_address", &excluded_address)
+        .fetch_all(self.storage)
+        .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
+    use chrono::Utc;
     use zksync_types::{
         bytecode::BytecodeHash,
-        contract_verification_api::{CompilerVersions, SourceCodeData},
+        contract_verification_api::{CompilationArtifacts, CompilerVersions, SourceCodeData},
         tx::IncludedTxLocation,
         Execute, L1BatchNumber, L2BlockNumber, ProtocolVersion,
     };
@@ -662,6 +729,7 @@ mod tests {
             constructor_arguments: web3::Bytes(b"test".to_vec()),
             is_system: false,
             force_evmla: true,
+            search_partial_matches: true,
         };
 
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -695,6 +763,10 @@ mod tests {
         assert_eq!(req.req.constructor_arguments, request.constructor_arguments);
         assert_eq!(req.req.is_system, request.is_system);
         assert_eq!(req.req.force_evmla, request.force_evmla);
+        assert_eq!(
+            req.req.search_partial_matches,
+            request.search_partial_matches
+        );
 
         let maybe_req = conn
             .contract_verification_dal()
@@ -709,4 +781,111 @@ mod tests {
         test_working_with_verification_requests(None).await;
         test_working_with_verification_requests(Some("1.5.7")).await;
     }
+
+    fn mock_verification_info(id: usize, address: Address) -> VerificationInfo {
+        VerificationInfo {
+            request: VerificationRequest {
+                id,
+                req: VerificationIncomingRequest {
+                    contract_address: address,
+                    source_code_data: SourceCodeData::SolSingleFile("contract Test {}".to_owned()),
+                    contract_name: "Test".to_owned(),
+                    compiler_versions: CompilerVersions::Solc {
+                        compiler_zksolc_version: None,
+                        compiler_solc_version: "0.8.27".to_owned(),
+                    },
+                    optimization_used: true,
+                    optimizer_mode: None,
+                    constructor_arguments: web3::Bytes::default(),
+                    is_system: false,
+                    force_evmla: false,
+                    search_partial_matches: false,
+                },
+            },
+            artifacts: CompilationArtifacts {
+                bytecode: vec![1; 32],
+                deployed_bytecode: Some(vec![2; 32]),
+                abi: serde_json::json!([]),
+            },
+            verified_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn working_with_partial_matches() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let skeleton_hash = H256::repeat_byte(1);
+        let verified_addresses = [Address::repeat_byte(1), Address::repeat_byte(2)];
+        for (id, &address) in verified_addresses.iter().enumerate() {
+            conn.contract_verification_dal()
+                .save_verification_info(mock_verification_info(id, address), Some(skeleton_hash))
+                .await
+                .unwrap();
+        }
+        let unrelated_address = Address::repeat_byte(3);
+        conn.contract_verification_dal()
+            .save_verification_info(mock_verification_info(2, unrelated_address), None)
+            .await
+            .unwrap();
+
+        let candidates = conn
+            .contract_verification_dal()
+            .get_partial_match_candidates(skeleton_hash, verified_addresses[0], 10)
+            .await
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].0, verified_addresses[1]);
+        assert_eq!(candidates[0].1.request.req.contract_name, "Test");
+
+        let candidates = conn
+            .contract_verification_dal()
+            .get_partial_match_candidates(H256::repeat_byte(2), Address::zero(), 10)
+            .await
+            .unwrap();
+        assert!(candidates.is_empty());
+
+        let mut request = mock_verification_info(0, Address::repeat_byte(4))
+            .request
+            .req;
+        request.search_partial_matches = true;
+        let id = conn
+            .contract_verification_dal()
+            .add_contract_verification_request(&request)
+            .await
+            .unwrap();
+        let partial_match = PartialMatch {
+            address: verified_addresses[1],
+            source_code_data: request.source_code_data.clone(),
+            contract_name: request.contract_name.clone(),
+            compiler_versions: request.compiler_versions.clone(),
+            optimization_used: true,
+            optimizer_mode: None,
+            metadata_differs: true,
+            immutables_differ: false,
+        };
+        conn.contract_verification_dal()
+            .save_verification_error(
+                id,
+                "bytecode mismatch",
+                &serde_json::json!([]),
+                None,
+                &[partial_match],
+            )
+            .await
+            .unwrap();
+
+        let status = conn
+            .contract_verification_dal()
+            .get_verification_request_status(id)
+            .await
+            .unwrap()
+            .expect("no status");
+        assert_eq!(status.status, "failed");
+        let partial_matches = status.partial_matches.expect("no partial matches");
+        assert_eq!(partial_matches.len(), 1);
+        assert_eq!(partial_matches[0].address, verified_addresses[1]);
+        assert!(partial_matches[0].metadata_differs);
+        assert!(!partial_matches[0].immutables_differ);
+    }
 }
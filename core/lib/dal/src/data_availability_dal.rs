@@ -147,11 +147,12 @@ impl DataAvailabilityDal<'_, '_> {
         Ok(())
     }
 
-    /// Assumes that the L1 batches are sorted by number, and returns the first one that is ready for DA dispatch.
-    pub async fn get_first_da_blob_awaiting_inclusion(
+    /// Fetches up to `limit` DA blobs awaiting inclusion data, ordered by the L1 batch number.
+    pub async fn get_da_blobs_awaiting_inclusion(
         &mut self,
-    ) -> DalResult<Option<DataAvailabilityBlob>> {
-        Ok(sqlx::query_as!(
+        limit: usize,
+    ) -> DalResult<Vec<DataAvailabilityBlob>> {
+        let rows = sqlx::query_as!(
             StorageDABlob,
             r#"
             SELECT
@@ -166,13 +167,16 @@ impl DataAvailabilityDal<'_, '_> {
             ORDER BY
                 l1_batch_number
             LIMIT
-                1
+                $1
             "#,
+            limit as i64,
         )
-        .instrument("get_first_da_blob_awaiting_inclusion")
-        .fetch_optional(self.storage)
-        .await?
-        .map(DataAvailabilityBlob::from))
+        .instrument("get_da_blobs_awaiting_inclusion")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().map(DataAvailabilityBlob::from).collect())
     }
 
     /// Fetches the pubdata and `l1_batch_number` for the L1 batches that are ready for DA dispatch.
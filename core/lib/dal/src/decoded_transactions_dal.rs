@@ -0,0 +1,293 @@
+use std::ops;
+
+use zksync_db_connection::{
+    connection::Connection,
+    error::{DalResult, SqlxContext},
+    instrument::InstrumentExt,
+};
+use zksync_types::{api, web3::Bytes, Address, L2BlockNumber, H256};
+
+use crate::{events_dal::EventsDal, Core};
+
+/// Transaction data necessary to decode it using contract ABIs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionToDecode {
+    pub hash: H256,
+    pub l2_block_number: L2BlockNumber,
+    /// Transaction recipient. `None` for contract deployments.
+    pub contract_address: Option<Address>,
+    pub calldata: Vec<u8>,
+    pub logs: Vec<api::Log>,
+}
+
+#[derive(Debug)]
+pub struct DecodedTransactionsDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl DecodedTransactionsDal<'_, '_> {
+    /// Returns the greatest L2 block number among stored decoded transactions.
+    pub async fn get_last_decoded_l2_block(&mut self) -> DalResult<Option<L2BlockNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(miniblock_number) AS "number"
+            FROM
+                decoded_transactions
+            "#
+        )
+        .instrument("get_last_decoded_l2_block")
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(row.number.map(|number| L2BlockNumber(number as u32)))
+    }
+
+    /// Loads transactions together with their logs in the specified L2 block range, ordered by their position in the chain.
+    pub async fn get_transactions_to_decode(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<TransactionToDecode>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                hash,
+                miniblock_number AS "miniblock_number!",
+                data -> 'contractAddress' AS "contract_address",
+                data -> 'calldata' AS "calldata"
+            FROM
+                transactions
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+            ORDER BY
+                miniblock_number,
+                index_in_block
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .try_map(|row| {
+            let contract_address = row
+                .contract_address
+                .map(serde_json::from_value::<Option<Address>>)
+                .transpose()
+                .decode_column("contract_address")?
+                .flatten();
+            let calldata = row
+                .calldata
+                .map(serde_json::from_value::<Bytes>)
+                .transpose()
+                .decode_column("calldata")?
+                .map_or_else(Vec::new, |bytes| bytes.0);
+            Ok(TransactionToDecode {
+                hash: H256::from_slice(&row.hash),
+                l2_block_number: L2BlockNumber(row.miniblock_number as u32),
+                contract_address,
+                calldata,
+                logs: vec![],
+            })
+        })
+        .instrument("get_transactions_to_decode")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await?;
+
+        let hashes: Vec<_> = rows.iter().map(|tx| tx.hash).collect();
+        let mut logs = EventsDal {
+            storage: self.storage,
+        }
+        .get_logs_by_tx_hashes(&hashes)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|mut tx| {
+                tx.logs = logs.remove(&tx.hash).unwrap_or_default();
+                tx
+            })
+            .collect())
+    }
+
+    /// Inserts decoded transactions, overwriting previously decoded data for the same transactions.
+    pub async fn insert_decoded_transactions(
+        &mut self,
+        transactions: &[(L2BlockNumber, api::DecodedTransaction)],
+    ) -> DalResult<()> {
+        let mut hashes = Vec::with_capacity(transactions.len());
+        let mut l2_block_numbers = Vec::with_capacity(transactions.len());
+        let mut decoded = Vec::with_capacity(transactions.len());
+        for (l2_block_number, tx) in transactions {
+            hashes.push(tx.transaction_hash.as_bytes().to_vec());
+            l2_block_numbers.push(i64::from(l2_block_number.0));
+            decoded.push(serde_json::to_value(tx).expect("failed serializing decoded transaction"));
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            decoded_transactions (
+                tx_hash, miniblock_number, decoded, created_at, updated_at
+            )
+            SELECT
+                u.tx_hash,
+                u.miniblock_number,
+                u.decoded,
+                NOW(),
+                NOW()
+            FROM
+                UNNEST($1::bytea [], $2::bigint [], $3::jsonb []) AS u (
+                    tx_hash, miniblock_number, decoded
+                )
+            ON CONFLICT (tx_hash) DO
+            UPDATE
+            SET
+            miniblock_number = excluded.miniblock_number,
+            decoded = excluded.decoded,
+            updated_at = NOW()
+            "#,
+            &hashes,
+            &l2_block_numbers,
+            &decoded
+        )
+        .instrument("insert_decoded_transactions")
+        .with_arg("transactions.len", &transactions.len())
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_decoded_transaction(
+        &mut self,
+        hash: H256,
+    ) -> DalResult<Option<api::DecodedTransaction>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                decoded
+            FROM
+                decoded_transactions
+            WHERE
+                tx_hash = $1
+            "#,
+            hash.as_bytes()
+        )
+        .try_map(|row| serde_json::from_value(row.decoded).decode_column("decoded"))
+        .instrument("get_decoded_transaction")
+        .with_arg("hash", &hash)
+        .fetch_optional(self.storage)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{tx::IncludedTxLocation, ProtocolVersion, ProtocolVersionId, U256};
+    use zksync_vm_interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
+
+    use super::*;
+    use crate::{
+        tests::{
+            create_l2_block_header, mock_execution_result, mock_l2_transaction, mock_vm_event,
+        },
+        ConnectionPool, CoreDal,
+    };
+
+    #[tokio::test]
+    async fn decoded_transactions_roundtrip() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        conn.transactions_dal()
+            .insert_transaction_l2(
+                &tx,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[mock_execution_result(tx.clone())],
+                U256::from(1),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+        let event = mock_vm_event(0);
+        let location = IncludedTxLocation {
+            tx_hash,
+            tx_index_in_l2_block: 0,
+            tx_initiator_address: tx.initiator_account(),
+        };
+        conn.events_dal()
+            .save_events(L2BlockNumber(1), &[(location, vec![&event])])
+            .await
+            .unwrap();
+
+        let txs = conn
+            .decoded_transactions_dal()
+            .get_transactions_to_decode(L2BlockNumber(0)..=L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].hash, tx_hash);
+        assert_eq!(txs[0].l2_block_number, L2BlockNumber(1));
+        assert_eq!(txs[0].contract_address, tx.execute.contract_address);
+        assert_eq!(txs[0].calldata, tx.execute.calldata);
+        assert_eq!(txs[0].logs.len(), 1);
+        assert_eq!(txs[0].logs[0].address, event.address);
+
+        let last_block = conn
+            .decoded_transactions_dal()
+            .get_last_decoded_l2_block()
+            .await
+            .unwrap();
+        assert_eq!(last_block, None);
+
+        let decoded = api::DecodedTransaction {
+            transaction_hash: tx_hash,
+            call: None,
+            logs: vec![],
+        };
+        conn.decoded_transactions_dal()
+            .insert_decoded_transactions(&[(L2BlockNumber(1), decoded.clone())])
+            .await
+            .unwrap();
+        let last_block = conn
+            .decoded_transactions_dal()
+            .get_last_decoded_l2_block()
+            .await
+            .unwrap();
+        assert_eq!(last_block, Some(L2BlockNumber(1)));
+        let loaded = conn
+            .decoded_transactions_dal()
+            .get_decoded_transaction(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(loaded, Some(decoded));
+
+        // Decoded transactions must be removed together with their L2 block.
+        conn.blocks_dal()
+            .delete_l2_blocks(L2BlockNumber(0))
+            .await
+            .unwrap();
+        let loaded = conn
+            .decoded_transactions_dal()
+            .get_decoded_transaction(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(loaded, None);
+    }
+}
@@ -653,6 +653,61 @@ impl EthSenderDal<'_, '_> {
         Ok(nonce.map(|row| row.nonce as u64 + 1))
     }
 
+    /// Returns the number of transactions for the operator account that are neither confirmed nor failed
+    /// (including transactions that were not sent yet).
+    ///
+    /// # Params
+    /// * `from_address`: same semantics as for [`Self::get_next_nonce()`].
+    pub async fn get_unconfirmed_txs_count(
+        &mut self,
+        from_address: Option<Address>,
+        is_gateway: bool,
+    ) -> sqlx::Result<usize> {
+        let count = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                eth_txs
+            WHERE
+                from_addr IS NOT DISTINCT FROM $1
+                AND is_gateway = $2
+                AND confirmed_eth_tx_history_id IS NULL
+                AND NOT has_failed
+            "#,
+            from_address.as_ref().map(|h160| h160.as_bytes()),
+            is_gateway
+        )
+        .fetch_one(self.storage.conn())
+        .await?
+        .count;
+        Ok(count as usize)
+    }
+
+    /// Returns distinct custom senders (i.e., excluding the main operator) of settlement layer transactions
+    /// that are neither confirmed nor failed.
+    pub async fn get_unconfirmed_txs_custom_senders(&mut self) -> sqlx::Result<Vec<Address>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT
+                from_addr AS "from_addr!"
+            FROM
+                eth_txs
+            WHERE
+                from_addr IS NOT NULL
+                AND is_gateway = FALSE
+                AND confirmed_eth_tx_history_id IS NULL
+                AND NOT has_failed
+            "#
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| Address::from_slice(&row.from_addr))
+            .collect())
+    }
+
     pub async fn mark_failed_transaction(&mut self, eth_tx_id: u32) -> sqlx::Result<()> {
         sqlx::query!(
             r#"
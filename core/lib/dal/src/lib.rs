@@ -14,9 +14,10 @@ pub use zksync_db_connection::{
 use crate::{
     base_token_dal::BaseTokenDal, blocks_dal::BlocksDal, blocks_web3_dal::BlocksWeb3Dal,
     consensus_dal::ConsensusDal, contract_verification_dal::ContractVerificationDal,
-    data_availability_dal::DataAvailabilityDal, eth_sender_dal::EthSenderDal,
-    eth_watcher_dal::EthWatcherDal, events_dal::EventsDal, events_web3_dal::EventsWeb3Dal,
-    factory_deps_dal::FactoryDepsDal, proof_generation_dal::ProofGenerationDal,
+    data_availability_dal::DataAvailabilityDal, decoded_transactions_dal::DecodedTransactionsDal,
+    eth_sender_dal::EthSenderDal, eth_watcher_dal::EthWatcherDal, events_dal::EventsDal,
+    events_web3_dal::EventsWeb3Dal, factory_deps_dal::FactoryDepsDal,
+    proof_generation_dal::ProofGenerationDal, proof_latency_dal::ProofLatencyDal,
     protocol_versions_dal::ProtocolVersionsDal,
     protocol_versions_web3_dal::ProtocolVersionsWeb3Dal, pruning_dal::PruningDal,
     snapshot_recovery_dal::SnapshotRecoveryDal, snapshots_creator_dal::SnapshotsCreatorDal,
@@ -34,6 +35,7 @@ pub mod consensus;
 pub mod consensus_dal;
 pub mod contract_verification_dal;
 mod data_availability_dal;
+pub mod decoded_transactions_dal;
 pub mod eth_sender_dal;
 pub mod eth_watcher_dal;
 pub mod events_dal;
@@ -43,6 +45,7 @@ pub mod helpers;
 pub mod metrics;
 mod models;
 pub mod proof_generation_dal;
+pub mod proof_latency_dal;
 pub mod protocol_versions_dal;
 pub mod protocol_versions_web3_dal;
 pub mod pruning_dal;
@@ -113,6 +116,8 @@ where
 
     fn proof_generation_dal(&mut self) -> ProofGenerationDal<'_, 'a>;
 
+    fn proof_latency_dal(&mut self) -> ProofLatencyDal<'_, 'a>;
+
     fn tee_proof_generation_dal(&mut self) -> TeeProofGenerationDal<'_, 'a>;
 
     fn system_dal(&mut self) -> SystemDal<'_, 'a>;
@@ -132,6 +137,8 @@ where
     fn base_token_dal(&mut self) -> BaseTokenDal<'_, 'a>;
 
     fn eth_watcher_dal(&mut self) -> EthWatcherDal<'_, 'a>;
+
+    fn decoded_transactions_dal(&mut self) -> DecodedTransactionsDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -219,6 +226,10 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
         ProofGenerationDal { storage: self }
     }
 
+    fn proof_latency_dal(&mut self) -> ProofLatencyDal<'_, 'a> {
+        ProofLatencyDal { storage: self }
+    }
+
     fn tee_proof_generation_dal(&mut self) -> TeeProofGenerationDal<'_, 'a> {
         TeeProofGenerationDal { storage: self }
     }
@@ -258,4 +269,8 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
     fn eth_watcher_dal(&mut self) -> EthWatcherDal<'_, 'a> {
         EthWatcherDal { storage: self }
     }
+
+    fn decoded_transactions_dal(&mut self) -> DecodedTransactionsDal<'_, 'a> {
+        DecodedTransactionsDal { storage: self }
+    }
 }
@@ -10,7 +10,7 @@ use zksync_types::{
     commitment::{L1BatchCommitmentMode, L1BatchMetaParameters, L1BatchMetadata, PubdataParams},
     fee_model::{BatchFeeInput, L1PeggedBatchFeeModelInput, PubdataIndependentBatchFeeModelInput},
     l2_to_l1_log::{L2ToL1Log, SystemL2ToL1Log, UserL2ToL1Log},
-    Address, Bloom, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256,
+    Address, Bloom, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256, U256,
 };
 
 /// This is the gas limit that was used inside blocks before we started saving block gas limit into the database.
@@ -462,6 +462,87 @@ impl From<StorageL1BatchDetails> for api::L1BatchDetails {
     }
 }
 
+/// Parts of an L1 batch report not included into [`StorageL1BatchDetails`].
+pub(crate) struct StorageL1BatchReportData {
+    pub sealed_at: Option<NaiveDateTime>,
+    pub execution_stats: Option<serde_json::Value>,
+    pub predicted_circuits_by_type: Option<serde_json::Value>,
+    pub predicted_commit_gas_cost: i64,
+    pub predicted_prove_gas_cost: i64,
+    pub predicted_execute_gas_cost: i64,
+    pub proof_inputs_ready_at: Option<NaiveDateTime>,
+    pub proof_picked_at: Option<NaiveDateTime>,
+    pub proof_updated_at: Option<NaiveDateTime>,
+    pub proof_status: Option<String>,
+}
+
+impl StorageL1BatchReportData {
+    pub(crate) fn into_report(
+        self,
+        details: api::L1BatchDetails,
+        l1_costs: api::L1BatchL1Costs,
+    ) -> api::L1BatchReport {
+        let to_utc = |time: NaiveDateTime| DateTime::<Utc>::from_naive_utc_and_offset(time, Utc);
+        let proof = self
+            .proof_inputs_ready_at
+            .map(|inputs_ready_at| api::L1BatchProofTimings {
+                inputs_ready_at: to_utc(inputs_ready_at),
+                picked_by_prover_at: self.proof_picked_at.map(to_utc),
+                proof_generated_at: self
+                    .proof_updated_at
+                    .filter(|_| self.proof_status.as_deref() == Some("generated"))
+                    .map(to_utc),
+            });
+
+        api::L1BatchReport {
+            details,
+            sealed_at: self.sealed_at.map(to_utc),
+            execution: self
+                .execution_stats
+                .map(|stats| serde_json::from_value(stats).expect("invalid execution stats")),
+            circuits_by_type: self
+                .predicted_circuits_by_type
+                .map(|stats| serde_json::from_value(stats).expect("invalid circuits statistic")),
+            predicted_l1_gas: api::L1BatchPredictedGas {
+                commit: self.predicted_commit_gas_cost as u64,
+                prove: self.predicted_prove_gas_cost as u64,
+                execute: self.predicted_execute_gas_cost as u64,
+            },
+            l1_costs,
+            proof,
+        }
+    }
+}
+
+pub(crate) struct StorageL1BatchOperationCost {
+    pub tx_type: String,
+    pub tx_hash: String,
+    pub gas_used: Option<i64>,
+    pub base_fee_per_gas: i64,
+    pub priority_fee_per_gas: i64,
+    pub blob_base_fee_per_gas: Option<i64>,
+    pub batch_count: i64,
+}
+
+impl From<StorageL1BatchOperationCost> for api::L1BatchOperationCost {
+    fn from(cost: StorageL1BatchOperationCost) -> Self {
+        let gas_used = cost.gas_used.map(|gas| gas as u64);
+        // Mirrors how the max fee per gas is computed when sending transactions.
+        let max_fee_per_gas = (cost.base_fee_per_gas + cost.priority_fee_per_gas) as u64;
+        let batch_count = cost.batch_count.max(1) as u64;
+        Self {
+            tx_hash: H256::from_str(&cost.tx_hash).expect("Incorrect tx hash"),
+            gas_used,
+            max_fee_per_gas,
+            max_priority_fee_per_gas: cost.priority_fee_per_gas as u64,
+            max_fee_per_blob_gas: cost.blob_base_fee_per_gas.map(|fee| fee as u64),
+            batch_count,
+            max_cost: gas_used
+                .map(|gas| U256::from(gas) * U256::from(max_fee_per_gas) / batch_count),
+        }
+    }
+}
+
 pub(crate) struct StorageL2BlockHeader {
     pub number: i64,
     pub timestamp: i64,
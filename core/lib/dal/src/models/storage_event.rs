@@ -49,6 +49,7 @@ impl From<StorageWeb3Log> for api::Log {
             log_type: None,
             removed: Some(false),
             block_timestamp: log.block_timestamp.map(|t| (t as u64).into()),
+            decoded_args: None,
         }
     }
 }
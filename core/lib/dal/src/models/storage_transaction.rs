@@ -3,17 +3,19 @@ use std::{convert::TryInto, str::FromStr};
 use bigdecimal::Zero;
 use serde_json::Value;
 use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
+use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
 use zksync_types::{
     api::{self, TransactionDetails, TransactionReceipt, TransactionStatus},
     fee::Fee,
+    fee_model::BatchFeeInput,
     h256_to_address,
     l1::{OpProcessingType, PriorityQueueType},
     l2::TransactionType,
     protocol_upgrade::ProtocolUpgradeTxCommonData,
     transaction_request::PaymasterParams,
     web3::Bytes,
-    Address, Execute, ExecuteTransactionCommon, L1TxCommonData, L2ChainId, L2TxCommonData, Nonce,
-    PackedEthSignature, PriorityOpId, ProtocolVersionId, Transaction,
+    Address, Execute, ExecuteTransactionCommon, L1TxCommonData, L2BlockNumber, L2ChainId,
+    L2TxCommonData, Nonce, PackedEthSignature, PriorityOpId, ProtocolVersionId, Transaction,
     TransactionTimeRangeConstraint, EIP_1559_TX_TYPE, EIP_2930_TX_TYPE, EIP_712_TX_TYPE, H160,
     H256, PRIORITY_OPERATION_L2_TX_TYPE, PROTOCOL_UPGRADE_TX_TYPE, U256, U64,
 };
@@ -71,6 +73,8 @@ pub struct StorageTransaction {
     pub timestamp_asserter_range_start: Option<NaiveDateTime>,
     pub timestamp_asserter_range_end: Option<NaiveDateTime>,
 
+    pub l1_block_timestamp: Option<i64>,
+
     // DEPRECATED.
     pub l1_block_number: Option<i32>,
 }
@@ -354,6 +358,12 @@ pub(crate) struct StorageTransactionReceipt {
     pub contract_address: Option<Vec<u8>>,
     pub initiator_address: Vec<u8>,
     pub block_timestamp: Option<i64>,
+    pub gas_per_pubdata_limit: Option<BigDecimal>,
+    pub l1_gas_price: i64,
+    pub l2_fair_gas_price: i64,
+    pub fair_pubdata_price: Option<i64>,
+    pub protocol_version: Option<i32>,
+    pub pubdata_published: Option<i64>,
 }
 
 impl From<StorageTransactionReceipt> for TransactionReceipt {
@@ -377,6 +387,31 @@ impl From<StorageTransactionReceipt> for TransactionReceipt {
             })
             .unwrap_or_else(Address::zero);
 
+        let is_l1_tx = matches!(
+            storage_receipt.tx_format.map(|format| format as u8),
+            Some(PRIORITY_OPERATION_L2_TX_TYPE | PROTOCOL_UPGRADE_TX_TYPE)
+        );
+        let gas_per_pubdata = if is_l1_tx {
+            // L1 transactions pay for pubdata according to the limit specified in the transaction.
+            storage_receipt
+                .gas_per_pubdata_limit
+                .map(|limit| bigdecimal_to_u256(limit).as_u64().into())
+        } else {
+            let protocol_version = storage_receipt
+                .protocol_version
+                .map(|v| (v as u16).try_into().unwrap())
+                .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
+            let fee_input = BatchFeeInput::for_protocol_version(
+                protocol_version,
+                storage_receipt.l2_fair_gas_price as u64,
+                storage_receipt.fair_pubdata_price.map(|p| p as u64),
+                storage_receipt.l1_gas_price as u64,
+            );
+            let (_, gas_per_pubdata) =
+                derive_base_fee_and_gas_per_pubdata(fee_input, protocol_version.into());
+            Some(gas_per_pubdata.into())
+        };
+
         let block_hash = H256::from_slice(&storage_receipt.block_hash);
         TransactionReceipt {
             transaction_hash: H256::from_slice(&storage_receipt.tx_hash),
@@ -411,6 +446,10 @@ impl From<StorageTransactionReceipt> for TransactionReceipt {
             // Even though the Rust SDK recommends us to supply "None" for legacy transactions
             // we always supply some number anyway to have the same behavior as most popular RPCs
             transaction_type: Some(tx_type),
+            pubdata_used: storage_receipt
+                .pubdata_published
+                .map(|bytes| U64::from(bytes as u64)),
+            gas_per_pubdata,
         }
     }
 }
@@ -497,6 +536,29 @@ impl From<StorageTransactionDetails> for TransactionDetails {
     }
 }
 
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct StorageTransactionBySelector {
+    pub hash: Vec<u8>,
+    pub miniblock_number: i64,
+    pub index_in_block: i32,
+    pub initiator_address: Vec<u8>,
+    pub contract_address: Option<Vec<u8>>,
+}
+
+impl From<StorageTransactionBySelector> for api::TransactionBySelector {
+    fn from(tx: StorageTransactionBySelector) -> Self {
+        Self {
+            hash: H256::from_slice(&tx.hash),
+            position: api::TransactionPosition {
+                block_number: L2BlockNumber(tx.miniblock_number as u32),
+                transaction_index: tx.index_in_block as u32,
+            },
+            from: Address::from_slice(&tx.initiator_address),
+            to: tx.contract_address.as_deref().map(Address::from_slice),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct StorageApiTransaction {
     pub tx_hash: Vec<u8>,
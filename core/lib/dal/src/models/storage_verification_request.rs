@@ -19,6 +19,7 @@ pub struct StorageVerificationRequest {
     pub constructor_arguments: Vec<u8>,
     pub is_system: bool,
     pub force_evmla: bool,
+    pub search_partial_matches: bool,
 }
 
 impl From<StorageVerificationRequest> for VerificationRequest {
@@ -46,6 +47,7 @@ impl From<StorageVerificationRequest> for VerificationRequest {
                 constructor_arguments: value.constructor_arguments.into(),
                 is_system: value.is_system,
                 force_evmla: value.force_evmla,
+                search_partial_matches: value.search_partial_matches,
             },
         }
     }
@@ -8,15 +8,25 @@ use zksync_db_connection::{
     instrument::{InstrumentExt, Instrumented},
     utils::pg_interval_from_duration,
 };
-use zksync_types::L1BatchNumber;
+use zksync_types::{prover_dal::ProvingStage, L1BatchNumber};
 
-use crate::Core;
+use crate::{proof_latency_dal::ProofLatencyDal, Core};
 
 #[derive(Debug)]
 pub struct ProofGenerationDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
 }
 
+/// L1 batch that doesn't have a proof yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAwaitingProof {
+    pub l1_batch_number: L1BatchNumber,
+    /// Whether all the data necessary to generate the proof (witness inputs and batch metadata) is available.
+    pub witness_inputs_available: bool,
+    /// Whether the batch is currently picked by a prover, and the processing timeout hasn't passed yet.
+    pub is_picked: bool,
+}
+
 #[derive(Debug, EnumString, Display)]
 enum ProofGenerationJobStatus {
     #[strum(serialize = "unpicked")]
@@ -48,7 +58,8 @@ impl ProofGenerationDal<'_, '_> {
             SET
                 status = 'picked_by_prover',
                 updated_at = NOW(),
-                prover_taken_at = NOW()
+                prover_taken_at = NOW(),
+                claimed_by = NULL
             WHERE
                 l1_batch_number = (
                     SELECT
@@ -88,6 +99,139 @@ impl ProofGenerationDal<'_, '_> {
         Ok(result)
     }
 
+    /// Same as [`Self::lock_batch_for_proving()`], but locks the specified batch on behalf of `claimed_by`
+    /// (e.g., an external prover). Returns `false` if the batch cannot be locked, i.e. it doesn't have
+    /// all the necessary data, is already proven or is picked by another prover.
+    pub async fn claim_batch_for_proving(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        claimed_by: &[u8],
+        processing_timeout: Duration,
+    ) -> DalResult<bool> {
+        let batch_number = i64::from(l1_batch_number.0);
+        let processing_timeout = pg_interval_from_duration(processing_timeout);
+        let result = sqlx::query!(
+            r#"
+            UPDATE proof_generation_details
+            SET
+                status = 'picked_by_prover',
+                updated_at = NOW(),
+                prover_taken_at = NOW(),
+                claimed_by = $2
+            FROM
+                l1_batches
+            WHERE
+                proof_generation_details.l1_batch_number = $1
+                AND l1_batches.number = proof_generation_details.l1_batch_number
+                AND (
+                    (
+                        vm_run_data_blob_url IS NOT NULL
+                        AND proof_gen_data_blob_url IS NOT NULL
+                        AND l1_batches.hash IS NOT NULL
+                        AND l1_batches.aux_data_hash IS NOT NULL
+                        AND l1_batches.meta_parameters_hash IS NOT NULL
+                        AND status = 'unpicked'
+                    )
+                    OR (
+                        status = 'picked_by_prover'
+                        AND prover_taken_at < NOW() - $3::INTERVAL
+                    )
+                )
+            RETURNING
+            proof_generation_details.l1_batch_number
+            "#,
+            batch_number,
+            claimed_by,
+            &processing_timeout,
+        )
+        .instrument("claim_batch_for_proving")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("processing_timeout", &processing_timeout)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    /// Returns the identifier passed to [`Self::claim_batch_for_proving()`] if the batch is currently
+    /// picked on behalf of it.
+    pub async fn get_batch_claimant(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Option<Vec<u8>>> {
+        let batch_number = i64::from(l1_batch_number.0);
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                claimed_by
+            FROM
+                proof_generation_details
+            WHERE
+                l1_batch_number = $1
+                AND status = 'picked_by_prover'
+            "#,
+            batch_number,
+        )
+        .instrument("get_batch_claimant")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.and_then(|row| row.claimed_by))
+    }
+
+    /// Lists the oldest batches that don't have a proof yet and weren't skipped, in the ascending order.
+    /// A picked batch is reported as not picked if `processing_timeout` has passed since it was picked.
+    pub async fn get_batches_awaiting_proof(
+        &mut self,
+        processing_timeout: Duration,
+        limit: usize,
+    ) -> DalResult<Vec<BatchAwaitingProof>> {
+        let processing_timeout = pg_interval_from_duration(processing_timeout);
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                proof_generation_details.l1_batch_number,
+                (
+                    vm_run_data_blob_url IS NOT NULL
+                    AND proof_gen_data_blob_url IS NOT NULL
+                    AND l1_batches.hash IS NOT NULL
+                    AND l1_batches.aux_data_hash IS NOT NULL
+                    AND l1_batches.meta_parameters_hash IS NOT NULL
+                ) AS "witness_inputs_available!",
+                (
+                    status = 'picked_by_prover'
+                    AND prover_taken_at >= NOW() - $1::INTERVAL
+                ) AS "is_picked!"
+            FROM
+                proof_generation_details
+            LEFT JOIN l1_batches ON l1_batch_number = l1_batches.number
+            WHERE
+                status IN ('unpicked', 'picked_by_prover')
+            ORDER BY
+                l1_batch_number ASC
+            LIMIT
+                $2
+            "#,
+            &processing_timeout,
+            limit as i64,
+        )
+        .instrument("get_batches_awaiting_proof")
+        .with_arg("processing_timeout", &processing_timeout)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BatchAwaitingProof {
+                l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+                witness_inputs_available: row.witness_inputs_available,
+                is_picked: row.is_picked,
+            })
+            .collect())
+    }
+
     pub async fn get_latest_proven_batch(&mut self) -> DalResult<L1BatchNumber> {
         let result = sqlx::query!(
             r#"
@@ -169,7 +313,11 @@ impl ProofGenerationDal<'_, '_> {
             return Err(err);
         }
 
-        Ok(())
+        ProofLatencyDal {
+            storage: self.storage,
+        }
+        .record_stage(L1BatchNumber(batch_number as u32), ProvingStage::Submitted)
+        .await
     }
 
     pub async fn save_vm_runner_artifacts_metadata(
@@ -272,8 +420,14 @@ impl ProofGenerationDal<'_, '_> {
             // for the same node. Unlike tree data, we don't particularly care about correspondence of `proof_gen_data_blob_url` across calls,
             // so just log this fact and carry on.
             tracing::debug!("L1 batch #{l1_batch_number}: proof generation data wasn't updated as it's already present");
+            return Ok(());
         }
-        Ok(())
+
+        ProofLatencyDal {
+            storage: self.storage,
+        }
+        .record_stage(l1_batch_number, ProvingStage::Queued)
+        .await
     }
 
     pub async fn mark_proof_generation_job_as_skipped(
@@ -369,6 +523,35 @@ mod tests {
     use super::*;
     use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
 
+    async fn prepare_batch_for_proving(conn: &mut Connection<'_, Core>, number: L1BatchNumber) {
+        conn.proof_generation_dal()
+            .insert_proof_generation_details(number)
+            .await
+            .unwrap();
+        conn.proof_generation_dal()
+            .save_vm_runner_artifacts_metadata(number, "vm_run")
+            .await
+            .unwrap();
+        conn.proof_generation_dal()
+            .save_merkle_paths_artifacts_metadata(number, "data")
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .save_l1_batch_tree_data(
+                number,
+                &L1BatchTreeData {
+                    hash: H256::zero(),
+                    rollup_last_leaf_index: 123,
+                },
+            )
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .save_l1_batch_commitment_artifacts(number, &L1BatchCommitmentArtifacts::default())
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn proof_generation_workflow() {
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -490,5 +673,123 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(unpicked_l1_batch, None);
+
+        let stages = conn
+            .proof_latency_dal()
+            .get_stages(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let stages: Vec<_> = stages.into_iter().map(|(stage, _)| stage).collect();
+        assert_eq!(stages, [ProvingStage::Queued, ProvingStage::Submitted]);
+    }
+
+    #[tokio::test]
+    async fn claiming_batches_for_proving() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        for number in 1..=2 {
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch_header(number))
+                .await
+                .unwrap();
+        }
+        prepare_batch_for_proving(&mut conn, L1BatchNumber(1)).await;
+        // The second batch doesn't have witness inputs yet.
+        conn.proof_generation_dal()
+            .insert_proof_generation_details(L1BatchNumber(2))
+            .await
+            .unwrap();
+
+        let batches = conn
+            .proof_generation_dal()
+            .get_batches_awaiting_proof(Duration::MAX, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            batches,
+            [
+                BatchAwaitingProof {
+                    l1_batch_number: L1BatchNumber(1),
+                    witness_inputs_available: true,
+                    is_picked: false,
+                },
+                BatchAwaitingProof {
+                    l1_batch_number: L1BatchNumber(2),
+                    witness_inputs_available: false,
+                    is_picked: false,
+                },
+            ]
+        );
+
+        let claimed = conn
+            .proof_generation_dal()
+            .claim_batch_for_proving(L1BatchNumber(2), b"prover", Duration::MAX)
+            .await
+            .unwrap();
+        assert!(!claimed);
+        let claimed = conn
+            .proof_generation_dal()
+            .claim_batch_for_proving(L1BatchNumber(1), b"prover", Duration::MAX)
+            .await
+            .unwrap();
+        assert!(claimed);
+        let claimant = conn
+            .proof_generation_dal()
+            .get_batch_claimant(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(claimant.as_deref(), Some(&b"prover"[..]));
+
+        // The batch cannot be claimed or locked by another prover until the processing timeout passes.
+        let claimed = conn
+            .proof_generation_dal()
+            .claim_batch_for_proving(L1BatchNumber(1), b"other", Duration::MAX)
+            .await
+            .unwrap();
+        assert!(!claimed);
+        let picked_l1_batch = conn
+            .proof_generation_dal()
+            .lock_batch_for_proving(Duration::MAX)
+            .await
+            .unwrap();
+        assert_eq!(picked_l1_batch, None);
+        let batches = conn
+            .proof_generation_dal()
+            .get_batches_awaiting_proof(Duration::MAX, 1)
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert!(batches[0].is_picked);
+
+        // Locking a batch after the timeout resets the claim.
+        let picked_l1_batch = conn
+            .proof_generation_dal()
+            .lock_batch_for_proving(Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(picked_l1_batch, Some(L1BatchNumber(1)));
+        let claimant = conn
+            .proof_generation_dal()
+            .get_batch_claimant(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(claimant, None);
+
+        conn.proof_generation_dal()
+            .save_proof_artifacts_metadata(L1BatchNumber(1), "proof")
+            .await
+            .unwrap();
+        let batches = conn
+            .proof_generation_dal()
+            .get_batches_awaiting_proof(Duration::MAX, 10)
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].l1_batch_number, L1BatchNumber(2));
     }
 }
@@ -0,0 +1,294 @@
+use std::ops;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+use zksync_types::{api, prover_dal::ProvingStage, L1BatchNumber};
+
+use crate::Core;
+
+/// DAL for timestamps of L1 batches reaching [`ProvingStage`]s. Only the first timestamp recorded
+/// for each stage is retained, so recording is idempotent.
+#[derive(Debug)]
+pub struct ProofLatencyDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl ProofLatencyDal<'_, '_> {
+    /// Records that the specified L1 batch has reached `stage` at the current moment.
+    pub async fn record_stage(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        stage: ProvingStage,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            proving_stage_timestamps (l1_batch_number, stage, recorded_at)
+            VALUES
+            ($1, $2, NOW())
+            ON CONFLICT (l1_batch_number, stage) DO NOTHING
+            "#,
+            i64::from(l1_batch_number.0),
+            stage.as_ref()
+        )
+        .instrument("record_stage")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("stage", &stage)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Records timestamps for multiple stages of the specified L1 batch, e.g. ones reported by the prover subsystem.
+    pub async fn record_stages(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        stages: &[(ProvingStage, DateTime<Utc>)],
+    ) -> DalResult<()> {
+        let (stage_names, timestamps): (Vec<_>, Vec<_>) = stages
+            .iter()
+            .map(|(stage, timestamp)| (stage.to_string(), timestamp.naive_utc()))
+            .unzip();
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            proving_stage_timestamps (l1_batch_number, stage, recorded_at)
+            SELECT
+                $1,
+                stages.stage,
+                stages.recorded_at
+            FROM
+                UNNEST($2::TEXT [], $3::TIMESTAMP []) AS stages (stage, recorded_at)
+            ON CONFLICT (l1_batch_number, stage) DO NOTHING
+            "#,
+            i64::from(l1_batch_number.0),
+            &stage_names,
+            &timestamps as &[NaiveDateTime]
+        )
+        .instrument("record_stages")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("stages.len", &stages.len())
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Records that all L1 batches proven by the specified `eth_txs` entry have reached `stage` at the current moment.
+    pub async fn record_stage_for_prove_tx(
+        &mut self,
+        eth_tx_id: u32,
+        stage: ProvingStage,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            proving_stage_timestamps (l1_batch_number, stage, recorded_at)
+            SELECT
+                number,
+                $2,
+                NOW()
+            FROM
+                l1_batches
+            WHERE
+                eth_prove_tx_id = $1
+            ON CONFLICT (l1_batch_number, stage) DO NOTHING
+            "#,
+            eth_tx_id as i32,
+            stage.as_ref()
+        )
+        .instrument("record_stage_for_prove_tx")
+        .with_arg("eth_tx_id", &eth_tx_id)
+        .with_arg("stage", &stage)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns all recorded stage timestamps for the specified L1 batch ordered by stage.
+    pub async fn get_stages(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<(ProvingStage, DateTime<Utc>)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                stage,
+                recorded_at
+            FROM
+                proving_stage_timestamps
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_stages")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        // Unknown stages may be present after a server downgrade; they are silently skipped.
+        let mut stages: Vec<_> = rows
+            .into_iter()
+            .filter_map(|row| Some((row.stage.parse().ok()?, row.recorded_at.and_utc())))
+            .collect();
+        stages.sort_unstable_by_key(|(stage, _)| *stage);
+        Ok(stages)
+    }
+
+    /// Returns aggregated latencies for the L1 batches in the specified range. A latency of a stage is measured
+    /// from the moment a batch was [queued](ProvingStage::Queued); batches without a queued timestamp are ignored.
+    pub async fn get_latency_stats(
+        &mut self,
+        l1_batches: ops::RangeInclusive<L1BatchNumber>,
+    ) -> DalResult<Vec<api::ProvingStageLatency>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                stage AS "stage!",
+                COUNT(*) AS "batch_count!",
+                AVG(latency_ms) AS "avg_ms!",
+                PERCENTILE_CONT(0.5) WITHIN GROUP (
+                    ORDER BY
+                        latency_ms
+                ) AS "p50_ms!",
+                PERCENTILE_CONT(0.95) WITHIN GROUP (
+                    ORDER BY
+                        latency_ms
+                ) AS "p95_ms!",
+                MAX(latency_ms) AS "max_ms!"
+            FROM
+                (
+                    SELECT
+                        stages.stage,
+                        EXTRACT(
+                            EPOCH
+                            FROM
+                            stages.recorded_at - queued.recorded_at
+                        )::DOUBLE PRECISION * 1000 AS latency_ms
+                    FROM
+                        proving_stage_timestamps AS stages
+                    INNER JOIN proving_stage_timestamps AS queued
+                        ON
+                            stages.l1_batch_number = queued.l1_batch_number
+                            AND queued.stage = 'queued'
+                    WHERE
+                        stages.l1_batch_number BETWEEN $1 AND $2
+                        AND stages.stage != 'queued'
+                ) AS latencies
+            GROUP BY
+                stage
+            "#,
+            i64::from(l1_batches.start().0),
+            i64::from(l1_batches.end().0)
+        )
+        .instrument("get_latency_stats")
+        .with_arg("l1_batches", &l1_batches)
+        .fetch_all(self.storage)
+        .await?;
+
+        let mut stats: Vec<_> = rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(api::ProvingStageLatency {
+                    stage: row.stage.parse().ok()?,
+                    batch_count: row.batch_count as u64,
+                    avg_ms: row.avg_ms.max(0.0).round() as u64,
+                    p50_ms: row.p50_ms.max(0.0).round() as u64,
+                    p95_ms: row.p95_ms.max(0.0).round() as u64,
+                    max_ms: row.max_ms.max(0.0).round() as u64,
+                })
+            })
+            .collect();
+        stats.sort_unstable_by_key(|stats| stats.stage);
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn recording_stages_and_getting_latency_stats() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let start = Utc::now() - Duration::hours(1);
+        for number in 1..=4 {
+            let l1_batch_number = L1BatchNumber(number);
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch_header(number))
+                .await
+                .unwrap();
+            let queued_at = start + Duration::minutes(number.into());
+            let stages = [
+                (ProvingStage::Queued, queued_at),
+                (
+                    ProvingStage::BasicCircuitsStarted,
+                    queued_at + Duration::seconds(number.into()),
+                ),
+                (
+                    ProvingStage::CompressionFinished,
+                    queued_at + Duration::seconds(10 * i64::from(number)),
+                ),
+            ];
+            conn.proof_latency_dal()
+                .record_stages(l1_batch_number, &stages)
+                .await
+                .unwrap();
+        }
+
+        // Timestamps should not be overwritten.
+        conn.proof_latency_dal()
+            .record_stage(L1BatchNumber(1), ProvingStage::Queued)
+            .await
+            .unwrap();
+        conn.proof_latency_dal()
+            .record_stage(L1BatchNumber(1), ProvingStage::Submitted)
+            .await
+            .unwrap();
+        let stages = conn
+            .proof_latency_dal()
+            .get_stages(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let stage_names: Vec<_> = stages.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(
+            stage_names,
+            [
+                ProvingStage::Queued,
+                ProvingStage::BasicCircuitsStarted,
+                ProvingStage::CompressionFinished,
+                ProvingStage::Submitted,
+            ]
+        );
+        assert_eq!(
+            stages[0].1.timestamp_millis(),
+            (start + Duration::minutes(1)).timestamp_millis()
+        );
+
+        let stats = conn
+            .proof_latency_dal()
+            .get_latency_stats(L1BatchNumber(2)..=L1BatchNumber(4))
+            .await
+            .unwrap();
+        assert_eq!(stats.len(), 2, "{stats:?}");
+        assert_eq!(stats[0].stage, ProvingStage::BasicCircuitsStarted);
+        assert_eq!(stats[0].batch_count, 3);
+        assert_eq!(stats[0].avg_ms, 3_000);
+        assert_eq!(stats[0].p50_ms, 3_000);
+        assert_eq!(stats[0].max_ms, 4_000);
+        assert_eq!(stats[1].stage, ProvingStage::CompressionFinished);
+        assert_eq!(stats[1].batch_count, 3);
+        assert_eq!(stats[1].avg_ms, 30_000);
+        assert_eq!(stats[1].max_ms, 40_000);
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops;
 
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
-use zksync_types::{L1BatchNumber, L2BlockNumber};
+use zksync_types::{Address, L1BatchNumber, L2BlockNumber};
 
 use crate::Core;
 
@@ -141,6 +141,19 @@ impl PruningDal<'_, '_> {
         &mut self,
         last_l1_batch_to_prune: L1BatchNumber,
         last_l2_block_to_prune: L2BlockNumber,
+    ) -> DalResult<HardPruningStats> {
+        self.hard_prune_batches_range_retaining(last_l1_batch_to_prune, last_l2_block_to_prune, &[])
+            .await
+    }
+
+    /// Same as [`Self::hard_prune_batches_range()`], but retains full history for the specified addresses:
+    /// their events, L2-to-L1 logs, call traces, transaction data and overwritten storage values are not removed.
+    /// Transactions are retained if either their initiator or their target is in `retained_addresses`.
+    pub async fn hard_prune_batches_range_retaining(
+        &mut self,
+        last_l1_batch_to_prune: L1BatchNumber,
+        last_l2_block_to_prune: L2BlockNumber,
+        retained_addresses: &[Address],
     ) -> DalResult<HardPruningStats> {
         let row = sqlx::query!(
             r#"
@@ -164,19 +177,34 @@ impl PruningDal<'_, '_> {
             let first_l2_block_to_prune = L2BlockNumber(first_l2_block_to_prune as u32);
 
             let deleted_events = self
-                .delete_events(first_l2_block_to_prune..=last_l2_block_to_prune)
+                .delete_events(
+                    first_l2_block_to_prune..=last_l2_block_to_prune,
+                    retained_addresses,
+                )
                 .await?;
             let deleted_l2_to_l1_logs = self
-                .delete_l2_to_l1_logs(first_l2_block_to_prune..=last_l2_block_to_prune)
+                .delete_l2_to_l1_logs(
+                    first_l2_block_to_prune..=last_l2_block_to_prune,
+                    retained_addresses,
+                )
                 .await?;
             let deleted_call_traces = self
-                .delete_call_traces(first_l2_block_to_prune..=last_l2_block_to_prune)
-                .await?;
-            self.clear_transaction_fields(first_l2_block_to_prune..=last_l2_block_to_prune)
+                .delete_call_traces(
+                    first_l2_block_to_prune..=last_l2_block_to_prune,
+                    retained_addresses,
+                )
                 .await?;
+            self.clear_transaction_fields(
+                first_l2_block_to_prune..=last_l2_block_to_prune,
+                retained_addresses,
+            )
+            .await?;
 
             let deleted_storage_logs = self
-                .prune_storage_logs(first_l2_block_to_prune..=last_l2_block_to_prune)
+                .prune_storage_logs(
+                    first_l2_block_to_prune..=last_l2_block_to_prune,
+                    retained_addresses,
+                )
                 .await?;
             let deleted_l1_batches = self.delete_l1_batches(last_l1_batch_to_prune).await?;
             let deleted_l2_blocks = self.delete_l2_blocks(last_l2_block_to_prune).await?;
@@ -201,15 +229,19 @@ impl PruningDal<'_, '_> {
     async fn delete_events(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_addresses: &[Address],
     ) -> DalResult<u64> {
+        let retained_addresses: Vec<_> = retained_addresses.iter().map(Address::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             DELETE FROM events
             WHERE
                 miniblock_number BETWEEN $1 AND $2
+                AND address != ALL($3)
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_addresses as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#delete_events")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
@@ -222,15 +254,19 @@ impl PruningDal<'_, '_> {
     async fn delete_l2_to_l1_logs(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_addresses: &[Address],
     ) -> DalResult<u64> {
+        let retained_addresses: Vec<_> = retained_addresses.iter().map(Address::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             DELETE FROM l2_to_l1_logs
             WHERE
                 miniblock_number BETWEEN $1 AND $2
+                AND sender != ALL($3)
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_addresses as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#delete_l2_to_l1_logs")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
@@ -246,7 +282,9 @@ impl PruningDal<'_, '_> {
     async fn delete_call_traces(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_addresses: &[Address],
     ) -> DalResult<u64> {
+        let retained_addresses: Vec<_> = retained_addresses.iter().map(Address::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             DELETE FROM call_traces
@@ -258,10 +296,13 @@ impl PruningDal<'_, '_> {
                         transactions
                     WHERE
                         miniblock_number BETWEEN $1 AND $2
+                        AND initiator_address != ALL($3)
+                        AND (contract_address IS NULL OR contract_address != ALL($3))
                 )
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_addresses as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#delete_call_traces")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
@@ -282,7 +323,9 @@ impl PruningDal<'_, '_> {
     async fn clear_transaction_fields(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_addresses: &[Address],
     ) -> DalResult<u64> {
+        let retained_addresses: Vec<_> = retained_addresses.iter().map(Address::as_bytes).collect();
         let execution_result = sqlx::query!(
             r#"
             UPDATE transactions
@@ -294,9 +337,12 @@ impl PruningDal<'_, '_> {
             WHERE
                 miniblock_number BETWEEN $1 AND $2
                 AND upgrade_id IS NULL
+                AND initiator_address != ALL($3)
+                AND (contract_address IS NULL OR contract_address != ALL($3))
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_addresses as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#clear_transaction_fields")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
@@ -310,7 +356,9 @@ impl PruningDal<'_, '_> {
     async fn prune_storage_logs(
         &mut self,
         l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+        retained_addresses: &[Address],
     ) -> DalResult<u64> {
+        let retained_addresses: Vec<_> = retained_addresses.iter().map(Address::as_bytes).collect();
         // Storage log pruning is designed to use deterministic indexes and thus have predictable performance.
         //
         // - The WITH query is guaranteed to use the block number index (that's the only WHERE condition),
@@ -343,9 +391,11 @@ impl PruningDal<'_, '_> {
                 AND storage_logs.miniblock_number <= $2
                 AND (storage_logs.miniblock_number, storage_logs.operation_number)
                 < (new_logs.miniblock_number, new_logs.operation_number)
+                AND (storage_logs.address IS NULL OR storage_logs.address != ALL($3))
             "#,
             i64::from(l2_blocks_to_prune.start().0),
-            i64::from(l2_blocks_to_prune.end().0)
+            i64::from(l2_blocks_to_prune.end().0),
+            &retained_addresses as &[&[u8]]
         )
         .instrument("hard_prune_batches_range#prune_storage_logs")
         .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
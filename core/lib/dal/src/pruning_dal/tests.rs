@@ -385,6 +385,56 @@ async fn storage_logs_pruning_works_correctly() {
     assert_eq!(stats.deleted_storage_logs, 2);
 }
 
+#[tokio::test]
+async fn hard_pruning_retains_history_for_addresses() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+
+    let mut conn = pool.connection().await.unwrap();
+    let mut transaction = conn.start_transaction().await.unwrap();
+    insert_realistic_l1_batches(&mut transaction, 10).await;
+    insert_l2_block_storage_logs(
+        &mut transaction,
+        L2BlockNumber(0),
+        vec![random_storage_log(1, 1), random_storage_log(2, 2)],
+    )
+    .await;
+    insert_l2_block_storage_logs(
+        &mut transaction,
+        L2BlockNumber(5),
+        vec![random_storage_log(1, 3), random_storage_log(2, 4)],
+    )
+    .await;
+
+    let retained_address = Address::repeat_byte(1);
+    let stats = transaction
+        .pruning_dal()
+        .hard_prune_batches_range_retaining(
+            L1BatchNumber(9),
+            L2BlockNumber(19),
+            &[retained_address],
+        )
+        .await
+        .unwrap();
+    assert_eq!(stats.deleted_l1_batches, 10);
+    assert_eq!(stats.deleted_l2_blocks, 20);
+    // Each L2 block has 5 events with distinct addresses, one of which is retained.
+    assert_eq!(stats.deleted_events, 80);
+    assert_eq!(stats.deleted_l2_to_l1_logs, 100);
+    assert_eq!(stats.deleted_storage_logs, 1);
+
+    let actual_logs = transaction
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    // The overwritten value for the retained address is kept.
+    assert_l2_block_storage_logs_equal(L2BlockNumber(0), &actual_logs, &[random_storage_log(1, 1)]);
+    assert_l2_block_storage_logs_equal(
+        L2BlockNumber(5),
+        &actual_logs,
+        &[random_storage_log(1, 3), random_storage_log(2, 4)],
+    );
+}
+
 #[tokio::test]
 async fn l1_batches_can_be_hard_pruned() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -481,7 +531,7 @@ async fn transactions_are_handled_correctly_after_pruning() {
 
     let affected_count = conn
         .pruning_dal()
-        .clear_transaction_fields(L2BlockNumber(1)..=L2BlockNumber(1))
+        .clear_transaction_fields(L2BlockNumber(1)..=L2BlockNumber(1), &[])
         .await
         .unwrap();
     assert_eq!(affected_count, 1);
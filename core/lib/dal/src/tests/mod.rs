@@ -308,7 +308,7 @@ async fn remove_stuck_txs() {
     let mut tx = mock_l1_execute();
     tx.received_timestamp_ms = unix_timestamp_ms() - Duration::new(1000, 0).as_millis() as u64;
     transactions_dal
-        .insert_transaction_l1(&tx, L1BlockNumber(1))
+        .insert_transaction_l1(&tx, L1BlockNumber(1), 0)
         .await
         .unwrap();
 
@@ -132,7 +132,11 @@ mod tests {
     use std::{collections::HashSet, slice};
 
     use zksync_system_constants::FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH;
-    use zksync_types::{get_code_key, tokens::TokenMetadata, ProtocolVersion, StorageLog, H256};
+    use zksync_types::{
+        get_code_key,
+        tokens::{TokenMetadata, TokenOrigin},
+        ProtocolVersion, StorageLog, H256, L2_BASE_TOKEN_ADDRESS,
+    };
 
     use super::*;
     use crate::{tests::create_l2_block_header, ConnectionPool, Core, CoreDal};
@@ -237,6 +241,87 @@ mod tests {
         assert!(well_known_tokens.contains(&tokens[1]));
     }
 
+    #[tokio::test]
+    async fn paginating_well_known_tokens() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        storage
+            .protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let base_token = TokenInfo {
+            l2_address: L2_BASE_TOKEN_ADDRESS,
+            ..eth_token_info()
+        };
+        let tokens = [base_token, test_token_info()];
+        storage.tokens_dal().add_tokens(&tokens).await.unwrap();
+        let storage_logs: Vec<_> = tokens
+            .iter()
+            .map(|token_info| {
+                StorageLog::new_write_log(
+                    get_code_key(&token_info.l2_address),
+                    H256::repeat_byte(0xff),
+                )
+            })
+            .collect();
+        insert_l2_block(&mut storage, 1, storage_logs).await;
+        for token in &tokens {
+            storage
+                .tokens_dal()
+                .mark_token_as_well_known(token.l1_address)
+                .await
+                .unwrap();
+        }
+
+        // Tokens are ordered by their L2 addresses.
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(None, None, None, 1)
+            .await
+            .unwrap();
+        assert_eq!(page.tokens.len(), 1);
+        assert_eq!(page.tokens[0].info, tokens[0]);
+        assert_eq!(page.tokens[0].origin, TokenOrigin::BaseToken);
+        assert_eq!(page.next_cursor, Some(tokens[0].l2_address));
+
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(page.next_cursor, None, None, 1)
+            .await
+            .unwrap();
+        assert_eq!(page.tokens.len(), 1);
+        assert_eq!(page.tokens[0].info, tokens[1]);
+        assert_eq!(page.tokens[0].origin, TokenOrigin::L1Bridge);
+        assert_eq!(page.next_cursor, Some(tokens[1].l2_address));
+
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(page.next_cursor, None, None, 1)
+            .await
+            .unwrap();
+        assert!(page.tokens.is_empty());
+        assert_eq!(page.next_cursor, None);
+
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(None, Some(TokenOrigin::L1Bridge), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(page.tokens.len(), 1);
+        assert_eq!(page.tokens[0].info, tokens[1]);
+        assert_eq!(page.next_cursor, None);
+
+        let updated_at = page.tokens[0].updated_at;
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(None, None, Some(updated_at + 3_600), 10)
+            .await
+            .unwrap();
+        assert!(page.tokens.is_empty());
+    }
+
     #[tokio::test]
     async fn rolling_back_tokens() {
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -1,7 +1,8 @@
+use chrono::{DateTime, NaiveDateTime};
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
 use zksync_types::{
-    tokens::{TokenInfo, TokenMetadata},
-    Address, L2BlockNumber,
+    tokens::{TokenInfo, TokenMetadata, TokenOrigin, WellKnownToken, WellKnownTokensPage},
+    Address, L2BlockNumber, L2_BASE_TOKEN_ADDRESS,
 };
 
 use crate::{Core, CoreDal};
@@ -29,6 +30,39 @@ impl From<StorageTokenInfo> for TokenInfo {
     }
 }
 
+#[derive(Debug)]
+struct StorageWellKnownToken {
+    l1_address: Vec<u8>,
+    l2_address: Vec<u8>,
+    name: String,
+    symbol: String,
+    decimals: i32,
+    updated_at: NaiveDateTime,
+}
+
+impl From<StorageWellKnownToken> for WellKnownToken {
+    fn from(row: StorageWellKnownToken) -> Self {
+        let l2_address = Address::from_slice(&row.l2_address);
+        Self {
+            info: TokenInfo {
+                l1_address: Address::from_slice(&row.l1_address),
+                l2_address,
+                metadata: TokenMetadata {
+                    name: row.name,
+                    symbol: row.symbol,
+                    decimals: row.decimals as u8,
+                },
+            },
+            origin: if l2_address == L2_BASE_TOKEN_ADDRESS {
+                TokenOrigin::BaseToken
+            } else {
+                TokenOrigin::L1Bridge
+            },
+            updated_at: row.updated_at.and_utc().timestamp() as u64,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TokensWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -84,6 +118,94 @@ impl TokensWeb3Dal<'_, '_> {
         Ok(tokens)
     }
 
+    /// Returns a page of well-known tokens ordered by their L2 address, starting after the `cursor` address.
+    ///
+    /// Tokens can be filtered by their origin and by the time their metadata was last updated
+    /// (`updated_since` is a UNIX timestamp in seconds). Tokens whose L2 contracts aren't deployed are skipped,
+    /// so the returned page may contain fewer than `limit` tokens even if it's not the last one.
+    pub async fn get_well_known_tokens_page(
+        &mut self,
+        cursor: Option<Address>,
+        origin: Option<TokenOrigin>,
+        updated_since: Option<u64>,
+        limit: usize,
+    ) -> DalResult<WellKnownTokensPage> {
+        let is_base_token = origin.map(|origin| origin == TokenOrigin::BaseToken);
+        let updated_since = updated_since
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp.try_into().ok()?, 0))
+            .map(|timestamp| timestamp.naive_utc());
+        let records = sqlx::query_as!(
+            StorageWellKnownToken,
+            r#"
+            SELECT
+                l1_address,
+                l2_address,
+                name,
+                symbol,
+                decimals,
+                updated_at
+            FROM
+                tokens
+            WHERE
+                well_known = TRUE
+                AND (
+                    $1::BYTEA IS NULL
+                    OR l2_address > $1
+                )
+                AND (
+                    $2::BOOLEAN IS NULL
+                    OR (l2_address = $3) = $2
+                )
+                AND (
+                    $4::TIMESTAMP IS NULL
+                    OR updated_at >= $4
+                )
+            ORDER BY
+                l2_address
+            LIMIT
+                $5
+            "#,
+            cursor.as_ref().map(Address::as_bytes),
+            is_base_token,
+            L2_BASE_TOKEN_ADDRESS.as_bytes(),
+            updated_since,
+            limit as i64
+        )
+        .instrument("get_well_known_tokens_page")
+        .with_arg("cursor", &cursor)
+        .with_arg("origin", &origin)
+        .with_arg("updated_since", &updated_since)
+        .with_arg("limit", &limit)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        let next_cursor = if records.len() == limit {
+            records
+                .last()
+                .map(|record| Address::from_slice(&record.l2_address))
+        } else {
+            None
+        };
+        let mut tokens: Vec<_> = records.into_iter().map(WellKnownToken::from).collect();
+
+        let token_addresses = tokens.iter().map(|token| token.info.l2_address);
+        let filtered_addresses = self
+            .storage
+            .storage_logs_dal()
+            .filter_deployed_contracts(token_addresses, None)
+            .await?;
+        tokens.retain(|token| {
+            token.info.l2_address.is_zero()
+                || filtered_addresses.contains_key(&token.info.l2_address)
+        });
+
+        Ok(WellKnownTokensPage {
+            tokens,
+            next_cursor,
+        })
+    }
+
     /// Returns information about all tokens.
     pub async fn get_all_tokens(
         &mut self,
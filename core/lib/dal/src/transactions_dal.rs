@@ -61,6 +61,7 @@ impl TransactionsDal<'_, '_> {
         &mut self,
         tx: &L1Tx,
         l1_block_number: L1BlockNumber,
+        l1_block_timestamp: u64,
     ) -> DalResult<()> {
         let contract_address = tx.execute.contract_address;
         let contract_address_as_bytes = contract_address.map(|addr| addr.as_bytes().to_vec());
@@ -103,6 +104,7 @@ impl TransactionsDal<'_, '_> {
                 layer_2_tip_fee,
                 contract_address,
                 l1_block_number,
+                l1_block_timestamp,
                 value,
                 paymaster,
                 paymaster_input,
@@ -134,6 +136,7 @@ impl TransactionsDal<'_, '_> {
                 $16,
                 $17,
                 $18,
+                $19,
                 NOW(),
                 NOW()
             )
@@ -150,6 +153,7 @@ impl TransactionsDal<'_, '_> {
             layer_2_tip_fee,
             contract_address_as_bytes,
             l1_block_number.0 as i32,
+            l1_block_timestamp as i64,
             value,
             empty_address.as_bytes(),
             &[] as &[u8],
@@ -1739,6 +1743,36 @@ impl TransactionsDal<'_, '_> {
         Ok(rows.len())
     }
 
+    /// Checks whether there are transactions (L1 or L2) not yet included into a sealed L2 block, other than
+    /// the specified ones. This includes transactions in the mempool and ones executed in the pending L2 block.
+    pub async fn has_pending_transactions_except(
+        &mut self,
+        excluded_hashes: &[H256],
+    ) -> DalResult<bool> {
+        let excluded_hashes: Vec<_> = excluded_hashes.iter().map(H256::as_bytes).collect();
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number IS NULL
+                        AND error IS NULL
+                        AND NOT (hash = ANY($1))
+                ) AS "exists!"
+            "#,
+            &excluded_hashes as &[&[u8]]
+        )
+        .instrument("has_pending_transactions_except")
+        .with_arg("excluded_hashes.len", &excluded_hashes.len())
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.exists)
+    }
+
     /// Fetches new updates for mempool. Returns new transactions and current nonces for related accounts;
     /// the latter are only used to bootstrap mempool for given account.
     pub async fn sync_mempool(
@@ -1938,6 +1972,94 @@ impl TransactionsDal<'_, '_> {
         }
     }
 
+    /// Returns the ID and the L1 block number of the first priority operation included into the specified L1 batch.
+    pub async fn get_first_priority_op_in_l1_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Option<(PriorityOpId, L1BlockNumber)>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                priority_op_id AS "priority_op_id!",
+                l1_block_number AS "l1_block_number!"
+            FROM
+                transactions
+            WHERE
+                is_priority = TRUE
+                AND l1_batch_number = $1
+            ORDER BY
+                priority_op_id
+            LIMIT
+                1
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_first_priority_op_in_l1_batch")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| {
+            (
+                PriorityOpId(row.priority_op_id as u64),
+                L1BlockNumber(row.l1_block_number as u32),
+            )
+        }))
+    }
+
+    /// Returns priority operations that have missed their inclusion deadline by the start of the specified L1 batch,
+    /// ordered by ID. A priority operation misses the deadline if the L1 batch `deadline_batches` batches before
+    /// `l1_batch_number` was opened after the L1 block emitting the operation (i.e., at least `deadline_batches`
+    /// L1 batches were opened after it), and the operation is not included into any L1 batch yet.
+    pub async fn get_priority_ops_past_inclusion_deadline(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        deadline_batches: u32,
+    ) -> DalResult<Vec<(PriorityOpId, H256)>> {
+        let Some(reference_batch) = l1_batch_number.0.checked_sub(deadline_batches) else {
+            return Ok(vec![]);
+        };
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                priority_op_id AS "priority_op_id!",
+                hash
+            FROM
+                transactions
+            WHERE
+                is_priority = TRUE
+                AND l1_batch_number IS NULL
+                AND l1_block_timestamp < (
+                    SELECT
+                        timestamp
+                    FROM
+                        l1_batches
+                    WHERE
+                        number = $1
+                )
+            ORDER BY
+                priority_op_id
+            "#,
+            i64::from(reference_batch)
+        )
+        .instrument("get_priority_ops_past_inclusion_deadline")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("deadline_batches", &deadline_batches)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    PriorityOpId(row.priority_op_id as u64),
+                    H256::from_slice(&row.hash),
+                )
+            })
+            .collect())
+    }
+
     /// Returns L2 blocks with their transactions that state_keeper needs to re-execute on restart.
     /// These are the transactions that are included to some L2 block,
     /// but not included to L1 batch. The order of the transactions is the same as it was
@@ -2269,7 +2391,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        tests::{create_l2_block_header, mock_execution_result, mock_l2_transaction},
+        tests::{
+            create_l1_batch_header, create_l2_block_header, mock_execution_result, mock_l1_execute,
+            mock_l2_transaction,
+        },
         ConnectionPool, Core, CoreDal,
     };
 
@@ -2354,4 +2479,131 @@ mod tests {
             .unwrap();
         assert_eq!(tx_from_db[0].hash, tx_hash);
     }
+
+    #[tokio::test]
+    async fn checking_pending_transactions() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let has_pending_txs = conn
+            .transactions_dal()
+            .has_pending_transactions_except(&[])
+            .await
+            .unwrap();
+        assert!(!has_pending_txs);
+
+        let txs = [mock_l2_transaction(), mock_l2_transaction()];
+        for tx in &txs {
+            conn.transactions_dal()
+                .insert_transaction_l2(
+                    tx,
+                    TransactionExecutionMetrics::default(),
+                    ValidationTraces::default(),
+                )
+                .await
+                .unwrap();
+        }
+        let has_pending_txs = conn
+            .transactions_dal()
+            .has_pending_transactions_except(&[txs[0].hash()])
+            .await
+            .unwrap();
+        assert!(has_pending_txs);
+
+        // Rejected and executed transactions are not pending.
+        conn.transactions_dal()
+            .mark_tx_as_rejected(txs[1].hash(), "rejected")
+            .await
+            .unwrap();
+        let has_pending_txs = conn
+            .transactions_dal()
+            .has_pending_transactions_except(&[txs[0].hash()])
+            .await
+            .unwrap();
+        assert!(!has_pending_txs);
+
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[mock_execution_result(txs[0].clone())],
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+        let has_pending_txs = conn
+            .transactions_dal()
+            .has_pending_transactions_except(&[])
+            .await
+            .unwrap();
+        assert!(!has_pending_txs);
+    }
+
+    #[tokio::test]
+    async fn getting_priority_ops_past_inclusion_deadline() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        for (number, timestamp) in [(1, 100), (2, 200), (3, 300), (4, 400)] {
+            let mut header = create_l1_batch_header(number);
+            header.timestamp = timestamp;
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&header)
+                .await
+                .unwrap();
+        }
+
+        let tx = mock_l1_execute();
+        let tx_hash = tx.hash();
+        conn.transactions_dal()
+            .insert_transaction_l1(&tx, L1BlockNumber(1), 150)
+            .await
+            .unwrap();
+
+        // Only L1 batch #2 is opened after the operation is emitted on L1 and before L1 batch #3.
+        let overdue_ops = conn
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(L1BatchNumber(3), 2)
+            .await
+            .unwrap();
+        assert_eq!(overdue_ops, []);
+        let overdue_ops = conn
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(L1BatchNumber(4), 2)
+            .await
+            .unwrap();
+        assert_eq!(overdue_ops, [(PriorityOpId(1), tx_hash)]);
+        let overdue_ops = conn
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(L1BatchNumber(1), 2)
+            .await
+            .unwrap();
+        assert_eq!(overdue_ops, []);
+
+        let mut tx_result = mock_execution_result(mock_l2_transaction());
+        tx_result.hash = tx_hash;
+        tx_result.transaction = tx.into();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(3), &[tx_result])
+            .await
+            .unwrap();
+
+        let overdue_ops = conn
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(L1BatchNumber(4), 2)
+            .await
+            .unwrap();
+        assert_eq!(overdue_ops, []);
+    }
 }
@@ -16,8 +16,8 @@ use zksync_vm_interface::VmEvent;
 
 use crate::{
     models::storage_transaction::{
-        StorageApiTransaction, StorageTransaction, StorageTransactionDetails,
-        StorageTransactionExecutionInfo, StorageTransactionReceipt,
+        StorageApiTransaction, StorageTransaction, StorageTransactionBySelector,
+        StorageTransactionDetails, StorageTransactionExecutionInfo, StorageTransactionReceipt,
     },
     Core, CoreDal,
 };
@@ -79,7 +79,13 @@ impl TransactionsWeb3Dal<'_, '_> {
                 miniblocks.hash AS "block_hash",
                 miniblocks.l1_batch_number AS "l1_batch_number?",
                 events.topic4 AS "contract_address?",
-                miniblocks.timestamp AS "block_timestamp?"
+                miniblocks.timestamp AS "block_timestamp?",
+                transactions.gas_per_pubdata_limit,
+                miniblocks.l1_gas_price,
+                miniblocks.l2_fair_gas_price,
+                miniblocks.fair_pubdata_price,
+                miniblocks.protocol_version,
+                (transactions.execution_info ->> 'pubdata_published')::BIGINT AS "pubdata_published?"
             FROM
                 transactions
             JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
@@ -380,10 +386,34 @@ impl TransactionsWeb3Dal<'_, '_> {
     ) -> DalResult<U256> {
         // Get nonces of non-rejected transactions, starting from the 'latest' nonce.
         // `latest` nonce is used, because it is guaranteed that there are no gaps before it.
+        let non_rejected_nonces = self
+            .get_non_rejected_nonces(initiator_address, committed_next_nonce)
+            .await?;
+
+        // Find pending nonce as the first "gap" in nonces.
+        let mut pending_nonce = committed_next_nonce;
+        for nonce in non_rejected_nonces {
+            if pending_nonce == nonce {
+                pending_nonce += 1;
+            } else {
+                break;
+            }
+        }
+
+        Ok(U256::from(pending_nonce))
+    }
+
+    /// Returns nonces of non-rejected L2 transactions from the specified account starting from `committed_next_nonce`,
+    /// in ascending order.
+    pub async fn get_non_rejected_nonces(
+        &mut self,
+        initiator_address: Address,
+        committed_next_nonce: u64,
+    ) -> DalResult<Vec<u64>> {
         // `(miniblock_number IS NOT NULL OR error IS NULL)` is the condition that filters non-rejected transactions.
         // Query is fast because we have an index on (`initiator_address`, `nonce`)
         // and it cannot return more than `max_nonce_ahead` nonces.
-        let non_rejected_nonces: Vec<u64> = sqlx::query!(
+        let nonces = sqlx::query!(
             r#"
             SELECT
                 nonce AS "nonce!"
@@ -403,7 +433,7 @@ impl TransactionsWeb3Dal<'_, '_> {
             initiator_address.as_bytes(),
             committed_next_nonce as i64
         )
-        .instrument("next_nonce_by_initiator_account#non_rejected_nonces")
+        .instrument("get_non_rejected_nonces")
         .with_arg("initiator_address", &initiator_address)
         .with_arg("committed_next_nonce", &committed_next_nonce)
         .fetch_all(self.storage)
@@ -411,18 +441,7 @@ impl TransactionsWeb3Dal<'_, '_> {
         .into_iter()
         .map(|row| row.nonce as u64)
         .collect();
-
-        // Find pending nonce as the first "gap" in nonces.
-        let mut pending_nonce = committed_next_nonce;
-        for nonce in non_rejected_nonces {
-            if pending_nonce == nonce {
-                pending_nonce += 1;
-            } else {
-                break;
-            }
-        }
-
-        Ok(U256::from(pending_nonce))
+        Ok(nonces)
     }
 
     /// Returns the server transactions (not API ones) from a L2 block range.
@@ -475,6 +494,119 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(txs)
     }
 
+    /// Returns hashes of transactions initiated by or sent to the specified account in the specified L2 block range.
+    /// Transactions are ordered by their position in the chain (in reverse if `descending` is set) and paginated
+    /// using `offset` and `limit`.
+    pub async fn get_account_transaction_hashes(
+        &mut self,
+        account: Address,
+        blocks: std::ops::RangeInclusive<L2BlockNumber>,
+        offset: usize,
+        limit: usize,
+        descending: bool,
+    ) -> DalResult<Vec<H256>> {
+        let order = if descending { "DESC" } else { "ASC" };
+        // The query cannot be checked at compile time since the ordering direction is dynamic.
+        let query = format!(
+            r#"
+            SELECT
+                hash
+            FROM
+                transactions
+            WHERE
+                (initiator_address = $1 OR contract_address = $1)
+                AND miniblock_number BETWEEN $2 AND $3
+            ORDER BY
+                miniblock_number {order},
+                index_in_block {order}
+            LIMIT
+                $4
+            OFFSET
+                $5
+            "#
+        );
+        let hashes: Vec<Vec<u8>> = sqlx::query_scalar(&query)
+            .bind(account.as_bytes())
+            .bind(i64::from(blocks.start().0))
+            .bind(i64::from(blocks.end().0))
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .instrument("get_account_transaction_hashes")
+            .with_arg("account", &account)
+            .with_arg("blocks", &blocks)
+            .with_arg("offset", &offset)
+            .with_arg("limit", &limit)
+            .fetch_all(self.storage)
+            .await?;
+        Ok(hashes.iter().map(|hash| H256::from_slice(hash)).collect())
+    }
+
+    /// Returns transactions calling a function with the specified 4-byte `selector` in the specified L2 block range,
+    /// optionally only those sent to `contract_address`. Transactions are ordered by their position in the chain
+    /// and, if `cursor` is specified, start after the transaction at this position.
+    pub async fn get_transactions_by_selector(
+        &mut self,
+        selector: [u8; 4],
+        contract_address: Option<Address>,
+        blocks: std::ops::RangeInclusive<L2BlockNumber>,
+        cursor: Option<api::TransactionPosition>,
+        limit: usize,
+    ) -> DalResult<Vec<api::TransactionBySelector>> {
+        // Calldata is stored as a `0x`-prefixed hex string, so the selector occupies its first 10 chars.
+        // The expression must match the one in `transactions_calldata_selector_idx`.
+        let selector_hex = format!("0x{}", hex::encode(selector));
+        // Positions start from (0, 0), so the default cursor doesn't filter out any transactions.
+        let (cursor_block, cursor_index) = cursor.as_ref().map_or((-1, -1), |cursor| {
+            (
+                i64::from(cursor.block_number.0),
+                cursor.transaction_index as i32,
+            )
+        });
+        let rows = sqlx::query_as!(
+            StorageTransactionBySelector,
+            r#"
+            SELECT
+                hash,
+                miniblock_number AS "miniblock_number!",
+                index_in_block AS "index_in_block!",
+                initiator_address,
+                contract_address
+            FROM
+                transactions
+            WHERE
+                SUBSTRING(data ->> 'calldata' FROM 1 FOR 10) = $1
+                AND miniblock_number BETWEEN $2 AND $3
+                AND (
+                    $4::BYTEA IS NULL
+                    OR contract_address = $4
+                )
+                AND (miniblock_number, index_in_block) > ($5, $6)
+            ORDER BY
+                miniblock_number,
+                index_in_block
+            LIMIT
+                $7
+            "#,
+            selector_hex,
+            i64::from(blocks.start().0),
+            i64::from(blocks.end().0),
+            contract_address.as_ref().map(Address::as_bytes),
+            cursor_block,
+            cursor_index,
+            limit as i64
+        )
+        .instrument("get_transactions_by_selector")
+        .with_arg("selector", &selector_hex)
+        .with_arg("contract_address", &contract_address)
+        .with_arg("blocks", &blocks)
+        .with_arg("cursor", &cursor)
+        .with_arg("limit", &limit)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     /// Returns the server transactions (not API ones) from an L2 block.
     pub async fn get_raw_l2_block_transactions(
         &mut self,
@@ -492,7 +624,10 @@ impl TransactionsWeb3Dal<'_, '_> {
 mod tests {
     use std::collections::HashMap;
 
-    use zksync_types::{l2::L2Tx, Nonce, ProtocolVersion, ProtocolVersionId};
+    use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
+    use zksync_types::{
+        fee_model::BatchFeeInput, l2::L2Tx, Nonce, ProtocolVersion, ProtocolVersionId,
+    };
     use zksync_vm_interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
 
     use super::*;
@@ -671,6 +806,21 @@ mod tests {
         assert_eq!(receipts.len(), 2);
         assert_eq!(receipts[0].transaction_hash, tx1_hash);
         assert_eq!(receipts[1].transaction_hash, tx2_hash);
+        // Mock execution results report no published pubdata.
+        assert_eq!(receipts[0].pubdata_used, Some(0.into()));
+
+        let fee_input = create_l2_block_header(1).batch_fee_input;
+        let fee_input = BatchFeeInput::pubdata_independent(
+            fee_input.l1_gas_price(),
+            fee_input.fair_l2_gas_price(),
+            fee_input.fair_pubdata_price(),
+        );
+        let (_, expected_gas_per_pubdata) =
+            derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::default().into());
+        assert_eq!(
+            receipts[0].gas_per_pubdata,
+            Some(expected_gas_per_pubdata.into())
+        );
     }
 
     #[tokio::test]
@@ -726,6 +876,139 @@ mod tests {
         assert_eq!(raw_txs[0].hash(), tx_hash);
     }
 
+    #[tokio::test]
+    async fn getting_account_transaction_hashes() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let initiator = Address::repeat_byte(1);
+        let mut txs: Vec<_> = (0..3)
+            .map(|i| {
+                let mut tx = mock_l2_transaction();
+                tx.common_data.initiator_address = initiator;
+                tx.common_data.nonce = Nonce(i);
+                tx
+            })
+            .collect();
+        let tx_hashes: Vec<_> = txs.iter().map(L2Tx::hash).collect();
+        // Transaction from another account sent to the tested one.
+        let mut incoming_tx = mock_l2_transaction();
+        incoming_tx.execute.contract_address = Some(initiator);
+        let incoming_tx_hash = incoming_tx.hash();
+        txs.push(incoming_tx);
+        prepare_transactions(&mut conn, txs).await;
+
+        let blocks = L2BlockNumber(0)..=L2BlockNumber(1);
+        let hashes = conn
+            .transactions_web3_dal()
+            .get_account_transaction_hashes(initiator, blocks.clone(), 0, 100, false)
+            .await
+            .unwrap();
+        let mut expected_hashes = tx_hashes.clone();
+        expected_hashes.push(incoming_tx_hash);
+        assert_eq!(hashes, expected_hashes);
+
+        let hashes = conn
+            .transactions_web3_dal()
+            .get_account_transaction_hashes(initiator, blocks.clone(), 1, 2, true)
+            .await
+            .unwrap();
+        assert_eq!(hashes, [tx_hashes[2], tx_hashes[1]]);
+
+        let hashes = conn
+            .transactions_web3_dal()
+            .get_account_transaction_hashes(initiator, blocks, expected_hashes.len(), 100, false)
+            .await
+            .unwrap();
+        assert!(hashes.is_empty());
+
+        let hashes = conn
+            .transactions_web3_dal()
+            .get_account_transaction_hashes(
+                initiator,
+                L2BlockNumber(2)..=L2BlockNumber(10),
+                0,
+                100,
+                false,
+            )
+            .await
+            .unwrap();
+        assert!(hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn getting_transactions_by_selector() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let selector = [0xa9, 0x05, 0x9c, 0xbb];
+        let contract = Address::repeat_byte(1);
+        let txs: Vec<_> = [
+            (Some(contract), [&selector[..], &[0; 32]].concat()),
+            (Some(Address::repeat_byte(2)), selector.to_vec()),
+            (Some(contract), vec![0x12, 0x34, 0x56, 0x78]),
+            (Some(contract), selector.to_vec()),
+        ]
+        .into_iter()
+        .map(|(contract_address, calldata)| {
+            let mut tx = mock_l2_transaction();
+            tx.execute.contract_address = contract_address;
+            tx.execute.calldata = calldata;
+            tx
+        })
+        .collect();
+        let tx_hashes: Vec<_> = txs.iter().map(L2Tx::hash).collect();
+        prepare_transactions(&mut conn, txs).await;
+
+        let blocks = L2BlockNumber(0)..=L2BlockNumber(1);
+        let found = conn
+            .transactions_web3_dal()
+            .get_transactions_by_selector(selector, None, blocks.clone(), None, 100)
+            .await
+            .unwrap();
+        let found_hashes: Vec<_> = found.iter().map(|tx| tx.hash).collect();
+        assert_eq!(found_hashes, [tx_hashes[0], tx_hashes[1], tx_hashes[3]]);
+        assert_eq!(found[0].to, Some(contract));
+        assert_eq!(found[1].position.block_number, L2BlockNumber(1));
+        assert_eq!(found[1].position.transaction_index, 1);
+
+        let found = conn
+            .transactions_web3_dal()
+            .get_transactions_by_selector(selector, Some(contract), blocks.clone(), None, 1)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].hash, tx_hashes[0]);
+
+        let cursor = Some(found[0].position);
+        let found = conn
+            .transactions_web3_dal()
+            .get_transactions_by_selector(selector, Some(contract), blocks, cursor, 100)
+            .await
+            .unwrap();
+        let found_hashes: Vec<_> = found.iter().map(|tx| tx.hash).collect();
+        assert_eq!(found_hashes, [tx_hashes[3]]);
+
+        let found = conn
+            .transactions_web3_dal()
+            .get_transactions_by_selector(
+                selector,
+                None,
+                L2BlockNumber(2)..=L2BlockNumber(10),
+                None,
+                100,
+            )
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+    }
+
     #[tokio::test]
     async fn getting_next_nonce_by_initiator_account() {
         let connection_pool = ConnectionPool::<Core>::test_pool().await;
@@ -1,5 +1,7 @@
+use std::{collections::HashMap, ops};
+
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
-use zksync_types::L1BatchNumber;
+use zksync_types::{L1BatchNumber, H256};
 
 use crate::Core;
 
@@ -286,4 +288,200 @@ impl VmRunnerDal<'_, '_> {
         }
         Ok(())
     }
+
+    pub async fn get_batch_verifier_latest_processed_batch(
+        &mut self,
+    ) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(l1_batch_number) AS "last_processed_l1_batch"
+            FROM
+                vm_runner_batch_verifier
+            WHERE
+                time_taken IS NOT NULL
+            "#,
+        )
+        .instrument("get_batch_verifier_latest_processed_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.last_processed_l1_batch.map(|n| L1BatchNumber(n as u32)))
+    }
+
+    pub async fn get_batch_verifier_last_ready_batch(
+        &mut self,
+        default_batch: L1BatchNumber,
+        window_size: u32,
+    ) -> DalResult<L1BatchNumber> {
+        let row = sqlx::query!(
+            r#"
+            WITH
+            available_batches AS (
+                SELECT
+                    MAX(number) AS "last_batch"
+                FROM
+                    l1_batches
+                WHERE
+                    is_sealed
+            ),
+            
+            processed_batches AS (
+                SELECT
+                    COALESCE(MAX(l1_batch_number), $1) + $2 AS "last_ready_batch"
+                FROM
+                    vm_runner_batch_verifier
+                WHERE
+                    time_taken IS NOT NULL
+            )
+            
+            SELECT
+                LEAST(last_batch, last_ready_batch) AS "last_ready_batch!"
+            FROM
+                available_batches
+            FULL JOIN processed_batches ON TRUE
+            "#,
+            default_batch.0 as i32,
+            window_size as i32
+        )
+        .instrument("get_batch_verifier_last_ready_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(L1BatchNumber(row.last_ready_batch as u32))
+    }
+
+    pub async fn mark_batch_verifier_batch_as_processing(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            vm_runner_batch_verifier (
+                l1_batch_number, created_at, updated_at, processing_started_at
+            )
+            VALUES
+            ($1, NOW(), NOW(), NOW())
+            ON CONFLICT (l1_batch_number) DO
+            UPDATE
+            SET
+            updated_at = NOW(),
+            processing_started_at = NOW()
+            "#,
+            i64::from(l1_batch_number.0),
+        )
+        .instrument("mark_batch_verifier_batch_as_processing")
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn mark_batch_verifier_batch_as_completed(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE vm_runner_batch_verifier
+            SET
+                time_taken = NOW() - processing_started_at
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(l1_batch_number.0),
+        )
+        .instrument("mark_batch_verifier_batch_as_completed")
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        if update_result.rows_affected() == 0 {
+            anyhow::bail!(
+                "Trying to mark an L1 batch as completed while it is not being processed"
+            );
+        }
+        Ok(())
+    }
+
+    /// Saves the commitment recomputed by the batch verifier for the specified L1 batch.
+    pub async fn save_batch_verifier_commitment(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        commitment: H256,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE vm_runner_batch_verifier
+            SET
+                commitment = $2,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(l1_batch_number.0),
+            commitment.as_bytes()
+        )
+        .instrument("save_batch_verifier_commitment")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns commitments recomputed by the batch verifier for L1 batches in the specified range.
+    /// Batches that weren't verified yet are not included in the output.
+    pub async fn get_batch_verifier_commitments(
+        &mut self,
+        l1_batch_numbers: ops::RangeInclusive<L1BatchNumber>,
+    ) -> DalResult<HashMap<L1BatchNumber, H256>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                commitment AS "commitment!"
+            FROM
+                vm_runner_batch_verifier
+            WHERE
+                l1_batch_number BETWEEN $1 AND $2
+                AND commitment IS NOT NULL
+            "#,
+            i64::from(l1_batch_numbers.start().0),
+            i64::from(l1_batch_numbers.end().0)
+        )
+        .instrument("get_batch_verifier_commitments")
+        .with_arg("l1_batch_numbers", &l1_batch_numbers)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    L1BatchNumber(row.l1_batch_number as u32),
+                    H256::from_slice(&row.commitment),
+                )
+            })
+            .collect())
+    }
+
+    pub async fn delete_batch_verifier_data(
+        &mut self,
+        last_batch_to_keep: L1BatchNumber,
+    ) -> DalResult<()> {
+        let l1_batch_number = i64::from(last_batch_to_keep.0);
+        sqlx::query!(
+            r#"
+            DELETE FROM vm_runner_batch_verifier
+            WHERE
+                l1_batch_number > $1
+            "#,
+            l1_batch_number
+        )
+        .instrument("delete_batch_verifier_data")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
 }
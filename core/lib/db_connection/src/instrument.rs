@@ -5,6 +5,9 @@
 //! - Report query latency as a metric
 //! - Report slow and failing queries as metrics
 //! - Log slow and failing queries together with their arguments, which makes it easier to debug.
+//! - Record finished slow queries to a dedicated diagnostics log target ([`SLOW_QUERIES_LOG_TARGET`])
+//!   together with the requesting component, so that they can be collected separately from other logs.
+//! - Emit a `dal_query` span for queries executed as a part of an OpenTelemetry trace (e.g., a traced API request).
 //!
 //! The entry point for instrumentation is the [`InstrumentExt`] trait. After it is imported into the scope,
 //! its `instrument()` method can be placed on the output of `query*` functions or macros. You can then call
@@ -19,17 +22,39 @@ use sqlx::{
     FromRow, IntoArguments, PgConnection, Postgres,
 };
 use tokio::time::Instant;
+use tracing::Instrument as _;
 
 use crate::{
     connection::{Connection, ConnectionTags, DbMarker},
     connection_pool::ConnectionPool,
     error::{DalError, DalRequestError, DalResult},
-    metrics::REQUEST_METRICS,
+    metrics::{NOT_TAGGED_REQUESTER, REQUEST_METRICS},
     utils::InternalMarker,
 };
 
 type ThreadSafeDebug<'a> = dyn fmt::Debug + Send + Sync + 'a;
 
+/// `tracing` target for finished slow queries. Events for this target contain the query name, its call site,
+/// the requesting component (if the connection is tagged), latency and (sanitized) query arguments as structured fields.
+pub const SLOW_QUERIES_LOG_TARGET: &str = "zksync_db_connection::slow_queries";
+
+/// Maximum logged length of a single query argument. Longer arguments (e.g., bytecodes or large lists) are truncated.
+const MAX_LOGGED_ARG_LEN: usize = 256;
+
+fn sanitize_arg(value: &ThreadSafeDebug<'_>) -> String {
+    let mut value = format!("{value:?}");
+    if value.len() > MAX_LOGGED_ARG_LEN {
+        let original_len = value.len();
+        let mut end = MAX_LOGGED_ARG_LEN;
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        value.truncate(end);
+        value.push_str(&format!("... ({original_len} bytes total)"));
+    }
+    value
+}
+
 /// Logged arguments for an SQL query.
 #[derive(Debug, Clone, Default)]
 struct QueryArgs<'a> {
@@ -40,7 +65,7 @@ impl QueryArgs<'_> {
     fn to_owned(&self) -> Vec<(&'static str, String)> {
         self.inner
             .iter()
-            .map(|(name, value)| (*name, format!("{value:?}")))
+            .map(|(name, value)| (*name, sanitize_arg(*value)))
             .collect()
     }
 }
@@ -52,7 +77,7 @@ impl fmt::Display for QueryArgs<'_> {
         } else {
             formatter.write_str("(")?;
             for (i, (name, value)) in self.inner.iter().enumerate() {
-                write!(formatter, "{name}={value:?}")?;
+                write!(formatter, "{name}={}", sanitize_arg(*value))?;
                 if i + 1 < self.inner.len() {
                     formatter.write_str(", ")?;
                 }
@@ -219,6 +244,23 @@ impl<'a> InstrumentedData<'a> {
         self,
         connection_tags: Option<&ConnectionTags>,
         query_future: impl Future<Output = Result<R, sqlx::Error>>,
+    ) -> DalResult<R> {
+        // Only create spans for traced queries; otherwise, each query would start a separate trace.
+        let span = if zksync_vlog::opentelemetry::is_current_span_traced() {
+            let requester = connection_tags.map_or(NOT_TAGGED_REQUESTER, |tags| tags.requester);
+            tracing::info_span!("dal_query", query = self.name, requester)
+        } else {
+            tracing::Span::none()
+        };
+        self.fetch_inner(connection_tags, query_future)
+            .instrument(span)
+            .await
+    }
+
+    async fn fetch_inner<R>(
+        self,
+        connection_tags: Option<&ConnectionTags>,
+        query_future: impl Future<Output = Result<R, sqlx::Error>>,
     ) -> DalResult<R> {
         let Self {
             name,
@@ -266,7 +308,15 @@ impl<'a> InstrumentedData<'a> {
             );
             REQUEST_METRICS.request_error[&name].inc();
         } else if is_slow {
+            let requester = connection_tags.map_or(NOT_TAGGED_REQUESTER, |tags| tags.requester);
+            REQUEST_METRICS.request_slow_by_requester[&(name, requester)].inc();
             tracing::info!(
+                target: SLOW_QUERIES_LOG_TARGET,
+                query = name,
+                requester,
+                location = %format_args!("{}:{}", location.file(), location.line()),
+                elapsed_ms = elapsed.as_millis() as u64,
+                args = %args,
                 "Slow query {name}{args} called at {file}:{line} [{connection_tags_display}] has finished after {elapsed:?}",
                 file = location.file(),
                 line = location.line()
@@ -495,6 +545,25 @@ mod tests {
     use super::*;
     use crate::{connection_pool::ConnectionPool, utils::InternalMarker};
 
+    #[test]
+    fn sanitizing_query_args() {
+        let short_arg: &ThreadSafeDebug = &L2BlockNumber(1);
+        let long_arg: &ThreadSafeDebug = &vec![0xff_u8; 1_000];
+        let args = QueryArgs {
+            inner: vec![("l2_block", short_arg), ("bytecode", long_arg)],
+        };
+
+        let owned_args = args.to_owned();
+        assert_eq!(owned_args[0], ("l2_block", "L2BlockNumber(1)".to_owned()));
+        let (_, bytecode) = &owned_args[1];
+        assert!(bytecode.len() < 300, "{bytecode}");
+        assert!(bytecode.ends_with("bytes total)"), "{bytecode}");
+
+        let args_display = args.to_string();
+        assert!(args_display.starts_with("(l2_block=L2BlockNumber(1), bytecode=[255, 255"));
+        assert!(args_display.len() < 300, "{args_display}");
+    }
+
     #[tokio::test]
     async fn instrumenting_erroneous_query() {
         let pool = ConnectionPool::<InternalMarker>::test_pool().await;
@@ -15,11 +15,17 @@ pub(crate) struct RequestMetrics {
     /// Counter of slow DB requests.
     #[metrics(labels = ["method"])]
     pub request_slow: LabeledFamily<&'static str, Counter>,
+    /// Counter of slow DB requests, tagged with the component that has requested the connection.
+    #[metrics(labels = ["method", "requester"])]
+    pub request_slow_by_requester: LabeledFamily<(&'static str, &'static str), Counter, 2>,
     /// Counter of errored DB requests.
     #[metrics(labels = ["method"])]
     pub request_error: LabeledFamily<&'static str, Counter>,
 }
 
+/// Requester label used for connections without tags.
+pub(crate) const NOT_TAGGED_REQUESTER: &str = "not_tagged";
+
 #[vise::register]
 pub(crate) static REQUEST_METRICS: vise::Global<RequestMetrics> = vise::Global::new();
 
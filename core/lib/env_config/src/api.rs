@@ -1,7 +1,8 @@
 use anyhow::Context as _;
 use zksync_config::configs::{
     api::{
-        ContractVerificationApiConfig, HealthCheckConfig, MerkleTreeApiConfig, Web3JsonRpcConfig,
+        ContractVerificationApiConfig, EtherscanApiConfig, HealthCheckConfig, MerkleTreeApiConfig,
+        Web3JsonRpcConfig,
     },
     ApiConfig, PrometheusConfig,
 };
@@ -15,6 +16,7 @@ impl FromEnv for ApiConfig {
             prometheus: PrometheusConfig::from_env().context("PrometheusConfig")?,
             healthcheck: HealthCheckConfig::from_env().context("HealthCheckConfig")?,
             merkle_tree: MerkleTreeApiConfig::from_env().context("MerkleTreeApiConfig")?,
+            etherscan: EtherscanApiConfig::from_env().context("EtherscanApiConfig")?,
         })
     }
 }
@@ -44,6 +46,12 @@ impl FromEnv for MerkleTreeApiConfig {
     }
 }
 
+impl FromEnv for EtherscanApiConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("etherscan_api", "API_ETHERSCAN_")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::{NonZeroU32, NonZeroUsize};
@@ -72,11 +80,17 @@ mod tests {
                 estimate_gas_optimize_search: false,
                 max_tx_size: 1000000,
                 vm_execution_cache_misses_limit: None,
+                vm_execution_max_cycles: Some(100_000_000),
+                vm_execution_timeout_ms: Some(5_000),
                 vm_concurrency_limit: Some(512),
+                call_vm_concurrency_limit: Some(256),
+                estimate_vm_concurrency_limit: None,
+                trace_vm_concurrency_limit: Some(16),
                 factory_deps_cache_size_mb: Some(128),
                 initial_writes_cache_size_mb: Some(32),
                 latest_values_cache_size_mb: Some(256),
                 latest_values_max_block_lag: Some(NonZeroU32::new(50).unwrap()),
+                block_state_cache_size_mb: Some(64),
                 fee_history_limit: Some(100),
                 max_batch_request_size: Some(200),
                 max_response_body_size_mb: Some(10),
@@ -107,8 +121,13 @@ mod tests {
                 port: 8081,
                 slow_time_limit_ms: Some(250),
                 hard_time_limit_ms: Some(2_000),
+                expose_config: true,
             },
             merkle_tree: MerkleTreeApiConfig { port: 8082 },
+            etherscan: EtherscanApiConfig {
+                port: 8083,
+                max_page_size: 1000,
+            },
         }
     }
 
@@ -133,11 +152,16 @@ mod tests {
             API_WEB3_JSON_RPC_ESTIMATE_GAS_SCALE_FACTOR=1.0
             API_WEB3_JSON_RPC_ESTIMATE_GAS_ACCEPTABLE_OVERESTIMATION=1000
             API_WEB3_JSON_RPC_MAX_TX_SIZE=1000000
+            API_WEB3_JSON_RPC_VM_EXECUTION_MAX_CYCLES=100000000
+            API_WEB3_JSON_RPC_VM_EXECUTION_TIMEOUT_MS=5000
             API_WEB3_JSON_RPC_VM_CONCURRENCY_LIMIT=512
+            API_WEB3_JSON_RPC_CALL_VM_CONCURRENCY_LIMIT=256
+            API_WEB3_JSON_RPC_TRACE_VM_CONCURRENCY_LIMIT=16
             API_WEB3_JSON_RPC_FACTORY_DEPS_CACHE_SIZE_MB=128
             API_WEB3_JSON_RPC_INITIAL_WRITES_CACHE_SIZE_MB=32
             API_WEB3_JSON_RPC_LATEST_VALUES_CACHE_SIZE_MB=256
             API_WEB3_JSON_RPC_LATEST_VALUES_MAX_BLOCK_LAG=50
+            API_WEB3_JSON_RPC_BLOCK_STATE_CACHE_SIZE_MB=64
             API_WEB3_JSON_RPC_FEE_HISTORY_LIMIT=100
             API_WEB3_JSON_RPC_MAX_BATCH_REQUEST_SIZE=200
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
@@ -153,7 +177,10 @@ mod tests {
             API_HEALTHCHECK_PORT=8081
             API_HEALTHCHECK_SLOW_TIME_LIMIT_MS=250
             API_HEALTHCHECK_HARD_TIME_LIMIT_MS=2000
+            API_HEALTHCHECK_EXPOSE_CONFIG=true
             API_MERKLE_TREE_PORT=8082
+            API_ETHERSCAN_PORT=8083
+            API_ETHERSCAN_MAX_PAGE_SIZE=1000
         "#;
         lock.set_env(config);
 
@@ -37,7 +37,7 @@ impl FromEnv for MempoolConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::{commitment::L1BatchCommitmentMode, L2ChainId};
-    use zksync_config::configs::chain::FeeModelVersion;
+    use zksync_config::configs::chain::{FeeModelVersion, MempoolOrderingPolicy};
 
     use super::*;
     use crate::test_utils::{addr, hash, EnvMutex};
@@ -106,6 +106,12 @@ mod tests {
             l1_batch_commit_data_generator_mode,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            l2_base_fee_target_utilization: Some(0.5),
+            l2_base_fee_max_change_rate: None,
+            l2_base_fee_max_multiplier: Some(5.0),
+            forced_inclusion_deadline_batches: Some(3),
+            max_l2_tx_size_bytes: Some(1_000_000),
+            max_l2_tx_calldata_bytes: None,
         }
     }
 
@@ -139,6 +145,10 @@ mod tests {
             CHAIN_STATE_KEEPER_BOOTLOADER_HASH=0x010007ede999d096c84553fb514d3d6ca76fbf39789dda76bfeda9f3ae06236e
             CHAIN_STATE_KEEPER_DEFAULT_AA_HASH=0x0100055b041eb28aff6e3a6e0f37c31fd053fc9ef142683b05e5f0aee6934066
             CHAIN_STATE_KEEPER_PROTECTIVE_READS_PERSISTENCE_ENABLED=true
+            CHAIN_STATE_KEEPER_L2_BASE_FEE_TARGET_UTILIZATION="0.5"
+            CHAIN_STATE_KEEPER_L2_BASE_FEE_MAX_MULTIPLIER="5.0"
+            CHAIN_STATE_KEEPER_FORCED_INCLUSION_DEADLINE_BATCHES="3"
+            CHAIN_STATE_KEEPER_MAX_L2_TX_SIZE_BYTES="1000000"
             CHAIN_STATE_KEEPER_L1_BATCH_COMMIT_DATA_GENERATOR_MODE="{l1_batch_commit_data_generator_mode}"
         "#
         )
@@ -172,6 +182,9 @@ mod tests {
             stuck_tx_timeout: 10,
             remove_stuck_txs: true,
             delay_interval: 100,
+            admission_dry_run_concurrency: Some(8),
+            admission_reject_halted_txs: true,
+            ordering_policy: MempoolOrderingPolicy::Fifo,
         }
     }
 
@@ -185,6 +198,9 @@ mod tests {
             CHAIN_MEMPOOL_REMOVE_STUCK_TXS="true"
             CHAIN_MEMPOOL_DELAY_INTERVAL="100"
             CHAIN_MEMPOOL_CAPACITY="1000000"
+            CHAIN_MEMPOOL_ADMISSION_DRY_RUN_CONCURRENCY="8"
+            CHAIN_MEMPOOL_ADMISSION_REJECT_HALTED_TXS="true"
+            CHAIN_MEMPOOL_ORDERING_POLICY="fifo"
         "#;
         lock.set_env(config);
 
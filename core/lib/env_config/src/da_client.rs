@@ -113,6 +113,8 @@ mod tests {
             },
             max_retries,
             local_mirror_path: None,
+            local_mirror_max_size_mb: None,
+            replication: None,
         })
     }
 
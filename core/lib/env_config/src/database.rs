@@ -86,6 +86,10 @@ mod tests {
             DATABASE_MERKLE_TREE_MEMTABLE_CAPACITY_MB=512
             DATABASE_MERKLE_TREE_STALLED_WRITES_TIMEOUT_SEC=60
             DATABASE_MERKLE_TREE_MAX_L1_BATCHES_PER_ITER=50
+            DATABASE_MERKLE_TREE_RETAINED_L1_BATCHES=10000
+            DATABASE_MERKLE_TREE_MAINTENANCE_WINDOW_START_HOUR=22
+            DATABASE_MERKLE_TREE_MAINTENANCE_WINDOW_END_HOUR=4
+            DATABASE_MERKLE_TREE_MAINTENANCE_COMPACTION_MIN_PRUNED_L1_BATCHES=500
             DATABASE_EXPERIMENTAL_STATE_KEEPER_DB_BLOCK_CACHE_CAPACITY_MB=64
             DATABASE_EXPERIMENTAL_STATE_KEEPER_DB_MAX_OPEN_FILES=100
             DATABASE_EXPERIMENTAL_MERKLE_TREE_REPAIR_STALE_KEYS=true
@@ -100,6 +104,18 @@ mod tests {
         assert_eq!(db_config.merkle_tree.max_l1_batches_per_iter, 50);
         assert_eq!(db_config.merkle_tree.memtable_capacity_mb, 512);
         assert_eq!(db_config.merkle_tree.stalled_writes_timeout_sec, 60);
+        assert_eq!(db_config.merkle_tree.retained_l1_batches, Some(10_000));
+        assert_eq!(
+            db_config.merkle_tree.maintenance_window_start_hour,
+            Some(22)
+        );
+        assert_eq!(db_config.merkle_tree.maintenance_window_end_hour, Some(4));
+        assert_eq!(
+            db_config
+                .merkle_tree
+                .maintenance_compaction_min_pruned_l1_batches,
+            Some(500)
+        );
         assert_eq!(
             db_config
                 .experimental
@@ -129,6 +145,10 @@ mod tests {
             "DATABASE_MERKLE_TREE_MEMTABLE_CAPACITY_MB",
             "DATABASE_MERKLE_TREE_STALLED_WRITES_TIMEOUT_SEC",
             "DATABASE_MERKLE_TREE_MAX_L1_BATCHES_PER_ITER",
+            "DATABASE_MERKLE_TREE_RETAINED_L1_BATCHES",
+            "DATABASE_MERKLE_TREE_MAINTENANCE_WINDOW_START_HOUR",
+            "DATABASE_MERKLE_TREE_MAINTENANCE_WINDOW_END_HOUR",
+            "DATABASE_MERKLE_TREE_MAINTENANCE_COMPACTION_MIN_PRUNED_L1_BATCHES",
         ]);
 
         let db_config = DBConfig::from_env().unwrap();
@@ -140,6 +160,8 @@ mod tests {
         assert_eq!(db_config.merkle_tree.block_cache_size_mb, 128);
         assert_eq!(db_config.merkle_tree.memtable_capacity_mb, 256);
         assert_eq!(db_config.merkle_tree.stalled_writes_timeout_sec, 30);
+        assert_eq!(db_config.merkle_tree.retained_l1_batches, None);
+        assert_eq!(db_config.merkle_tree.maintenance_window_start_hour, None);
         assert_eq!(
             db_config
                 .experimental
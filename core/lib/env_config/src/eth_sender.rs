@@ -42,7 +42,7 @@ impl FromEnv for GasAdjusterConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::pubdata_da::PubdataSendingMode;
-    use zksync_config::configs::eth_sender::ProofSendingMode;
+    use zksync_config::configs::eth_sender::{L1FeeMarket, ProofSendingMode};
 
     use super::*;
     use crate::test_utils::{hash, EnvMutex};
@@ -74,6 +74,7 @@ mod tests {
                     tx_aggregation_only_prove_and_execute: false,
                     tx_aggregation_paused: false,
                     time_in_mempool_in_l1_blocks_cap: 2000,
+                    wait_for_batch_verification: false,
                 }),
                 gas_adjuster: Some(GasAdjusterConfig {
                     default_priority_fee_per_gas: 20000000000,
@@ -89,6 +90,7 @@ mod tests {
                     internal_pubdata_pricing_multiplier: 1.0,
                     max_blob_base_fee: None,
                     settlement_mode: Default::default(),
+                    l1_fee_market: L1FeeMarket::Eip1559,
                 }),
                 watcher: Some(EthWatchConfig {
                     confirmations_for_eth_event: Some(0),
@@ -123,6 +125,7 @@ mod tests {
             ETH_SENDER_GAS_ADJUSTER_MAX_L1_GAS_PRICE="100000000"
             ETH_SENDER_GAS_ADJUSTER_MAX_BLOB_BASE_FEE_SAMPLES="10"
             ETH_SENDER_GAS_ADJUSTER_INTERNAL_PUBDATA_PRICING_MULTIPLIER="1.0"
+            ETH_SENDER_GAS_ADJUSTER_L1_FEE_MARKET="eip1559"
             ETH_SENDER_WAIT_FOR_PROOFS="false"
             ETH_SENDER_SENDER_AGGREGATED_PROOF_SIZES="1,5"
             ETH_SENDER_SENDER_MAX_AGGREGATED_BLOCKS_TO_COMMIT="3"
@@ -0,0 +1,39 @@
+use zksync_config::configs::ExternalBlockBuilderConfig;
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for ExternalBlockBuilderConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("external_block_builder", "EXTERNAL_BLOCK_BUILDER_")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::EnvMutex;
+
+    static MUTEX: EnvMutex = EnvMutex::new();
+
+    fn expected_config() -> ExternalBlockBuilderConfig {
+        ExternalBlockBuilderConfig {
+            http_port: 3330,
+            auth_token: "secret".to_owned(),
+            proposal_timeout_ms: 500,
+            max_transactions_per_proposal: 1_000,
+        }
+    }
+
+    #[test]
+    fn from_env() {
+        let config = r#"
+            EXTERNAL_BLOCK_BUILDER_HTTP_PORT="3330"
+            EXTERNAL_BLOCK_BUILDER_AUTH_TOKEN="secret"
+            EXTERNAL_BLOCK_BUILDER_PROPOSAL_TIMEOUT_MS="500"
+        "#;
+        let mut lock = MUTEX.lock();
+        lock.set_env(config);
+        let actual = ExternalBlockBuilderConfig::from_env().unwrap();
+        assert_eq!(actual, expected_config());
+    }
+}
@@ -79,6 +79,7 @@ impl FromEnv for GenesisConfig {
                 .context("Fee account required for genesis")?,
             dummy_verifier: false,
             l1_batch_commit_data_generator_mode: state_keeper.l1_batch_commit_data_generator_mode,
+            custom_system_contracts: vec![],
         })
     }
 }
@@ -23,6 +23,7 @@ mod utils;
 
 mod base_token_adjuster;
 mod da_dispatcher;
+mod external_block_builder;
 mod external_price_api_client;
 mod external_proof_integration_api;
 mod genesis;
@@ -57,6 +57,8 @@ mod tests {
             },
             max_retries: 5,
             local_mirror_path: Some("/var/cache".to_owned()),
+            local_mirror_max_size_mb: Some(10_240),
+            replication: None,
         }
     }
 
@@ -69,6 +71,7 @@ mod tests {
             OBJECT_STORE_GCS_CREDENTIAL_FILE_PATH="/path/to/credentials.json"
             OBJECT_STORE_MAX_RETRIES="5"
             OBJECT_STORE_LOCAL_MIRROR_PATH="/var/cache"
+            OBJECT_STORE_LOCAL_MIRROR_MAX_SIZE_MB="10240"
         "#;
         lock.set_env(config);
         let actual = ObjectStoreConfig::from_env().unwrap();
@@ -120,6 +123,7 @@ mod tests {
             PROVER_OBJECT_STORE_GCS_CREDENTIAL_FILE_PATH="/path/to/credentials.json"
             PROVER_OBJECT_STORE_MAX_RETRIES="5"
             PROVER_OBJECT_STORE_LOCAL_MIRROR_PATH="/var/cache"
+            PROVER_OBJECT_STORE_LOCAL_MIRROR_MAX_SIZE_MB="10240"
         "#;
         lock.set_env(config);
         let actual = ProverObjectStoreConfig::from_env().unwrap().0;
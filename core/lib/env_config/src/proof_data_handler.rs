@@ -6,6 +6,7 @@ impl FromEnv for ProofDataHandlerConfig {
     fn from_env() -> anyhow::Result<Self> {
         Ok(Self {
             tee_config: envy_load("proof_data_handler.tee", "PROOF_DATA_HANDLER_")?,
+            marketplace_config: envy_load("proof_data_handler.marketplace", "PROOF_DATA_HANDLER_")?,
             ..envy_load("proof_data_handler", "PROOF_DATA_HANDLER_")?
         })
     }
@@ -13,8 +14,8 @@ impl FromEnv for ProofDataHandlerConfig {
 
 #[cfg(test)]
 mod tests {
-    use zksync_basic_types::L1BatchNumber;
-    use zksync_config::configs::TeeConfig;
+    use zksync_basic_types::{L1BatchNumber, H256};
+    use zksync_config::configs::{ProofMarketplaceConfig, TeeConfig};
 
     use super::*;
     use crate::test_utils::EnvMutex;
@@ -30,6 +31,12 @@ mod tests {
                 first_tee_processed_batch: L1BatchNumber(1337),
                 tee_proof_generation_timeout_in_secs: 600,
             },
+            marketplace_config: ProofMarketplaceConfig {
+                marketplace_support: true,
+                marketplace_api_key_hashes: vec![H256::repeat_byte(0x11), H256::repeat_byte(0x22)],
+                marketplace_reward_token: Some("ETH".to_owned()),
+                marketplace_reward_per_batch: Some("0.01".to_owned()),
+            },
         }
     }
 
@@ -41,6 +48,10 @@ mod tests {
             PROOF_DATA_HANDLER_TEE_SUPPORT="true"
             PROOF_DATA_HANDLER_FIRST_TEE_PROCESSED_BATCH="1337"
             PROOF_DATA_HANDLER_TEE_PROOF_GENERATION_TIMEOUT_IN_SECS="600"
+            PROOF_DATA_HANDLER_MARKETPLACE_SUPPORT="true"
+            PROOF_DATA_HANDLER_MARKETPLACE_API_KEY_HASHES="0x1111111111111111111111111111111111111111111111111111111111111111,0x2222222222222222222222222222222222222222222222222222222222222222"
+            PROOF_DATA_HANDLER_MARKETPLACE_REWARD_TOKEN="ETH"
+            PROOF_DATA_HANDLER_MARKETPLACE_REWARD_PER_BATCH="0.01"
         "#;
         let mut lock = MUTEX.lock();
         lock.set_env(config);
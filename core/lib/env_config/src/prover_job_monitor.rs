@@ -31,6 +31,8 @@ mod tests {
             prover_queue_reporter_run_interval_ms: 10000,
             witness_generator_queue_reporter_run_interval_ms: 10000,
             witness_job_queuer_run_interval_ms: 10000,
+            artifacts_gc_run_interval_ms: 3600000,
+            artifacts_gc_retention_ms: None,
             http_port: 3074,
         }
     }
@@ -49,6 +51,8 @@ mod tests {
         config.prover_queue_reporter_run_interval_ms += 1;
         config.witness_generator_queue_reporter_run_interval_ms += 1;
         config.witness_job_queuer_run_interval_ms += 1;
+        config.artifacts_gc_run_interval_ms += 1;
+        config.artifacts_gc_retention_ms = Some(604800000);
         config
     }
 
@@ -82,6 +86,8 @@ mod tests {
             PROVER_JOB_MONITOR_PROVER_QUEUE_REPORTER_RUN_INTERVAL_MS=10001
             PROVER_JOB_MONITOR_WITNESS_GENERATOR_QUEUE_REPORTER_RUN_INTERVAL_MS=10001
             PROVER_JOB_MONITOR_WITNESS_JOB_QUEUER_RUN_INTERVAL_MS=10001
+            PROVER_JOB_MONITOR_ARTIFACTS_GC_RUN_INTERVAL_MS=3600001
+            PROVER_JOB_MONITOR_ARTIFACTS_GC_RETENTION_MS=604800000
             PROVER_JOB_MONITOR_HTTP_PORT=3074
         "#;
         let mut lock = MUTEX.lock();
@@ -1,5 +1,6 @@
 use zksync_config::configs::{
-    BasicWitnessInputProducerConfig, ExperimentalVmConfig, ProtectiveReadsWriterConfig,
+    BasicWitnessInputProducerConfig, BatchVerifierConfig, ExperimentalVmConfig,
+    ProtectiveReadsWriterConfig,
 };
 
 use crate::{envy_load, FromEnv};
@@ -16,6 +17,12 @@ impl FromEnv for BasicWitnessInputProducerConfig {
     }
 }
 
+impl FromEnv for BatchVerifierConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("vm_runner.batch_verifier", "VM_RUNNER_BATCH_VERIFIER_")
+    }
+}
+
 impl FromEnv for ExperimentalVmConfig {
     fn from_env() -> anyhow::Result<Self> {
         Ok(Self {
@@ -50,6 +57,21 @@ mod tests {
         assert_eq!(config.first_processed_batch, L1BatchNumber(123));
     }
 
+    #[test]
+    fn batch_verifier_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            VM_RUNNER_BATCH_VERIFIER_WINDOW_SIZE=3
+            VM_RUNNER_BATCH_VERIFIER_FIRST_PROCESSED_BATCH=10
+        "#;
+        lock.set_env(config);
+
+        let config = BatchVerifierConfig::from_env().unwrap();
+        assert_eq!(config.db_path, "./db/batch_verifier");
+        assert_eq!(config.window_size, 3);
+        assert_eq!(config.first_processed_batch, L1BatchNumber(10));
+    }
+
     #[test]
     fn experimental_vm_config_from_env() {
         let mut lock = MUTEX.lock();
@@ -350,11 +350,16 @@ where
         // Per specification, the values should always be provided, and must be 0 for blocks
         // prior to EIP-4844.
         // https://ethereum.github.io/execution-apis/api-documentation/
-        if fee_history.base_fee_per_blob_gas.len() != chunk_size + 1 {
+        // Some L1s without blob support omit the list altogether; we treat this as zero blob fees.
+        let mut base_fee_per_blob_gas = fee_history.base_fee_per_blob_gas;
+        if base_fee_per_blob_gas.is_empty() {
+            base_fee_per_blob_gas = vec![U256::zero(); chunk_size + 1];
+        }
+        if base_fee_per_blob_gas.len() != chunk_size + 1 {
             let message = format!(
                 "unexpected `base_fee_per_blob_gas.len()`, expected: {}, got {}",
                 chunk_size + 1,
-                fee_history.base_fee_per_blob_gas.len()
+                base_fee_per_blob_gas.len()
             );
             return Err(EnrichedClientError::custom(message, "l1_fee_history")
                 .with_arg("chunk_size", &chunk_size)
@@ -366,7 +371,7 @@ where
         for (base, blob) in fee_history
             .base_fee_per_gas
             .into_iter()
-            .zip(fee_history.base_fee_per_blob_gas)
+            .zip(base_fee_per_blob_gas)
             .take(chunk_size)
         {
             let fees = BaseFees {
@@ -8,7 +8,9 @@ use url::Url;
 use zksync_config::configs::ExternalPriceApiClientConfig;
 use zksync_types::{base_token_ratio::BaseTokenAPIRatio, Address};
 
-use crate::{address_to_string, utils::get_fraction, PriceAPIClient};
+use crate::{
+    address_to_string, token_price::TokenPriceProvider, utils::get_fraction, PriceAPIClient,
+};
 
 #[derive(Debug)]
 pub struct CoinGeckoPriceAPIClient {
@@ -19,6 +21,7 @@ pub struct CoinGeckoPriceAPIClient {
 const DEFAULT_COINGECKO_API_URL: &str = "https://pro-api.coingecko.com";
 const COINGECKO_AUTH_HEADER: &str = "x-cg-pro-api-key";
 const ETH_ID: &str = "eth";
+const USD_ID: &str = "usd";
 
 impl CoinGeckoPriceAPIClient {
     pub fn new(config: ExternalPriceApiClientConfig) -> Self {
@@ -49,15 +52,20 @@ impl CoinGeckoPriceAPIClient {
         }
     }
 
-    /// returns token price in ETH by token address. Returned value is X such that 1 TOKEN = X ETH.
-    async fn get_token_price_by_address(&self, address: Address) -> anyhow::Result<f64> {
+    /// returns token price in `currency` by token address. Returned value is X such that 1 TOKEN = X `currency`,
+    /// or `None` if CoinGecko doesn't know the token.
+    async fn get_token_price_by_address(
+        &self,
+        address: Address,
+        currency: &str,
+    ) -> anyhow::Result<Option<f64>> {
         let address_str = address_to_string(&address);
         let price_url = self
             .base_url
             .join(
                 format!(
                     "/api/v3/simple/token_price/ethereum?contract_addresses={}&vs_currencies={}",
-                    address_str, ETH_ID
+                    address_str, currency
                 )
                 .as_str(),
             )
@@ -74,20 +82,22 @@ impl CoinGeckoPriceAPIClient {
         }
 
         let cg_response = response.json::<CoinGeckoPriceResponse>().await?;
-        match cg_response.get_price(&address_str, &ETH_ID.to_string()) {
-            Some(&price) => Ok(price),
-            None => Err(anyhow::anyhow!(
-                "Price not found for token: {}",
-                address_str
-            )),
-        }
+        Ok(cg_response.get_price(&address_str, currency).copied())
     }
 }
 
 #[async_trait]
 impl PriceAPIClient for CoinGeckoPriceAPIClient {
     async fn fetch_ratio(&self, token_address: Address) -> anyhow::Result<BaseTokenAPIRatio> {
-        let base_token_in_eth = self.get_token_price_by_address(token_address).await?;
+        let base_token_in_eth = self
+            .get_token_price_by_address(token_address, ETH_ID)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Price not found for token: {}",
+                    address_to_string(&token_address)
+                )
+            })?;
         let (num_in_eth, denom_in_eth) = get_fraction(base_token_in_eth)?;
         // take reciprocal of price as returned price is ETH/BaseToken and BaseToken/ETH is needed
         let (num_in_base, denom_in_base) = (denom_in_eth, num_in_eth);
@@ -100,6 +110,17 @@ impl PriceAPIClient for CoinGeckoPriceAPIClient {
     }
 }
 
+#[async_trait]
+impl TokenPriceProvider for CoinGeckoPriceAPIClient {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch_price_usd(&self, token_address: Address) -> anyhow::Result<Option<f64>> {
+        self.get_token_price_by_address(token_address, USD_ID).await
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CoinGeckoPriceResponse {
     #[serde(flatten)]
@@ -107,7 +128,7 @@ struct CoinGeckoPriceResponse {
 }
 
 impl CoinGeckoPriceResponse {
-    fn get_price(&self, address: &String, currency: &String) -> Option<&f64> {
+    fn get_price(&self, address: &str, currency: &str) -> Option<&f64> {
         self.prices
             .get(address)
             .and_then(|price| price.get(currency))
@@ -269,4 +290,21 @@ mod test {
             error_string
         )
     }
+
+    #[tokio::test]
+    async fn unknown_token_has_no_usd_price() {
+        let server = MockServer::start();
+        let address = Address::repeat_byte(0xde);
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/api/v3/simple/token_price/ethereum")
+                .query_param("contract_addresses", address_to_string(&address))
+                .query_param("vs_currencies", USD_ID);
+            then.status(200).body("{}");
+        });
+
+        let client = CoinGeckoPriceAPIClient::new(get_config(server.url(""), None));
+        let price = client.fetch_price_usd(address).await.unwrap();
+        assert_eq!(price, None);
+    }
 }
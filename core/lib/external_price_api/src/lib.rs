@@ -3,6 +3,7 @@ pub mod coingecko_api;
 pub mod forced_price_client;
 #[cfg(test)]
 mod tests;
+pub mod token_price;
 mod utils;
 
 use std::fmt;
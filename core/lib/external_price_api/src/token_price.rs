@@ -0,0 +1,562 @@
+//! USD token prices served by the `zks_getTokenPrice` API method.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use zksync_config::configs::{
+    token_price::{TokenPriceConfig, TokenPriceProviderConfig, UniswapV3TwapConfig},
+    ExternalPriceApiClientConfig,
+};
+use zksync_eth_client::{
+    clients::{DynClient, L1},
+    CallFunctionArgs, EthInterface,
+};
+use zksync_types::{api, ethabi, web3, Address, U256};
+
+use crate::coingecko_api::CoinGeckoPriceAPIClient;
+
+/// Upper bound on the number of tokens with cached prices.
+const MAX_CACHED_TOKENS: usize = 10_000;
+
+/// Source of USD token prices.
+#[async_trait]
+pub trait TokenPriceProvider: Sync + Send + fmt::Debug + 'static {
+    /// Name of the provider reported in API responses.
+    fn name(&self) -> &'static str;
+
+    /// Returns the price of a single unit of the token with the specified L1 address in USD,
+    /// or `None` if the provider doesn't know about the token.
+    async fn fetch_price_usd(&self, token_address: Address) -> anyhow::Result<Option<f64>>;
+}
+
+/// Provider returning prices from the configuration.
+#[derive(Debug)]
+pub struct StaticTokenPriceProvider {
+    prices: HashMap<Address, f64>,
+}
+
+impl StaticTokenPriceProvider {
+    pub fn new(prices: impl IntoIterator<Item = (Address, f64)>) -> Self {
+        Self {
+            prices: prices.into_iter().collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenPriceProvider for StaticTokenPriceProvider {
+    fn name(&self) -> &'static str {
+        "static"
+    }
+
+    async fn fetch_price_usd(&self, token_address: Address) -> anyhow::Result<Option<f64>> {
+        Ok(self.prices.get(&token_address).copied())
+    }
+}
+
+const UNISWAP_V3_POOL_ABI: &str = r#"[
+  {"type":"function","name":"observe","stateMutability":"view",
+   "inputs":[{"name":"secondsAgos","type":"uint32[]"}],
+   "outputs":[{"name":"tickCumulatives","type":"int56[]"},{"name":"secondsPerLiquidityCumulativeX128s","type":"uint160[]"}]},
+  {"type":"function","name":"token0","stateMutability":"view","inputs":[],"outputs":[{"name":"","type":"address"}]},
+  {"type":"function","name":"token1","stateMutability":"view","inputs":[],"outputs":[{"name":"","type":"address"}]}
+]"#;
+
+const ERC20_DECIMALS_ABI: &str = r#"[
+  {"type":"function","name":"decimals","stateMutability":"view","inputs":[],"outputs":[{"name":"","type":"uint8"}]}
+]"#;
+
+/// Provider computing time-weighted average prices from Uniswap V3 pools on L1. The other token in each pool
+/// is assumed to be pegged to USD.
+#[derive(Debug)]
+pub struct UniswapV3TwapPriceProvider {
+    client: Box<DynClient<L1>>,
+    pools: HashMap<Address, Address>,
+    window: Duration,
+    pool_abi: ethabi::Contract,
+    erc20_abi: ethabi::Contract,
+}
+
+impl UniswapV3TwapPriceProvider {
+    pub fn new(client: Box<DynClient<L1>>, config: &UniswapV3TwapConfig) -> Self {
+        Self {
+            client,
+            pools: config
+                .pools
+                .iter()
+                .map(|pool| (pool.token, pool.pool))
+                .collect(),
+            window: Duration::from_secs(config.window_sec.into()),
+            pool_abi: ethabi::Contract::load(UNISWAP_V3_POOL_ABI.as_bytes())
+                .expect("invalid Uniswap V3 pool ABI"),
+            erc20_abi: ethabi::Contract::load(ERC20_DECIMALS_ABI.as_bytes())
+                .expect("invalid ERC-20 ABI"),
+        }
+    }
+
+    async fn decimals(&self, token: Address) -> anyhow::Result<i32> {
+        let decimals: U256 = CallFunctionArgs::new("decimals", ())
+            .for_contract(token, &self.erc20_abi)
+            .call(&self.client)
+            .await
+            .with_context(|| format!("failed getting decimals for token {token:?}"))?;
+        Ok(decimals.as_u32() as i32)
+    }
+
+    async fn pool_token(&self, pool: Address, name: &str) -> anyhow::Result<Address> {
+        CallFunctionArgs::new(name, ())
+            .for_contract(pool, &self.pool_abi)
+            .call(&self.client)
+            .await
+            .with_context(|| format!("failed calling `{name}` for pool {pool:?}"))
+    }
+
+    /// Returns the average tick over the TWAP window. `observe()` returns multiple values, so it cannot be called
+    /// via `CallFunctionArgs`.
+    async fn average_tick(&self, pool: Address) -> anyhow::Result<f64> {
+        let window_sec = self.window.as_secs() as u32;
+        anyhow::ensure!(window_sec > 0, "TWAP window must be positive");
+
+        let observe = self.pool_abi.function("observe")?;
+        let input = observe.encode_input(&[ethabi::Token::Array(vec![
+            ethabi::Token::Uint(window_sec.into()),
+            ethabi::Token::Uint(0.into()),
+        ])])?;
+        let request = web3::CallRequest {
+            to: Some(pool),
+            data: Some(web3::Bytes(input)),
+            ..web3::CallRequest::default()
+        };
+        let output = self
+            .client
+            .call_contract_function(request, None)
+            .await
+            .with_context(|| format!("failed calling `observe` for pool {pool:?}"))?;
+        let mut output = observe.decode_output(&output.0)?;
+        let Some(ethabi::Token::Array(tick_cumulatives)) = output.drain(..).next() else {
+            anyhow::bail!("unexpected `observe` output for pool {pool:?}");
+        };
+        let [ethabi::Token::Int(past), ethabi::Token::Int(now)] = tick_cumulatives.as_slice()
+        else {
+            anyhow::bail!("unexpected tick cumulatives for pool {pool:?}: {tick_cumulatives:?}");
+        };
+        // `int56` values are sign-extended to 256 bits, so the lower 64 bits are a valid `i64`.
+        let delta = (now.low_u64() as i64).wrapping_sub(past.low_u64() as i64);
+        Ok(delta as f64 / f64::from(window_sec))
+    }
+}
+
+#[async_trait]
+impl TokenPriceProvider for UniswapV3TwapPriceProvider {
+    fn name(&self) -> &'static str {
+        "uniswap_v3_twap"
+    }
+
+    async fn fetch_price_usd(&self, token_address: Address) -> anyhow::Result<Option<f64>> {
+        let Some(&pool) = self.pools.get(&token_address) else {
+            return Ok(None);
+        };
+        let token0 = self.pool_token(pool, "token0").await?;
+        let token1 = self.pool_token(pool, "token1").await?;
+        anyhow::ensure!(
+            token_address == token0 || token_address == token1,
+            "pool {pool:?} doesn't contain token {token_address:?}"
+        );
+        let decimals0 = self.decimals(token0).await?;
+        let decimals1 = self.decimals(token1).await?;
+        let tick = self.average_tick(pool).await?;
+
+        // Price of a whole `token0` expressed in whole `token1` units.
+        let price0 = 1.0001_f64.powf(tick) * 10_f64.powi(decimals0 - decimals1);
+        let price = if token_address == token0 {
+            price0
+        } else {
+            price0.recip()
+        };
+        anyhow::ensure!(
+            price.is_finite() && price > 0.0,
+            "invalid TWAP price for {token_address:?}: {price}"
+        );
+        Ok(Some(price))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CachedPrice {
+    price_usd: f64,
+    source: &'static str,
+    updated_at: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CacheEntry {
+    /// `None` if none of the providers knows about the token.
+    price: Option<CachedPrice>,
+    fetched_at: Instant,
+}
+
+/// Guard for an in-flight price refresh for a single token. Removes the token lock once no other requests wait for it.
+struct RefreshGuard<'a> {
+    cache: &'a TokenPriceCache,
+    token_address: Address,
+    lock: tokio::sync::OwnedMutexGuard<()>,
+}
+
+impl Drop for RefreshGuard<'_> {
+    fn drop(&mut self) {
+        let mut locks = self
+            .cache
+            .refresh_locks
+            .lock()
+            .expect("token price refresh locks are poisoned");
+        // The lock is referenced by the map and by this guard; other references belong to waiting requests.
+        let lock = tokio::sync::OwnedMutexGuard::mutex(&self.lock);
+        if locks
+            .get(&self.token_address)
+            .is_some_and(|map_lock| Arc::ptr_eq(map_lock, lock) && Arc::strong_count(lock) == 2)
+        {
+            locks.remove(&self.token_address);
+        }
+    }
+}
+
+/// Caching wrapper around a chain of [`TokenPriceProvider`]s. Providers are queried in order until one
+/// of them returns a price; if all of them fail, the last cached price is returned (marked as stale if it's too old).
+/// Tokens unknown to all providers are cached as well, so that they aren't requested from providers on each call.
+#[derive(Debug)]
+pub struct TokenPriceCache {
+    providers: Vec<Box<dyn TokenPriceProvider>>,
+    entries: RwLock<HashMap<Address, CacheEntry>>,
+    /// Per-token locks preventing concurrent requests for an uncached price from hammering providers.
+    refresh_locks: Mutex<HashMap<Address, Arc<tokio::sync::Mutex<()>>>>,
+    cache_ttl: Duration,
+    stale_after: Duration,
+}
+
+impl TokenPriceCache {
+    pub fn new(
+        providers: Vec<Box<dyn TokenPriceProvider>>,
+        cache_ttl: Duration,
+        stale_after: Duration,
+    ) -> Self {
+        Self {
+            providers,
+            entries: RwLock::default(),
+            refresh_locks: Mutex::default(),
+            cache_ttl,
+            stale_after,
+        }
+    }
+
+    /// Creates a cache with providers specified in the config. `l1_client` is required if any of the providers
+    /// reads prices from L1.
+    pub fn from_config(
+        config: &TokenPriceConfig,
+        l1_client: Option<Box<DynClient<L1>>>,
+    ) -> anyhow::Result<Self> {
+        let mut providers =
+            Vec::<Box<dyn TokenPriceProvider>>::with_capacity(config.providers.len());
+        for provider in &config.providers {
+            providers.push(match provider {
+                TokenPriceProviderConfig::CoinGecko(config) => {
+                    Box::new(CoinGeckoPriceAPIClient::new(ExternalPriceApiClientConfig {
+                        source: "coingecko".to_owned(),
+                        base_url: config.base_url.clone(),
+                        api_key: config.api_key.clone(),
+                        client_timeout_ms: config.client_timeout_ms,
+                        forced: None,
+                    }))
+                }
+                TokenPriceProviderConfig::UniswapV3Twap(config) => {
+                    let client = l1_client
+                        .clone()
+                        .context("Uniswap V3 TWAP price provider requires L1 client")?;
+                    Box::new(UniswapV3TwapPriceProvider::new(client, config))
+                }
+                TokenPriceProviderConfig::Static(config) => {
+                    Box::new(StaticTokenPriceProvider::new(
+                        config
+                            .prices
+                            .iter()
+                            .map(|price| (price.token, price.price_usd)),
+                    ))
+                }
+            });
+        }
+        Ok(Self::new(
+            providers,
+            config.cache_ttl(),
+            config.stale_after(),
+        ))
+    }
+
+    fn cached(&self, token_address: Address) -> Option<CacheEntry> {
+        self.entries
+            .read()
+            .expect("token price cache is poisoned")
+            .get(&token_address)
+            .copied()
+    }
+
+    fn insert(&self, token_address: Address, entry: CacheEntry) {
+        let mut entries = self.entries.write().expect("token price cache is poisoned");
+        if entries.len() >= MAX_CACHED_TOKENS && !entries.contains_key(&token_address) {
+            let stale_after = self.stale_after;
+            entries.retain(|_, entry| entry.fetched_at.elapsed() < stale_after);
+            if entries.len() >= MAX_CACHED_TOKENS {
+                tracing::debug!(
+                    "Token price cache is full; not caching price for {token_address:?}"
+                );
+                return;
+            }
+        }
+        entries.insert(token_address, entry);
+    }
+
+    fn to_api(&self, entry: CacheEntry) -> Option<api::TokenPrice> {
+        let price = entry.price?;
+        Some(api::TokenPrice {
+            price_usd: price.price_usd,
+            source: price.source.to_owned(),
+            updated_at: price.updated_at,
+            is_stale: entry.fetched_at.elapsed() >= self.stale_after,
+        })
+    }
+
+    async fn lock_refresh(&self, token_address: Address) -> RefreshGuard<'_> {
+        let lock = self
+            .refresh_locks
+            .lock()
+            .expect("token price refresh locks are poisoned")
+            .entry(token_address)
+            .or_default()
+            .clone();
+        RefreshGuard {
+            cache: self,
+            token_address,
+            lock: lock.lock_owned().await,
+        }
+    }
+
+    /// Returns the USD price for the token with the specified L1 address, or `None` if none of the providers
+    /// knows about the token.
+    pub async fn get_price(
+        &self,
+        token_address: Address,
+    ) -> anyhow::Result<Option<api::TokenPrice>> {
+        if let Some(entry) = self.cached(token_address) {
+            if entry.fetched_at.elapsed() < self.cache_ttl {
+                return Ok(self.to_api(entry));
+            }
+        }
+
+        let _guard = self.lock_refresh(token_address).await;
+        // The price may have been refreshed while we were waiting for the lock.
+        let cached = self.cached(token_address);
+        if let Some(entry) = cached {
+            if entry.fetched_at.elapsed() < self.cache_ttl {
+                return Ok(self.to_api(entry));
+            }
+        }
+
+        let mut last_error = None;
+        for provider in &self.providers {
+            match provider.fetch_price_usd(token_address).await {
+                Ok(Some(price_usd)) => {
+                    let updated_at = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |duration| duration.as_secs());
+                    let entry = CacheEntry {
+                        price: Some(CachedPrice {
+                            price_usd,
+                            source: provider.name(),
+                            updated_at,
+                        }),
+                        fetched_at: Instant::now(),
+                    };
+                    self.insert(token_address, entry);
+                    return Ok(self.to_api(entry));
+                }
+                Ok(None) => continue,
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed fetching price for token {token_address:?} from `{}`: {err:#}",
+                        provider.name()
+                    );
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        if let Some(err) = last_error {
+            return match cached.and_then(|entry| self.to_api(entry)) {
+                Some(price) => Ok(Some(price)),
+                None => Err(err.context(format!("no price for token {token_address:?}"))),
+            };
+        }
+        let entry = CacheEntry {
+            price: None,
+            fetched_at: Instant::now(),
+        };
+        self.insert(token_address, entry);
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockProvider {
+        calls: AtomicUsize,
+        fail: AtomicBool,
+        price: Option<f64>,
+    }
+
+    #[async_trait]
+    impl TokenPriceProvider for Arc<MockProvider> {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn fetch_price_usd(&self, _token_address: Address) -> anyhow::Result<Option<f64>> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            // Emulate a network call.
+            tokio::task::yield_now().await;
+            if self.fail.load(Ordering::Relaxed) {
+                anyhow::bail!("provider is down");
+            }
+            Ok(self.price)
+        }
+    }
+
+    fn mock_provider(price: impl Into<Option<f64>>) -> Arc<MockProvider> {
+        Arc::new(MockProvider {
+            price: price.into(),
+            ..MockProvider::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn prices_are_cached() {
+        let provider = mock_provider(2.5);
+        let cache = TokenPriceCache::new(
+            vec![Box::new(provider.clone())],
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+        let token = Address::repeat_byte(1);
+
+        for _ in 0..3 {
+            let price = cache.get_price(token).await.unwrap().unwrap();
+            assert_eq!(price.price_usd, 2.5);
+            assert_eq!(price.source, "mock");
+            assert!(!price.is_stale);
+        }
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn falling_back_to_next_provider() {
+        let provider = mock_provider(2.5);
+        provider.fail.store(true, Ordering::Relaxed);
+        let token = Address::repeat_byte(1);
+        let cache = TokenPriceCache::new(
+            vec![
+                Box::new(provider.clone()),
+                Box::new(StaticTokenPriceProvider::new([(token, 1.0)])),
+            ],
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+
+        let price = cache.get_price(token).await.unwrap().unwrap();
+        assert_eq!(price.price_usd, 1.0);
+        assert_eq!(price.source, "static");
+
+        let unknown_token = Address::repeat_byte(2);
+        cache.get_price(unknown_token).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn unknown_token() {
+        let cache = TokenPriceCache::new(
+            vec![Box::new(StaticTokenPriceProvider::new([]))],
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+        let price = cache.get_price(Address::repeat_byte(1)).await.unwrap();
+        assert_eq!(price, None);
+    }
+
+    #[tokio::test]
+    async fn unknown_tokens_are_cached() {
+        let provider = mock_provider(None);
+        let cache = TokenPriceCache::new(
+            vec![Box::new(provider.clone())],
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+        let token = Address::repeat_byte(1);
+
+        for _ in 0..3 {
+            let price = cache.get_price(token).await.unwrap();
+            assert_eq!(price, None);
+        }
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_for_token_are_deduplicated() {
+        let provider = mock_provider(2.5);
+        let cache = TokenPriceCache::new(
+            vec![Box::new(provider.clone())],
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+        let token = Address::repeat_byte(1);
+        let other_token = Address::repeat_byte(2);
+
+        let (price, same_price, other_price) = tokio::join!(
+            cache.get_price(token),
+            cache.get_price(token),
+            cache.get_price(other_token)
+        );
+        assert_eq!(price.unwrap().unwrap().price_usd, 2.5);
+        assert_eq!(same_price.unwrap().unwrap().price_usd, 2.5);
+        assert_eq!(other_price.unwrap().unwrap().price_usd, 2.5);
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 2);
+        assert!(cache.refresh_locks.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn stale_price_is_returned_if_providers_fail() {
+        let provider = mock_provider(2.5);
+        let cache = TokenPriceCache::new(
+            vec![Box::new(provider.clone())],
+            Duration::ZERO,
+            Duration::ZERO,
+        );
+        let token = Address::repeat_byte(1);
+        let price = cache.get_price(token).await.unwrap().unwrap();
+        assert_eq!(price.price_usd, 2.5);
+
+        provider.fail.store(true, Ordering::Relaxed);
+        let price = cache.get_price(token).await.unwrap().unwrap();
+        assert_eq!(price.price_usd, 2.5);
+        assert!(price.is_stale);
+        assert_eq!(provider.calls.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,9 +1,10 @@
 mod mempool_store;
+pub mod ordering;
 #[cfg(test)]
 mod tests;
 mod types;
 
 pub use crate::{
-    mempool_store::{MempoolInfo, MempoolStats, MempoolStore},
+    mempool_store::{AccountMempoolContents, MempoolInfo, MempoolStats, MempoolStore},
     types::L2TxFilter,
 };
@@ -1,11 +1,17 @@
-use std::collections::{hash_map, BTreeSet, HashMap};
+use std::{
+    collections::{hash_map, HashMap},
+    sync::Arc,
+};
 
 use zksync_types::{
     l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
-    TransactionTimeRangeConstraint,
+    TransactionTimeRangeConstraint, H256,
 };
 
-use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
+use crate::{
+    ordering::{TimestampOrdering, TxOrderingPolicy},
+    types::{AccountTransactions, L2PriorityQueue, L2TxFilter, MempoolScore},
+};
 
 #[derive(Debug)]
 pub struct MempoolInfo {
@@ -13,6 +19,15 @@ pub struct MempoolInfo {
     pub purged_accounts: Vec<Address>,
 }
 
+/// L2 transactions of a single account in the mempool, ordered by nonce.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountMempoolContents {
+    /// Transactions that can be executed right away (potentially after other pending transactions of the account).
+    pub pending: Vec<L2Tx>,
+    /// Transactions that cannot be executed until a nonce gap is filled.
+    pub queued: Vec<L2Tx>,
+}
+
 #[derive(Debug)]
 pub struct MempoolStats {
     pub l1_transaction_count: usize,
@@ -27,13 +42,14 @@ pub struct MempoolStore {
     /// Pending L2 transactions grouped by initiator address
     l2_transactions_per_account: HashMap<Address, AccountTransactions>,
     /// Global priority queue for L2 transactions. Used for scoring
-    l2_priority_queue: BTreeSet<MempoolScore>,
+    l2_priority_queue: L2PriorityQueue,
     /// Next priority operation
     next_priority_id: PriorityOpId,
     stashed_accounts: Vec<Address>,
     /// Number of L2 transactions in the mempool.
     size: u64,
     capacity: u64,
+    ordering_policy: Arc<dyn TxOrderingPolicy>,
 }
 
 impl MempoolStore {
@@ -41,14 +57,29 @@ impl MempoolStore {
         Self {
             l1_transactions: HashMap::new(),
             l2_transactions_per_account: HashMap::new(),
-            l2_priority_queue: BTreeSet::new(),
+            l2_priority_queue: L2PriorityQueue::default(),
             next_priority_id,
             stashed_accounts: vec![],
             size: 0,
             capacity,
+            ordering_policy: Arc::new(TimestampOrdering),
         }
     }
 
+    /// Sets the policy used to order L2 transactions. By default, [`TimestampOrdering`] is used.
+    pub fn with_ordering_policy(mut self, policy: Arc<dyn TxOrderingPolicy>) -> Self {
+        assert_eq!(
+            self.size, 0,
+            "ordering policy must be set on an empty mempool"
+        );
+        self.ordering_policy = policy;
+        self
+    }
+
+    pub fn ordering_policy(&self) -> &Arc<dyn TxOrderingPolicy> {
+        &self.ordering_policy
+    }
+
     /// Inserts batch of new transactions to mempool
     /// `initial_nonces` provides current committed nonce information to mempool
     /// variable is used only if account is not present in mempool yet and we have to bootstrap it
@@ -119,14 +150,17 @@ impl MempoolStore {
         initial_nonces: &HashMap<Address, Nonce>,
     ) {
         let account = transaction.initiator_account();
+        let policy = &*self.ordering_policy;
 
         let metadata = match self.l2_transactions_per_account.entry(account) {
-            hash_map::Entry::Occupied(mut txs) => txs.get_mut().insert(transaction, constraint),
+            hash_map::Entry::Occupied(mut txs) => {
+                txs.get_mut().insert(transaction, constraint, policy)
+            }
             hash_map::Entry::Vacant(entry) => {
                 let account_nonce = initial_nonces.get(&account).cloned().unwrap_or(Nonce(0));
                 entry
                     .insert(AccountTransactions::new(account_nonce))
-                    .insert(transaction, constraint)
+                    .insert(transaction, constraint, policy)
             }
         };
         if let Some(score) = metadata.previous_score {
@@ -143,11 +177,37 @@ impl MempoolStore {
     /// Returns `true` if there is a transaction in the mempool satisfying the filter.
     pub fn has_next(&self, filter: &L2TxFilter) -> bool {
         self.l1_transactions.contains_key(&self.next_priority_id)
-            || self
-                .l2_priority_queue
+            || self.next_l2_pointer(filter).is_some()
+    }
+
+    /// Returns the score of the next L2 transaction to execute. With a strict ordering policy, only the transaction
+    /// with the highest priority is considered.
+    fn next_l2_pointer(&self, filter: &L2TxFilter) -> Option<&MempoolScore> {
+        if self.ordering_policy.is_strict() {
+            self.l2_priority_queue
+                .last()
+                .filter(|el| el.matches_filter(filter))
+        } else {
+            self.l2_priority_queue
                 .iter()
                 .rfind(|el| el.matches_filter(filter))
-                .is_some()
+        }
+    }
+
+    /// Returns the ID of the next priority operation to execute.
+    pub fn next_priority_id(&self) -> PriorityOpId {
+        self.next_priority_id
+    }
+
+    /// Returns the next L1 transaction for execution from mempool, if it's present.
+    pub fn next_l1_transaction(&mut self) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        let transaction = self.l1_transactions.remove(&self.next_priority_id)?;
+        self.next_priority_id += 1;
+        // L1 transactions can't use block.timestamp in AA and hence do not need to have a constraint
+        Some((
+            transaction.into(),
+            TransactionTimeRangeConstraint::default(),
+        ))
     }
 
     /// Returns next transaction for execution from mempool
@@ -155,24 +215,15 @@ impl MempoolStore {
         &mut self,
         filter: &L2TxFilter,
     ) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
-        if let Some(transaction) = self.l1_transactions.remove(&self.next_priority_id) {
-            self.next_priority_id += 1;
-            // L1 transactions can't use block.timestamp in AA and hence do not need to have a constraint
-            return Some((
-                transaction.into(),
-                TransactionTimeRangeConstraint::default(),
-            ));
+        if let Some(transaction) = self.next_l1_transaction() {
+            return Some(transaction);
         }
 
         let mut removed = 0;
         // We want to fetch the next transaction that would match the fee requirements.
-        let tx_pointer = self
-            .l2_priority_queue
-            .iter()
-            .rfind(|el| el.matches_filter(filter))?
-            .clone();
+        let tx_pointer = self.next_l2_pointer(filter)?.clone();
 
-        // Stash all observed transactions that don't meet criteria
+        // Stash all observed transactions that don't meet criteria (there are none for strict ordering policies)
         for stashed_pointer in self
             .l2_priority_queue
             .split_off(&tx_pointer)
@@ -192,7 +243,7 @@ impl MempoolStore {
             .l2_transactions_per_account
             .get_mut(&tx_pointer.account)
             .expect("mempool: dangling pointer in priority queue")
-            .next();
+            .next(&*self.ordering_policy);
 
         if let Some(score) = score {
             self.l2_priority_queue.insert(score);
@@ -204,6 +255,36 @@ impl MempoolStore {
         Some((transaction.into(), constraint))
     }
 
+    /// Returns the L2 transaction with the specified hash if it's the next transaction of its initiator
+    /// and it matches the filter, i.e., if it can be executed right away. Unlike [`Self::next_transaction()`],
+    /// ignores the priority order; used to execute transactions in the order proposed by an external block builder.
+    pub fn next_transaction_with_hash(
+        &mut self,
+        hash: H256,
+        filter: &L2TxFilter,
+    ) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        let tx_pointer = self
+            .l2_priority_queue
+            .get_by_hash(&hash)
+            .filter(|pointer| pointer.matches_filter(filter))?
+            .clone();
+        self.l2_priority_queue.remove(&tx_pointer);
+
+        let (transaction, constraint, score) = self
+            .l2_transactions_per_account
+            .get_mut(&tx_pointer.account)
+            .expect("mempool: dangling pointer in priority queue")
+            .next(&*self.ordering_policy);
+        if let Some(score) = score {
+            self.l2_priority_queue.insert(score);
+        }
+        self.size = self
+            .size
+            .checked_sub(1)
+            .expect("mempool size can't be negative");
+        Some((transaction.into(), constraint))
+    }
+
     /// When a state_keeper starts the block over after a rejected transaction,
     /// we have to rollback the nonces/ids in the mempool and
     /// reinsert the transactions from the block back into mempool.
@@ -220,7 +301,7 @@ impl MempoolStore {
                     .l2_transactions_per_account
                     .get_mut(&tx.initiator_account())
                     .expect("account is not available in mempool")
-                    .reset(tx)
+                    .reset(tx, &*self.ordering_policy)
                 {
                     self.l2_priority_queue.remove(&score);
                     return constraint;
@@ -240,6 +321,44 @@ impl MempoolStore {
         }
     }
 
+    /// Returns the number of pending and queued L2 transactions in the mempool (see [`AccountMempoolContents`]).
+    pub fn pending_and_queued_counts(&self) -> (usize, usize) {
+        self.l2_transactions_per_account
+            .values()
+            .fold((0, 0), |(pending, queued), txs| {
+                let (account_pending, account_queued) = txs.split_by_nonce();
+                (
+                    pending + account_pending.len(),
+                    queued + account_queued.len(),
+                )
+            })
+    }
+
+    /// Returns L2 transactions in the mempool grouped by the initiator account. Accounts without transactions are omitted.
+    pub fn contents(&self) -> HashMap<Address, AccountMempoolContents> {
+        self.l2_transactions_per_account
+            .iter()
+            .filter(|(_, txs)| txs.len() > 0)
+            .map(|(&account, txs)| (account, Self::account_contents_inner(txs)))
+            .collect()
+    }
+
+    /// Returns L2 transactions in the mempool initiated by the specified account.
+    pub fn account_contents(&self, account: Address) -> AccountMempoolContents {
+        self.l2_transactions_per_account
+            .get(&account)
+            .map(Self::account_contents_inner)
+            .unwrap_or_default()
+    }
+
+    fn account_contents_inner(txs: &AccountTransactions) -> AccountMempoolContents {
+        let (pending, queued) = txs.split_by_nonce();
+        AccountMempoolContents {
+            pending: pending.into_iter().cloned().collect(),
+            queued: queued.into_iter().cloned().collect(),
+        }
+    }
+
     pub fn stats(&self) -> MempoolStats {
         MempoolStats {
             l1_transaction_count: self.l1_transactions.len(),
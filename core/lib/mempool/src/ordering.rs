@@ -0,0 +1,76 @@
+//! Policies determining the order in which L2 transactions are taken from the mempool.
+
+use std::fmt;
+
+use zksync_types::{l2::L2Tx, U256};
+
+/// Policy determining the order in which L2 transactions of different accounts are taken from the mempool.
+/// Transactions of a single account are always executed in the nonce order, so the policy only ranks the next
+/// executable transaction of each account.
+///
+/// Operators may provide their own implementations, e.g. to prioritize transactions to specific contracts.
+pub trait TxOrderingPolicy: fmt::Debug + Send + Sync + 'static {
+    /// Name of the policy reported via API.
+    fn name(&self) -> &str;
+
+    /// Returns the priority of a transaction; transactions with higher priority are executed first.
+    /// Transactions with equal priority are executed in the order of their arrival.
+    ///
+    /// The priority must be deterministic, i.e., depend only on the transaction.
+    fn priority(&self, tx: &L2Tx) -> U256;
+
+    /// If set, the mempool never skips transactions: if the next transaction doesn't match the fee requirements
+    /// of the state keeper, no L2 transactions are returned until it does. Otherwise, non-matching transactions
+    /// are skipped, and their accounts are stashed.
+    fn is_strict(&self) -> bool {
+        false
+    }
+}
+
+/// Default policy: transactions are ordered by the arrival time, and transactions not matching the fee requirements
+/// are skipped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimestampOrdering;
+
+impl TxOrderingPolicy for TimestampOrdering {
+    fn name(&self) -> &str {
+        "timestamp"
+    }
+
+    fn priority(&self, _tx: &L2Tx) -> U256 {
+        U256::zero()
+    }
+}
+
+/// Strict first-in-first-out ordering by the arrival time. Unlike [`TimestampOrdering`], transactions are never
+/// skipped, so a transaction cannot be overtaken by transactions that arrived later.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FifoOrdering;
+
+impl TxOrderingPolicy for FifoOrdering {
+    fn name(&self) -> &str {
+        "fifo"
+    }
+
+    fn priority(&self, _tx: &L2Tx) -> U256 {
+        U256::zero()
+    }
+
+    fn is_strict(&self) -> bool {
+        true
+    }
+}
+
+/// Priority fee auction: transactions with higher `max_priority_fee_per_gas` are executed first.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PriorityFeeOrdering;
+
+impl TxOrderingPolicy for PriorityFeeOrdering {
+    fn name(&self) -> &str {
+        "priority_fee"
+    }
+
+    fn priority(&self, tx: &L2Tx) -> U256 {
+        tx.common_data.fee.max_priority_fee_per_gas
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::FromIterator,
+    sync::Arc,
 };
 
 use zksync_types::{
@@ -12,7 +13,11 @@ use zksync_types::{
     TransactionTimeRangeConstraint, H256, U256,
 };
 
-use crate::{mempool_store::MempoolStore, types::L2TxFilter};
+use crate::{
+    mempool_store::{AccountMempoolContents, MempoolStore},
+    ordering::{FifoOrdering, PriorityFeeOrdering, TxOrderingPolicy},
+    types::L2TxFilter,
+};
 
 #[test]
 fn basic_flow() {
@@ -321,6 +326,90 @@ fn stashed_accounts() {
     assert!(mempool.next_transaction(&filter_zero).is_none());
 }
 
+#[test]
+fn fifo_ordering_does_not_skip_transactions() {
+    let filter_non_zero = L2TxFilter {
+        fee_input: Default::default(),
+        fee_per_gas: 0u64,
+        gas_per_pubdata: 1u32,
+    };
+    let filter_zero = L2TxFilter {
+        fee_input: Default::default(),
+        fee_per_gas: 0u64,
+        gas_per_pubdata: 0u32,
+    };
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering_policy(Arc::new(FifoOrdering));
+    assert_eq!(mempool.ordering_policy().name(), "fifo");
+    let account0 = Address::random();
+    let account1 = Address::random();
+
+    let now = unix_timestamp_ms();
+    mempool.insert_without_constraints(
+        gen_transactions_for_filtering(vec![
+            (account0, Nonce(0), now, 0),
+            (account1, Nonce(0), now + 10, 1),
+        ]),
+        HashMap::new(),
+    );
+
+    // The first transaction doesn't match the filter, and it must not be overtaken by the later transaction.
+    assert!(!mempool.has_next(&filter_non_zero));
+    assert_eq!(mempool.next_transaction(&filter_non_zero), None);
+    assert!(mempool.get_mempool_info().stashed_accounts.is_empty());
+
+    assert!(mempool.has_next(&filter_zero));
+    assert_eq!(view(mempool.next_transaction(&filter_zero)), (account0, 0));
+    assert_eq!(
+        view(mempool.next_transaction(&filter_non_zero)),
+        (account1, 0)
+    );
+}
+
+#[test]
+fn priority_fee_ordering() {
+    fn gen_l2_tx_with_priority_fee(
+        address: Address,
+        nonce: Nonce,
+        received_at_ms: u64,
+        priority_fee: u64,
+    ) -> Transaction {
+        let mut tx = gen_l2_tx_with_timestamp(address, nonce, received_at_ms);
+        match &mut tx.common_data {
+            ExecuteTransactionCommon::L2(data) => {
+                data.fee.max_priority_fee_per_gas = priority_fee.into();
+            }
+            _ => unreachable!(),
+        }
+        tx
+    }
+
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering_policy(Arc::new(PriorityFeeOrdering));
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+
+    let now = unix_timestamp_ms();
+    mempool.insert_without_constraints(
+        vec![
+            gen_l2_tx_with_priority_fee(account0, Nonce(0), now, 1),
+            gen_l2_tx_with_priority_fee(account0, Nonce(1), now, 100),
+            gen_l2_tx_with_priority_fee(account1, Nonce(0), now + 10, 10),
+            gen_l2_tx_with_priority_fee(account2, Nonce(0), now + 20, 10),
+        ],
+        HashMap::new(),
+    );
+
+    // Transactions with equal priority fees are ordered by the arrival time.
+    let filter = L2TxFilter::default();
+    assert_eq!(view(mempool.next_transaction(&filter)), (account1, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account2, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account0, 0));
+    assert_eq!(view(mempool.next_transaction(&filter)), (account0, 1));
+    assert_eq!(mempool.next_transaction(&filter), None);
+}
+
 #[test]
 fn mempool_capacity() {
     let mut mempool = MempoolStore::new(PriorityOpId(0), 4);
@@ -392,6 +481,135 @@ fn mempool_does_not_purge_all_accounts() {
     assert!(!mempool.has_next(&L2TxFilter::default()));
 }
 
+#[test]
+fn taking_transactions_by_hash() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let tx0 = gen_l2_tx_with_hash(account0, Nonce(0));
+    let tx1 = gen_l2_tx_with_hash(account0, Nonce(1));
+    let tx2 = gen_l2_tx_with_hash(account1, Nonce(0));
+    mempool.insert_without_constraints(vec![tx0.clone(), tx1.clone(), tx2.clone()], HashMap::new());
+
+    // Transactions with nonce gaps or unknown hashes cannot be taken.
+    assert_eq!(
+        mempool.next_transaction_with_hash(tx1.hash(), &L2TxFilter::default()),
+        None
+    );
+    assert_eq!(
+        mempool.next_transaction_with_hash(H256::random(), &L2TxFilter::default()),
+        None
+    );
+
+    assert_eq!(
+        view(mempool.next_transaction_with_hash(tx2.hash(), &L2TxFilter::default())),
+        (account1, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction_with_hash(tx0.hash(), &L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 1);
+    // The successor of a taken transaction becomes available via the priority queue.
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 1)
+    );
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn taking_replaced_transaction_by_hash() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account = Address::random();
+    let tx = gen_l2_tx_with_hash(account, Nonce(0));
+    let replacement = gen_l2_tx_with_hash(account, Nonce(0));
+    mempool.insert_without_constraints(vec![tx.clone()], HashMap::new());
+    mempool.insert_without_constraints(vec![replacement.clone()], HashMap::new());
+
+    assert_eq!(
+        mempool.next_transaction_with_hash(tx.hash(), &L2TxFilter::default()),
+        None
+    );
+    assert_eq!(
+        view(mempool.next_transaction_with_hash(replacement.hash(), &L2TxFilter::default())),
+        (account, 0)
+    );
+    assert!(!mempool.has_next(&L2TxFilter::default()));
+}
+
+#[test]
+fn pending_and_queued_transactions() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let transactions = vec![
+        gen_l2_tx(account0, Nonce(0)),
+        gen_l2_tx(account0, Nonce(1)),
+        gen_l2_tx(account0, Nonce(3)),
+        gen_l2_tx(account1, Nonce(1)),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+    assert_eq!(mempool.pending_and_queued_counts(), (2, 2));
+
+    let contents = mempool.contents();
+    assert_eq!(contents.len(), 2);
+    let nonces = |txs: &[L2Tx]| -> Vec<_> { txs.iter().map(|tx| tx.nonce().0).collect() };
+    assert_eq!(nonces(&contents[&account0].pending), [0, 1]);
+    assert_eq!(nonces(&contents[&account0].queued), [3]);
+    assert!(contents[&account1].pending.is_empty());
+    assert_eq!(nonces(&contents[&account1].queued), [1]);
+    assert_eq!(mempool.account_contents(account0), contents[&account0]);
+    assert_eq!(
+        mempool.account_contents(Address::random()),
+        AccountMempoolContents::default()
+    );
+
+    // Transactions sent to the state keeper are no longer in the mempool.
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    let contents = mempool.account_contents(account0);
+    assert_eq!(nonces(&contents.pending), [1]);
+    assert_eq!(nonces(&contents.queued), [3]);
+
+    // Filling the nonce gap makes all transactions pending.
+    mempool.insert_without_constraints(vec![gen_l2_tx(account1, Nonce(0))], HashMap::new());
+    let contents = mempool.account_contents(account1);
+    assert_eq!(nonces(&contents.pending), [0, 1]);
+    assert!(contents.queued.is_empty());
+    assert_eq!(mempool.pending_and_queued_counts(), (3, 1));
+}
+
+#[test]
+fn taking_only_l1_transactions() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account = Address::random();
+    mempool.insert_without_constraints(
+        vec![gen_l2_tx(account, Nonce(0)), gen_l1_tx(PriorityOpId(1))],
+        HashMap::new(),
+    );
+    // The next priority operation is missing, so L2 transactions must not be returned instead.
+    assert!(mempool.next_l1_transaction().is_none());
+    assert_eq!(mempool.next_priority_id(), PriorityOpId(0));
+
+    mempool.insert_without_constraints(vec![gen_l1_tx(PriorityOpId(0))], HashMap::new());
+    for idx in 0..2 {
+        let (tx, _) = mempool.next_l1_transaction().unwrap();
+        let ExecuteTransactionCommon::L1(data) = tx.common_data else {
+            unreachable!("expected L1 transaction");
+        };
+        assert_eq!(data.serial_id, PriorityOpId(idx));
+    }
+    assert_eq!(mempool.next_priority_id(), PriorityOpId(2));
+    assert!(mempool.next_l1_transaction().is_none());
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account, 0)
+    );
+}
+
 fn gen_l2_tx(address: Address, nonce: Nonce) -> Transaction {
     gen_l2_tx_with_timestamp(address, nonce, unix_timestamp_ms())
 }
@@ -411,6 +629,21 @@ fn gen_l2_tx_with_timestamp(address: Address, nonce: Nonce, received_at_ms: u64)
     txn.into()
 }
 
+fn gen_l2_tx_with_hash(address: Address, nonce: Nonce) -> Transaction {
+    let mut txn = L2Tx::new(
+        Some(Address::default()),
+        Vec::new(),
+        nonce,
+        Fee::default(),
+        address,
+        U256::zero(),
+        vec![],
+        Default::default(),
+    );
+    txn.set_input(vec![], H256::random());
+    txn.into()
+}
+
 fn gen_l1_tx(priority_id: PriorityOpId) -> Transaction {
     let execute = Execute {
         contract_address: Some(Address::repeat_byte(0x11)),
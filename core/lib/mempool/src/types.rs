@@ -1,10 +1,15 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+};
 
 use zksync_types::{
     fee::Fee, fee_model::BatchFeeInput, l2::L2Tx, Address, Nonce, Transaction,
-    TransactionTimeRangeConstraint, U256,
+    TransactionTimeRangeConstraint, H256, U256,
 };
 
+use crate::ordering::TxOrderingPolicy;
+
 /// Pending mempool transactions of account
 #[derive(Debug)]
 pub(crate) struct AccountTransactions {
@@ -28,6 +33,7 @@ impl AccountTransactions {
         &mut self,
         transaction: L2Tx,
         constraint: TransactionTimeRangeConstraint,
+        policy: &dyn TxOrderingPolicy,
     ) -> InsertionMetadata {
         let mut metadata = InsertionMetadata::default();
         let nonce = transaction.common_data.nonce;
@@ -35,11 +41,11 @@ impl AccountTransactions {
         if nonce < self.nonce {
             return metadata;
         }
-        let new_score = Self::score_for_transaction(&transaction);
+        let new_score = Self::score_for_transaction(&transaction, policy);
         let previous_score = self
             .transactions
             .insert(nonce, (transaction, constraint))
-            .map(|x| Self::score_for_transaction(&x.0));
+            .map(|x| Self::score_for_transaction(&x.0, policy));
         metadata.is_new = previous_score.is_none();
         if nonce == self.nonce {
             metadata.new_score = Some(new_score);
@@ -50,7 +56,10 @@ impl AccountTransactions {
 
     /// Returns next transaction to be included in block, its time range constraint and optional
     /// score of its successor. Panics if no such transaction exists
-    pub fn next(&mut self) -> (L2Tx, TransactionTimeRangeConstraint, Option<MempoolScore>) {
+    pub fn next(
+        &mut self,
+        policy: &dyn TxOrderingPolicy,
+    ) -> (L2Tx, TransactionTimeRangeConstraint, Option<MempoolScore>) {
         let transaction = self
             .transactions
             .remove(&self.nonce)
@@ -59,7 +68,7 @@ impl AccountTransactions {
         let score = self
             .transactions
             .get(&self.nonce)
-            .map(|(tx, _c)| Self::score_for_transaction(tx));
+            .map(|(tx, _c)| Self::score_for_transaction(tx, policy));
         (transaction.0, transaction.1, score)
     }
 
@@ -68,6 +77,7 @@ impl AccountTransactions {
     pub fn reset(
         &mut self,
         transaction: &Transaction,
+        policy: &dyn TxOrderingPolicy,
     ) -> Option<(MempoolScore, TransactionTimeRangeConstraint)> {
         // current nonce for the group needs to be reset
         let tx_nonce = transaction
@@ -76,27 +86,53 @@ impl AccountTransactions {
         self.nonce = self.nonce.min(tx_nonce);
         self.transactions
             .get(&(tx_nonce + 1))
-            .map(|(tx, c)| (Self::score_for_transaction(tx), c.clone()))
+            .map(|(tx, c)| (Self::score_for_transaction(tx, policy), c.clone()))
     }
 
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
 
-    fn score_for_transaction(transaction: &L2Tx) -> MempoolScore {
+    /// Splits transactions into ones that can be executed after the previous transactions of the account
+    /// (i.e., have consecutive nonces starting from the account nonce), and ones blocked by a nonce gap.
+    /// Both lists are ordered by nonce.
+    pub fn split_by_nonce(&self) -> (Vec<&L2Tx>, Vec<&L2Tx>) {
+        let mut nonces: Vec<_> = self.transactions.keys().copied().collect();
+        nonces.sort_unstable();
+        let mut next_nonce = self.nonce;
+        let mut pending = vec![];
+        let mut queued = vec![];
+        for nonce in nonces {
+            let (tx, _) = &self.transactions[&nonce];
+            if nonce == next_nonce && queued.is_empty() {
+                pending.push(tx);
+                next_nonce += 1;
+            } else {
+                queued.push(tx);
+            }
+        }
+        (pending, queued)
+    }
+
+    fn score_for_transaction(transaction: &L2Tx, policy: &dyn TxOrderingPolicy) -> MempoolScore {
         MempoolScore {
+            priority: policy.priority(transaction),
             account: transaction.initiator_account(),
+            tx_hash: transaction.hash(),
             received_at_ms: transaction.received_timestamp_ms,
             fee_data: transaction.common_data.fee.clone(),
         }
     }
 }
 
-/// Mempool score of transaction. Used to prioritize L2 transactions in mempool
-/// Currently trivial ordering is used based on received at timestamp
+/// Mempool score of transaction. Used to prioritize L2 transactions in mempool.
+/// Transactions are ordered by the priority assigned by [`TxOrderingPolicy`], and then by received at timestamp.
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 pub struct MempoolScore {
+    pub priority: U256,
     pub account: Address,
+    /// Hash of the transaction. Not used for scoring.
+    pub tx_hash: H256,
     pub received_at_ms: u64,
     // Not used for actual scoring, but state keeper would request
     // transactions that have acceptable fee values (so transactions
@@ -114,6 +150,10 @@ impl MempoolScore {
 
 impl Ord for MempoolScore {
     fn cmp(&self, other: &MempoolScore) -> Ordering {
+        match self.priority.cmp(&other.priority) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
         match self.received_at_ms.cmp(&other.received_at_ms).reverse() {
             Ordering::Equal => {}
             ordering => return ordering,
@@ -128,6 +168,71 @@ impl PartialOrd for MempoolScore {
     }
 }
 
+/// Global priority queue for L2 transactions. Contains a pointer to the next transaction of each account
+/// and allows looking up pointers by the transaction hash.
+#[derive(Debug, Default)]
+pub(crate) struct L2PriorityQueue {
+    scores: BTreeSet<MempoolScore>,
+    scores_by_hash: HashMap<H256, MempoolScore>,
+}
+
+impl L2PriorityQueue {
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &MempoolScore> + '_ {
+        self.scores.iter()
+    }
+
+    pub fn last(&self) -> Option<&MempoolScore> {
+        self.scores.last()
+    }
+
+    pub fn get_by_hash(&self, tx_hash: &H256) -> Option<&MempoolScore> {
+        self.scores_by_hash.get(tx_hash)
+    }
+
+    pub fn insert(&mut self, score: MempoolScore) {
+        self.scores_by_hash.insert(score.tx_hash, score.clone());
+        self.scores.insert(score);
+    }
+
+    pub fn remove(&mut self, score: &MempoolScore) {
+        if let Some(score) = self.scores.take(score) {
+            self.scores_by_hash.remove(&score.tx_hash);
+        }
+    }
+
+    /// Removes and returns all pointers greater than or equal to `score`.
+    pub fn split_off(&mut self, score: &MempoolScore) -> BTreeSet<MempoolScore> {
+        let removed = self.scores.split_off(score);
+        for score in &removed {
+            self.scores_by_hash.remove(&score.tx_hash);
+        }
+        removed
+    }
+}
+
+impl FromIterator<MempoolScore> for L2PriorityQueue {
+    fn from_iter<I: IntoIterator<Item = MempoolScore>>(iter: I) -> Self {
+        let mut queue = Self::default();
+        for score in iter {
+            queue.insert(score);
+        }
+        queue
+    }
+}
+
+impl IntoIterator for L2PriorityQueue {
+    type Item = MempoolScore;
+    type IntoIter = std::collections::btree_set::IntoIter<MempoolScore>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scores.into_iter()
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct InsertionMetadata {
     pub new_score: Option<MempoolScore>,
@@ -167,7 +272,9 @@ mod tests {
         const GAS_PER_PUBDATA_LIMIT: u32 = 100u32;
 
         let score = MempoolScore {
+            priority: U256::zero(),
             account: Address::random(),
+            tx_hash: H256::zero(),              // Not important
             received_at_ms: Default::default(), // Not important
             fee_data: Fee {
                 gas_limit: Default::default(), // Not important
@@ -21,6 +21,7 @@ pub use crate::{
 
 mod glue;
 pub mod pubdata_builders;
+pub mod speculative;
 pub mod tracers;
 pub mod utils;
 mod versions;
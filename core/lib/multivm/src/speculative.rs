@@ -0,0 +1,332 @@
+//! Speculative parallel execution of transactions for simulation workloads, such as mempool pre-screening
+//! or bundle simulation.
+//!
+//! Each transaction in a candidate block is executed in a separate VM instance on its own copy-on-write fork
+//! of the storage, as if it were the first transaction in the block. If the transactions don't conflict on storage,
+//! their outputs are equivalent to ones produced by sequential execution (modulo block bookkeeping), so they can be merged.
+//!
+//! This mode is **not** suitable for consensus-critical execution (e.g., in the state keeper).
+
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use zksync_system_constants::{BOOTLOADER_ADDRESS, L1_MESSENGER_ADDRESS, SYSTEM_CONTEXT_ADDRESS};
+use zksync_types::{
+    h256_to_u256, u256_to_h256, utils::storage_key_for_eth_balance, Address, StorageKey,
+    StorageValue, Transaction,
+};
+
+use crate::{
+    interface::{
+        storage::{ForkableStorage, ReadStorage, StorageView, WriteStorage},
+        InspectExecutionMode, L1BatchEnv, SystemEnv, VmExecutionResultAndLogs, VmFactory,
+        VmInterface,
+    },
+    vm_latest::HistoryDisabled,
+    LegacyVmInstance,
+};
+
+/// Storage conflict between two speculatively executed transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageConflict {
+    pub key: StorageKey,
+    /// Index of the transaction writing to the key.
+    pub writer: usize,
+    /// Index of a later transaction that accesses the key.
+    pub reader: usize,
+}
+
+/// Output of a single speculatively executed transaction.
+#[derive(Debug)]
+pub struct SpeculativeTxOutput {
+    pub result: VmExecutionResultAndLogs,
+    /// Storage keys read by the transaction before writing to them.
+    pub read_keys: HashSet<StorageKey>,
+    /// Storage writes produced by the transaction.
+    pub writes: HashMap<StorageKey, StorageValue>,
+}
+
+/// Output of speculatively executing a candidate block.
+#[derive(Debug)]
+pub struct SpeculativeBlockOutput {
+    /// Outputs for each transaction, in the order of transactions in the block.
+    pub transactions: Vec<SpeculativeTxOutput>,
+    /// Detected conflicts among transactions. If there are any, outputs of readers in the conflicts
+    /// may diverge from sequential execution.
+    pub conflicts: Vec<StorageConflict>,
+    /// Storage writes of all transactions merged together. `None` if there are conflicts.
+    pub merged_writes: Option<HashMap<StorageKey, StorageValue>>,
+}
+
+/// Executor running transactions of a candidate block in parallel.
+///
+/// Some storage slots are written by each transaction as a part of block bookkeeping. Such slots are handled
+/// specially so that they don't lead to conflicts:
+///
+/// - Slots of *ignored accounts* (by default, system context and L1 messenger) are excluded from transaction
+///   outputs altogether.
+/// - *Additive slots* (by default, the base token balance of the bootloader receiving transaction fees)
+///   are excluded from conflict detection; merged values for them are computed by summing up changes
+///   produced by each transaction.
+#[derive(Debug)]
+pub struct SpeculativeExecutor {
+    l1_batch_env: L1BatchEnv,
+    system_env: SystemEnv,
+    parallelism: NonZeroUsize,
+    additive_keys: HashSet<StorageKey>,
+    ignored_accounts: HashSet<Address>,
+}
+
+impl SpeculativeExecutor {
+    /// Creates an executor with parallelism equal to the number of available CPUs.
+    pub fn new(l1_batch_env: L1BatchEnv, system_env: SystemEnv) -> Self {
+        Self {
+            l1_batch_env,
+            system_env,
+            parallelism: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            additive_keys: HashSet::from([storage_key_for_eth_balance(&BOOTLOADER_ADDRESS)]),
+            ignored_accounts: HashSet::from([SYSTEM_CONTEXT_ADDRESS, L1_MESSENGER_ADDRESS]),
+        }
+    }
+
+    /// Sets the maximum number of threads used for execution.
+    pub fn with_parallelism(mut self, parallelism: NonZeroUsize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Marks the storage slot as additive (e.g., a balance of a fee recipient).
+    pub fn with_additive_key(mut self, key: StorageKey) -> Self {
+        self.additive_keys.insert(key);
+        self
+    }
+
+    /// Executes the provided transactions in parallel on top of `storage`. The storage is never modified.
+    pub fn execute<S: ReadStorage + Send>(
+        &self,
+        storage: S,
+        transactions: &[Transaction],
+    ) -> SpeculativeBlockOutput {
+        let storage = ForkableStorage::new(storage);
+        let next_tx_index = AtomicUsize::new(0);
+        let worker_count = self.parallelism.get().min(transactions.len());
+
+        let mut outputs: Vec<_> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..worker_count)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut outputs = vec![];
+                        loop {
+                            let tx_index = next_tx_index.fetch_add(1, Ordering::Relaxed);
+                            let Some(tx) = transactions.get(tx_index) else {
+                                break outputs;
+                            };
+                            outputs.push((tx_index, self.execute_transaction(&storage, tx)));
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("speculative execution panicked"))
+                .collect()
+        });
+        outputs.sort_unstable_by_key(|(tx_index, _)| *tx_index);
+        let outputs: Vec<_> = outputs.into_iter().map(|(_, output)| output).collect();
+
+        let conflicts = self.find_conflicts(&outputs);
+        let merged_writes = conflicts
+            .is_empty()
+            .then(|| self.merge_writes(&storage, &outputs));
+        SpeculativeBlockOutput {
+            transactions: outputs,
+            conflicts,
+            merged_writes,
+        }
+    }
+
+    fn execute_transaction<S: ReadStorage>(
+        &self,
+        storage: &ForkableStorage<S>,
+        tx: &Transaction,
+    ) -> SpeculativeTxOutput {
+        let storage_view = StorageView::new(storage.fork()).to_rc_ptr();
+        let mut vm = LegacyVmInstance::<_, HistoryDisabled>::new(
+            self.l1_batch_env.clone(),
+            self.system_env.clone(),
+            storage_view.clone(),
+        );
+        vm.push_transaction(tx.clone());
+        let result = vm.execute(InspectExecutionMode::OneTx);
+        drop(vm);
+
+        let storage_view = storage_view.borrow();
+        let is_tracked = |key: &StorageKey| !self.ignored_accounts.contains(key.address());
+        SpeculativeTxOutput {
+            result,
+            read_keys: storage_view
+                .read_storage_keys()
+                .keys()
+                .copied()
+                .filter(is_tracked)
+                .collect(),
+            writes: storage_view
+                .modified_storage_keys()
+                .iter()
+                .filter(|(key, _)| is_tracked(*key))
+                .map(|(key, value)| (*key, *value))
+                .collect(),
+        }
+    }
+
+    fn find_conflicts(&self, outputs: &[SpeculativeTxOutput]) -> Vec<StorageConflict> {
+        let mut first_writers = HashMap::new();
+        let mut conflicts = vec![];
+        for (tx_index, output) in outputs.iter().enumerate() {
+            let accessed_keys: HashSet<_> = output
+                .read_keys
+                .iter()
+                .chain(output.writes.keys())
+                .filter(|key| !self.additive_keys.contains(*key))
+                .collect();
+            for key in accessed_keys {
+                if let Some(&writer) = first_writers.get(key) {
+                    conflicts.push(StorageConflict {
+                        key: *key,
+                        writer,
+                        reader: tx_index,
+                    });
+                }
+            }
+
+            for key in output.writes.keys() {
+                if !self.additive_keys.contains(key) {
+                    first_writers.entry(*key).or_insert(tx_index);
+                }
+            }
+        }
+        conflicts.sort_unstable_by_key(|conflict| (conflict.reader, conflict.writer));
+        conflicts
+    }
+
+    fn merge_writes<S: ReadStorage>(
+        &self,
+        storage: &ForkableStorage<S>,
+        outputs: &[SpeculativeTxOutput],
+    ) -> HashMap<StorageKey, StorageValue> {
+        let mut merged = HashMap::new();
+        for output in outputs {
+            let non_additive_writes = output
+                .writes
+                .iter()
+                .filter(|(key, _)| !self.additive_keys.contains(*key));
+            merged.extend(non_additive_writes.map(|(key, value)| (*key, *value)));
+        }
+
+        let mut fork = storage.fork();
+        for key in &self.additive_keys {
+            let initial_value = h256_to_u256(fork.read_value(key));
+            let mut merged_value = initial_value;
+            let mut is_written = false;
+            for output in outputs {
+                if let Some(value) = output.writes.get(key) {
+                    let (diff, _) = h256_to_u256(*value).overflowing_sub(initial_value);
+                    (merged_value, _) = merged_value.overflowing_add(diff);
+                    is_written = true;
+                }
+            }
+            if is_written {
+                merged.insert(*key, u256_to_h256(merged_value));
+            }
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::slice;
+
+    use zksync_test_contracts::Account;
+    use zksync_types::{get_nonce_key, Execute, L1BatchNumber, U256};
+
+    use super::*;
+    use crate::{
+        interface::storage::InMemoryStorage,
+        versions::testonly::{default_l1_batch, default_system_env, make_address_rich},
+    };
+
+    fn prepare_storage(accounts: &[Account]) -> InMemoryStorage {
+        let mut storage = InMemoryStorage::with_system_contracts();
+        for account in accounts {
+            make_address_rich(&mut storage, account.address);
+        }
+        storage
+    }
+
+    fn create_executor() -> SpeculativeExecutor {
+        SpeculativeExecutor::new(default_l1_batch(L1BatchNumber(1)), default_system_env())
+            .with_parallelism(NonZeroUsize::new(2).unwrap())
+    }
+
+    #[test]
+    fn executing_independent_transactions() {
+        let mut accounts: Vec<_> = (0..3).map(|_| Account::random()).collect();
+        let storage = prepare_storage(&accounts);
+        let recipients: Vec<_> = (1..=3).map(Address::repeat_byte).collect();
+        let transactions: Vec<_> = accounts
+            .iter_mut()
+            .zip(&recipients)
+            .map(|(account, &recipient)| {
+                account.get_l2_tx_for_execute(Execute::transfer(recipient, 100.into()), None)
+            })
+            .collect();
+
+        let output = create_executor().execute(storage, &transactions);
+        for tx_output in &output.transactions {
+            assert!(!tx_output.result.result.is_failed(), "{tx_output:#?}");
+        }
+        assert_eq!(output.conflicts, []);
+
+        let merged_writes = output.merged_writes.unwrap();
+        for recipient in &recipients {
+            let balance_key = storage_key_for_eth_balance(recipient);
+            assert_eq!(merged_writes[&balance_key], u256_to_h256(100.into()));
+        }
+        let fee_key = storage_key_for_eth_balance(&BOOTLOADER_ADDRESS);
+        let total_fee: U256 = output
+            .transactions
+            .iter()
+            .map(|tx_output| h256_to_u256(tx_output.writes[&fee_key]))
+            .fold(U256::zero(), |acc, fee| acc + fee);
+        assert_eq!(merged_writes[&fee_key], u256_to_h256(total_fee));
+    }
+
+    #[test]
+    fn detecting_conflicts() {
+        let mut account = Account::random();
+        let storage = prepare_storage(slice::from_ref(&account));
+        let transactions: Vec<_> = (1..=2)
+            .map(|byte| {
+                let recipient = Address::repeat_byte(byte);
+                account.get_l2_tx_for_execute(Execute::transfer(recipient, 100.into()), None)
+            })
+            .collect();
+
+        let output = create_executor().execute(storage, &transactions);
+        assert!(output.merged_writes.is_none());
+        assert!(!output.conflicts.is_empty());
+        for conflict in &output.conflicts {
+            assert_eq!((conflict.writer, conflict.reader), (0, 1));
+        }
+        let nonce_key = get_nonce_key(&account.address);
+        assert!(output
+            .conflicts
+            .iter()
+            .any(|conflict| conflict.key == nonce_key));
+    }
+}
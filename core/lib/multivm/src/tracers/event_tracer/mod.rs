@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+use zksync_types::{Address, L1BatchNumber, H256};
+
+use crate::interface::VmEvent;
+
+pub mod vm_latest;
+
+/// Filter for events captured by [`EventTracer`]. Follows the semantics of `eth_getLogs` filters.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Addresses of the emitting contracts. If empty, events emitted by all contracts match.
+    pub addresses: Vec<Address>,
+    /// Filters for topics by their position. An event matches if, for each position, the filter is `None`
+    /// or contains the event topic at this position. Events with fewer topics than constrained positions
+    /// don't match.
+    pub topics: Vec<Option<Vec<H256>>>,
+}
+
+impl EventFilter {
+    /// Creates a filter for events emitted by the specified contract.
+    pub fn for_address(address: Address) -> Self {
+        Self {
+            addresses: vec![address],
+            topics: vec![],
+        }
+    }
+
+    /// Adds a constraint for the topic at the specified position.
+    pub fn with_topic(mut self, position: usize, topics: Vec<H256>) -> Self {
+        if self.topics.len() <= position {
+            self.topics.resize(position + 1, None);
+        }
+        self.topics[position] = Some(topics);
+        self
+    }
+
+    /// Checks whether an event with the specified emitter and topics matches this filter.
+    pub fn matches(&self, address: Address, topics: &[H256]) -> bool {
+        if !self.addresses.is_empty() && !self.addresses.contains(&address) {
+            return false;
+        }
+        self.topics
+            .iter()
+            .enumerate()
+            .all(|(position, filter)| match filter {
+                None => true,
+                Some(expected) => topics
+                    .get(position)
+                    .is_some_and(|topic| expected.contains(topic)),
+            })
+    }
+}
+
+/// Tracer capturing L2 events emitted during the execution.
+///
+/// Events are filtered before they are decoded, so only matching events are retained in the result;
+/// this keeps memory usage low when tracing transactions emitting a lot of events. Events from reverted
+/// frames are not reported, same as in the execution result.
+#[derive(Debug, Clone)]
+pub struct EventTracer {
+    l1_batch_number: L1BatchNumber,
+    filter: EventFilter,
+    result: Arc<OnceCell<Vec<VmEvent>>>,
+    from_timestamp: u32,
+}
+
+impl EventTracer {
+    /// Creates a tracer. `l1_batch_number` is used to set the location of the captured events.
+    pub fn new(
+        l1_batch_number: L1BatchNumber,
+        filter: EventFilter,
+        result: Arc<OnceCell<Vec<VmEvent>>>,
+    ) -> Self {
+        Self {
+            l1_batch_number,
+            filter,
+            result,
+            from_timestamp: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filtering_events() {
+        let address = Address::repeat_byte(1);
+        let topics = [H256::repeat_byte(0xaa), H256::repeat_byte(0xbb)];
+
+        assert!(EventFilter::default().matches(address, &topics));
+        assert!(EventFilter::for_address(address).matches(address, &topics));
+        assert!(!EventFilter::for_address(Address::repeat_byte(2)).matches(address, &topics));
+
+        let filter = EventFilter::default().with_topic(1, vec![topics[0], topics[1]]);
+        assert_eq!(filter.topics, [None, Some(vec![topics[0], topics[1]])]);
+        assert!(filter.matches(address, &topics));
+        assert!(!filter.matches(address, &topics[..1]));
+
+        let filter = EventFilter::for_address(address).with_topic(0, vec![topics[1]]);
+        assert!(!filter.matches(address, &topics));
+    }
+}
@@ -0,0 +1,39 @@
+use zk_evm_1_5_0::aux_structures::Timestamp;
+use zksync_types::H256;
+
+use super::EventTracer;
+use crate::{
+    interface::{storage::WriteStorage, tracer::VmExecutionStopReason},
+    tracers::dynamic::vm_1_5_0::DynTracer,
+    vm_latest::{
+        merge_events, BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState,
+    },
+};
+
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for EventTracer {}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for EventTracer {
+    fn initialize_tracer(&mut self, state: &mut ZkSyncVmState<S, H>) {
+        self.from_timestamp = state.local_state.timestamp;
+    }
+
+    fn after_vm_execution(
+        &mut self,
+        state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        let (raw_events, _) = state
+            .event_sink
+            .get_events_and_l2_l1_logs_after_timestamp(Timestamp(self.from_timestamp));
+        let events = merge_events(raw_events)
+            .into_iter()
+            .filter(|event| {
+                let topics: Vec<_> = event.topics.iter().map(|&topic| H256(topic)).collect();
+                self.filter.matches(event.address, &topics)
+            })
+            .map(|event| event.into_vm_event(self.l1_batch_number))
+            .collect();
+        self.result.set(events).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+//! Tracer aggregating gas consumption by opcode and by contract, e.g. to render flamegraphs.
+
+use std::{collections::HashMap, fmt::Write as _, sync::Arc};
+
+use once_cell::sync::OnceCell;
+use zksync_types::Address;
+
+pub mod vm_latest;
+
+/// Call stack of far-call frames together with the executed instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GasStack {
+    /// Code addresses of far-call frames, from the outermost (the bootloader) to the innermost one.
+    pub frames: Vec<Address>,
+    /// Human-readable instruction name, e.g. `Log(StorageRead)`.
+    pub opcode: String,
+}
+
+/// Gas profile of the VM execution. All values are in ergs (i.e., computational gas).
+///
+/// Gas is attributed to the frame executing the instruction and does not include gas of nested far calls.
+/// For instructions changing the call stack (far / near calls and returns), only the base instruction price
+/// is accounted; gas passed to the callee is attributed to the instructions executed by it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GasProfile {
+    /// Gas spent on each instruction kind across all contracts.
+    pub by_opcode: HashMap<String, u64>,
+    /// Gas spent by the code of each contract (excluding nested far calls).
+    pub by_contract: HashMap<Address, u64>,
+    /// Gas spent on each instruction kind in each call stack.
+    pub by_stack: HashMap<GasStack, u64>,
+}
+
+impl GasProfile {
+    /// Returns the total gas recorded in the profile.
+    pub fn total_gas(&self) -> u64 {
+        self.by_opcode.values().sum()
+    }
+
+    /// Renders the profile in the folded stacks format (`<frame>;<frame>;<opcode> <gas>` lines) accepted
+    /// by flamegraph tools, such as `inferno` or `flamegraph.pl`. Lines are sorted to make output deterministic.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut lines: Vec<_> = self
+            .by_stack
+            .iter()
+            .map(|(stack, gas)| {
+                let mut line = String::new();
+                for address in &stack.frames {
+                    write!(line, "{address:?};").unwrap();
+                }
+                write!(line, "{} {gas}", stack.opcode).unwrap();
+                line
+            })
+            .collect();
+        lines.sort_unstable();
+        lines.join("\n")
+    }
+
+    fn record(&mut self, frames: &[Address], opcode: String, gas: u64) {
+        if let Some(&address) = frames.last() {
+            *self.by_contract.entry(address).or_default() += gas;
+        }
+        *self.by_opcode.entry(opcode.clone()).or_default() += gas;
+        let stack = GasStack {
+            frames: frames.to_vec(),
+            opcode,
+        };
+        *self.by_stack.entry(stack).or_default() += gas;
+    }
+}
+
+/// Tracer collecting a [`GasProfile`] of the execution. Currently, only the latest legacy VM is supported.
+#[derive(Debug, Clone)]
+pub struct GasProfilerTracer {
+    profile: GasProfile,
+    /// Code addresses of far-call frames for the currently executed instruction.
+    frames: Vec<Address>,
+    /// Call stack depth corresponding to `frames`.
+    frames_depth: Option<usize>,
+    /// Ergs remaining and call stack depth before executing the current instruction.
+    pending_instruction: Option<(u32, usize)>,
+    result: Arc<OnceCell<GasProfile>>,
+}
+
+impl GasProfilerTracer {
+    pub fn new(result: Arc<OnceCell<GasProfile>>) -> Self {
+        Self {
+            profile: GasProfile::default(),
+            frames: vec![],
+            frames_depth: None,
+            pending_instruction: None,
+            result,
+        }
+    }
+
+    fn store_result(&mut self) {
+        let profile = std::mem::take(&mut self.profile);
+        self.result.set(profile).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_gas() {
+        let bootloader = Address::repeat_byte(0x80);
+        let contract = Address::repeat_byte(1);
+        let mut profile = GasProfile::default();
+        profile.record(&[bootloader], "Add(Add)".to_owned(), 6);
+        profile.record(&[bootloader, contract], "Log(StorageRead)".to_owned(), 100);
+        profile.record(&[bootloader, contract], "Add(Add)".to_owned(), 6);
+        profile.record(&[bootloader, contract], "Log(StorageRead)".to_owned(), 50);
+
+        assert_eq!(profile.total_gas(), 162);
+        assert_eq!(profile.by_opcode["Add(Add)"], 12);
+        assert_eq!(profile.by_opcode["Log(StorageRead)"], 150);
+        assert_eq!(profile.by_contract[&bootloader], 6);
+        assert_eq!(profile.by_contract[&contract], 156);
+        assert_eq!(profile.by_stack.len(), 3);
+
+        let folded = profile.to_folded_stacks();
+        let lines: Vec<_> = folded.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                format!("{bootloader:?};Add(Add) 6"),
+                format!("{bootloader:?};{contract:?};Add(Add) 6"),
+                format!("{bootloader:?};{contract:?};Log(StorageRead) 150"),
+            ]
+        );
+    }
+}
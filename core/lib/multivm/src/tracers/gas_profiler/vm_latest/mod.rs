@@ -0,0 +1,66 @@
+use zk_evm_1_5_0::tracing::{AfterExecutionData, BeforeExecutionData, VmLocalStateData};
+
+use super::GasProfilerTracer;
+use crate::{
+    interface::{
+        storage::{StoragePtr, WriteStorage},
+        tracer::VmExecutionStopReason,
+    },
+    tracers::dynamic::vm_1_5_0::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for GasProfilerTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        _data: BeforeExecutionData,
+        _memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        let callstack = &state.vm_local_state.callstack;
+        let depth = callstack.depth();
+        if self.frames_depth != Some(depth) {
+            // The bottom entry of the call stack is a formal empty context preceding the bootloader frame.
+            let entries = callstack.inner.iter().skip(1).chain([&callstack.current]);
+            self.frames = entries
+                .filter(|entry| !entry.is_local_frame)
+                .map(|entry| entry.code_address)
+                .collect();
+            self.frames_depth = Some(depth);
+        }
+        self.pending_instruction = Some((callstack.current.ergs_remaining, depth));
+    }
+
+    fn after_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: AfterExecutionData,
+        _memory: &SimpleMemory<H>,
+        _storage: StoragePtr<S>,
+    ) {
+        let Some((ergs_before, depth_before)) = self.pending_instruction.take() else {
+            return;
+        };
+        let callstack = &state.vm_local_state.callstack;
+        let gas = if callstack.depth() == depth_before {
+            ergs_before.saturating_sub(callstack.current.ergs_remaining)
+        } else {
+            // The instruction has changed the call stack, so remaining ergs belong to a different frame.
+            data.opcode.inner.variant.ergs_price()
+        };
+        let opcode = format!("{:?}", data.opcode.variant.opcode);
+        self.profile.record(&self.frames, opcode, gas.into());
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for GasProfilerTracer {
+    fn after_vm_execution(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        self.store_result();
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    glue::tracers::IntoOldVmTracer,
+    interface::tracer::{ExecutionLimits, InterruptionReason},
+};
+
+pub mod vm_1_4_1;
+pub mod vm_1_4_2;
+pub mod vm_boojum_integration;
+pub mod vm_latest;
+pub mod vm_refunds_enhancement;
+pub mod vm_virtual_blocks;
+
+/// Tracer interrupting the VM execution once it exceeds the specified [`ExecutionLimits`].
+///
+/// With the latest VM version, interrupted execution results in [`ExecutionResult::Interrupted`](crate::interface::ExecutionResult::Interrupted).
+/// Older VM versions don't distinguish interruptions, so execution is aborted with [`Halt::TracerCustom`](crate::interface::Halt::TracerCustom)
+/// instead. VM versions preceding virtual blocks are not supported.
+#[derive(Debug, Clone)]
+pub struct InterruptTracer {
+    max_cycles: Option<u64>,
+    deadline: Option<(Instant, Duration)>,
+    cycles: u64,
+    interrupted: Option<InterruptionReason>,
+}
+
+impl InterruptTracer {
+    /// Getting the current time is relatively expensive, so the deadline is only checked once per this number of cycles.
+    const DEADLINE_CHECK_INTERVAL: u64 = 1_024;
+
+    /// Creates a tracer. The timeout is measured from the tracer creation.
+    pub fn new(limits: ExecutionLimits) -> Self {
+        Self {
+            max_cycles: limits.max_cycles,
+            deadline: limits
+                .timeout
+                .map(|timeout| (Instant::now() + timeout, timeout)),
+            cycles: 0,
+            interrupted: None,
+        }
+    }
+
+    /// Accounts for a single VM cycle and checks whether the execution should be interrupted.
+    fn on_cycle(&mut self) -> Option<InterruptionReason> {
+        if self.interrupted.is_some() {
+            return self.interrupted;
+        }
+
+        self.cycles += 1;
+        if let Some(max_cycles) = self.max_cycles {
+            if self.cycles >= max_cycles {
+                self.interrupted = Some(InterruptionReason::CycleLimit(max_cycles));
+            }
+        }
+        if let Some((deadline, timeout)) = self.deadline {
+            if self.cycles % Self::DEADLINE_CHECK_INTERVAL == 0 && Instant::now() >= deadline {
+                self.interrupted = Some(InterruptionReason::Timeout(timeout));
+            }
+        }
+        self.interrupted
+    }
+}
+
+impl IntoOldVmTracer for InterruptTracer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interrupting_on_cycle_limit() {
+        let mut tracer = InterruptTracer::new(ExecutionLimits {
+            max_cycles: Some(10),
+            timeout: None,
+        });
+        for _ in 0..9 {
+            assert_eq!(tracer.on_cycle(), None);
+        }
+        assert_eq!(tracer.on_cycle(), Some(InterruptionReason::CycleLimit(10)));
+        assert_eq!(tracer.on_cycle(), Some(InterruptionReason::CycleLimit(10)));
+    }
+
+    #[test]
+    fn interrupting_on_timeout() {
+        let mut tracer = InterruptTracer::new(ExecutionLimits {
+            max_cycles: None,
+            timeout: Some(Duration::ZERO),
+        });
+        for _ in 1..InterruptTracer::DEADLINE_CHECK_INTERVAL {
+            assert_eq!(tracer.on_cycle(), None);
+        }
+        assert_eq!(
+            tracer.on_cycle(),
+            Some(InterruptionReason::Timeout(Duration::ZERO))
+        );
+    }
+
+    #[test]
+    fn no_interruption_without_limits() {
+        let mut tracer = InterruptTracer::new(ExecutionLimits::default());
+        for _ in 0..10 * InterruptTracer::DEADLINE_CHECK_INTERVAL {
+            assert_eq!(tracer.on_cycle(), None);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    interface::{
+        storage::WriteStorage,
+        tracer::{TracerExecutionStatus, TracerExecutionStopReason},
+        Halt,
+    },
+    tracers::{dynamic::vm_1_4_1::DynTracer, InterruptTracer},
+    vm_1_4_2::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+impl<S, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for InterruptTracer {}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for InterruptTracer {
+    fn finish_cycle(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &mut BootloaderState,
+    ) -> TracerExecutionStatus {
+        match self.on_cycle() {
+            Some(reason) => TracerExecutionStatus::Stop(TracerExecutionStopReason::Abort(
+                Halt::TracerCustom(reason.to_string()),
+            )),
+            None => TracerExecutionStatus::Continue,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    interface::{
+        storage::WriteStorage,
+        tracer::{TracerExecutionStatus, TracerExecutionStopReason},
+    },
+    tracers::{dynamic::vm_1_5_0::DynTracer, InterruptTracer},
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+impl<S, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for InterruptTracer {}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for InterruptTracer {
+    fn finish_cycle(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &mut BootloaderState,
+    ) -> TracerExecutionStatus {
+        match self.on_cycle() {
+            Some(reason) => {
+                TracerExecutionStatus::Stop(TracerExecutionStopReason::Interrupt(reason))
+            }
+            None => TracerExecutionStatus::Continue,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    interface::storage::WriteStorage,
+    tracers::{dynamic::vm_1_3_3::DynTracer, InterruptTracer},
+    vm_virtual_blocks::{
+        BootloaderState, ExecutionEndTracer, ExecutionProcessing, HistoryMode, SimpleMemory,
+        VmTracer, ZkSyncVmState,
+    },
+};
+
+impl<H: HistoryMode> ExecutionEndTracer<H> for InterruptTracer {
+    fn should_stop_execution(&self) -> bool {
+        self.interrupted.is_some()
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for InterruptTracer {}
+
+impl<S: WriteStorage, H: HistoryMode> ExecutionProcessing<S, H> for InterruptTracer {
+    fn after_cycle(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &mut BootloaderState,
+    ) {
+        self.on_cycle();
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for InterruptTracer {}
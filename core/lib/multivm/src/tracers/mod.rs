@@ -1,12 +1,23 @@
 pub use self::{
-    call_tracer::CallTracer, multivm_dispatcher::TracerDispatcher, prestate_tracer::PrestateTracer,
-    storage_invocation::StorageInvocations, validator::ValidationTracer,
+    call_tracer::CallTracer,
+    event_tracer::{EventFilter, EventTracer},
+    interrupt::InterruptTracer,
+    multivm_dispatcher::TracerDispatcher,
+    prestate_tracer::PrestateTracer,
+    storage_diff_tracer::{StorageDiffTracer, StorageSlotDiff},
+    storage_invocation::StorageInvocations,
+    validator::ValidationTracer,
 };
 
 mod call_tracer;
 pub mod dynamic;
+mod event_tracer;
+pub mod gas_profiler;
+mod interrupt;
 mod multivm_dispatcher;
 pub mod old;
 mod prestate_tracer;
+pub mod stepping;
+mod storage_diff_tracer;
 mod storage_invocation;
 mod validator;
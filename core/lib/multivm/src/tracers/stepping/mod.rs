@@ -0,0 +1,322 @@
+//! Tracer allowing to step through the VM execution instruction by instruction, e.g. in an interactive debugger.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Context as _;
+use zksync_types::{Address, U256};
+
+pub mod vm_latest;
+
+/// VM register value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Register {
+    pub value: U256,
+    pub is_pointer: bool,
+}
+
+/// Storage slot accessed by an instruction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StorageAccess {
+    pub address: Address,
+    pub key: U256,
+    /// Value of the slot before the instruction is executed. Not available for transient storage.
+    pub value: Option<U256>,
+    /// New value of the slot for writes.
+    pub written_value: Option<U256>,
+    pub is_transient: bool,
+}
+
+/// VM state before executing an instruction.
+#[derive(Debug, Clone)]
+pub struct VmStep {
+    /// Number of instructions executed before this one.
+    pub cycle: u64,
+    /// Human-readable instruction name, e.g. `Log(StorageRead)`.
+    pub opcode: String,
+    pub pc: u64,
+    pub sp: u64,
+    /// Depth of the call stack (1 for the bootloader).
+    pub depth: usize,
+    pub this_address: Address,
+    pub code_address: Address,
+    pub msg_sender: Address,
+    pub ergs_remaining: u32,
+    pub registers: Vec<Register>,
+    pub storage_access: Option<StorageAccess>,
+}
+
+/// Memory of the current call frame.
+pub trait FrameMemory {
+    /// Reads bytes from the heap of the current frame.
+    fn read_heap(&self, offset: u32, len: u32) -> Vec<u8>;
+    /// Reads bytes from the auxiliary heap of the current frame.
+    fn read_aux_heap(&self, offset: u32, len: u32) -> Vec<u8>;
+    /// Reads a stack slot of the current frame.
+    fn read_stack(&self, slot: u32) -> Register;
+}
+
+/// Breakpoint pausing the execution before an instruction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    /// Instructions with the name containing the specified string, e.g. `farcall` or `storagewrite`.
+    /// Names are compared case-insensitively, ignoring non-alphanumeric chars.
+    Opcode(String),
+    /// Instructions executed in the context of the specified contract.
+    Address(Address),
+    /// Reads or writes of the specified storage key (in any contract).
+    StorageKey(U256),
+}
+
+impl Breakpoint {
+    pub fn matches(&self, step: &VmStep) -> bool {
+        match self {
+            Self::Opcode(name) => normalize_opcode(&step.opcode).contains(&normalize_opcode(name)),
+            Self::Address(address) => step.this_address == *address,
+            Self::StorageKey(key) => step.storage_access.is_some_and(|access| access.key == *key),
+        }
+    }
+}
+
+fn normalize_opcode(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect()
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Opcode(name) => write!(formatter, "opcode:{name}"),
+            Self::Address(address) => write!(formatter, "address:{address:?}"),
+            Self::StorageKey(key) => write!(formatter, "storage:{key:#x}"),
+        }
+    }
+}
+
+/// Parses breakpoints in the `opcode:<name>`, `address:<address>` or `storage:<key>` format.
+impl FromStr for Breakpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = s
+            .split_once(':')
+            .context("breakpoint must have `<kind>:<value>` format")?;
+        Ok(match kind {
+            "opcode" | "op" => {
+                anyhow::ensure!(!value.is_empty(), "opcode name is empty");
+                Self::Opcode(value.to_owned())
+            }
+            "address" | "addr" => Self::Address(value.parse().context("invalid address")?),
+            "storage" | "key" => {
+                Self::StorageKey(parse_u256(value).context("invalid storage key")?)
+            }
+            _ => anyhow::bail!(
+                "unknown breakpoint kind `{kind}`; expected one of `opcode`, `address` or `storage`"
+            ),
+        })
+    }
+}
+
+/// Parses a hex (`0x`-prefixed) or decimal number.
+fn parse_u256(s: &str) -> anyhow::Result<U256> {
+    Ok(match s.strip_prefix("0x") {
+        Some(hex) => U256::from_str_radix(hex, 16)?,
+        None => U256::from_dec_str(s)?,
+    })
+}
+
+/// Action taken by [`StepDebugger`] after a pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepAction {
+    /// Execute the instruction and pause before the next one.
+    Step,
+    /// Continue execution until the next breakpoint.
+    Continue,
+    /// Abort the VM execution.
+    Abort,
+}
+
+/// Debugger driving [`StepTracer`].
+pub trait StepDebugger {
+    /// Called before executing an instruction when the execution is paused, i.e., after [`StepAction::Step`]
+    /// or if a breakpoint is hit. `breakpoint` is the index of the hit breakpoint in `breakpoints`;
+    /// the debugger may modify breakpoints.
+    fn on_pause(
+        &mut self,
+        step: &VmStep,
+        breakpoint: Option<usize>,
+        memory: &dyn FrameMemory,
+        breakpoints: &mut Vec<Breakpoint>,
+    ) -> StepAction;
+}
+
+/// Tracer pausing the VM execution on breakpoints or after each instruction, and handing control over
+/// to a [`StepDebugger`]. Currently, only the latest legacy VM is supported.
+#[derive(Debug)]
+pub struct StepTracer<D> {
+    debugger: D,
+    breakpoints: Vec<Breakpoint>,
+    paused: bool,
+    aborted: bool,
+    cycle: u64,
+}
+
+impl<D: StepDebugger> StepTracer<D> {
+    /// Creates a tracer. If `start_paused` is set, the execution is paused before the first instruction.
+    pub fn new(debugger: D, breakpoints: Vec<Breakpoint>, start_paused: bool) -> Self {
+        Self {
+            debugger,
+            breakpoints,
+            paused: start_paused,
+            aborted: false,
+            cycle: 0,
+        }
+    }
+
+    fn on_step(&mut self, step_fn: impl FnOnce(u64) -> VmStep, memory: &dyn FrameMemory) {
+        let cycle = self.cycle;
+        self.cycle += 1;
+        if self.aborted || (!self.paused && self.breakpoints.is_empty()) {
+            return;
+        }
+
+        let step = step_fn(cycle);
+        let breakpoint = self.breakpoints.iter().position(|bp| bp.matches(&step));
+        if !self.paused && breakpoint.is_none() {
+            return;
+        }
+        let action = self
+            .debugger
+            .on_pause(&step, breakpoint, memory, &mut self.breakpoints);
+        self.paused = action == StepAction::Step;
+        self.aborted = action == StepAction::Abort;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(opcode: &str) -> VmStep {
+        VmStep {
+            cycle: 0,
+            opcode: opcode.to_owned(),
+            pc: 0,
+            sp: 0,
+            depth: 1,
+            this_address: Address::repeat_byte(1),
+            code_address: Address::repeat_byte(1),
+            msg_sender: Address::zero(),
+            ergs_remaining: 0,
+            registers: vec![],
+            storage_access: None,
+        }
+    }
+
+    #[test]
+    fn parsing_breakpoints() {
+        let bp: Breakpoint = "opcode:FarCall".parse().unwrap();
+        assert_eq!(bp, Breakpoint::Opcode("FarCall".to_owned()));
+        let bp: Breakpoint = "address:0x0101010101010101010101010101010101010101"
+            .parse()
+            .unwrap();
+        assert_eq!(bp, Breakpoint::Address(Address::repeat_byte(1)));
+        let bp: Breakpoint = "storage:0x10".parse().unwrap();
+        assert_eq!(bp, Breakpoint::StorageKey(16.into()));
+        let bp: Breakpoint = "key:16".parse().unwrap();
+        assert_eq!(bp, Breakpoint::StorageKey(16.into()));
+
+        "farcall".parse::<Breakpoint>().unwrap_err();
+        "pc:10".parse::<Breakpoint>().unwrap_err();
+        "address:0x01".parse::<Breakpoint>().unwrap_err();
+    }
+
+    #[test]
+    fn matching_breakpoints() {
+        let far_call = step("FarCall(Normal)");
+        assert!(Breakpoint::Opcode("farcall".to_owned()).matches(&far_call));
+        assert!(Breakpoint::Opcode("far_call".to_owned()).matches(&far_call));
+        assert!(!Breakpoint::Opcode("nearcall".to_owned()).matches(&far_call));
+        assert!(Breakpoint::Address(Address::repeat_byte(1)).matches(&far_call));
+        assert!(!Breakpoint::StorageKey(1.into()).matches(&far_call));
+
+        let mut sstore = step("Log(StorageWrite)");
+        sstore.storage_access = Some(StorageAccess {
+            address: Address::repeat_byte(1),
+            key: 1.into(),
+            value: Some(U256::zero()),
+            written_value: Some(2.into()),
+            is_transient: false,
+        });
+        assert!(Breakpoint::StorageKey(1.into()).matches(&sstore));
+        assert!(Breakpoint::Opcode("storagewrite".to_owned()).matches(&sstore));
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingDebugger {
+        paused_at: Vec<u64>,
+    }
+
+    impl StepDebugger for RecordingDebugger {
+        fn on_pause(
+            &mut self,
+            step: &VmStep,
+            breakpoint: Option<usize>,
+            _memory: &dyn FrameMemory,
+            _breakpoints: &mut Vec<Breakpoint>,
+        ) -> StepAction {
+            self.paused_at.push(step.cycle);
+            match (step.cycle, breakpoint) {
+                (0, _) => StepAction::Step,
+                (1, _) => StepAction::Continue,
+                (_, Some(_)) => StepAction::Abort,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    struct NoMemory;
+
+    impl FrameMemory for NoMemory {
+        fn read_heap(&self, _offset: u32, _len: u32) -> Vec<u8> {
+            vec![]
+        }
+
+        fn read_aux_heap(&self, _offset: u32, _len: u32) -> Vec<u8> {
+            vec![]
+        }
+
+        fn read_stack(&self, _slot: u32) -> Register {
+            Register {
+                value: U256::zero(),
+                is_pointer: false,
+            }
+        }
+    }
+
+    #[test]
+    fn stepping_and_continuing() {
+        let breakpoints = vec![Breakpoint::Opcode("storagewrite".to_owned())];
+        let mut tracer = StepTracer::new(RecordingDebugger::default(), breakpoints, true);
+        let opcodes = [
+            "Add(Add)",
+            "Add(Add)",
+            "Sub(Sub)",
+            "Log(StorageWrite)",
+            "Log(StorageWrite)",
+        ];
+        for opcode in opcodes {
+            tracer.on_step(
+                |cycle| VmStep {
+                    cycle,
+                    ..step(opcode)
+                },
+                &NoMemory,
+            );
+        }
+        // Paused on the start, then stepped once, continued to the breakpoint and aborted.
+        assert_eq!(tracer.debugger.paused_at, [0, 1, 3]);
+        assert!(tracer.aborted);
+    }
+}
@@ -0,0 +1,128 @@
+use zk_evm_1_5_0::{
+    aux_structures::MemoryPage,
+    tracing::{BeforeExecutionData, VmLocalStateData},
+    zkevm_opcode_defs::{decoding::AllowedPcOrImm, LogOpcode, Opcode},
+};
+use zksync_types::{h256_to_u256, u256_to_h256, AccountTreeId, StorageKey};
+
+use super::{FrameMemory, Register, StepDebugger, StepTracer, StorageAccess, VmStep};
+use crate::{
+    interface::{
+        storage::{StoragePtr, WriteStorage},
+        tracer::{TracerExecutionStatus, TracerExecutionStopReason},
+        Halt,
+    },
+    tracers::dynamic::vm_1_5_0::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+/// Memory of the current call frame. Stack, heap and aux heap pages follow the base page of the frame.
+struct LatestFrameMemory<'a, H: HistoryMode> {
+    memory: &'a SimpleMemory<H>,
+    base_page: MemoryPage,
+}
+
+impl<H: HistoryMode> FrameMemory for LatestFrameMemory<'_, H> {
+    fn read_heap(&self, offset: u32, len: u32) -> Vec<u8> {
+        let page = self.base_page.0 + 2;
+        self.memory
+            .read_unaligned_bytes(page as usize, offset as usize, len as usize)
+    }
+
+    fn read_aux_heap(&self, offset: u32, len: u32) -> Vec<u8> {
+        let page = self.base_page.0 + 3;
+        self.memory
+            .read_unaligned_bytes(page as usize, offset as usize, len as usize)
+    }
+
+    fn read_stack(&self, slot: u32) -> Register {
+        let page = self.base_page.0 + 1;
+        let value = self.memory.read_slot(page as usize, slot as usize);
+        Register {
+            value: value.value,
+            is_pointer: value.is_pointer,
+        }
+    }
+}
+
+fn storage_access<S: WriteStorage>(
+    state: &VmLocalStateData<'_>,
+    data: &BeforeExecutionData,
+    storage: &StoragePtr<S>,
+) -> Option<StorageAccess> {
+    let (is_write, is_transient) = match data.opcode.variant.opcode {
+        Opcode::Log(LogOpcode::StorageRead) => (false, false),
+        Opcode::Log(LogOpcode::StorageWrite) => (true, false),
+        Opcode::Log(LogOpcode::TransientStorageRead) => (false, true),
+        Opcode::Log(LogOpcode::TransientStorageWrite) => (true, true),
+        _ => return None,
+    };
+    let address = state.vm_local_state.callstack.current.this_address;
+    let key = data.src0_value.value;
+    let value = (!is_transient).then(|| {
+        let storage_key = StorageKey::new(AccountTreeId::new(address), u256_to_h256(key));
+        h256_to_u256(storage.borrow_mut().read_value(&storage_key))
+    });
+    Some(StorageAccess {
+        address,
+        key,
+        value,
+        written_value: is_write.then_some(data.src1_value.value),
+        is_transient,
+    })
+}
+
+impl<S: WriteStorage, H: HistoryMode, D: StepDebugger> DynTracer<S, SimpleMemory<H>>
+    for StepTracer<D>
+{
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        memory: &SimpleMemory<H>,
+        storage: StoragePtr<S>,
+    ) {
+        let current = &state.vm_local_state.callstack.current;
+        let frame_memory = LatestFrameMemory {
+            memory,
+            base_page: current.base_memory_page,
+        };
+        let step_fn = |cycle| VmStep {
+            cycle,
+            opcode: format!("{:?}", data.opcode.variant.opcode),
+            pc: current.pc.as_u64(),
+            sp: current.sp.as_u64(),
+            depth: state.vm_local_state.callstack.depth(),
+            this_address: current.this_address,
+            code_address: current.code_address,
+            msg_sender: current.msg_sender,
+            ergs_remaining: current.ergs_remaining,
+            registers: state
+                .vm_local_state
+                .registers
+                .iter()
+                .map(|register| Register {
+                    value: register.value,
+                    is_pointer: register.is_pointer,
+                })
+                .collect(),
+            storage_access: storage_access(&state, &data, &storage),
+        };
+        self.on_step(step_fn, &frame_memory);
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode, D: StepDebugger> VmTracer<S, H> for StepTracer<D> {
+    fn finish_cycle(
+        &mut self,
+        _state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &mut BootloaderState,
+    ) -> TracerExecutionStatus {
+        if self.aborted {
+            return TracerExecutionStatus::Stop(TracerExecutionStopReason::Abort(
+                Halt::TracerCustom("Execution aborted by the debugger".to_string()),
+            ));
+        }
+        TracerExecutionStatus::Continue
+    }
+}
@@ -0,0 +1,80 @@
+use std::{collections::HashMap, sync::Arc};
+
+use once_cell::sync::OnceCell;
+use zksync_types::{StorageKey, H256};
+
+pub mod vm_latest;
+
+/// Accesses to a single storage slot made during the traced execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotDiff {
+    pub key: StorageKey,
+    /// Slot value before the first access.
+    pub pre: H256,
+    /// Slot value after the execution has finished. Reverted writes are not reflected in this value.
+    pub post: H256,
+    /// Whether the slot was read.
+    pub read: bool,
+    /// Whether the slot was written to (the write may have been reverted afterwards).
+    pub written: bool,
+}
+
+impl StorageSlotDiff {
+    /// Returns `true` if the slot value has changed as a result of the execution.
+    pub fn is_changed(&self) -> bool {
+        self.pre != self.post
+    }
+}
+
+/// Tracer recording every storage slot read or written during the execution together with its
+/// pre- and post-execution values.
+///
+/// The tracer should be attached to a single transaction (i.e., used with the one-tx execution mode);
+/// it will then report accesses made by this transaction, including the ones made by the bootloader
+/// on its behalf (e.g., nonce increments or fee transfers). Transient storage is not tracked.
+#[derive(Debug, Clone)]
+pub struct StorageDiffTracer {
+    slots: Vec<StorageSlotDiff>,
+    slot_indices: HashMap<StorageKey, usize>,
+    result: Arc<OnceCell<Vec<StorageSlotDiff>>>,
+}
+
+impl StorageDiffTracer {
+    pub fn new(result: Arc<OnceCell<Vec<StorageSlotDiff>>>) -> Self {
+        Self {
+            slots: vec![],
+            slot_indices: HashMap::new(),
+            result,
+        }
+    }
+
+    fn record_access(&mut self, key: StorageKey, current_value: H256, is_write: bool) {
+        let idx = *self.slot_indices.entry(key).or_insert_with(|| {
+            self.slots.push(StorageSlotDiff {
+                key,
+                pre: current_value,
+                post: current_value,
+                read: false,
+                written: false,
+            });
+            self.slots.len() - 1
+        });
+        let slot = &mut self.slots[idx];
+        if is_write {
+            slot.written = true;
+        } else {
+            slot.read = true;
+        }
+    }
+
+    /// Sets post-execution values for the accessed slots and stores the result. Slots are reported
+    /// in the order of their first access.
+    fn store_result(&mut self, read_value: impl Fn(&StorageKey) -> H256) {
+        let mut slots = std::mem::take(&mut self.slots);
+        for slot in &mut slots {
+            slot.post = read_value(&slot.key);
+        }
+        self.slot_indices.clear();
+        self.result.set(slots).unwrap();
+    }
+}
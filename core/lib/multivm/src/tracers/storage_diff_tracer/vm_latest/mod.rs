@@ -0,0 +1,50 @@
+use zk_evm_1_5_0::{
+    tracing::{BeforeExecutionData, VmLocalStateData},
+    zkevm_opcode_defs::{LogOpcode, Opcode},
+};
+use zksync_types::{u256_to_h256, AccountTreeId, StorageKey};
+
+use super::StorageDiffTracer;
+use crate::{
+    interface::{
+        storage::{StoragePtr, WriteStorage},
+        tracer::VmExecutionStopReason,
+    },
+    tracers::dynamic::vm_1_5_0::DynTracer,
+    vm_latest::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for StorageDiffTracer {
+    fn before_execution(
+        &mut self,
+        state: VmLocalStateData<'_>,
+        data: BeforeExecutionData,
+        _memory: &SimpleMemory<H>,
+        storage: StoragePtr<S>,
+    ) {
+        let is_write = match data.opcode.variant.opcode {
+            Opcode::Log(LogOpcode::StorageRead) => false,
+            Opcode::Log(LogOpcode::StorageWrite) => true,
+            _ => return,
+        };
+        // Storage opcodes always access the storage of the current frame, and take the slot in `src0`.
+        let address = state.vm_local_state.callstack.current.this_address;
+        let key = StorageKey::new(
+            AccountTreeId::new(address),
+            u256_to_h256(data.src0_value.value),
+        );
+        let current_value = storage.borrow_mut().read_value(&key);
+        self.record_access(key, current_value, is_write);
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for StorageDiffTracer {
+    fn after_vm_execution(
+        &mut self,
+        state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: VmExecutionStopReason,
+    ) {
+        self.store_result(|key| u256_to_h256(state.storage.storage.read_from_storage(key)));
+    }
+}
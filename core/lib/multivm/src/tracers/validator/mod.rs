@@ -14,7 +14,7 @@ use zksync_types::{
     StorageKey, H256, U256,
 };
 use zksync_vm_interface::{
-    tracer::{TimestampAsserterParams, ValidationTraces},
+    tracer::{TimestampAsserterParams, ValidationTraces, ValidationViolation, ViolationLocation},
     L1BatchEnv,
 };
 
@@ -55,7 +55,7 @@ pub struct ValidationTracer<H> {
     timestamp_asserter_params: Option<TimestampAsserterParams>,
     vm_version: VmVersion,
     l1_batch_env: L1BatchEnv,
-    pub result: Arc<OnceCell<ViolatedValidationRule>>,
+    pub result: Arc<OnceCell<ValidationViolation>>,
     pub traces: Arc<Mutex<ValidationTraces>>,
     _marker: PhantomData<fn(H) -> H>,
 }
@@ -87,7 +87,7 @@ impl<H> ValidationTracer<H> {
         }
     }
 
-    pub fn get_result(&self) -> Arc<OnceCell<ViolatedValidationRule>> {
+    pub fn get_result(&self) -> Arc<OnceCell<ValidationViolation>> {
         self.result.clone()
     }
 
@@ -95,7 +95,12 @@ impl<H> ValidationTracer<H> {
         self.traces.clone()
     }
 
-    fn process_validation_round_result(&mut self, result: ValidationRoundResult) {
+    /// Processes the result of checks for a single opcode. `location` is only called if a rule is violated.
+    fn process_validation_round_result(
+        &mut self,
+        result: ValidationRoundResult,
+        location: impl FnOnce() -> ViolationLocation,
+    ) {
         match result {
             Ok(NewTrustedValidationItems {
                 new_allowed_slots,
@@ -104,12 +109,16 @@ impl<H> ValidationTracer<H> {
                 self.auxilary_allowed_slots.extend(new_allowed_slots);
                 self.trusted_addresses.extend(new_trusted_addresses);
             }
-            Err(err) => {
+            Err(rule) => {
                 if self.result.get().is_some() {
                     tracing::trace!("Validation error is already set, skipping");
                     return;
                 }
-                self.result.set(err).expect("Result should be empty");
+                let violation = ValidationViolation {
+                    rule,
+                    location: Some(location()),
+                };
+                self.result.set(violation).expect("Result should be empty");
             }
         }
     }
@@ -10,7 +10,10 @@ use zksync_types::{
 use crate::{
     interface::{
         storage::{StoragePtr, WriteStorage},
-        tracer::{TracerExecutionStatus, TracerExecutionStopReason, ViolatedValidationRule},
+        tracer::{
+            TracerExecutionStatus, TracerExecutionStopReason, ViolatedValidationRule,
+            ViolationLocation,
+        },
         Halt,
     },
     tracers::{
@@ -146,7 +149,14 @@ impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H::Vm1_4_1>>
 
             let validation_round_result =
                 self.check_user_restrictions_vm_1_4_1(state, data, memory, storage);
-            self.process_validation_round_result(validation_round_result);
+            self.process_validation_round_result(validation_round_result, || {
+                let frame = &state.vm_local_state.callstack.current;
+                ViolationLocation {
+                    opcode: format!("{:?}", data.opcode.variant.opcode),
+                    pc: frame.pc,
+                    frame_address: frame.this_address,
+                }
+            });
         }
 
         let hook = VmHook::from_opcode_memory(&state, &data);
@@ -194,7 +204,7 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H::Vm1_4_1> for ValidationTrac
         }
         if let Some(result) = self.result.get() {
             return TracerExecutionStatus::Stop(TracerExecutionStopReason::Abort(
-                Halt::TracerCustom(format!("Validation error: {:#?}", result)),
+                Halt::TracerCustom(format!("Validation error: {:#?}", result.rule)),
             ));
         }
         TracerExecutionStatus::Continue
@@ -10,7 +10,7 @@ use zksync_types::{
 use crate::{
     interface::{
         storage::{StoragePtr, WriteStorage},
-        tracer::ViolatedValidationRule,
+        tracer::{ViolatedValidationRule, ViolationLocation},
         VmExecutionResultAndLogs,
     },
     tracers::{
@@ -146,7 +146,14 @@ impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H::VmVirtualBloc
 
             let validation_round_result =
                 self.check_user_restrictions_vm_virtual_blocks(state, data, memory, storage);
-            self.process_validation_round_result(validation_round_result);
+            self.process_validation_round_result(validation_round_result, || {
+                let frame = &state.vm_local_state.callstack.current;
+                ViolationLocation {
+                    opcode: format!("{:?}", data.opcode.variant.opcode),
+                    pc: frame.pc,
+                    frame_address: frame.this_address,
+                }
+            });
         }
 
         let hook = VmHook::from_opcode_memory(&state, &data);
@@ -2,7 +2,7 @@
 mod shadow;
 mod shared;
 #[cfg(test)]
-mod testonly;
+pub(crate) mod testonly;
 pub mod vm_1_3_2;
 pub mod vm_1_4_1;
 pub mod vm_1_4_2;
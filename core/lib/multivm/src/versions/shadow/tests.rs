@@ -297,6 +297,15 @@ mod is_write_initial {
     }
 }
 
+mod l1_messenger {
+    use crate::versions::testonly::l1_messenger::*;
+
+    #[test]
+    fn l1_messenger_failure() {
+        test_l1_messenger_failure::<super::ShadowedFastVm>();
+    }
+}
+
 mod l1_tx_execution {
     use crate::versions::testonly::l1_tx_execution::*;
 
@@ -309,6 +318,16 @@ mod l1_tx_execution {
     fn l1_tx_execution_high_gas_limit() {
         test_l1_tx_execution_high_gas_limit::<super::ShadowedFastVm>();
     }
+
+    #[test]
+    fn forced_l1_tx_from_arbitrary_sender() {
+        test_forced_l1_tx_from_arbitrary_sender::<super::ShadowedFastVm>();
+    }
+
+    #[test]
+    fn underfunded_l1_tx() {
+        test_underfunded_l1_tx::<super::ShadowedFastVm>();
+    }
 }
 
 mod l2_blocks {
@@ -0,0 +1,89 @@
+use ethabi::Token;
+use zksync_test_contracts::{L1TxBuilder, TestContract};
+use zksync_types::{
+    AccountTreeId, Address, Execute, StorageKey, Transaction, H256, L1_MESSENGER_ADDRESS, U256,
+};
+
+use super::{l1_tx_execution::l1_tx_status_log, tester::VmTesterBuilder, TestedVm};
+use crate::interface::{InspectExecutionMode, TxExecutionMode, VmInterfaceExt};
+
+fn call_mock_messenger(
+    sender: Address,
+    serial_id: u64,
+    function: &str,
+    args: &[Token],
+) -> Transaction {
+    let calldata = TestContract::mock_l1_messenger()
+        .function(function)
+        .encode_input(args)
+        .unwrap();
+    L1TxBuilder::new(
+        sender,
+        Execute {
+            contract_address: Some(L1_MESSENGER_ADDRESS),
+            calldata,
+            value: U256::zero(),
+            factory_deps: vec![],
+        },
+    )
+    .with_serial_id(serial_id)
+    .build()
+}
+
+fn sent_message_count<VM: TestedVm>(vm: &mut VM) -> U256 {
+    // `sentMessageCount` is stored in the second slot of the mock messenger.
+    let key = StorageKey::new(
+        AccountTreeId::new(L1_MESSENGER_ADDRESS),
+        H256::from_low_u64_be(1),
+    );
+    vm.read_storage(key)
+}
+
+/// Checks that L1 transactions failing because of `L1Messenger` errors are reverted and reported to L1 as failed.
+pub(crate) fn test_l1_messenger_failure<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_mock_l1_messenger()
+        .build::<VM>();
+
+    let sender = Address::repeat_byte(0x42);
+    let message = Token::Bytes(b"hello".to_vec());
+    let tx = call_mock_messenger(sender, 0, "sendToL1", &[message.clone()]);
+    let tx_hash = tx.hash();
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!res.result.is_failed(), "{:?}", res.result);
+    assert_eq!(
+        res.logs.user_l2_to_l1_logs,
+        [l1_tx_status_log(tx_hash, 0, true)]
+    );
+    let messenger_events = res
+        .logs
+        .events
+        .iter()
+        .filter(|event| event.address == L1_MESSENGER_ADDRESS);
+    assert_eq!(messenger_events.count(), 1);
+    assert_eq!(sent_message_count(&mut vm.vm), U256::one());
+
+    let tx = call_mock_messenger(sender, 1, "setSendToL1Failure", &[Token::Bool(true)]);
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!res.result.is_failed(), "{:?}", res.result);
+
+    let tx = call_mock_messenger(sender, 2, "sendToL1", &[message]);
+    let tx_hash = tx.hash();
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(res.result.is_failed(), "{:?}", res.result);
+    assert_eq!(
+        res.logs.user_l2_to_l1_logs,
+        [l1_tx_status_log(tx_hash, 2, false)]
+    );
+    assert!(res
+        .logs
+        .events
+        .iter()
+        .all(|event| event.address != L1_MESSENGER_ADDRESS));
+    assert_eq!(sent_message_count(&mut vm.vm), U256::one());
+}
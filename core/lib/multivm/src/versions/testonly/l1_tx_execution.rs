@@ -2,11 +2,11 @@ use assert_matches::assert_matches;
 use ethabi::Token;
 use zksync_contracts::l1_messenger_contract;
 use zksync_system_constants::{BOOTLOADER_ADDRESS, L1_MESSENGER_ADDRESS};
-use zksync_test_contracts::{TestContract, TxType};
+use zksync_test_contracts::{L1TxBuilder, TestContract, TxType};
 use zksync_types::{
     get_code_key, get_known_code_key, h256_to_u256,
     l2_to_l1_log::{L2ToL1Log, UserL2ToL1Log},
-    u256_to_h256, Address, Execute, ExecuteTransactionCommon, U256,
+    u256_to_h256, Address, Execute, ExecuteTransactionCommon, H256, U256,
 };
 
 use super::{tester::VmTesterBuilder, ContractToDeploy, TestedVm, BASE_SYSTEM_CONTRACTS};
@@ -17,6 +17,22 @@ use crate::{
     utils::StorageWritesDeduplicator,
 };
 
+/// Returns the log sent by the bootloader to L1 with the execution status of an L1 transaction.
+pub(super) fn l1_tx_status_log(
+    tx_hash: H256,
+    tx_number_in_block: u16,
+    success: bool,
+) -> UserL2ToL1Log {
+    UserL2ToL1Log(L2ToL1Log {
+        shard_id: 0,
+        is_service: true,
+        tx_number_in_block,
+        sender: BOOTLOADER_ADDRESS,
+        key: tx_hash,
+        value: u256_to_h256(U256::from(u32::from(success))),
+    })
+}
+
 pub(crate) fn test_l1_tx_execution<VM: TestedVm>() {
     // In this test, we try to execute a contract deployment from L1
     // Here instead of marking code hash via the bootloader means, we will be
@@ -51,17 +67,7 @@ pub(crate) fn test_l1_tx_execution<VM: TestedVm>() {
     );
     let tx_hash = deploy_tx.tx.hash();
 
-    let required_l2_to_l1_logs: Vec<_> = vec![L2ToL1Log {
-        shard_id: 0,
-        is_service: true,
-        tx_number_in_block: 0,
-        sender: BOOTLOADER_ADDRESS,
-        key: tx_hash,
-        value: u256_to_h256(U256::from(1u32)),
-    }]
-    .into_iter()
-    .map(UserL2ToL1Log)
-    .collect();
+    let required_l2_to_l1_logs = vec![l1_tx_status_log(tx_hash, 0, true)];
 
     vm.vm.push_transaction(deploy_tx.tx.clone());
 
@@ -223,3 +229,61 @@ pub(crate) fn test_l1_tx_execution_gas_estimation_with_low_gas<VM: TestedVm>() {
             if msg.contains("reverted with empty reason")
     );
 }
+
+/// Tests a priority operation forced from L1 by an address without an L2 account (e.g., an L1 contract).
+pub(crate) fn test_forced_l1_tx_from_arbitrary_sender<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .build::<VM>();
+
+    let sender = Address::repeat_byte(0x42);
+    let recipient = Address::repeat_byte(0x43);
+    let value = U256::from(1_000_000);
+    let tx = L1TxBuilder::new(
+        sender,
+        Execute {
+            contract_address: Some(recipient),
+            calldata: vec![],
+            value,
+            factory_deps: vec![],
+        },
+    )
+    .build();
+    let tx_hash = tx.hash();
+
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!res.result.is_failed(), "{:?}", res.result);
+    assert_eq!(
+        res.logs.user_l2_to_l1_logs,
+        [l1_tx_status_log(tx_hash, 0, true)]
+    );
+    assert_eq!(vm.get_eth_balance(recipient), value);
+}
+
+/// Tests a priority operation that mints less than its value (which should be prevented by L1 contracts).
+pub(crate) fn test_underfunded_l1_tx<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .build::<VM>();
+
+    let recipient = Address::repeat_byte(0x43);
+    let tx = L1TxBuilder::new(
+        Address::repeat_byte(0x42),
+        Execute {
+            contract_address: Some(recipient),
+            calldata: vec![],
+            value: U256::from(1_000_000),
+            factory_deps: vec![],
+        },
+    )
+    .with_to_mint(U256::zero())
+    .build();
+
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(res.result.is_failed(), "{:?}", res.result);
+    assert_eq!(vm.get_eth_balance(recipient), U256::zero());
+}
@@ -40,6 +40,7 @@ pub(super) mod evm_emulator;
 pub(super) mod gas_limit;
 pub(super) mod get_used_contracts;
 pub(super) mod is_write_initial;
+pub(super) mod l1_messenger;
 pub(super) mod l1_tx_execution;
 pub(super) mod l2_blocks;
 pub(super) mod nonce_holder;
@@ -84,7 +85,7 @@ pub(crate) fn filter_out_base_system_contracts(all_bytecode_hashes: &mut HashSet
     }
 }
 
-pub(super) fn default_system_env() -> SystemEnv {
+pub(crate) fn default_system_env() -> SystemEnv {
     SystemEnv {
         zk_porter_available: false,
         version: ProtocolVersionId::latest(),
@@ -96,7 +97,7 @@ pub(super) fn default_system_env() -> SystemEnv {
     }
 }
 
-pub(super) fn default_l1_batch(number: L1BatchNumber) -> L1BatchEnv {
+pub(crate) fn default_l1_batch(number: L1BatchNumber) -> L1BatchEnv {
     // Add a bias to the timestamp to make it more realistic / "random".
     let timestamp = 1_700_000_000 + u64::from(number.0);
     L1BatchEnv {
@@ -122,7 +123,7 @@ pub(super) fn default_pubdata_builder() -> Rc<dyn PubdataBuilder> {
     Rc::new(RollupPubdataBuilder::new(Address::zero()))
 }
 
-pub(super) fn make_address_rich(storage: &mut InMemoryStorage, address: Address) {
+pub(crate) fn make_address_rich(storage: &mut InMemoryStorage, address: Address) {
     let key = storage_key_for_eth_balance(&address);
     storage.set_value(key, u256_to_h256(U256::from(10_u64.pow(19))));
 }
@@ -5,7 +5,7 @@ use zksync_test_contracts::{Account, TestContract, TxType};
 use zksync_types::{
     utils::{deployed_address_create, storage_key_for_eth_balance},
     writes::StateDiffRecord,
-    Address, L1BatchNumber, StorageKey, Transaction, H256, U256,
+    Address, L1BatchNumber, StorageKey, Transaction, H256, L1_MESSENGER_ADDRESS, U256,
 };
 use zksync_vm_interface::{
     pubdata::PubdataBuilder, CurrentExecutionState, InspectExecutionMode, VmExecutionResultAndLogs,
@@ -141,6 +141,16 @@ impl VmTesterBuilder {
         self
     }
 
+    /// Replaces `L1Messenger` with [a mock](TestContract::mock_l1_messenger()) that can be programmed to fail
+    /// sending messages. Must be called after [`Self::with_custom_contracts()`], which overwrites custom contracts.
+    pub(crate) fn with_mock_l1_messenger(mut self) -> Self {
+        self.custom_contracts.push(ContractToDeploy::new(
+            TestContract::mock_l1_messenger().bytecode.to_vec(),
+            L1_MESSENGER_ADDRESS,
+        ));
+        self
+    }
+
     pub(crate) fn build<VM>(self) -> VmTester<VM>
     where
         VM: VmFactory<StorageView<InMemoryStorage>>,
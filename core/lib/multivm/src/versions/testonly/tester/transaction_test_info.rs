@@ -161,6 +161,9 @@ impl TransactionTestInfo {
                         panic!("Error types mismatch");
                     }
                 },
+                ExecutionResult::Interrupted { reason } => {
+                    panic!("Transaction execution was interrupted: {reason}")
+                }
             },
             TxExpectedResult::Processed { .. } => {
                 assert!(!result.result.is_failed());
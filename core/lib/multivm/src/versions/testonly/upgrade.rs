@@ -1,13 +1,11 @@
 use zksync_contracts::{deployer_contract, load_sys_contract};
-use zksync_test_contracts::{TestContract, TxType};
+use zksync_test_contracts::{L1TxBuilder, TestContract, TxType};
 use zksync_types::{
     bytecode::BytecodeHash,
     ethabi::{Contract, Token},
-    get_code_key, get_known_code_key, h256_to_u256,
-    protocol_upgrade::ProtocolUpgradeTxCommonData,
-    u256_to_h256, Address, Execute, ExecuteTransactionCommon, Transaction,
-    COMPLEX_UPGRADER_ADDRESS, CONTRACT_DEPLOYER_ADDRESS, CONTRACT_FORCE_DEPLOYER_ADDRESS, H256,
-    REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256,
+    get_code_key, get_known_code_key, h256_to_u256, u256_to_h256, Address, Execute,
+    ProtocolVersionId, Transaction, COMPLEX_UPGRADER_ADDRESS, CONTRACT_DEPLOYER_ADDRESS,
+    CONTRACT_FORCE_DEPLOYER_ADDRESS, H256, U256,
 };
 
 use super::{get_empty_storage, tester::VmTesterBuilder, TestedVm};
@@ -255,17 +253,9 @@ fn get_forced_deploy_tx(deployment: &[ForceDeployment]) -> Transaction {
         value: U256::zero(),
     };
 
-    Transaction {
-        common_data: ExecuteTransactionCommon::ProtocolUpgrade(ProtocolUpgradeTxCommonData {
-            sender: CONTRACT_FORCE_DEPLOYER_ADDRESS,
-            gas_limit: U256::from(200_000_000u32),
-            gas_per_pubdata_limit: REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into(),
-            ..Default::default()
-        }),
-        execute,
-        received_timestamp_ms: 0,
-        raw_bytes: None,
-    }
+    L1TxBuilder::new(CONTRACT_FORCE_DEPLOYER_ADDRESS, execute)
+        .with_gas_limit(U256::from(200_000_000u32))
+        .build_upgrade(ProtocolVersionId::latest())
 }
 
 // Returns the transaction that performs a complex protocol upgrade.
@@ -304,17 +294,9 @@ fn get_complex_upgrade_tx(
         value: U256::zero(),
     };
 
-    Transaction {
-        common_data: ExecuteTransactionCommon::ProtocolUpgrade(ProtocolUpgradeTxCommonData {
-            sender: CONTRACT_FORCE_DEPLOYER_ADDRESS,
-            gas_limit: U256::from(200_000_000u32),
-            gas_per_pubdata_limit: REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into(),
-            ..Default::default()
-        }),
-        execute,
-        received_timestamp_ms: 0,
-        raw_bytes: None,
-    }
+    L1TxBuilder::new(CONTRACT_FORCE_DEPLOYER_ADDRESS, execute)
+        .with_gas_limit(U256::from(200_000_000u32))
+        .build_upgrade(ProtocolVersionId::latest())
 }
 
 fn get_complex_upgrader_abi() -> Contract {
@@ -0,0 +1,16 @@
+use vise::{Buckets, Histogram, Metrics};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "vm_fast")]
+pub(super) struct FastVmMetrics {
+    /// Number of bytecodes decoded into programs during a batch.
+    #[metrics(buckets = Buckets::exponential(1.0..=4096.0, 2.0))]
+    pub decoded_programs: Histogram<usize>,
+    /// Number of decommits during a batch served by the batch-scoped program cache without decoding.
+    /// Decommits of system contracts preloaded into the cache are not counted.
+    #[metrics(buckets = Buckets::exponential(1.0..=1_048_576.0, 4.0))]
+    pub program_cache_hits: Histogram<usize>,
+}
+
+#[vise::register]
+pub(super) static FAST_VM_METRICS: vise::Global<FastVmMetrics> = vise::Global::new();
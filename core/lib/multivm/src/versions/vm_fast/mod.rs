@@ -11,6 +11,7 @@ mod evm_deploy_tracer;
 mod glue;
 mod hook;
 mod initial_bootloader_memory;
+mod metrics;
 mod refund;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,6 @@
+use crate::{versions::testonly::l1_messenger::test_l1_messenger_failure, vm_fast::Vm};
+
+#[test]
+fn l1_messenger_failure() {
+    test_l1_messenger_failure::<Vm<_>>();
+}
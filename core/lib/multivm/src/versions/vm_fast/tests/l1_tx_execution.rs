@@ -1,7 +1,8 @@
 use crate::{
     versions::testonly::l1_tx_execution::{
-        test_l1_tx_execution, test_l1_tx_execution_gas_estimation_with_low_gas,
-        test_l1_tx_execution_high_gas_limit,
+        test_forced_l1_tx_from_arbitrary_sender, test_l1_tx_execution,
+        test_l1_tx_execution_gas_estimation_with_low_gas, test_l1_tx_execution_high_gas_limit,
+        test_underfunded_l1_tx,
     },
     vm_fast::Vm,
 };
@@ -20,3 +21,13 @@ fn l1_tx_execution_high_gas_limit() {
 fn l1_tx_execution_gas_estimation_with_low_gas() {
     test_l1_tx_execution_gas_estimation_with_low_gas::<Vm<_>>();
 }
+
+#[test]
+fn forced_l1_tx_from_arbitrary_sender() {
+    test_forced_l1_tx_from_arbitrary_sender::<Vm<_>>();
+}
+
+#[test]
+fn underfunded_l1_tx() {
+    test_underfunded_l1_tx::<Vm<_>>();
+}
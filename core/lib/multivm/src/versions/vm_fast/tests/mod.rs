@@ -26,10 +26,12 @@ mod evm_emulator;
 mod gas_limit;
 mod get_used_contracts;
 mod is_write_initial;
+mod l1_messenger;
 mod l1_tx_execution;
 mod l2_blocks;
 mod nonce_holder;
 mod precompiles;
+mod program_cache;
 mod refunds;
 mod require_eip712;
 mod rollbacks;
@@ -0,0 +1,70 @@
+use assert_matches::assert_matches;
+use zksync_test_contracts::TxType;
+use zksync_types::h256_to_u256;
+
+use crate::{
+    interface::{
+        storage::{ImmutableStorageView, InMemoryStorage},
+        ExecutionResult, InspectExecutionMode, VmInterface, VmInterfaceExt,
+        VmInterfaceHistoryEnabled,
+    },
+    versions::testonly::{VmTester, VmTesterBuilder},
+    vm_fast::{vm::ProgramCacheStats, Vm},
+};
+
+type TestedFastVm = Vm<ImmutableStorageView<InMemoryStorage>>;
+
+fn call_test_contract(vm_tester: &mut VmTester<TestedFastVm>) -> ProgramCacheStats {
+    let tx = vm_tester.rich_accounts[0].get_test_contract_transaction(
+        vm_tester.test_contract.unwrap(),
+        false,
+        Default::default(),
+        false,
+        TxType::L2,
+    );
+    vm_tester.vm.push_transaction(tx);
+    let result = vm_tester.vm.execute(InspectExecutionMode::OneTx);
+    assert_matches!(result.result, ExecutionResult::Success { .. });
+    vm_tester.vm.world.program_cache_stats()
+}
+
+#[test]
+fn programs_are_decoded_once_per_batch() {
+    let mut vm_tester = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .build::<TestedFastVm>();
+    vm_tester.deploy_test_contract();
+
+    let first_stats = call_test_contract(&mut vm_tester);
+    assert!(first_stats.decoded > 0);
+    // The second transaction calls the same contracts, so all of them must be served from the cache.
+    let second_stats = call_test_contract(&mut vm_tester);
+    assert_eq!(second_stats.decoded, first_stats.decoded);
+    assert!(second_stats.hits > first_stats.hits);
+
+    // Rolling back a transaction must not evict decoded programs.
+    vm_tester.vm.make_snapshot();
+    call_test_contract(&mut vm_tester);
+    vm_tester.vm.rollback_to_the_latest_snapshot();
+    let stats_after_rollback = call_test_contract(&mut vm_tester);
+    assert_eq!(stats_after_rollback.decoded, first_stats.decoded);
+}
+
+#[test]
+fn decommitting_preloaded_programs_is_not_a_cache_hit() {
+    let mut vm_tester = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .build::<TestedFastVm>();
+    let default_aa_hash = vm_tester
+        .vm
+        .system_env
+        .base_system_smart_contracts
+        .default_aa
+        .hash;
+
+    let stats = vm_tester.vm.world.program_cache_stats();
+    zksync_vm2::World::decommit(&mut vm_tester.vm.world, h256_to_u256(default_aa_hash));
+    assert_eq!(vm_tester.vm.world.program_cache_stats(), stats);
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, fmt, mem, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, mem,
+    rc::Rc,
+};
 
 use zk_evm_1_5_0::{
     aux_structures::LogQuery, zkevm_opcode_defs::system_params::INITIAL_FRAME_FORMAL_EH_LOCATION,
@@ -31,6 +35,7 @@ use super::{
     evm_deploy_tracer::{DynamicBytecodes, EvmDeployTracer},
     hook::Hook,
     initial_bootloader_memory::bootloader_initial_memory,
+    metrics::FAST_VM_METRICS,
     transaction_data::TransactionData,
 };
 use crate::{
@@ -77,7 +82,7 @@ impl VmRunResult {
     fn should_ignore_vm_logs(&self) -> bool {
         match &self.execution_result {
             ExecutionResult::Success { .. } => false,
-            ExecutionResult::Halt { .. } => true,
+            ExecutionResult::Halt { .. } | ExecutionResult::Interrupted { .. } => true,
             // Logs generated during reverts should only be ignored if the revert has reached the root (bootloader) call frame,
             // which is only possible with `TxExecutionMode::EthCall`.
             ExecutionResult::Revert { .. } => self.execution_ended,
@@ -757,6 +762,11 @@ impl<S: ReadStorage, Tr: Tracer + Default + 'static> VmInterface for Vm<S, Tr> {
             VmExecutionMode::Batch,
             Some(pubdata_builder.as_ref()),
         );
+        let cache_stats = self.world.program_cache_stats();
+        FAST_VM_METRICS
+            .decoded_programs
+            .observe(cache_stats.decoded);
+        FAST_VM_METRICS.program_cache_hits.observe(cache_stats.hits);
         let execution_state = self.get_current_execution_state();
         let bootloader_memory = self
             .bootloader_state
@@ -839,11 +849,27 @@ impl<S: fmt::Debug, Tr: fmt::Debug> fmt::Debug for Vm<S, Tr> {
     }
 }
 
+/// Statistics for the batch-scoped [`Program`] cache in [`World`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ProgramCacheStats {
+    /// Number of bytecodes decoded into programs.
+    pub decoded: usize,
+    /// Number of decommits served from the cache without decoding. Decommits of preloaded system contracts
+    /// (default AA and EVM emulator) are not counted.
+    pub hits: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct World<S, T> {
     pub(crate) storage: S,
     dynamic_bytecodes: DynamicBytecodes,
+    /// Decoded programs. Populated lazily on the first decommit of each bytecode and retained for the entire batch
+    /// (i.e., across transactions and rollbacks), so that contracts called by many transactions are decoded once.
     program_cache: HashMap<U256, Program<T, Self>>,
+    /// Hashes of programs preloaded into `program_cache` on VM creation.
+    preloaded_programs: HashSet<U256>,
+    program_cache_stats: ProgramCacheStats,
+    /// Raw bytecodes inserted into the VM or loaded from storage. These are decoded only if actually decommitted.
     pub(crate) bytecode_cache: HashMap<U256, Vec<u8>>,
 }
 
@@ -852,11 +878,17 @@ impl<S: ReadStorage, T: Tracer> World<S, T> {
         Self {
             storage,
             dynamic_bytecodes: DynamicBytecodes::default(),
+            preloaded_programs: program_cache.keys().copied().collect(),
             program_cache,
+            program_cache_stats: ProgramCacheStats::default(),
             bytecode_cache: HashMap::default(),
         }
     }
 
+    pub(crate) fn program_cache_stats(&self) -> ProgramCacheStats {
+        self.program_cache_stats
+    }
+
     fn convert_system_contract_code(
         code: &SystemContractCode,
         is_bootloader: bool,
@@ -948,33 +980,37 @@ impl<S: ReadStorage, T: Tracer> zksync_vm2::StorageInterface for World<S, T> {
 /// Thus, if storage is reverted correctly, additional EVM bytecodes occupy the cache, but are unreachable.
 impl<S: ReadStorage, T: Tracer> zksync_vm2::World<T> for World<S, T> {
     fn decommit(&mut self, hash: U256) -> Program<T, Self> {
-        self.program_cache
-            .entry(hash)
-            .or_insert_with(|| {
-                let cached = self
-                    .bytecode_cache
-                    .get(&hash)
-                    .map(|code| Program::new(code, false))
-                    .or_else(|| {
-                        self.dynamic_bytecodes
-                            .map(hash, |code| Program::new(code, false))
-                    });
+        if let Some(program) = self.program_cache.get(&hash) {
+            if !self.preloaded_programs.contains(&hash) {
+                self.program_cache_stats.hits += 1;
+            }
+            return program.clone();
+        }
 
-                if let Some(cached) = cached {
-                    cached
-                } else {
-                    let code = self
-                        .storage
-                        .load_factory_dep(u256_to_h256(hash))
-                        .unwrap_or_else(|| {
-                            panic!("VM tried to decommit nonexistent bytecode: {hash:?}");
-                        });
-                    let program = Program::new(&code, false);
-                    self.bytecode_cache.insert(hash, code);
-                    program
-                }
-            })
-            .clone()
+        let cached = self
+            .bytecode_cache
+            .get(&hash)
+            .map(|code| Program::new(code, false))
+            .or_else(|| {
+                self.dynamic_bytecodes
+                    .map(hash, |code| Program::new(code, false))
+            });
+        let program = if let Some(cached) = cached {
+            cached
+        } else {
+            let code = self
+                .storage
+                .load_factory_dep(u256_to_h256(hash))
+                .unwrap_or_else(|| {
+                    panic!("VM tried to decommit nonexistent bytecode: {hash:?}");
+                });
+            let program = Program::new(&code, false);
+            self.bytecode_cache.insert(hash, code);
+            program
+        };
+        self.program_cache_stats.decoded += 1;
+        self.program_cache.insert(hash, program.clone());
+        program
     }
 
     fn decommit_code(&mut self, hash: U256) -> Vec<u8> {
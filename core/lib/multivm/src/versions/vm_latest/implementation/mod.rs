@@ -1,3 +1,5 @@
+pub use self::snapshots::UnknownSnapshotError;
+
 mod bytecode;
 mod execution;
 mod gas;
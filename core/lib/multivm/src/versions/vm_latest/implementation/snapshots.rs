@@ -33,10 +33,63 @@ struct VmMetrics {
 #[vise::register]
 static METRICS: vise::Global<VmMetrics> = vise::Global::new();
 
+/// Error returned when rolling back to a named snapshot that doesn't exist.
+#[derive(Debug, thiserror::Error)]
+#[error("snapshot `{0}` does not exist")]
+pub struct UnknownSnapshotError(pub String);
+
+/// Named snapshots allowing to roll back the VM to an arbitrary earlier point of the batch, e.g.
+/// for speculative execution or `evm_snapshot` / `evm_revert`-style semantics.
+///
+/// Unlike snapshots from [`VmInterfaceHistoryEnabled`](crate::interface::VmInterfaceHistoryEnabled),
+/// named snapshots don't form a stack: the VM can be rolled back to any of them, any number of times.
+/// Snapshots should be created between transactions, since their cost grows with the call stack depth.
+impl<S: WriteStorage> Vm<S, HistoryEnabled> {
+    /// Creates a named snapshot of the current VM state. If a snapshot with the same name exists, it is replaced.
+    pub fn make_named_snapshot(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        self.named_snapshots
+            .retain(|(existing_name, _)| *existing_name != name);
+        let snapshot = self.create_snapshot();
+        self.named_snapshots.push((name, snapshot));
+    }
+
+    /// Rolls back the VM to the named snapshot. The snapshot is retained, so it's possible to roll back to it again.
+    /// All snapshots created after the specified one (both named and ones created via `make_snapshot()`)
+    /// are discarded since they no longer correspond to the VM history.
+    pub fn rollback_to_named_snapshot(&mut self, name: &str) -> Result<(), UnknownSnapshotError> {
+        let idx = self
+            .named_snapshots
+            .iter()
+            .position(|(existing_name, _)| existing_name == name)
+            .ok_or_else(|| UnknownSnapshotError(name.to_owned()))?;
+        self.named_snapshots.truncate(idx + 1);
+        let snapshot = self.named_snapshots[idx].1.clone();
+        let timestamp = snapshot.local_state.timestamp;
+        self.snapshots
+            .retain(|snapshot| snapshot.local_state.timestamp <= timestamp);
+        self.rollback_to_snapshot(snapshot);
+        Ok(())
+    }
+
+    /// Removes the named snapshot without rolling back to it. Returns `false` if the snapshot doesn't exist.
+    pub fn remove_named_snapshot(&mut self, name: &str) -> bool {
+        let len = self.named_snapshots.len();
+        self.named_snapshots
+            .retain(|(existing_name, _)| existing_name != name);
+        self.named_snapshots.len() < len
+    }
+
+    /// Returns names of all named snapshots in the order of their creation.
+    pub fn named_snapshots(&self) -> impl Iterator<Item = &str> + '_ {
+        self.named_snapshots.iter().map(|(name, _)| name.as_str())
+    }
+}
+
 /// Implementation of VM related to rollbacks inside virtual machine
 impl<S: WriteStorage> Vm<S, HistoryEnabled> {
-    pub(crate) fn make_snapshot_inner(&mut self) {
-        self.snapshots.push(VmSnapshot {
+    fn create_snapshot(&self) -> VmSnapshot {
+        VmSnapshot {
             // Vm local state contains O(1) various parameters (registers/etc).
             // The only "expensive" copying here is copying of the call stack.
             // It will take `O(callstack_depth)` to copy it.
@@ -44,7 +97,12 @@ impl<S: WriteStorage> Vm<S, HistoryEnabled> {
             // where the depth is 1.
             local_state: self.state.local_state.clone(),
             bootloader_state: self.bootloader_state.get_snapshot(),
-        });
+        }
+    }
+
+    pub(crate) fn make_snapshot_inner(&mut self) {
+        let snapshot = self.create_snapshot();
+        self.snapshots.push(snapshot);
     }
 
     pub(crate) fn rollback_to_snapshot(&mut self, snapshot: VmSnapshot) {
@@ -1,6 +1,7 @@
-pub(crate) use self::vm::MultiVmSubversion;
+pub(crate) use self::{old_vm::events::merge_events, vm::MultiVmSubversion};
 pub use self::{
     bootloader_state::BootloaderState,
+    implementation::UnknownSnapshotError,
     old_vm::{
         history_recorder::{
             AppDataFrameManagerWithHistory, HistoryDisabled, HistoryEnabled, HistoryMode,
@@ -6,7 +6,7 @@ use zksync_types::{Address, Execute};
 
 use super::TestedLatestVm;
 use crate::{
-    interface::{InspectExecutionMode, TxExecutionMode, VmInterface},
+    interface::{Call, CallKind, InspectExecutionMode, TxExecutionMode, VmInterface},
     tracers::CallTracer,
     versions::testonly::{read_max_depth_contract, ContractToDeploy, VmTesterBuilder},
     vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, ToTracerPointer},
@@ -87,4 +87,23 @@ fn test_basic_behavior() {
     let subcall = &call_tracer_result[0].calls;
     assert!(subcall.len() > 10);
     assert!(!res.result.is_failed());
+
+    let all_calls = flatten_calls(call_tracer_result);
+    let counter_call = all_calls.iter().find(|call| call.to == address).unwrap();
+    assert_eq!(counter_call.kind(), CallKind::UserContract);
+    assert_eq!(counter_call.system_call_name(), None);
+    // Transaction validation increments the account nonce.
+    assert!(all_calls.iter().any(|call| {
+        call.kind() == CallKind::SystemContract
+            && call.system_call_name().as_deref() == Some("NonceHolder.incrementMinNonceIfEquals")
+    }));
+}
+
+fn flatten_calls(calls: &[Call]) -> Vec<&Call> {
+    let mut flattened = vec![];
+    for call in calls {
+        flattened.push(call);
+        flattened.extend(flatten_calls(&call.calls));
+    }
+    flattened
 }
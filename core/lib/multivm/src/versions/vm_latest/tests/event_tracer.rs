@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+use zksync_system_constants::L2_BASE_TOKEN_ADDRESS;
+use zksync_test_contracts::TestContract;
+use zksync_types::{Address, Execute, H256};
+
+use super::TestedLatestVm;
+use crate::{
+    interface::{InspectExecutionMode, TxExecutionMode, VmInterface},
+    tracers::{EventFilter, EventTracer},
+    versions::testonly::{ContractToDeploy, VmTesterBuilder},
+    vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, ToTracerPointer},
+};
+
+#[test]
+fn test_event_tracer() {
+    let contract = TestContract::counter().bytecode.to_vec();
+    let address = Address::repeat_byte(1);
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .with_bootloader_gas_limit(BATCH_COMPUTATIONAL_GAS_LIMIT)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_custom_contracts(vec![ContractToDeploy::account(contract, address)])
+        .build::<TestedLatestVm>();
+
+    let increment_by_6_calldata =
+        "7cf5dab00000000000000000000000000000000000000000000000000000000000000006";
+
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(address),
+            calldata: hex::decode(increment_by_6_calldata).unwrap(),
+            value: Default::default(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+
+    let l1_batch_number = vm.l1_batch_env.number;
+    // Fee payment emits `Transfer` events from the base token contract.
+    let base_token_events = Arc::new(OnceCell::new());
+    let base_token_tracer = EventTracer::new(
+        l1_batch_number,
+        EventFilter::for_address(L2_BASE_TOKEN_ADDRESS),
+        base_token_events.clone(),
+    );
+    let unmatched_events = Arc::new(OnceCell::new());
+    let unmatched_tracer = EventTracer::new(
+        l1_batch_number,
+        EventFilter::default().with_topic(0, vec![H256::repeat_byte(0xff)]),
+        unmatched_events.clone(),
+    );
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.inspect(
+        &mut vec![
+            base_token_tracer.into_tracer_pointer(),
+            unmatched_tracer.into_tracer_pointer(),
+        ]
+        .into(),
+        InspectExecutionMode::OneTx,
+    );
+    assert!(!res.result.is_failed());
+
+    let expected_events: Vec<_> = res
+        .logs
+        .events
+        .iter()
+        .filter(|event| event.address == L2_BASE_TOKEN_ADDRESS)
+        .cloned()
+        .collect();
+    assert!(!expected_events.is_empty());
+    assert_eq!(*base_token_events.get().unwrap(), expected_events);
+    assert!(unmatched_events.get().unwrap().is_empty());
+}
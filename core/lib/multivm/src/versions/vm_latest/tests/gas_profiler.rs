@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+use zksync_system_constants::BOOTLOADER_ADDRESS;
+use zksync_test_contracts::TestContract;
+use zksync_types::{Address, Execute};
+
+use super::TestedLatestVm;
+use crate::{
+    interface::{InspectExecutionMode, TxExecutionMode, VmInterface},
+    tracers::gas_profiler::GasProfilerTracer,
+    versions::testonly::{ContractToDeploy, VmTesterBuilder},
+    vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, ToTracerPointer},
+};
+
+#[test]
+fn test_gas_profiler() {
+    let contract = TestContract::counter().bytecode.to_vec();
+    let address = Address::repeat_byte(1);
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .with_bootloader_gas_limit(BATCH_COMPUTATIONAL_GAS_LIMIT)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_custom_contracts(vec![ContractToDeploy::account(contract, address)])
+        .build::<TestedLatestVm>();
+
+    let increment_by_6_calldata =
+        "7cf5dab00000000000000000000000000000000000000000000000000000000000000006";
+
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(address),
+            calldata: hex::decode(increment_by_6_calldata).unwrap(),
+            value: Default::default(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+
+    let result = Arc::new(OnceCell::new());
+    let tracer = GasProfilerTracer::new(result.clone()).into_tracer_pointer();
+    vm.vm.push_transaction(tx);
+    let res = vm
+        .vm
+        .inspect(&mut tracer.into(), InspectExecutionMode::OneTx);
+    assert!(!res.result.is_failed());
+
+    let profile = result.get().unwrap();
+    assert!(profile.by_contract[&BOOTLOADER_ADDRESS] > 0);
+    assert!(profile.by_contract[&address] > 0);
+    assert!(profile.by_opcode["Log(StorageWrite)"] > 0);
+
+    let total_gas = profile.total_gas();
+    assert_eq!(profile.by_contract.values().sum::<u64>(), total_gas);
+    assert_eq!(profile.by_stack.values().sum::<u64>(), total_gas);
+    // The counter contract is called by the bootloader (possibly, via other system contracts).
+    assert!(profile.by_stack.keys().any(|stack| {
+        stack.frames.first() == Some(&BOOTLOADER_ADDRESS) && stack.frames.last() == Some(&address)
+    }));
+}
@@ -0,0 +1,9 @@
+use crate::{
+    versions::testonly::l1_messenger::test_l1_messenger_failure,
+    vm_latest::{HistoryEnabled, Vm},
+};
+
+#[test]
+fn l1_messenger_failure() {
+    test_l1_messenger_failure::<Vm<_, HistoryEnabled>>();
+}
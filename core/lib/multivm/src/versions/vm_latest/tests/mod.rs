@@ -42,10 +42,13 @@ mod call_tracer;
 mod circuits;
 mod code_oracle;
 mod constants;
+mod event_tracer;
 mod evm_emulator;
 mod gas_limit;
+mod gas_profiler;
 mod get_used_contracts;
 mod is_write_initial;
+mod l1_messenger;
 mod l1_tx_execution;
 mod l2_blocks;
 mod nonce_holder;
@@ -57,6 +60,7 @@ mod rollbacks;
 mod secp256r1;
 mod simple_execution;
 mod storage;
+mod storage_diff_tracer;
 mod tracing_execution_error;
 mod transfer;
 mod upgrade;
@@ -144,3 +144,70 @@ fn layered_rollback() {
 fn rollback_in_call_mode() {
     test_rollback_in_call_mode::<Vm<_, HistoryEnabled>>();
 }
+
+#[test]
+fn rollbacks_to_named_snapshots() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .build::<TestedLatestVm>();
+    let account = &mut vm.rich_accounts[0];
+    let nonce_key = get_nonce_key(&account.address);
+    let DeployContractsTx {
+        tx: deploy_tx,
+        address,
+        ..
+    } = account.get_deploy_tx(
+        TestContract::load_test().bytecode,
+        Some(&[Token::Uint(0.into())]),
+        TxType::L2,
+    );
+    let loadnext_tx = account.get_loadnext_transaction(
+        address,
+        LoadnextContractExecutionParams::empty(),
+        TxType::L2,
+    );
+    let read_nonce =
+        |vm: &mut TestedLatestVm| vm.state.storage.storage.read_from_storage(&nonce_key);
+
+    let initial_nonce = read_nonce(&mut vm.vm);
+    vm.vm.make_named_snapshot("start");
+    vm.vm.push_transaction(deploy_tx.clone());
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!result.result.is_failed(), "transaction failed");
+    let nonce_after_deploy = read_nonce(&mut vm.vm);
+    assert_ne!(nonce_after_deploy, initial_nonce);
+
+    vm.vm.make_named_snapshot("deployed");
+    // Unnamed snapshots created after a named snapshot must not survive rolling back to it.
+    vm.vm.make_snapshot();
+    vm.vm.push_transaction(loadnext_tx);
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!result.result.is_failed(), "transaction failed");
+    assert_ne!(read_nonce(&mut vm.vm), nonce_after_deploy);
+    assert_eq!(
+        vm.vm.named_snapshots().collect::<Vec<_>>(),
+        ["start", "deployed"]
+    );
+
+    vm.vm.rollback_to_named_snapshot("deployed").unwrap();
+    assert_eq!(read_nonce(&mut vm.vm), nonce_after_deploy);
+    assert!(vm.vm.snapshots.is_empty());
+    // The snapshot is retained after the rollback.
+    vm.vm.rollback_to_named_snapshot("deployed").unwrap();
+    assert_eq!(read_nonce(&mut vm.vm), nonce_after_deploy);
+
+    vm.vm.rollback_to_named_snapshot("start").unwrap();
+    assert_eq!(read_nonce(&mut vm.vm), initial_nonce);
+    assert_eq!(vm.vm.named_snapshots().collect::<Vec<_>>(), ["start"]);
+    vm.vm.rollback_to_named_snapshot("deployed").unwrap_err();
+
+    // The VM must be able to continue execution after the rollback.
+    vm.vm.push_transaction(deploy_tx);
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!result.result.is_failed(), "transaction failed");
+    assert_eq!(read_nonce(&mut vm.vm), nonce_after_deploy);
+    assert!(vm.vm.remove_named_snapshot("start"));
+    assert!(!vm.vm.remove_named_snapshot("start"));
+}
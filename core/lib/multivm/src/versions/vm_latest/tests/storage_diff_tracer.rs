@@ -0,0 +1,64 @@
+use std::{collections::HashSet, sync::Arc};
+
+use once_cell::sync::OnceCell;
+use zksync_test_contracts::TestContract;
+use zksync_types::{u256_to_h256, AccountTreeId, Address, Execute, StorageKey, H256};
+
+use super::TestedLatestVm;
+use crate::{
+    interface::{InspectExecutionMode, TxExecutionMode, VmInterface},
+    tracers::StorageDiffTracer,
+    versions::testonly::{ContractToDeploy, VmTesterBuilder},
+    vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, ToTracerPointer},
+};
+
+#[test]
+fn test_storage_diff_tracer() {
+    let contract = TestContract::counter().bytecode.to_vec();
+    let address = Address::repeat_byte(1);
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .with_bootloader_gas_limit(BATCH_COMPUTATIONAL_GAS_LIMIT)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_custom_contracts(vec![ContractToDeploy::account(contract, address)])
+        .build::<TestedLatestVm>();
+
+    let increment_by_6_calldata =
+        "7cf5dab00000000000000000000000000000000000000000000000000000000000000006";
+
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(address),
+            calldata: hex::decode(increment_by_6_calldata).unwrap(),
+            value: Default::default(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+
+    let result = Arc::new(OnceCell::new());
+    let tracer = StorageDiffTracer::new(result.clone()).into_tracer_pointer();
+    vm.vm.push_transaction(tx);
+    let res = vm
+        .vm
+        .inspect(&mut tracer.into(), InspectExecutionMode::OneTx);
+    assert!(!res.result.is_failed());
+
+    let slots = result.get().unwrap();
+    let counter_key = StorageKey::new(AccountTreeId::new(address), H256::zero());
+    let counter_slot = slots
+        .iter()
+        .find(|slot| slot.key == counter_key)
+        .expect("counter slot was not accessed");
+    assert!(counter_slot.read);
+    assert!(counter_slot.written);
+    assert_eq!(counter_slot.pre, H256::zero());
+    assert_eq!(counter_slot.post, u256_to_h256(6.into()));
+    assert!(counter_slot.is_changed());
+
+    // Each slot is reported once.
+    let keys: HashSet<_> = slots.iter().map(|slot| slot.key).collect();
+    assert_eq!(keys.len(), slots.len());
+}
@@ -11,7 +11,7 @@ use zksync_types::U256;
 use crate::{
     interface::{
         storage::{StoragePtr, WriteStorage},
-        tracer::{TracerExecutionStopReason, VmExecutionStopReason},
+        tracer::{InterruptionReason, TracerExecutionStopReason, VmExecutionStopReason},
         ExecutionResult, Halt, TxRevertReason, VmExecutionMode, VmRevertReason,
     },
     tracers::dynamic::vm_1_5_0::DynTracer,
@@ -33,6 +33,7 @@ enum Result {
     Error { error_reason: VmRevertReason },
     Success { return_data: Vec<u8> },
     Halt { reason: Halt },
+    Interrupted { reason: InterruptionReason },
 }
 
 /// Responsible for tracing the far calls from the bootloader.
@@ -212,6 +213,12 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for ResultTracer<S> {
             VmExecutionStopReason::VmFinished => {
                 self.vm_finished_execution(state);
             }
+            // Interrupted execution has no meaningful result regardless of the execution mode.
+            VmExecutionStopReason::TracerRequestedStop(TracerExecutionStopReason::Interrupt(
+                reason,
+            )) => {
+                self.result = Some(Result::Interrupted { reason });
+            }
             // One of the tracers above has requested to stop the execution.
             // If it was the correct stop we already have the result,
             // otherwise it can be out of gas error
@@ -325,6 +332,7 @@ impl<S: WriteStorage> ResultTracer<S> {
                 output: return_data,
             },
             Result::Halt { reason } => ExecutionResult::Halt { reason },
+            Result::Interrupted { reason } => ExecutionResult::Interrupted { reason },
         }
     }
 
@@ -77,6 +77,8 @@ pub struct Vm<S: WriteStorage, H: HistoryMode> {
     pub(crate) batch_env: L1BatchEnv,
     // Snapshots for the current run
     pub(crate) snapshots: Vec<VmSnapshot>,
+    // Named snapshots created via the public API, ordered by creation
+    pub(crate) named_snapshots: Vec<(String, VmSnapshot)>,
     pub(crate) subversion: MultiVmSubversion,
     _phantom: std::marker::PhantomData<H>,
 }
@@ -258,6 +260,7 @@ impl<S: WriteStorage, H: HistoryMode> Vm<S, H> {
             batch_env,
             subversion,
             snapshots: vec![],
+            named_snapshots: vec![],
             _phantom: Default::default(),
         }
     }
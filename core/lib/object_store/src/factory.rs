@@ -9,6 +9,7 @@ use crate::{
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mirror::MirroringObjectStore,
     raw::{ObjectStore, ObjectStoreError},
+    replication::ReplicatingObjectStore,
     retries::StoreWithRetries,
 };
 
@@ -61,22 +62,58 @@ impl ObjectStoreFactory {
         config: &ObjectStoreConfig,
     ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         tracing::trace!("Initializing object store with configuration {config:?}");
-        match &config.mode {
+        let store = Self::create_from_mode(&config.mode, config.max_retries).await?;
+        let store = if let Some(replication) = &config.replication {
+            let mut mirrors = Vec::with_capacity(replication.mirrors.len());
+            for mirror in &replication.mirrors {
+                let mirror_store = Self::create_from_mode(&mirror.mode, config.max_retries).await?;
+                mirrors.push((mirror.name.clone(), mirror_store));
+            }
+            let store =
+                ReplicatingObjectStore::new(store, mirrors, &replication.read_preference)?;
+            Arc::new(store)
+        } else {
+            store
+        };
+
+        let Some(mirror_path) = &config.local_mirror_path else {
+            return Ok(store);
+        };
+        if matches!(config.mode, ObjectStoreMode::FileBacked { .. }) && config.replication.is_none()
+        {
+            tracing::warn!("Mirroring doesn't make sense with file-backed object store; ignoring mirror path `{mirror_path}`");
+            return Ok(store);
+        }
+        Ok(Arc::new(
+            MirroringObjectStore::new(
+                store,
+                mirror_path.clone(),
+                config.local_mirror_max_size_mb.map(|mb| mb << 20),
+            )
+            .await?,
+        ))
+    }
+
+    async fn create_from_mode(
+        mode: &ObjectStoreMode,
+        max_retries: u16,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
+        Ok(match mode {
             ObjectStoreMode::GCS { bucket_base_url } => {
-                let store = StoreWithRetries::try_new(config.max_retries, || {
+                let store = StoreWithRetries::try_new(max_retries, || {
                     GoogleCloudStore::new(
                         GoogleCloudStoreAuthMode::Authenticated,
                         bucket_base_url.clone(),
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Arc::new(store)
             }
             ObjectStoreMode::GCSWithCredentialFile {
                 bucket_base_url,
                 gcs_credential_file_path,
             } => {
-                let store = StoreWithRetries::try_new(config.max_retries, || {
+                let store = StoreWithRetries::try_new(max_retries, || {
                     GoogleCloudStore::new(
                         GoogleCloudStoreAuthMode::AuthenticatedWithCredentialFile(
                             gcs_credential_file_path.clone(),
@@ -85,43 +122,27 @@ impl ObjectStoreFactory {
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Arc::new(store)
             }
             ObjectStoreMode::GCSAnonymousReadOnly { bucket_base_url } => {
-                let store = StoreWithRetries::try_new(config.max_retries, || {
+                let store = StoreWithRetries::try_new(max_retries, || {
                     GoogleCloudStore::new(
                         GoogleCloudStoreAuthMode::Anonymous,
                         bucket_base_url.clone(),
                     )
                 })
                 .await?;
-                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+                Arc::new(store)
             }
-
             ObjectStoreMode::FileBacked {
                 file_backed_base_path,
             } => {
-                let store = StoreWithRetries::try_new(config.max_retries, || {
+                let store = StoreWithRetries::try_new(max_retries, || {
                     FileBackedObjectStore::new(file_backed_base_path.clone())
                 })
                 .await?;
-
-                if let Some(mirror_path) = &config.local_mirror_path {
-                    tracing::warn!("Mirroring doesn't make sense with file-backed object store; ignoring mirror path `{mirror_path}`");
-                }
-                Ok(Arc::new(store))
+                Arc::new(store)
             }
-        }
-    }
-
-    async fn wrap_mirroring(
-        store: impl ObjectStore,
-        mirror_path: Option<&String>,
-    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
-        Ok(if let Some(mirror_path) = mirror_path {
-            Arc::new(MirroringObjectStore::new(store, mirror_path.clone()).await?)
-        } else {
-            Arc::new(store)
         })
     }
 }
@@ -1,7 +1,11 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
 
 use async_trait::async_trait;
-use tokio::{fs, io};
+use tokio::{fs, io, io::AsyncWriteExt};
 
 use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
 
@@ -24,26 +28,29 @@ pub struct FileBackedObjectStore {
 }
 
 impl FileBackedObjectStore {
+    const BUCKETS: &'static [Bucket] = &[
+        Bucket::ProverJobs,
+        Bucket::WitnessInput,
+        Bucket::LeafAggregationWitnessJobs,
+        Bucket::NodeAggregationWitnessJobs,
+        Bucket::SchedulerWitnessJobs,
+        Bucket::ProverJobsFri,
+        Bucket::LeafAggregationWitnessJobsFri,
+        Bucket::NodeAggregationWitnessJobsFri,
+        Bucket::SchedulerWitnessJobsFri,
+        Bucket::ProofsFri,
+        Bucket::StorageSnapshot,
+        Bucket::VmDumps,
+        Bucket::SetupData,
+    ];
+
     /// Creates a new file-backed store with its root at the specified path.
     ///
     /// # Errors
     ///
     /// Propagates I/O errors.
     pub async fn new(base_dir: String) -> Result<Self, ObjectStoreError> {
-        for bucket in &[
-            Bucket::ProverJobs,
-            Bucket::WitnessInput,
-            Bucket::LeafAggregationWitnessJobs,
-            Bucket::NodeAggregationWitnessJobs,
-            Bucket::SchedulerWitnessJobs,
-            Bucket::ProverJobsFri,
-            Bucket::LeafAggregationWitnessJobsFri,
-            Bucket::NodeAggregationWitnessJobsFri,
-            Bucket::SchedulerWitnessJobsFri,
-            Bucket::ProofsFri,
-            Bucket::StorageSnapshot,
-            Bucket::VmDumps,
-        ] {
+        for bucket in Self::BUCKETS {
             let bucket_path = format!("{base_dir}/{bucket}");
             fs::create_dir_all(&bucket_path).await?;
         }
@@ -53,6 +60,46 @@ impl FileBackedObjectStore {
     fn filename(&self, bucket: Bucket, key: &str) -> String {
         format!("{}/{bucket}/{key}", self.base_dir)
     }
+
+    /// Lists all objects in the store together with their size and modification time.
+    /// Objects concurrently removed (e.g., by another process sharing the store directory) are skipped.
+    pub(crate) async fn list_objects(
+        &self,
+    ) -> Result<Vec<(Bucket, String, u64, SystemTime)>, ObjectStoreError> {
+        let mut objects = vec![];
+        for &bucket in Self::BUCKETS {
+            let mut entries = fs::read_dir(self.storage_prefix_raw(bucket)).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let metadata = match entry.metadata().await {
+                    Ok(metadata) => metadata,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err.into()),
+                };
+                if !metadata.is_file() {
+                    continue;
+                }
+                let Ok(key) = entry.file_name().into_string() else {
+                    continue;
+                };
+                if key.starts_with('.') {
+                    // Temporary file created by `put_raw()`
+                    continue;
+                }
+                objects.push((bucket, key, metadata.len(), metadata.modified()?));
+            }
+        }
+        Ok(objects)
+    }
+
+    /// Sets the modification time of the object to the current time.
+    pub(crate) async fn touch(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        let file = fs::OpenOptions::new()
+            .append(true)
+            .open(self.filename(bucket, key))
+            .await?;
+        file.into_std().await.set_modified(SystemTime::now())?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -68,8 +115,24 @@ impl ObjectStore for FileBackedObjectStore {
         key: &str,
         value: Vec<u8>,
     ) -> Result<(), ObjectStoreError> {
-        let filename = self.filename(bucket, key);
-        fs::write(filename, value).await.map_err(From::from)
+        static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        // Write the object to a temporary file and then atomically rename it, so that other processes
+        // sharing the store directory never observe partially written objects.
+        let tmp_filename = format!(
+            "{}/{bucket}/.{key}.{}.{}.tmp",
+            self.base_dir,
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        let mut file = fs::File::create(&tmp_filename).await?;
+        file.write_all(&value).await?;
+        // Set the modification time explicitly; the filesystem may use coarse-grained timestamps,
+        // which are not precise enough to order objects by recency.
+        file.into_std().await.set_modified(SystemTime::now())?;
+        fs::rename(&tmp_filename, self.filename(bucket, key))
+            .await
+            .map_err(From::from)
     }
 
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
@@ -77,6 +140,11 @@ impl ObjectStore for FileBackedObjectStore {
         fs::remove_file(filename).await.map_err(From::from)
     }
 
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        let filename = self.filename(bucket, key);
+        Ok(fs::metadata(filename).await?.len())
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!("{}/{}", self.base_dir, bucket)
     }
@@ -131,4 +199,25 @@ mod test {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_object_size() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+        let object_store = FileBackedObjectStore::new(path).await.unwrap();
+        object_store
+            .put_raw(Bucket::ProverJobs, "test-key.bin", vec![0, 1, 2])
+            .await
+            .unwrap();
+        let size = object_store
+            .object_size_raw(Bucket::ProverJobs, "test-key.bin")
+            .await
+            .unwrap();
+        assert_eq!(size, 3);
+        let err = object_store
+            .object_size_raw(Bucket::ProverJobs, "missing-key.bin")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ObjectStoreError::KeyNotFound(_)), "{err:?}");
+    }
 }
@@ -5,6 +5,9 @@
 //! - [GCS-based store](GoogleCloudStore)
 //! - [Mock in-memory store](MockObjectStore)
 //!
+//! Stores can be decorated with local mirroring and replication to mirror stores (e.g., located in different regions);
+//! see [`ObjectStoreConfig`](zksync_config::configs::object_store::ObjectStoreConfig) for details.
+//!
 //! Normally, these implementations are not used directly. Instead, a store trait object (`Arc<dyn ObjectStore>`)
 //! can be constructed using an [`ObjectStoreFactory`] based on the configuration.
 //! This trait object is what should be used for dependency injection.
@@ -31,6 +34,7 @@ mod mirror;
 mod mock;
 mod objects;
 mod raw;
+mod replication;
 mod retries;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
@@ -2,7 +2,10 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, Histogram, LabeledFamily, LatencyObserver, Metrics};
+use vise::{
+    Buckets, Counter, EncodeLabelValue, Gauge, Histogram, LabeledFamily, LatencyObserver, Metrics,
+    Unit,
+};
 
 use crate::Bucket;
 
@@ -29,3 +32,61 @@ impl ObjectStoreMetrics {
 
 #[vise::register]
 pub(crate) static OBJECT_STORE_METRICS: vise::Global<ObjectStoreMetrics> = vise::Global::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum ReplicationOperation {
+    Put,
+    Remove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum ReplicationResult {
+    Success,
+    Failure,
+    /// Operation was not performed (or not retried) because it was superseded by a newer operation on the same key.
+    Superseded,
+}
+
+/// Whether an object was read from the most preferred store, or from one of the fallback stores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum ReadSource {
+    Preferred,
+    Fallback,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_object_store_replication")]
+pub(crate) struct ReplicationMetrics {
+    /// Number of operations replicated to mirror stores.
+    #[metrics(labels = ["mirror", "operation", "result"])]
+    pub replicated: LabeledFamily<(String, ReplicationOperation, ReplicationResult), Counter, 3>,
+    /// Number of retried replication operations.
+    #[metrics(labels = ["mirror"])]
+    pub retries: LabeledFamily<String, Counter>,
+    /// Number of objects read from each store.
+    #[metrics(labels = ["store", "source"])]
+    pub reads: LabeledFamily<(String, ReadSource), Counter, 2>,
+}
+
+#[vise::register]
+pub(crate) static REPLICATION_METRICS: vise::Global<ReplicationMetrics> = vise::Global::new();
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_object_store_mirror")]
+pub(crate) struct MirrorMetrics {
+    /// Number of objects read from the local mirror.
+    pub hits: Counter,
+    /// Number of objects missing from the local mirror and read from the underlying store.
+    pub misses: Counter,
+    /// Number of objects evicted from the size-limited local mirror.
+    pub evicted_objects: Counter,
+    /// Total size of objects in the size-limited local mirror.
+    #[metrics(unit = Unit::Bytes)]
+    pub size: Gauge<u64>,
+}
+
+#[vise::register]
+pub(crate) static MIRROR_METRICS: vise::Global<MirrorMetrics> = vise::Global::new();
@@ -1,23 +1,108 @@
 //! Mirroring object store.
 
 use async_trait::async_trait;
+use tokio::sync::Mutex;
 
-use crate::{file::FileBackedObjectStore, raw::ObjectStore, Bucket, ObjectStoreError};
+use crate::{
+    file::FileBackedObjectStore, metrics::MIRROR_METRICS, raw::ObjectStore, Bucket,
+    ObjectStoreError,
+};
 
+/// Object store mirroring objects from the underlying store to a local directory.
+///
+/// If the mirror size is limited, the least recently used objects are evicted from the mirror once its size
+/// exceeds the limit. Since all objects for a job (and, usually, all jobs for an L1 batch) are accessed close
+/// to each other, this effectively evicts objects for the least recently processed batches. Recency is tracked
+/// via modification times of mirrored files, and the mirror size is computed from the directory contents,
+/// so the mirror directory can be shared by multiple processes (e.g., co-located provers).
 #[derive(Debug)]
 pub(crate) struct MirroringObjectStore<S> {
     inner: S,
     mirror_store: FileBackedObjectStore,
+    /// Set if the mirror size is limited.
+    max_size: Option<u64>,
+    /// Prevents concurrent evictions within the process.
+    eviction_lock: Mutex<()>,
 }
 
 impl<S: ObjectStore> MirroringObjectStore<S> {
-    pub async fn new(inner: S, mirror_path: String) -> Result<Self, ObjectStoreError> {
+    pub async fn new(
+        inner: S,
+        mirror_path: String,
+        max_size: Option<u64>,
+    ) -> Result<Self, ObjectStoreError> {
         tracing::info!("Initializing mirroring for store {inner:?} at `{mirror_path}`");
         let mirror_store = FileBackedObjectStore::new(mirror_path).await?;
-        Ok(Self {
+        let this = Self {
             inner,
             mirror_store,
-        })
+            max_size,
+            eviction_lock: Mutex::new(()),
+        };
+        if let Some(max_size) = max_size {
+            tracing::info!("Max mirror size: {max_size} bytes");
+            // Account for objects left in the mirror by previous runs or other processes.
+            this.evict_objects(max_size).await;
+        }
+        Ok(this)
+    }
+
+    /// Evicts the least recently used objects from the mirror until its size fits into `max_size`.
+    /// The mirror state is read from the directory, so that objects put by other processes are accounted for.
+    async fn evict_objects(&self, max_size: u64) {
+        let _guard = self.eviction_lock.lock().await;
+        let mut objects = match self.mirror_store.list_objects().await {
+            Ok(objects) => objects,
+            Err(err) => {
+                tracing::warn!(
+                    "failed listing objects in mirror: {:#}",
+                    anyhow::Error::from(err)
+                );
+                return;
+            }
+        };
+        objects.sort_unstable_by_key(|(_, _, _, modified_at)| *modified_at);
+        let mut total_size: u64 = objects.iter().map(|(_, _, size, _)| size).sum();
+
+        for (bucket, key, size, _) in objects {
+            if total_size <= max_size {
+                break;
+            }
+            match self.mirror_store.remove_raw(bucket, &key).await {
+                Ok(()) => {
+                    MIRROR_METRICS.evicted_objects.inc();
+                    tracing::trace!("evicted object `{key}` from mirror");
+                }
+                // The object was concurrently removed, e.g. by another process sharing the mirror.
+                Err(ObjectStoreError::KeyNotFound(_)) => {}
+                Err(err) => {
+                    tracing::warn!(
+                        "failed evicting object `{key}` from mirror: {:#}",
+                        anyhow::Error::from(err)
+                    );
+                    continue;
+                }
+            }
+            total_size -= size;
+        }
+        MIRROR_METRICS.size.set(total_size);
+    }
+
+    async fn mirror_object(&self, bucket: Bucket, key: &str, value: Vec<u8>) {
+        let size = value.len() as u64;
+        if self.max_size.is_some_and(|max_size| size > max_size) {
+            tracing::trace!("object is larger than the mirror; not mirroring it");
+            return;
+        }
+
+        if let Err(err) = self.mirror_store.put_raw(bucket, key, value).await {
+            tracing::warn!("failed mirroring object: {:#}", anyhow::Error::from(err));
+        } else {
+            tracing::trace!("mirrored object");
+            if let Some(max_size) = self.max_size {
+                self.evict_objects(max_size).await;
+            }
+        }
     }
 }
 
@@ -28,6 +113,16 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         match self.mirror_store.get_raw(bucket, key).await {
             Ok(object) => {
                 tracing::trace!("obtained object from mirror");
+                MIRROR_METRICS.hits.inc();
+                if self.max_size.is_some() {
+                    // Mark the object as recently used.
+                    if let Err(err) = self.mirror_store.touch(bucket, key).await {
+                        tracing::debug!(
+                            "failed touching mirrored object: {:#}",
+                            anyhow::Error::from(err)
+                        );
+                    }
+                }
                 return Ok(object);
             }
             Err(err) => {
@@ -37,13 +132,10 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
                         anyhow::Error::from(err)
                     );
                 }
+                MIRROR_METRICS.misses.inc();
                 let object = self.inner.get_raw(bucket, key).await?;
                 tracing::trace!("obtained object from underlying store");
-                if let Err(err) = self.mirror_store.put_raw(bucket, key, object.clone()).await {
-                    tracing::warn!("failed mirroring object: {:#}", anyhow::Error::from(err));
-                } else {
-                    tracing::trace!("mirrored object");
-                }
+                self.mirror_object(bucket, key, object.clone()).await;
                 Ok(object)
             }
         }
@@ -62,11 +154,7 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
     ) -> Result<(), ObjectStoreError> {
         self.inner.put_raw(bucket, key, value.clone()).await?;
         // Only put the value into the mirror once it has been put in the underlying store
-        if let Err(err) = self.mirror_store.put_raw(bucket, key, value).await {
-            tracing::warn!("failed mirroring object: {:#}", anyhow::Error::from(err));
-        } else {
-            tracing::trace!("mirrored object");
-        }
+        self.mirror_object(bucket, key, value).await;
         Ok(())
     }
 
@@ -85,6 +173,10 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         Ok(())
     }
 
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        self.inner.object_size_raw(bucket, key).await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -92,6 +184,8 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use assert_matches::assert_matches;
     use tempfile::TempDir;
 
@@ -108,7 +202,9 @@ mod tests {
             .put_raw(Bucket::StorageSnapshot, "test", vec![1, 2, 3])
             .await
             .unwrap();
-        let mirroring_store = MirroringObjectStore::new(mock_store, path).await.unwrap();
+        let mirroring_store = MirroringObjectStore::new(mock_store, path, None)
+            .await
+            .unwrap();
 
         let object = mirroring_store
             .get_raw(Bucket::StorageSnapshot, "test")
@@ -151,4 +247,101 @@ mod tests {
             .unwrap();
         assert_eq!(object, [3, 2, 1]);
     }
+
+    #[tokio::test]
+    async fn mirror_evicts_least_recently_used_objects() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap().to_owned();
+
+        let mock_store = MockObjectStore::default();
+        for key in ["a", "b", "c"] {
+            mock_store
+                .put_raw(Bucket::ProverJobsFri, key, vec![0; 2])
+                .await
+                .unwrap();
+        }
+        let mirroring_store = MirroringObjectStore::new(mock_store, path.clone(), Some(4))
+            .await
+            .unwrap();
+
+        mirroring_store
+            .get_raw(Bucket::ProverJobsFri, "a")
+            .await
+            .unwrap();
+        mirroring_store
+            .get_raw(Bucket::ProverJobsFri, "b")
+            .await
+            .unwrap();
+        // Make `b` the least recently used object.
+        mirroring_store
+            .get_raw(Bucket::ProverJobsFri, "a")
+            .await
+            .unwrap();
+        mirroring_store
+            .get_raw(Bucket::ProverJobsFri, "c")
+            .await
+            .unwrap();
+
+        let mirror = &mirroring_store.mirror_store;
+        mirror.get_raw(Bucket::ProverJobsFri, "a").await.unwrap();
+        mirror.get_raw(Bucket::ProverJobsFri, "c").await.unwrap();
+        let err = mirror
+            .get_raw(Bucket::ProverJobsFri, "b")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+
+        // Objects too large for the mirror are not mirrored.
+        mirroring_store
+            .put_raw(Bucket::ProverJobsFri, "large", vec![0; 5])
+            .await
+            .unwrap();
+        let err = mirror
+            .get_raw(Bucket::ProverJobsFri, "large")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+
+        // Existing objects are accounted for after a restart.
+        let mirroring_store = MirroringObjectStore::new(MockObjectStore::default(), path, Some(2))
+            .await
+            .unwrap();
+        let objects = mirroring_store.mirror_store.list_objects().await.unwrap();
+        assert_eq!(objects.len(), 1, "{objects:?}");
+    }
+
+    #[tokio::test]
+    async fn mirror_shared_by_multiple_stores() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap().to_owned();
+
+        let mock_store = MockObjectStore::default();
+        for key in ["a", "b", "c"] {
+            mock_store
+                .put_raw(Bucket::SetupData, key, vec![0; 2])
+                .await
+                .unwrap();
+        }
+        let mock_store: Arc<dyn ObjectStore> = Arc::new(mock_store);
+        let first_store = MirroringObjectStore::new(mock_store.clone(), path.clone(), Some(4))
+            .await
+            .unwrap();
+        let second_store = MirroringObjectStore::new(mock_store, path, Some(4))
+            .await
+            .unwrap();
+
+        first_store.get_raw(Bucket::SetupData, "a").await.unwrap();
+        second_store.get_raw(Bucket::SetupData, "b").await.unwrap();
+        // Objects mirrored by the second store must be accounted for by the first one.
+        first_store.get_raw(Bucket::SetupData, "c").await.unwrap();
+
+        let mut objects = first_store.mirror_store.list_objects().await.unwrap();
+        objects.sort_unstable_by(|(_, key, ..), (_, other_key, ..)| key.cmp(other_key));
+        let keys: Vec<_> = objects.iter().map(|(_, key, ..)| key.as_str()).collect();
+        assert_eq!(keys, ["b", "c"]);
+
+        // The evicted object is transparently reloaded from the underlying store.
+        let object = second_store.get_raw(Bucket::SetupData, "a").await.unwrap();
+        assert_eq!(object, [0; 2]);
+    }
 }
@@ -1,4 +1,4 @@
-use std::{error, fmt};
+use std::{error, fmt, sync::Arc};
 
 use async_trait::async_trait;
 
@@ -20,6 +20,7 @@ pub enum Bucket {
     StorageSnapshot,
     DataAvailability,
     VmDumps,
+    SetupData,
 }
 
 impl Bucket {
@@ -39,6 +40,7 @@ impl Bucket {
             Self::StorageSnapshot => "storage_logs_snapshots",
             Self::DataAvailability => "data_availability",
             Self::VmDumps => "vm_dumps",
+            Self::SetupData => "setup_data",
         }
     }
 }
@@ -157,5 +159,44 @@ pub trait ObjectStore: 'static + fmt::Debug + Send + Sync {
     /// Returns an error if removal fails.
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError>;
 
+    /// Returns the size of the value associated with the key in the given bucket, in bytes.
+    /// The default implementation fetches the value; stores should override it if the size can be obtained
+    /// without downloading the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an object with the `key` does not exist or cannot be accessed.
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        Ok(self.get_raw(bucket, key).await?.len() as u64)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String;
 }
+
+#[async_trait]
+impl ObjectStore for Arc<dyn ObjectStore> {
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        (**self).get_raw(bucket, key).await
+    }
+
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        (**self).put_raw(bucket, key, value).await
+    }
+
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        (**self).remove_raw(bucket, key).await
+    }
+
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        (**self).object_size_raw(bucket, key).await
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        (**self).storage_prefix_raw(bucket)
+    }
+}
@@ -0,0 +1,656 @@
+//! Object store replicating objects to mirror stores.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use rand::Rng;
+use zksync_config::configs::object_store::ObjectStoreReplicationConfig;
+
+use crate::{
+    metrics::{ReadSource, ReplicationOperation, ReplicationResult, REPLICATION_METRICS},
+    raw::{Bucket, ObjectStore, ObjectStoreError},
+};
+
+#[derive(Debug, Clone)]
+struct NamedStore {
+    name: Arc<str>,
+    store: Arc<dyn ObjectStore>,
+}
+
+#[derive(Clone)]
+enum ReplicationOp {
+    Put(Vec<u8>),
+    Remove,
+}
+
+impl fmt::Debug for ReplicationOp {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Put(value) => formatter
+                .debug_struct("Put")
+                .field("value.len", &value.len())
+                .finish(),
+            Self::Remove => formatter.write_str("Remove"),
+        }
+    }
+}
+
+impl ReplicationOp {
+    fn metrics_label(&self) -> ReplicationOperation {
+        match self {
+            Self::Put(_) => ReplicationOperation::Put,
+            Self::Remove => ReplicationOperation::Remove,
+        }
+    }
+}
+
+/// Policy for retrying failed replication operations.
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Mirror store together with replication operations pending for it.
+///
+/// Operations for each key are performed by at most one background task at a time, so that they are applied
+/// to the mirror in order. If more operations arrive while an operation for the key is in progress, only the latest
+/// of them is retained and performed afterward; it determines the final state of the object anyway.
+#[derive(Debug, Clone)]
+struct Mirror {
+    inner: NamedStore,
+    /// Keys with an in-progress replication task, mapped to the next operation to perform for the key (if any).
+    pending: Arc<Mutex<HashMap<(Bucket, String), Option<ReplicationOp>>>>,
+}
+
+impl Mirror {
+    fn new(inner: NamedStore) -> Self {
+        Self {
+            inner,
+            pending: Arc::default(),
+        }
+    }
+
+    fn replicate(&self, bucket: Bucket, key: &str, op: ReplicationOp, retry_policy: RetryPolicy) {
+        let pending_key = (bucket, key.to_owned());
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(next_op) = pending.get_mut(&pending_key) {
+            if let Some(superseded_op) = next_op.replace(op) {
+                self.report(superseded_op.metrics_label(), ReplicationResult::Superseded);
+            }
+            return;
+        }
+        pending.insert(pending_key, None);
+        drop(pending);
+
+        let this = self.clone();
+        let key = key.to_owned();
+        tokio::spawn(async move { this.run_replication(bucket, key, op, retry_policy).await });
+    }
+
+    async fn run_replication(
+        self,
+        bucket: Bucket,
+        key: String,
+        mut op: ReplicationOp,
+        retry_policy: RetryPolicy,
+    ) {
+        let pending_key = (bucket, key);
+        loop {
+            let result = self
+                .replicate_with_retries(bucket, &pending_key.1, &op, retry_policy)
+                .await;
+            self.report(op.metrics_label(), result);
+
+            let mut pending = self.pending.lock().unwrap();
+            let next_op = pending.get_mut(&pending_key).and_then(Option::take);
+            match next_op {
+                Some(next_op) => op = next_op,
+                None => {
+                    pending.remove(&pending_key);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn has_newer_op(&self, bucket: Bucket, key: &str) -> bool {
+        let pending = self.pending.lock().unwrap();
+        pending
+            .get(&(bucket, key.to_owned()))
+            .is_some_and(Option::is_some)
+    }
+
+    async fn replicate_with_retries(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        op: &ReplicationOp,
+        retry_policy: RetryPolicy,
+    ) -> ReplicationResult {
+        let name = &self.inner.name;
+        let operation = op.metrics_label();
+        let mut backoff = retry_policy.initial_backoff;
+        for attempt in 1..=retry_policy.max_attempts {
+            let result = match op {
+                ReplicationOp::Put(value) => {
+                    self.inner.store.put_raw(bucket, key, value.clone()).await
+                }
+                ReplicationOp::Remove => match self.inner.store.remove_raw(bucket, key).await {
+                    Err(ObjectStoreError::KeyNotFound(_)) => Ok(()),
+                    result => result,
+                },
+            };
+            let err = match result {
+                Ok(()) => {
+                    tracing::trace!(
+                        "replicated {operation:?} of `{bucket}/{key}` to mirror `{name}`"
+                    );
+                    return ReplicationResult::Success;
+                }
+                Err(err) => anyhow::Error::from(err),
+            };
+
+            if self.has_newer_op(bucket, key) {
+                tracing::info!(
+                    "failed replicating {operation:?} of `{bucket}/{key}` to mirror `{name}`: {err:#}; \
+                     not retrying since it is superseded by a newer operation"
+                );
+                return ReplicationResult::Superseded;
+            }
+            if attempt == retry_policy.max_attempts {
+                tracing::warn!(
+                    "failed replicating {operation:?} of `{bucket}/{key}` to mirror `{name}` after {attempt} attempts: {err:#}"
+                );
+                return ReplicationResult::Failure;
+            }
+            tracing::info!(
+                "failed replicating {operation:?} of `{bucket}/{key}` to mirror `{name}` (attempt {attempt}/{}): {err:#}",
+                retry_policy.max_attempts
+            );
+            REPLICATION_METRICS.retries[&name.to_string()].inc();
+            // Randomize sleep duration to prevent stampeding the mirror if multiple replications fail at the same time.
+            let sleep_duration = backoff.mul_f32(rand::thread_rng().gen_range(0.8..1.2));
+            tokio::time::sleep(sleep_duration).await;
+            backoff = (backoff * 2).min(retry_policy.max_backoff);
+        }
+        ReplicationResult::Failure
+    }
+
+    fn report(&self, operation: ReplicationOperation, result: ReplicationResult) {
+        REPLICATION_METRICS.replicated[&(self.inner.name.to_string(), operation, result)].inc();
+    }
+}
+
+/// Object store that writes objects into the primary store and asynchronously replicates them to mirror stores.
+/// Reads are served by stores in the configured order of preference.
+///
+/// Failed replication operations are retried with exponential backoff. Operations on the same key are applied
+/// to each mirror in order.
+#[derive(Debug)]
+pub(crate) struct ReplicatingObjectStore {
+    primary: Arc<dyn ObjectStore>,
+    mirrors: Vec<Mirror>,
+    retry_policy: RetryPolicy,
+    /// Stores in the order they are queried on reads. Always includes all stores.
+    read_order: Vec<NamedStore>,
+}
+
+impl ReplicatingObjectStore {
+    pub fn new(
+        primary: Arc<dyn ObjectStore>,
+        mirrors: Vec<(String, Arc<dyn ObjectStore>)>,
+        read_preference: &[String],
+    ) -> Result<Self, ObjectStoreError> {
+        const PRIMARY_NAME: &str = ObjectStoreReplicationConfig::PRIMARY_STORE_NAME;
+
+        let primary_store = NamedStore {
+            name: PRIMARY_NAME.into(),
+            store: primary.clone(),
+        };
+        let mirrors: Vec<_> = mirrors
+            .into_iter()
+            .map(|(name, store)| NamedStore {
+                name: name.into(),
+                store,
+            })
+            .collect();
+
+        let mut names = HashSet::from([PRIMARY_NAME]);
+        for mirror in &mirrors {
+            if !names.insert(&*mirror.name) {
+                return Err(config_error(format!(
+                    "mirror name `{}` is not unique or coincides with the primary store name",
+                    mirror.name
+                )));
+            }
+        }
+
+        let all_stores = || std::iter::once(&primary_store).chain(&mirrors);
+        let mut read_order = Vec::with_capacity(mirrors.len() + 1);
+        for name in read_preference {
+            let store = all_stores()
+                .find(|store| *store.name == **name)
+                .ok_or_else(|| {
+                    config_error(format!("unknown store `{name}` in read preference"))
+                })?;
+            if read_order
+                .iter()
+                .any(|other: &NamedStore| other.name == store.name)
+            {
+                return Err(config_error(format!(
+                    "store `{name}` is mentioned in read preference multiple times"
+                )));
+            }
+            read_order.push(store.clone());
+        }
+        for store in all_stores() {
+            if !read_order.iter().any(|other| other.name == store.name) {
+                read_order.push(store.clone());
+            }
+        }
+
+        let read_order_names: Vec<_> = read_order.iter().map(|store| &*store.name).collect();
+        tracing::info!(
+            "Initialized replication for store {primary:?} to {} mirror(s); read order: {read_order_names:?}",
+            mirrors.len()
+        );
+        Ok(Self {
+            primary,
+            mirrors: mirrors.into_iter().map(Mirror::new).collect(),
+            retry_policy: RetryPolicy::default(),
+            read_order,
+        })
+    }
+
+    fn replicate(&self, bucket: Bucket, key: &str, op: ReplicationOp) {
+        for mirror in &self.mirrors {
+            mirror.replicate(bucket, key, op.clone(), self.retry_policy);
+        }
+    }
+}
+
+fn config_error(message: String) -> ObjectStoreError {
+    ObjectStoreError::Initialization {
+        source: message.into(),
+        is_retriable: false,
+    }
+}
+
+#[async_trait]
+impl ObjectStore for ReplicatingObjectStore {
+    #[tracing::instrument(name = "ReplicatingObjectStore::get_raw", skip(self))]
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        let mut primary_err = None;
+        for (i, store) in self.read_order.iter().enumerate() {
+            match store.store.get_raw(bucket, key).await {
+                Ok(object) => {
+                    tracing::trace!("obtained object from store `{}`", store.name);
+                    let source = if i == 0 {
+                        ReadSource::Preferred
+                    } else {
+                        ReadSource::Fallback
+                    };
+                    REPLICATION_METRICS.reads[&(store.name.to_string(), source)].inc();
+                    return Ok(object);
+                }
+                Err(err) => {
+                    // Missing objects are expected in mirrors if replication lags behind or has failed.
+                    if !matches!(err, ObjectStoreError::KeyNotFound(_)) {
+                        tracing::warn!("failed getting object from store `{}`: {err}", store.name);
+                    }
+                    if *store.name == *ObjectStoreReplicationConfig::PRIMARY_STORE_NAME {
+                        primary_err = Some(err);
+                    }
+                }
+            }
+        }
+        // The primary store is the source of truth, so we return its error.
+        Err(primary_err.expect("primary store is always queried"))
+    }
+
+    #[tracing::instrument(
+        name = "ReplicatingObjectStore::put_raw",
+        skip(self, value),
+        fields(value.len = value.len())
+    )]
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        self.primary.put_raw(bucket, key, value.clone()).await?;
+        // Only replicate the value once it has been put in the primary store
+        self.replicate(bucket, key, ReplicationOp::Put(value));
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "ReplicatingObjectStore::remove_raw", skip(self))]
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        self.primary.remove_raw(bucket, key).await?;
+        self.replicate(bucket, key, ReplicationOp::Remove);
+        Ok(())
+    }
+
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        self.primary.object_size_raw(bucket, key).await
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        self.primary.storage_prefix_raw(bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use assert_matches::assert_matches;
+    use tokio::sync::Semaphore;
+
+    use super::*;
+    use crate::MockObjectStore;
+
+    const TEST_RETRY_POLICY: RetryPolicy = RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+    };
+
+    /// Store that fails the specified number of first puts with a retriable error.
+    #[derive(Debug)]
+    struct FlakyStore {
+        inner: Arc<dyn ObjectStore>,
+        remaining_failures: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ObjectStore for FlakyStore {
+        async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+            self.inner.get_raw(bucket, key).await
+        }
+
+        async fn put_raw(
+            &self,
+            bucket: Bucket,
+            key: &str,
+            value: Vec<u8>,
+        ) -> Result<(), ObjectStoreError> {
+            let should_fail = self
+                .remaining_failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                    count.checked_sub(1)
+                })
+                .is_ok();
+            if should_fail {
+                return Err(ObjectStoreError::Other {
+                    source: "transient failure".into(),
+                    is_retriable: true,
+                });
+            }
+            self.inner.put_raw(bucket, key, value).await
+        }
+
+        async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+            self.inner.remove_raw(bucket, key).await
+        }
+
+        fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+            self.inner.storage_prefix_raw(bucket)
+        }
+    }
+
+    /// Store that blocks puts until permits are added to the semaphore.
+    #[derive(Debug)]
+    struct BlockingStore {
+        inner: Arc<dyn ObjectStore>,
+        permits: Arc<Semaphore>,
+    }
+
+    #[async_trait]
+    impl ObjectStore for BlockingStore {
+        async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+            self.inner.get_raw(bucket, key).await
+        }
+
+        async fn put_raw(
+            &self,
+            bucket: Bucket,
+            key: &str,
+            value: Vec<u8>,
+        ) -> Result<(), ObjectStoreError> {
+            self.permits.acquire().await.unwrap().forget();
+            self.inner.put_raw(bucket, key, value).await
+        }
+
+        async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+            self.inner.remove_raw(bucket, key).await
+        }
+
+        fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+            self.inner.storage_prefix_raw(bucket)
+        }
+    }
+
+    async fn wait_for_replication(store: &ReplicatingObjectStore) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while store
+                .mirrors
+                .iter()
+                .any(|mirror| !mirror.pending.lock().unwrap().is_empty())
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for replication");
+    }
+
+    async fn wait_for_object(store: &dyn ObjectStore, key: &str) -> Vec<u8> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                match store.get_raw(Bucket::WitnessInput, key).await {
+                    Ok(object) => break object,
+                    Err(ObjectStoreError::KeyNotFound(_)) => {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                    Err(err) => panic!("unexpected error: {err}"),
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for replication")
+    }
+
+    #[tokio::test]
+    async fn replication_basics() {
+        let primary = MockObjectStore::arc();
+        let mirror = MockObjectStore::arc();
+        let store = ReplicatingObjectStore::new(
+            primary.clone(),
+            vec![("eu".to_owned(), mirror.clone())],
+            &["eu".to_owned()],
+        )
+        .unwrap();
+        let read_order: Vec<_> = store.read_order.iter().map(|store| &*store.name).collect();
+        assert_eq!(read_order, ["eu", "primary"]);
+
+        store
+            .put_raw(Bucket::WitnessInput, "test", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let object = primary.get_raw(Bucket::WitnessInput, "test").await.unwrap();
+        assert_eq!(object, [1, 2, 3]);
+        assert_eq!(wait_for_object(&*mirror, "test").await, [1, 2, 3]);
+
+        // Objects missing in the preferred mirror should be read from the primary store.
+        primary
+            .put_raw(Bucket::WitnessInput, "other", vec![3, 2, 1])
+            .await
+            .unwrap();
+        let object = store.get_raw(Bucket::WitnessInput, "other").await.unwrap();
+        assert_eq!(object, [3, 2, 1]);
+        // ...and vice versa, the preferred mirror should be queried first.
+        mirror
+            .put_raw(Bucket::WitnessInput, "mirrored", vec![0])
+            .await
+            .unwrap();
+        let object = store
+            .get_raw(Bucket::WitnessInput, "mirrored")
+            .await
+            .unwrap();
+        assert_eq!(object, [0]);
+
+        let err = store
+            .get_raw(Bucket::WitnessInput, "missing")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+
+        store
+            .remove_raw(Bucket::WitnessInput, "test")
+            .await
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while mirror.get_raw(Bucket::WitnessInput, "test").await.is_ok() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for replicated removal");
+    }
+
+    #[tokio::test]
+    async fn failed_replication_is_retried() {
+        let mirror = MockObjectStore::arc();
+        let flaky_mirror = Arc::new(FlakyStore {
+            inner: mirror.clone(),
+            remaining_failures: AtomicUsize::new(2),
+        });
+        let mut store = ReplicatingObjectStore::new(
+            MockObjectStore::arc(),
+            vec![("eu".to_owned(), flaky_mirror.clone())],
+            &[],
+        )
+        .unwrap();
+        store.retry_policy = TEST_RETRY_POLICY;
+
+        store
+            .put_raw(Bucket::WitnessInput, "test", vec![1, 2, 3])
+            .await
+            .unwrap();
+        assert_eq!(wait_for_object(&*mirror, "test").await, [1, 2, 3]);
+
+        // If retries are exhausted, the object is not replicated.
+        flaky_mirror.remaining_failures.store(3, Ordering::SeqCst);
+        store
+            .put_raw(Bucket::WitnessInput, "other", vec![3, 2, 1])
+            .await
+            .unwrap();
+        wait_for_replication(&store).await;
+        let err = mirror
+            .get_raw(Bucket::WitnessInput, "other")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+    }
+
+    #[tokio::test]
+    async fn replication_preserves_operation_order() {
+        let mirror = MockObjectStore::arc();
+        let permits = Arc::new(Semaphore::new(0));
+        let blocking_mirror = Arc::new(BlockingStore {
+            inner: mirror.clone(),
+            permits: permits.clone(),
+        });
+        let mut store = ReplicatingObjectStore::new(
+            MockObjectStore::arc(),
+            vec![("eu".to_owned(), blocking_mirror)],
+            &[],
+        )
+        .unwrap();
+        store.retry_policy = TEST_RETRY_POLICY;
+
+        // The first put is blocked, so the following operations must wait for it to complete.
+        store
+            .put_raw(Bucket::WitnessInput, "test", vec![1])
+            .await
+            .unwrap();
+        store
+            .remove_raw(Bucket::WitnessInput, "test")
+            .await
+            .unwrap();
+        permits.add_permits(1);
+        wait_for_replication(&store).await;
+        let err = mirror
+            .get_raw(Bucket::WitnessInput, "test")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::KeyNotFound(_));
+
+        store
+            .put_raw(Bucket::WitnessInput, "test", vec![2])
+            .await
+            .unwrap();
+        store
+            .remove_raw(Bucket::WitnessInput, "test")
+            .await
+            .unwrap();
+        store
+            .put_raw(Bucket::WitnessInput, "test", vec![3])
+            .await
+            .unwrap();
+        permits.add_permits(2);
+        wait_for_replication(&store).await;
+        let object = mirror.get_raw(Bucket::WitnessInput, "test").await.unwrap();
+        assert_eq!(object, [3]);
+        // The remove operation was superseded by the last put, so only 2 puts should have been performed.
+        assert_eq!(permits.available_permits(), 0);
+    }
+
+    #[test]
+    fn invalid_replication_config() {
+        let mirrors = || {
+            vec![
+                ("eu".to_owned(), MockObjectStore::arc()),
+                ("us".to_owned(), MockObjectStore::arc()),
+            ]
+        };
+
+        let store = ReplicatingObjectStore::new(MockObjectStore::arc(), mirrors(), &[]).unwrap();
+        let read_order: Vec<_> = store.read_order.iter().map(|store| &*store.name).collect();
+        assert_eq!(read_order, ["primary", "eu", "us"]);
+
+        let err =
+            ReplicatingObjectStore::new(MockObjectStore::arc(), mirrors(), &["asia".to_owned()])
+                .unwrap_err();
+        assert!(err.to_string().contains("unknown store `asia`"), "{err}");
+
+        let read_preference = ["us".to_owned(), "us".to_owned()];
+        let err = ReplicatingObjectStore::new(MockObjectStore::arc(), mirrors(), &read_preference)
+            .unwrap_err();
+        assert!(err.to_string().contains("multiple times"), "{err}");
+
+        let mut mirrors = mirrors();
+        mirrors[1].0 = "primary".to_owned();
+        let err = ReplicatingObjectStore::new(MockObjectStore::arc(), mirrors, &[]).unwrap_err();
+        assert!(err.to_string().contains("not unique"), "{err}");
+    }
+}
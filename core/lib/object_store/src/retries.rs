@@ -16,6 +16,7 @@ enum Request<'a> {
     Get(Bucket, &'a str),
     Put(Bucket, &'a str),
     Remove(Bucket, &'a str),
+    GetSize(Bucket, &'a str),
 }
 
 impl Request<'_> {
@@ -127,6 +128,14 @@ impl<S: ObjectStore> ObjectStore for StoreWithRetries<S> {
             .await
     }
 
+    async fn object_size_raw(&self, bucket: Bucket, key: &str) -> Result<u64, ObjectStoreError> {
+        Request::GetSize(bucket, key)
+            .retry(&self.inner, self.max_retries, || {
+                self.inner.object_size_raw(bucket, key)
+            })
+            .await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
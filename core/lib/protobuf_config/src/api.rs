@@ -7,7 +7,7 @@ use zksync_protobuf::{
     required,
 };
 
-use crate::{parse_h160, proto::api as proto};
+use crate::{parse_h160, proto::api as proto, read_optional_repr};
 
 impl ProtoRepr for proto::Api {
     type Type = ApiConfig;
@@ -17,6 +17,7 @@ impl ProtoRepr for proto::Api {
             prometheus: read_required_repr(&self.prometheus).context("prometheus")?,
             healthcheck: read_required_repr(&self.healthcheck).context("healthcheck")?,
             merkle_tree: read_required_repr(&self.merkle_tree).context("merkle_tree")?,
+            etherscan: read_optional_repr(&self.etherscan).unwrap_or_default(),
         })
     }
 
@@ -26,6 +27,7 @@ impl ProtoRepr for proto::Api {
             prometheus: Some(ProtoRepr::build(&this.prometheus)),
             healthcheck: Some(ProtoRepr::build(&this.healthcheck)),
             merkle_tree: Some(ProtoRepr::build(&this.merkle_tree)),
+            etherscan: Some(ProtoRepr::build(&this.etherscan)),
         }
     }
 }
@@ -93,11 +95,28 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into())
                 .transpose()
                 .context("vm_execution_cache_misses_limit")?,
+            vm_execution_max_cycles: self.vm_execution_max_cycles,
+            vm_execution_timeout_ms: self.vm_execution_timeout_ms,
             vm_concurrency_limit: self
                 .vm_concurrency_limit
                 .map(|x| x.try_into())
                 .transpose()
                 .context("vm_concurrency_limit")?,
+            call_vm_concurrency_limit: self
+                .call_vm_concurrency_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("call_vm_concurrency_limit")?,
+            estimate_vm_concurrency_limit: self
+                .estimate_vm_concurrency_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("estimate_vm_concurrency_limit")?,
+            trace_vm_concurrency_limit: self
+                .trace_vm_concurrency_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("trace_vm_concurrency_limit")?,
             factory_deps_cache_size_mb: self
                 .factory_deps_cache_size_mb
                 .map(|x| x.try_into())
@@ -118,6 +137,11 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into())
                 .transpose()
                 .context("latest_values_max_block_lag")?,
+            block_state_cache_size_mb: self
+                .block_state_cache_size_mb
+                .map(|x| x.try_into())
+                .transpose()
+                .context("block_state_cache_size_mb")?,
             fee_history_limit: self.fee_history_limit,
             max_batch_request_size: self
                 .max_batch_request_size
@@ -178,7 +202,18 @@ impl ProtoRepr for proto::Web3JsonRpc {
             vm_execution_cache_misses_limit: this
                 .vm_execution_cache_misses_limit
                 .map(|x| x.try_into().unwrap()),
+            vm_execution_max_cycles: this.vm_execution_max_cycles,
+            vm_execution_timeout_ms: this.vm_execution_timeout_ms,
             vm_concurrency_limit: this.vm_concurrency_limit.map(|x| x.try_into().unwrap()),
+            call_vm_concurrency_limit: this
+                .call_vm_concurrency_limit
+                .map(|x| x.try_into().unwrap()),
+            estimate_vm_concurrency_limit: this
+                .estimate_vm_concurrency_limit
+                .map(|x| x.try_into().unwrap()),
+            trace_vm_concurrency_limit: this
+                .trace_vm_concurrency_limit
+                .map(|x| x.try_into().unwrap()),
             factory_deps_cache_size_mb: this
                 .factory_deps_cache_size_mb
                 .map(|x| x.try_into().unwrap()),
@@ -189,6 +224,9 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .latest_values_cache_size_mb
                 .map(|x| x.try_into().unwrap()),
             latest_values_max_block_lag: this.latest_values_max_block_lag.map(NonZeroU32::get),
+            block_state_cache_size_mb: this
+                .block_state_cache_size_mb
+                .map(|x| x.try_into().unwrap()),
             fee_history_limit: this.fee_history_limit,
             max_batch_request_size: this.max_batch_request_size.map(|x| x.try_into().unwrap()),
             max_response_body_size_mb: this
@@ -231,6 +269,7 @@ impl ProtoRepr for proto::HealthCheck {
                 .context("port")?,
             slow_time_limit_ms: self.slow_time_limit_ms,
             hard_time_limit_ms: self.hard_time_limit_ms,
+            expose_config: self.expose_config.unwrap_or(false),
         })
     }
 
@@ -239,6 +278,7 @@ impl ProtoRepr for proto::HealthCheck {
             port: Some(this.port.into()),
             slow_time_limit_ms: this.slow_time_limit_ms,
             hard_time_limit_ms: this.hard_time_limit_ms,
+            expose_config: Some(this.expose_config),
         }
     }
 }
@@ -258,3 +298,30 @@ impl ProtoRepr for proto::MerkleTreeApi {
         }
     }
 }
+
+impl ProtoRepr for proto::EtherscanApi {
+    type Type = api::EtherscanApiConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let defaults = Self::Type::default();
+        Ok(Self::Type {
+            port: self
+                .port
+                .map(|port| port.try_into())
+                .transpose()
+                .context("port")?
+                .unwrap_or(defaults.port),
+            max_page_size: self
+                .max_page_size
+                .map(|size| size.try_into())
+                .transpose()
+                .context("max_page_size")?
+                .unwrap_or(defaults.max_page_size),
+        })
+    }
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            port: Some(this.port.into()),
+            max_page_size: Some(this.max_page_size as u64),
+        }
+    }
+}
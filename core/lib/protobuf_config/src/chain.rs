@@ -22,6 +22,28 @@ impl proto::FeeModelVersion {
     }
 }
 
+impl proto::MempoolOrderingPolicy {
+    fn new(policy: &configs::chain::MempoolOrderingPolicy) -> Self {
+        use configs::chain::MempoolOrderingPolicy as From;
+        match policy {
+            From::Timestamp => Self::Timestamp,
+            From::Fifo => Self::Fifo,
+            From::PriorityFee => Self::PriorityFee,
+            From::Custom => Self::Custom,
+        }
+    }
+
+    fn parse(&self) -> configs::chain::MempoolOrderingPolicy {
+        use configs::chain::MempoolOrderingPolicy as To;
+        match self {
+            Self::Timestamp => To::Timestamp,
+            Self::Fifo => To::Fifo,
+            Self::PriorityFee => To::PriorityFee,
+            Self::Custom => To::Custom,
+        }
+    }
+}
+
 impl ProtoRepr for proto::StateKeeper {
     type Type = configs::chain::StateKeeperConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -81,6 +103,20 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            l2_base_fee_target_utilization: self.l2_base_fee_target_utilization,
+            l2_base_fee_max_change_rate: self.l2_base_fee_max_change_rate,
+            l2_base_fee_max_multiplier: self.l2_base_fee_max_multiplier,
+            forced_inclusion_deadline_batches: self.forced_inclusion_deadline_batches,
+            max_l2_tx_size_bytes: self
+                .max_l2_tx_size_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_l2_tx_size_bytes")?,
+            max_l2_tx_calldata_bytes: self
+                .max_l2_tx_calldata_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_l2_tx_calldata_bytes")?,
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -120,6 +156,12 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            l2_base_fee_target_utilization: this.l2_base_fee_target_utilization,
+            l2_base_fee_max_change_rate: this.l2_base_fee_max_change_rate,
+            l2_base_fee_max_multiplier: this.l2_base_fee_max_multiplier,
+            forced_inclusion_deadline_batches: this.forced_inclusion_deadline_batches,
+            max_l2_tx_size_bytes: this.max_l2_tx_size_bytes.map(|x| x.try_into().unwrap()),
+            max_l2_tx_calldata_bytes: this.max_l2_tx_calldata_bytes.map(|x| x.try_into().unwrap()),
         }
     }
 }
@@ -151,6 +193,18 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: *required(&self.stuck_tx_timeout).context("stuck_tx_timeout")?,
             remove_stuck_txs: *required(&self.remove_stuck_txs).context("remove_stuck_txs")?,
             delay_interval: *required(&self.delay_interval).context("delay_interval")?,
+            admission_dry_run_concurrency: self
+                .admission_dry_run_concurrency
+                .map(|x| x.try_into())
+                .transpose()
+                .context("admission_dry_run_concurrency")?,
+            admission_reject_halted_txs: self.admission_reject_halted_txs.unwrap_or(false),
+            ordering_policy: self
+                .ordering_policy
+                .map(|x| anyhow::Ok(proto::MempoolOrderingPolicy::try_from(x)?.parse()))
+                .transpose()
+                .context("ordering_policy")?
+                .unwrap_or_default(),
         })
     }
 
@@ -162,6 +216,11 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: Some(this.stuck_tx_timeout),
             remove_stuck_txs: Some(this.remove_stuck_txs),
             delay_interval: Some(this.delay_interval),
+            admission_dry_run_concurrency: this
+                .admission_dry_run_concurrency
+                .map(|x| x.try_into().unwrap()),
+            admission_reject_halted_txs: Some(this.admission_reject_halted_txs),
+            ordering_policy: Some(proto::MempoolOrderingPolicy::new(&this.ordering_policy).into()),
         }
     }
 }
@@ -48,6 +48,19 @@ impl ProtoRepr for proto::MerkleTree {
             max_l1_batches_per_iter: required(&self.max_l1_batches_per_iter)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_l1_batches_per_iter")?,
+            retained_l1_batches: self.retained_l1_batches,
+            maintenance_window_start_hour: self
+                .maintenance_window_start_hour
+                .map(u8::try_from)
+                .transpose()
+                .context("maintenance_window_start_hour")?,
+            maintenance_window_end_hour: self
+                .maintenance_window_end_hour
+                .map(u8::try_from)
+                .transpose()
+                .context("maintenance_window_end_hour")?,
+            maintenance_compaction_min_pruned_l1_batches: self
+                .maintenance_compaction_min_pruned_l1_batches,
         })
     }
 
@@ -60,6 +73,11 @@ impl ProtoRepr for proto::MerkleTree {
             memtable_capacity_mb: Some(this.memtable_capacity_mb.try_into().unwrap()),
             stalled_writes_timeout_sec: Some(this.stalled_writes_timeout_sec),
             max_l1_batches_per_iter: Some(this.max_l1_batches_per_iter.try_into().unwrap()),
+            retained_l1_batches: this.retained_l1_batches,
+            maintenance_window_start_hour: this.maintenance_window_start_hour.map(Into::into),
+            maintenance_window_end_hour: this.maintenance_window_end_hour.map(Into::into),
+            maintenance_compaction_min_pruned_l1_batches: this
+                .maintenance_compaction_min_pruned_l1_batches,
         }
     }
 }
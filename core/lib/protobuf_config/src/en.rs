@@ -4,7 +4,7 @@ use std::{
 };
 
 use anyhow::Context;
-use zksync_basic_types::{url::SensitiveUrl, L1ChainId, L2ChainId};
+use zksync_basic_types::{url::SensitiveUrl, L1BatchNumber, L1ChainId, L2ChainId};
 use zksync_config::configs::en_config::ENConfig;
 use zksync_protobuf::{required, ProtoRepr};
 
@@ -31,6 +31,13 @@ impl ProtoRepr for proto::ExternalNode {
             .and_then(|x| Ok(crate::proto::genesis::L1BatchCommitDataGeneratorMode::try_from(*x)?))
             .context("l1_batch_commit_data_generator_mode")?
             .parse(),
+            commitment_mode_transition_batches: self
+                .commitment_mode_transition_batches
+                .iter()
+                .copied()
+                .map(L1BatchNumber)
+                .collect(),
+            forced_inclusion_deadline_batches: self.forced_inclusion_deadline_batches,
             main_node_rate_limit_rps: self
                 .main_node_rate_limit_rps
                 .and_then(|a| NonZeroUsize::new(a as usize)),
@@ -56,6 +63,12 @@ impl ProtoRepr for proto::ExternalNode {
                 )
                 .into(),
             ),
+            commitment_mode_transition_batches: this
+                .commitment_mode_transition_batches
+                .iter()
+                .map(|a| a.0)
+                .collect(),
+            forced_inclusion_deadline_batches: this.forced_inclusion_deadline_batches,
             main_node_rate_limit_rps: this.main_node_rate_limit_rps.map(|a| a.get() as u64),
             gateway_url: this
                 .gateway_url
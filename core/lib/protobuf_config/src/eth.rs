@@ -45,6 +45,26 @@ impl proto::PubdataSendingMode {
     }
 }
 
+impl proto::L1FeeMarket {
+    fn new(x: &configs::eth_sender::L1FeeMarket) -> Self {
+        use configs::eth_sender::L1FeeMarket as From;
+        match x {
+            From::Eip4844 => Self::Eip4844,
+            From::Eip1559 => Self::Eip1559,
+            From::Legacy => Self::Legacy,
+        }
+    }
+
+    fn parse(&self) -> configs::eth_sender::L1FeeMarket {
+        use configs::eth_sender::L1FeeMarket as To;
+        match self {
+            Self::Eip4844 => To::Eip4844,
+            Self::Eip1559 => To::Eip1559,
+            Self::Legacy => To::Legacy,
+        }
+    }
+}
+
 impl ProtoRepr for proto::Eth {
     type Type = configs::eth_sender::EthConfig;
 
@@ -117,6 +137,7 @@ impl ProtoRepr for proto::Sender {
             time_in_mempool_in_l1_blocks_cap: self
                 .time_in_mempool_in_l1_blocks_cap
                 .unwrap_or(Self::Type::default_time_in_mempool_in_l1_blocks_cap()),
+            wait_for_batch_verification: self.wait_for_batch_verification.unwrap_or(false),
         })
     }
 
@@ -150,6 +171,7 @@ impl ProtoRepr for proto::Sender {
             tx_aggregation_only_prove_and_execute: Some(this.tx_aggregation_only_prove_and_execute),
             tx_aggregation_paused: Some(this.tx_aggregation_paused),
             time_in_mempool_in_l1_blocks_cap: Some(this.time_in_mempool_in_l1_blocks_cap),
+            wait_for_batch_verification: Some(this.wait_for_batch_verification),
         }
     }
 }
@@ -185,6 +207,12 @@ impl ProtoRepr for proto::GasAdjuster {
             max_blob_base_fee: self.max_blob_base_fee,
             // TODO(EVM-676): support this field
             settlement_mode: Default::default(),
+            l1_fee_market: self
+                .l1_fee_market
+                .map(proto::L1FeeMarket::try_from)
+                .transpose()
+                .context("l1_fee_market")?
+                .map_or_else(Default::default, |market| market.parse()),
         })
     }
 
@@ -206,6 +234,7 @@ impl ProtoRepr for proto::GasAdjuster {
             ),
             internal_pubdata_pricing_multiplier: Some(this.internal_pubdata_pricing_multiplier),
             max_blob_base_fee: this.max_blob_base_fee,
+            l1_fee_market: Some(proto::L1FeeMarket::new(&this.l1_fee_market).into()),
         }
     }
 }
@@ -0,0 +1,35 @@
+use anyhow::Context;
+use zksync_config::configs::ExternalBlockBuilderConfig;
+use zksync_protobuf::{required, ProtoRepr};
+
+use crate::proto::external_block_builder as proto;
+
+impl ProtoRepr for proto::ExternalBlockBuilder {
+    type Type = ExternalBlockBuilderConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            http_port: required(&self.http_port)
+                .and_then(|p| Ok((*p).try_into()?))
+                .context("http_port")?,
+            auth_token: required(&self.auth_token).context("auth_token")?.clone(),
+            proposal_timeout_ms: self
+                .proposal_timeout_ms
+                .unwrap_or_else(Self::Type::default_proposal_timeout_ms),
+            max_transactions_per_proposal: self
+                .max_transactions_per_proposal
+                .map(|count| count.try_into())
+                .transpose()
+                .context("max_transactions_per_proposal")?
+                .unwrap_or_else(Self::Type::default_max_transactions_per_proposal),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            http_port: Some(this.http_port.into()),
+            auth_token: Some(this.auth_token.clone()),
+            proposal_timeout_ms: Some(this.proposal_timeout_ms),
+            max_transactions_per_proposal: Some(this.max_transactions_per_proposal as u64),
+        }
+    }
+}
@@ -34,6 +34,7 @@ impl ProtoRepr for proto::GeneralConfig {
             basic_witness_input_producer_config: read_optional_repr(
                 &self.basic_witness_input_producer,
             ),
+            batch_verifier_config: read_optional_repr(&self.batch_verifier),
             core_object_store: read_optional_repr(&self.core_object_store),
             base_token_adjuster: read_optional_repr(&self.base_token_adjuster),
             commitment_generator: read_optional_repr(&self.commitment_generator),
@@ -47,6 +48,8 @@ impl ProtoRepr for proto::GeneralConfig {
             experimental_vm_config: read_optional_repr(&self.experimental_vm),
             prover_job_monitor_config: read_optional_repr(&self.prover_job_monitor),
             timestamp_asserter_config: read_optional_repr(&self.timestamp_asserter),
+            external_block_builder_config: read_optional_repr(&self.external_block_builder),
+            token_price_config: read_optional_repr(&self.token_price),
         })
     }
 
@@ -88,6 +91,7 @@ impl ProtoRepr for proto::GeneralConfig {
                 .basic_witness_input_producer_config
                 .as_ref()
                 .map(ProtoRepr::build),
+            batch_verifier: this.batch_verifier_config.as_ref().map(ProtoRepr::build),
             commitment_generator: this.commitment_generator.as_ref().map(ProtoRepr::build),
             snapshot_recovery: this.snapshot_recovery.as_ref().map(ProtoRepr::build),
             pruning: this.pruning.as_ref().map(ProtoRepr::build),
@@ -111,6 +115,11 @@ impl ProtoRepr for proto::GeneralConfig {
                 .timestamp_asserter_config
                 .as_ref()
                 .map(ProtoRepr::build),
+            external_block_builder: this
+                .external_block_builder_config
+                .as_ref()
+                .map(ProtoRepr::build),
+            token_price: this.token_price_config.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -26,6 +26,29 @@ impl proto::L1BatchCommitDataGeneratorMode {
     }
 }
 
+impl ProtoRepr for proto::CustomSystemContract {
+    type Type = configs::CustomSystemContract;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            address: required(&self.address)
+                .and_then(|x| parse_h160(x))
+                .context("address")?,
+            bytecode_hash: required(&self.bytecode_hash)
+                .and_then(|x| parse_h256(x))
+                .context("bytecode_hash")?,
+            source: required(&self.source).context("source")?.clone(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            address: Some(format!("{:?}", this.address)),
+            bytecode_hash: Some(format!("{:?}", this.bytecode_hash)),
+            source: Some(this.source.clone()),
+        }
+    }
+}
+
 impl ProtoRepr for proto::Genesis {
     type Type = configs::GenesisConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -99,6 +122,13 @@ impl ProtoRepr for proto::Genesis {
             .and_then(|x| Ok(proto::L1BatchCommitDataGeneratorMode::try_from(*x)?))
             .context("l1_batch_commit_data_generator_mode")?
             .parse(),
+            custom_system_contracts: self
+                .custom_system_contracts
+                .iter()
+                .enumerate()
+                .map(|(i, contract)| contract.read().context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("custom_system_contracts")?,
         })
     }
 
@@ -126,6 +156,11 @@ impl ProtoRepr for proto::Genesis {
                 )
                 .into(),
             ),
+            custom_system_contracts: this
+                .custom_system_contracts
+                .iter()
+                .map(ProtoRepr::build)
+                .collect(),
         }
     }
 }
@@ -18,6 +18,7 @@ mod database;
 mod en;
 mod eth;
 mod experimental;
+mod external_block_builder;
 mod external_price_api_client;
 mod external_proof_integration_api;
 mod general;
@@ -36,6 +37,7 @@ mod snapshots_creator;
 #[cfg(test)]
 mod tests;
 mod timestamp_asserter;
+mod token_price;
 mod utils;
 mod vm_runner;
 mod wallets;
@@ -90,6 +92,15 @@ pub fn encode_yaml_repr<T: ProtoRepr>(value: &T::Type) -> anyhow::Result<Vec<u8>
     Ok(buffer)
 }
 
+/// Encodes a config as JSON. The output uses the same field names as YAML configs, so it can be compared
+/// with the contents of a YAML file read via [`read_yaml_repr()`].
+pub fn encode_json_repr<T: ProtoRepr>(value: &T::Type) -> anyhow::Result<serde_json::Value> {
+    Ok(serialize_proto(
+        &T::build(value),
+        serde_json::value::Serializer,
+    )?)
+}
+
 fn serialize_proto<T: ReflectMessage, S: serde::Serializer>(
     x: &T,
     s: S,
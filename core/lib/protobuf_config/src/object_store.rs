@@ -1,87 +1,171 @@
 use anyhow::Context as _;
-use zksync_config::configs::object_store::{ObjectStoreConfig, ObjectStoreMode};
+use zksync_config::configs::object_store::{
+    ObjectStoreConfig, ObjectStoreMirrorConfig, ObjectStoreMode, ObjectStoreReplicationConfig,
+};
 use zksync_protobuf::{repr::ProtoRepr, required};
 
 use crate::proto::object_store as proto;
 
-impl ProtoRepr for proto::ObjectStore {
-    type Type = ObjectStoreConfig;
+impl From<proto::object_store::mirror::Mode> for proto::object_store::Mode {
+    fn from(mode: proto::object_store::mirror::Mode) -> Self {
+        use proto::object_store::mirror::Mode;
 
-    fn read(&self) -> anyhow::Result<Self::Type> {
-        let mode = required(&self.mode).context("mode")?;
-        let mode = match mode {
-            proto::object_store::Mode::Gcs(mode) => ObjectStoreMode::GCS {
+        match mode {
+            Mode::Gcs(mode) => Self::Gcs(mode),
+            Mode::GcsWithCredentialFile(mode) => Self::GcsWithCredentialFile(mode),
+            Mode::GcsAnonymousReadOnly(mode) => Self::GcsAnonymousReadOnly(mode),
+            Mode::FileBacked(mode) => Self::FileBacked(mode),
+        }
+    }
+}
+
+impl From<proto::object_store::Mode> for proto::object_store::mirror::Mode {
+    fn from(mode: proto::object_store::Mode) -> Self {
+        use proto::object_store::Mode;
+
+        match mode {
+            Mode::Gcs(mode) => Self::Gcs(mode),
+            Mode::GcsWithCredentialFile(mode) => Self::GcsWithCredentialFile(mode),
+            Mode::GcsAnonymousReadOnly(mode) => Self::GcsAnonymousReadOnly(mode),
+            Mode::FileBacked(mode) => Self::FileBacked(mode),
+        }
+    }
+}
+
+fn read_mode(mode: &proto::object_store::Mode) -> anyhow::Result<ObjectStoreMode> {
+    Ok(match mode {
+        proto::object_store::Mode::Gcs(mode) => ObjectStoreMode::GCS {
+            bucket_base_url: required(&mode.bucket_base_url)
+                .context("bucket_base_url")?
+                .clone(),
+        },
+        proto::object_store::Mode::GcsWithCredentialFile(mode) => {
+            ObjectStoreMode::GCSWithCredentialFile {
                 bucket_base_url: required(&mode.bucket_base_url)
                     .context("bucket_base_url")?
                     .clone(),
-            },
-            proto::object_store::Mode::GcsWithCredentialFile(mode) => {
-                ObjectStoreMode::GCSWithCredentialFile {
-                    bucket_base_url: required(&mode.bucket_base_url)
-                        .context("bucket_base_url")?
-                        .clone(),
-                    gcs_credential_file_path: required(&mode.gcs_credential_file_path)
-                        .context("gcs_credential_file_path")?
-                        .clone(),
-                }
-            }
-            proto::object_store::Mode::GcsAnonymousReadOnly(mode) => {
-                ObjectStoreMode::GCSAnonymousReadOnly {
-                    bucket_base_url: required(&mode.bucket_base_url)
-                        .context("bucket_base_url")?
-                        .clone(),
-                }
+                gcs_credential_file_path: required(&mode.gcs_credential_file_path)
+                    .context("gcs_credential_file_path")?
+                    .clone(),
             }
-            proto::object_store::Mode::FileBacked(mode) => ObjectStoreMode::FileBacked {
-                file_backed_base_path: required(&mode.file_backed_base_path)
-                    .context("file_backed_base_path")?
+        }
+        proto::object_store::Mode::GcsAnonymousReadOnly(mode) => {
+            ObjectStoreMode::GCSAnonymousReadOnly {
+                bucket_base_url: required(&mode.bucket_base_url)
+                    .context("bucket_base_url")?
                     .clone(),
+            }
+        }
+        proto::object_store::Mode::FileBacked(mode) => ObjectStoreMode::FileBacked {
+            file_backed_base_path: required(&mode.file_backed_base_path)
+                .context("file_backed_base_path")?
+                .clone(),
+        },
+    })
+}
+
+fn build_mode(mode: &ObjectStoreMode) -> proto::object_store::Mode {
+    match mode {
+        ObjectStoreMode::GCS { bucket_base_url } => {
+            proto::object_store::Mode::Gcs(proto::object_store::Gcs {
+                bucket_base_url: Some(bucket_base_url.clone()),
+            })
+        }
+        ObjectStoreMode::GCSWithCredentialFile {
+            bucket_base_url,
+            gcs_credential_file_path,
+        } => proto::object_store::Mode::GcsWithCredentialFile(
+            proto::object_store::GcsWithCredentialFile {
+                bucket_base_url: Some(bucket_base_url.clone()),
+                gcs_credential_file_path: Some(gcs_credential_file_path.clone()),
             },
-        };
+        ),
+        ObjectStoreMode::GCSAnonymousReadOnly { bucket_base_url } => {
+            proto::object_store::Mode::GcsAnonymousReadOnly(
+                proto::object_store::GcsAnonymousReadOnly {
+                    bucket_base_url: Some(bucket_base_url.clone()),
+                },
+            )
+        }
+        ObjectStoreMode::FileBacked {
+            file_backed_base_path,
+        } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
+            file_backed_base_path: Some(file_backed_base_path.clone()),
+        }),
+    }
+}
+
+impl ProtoRepr for proto::ObjectStore {
+    type Type = ObjectStoreConfig;
 
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let mode = required(&self.mode).context("mode")?;
         Ok(Self::Type {
-            mode,
+            mode: read_mode(mode)?,
             max_retries: required(&self.max_retries)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_retries")?,
             local_mirror_path: self.local_mirror_path.clone(),
+            local_mirror_max_size_mb: self.local_mirror_max_size_mb,
+            replication: self
+                .replication
+                .as_ref()
+                .map(ProtoRepr::read)
+                .transpose()
+                .context("replication")?,
         })
     }
 
     fn build(this: &Self::Type) -> Self {
-        let mode = match &this.mode {
-            ObjectStoreMode::GCS { bucket_base_url } => {
-                proto::object_store::Mode::Gcs(proto::object_store::Gcs {
-                    bucket_base_url: Some(bucket_base_url.clone()),
-                })
-            }
-            ObjectStoreMode::GCSWithCredentialFile {
-                bucket_base_url,
-                gcs_credential_file_path,
-            } => proto::object_store::Mode::GcsWithCredentialFile(
-                proto::object_store::GcsWithCredentialFile {
-                    bucket_base_url: Some(bucket_base_url.clone()),
-                    gcs_credential_file_path: Some(gcs_credential_file_path.clone()),
-                },
-            ),
-            ObjectStoreMode::GCSAnonymousReadOnly { bucket_base_url } => {
-                proto::object_store::Mode::GcsAnonymousReadOnly(
-                    proto::object_store::GcsAnonymousReadOnly {
-                        bucket_base_url: Some(bucket_base_url.clone()),
-                    },
-                )
-            }
-            ObjectStoreMode::FileBacked {
-                file_backed_base_path,
-            } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
-                file_backed_base_path: Some(file_backed_base_path.clone()),
-            }),
-        };
-
         Self {
-            mode: Some(mode),
+            mode: Some(build_mode(&this.mode)),
             max_retries: Some(this.max_retries.into()),
             local_mirror_path: this.local_mirror_path.clone(),
+            local_mirror_max_size_mb: this.local_mirror_max_size_mb,
+            replication: this.replication.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::object_store::Replication {
+    type Type = ObjectStoreReplicationConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let mirrors = self
+            .mirrors
+            .iter()
+            .enumerate()
+            .map(|(i, mirror)| mirror.read().with_context(|| format!("mirrors[{i}]")))
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self::Type {
+            mirrors,
+            read_preference: self.read_preference.clone(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            mirrors: this.mirrors.iter().map(ProtoRepr::build).collect(),
+            read_preference: this.read_preference.clone(),
+        }
+    }
+}
+
+impl ProtoRepr for proto::object_store::Mirror {
+    type Type = ObjectStoreMirrorConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let mode = required(&self.mode).context("mode")?.clone().into();
+        Ok(Self::Type {
+            name: required(&self.name).context("name")?.clone(),
+            mode: read_mode(&mode)?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            name: Some(this.name.clone()),
+            mode: Some(build_mode(&this.mode).into()),
         }
     }
 }
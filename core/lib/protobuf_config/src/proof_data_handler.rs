@@ -3,7 +3,7 @@ use zksync_config::configs;
 use zksync_protobuf::{repr::ProtoRepr, required};
 use zksync_types::L1BatchNumber;
 
-use crate::proto::prover as proto;
+use crate::{parse_h256, proto::prover as proto};
 
 impl ProtoRepr for proto::ProofDataHandler {
     type Type = configs::ProofDataHandlerConfig;
@@ -30,6 +30,18 @@ impl ProtoRepr for proto::ProofDataHandler {
                         configs::TeeConfig::default_tee_proof_generation_timeout_in_secs,
                     ),
             },
+            marketplace_config: configs::ProofMarketplaceConfig {
+                marketplace_support: self.marketplace_support.unwrap_or_default(),
+                marketplace_api_key_hashes: self
+                    .marketplace_api_key_hashes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, hash)| parse_h256(hash).context(i))
+                    .collect::<anyhow::Result<_>>()
+                    .context("marketplace_api_key_hashes")?,
+                marketplace_reward_token: self.marketplace_reward_token.clone(),
+                marketplace_reward_per_batch: self.marketplace_reward_per_batch.clone(),
+            },
         })
     }
 
@@ -42,6 +54,18 @@ impl ProtoRepr for proto::ProofDataHandler {
             tee_proof_generation_timeout_in_secs: Some(
                 this.tee_config.tee_proof_generation_timeout_in_secs.into(),
             ),
+            marketplace_support: Some(this.marketplace_config.marketplace_support),
+            marketplace_api_key_hashes: this
+                .marketplace_config
+                .marketplace_api_key_hashes
+                .iter()
+                .map(|hash| format!("{hash:?}"))
+                .collect(),
+            marketplace_reward_token: this.marketplace_config.marketplace_reward_token.clone(),
+            marketplace_reward_per_batch: this
+                .marketplace_config
+                .marketplace_reward_per_batch
+                .clone(),
         }
     }
 }
@@ -95,6 +95,13 @@ impl ProtoRepr for proto::ProverJobMonitor {
                     .or_else(|| Some(Self::Type::default_witness_job_queuer_run_interval_ms())),
             )
             .context("witness_job_queuer_run_interval_ms")?,
+            artifacts_gc_run_interval_ms: *required(
+                &self
+                    .artifacts_gc_run_interval_ms
+                    .or_else(|| Some(Self::Type::default_artifacts_gc_run_interval_ms())),
+            )
+            .context("artifacts_gc_run_interval_ms")?,
+            artifacts_gc_retention_ms: self.artifacts_gc_retention_ms,
             http_port: required(&self.http_port)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("http_port")?,
@@ -129,6 +136,8 @@ impl ProtoRepr for proto::ProverJobMonitor {
                 this.witness_generator_queue_reporter_run_interval_ms,
             ),
             witness_job_queuer_run_interval_ms: Some(this.witness_job_queuer_run_interval_ms),
+            artifacts_gc_run_interval_ms: Some(this.artifacts_gc_run_interval_ms),
+            artifacts_gc_retention_ms: this.artifacts_gc_retention_ms,
             http_port: Some(this.http_port.into()),
         }
     }
@@ -1,9 +1,10 @@
 use std::num::NonZeroU64;
 
+use anyhow::Context as _;
 use zksync_config::configs::PruningConfig;
 use zksync_protobuf::ProtoRepr;
 
-use crate::proto::pruning as proto;
+use crate::{parse_h160, proto::pruning as proto};
 
 impl ProtoRepr for proto::Pruning {
     type Type = PruningConfig;
@@ -14,6 +15,13 @@ impl ProtoRepr for proto::Pruning {
             chunk_size: self.chunk_size,
             removal_delay_sec: self.removal_delay_sec.and_then(NonZeroU64::new),
             data_retention_sec: self.data_retention_sec,
+            retained_addresses: self
+                .retained_addresses
+                .iter()
+                .enumerate()
+                .map(|(i, address)| parse_h160(address).context(i))
+                .collect::<Result<_, _>>()
+                .context("retained_addresses")?,
         })
     }
 
@@ -23,6 +31,11 @@ impl ProtoRepr for proto::Pruning {
             chunk_size: this.chunk_size,
             removal_delay_sec: this.removal_delay_sec.map(|a| a.get()),
             data_retention_sec: this.data_retention_sec,
+            retained_addresses: this
+                .retained_addresses
+                .iter()
+                .map(|address| format!("{address:?}"))
+                .collect(),
         }
     }
 }
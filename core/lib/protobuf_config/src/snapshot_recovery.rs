@@ -17,12 +17,16 @@ impl ProtoRepr for proto::Postgres {
             max_concurrency: self
                 .max_concurrency
                 .and_then(|a| NonZeroUsize::new(a as usize)),
+            max_chunks_in_memory: self
+                .max_chunks_in_memory
+                .and_then(|a| NonZeroUsize::new(a as usize)),
         })
     }
 
     fn build(this: &Self::Type) -> Self {
         Self {
             max_concurrency: this.max_concurrency.map(|a| a.get() as u64),
+            max_chunks_in_memory: this.max_chunks_in_memory.map(|a| a.get() as u64),
         }
     }
 }
@@ -11,6 +11,7 @@ fn test_encoding() {
     test_encode_all_formats::<ReprConv<proto::api::Web3JsonRpc>>(rng);
     test_encode_all_formats::<ReprConv<proto::api::HealthCheck>>(rng);
     test_encode_all_formats::<ReprConv<proto::api::MerkleTreeApi>>(rng);
+    test_encode_all_formats::<ReprConv<proto::api::EtherscanApi>>(rng);
     test_encode_all_formats::<ReprConv<proto::api::Api>>(rng);
     test_encode_all_formats::<ReprConv<proto::utils::Prometheus>>(rng);
     test_encode_all_formats::<ReprConv<proto::chain::StateKeeper>>(rng);
@@ -46,6 +47,7 @@ fn test_encoding() {
     test_encode_all_formats::<ReprConv<proto::da_dispatcher::DataAvailabilityDispatcher>>(rng);
     test_encode_all_formats::<ReprConv<proto::vm_runner::ProtectiveReadsWriter>>(rng);
     test_encode_all_formats::<ReprConv<proto::vm_runner::BasicWitnessInputProducer>>(rng);
+    test_encode_all_formats::<ReprConv<proto::vm_runner::BatchVerifier>>(rng);
     test_encode_all_formats::<ReprConv<proto::commitment_generator::CommitmentGenerator>>(rng);
     test_encode_all_formats::<ReprConv<proto::snapshot_recovery::Postgres>>(rng);
     test_encode_all_formats::<ReprConv<proto::snapshot_recovery::SnapshotRecovery>>(rng);
@@ -54,6 +56,8 @@ fn test_encoding() {
     test_encode_all_formats::<ReprConv<proto::external_price_api_client::ExternalPriceApiClient>>(
         rng,
     );
+    test_encode_all_formats::<ReprConv<proto::external_block_builder::ExternalBlockBuilder>>(rng);
+    test_encode_all_formats::<ReprConv<proto::token_price::TokenPrice>>(rng);
     test_encode_all_formats::<ReprConv<proto::general::GeneralConfig>>(rng);
 }
 
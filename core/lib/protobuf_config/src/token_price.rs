@@ -0,0 +1,149 @@
+use anyhow::Context as _;
+use zksync_config::configs::token_price::{
+    CoinGeckoTokenPriceConfig, StaticTokenPrice, StaticTokenPriceConfig, TokenPriceConfig,
+    TokenPriceProviderConfig, UniswapV3PoolConfig, UniswapV3TwapConfig,
+};
+use zksync_protobuf::{repr::ProtoRepr, required};
+
+use crate::{parse_h160, proto::token_price as proto};
+
+impl ProtoRepr for proto::UniswapV3Pool {
+    type Type = UniswapV3PoolConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            token: required(&self.token)
+                .and_then(|x| parse_h160(x))
+                .context("token")?,
+            pool: required(&self.pool)
+                .and_then(|x| parse_h160(x))
+                .context("pool")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            token: Some(format!("{:?}", this.token)),
+            pool: Some(format!("{:?}", this.pool)),
+        }
+    }
+}
+
+impl ProtoRepr for proto::StaticPrice {
+    type Type = StaticTokenPrice;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            token: required(&self.token)
+                .and_then(|x| parse_h160(x))
+                .context("token")?,
+            price_usd: *required(&self.price_usd).context("price_usd")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            token: Some(format!("{:?}", this.token)),
+            price_usd: Some(this.price_usd),
+        }
+    }
+}
+
+impl ProtoRepr for proto::Provider {
+    type Type = TokenPriceProviderConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(match required(&self.source).context("source")? {
+            proto::provider::Source::Coingecko(config) => {
+                TokenPriceProviderConfig::CoinGecko(CoinGeckoTokenPriceConfig {
+                    base_url: config.base_url.clone(),
+                    api_key: config.api_key.clone(),
+                    client_timeout_ms: config
+                        .client_timeout_ms
+                        .unwrap_or_else(CoinGeckoTokenPriceConfig::default_client_timeout_ms),
+                })
+            }
+            proto::provider::Source::UniswapV3Twap(config) => {
+                TokenPriceProviderConfig::UniswapV3Twap(UniswapV3TwapConfig {
+                    pools: config
+                        .pools
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| x.read().context(i))
+                        .collect::<Result<_, _>>()
+                        .context("pools")?,
+                    window_sec: config
+                        .window_sec
+                        .unwrap_or_else(UniswapV3TwapConfig::default_window_sec),
+                })
+            }
+            proto::provider::Source::Static(config) => {
+                TokenPriceProviderConfig::Static(StaticTokenPriceConfig {
+                    prices: config
+                        .prices
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| x.read().context(i))
+                        .collect::<Result<_, _>>()
+                        .context("prices")?,
+                })
+            }
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        let source = match this {
+            TokenPriceProviderConfig::CoinGecko(config) => {
+                proto::provider::Source::Coingecko(proto::CoinGecko {
+                    base_url: config.base_url.clone(),
+                    api_key: config.api_key.clone(),
+                    client_timeout_ms: Some(config.client_timeout_ms),
+                })
+            }
+            TokenPriceProviderConfig::UniswapV3Twap(config) => {
+                proto::provider::Source::UniswapV3Twap(proto::UniswapV3Twap {
+                    pools: config.pools.iter().map(ProtoRepr::build).collect(),
+                    window_sec: Some(config.window_sec),
+                })
+            }
+            TokenPriceProviderConfig::Static(config) => {
+                proto::provider::Source::Static(proto::Static {
+                    prices: config.prices.iter().map(ProtoRepr::build).collect(),
+                })
+            }
+        };
+        Self {
+            source: Some(source),
+        }
+    }
+}
+
+impl ProtoRepr for proto::TokenPrice {
+    type Type = TokenPriceConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            providers: self
+                .providers
+                .iter()
+                .enumerate()
+                .map(|(i, x)| x.read().context(i))
+                .collect::<Result<_, _>>()
+                .context("providers")?,
+            cache_ttl_ms: self
+                .cache_ttl_ms
+                .unwrap_or_else(TokenPriceConfig::default_cache_ttl_ms),
+            stale_after_ms: self
+                .stale_after_ms
+                .unwrap_or_else(TokenPriceConfig::default_stale_after_ms),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            providers: this.providers.iter().map(ProtoRepr::build).collect(),
+            cache_ttl_ms: Some(this.cache_ttl_ms),
+            stale_after_ms: Some(this.stale_after_ms),
+        }
+    }
+}
@@ -48,3 +48,25 @@ impl ProtoRepr for proto::BasicWitnessInputProducer {
         }
     }
 }
+
+impl ProtoRepr for proto::BatchVerifier {
+    type Type = configs::BatchVerifierConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            db_path: required(&self.db_path).context("db_path")?.clone(),
+            window_size: *required(&self.window_size).context("window_size")? as u32,
+            first_processed_batch: L1BatchNumber(
+                *required(&self.first_processed_batch).context("first_batch")? as u32,
+            ),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            db_path: Some(this.db_path.clone()),
+            window_size: Some(this.window_size as u64),
+            first_processed_batch: Some(this.first_processed_batch.0 as u64),
+        }
+    }
+}
@@ -8,6 +8,13 @@ use zksync_types::{Address, K256PrivateKey};
 
 use crate::{parse_h160, parse_h256, proto::wallets as proto};
 
+fn read_pk_wallet(wallet: &proto::PrivateKeyWallet) -> anyhow::Result<Wallet> {
+    Wallet::from_private_key_bytes(
+        parse_h256(required(&wallet.private_key).context("private_key")?)?,
+        wallet.address.as_ref().and_then(|a| parse_h160(a).ok()),
+    )
+}
+
 impl ProtoRepr for proto::Wallets {
     type Type = configs::wallets::Wallets;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -34,9 +41,22 @@ impl ProtoRepr for proto::Wallets {
                     .and_then(|a| parse_h160(a).ok()),
             )?;
 
+            let prove_operator = self
+                .prove_operator
+                .as_ref()
+                .map(|wallet| read_pk_wallet(wallet).context("prove_operator"))
+                .transpose()?;
+            let execute_operator = self
+                .execute_operator
+                .as_ref()
+                .map(|wallet| read_pk_wallet(wallet).context("execute_operator"))
+                .transpose()?;
+
             Some(EthSender {
                 operator,
                 blob_operator,
+                prove_operator,
+                execute_operator,
             })
         } else {
             None
@@ -86,21 +106,25 @@ impl ProtoRepr for proto::Wallets {
             }
         };
 
-        let (operator, blob_operator) = if let Some(eth_sender) = &this.eth_sender {
-            let blob = eth_sender
-                .blob_operator
-                .as_ref()
-                .map(|blob| create_pk_wallet(blob.address(), blob.private_key()));
-            (
-                Some(create_pk_wallet(
-                    eth_sender.operator.address(),
-                    eth_sender.operator.private_key(),
-                )),
-                blob,
-            )
-        } else {
-            (None, None)
-        };
+        let (operator, blob_operator, prove_operator, execute_operator) =
+            if let Some(eth_sender) = &this.eth_sender {
+                let create_opt_pk_wallet = |wallet: &Option<Wallet>| {
+                    wallet
+                        .as_ref()
+                        .map(|wallet| create_pk_wallet(wallet.address(), wallet.private_key()))
+                };
+                (
+                    Some(create_pk_wallet(
+                        eth_sender.operator.address(),
+                        eth_sender.operator.private_key(),
+                    )),
+                    create_opt_pk_wallet(&eth_sender.blob_operator),
+                    create_opt_pk_wallet(&eth_sender.prove_operator),
+                    create_opt_pk_wallet(&eth_sender.execute_operator),
+                )
+            } else {
+                (None, None, None, None)
+            };
 
         let fee_account = this
             .state_keeper
@@ -124,6 +148,8 @@ impl ProtoRepr for proto::Wallets {
             operator,
             fee_account,
             token_multiplier_setter,
+            prove_operator,
+            execute_operator,
         }
     }
 }
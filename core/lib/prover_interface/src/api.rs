@@ -1,10 +1,12 @@
 //! Prover and server subsystems communicate via the API.
 //! This module defines the types used in the API.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::{hex::Hex, serde_as};
 use zksync_types::{
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+    prover_dal::ProvingStage,
     tee_types::TeeType,
     L1BatchNumber,
 };
@@ -30,6 +32,28 @@ pub enum ProofGenerationDataResponse {
     Error(String),
 }
 
+/// Reward for proving a batch advertised via the proving marketplace API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofReward {
+    pub token: Option<String>,
+    pub amount_per_batch: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchAwaitingProof {
+    pub l1_batch_number: L1BatchNumber,
+    /// Whether witness inputs for the batch are available, i.e. the batch can be claimed.
+    pub witness_inputs_available: bool,
+    /// Whether the batch is currently claimed by a prover.
+    pub claimed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchesAwaitingProofResponse {
+    pub batches: Vec<BatchAwaitingProof>,
+    pub reward: Option<ProofReward>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TeeProofGenerationDataResponse(pub Box<TeeVerifierInput>);
 
@@ -49,6 +73,11 @@ pub enum RegisterTeeAttestationResponse {
     Success,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SubmitProvingStagesResponse {
+    Success,
+}
+
 // Structs to hold data necessary for making HTTP requests
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,6 +95,19 @@ pub enum SubmitProofRequest {
     SkippedProofGeneration,
 }
 
+/// Timestamp of an L1 batch reaching a proving stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvingStageTimestamp {
+    pub stage: ProvingStage,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Timestamps of proving stages tracked by the prover subsystem, reported to the server after the proof is submitted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmitProvingStagesRequest {
+    pub stages: Vec<ProvingStageTimestamp>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VerifyProofRequest(pub Box<L1BatchProofForL1>);
 
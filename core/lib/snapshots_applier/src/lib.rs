@@ -1,7 +1,15 @@
 //! Logic for applying application-level snapshots to Postgres storage.
 
 use std::{
-    cmp::Ordering, collections::HashMap, fmt, mem, num::NonZeroUsize, sync::Arc, time::Duration,
+    cmp::Ordering,
+    collections::HashMap,
+    fmt, mem,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+        Arc,
+    },
+    time::Duration,
 };
 
 use anyhow::Context as _;
@@ -15,8 +23,9 @@ use zksync_types::{
     api,
     bytecode::BytecodeHash,
     snapshots::{
-        SnapshotFactoryDependencies, SnapshotHeader, SnapshotRecoveryStatus, SnapshotStorageLog,
-        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey, SnapshotVersion,
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotHeader,
+        SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsStorageKey, SnapshotVersion,
     },
     tokens::TokenInfo,
     L1BatchNumber, L2BlockNumber, StorageKey, H256,
@@ -41,6 +50,9 @@ struct SnapshotsApplierHealthDetails {
     factory_deps_recovered: bool,
     storage_logs_chunk_count: usize,
     storage_logs_chunks_left_to_process: usize,
+    /// Number of storage logs recovered by the current recovery run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_logs_recovered: Option<u64>,
     tokens_recovered: bool,
 }
 
@@ -59,6 +71,7 @@ impl SnapshotsApplierHealthDetails {
             factory_deps_recovered: true,
             storage_logs_chunk_count: status.storage_logs_chunks_processed.len(),
             storage_logs_chunks_left_to_process: 0,
+            storage_logs_recovered: None,
             tokens_recovered: true,
         })
     }
@@ -219,6 +232,10 @@ pub struct SnapshotsApplierConfig {
     /// Maximum concurrency factor when performing concurrent operations (for now, the only such operation
     /// is recovering chunks of storage logs).
     pub max_concurrency: NonZeroUsize,
+    /// Maximum number of storage log chunks held in memory at the same time, i.e., being downloaded from the object store
+    /// or waiting to be persisted in Postgres. Chunks are downloaded ahead of persisting as long as this limit allows,
+    /// so that Postgres doesn't wait for the object store. Values lower than [`Self::max_concurrency`] are ignored.
+    pub max_chunks_in_memory: NonZeroUsize,
 }
 
 impl Default for SnapshotsApplierConfig {
@@ -228,6 +245,7 @@ impl Default for SnapshotsApplierConfig {
             initial_retry_backoff: Duration::from_secs(2),
             retry_backoff_multiplier: 2.0,
             max_concurrency: NonZeroUsize::new(10).unwrap(),
+            max_chunks_in_memory: NonZeroUsize::new(20).unwrap(),
         }
     }
 }
@@ -593,19 +611,34 @@ impl StorageLogs {
         }
     }
 
-    /// Performs basic sanity check for a storage logs chunk.
-    fn validate(&self, snapshot_status: &SnapshotRecoveryStatus) -> anyhow::Result<()> {
+    /// Performs basic sanity check for a storage logs chunk. In particular, checks that all logs belong to the range
+    /// of hashed keys covered by the chunk, which guards against a misplaced or corrupted chunk in the object store.
+    fn validate(
+        &self,
+        chunk_id: u64,
+        snapshot_status: &SnapshotRecoveryStatus,
+    ) -> anyhow::Result<()> {
         match self {
-            Self::V0(logs) => Self::validate_inner(logs, snapshot_status),
-            Self::V1(logs) => Self::validate_inner(logs, snapshot_status),
+            Self::V0(logs) => {
+                Self::validate_inner(logs, chunk_id, snapshot_status, StorageKey::hashed_key)
+            }
+            Self::V1(logs) => Self::validate_inner(logs, chunk_id, snapshot_status, |key| *key),
         }
     }
 
     fn validate_inner<K: fmt::Debug>(
         storage_logs: &[SnapshotStorageLog<K>],
+        chunk_id: u64,
         snapshot_status: &SnapshotRecoveryStatus,
+        hashed_key: impl Fn(&K) -> H256,
     ) -> anyhow::Result<()> {
+        let chunk_count = snapshot_status.storage_logs_chunks_processed.len() as u64;
+        let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
         for log in storage_logs {
+            anyhow::ensure!(
+                hashed_keys_range.contains(&hashed_key(&log.key)),
+                "invalid storage log outside of the hashed keys range {hashed_keys_range:?} for chunk {chunk_id}: {log:?}"
+            );
             anyhow::ensure!(
                 log.enumeration_index > 0,
                 "invalid storage log with zero enumeration_index: {log:?}"
@@ -643,6 +676,14 @@ impl StorageLogs {
     }
 }
 
+/// Semaphores limiting storage log chunks processed concurrently. Chunks are downloaded in the order of acquiring
+/// `in_memory` permits (Tokio semaphores are fair), and each downloaded chunk waits for a `postgres` permit to be persisted.
+#[derive(Debug)]
+struct ChunkSemaphores {
+    in_memory: Semaphore,
+    postgres: Semaphore,
+}
+
 /// Applying application-level storage snapshots to the Postgres storage.
 #[derive(Debug)]
 struct SnapshotsApplier<'a> {
@@ -653,8 +694,10 @@ struct SnapshotsApplier<'a> {
     health_updater: &'a HealthUpdater,
     snapshot_version: SnapshotVersion,
     max_concurrency: usize,
+    max_chunks_in_memory: usize,
     drop_storage_key_preimages: bool,
     factory_deps_recovered: bool,
+    storage_logs_recovered: AtomicU64,
     tokens_recovered: bool,
 }
 
@@ -698,8 +741,10 @@ impl<'a> SnapshotsApplier<'a> {
             health_updater,
             snapshot_version,
             max_concurrency: task.config.max_concurrency.get(),
+            max_chunks_in_memory: task.config.max_chunks_in_memory.get(),
             drop_storage_key_preimages: task.drop_storage_key_preimages,
             factory_deps_recovered: !created_from_scratch,
+            storage_logs_recovered: AtomicU64::new(0),
             tokens_recovered: false,
         };
 
@@ -766,6 +811,7 @@ impl<'a> SnapshotsApplier<'a> {
                 .len(),
             // We don't use `self.applied_snapshot_status` here because it's not updated during recovery
             storage_logs_chunks_left_to_process: METRICS.storage_logs_chunks_left_to_process.get(),
+            storage_logs_recovered: Some(self.storage_logs_recovered.load(AtomicOrdering::Relaxed)),
         };
         let status = if details.is_done() {
             HealthStatus::Ready
@@ -866,14 +912,14 @@ impl<'a> SnapshotsApplier<'a> {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", err, skip(self, semaphore))]
+    #[tracing::instrument(level = "debug", err, skip(self, semaphores))]
     async fn recover_storage_logs_single_chunk(
         &self,
-        semaphore: &Semaphore,
+        semaphores: &ChunkSemaphores,
         chunk_id: u64,
     ) -> Result<(), SnapshotsApplierError> {
-        // `unwrap()` is safe: the semaphore is never closed
-        let _permit = semaphore.acquire().await.unwrap();
+        // `unwrap()`s are safe: semaphores are never closed
+        let _memory_permit = semaphores.in_memory.acquire().await.unwrap();
 
         tracing::info!("Processing storage logs chunk {chunk_id}");
         let latency =
@@ -892,7 +938,7 @@ impl<'a> SnapshotsApplier<'a> {
                     SnapshotsApplierError::object_store(err, context)
                 })?;
 
-        storage_logs.validate(&self.applied_snapshot_status)?;
+        storage_logs.validate(chunk_id, &self.applied_snapshot_status)?;
         if self.drop_storage_key_preimages {
             storage_logs.drop_key_preimages();
         }
@@ -902,6 +948,11 @@ impl<'a> SnapshotsApplier<'a> {
             storage_logs.len()
         );
 
+        let latency =
+            METRICS.storage_logs_chunks_duration[&StorageLogsChunksStage::WaitForPostgres].start();
+        let _postgres_permit = semaphores.postgres.acquire().await.unwrap();
+        latency.observe();
+
         let latency =
             METRICS.storage_logs_chunks_duration[&StorageLogsChunksStage::SaveToPostgres].start();
 
@@ -911,7 +962,8 @@ impl<'a> SnapshotsApplier<'a> {
             .await?;
         let mut storage_transaction = storage.start_transaction().await?;
 
-        tracing::info!("Loading {} storage logs into Postgres", storage_logs.len());
+        let storage_logs_count = storage_logs.len() as u64;
+        tracing::info!("Loading {storage_logs_count} storage logs into Postgres");
 
         self.insert_storage_logs_chunk(&storage_logs, &mut storage_transaction)
             .await?;
@@ -926,6 +978,9 @@ impl<'a> SnapshotsApplier<'a> {
         storage_transaction.commit().await?;
 
         let chunks_left = METRICS.storage_logs_chunks_left_to_process.dec_by(1) - 1;
+        self.storage_logs_recovered
+            .fetch_add(storage_logs_count, AtomicOrdering::Relaxed);
+        self.update_health();
         let latency = latency.observe();
         tracing::info!("Saved storage logs for chunk {chunk_id} in {latency:?}, there are {chunks_left} left to process");
 
@@ -938,10 +993,15 @@ impl<'a> SnapshotsApplier<'a> {
     ) -> Result<(), SnapshotsApplierError> {
         let effective_concurrency =
             (self.connection_pool.max_size() as usize).min(self.max_concurrency);
+        let max_chunks_in_memory = self.max_chunks_in_memory.max(effective_concurrency);
         tracing::info!(
-            "Recovering storage log chunks with {effective_concurrency} max concurrency"
+            "Recovering storage log chunks with {effective_concurrency} max concurrency \
+             and at most {max_chunks_in_memory} chunks in memory"
         );
-        let semaphore = Semaphore::new(effective_concurrency);
+        let semaphores = ChunkSemaphores {
+            in_memory: Semaphore::new(max_chunks_in_memory),
+            postgres: Semaphore::new(effective_concurrency),
+        };
 
         let tasks = self
             .applied_snapshot_status
@@ -950,7 +1010,7 @@ impl<'a> SnapshotsApplier<'a> {
             .enumerate()
             .filter(|(_, is_processed)| !**is_processed)
             .map(|(chunk_id, _)| {
-                self.recover_storage_logs_single_chunk(&semaphore, chunk_id as u64)
+                self.recover_storage_logs_single_chunk(&semaphores, chunk_id as u64)
             });
         let job_completion = futures::future::try_join_all(tasks);
 
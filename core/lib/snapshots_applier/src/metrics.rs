@@ -8,6 +8,7 @@ use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram,
 #[metrics(label = "stage", rename_all = "snake_case")]
 pub(crate) enum StorageLogsChunksStage {
     LoadFromGcs,
+    WaitForPostgres,
     SaveToPostgres,
 }
 
@@ -278,6 +278,7 @@ async fn snapshot_applier_recovers_after_stopping() {
 
     let mut config = SnapshotsApplierConfig::for_tests();
     config.max_concurrency = NonZeroUsize::new(1).unwrap();
+    config.max_chunks_in_memory = NonZeroUsize::new(1).unwrap();
     let task = SnapshotsApplierTask::new(
         config.clone(),
         pool.clone(),
@@ -516,6 +517,41 @@ async fn applier_errors_with_unrecognized_snapshot_version() {
     task.run(stop_receiver).await.unwrap_err();
 }
 
+#[tokio::test]
+async fn applier_errors_on_misplaced_storage_logs() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let expected_status = mock_recovery_status();
+    let storage_logs = random_storage_logs::<H256>(expected_status.l1_batch_number, 100);
+    let (object_store, client) = prepare_clients(&expected_status, &storage_logs).await;
+    // Put all logs into the first chunk.
+    let chunk_key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: expected_status.l1_batch_number,
+        chunk_id: 0,
+    };
+    let chunk = SnapshotStorageLogsChunk { storage_logs };
+    object_store.put(chunk_key, &chunk).await.unwrap();
+
+    let task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(client),
+        object_store,
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let err = task.run(stop_receiver).await.unwrap_err();
+    let err = format!("{err:#}");
+    assert!(err.contains("outside of the hashed keys range"), "{err}");
+
+    let mut storage = pool.connection().await.unwrap();
+    let status = storage
+        .snapshot_recovery_dal()
+        .get_applied_snapshot_status()
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!status.storage_logs_chunks_processed[0]);
+}
+
 #[tokio::test]
 async fn applier_returns_error_on_fatal_object_store_error() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -669,6 +705,7 @@ async fn snapshot_applier_can_be_canceled() {
 
     let mut config = SnapshotsApplierConfig::for_tests();
     config.max_concurrency = NonZeroUsize::new(1).unwrap();
+    config.max_chunks_in_memory = NonZeroUsize::new(1).unwrap();
     let task = SnapshotsApplierTask::new(
         config.clone(),
         pool.clone(),
@@ -13,8 +13,8 @@ use zksync_types::{
     api,
     block::L2BlockHeader,
     snapshots::{
-        SnapshotFactoryDependencies, SnapshotFactoryDependency, SnapshotHeader,
-        SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotHeader, SnapshotRecoveryStatus, SnapshotStorageLog, SnapshotStorageLogsChunk,
         SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey, SnapshotVersion,
     },
     tokens::{TokenInfo, TokenMetadata},
@@ -30,6 +30,8 @@ pub(super) trait SnapshotLogKey: Clone {
     const VERSION: SnapshotVersion;
 
     fn random() -> Self;
+
+    fn to_hashed_key(&self) -> H256;
 }
 
 impl SnapshotLogKey for H256 {
@@ -38,6 +40,10 @@ impl SnapshotLogKey for H256 {
     fn random() -> Self {
         Self::random()
     }
+
+    fn to_hashed_key(&self) -> H256 {
+        *self
+    }
 }
 
 impl SnapshotLogKey for StorageKey {
@@ -46,6 +52,10 @@ impl SnapshotLogKey for StorageKey {
     fn random() -> Self {
         Self::new(AccountTreeId::new(Address::random()), H256::random())
     }
+
+    fn to_hashed_key(&self) -> H256 {
+        self.hashed_key()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -316,18 +326,20 @@ where
         .await
         .unwrap();
 
-    let chunk_size = logs
-        .len()
-        .div_ceil(status.storage_logs_chunks_processed.len());
-    assert!(chunk_size > 0);
-
-    for (chunk_id, chunk) in logs.chunks(chunk_size).enumerate() {
+    // Distribute logs among chunks in the same way as the snapshot creator does.
+    let chunk_count = status.storage_logs_chunks_processed.len() as u64;
+    for chunk_id in 0..chunk_count {
+        let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
         let chunk_storage_logs = SnapshotStorageLogsChunk {
-            storage_logs: chunk.to_vec(),
+            storage_logs: logs
+                .iter()
+                .filter(|log| hashed_keys_range.contains(&log.key.to_hashed_key()))
+                .cloned()
+                .collect(),
         };
         let chunk_key = SnapshotStorageLogsStorageKey {
             l1_batch_number: status.l1_batch_number,
-            chunk_id: chunk_id as u64,
+            chunk_id,
         };
         object_store
             .put(chunk_key, &chunk_storage_logs)
@@ -26,6 +26,8 @@ pub(super) struct ValuesCacheMetrics {
     pub values_update_modified_keys: Histogram<usize>,
     /// Current L2 block for the values cache.
     pub values_valid_for_miniblock: Gauge<u64>,
+    /// Current L2 block for the block state cache.
+    pub block_state_valid_for_miniblock: Gauge<u64>,
     /// Number of times the negative initial writes cache was successfully used. This is distinct
     /// from cache hits (we can hit the cache, but the cached value may be outdated).
     pub effective_values: Counter,
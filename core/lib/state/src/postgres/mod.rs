@@ -255,6 +255,94 @@ struct ValuesCacheAndUpdater {
     command_sender: mpsc::UnboundedSender<L2BlockNumber>,
 }
 
+/// Key of an entry in [`BlockStateCache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum BlockStateKey {
+    /// Storage value for the hashed storage key.
+    Value(H256),
+    /// Enumeration index for the hashed storage key.
+    EnumerationIndex(H256),
+    /// Factory dependency with the specified bytecode hash.
+    FactoryDep(H256),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockStateEntry {
+    Value(StorageValue),
+    EnumerationIndex(u64),
+    /// Factory dependency is not present in Postgres.
+    MissingFactoryDep,
+}
+
+impl CacheValue<BlockStateKey> for BlockStateEntry {
+    #[allow(clippy::cast_possible_truncation)] // doesn't happen in practice
+    fn cache_weight(&self) -> u32 {
+        const WEIGHT: usize = mem::size_of::<BlockStateEntry>() + mem::size_of::<BlockStateKey>();
+        // ^ Since values are small, we want to account for key sizes as well
+        WEIGHT as u32
+    }
+}
+
+#[derive(Debug)]
+struct BlockStateCacheInner {
+    /// L2 block for which `self.entries` are valid.
+    l2_block_number: L2BlockNumber,
+    entries: LruCache<BlockStateKey, BlockStateEntry>,
+}
+
+/// Cache of the VM storage data for a single L2 block shared by all VM executions against this block.
+///
+/// Unlike [`ValuesCache`], this cache doesn't need a background updater: it holds exact data for the most recent
+/// L2 block it was populated for, and is emptied as soon as data for a newer L2 block is inserted. Data loaded
+/// for older L2 blocks is not cached. Besides storage values, the cache holds enumeration indices and misses
+/// for factory dependencies, which are not covered by other caches.
+#[derive(Debug, Clone)]
+struct BlockStateCache(Arc<RwLock<BlockStateCacheInner>>);
+
+impl BlockStateCache {
+    fn new(capacity: u64) -> Self {
+        let inner = BlockStateCacheInner {
+            l2_block_number: L2BlockNumber(0),
+            entries: LruCache::new("block_state_cache", capacity),
+        };
+        Self(Arc::new(RwLock::new(inner)))
+    }
+
+    fn get(&self, l2_block_number: L2BlockNumber, key: &BlockStateKey) -> Option<BlockStateEntry> {
+        let lock = self.0.read().expect("block state cache is poisoned");
+        if lock.l2_block_number != l2_block_number {
+            return None;
+        }
+        lock.entries.get(key)
+    }
+
+    /// Caches `entry` for `key` if `l2_block_number` is not older than the L2 block the cache holds data for.
+    /// If it is newer, the cache is emptied and moved to `l2_block_number`.
+    fn insert(&self, l2_block_number: L2BlockNumber, key: BlockStateKey, entry: BlockStateEntry) {
+        let lock = self.0.read().expect("block state cache is poisoned");
+        if lock.l2_block_number == l2_block_number {
+            lock.entries.insert(key, entry);
+            return;
+        } else if lock.l2_block_number > l2_block_number {
+            return;
+        }
+        drop(lock);
+
+        let mut lock = self.0.write().expect("block state cache is poisoned");
+        // The cache may have been moved by a concurrent insertion after the read lock was released.
+        if lock.l2_block_number < l2_block_number {
+            lock.l2_block_number = l2_block_number;
+            lock.entries.clear();
+            CACHE_METRICS
+                .block_state_valid_for_miniblock
+                .set(u64::from(l2_block_number.0));
+        }
+        if lock.l2_block_number == l2_block_number {
+            lock.entries.insert(key, entry);
+        }
+    }
+}
+
 /// Caches used during VM execution.
 ///
 /// Currently, this struct includes the following caches:
@@ -263,6 +351,7 @@ struct ValuesCacheAndUpdater {
 /// - Cache for L1 batch numbers of initial writes for storage keys (never invalidated, except after
 ///   reverting L1 batch execution)
 /// - Cache of the VM storage snapshot corresponding to the latest sealed L2 block
+/// - Cache of storage data for the most recent L2 block requested by VM executions
 #[derive(Debug, Clone)]
 pub struct PostgresStorageCaches {
     factory_deps: FactoryDepsCache,
@@ -276,6 +365,7 @@ pub struct PostgresStorageCaches {
     // it wasn't written to at the point that interests us.
     negative_initial_writes: InitialWritesCache,
     values: Option<ValuesCacheAndUpdater>,
+    block_state: Option<BlockStateCache>,
 }
 
 impl PostgresStorageCaches {
@@ -297,9 +387,23 @@ impl PostgresStorageCaches {
                 initial_writes_capacity / 2,
             ),
             values: None,
+            block_state: None,
         }
     }
 
+    /// Configures the cache of storage data shared by all VM executions against the same L2 block. The cache
+    /// is emptied once a VM execution against a newer L2 block loads data from Postgres.
+    ///
+    /// # Panics
+    ///
+    /// Panics if provided `capacity` is zero. (Check on the caller side beforehand if there is
+    /// such possibility.)
+    pub fn configure_block_state_cache(&mut self, capacity: u64) {
+        assert!(capacity > 0, "Block state cache capacity must be positive");
+        tracing::debug!("Initializing VM block state cache with {capacity}B capacity");
+        self.block_state = Some(BlockStateCache::new(capacity));
+    }
+
     /// Configures the VM storage values cache. The returned closure is the background task that will update
     /// the cache according to [`Self::schedule_values_update()`] calls. It should be spawned on a separate thread
     /// or a blocking Tokio task.
@@ -503,6 +607,27 @@ impl<'a> PostgresStorage<'a> {
     fn values_cache(&self) -> Option<&ValuesCache> {
         Some(&self.caches.as_ref()?.values.as_ref()?.cache)
     }
+
+    fn block_state_cache(&self) -> Option<&BlockStateCache> {
+        self.caches.as_ref()?.block_state.as_ref()
+    }
+
+    fn get_block_state(&self, key: &BlockStateKey) -> Option<BlockStateEntry> {
+        self.block_state_cache()?.get(self.l2_block_number, key)
+    }
+
+    fn get_block_state_value(&self, hashed_key: H256) -> Option<StorageValue> {
+        match self.get_block_state(&BlockStateKey::Value(hashed_key))? {
+            BlockStateEntry::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn insert_block_state(&self, key: BlockStateKey, entry: BlockStateEntry) {
+        if let Some(cache) = self.block_state_cache() {
+            cache.insert(self.l2_block_number, key, entry);
+        }
+    }
 }
 
 impl ReadStorage for PostgresStorage<'_> {
@@ -510,8 +635,9 @@ impl ReadStorage for PostgresStorage<'_> {
         let hashed_key = key.hashed_key();
         let latency = STORAGE_METRICS.storage[&Method::ReadValue].start();
         let values_cache = self.values_cache();
-        let cached_value =
-            values_cache.and_then(|cache| cache.get(self.l2_block_number, hashed_key));
+        let cached_value = values_cache
+            .and_then(|cache| cache.get(self.l2_block_number, hashed_key))
+            .or_else(|| self.get_block_state_value(hashed_key));
 
         let value = cached_value.unwrap_or_else(|| {
             const RETRY_INTERVAL: Duration = Duration::from_millis(500);
@@ -537,6 +663,10 @@ impl ReadStorage for PostgresStorage<'_> {
             if let Some(cache) = self.values_cache() {
                 cache.insert(self.l2_block_number, hashed_key, value);
             }
+            self.insert_block_state(
+                BlockStateKey::Value(hashed_key),
+                BlockStateEntry::Value(value),
+            );
             value
         });
 
@@ -604,6 +734,11 @@ impl ReadStorage for PostgresStorage<'_> {
             .and_then(|caches| caches.factory_deps.get(&hash));
 
         let value = cached_value.or_else(|| {
+            let key = BlockStateKey::FactoryDep(hash);
+            if self.get_block_state(&key) == Some(BlockStateEntry::MissingFactoryDep) {
+                return None;
+            }
+
             let mut dal = self.connection.storage_web3_dal();
             let value = self
                 .rt_handle
@@ -615,9 +750,11 @@ impl ReadStorage for PostgresStorage<'_> {
                 });
 
             if let Some(caches) = &self.caches {
-                // If we receive None, we won't cache it.
                 if let Some(value) = value.clone() {
                     caches.factory_deps.insert(hash, value);
+                } else {
+                    // Misses are only cached for the current L2 block, since the dependency may be inserted later.
+                    self.insert_block_state(key, BlockStateEntry::MissingFactoryDep);
                 }
             };
 
@@ -634,10 +771,21 @@ impl ReadStorage for PostgresStorage<'_> {
 
     fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
         let hashed_key = key.hashed_key();
+        let cache_key = BlockStateKey::EnumerationIndex(hashed_key);
+        if let Some(BlockStateEntry::EnumerationIndex(index)) = self.get_block_state(&cache_key) {
+            return Some(index);
+        }
+
         let mut dal = self.connection.storage_logs_dedup_dal();
         let value = self.rt_handle.block_on(
             dal.get_enumeration_index_in_l1_batch(hashed_key, self.l1_batch_number_for_l2_block),
         );
-        value.expect("failed getting enumeration index for key")
+        let value = value.expect("failed getting enumeration index for key");
+        // Missing indices are not cached: the L1 batch of the L2 block may be unsealed, in which case the index
+        // can appear once the batch is sealed.
+        if let Some(index) = value {
+            self.insert_block_state(cache_key, BlockStateEntry::EnumerationIndex(index));
+        }
+        value
     }
 }
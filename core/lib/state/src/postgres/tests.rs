@@ -2,6 +2,7 @@
 
 use std::{collections::HashMap, mem, time::Duration};
 
+use assert_matches::assert_matches;
 use rand::{
     rngs::StdRng,
     seq::{IteratorRandom, SliceRandom},
@@ -419,6 +420,111 @@ async fn using_initial_writes_cache() {
         .unwrap();
 }
 
+fn test_block_state_cache(pool: &ConnectionPool<Core>, rt_handle: Handle) {
+    let mut connection = rt_handle.block_on(pool.connection()).unwrap();
+    rt_handle.block_on(prepare_postgres(&mut connection));
+
+    let mut caches = PostgresStorageCaches::new(1_024, 1_024);
+    caches.configure_block_state_cache(1_024 * 1_024);
+    let block_state = caches.block_state.clone().unwrap();
+    let mut storage = PostgresStorage::new(rt_handle, connection, L2BlockNumber(0), true)
+        .with_caches(caches.clone());
+
+    let existing_logs = gen_storage_logs(0..20);
+    for log in &existing_logs {
+        assert_eq!(storage.read_value(&log.key), log.value);
+        assert!(storage.get_enumeration_index(&log.key).is_some());
+    }
+    let missing_dep = H256::repeat_byte(0xff);
+    assert_eq!(storage.load_factory_dep(missing_dep), None);
+
+    let l2_block = L2BlockNumber(0);
+    for log in &existing_logs {
+        let hashed_key = log.key.hashed_key();
+        assert_eq!(
+            block_state.get(l2_block, &BlockStateKey::Value(hashed_key)),
+            Some(BlockStateEntry::Value(log.value))
+        );
+        assert_matches!(
+            block_state.get(l2_block, &BlockStateKey::EnumerationIndex(hashed_key)),
+            Some(BlockStateEntry::EnumerationIndex(_))
+        );
+    }
+    assert_eq!(
+        block_state.get(l2_block, &BlockStateKey::FactoryDep(missing_dep)),
+        Some(BlockStateEntry::MissingFactoryDep)
+    );
+
+    // Cached values must be used by other storage instances for the same L2 block.
+    let key = existing_logs[0].key;
+    let fake_value = H256::repeat_byte(0xee);
+    block_state.insert(
+        l2_block,
+        BlockStateKey::Value(key.hashed_key()),
+        BlockStateEntry::Value(fake_value),
+    );
+    let mut storage = PostgresStorage::new(
+        storage.rt_handle,
+        storage.connection,
+        L2BlockNumber(0),
+        true,
+    )
+    .with_caches(caches.clone());
+    assert_eq!(storage.read_value(&key), fake_value);
+
+    let new_logs = gen_storage_logs(20..30);
+    storage.rt_handle.block_on(create_l2_block(
+        &mut storage.connection,
+        L2BlockNumber(1),
+        new_logs.clone(),
+    ));
+    let mut storage = PostgresStorage::new(
+        storage.rt_handle,
+        storage.connection,
+        L2BlockNumber(1),
+        true,
+    )
+    .with_caches(caches.clone());
+    // The cache must move to the new L2 block, evicting data for the previous block.
+    assert_eq!(storage.read_value(&key), existing_logs[0].value);
+    assert_eq!(storage.read_value(&new_logs[0].key), new_logs[0].value);
+    assert_eq!(
+        block_state.0.read().unwrap().l2_block_number,
+        L2BlockNumber(1)
+    );
+    assert_eq!(
+        block_state.get(l2_block, &BlockStateKey::Value(key.hashed_key())),
+        None
+    );
+
+    // Values for the older L2 block must not be cached.
+    let mut storage = PostgresStorage::new(
+        storage.rt_handle,
+        storage.connection,
+        L2BlockNumber(0),
+        true,
+    )
+    .with_caches(caches);
+    assert_eq!(storage.read_value(&new_logs[0].key), H256::zero());
+    let new_l2_block_value = block_state.get(
+        L2BlockNumber(1),
+        &BlockStateKey::Value(new_logs[0].key.hashed_key()),
+    );
+    assert_eq!(
+        new_l2_block_value,
+        Some(BlockStateEntry::Value(new_logs[0].value))
+    );
+}
+
+#[tokio::test]
+async fn using_block_state_cache() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let handle = Handle::current();
+    tokio::task::spawn_blocking(move || test_block_state_cache(&pool, handle))
+        .await
+        .unwrap();
+}
+
 #[derive(Debug)]
 struct ValueCacheAssertions<'a> {
     cache: &'a ValuesCache,
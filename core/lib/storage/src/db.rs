@@ -479,6 +479,29 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             .unwrap_or(0)
     }
 
+    /// Returns the total size of SST files across all column families, in bytes.
+    pub fn total_sst_size(&self) -> u64 {
+        CF::ALL
+            .iter()
+            .filter_map(|&cf| {
+                let cf = self.column_family(cf);
+                self.inner
+                    .int_property(cf, properties::TOTAL_SST_FILES_SIZE)
+            })
+            .sum()
+    }
+
+    /// Performs manual compaction of all column families, which physically removes deleted entries.
+    /// This is a potentially long blocking operation.
+    pub fn compact(&self) {
+        for &cf in CF::ALL {
+            let cf = self.column_family(cf);
+            self.inner
+                .db
+                .compact_range_cf(cf, None::<&[u8]>, None::<&[u8]>);
+        }
+    }
+
     pub fn multi_get<K, I>(&self, keys: I) -> Vec<Result<Option<Vec<u8>>, rocksdb::Error>>
     where
         K: AsRef<[u8]>,
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+pragma solidity ^0.8.0;
+
+/**
+ * Mock `L1Messenger` counterpart that can be programmed to fail sending messages. Unlike the real contract,
+ * it doesn't send L2-to-L1 logs or account for pubdata; it only emits `L1MessageSent` events.
+ */
+contract MockL1Messenger {
+    event L1MessageSent(address indexed _sender, bytes32 indexed _hash, bytes _message);
+
+    bool public sendToL1Fails;
+    uint256 public sentMessageCount;
+
+    /// Makes all subsequent `sendToL1` calls revert (or succeed if `_fails` is false).
+    function setSendToL1Failure(bool _fails) external {
+        sendToL1Fails = _fails;
+    }
+
+    function sendToL1(bytes calldata _message) external returns (bytes32 hash) {
+        require(!sendToL1Fails, "MockL1Messenger: sendToL1 failed");
+        hash = keccak256(_message);
+        sentMessageCount += 1;
+        emit L1MessageSent(msg.sender, hash, _message);
+    }
+
+    /// Other calls (e.g., requesting bytecode publication from `KnownCodesStorage` or publishing pubdata
+    /// by the bootloader) are no-ops.
+    fallback() external {}
+}
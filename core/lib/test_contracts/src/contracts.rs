@@ -185,6 +185,13 @@ impl TestContract {
         &CONTRACT
     }
 
+    /// Returns a mock version of `L1Messenger` that can be programmed to fail sending messages.
+    pub fn mock_l1_messenger() -> &'static Self {
+        static CONTRACT: Lazy<TestContract> =
+            Lazy::new(|| TestContract::new(raw::mock_l1_messenger::MockL1Messenger));
+        &CONTRACT
+    }
+
     /// Returns a mock EVM emulator.
     pub fn mock_evm_emulator() -> &'static Self {
         static CONTRACT: Lazy<TestContract> =
@@ -0,0 +1,124 @@
+//! Builder for L1-originated transactions.
+
+use zksync_system_constants::REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE;
+use zksync_types::{
+    abi, address_to_u256, bytecode::BytecodeHash, Address, Execute, ProtocolVersionId, Transaction,
+    PRIORITY_OPERATION_L2_TX_TYPE, PROTOCOL_UPGRADE_TX_TYPE, U256,
+};
+
+/// Builder for L1-originated transactions, i.e. priority operations and protocol upgrade transactions.
+///
+/// Unlike [`Account`](crate::Account), the builder doesn't need a private key for the sender, so it can be used
+/// to emulate transactions forced from L1 by arbitrary addresses (e.g., L1 contracts). It also allows to override fields
+/// that are normally validated by L1 contracts (e.g., the minted amount) to test how the bootloader handles edge cases.
+#[derive(Debug, Clone)]
+pub struct L1TxBuilder {
+    sender: Address,
+    execute: Execute,
+    serial_id: u64,
+    gas_limit: U256,
+    gas_per_pubdata_limit: U256,
+    max_fee_per_gas: U256,
+    to_mint: Option<U256>,
+    refund_recipient: Option<Address>,
+}
+
+impl L1TxBuilder {
+    pub fn new(sender: Address, execute: Execute) -> Self {
+        Self {
+            sender,
+            execute,
+            serial_id: 0,
+            gas_limit: U256::from(20_000_000),
+            gas_per_pubdata_limit: REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into(),
+            max_fee_per_gas: U256::zero(),
+            to_mint: None,
+            refund_recipient: None,
+        }
+    }
+
+    /// Sets the serial ID of the priority operation. Ignored for upgrade transactions.
+    pub fn with_serial_id(mut self, serial_id: u64) -> Self {
+        self.serial_id = serial_id;
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: U256) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub fn with_gas_per_pubdata_limit(mut self, gas_per_pubdata_limit: U256) -> Self {
+        self.gas_per_pubdata_limit = gas_per_pubdata_limit;
+        self
+    }
+
+    pub fn with_max_fee_per_gas(mut self, max_fee_per_gas: U256) -> Self {
+        self.max_fee_per_gas = max_fee_per_gas;
+        self
+    }
+
+    /// Sets the amount of base token minted for the transaction. By default, the minted amount is
+    /// `gas_limit * max_fee_per_gas + value`, i.e., the minimum amount accepted by L1 contracts.
+    pub fn with_to_mint(mut self, to_mint: U256) -> Self {
+        self.to_mint = Some(to_mint);
+        self
+    }
+
+    /// Sets the recipient of the refund. By default, it's the transaction sender.
+    pub fn with_refund_recipient(mut self, refund_recipient: Address) -> Self {
+        self.refund_recipient = Some(refund_recipient);
+        self
+    }
+
+    /// Builds a priority operation.
+    pub fn build(self) -> Transaction {
+        let serial_id = self.serial_id;
+        self.build_inner(PRIORITY_OPERATION_L2_TX_TYPE, serial_id.into())
+    }
+
+    /// Builds a protocol upgrade transaction for the specified protocol version.
+    pub fn build_upgrade(self, upgrade_id: ProtocolVersionId) -> Transaction {
+        self.build_inner(PROTOCOL_UPGRADE_TX_TYPE, (upgrade_id as u16).into())
+    }
+
+    fn build_inner(self, tx_type: u8, nonce: U256) -> Transaction {
+        let to_mint = self
+            .to_mint
+            .unwrap_or(self.gas_limit * self.max_fee_per_gas + self.execute.value);
+        let refund_recipient = self.refund_recipient.unwrap_or(self.sender);
+        let factory_deps = self.execute.factory_deps;
+        let tx = abi::Transaction::L1 {
+            tx: abi::L2CanonicalTransaction {
+                tx_type: tx_type.into(),
+                from: address_to_u256(&self.sender),
+                to: address_to_u256(&self.execute.contract_address.unwrap_or_default()),
+                gas_limit: self.gas_limit,
+                gas_per_pubdata_byte_limit: self.gas_per_pubdata_limit,
+                max_fee_per_gas: self.max_fee_per_gas,
+                max_priority_fee_per_gas: 0.into(),
+                paymaster: 0.into(),
+                nonce,
+                value: self.execute.value,
+                reserved: [
+                    to_mint,
+                    address_to_u256(&refund_recipient),
+                    0.into(),
+                    0.into(),
+                ],
+                data: self.execute.calldata,
+                signature: vec![],
+                factory_deps: factory_deps
+                    .iter()
+                    .map(|b| BytecodeHash::for_bytecode(b).value_u256())
+                    .collect(),
+                paymaster_input: vec![],
+                reserved_dynamic: vec![],
+            }
+            .into(),
+            factory_deps,
+            eth_block: 0,
+        };
+        Transaction::from_abi(tx, false).unwrap()
+    }
+}
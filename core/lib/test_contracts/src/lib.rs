@@ -1,17 +1,18 @@
 use ethabi::Token;
 use zksync_eth_signer::{PrivateKeySigner, TransactionParameters};
-use zksync_system_constants::{
-    DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE, REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE,
-};
+use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
-    abi, address_to_u256, bytecode::BytecodeHash, fee::Fee, l2::L2Tx,
-    utils::deployed_address_create, Address, Execute, K256PrivateKey, L2ChainId, Nonce,
-    Transaction, H256, PRIORITY_OPERATION_L2_TX_TYPE, U256,
+    bytecode::BytecodeHash, fee::Fee, l2::L2Tx, utils::deployed_address_create, Address, Execute,
+    K256PrivateKey, L2ChainId, Nonce, Transaction, H256, U256,
 };
 
-pub use self::contracts::{LoadnextContractExecutionParams, TestContract};
+pub use self::{
+    contracts::{LoadnextContractExecutionParams, TestContract},
+    l1_tx::L1TxBuilder,
+};
 
 mod contracts;
+mod l1_tx;
 
 pub const L1_TEST_GAS_PER_PUBDATA_BYTE: u32 = 800;
 const BASE_FEE: u64 = 2_000_000_000;
@@ -139,43 +140,9 @@ impl Account {
     }
 
     pub fn get_l1_tx(&self, execute: Execute, serial_id: u64) -> Transaction {
-        let max_fee_per_gas = U256::from(0u32);
-        let gas_limit = U256::from(20_000_000);
-        let factory_deps = execute.factory_deps;
-        let tx = abi::Transaction::L1 {
-            tx: abi::L2CanonicalTransaction {
-                tx_type: PRIORITY_OPERATION_L2_TX_TYPE.into(),
-                from: address_to_u256(&self.address),
-                to: address_to_u256(&execute.contract_address.unwrap_or_default()),
-                gas_limit,
-                gas_per_pubdata_byte_limit: REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into(),
-                max_fee_per_gas,
-                max_priority_fee_per_gas: 0.into(),
-                paymaster: 0.into(),
-                nonce: serial_id.into(),
-                value: execute.value,
-                reserved: [
-                    // `to_mint`
-                    gas_limit * max_fee_per_gas + execute.value,
-                    // `refund_recipient`
-                    address_to_u256(&self.address),
-                    0.into(),
-                    0.into(),
-                ],
-                data: execute.calldata,
-                signature: vec![],
-                factory_deps: factory_deps
-                    .iter()
-                    .map(|b| BytecodeHash::for_bytecode(b).value_u256())
-                    .collect(),
-                paymaster_input: vec![],
-                reserved_dynamic: vec![],
-            }
-            .into(),
-            factory_deps,
-            eth_block: 0,
-        };
-        Transaction::from_abi(tx, false).unwrap()
+        L1TxBuilder::new(self.address, execute)
+            .with_serial_id(serial_id)
+            .build()
     }
 
     pub fn get_test_contract_transaction(
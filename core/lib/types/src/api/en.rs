@@ -72,3 +72,36 @@ pub struct AttestationStatus(pub serde_json::Value);
 /// The wrapped JSON value corresponds to `zksync_dal::consensus::BlockMetadata`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockMetadata(pub serde_json::Value);
+
+/// Member of a consensus committee with its weight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsensusCommitteeMember {
+    /// Public key in the text format used in consensus configs.
+    pub key: String,
+    pub weight: u64,
+}
+
+/// Consensus state of a node, used for diagnostics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsensusStatus {
+    /// Current view of the local BFT replica. Only progresses on validator nodes.
+    pub view_number: u64,
+    /// Phase of the current view.
+    pub phase: String,
+    /// Public key of the leader of the current view.
+    pub leader: String,
+    /// Last L2 block finalized with a commit certificate.
+    pub last_certified_block: Option<L2BlockNumber>,
+    /// View in which the last certified block was finalized.
+    pub last_certificate_view: Option<u64>,
+    /// Validator committee from the consensus genesis.
+    pub validators: Vec<ConsensusCommitteeMember>,
+    /// Last L1 batch with an attester certificate.
+    pub last_certified_batch: Option<L1BatchNumber>,
+    /// Next L1 batch to be attested. `None` if the first consensus block is not in storage yet.
+    pub next_batch_to_attest: Option<L1BatchNumber>,
+    /// Attester committee for `next_batch_to_attest`.
+    pub attesters: Vec<ConsensusCommitteeMember>,
+}
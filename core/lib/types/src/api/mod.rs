@@ -1,12 +1,17 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use serde_with::{hex::Hex, serde_as};
 use strum::Display;
 use zksync_basic_types::{
+    commitment::L1BatchCommitmentMode,
+    ethabi::{self, ParamType, RawLog, Token},
+    prover_dal::ProvingStage,
     tee_types::TeeType,
     web3::{AccessList, Bytes, Index},
-    Bloom, L1BatchNumber, H160, H256, H64, U256, U64,
+    Bloom, L1BatchNumber, SLChainId, H160, H256, H64, U256, U64,
 };
 use zksync_contracts::BaseSystemContractsHashes;
 
@@ -14,8 +19,10 @@ pub use crate::transaction_request::{
     Eip712Meta, SerializationTransactionError, TransactionRequest,
 };
 use crate::{
+    block::L2BlockHasher,
     debug_flat_call::{DebugCallFlat, ResultDebugCallFlat},
     protocol_version::L1VerifierConfig,
+    tokens::TokenOrigin,
     Address, L2BlockNumber, ProtocolVersionId,
 };
 
@@ -266,6 +273,22 @@ pub struct TransactionReceipt {
     /// Effective gas price
     #[serde(rename = "effectiveGasPrice")]
     pub effective_gas_price: Option<U256>,
+    /// Number of pubdata bytes attributed to this transaction.
+    /// `None` if the node doesn't have this information (e.g., for transactions executed by old VM versions).
+    #[serde(
+        rename = "pubdataUsed",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pubdata_used: Option<U64>,
+    /// Gas charged per published pubdata byte for this transaction, derived from the fee input
+    /// of the L2 block the transaction was included in.
+    #[serde(
+        rename = "gasPerPubdata",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gas_per_pubdata: Option<U64>,
 }
 
 /// The block type returned from RPC calls.
@@ -448,6 +471,14 @@ pub struct Log {
     /// L2 block timestamp
     #[serde(rename = "blockTimestamp")]
     pub block_timestamp: Option<U64>,
+    /// Log parameters decoded using the event ABI fragment supplied in the `eth_getLogs` filter.
+    /// Only set if the filter contains the fragment.
+    #[serde(
+        rename = "decodedArgs",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub decoded_args: Option<Vec<DecodedParam>>,
 }
 
 impl Log {
@@ -726,12 +757,46 @@ impl ProtocolVersion {
 pub enum SupportedTracers {
     CallTracer,
     FlatCallTracer,
+    /// Returns state of accounts touched by the transaction, similar to the geth tracer of the same name.
+    PrestateTracer,
+    /// Combines several tracers configured in [`TracerConfig::mux_tracer_config`] so that they share a single execution.
+    MuxTracer,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct CallTracerConfig {
+    #[serde(default)]
     pub only_top_call: bool,
+    /// Only used by [`SupportedTracers::PrestateTracer`]; see [`PrestateTracerConfig::diff_mode`].
+    #[serde(default)]
+    pub diff_mode: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct PrestateTracerConfig {
+    /// If set, returns state of accounts modified by the transaction before and after its execution.
+    /// Otherwise, returns state of all accounts touched by the transaction before its execution.
+    #[serde(default)]
+    pub diff_mode: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Copy)]
+pub struct GasProfilerConfig {}
+
+/// Tracers combined by [`SupportedTracers::MuxTracer`]. Only tracers present in the config are run.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct MuxTracerConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_tracer: Option<CallTracerConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flat_call_tracer: Option<CallTracerConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prestate_tracer: Option<PrestateTracerConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_profiler: Option<GasProfilerConfig>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
@@ -740,15 +805,17 @@ pub struct TracerConfig {
     pub tracer: SupportedTracers,
     #[serde(default)]
     pub tracer_config: CallTracerConfig,
+    /// Ignored unless `tracer` is [`SupportedTracers::MuxTracer`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mux_tracer_config: Option<MuxTracerConfig>,
 }
 
 impl Default for TracerConfig {
     fn default() -> Self {
         TracerConfig {
             tracer: SupportedTracers::CallTracer,
-            tracer_config: CallTracerConfig {
-                only_top_call: false,
-            },
+            tracer_config: CallTracerConfig::default(),
+            mux_tracer_config: None,
         }
     }
 }
@@ -790,22 +857,98 @@ impl CallTracerBlockResult {
 pub enum CallTracerResult {
     CallTrace(DebugCall),
     FlatCallTrace(Vec<DebugCallFlat>),
+    // Must be placed before `MuxTrace`, which can be deserialized from any object.
+    PrestateTrace(PrestateTracerResult),
+    MuxTrace(MuxTracerResult),
 }
 
 impl CallTracerResult {
     pub fn unwrap_flat(self) -> Vec<DebugCallFlat> {
         match self {
-            Self::CallTrace(_) => panic!("Result is a FlatCallTrace"),
             Self::FlatCallTrace(trace) => trace,
+            _ => panic!("Result is not a FlatCallTrace"),
         }
     }
 
     pub fn unwrap_default(self) -> DebugCall {
         match self {
             Self::CallTrace(trace) => trace,
-            Self::FlatCallTrace(_) => panic!("Result is a CallTrace"),
+            _ => panic!("Result is not a CallTrace"),
         }
     }
+
+    pub fn unwrap_prestate(self) -> PrestateTracerResult {
+        match self {
+            Self::PrestateTrace(trace) => trace,
+            _ => panic!("Result is not a PrestateTrace"),
+        }
+    }
+
+    pub fn unwrap_mux(self) -> MuxTracerResult {
+        match self {
+            Self::MuxTrace(trace) => trace,
+            _ => panic!("Result is not a MuxTrace"),
+        }
+    }
+}
+
+/// Output of [`SupportedTracers::MuxTracer`] keyed by the tracer name. Contains outputs only for the requested tracers.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MuxTracerResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_tracer: Option<DebugCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flat_call_tracer: Option<Vec<DebugCallFlat>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prestate_tracer: Option<PrestateTracerResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_profiler: Option<GasProfile>,
+}
+
+/// Account state reported by [`SupportedTracers::PrestateTracer`]. In the diff mode, the post-state only contains
+/// changed fields, and storage only contains modified slots.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PrestateAccount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// Transaction nonce of the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<H256, H256>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PrestateTracerResult {
+    Prestate(BTreeMap<Address, PrestateAccount>),
+    Diff {
+        pre: BTreeMap<Address, PrestateAccount>,
+        post: BTreeMap<Address, PrestateAccount>,
+    },
+}
+
+/// Breakdown of gas used by a transaction across contracts.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GasProfile {
+    /// Gas used by the top-level call.
+    pub gas_used: U256,
+    /// Contracts sorted by `selfGasUsed` in the descending order.
+    pub contracts: Vec<ContractGasUsage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractGasUsage {
+    pub address: Address,
+    /// Number of calls to the contract.
+    pub calls: u64,
+    /// Gas used by the contract code itself, excluding nested calls.
+    pub self_gas_used: U256,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -849,6 +992,246 @@ pub struct L1BatchDetails {
     pub base: BlockDetailsBase,
 }
 
+/// Stage of the L1 batch lifecycle on L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum L1BatchStage {
+    Committed,
+    Proven,
+    Executed,
+}
+
+/// Notification about an L1 batch reaching an [`L1BatchStage`]; sent to `zks_subscribe("l1Batches")` subscribers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchStageUpdate {
+    pub number: L1BatchNumber,
+    pub stage: L1BatchStage,
+    /// Hash of the L1 transaction that moved the batch to the stage.
+    pub tx_hash: H256,
+    pub confirmed_at: DateTime<Utc>,
+}
+
+/// Breakdown of pubdata published for an L1 batch. All values are in bytes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PubdataComposition {
+    pub total: u64,
+    pub l2_to_l1_logs: u64,
+    pub l2_to_l1_messages: u64,
+    pub published_bytecodes: u64,
+    /// Computed as the remainder of the total pubdata.
+    pub state_diffs: u64,
+}
+
+/// Execution statistics persisted by the state keeper when sealing an L1 batch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchExecutionStats {
+    /// Total L2 gas used by the batch transactions.
+    pub gas_used: u64,
+    pub computational_gas_used: u64,
+    pub events_count: u64,
+    /// Number of initial storage writes. `None` for VM versions not reporting state diffs.
+    pub initial_writes_count: Option<u64>,
+    /// Number of repeated storage writes. `None` for VM versions not reporting state diffs.
+    pub repeated_writes_count: Option<u64>,
+    pub pubdata: PubdataComposition,
+}
+
+/// L1 gas predicted by the state keeper for operations on an L1 batch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchPredictedGas {
+    pub commit: u64,
+    pub prove: u64,
+    pub execute: u64,
+}
+
+/// Confirmed settlement layer transaction performing an operation on an L1 batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchOperationCost {
+    pub tx_hash: H256,
+    pub gas_used: Option<u64>,
+    pub max_fee_per_gas: u64,
+    pub max_priority_fee_per_gas: u64,
+    pub max_fee_per_blob_gas: Option<u64>,
+    /// Number of L1 batches processed by the transaction.
+    pub batch_count: u64,
+    /// Upper bound on the transaction cost (in wei) attributed to this batch: the gas used multiplied
+    /// by the max fee per gas and divided by the number of processed batches. Doesn't include blob fees.
+    pub max_cost: Option<U256>,
+}
+
+/// Costs of settlement layer transactions for an L1 batch. Only confirmed transactions are included.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchL1Costs {
+    pub commit: Option<L1BatchOperationCost>,
+    pub prove: Option<L1BatchOperationCost>,
+    pub execute: Option<L1BatchOperationCost>,
+}
+
+/// Timestamps of proof generation for an L1 batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchProofTimings {
+    /// Time when proof generation inputs became available.
+    pub inputs_ready_at: DateTime<Utc>,
+    pub picked_by_prover_at: Option<DateTime<Utc>>,
+    pub proof_generated_at: Option<DateTime<Utc>>,
+}
+
+/// Execution report for an L1 batch returned by `zks_getBatchReport`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchReport {
+    #[serde(flatten)]
+    pub details: L1BatchDetails,
+    pub sealed_at: Option<DateTime<Utc>>,
+    /// `None` for batches sealed before execution statistics were persisted.
+    pub execution: Option<L1BatchExecutionStats>,
+    /// Predicted number of circuits by the circuit type.
+    pub circuits_by_type: Option<BTreeMap<String, f32>>,
+    pub predicted_l1_gas: L1BatchPredictedGas,
+    pub l1_costs: L1BatchL1Costs,
+    /// `None` if proof generation data for the batch is not available (e.g., on external nodes).
+    pub proof: Option<L1BatchProofTimings>,
+}
+
+/// Role of a node in the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeRole {
+    /// Main node, i.e. the node running the sequencer.
+    Main,
+    /// External node syncing from the main node.
+    External,
+}
+
+/// Pruning settings of a node.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodePruningSettings {
+    pub enabled: bool,
+    /// Minimum age of L1 batches to be pruned. `None` if pruning is disabled.
+    pub data_retention_sec: Option<u64>,
+}
+
+/// Pruning settings and the currently retained data of a node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodePruningInfo {
+    #[serde(flatten)]
+    pub settings: NodePruningSettings,
+    pub first_retained_l1_batch: L1BatchNumber,
+    pub first_retained_l2_block: L2BlockNumber,
+}
+
+/// Sync status of a node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeSyncStatus {
+    /// Always `true` for the main node.
+    pub is_synced: bool,
+    /// Last sealed L2 block in the node storage.
+    pub local_l2_block: Option<L2BlockNumber>,
+    /// Last L2 block known to be sealed on the main node. `None` for the main node.
+    pub main_node_l2_block: Option<L2BlockNumber>,
+}
+
+/// Node metadata returned by `zks_getNodeInfo`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfo {
+    pub role: NodeRole,
+    /// Version of the node software.
+    pub version: String,
+    /// Protocol version of the last sealed L1 batch. `None` if there are no batches yet.
+    pub protocol_version: Option<ProtocolVersionId>,
+    /// Components run by the node process serving the API.
+    pub components: Vec<String>,
+    pub pruning: NodePruningInfo,
+    pub sync: NodeSyncStatus,
+}
+
+/// Chain-level features returned by `zks_getChainFeatures`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainFeatures {
+    /// Protocol version the features are resolved for, i.e. the version of the pending L2 block.
+    pub protocol_version: ProtocolVersionId,
+    /// Whether the EVM emulator is enabled, i.e. EVM bytecodes can be deployed and executed on the chain.
+    pub evm_emulator: bool,
+    /// Whether the chain uses a base token other than ETH.
+    pub custom_base_token: bool,
+    /// L1 address of the base token. `None` if the node doesn't know the base token.
+    pub base_token_address: Option<Address>,
+    /// Whether the chain operates in the validium mode, i.e. doesn't publish pubdata to L1.
+    pub validium: bool,
+    pub l1_batch_commitment_mode: L1BatchCommitmentMode,
+    /// Latest account abstraction version supported by the `ContractDeployer` system contract.
+    pub aa_version: u8,
+    /// Whether the chain settles to Gateway rather than to L1.
+    pub gateway_settlement: bool,
+    pub settlement_layer_chain_id: SLChainId,
+}
+
+/// Proof of transaction inclusion returned by `zks_getTransactionProof`.
+///
+/// The proof links the transaction hash to the L1 batch state committed on L1 as follows:
+///
+/// 1. The rolling hash of the L2 block transactions is obtained by folding `transaction_hash` and `following_tx_hashes`
+///    into `preceding_txs_rolling_hash` (see [`L2BlockHasher`] for details).
+/// 2. The L2 block hash is computed from the rolling hash, block number, timestamp and `prev_l2_block_hash`;
+///    [`Self::compute_l2_block_hash()`] performs steps 1 and 2.
+/// 3. The L2 block hash is stored in the `SystemContext` contract storage. `l2_block_hash_proof` is a Merkle proof
+///    for the corresponding storage slot against `l1_batch_root_hash`, in the same format as returned by `zks_getProof`.
+/// 4. `l1_batch_root_hash` is a part of `l1_batch_commitment`, which is committed on L1.
+///
+/// Note that the transaction execution status is not a part of the L2 block hash, so the proof only establishes
+/// that the transaction was included into the block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionProof {
+    pub transaction_hash: H256,
+    pub l2_block_number: L2BlockNumber,
+    pub l2_block_timestamp: u64,
+    pub l2_block_hash: H256,
+    pub prev_l2_block_hash: H256,
+    /// Rolling hash of the L2 block transactions preceding the proven transaction.
+    pub preceding_txs_rolling_hash: H256,
+    /// Hashes of the L2 block transactions following the proven transaction, in the execution order.
+    pub following_tx_hashes: Vec<H256>,
+    pub l1_batch_number: L1BatchNumber,
+    /// Proof of the `SystemContext` storage slot holding `l2_block_hash`. `None` if the slot was overwritten
+    /// within the L1 batch, which happens if the batch contains more than 256 L2 blocks after the proven one.
+    pub l2_block_hash_proof: Option<StorageProof>,
+    pub l1_batch_root_hash: H256,
+    pub l1_batch_commitment: H256,
+    /// Hash of the L1 transaction committing the L1 batch. `None` if the batch is not committed yet.
+    pub commit_tx_hash: Option<H256>,
+}
+
+impl TransactionProof {
+    /// Computes the L2 block hash from the transaction hash and other proof data. The result should be equal
+    /// to `l2_block_hash` for all L2 blocks produced with protocol version 13 or newer.
+    pub fn compute_l2_block_hash(&self) -> H256 {
+        let mut hasher = L2BlockHasher::with_txs_rolling_hash(
+            self.l2_block_number,
+            self.l2_block_timestamp,
+            self.prev_l2_block_hash,
+            self.preceding_txs_rolling_hash,
+        );
+        hasher.push_tx_hash(self.transaction_hash);
+        for &tx_hash in &self.following_tx_hashes {
+            hasher.push_tx_hash(tx_hash);
+        }
+        hasher.finalize(ProtocolVersionId::latest())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageProof {
@@ -890,6 +1273,58 @@ pub struct TransactionDetailedResult {
     pub events: Vec<Log>,
 }
 
+/// Result of simulating an L1->L2 transaction (priority operation) against the current L2 state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1ToL2TxSimulationResult {
+    /// Whether the transaction would succeed on L2.
+    pub success: bool,
+    /// Human-readable revert / halt reason if the transaction would fail.
+    pub revert_reason: Option<String>,
+    /// Gas that would be used by the transaction on L2.
+    pub gas_used: U256,
+    /// Number of pubdata bytes that would be published by the transaction.
+    pub pubdata_used: U64,
+    pub storage_logs: Vec<ApiStorageLog>,
+    pub events: Vec<Log>,
+}
+
+/// Number of circuits used per circuit type. Fractional values correspond to partially filled circuits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitsUsage {
+    pub main_vm: f32,
+    pub ram_permutation: f32,
+    pub storage_application: f32,
+    pub storage_sorter: f32,
+    pub code_decommitter: f32,
+    pub code_decommitter_sorter: f32,
+    pub log_demuxer: f32,
+    pub events_sorter: f32,
+    pub keccak256: f32,
+    pub ecrecover: f32,
+    pub sha256: f32,
+    pub secp256k1_verify: f32,
+    pub transient_storage_checker: f32,
+}
+
+/// Circuit usage of a transaction returned by `zks_estimateCircuits`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitsEstimate {
+    /// Whether the transaction would succeed.
+    pub success: bool,
+    /// Human-readable revert / halt reason if the transaction would fail.
+    pub revert_reason: Option<String>,
+    pub circuits: CircuitsUsage,
+    /// Total number of circuits used by the transaction, with usage of each circuit type rounded up.
+    pub total_circuits: U64,
+    /// Maximum number of base layer circuits in an L1 batch for the current protocol version.
+    pub max_batch_circuits: U64,
+    /// Fraction of the batch capacity consumed by the transaction, i.e. `total_circuits / max_batch_circuits`.
+    pub batch_capacity_fraction: f64,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiStorageLog {
@@ -917,10 +1352,371 @@ pub struct FeeHistory {
     pub l2_pubdata_price: Vec<U256>,
 }
 
+/// Token price returned by `zks_getTokenPrice`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPrice {
+    /// Price of a single token unit in USD.
+    pub price_usd: f64,
+    /// Name of the provider that has supplied the price.
+    pub source: String,
+    /// UNIX timestamp (in seconds) at which the price was fetched.
+    pub updated_at: u64,
+    /// Whether the price is older than the configured staleness threshold.
+    pub is_stale: bool,
+}
+
+/// Nonce information for an account returned by `zks_getNonceDetails`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceDetails {
+    /// Account nonce as of the latest sealed L2 block, i.e., the nonce of the next transaction to be executed.
+    pub executed_nonce: u64,
+    /// Next nonce to be used by the account taking into account transactions in the mempool.
+    /// Matches `eth_getTransactionCount` for the pending block.
+    pub mempool_nonce: u64,
+    /// Nonces of transactions in the mempool that are not executed yet, in ascending order.
+    pub pending_nonces: Vec<u64>,
+    /// Nonces missing between the executed nonce and the greatest pending nonce, in ascending order.
+    /// Pending transactions with nonces after the first gap won't be executed until the gap is filled.
+    pub nonce_gaps: Vec<u64>,
+}
+
+/// Numbers of transactions in the mempool returned by `txpool_status`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxpoolStatus {
+    /// Number of transactions that can be executed right away.
+    pub pending: U64,
+    /// Number of transactions that cannot be executed until a nonce gap is filled.
+    pub queued: U64,
+}
+
+/// Transaction ordering policy of the mempool returned by `txpool_orderingPolicy`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolOrderingPolicy {
+    /// Name of the policy, e.g. `timestamp`, `fifo` or `priority_fee`.
+    pub name: String,
+    /// Whether transactions are never skipped, i.e. a transaction cannot be overtaken by lower-priority transactions
+    /// even if it doesn't match the current fee requirements.
+    pub strict: bool,
+}
+
+/// Mempool transactions of a single account keyed by nonce, returned by `txpool_contentFrom`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxpoolAccountContent {
+    pub pending: BTreeMap<u64, Transaction>,
+    pub queued: BTreeMap<u64, Transaction>,
+}
+
+/// Mempool transactions keyed by the initiator address and nonce, returned by `txpool_content`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TxpoolContent {
+    pub pending: BTreeMap<Address, BTreeMap<u64, Transaction>>,
+    pub queued: BTreeMap<Address, BTreeMap<u64, Transaction>>,
+}
+
+/// Query parameters of `zks_getConfirmedTokensPage`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTokensQuery {
+    /// L2 address of the last token on the previous page, as returned in [`ConfirmedTokensPage::next_cursor`].
+    /// If not specified, the first page is returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<Address>,
+    /// Maximum number of tokens on the page. Capped by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u16>,
+    /// If specified, only tokens with this origin are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<TokenOrigin>,
+    /// If specified, only tokens with metadata updated at or after this UNIX timestamp (in seconds) are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_since: Option<u64>,
+}
+
+/// Confirmed token returned by `zks_getConfirmedTokensPage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedToken {
+    pub l1_address: Address,
+    pub l2_address: Address,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub origin: TokenOrigin,
+    /// UNIX timestamp (in seconds) at which token metadata was last updated.
+    pub metadata_updated_at: u64,
+}
+
+/// Page of confirmed tokens returned by `zks_getConfirmedTokensPage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTokensPage {
+    pub tokens: Vec<ConfirmedToken>,
+    /// Cursor to pass in the query to get the next page. `None` if this is the last page.
+    pub next_cursor: Option<Address>,
+}
+
+/// Filter of `zks_getTransactionsBySelector`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsBySelectorFilter {
+    /// 4-byte selector of the called function.
+    #[serde(with = "function_selector")]
+    pub selector: [u8; 4],
+    /// If specified, only transactions calling this contract are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// First L2 block of the searched range. Defaults to the genesis block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<L2BlockNumber>,
+    /// Last L2 block of the searched range (inclusive). Defaults to the latest sealed L2 block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<L2BlockNumber>,
+    /// Position of the last transaction on the previous page, as returned in [`TransactionsBySelectorPage::next_cursor`].
+    /// If not specified, the first page is returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<TransactionPosition>,
+    /// Maximum number of transactions on the page. Capped by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u16>,
+}
+
+/// (De)serializes a 4-byte function selector as a `0x`-prefixed hex string.
+mod function_selector {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use zksync_basic_types::web3::Bytes;
+
+    pub(super) fn serialize<S: Serializer>(
+        selector: &[u8; 4],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Bytes(selector.to_vec()).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; 4], D::Error> {
+        let bytes = Bytes::deserialize(deserializer)?;
+        bytes
+            .0
+            .as_slice()
+            .try_into()
+            .map_err(|_| de::Error::invalid_length(bytes.0.len(), &"a 4-byte function selector"))
+    }
+}
+
+/// Position of a transaction in the chain.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPosition {
+    pub block_number: L2BlockNumber,
+    pub transaction_index: u32,
+}
+
+/// Transaction returned by `zks_getTransactionsBySelector`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBySelector {
+    pub hash: H256,
+    #[serde(flatten)]
+    pub position: TransactionPosition,
+    pub from: Address,
+    pub to: Option<Address>,
+}
+
+/// Page of transactions returned by `zks_getTransactionsBySelector`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsBySelectorPage {
+    pub transactions: Vec<TransactionBySelector>,
+    /// Cursor to pass in the filter to get the next page. `None` if this is the last page.
+    pub next_cursor: Option<TransactionPosition>,
+}
+
+/// Transaction calldata and logs decoded using ABIs of verified contracts, as returned by `zks_getDecodedTransaction`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedTransaction {
+    pub transaction_hash: H256,
+    /// Decoded call to the transaction recipient. `None` if the recipient is not verified, or the calldata
+    /// doesn't match any function in its ABI.
+    pub call: Option<DecodedCall>,
+    /// Logs emitted by verified contracts and matching their ABIs. Other logs are omitted.
+    pub logs: Vec<DecodedLog>,
+}
+
+/// Contract function call decoded using the contract ABI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCall {
+    pub contract_address: Address,
+    /// Function name, e.g. `transfer`.
+    pub name: String,
+    /// Canonical function signature, e.g. `transfer(address,uint256)`.
+    pub signature: String,
+    pub params: Vec<DecodedParam>,
+}
+
+/// Event log decoded using the ABI of the emitting contract.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedLog {
+    /// Index of the log in the L2 block.
+    pub log_index: U256,
+    pub address: Address,
+    /// Event name, e.g. `Transfer`.
+    pub name: String,
+    /// Canonical event signature, e.g. `Transfer(address,address,uint256)`.
+    pub signature: String,
+    pub params: Vec<DecodedParam>,
+}
+
+impl DecodedLog {
+    /// Decodes a log using the provided event ABI. Returns `None` if the log doesn't match the event,
+    /// e.g. has a different signature or a different number of indexed parameters.
+    pub fn decode(event: &ethabi::Event, log: &Log) -> Option<Self> {
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.0.clone(),
+        };
+        let parsed = event.parse_log(raw_log).ok()?;
+
+        let params = event
+            .inputs
+            .iter()
+            .zip(parsed.params)
+            .map(|(input, param)| {
+                DecodedParam::new(param.name, &input.kind, &param.value, Some(input.indexed))
+            })
+            .collect();
+        let types: Vec<_> = event
+            .inputs
+            .iter()
+            .map(|input| input.kind.to_string())
+            .collect();
+        Some(Self {
+            log_index: log.log_index.unwrap_or_default(),
+            address: log.address,
+            name: event.name.clone(),
+            signature: format!("{}({})", event.name, types.join(",")),
+            params,
+        })
+    }
+}
+
+/// Decoded function argument or event parameter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedParam {
+    pub name: String,
+    /// Solidity type of the parameter, e.g. `uint256`.
+    #[serde(rename = "type")]
+    pub param_type: String,
+    /// Decoded value. Integers are encoded as decimal strings, byte sequences as `0x`-prefixed hex strings,
+    /// and arrays / tuples as JSON arrays.
+    pub value: Value,
+    /// Whether the parameter is indexed; only set for event parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed: Option<bool>,
+}
+
+impl DecodedParam {
+    pub fn new(name: String, param_type: &ParamType, value: &Token, indexed: Option<bool>) -> Self {
+        Self {
+            name,
+            param_type: param_type.to_string(),
+            value: Self::token_to_json(value),
+            indexed,
+        }
+    }
+
+    fn token_to_json(token: &Token) -> Value {
+        match token {
+            Token::Address(address) => Value::String(format!("{address:?}")),
+            Token::FixedBytes(bytes) | Token::Bytes(bytes) => {
+                serde_json::to_value(Bytes(bytes.clone())).expect("failed serializing bytes")
+            }
+            Token::Int(value) => Value::String(Self::int_to_string(*value)),
+            Token::Uint(value) => Value::String(value.to_string()),
+            Token::Bool(value) => Value::Bool(*value),
+            Token::String(value) => Value::String(value.clone()),
+            Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+                Value::Array(tokens.iter().map(Self::token_to_json).collect())
+            }
+        }
+    }
+
+    /// Converts a two's complement signed integer into a decimal string.
+    fn int_to_string(value: U256) -> String {
+        if value.bit(255) {
+            let abs = (!value).overflowing_add(U256::one()).0;
+            format!("-{abs}")
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+/// Aggregated latency of L1 batches reaching a certain proving stage. Latencies are measured in milliseconds
+/// from the moment a batch was queued for proving.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvingStageLatency {
+    pub stage: ProvingStage,
+    /// Number of batches in the requested range that have reached the stage.
+    pub batch_count: u64,
+    pub avg_ms: u64,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub max_ms: u64,
+}
+
+/// Proof latency stats returned by `zks_getProofLatencyStats`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofLatencyStats {
+    /// Start of the L1 batch range the stats are computed for (inclusive).
+    pub from_l1_batch: L1BatchNumber,
+    /// End of the L1 batch range the stats are computed for (inclusive). May be less than the requested value
+    /// if the range is too large.
+    pub to_l1_batch: L1BatchNumber,
+    /// Latencies for each stage reached by at least one batch in the range, ordered by stage.
+    pub stages: Vec<ProvingStageLatency>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn converting_tokens_to_json() {
+        assert_eq!(DecodedParam::token_to_json(&Token::Int(U256::MAX)), "-1");
+        assert_eq!(DecodedParam::token_to_json(&Token::Int(42.into())), "42");
+        assert_eq!(
+            DecodedParam::token_to_json(&Token::Bytes(vec![1, 2])),
+            "0x0102"
+        );
+        assert_eq!(
+            DecodedParam::token_to_json(&Token::Tuple(vec![
+                Token::Bool(true),
+                Token::Array(vec![Token::String("test".into())]),
+            ])),
+            serde_json::json!([true, ["test"]])
+        );
+    }
+
     // TODO (PLA-965): remove test after removing deprecating fields.
     #[allow(deprecated)]
     #[test]
@@ -951,4 +1747,60 @@ mod tests {
         serde_json::from_str::<OldProtocolVersion>(&serde_json::to_string(&new_version).unwrap())
             .unwrap();
     }
+
+    #[test]
+    fn deserializing_transactions_by_selector_filter() {
+        let filter: TransactionsBySelectorFilter =
+            serde_json::from_value(serde_json::json!({ "selector": "0xa9059cbb", "toBlock": 10 }))
+                .unwrap();
+        assert_eq!(filter.selector, [0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(filter.to_block, Some(L2BlockNumber(10)));
+        assert_eq!(filter.from_block, None);
+
+        let err = serde_json::from_value::<TransactionsBySelectorFilter>(
+            serde_json::json!({ "selector": "0xa9059c" }),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("4-byte function selector"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn computing_l2_block_hash_from_transaction_proof() {
+        let l2_block_number = L2BlockNumber(5);
+        let prev_l2_block_hash = H256::repeat_byte(1);
+        let tx_hashes: Vec<_> = (2..6).map(H256::repeat_byte).collect();
+        let mut hasher = L2BlockHasher::new(l2_block_number, 100, prev_l2_block_hash);
+        for &tx_hash in &tx_hashes {
+            hasher.push_tx_hash(tx_hash);
+        }
+        let l2_block_hash = hasher.finalize(ProtocolVersionId::latest());
+
+        let preceding_txs_rolling_hash = crate::web3::keccak256_concat(H256::zero(), tx_hashes[0]);
+        let proof = TransactionProof {
+            transaction_hash: tx_hashes[1],
+            l2_block_number,
+            l2_block_timestamp: 100,
+            l2_block_hash,
+            prev_l2_block_hash,
+            preceding_txs_rolling_hash,
+            following_tx_hashes: tx_hashes[2..].to_vec(),
+            l1_batch_number: L1BatchNumber(1),
+            l2_block_hash_proof: None,
+            l1_batch_root_hash: H256::zero(),
+            l1_batch_commitment: H256::zero(),
+            commit_tx_hash: None,
+        };
+        assert_eq!(proof.compute_l2_block_hash(), l2_block_hash);
+
+        let proof_for_first_tx = TransactionProof {
+            transaction_hash: tx_hashes[0],
+            preceding_txs_rolling_hash: H256::zero(),
+            following_tx_hashes: tx_hashes[1..].to_vec(),
+            ..proof
+        };
+        assert_eq!(proof_for_first_tx.compute_l2_block_hash(), l2_block_hash);
+    }
 }
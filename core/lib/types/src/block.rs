@@ -249,6 +249,22 @@ impl L2BlockHasher {
         }
     }
 
+    /// Creates a hasher for an L2 block with some transactions already processed. `txs_rolling_hash` is the rolling hash
+    /// of these transactions; hashes of the following transactions can be supplied using [`Self::push_tx_hash()`].
+    pub fn with_txs_rolling_hash(
+        number: L2BlockNumber,
+        timestamp: u64,
+        prev_l2_block_hash: H256,
+        txs_rolling_hash: H256,
+    ) -> Self {
+        Self {
+            number,
+            timestamp,
+            prev_l2_block_hash,
+            txs_rolling_hash,
+        }
+    }
+
     /// Updates this hasher with a transaction hash. This should be called for all transactions in the block
     /// in the order of their execution.
     pub fn push_tx_hash(&mut self, tx_hash: H256) {
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 use chrono::{DateTime, Utc};
 use serde::{
@@ -146,6 +149,11 @@ pub struct VerificationIncomingRequest {
     pub is_system: bool,
     #[serde(default)]
     pub force_evmla: bool,
+    /// If set and the verification fails, the verifier will look for already verified contracts
+    /// with a similar deployed bytecode (i.e., differing only in metadata and / or immutables) and return them
+    /// as partial matches in the request status.
+    #[serde(default)]
+    pub search_partial_matches: bool,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -240,6 +248,84 @@ pub struct VerificationInfo {
     pub verified_at: DateTime<Utc>,
 }
 
+/// Verified contract sources together with everything necessary to reproduce the contract compilation.
+/// Intended to be consumed programmatically, e.g. by security tooling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedSourceBundle {
+    pub contract_address: Address,
+    pub contract_name: String,
+    /// Source files keyed by their path. For single-file sources, the path is derived from the contract name
+    /// the same way the verifier does it.
+    pub sources: BTreeMap<String, String>,
+    /// Compiler settings from the standard JSON input. `None` for other source code formats; for them,
+    /// the compilation is fully defined by the compiler versions and optimization params.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub compiler_versions: CompilerVersions,
+    pub optimization_used: bool,
+    pub optimizer_mode: Option<String>,
+    pub constructor_arguments: Bytes,
+    pub is_system: bool,
+    pub force_evmla: bool,
+    pub abi: serde_json::Value,
+    pub verified_at: DateTime<Utc>,
+}
+
+impl VerifiedSourceBundle {
+    pub fn new(info: VerificationInfo) -> Self {
+        let req = info.request.req;
+        let (sources, settings) = match req.source_code_data {
+            SourceCodeData::SolSingleFile(source_code) => {
+                let file_name = Self::single_file_name(&req.contract_name, "sol");
+                (BTreeMap::from([(file_name, source_code)]), None)
+            }
+            SourceCodeData::YulSingleFile(source_code) => {
+                let file_name = Self::single_file_name(&req.contract_name, "yul");
+                (BTreeMap::from([(file_name, source_code)]), None)
+            }
+            SourceCodeData::VyperMultiFile(sources) => (sources.into_iter().collect(), None),
+            SourceCodeData::StandardJsonInput(mut input) => {
+                let sources = input
+                    .get("sources")
+                    .and_then(serde_json::Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(path, source)| {
+                        let content = source.get("content")?.as_str()?;
+                        Some((path.clone(), content.to_owned()))
+                    })
+                    .collect();
+                (sources, input.remove("settings"))
+            }
+        };
+
+        Self {
+            contract_address: req.contract_address,
+            contract_name: req.contract_name,
+            sources,
+            settings,
+            compiler_versions: req.compiler_versions,
+            optimization_used: req.optimization_used,
+            optimizer_mode: req.optimizer_mode,
+            constructor_arguments: req.constructor_arguments,
+            is_system: req.is_system,
+            force_evmla: req.force_evmla,
+            abi: info.artifacts.abi,
+            verified_at: info.verified_at,
+        }
+    }
+
+    /// Users may provide either just contract name or source file name and contract name joined with ":".
+    fn single_file_name(contract_name: &str, extension: &str) -> String {
+        match contract_name.rsplit_once(':') {
+            Some((file_name, _)) => file_name.to_owned(),
+            None => format!("{contract_name}.{extension}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationRequestStatus {
@@ -248,13 +334,35 @@ pub struct VerificationRequestStatus {
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compilation_errors: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_matches: Option<Vec<PartialMatch>>,
+}
+
+/// Already verified contract with the deployed bytecode similar to the bytecode of a contract being verified.
+/// Similar bytecodes are identical up to the embedded compiler metadata and values of immutable variables.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialMatch {
+    /// Address of the verified contract. Its full verification info can be retrieved via the API.
+    pub address: Address,
+    #[serde(flatten)]
+    pub source_code_data: SourceCodeData,
+    pub contract_name: String,
+    #[serde(flatten)]
+    pub compiler_versions: CompilerVersions,
+    pub optimization_used: bool,
+    pub optimizer_mode: Option<String>,
+    /// Whether compiler metadata (e.g., hashes of the source files) differs from the verified contract.
+    pub metadata_differs: bool,
+    /// Whether values of immutable variables differ from the verified contract.
+    pub immutables_differ: bool,
 }
 
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
 
-    use super::SourceCodeData;
+    use super::*;
 
     #[test]
     fn source_code_deserialization() {
@@ -283,4 +391,76 @@ mod tests {
             serde_json::from_str::<SourceCodeData>(type_not_specified_object_str);
         assert!(type_not_specified_object_result.is_err());
     }
+
+    fn mock_verification_info(source_code_data: SourceCodeData) -> VerificationInfo {
+        VerificationInfo {
+            request: VerificationRequest {
+                id: 1,
+                req: VerificationIncomingRequest {
+                    contract_address: Address::repeat_byte(1),
+                    source_code_data,
+                    contract_name: "contracts/Counter.sol:Counter".to_owned(),
+                    compiler_versions: CompilerVersions::Solc {
+                        compiler_zksolc_version: Some("1.5.6".to_owned()),
+                        compiler_solc_version: "0.8.27".to_owned(),
+                    },
+                    optimization_used: true,
+                    optimizer_mode: None,
+                    constructor_arguments: Bytes(vec![0; 32]),
+                    is_system: false,
+                    force_evmla: false,
+                    search_partial_matches: false,
+                },
+            },
+            artifacts: CompilationArtifacts {
+                bytecode: vec![0; 32],
+                deployed_bytecode: None,
+                abi: serde_json::json!([]),
+            },
+            verified_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn source_bundle_for_single_file() {
+        let source_code_data = SourceCodeData::SolSingleFile("contract Counter {}".to_owned());
+        let bundle = VerifiedSourceBundle::new(mock_verification_info(source_code_data));
+        assert_eq!(
+            bundle.sources,
+            BTreeMap::from([(
+                "contracts/Counter.sol".to_owned(),
+                "contract Counter {}".to_owned()
+            )])
+        );
+        assert_eq!(bundle.settings, None);
+        assert_eq!(bundle.constructor_arguments.0, [0; 32]);
+    }
+
+    #[test]
+    fn source_bundle_for_standard_json_input() {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {
+                "contracts/Counter.sol": { "content": "contract Counter {}" },
+                "contracts/Lib.sol": { "content": "library Lib {}" },
+            },
+            "settings": {
+                "optimizer": { "enabled": true, "runs": 200 },
+            },
+        });
+        let serde_json::Value::Object(input) = input else {
+            unreachable!();
+        };
+        let bundle = VerifiedSourceBundle::new(mock_verification_info(
+            SourceCodeData::StandardJsonInput(input),
+        ));
+        assert_eq!(
+            bundle.sources.keys().collect::<Vec<_>>(),
+            ["contracts/Counter.sol", "contracts/Lib.sol"]
+        );
+        assert_eq!(
+            bundle.settings.unwrap(),
+            serde_json::json!({ "optimizer": { "enabled": true, "runs": 200 } })
+        );
+    }
 }
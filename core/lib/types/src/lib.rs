@@ -405,14 +405,54 @@ impl Transaction {
                 }
             }
             abi::Transaction::L2(raw) => {
-                let (req, hash) =
-                    transaction_request::TransactionRequest::from_bytes_unverified(&raw)?;
-                let mut tx = L2Tx::from_request_unverified(req, allow_no_target)?;
-                tx.set_input(raw, hash);
-                tx.into()
+                let decoded = transaction_request::TransactionRequest::from_bytes_unverified(&raw);
+                Self::from_decoded_l2(raw, decoded, allow_no_target)?
             }
         })
     }
+
+    /// Batched version of [`Self::from_abi()`]. Signers of all L2 transactions are recovered in a single batch,
+    /// which is significantly faster for large batches. Results are returned in the same order as inputs.
+    pub fn from_abi_batch(
+        txs: Vec<abi::Transaction>,
+        allow_no_target: bool,
+    ) -> Vec<anyhow::Result<Self>> {
+        let raw_l2_txs: Vec<_> = txs
+            .iter()
+            .filter_map(|tx| match tx {
+                abi::Transaction::L2(raw) => Some(raw.as_slice()),
+                abi::Transaction::L1 { .. } => None,
+            })
+            .collect();
+        let mut decoded_l2_txs =
+            transaction_request::TransactionRequest::from_bytes_batch_unverified(&raw_l2_txs)
+                .into_iter();
+
+        txs.into_iter()
+            .map(|tx| match tx {
+                abi::Transaction::L2(raw) => {
+                    // Decoded transactions are returned in the same order as raw ones, so unwrap is safe here.
+                    let decoded = decoded_l2_txs.next().unwrap();
+                    Self::from_decoded_l2(raw, decoded, allow_no_target)
+                }
+                tx @ abi::Transaction::L1 { .. } => Self::from_abi(tx, allow_no_target),
+            })
+            .collect()
+    }
+
+    fn from_decoded_l2(
+        raw: Vec<u8>,
+        decoded: Result<
+            (transaction_request::TransactionRequest, H256),
+            transaction_request::SerializationTransactionError,
+        >,
+        allow_no_target: bool,
+    ) -> anyhow::Result<Self> {
+        let (req, hash) = decoded?;
+        let mut tx = L2Tx::from_request_unverified(req, allow_no_target)?;
+        tx.set_input(raw, hash);
+        Ok(tx.into())
+    }
 }
 
 #[derive(Clone, Serialize, Debug, Default, Eq, PartialEq, Hash)]
@@ -9,6 +9,33 @@ pub struct TokenInfo {
     pub metadata: TokenMetadata,
 }
 
+/// Origin of a token on L2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenOrigin {
+    /// Base token of the chain, added at genesis.
+    BaseToken,
+    /// Token deployed by the L2 shared bridge when it was first bridged from L1.
+    L1Bridge,
+}
+
+/// Well-known token together with the time its metadata was last updated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WellKnownToken {
+    pub info: TokenInfo,
+    pub origin: TokenOrigin,
+    /// UNIX timestamp (in seconds) at which token metadata was last updated.
+    pub updated_at: u64,
+}
+
+/// Page of well-known tokens ordered by their L2 address.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WellKnownTokensPage {
+    pub tokens: Vec<WellKnownToken>,
+    /// L2 address to continue pagination from. `None` if there are no more tokens.
+    pub next_cursor: Option<Address>,
+}
+
 /// Relevant information about tokens supported by ZKsync protocol.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenMetadata {
@@ -1,6 +1,10 @@
 use std::str::FromStr;
 
-use opentelemetry::{trace::TracerProvider, KeyValue};
+use opentelemetry::{
+    propagation::Extractor,
+    trace::{TraceContextExt, TracerProvider},
+    Context, KeyValue,
+};
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{
     propagation::TraceContextPropagator,
@@ -10,7 +14,8 @@ use opentelemetry_sdk::{
 use opentelemetry_semantic_conventions::resource::{
     DEPLOYMENT_ENVIRONMENT, K8S_CLUSTER_NAME, K8S_NAMESPACE_NAME, K8S_POD_NAME, SERVICE_NAME,
 };
-use tracing_subscriber::{registry::LookupSpan, EnvFilter, Layer};
+use tracing_opentelemetry::{OpenTelemetrySpanExt, OtelData};
+use tracing_subscriber::{registry::LookupSpan, EnvFilter, Layer, Registry};
 use url::Url;
 
 /// Information about the service.
@@ -234,7 +239,8 @@ impl OpenTelemetry {
 
         let config = opentelemetry_sdk::trace::Config::default()
             .with_id_generator(RandomIdGenerator::default())
-            .with_sampler(Sampler::AlwaysOn)
+            // Respect the sampling decision of the remote caller for propagated traces.
+            .with_sampler(Sampler::ParentBased(Box::new(Sampler::AlwaysOn)))
             .with_resource(resource);
 
         let provider = opentelemetry_sdk::trace::TracerProvider::builder()
@@ -266,6 +272,62 @@ impl OpenTelemetry {
     }
 }
 
+/// Trace context propagated from a remote caller, e.g. via W3C `traceparent` / `tracestate` HTTP headers.
+#[derive(Debug, Clone)]
+pub struct RemoteTraceContext(Context);
+
+impl RemoteTraceContext {
+    /// Extracts the trace context using the globally configured propagator. `get_header` should return
+    /// the value of the header with the specified (lowercase) name.
+    ///
+    /// Returns `None` if the context is missing or invalid, or if OpenTelemetry tracing is not configured.
+    pub fn extract<'a>(get_header: impl Fn(&str) -> Option<&'a str>) -> Option<Self> {
+        struct HeaderExtractor<F>(F);
+
+        impl<'a, F: Fn(&str) -> Option<&'a str>> Extractor for HeaderExtractor<F> {
+            fn get(&self, key: &str) -> Option<&str> {
+                (self.0)(key)
+            }
+
+            fn keys(&self) -> Vec<&str> {
+                // Not used by the trace context propagator.
+                vec![]
+            }
+        }
+
+        let context = opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(get_header))
+        });
+        let is_valid = context.span().span_context().is_valid();
+        is_valid.then_some(Self(context))
+    }
+
+    /// Makes the remote span the parent of the provided `tracing` span.
+    pub fn set_as_parent(self, span: &tracing::Span) {
+        span.set_parent(self.0);
+    }
+}
+
+/// Checks whether the current `tracing` span (or any of its ancestors) is exported via OpenTelemetry.
+/// Can be used to create additional spans only for traced requests, so that they don't produce
+/// standalone traces.
+pub fn is_current_span_traced() -> bool {
+    let Some(id) = tracing::Span::current().id() else {
+        return false;
+    };
+    tracing::dispatcher::get_default(|dispatch| {
+        let Some(registry) = dispatch.downcast_ref::<Registry>() else {
+            return false;
+        };
+        let Some(span) = registry.span(&id) else {
+            return false;
+        };
+        // Spans filtered out by the OpenTelemetry layer don't have `OtelData`.
+        span.scope()
+            .any(|span| span.extensions().get::<OtelData>().is_some())
+    })
+}
+
 // Doesn't define WARN and ERROR, because the highest verbosity of spans is INFO.
 #[derive(Copy, Clone, Debug, Default)]
 pub enum OpenTelemetryLevel {
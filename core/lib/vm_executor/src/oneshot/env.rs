@@ -82,6 +82,23 @@ impl OneshotEnvParameters<CallOrExecute> {
         .await
     }
 
+    /// Prepares environment for replaying a transaction executed in the resolved L2 block.
+    pub async fn to_replay_env(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        resolved_block_info: &ResolvedBlockInfo,
+        fee_input: BatchFeeInput,
+    ) -> anyhow::Result<OneshotEnv> {
+        self.to_env_inner(
+            connection,
+            TxExecutionMode::VerifyExecute,
+            resolved_block_info,
+            fee_input,
+            None,
+        )
+        .await
+    }
+
     /// Prepares environment for executing a provided transaction.
     pub async fn to_execute_env(
         &self,
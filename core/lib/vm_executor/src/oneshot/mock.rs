@@ -113,6 +113,7 @@ where
             tx_result: Box::new(self.mock_inspect(&env, args)),
             compression_result: Ok(()),
             call_traces: vec![],
+            touched_state: None,
         })
     }
 }
@@ -135,7 +136,9 @@ where
                 .result
             {
                 ExecutionResult::Halt { reason } => Err(ValidationError::FailedTx(reason)),
-                ExecutionResult::Success { .. } | ExecutionResult::Revert { .. } => {
+                ExecutionResult::Success { .. }
+                | ExecutionResult::Revert { .. }
+                | ExecutionResult::Interrupted { .. } => {
                     Ok((self.tx_validation_traces_responses)(&tx.into(), &env))
                 }
             },
@@ -7,6 +7,10 @@
 //! which can be used to prepare environment for `MainOneshotExecutor` (i.e., a [`OneshotEnv`] instance).
 
 use std::{
+    cell::RefCell,
+    collections::HashSet,
+    mem,
+    rc::Rc,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -17,15 +21,17 @@ use once_cell::sync::OnceCell;
 use zksync_multivm::{
     interface::{
         executor::{OneshotExecutor, TransactionValidator},
-        storage::{ReadStorage, StorageView, StorageWithOverrides},
-        tracer::{ValidationError, ValidationParams, ValidationTraces},
+        storage::{ReadStorage, StoragePtr, StorageView, StorageWithOverrides, WriteStorage},
+        tracer::{ExecutionLimits, ValidationError, ValidationParams, ValidationTraces},
         utils::{DivergenceHandler, ShadowVm},
         Call, ExecutionResult, InspectExecutionMode, OneshotEnv, OneshotTracingParams,
-        OneshotTransactionExecutionResult, StoredL2BlockEnv, TxExecutionArgs, TxExecutionMode,
-        VmFactory, VmInterface,
+        OneshotTransactionExecutionResult, StoredL2BlockEnv, TouchedState, TxExecutionArgs,
+        TxExecutionMode, VmFactory, VmInterface,
     },
     is_supported_by_fast_vm,
-    tracers::{CallTracer, StorageInvocations, TracerDispatcher, ValidationTracer},
+    tracers::{
+        CallTracer, InterruptTracer, StorageInvocations, TracerDispatcher, ValidationTracer,
+    },
     utils::adjust_pubdata_price_for_tx,
     vm_latest::{HistoryDisabled, HistoryEnabled},
     zk_evm_latest::ethereum_types::U256,
@@ -33,12 +39,13 @@ use zksync_multivm::{
 };
 use zksync_types::{
     block::pack_block_info,
-    get_nonce_key, h256_to_u256,
+    bytecode::BytecodeHash,
+    get_code_key, get_nonce_key, h256_to_u256,
     l2::L2Tx,
     u256_to_h256,
     utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
     vm::FastVmMode,
-    AccountTreeId, Nonce, StorageKey, Transaction, SYSTEM_CONTEXT_ADDRESS,
+    AccountTreeId, Address, Nonce, StorageKey, Transaction, SYSTEM_CONTEXT_ADDRESS,
     SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION, SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION,
 };
 
@@ -66,6 +73,7 @@ pub struct MainOneshotExecutor {
     fast_vm_mode: FastVmMode,
     panic_on_divergence: bool,
     missed_storage_invocation_limit: usize,
+    execution_limits: ExecutionLimits,
     execution_latency_histogram: Option<&'static vise::Histogram<Duration>>,
 }
 
@@ -77,10 +85,20 @@ impl MainOneshotExecutor {
             fast_vm_mode: FastVmMode::Old,
             panic_on_divergence: false,
             missed_storage_invocation_limit,
+            execution_limits: ExecutionLimits::default(),
             execution_latency_histogram: None,
         }
     }
 
+    /// Sets limits on the number of VM cycles and wall-clock time for calls and gas estimations; execution exceeding
+    /// these limits is interrupted with [`ExecutionResult::Interrupted`]. Like the limit on storage cache misses,
+    /// the limits are not applied during transaction validation. By default, execution is not limited.
+    ///
+    /// If any limit is set, transactions are executed in the legacy VM, since the fast VM doesn't support interruption.
+    pub fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        self.execution_limits = limits;
+    }
+
     /// Sets the fast VM mode used by this executor.
     pub fn set_fast_vm_mode(&mut self, fast_vm_mode: FastVmMode) {
         if !matches!(fast_vm_mode, FastVmMode::Old) {
@@ -108,9 +126,16 @@ impl MainOneshotExecutor {
         &self,
         env: &OneshotEnv,
         tracing_params: &OneshotTracingParams,
+        execution_limits: &ExecutionLimits,
     ) -> FastVmMode {
-        if tracing_params.trace_calls || !is_supported_by_fast_vm(env.system.version) {
-            FastVmMode::Old // the fast VM doesn't support call tracing or old protocol versions
+        if tracing_params.trace_calls
+            || tracing_params.trace_state
+            || execution_limits.is_set()
+            || !is_supported_by_fast_vm(env.system.version)
+        {
+            // The fast VM doesn't support call tracing, interruption or old protocol versions. It also doesn't write
+            // to `StorageView`, so touched state cannot be collected.
+            FastVmMode::Old
         } else {
             self.fast_vm_mode
         }
@@ -129,15 +154,15 @@ where
         args: TxExecutionArgs,
         tracing_params: OneshotTracingParams,
     ) -> anyhow::Result<OneshotTransactionExecutionResult> {
-        let missed_storage_invocation_limit = match env.system.execution_mode {
-            // storage accesses are not limited for tx validation
-            TxExecutionMode::VerifyExecute => usize::MAX,
+        let (missed_storage_invocation_limit, execution_limits) = match env.system.execution_mode {
+            // storage accesses and execution are not limited for tx validation
+            TxExecutionMode::VerifyExecute => (usize::MAX, ExecutionLimits::default()),
             TxExecutionMode::EthCall | TxExecutionMode::EstimateFee => {
-                self.missed_storage_invocation_limit
+                (self.missed_storage_invocation_limit, self.execution_limits)
             }
         };
         let sandbox = VmSandbox {
-            fast_vm_mode: self.select_fast_vm_mode(&env, &tracing_params),
+            fast_vm_mode: self.select_fast_vm_mode(&env, &tracing_params, &execution_limits),
             panic_on_divergence: self.panic_on_divergence,
             storage,
             env,
@@ -145,14 +170,27 @@ where
             execution_latency_histogram: self.execution_latency_histogram,
         };
 
+        // Propagate the current span, so that VM execution is attributed to the request it's performed for.
+        let span = tracing::Span::current();
         tokio::task::spawn_blocking(move || {
-            sandbox.execute_in_vm(|vm, transaction| {
-                vm.inspect_transaction_with_bytecode_compression(
+            let _entered = span.entered();
+            sandbox.execute_in_vm(|vm, transaction, storage_view| {
+                let trace_state = tracing_params.trace_state;
+                let initiator = transaction.initiator_account();
+                let mut result = vm.inspect_transaction_with_bytecode_compression(
                     missed_storage_invocation_limit,
+                    execution_limits,
                     tracing_params,
                     transaction,
                     true,
-                )
+                );
+                if trace_state {
+                    let storage_view = &mut storage_view.borrow_mut();
+                    let touched_state =
+                        collect_touched_state(storage_view, &result.call_traces, initiator);
+                    result.touched_state = Some(touched_state);
+                }
+                result
             })
         })
         .await
@@ -188,7 +226,9 @@ where
             execution_latency_histogram: self.execution_latency_histogram,
         };
 
+        let span = tracing::Span::current();
         tokio::task::spawn_blocking(move || {
+            let _entered = span.entered();
             let validation_tracer = ValidationTracer::<HistoryDisabled>::new(
                 validation_params,
                 sandbox.env.system.version.into(),
@@ -198,7 +238,7 @@ where
             let validation_traces = validation_tracer.get_traces();
             let tracers = vec![validation_tracer.into_tracer_pointer()];
 
-            let exec_result = sandbox.execute_in_vm(|vm, transaction| {
+            let exec_result = sandbox.execute_in_vm(|vm, transaction, _| {
                 let Vm::Legacy(vm) = vm else {
                     unreachable!("Fast VM is never used for validation yet");
                 };
@@ -230,6 +270,7 @@ impl<S: ReadStorage> Vm<S> {
     fn inspect_transaction_with_bytecode_compression(
         &mut self,
         missed_storage_invocation_limit: usize,
+        execution_limits: ExecutionLimits,
         params: OneshotTracingParams,
         tx: Transaction,
         with_compression: bool,
@@ -239,6 +280,7 @@ impl<S: ReadStorage> Vm<S> {
             Self::Legacy(vm) => {
                 let mut tracers = Self::create_legacy_tracers(
                     missed_storage_invocation_limit,
+                    execution_limits,
                     params.trace_calls.then(|| calls_result.clone()),
                 );
                 vm.inspect_transaction_with_bytecode_compression(&mut tracers, tx, with_compression)
@@ -250,6 +292,7 @@ impl<S: ReadStorage> Vm<S> {
                 );
                 let legacy_tracers = Self::create_legacy_tracers::<HistoryEnabled>(
                     missed_storage_invocation_limit,
+                    execution_limits,
                     None,
                 );
                 let mut full_tracer = (legacy_tracers.into(), ());
@@ -265,11 +308,13 @@ impl<S: ReadStorage> Vm<S> {
             tx_result: Box::new(tx_result),
             compression_result: compression_result.map(drop),
             call_traces: Arc::make_mut(&mut calls_result).take().unwrap_or_default(),
+            touched_state: None,
         }
     }
 
     fn create_legacy_tracers<H: HistoryMode>(
         missed_storage_invocation_limit: usize,
+        execution_limits: ExecutionLimits,
         calls_result: Option<Arc<OnceCell<Vec<Call>>>>,
     ) -> TracerDispatcher<StorageView<S>, H> {
         let mut tracers = vec![];
@@ -278,10 +323,66 @@ impl<S: ReadStorage> Vm<S> {
         }
         tracers
             .push(StorageInvocations::new(missed_storage_invocation_limit).into_tracer_pointer());
+        if execution_limits.is_set() {
+            tracers.push(InterruptTracer::new(execution_limits).into_tracer_pointer());
+        }
         tracers.into()
     }
 }
 
+/// Collects state touched by a transaction from the storage view it was executed on. This method is blocking.
+fn collect_touched_state<S: ReadStorage>(
+    storage_view: &mut StorageView<S>,
+    call_traces: &[Call],
+    initiator: Address,
+) -> TouchedState {
+    let mut accounts: HashSet<_> = storage_view
+        .read_storage_keys()
+        .keys()
+        .map(|key| *key.address())
+        .collect();
+    accounts.insert(initiator);
+    let mut pending_calls: Vec<_> = call_traces.iter().collect();
+    while let Some(call) = pending_calls.pop() {
+        accounts.extend([call.from, call.to]);
+        pending_calls.extend(&call.calls);
+    }
+
+    // Slots that weren't read during execution weren't modified either, so reading them now returns initial values.
+    for address in &accounts {
+        for key in [
+            storage_key_for_eth_balance(address),
+            get_nonce_key(address),
+            get_code_key(address),
+        ] {
+            storage_view.read_value(&key);
+        }
+    }
+    // Written slots are always read before the write, so read values are the initial ones for all touched slots.
+    let initial_values = storage_view.read_storage_keys().clone();
+    let final_values = storage_view.modified_storage_keys().clone();
+
+    let bytecode_hashes: HashSet<_> = accounts
+        .iter()
+        .map(get_code_key)
+        .flat_map(|key| [initial_values.get(&key), final_values.get(&key)])
+        .flatten()
+        .copied()
+        .filter(|hash| !hash.is_zero())
+        .collect();
+    let bytecodes = bytecode_hashes
+        .into_iter()
+        .filter_map(|hash| Some((hash, storage_view.load_factory_dep(hash)?)))
+        .collect();
+
+    TouchedState {
+        initial_values,
+        final_values,
+        accounts,
+        bytecodes,
+    }
+}
+
 /// Full parameters necessary to instantiate a VM for oneshot execution.
 #[derive(Debug)]
 struct VmSandbox<S> {
@@ -342,11 +443,74 @@ impl<S: ReadStorage> VmSandbox<S> {
         }
     }
 
+    /// Executes transactions preceding the target transaction and records their effects as storage overrides.
+    /// A separate VM is used, so that the storage view of the target transaction only contains slots touched by it.
+    /// This method is blocking.
+    fn execute_preceding_transactions(
+        storage: StorageWithOverrides<S>,
+        env: &OneshotEnv,
+        transactions: Vec<Transaction>,
+    ) -> StorageWithOverrides<S> {
+        let storage = Rc::new(RefCell::new(storage));
+        let storage_view = StorageView::new(storage.clone()).to_rc_ptr();
+        // The fast VM doesn't write to `StorageView`, so the legacy VM is always used.
+        let mut vm = Vm::Legacy(LegacyVmInstance::new_with_specific_version(
+            env.l1_batch.clone(),
+            env.system.clone(),
+            storage_view.clone(),
+            env.system.version.into_api_vm_version(),
+        ));
+
+        let mut factory_deps = vec![];
+        for transaction in transactions {
+            let tx_hash = transaction.hash();
+            factory_deps.extend(transaction.execute.factory_deps.clone());
+            let result = vm.inspect_transaction_with_bytecode_compression(
+                usize::MAX,
+                OneshotTracingParams::default(),
+                transaction,
+                true,
+            );
+            if let ExecutionResult::Halt { reason } = &result.tx_result.result {
+                tracing::warn!("Preceding transaction {tx_hash:?} halted during replay: {reason}");
+            }
+        }
+        drop(vm);
+
+        let modified_storage_keys = storage_view.borrow().modified_storage_keys().clone();
+        drop(storage_view);
+        let mut storage = Rc::try_unwrap(storage)
+            .expect("storage is referenced after VM execution")
+            .into_inner();
+        for (key, value) in modified_storage_keys {
+            storage.set_value(key, value);
+        }
+        for bytecode in factory_deps {
+            let hash = BytecodeHash::for_bytecode(&bytecode).value();
+            storage.store_factory_dep(hash, bytecode);
+        }
+        storage
+    }
+
     /// This method is blocking.
     fn execute_in_vm<T>(
         mut self,
-        action: impl FnOnce(&mut Vm<StorageWithOverrides<S>>, Transaction) -> T,
+        action: impl FnOnce(
+            &mut Vm<StorageWithOverrides<S>>,
+            Transaction,
+            &StoragePtr<StorageView<StorageWithOverrides<S>>>,
+        ) -> T,
     ) -> T {
+        let preceding_transactions = mem::take(&mut self.execution_args.preceding_transactions);
+        if !preceding_transactions.is_empty() {
+            self.storage = Self::execute_preceding_transactions(
+                self.storage,
+                &self.env,
+                preceding_transactions,
+            );
+        }
+        // L2 block info modified by preceding transactions is reset here, so that the target transaction
+        // starts the same L2 block.
         Self::setup_storage(
             &mut self.storage,
             &self.execution_args,
@@ -399,7 +563,7 @@ impl<S: ReadStorage> VmSandbox<S> {
         };
 
         let started_at = Instant::now();
-        let result = action(&mut vm, transaction);
+        let result = action(&mut vm, transaction, &storage_view);
         let vm_execution_took = started_at.elapsed();
 
         if let Some(histogram) = self.execution_latency_histogram {
@@ -31,7 +31,19 @@ fn selecting_vm_for_execution() {
         assert_matches!(mode, FastVmMode::New);
 
         // Tracing calls is not supported by the new VM.
-        let mode = executor.select_fast_vm_mode(&env, &OneshotTracingParams { trace_calls: true });
+        let tracing = OneshotTracingParams {
+            trace_calls: true,
+            ..OneshotTracingParams::default()
+        };
+        let mode = executor.select_fast_vm_mode(&env, &tracing);
+        assert_matches!(mode, FastVmMode::Old);
+
+        // Neither is collecting touched state.
+        let tracing = OneshotTracingParams {
+            trace_state: true,
+            ..OneshotTracingParams::default()
+        };
+        let mode = executor.select_fast_vm_mode(&env, &tracing);
         assert_matches!(mode, FastVmMode::Old);
 
         // Old protocol versions are not supported either.
@@ -104,3 +116,62 @@ async fn inspecting_transfer(exec_mode: TxExecutionMode, fast_vm_mode: FastVmMod
     let exec_result = result.tx_result.result;
     assert!(!exec_result.is_failed(), "{exec_result:?}");
 }
+
+#[tokio::test]
+async fn collecting_touched_state_for_transfer() {
+    let tx = create_l2_transaction(1_000_000_000.into(), Nonce(0));
+    let initiator = tx.initiator_account();
+    let balance_key = storage_key_for_eth_balance(&initiator);
+    let initial_balance = u256_to_h256(u64::MAX.into());
+    let mut storage = InMemoryStorage::with_system_contracts();
+    storage.set_value(balance_key, initial_balance);
+    let storage = StorageWithOverrides::new(storage);
+
+    let l1_batch = default_l1_batch_env(1);
+    let env = OneshotEnv {
+        system: default_system_env(TxExecutionMode::VerifyExecute),
+        current_block: Some(StoredL2BlockEnv {
+            number: l1_batch.first_l2_block.number - 1,
+            timestamp: l1_batch.first_l2_block.timestamp - 1,
+            txs_rolling_hash: H256::zero(),
+        }),
+        l1_batch,
+    };
+    let args = TxExecutionArgs::for_validation(tx);
+    let tracing = OneshotTracingParams {
+        trace_state: true,
+        ..OneshotTracingParams::default()
+    };
+
+    let mut executor = MainOneshotExecutor::new(usize::MAX);
+    executor.set_fast_vm_mode(FastVmMode::New);
+    let result = executor
+        .inspect_transaction_with_bytecode_compression(storage, env, args, tracing)
+        .await
+        .unwrap();
+    let exec_result = result.tx_result.result;
+    assert!(!exec_result.is_failed(), "{exec_result:?}");
+
+    let touched_state = result.touched_state.expect("no touched state");
+    // The initiator pays for the transaction, so its balance is both read and written.
+    assert_eq!(touched_state.initial_values[&balance_key], initial_balance);
+    let final_balance = touched_state.final_values[&balance_key];
+    assert!(h256_to_u256(final_balance) < h256_to_u256(initial_balance));
+    for key in touched_state.final_values.keys() {
+        assert!(touched_state.initial_values.contains_key(key), "{key:?}");
+    }
+
+    assert!(touched_state.accounts.contains(&initiator));
+    for address in &touched_state.accounts {
+        let code_key = get_code_key(address);
+        let code_hash = touched_state.initial_values[&code_key];
+        if !code_hash.is_zero() {
+            assert!(
+                touched_state.bytecodes.contains_key(&code_hash),
+                "{address:?}"
+            );
+        }
+    }
+    // The initiator is an EOA, so it has no bytecode.
+    assert!(touched_state.initial_values[&get_code_key(&initiator)].is_zero());
+}
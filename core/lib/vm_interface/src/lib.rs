@@ -28,12 +28,13 @@ pub use crate::{
             StoredL2BlockEnv, SystemEnv, TxExecutionArgs, TxExecutionMode, VmExecutionMode,
         },
         outputs::{
-            BatchTransactionExecutionResult, BootloaderMemory, Call, CallType, CircuitStatistic,
-            CompressedBytecodeInfo, CurrentExecutionState, DeduplicatedWritesMetrics,
-            ExecutionResult, FinishedL1Batch, L2Block, OneshotTransactionExecutionResult,
-            PushTransactionResult, Refunds, TransactionExecutionMetrics,
-            TransactionExecutionResult, TxExecutionStatus, VmEvent, VmExecutionLogs,
-            VmExecutionMetrics, VmExecutionResultAndLogs, VmExecutionStatistics, VmMemoryMetrics,
+            BatchTransactionExecutionResult, BootloaderMemory, Call, CallKind, CallType,
+            CircuitStatistic, CompressedBytecodeInfo, CurrentExecutionState,
+            DeduplicatedWritesMetrics, ExecutionResult, FinishedL1Batch, L2Block,
+            OneshotTransactionExecutionResult, PushTransactionResult, Refunds, TouchedState,
+            TransactionExecutionMetrics, TransactionExecutionResult, TxExecutionStatus, VmEvent,
+            VmExecutionLogs, VmExecutionMetrics, VmExecutionResultAndLogs, VmExecutionStatistics,
+            VmMemoryMetrics,
         },
         tracer,
     },
@@ -0,0 +1,169 @@
+//! Cheap storage forks that can be used from multiple threads.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use zksync_types::{StorageKey, StorageValue, H256};
+
+use super::ReadStorage;
+
+#[derive(Debug, Default)]
+struct SharedCache {
+    values: HashMap<StorageKey, StorageValue>,
+    initial_writes: HashMap<StorageKey, bool>,
+    factory_deps: HashMap<H256, Option<Vec<u8>>>,
+}
+
+#[derive(Debug)]
+struct SharedStorage<S> {
+    storage: Mutex<S>,
+    cache: RwLock<SharedCache>,
+}
+
+/// Storage that can be cheaply forked into multiple [`StorageFork`]s. Forks can be sent to other threads;
+/// reads from the underlying storage are cached and shared among all forks.
+///
+/// Forks are read-only. To make a copy-on-write fork, wrap it into a [`StorageView`](super::StorageView);
+/// this way, writes are local to the fork, and the underlying storage is never modified.
+pub struct ForkableStorage<S> {
+    shared: Arc<SharedStorage<S>>,
+}
+
+impl<S: fmt::Debug> fmt::Debug for ForkableStorage<S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ForkableStorage")
+            .field("forks", &(Arc::strong_count(&self.shared) - 1))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: ReadStorage> ForkableStorage<S> {
+    /// Wraps the provided storage.
+    pub fn new(storage: S) -> Self {
+        Self {
+            shared: Arc::new(SharedStorage {
+                storage: Mutex::new(storage),
+                cache: RwLock::default(),
+            }),
+        }
+    }
+
+    /// Creates a new fork of this storage.
+    pub fn fork(&self) -> StorageFork<S> {
+        StorageFork {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+/// Read-only fork of a [`ForkableStorage`].
+pub struct StorageFork<S> {
+    shared: Arc<SharedStorage<S>>,
+}
+
+impl<S> fmt::Debug for StorageFork<S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("StorageFork")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: ReadStorage> StorageFork<S> {
+    fn cached_or_load<T: Clone>(
+        &self,
+        get: impl FnOnce(&SharedCache) -> Option<&T>,
+        load: impl FnOnce(&mut S) -> T,
+        insert: impl FnOnce(&mut SharedCache, T),
+    ) -> T {
+        if let Some(value) = get(&self.shared.cache.read().unwrap()) {
+            return value.clone();
+        }
+        let value = load(&mut self.shared.storage.lock().unwrap());
+        insert(&mut self.shared.cache.write().unwrap(), value.clone());
+        value
+    }
+}
+
+impl<S: ReadStorage> ReadStorage for StorageFork<S> {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        self.cached_or_load(
+            |cache| cache.values.get(key),
+            |storage| storage.read_value(key),
+            |cache, value| {
+                cache.values.insert(*key, value);
+            },
+        )
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.cached_or_load(
+            |cache| cache.initial_writes.get(key),
+            |storage| storage.is_write_initial(key),
+            |cache, is_initial| {
+                cache.initial_writes.insert(*key, is_initial);
+            },
+        )
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.cached_or_load(
+            |cache| cache.factory_deps.get(&hash),
+            |storage| storage.load_factory_dep(hash),
+            |cache, dep| {
+                cache.factory_deps.insert(hash, dep);
+            },
+        )
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.shared
+            .storage
+            .lock()
+            .unwrap()
+            .get_enumeration_index(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use zksync_types::{AccountTreeId, Address};
+
+    use super::*;
+    use crate::storage::{InMemoryStorage, StorageView, WriteStorage};
+
+    #[test]
+    fn forks_are_isolated() {
+        let account = AccountTreeId::new(Address::repeat_byte(0xfe));
+        let key = StorageKey::new(account, H256::from_low_u64_be(1));
+        let mut storage = InMemoryStorage::default();
+        storage.set_value(key, H256::repeat_byte(1));
+        let storage = ForkableStorage::new(storage);
+
+        let handles: Vec<_> = (2..=3)
+            .map(|byte| {
+                let mut view = StorageView::new(storage.fork());
+                thread::spawn(move || {
+                    assert_eq!(view.read_value(&key), H256::repeat_byte(1));
+                    view.set_value(key, H256::repeat_byte(byte));
+                    view.read_value(&key)
+                })
+            })
+            .collect();
+        let values: Vec<_> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+        assert_eq!(values, [H256::repeat_byte(2), H256::repeat_byte(3)]);
+
+        assert_eq!(storage.fork().read_value(&key), H256::repeat_byte(1));
+        let cache = storage.shared.cache.read().unwrap();
+        assert_eq!(cache.values.len(), 1);
+    }
+}
@@ -3,6 +3,7 @@ use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
 use zksync_types::{get_known_code_key, StorageKey, StorageValue, H256};
 
 pub use self::{
+    fork::{ForkableStorage, StorageFork},
     // Note, that `test_infra` of the bootloader tests relies on this value to be exposed
     in_memory::{InMemoryStorage, IN_MEMORY_STORAGE_DEFAULT_NETWORK_ID},
     overrides::StorageWithOverrides,
@@ -10,6 +11,7 @@ pub use self::{
     view::{ImmutableStorageView, StorageView, StorageViewCache, StorageViewStats},
 };
 
+mod fork;
 mod in_memory;
 mod overrides;
 mod snapshot;
@@ -3,7 +3,7 @@ use std::{collections::HashMap, fmt};
 use serde::{Deserialize, Serialize};
 use zksync_types::{web3, StorageKey, StorageValue, H256};
 
-use super::ReadStorage;
+use super::{ReadStorage, StorageView};
 
 /// Self-sufficient or almost self-sufficient storage snapshot for a particular VM execution (e.g., executing a single L1 batch).
 ///
@@ -41,6 +41,45 @@ impl StorageSnapshot {
         }
     }
 
+    /// Creates a snapshot of all storage slots accessed via the provided storage view, e.g. after executing an L1 batch.
+    /// `used_contract_hashes` should contain hashes of all bytecodes used during execution; the corresponding bytecodes
+    /// are loaded from the storage.
+    pub fn from_storage_view<S: ReadStorage>(
+        storage: &mut StorageView<S>,
+        used_contract_hashes: impl IntoIterator<Item = H256>,
+    ) -> Self {
+        let storage_cache = storage.cache();
+        let mut storage_slots: HashMap<_, _> = storage_cache
+            .read_storage_keys()
+            .into_iter()
+            .map(|(key, value)| {
+                let enum_index = storage.get_enumeration_index(&key);
+                let value_and_index = enum_index.map(|idx| (value, idx));
+                (key.hashed_key(), value_and_index)
+            })
+            .collect();
+
+        // Normally, all writes are internally read in order to calculate their gas costs, so the code below
+        // is defensive programming.
+        for (key, _) in storage_cache.initial_writes() {
+            let hashed_key = key.hashed_key();
+            if storage_slots.contains_key(&hashed_key) {
+                continue;
+            }
+
+            let enum_index = storage.get_enumeration_index(&key);
+            let value_and_index = enum_index.map(|idx| (storage.read_value(&key), idx));
+            storage_slots.insert(hashed_key, value_and_index);
+        }
+
+        let factory_deps = used_contract_hashes
+            .into_iter()
+            .filter_map(|hash| Some((hash, storage.load_factory_dep(hash)?)))
+            .collect();
+
+        Self::new(storage_slots, factory_deps)
+    }
+
     /// Creates a [`ReadStorage`] implementation based on this snapshot and the provided fallback implementation.
     /// Fallback will be called for storage slots / factory deps not in this snapshot (which, if this snapshot
     /// is reasonably constructed, would be a rare occurrence). If `shadow` flag is set, the fallback will be
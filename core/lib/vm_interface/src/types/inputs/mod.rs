@@ -44,6 +44,9 @@ pub struct TxExecutionArgs {
     /// to the one in the block. This is often helpful in case we want the transaction validation to work regardless of the
     /// current L1 prices for gas or pubdata.
     pub adjust_pubdata_price: bool,
+    /// Transactions executed before the transaction in the same VM environment. Used to reproduce the state
+    /// an executed transaction was applied to; empty for calls and new transactions.
+    pub preceding_transactions: Vec<Transaction>,
 }
 
 impl TxExecutionArgs {
@@ -52,6 +55,7 @@ impl TxExecutionArgs {
             enforced_nonce: Some(tx.nonce()),
             added_balance: U256::zero(),
             adjust_pubdata_price: true,
+            preceding_transactions: vec![],
             transaction: tx.into(),
         }
     }
@@ -65,6 +69,7 @@ impl TxExecutionArgs {
             enforced_nonce: None,
             added_balance: U256::zero(),
             adjust_pubdata_price: false,
+            preceding_transactions: vec![],
             transaction: call.into(),
         }
     }
@@ -82,6 +87,19 @@ impl TxExecutionArgs {
             enforced_nonce: transaction.nonce(),
             added_balance,
             adjust_pubdata_price: true,
+            preceding_transactions: vec![],
+            transaction,
+        }
+    }
+
+    /// Creates arguments to replay an executed transaction. `preceding_transactions` are transactions
+    /// executed before it in the same L2 block, in the execution order.
+    pub fn for_replay(transaction: Transaction, preceding_transactions: Vec<Transaction>) -> Self {
+        Self {
+            enforced_nonce: None,
+            added_balance: U256::zero(),
+            adjust_pubdata_price: false,
+            preceding_transactions,
             transaction,
         }
     }
@@ -92,4 +110,6 @@ impl TxExecutionArgs {
 pub struct OneshotTracingParams {
     /// Whether to trace contract calls.
     pub trace_calls: bool,
+    /// Whether to collect state (storage slots, balances, nonces and bytecodes) touched by the transaction.
+    pub trace_state: bool,
 }
@@ -0,0 +1,421 @@
+//! Classification of calls in VM traces.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+use zksync_system_constants::{
+    ACCOUNT_CODE_STORAGE_ADDRESS, BOOTLOADER_ADDRESS, BOOTLOADER_UTILITIES_ADDRESS,
+    CODE_ORACLE_ADDRESS, COMPLEX_UPGRADER_ADDRESS, COMPRESSOR_ADDRESS, CONTRACT_DEPLOYER_ADDRESS,
+    CONTRACT_FORCE_DEPLOYER_ADDRESS, CREATE2_FACTORY_ADDRESS, ECRECOVER_PRECOMPILE_ADDRESS,
+    EC_ADD_PRECOMPILE_ADDRESS, EC_MUL_PRECOMPILE_ADDRESS, EC_PAIRING_PRECOMPILE_ADDRESS,
+    EVENT_WRITER_ADDRESS, EVM_GAS_MANAGER_ADDRESS, IMMUTABLE_SIMULATOR_STORAGE_ADDRESS,
+    KECCAK256_PRECOMPILE_ADDRESS, KNOWN_CODES_STORAGE_ADDRESS, L1_MESSENGER_ADDRESS,
+    L2_ASSET_ROUTER_ADDRESS, L2_BASE_TOKEN_ADDRESS, L2_BRIDGEHUB_ADDRESS,
+    L2_GENESIS_UPGRADE_ADDRESS, L2_MESSAGE_ROOT_ADDRESS, L2_NATIVE_TOKEN_VAULT_ADDRESS,
+    MSG_VALUE_SIMULATOR_ADDRESS, NONCE_HOLDER_ADDRESS, PUBDATA_CHUNK_PUBLISHER_ADDRESS,
+    SECP256R1_VERIFY_PRECOMPILE_ADDRESS, SHA256_PRECOMPILE_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
+};
+use zksync_types::{web3::keccak256, Address};
+
+use super::Call;
+
+/// Kind of the callee in a [`Call`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallKind {
+    /// Contract deployed by a user.
+    UserContract,
+    /// Built-in contract, including the bootloader, kernel-space system contracts and user-space
+    /// built-in contracts (e.g., the L2 bridgehub).
+    SystemContract,
+    /// Precompile, e.g. `ecrecover` or `keccak256`.
+    Precompile,
+    /// EVM contract executed by the EVM emulator.
+    EvmEmulator,
+}
+
+/// Names and kinds of built-in contracts.
+const KNOWN_CONTRACTS: &[(Address, &str, CallKind)] = &[
+    (BOOTLOADER_ADDRESS, "Bootloader", CallKind::SystemContract),
+    (
+        ACCOUNT_CODE_STORAGE_ADDRESS,
+        "AccountCodeStorage",
+        CallKind::SystemContract,
+    ),
+    (
+        NONCE_HOLDER_ADDRESS,
+        "NonceHolder",
+        CallKind::SystemContract,
+    ),
+    (
+        KNOWN_CODES_STORAGE_ADDRESS,
+        "KnownCodesStorage",
+        CallKind::SystemContract,
+    ),
+    (
+        IMMUTABLE_SIMULATOR_STORAGE_ADDRESS,
+        "ImmutableSimulator",
+        CallKind::SystemContract,
+    ),
+    (
+        CONTRACT_DEPLOYER_ADDRESS,
+        "ContractDeployer",
+        CallKind::SystemContract,
+    ),
+    (
+        CONTRACT_FORCE_DEPLOYER_ADDRESS,
+        "ForceDeployer",
+        CallKind::SystemContract,
+    ),
+    (
+        L1_MESSENGER_ADDRESS,
+        "L1Messenger",
+        CallKind::SystemContract,
+    ),
+    (
+        MSG_VALUE_SIMULATOR_ADDRESS,
+        "MsgValueSimulator",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_BASE_TOKEN_ADDRESS,
+        "L2BaseToken",
+        CallKind::SystemContract,
+    ),
+    (
+        SYSTEM_CONTEXT_ADDRESS,
+        "SystemContext",
+        CallKind::SystemContract,
+    ),
+    (
+        BOOTLOADER_UTILITIES_ADDRESS,
+        "BootloaderUtilities",
+        CallKind::SystemContract,
+    ),
+    (
+        EVENT_WRITER_ADDRESS,
+        "EventWriter",
+        CallKind::SystemContract,
+    ),
+    (COMPRESSOR_ADDRESS, "Compressor", CallKind::SystemContract),
+    (
+        COMPLEX_UPGRADER_ADDRESS,
+        "ComplexUpgrader",
+        CallKind::SystemContract,
+    ),
+    (
+        PUBDATA_CHUNK_PUBLISHER_ADDRESS,
+        "PubdataChunkPublisher",
+        CallKind::SystemContract,
+    ),
+    (
+        EVM_GAS_MANAGER_ADDRESS,
+        "EvmGasManager",
+        CallKind::SystemContract,
+    ),
+    (
+        CREATE2_FACTORY_ADDRESS,
+        "Create2Factory",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_GENESIS_UPGRADE_ADDRESS,
+        "L2GenesisUpgrade",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_BRIDGEHUB_ADDRESS,
+        "L2Bridgehub",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_ASSET_ROUTER_ADDRESS,
+        "L2AssetRouter",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_NATIVE_TOKEN_VAULT_ADDRESS,
+        "L2NativeTokenVault",
+        CallKind::SystemContract,
+    ),
+    (
+        L2_MESSAGE_ROOT_ADDRESS,
+        "L2MessageRoot",
+        CallKind::SystemContract,
+    ),
+    (
+        ECRECOVER_PRECOMPILE_ADDRESS,
+        "ecrecover",
+        CallKind::Precompile,
+    ),
+    (SHA256_PRECOMPILE_ADDRESS, "sha256", CallKind::Precompile),
+    (EC_ADD_PRECOMPILE_ADDRESS, "ecAdd", CallKind::Precompile),
+    (EC_MUL_PRECOMPILE_ADDRESS, "ecMul", CallKind::Precompile),
+    (
+        EC_PAIRING_PRECOMPILE_ADDRESS,
+        "ecPairing",
+        CallKind::Precompile,
+    ),
+    (
+        SECP256R1_VERIFY_PRECOMPILE_ADDRESS,
+        "p256Verify",
+        CallKind::Precompile,
+    ),
+    (
+        KECCAK256_PRECOMPILE_ADDRESS,
+        "keccak256",
+        CallKind::Precompile,
+    ),
+    (CODE_ORACLE_ADDRESS, "codeOracle", CallKind::Precompile),
+];
+
+/// Signatures of the commonly called functions of system contracts. Used to decode system call names.
+const SYSTEM_FUNCTIONS: &[(Address, &[&str])] = &[
+    (
+        ACCOUNT_CODE_STORAGE_ADDRESS,
+        &[
+            "getRawCodeHash(address)",
+            "getCodeHash(uint256)",
+            "getCodeSize(uint256)",
+            "storeAccountConstructingCodeHash(address,bytes32)",
+            "storeAccountConstructedCodeHash(address,bytes32)",
+            "markAccountCodeHashAsConstructed(address)",
+            "isAccountEVM(address)",
+        ],
+    ),
+    (
+        NONCE_HOLDER_ADDRESS,
+        &[
+            "getMinNonce(address)",
+            "getRawNonce(address)",
+            "increaseMinNonce(uint256)",
+            "incrementMinNonceIfEquals(uint256)",
+            "getDeploymentNonce(address)",
+            "incrementDeploymentNonce(address)",
+            "validateNonceUsage(address,uint256,bool)",
+            "isNonceUsed(address,uint256)",
+            "setValueUnderNonce(uint256,uint256)",
+            "getValueUnderNonce(uint256)",
+        ],
+    ),
+    (
+        KNOWN_CODES_STORAGE_ADDRESS,
+        &[
+            "markFactoryDeps(bool,bytes32[])",
+            "markBytecodeAsPublished(bytes32)",
+            "getMarker(bytes32)",
+        ],
+    ),
+    (
+        IMMUTABLE_SIMULATOR_STORAGE_ADDRESS,
+        &[
+            "getImmutable(address,uint256)",
+            "setImmutables(address,(uint256,bytes32)[])",
+        ],
+    ),
+    (
+        CONTRACT_DEPLOYER_ADDRESS,
+        &[
+            "create(bytes32,bytes32,bytes)",
+            "create2(bytes32,bytes32,bytes)",
+            "createAccount(bytes32,bytes32,bytes,uint8)",
+            "create2Account(bytes32,bytes32,bytes,uint8)",
+            "createEVM(bytes)",
+            "create2EVM(bytes32,bytes)",
+            "forceDeployOnAddresses((bytes32,address,bool,uint256,bytes)[])",
+            "getNewAddressCreate(address,uint256)",
+            "getNewAddressCreate2(address,bytes32,bytes32,bytes)",
+            "extendedAccountVersion(address)",
+        ],
+    ),
+    (
+        L1_MESSENGER_ADDRESS,
+        &[
+            "sendToL1(bytes)",
+            "sendL2ToL1Log(bool,bytes32,bytes32)",
+            "requestBytecodeL1Publication(bytes32)",
+            "publishPubdataAndClearState(bytes)",
+            "publishPubdataAndClearState(address,bytes)",
+        ],
+    ),
+    (
+        L2_BASE_TOKEN_ADDRESS,
+        &[
+            "transferFromTo(address,address,uint256)",
+            "balanceOf(uint256)",
+            "mint(address,uint256)",
+            "withdraw(address)",
+            "withdrawWithMessage(address,bytes)",
+            "totalSupply()",
+        ],
+    ),
+    (
+        SYSTEM_CONTEXT_ADDRESS,
+        &[
+            "setL2Block(uint128,uint128,bytes32,bool,uint128)",
+            "setNewBatch(bytes32,uint128,uint128,uint256)",
+            "setTxOrigin(address)",
+            "setGasPrice(uint256)",
+            "setPubdataInfo(uint256,uint256)",
+            "incrementTxNumberInBatch()",
+            "resetTxNumberInBatch()",
+            "appendTransactionToCurrentL2Block(bytes32)",
+            "publishTimestampDataToL1()",
+            "unsafeOverrideBatch(uint256,uint256,uint256)",
+            "chainId()",
+            "origin()",
+            "gasPrice()",
+            "baseFee()",
+            "gasPerPubdataByte()",
+            "getCurrentPubdataSpent()",
+            "getBlockHashEVM(uint256)",
+            "getBatchHash(uint256)",
+            "getBlockNumber()",
+            "getBlockTimestamp()",
+            "getL2BlockNumberAndTimestamp()",
+        ],
+    ),
+    (
+        COMPRESSOR_ADDRESS,
+        &[
+            "publishCompressedBytecode(bytes,bytes)",
+            "verifyCompressedStateDiffs(uint256,uint256,bytes,bytes)",
+        ],
+    ),
+    (COMPLEX_UPGRADER_ADDRESS, &["upgrade(address,bytes)"]),
+    (
+        PUBDATA_CHUNK_PUBLISHER_ADDRESS,
+        &["chunkPubdataToBlobs(bytes)"],
+    ),
+    (
+        CREATE2_FACTORY_ADDRESS,
+        &[
+            "create2(bytes32,bytes32,bytes)",
+            "create2Account(bytes32,bytes32,bytes,uint8)",
+        ],
+    ),
+];
+
+fn known_contract(address: Address) -> Option<(&'static str, CallKind)> {
+    KNOWN_CONTRACTS
+        .iter()
+        .find(|(known_address, ..)| *known_address == address)
+        .map(|&(_, name, kind)| (name, kind))
+}
+
+fn system_function_name(address: Address, selector: [u8; 4]) -> Option<&'static str> {
+    static FUNCTIONS: OnceLock<HashMap<(Address, [u8; 4]), &'static str>> = OnceLock::new();
+
+    let functions = FUNCTIONS.get_or_init(|| {
+        let mut functions = HashMap::new();
+        for &(address, signatures) in SYSTEM_FUNCTIONS {
+            for &signature in signatures {
+                let hash = keccak256(signature.as_bytes());
+                let selector = [hash[0], hash[1], hash[2], hash[3]];
+                let name = signature.split('(').next().unwrap();
+                functions.insert((address, selector), name);
+            }
+        }
+        functions
+    });
+    functions.get(&(address, selector)).copied()
+}
+
+impl Call {
+    /// Classifies the callee of this call.
+    ///
+    /// EVM contracts are recognized by the calls the EVM emulator makes to the `EvmGasManager` system contract
+    /// on behalf of the contract, so classification requires subcalls to be present in the trace.
+    pub fn kind(&self) -> CallKind {
+        if let Some((_, kind)) = known_contract(self.to) {
+            return kind;
+        }
+        let is_emulated = self
+            .calls
+            .iter()
+            .any(|call| call.from == self.to && call.to == EVM_GAS_MANAGER_ADDRESS);
+        if is_emulated {
+            CallKind::EvmEmulator
+        } else {
+            CallKind::UserContract
+        }
+    }
+
+    /// Returns a human-readable name of the call to a system contract or a precompile, e.g.
+    /// `NonceHolder.incrementMinNonceIfEquals` or `keccak256`. If the called function is not recognized,
+    /// only the contract name is returned. Returns `None` for calls to other contracts.
+    pub fn system_call_name(&self) -> Option<String> {
+        let (contract_name, kind) = known_contract(self.to)?;
+        if kind == CallKind::Precompile {
+            return Some(contract_name.to_owned());
+        }
+        let function_name = self
+            .input
+            .get(..4)
+            .and_then(|selector| system_function_name(self.to, selector.try_into().unwrap()));
+        Some(match function_name {
+            Some(function_name) => format!("{contract_name}.{function_name}"),
+            None => contract_name.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call_to(to: Address, input: Vec<u8>) -> Call {
+        Call {
+            to,
+            input,
+            ..Call::default()
+        }
+    }
+
+    #[test]
+    fn classifying_calls() {
+        let user_contract = Address::repeat_byte(0x42);
+        assert_eq!(
+            call_to(NONCE_HOLDER_ADDRESS, vec![]).kind(),
+            CallKind::SystemContract
+        );
+        assert_eq!(
+            call_to(L2_BRIDGEHUB_ADDRESS, vec![]).kind(),
+            CallKind::SystemContract
+        );
+        assert_eq!(
+            call_to(ECRECOVER_PRECOMPILE_ADDRESS, vec![]).kind(),
+            CallKind::Precompile
+        );
+        assert_eq!(
+            call_to(user_contract, vec![]).kind(),
+            CallKind::UserContract
+        );
+
+        let mut evm_call = call_to(user_contract, vec![]);
+        evm_call.calls.push(Call {
+            from: user_contract,
+            ..call_to(EVM_GAS_MANAGER_ADDRESS, vec![])
+        });
+        assert_eq!(evm_call.kind(), CallKind::EvmEmulator);
+    }
+
+    #[test]
+    fn decoding_system_call_names() {
+        let selector = keccak256(b"incrementMinNonceIfEquals(uint256)")[..4].to_vec();
+        let mut input = selector;
+        input.extend_from_slice(&[0; 32]);
+        let call = call_to(NONCE_HOLDER_ADDRESS, input);
+        assert_eq!(
+            call.system_call_name().as_deref(),
+            Some("NonceHolder.incrementMinNonceIfEquals")
+        );
+
+        let call = call_to(NONCE_HOLDER_ADDRESS, vec![0xff; 4]);
+        assert_eq!(call.system_call_name().as_deref(), Some("NonceHolder"));
+        let call = call_to(KECCAK256_PRECOMPILE_ADDRESS, vec![1, 2, 3]);
+        assert_eq!(call.system_call_name().as_deref(), Some("keccak256"));
+        let call = call_to(Address::repeat_byte(0x42), vec![]);
+        assert_eq!(call.system_call_name(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zksync_system_constants::{
@@ -9,12 +9,13 @@ use zksync_types::{
     ethabi,
     l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
     zk_evm_types::FarCallOpcode,
-    Address, L1BatchNumber, StorageLogWithPreviousValue, Transaction, H256, U256,
+    Address, L1BatchNumber, StorageKey, StorageLogWithPreviousValue, StorageValue, Transaction,
+    H256, U256,
 };
 
 use crate::{
-    BytecodeCompressionError, CompressedBytecodeInfo, Halt, VmExecutionMetrics,
-    VmExecutionStatistics, VmRevertReason,
+    tracer::InterruptionReason, BytecodeCompressionError, CompressedBytecodeInfo, Halt,
+    VmExecutionMetrics, VmExecutionStatistics, VmRevertReason,
 };
 
 const L1_MESSAGE_EVENT_SIGNATURE: H256 = H256([
@@ -148,12 +149,17 @@ pub enum ExecutionResult {
     Revert { output: VmRevertReason },
     /// Reverted for various reasons
     Halt { reason: Halt },
+    /// Interrupted before completion because execution has exceeded [`ExecutionLimits`](crate::tracer::ExecutionLimits).
+    Interrupted { reason: InterruptionReason },
 }
 
 impl ExecutionResult {
     /// Returns `true` if the execution was failed.
     pub fn is_failed(&self) -> bool {
-        matches!(self, Self::Revert { .. } | Self::Halt { .. })
+        matches!(
+            self,
+            Self::Revert { .. } | Self::Halt { .. } | Self::Interrupted { .. }
+        )
     }
 }
 
@@ -360,6 +366,24 @@ pub struct OneshotTransactionExecutionResult {
     pub compression_result: Result<(), BytecodeCompressionError>,
     /// Call traces (if requested; otherwise, empty).
     pub call_traces: Vec<Call>,
+    /// State touched by the transaction (if requested).
+    pub touched_state: Option<TouchedState>,
+}
+
+/// State touched by a transaction during oneshot execution.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TouchedState {
+    /// Values before the transaction execution for all slots read or written by the transaction.
+    /// Also includes balance, nonce and bytecode hash slots for all `accounts`.
+    pub initial_values: HashMap<StorageKey, StorageValue>,
+    /// Values after the transaction execution for all slots written by the transaction. May include slots
+    /// with the value equal to the initial one.
+    pub final_values: HashMap<StorageKey, StorageValue>,
+    /// Accounts touched by the transaction: the transaction initiator, owners of touched storage slots,
+    /// and all callers / callees if calls are traced.
+    pub accounts: HashSet<Address>,
+    /// Bytecodes of `accounts` before and after the transaction execution, keyed by the bytecode hash.
+    pub bytecodes: HashMap<H256, Vec<u8>>,
 }
 
 /// High-level transaction execution result used by the API server sandbox etc.
@@ -2,10 +2,11 @@ use std::borrow::Cow;
 
 pub use self::{
     bytecode::CompressedBytecodeInfo,
+    call_kind::CallKind,
     execution_result::{
         BatchTransactionExecutionResult, Call, CallType, ExecutionResult,
-        OneshotTransactionExecutionResult, Refunds, TransactionExecutionResult, TxExecutionStatus,
-        VmEvent, VmExecutionLogs, VmExecutionResultAndLogs,
+        OneshotTransactionExecutionResult, Refunds, TouchedState, TransactionExecutionResult,
+        TxExecutionStatus, VmEvent, VmExecutionLogs, VmExecutionResultAndLogs,
     },
     execution_state::{BootloaderMemory, CurrentExecutionState},
     finished_l1batch::FinishedL1Batch,
@@ -17,6 +18,7 @@ pub use self::{
 };
 
 mod bytecode;
+mod call_kind;
 mod execution_result;
 mod execution_state;
 mod finished_l1batch;
@@ -1,5 +1,6 @@
 use std::{collections::HashSet, fmt, ops::Range, time};
 
+use serde::{Deserialize, Serialize};
 use zksync_types::{Address, U256};
 
 use crate::Halt;
@@ -8,6 +9,45 @@ use crate::Halt;
 pub enum TracerExecutionStopReason {
     Finish,
     Abort(Halt),
+    /// Execution was interrupted because it has exceeded [`ExecutionLimits`].
+    Interrupt(InterruptionReason),
+}
+
+/// Reason why VM execution was interrupted before it has finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptionReason {
+    /// Execution has reached the limit on the number of VM cycles (roughly, executed opcodes).
+    CycleLimit(u64),
+    /// Execution has exceeded the wall-clock timeout.
+    Timeout(time::Duration),
+}
+
+impl fmt::Display for InterruptionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CycleLimit(limit) => {
+                write!(f, "execution exceeded the limit of {limit} VM cycles")
+            }
+            Self::Timeout(timeout) => write!(f, "execution exceeded the timeout of {timeout:?}"),
+        }
+    }
+}
+
+/// Limits on VM execution that are independent of the gas limit. Execution exceeding any of the limits
+/// is interrupted, which results in [`ExecutionResult::Interrupted`](crate::ExecutionResult::Interrupted).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    /// Maximum number of VM cycles.
+    pub max_cycles: Option<u64>,
+    /// Maximum wall-clock duration of the execution.
+    pub timeout: Option<time::Duration>,
+}
+
+impl ExecutionLimits {
+    /// Checks whether any of the limits is set.
+    pub fn is_set(&self) -> bool {
+        self.max_cycles.is_some() || self.timeout.is_some()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +60,7 @@ impl TracerExecutionStatus {
     /// Chose the stricter ExecutionStatus
     /// If both statuses are Continue, then the result is Continue
     /// If one of the statuses is Abort, then the result is Abort
+    /// If one of the statuses is Interrupt, then the result is Interrupt
     /// If one of the statuses is Finish, then the result is Finish
     pub fn stricter(&self, other: &Self) -> Self {
         match (self, other) {
@@ -28,6 +69,10 @@ impl TracerExecutionStatus {
             | (_, Self::Stop(TracerExecutionStopReason::Abort(reason))) => {
                 Self::Stop(TracerExecutionStopReason::Abort(reason.clone()))
             }
+            (Self::Stop(TracerExecutionStopReason::Interrupt(reason)), _)
+            | (_, Self::Stop(TracerExecutionStopReason::Interrupt(reason))) => {
+                Self::Stop(TracerExecutionStopReason::Interrupt(*reason))
+            }
             (Self::Stop(TracerExecutionStopReason::Finish), _)
             | (_, Self::Stop(TracerExecutionStopReason::Finish)) => {
                 Self::Stop(TracerExecutionStopReason::Finish)
@@ -111,13 +156,98 @@ impl fmt::Display for ViolatedValidationRule {
     }
 }
 
+impl ViolatedValidationRule {
+    /// Returns a stable machine-readable name of the rule.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TouchedDisallowedStorageSlots(..) => "touchedDisallowedStorageSlots",
+            Self::CalledContractWithNoCode(_) => "calledContractWithNoCode",
+            Self::TouchedDisallowedContext => "touchedDisallowedContext",
+            Self::TookTooManyComputationalGas(_) => "tookTooManyComputationalGas",
+            Self::TimestampAssertionCloseToRangeEnd => "timestampAssertionCloseToRangeEnd",
+        }
+    }
+}
+
+/// Point of the validation execution at which a rule was violated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViolationLocation {
+    /// Executed opcode, e.g. `Log(StorageRead)`.
+    pub opcode: String,
+    /// Program counter in the current call frame.
+    pub pc: u16,
+    /// Address of the contract executed in the current call frame.
+    pub frame_address: Address,
+}
+
+/// Violated validation rule together with the point of execution where it was detected.
+#[derive(Debug, Clone)]
+pub struct ValidationViolation {
+    pub rule: ViolatedValidationRule,
+    /// Not set by legacy VM versions.
+    pub location: Option<ViolationLocation>,
+}
+
+impl From<ViolatedValidationRule> for ValidationViolation {
+    fn from(rule: ViolatedValidationRule) -> Self {
+        Self {
+            rule,
+            location: None,
+        }
+    }
+}
+
+impl ValidationViolation {
+    /// Creates a machine-readable report on this violation.
+    pub fn report(&self) -> ValidationViolationReport {
+        let (slot, called_address) = match &self.rule {
+            ViolatedValidationRule::TouchedDisallowedStorageSlots(_, key) => (Some(*key), None),
+            ViolatedValidationRule::CalledContractWithNoCode(address) => (None, Some(*address)),
+            _ => (None, None),
+        };
+        ValidationViolationReport {
+            rule: self.rule.name().to_owned(),
+            message: self.rule.to_string(),
+            opcode: self.location.as_ref().map(|loc| loc.opcode.clone()),
+            pc: self.location.as_ref().map(|loc| loc.pc),
+            frame_address: self.location.as_ref().map(|loc| loc.frame_address),
+            slot,
+            called_address,
+        }
+    }
+}
+
+/// Machine-readable report on a violated validation rule returned to API clients, so that they can explain
+/// why an account abstraction transaction was rejected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationViolationReport {
+    /// Name of the violated rule as returned by [`ViolatedValidationRule::name()`].
+    pub rule: String,
+    /// Human-readable description of the violation.
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opcode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pc: Option<u16>,
+    /// Address of the contract executed in the call frame that violated the rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_address: Option<Address>,
+    /// Accessed storage slot of the frame contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<U256>,
+    /// Called contract without bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub called_address: Option<Address>,
+}
+
 /// Errors returned when validating a transaction.
 #[derive(Debug)]
 pub enum ValidationError {
     /// VM execution was halted during validation.
     FailedTx(Halt),
     /// Transaction violated one of account validation rules.
-    ViolatedRule(ViolatedValidationRule),
+    ViolatedRule(ValidationViolation),
 }
 
 /// Traces the validation of a transaction, providing visibility into the aspects the transaction interacts with.
@@ -150,8 +280,8 @@ impl fmt::Display for ValidationError {
             Self::FailedTx(revert_reason) => {
                 write!(f, "Validation revert: {}", revert_reason)
             }
-            Self::ViolatedRule(rule) => {
-                write!(f, "Violated validation rules: {}", rule)
+            Self::ViolatedRule(violation) => {
+                write!(f, "Violated validation rules: {}", violation.rule)
             }
         }
     }
@@ -188,4 +318,41 @@ mod tests {
         validation_traces.apply_timestamp_asserter_range(20..40);
         assert_eq!(validation_traces.timestamp_asserter_range, Some(20..30));
     }
+
+    #[test]
+    fn serializing_violation_report() {
+        let violation = ValidationViolation {
+            rule: ViolatedValidationRule::TouchedDisallowedStorageSlots(
+                Address::repeat_byte(1),
+                U256::from(5),
+            ),
+            location: Some(ViolationLocation {
+                opcode: "Log(StorageRead)".to_owned(),
+                pc: 42,
+                frame_address: Address::repeat_byte(1),
+            }),
+        };
+        let report = serde_json::to_value(violation.report()).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "rule": "touchedDisallowedStorageSlots",
+                "message": violation.rule.to_string(),
+                "opcode": "Log(StorageRead)",
+                "pc": 42,
+                "frameAddress": "0x0101010101010101010101010101010101010101",
+                "slot": "0x5",
+            })
+        );
+
+        let violation = ValidationViolation::from(ViolatedValidationRule::TouchedDisallowedContext);
+        let report = serde_json::to_value(violation.report()).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "rule": "touchedDisallowedContext",
+                "message": "Touched disallowed context",
+            })
+        );
+    }
 }
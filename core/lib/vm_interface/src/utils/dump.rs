@@ -1,7 +1,7 @@
-use std::{collections::HashMap, rc::Rc};
+use std::rc::Rc;
 
 use serde::{Deserialize, Serialize};
-use zksync_types::{block::L2BlockExecutionData, L1BatchNumber, L2BlockNumber, Transaction, H256};
+use zksync_types::{block::L2BlockExecutionData, L1BatchNumber, L2BlockNumber, Transaction};
 
 use crate::{
     pubdata::PubdataBuilder,
@@ -11,43 +11,6 @@ use crate::{
     VmInterfaceExt, VmInterfaceHistoryEnabled, VmTrackingContracts,
 };
 
-fn create_storage_snapshot<S: ReadStorage>(
-    storage: &StoragePtr<StorageView<S>>,
-    used_contract_hashes: Vec<H256>,
-) -> StorageSnapshot {
-    let mut storage = storage.borrow_mut();
-    let storage_cache = storage.cache();
-    let mut storage_slots: HashMap<_, _> = storage_cache
-        .read_storage_keys()
-        .into_iter()
-        .map(|(key, value)| {
-            let enum_index = storage.get_enumeration_index(&key);
-            let value_and_index = enum_index.map(|idx| (value, idx));
-            (key.hashed_key(), value_and_index)
-        })
-        .collect();
-
-    // Normally, all writes are internally read in order to calculate their gas costs, so the code below
-    // is defensive programming.
-    for (key, _) in storage_cache.initial_writes() {
-        let hashed_key = key.hashed_key();
-        if storage_slots.contains_key(&hashed_key) {
-            continue;
-        }
-
-        let enum_index = storage.get_enumeration_index(&key);
-        let value_and_index = enum_index.map(|idx| (storage.read_value(&key), idx));
-        storage_slots.insert(hashed_key, value_and_index);
-    }
-
-    let factory_deps = used_contract_hashes
-        .into_iter()
-        .filter_map(|hash| Some((hash, storage.load_factory_dep(hash)?)))
-        .collect();
-
-    StorageSnapshot::new(storage_slots, factory_deps)
-}
-
 /// VM dump allowing to re-run the VM on the same inputs. Can be (de)serialized.
 /// Note, dump is not capable of finishing batch in terms of VM execution.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -135,7 +98,10 @@ impl<S: ReadStorage, Vm: VmTrackingContracts> DumpingVm<S, Vm> {
             l1_batch_env: self.l1_batch_env.clone(),
             system_env: self.system_env.clone(),
             l2_blocks: self.l2_blocks.clone(),
-            storage: create_storage_snapshot(&self.storage, self.inner.used_contract_hashes()),
+            storage: StorageSnapshot::from_storage_view(
+                &mut self.storage.borrow_mut(),
+                self.inner.used_contract_hashes(),
+            ),
         }
     }
 }
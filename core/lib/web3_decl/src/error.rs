@@ -29,6 +29,10 @@ pub enum Web3Error {
     ProxyError(#[from] EnrichedClientError),
     #[error("{0}")]
     SubmitTransactionError(String, Vec<u8>),
+    /// Transaction violated account validation rules. Besides the message, contains a machine-readable report
+    /// on the violation returned as the error data.
+    #[error("{0}")]
+    ValidationRuleViolated(String, serde_json::Value),
     #[error("Failed to serialize transaction: {0}")]
     SerializationError(#[from] SerializationTransactionError),
     #[error("More than four topics in filter")]
@@ -39,6 +43,10 @@ pub enum Web3Error {
     LogsLimitExceeded(usize, u32, u32),
     #[error("invalid filter: if blockHash is supplied fromBlock and toBlock must not be")]
     InvalidFilterBlockHash,
+    #[error("Tracer is not supported: {0}")]
+    UnsupportedTracer(&'static str),
+    #[error("invalid reward percentiles: {0}")]
+    InvalidRewardPercentiles(&'static str),
     /// Weaker form of a "method not found" error; the method implementation is technically present,
     /// but the node configuration prevents the method from functioning.
     #[error("Method not implemented")]
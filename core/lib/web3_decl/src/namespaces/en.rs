@@ -51,6 +51,11 @@ pub trait EnNamespace {
     #[method(name = "attestationStatus")]
     async fn attestation_status(&self) -> RpcResult<Option<en::AttestationStatus>>;
 
+    /// Gets the consensus state of the node for diagnostics: current view and its leader,
+    /// last certificates, and committees. `None` if consensus is not enabled.
+    #[method(name = "consensusStatus")]
+    async fn consensus_status(&self) -> RpcResult<Option<en::ConsensusStatus>>;
+
     /// Get tokens that are white-listed and it can be used by paymasters.
     #[method(name = "whitelistedTokensForAA")]
     async fn whitelisted_tokens_for_aa(&self) -> RpcResult<Vec<Address>>;
@@ -0,0 +1,33 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::{
+    api::{TxpoolAccountContent, TxpoolContent, TxpoolOrderingPolicy, TxpoolStatus},
+    Address,
+};
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Transaction pool inspection methods compatible with the `txpool` namespace of Geth.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "txpool", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait TxpoolNamespace {
+    #[method(name = "status")]
+    fn status(&self) -> RpcResult<TxpoolStatus>;
+
+    #[method(name = "content")]
+    fn content(&self) -> RpcResult<TxpoolContent>;
+
+    #[method(name = "contentFrom")]
+    fn content_from(&self, address: Address) -> RpcResult<TxpoolAccountContent>;
+
+    /// Returns the policy used to order transactions in the mempool. This method is not a part of the Geth `txpool` namespace.
+    #[method(name = "orderingPolicy")]
+    fn ordering_policy(&self) -> RpcResult<TxpoolOrderingPolicy>;
+}
@@ -5,8 +5,12 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockDetails, BridgeAddresses, L1BatchDetails,
-        L2ToL1LogProof, Proof, ProtocolVersion, TransactionDetailedResult, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BridgeAddresses, ChainFeatures,
+        CircuitsEstimate, ConfirmedTokensPage, ConfirmedTokensQuery, DecodedTransaction,
+        L1BatchDetails, L1BatchReport, L1ToL2TxSimulationResult, L2ToL1LogProof, NodeInfo,
+        NonceDetails, Proof, ProofLatencyStats, ProtocolVersion, TokenPrice,
+        TransactionDetailedResult, TransactionDetails, TransactionProof,
+        TransactionsBySelectorFilter, TransactionsBySelectorPage,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -42,6 +46,22 @@ pub trait ZksNamespace {
         state_override: Option<StateOverride>,
     ) -> RpcResult<U256>;
 
+    #[method(name = "simulateL1ToL2Transaction")]
+    async fn simulate_l1_to_l2_transaction(
+        &self,
+        req: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<L1ToL2TxSimulationResult>;
+
+    /// Executes an L2 transaction against the pending state and returns the number of circuits it would use
+    /// together with the fraction of the L1 batch capacity it would consume.
+    #[method(name = "estimateCircuits")]
+    async fn estimate_circuits(
+        &self,
+        req: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<CircuitsEstimate>;
+
     #[method(name = "getBridgehubContract")]
     async fn get_bridgehub_contract(&self) -> RpcResult<Option<Address>>;
 
@@ -66,6 +86,15 @@ pub trait ZksNamespace {
     #[method(name = "getConfirmedTokens")]
     async fn get_confirmed_tokens(&self, from: u32, limit: u8) -> RpcResult<Vec<Token>>;
 
+    #[method(name = "getConfirmedTokensPage")]
+    async fn get_confirmed_tokens_page(
+        &self,
+        query: Option<ConfirmedTokensQuery>,
+    ) -> RpcResult<ConfirmedTokensPage>;
+
+    #[method(name = "getNonceDetails")]
+    async fn get_nonce_details(&self, address: Address) -> RpcResult<NonceDetails>;
+
     #[method(name = "getAllAccountBalances")]
     async fn get_all_account_balances(&self, address: Address)
         -> RpcResult<HashMap<Address, U256>>;
@@ -111,6 +140,9 @@ pub trait ZksNamespace {
     async fn get_l1_batch_details(&self, batch: L1BatchNumber)
         -> RpcResult<Option<L1BatchDetails>>;
 
+    #[method(name = "getBatchReport")]
+    async fn get_batch_report(&self, batch: L1BatchNumber) -> RpcResult<Option<L1BatchReport>>;
+
     #[method(name = "getBytecodeByHash")]
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>>;
 
@@ -120,6 +152,15 @@ pub trait ZksNamespace {
     #[method(name = "getFeeParams")]
     async fn get_fee_params(&self) -> RpcResult<FeeParams>;
 
+    #[method(name = "getBatchFeeParams")]
+    async fn get_batch_fee_params(&self, batch: L1BatchNumber) -> RpcResult<Option<FeeParams>>;
+
+    #[method(name = "getNodeInfo")]
+    async fn get_node_info(&self) -> RpcResult<NodeInfo>;
+
+    #[method(name = "getChainFeatures")]
+    async fn get_chain_features(&self) -> RpcResult<ChainFeatures>;
+
     #[method(name = "getProtocolVersion")]
     async fn get_protocol_version(
         &self,
@@ -134,6 +175,9 @@ pub trait ZksNamespace {
         l1_batch_number: L1BatchNumber,
     ) -> RpcResult<Option<Proof>>;
 
+    #[method(name = "getTransactionProof")]
+    async fn get_transaction_proof(&self, tx_hash: H256) -> RpcResult<Option<TransactionProof>>;
+
     #[method(name = "getBatchFeeInput")]
     async fn get_batch_fee_input(&self) -> RpcResult<PubdataIndependentBatchFeeModelInput>;
 
@@ -142,4 +186,42 @@ pub trait ZksNamespace {
         &self,
         tx_bytes: Bytes,
     ) -> RpcResult<TransactionDetailedResult>;
+
+    /// Returns the USD price of the token with the specified L1 address, or `None` if the price is unknown.
+    #[method(name = "getTokenPrice")]
+    async fn get_token_price(&self, token: Address) -> RpcResult<Option<TokenPrice>>;
+
+    #[method(name = "getDecodedTransaction")]
+    async fn get_decoded_transaction(&self, hash: H256) -> RpcResult<Option<DecodedTransaction>>;
+
+    #[method(name = "getTransactionsBySelector")]
+    async fn get_transactions_by_selector(
+        &self,
+        filter: TransactionsBySelectorFilter,
+    ) -> RpcResult<TransactionsBySelectorPage>;
+
+    /// Returns aggregated latencies of L1 batches in the specified range reaching each proving stage.
+    /// If `to_l1_batch` is not specified, the range ends with the latest sealed L1 batch.
+    #[method(name = "getProofLatencyStats")]
+    async fn get_proof_latency_stats(
+        &self,
+        from_l1_batch: L1BatchNumber,
+        to_l1_batch: Option<L1BatchNumber>,
+    ) -> RpcResult<ProofLatencyStats>;
 }
+
+#[cfg(feature = "server")]
+mod pub_sub {
+    use jsonrpsee::{core::SubscriptionResult, proc_macros::rpc};
+
+    #[rpc(server, namespace = "zks")]
+    pub trait ZksPubSub {
+        /// Subscribes to ZKsync-specific notifications. The only supported `sub_type` is `l1Batches`,
+        /// which notifies about L1 batches being committed, proven or executed on L1.
+        #[subscription(name = "subscribe" => "subscription", unsubscribe = "unsubscribe", item = PubSubResult)]
+        async fn subscribe(&self, sub_type: String) -> SubscriptionResult;
+    }
+}
+
+#[cfg(feature = "server")]
+pub use self::pub_sub::ZksPubSubServer;
@@ -14,7 +14,7 @@ use core::{
 use rlp::Rlp;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 pub use zksync_types::{
-    api::{Block, BlockNumber, Log, TransactionReceipt, TransactionRequest},
+    api::{Block, BlockNumber, L1BatchStageUpdate, Log, TransactionReceipt, TransactionRequest},
     ethabi,
     web3::{
         BlockHeader, Bytes, CallRequest, FeeHistory, Index, SyncState, TraceFilter, U64Number, Work,
@@ -183,6 +183,11 @@ pub struct Filter {
     pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
     #[serde(rename = "blockHash", skip_serializing_if = "Option::is_none")]
     pub block_hash: Option<H256>,
+    /// ABI fragment of the event in the JSON ABI format (ZKsync-specific extension). If specified, only logs matching
+    /// the event signature and the layout of indexed parameters are returned, with parameters decoded
+    /// into [`Log::decoded_args`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<ethabi::Event>,
 }
 
 /// Filter Builder
@@ -240,6 +245,12 @@ impl FilterBuilder {
         )
     }
 
+    /// Sets the event ABI fragment used to match and decode logs
+    pub fn set_event(mut self, event: ethabi::Event) -> Self {
+        self.filter.event = Some(event);
+        self
+    }
+
     /// Returns filter
     pub fn build(&self) -> Filter {
         self.filter.clone()
@@ -343,6 +354,7 @@ pub enum PubSubResult {
     Log(Log),
     TxHash(H256),
     Syncing(bool),
+    L1BatchStage(L1BatchStageUpdate),
 }
 
 #[cfg(test)]
@@ -63,6 +63,12 @@ pub enum Component {
     ExternalProofIntegrationApi,
     /// VM runner-based component that allows to test experimental VM features. Doesn't save any data to Postgres.
     VmPlayground,
+    /// VM runner-based component that re-executes batches using the fast VM and recomputes their commitments.
+    VmRunnerBatchVerifier,
+    /// Component decoding transactions using ABIs of verified contracts.
+    TransactionDecoder,
+    /// Etherscan-compatible REST API.
+    EtherscanApi,
 }
 
 #[derive(Debug)]
@@ -105,9 +111,12 @@ impl FromStr for Components {
             }
             "vm_runner_bwip" => Ok(Components(vec![Component::VmRunnerBwip])),
             "vm_playground" => Ok(Components(vec![Component::VmPlayground])),
+            "vm_runner_batch_verifier" => Ok(Components(vec![Component::VmRunnerBatchVerifier])),
             "external_proof_integration_api" => {
                 Ok(Components(vec![Component::ExternalProofIntegrationApi]))
             }
+            "transaction_decoder" => Ok(Components(vec![Component::TransactionDecoder])),
+            "etherscan_api" => Ok(Components(vec![Component::EtherscanApi])),
             other => Err(format!("{} is not a valid component name", other)),
         }
     }
@@ -5,13 +5,17 @@ use std::time::{Duration, Instant};
 use anyhow::Context as _;
 use async_trait::async_trait;
 use tokio::runtime::Handle;
+use tracing::Instrument as _;
 use zksync_dal::{Connection, Core};
 use zksync_multivm::interface::{
     executor::{OneshotExecutor, TransactionValidator},
     storage::{ReadStorage, StorageWithOverrides},
-    tracer::{TimestampAsserterParams, ValidationError, ValidationParams, ValidationTraces},
-    Call, OneshotEnv, OneshotTracingParams, OneshotTransactionExecutionResult,
-    TransactionExecutionMetrics, TxExecutionArgs, VmExecutionResultAndLogs,
+    tracer::{
+        ExecutionLimits, TimestampAsserterParams, ValidationError, ValidationParams,
+        ValidationTraces,
+    },
+    Call, OneshotEnv, OneshotTracingParams, OneshotTransactionExecutionResult, TouchedState,
+    TransactionExecutionMetrics, TxExecutionArgs, TxExecutionMode, VmExecutionResultAndLogs,
 };
 use zksync_state::{PostgresStorage, PostgresStorageCaches};
 use zksync_types::{
@@ -43,6 +47,14 @@ pub(crate) enum SandboxAction {
         fee_input: BatchFeeInput,
         base_fee: u64,
     },
+    /// Replay a transaction from a sealed L2 block, possibly with tracing. The transaction is executed
+    /// on top of the state before the block after `preceding_txs` from the same block.
+    Replay {
+        tx: Transaction,
+        preceding_txs: Vec<Transaction>,
+        fee_input: BatchFeeInput,
+        tracing_params: OneshotTracingParams,
+    },
 }
 
 impl SandboxAction {
@@ -51,7 +63,9 @@ impl SandboxAction {
             Self::Execution { tx, .. } | Self::Call { call: tx, .. } => {
                 tx.execute.factory_deps.len()
             }
-            Self::GasEstimation { tx, .. } => tx.execute.factory_deps.len(),
+            Self::GasEstimation { tx, .. } | Self::Replay { tx, .. } => {
+                tx.execute.factory_deps.len()
+            }
         }
     }
 
@@ -70,6 +84,15 @@ impl SandboxAction {
                 tracing_params,
                 ..
             } => (TxExecutionArgs::for_eth_call(call), tracing_params),
+            Self::Replay {
+                tx,
+                preceding_txs,
+                tracing_params,
+                ..
+            } => (
+                TxExecutionArgs::for_replay(tx, preceding_txs),
+                tracing_params,
+            ),
         }
     }
 }
@@ -81,6 +104,8 @@ pub(crate) struct SandboxExecutionOutput {
     pub vm: VmExecutionResultAndLogs,
     /// Traced calls if requested.
     pub call_traces: Vec<Call>,
+    /// State touched by the transaction if requested.
+    pub touched_state: Option<TouchedState>,
     /// Execution metrics.
     pub metrics: TransactionExecutionMetrics,
     /// Were published bytecodes OK?
@@ -111,6 +136,7 @@ impl SandboxExecutor {
     ) -> Self {
         let mut executor = MainOneshotExecutor::new(missed_storage_invocation_limit);
         executor.set_fast_vm_mode(options.fast_vm_mode);
+        executor.set_execution_limits(options.execution_limits);
         #[cfg(test)]
         executor.panic_on_divergence();
         executor
@@ -139,6 +165,11 @@ impl SandboxExecutor {
         }
     }
 
+    /// Returns limits on VM execution for calls and gas estimation.
+    pub(crate) fn execution_limits(&self) -> ExecutionLimits {
+        self.options.execution_limits
+    }
+
     /// This method assumes that (block with number `resolved_block_number` is present in DB)
     /// or (`block_id` is `pending` and block with number `resolved_block_number - 1` is present in DB)
     #[allow(clippy::too_many_arguments)]
@@ -174,6 +205,7 @@ impl SandboxExecutor {
         Ok(SandboxExecutionOutput {
             vm: *result.tx_result,
             call_traces: result.call_traces,
+            touched_state: result.touched_state,
             metrics,
             are_published_bytecodes_ok: result.compression_result.is_ok(),
         })
@@ -226,6 +258,12 @@ impl SandboxExecutor {
                     .to_env(&mut connection, resolved_block_info, fee_input, base_fee)
                     .await?
             }
+            &SandboxAction::Replay { fee_input, .. } => {
+                self.options
+                    .eth_call
+                    .to_replay_env(&mut connection, resolved_block_info, fee_input)
+                    .await?
+            }
         };
 
         if block_args.resolves_to_latest_sealed_l2_block() {
@@ -234,14 +272,15 @@ impl SandboxExecutor {
             }
         }
 
-        let mut storage = PostgresStorage::new_async(
-            Handle::current(),
-            connection,
-            resolved_block_info.state_l2_block_number(),
-            false,
-        )
-        .await
-        .context("cannot create `PostgresStorage`")?;
+        let mut state_l2_block_number = resolved_block_info.state_l2_block_number();
+        if matches!(action, SandboxAction::Replay { .. }) {
+            // Preceding transactions from the replayed block are executed by the VM, so the storage must not include them.
+            state_l2_block_number -= 1;
+        }
+        let mut storage =
+            PostgresStorage::new_async(Handle::current(), connection, state_l2_block_number, false)
+                .await
+                .context("cannot create `PostgresStorage`")?;
 
         if let Some(caches) = &self.storage_caches {
             storage = storage.with_caches(caches.clone());
@@ -263,6 +302,7 @@ where
         args: TxExecutionArgs,
         tracing_params: OneshotTracingParams,
     ) -> anyhow::Result<OneshotTransactionExecutionResult> {
+        let span = vm_span(env.system.execution_mode);
         match &self.engine {
             SandboxExecutorEngine::Real(executor) => {
                 executor
@@ -272,6 +312,7 @@ where
                         args,
                         tracing_params,
                     )
+                    .instrument(span)
                     .await
             }
             SandboxExecutorEngine::Mock(executor) => {
@@ -282,6 +323,7 @@ where
                         args,
                         tracing_params,
                     )
+                    .instrument(span)
                     .await
             }
         }
@@ -300,17 +342,29 @@ where
         tx: L2Tx,
         validation_params: ValidationParams,
     ) -> anyhow::Result<Result<ValidationTraces, ValidationError>> {
+        let span = vm_span(env.system.execution_mode);
         match &self.engine {
             SandboxExecutorEngine::Real(executor) => {
                 executor
                     .validate_transaction(storage, env, tx, validation_params)
+                    .instrument(span)
                     .await
             }
             SandboxExecutorEngine::Mock(executor) => {
                 executor
                     .validate_transaction(storage, env, tx, validation_params)
+                    .instrument(span)
                     .await
             }
         }
     }
 }
+
+/// Creates a span for a VM invocation if it's performed as a part of a traced API request.
+fn vm_span(execution_mode: TxExecutionMode) -> tracing::Span {
+    if zksync_vlog::opentelemetry::is_current_span_traced() {
+        tracing::info_span!("vm_execution", mode = ?execution_mode)
+    } else {
+        tracing::Span::none()
+    }
+}
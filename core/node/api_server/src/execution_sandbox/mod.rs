@@ -1,10 +1,12 @@
 use std::{
+    collections::HashMap,
     sync::{Arc, RwLock},
     time::{Duration, Instant},
 };
 
 use anyhow::Context as _;
 use rand::{thread_rng, Rng};
+use vise::{EncodeLabelSet, EncodeLabelValue};
 use zksync_dal::{pruning_dal::PruningInfo, Connection, Core, CoreDal, DalError};
 use zksync_multivm::utils::get_eth_call_gas_limit;
 use zksync_types::{
@@ -38,18 +40,66 @@ pub struct VmPermit {
     _permit: Arc<tokio::sync::OwnedSemaphorePermit>,
 }
 
+/// Class of VM workloads. Each class can be assigned a dedicated pool of VM permits, so that a burst
+/// of heavy requests of one class (e.g., `debug_traceCall`) doesn't starve requests of other classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "workload", rename_all = "snake_case")]
+pub enum VmWorkload {
+    /// `eth_call` and dry-running submitted transactions.
+    Call,
+    /// Gas estimation (`eth_estimateGas` and similar methods).
+    Estimate,
+    /// Tracing calls (`debug_traceCall`).
+    Trace,
+}
+
+/// Dedicated concurrency limits for [`VmWorkload`] classes. Classes without a dedicated limit share
+/// the common pool of VM permits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VmWorkloadLimits {
+    pub call: Option<usize>,
+    pub estimate: Option<usize>,
+    pub trace: Option<usize>,
+}
+
+impl VmWorkloadLimits {
+    fn get(&self, workload: VmWorkload) -> Option<usize> {
+        match workload {
+            VmWorkload::Call => self.call,
+            VmWorkload::Estimate => self.estimate,
+            VmWorkload::Trace => self.trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PermitPool {
+    semaphore: Arc<tokio::sync::Semaphore>,
+    max_concurrency: usize,
+}
+
+impl PermitPool {
+    fn new(max_concurrency: usize) -> Self {
+        Self {
+            semaphore: Arc::new(tokio::sync::Semaphore::new(max_concurrency)),
+            max_concurrency,
+        }
+    }
+}
+
 /// Barrier-like synchronization primitive allowing to close a [`VmConcurrencyLimiter`] it's attached to
 /// so that it doesn't issue new permits, and to wait for all permits to drop.
 #[derive(Debug, Clone)]
 pub struct VmConcurrencyBarrier {
-    limiter: Arc<tokio::sync::Semaphore>,
-    max_concurrency: usize,
+    pools: Vec<PermitPool>,
 }
 
 impl VmConcurrencyBarrier {
     /// Shuts down the related VM concurrency limiter so that it won't issue new permits.
     pub fn close(&self) {
-        self.limiter.close();
+        for pool in &self.pools {
+            pool.semaphore.close();
+        }
         tracing::info!("VM concurrency limiter closed");
     }
 
@@ -58,18 +108,22 @@ impl VmConcurrencyBarrier {
         const POLL_INTERVAL: Duration = Duration::from_millis(50);
 
         assert!(
-            self.limiter.is_closed(),
+            self.pools.iter().all(|pool| pool.semaphore.is_closed()),
             "Cannot wait on non-closed VM concurrency limiter"
         );
 
+        let max_concurrency: usize = self.pools.iter().map(|pool| pool.max_concurrency).sum();
         loop {
-            let current_permits = self.limiter.available_permits();
+            let current_permits: usize = self
+                .pools
+                .iter()
+                .map(|pool| pool.semaphore.available_permits())
+                .sum();
             tracing::debug!(
-                "Waiting until all VM permits are dropped; currently remaining: {} / {}",
-                self.max_concurrency - current_permits,
-                self.max_concurrency
+                "Waiting until all VM permits are dropped; currently remaining: {} / {max_concurrency}",
+                max_concurrency - current_permits
             );
-            if current_permits == self.max_concurrency {
+            if current_permits == max_concurrency {
                 return;
             }
             tokio::time::sleep(POLL_INTERVAL).await;
@@ -83,48 +137,66 @@ impl VmConcurrencyBarrier {
 /// This structure is expected to be used in every method that executes VM code, on a topmost
 /// level (i.e. before any async calls are made or VM is instantiated),
 ///
+/// Permits are issued from independent pools per [`VmWorkload`] class if the class has a dedicated limit,
+/// and from the shared pool otherwise.
+///
 /// Note that the actual limit on the number of VMs is a minimum of the limit in this structure,
 /// *and* the size of the blocking tokio threadpool. So, even if the limit is set to 1024, but
 /// tokio is configured to have no more than 512 blocking threads, the actual limit will be 512.
 #[derive(Debug)]
 pub struct VmConcurrencyLimiter {
-    /// Semaphore that limits the number of concurrent VM executions.
-    limiter: Arc<tokio::sync::Semaphore>,
+    /// Pool used by workloads without a dedicated limit.
+    shared: PermitPool,
+    dedicated: HashMap<VmWorkload, PermitPool>,
 }
 
 impl VmConcurrencyLimiter {
-    /// Creates a limiter together with a barrier allowing to control its shutdown.
+    /// Creates a limiter with a single pool shared by all workloads together with a barrier
+    /// allowing to control its shutdown.
     pub fn new(max_concurrency: usize) -> (Self, VmConcurrencyBarrier) {
+        Self::with_workload_limits(max_concurrency, VmWorkloadLimits::default())
+    }
+
+    /// Creates a limiter with dedicated pools for workloads specified in `workload_limits`.
+    pub fn with_workload_limits(
+        max_concurrency: usize,
+        workload_limits: VmWorkloadLimits,
+    ) -> (Self, VmConcurrencyBarrier) {
         tracing::info!(
-            "Initializing the VM concurrency limiter with max concurrency {max_concurrency}"
+            "Initializing the VM concurrency limiter with max concurrency {max_concurrency} \
+             and dedicated workload limits {workload_limits:?}"
         );
-        let limiter = Arc::new(tokio::sync::Semaphore::new(max_concurrency));
+        let shared = PermitPool::new(max_concurrency);
+        let dedicated: HashMap<_, _> = [VmWorkload::Call, VmWorkload::Estimate, VmWorkload::Trace]
+            .into_iter()
+            .filter_map(|workload| {
+                Some((workload, PermitPool::new(workload_limits.get(workload)?)))
+            })
+            .collect();
 
-        let this = Self {
-            limiter: Arc::clone(&limiter),
-        };
         let barrier = VmConcurrencyBarrier {
-            limiter,
-            max_concurrency,
+            pools: [shared.clone()]
+                .into_iter()
+                .chain(dedicated.values().cloned())
+                .collect(),
         };
-        (this, barrier)
+        (Self { shared, dedicated }, barrier)
     }
 
-    /// Waits until there is a free slot in the concurrency limiter.
+    /// Waits until there is a free slot in the concurrency limiter for the specified workload.
     /// Returns a permit that should be dropped when the VM execution is finished.
-    pub async fn acquire(&self) -> Option<VmPermit> {
-        let available_permits = self.limiter.available_permits();
-        SANDBOX_METRICS
-            .sandbox_execution_permits
-            .observe(available_permits);
+    pub async fn acquire(&self, workload: VmWorkload) -> Option<VmPermit> {
+        let pool = self.dedicated.get(&workload).unwrap_or(&self.shared);
+        let available_permits = pool.semaphore.available_permits();
+        SANDBOX_METRICS.sandbox_execution_permits[&workload].observe(available_permits);
 
         let latency = SANDBOX_METRICS.sandbox[&SandboxStage::VmConcurrencyLimiterAcquire].start();
-        let permit = Arc::clone(&self.limiter).acquire_owned().await.ok()?;
+        let permit = Arc::clone(&pool.semaphore).acquire_owned().await.ok()?;
         let elapsed = latency.observe();
         // We don't want to emit too many logs.
         if elapsed > Duration::from_millis(10) {
             tracing::debug!(
-                "Permit is obtained. Available permits: {available_permits}. Took {elapsed:?}"
+                "Permit is obtained for {workload:?}. Available permits: {available_permits}. Took {elapsed:?}"
             );
         }
 
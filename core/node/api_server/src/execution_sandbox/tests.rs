@@ -191,6 +191,37 @@ async fn creating_block_args_after_snapshot_recovery() {
     }
 }
 
+#[tokio::test]
+async fn vm_concurrency_limiter_with_dedicated_pools() {
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    let workload_limits = VmWorkloadLimits {
+        trace: Some(1),
+        ..VmWorkloadLimits::default()
+    };
+    let (limiter, barrier) = VmConcurrencyLimiter::with_workload_limits(1, workload_limits);
+
+    let trace_permit = limiter.acquire(VmWorkload::Trace).await.unwrap();
+    // The trace pool is exhausted, but it must not influence calls using the shared pool.
+    let call_permit = limiter.acquire(VmWorkload::Call).await.unwrap();
+    tokio::time::timeout(TIMEOUT, limiter.acquire(VmWorkload::Trace))
+        .await
+        .unwrap_err();
+    tokio::time::timeout(TIMEOUT, limiter.acquire(VmWorkload::Estimate))
+        .await
+        .unwrap_err();
+
+    drop(call_permit);
+    let estimate_permit = limiter.acquire(VmWorkload::Estimate).await.unwrap();
+    drop(estimate_permit);
+    drop(trace_permit);
+
+    barrier.close();
+    assert!(limiter.acquire(VmWorkload::Trace).await.is_none());
+    assert!(limiter.acquire(VmWorkload::Call).await.is_none());
+    barrier.wait_until_stopped().await;
+}
+
 #[tokio::test]
 async fn estimating_gas() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -235,7 +266,7 @@ async fn test_instantiating_vm(connection: Connection<'static, Core>, block_args
     let tx = Transaction::from(tx);
 
     let (limiter, _) = VmConcurrencyLimiter::new(1);
-    let vm_permit = limiter.acquire().await.unwrap();
+    let vm_permit = limiter.acquire(VmWorkload::Call).await.unwrap();
     let action = SandboxAction::GasEstimation {
         fee_input,
         base_fee,
@@ -283,7 +314,7 @@ async fn validating_transaction(set_balance: bool) {
     );
 
     let (limiter, _) = VmConcurrencyLimiter::new(1);
-    let vm_permit = limiter.acquire().await.unwrap();
+    let vm_permit = limiter.acquire(VmWorkload::Call).await.unwrap();
     let state_override = if set_balance {
         let account_override = OverrideAccount {
             balance: Some(U256::from(1) << 128),
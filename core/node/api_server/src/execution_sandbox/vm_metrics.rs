@@ -9,6 +9,7 @@ use zksync_multivm::{
 };
 use zksync_types::{bytecode::BytecodeHash, H256};
 
+use super::VmWorkload;
 use crate::utils::ReportFilter;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
@@ -85,7 +86,7 @@ pub(crate) struct SandboxMetrics {
     #[metrics(buckets = Buckets::LATENCIES)]
     pub(super) sandbox: Family<SandboxStage, Histogram<Duration>>,
     #[metrics(buckets = Buckets::linear(0.0..=2_000.0, 200.0))]
-    pub(super) sandbox_execution_permits: Histogram<usize>,
+    pub(super) sandbox_execution_permits: Family<VmWorkload, Histogram<usize>>,
     #[metrics(buckets = Buckets::LATENCIES)]
     submit_tx: Family<SubmitTxStage, Histogram<Duration>>,
 
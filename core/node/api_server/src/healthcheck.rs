@@ -19,6 +19,7 @@ async fn check_health(
 async fn run_server(
     bind_address: &SocketAddr,
     app_health_check: Arc<AppHealthCheck>,
+    effective_config: Option<serde_json::Value>,
     mut stop_receiver: watch::Receiver<bool>,
 ) {
     tracing::debug!(
@@ -26,9 +27,13 @@ async fn run_server(
     );
 
     app_health_check.expose_metrics();
-    let app = Router::new()
+    let mut app = Router::new()
         .route("/health", get(check_health))
         .with_state(app_health_check);
+    if let Some(config) = effective_config {
+        // Allows operators to detect drift between the loaded config and the on-disk config files.
+        app = app.route("/config", get(|| async move { Json(config) }));
+    }
     let listener = tokio::net::TcpListener::bind(bind_address)
         .await
         .unwrap_or_else(|err| panic!("Failed binding healthcheck server to {bind_address}: {err}"));
@@ -51,10 +56,15 @@ pub struct HealthCheckHandle {
 }
 
 impl HealthCheckHandle {
-    pub fn spawn_server(addr: SocketAddr, app_health_check: Arc<AppHealthCheck>) -> Self {
+    /// Spawns the healthcheck server. If `effective_config` is provided, it is served on the `/config` endpoint.
+    pub fn spawn_server(
+        addr: SocketAddr,
+        app_health_check: Arc<AppHealthCheck>,
+        effective_config: Option<serde_json::Value>,
+    ) -> Self {
         let (stop_sender, stop_receiver) = watch::channel(false);
         let server = tokio::spawn(async move {
-            run_server(&addr, app_health_check, stop_receiver).await;
+            run_server(&addr, app_health_check, effective_config, stop_receiver).await;
         });
 
         Self {
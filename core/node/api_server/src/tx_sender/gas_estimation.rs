@@ -1,9 +1,15 @@
-use std::{ops, time::Instant};
+use std::{
+    ops,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context;
 use zksync_dal::CoreDal;
 use zksync_multivm::{
-    interface::{TransactionExecutionMetrics, VmExecutionResultAndLogs},
+    interface::{
+        tracer::InterruptionReason, ExecutionResult, TransactionExecutionMetrics,
+        VmExecutionResultAndLogs,
+    },
     utils::{
         adjust_pubdata_price_for_tx, derive_base_fee_and_gas_per_pubdata, derive_overhead,
         get_max_batch_gas_limit,
@@ -11,12 +17,12 @@ use zksync_multivm::{
 };
 use zksync_system_constants::MAX_L2_TX_GAS_LIMIT;
 use zksync_types::{
-    api::state_override::StateOverride, fee::Fee, fee_model::BatchFeeInput, get_code_key,
-    ExecuteTransactionCommon, PackedEthSignature, ProtocolVersionId, Transaction, H256,
+    api::state_override::StateOverride, fee::Fee, fee_model::BatchFeeInput, get_code_key, l1::L1Tx,
+    ExecuteTransactionCommon, PackedEthSignature, ProtocolVersionId, Transaction, H256, U256,
 };
 
 use super::{result::ApiCallResult, SubmitTxError, TxSender};
-use crate::execution_sandbox::{BlockArgs, SandboxAction, VmPermit, SANDBOX_METRICS};
+use crate::execution_sandbox::{BlockArgs, SandboxAction, VmPermit, VmWorkload, SANDBOX_METRICS};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum BinarySearchKind {
@@ -119,6 +125,50 @@ impl TxSender {
             .await
     }
 
+    /// Executes an L1->L2 transaction once against the pending L2 state without estimating its gas limit.
+    /// If the transaction doesn't specify a gas limit, the maximum gas limit for a batch is used.
+    ///
+    /// Returns the gas limit the transaction was executed with together with the VM output.
+    #[tracing::instrument(level = "debug", skip_all, fields(
+        initiator = ?tx.common_data.sender,
+    ))]
+    pub async fn simulate_l1_tx(
+        &self,
+        tx: L1Tx,
+        block_args: BlockArgs,
+        state_override: Option<StateOverride>,
+    ) -> Result<(u64, VmExecutionResultAndLogs), SubmitTxError> {
+        let requested_gas_limit = tx.common_data.gas_limit;
+        let mut estimator = GasEstimator::new(self, tx.into(), block_args, state_override).await?;
+        estimator.adjust_transaction_fee();
+
+        let gas_limit = estimator.simulation_gas_limit(requested_gas_limit)?;
+        let (result, _) = estimator.unadjusted_step(gas_limit).await?;
+        Ok((gas_limit, result))
+    }
+
+    /// Executes a transaction once against the pending L2 state, so that the circuits tracer
+    /// can predict the number of circuits used by the transaction. If the transaction doesn't specify
+    /// a gas limit, the maximum gas limit for a batch is used.
+    #[tracing::instrument(level = "debug", skip_all, fields(
+        initiator = ?tx.initiator_account(),
+        nonce = ?tx.nonce(),
+    ))]
+    pub async fn estimate_circuits(
+        &self,
+        tx: Transaction,
+        block_args: BlockArgs,
+        state_override: Option<StateOverride>,
+    ) -> Result<VmExecutionResultAndLogs, SubmitTxError> {
+        let requested_gas_limit = tx.gas_limit();
+        let mut estimator = GasEstimator::new(self, tx, block_args, state_override).await?;
+        estimator.adjust_transaction_fee();
+
+        let gas_limit = estimator.simulation_gas_limit(requested_gas_limit)?;
+        let (result, _) = estimator.unadjusted_step(gas_limit).await?;
+        Ok(result)
+    }
+
     async fn binary_search(
         estimator: &GasEstimator<'_>,
         bounds: ops::RangeInclusive<u64>,
@@ -132,7 +182,7 @@ impl TxSender {
         if let Some(pivot) = initial_pivot {
             let iteration_started_at = Instant::now();
             let (result, _) = estimator.step(pivot).await?;
-            Self::adjust_search_bounds(&mut lower_bound, &mut upper_bound, pivot, &result);
+            Self::adjust_search_bounds(&mut lower_bound, &mut upper_bound, pivot, &result)?;
 
             tracing::trace!(
                 "iteration {number_of_iterations} took {:?}. lower_bound: {lower_bound}, upper_bound: {upper_bound}",
@@ -149,7 +199,7 @@ impl TxSender {
             // gas limit will make the transaction successful
             let iteration_started_at = Instant::now();
             let (result, _) = estimator.step(mid).await?;
-            Self::adjust_search_bounds(&mut lower_bound, &mut upper_bound, mid, &result);
+            Self::adjust_search_bounds(&mut lower_bound, &mut upper_bound, mid, &result)?;
 
             tracing::trace!(
                 "iteration {number_of_iterations} took {:?}. lower_bound: {lower_bound}, upper_bound: {upper_bound}",
@@ -210,18 +260,23 @@ impl TxSender {
         Ok(())
     }
 
-    fn adjust_search_bounds(
+    pub(super) fn adjust_search_bounds(
         lower_bound: &mut u64,
         upper_bound: &mut u64,
         pivot: u64,
         result: &VmExecutionResultAndLogs,
-    ) {
+    ) -> Result<(), SubmitTxError> {
+        // Interruption doesn't depend on the gas limit, so increasing it wouldn't help.
+        if let ExecutionResult::Interrupted { reason } = &result.result {
+            return Err(SubmitTxError::ExecutionInterrupted(*reason));
+        }
         // For now, we don't discern between "out of gas" and other failure reasons since it's difficult in the general case.
         if result.result.is_failed() {
             *lower_bound = pivot + 1;
         } else {
             *upper_bound = pivot;
         }
+        Ok(())
     }
 }
 
@@ -302,6 +357,8 @@ pub(super) struct GasEstimator<'a> {
     max_gas_limit: u64,
     block_args: BlockArgs,
     protocol_version: ProtocolVersionId,
+    /// Deadline for the entire estimation derived from the sandbox timeout, together with the timeout itself.
+    deadline: Option<(Instant, Duration)>,
 }
 
 impl<'a> GasEstimator<'a> {
@@ -337,8 +394,20 @@ impl<'a> GasEstimator<'a> {
         }
 
         // Acquire the vm token for the whole duration of the binary search.
-        let vm_permit = sender.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = sender
+            .0
+            .vm_concurrency_limiter
+            .acquire(VmWorkload::Estimate)
+            .await;
         let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
+        // The sandbox timeout applies to each execution separately, so without an overall deadline,
+        // the estimation could take the timeout multiplied by the number of binary search iterations.
+        let deadline = sender
+            .0
+            .executor
+            .execution_limits()
+            .timeout
+            .map(|timeout| (Instant::now() + timeout, timeout));
 
         Ok(Self {
             sender,
@@ -351,6 +420,7 @@ impl<'a> GasEstimator<'a> {
             max_gas_limit,
             block_args,
             protocol_version,
+            deadline,
         })
     }
 
@@ -410,6 +480,18 @@ impl<'a> GasEstimator<'a> {
         }
     }
 
+    /// Returns the gas limit to execute the transaction with a single step; zero `requested_gas_limit`
+    /// is replaced with the maximum gas limit for a batch.
+    fn simulation_gas_limit(&self, requested_gas_limit: U256) -> Result<u64, SubmitTxError> {
+        if requested_gas_limit.is_zero() {
+            return Ok(self.max_gas_limit);
+        }
+        u64::try_from(requested_gas_limit)
+            .ok()
+            .filter(|&limit| limit <= self.max_gas_limit)
+            .ok_or(SubmitTxError::GasLimitIsTooBig)
+    }
+
     /// Derives operator overhead for a transaction given its gas limit.
     fn tx_overhead(&self, tx_gas_limit: u64) -> u64 {
         derive_overhead(
@@ -437,6 +519,14 @@ impl<'a> GasEstimator<'a> {
         &self,
         forced_gas_limit: u64,
     ) -> Result<(VmExecutionResultAndLogs, TransactionExecutionMetrics), SubmitTxError> {
+        if let Some((deadline, timeout)) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(SubmitTxError::ExecutionInterrupted(
+                    InterruptionReason::Timeout(timeout),
+                ));
+            }
+        }
+
         let mut tx = self.transaction.clone();
         match &mut tx.common_data {
             ExecuteTransactionCommon::L1(l1_common_data) => {
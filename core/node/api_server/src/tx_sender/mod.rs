@@ -8,6 +8,7 @@ use zksync_config::configs::{api::Web3JsonRpcConfig, chain::StateKeeperConfig};
 use zksync_dal::{
     transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool, Core, CoreDal,
 };
+pub use zksync_multivm::interface::tracer::ExecutionLimits;
 use zksync_multivm::{
     interface::{
         tracer::TimestampAsserterParams as TracerTimestampAsserterParams, OneshotTracingParams,
@@ -23,6 +24,7 @@ use zksync_state_keeper::{
 };
 use zksync_types::{
     api::state_override::StateOverride,
+    bytecode::{validate_bytecode, BytecodeHash},
     fee_model::BatchFeeInput,
     get_intrinsic_constants, h256_to_u256,
     l2::{error::TxCheckError::TxDuplication, L2Tx},
@@ -40,7 +42,7 @@ pub(super) use self::{gas_estimation::BinarySearchKind, result::SubmitTxError};
 use self::{master_pool_sink::MasterPoolSink, result::ApiCallResult, tx_sink::TxSink};
 use crate::execution_sandbox::{
     BlockArgs, SandboxAction, SandboxExecutor, SubmitTxStage, VmConcurrencyBarrier,
-    VmConcurrencyLimiter, SANDBOX_METRICS,
+    VmConcurrencyLimiter, VmWorkload, SANDBOX_METRICS,
 };
 
 mod gas_estimation;
@@ -93,6 +95,8 @@ pub async fn build_tx_sender(
 #[derive(Debug)]
 pub struct SandboxExecutorOptions {
     pub(crate) fast_vm_mode: FastVmMode,
+    /// Limits on VM execution for calls and gas estimation.
+    pub(crate) execution_limits: ExecutionLimits,
     /// Env parameters to be used when estimating gas.
     pub(crate) estimate_gas: OneshotEnvParameters<EstimateGas>,
     /// Env parameters to be used when performing `eth_call` requests.
@@ -119,6 +123,7 @@ impl SandboxExecutorOptions {
 
         Ok(Self {
             fast_vm_mode: FastVmMode::Old,
+            execution_limits: ExecutionLimits::default(),
             estimate_gas: OneshotEnvParameters::new(
                 Arc::new(estimate_gas_contracts),
                 chain_id,
@@ -139,6 +144,13 @@ impl SandboxExecutorOptions {
         self.fast_vm_mode = fast_vm_mode;
     }
 
+    /// Sets limits on VM cycles and wall-clock time for calls and gas estimation. Execution exceeding the limits
+    /// is interrupted and returns an error to the caller. The timeout bounds the entire gas estimation rather than
+    /// its individual steps. By default, execution is only bounded by the gas limit.
+    pub fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        self.execution_limits = limits;
+    }
+
     pub(crate) async fn mock() -> Self {
         Self::new(L2ChainId::default(), AccountTreeId::default(), u32::MAX)
             .await
@@ -238,6 +250,8 @@ pub struct TxSenderConfig {
     pub gas_price_scale_factor: f64,
     pub max_nonce_ahead: u32,
     pub max_allowed_l2_tx_gas_limit: u64,
+    pub max_l2_tx_size_bytes: Option<usize>,
+    pub max_l2_tx_calldata_bytes: Option<usize>,
     pub vm_execution_cache_misses_limit: Option<usize>,
     pub validation_computational_gas_limit: u32,
     pub chain_id: L2ChainId,
@@ -264,6 +278,8 @@ impl TxSenderConfig {
             gas_price_scale_factor: web3_json_config.gas_price_scale_factor,
             max_nonce_ahead: web3_json_config.max_nonce_ahead,
             max_allowed_l2_tx_gas_limit: state_keeper_config.max_allowed_l2_tx_gas_limit,
+            max_l2_tx_size_bytes: state_keeper_config.max_l2_tx_size_bytes,
+            max_l2_tx_calldata_bytes: state_keeper_config.max_l2_tx_calldata_bytes,
             vm_execution_cache_misses_limit: web3_json_config.vm_execution_cache_misses_limit,
             validation_computational_gas_limit: state_keeper_config
                 .validation_computational_gas_limit,
@@ -325,6 +341,7 @@ impl TxSender {
         let tx_hash = tx.hash();
         let stage_latency = SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::Validate);
         self.validate_tx(&tx, block_args.protocol_version()).await?;
+        self.ensure_tx_fits_batch(&tx).await?;
         stage_latency.observe();
 
         let stage_latency = SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::DryRun);
@@ -337,7 +354,11 @@ impl TxSender {
             .await
             .context("cannot get batch fee input")?;
 
-        let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = self
+            .0
+            .vm_concurrency_limiter
+            .acquire(VmWorkload::Call)
+            .await;
         let action = SandboxAction::Execution {
             fee_input,
             tx: tx.clone(),
@@ -475,6 +496,26 @@ impl TxSender {
             );
             return Err(SubmitTxError::MaxPriorityFeeGreaterThanMaxFee);
         }
+        if let Some(max_size) = self.0.sender_config.max_l2_tx_size_bytes {
+            let tx_size = tx.common_data.input_data().map_or(0, <[u8]>::len);
+            if tx_size > max_size {
+                tracing::info!(
+                    "Submitted Tx is Unexecutable {:?} because of TxSizeTooBig {tx_size}",
+                    tx.hash()
+                );
+                return Err(SubmitTxError::TxSizeTooBig(tx_size, max_size));
+            }
+        }
+        if let Some(max_size) = self.0.sender_config.max_l2_tx_calldata_bytes {
+            let calldata_size = tx.execute.calldata.len();
+            if calldata_size > max_size {
+                tracing::info!(
+                    "Submitted Tx is Unexecutable {:?} because of CalldataSizeTooBig {calldata_size}",
+                    tx.hash()
+                );
+                return Err(SubmitTxError::CalldataSizeTooBig(calldata_size, max_size));
+            }
+        }
         if tx.execute.factory_deps.len() > MAX_NEW_FACTORY_DEPS {
             return Err(SubmitTxError::TooManyFactoryDependencies(
                 tx.execute.factory_deps.len(),
@@ -605,7 +646,11 @@ impl TxSender {
         call: L2Tx,
         state_override: Option<StateOverride>,
     ) -> Result<Vec<u8>, SubmitTxError> {
-        let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = self
+            .0
+            .vm_concurrency_limiter
+            .acquire(VmWorkload::Call)
+            .await;
         let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
 
         let mut connection;
@@ -672,10 +717,58 @@ impl TxSender {
         // still reject them as it's not.
         let protocol_version = ProtocolVersionId::latest();
         let seal_data = SealData::for_transaction(transaction, tx_metrics, protocol_version);
+        self.ensure_seal_data_executable(tx_hash, &seal_data, protocol_version, log_message)
+    }
+
+    /// Rejects a transaction that cannot fit into an L1 batch before executing it. Besides the encoding size,
+    /// this takes into account pubdata necessary to publish factory deps not yet known to the network.
+    async fn ensure_tx_fits_batch(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
+        let min_pubdata = self.estimate_min_pubdata(tx).await?;
+        let transaction = Transaction::from(tx.clone());
+        let seal_data = SealData::for_unexecuted_transaction(&transaction, min_pubdata);
+        // See `ensure_tx_executable()` for why the latest protocol version is used.
+        self.ensure_seal_data_executable(tx.hash(), &seal_data, ProtocolVersionId::latest(), true)
+    }
+
+    /// Returns a lower bound on the pubdata published by the transaction, which can be computed without executing it.
+    async fn estimate_min_pubdata(&self, tx: &L2Tx) -> anyhow::Result<u32> {
+        if tx.execute.factory_deps.is_empty() {
+            return Ok(0);
+        }
+
+        let mut connection = self.acquire_replica_connection().await?;
+        let mut min_pubdata = 0_usize;
+        for bytecode in &tx.execute.factory_deps {
+            // Invalid bytecodes will be rejected during execution.
+            if validate_bytecode(bytecode).is_err() {
+                continue;
+            }
+            let hash = BytecodeHash::for_bytecode(bytecode).value();
+            let is_known = connection
+                .factory_deps_dal()
+                .get_sealed_factory_dep(hash)
+                .await?
+                .is_some();
+            if !is_known {
+                // New bytecodes are published compressed, with each 8-byte chunk replaced by a 2-byte index
+                // into the chunk dictionary. Thus, a compressed bytecode is at least 4 times smaller than the original.
+                min_pubdata += bytecode.len() / 4;
+            }
+        }
+        Ok(u32::try_from(min_pubdata).unwrap_or(u32::MAX))
+    }
+
+    fn ensure_seal_data_executable(
+        &self,
+        tx_hash: H256,
+        seal_data: &SealData,
+        protocol_version: ProtocolVersionId,
+        log_message: bool,
+    ) -> Result<(), SubmitTxError> {
         if let Some(reason) = self
             .0
             .sealer
-            .find_unexecutable_reason(&seal_data, protocol_version)
+            .find_unexecutable_reason(seal_data, protocol_version)
         {
             let message = format!(
                 "Tx is Unexecutable because of {reason}; inputs for decision: {seal_data:?}"
@@ -1,5 +1,8 @@
 use thiserror::Error;
-use zksync_multivm::interface::{ExecutionResult, VmExecutionResultAndLogs};
+use zksync_multivm::interface::{
+    tracer::{InterruptionReason, ValidationError as VmValidationError, ValidationViolationReport},
+    ExecutionResult, VmExecutionResultAndLogs,
+};
 use zksync_types::{l2::error::TxCheckError, U256};
 use zksync_web3_decl::error::EnrichedClientError;
 
@@ -20,6 +23,9 @@ pub enum SubmitTxError {
     NotEnoughBalanceForFeeValue(U256, U256, U256),
     #[error("execution reverted{}{}" , if .0.is_empty() { "" } else { ": " }, .0)]
     ExecutionReverted(String, Vec<u8>),
+    /// Execution was interrupted by the sandbox because it exceeded the configured execution limits.
+    #[error("execution interrupted: {0}")]
+    ExecutionInterrupted(InterruptionReason),
     #[error("exceeds block gas limit")]
     GasLimitIsTooBig,
     #[error("{0}")]
@@ -30,6 +36,10 @@ pub enum SubmitTxError {
     BootloaderFailure(String),
     #[error("failed to validate the transaction. reason: {0}")]
     ValidationFailed(String),
+    /// Same as [`Self::ValidationFailed`], but caused by a violated account validation rule.
+    /// Contains a report on the violation that is returned to the client as the error data.
+    #[error("failed to validate the transaction. reason: {0}")]
+    ValidationRuleViolated(String, Box<ValidationViolationReport>),
     #[error("not enough balance to cover the fee. error message: {0}")]
     FailedToChargeFee(String),
     #[error("failed paymaster validation. error message: {0}")]
@@ -51,6 +61,12 @@ pub enum SubmitTxError {
         "too many factory dependencies in the transaction. {0} provided, while only {1} allowed"
     )]
     TooManyFactoryDependencies(usize, usize),
+    #[error("transaction size exceeds the limit. {0} bytes provided, while only {1} allowed")]
+    TxSizeTooBig(usize, usize),
+    #[error(
+        "transaction calldata size exceeds the limit. {0} bytes provided, while only {1} allowed"
+    )]
+    CalldataSizeTooBig(usize, usize),
     /// IntrinsicGas is returned if the transaction is specified to use less gas
     /// than required to start the invocation.
     #[error("intrinsic gas too low")]
@@ -80,11 +96,12 @@ impl SubmitTxError {
             Self::IncorrectTx(_) => "incorrect-tx",
             Self::NotEnoughBalanceForFeeValue(_, _, _) => "not-enough-balance-for-fee",
             Self::ExecutionReverted(_, _) => "execution-reverted",
+            Self::ExecutionInterrupted(_) => "execution-interrupted",
             Self::GasLimitIsTooBig => "gas-limit-is-too-big",
             Self::Unexecutable(_) => "unexecutable",
             Self::ServerShuttingDown => "shutting-down",
             Self::BootloaderFailure(_) => "bootloader-failure",
-            Self::ValidationFailed(_) => "validation-failed",
+            Self::ValidationFailed(_) | Self::ValidationRuleViolated(..) => "validation-failed",
             Self::FailedToChargeFee(_) => "failed-too-charge-fee",
             Self::PaymasterValidationFailed(_) => "failed-paymaster-validation",
             Self::PrePaymasterPreparationFailed(_) => "failed-prepaymaster-preparation",
@@ -93,6 +110,8 @@ impl SubmitTxError {
             Self::MaxPriorityFeeGreaterThanMaxFee => "max-priority-fee-greater-than-max-fee",
             Self::UnexpectedVMBehavior(_) => "unexpected-vm-behavior",
             Self::TooManyFactoryDependencies(_, _) => "too-many-factory-dependencies",
+            Self::TxSizeTooBig(_, _) => "tx-size-too-big",
+            Self::CalldataSizeTooBig(_, _) => "calldata-size-too-big",
             Self::IntrinsicGas => "intrinsic-gas",
             Self::FailedToPublishCompressedBytecodes => "failed-to-publish-compressed-bytecodes",
             Self::MintedAmountOverflow => "minted-amount-overflow",
@@ -147,6 +166,9 @@ impl From<ValidationError> for SubmitTxError {
     fn from(err: ValidationError) -> Self {
         match err {
             ValidationError::Internal(err) => Self::Internal(err),
+            ValidationError::Vm(VmValidationError::ViolatedRule(ref violation)) => {
+                Self::ValidationRuleViolated(err.to_string(), Box::new(violation.report()))
+            }
             ValidationError::Vm(err) => Self::ValidationFailed(err.to_string()),
         }
     }
@@ -170,6 +192,9 @@ impl ApiCallResult for VmExecutionResultAndLogs {
                 let output: SandboxExecutionError = reason.clone().into();
                 Err(output.into())
             }
+            ExecutionResult::Interrupted { reason } => {
+                Err(SubmitTxError::ExecutionInterrupted(reason.clone()))
+            }
         }
     }
 
@@ -184,6 +209,9 @@ impl ApiCallResult for VmExecutionResultAndLogs {
                 let output: SandboxExecutionError = reason.into();
                 Err(output.into())
             }
+            ExecutionResult::Interrupted { reason } => {
+                Err(SubmitTxError::ExecutionInterrupted(reason))
+            }
         }
     }
 }
@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 use assert_matches::assert_matches;
-use zksync_multivm::interface::ExecutionResult;
+use zksync_multivm::interface::{tracer::InterruptionReason, ExecutionResult};
 use zksync_node_test_utils::create_l2_transaction;
 use zksync_types::{
     api::state_override::OverrideAccount, transaction_request::CallRequest, K256PrivateKey,
@@ -217,6 +217,29 @@ async fn eth_call_out_of_gas() {
     assert_matches!(err, SubmitTxError::ExecutionReverted(..));
 }
 
+#[tokio::test]
+async fn eth_call_interrupted_by_cycle_limit() {
+    let alice = K256PrivateKey::random();
+    let state_override = StateBuilder::default()
+        .with_infinite_loop_contract()
+        .build();
+
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let limits = ExecutionLimits {
+        max_cycles: Some(10_000),
+        timeout: None,
+    };
+    let tx_sender = create_real_tx_sender_with_limits(pool, limits).await;
+    let tx_as_call = alice.create_infinite_loop_tx().into();
+    let err = test_call(&tx_sender, state_override, tx_as_call)
+        .await
+        .unwrap_err();
+    assert_matches!(
+        err,
+        SubmitTxError::ExecutionInterrupted(InterruptionReason::CycleLimit(10_000))
+    );
+}
+
 #[tokio::test]
 async fn eth_call_with_load_test_transactions() {
     let alice = K256PrivateKey::random();
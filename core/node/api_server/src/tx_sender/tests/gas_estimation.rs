@@ -1,9 +1,12 @@
 //! Tests for gas estimation (mostly with the real oneshot VM executor).
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use assert_matches::assert_matches;
 use test_casing::{test_casing, Product};
+use zksync_multivm::interface::{
+    tracer::InterruptionReason, ExecutionResult, VmExecutionResultAndLogs,
+};
 use zksync_system_constants::CODE_ORACLE_ADDRESS;
 use zksync_types::{
     api::state_override::{OverrideAccount, OverrideState},
@@ -96,6 +99,55 @@ async fn initial_gas_estimate_for_l1_transaction() {
     assert!(!vm_result.result.is_failed(), "{:?}", vm_result.result);
 }
 
+#[test_casing(2, [false, true])]
+#[tokio::test]
+async fn simulating_l1_transaction(revert: bool) {
+    let alice = K256PrivateKey::random();
+    let state_override = StateBuilder::default().with_counter_contract(0).build();
+    let tx = alice.create_l1_counter_tx(1.into(), revert);
+
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tx_sender = create_real_tx_sender(pool).await;
+    let block_args = pending_block_args(&tx_sender).await;
+    let (gas_limit, vm_result) = tx_sender
+        .simulate_l1_tx(tx, block_args, Some(state_override))
+        .await
+        .unwrap();
+
+    assert!(gas_limit > 0);
+    assert_eq!(
+        vm_result.result.is_failed(),
+        revert,
+        "{:?}",
+        vm_result.result
+    );
+    assert!(vm_result.refunds.gas_refunded < gas_limit);
+}
+
+#[tokio::test]
+async fn estimating_circuits_for_transaction() {
+    let alice = K256PrivateKey::random();
+    let state_override = StateBuilder::default().with_load_test_contract().build();
+    let tx = alice.create_load_test_tx(LoadnextContractExecutionParams {
+        hashes: 10,
+        ..LoadnextContractExecutionParams::empty()
+    });
+
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tx_sender = create_real_tx_sender(pool).await;
+    let block_args = pending_block_args(&tx_sender).await;
+    let vm_result = tx_sender
+        .estimate_circuits(tx.into(), block_args, Some(state_override))
+        .await
+        .unwrap();
+
+    assert!(!vm_result.result.is_failed(), "{:?}", vm_result.result);
+    let stats = &vm_result.statistics.circuit_statistic;
+    assert!(stats.main_vm > 0.0, "{stats:?}");
+    assert!(stats.keccak256 > 0.0, "{stats:?}");
+    assert!(stats.total() > 0, "{stats:?}");
+}
+
 #[test_casing(2, [false, true])]
 #[tokio::test]
 async fn initial_estimate_for_deep_recursion(with_reads: bool) {
@@ -513,3 +565,75 @@ async fn estimating_gas_for_infinite_loop_tx() {
         assert_matches!(err, SubmitTxError::ExecutionReverted(msg, _) if msg.is_empty());
     }
 }
+
+#[test]
+fn interrupted_execution_does_not_adjust_search_bounds() {
+    let reason = InterruptionReason::Timeout(Duration::from_millis(10));
+    let result = VmExecutionResultAndLogs::mock(ExecutionResult::Interrupted { reason });
+    let (mut lower_bound, mut upper_bound) = (100, 1_000);
+    let err = TxSender::adjust_search_bounds(&mut lower_bound, &mut upper_bound, 500, &result)
+        .unwrap_err();
+
+    assert_matches!(err, SubmitTxError::ExecutionInterrupted(r) if r == reason);
+    assert_eq!((lower_bound, upper_bound), (100, 1_000));
+}
+
+#[tokio::test]
+async fn estimating_gas_for_infinite_loop_tx_with_cycle_limit() {
+    let alice = K256PrivateKey::random();
+    let state_override = StateBuilder::default()
+        .with_infinite_loop_contract()
+        .build();
+
+    let tx = alice.create_infinite_loop_tx();
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let limits = ExecutionLimits {
+        max_cycles: Some(10_000),
+        timeout: None,
+    };
+    let tx_sender = create_real_tx_sender_with_limits(pool, limits).await;
+    let block_args = pending_block_args(&tx_sender).await;
+
+    for binary_search_kind in [BinarySearchKind::Full, BinarySearchKind::Optimized] {
+        let err = tx_sender
+            .get_txs_fee_in_wei(
+                tx.clone().into(),
+                block_args.clone(),
+                1.0,
+                0,
+                Some(state_override.clone()),
+                binary_search_kind,
+            )
+            .await
+            .unwrap_err();
+        assert_matches!(
+            err,
+            SubmitTxError::ExecutionInterrupted(InterruptionReason::CycleLimit(10_000))
+        );
+    }
+}
+
+#[tokio::test]
+async fn timeout_applies_to_entire_gas_estimation() {
+    let alice = K256PrivateKey::random();
+    let state_override = StateBuilder::default().with_counter_contract(0).build();
+    let tx = alice.create_counter_tx(1.into(), false);
+
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let limits = ExecutionLimits {
+        max_cycles: None,
+        timeout: Some(Duration::ZERO),
+    };
+    let tx_sender = create_real_tx_sender_with_limits(pool, limits).await;
+    let block_args = pending_block_args(&tx_sender).await;
+    let estimator = GasEstimator::new(&tx_sender, tx.into(), block_args, Some(state_override))
+        .await
+        .unwrap();
+    // The deadline has already passed, so no further executions should be performed.
+    let err = estimator.unadjusted_step(1_000_000).await.unwrap_err();
+    assert_matches!(
+        err,
+        SubmitTxError::ExecutionInterrupted(InterruptionReason::Timeout(timeout))
+            if timeout.is_zero()
+    );
+}
@@ -138,6 +138,13 @@ async fn getting_nonce_for_account_after_snapshot_recovery() {
 }
 
 async fn create_real_tx_sender(pool: ConnectionPool<Core>) -> TxSender {
+    create_real_tx_sender_with_limits(pool, ExecutionLimits::default()).await
+}
+
+async fn create_real_tx_sender_with_limits(
+    pool: ConnectionPool<Core>,
+    execution_limits: ExecutionLimits,
+) -> TxSender {
     let mut storage = pool.connection().await.unwrap();
     let genesis_params = GenesisParams::mock();
     insert_genesis_batch(&mut storage, &genesis_params)
@@ -154,6 +161,7 @@ async fn create_real_tx_sender(pool: ConnectionPool<Core>) -> TxSender {
     .await
     .unwrap();
     executor_options.set_fast_vm_mode(FastVmMode::Shadow);
+    executor_options.set_execution_limits(execution_limits);
 
     let pg_caches = PostgresStorageCaches::new(1, 1);
     let tx_executor = SandboxExecutor::real(executor_options, pg_caches, usize::MAX, None);
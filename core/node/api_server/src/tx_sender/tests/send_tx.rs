@@ -69,6 +69,82 @@ async fn submitting_tx_requires_one_connection() {
     assert!(storage_tx.timestamp_asserter_range_start.is_none());
 }
 
+#[tokio::test]
+async fn submitting_tx_not_fitting_into_batch() {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l2_chain_id = L2ChainId::default();
+    let fee_input = MockBatchFeeParamsProvider::default()
+        .get_batch_fee_input_scaled(1.0, 1.0)
+        .await
+        .unwrap();
+    let (base_fee, gas_per_pubdata) =
+        derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+    let mut tx = create_l2_transaction(base_fee, gas_per_pubdata);
+    // Even compressed, this bytecode exceeds `max_pubdata_per_batch` from `StateKeeperConfig::for_tests()`.
+    tx.execute.factory_deps = vec![vec![1; 32 * 12_501]];
+
+    StateBuilder::default()
+        .with_balance(tx.initiator_account(), u64::MAX.into())
+        .apply(&mut storage)
+        .await;
+    drop(storage);
+
+    let mut tx_executor = MockOneshotExecutor::default();
+    tx_executor.set_tx_responses(|_, _| panic!("transaction should not be executed"));
+    let tx_executor = SandboxExecutor::mock(tx_executor).await;
+    let (tx_sender, _) = create_test_tx_sender(pool, l2_chain_id, tx_executor).await;
+    let block_args = pending_block_args(&tx_sender).await;
+
+    let err = tx_sender.submit_tx(tx, block_args).await.unwrap_err();
+    assert_matches!(err, SubmitTxError::Unexecutable(_));
+}
+
+#[test_casing(2, [false, true])]
+#[tokio::test]
+async fn submitting_tx_exceeding_size_limits(limit_calldata: bool) {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    drop(storage);
+
+    let l2_chain_id = L2ChainId::default();
+    let fee_input = MockBatchFeeParamsProvider::default()
+        .get_batch_fee_input_scaled(1.0, 1.0)
+        .await
+        .unwrap();
+    let (base_fee, gas_per_pubdata) =
+        derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+    let mut tx = create_l2_transaction(base_fee, gas_per_pubdata);
+    tx.execute.calldata = vec![1; 64];
+
+    let mut tx_executor = MockOneshotExecutor::default();
+    tx_executor.set_tx_responses(|_, _| panic!("transaction should not be executed"));
+    let tx_executor = SandboxExecutor::mock(tx_executor).await;
+    let (mut tx_sender, _) = create_test_tx_sender(pool, l2_chain_id, tx_executor).await;
+    let sender_config = &mut Arc::get_mut(&mut tx_sender.0).unwrap().sender_config;
+    if limit_calldata {
+        sender_config.max_l2_tx_calldata_bytes = Some(32);
+    } else {
+        // `create_l2_transaction()` sets 32-byte input data.
+        sender_config.max_l2_tx_size_bytes = Some(16);
+    }
+    let block_args = pending_block_args(&tx_sender).await;
+
+    let err = tx_sender.submit_tx(tx, block_args).await.unwrap_err();
+    if limit_calldata {
+        assert_matches!(err, SubmitTxError::CalldataSizeTooBig(64, 32));
+    } else {
+        assert_matches!(err, SubmitTxError::TxSizeTooBig(32, 16));
+    }
+}
+
 #[tokio::test]
 async fn nonce_validation_errors() {
     let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
@@ -23,6 +23,7 @@ use tracing::instrument::{Instrument, Instrumented};
 use vise::{
     Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, GaugeGuard, Histogram, Metrics,
 };
+use zksync_vlog::opentelemetry::RemoteTraceContext;
 use zksync_web3_decl::jsonrpsee::{
     server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
     types::{error::ErrorCode, ErrorObject, Request},
@@ -225,14 +226,21 @@ impl<Svc, const TRACE_PARAMS: bool> tower::Layer<Svc> for MetadataLayer<TRACE_PA
 
 /// Middleware that adds tracing spans to each RPC call, so that logs belonging to the same call
 /// can be easily filtered.
+///
+/// Spans are created if extended tracing is enabled, or if the call is a part of an OpenTelemetry trace
+/// propagated by the caller (see [`TraceContextLayer`]); in the latter case, spans are exported as a part of this trace.
 #[derive(Debug)]
 pub(crate) struct CorrelationMiddleware<S> {
     inner: S,
+    extended_tracing: bool,
 }
 
 impl<S> CorrelationMiddleware<S> {
-    pub fn new(inner: S) -> Self {
-        Self { inner }
+    pub fn new(inner: S, extended_tracing: bool) -> Self {
+        Self {
+            inner,
+            extended_tracing,
+        }
     }
 }
 
@@ -247,17 +255,77 @@ where
             static CORRELATION_ID_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
         }
 
+        let is_traced = zksync_vlog::opentelemetry::is_current_span_traced();
+        if !is_traced && !self.extended_tracing {
+            return self.inner.call(request).instrument(tracing::Span::none());
+        }
+
         // Unlike `MetadataMiddleware`, we don't need to extend the method lifetime to `'static`;
         // `tracing` span instantiation allocates a `String` for supplied `&str`s in any case.
         let method = request.method_name();
         // Wrap a call into a span with unique correlation ID, so that events occurring in the span can be easily filtered.
         // This works as a cheap alternative to Open Telemetry tracing with its trace / span IDs.
         let correlation_id = CORRELATION_ID_RNG.with(|rng| rng.borrow_mut().next_u64());
-        let call_span = tracing::debug_span!("rpc_call", method, correlation_id);
+        let call_span = if is_traced {
+            tracing::info_span!("rpc_call", method, correlation_id)
+        } else {
+            tracing::debug_span!("rpc_call", method, correlation_id)
+        };
         self.inner.call(request).instrument(call_span)
     }
 }
 
+/// HTTP middleware continuing traces propagated by callers via W3C `traceparent` / `tracestate` headers.
+///
+/// A request with a valid trace context is processed in an `http_request` span having the remote span as the parent.
+/// This span is exported via OpenTelemetry together with the nested spans for RPC calls, DAL queries and VM executions
+/// performed for the request. Requests without a trace context (or all requests if OpenTelemetry tracing is not configured)
+/// are passed through as is.
+///
+/// Must only be used for the HTTP transport; for WebSockets, the span would cover the entire connection.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TraceContextLayer;
+
+impl<S> tower::Layer<S> for TraceContextLayer {
+    type Service = TraceContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceContextService { inner }
+    }
+}
+
+/// Service produced by [`TraceContextLayer`].
+#[derive(Debug, Clone)]
+pub(crate) struct TraceContextService<S> {
+    inner: S,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for TraceContextService<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Instrumented<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let headers = request.headers();
+        let remote_context = RemoteTraceContext::extract(|name| headers.get(name)?.to_str().ok());
+        let span = if let Some(remote_context) = remote_context {
+            let span = tracing::info_span!("http_request", otel.kind = "server");
+            remote_context.set_as_parent(&span);
+            span
+        } else {
+            tracing::Span::none()
+        };
+        span.in_scope(|| self.inner.call(request)).instrument(span)
+    }
+}
+
 /// Tracks the timestamp of the last call to the RPC. Used during server shutdown to start dropping new traffic
 /// only after this is coordinated by the external load balancer.
 #[derive(Debug, Clone, Default)]
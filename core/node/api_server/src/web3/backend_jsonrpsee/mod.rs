@@ -10,7 +10,8 @@ use zksync_web3_decl::{
 pub(crate) use self::{
     metadata::{MethodMetadata, MethodTracer},
     middleware::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware, TrafficTracker,
+        CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware,
+        TraceContextLayer, TrafficTracker,
     },
 };
 use crate::tx_sender::SubmitTxError;
@@ -25,9 +26,12 @@ impl MethodTracer {
     pub(crate) fn map_err(&self, err: Web3Error) -> ErrorObjectOwned {
         self.observe_error(&err);
 
-        let data = match &err {
-            Web3Error::SubmitTransactionError(_, data) => Some(format!("0x{}", hex::encode(data))),
-            Web3Error::ProxyError(_) => Some("0x".to_owned()),
+        let data: Option<serde_json::Value> = match &err {
+            Web3Error::SubmitTransactionError(_, data) => {
+                Some(format!("0x{}", hex::encode(data)).into())
+            }
+            Web3Error::ValidationRuleViolated(_, report) => Some(report.clone()),
+            Web3Error::ProxyError(_) => Some("0x".into()),
             _ => None,
         };
         let code = match err {
@@ -39,8 +43,11 @@ impl MethodTracer {
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
+            | Web3Error::UnsupportedTracer(_)
+            | Web3Error::InvalidRewardPercentiles(_)
             | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
             Web3Error::SubmitTransactionError(_, _)
+            | Web3Error::ValidationRuleViolated(_, _)
             | Web3Error::SerializationError(_)
             | Web3Error::ProxyError(_) => 3,
             Web3Error::TreeApiUnavailable => 6,
@@ -49,7 +56,8 @@ impl MethodTracer {
             // Do not expose internal error details to the client.
             Web3Error::InternalError(_) => "Internal error".to_owned(),
             Web3Error::ProxyError(err) => err.as_ref().to_string(),
-            Web3Error::SubmitTransactionError(message, _) => message,
+            Web3Error::SubmitTransactionError(message, _)
+            | Web3Error::ValidationRuleViolated(message, _) => message,
             _ => err.to_string(),
         };
 
@@ -62,6 +70,10 @@ impl From<SubmitTxError> for Web3Error {
         match err {
             SubmitTxError::Internal(err) => Self::InternalError(err),
             SubmitTxError::ProxyError(err) => Self::ProxyError(err),
+            SubmitTxError::ValidationRuleViolated(_, ref report) => {
+                let report = serde_json::to_value(report).expect("failed serializing report");
+                Self::ValidationRuleViolated(err.to_string(), report)
+            }
             _ => Self::SubmitTransactionError(err.to_string(), err.data()),
         }
     }
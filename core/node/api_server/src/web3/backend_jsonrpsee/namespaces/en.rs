@@ -37,6 +37,12 @@ impl EnNamespaceServer for EnNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn consensus_status(&self) -> RpcResult<Option<en::ConsensusStatus>> {
+        self.consensus_status_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn block_metadata(
         &self,
         block_number: L2BlockNumber,
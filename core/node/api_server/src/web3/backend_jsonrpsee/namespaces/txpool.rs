@@ -0,0 +1,25 @@
+use zksync_types::{
+    api::{TxpoolAccountContent, TxpoolContent, TxpoolOrderingPolicy, TxpoolStatus},
+    Address,
+};
+use zksync_web3_decl::{jsonrpsee::core::RpcResult, namespaces::TxpoolNamespaceServer};
+
+use crate::web3::namespaces::TxpoolNamespace;
+
+impl TxpoolNamespaceServer for TxpoolNamespace {
+    fn status(&self) -> RpcResult<TxpoolStatus> {
+        Ok(self.status_impl())
+    }
+
+    fn content(&self) -> RpcResult<TxpoolContent> {
+        Ok(self.content_impl())
+    }
+
+    fn content_from(&self, address: Address) -> RpcResult<TxpoolAccountContent> {
+        Ok(self.content_from_impl(address))
+    }
+
+    fn ordering_policy(&self) -> RpcResult<TxpoolOrderingPolicy> {
+        Ok(self.ordering_policy_impl())
+    }
+}
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 
-use zksync_multivm::interface::VmEvent;
+use zksync_multivm::interface::{ExecutionResult, VmEvent};
 use zksync_types::{
     api::{
-        state_override::StateOverride, ApiStorageLog, BlockDetails, BridgeAddresses,
-        L1BatchDetails, L2ToL1LogProof, Log, Proof, ProtocolVersion, TransactionDetailedResult,
-        TransactionDetails,
+        state_override::StateOverride, ApiStorageLog, BlockDetails, BridgeAddresses, ChainFeatures,
+        CircuitsEstimate, ConfirmedTokensPage, ConfirmedTokensQuery, DecodedTransaction,
+        L1BatchDetails, L1BatchReport, L1ToL2TxSimulationResult, L2ToL1LogProof, Log, NodeInfo,
+        NonceDetails, Proof, ProofLatencyStats, ProtocolVersion, TokenPrice,
+        TransactionDetailedResult, TransactionDetails, TransactionProof,
+        TransactionsBySelectorFilter, TransactionsBySelectorPage,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -42,6 +45,48 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn simulate_l1_to_l2_transaction(
+        &self,
+        req: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<L1ToL2TxSimulationResult> {
+        self.simulate_l1_to_l2_transaction_impl(req, state_override)
+            .await
+            .map(|(gas_limit, result)| {
+                let revert_reason = match &result.result {
+                    ExecutionResult::Success { .. } => None,
+                    ExecutionResult::Revert { output } => Some(output.to_user_friendly_string()),
+                    ExecutionResult::Halt { reason } => Some(reason.to_string()),
+                    ExecutionResult::Interrupted { reason } => Some(reason.to_string()),
+                };
+                L1ToL2TxSimulationResult {
+                    success: revert_reason.is_none(),
+                    revert_reason,
+                    gas_used: gas_limit.saturating_sub(result.refunds.gas_refunded).into(),
+                    pubdata_used: result.statistics.pubdata_published.into(),
+                    storage_logs: result
+                        .logs
+                        .storage_logs
+                        .iter()
+                        .filter(|x| x.log.is_write())
+                        .map(ApiStorageLog::from)
+                        .collect(),
+                    events: result.logs.events.iter().map(map_event).collect(),
+                }
+            })
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn estimate_circuits(
+        &self,
+        req: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<CircuitsEstimate> {
+        self.estimate_circuits_impl(req, state_override)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_bridgehub_contract(&self) -> RpcResult<Option<Address>> {
         Ok(self.get_bridgehub_contract_impl())
     }
@@ -72,6 +117,21 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_confirmed_tokens_page(
+        &self,
+        query: Option<ConfirmedTokensQuery>,
+    ) -> RpcResult<ConfirmedTokensPage> {
+        self.get_confirmed_tokens_page_impl(query.unwrap_or_default())
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_nonce_details(&self, address: Address) -> RpcResult<NonceDetails> {
+        self.get_nonce_details_impl(address)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_all_account_balances(
         &self,
         address: Address,
@@ -148,6 +208,15 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_batch_report(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> RpcResult<Option<L1BatchReport>> {
+        self.get_batch_report_impl(batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>> {
         self.get_bytecode_by_hash_impl(hash)
             .await
@@ -166,12 +235,33 @@ impl ZksNamespaceServer for ZksNamespace {
         Ok(self.get_fee_params_impl())
     }
 
+    async fn get_batch_fee_params(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> RpcResult<Option<FeeParams>> {
+        self.get_batch_fee_params_impl(batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_batch_fee_input(&self) -> RpcResult<PubdataIndependentBatchFeeModelInput> {
         self.get_batch_fee_input_impl()
             .await
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_node_info(&self) -> RpcResult<NodeInfo> {
+        self.get_node_info_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_chain_features(&self) -> RpcResult<ChainFeatures> {
+        self.get_chain_features_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_protocol_version(
         &self,
         version_id: Option<u16>,
@@ -192,6 +282,12 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_transaction_proof(&self, tx_hash: H256) -> RpcResult<Option<TransactionProof>> {
+        self.get_transaction_proof_impl(tx_hash)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_base_token_l1_address(&self) -> RpcResult<Address> {
         self.get_base_token_l1_address_impl()
             .map_err(|err| self.current_method().map_err(err))
@@ -227,6 +323,37 @@ impl ZksNamespaceServer for ZksNamespace {
             })
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn get_token_price(&self, token: Address) -> RpcResult<Option<TokenPrice>> {
+        self.get_token_price_impl(token)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_decoded_transaction(&self, hash: H256) -> RpcResult<Option<DecodedTransaction>> {
+        self.get_decoded_transaction_impl(hash)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_transactions_by_selector(
+        &self,
+        filter: TransactionsBySelectorFilter,
+    ) -> RpcResult<TransactionsBySelectorPage> {
+        self.get_transactions_by_selector_impl(filter)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_proof_latency_stats(
+        &self,
+        from_l1_batch: L1BatchNumber,
+        to_l1_batch: Option<L1BatchNumber>,
+    ) -> RpcResult<ProofLatencyStats> {
+        self.get_proof_latency_stats_impl(from_l1_batch, to_l1_batch)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
 
 fn map_event(vm_event: &VmEvent) -> Log {
@@ -244,5 +371,6 @@ fn map_event(vm_event: &VmEvent) -> Log {
         log_type: None,
         removed: Some(false),
         block_timestamp: None,
+        decoded_args: None,
     }
 }
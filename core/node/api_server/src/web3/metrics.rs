@@ -170,6 +170,8 @@ enum Web3ErrorKind {
     FilterNotFound,
     LogsLimitExceeded,
     InvalidFilterBlockHash,
+    UnsupportedTracer,
+    InvalidRewardPercentiles,
     TreeApiUnavailable,
     Internal,
 }
@@ -179,13 +181,17 @@ impl Web3ErrorKind {
         match err {
             Web3Error::NoBlock => Self::NoBlock,
             Web3Error::PrunedBlock(_) | Web3Error::PrunedL1Batch(_) => Self::Pruned,
-            Web3Error::SubmitTransactionError(..) => Self::SubmitTransaction,
+            Web3Error::SubmitTransactionError(..) | Web3Error::ValidationRuleViolated(..) => {
+                Self::SubmitTransaction
+            }
             Web3Error::ProxyError(_) => Self::Proxy,
             Web3Error::SerializationError(_) => Self::TransactionSerialization,
             Web3Error::TooManyTopics => Self::TooManyTopics,
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::UnsupportedTracer(_) => Self::UnsupportedTracer,
+            Web3Error::InvalidRewardPercentiles(_) => Self::InvalidRewardPercentiles,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
@@ -421,6 +427,7 @@ pub enum SubscriptionType {
     Blocks,
     Txs,
     Logs,
+    L1Batches,
 }
 
 #[derive(Debug, Metrics)]
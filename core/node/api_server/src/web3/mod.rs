@@ -11,9 +11,11 @@ use tokio::{
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
 use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
+use zksync_external_price_api::token_price::TokenPriceCache;
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_sync::SyncState;
+use zksync_state_keeper::MempoolGuard;
 use zksync_types::L2BlockNumber;
 use zksync_web3_decl::{
     jsonrpsee::{
@@ -24,8 +26,8 @@ use zksync_web3_decl::{
     },
     namespaces::{
         DebugNamespaceServer, EnNamespaceServer, EthNamespaceServer, EthPubSubServer,
-        NetNamespaceServer, SnapshotsNamespaceServer, UnstableNamespaceServer, Web3NamespaceServer,
-        ZksNamespaceServer,
+        NetNamespaceServer, SnapshotsNamespaceServer, TxpoolNamespaceServer,
+        UnstableNamespaceServer, Web3NamespaceServer, ZksNamespaceServer, ZksPubSubServer,
     },
     types::Filter,
 };
@@ -33,13 +35,13 @@ use zksync_web3_decl::{
 use self::{
     backend_jsonrpsee::{
         CorrelationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
-        TrafficTracker,
+        TraceContextLayer, TrafficTracker,
     },
     mempool_cache::MempoolCache,
     metrics::API_METRICS,
     namespaces::{
         DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotsNamespace,
-        UnstableNamespace, Web3Namespace, ZksNamespace,
+        TxpoolNamespace, UnstableNamespace, Web3Namespace, ZksNamespace,
     },
     pubsub::{EthSubscribe, EthSubscriptionIdProvider, PubSubEvent},
     state::{Filters, InternalApiConfig, RpcState, SealedL2BlockNumber},
@@ -101,6 +103,7 @@ pub enum Namespace {
     Pubsub,
     Snapshots,
     Unstable,
+    Txpool,
 }
 
 impl Namespace {
@@ -135,6 +138,8 @@ struct OptionalApiParams {
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
+    mempool: Option<MempoolGuard>,
+    token_price_cache: Option<Arc<TokenPriceCache>>,
     extended_tracing: bool,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
@@ -275,6 +280,17 @@ impl ApiBuilder {
         self
     }
 
+    /// Sets the mempool used by the state keeper. Required for the `txpool` namespace.
+    pub fn with_mempool(mut self, mempool: MempoolGuard) -> Self {
+        self.optional.mempool = Some(mempool);
+        self
+    }
+
+    pub fn with_token_price_cache(mut self, cache: Arc<TokenPriceCache>) -> Self {
+        self.optional.token_price_cache = Some(cache);
+        self
+    }
+
     pub fn with_extended_tracing(mut self, extended_tracing: bool) -> Self {
         self.optional.extended_tracing = extended_tracing;
         self
@@ -379,6 +395,7 @@ impl ApiServer {
             last_sealed_l2_block: self.sealed_l2_block_handle,
             bridge_addresses_handle: self.bridge_addresses_handle,
             tree_api: self.optional.tree_api,
+            token_price_cache: self.optional.token_price_cache,
         })
     }
 
@@ -388,13 +405,16 @@ impl ApiServer {
     ) -> anyhow::Result<RpcModule<()>> {
         let namespaces = self.namespaces.clone();
         let zksync_network_id = self.config.l2_chain_id;
+        let mempool = self.optional.mempool.clone();
         let rpc_state = self.build_rpc_state().await?;
 
         // Collect all the methods into a single RPC module.
         let mut rpc = RpcModule::new(());
         if let Some(pub_sub) = pub_sub {
-            rpc.merge(pub_sub.into_rpc())
+            rpc.merge(EthPubSubServer::into_rpc(pub_sub.clone()))
                 .context("cannot merge eth pubsub namespace")?;
+            rpc.merge(ZksPubSubServer::into_rpc(pub_sub))
+                .context("cannot merge zks pubsub namespace")?;
         }
 
         if namespaces.contains(&Namespace::Debug) {
@@ -425,6 +445,14 @@ impl ApiServer {
             rpc.merge(SnapshotsNamespace::new(rpc_state.clone()).into_rpc())
                 .context("cannot merge snapshots namespace")?;
         }
+        if namespaces.contains(&Namespace::Txpool) {
+            let mempool = mempool.context(
+                "txpool namespace requires the state keeper mempool; it's only available if the state keeper \
+                 runs in the same process as the API server",
+            )?;
+            rpc.merge(TxpoolNamespace::new(mempool).into_rpc())
+                .context("cannot merge txpool namespace")?;
+        }
         if namespaces.contains(&Namespace::Unstable) {
             rpc.merge(UnstableNamespace::new(rpc_state).into_rpc())
                 .context("cannot merge unstable namespace")?;
@@ -651,7 +679,12 @@ impl ApiServer {
                 .allow_methods([http::Method::POST])
                 // Allow requests from any origin
                 .allow_origin(tower_http::cors::Any)
-                .allow_headers([http::header::CONTENT_TYPE])
+                .allow_headers([
+                    http::header::CONTENT_TYPE,
+                    // W3C trace context headers (see `TraceContextLayer`)
+                    http::HeaderName::from_static("traceparent"),
+                    http::HeaderName::from_static("tracestate"),
+                ])
         });
         // Setup metrics for the number of in-flight requests.
         let (in_flight_requests, counter) = InFlightRequestsLayer::pair();
@@ -664,7 +697,8 @@ impl ApiServer {
         // Assemble server middleware.
         let middleware = tower::ServiceBuilder::new()
             .layer(in_flight_requests)
-            .option_layer(cors);
+            .option_layer(cors)
+            .option_layer(is_http.then_some(TraceContextLayer));
 
         // Settings shared by HTTP and WS servers.
         let max_connections = !is_http
@@ -688,9 +722,7 @@ impl ApiServer {
                 ShutdownMiddleware::new(svc, traffic_tracker_for_middleware.clone())
             })
             // We want to output method logs with a correlation ID; hence, `CorrelationMiddleware` must precede `metadata_layer`.
-            .option_layer(
-                extended_tracing.then(|| tower::layer::layer_fn(CorrelationMiddleware::new)),
-            )
+            .layer_fn(move |svc| CorrelationMiddleware::new(svc, extended_tracing))
             .layer(metadata_layer)
             // We want to capture limit middleware errors with `metadata_layer`; hence, `LimitMiddleware` is placed after it.
             .option_layer((!is_http).then(|| {
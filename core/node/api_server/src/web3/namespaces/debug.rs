@@ -1,21 +1,29 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use anyhow::Context as _;
 use zksync_dal::{CoreDal, DalError};
-use zksync_multivm::interface::{Call, CallType, ExecutionResult, OneshotTracingParams};
+use zksync_multivm::interface::{
+    Call, CallType, ExecutionResult, OneshotTracingParams, TouchedState,
+};
 use zksync_system_constants::MAX_ENCODED_TX_SIZE;
 use zksync_types::{
     api::{
-        BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, DebugCall, DebugCallType,
-        ResultDebugCall, SupportedTracers, TracerConfig,
+        BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, ContractGasUsage, DebugCall,
+        DebugCallType, GasProfile, MuxTracerConfig, MuxTracerResult, PrestateAccount,
+        PrestateTracerResult, ResultDebugCall, SupportedTracers, TracerConfig,
     },
+    bytecode::{trim_padded_evm_bytecode, BytecodeMarker},
     debug_flat_call::{Action, CallResult, CallTraceMeta, DebugCallFlat, ResultDebugCallFlat},
+    get_code_key, get_nonce_key, h256_to_u256,
     l2::L2Tx,
     transaction_request::CallRequest,
-    web3, H256, U256,
+    utils::{decompose_full_nonce, storage_key_for_eth_balance},
+    web3, Address, L2BlockNumber, StorageKey, H256, U256,
 };
 use zksync_web3_decl::error::Web3Error;
 
 use crate::{
-    execution_sandbox::SandboxAction,
+    execution_sandbox::{SandboxAction, VmWorkload},
     web3::{backend_jsonrpsee::MethodTracer, state::RpcState},
 };
 
@@ -33,26 +41,220 @@ impl DebugNamespace {
         call: Call,
         meta: CallTraceMeta,
         tracer_option: TracerConfig,
+        touched_state: Option<TouchedState>,
     ) -> CallTracerResult {
         match tracer_option.tracer {
             SupportedTracers::CallTracer => CallTracerResult::CallTrace(Self::map_default_call(
                 call,
                 tracer_option.tracer_config.only_top_call,
             )),
-            SupportedTracers::FlatCallTracer => {
-                let mut calls = vec![];
-                let mut traces = vec![meta.index_in_block];
-                Self::flatten_call(
-                    call,
-                    &mut calls,
-                    &mut traces,
-                    tracer_option.tracer_config.only_top_call,
-                    &meta,
-                );
-                CallTracerResult::FlatCallTrace(calls)
+            SupportedTracers::FlatCallTracer => CallTracerResult::FlatCallTrace(
+                Self::map_flat_call(call, &meta, tracer_option.tracer_config.only_top_call),
+            ),
+            SupportedTracers::PrestateTracer => {
+                CallTracerResult::PrestateTrace(Self::map_prestate(
+                    touched_state.unwrap_or_default(),
+                    tracer_option.tracer_config.diff_mode,
+                ))
+            }
+            SupportedTracers::MuxTracer => CallTracerResult::MuxTrace(Self::map_mux_call(
+                call,
+                &meta,
+                tracer_option.mux_tracer_config.unwrap_or_default(),
+                touched_state,
+            )),
+        }
+    }
+
+    /// Returns tracing params necessary to produce output for the specified tracer.
+    fn tracing_params(tracer_option: &TracerConfig) -> OneshotTracingParams {
+        match tracer_option.tracer {
+            SupportedTracers::CallTracer | SupportedTracers::FlatCallTracer => {
+                OneshotTracingParams {
+                    trace_calls: !tracer_option.tracer_config.only_top_call,
+                    trace_state: false,
+                }
+            }
+            // Calls are traced to include all callees into the touched accounts.
+            SupportedTracers::PrestateTracer => OneshotTracingParams {
+                trace_calls: true,
+                trace_state: true,
+            },
+            SupportedTracers::MuxTracer => {
+                let config = tracer_option.mux_tracer_config.unwrap_or_default();
+                let traces_nested_calls = [config.call_tracer, config.flat_call_tracer]
+                    .into_iter()
+                    .flatten()
+                    .any(|config| !config.only_top_call);
+                let traces_state = config.prestate_tracer.is_some();
+                OneshotTracingParams {
+                    // The gas profiler and the prestate tracer need the entire call tree.
+                    trace_calls: traces_nested_calls
+                        || traces_state
+                        || config.gas_profiler.is_some(),
+                    trace_state: traces_state,
+                }
+            }
+        }
+    }
+
+    fn map_flat_call(call: Call, meta: &CallTraceMeta, only_top_call: bool) -> Vec<DebugCallFlat> {
+        let mut calls = vec![];
+        let mut traces = vec![meta.index_in_block];
+        Self::flatten_call(call, &mut calls, &mut traces, only_top_call, meta);
+        calls
+    }
+
+    /// Maps the output of a single execution to outputs of all tracers combined by the mux tracer.
+    /// `touched_state` must be provided if the prestate tracer is requested.
+    fn map_mux_call(
+        call: Call,
+        meta: &CallTraceMeta,
+        config: MuxTracerConfig,
+        touched_state: Option<TouchedState>,
+    ) -> MuxTracerResult {
+        MuxTracerResult {
+            gas_profiler: config.gas_profiler.map(|_| Self::profile_gas(&call)),
+            prestate_tracer: config
+                .prestate_tracer
+                .zip(touched_state)
+                .map(|(config, storage)| Self::map_prestate(storage, config.diff_mode)),
+            flat_call_tracer: config
+                .flat_call_tracer
+                .map(|config| Self::map_flat_call(call.clone(), meta, config.only_top_call)),
+            call_tracer: config
+                .call_tracer
+                .map(|config| Self::map_default_call(call, config.only_top_call)),
+        }
+    }
+
+    fn profile_gas(call: &Call) -> GasProfile {
+        let mut contracts = HashMap::new();
+        Self::collect_gas_usage(call, &mut contracts);
+        let mut contracts: Vec<_> = contracts.into_values().collect();
+        contracts.sort_unstable_by(|a, b| {
+            b.self_gas_used
+                .cmp(&a.self_gas_used)
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        GasProfile {
+            gas_used: U256::from(call.gas_used),
+            contracts,
+        }
+    }
+
+    fn collect_gas_usage(call: &Call, contracts: &mut HashMap<Address, ContractGasUsage>) {
+        let nested_gas_used: u64 = call.calls.iter().map(|call| call.gas_used).sum();
+        let usage = contracts
+            .entry(call.to)
+            .or_insert_with(|| ContractGasUsage {
+                address: call.to,
+                ..ContractGasUsage::default()
+            });
+        usage.calls += 1;
+        usage.self_gas_used += U256::from(call.gas_used.saturating_sub(nested_gas_used));
+        for call in &call.calls {
+            Self::collect_gas_usage(call, contracts);
+        }
+    }
+
+    /// Maps touched state to the prestate tracer output. Balances, nonces and bytecode hashes are stored in system contracts;
+    /// they are reported as fields of the corresponding accounts rather than as system contract storage.
+    pub(crate) fn map_prestate(state: TouchedState, diff_mode: bool) -> PrestateTracerResult {
+        let account_keys: HashSet<_> = state
+            .accounts
+            .iter()
+            .flat_map(|address| {
+                [
+                    storage_key_for_eth_balance(address),
+                    get_nonce_key(address),
+                    get_code_key(address),
+                ]
+            })
+            .collect();
+        let read_account = |values: &HashMap<StorageKey, H256>, address: &Address| {
+            let read = |key: StorageKey| {
+                values
+                    .get(&key)
+                    .or_else(|| state.initial_values.get(&key))
+                    .copied()
+                    .unwrap_or_default()
+            };
+            let (nonce, _) = decompose_full_nonce(h256_to_u256(read(get_nonce_key(address))));
+            PrestateAccount {
+                balance: Some(h256_to_u256(read(storage_key_for_eth_balance(address)))),
+                nonce: Some(nonce.low_u64()),
+                code: Self::prepare_bytecode(read(get_code_key(address)), &state.bytecodes),
+                storage: BTreeMap::new(),
+            }
+        };
+
+        if !diff_mode {
+            let mut prestate: BTreeMap<_, _> = state
+                .accounts
+                .iter()
+                .map(|address| (*address, read_account(&state.initial_values, address)))
+                .collect();
+            for (key, value) in &state.initial_values {
+                if account_keys.contains(key) {
+                    continue;
+                }
+                if let Some(account) = prestate.get_mut(key.address()) {
+                    account.storage.insert(*key.key(), *value);
+                }
+            }
+            return PrestateTracerResult::Prestate(prestate);
+        }
+
+        let mut modified_storage = HashMap::<_, (BTreeMap<_, _>, BTreeMap<_, _>)>::new();
+        for (key, &final_value) in &state.final_values {
+            let initial_value = state.initial_values.get(key).copied().unwrap_or_default();
+            if initial_value != final_value && !account_keys.contains(key) {
+                let (pre_storage, post_storage) =
+                    modified_storage.entry(*key.address()).or_default();
+                pre_storage.insert(*key.key(), initial_value);
+                post_storage.insert(*key.key(), final_value);
+            }
+        }
+
+        let mut pre = BTreeMap::new();
+        let mut post = BTreeMap::new();
+        for address in &state.accounts {
+            let mut pre_account = read_account(&state.initial_values, address);
+            let mut post_account = read_account(&state.final_values, address);
+            if let Some((pre_storage, post_storage)) = modified_storage.remove(address) {
+                pre_account.storage = pre_storage;
+                post_account.storage = post_storage;
+            }
+            // Only changed fields are included into the post-state.
+            if post_account.balance == pre_account.balance {
+                post_account.balance = None;
+            }
+            if post_account.nonce == pre_account.nonce {
+                post_account.nonce = None;
+            }
+            if post_account.code == pre_account.code {
+                post_account.code = None;
+            }
+            if post_account != PrestateAccount::default() {
+                pre.insert(*address, pre_account);
+                post.insert(*address, post_account);
             }
         }
+        PrestateTracerResult::Diff { pre, post }
+    }
+
+    /// Prepares bytecode in the same way as `eth_getCode`. Malformed EVM bytecodes are returned as is.
+    fn prepare_bytecode(hash: H256, bytecodes: &HashMap<H256, Vec<u8>>) -> Option<web3::Bytes> {
+        let bytecode = bytecodes.get(&hash)?.as_slice();
+        let bytecode = if BytecodeMarker::new(hash) == Some(BytecodeMarker::Evm) {
+            trim_padded_evm_bytecode(bytecode).unwrap_or(bytecode)
+        } else {
+            bytecode
+        };
+        Some(bytecode.to_vec().into())
     }
+
     pub(crate) fn map_default_call(call: Call, only_top_call: bool) -> DebugCall {
         let calls = if only_top_call {
             vec![]
@@ -173,24 +375,22 @@ impl DebugNamespace {
             SupportedTracers::FlatCallTracer => {
                 let res = call_traces
                     .into_iter()
-                    .map(|(call, meta)| {
-                        let mut traces = vec![meta.index_in_block];
-                        let mut flat_calls = vec![];
-                        Self::flatten_call(
+                    .map(|(call, meta)| ResultDebugCallFlat {
+                        tx_hash: meta.tx_hash,
+                        result: Self::map_flat_call(
                             call,
-                            &mut flat_calls,
-                            &mut traces,
-                            options.tracer_config.only_top_call,
                             &meta,
-                        );
-                        ResultDebugCallFlat {
-                            tx_hash: meta.tx_hash,
-                            result: flat_calls,
-                        }
+                            options.tracer_config.only_top_call,
+                        ),
                     })
                     .collect();
                 CallTracerBlockResult::FlatCallTrace(res)
             }
+            SupportedTracers::PrestateTracer | SupportedTracers::MuxTracer => {
+                return Err(Web3Error::UnsupportedTracer(
+                    "only `callTracer` and `flatCallTracer` are supported for block tracing",
+                ));
+            }
         };
         Ok(result)
     }
@@ -200,15 +400,133 @@ impl DebugNamespace {
         tx_hash: H256,
         options: Option<TracerConfig>,
     ) -> Result<Option<CallTracerResult>, Web3Error> {
+        let options = options.unwrap_or_default();
+        let tracing_params = Self::tracing_params(&options);
+        // Traces for executed transactions are taken from the storage, but storage slots touched by a transaction
+        // cannot be recovered from them, so the transaction is replayed instead.
+        if tracing_params.trace_state {
+            return self
+                .replay_transaction(tx_hash, options, tracing_params)
+                .await;
+        }
+
         let mut connection = self.state.acquire_connection().await?;
         let call_trace = connection
             .transactions_dal()
             .get_call_trace(tx_hash)
             .await
             .map_err(DalError::generalize)?;
-        Ok(call_trace.map(|(call_trace, meta)| {
-            Self::map_call(call_trace, meta, options.unwrap_or_default())
-        }))
+        Ok(call_trace.map(|(call_trace, meta)| Self::map_call(call_trace, meta, options, None)))
+    }
+
+    /// Replays an executed transaction on top of the state it was applied to, i.e. after all preceding transactions
+    /// in its L2 block.
+    async fn replay_transaction(
+        &self,
+        tx_hash: H256,
+        options: TracerConfig,
+        tracing_params: OneshotTracingParams,
+    ) -> Result<Option<CallTracerResult>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let chain_id = self.state.api_config.l2_chain_id;
+        let Some(api_tx) = connection
+            .transactions_web3_dal()
+            .get_transaction_by_hash(tx_hash, chain_id)
+            .await
+            .map_err(DalError::generalize)?
+        else {
+            return Ok(None);
+        };
+        let (Some(block_number), Some(block_hash)) = (api_tx.block_number, api_tx.block_hash)
+        else {
+            // The transaction is not executed yet.
+            return Ok(None);
+        };
+        let block_number = L2BlockNumber(block_number.as_u32());
+        let block_id = BlockId::Number(BlockNumber::Number(block_number.0.into()));
+        self.current_method().set_block_id(block_id);
+        self.current_method()
+            .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
+
+        // The state before the block must be available as well.
+        self.state
+            .start_info
+            .ensure_not_pruned(block_number - 1, &mut connection)
+            .await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        let fee_input = block_args.historical_fee_input(&mut connection).await?;
+
+        let mut block_txs = connection
+            .transactions_web3_dal()
+            .get_raw_l2_block_transactions(block_number)
+            .await
+            .map_err(DalError::generalize)?;
+        let index_in_block = block_txs
+            .iter()
+            .position(|tx| tx.hash() == tx_hash)
+            .with_context(|| {
+                format!("transaction {tx_hash:?} is missing in L2 block #{block_number}")
+            })?;
+        let tx = block_txs.remove(index_in_block);
+        block_txs.truncate(index_in_block);
+        drop(connection);
+
+        let vm_permit = self
+            .state
+            .tx_sender
+            .vm_concurrency_limiter()
+            .acquire(VmWorkload::Trace)
+            .await;
+        let vm_permit = vm_permit.context("cannot acquire VM permit")?;
+
+        let connection = self.state.acquire_connection().await?;
+        let executor = &self.state.tx_sender.0.executor;
+        let result = executor
+            .execute_in_sandbox(
+                vm_permit,
+                connection,
+                SandboxAction::Replay {
+                    tx: tx.clone(),
+                    preceding_txs: block_txs,
+                    fee_input,
+                    tracing_params,
+                },
+                &block_args,
+                None,
+            )
+            .await?;
+
+        let (output, revert_reason) = match result.vm.result {
+            ExecutionResult::Success { output, .. } => (output, None),
+            ExecutionResult::Revert { output } => (vec![], Some(output.to_string())),
+            // Unlike for calls, halted transactions are still included into blocks.
+            ExecutionResult::Halt { reason } => (vec![], Some(reason.to_string())),
+            ExecutionResult::Interrupted { reason } => (vec![], Some(reason.to_string())),
+        };
+        let call = Call::new_high_level(
+            tx.gas_limit().as_u64(),
+            result.vm.statistics.gas_used,
+            tx.execute.value,
+            tx.execute.calldata,
+            output,
+            revert_reason,
+            result.call_traces,
+        );
+        let meta = CallTraceMeta {
+            index_in_block,
+            tx_hash,
+            block_number: block_number.0,
+            block_hash,
+        };
+        Ok(Some(Self::map_call(
+            call,
+            meta,
+            options,
+            result.touched_state,
+        )))
     }
 
     pub async fn debug_trace_call_impl(
@@ -263,14 +581,12 @@ impl DebugNamespace {
             .state
             .tx_sender
             .vm_concurrency_limiter()
-            .acquire()
+            .acquire(VmWorkload::Trace)
             .await;
         let vm_permit = vm_permit.context("cannot acquire VM permit")?;
 
         // We don't need properly trace if we only need top call
-        let tracing_params = OneshotTracingParams {
-            trace_calls: !options.tracer_config.only_top_call,
-        };
+        let tracing_params = Self::tracing_params(&options);
 
         let connection = self.state.acquire_connection().await?;
         let executor = &self.state.tx_sender.0.executor;
@@ -298,6 +614,12 @@ impl DebugNamespace {
                     vec![],
                 ))
             }
+            ExecutionResult::Interrupted { reason } => {
+                return Err(Web3Error::SubmitTransactionError(
+                    reason.to_string(),
+                    vec![],
+                ))
+            }
         };
         let call = Call::new_high_level(
             call.common_data.fee.gas_limit.as_u64(),
@@ -314,6 +636,6 @@ impl DebugNamespace {
             // It's a call request, it's safe to everything as default
             ..Default::default()
         };
-        Ok(Self::map_call(call, meta, options))
+        Ok(Self::map_call(call, meta, options, result.touched_state))
     }
 }
@@ -1,6 +1,8 @@
 use anyhow::Context as _;
 use zksync_config::{configs::EcosystemContracts, GenesisConfig};
-use zksync_consensus_roles::validator;
+use zksync_consensus_crypto::TextFmt as _;
+use zksync_consensus_roles::{attester, validator};
+use zksync_consensus_storage::Last;
 use zksync_dal::{CoreDal, DalError};
 use zksync_types::{
     api::en, protocol_version::ProtocolSemanticVersion, tokens::TokenInfo, Address, L1BatchNumber,
@@ -87,6 +89,100 @@ impl EnNamespace {
         )))
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn consensus_status_impl(&self) -> Result<Option<en::ConsensusStatus>, Web3Error> {
+        let mut conn = self
+            .state
+            .acquire_connection()
+            .await?
+            // unwrap is ok, because we start outermost transaction.
+            .transaction_builder()
+            .unwrap()
+            // run readonly transaction to perform consistent reads.
+            .set_readonly()
+            .build()
+            .await
+            .context("TransactionBuilder::build()")?;
+        let Some(cfg) = conn
+            .consensus_dal()
+            .global_config()
+            .await
+            .context("global_config()")?
+        else {
+            return Ok(None);
+        };
+        let replica_state = conn
+            .consensus_dal()
+            .replica_state()
+            .await
+            .map_err(DalError::generalize)?;
+        let last_certificate = match conn
+            .consensus_dal()
+            .block_store_state()
+            .await
+            .context("block_store_state()")?
+            .last
+        {
+            Some(Last::Final(qc)) => Some(qc),
+            Some(Last::PreGenesis(_)) | None => None,
+        };
+        let last_certified_batch = conn
+            .consensus_dal()
+            .last_batch_certificate_number()
+            .await
+            .context("last_batch_certificate_number()")?;
+        let next_batch_to_attest = conn
+            .consensus_dal()
+            .attestation_status()
+            .await
+            .context("attestation_status()")?
+            .map(|status| status.next_batch_to_attest);
+        let attesters = match next_batch_to_attest {
+            Some(batch) => conn
+                .consensus_dal()
+                .attester_committee(batch)
+                .await
+                .context("attester_committee()")?,
+            None => None,
+        };
+
+        let last_certified_block = last_certificate
+            .as_ref()
+            .map(|qc| qc.header().number.0.try_into().map(L2BlockNumber))
+            .transpose()
+            .context("overflow")?;
+        let to_batch_number = |n: attester::BatchNumber| -> anyhow::Result<L1BatchNumber> {
+            Ok(L1BatchNumber(n.0.try_into().context("overflow")?))
+        };
+
+        Ok(Some(en::ConsensusStatus {
+            view_number: replica_state.view.0,
+            phase: format!("{:?}", replica_state.phase),
+            leader: cfg.genesis.view_leader(replica_state.view).encode(),
+            last_certified_block,
+            last_certificate_view: last_certificate.as_ref().map(|qc| qc.message.view.number.0),
+            validators: cfg
+                .genesis
+                .validators
+                .iter()
+                .map(|v| en::ConsensusCommitteeMember {
+                    key: v.key.encode(),
+                    weight: v.weight,
+                })
+                .collect(),
+            last_certified_batch: last_certified_batch.map(to_batch_number).transpose()?,
+            next_batch_to_attest: next_batch_to_attest.map(to_batch_number).transpose()?,
+            attesters: attesters
+                .iter()
+                .flat_map(|committee| committee.iter())
+                .map(|a| en::ConsensusCommitteeMember {
+                    key: a.key.encode(),
+                    weight: a.weight,
+                })
+                .collect(),
+        }))
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn block_metadata_impl(
         &self,
@@ -222,6 +318,7 @@ impl EnNamespace {
                 .state
                 .api_config
                 .l1_batch_commit_data_generator_mode,
+            custom_system_contracts: self.state.api_config.custom_system_contracts.clone(),
         };
         Ok(config)
     }
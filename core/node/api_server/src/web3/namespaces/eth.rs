@@ -1,9 +1,10 @@
 use anyhow::Context as _;
-use zksync_dal::{CoreDal, DalError};
+use itertools::Itertools;
+use zksync_dal::{blocks_web3_dal::TransactionPriorityFee, CoreDal, DalError};
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockId, BlockNumber, FeeHistory, GetLogsFilter,
+        state_override::StateOverride, BlockId, BlockNumber, DecodedLog, FeeHistory, GetLogsFilter,
         Transaction, TransactionId, TransactionReceipt, TransactionVariant,
     },
     bytecode::{trim_padded_evm_bytecode, BytecodeMarker},
@@ -27,6 +28,8 @@ use crate::{
 };
 
 pub const EVENT_TOPIC_NUMBER_LIMIT: usize = 4;
+/// Maximum number of reward percentiles accepted by `eth_feeHistory`.
+pub const REWARD_PERCENTILES_LIMIT: usize = 100;
 pub const PROTOCOL_VERSION: &str = "zks/1";
 
 #[derive(Debug)]
@@ -651,7 +654,8 @@ impl EthNamespace {
         drop(connection);
         let (mut tx, hash) = self
             .state
-            .parse_transaction_bytes(&tx_bytes.0, &block_args)?;
+            .parse_transaction_bytes(&tx_bytes.0, &block_args)
+            .await?;
         tx.set_input(tx_bytes.0, hash);
 
         let submit_result = self.state.tx_sender.submit_tx(tx, block_args).await;
@@ -692,6 +696,7 @@ impl EthNamespace {
     ) -> Result<FeeHistory, Web3Error> {
         self.current_method()
             .set_block_id(BlockId::Number(newest_block));
+        validate_reward_percentiles(&reward_percentiles)?;
 
         // Limit `block_count`.
         let block_count = block_count.clamp(1, self.state.api_config.fee_history_limit);
@@ -716,11 +721,21 @@ impl EthNamespace {
         let oldest_block = newest_l2_block.0 + 1 - base_fee_per_gas.len() as u32;
         // We do not store gas used ratio for blocks, returns array of zeroes as a placeholder.
         let gas_used_ratio = vec![0.0; base_fee_per_gas.len()];
-        // Effective priority gas price is currently 0.
-        let reward = Some(vec![
-            vec![U256::zero(); reward_percentiles.len()];
-            base_fee_per_gas.len()
-        ]);
+
+        let mut reward = vec![vec![U256::zero(); reward_percentiles.len()]; base_fee_per_gas.len()];
+        if !reward_percentiles.is_empty() {
+            let priority_fees = connection
+                .blocks_web3_dal()
+                .get_transaction_priority_fees(L2BlockNumber(oldest_block)..=newest_l2_block)
+                .await
+                .map_err(DalError::generalize)?;
+            // Fees are ordered by the L2 block number, so they can be grouped in a single pass.
+            let fees_by_block = priority_fees.iter().group_by(|fee| fee.l2_block_number);
+            for (l2_block_number, block_fees) in &fees_by_block {
+                let i = (l2_block_number.0 - oldest_block) as usize;
+                reward[i] = compute_block_rewards(block_fees, &reward_percentiles);
+            }
+        }
 
         // `base_fee_per_gas` for next L2 block cannot be calculated, appending last fee as a placeholder.
         base_fee_per_gas.push(*base_fee_per_gas.last().unwrap());
@@ -734,7 +749,7 @@ impl EthNamespace {
                 oldest_block: web3::BlockNumber::Number(oldest_block.into()),
                 base_fee_per_gas,
                 gas_used_ratio,
-                reward,
+                reward: Some(reward),
                 base_fee_per_blob_gas,
                 blob_gas_used_ratio,
             },
@@ -802,7 +817,7 @@ impl EthNamespace {
                 } else {
                     vec![]
                 };
-                let topics = if let Some(topics) = &filter.topics {
+                let mut topics = if let Some(topics) = &filter.topics {
                     if topics.len() > EVENT_TOPIC_NUMBER_LIMIT {
                         return Err(Web3Error::TooManyTopics);
                     }
@@ -813,6 +828,13 @@ impl EthNamespace {
                 } else {
                     vec![]
                 };
+                // If the first topic is not constrained, set it to the event signature so that most non-matching logs
+                // are filtered out on the DB level. Remaining logs are filtered out when decoding.
+                if let Some(event) = &filter.event {
+                    if !event.anonymous && !topics.iter().any(|(idx, _)| *idx == 1) {
+                        topics.push((1, vec![event.signature()]));
+                    }
+                }
 
                 let mut to_block = self
                     .state
@@ -856,11 +878,20 @@ impl EthNamespace {
                     }
                 }
 
-                let logs = storage
+                let mut logs = storage
                     .events_web3_dal()
                     .get_logs(get_logs_filter, i32::MAX as usize)
                     .await
                     .map_err(DalError::generalize)?;
+                if let Some(event) = &filter.event {
+                    logs = logs
+                        .into_iter()
+                        .filter_map(|mut log| {
+                            log.decoded_args = Some(DecodedLog::decode(event, &log)?.params);
+                            Some(log)
+                        })
+                        .collect();
+                }
                 *from_block = to_block + 1;
                 FilterChanges::Logs(logs)
             }
@@ -912,3 +943,51 @@ impl EthNamespace {
     // - `compile_solidity`.
     // - `compile_serpent`.
 }
+
+fn validate_reward_percentiles(percentiles: &[f32]) -> Result<(), Web3Error> {
+    if percentiles.len() > REWARD_PERCENTILES_LIMIT {
+        return Err(Web3Error::InvalidRewardPercentiles("too many percentiles"));
+    }
+    if percentiles
+        .iter()
+        .any(|percentile| !(0.0..=100.0).contains(percentile))
+    {
+        return Err(Web3Error::InvalidRewardPercentiles(
+            "percentiles must be in [0, 100]",
+        ));
+    }
+    if percentiles.windows(2).any(|window| window[0] > window[1]) {
+        return Err(Web3Error::InvalidRewardPercentiles(
+            "percentiles must be in non-decreasing order",
+        ));
+    }
+    Ok(())
+}
+
+/// Computes rewards for an L2 block as priority fees at the specified percentiles, with transactions weighted
+/// by the gas they have used. This follows the reference implementation in Geth.
+fn compute_block_rewards<'a>(
+    fees: impl Iterator<Item = &'a TransactionPriorityFee>,
+    percentiles: &[f32],
+) -> Vec<U256> {
+    let mut fees: Vec<_> = fees.collect();
+    if fees.is_empty() {
+        return vec![U256::zero(); percentiles.len()];
+    }
+    fees.sort_unstable_by_key(|fee| fee.priority_fee_per_gas);
+    let total_gas_used: u64 = fees.iter().map(|fee| fee.gas_used).sum();
+
+    let mut tx_index = 0;
+    let mut cumulative_gas_used = fees[0].gas_used;
+    percentiles
+        .iter()
+        .map(|&percentile| {
+            let threshold = (total_gas_used as f64 * f64::from(percentile) / 100.0) as u64;
+            while cumulative_gas_used < threshold && tx_index < fees.len() - 1 {
+                tx_index += 1;
+                cumulative_gas_used += fees[tx_index].gas_used;
+            }
+            fees[tx_index].priority_fee_per_gas
+        })
+        .collect()
+}
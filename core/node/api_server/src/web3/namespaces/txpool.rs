@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use zksync_state_keeper::MempoolGuard;
+use zksync_types::{
+    api::{self, TxpoolAccountContent, TxpoolContent, TxpoolOrderingPolicy, TxpoolStatus},
+    l2::L2Tx,
+    Address,
+};
+
+/// `txpool` namespace exposing the in-memory state of the mempool used by the state keeper. Unlike other namespaces,
+/// it doesn't query Postgres, so it's only available if the state keeper runs in the same process as the API server.
+#[derive(Debug, Clone)]
+pub(crate) struct TxpoolNamespace {
+    mempool: MempoolGuard,
+}
+
+fn to_api_transactions(txs: Vec<L2Tx>) -> BTreeMap<u64, api::Transaction> {
+    txs.into_iter()
+        .map(|tx| (tx.nonce().0.into(), tx.into()))
+        .collect()
+}
+
+impl TxpoolNamespace {
+    pub fn new(mempool: MempoolGuard) -> Self {
+        Self { mempool }
+    }
+
+    pub fn status_impl(&self) -> TxpoolStatus {
+        let (pending, queued) = self.mempool.pending_and_queued_counts();
+        TxpoolStatus {
+            pending: pending.into(),
+            queued: queued.into(),
+        }
+    }
+
+    pub fn content_impl(&self) -> TxpoolContent {
+        let mut content = TxpoolContent::default();
+        for (account, account_contents) in self.mempool.contents() {
+            if !account_contents.pending.is_empty() {
+                let pending = to_api_transactions(account_contents.pending);
+                content.pending.insert(account, pending);
+            }
+            if !account_contents.queued.is_empty() {
+                let queued = to_api_transactions(account_contents.queued);
+                content.queued.insert(account, queued);
+            }
+        }
+        content
+    }
+
+    pub fn content_from_impl(&self, address: Address) -> TxpoolAccountContent {
+        let account_contents = self.mempool.account_contents(address);
+        TxpoolAccountContent {
+            pending: to_api_transactions(account_contents.pending),
+            queued: to_api_transactions(account_contents.queued),
+        }
+    }
+
+    pub fn ordering_policy_impl(&self) -> TxpoolOrderingPolicy {
+        let policy = self.mempool.ordering_policy();
+        TxpoolOrderingPolicy {
+            name: policy.name().to_owned(),
+            strict: policy.is_strict(),
+        }
+    }
+}
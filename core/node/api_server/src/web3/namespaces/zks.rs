@@ -4,14 +4,25 @@ use anyhow::Context as _;
 use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_metadata_calculator::api_server::TreeApiError;
 use zksync_mini_merkle_tree::MiniMerkleTree;
-use zksync_multivm::interface::VmExecutionResultAndLogs;
-use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
+use zksync_multivm::{
+    interface::{CircuitStatistic, ExecutionResult, VmExecutionResultAndLogs},
+    utils::get_max_batch_base_layer_circuits,
+};
+use zksync_system_constants::{
+    DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE, SHARED_BRIDGE_ETHER_TOKEN_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
+    SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION, SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES,
+};
 use zksync_types::{
     address_to_h256,
     api::{
-        state_override::StateOverride, BlockDetails, BridgeAddresses, GetLogsFilter,
-        L1BatchDetails, L2ToL1LogProof, Proof, ProtocolVersion, StorageProof, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BlockId, BlockNumber, BridgeAddresses,
+        ChainFeatures, CircuitsEstimate, CircuitsUsage, ConfirmedToken, ConfirmedTokensPage,
+        ConfirmedTokensQuery, DecodedTransaction, GetLogsFilter, L1BatchDetails, L1BatchReport,
+        L2ToL1LogProof, NodeInfo, NodePruningInfo, NodeSyncStatus, NonceDetails, Proof,
+        ProofLatencyStats, ProtocolVersion, StorageProof, TokenPrice, TransactionDetails,
+        TransactionProof, TransactionsBySelectorFilter, TransactionsBySelectorPage,
     },
+    commitment::L1BatchCommitmentMode,
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
     h256_to_u256,
@@ -20,10 +31,12 @@ use zksync_types::{
     l2_to_l1_log::{l2_to_l1_logs_tree_size, L2ToL1Log},
     tokens::ETHEREUM_ADDRESS,
     transaction_request::CallRequest,
-    utils::storage_key_for_standard_token_balance,
-    web3::Bytes,
-    AccountTreeId, L1BatchNumber, L2BlockNumber, ProtocolVersionId, StorageKey, Transaction,
-    L1_MESSENGER_ADDRESS, L2_BASE_TOKEN_ADDRESS, REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256, U64,
+    u256_to_h256,
+    utils::{decompose_full_nonce, storage_key_for_standard_token_balance},
+    web3::{keccak256_concat, Bytes},
+    AccountTreeId, L1BatchNumber, L2BlockNumber, ProtocolVersionId, SLChainId, StorageKey,
+    Transaction, L1_MESSENGER_ADDRESS, L2_BASE_TOKEN_ADDRESS,
+    REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256, U64,
 };
 use zksync_web3_decl::{
     error::Web3Error,
@@ -37,6 +50,72 @@ use crate::{
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, RpcState},
 };
 
+/// Latest `AccountAbstractionVersion` supported by the `ContractDeployer` system contract.
+const SUPPORTED_AA_VERSION: u8 = 1;
+/// Number of tokens returned by `zks_getConfirmedTokensPage` if the limit is not specified.
+const DEFAULT_CONFIRMED_TOKENS_PAGE_SIZE: usize = 100;
+/// Maximum number of tokens returned by `zks_getConfirmedTokensPage`.
+const MAX_CONFIRMED_TOKENS_PAGE_SIZE: usize = 1_000;
+/// Number of transactions returned by `zks_getTransactionsBySelector` if the limit is not specified.
+const DEFAULT_TRANSACTIONS_BY_SELECTOR_PAGE_SIZE: usize = 100;
+/// Maximum number of transactions returned by `zks_getTransactionsBySelector`.
+const MAX_TRANSACTIONS_BY_SELECTOR_PAGE_SIZE: usize = 1_000;
+/// Maximum number of L1 batches covered by `zks_getProofLatencyStats`.
+const MAX_PROOF_LATENCY_STATS_L1_BATCHES: u32 = 10_000;
+
+/// ETH is specified as the base token either by the special shared bridge address or (in legacy configs) by the zero address.
+fn is_eth_base_token(address: Address) -> bool {
+    address == SHARED_BRIDGE_ETHER_TOKEN_ADDRESS || address == ETHEREUM_ADDRESS
+}
+
+fn circuits_usage(statistic: &CircuitStatistic) -> CircuitsUsage {
+    CircuitsUsage {
+        main_vm: statistic.main_vm,
+        ram_permutation: statistic.ram_permutation,
+        storage_application: statistic.storage_application,
+        storage_sorter: statistic.storage_sorter,
+        code_decommitter: statistic.code_decommitter,
+        code_decommitter_sorter: statistic.code_decommitter_sorter,
+        log_demuxer: statistic.log_demuxer,
+        events_sorter: statistic.events_sorter,
+        keccak256: statistic.keccak256,
+        ecrecover: statistic.ecrecover,
+        sha256: statistic.sha256,
+        secp256k1_verify: statistic.secp256k1_verify,
+        transient_storage_checker: statistic.transient_storage_checker,
+    }
+}
+
+fn circuits_estimate(
+    result: &VmExecutionResultAndLogs,
+    protocol_version: ProtocolVersionId,
+) -> CircuitsEstimate {
+    let revert_reason = match &result.result {
+        ExecutionResult::Success { .. } => None,
+        ExecutionResult::Revert { output } => Some(output.to_user_friendly_string()),
+        ExecutionResult::Halt { reason } => Some(reason.to_string()),
+        ExecutionResult::Interrupted { reason } => Some(reason.to_string()),
+    };
+    let statistic = &result.statistics.circuit_statistic;
+    let total_circuits = statistic.total();
+    let max_batch_circuits = get_max_batch_base_layer_circuits(protocol_version.into());
+    CircuitsEstimate {
+        success: revert_reason.is_none(),
+        revert_reason,
+        circuits: circuits_usage(statistic),
+        total_circuits: total_circuits.into(),
+        max_batch_circuits: max_batch_circuits.into(),
+        batch_capacity_fraction: total_circuits as f64 / max_batch_circuits as f64,
+    }
+}
+
+/// Returns the key of the `SystemContext` storage slot holding the hash of the specified L2 block.
+fn l2_block_hash_storage_key(l2_block_number: L2BlockNumber) -> H256 {
+    let position = h256_to_u256(SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION)
+        + U256::from(l2_block_number.0 % SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES);
+    u256_to_h256(position)
+}
+
 #[derive(Debug)]
 pub(crate) struct ZksNamespace {
     state: RpcState,
@@ -111,6 +190,66 @@ impl ZksNamespace {
         Ok(fee.gas_limit)
     }
 
+    pub async fn simulate_l1_to_l2_transaction_impl(
+        &self,
+        request: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> Result<(u64, VmExecutionResultAndLogs), Web3Error> {
+        let mut request_with_gas_per_pubdata_overridden = request;
+        if let Some(ref mut eip712_meta) = request_with_gas_per_pubdata_overridden.eip712_meta {
+            if eip712_meta.gas_per_pubdata == U256::zero() {
+                eip712_meta.gas_per_pubdata = REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into();
+            }
+        }
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        drop(connection);
+        let tx = L1Tx::from_request(
+            request_with_gas_per_pubdata_overridden,
+            block_args.use_evm_emulator(),
+        )
+        .map_err(Web3Error::SerializationError)?;
+
+        Ok(self
+            .state
+            .tx_sender
+            .simulate_l1_tx(tx, block_args, state_override)
+            .await?)
+    }
+
+    pub async fn estimate_circuits_impl(
+        &self,
+        request: CallRequest,
+        state_override: Option<StateOverride>,
+    ) -> Result<CircuitsEstimate, Web3Error> {
+        let mut request_with_gas_per_pubdata_overridden = request;
+        self.state
+            .set_nonce_for_call_request(&mut request_with_gas_per_pubdata_overridden)
+            .await?;
+        if let Some(ref mut eip712_meta) = request_with_gas_per_pubdata_overridden.eip712_meta {
+            eip712_meta.gas_per_pubdata = U256::from(DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE);
+        }
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        drop(connection);
+        let protocol_version = block_args.protocol_version();
+        let mut tx = L2Tx::from_request(
+            request_with_gas_per_pubdata_overridden.into(),
+            self.state.api_config.max_tx_size,
+            block_args.use_evm_emulator(),
+        )?;
+        tx.common_data.fee.gas_per_pubdata_limit = U256::from(DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE);
+
+        let result = self
+            .state
+            .tx_sender
+            .estimate_circuits(tx.into(), block_args, state_override)
+            .await?;
+        Ok(circuits_estimate(&result, protocol_version))
+    }
+
     async fn estimate_fee(
         &self,
         tx: Transaction,
@@ -187,6 +326,99 @@ impl ZksNamespace {
         Ok(tokens)
     }
 
+    pub async fn get_confirmed_tokens_page_impl(
+        &self,
+        query: ConfirmedTokensQuery,
+    ) -> Result<ConfirmedTokensPage, Web3Error> {
+        let limit = query
+            .limit
+            .map_or(DEFAULT_CONFIRMED_TOKENS_PAGE_SIZE, usize::from)
+            .clamp(1, MAX_CONFIRMED_TOKENS_PAGE_SIZE);
+        let mut storage = self.state.acquire_connection().await?;
+        let page = storage
+            .tokens_web3_dal()
+            .get_well_known_tokens_page(query.cursor, query.origin, query.updated_since, limit)
+            .await
+            .map_err(DalError::generalize)?;
+
+        let tokens = page
+            .tokens
+            .into_iter()
+            .map(|token| ConfirmedToken {
+                l1_address: token.info.l1_address,
+                l2_address: token.info.l2_address,
+                name: token.info.metadata.name,
+                symbol: token.info.metadata.symbol,
+                decimals: token.info.metadata.decimals,
+                origin: token.origin,
+                metadata_updated_at: token.updated_at,
+            })
+            .collect();
+        Ok(ConfirmedTokensPage {
+            tokens,
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    pub async fn get_nonce_details_impl(
+        &self,
+        address: Address,
+    ) -> Result<NonceDetails, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let block_number = self
+            .state
+            .resolve_block(&mut connection, BlockId::Number(BlockNumber::Latest))
+            .await?;
+        let full_nonce = connection
+            .storage_web3_dal()
+            .get_address_historical_nonce(address, block_number)
+            .await
+            .map_err(DalError::generalize)?;
+        let (executed_nonce, _) = decompose_full_nonce(full_nonce);
+        let executed_nonce = u64::try_from(executed_nonce)
+            .map_err(|err| anyhow::anyhow!("nonce conversion failed: {err}"))?;
+
+        let pending_nonces = connection
+            .transactions_web3_dal()
+            .get_non_rejected_nonces(address, executed_nonce)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(connection);
+
+        // The sink may have a more up-to-date view of the mempool (e.g., on external nodes).
+        let mempool_nonce = self
+            .state
+            .tx_sink()
+            .lookup_pending_nonce(address, executed_nonce as u32)
+            .await?;
+        let mempool_nonce = match mempool_nonce {
+            Some(nonce) => nonce.0.into(),
+            None => {
+                // Mempool nonce is the first gap in nonces.
+                let mut next_nonce = executed_nonce;
+                for &nonce in &pending_nonces {
+                    if nonce == next_nonce {
+                        next_nonce += 1;
+                    } else {
+                        break;
+                    }
+                }
+                next_nonce
+            }
+        };
+
+        let max_nonce = pending_nonces.last().copied().unwrap_or(executed_nonce);
+        let nonce_gaps = (executed_nonce..max_nonce)
+            .filter(|nonce| pending_nonces.binary_search(nonce).is_err())
+            .collect();
+        Ok(NonceDetails {
+            executed_nonce,
+            mempool_nonce,
+            pending_nonces,
+            nonce_gaps,
+        })
+    }
+
     pub async fn get_all_account_balances_impl(
         &self,
         address: Address,
@@ -474,6 +706,23 @@ impl ZksNamespace {
             .map_err(DalError::generalize)?)
     }
 
+    pub async fn get_batch_report_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<L1BatchReport>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(batch_number, &mut storage)
+            .await?;
+
+        Ok(storage
+            .blocks_web3_dal()
+            .get_l1_batch_report(batch_number)
+            .await
+            .map_err(DalError::generalize)?)
+    }
+
     pub async fn get_bytecode_by_hash_impl(
         &self,
         hash: H256,
@@ -495,6 +744,89 @@ impl ZksNamespace {
             .get_fee_model_params()
     }
 
+    pub async fn get_batch_fee_params_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<FeeParams>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(batch_number, &mut storage)
+            .await?;
+
+        Ok(storage
+            .blocks_dal()
+            .get_l1_batch_fee_params(batch_number)
+            .await
+            .map_err(DalError::generalize)?)
+    }
+
+    pub async fn get_node_info_impl(&self) -> Result<NodeInfo, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let protocol_version = storage.protocol_versions_dal().last_used_version_id().await;
+        let local_l2_block = storage
+            .blocks_dal()
+            .get_sealed_l2_block_number()
+            .await
+            .map_err(DalError::generalize)?;
+        let first_retained_l1_batch = self.state.start_info.first_l1_batch(&mut storage).await?;
+        let first_retained_l2_block = self.state.start_info.first_l2_block(&mut storage).await?;
+        drop(storage);
+
+        let metadata = &self.state.api_config.node_metadata;
+        let sync = if let Some(sync_state) = &self.state.sync_state {
+            NodeSyncStatus {
+                is_synced: sync_state.is_synced(),
+                local_l2_block,
+                main_node_l2_block: Some(sync_state.get_main_node_block()),
+            }
+        } else {
+            // The main node is always synced.
+            NodeSyncStatus {
+                is_synced: true,
+                local_l2_block,
+                main_node_l2_block: None,
+            }
+        };
+        Ok(NodeInfo {
+            role: metadata.role,
+            version: metadata.version.clone(),
+            protocol_version,
+            components: metadata.components.clone(),
+            pruning: NodePruningInfo {
+                settings: metadata.pruning.clone(),
+                first_retained_l1_batch,
+                first_retained_l2_block,
+            },
+            sync,
+        })
+    }
+
+    pub async fn get_chain_features_impl(&self) -> Result<ChainFeatures, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        // Features depending on the base system contracts are resolved for the pending block,
+        // so that they reflect transactions submitted now.
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        drop(connection);
+
+        let config = &self.state.api_config;
+        let custom_base_token = config
+            .base_token_address
+            .is_some_and(|address| !is_eth_base_token(address));
+        let commitment_mode = config.l1_batch_commit_data_generator_mode;
+        Ok(ChainFeatures {
+            protocol_version: block_args.protocol_version(),
+            evm_emulator: block_args.use_evm_emulator(),
+            custom_base_token,
+            base_token_address: config.base_token_address,
+            validium: commitment_mode == L1BatchCommitmentMode::Validium,
+            l1_batch_commitment_mode: commitment_mode,
+            aa_version: SUPPORTED_AA_VERSION,
+            gateway_settlement: config.sl_chain_id != SLChainId::from(config.l1_chain_id),
+            settlement_layer_chain_id: config.sl_chain_id,
+        })
+    }
+
     pub async fn get_protocol_version_impl(
         &self,
         version_id: Option<u16>,
@@ -577,6 +909,108 @@ impl ZksNamespace {
         }))
     }
 
+    pub async fn get_transaction_proof_impl(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<TransactionProof>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let Some(receipt) = storage
+            .transactions_web3_dal()
+            .get_transaction_receipts(&[tx_hash])
+            .await
+            .map_err(DalError::generalize)?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        let Some(l1_batch_number) = receipt.l1_batch_number else {
+            // The transaction is not sealed in an L1 batch yet.
+            return Ok(None);
+        };
+        let l1_batch_number = L1BatchNumber(l1_batch_number.as_u32());
+        let l2_block_number = L2BlockNumber(receipt.block_number.as_u32());
+        self.state
+            .start_info
+            .ensure_not_pruned(l2_block_number, &mut storage)
+            .await?;
+
+        let Some(l1_batch) = storage
+            .blocks_dal()
+            .get_l1_batch_metadata(l1_batch_number)
+            .await
+            .map_err(DalError::generalize)?
+        else {
+            // The L1 batch state root hash is not computed yet.
+            return Ok(None);
+        };
+        let commit_tx_hash = storage
+            .blocks_web3_dal()
+            .get_l1_batch_details(l1_batch_number)
+            .await
+            .map_err(DalError::generalize)?
+            .and_then(|details| details.base.commit_tx_hash);
+        let l2_block = storage
+            .blocks_dal()
+            .get_l2_block_header(l2_block_number)
+            .await
+            .map_err(DalError::generalize)?
+            .with_context(|| format!("L2 block #{l2_block_number} disappeared from storage"))?;
+        let prev_l2_block_hash = storage
+            .blocks_web3_dal()
+            .get_l2_block_hash(l2_block_number - 1)
+            .await
+            .map_err(DalError::generalize)?
+            .with_context(|| format!("L2 block #{} is missing", l2_block_number - 1))?;
+        let block_tx_hashes: Vec<_> = storage
+            .transactions_web3_dal()
+            .get_raw_l2_block_transactions(l2_block_number)
+            .await
+            .map_err(DalError::generalize)?
+            .iter()
+            .map(Transaction::hash)
+            .collect();
+        drop(storage);
+
+        let tx_index = receipt.transaction_index.as_usize();
+        if block_tx_hashes.get(tx_index) != Some(&tx_hash) {
+            let err = anyhow::anyhow!(
+                "transaction {tx_hash:?} is not at position {tx_index} in L2 block #{l2_block_number}"
+            );
+            return Err(err.into());
+        }
+        let preceding_txs_rolling_hash = block_tx_hashes[..tx_index]
+            .iter()
+            .fold(H256::zero(), |hash, &tx_hash| {
+                keccak256_concat(hash, tx_hash)
+            });
+
+        let l2_block_hash_key = l2_block_hash_storage_key(l2_block_number);
+        let l2_block_hash_proof = self
+            .get_proofs_impl(
+                SYSTEM_CONTEXT_ADDRESS,
+                vec![l2_block_hash_key],
+                l1_batch_number,
+            )
+            .await?
+            .and_then(|proof| proof.storage_proof.into_iter().next())
+            .filter(|proof| proof.value == l2_block.hash);
+
+        Ok(Some(TransactionProof {
+            transaction_hash: tx_hash,
+            l2_block_number,
+            l2_block_timestamp: l2_block.timestamp,
+            l2_block_hash: l2_block.hash,
+            prev_l2_block_hash,
+            preceding_txs_rolling_hash,
+            following_tx_hashes: block_tx_hashes[tx_index + 1..].to_vec(),
+            l1_batch_number,
+            l2_block_hash_proof,
+            l1_batch_root_hash: l1_batch.metadata.root_hash,
+            l1_batch_commitment: l1_batch.metadata.commitment,
+            commit_tx_hash,
+        }))
+    }
+
     pub fn get_base_token_l1_address_impl(&self) -> Result<Address, Web3Error> {
         self.state
             .api_config
@@ -608,7 +1042,8 @@ impl ZksNamespace {
         drop(connection);
         let (mut tx, hash) = self
             .state
-            .parse_transaction_bytes(&tx_bytes.0, &block_args)?;
+            .parse_transaction_bytes(&tx_bytes.0, &block_args)
+            .await?;
         tx.set_input(tx_bytes.0, hash);
 
         let submit_result = self.state.tx_sender.submit_tx(tx, block_args).await;
@@ -618,4 +1053,115 @@ impl ZksNamespace {
             err.into()
         })
     }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_token_price_impl(
+        &self,
+        token: Address,
+    ) -> Result<Option<TokenPrice>, Web3Error> {
+        let cache = self
+            .state
+            .token_price_cache
+            .as_ref()
+            .ok_or(Web3Error::MethodNotImplemented)?;
+        Ok(cache.get_price(token).await?)
+    }
+
+    /// Returns data decoded by the transaction decoder component; `None` if the transaction wasn't decoded
+    /// (e.g., if it doesn't interact with verified contracts, or the component is not running).
+    pub async fn get_decoded_transaction_impl(
+        &self,
+        hash: H256,
+    ) -> Result<Option<DecodedTransaction>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        Ok(storage
+            .decoded_transactions_dal()
+            .get_decoded_transaction(hash)
+            .await
+            .map_err(DalError::generalize)?)
+    }
+
+    pub async fn get_transactions_by_selector_impl(
+        &self,
+        filter: TransactionsBySelectorFilter,
+    ) -> Result<TransactionsBySelectorPage, Web3Error> {
+        let limit = filter
+            .limit
+            .map_or(DEFAULT_TRANSACTIONS_BY_SELECTOR_PAGE_SIZE, usize::from)
+            .clamp(1, MAX_TRANSACTIONS_BY_SELECTOR_PAGE_SIZE);
+        let mut storage = self.state.acquire_connection().await?;
+        let sealed_l2_block = storage
+            .blocks_dal()
+            .get_sealed_l2_block_number()
+            .await
+            .map_err(DalError::generalize)?;
+        let Some(sealed_l2_block) = sealed_l2_block else {
+            return Ok(TransactionsBySelectorPage {
+                transactions: vec![],
+                next_cursor: None,
+            });
+        };
+        // Transactions in unsealed L2 blocks may not be final, so they are never returned.
+        let to_block = filter
+            .to_block
+            .map_or(sealed_l2_block, |block| block.min(sealed_l2_block));
+        let from_block = filter.from_block.unwrap_or(L2BlockNumber(0));
+
+        let transactions = storage
+            .transactions_web3_dal()
+            .get_transactions_by_selector(
+                filter.selector,
+                filter.to,
+                from_block..=to_block,
+                filter.cursor,
+                limit,
+            )
+            .await
+            .map_err(DalError::generalize)?;
+        let next_cursor = if transactions.len() == limit {
+            transactions.last().map(|tx| tx.position)
+        } else {
+            None
+        };
+        Ok(TransactionsBySelectorPage {
+            transactions,
+            next_cursor,
+        })
+    }
+
+    pub async fn get_proof_latency_stats_impl(
+        &self,
+        from_l1_batch: L1BatchNumber,
+        to_l1_batch: Option<L1BatchNumber>,
+    ) -> Result<ProofLatencyStats, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let to_l1_batch = match to_l1_batch {
+            Some(number) => number,
+            None => storage
+                .blocks_dal()
+                .get_sealed_l1_batch_number()
+                .await
+                .map_err(DalError::generalize)?
+                .unwrap_or(L1BatchNumber(0)),
+        };
+        let max_l1_batch = from_l1_batch
+            .0
+            .saturating_add(MAX_PROOF_LATENCY_STATS_L1_BATCHES - 1);
+        let to_l1_batch = to_l1_batch.min(L1BatchNumber(max_l1_batch));
+
+        let stages = if from_l1_batch <= to_l1_batch {
+            storage
+                .proof_latency_dal()
+                .get_latency_stats(from_l1_batch..=to_l1_batch)
+                .await
+                .map_err(DalError::generalize)?
+        } else {
+            vec![]
+        };
+        Ok(ProofLatencyStats {
+            from_l1_batch,
+            to_l1_batch,
+            stages,
+        })
+    }
 }
@@ -9,7 +9,10 @@ use tokio::{
 };
 use tracing::Instrument as _;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::{L2BlockNumber, H128, H256};
+use zksync_types::{
+    api::{L1BatchStage, L1BatchStageUpdate},
+    L1BatchNumber, L2BlockNumber, H128, H256,
+};
 use zksync_web3_decl::{
     jsonrpsee::{
         core::{server::SubscriptionMessage, SubscriptionResult},
@@ -17,7 +20,7 @@ use zksync_web3_decl::{
         types::{error::ErrorCode, ErrorObject, SubscriptionId},
         PendingSubscriptionSink, SendTimeoutError, SubscriptionSink,
     },
-    namespaces::EthPubSubServer,
+    namespaces::{EthPubSubServer, ZksPubSubServer},
     types::{BlockHeader, Log, PubSubFilter, PubSubResult},
 };
 
@@ -229,13 +232,86 @@ impl PubSubNotifier {
             .await
             .map_err(Into::into)
     }
+
+    async fn notify_l1_batches(self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        const STAGES: [L1BatchStage; 3] = [
+            L1BatchStage::Committed,
+            L1BatchStage::Proven,
+            L1BatchStage::Executed,
+        ];
+
+        // Only batches reaching a stage after the notifier has started are reported.
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let mut blocks_dal = storage.blocks_dal();
+        let mut last_batch_numbers = [
+            blocks_dal
+                .get_number_of_last_l1_batch_committed_on_eth()
+                .await?,
+            blocks_dal
+                .get_number_of_last_l1_batch_proven_on_eth()
+                .await?,
+            blocks_dal
+                .get_number_of_last_l1_batch_executed_on_eth()
+                .await?,
+        ]
+        .map(|number| number.unwrap_or(L1BatchNumber(0)));
+        drop(storage);
+
+        let mut timer = interval(self.polling_interval);
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::info!("Stop signal received, pubsub_l1_batches_notifier is shutting down");
+                break;
+            }
+            timer.tick().await;
+
+            let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::L1Batches].start();
+            let mut new_updates = vec![];
+            for (stage, last_batch_number) in STAGES.into_iter().zip(&mut last_batch_numbers) {
+                let updates = self.new_l1_batch_updates(stage, *last_batch_number).await?;
+                if let Some(last_update) = updates.last() {
+                    *last_batch_number = last_update.number;
+                }
+                new_updates.extend(updates);
+            }
+            db_latency.observe();
+
+            if !new_updates.is_empty() {
+                let new_updates = new_updates
+                    .into_iter()
+                    .map(PubSubResult::L1BatchStage)
+                    .collect();
+                self.send_pub_sub_results(new_updates, SubscriptionType::L1Batches);
+            }
+            self.emit_event(PubSubEvent::NotifyIterationFinished(
+                SubscriptionType::L1Batches,
+            ));
+        }
+        Ok(())
+    }
+
+    async fn new_l1_batch_updates(
+        &self,
+        stage: L1BatchStage,
+        last_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Vec<L1BatchStageUpdate>> {
+        self.connection_pool
+            .connection_tagged("api")
+            .await?
+            .blocks_web3_dal()
+            .get_l1_batch_stage_updates(stage, last_batch_number)
+            .await
+            .map_err(Into::into)
+    }
 }
 
-/// Subscription support for Web3 APIs.
+/// Subscription support for Web3 APIs. Serves both `eth_subscribe` and `zks_subscribe`.
+#[derive(Clone)]
 pub(super) struct EthSubscribe {
     blocks: broadcast::Sender<Vec<PubSubResult>>,
     transactions: broadcast::Sender<Vec<PubSubResult>>,
     logs: broadcast::Sender<Vec<PubSubResult>>,
+    l1_batches: broadcast::Sender<Vec<PubSubResult>>,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
 
@@ -244,11 +320,13 @@ impl EthSubscribe {
         let (blocks, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (transactions, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
         let (logs, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        let (l1_batches, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
 
         Self {
             blocks,
             transactions,
             logs,
+            l1_batches,
             events_sender: None,
         }
     }
@@ -422,6 +500,28 @@ impl EthSubscribe {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self, pending_sink))]
+    pub async fn zks_sub(&self, pending_sink: PendingSubscriptionSink, sub_type: String) {
+        if sub_type != "l1Batches" {
+            Self::reject(pending_sink).await;
+            return;
+        }
+
+        let Ok(sink) = pending_sink.accept().await else {
+            return;
+        };
+        let l1_batches_rx = self.l1_batches.subscribe();
+        tokio::spawn(
+            Self::run_subscriber(sink, SubscriptionType::L1Batches, l1_batches_rx, None)
+                .in_current_span(),
+        );
+        if let Some(sender) = &self.events_sender {
+            sender
+                .send(PubSubEvent::Subscribed(SubscriptionType::L1Batches))
+                .ok();
+        }
+    }
+
     /// Spawns notifier tasks. This should be called once per instance.
     pub fn spawn_notifiers(
         &self,
@@ -429,7 +529,7 @@ impl EthSubscribe {
         polling_interval: Duration,
         stop_receiver: watch::Receiver<bool>,
     ) -> Vec<JoinHandle<anyhow::Result<()>>> {
-        let mut notifier_tasks = Vec::with_capacity(3);
+        let mut notifier_tasks = Vec::with_capacity(4);
 
         let notifier = PubSubNotifier {
             sender: self.blocks.clone(),
@@ -451,11 +551,20 @@ impl EthSubscribe {
 
         let notifier = PubSubNotifier {
             sender: self.logs.clone(),
+            connection_pool: connection_pool.clone(),
+            polling_interval,
+            events_sender: self.events_sender.clone(),
+        };
+        let notifier_task = tokio::spawn(notifier.notify_logs(stop_receiver.clone()));
+        notifier_tasks.push(notifier_task);
+
+        let notifier = PubSubNotifier {
+            sender: self.l1_batches.clone(),
             connection_pool,
             polling_interval,
             events_sender: self.events_sender.clone(),
         };
-        let notifier_task = tokio::spawn(notifier.notify_logs(stop_receiver));
+        let notifier_task = tokio::spawn(notifier.notify_l1_batches(stop_receiver));
 
         notifier_tasks.push(notifier_task);
         notifier_tasks
@@ -474,3 +583,15 @@ impl EthPubSubServer for EthSubscribe {
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+impl ZksPubSubServer for EthSubscribe {
+    async fn subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        sub_type: String,
+    ) -> SubscriptionResult {
+        self.zks_sub(pending, sub_type).await;
+        Ok(())
+    }
+}
@@ -13,16 +13,17 @@ use lru::LruCache;
 use tokio::sync::{Mutex, RwLock};
 use vise::GaugeGuard;
 use zksync_config::{
-    configs::{api::Web3JsonRpcConfig, ContractsConfig},
+    configs::{api::Web3JsonRpcConfig, ContractsConfig, CustomSystemContract},
     GenesisConfig,
 };
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
+use zksync_external_price_api::token_price::TokenPriceCache;
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_sync::SyncState;
 use zksync_types::{
     api, api::BridgeAddresses, commitment::L1BatchCommitmentMode, l2::L2Tx,
     transaction_request::CallRequest, Address, L1BatchNumber, L1ChainId, L2BlockNumber, L2ChainId,
-    H256, U256, U64,
+    SLChainId, H256, U256, U64,
 };
 use zksync_web3_decl::{error::Web3Error, types::Filter};
 
@@ -98,6 +99,8 @@ impl BlockStartInfo {
 pub struct InternalApiConfig {
     /// Chain ID of the L1 network. Note, that it may be different from the chain id of the settlement layer.
     pub l1_chain_id: L1ChainId,
+    /// Chain ID of the settlement layer. Equal to `l1_chain_id` unless the chain settles to Gateway.
+    pub sl_chain_id: SLChainId,
     pub l2_chain_id: L2ChainId,
     pub max_tx_size: usize,
     pub estimate_gas_scale_factor: f64,
@@ -115,7 +118,9 @@ pub struct InternalApiConfig {
     pub filters_disabled: bool,
     pub dummy_verifier: bool,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    pub custom_system_contracts: Vec<CustomSystemContract>,
     pub timestamp_asserter_address: Option<Address>,
+    pub node_metadata: NodeMetadata,
 }
 
 impl InternalApiConfig {
@@ -126,6 +131,7 @@ impl InternalApiConfig {
     ) -> Self {
         Self {
             l1_chain_id: genesis_config.l1_chain_id,
+            sl_chain_id: genesis_config.settlement_layer_id(),
             l2_chain_id: genesis_config.l2_chain_id,
             max_tx_size: web3_config.max_tx_size,
             estimate_gas_scale_factor: web3_config.estimate_gas_scale_factor,
@@ -169,7 +175,36 @@ impl InternalApiConfig {
             filters_disabled: web3_config.filters_disabled,
             dummy_verifier: genesis_config.dummy_verifier,
             l1_batch_commit_data_generator_mode: genesis_config.l1_batch_commit_data_generator_mode,
+            custom_system_contracts: genesis_config.custom_system_contracts.clone(),
             timestamp_asserter_address: contracts_config.l2_timestamp_asserter_addr,
+            node_metadata: NodeMetadata::main_node(),
+        }
+    }
+
+    /// Sets the node components reported by `zks_getNodeInfo`.
+    pub fn with_components(mut self, components: Vec<String>) -> Self {
+        self.node_metadata.components = components;
+        self
+    }
+}
+
+/// Static information about the node returned by `zks_getNodeInfo`.
+#[derive(Debug, Clone)]
+pub struct NodeMetadata {
+    pub role: api::NodeRole,
+    pub version: String,
+    pub components: Vec<String>,
+    pub pruning: api::NodePruningSettings,
+}
+
+impl NodeMetadata {
+    /// Metadata for the main node. The main node doesn't support pruning.
+    pub fn main_node() -> Self {
+        Self {
+            role: api::NodeRole::Main,
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            components: vec![],
+            pruning: api::NodePruningSettings::default(),
         }
     }
 }
@@ -245,24 +280,29 @@ pub(crate) struct RpcState {
     pub(super) mempool_cache: Option<MempoolCache>,
     pub(super) last_sealed_l2_block: SealedL2BlockNumber,
     pub(super) bridge_addresses_handle: BridgeAddressesHandle,
+    pub(super) token_price_cache: Option<Arc<TokenPriceCache>>,
 }
 
 impl RpcState {
-    pub fn parse_transaction_bytes(
+    /// Parses a raw transaction. Signer recovery is CPU-heavy, so it's performed on a blocking thread.
+    pub async fn parse_transaction_bytes(
         &self,
         bytes: &[u8],
         block_args: &BlockArgs,
     ) -> Result<(L2Tx, H256), Web3Error> {
         let chain_id = self.api_config.l2_chain_id;
-        let (tx_request, hash) = api::TransactionRequest::from_bytes(bytes, chain_id)?;
-        Ok((
-            L2Tx::from_request(
-                tx_request,
-                self.api_config.max_tx_size,
-                block_args.use_evm_emulator(),
-            )?,
-            hash,
-        ))
+        let max_tx_size = self.api_config.max_tx_size;
+        let use_evm_emulator = block_args.use_evm_emulator();
+        let bytes = bytes.to_vec();
+        tokio::task::spawn_blocking(move || -> Result<_, Web3Error> {
+            let (tx_request, hash) = api::TransactionRequest::from_bytes(&bytes, chain_id)?;
+            Ok((
+                L2Tx::from_request(tx_request, max_tx_size, use_evm_emulator)?,
+                hash,
+            ))
+        })
+        .await
+        .context("panicked while parsing transaction")?
     }
 
     pub fn u64_to_block_number(n: U64) -> L2BlockNumber {
@@ -1,8 +1,16 @@
 //! Tests for the `debug` Web3 namespace.
 
-use zksync_multivm::interface::{Call, TransactionExecutionResult};
+use std::collections::BTreeMap;
+
+use zksync_multivm::interface::{
+    Call, ExecutionResult, TouchedState, TransactionExecutionResult, TxExecutionMode,
+};
 use zksync_types::{
-    api::{CallTracerConfig, SupportedTracers, TracerConfig},
+    api::{
+        CallTracerConfig, GasProfilerConfig, MuxTracerConfig, PrestateAccount,
+        PrestateTracerConfig, PrestateTracerResult, SupportedTracers, TracerConfig,
+    },
+    bytecode::BytecodeHash,
     BOOTLOADER_ADDRESS,
 };
 use zksync_web3_decl::{
@@ -133,7 +141,9 @@ impl HttpTest for TraceBlockFlatTest {
                             tracer: SupportedTracers::FlatCallTracer,
                             tracer_config: CallTracerConfig {
                                 only_top_call: false,
+                                diff_mode: false,
                             },
+                            mux_tracer_config: None,
                         }),
                     )
                     .await?
@@ -173,7 +183,9 @@ impl HttpTest for TraceBlockFlatTest {
                     tracer: SupportedTracers::FlatCallTracer,
                     tracer_config: CallTracerConfig {
                         only_top_call: false,
+                        diff_mode: false,
                     },
+                    mux_tracer_config: None,
                 }),
             )
             .await
@@ -238,6 +250,240 @@ async fn tracing_transaction() {
     test_http_server(TraceTransactionTest).await;
 }
 
+#[derive(Debug)]
+struct TraceTransactionWithMuxTracerTest;
+
+#[async_trait]
+impl HttpTest for TraceTransactionWithMuxTracerTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let tx_results = [execute_l2_transaction_with_traces(0)];
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &tx_results).await?;
+        drop(storage);
+
+        let mux_tracer_config = MuxTracerConfig {
+            call_tracer: Some(CallTracerConfig::default()),
+            flat_call_tracer: Some(CallTracerConfig {
+                only_top_call: true,
+                diff_mode: false,
+            }),
+            prestate_tracer: None,
+            gas_profiler: Some(GasProfilerConfig {}),
+        };
+        let options = TracerConfig {
+            tracer: SupportedTracers::MuxTracer,
+            tracer_config: CallTracerConfig::default(),
+            mux_tracer_config: Some(mux_tracer_config),
+        };
+        let result = client
+            .trace_transaction(tx_results[0].hash, Some(options))
+            .await?
+            .context("no transaction traces")?
+            .unwrap_mux();
+        assert!(result.prestate_tracer.is_none());
+
+        let call_trace = result.call_tracer.context("no call trace")?;
+        assert_eq!(call_trace.to, BOOTLOADER_ADDRESS);
+        assert_eq!(call_trace.calls.len(), 2);
+        let flat_trace = result.flat_call_tracer.context("no flat call trace")?;
+        assert_eq!(flat_trace.len(), 1);
+        assert_eq!(flat_trace[0].action.to, BOOTLOADER_ADDRESS);
+
+        let gas_profile = result.gas_profiler.context("no gas profile")?;
+        assert_eq!(gas_profile.gas_used, call_trace.gas_used);
+        for call in &tx_results[0].call_traces {
+            let usage = gas_profile
+                .contracts
+                .iter()
+                .find(|usage| usage.address == call.to)
+                .with_context(|| format!("no gas usage for {:?}", call.to))?;
+            assert_eq!(usage.calls, 1);
+            assert_eq!(usage.self_gas_used, call.gas_used.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tracing_transaction_with_mux_tracer() {
+    test_http_server(TraceTransactionWithMuxTracerTest).await;
+}
+
+#[derive(Debug)]
+struct TraceTransactionWithPrestateTracerTest;
+
+#[async_trait]
+impl HttpTest for TraceTransactionWithPrestateTracerTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut executor = MockOneshotExecutor::default();
+        executor.set_tx_responses(|_, env| {
+            // The transaction must be replayed in the context of its block.
+            assert_eq!(env.system.execution_mode, TxExecutionMode::VerifyExecute);
+            assert_eq!(env.l1_batch.first_l2_block.number, 1);
+            ExecutionResult::Success { output: vec![] }
+        });
+        executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let tx_results = [
+            execute_l2_transaction_with_traces(0),
+            execute_l2_transaction_with_traces(1),
+        ];
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &tx_results).await?;
+        drop(storage);
+
+        for diff_mode in [false, true] {
+            let options = TracerConfig {
+                tracer: SupportedTracers::PrestateTracer,
+                tracer_config: CallTracerConfig {
+                    only_top_call: false,
+                    diff_mode,
+                },
+                mux_tracer_config: None,
+            };
+            let result = client
+                .trace_transaction(tx_results[1].hash, Some(options))
+                .await?
+                .context("no transaction traces")?
+                .unwrap_prestate();
+            if diff_mode {
+                assert_matches!(result, PrestateTracerResult::Diff { .. });
+            } else {
+                assert_matches!(result, PrestateTracerResult::Prestate(_));
+            }
+        }
+
+        let options = TracerConfig {
+            tracer: SupportedTracers::MuxTracer,
+            tracer_config: CallTracerConfig::default(),
+            mux_tracer_config: Some(MuxTracerConfig {
+                call_tracer: Some(CallTracerConfig::default()),
+                flat_call_tracer: None,
+                prestate_tracer: Some(PrestateTracerConfig::default()),
+                gas_profiler: None,
+            }),
+        };
+        let result = client
+            .trace_transaction(tx_results[0].hash, Some(options))
+            .await?
+            .context("no transaction traces")?
+            .unwrap_mux();
+        assert!(result.prestate_tracer.is_some());
+        let call_trace = result.call_tracer.context("no call trace")?;
+        assert_eq!(call_trace.to, BOOTLOADER_ADDRESS);
+        assert_eq!(call_trace.gas, tx_results[0].transaction.gas_limit());
+
+        let missing_tx = client
+            .trace_transaction(H256::repeat_byte(0xff), Some(options))
+            .await?;
+        assert!(missing_tx.is_none());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tracing_transaction_with_prestate_tracer() {
+    test_http_server(TraceTransactionWithPrestateTracerTest).await;
+}
+
+#[test]
+fn mapping_touched_state_to_prestate() {
+    let address = Address::repeat_byte(1);
+    let other_address = Address::repeat_byte(2);
+    let key = |byte| StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(byte));
+    let bytecode = vec![1_u8; 32];
+    let bytecode_hash = BytecodeHash::for_bytecode(&bytecode).value();
+    let state = TouchedState {
+        initial_values: HashMap::from([
+            (key(1), H256::repeat_byte(0x10)),
+            (key(2), H256::zero()),
+            (key(3), H256::repeat_byte(0x30)),
+            (
+                storage_key_for_eth_balance(&address),
+                u256_to_h256(100.into()),
+            ),
+            (get_nonce_key(&address), u256_to_h256(3.into())),
+            (get_code_key(&address), bytecode_hash),
+            (storage_key_for_eth_balance(&other_address), H256::zero()),
+        ]),
+        final_values: HashMap::from([
+            (key(2), H256::repeat_byte(0x20)),
+            (key(3), H256::repeat_byte(0x30)),
+            (
+                storage_key_for_eth_balance(&address),
+                u256_to_h256(90.into()),
+            ),
+        ]),
+        accounts: HashSet::from([address, other_address]),
+        bytecodes: HashMap::from([(bytecode_hash, bytecode.clone())]),
+    };
+
+    let PrestateTracerResult::Prestate(prestate) =
+        DebugNamespace::map_prestate(state.clone(), false)
+    else {
+        panic!("unexpected prestate");
+    };
+    assert_eq!(prestate.len(), 2);
+    let account = &prestate[&address];
+    assert_eq!(account.balance, Some(100.into()));
+    assert_eq!(account.nonce, Some(3));
+    assert_eq!(account.code, Some(bytecode.into()));
+    // Balance, nonce and code keys are not reported as storage.
+    assert_eq!(account.storage.len(), 3);
+    assert_eq!(
+        account.storage[&H256::repeat_byte(1)],
+        H256::repeat_byte(0x10)
+    );
+    let other_account = &prestate[&other_address];
+    assert_eq!(other_account.balance, Some(0.into()));
+    assert_eq!(other_account.code, None);
+    assert!(other_account.storage.is_empty());
+
+    // Unchanged slots and accounts are not a part of the diff.
+    let PrestateTracerResult::Diff { pre, post } = DebugNamespace::map_prestate(state, true) else {
+        panic!("unexpected prestate");
+    };
+    assert_eq!(pre.len(), 1);
+    assert_eq!(post.len(), 1);
+    assert_eq!(pre[&address].balance, Some(100.into()));
+    assert_eq!(pre[&address].nonce, Some(3));
+    assert_eq!(
+        pre[&address].storage,
+        BTreeMap::from([(H256::repeat_byte(2), H256::zero())])
+    );
+    assert_eq!(
+        post[&address],
+        PrestateAccount {
+            balance: Some(90.into()),
+            storage: BTreeMap::from([(H256::repeat_byte(2), H256::repeat_byte(0x20))]),
+            ..PrestateAccount::default()
+        }
+    );
+}
+
+#[test]
+fn parsing_prestate_tracer_config() {
+    let config: TracerConfig = serde_json::from_value(serde_json::json!({
+        "tracer": "prestateTracer",
+        "tracerConfig": { "diffMode": true },
+    }))
+    .unwrap();
+    assert!(matches!(config.tracer, SupportedTracers::PrestateTracer));
+    assert!(config.tracer_config.diff_mode);
+    assert!(!config.tracer_config.only_top_call);
+}
+
 #[derive(Debug)]
 struct TraceBlockTestWithSnapshotRecovery;
 
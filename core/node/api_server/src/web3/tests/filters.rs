@@ -2,6 +2,7 @@
 
 use std::fmt;
 
+use zksync_types::ethabi;
 use zksync_web3_decl::{
     jsonrpsee::{
         core::{client::Error, ClientError as RpcError},
@@ -291,6 +292,103 @@ fn assert_not_implemented<T: fmt::Debug>(result: Result<T, Error>) {
     });
 }
 
+#[derive(Debug)]
+struct DecodedLogsTest;
+
+#[async_trait]
+impl HttpTest for DecodedLogsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let event: ethabi::Event = serde_json::from_value(serde_json::json!({
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        }))?;
+        let signature = event.signature();
+        let (from, to) = (Address::repeat_byte(1), Address::repeat_byte(2));
+
+        let mut storage = pool.connection().await?;
+        let l2_block = create_l2_block(1);
+        storage.blocks_dal().insert_l2_block(&l2_block).await?;
+        let tx_location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(1),
+            tx_index_in_l2_block: 0,
+            tx_initiator_address: Address::repeat_byte(2),
+        };
+        let events = [
+            // Matches the event
+            VmEvent {
+                location: (L1BatchNumber(1), 0),
+                address: Address::repeat_byte(23),
+                indexed_topics: vec![signature, H256::from(from), H256::from(to)],
+                value: ethabi::encode(&[ethabi::Token::Uint(1_000.into())]),
+            },
+            // Has the same signature, but a different layout of indexed params (e.g., ERC-721 `Transfer`)
+            VmEvent {
+                location: (L1BatchNumber(1), 1),
+                address: Address::repeat_byte(23),
+                indexed_topics: vec![
+                    signature,
+                    H256::from(from),
+                    H256::from(to),
+                    H256::from_low_u64_be(1),
+                ],
+                value: vec![],
+            },
+            // Unrelated event
+            VmEvent {
+                location: (L1BatchNumber(1), 2),
+                address: Address::repeat_byte(23),
+                indexed_topics: vec![H256::repeat_byte(42)],
+                value: vec![],
+            },
+        ];
+        storage
+            .events_dal()
+            .save_events(l2_block.number, &[(tx_location, events.iter().collect())])
+            .await?;
+
+        let filter = Filter {
+            event: Some(event),
+            ..Filter::default()
+        };
+        let logs = client.get_logs(filter).await?;
+        assert_eq!(logs.len(), 1, "{logs:?}");
+        assert_eq!(logs[0].topics, events[0].indexed_topics);
+        let decoded_args = logs[0].decoded_args.as_ref().unwrap();
+        let values: Vec<_> = decoded_args
+            .iter()
+            .map(|arg| (arg.name.as_str(), arg.value.clone()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("from", format!("{from:?}").into()),
+                ("to", format!("{to:?}").into()),
+                ("value", "1000".into()),
+            ]
+        );
+
+        // Logs are not decoded if the event is not specified.
+        let logs = client.get_logs(Filter::default()).await?;
+        assert_eq!(logs.len(), 3);
+        assert!(logs.iter().all(|log| log.decoded_args.is_none()));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_decoded_logs() {
+    test_http_server(DecodedLogsTest).await;
+}
+
 #[derive(Debug)]
 struct DisableFiltersTest;
 
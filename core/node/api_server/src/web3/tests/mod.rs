@@ -37,17 +37,19 @@ use zksync_types::{
         testonly::{PROCESSED_EVM_BYTECODE, RAW_EVM_BYTECODE},
         BytecodeHash,
     },
+    commitment::L1BatchCommitmentMode,
     fee_model::{BatchFeeInput, FeeParams},
     get_nonce_key,
     l2::L2Tx,
+    prover_dal::ProvingStage,
     storage::get_code_key,
     system_contracts::get_system_smart_contracts,
     tokens::{TokenInfo, TokenMetadata},
     tx::IncludedTxLocation,
     u256_to_h256,
     utils::{storage_key_for_eth_balance, storage_key_for_standard_token_balance},
-    AccountTreeId, Address, L1BatchNumber, Nonce, ProtocolVersionId, StorageKey, StorageLog, H256,
-    U256, U64,
+    AccountTreeId, Address, L1BatchNumber, Nonce, ProtocolVersionId, SLChainId, StorageKey,
+    StorageLog, H256, U256, U64,
 };
 use zksync_vm_executor::oneshot::MockOneshotExecutor;
 use zksync_web3_decl::{
@@ -389,7 +391,7 @@ async fn store_custom_l2_block(
         .mark_txs_as_executed_in_l2_block(
             number,
             transaction_results,
-            1.into(),
+            header.base_fee_per_gas.into(),
             ProtocolVersionId::latest(),
             false,
         )
@@ -826,6 +828,81 @@ async fn getting_transaction_count_for_account() {
     test_http_server(TransactionCountTest).await;
 }
 
+#[derive(Debug)]
+struct NonceDetailsTest;
+
+#[async_trait]
+impl HttpTest for NonceDetailsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let test_address = Address::repeat_byte(11);
+        let mut storage = pool.connection().await?;
+        let mut committed_tx = create_l2_transaction(10, 200);
+        committed_tx.common_data.initiator_address = test_address;
+        committed_tx.common_data.nonce = Nonce(0);
+        store_l2_block(
+            &mut storage,
+            L2BlockNumber(1),
+            &[execute_l2_transaction(committed_tx)],
+        )
+        .await?;
+        let nonce_log =
+            StorageLog::new_write_log(get_nonce_key(&test_address), H256::from_low_u64_be(1));
+        storage
+            .storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(1), &[nonce_log])
+            .await?;
+
+        let details = client.get_nonce_details(test_address).await?;
+        assert_eq!(
+            details,
+            api::NonceDetails {
+                executed_nonce: 1,
+                mempool_nonce: 1,
+                pending_nonces: vec![],
+                nonce_gaps: vec![],
+            }
+        );
+
+        for nonce in [1, 2, 5] {
+            let mut pending_tx = create_l2_transaction(10, 200);
+            pending_tx.common_data.initiator_address = test_address;
+            pending_tx.common_data.nonce = Nonce(nonce);
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(
+                    &pending_tx,
+                    TransactionExecutionMetrics::default(),
+                    ValidationTraces::default(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let details = client.get_nonce_details(test_address).await?;
+        assert_eq!(
+            details,
+            api::NonceDetails {
+                executed_nonce: 1,
+                mempool_nonce: 3,
+                pending_nonces: vec![1, 2, 5],
+                nonce_gaps: vec![3, 4],
+            }
+        );
+        let pending_count = client.get_transaction_count(test_address, None).await?;
+        assert_eq!(pending_count, details.mempool_nonce.into());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_nonce_details_for_account() {
+    test_http_server(NonceDetailsTest).await;
+}
+
 #[derive(Debug)]
 struct TransactionCountAfterSnapshotRecoveryTest;
 
@@ -957,6 +1034,48 @@ async fn transaction_receipts() {
     test_http_server(TransactionReceiptsTest).await;
 }
 
+#[derive(Debug)]
+struct TransactionProofTest;
+
+#[async_trait]
+impl HttpTest for TransactionProofTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let tx_result = execute_l2_transaction(create_l2_transaction(10, 200));
+        store_l2_block(&mut storage, L2BlockNumber(1), &[tx_result.clone()]).await?;
+
+        let proof = client
+            .get_transaction_proof(H256::repeat_byte(0xff))
+            .await?;
+        assert!(proof.is_none());
+        // The transaction is not included into an L1 batch yet.
+        let proof = client.get_transaction_proof(tx_result.hash).await?;
+        assert!(proof.is_none());
+
+        // Storage proofs require the Merkle tree API, which isn't configured in tests.
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        let err = client
+            .get_transaction_proof(tx_result.hash)
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = err {
+            assert_eq!(error.code(), ErrorCode::MethodNotFound.code());
+        } else {
+            panic!("Unexpected error: {err:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_transaction_proof() {
+    test_http_server(TransactionProofTest).await;
+}
+
 #[derive(Debug)]
 struct AllAccountBalancesTest;
 
@@ -1162,6 +1281,71 @@ async fn tracing_genesis_config() {
     test_http_server(GenesisConfigTest).await;
 }
 
+#[derive(Debug)]
+struct NodeInfoTest;
+
+#[async_trait]
+impl HttpTest for NodeInfoTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let info = client.get_node_info().await?;
+        assert_eq!(info.role, api::NodeRole::Main);
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.protocol_version, Some(ProtocolVersionId::latest()));
+        assert!(!info.pruning.settings.enabled);
+        assert_eq!(info.pruning.first_retained_l1_batch, L1BatchNumber(0));
+        assert_eq!(info.pruning.first_retained_l2_block, L2BlockNumber(0));
+        assert!(info.sync.is_synced);
+        assert_eq!(info.sync.local_l2_block, Some(L2BlockNumber(0)));
+        assert_eq!(info.sync.main_node_l2_block, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_node_info() {
+    test_http_server(NodeInfoTest).await;
+}
+
+#[derive(Debug)]
+struct ChainFeaturesTest;
+
+#[async_trait]
+impl HttpTest for ChainFeaturesTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let features = client.get_chain_features().await?;
+        assert_eq!(features.protocol_version, ProtocolVersionId::latest());
+        assert!(!features.evm_emulator);
+        // The test contracts config specifies a custom base token.
+        assert!(features.custom_base_token);
+        assert_eq!(
+            features.base_token_address,
+            Some(Address::repeat_byte(0x14))
+        );
+        assert!(!features.validium);
+        assert_eq!(
+            features.l1_batch_commitment_mode,
+            L1BatchCommitmentMode::Rollup
+        );
+        assert_eq!(features.aa_version, 1);
+        assert!(!features.gateway_settlement);
+        assert_eq!(features.settlement_layer_chain_id, SLChainId(9));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_chain_features() {
+    test_http_server(ChainFeaturesTest).await;
+}
+
 #[derive(Debug)]
 struct GetBytecodeTest;
 
@@ -1391,3 +1575,131 @@ impl HttpTest for FeeHistoryTest {
 async fn getting_fee_history() {
     test_http_server(FeeHistoryTest).await;
 }
+
+#[derive(Debug)]
+struct FeeHistoryRewardsTest;
+
+#[async_trait]
+impl HttpTest for FeeHistoryRewardsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut connection = pool.connection().await?;
+        store_l2_block(&mut connection, L2BlockNumber(1), &[]).await?;
+        let block2 = L2BlockHeader {
+            base_fee_per_gas: 200,
+            ..create_l2_block(2)
+        };
+        let tx_results: Vec<_> = [50_u64, 10, 300]
+            .into_iter()
+            .map(|priority_fee| {
+                let mut tx = create_l2_transaction(1_000, 50);
+                tx.common_data.fee.max_priority_fee_per_gas = priority_fee.into();
+                execute_l2_transaction(tx)
+            })
+            .collect();
+        store_custom_l2_block(&mut connection, &block2, &tx_results).await?;
+
+        let history = client
+            .fee_history(10.into(), api::BlockNumber::Latest, vec![0.0, 50.0, 100.0])
+            .await?;
+        assert_eq!(history.inner.oldest_block, 0.into());
+        let expected_rewards = vec![
+            vec![U256::zero(); 3],
+            vec![U256::zero(); 3],
+            [10, 50, 300].map(U256::from).to_vec(),
+        ];
+        assert_eq!(history.inner.reward, Some(expected_rewards));
+        assert_eq!(history.inner.base_fee_per_blob_gas, [U256::zero(); 4]);
+        assert_eq!(history.inner.blob_gas_used_ratio, [0.0; 3]);
+
+        for invalid_percentiles in [vec![50.0, 10.0], vec![-1.0], vec![101.0]] {
+            let err = client
+                .fee_history(10.into(), api::BlockNumber::Latest, invalid_percentiles)
+                .await
+                .unwrap_err();
+            assert_matches!(
+                err,
+                ClientError::Call(err) if err.code() == INVALID_PARAMS_CODE
+            );
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_fee_history_with_rewards() {
+    test_http_server(FeeHistoryRewardsTest).await;
+}
+
+#[derive(Debug)]
+struct ProofLatencyStatsTest;
+
+#[async_trait]
+impl HttpTest for ProofLatencyStatsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let queued_at = chrono::Utc::now() - chrono::Duration::hours(1);
+        for number in 1..=2 {
+            let l1_batch_number = L1BatchNumber(number);
+            seal_l1_batch(&mut storage, l1_batch_number).await?;
+            let latency = chrono::Duration::seconds(10 * i64::from(number));
+            let stages = [
+                (ProvingStage::Queued, queued_at),
+                (ProvingStage::Submitted, queued_at + latency),
+            ];
+            storage
+                .proof_latency_dal()
+                .record_stages(l1_batch_number, &stages)
+                .await?;
+        }
+        // Batches without a queued timestamp should be ignored.
+        seal_l1_batch(&mut storage, L1BatchNumber(3)).await?;
+        storage
+            .proof_latency_dal()
+            .record_stage(L1BatchNumber(3), ProvingStage::Submitted)
+            .await?;
+
+        let stats = client
+            .get_proof_latency_stats(L1BatchNumber(1), None)
+            .await?;
+        assert_eq!(stats.from_l1_batch, L1BatchNumber(1));
+        assert_eq!(stats.to_l1_batch, L1BatchNumber(3));
+        assert_eq!(
+            stats.stages,
+            [api::ProvingStageLatency {
+                stage: ProvingStage::Submitted,
+                batch_count: 2,
+                avg_ms: 15_000,
+                p50_ms: 15_000,
+                p95_ms: 19_500,
+                max_ms: 20_000,
+            }]
+        );
+
+        let stats = client
+            .get_proof_latency_stats(L1BatchNumber(2), Some(L1BatchNumber(2)))
+            .await?;
+        assert_eq!(stats.stages.len(), 1);
+        assert_eq!(stats.stages[0].batch_count, 1);
+        assert_eq!(stats.stages[0].max_ms, 20_000);
+
+        let stats = client
+            .get_proof_latency_stats(L1BatchNumber(5), None)
+            .await?;
+        assert_eq!(stats.to_l1_batch, L1BatchNumber(3));
+        assert!(stats.stages.is_empty());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_proof_latency_stats() {
+    test_http_server(ProofLatencyStatsTest).await;
+}
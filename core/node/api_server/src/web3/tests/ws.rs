@@ -8,7 +8,10 @@ use http::StatusCode;
 use tokio::sync::watch;
 use zksync_config::configs::chain::NetworkConfig;
 use zksync_dal::ConnectionPool;
-use zksync_types::{api, Address, Bloom, L1BatchNumber, H160, H256, U64};
+use zksync_types::{
+    aggregated_operations::AggregatedActionType, api, Address, Bloom, L1BatchNumber, H160, H256,
+    U64,
+};
 use zksync_web3_decl::{
     client::{WsClient, L2},
     jsonrpsee::{
@@ -344,6 +347,110 @@ async fn basic_subscriptions_after_snapshot_recovery() {
     .await;
 }
 
+#[derive(Debug)]
+struct L1BatchSubscriptionTest;
+
+#[async_trait]
+impl WsTest for L1BatchSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::L1Batches]).await;
+
+        let params = rpc_params!["l1Batches"];
+        let mut l1_batches_subscription = client
+            .subscribe::<api::L1BatchStageUpdate, _>("zks_subscribe", params, "zks_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::L1Batches).await;
+
+        let mut storage = pool.connection().await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        let commit_tx = storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                0,
+                vec![],
+                AggregatedActionType::Commit,
+                Address::default(),
+                0,
+                None,
+                None,
+                false,
+            )
+            .await?;
+        let commit_tx_hash = H256::repeat_byte(1);
+        storage
+            .eth_sender_dal()
+            .insert_tx_history(commit_tx.id, 0, 0, None, commit_tx_hash, &[], 0)
+            .await?;
+        storage
+            .eth_sender_dal()
+            .confirm_tx(commit_tx_hash, U256::zero())
+            .await?;
+        storage
+            .blocks_dal()
+            .set_eth_tx_id(
+                L1BatchNumber(1)..=L1BatchNumber(1),
+                commit_tx.id,
+                AggregatedActionType::Commit,
+            )
+            .await?;
+        drop(storage);
+
+        let update = tokio::time::timeout(TEST_TIMEOUT, l1_batches_subscription.next())
+            .await
+            .context("Timed out waiting for L1 batch update")?
+            .context("L1 batches subscription terminated")??;
+        assert_eq!(update.number, L1BatchNumber(1));
+        assert_eq!(update.stage, api::L1BatchStage::Committed);
+        assert_eq!(update.tx_hash, commit_tx_hash);
+
+        let details = client
+            .get_l1_batch_details(L1BatchNumber(1))
+            .await?
+            .context("missing L1 batch")?;
+        assert_eq!(details.base.commit_tx_hash, Some(commit_tx_hash));
+        assert_eq!(details.base.committed_at, Some(update.confirmed_at));
+
+        l1_batches_subscription.unsubscribe().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn l1_batch_subscription() {
+    test_ws_server(L1BatchSubscriptionTest).await;
+}
+
+#[derive(Debug)]
+struct UnknownZksSubscriptionTest;
+
+#[async_trait]
+impl WsTest for UnknownZksSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        _pool: &ConnectionPool<Core>,
+        _pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        let params = rpc_params!["newHeads"];
+        let err = client
+            .subscribe::<serde_json::Value, _>("zks_subscribe", params, "zks_unsubscribe")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ClientError::Call(err) if err.code() == ErrorCode::InvalidParams.code());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn unknown_zks_subscription_is_rejected() {
+    test_ws_server(UnknownZksSubscriptionTest).await;
+}
+
 #[derive(Debug)]
 struct LogSubscriptionsTest {
     snapshot_recovery: bool,
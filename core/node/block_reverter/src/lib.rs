@@ -364,6 +364,11 @@ impl BlockReverter {
             .vm_runner_dal()
             .delete_bwip_data(last_l1_batch_to_keep)
             .await?;
+        tracing::info!("Rolling back vm_runner_batch_verifier");
+        transaction
+            .vm_runner_dal()
+            .delete_batch_verifier_data(last_l1_batch_to_keep)
+            .await?;
         tracing::info!("Rolling back L2 blocks");
         transaction
             .blocks_dal()
@@ -6,6 +6,7 @@ use tokio::{sync::watch, task::JoinHandle};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_l1_contract_interface::i_executor::commit::kzg::pubdata_to_blob_commitments;
+use zksync_multivm::interface::VmEvent;
 use zksync_types::{
     blob::num_blobs_required,
     commitment::{
@@ -13,6 +14,7 @@ use zksync_types::{
         L1BatchCommitment, L1BatchCommitmentArtifacts, L1BatchCommitmentMode,
     },
     h256_to_u256,
+    l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
     writes::{InitialStorageWrite, RepeatedStorageWrite, StateDiffRecord},
     L1BatchNumber, ProtocolVersionId, StorageKey, H256, U256,
 };
@@ -33,6 +35,23 @@ pub mod validation_task;
 
 const SLEEP_INTERVAL: Duration = Duration::from_millis(100);
 
+/// Outputs of executing an L1 batch that participate in its commitment.
+#[derive(Debug)]
+pub struct L1BatchExecutionOutputs {
+    /// User L2-to-L1 logs produced by the batch.
+    pub l2_to_l1_logs: Vec<UserL2ToL1Log>,
+    /// System L2-to-L1 logs produced by the batch.
+    pub system_logs: Vec<SystemL2ToL1Log>,
+    /// Events emitted by the batch.
+    pub events: Vec<VmEvent>,
+    /// State diffs produced by the batch.
+    pub state_diffs: Vec<StateDiffRecord>,
+    /// Initial bootloader heap contents.
+    pub initial_bootloader_contents: Vec<(usize, U256)>,
+    /// Pubdata published by the batch.
+    pub pubdata_input: Option<Vec<u8>>,
+}
+
 /// Component responsible for generating commitments for L1 batches.
 #[derive(Debug)]
 pub struct CommitmentGenerator {
@@ -87,16 +106,11 @@ impl CommitmentGenerator {
             .connection_tagged("commitment_generator")
             .await?;
 
-        // Calculate events queue using VM events.
-        let events_queue = {
-            let events = connection
-                .events_dal()
-                .get_vm_events_for_l1_batch(l1_batch_number)
-                .await?
-                .with_context(|| format!("Events are missing for L1 batch #{l1_batch_number}"))?;
-            convert_vm_events_to_log_queries(&events)
-        };
-
+        let events = connection
+            .events_dal()
+            .get_vm_events_for_l1_batch(l1_batch_number)
+            .await?
+            .with_context(|| format!("Events are missing for L1 batch #{l1_batch_number}"))?;
         let initial_bootloader_contents = connection
             .blocks_dal()
             .get_initial_bootloader_heap(l1_batch_number)
@@ -106,6 +120,25 @@ impl CommitmentGenerator {
             })?;
         drop(connection);
 
+        self.compute_aux_commitments(
+            l1_batch_number,
+            &events,
+            initial_bootloader_contents,
+            protocol_version,
+        )
+        .await
+    }
+
+    async fn compute_aux_commitments(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        events: &[VmEvent],
+        initial_bootloader_contents: Vec<(usize, U256)>,
+        protocol_version: ProtocolVersionId,
+    ) -> anyhow::Result<AuxCommitments> {
+        // Calculate events queue using VM events.
+        let events_queue = convert_vm_events_to_log_queries(events);
+
         let computer = self.computer.clone();
         let events_commitment_task: JoinHandle<anyhow::Result<H256>> =
             tokio::task::spawn_blocking(move || {
@@ -266,41 +299,11 @@ impl CommitmentGenerator {
             }
             state_diffs.sort_unstable_by_key(|rec| (rec.address, rec.key));
 
-            let blob_hashes = if protocol_version.is_post_1_4_2() {
-                let pubdata_input = header.pubdata_input.with_context(|| {
-                    format!("`pubdata_input` is missing for L1 batch #{l1_batch_number}")
-                })?;
-
-                let commitments = pubdata_to_blob_commitments(
-                    num_blobs_required(&protocol_version),
-                    &pubdata_input,
-                );
-                let linear_hashes = pubdata_to_blob_linear_hashes(
-                    num_blobs_required(&protocol_version),
-                    pubdata_input,
-                );
-
-                commitments
-                    .into_iter()
-                    .zip(linear_hashes)
-                    .map(|(commitment, linear_hash)| BlobHash {
-                        commitment,
-                        linear_hash,
-                    })
-                    .collect::<Vec<_>>()
-            } else {
-                vec![Default::default(); num_blobs_required(&protocol_version)]
-            };
-
-            let aggregation_root = if protocol_version.is_pre_gateway() {
-                let mut connection = self
-                    .connection_pool
-                    .connection_tagged("commitment_generator")
-                    .await?;
-                read_aggregation_root(&mut connection, l1_batch_number).await?
-            } else {
-                H256::zero()
-            };
+            let blob_hashes =
+                Self::blob_hashes(l1_batch_number, protocol_version, header.pubdata_input)?;
+            let aggregation_root = self
+                .aggregation_root(l1_batch_number, protocol_version)
+                .await?;
 
             CommitmentInput::PostBoojum {
                 common,
@@ -316,6 +319,125 @@ impl CommitmentGenerator {
         Ok(input)
     }
 
+    fn blob_hashes(
+        l1_batch_number: L1BatchNumber,
+        protocol_version: ProtocolVersionId,
+        pubdata_input: Option<Vec<u8>>,
+    ) -> anyhow::Result<Vec<BlobHash>> {
+        if !protocol_version.is_post_1_4_2() {
+            return Ok(vec![
+                Default::default();
+                num_blobs_required(&protocol_version)
+            ]);
+        }
+
+        let pubdata_input = pubdata_input.with_context(|| {
+            format!("`pubdata_input` is missing for L1 batch #{l1_batch_number}")
+        })?;
+        let commitments =
+            pubdata_to_blob_commitments(num_blobs_required(&protocol_version), &pubdata_input);
+        let linear_hashes =
+            pubdata_to_blob_linear_hashes(num_blobs_required(&protocol_version), pubdata_input);
+
+        Ok(commitments
+            .into_iter()
+            .zip(linear_hashes)
+            .map(|(commitment, linear_hash)| BlobHash {
+                commitment,
+                linear_hash,
+            })
+            .collect())
+    }
+
+    async fn aggregation_root(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        protocol_version: ProtocolVersionId,
+    ) -> anyhow::Result<H256> {
+        if protocol_version.is_pre_gateway() {
+            let mut connection = self
+                .connection_pool
+                .connection_tagged("commitment_generator")
+                .await?;
+            read_aggregation_root(&mut connection, l1_batch_number).await
+        } else {
+            Ok(H256::zero())
+        }
+    }
+
+    /// Recomputes the commitment of a sealed L1 batch based on the provided outputs of its (re-)execution
+    /// rather than on the data persisted by the state keeper. Data not produced by the VM (e.g., the Merkle tree root hash)
+    /// is loaded from Postgres.
+    ///
+    /// Returns `Ok(None)` for pre-boojum batches, which are not supported.
+    #[tracing::instrument(skip(self, outputs))]
+    pub async fn recompute_commitment(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        outputs: L1BatchExecutionOutputs,
+    ) -> anyhow::Result<Option<H256>> {
+        let mut connection = self
+            .connection_pool
+            .connection_tagged("commitment_generator")
+            .await?;
+        let header = connection
+            .blocks_dal()
+            .get_l1_batch_header(l1_batch_number)
+            .await?
+            .with_context(|| format!("header is missing for L1 batch #{l1_batch_number}"))?;
+        let tree_data = connection
+            .blocks_dal()
+            .get_l1_batch_tree_data(l1_batch_number)
+            .await?
+            .with_context(|| format!("`tree_data` is missing for L1 batch #{l1_batch_number}"))?;
+        drop(connection);
+
+        let protocol_version = header
+            .protocol_version
+            .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
+        if protocol_version.is_pre_boojum() {
+            return Ok(None);
+        }
+
+        let common = CommitmentCommonInput {
+            l2_to_l1_logs: outputs.l2_to_l1_logs,
+            rollup_last_leaf_index: tree_data.rollup_last_leaf_index,
+            rollup_root_hash: tree_data.hash,
+            bootloader_code_hash: header.base_system_contracts_hashes.bootloader,
+            default_aa_code_hash: header.base_system_contracts_hashes.default_aa,
+            evm_emulator_code_hash: header.base_system_contracts_hashes.evm_emulator,
+            protocol_version,
+        };
+        let aux_commitments = self
+            .compute_aux_commitments(
+                l1_batch_number,
+                &outputs.events,
+                outputs.initial_bootloader_contents,
+                protocol_version,
+            )
+            .await?;
+        let mut state_diffs = outputs.state_diffs;
+        state_diffs.sort_unstable_by_key(|rec| (rec.address, rec.key));
+        let blob_hashes =
+            Self::blob_hashes(l1_batch_number, protocol_version, outputs.pubdata_input)?;
+        let aggregation_root = self
+            .aggregation_root(l1_batch_number, protocol_version)
+            .await?;
+
+        let mut input = CommitmentInput::PostBoojum {
+            common,
+            system_logs: outputs.system_logs,
+            state_diffs,
+            aux_commitments,
+            blob_hashes,
+            aggregation_root,
+        };
+        self.tweak_input(&mut input);
+        let mut commitment = L1BatchCommitment::new(input);
+        self.post_process_commitment(&mut commitment);
+        Ok(Some(commitment.hash().commitment))
+    }
+
     #[tracing::instrument(skip(self))]
     async fn process_batch(
         &self,
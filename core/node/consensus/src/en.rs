@@ -371,7 +371,13 @@ impl EN {
         METRICS.fetch_block.inc();
         loop {
             match ctx.wait(self.client.sync_l2_block(n, true)).await? {
-                Ok(Some(block)) => return Ok(block.try_into()?),
+                Ok(Some(block)) => {
+                    let block: FetchedBlock = block.try_into()?;
+                    ctx.wait(block.verify_l2_transactions())
+                        .await?
+                        .context("verify_l2_transactions()")?;
+                    return Ok(block);
+                }
                 Ok(None) => {}
                 Err(err) if is_retriable(&err) => {}
                 Err(err) => Err(err).with_context(|| format!("client.sync_l2_block({n})"))?,
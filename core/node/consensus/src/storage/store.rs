@@ -17,17 +17,33 @@ use zksync_web3_decl::{
 use super::{Connection, PayloadQueue};
 use crate::storage::{ConnectionPool, InsertCertificateError};
 
-fn to_fetched_block(
+/// Decodes the payload on a blocking thread. Decoding recovers signers of all L2 transactions
+/// in the payload, which is CPU-heavy and would otherwise stall the async runtime.
+async fn decode_payload(ctx: &ctx::Ctx, payload: &validator::Payload) -> ctx::Result<Payload> {
+    let payload = payload.clone();
+    let decoded = ctx
+        .wait(tokio::task::spawn_blocking(move || {
+            Payload::decode(&payload)
+        }))
+        .await?
+        .context("panicked while decoding payload")??;
+    Ok(decoded)
+}
+
+async fn to_fetched_block(
+    ctx: &ctx::Ctx,
     number: validator::BlockNumber,
     payload: &validator::Payload,
-) -> anyhow::Result<FetchedBlock> {
+) -> ctx::Result<FetchedBlock> {
     let number = L2BlockNumber(
         number
             .0
             .try_into()
             .context("Integer overflow converting block number")?,
     );
-    let payload = Payload::decode(payload).context("Payload::decode()")?;
+    let payload = decode_payload(ctx, payload)
+        .await
+        .wrap("Payload::decode()")?;
     Ok(FetchedBlock {
         number,
         l1_batch_number: payload.l1_batch_number,
@@ -359,10 +375,10 @@ impl storage::PersistentBlockStore for Store {
             validator::Block::PreGenesis(block) => (&block.payload, None),
         };
         if let Some(payloads) = &mut *payloads {
-            payloads
-                .send(to_fetched_block(block.number(), p).context("to_fetched_block")?)
+            let block = to_fetched_block(ctx, block.number(), p)
                 .await
-                .context("payloads.send()")?;
+                .wrap("to_fetched_block")?;
+            payloads.send(block).await.context("payloads.send()")?;
         }
         if let Some(justification) = j {
             self.block_certificates.send(justification.clone());
@@ -431,7 +447,9 @@ impl PayloadManager for Store {
     ) -> ctx::Result<()> {
         let mut payloads = sync::lock(ctx, &self.block_payloads).await?.into_async();
         if let Some(payloads) = &mut *payloads {
-            let block = to_fetched_block(block_number, payload).context("to_fetched_block")?;
+            let block = to_fetched_block(ctx, block_number, payload)
+                .await
+                .wrap("to_fetched_block")?;
             let n = block.number;
             payloads.send(block).await.context("payload_queue.send()")?;
             // Wait for the block to be processed, without waiting for it to be stored.
@@ -443,7 +461,9 @@ impl PayloadManager for Store {
                 .await?;
         } else {
             let want = self.pool.wait_for_payload(ctx, block_number).await?;
-            let got = Payload::decode(payload).context("Payload::decode(got)")?;
+            let got = decode_payload(ctx, payload)
+                .await
+                .wrap("Payload::decode(got)")?;
             if got != want {
                 return Err(
                     anyhow::format_err!("unexpected payload: got {got:?} want {want:?}").into(),
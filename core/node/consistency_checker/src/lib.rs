@@ -20,6 +20,7 @@ use zksync_types::{
     ethabi,
     ethabi::Token,
     pubdata_da::PubdataSendingMode,
+    web3::{BlockId, BlockNumber},
     Address, L1BatchNumber, ProtocolVersionId, H256, U256,
 };
 
@@ -317,6 +318,11 @@ pub struct ConsistencyChecker {
     pool: ConnectionPool<Core>,
     health_check: ReactiveHealthCheck,
     commitment_mode: L1BatchCommitmentMode,
+    /// First L1 batches committed after each switch of the chain DA mode, in ascending order. Since there are only
+    /// two commitment modes, each transition flips the mode; batches after the last transition use `commitment_mode`.
+    commitment_mode_transitions: Vec<L1BatchNumber>,
+    /// Inclusion deadline for priority operations in L1 batches, if it should be checked.
+    forced_inclusion_deadline: Option<u32>,
 }
 
 impl ConsistencyChecker {
@@ -340,6 +346,8 @@ impl ConsistencyChecker {
             pool,
             health_check,
             commitment_mode,
+            commitment_mode_transitions: vec![],
+            forced_inclusion_deadline: None,
         })
     }
 
@@ -348,6 +356,40 @@ impl ConsistencyChecker {
         self
     }
 
+    /// Specifies the first L1 batches committed after each switch of the chain between rollup and validium modes.
+    /// Batches after the last transition are checked using the configured commitment mode; each earlier transition
+    /// flips the mode for the batches preceding it.
+    pub fn with_commitment_mode_transitions(
+        mut self,
+        mut first_batches: Vec<L1BatchNumber>,
+    ) -> Self {
+        first_batches.sort_unstable();
+        first_batches.dedup();
+        self.commitment_mode_transitions = first_batches;
+        self
+    }
+
+    /// Makes the checker verify that each priority operation is included into one of the first `deadline_batches`
+    /// L1 batches opened after the operation was submitted on L1.
+    pub fn with_forced_inclusion_deadline(mut self, deadline_batches: u32) -> Self {
+        self.forced_inclusion_deadline = Some(deadline_batches);
+        self
+    }
+
+    fn commitment_mode_for_batch(&self, batch_number: L1BatchNumber) -> L1BatchCommitmentMode {
+        let later_transitions = self.commitment_mode_transitions.len()
+            - self
+                .commitment_mode_transitions
+                .partition_point(|&transition| transition <= batch_number);
+        if later_transitions % 2 == 0 {
+            return self.commitment_mode;
+        }
+        match self.commitment_mode {
+            L1BatchCommitmentMode::Rollup => L1BatchCommitmentMode::Validium,
+            L1BatchCommitmentMode::Validium => L1BatchCommitmentMode::Rollup,
+        }
+    }
+
     /// Returns health check associated with this checker.
     pub fn health_check(&self) -> &ReactiveHealthCheck {
         &self.health_check
@@ -445,6 +487,78 @@ impl ConsistencyChecker {
             .map_err(CheckError::Validation)
     }
 
+    /// Checks the inclusion deadline for priority operations in the specified L1 batch. It's sufficient to check
+    /// the first operation since it was submitted no later than the other ones. The deadline is measured from the timestamp
+    /// of the L1 block the operation was submitted in, so that the check doesn't rely on when the main node has received
+    /// the operation.
+    async fn check_inclusion_deadline(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<(), CheckError> {
+        let Some(deadline_batches) = self.forced_inclusion_deadline else {
+            return Ok(());
+        };
+
+        let mut storage = self
+            .pool
+            .connection()
+            .await
+            .map_err(|err| CheckError::Internal(err.generalize()))?;
+        let first_priority_op = storage
+            .transactions_dal()
+            .get_first_priority_op_in_l1_batch(batch_number)
+            .await
+            .map_err(|err| CheckError::Internal(err.generalize()))?;
+        drop(storage);
+        let Some((op_id, l1_block_number)) = first_priority_op else {
+            return Ok(());
+        };
+
+        let l1_block = self
+            .l1_client
+            .block(BlockId::Number(BlockNumber::Number(
+                l1_block_number.0.into(),
+            )))
+            .await?
+            .with_context(|| {
+                format!("L1 block #{l1_block_number} with priority operation #{op_id} not found")
+            })
+            .map_err(CheckError::Validation)?;
+        let submitted_at = l1_block.timestamp.as_u64();
+
+        // Batch timestamps are strictly increasing, so `deadline_batches` batches were opened after the operation
+        // was submitted iff the earliest of them was opened after it.
+        let Some(reference_batch) = batch_number.0.checked_sub(deadline_batches) else {
+            return Ok(());
+        };
+        let reference_batch = L1BatchNumber(reference_batch);
+        let mut storage = self
+            .pool
+            .connection()
+            .await
+            .map_err(|err| CheckError::Internal(err.generalize()))?;
+        let reference_timestamp = storage
+            .blocks_dal()
+            .get_l1_batch_timestamp(reference_batch)
+            .await
+            .map_err(|err| CheckError::Internal(err.generalize()))?;
+        drop(storage);
+        // The batch may be missing after snapshot recovery, in which case the check is skipped.
+        let Some(reference_timestamp) = reference_timestamp else {
+            return Ok(());
+        };
+
+        if reference_timestamp > submitted_at {
+            let err = anyhow::anyhow!(
+                "priority operation #{op_id} submitted in L1 block #{l1_block_number} is included into L1 batch \
+                 #{batch_number}, while L1 batch #{reference_batch} was already opened after its submission; \
+                 the inclusion deadline is {deadline_batches} L1 batches"
+            );
+            return Err(CheckError::Validation(err));
+        }
+        Ok(())
+    }
+
     /// All returned errors are validation errors.
     fn extract_commit_data(
         commit_tx_input_data: &[u8],
@@ -596,9 +710,9 @@ impl ConsistencyChecker {
             // The batch might be already committed but not yet processed by the external node's tree
             // OR the batch might be processed by the external node's tree but not yet committed.
             // We need both.
+            let commitment_mode = self.commitment_mode_for_batch(batch_number);
             let local =
-                LocalL1BatchCommitData::new(&mut storage, batch_number, self.commitment_mode)
-                    .await?;
+                LocalL1BatchCommitData::new(&mut storage, batch_number, commitment_mode).await?;
             let Some(local) = local else {
                 if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
                     .await
@@ -610,7 +724,11 @@ impl ConsistencyChecker {
             };
             drop(storage);
 
-            match self.check_commitments(batch_number, &local).await {
+            let check_result = match self.check_commitments(batch_number, &local).await {
+                Ok(()) => self.check_inclusion_deadline(batch_number).await,
+                Err(err) => Err(err),
+            };
+            match check_result {
                 Ok(()) => {
                     let mut storage = self.pool.connection().await?;
                     storage
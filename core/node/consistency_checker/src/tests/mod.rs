@@ -11,11 +11,16 @@ use zksync_eth_client::{clients::MockSettlementLayer, Options};
 use zksync_l1_contract_interface::{i_executor::methods::CommitBatches, Tokenizable, Tokenize};
 use zksync_node_genesis::{insert_genesis_batch, mock_genesis_config, GenesisParams};
 use zksync_node_test_utils::{
-    create_l1_batch, create_l1_batch_metadata, l1_batch_metadata_to_commitment_artifacts,
+    create_l1_batch, create_l1_batch_metadata, create_l2_transaction, execute_l2_transaction,
+    l1_batch_metadata_to_commitment_artifacts,
 };
 use zksync_types::{
-    aggregated_operations::AggregatedActionType, commitment::L1BatchWithMetadata,
-    protocol_version::ProtocolSemanticVersion, web3::Log, ProtocolVersion, ProtocolVersionId, H256,
+    aggregated_operations::AggregatedActionType,
+    commitment::L1BatchWithMetadata,
+    l1::{L1Tx, L1TxCommonData},
+    protocol_version::ProtocolSemanticVersion,
+    web3::Log,
+    Execute, L1BlockNumber, PriorityOpId, ProtocolVersion, ProtocolVersionId, H256,
 };
 
 use super::*;
@@ -107,6 +112,8 @@ pub(crate) fn create_mock_checker(
         l1_data_mismatch_behavior: L1DataMismatchBehavior::Bail,
         pool,
         commitment_mode,
+        commitment_mode_transitions: vec![],
+        forced_inclusion_deadline: None,
         health_check,
     }
 }
@@ -459,6 +466,241 @@ async fn normal_checker_function(
     checker_task.await.unwrap().unwrap();
 }
 
+fn flip_commitment_mode(mode: L1BatchCommitmentMode) -> L1BatchCommitmentMode {
+    match mode {
+        L1BatchCommitmentMode::Rollup => L1BatchCommitmentMode::Validium,
+        L1BatchCommitmentMode::Validium => L1BatchCommitmentMode::Rollup,
+    }
+}
+
+#[test_casing(2, COMMITMENT_MODES)]
+#[tokio::test]
+async fn commitment_mode_for_batch_with_transitions(commitment_mode: L1BatchCommitmentMode) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let checker = create_mock_checker(create_mock_ethereum(), pool, commitment_mode)
+        .with_commitment_mode_transitions(vec![
+            L1BatchNumber(8),
+            L1BatchNumber(4),
+            L1BatchNumber(8),
+        ]);
+    assert_eq!(
+        checker.commitment_mode_transitions,
+        [L1BatchNumber(4), L1BatchNumber(8)]
+    );
+
+    let previous_mode = flip_commitment_mode(commitment_mode);
+    for number in 0..4 {
+        assert_eq!(
+            checker.commitment_mode_for_batch(L1BatchNumber(number)),
+            commitment_mode
+        );
+    }
+    for number in 4..8 {
+        assert_eq!(
+            checker.commitment_mode_for_batch(L1BatchNumber(number)),
+            previous_mode
+        );
+    }
+    for number in 8..12 {
+        assert_eq!(
+            checker.commitment_mode_for_batch(L1BatchNumber(number)),
+            commitment_mode
+        );
+    }
+}
+
+/// Commits batches #1..=10 switching the DA mode at batches #4 and #8, so the checked range crosses both switches.
+/// If `all_transitions_known` is false, the checker only knows about the last switch and must bail on batch #1.
+#[test_casing(4, Product(([false, true], COMMITMENT_MODES)))]
+#[tokio::test]
+async fn checker_handles_commitment_mode_transitions(
+    all_transitions_known: bool,
+    commitment_mode: L1BatchCommitmentMode,
+) {
+    let transitions = [L1BatchNumber(4), L1BatchNumber(8)];
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batches: Vec<_> = (1..=10).map(create_l1_batch_with_metadata).collect();
+    let mut commit_tx_hash_by_l1_batch = HashMap::with_capacity(l1_batches.len());
+    let client = create_mock_ethereum();
+
+    // Batches committed in each DA mode are sent in a separate transaction.
+    let chunks = [&l1_batches[..3], &l1_batches[3..7], &l1_batches[7..]];
+    let modes = [
+        commitment_mode,
+        flip_commitment_mode(commitment_mode),
+        commitment_mode,
+    ];
+    for (i, (l1_batches, mode)) in chunks.into_iter().zip(modes).enumerate() {
+        let input_data = build_commit_tx_input_data(l1_batches, mode);
+        let signed_tx = client.sign_prepared_tx(
+            input_data,
+            VALIDATOR_TIMELOCK_ADDR,
+            Options {
+                nonce: Some(i.into()),
+                ..Options::default()
+            },
+        );
+        let signed_tx = signed_tx.unwrap();
+        client.as_ref().send_raw_tx(signed_tx.raw_tx).await.unwrap();
+        client
+            .execute_tx(signed_tx.hash, true, 1)
+            .with_logs(l1_batches.iter().map(l1_batch_commit_log).collect());
+
+        commit_tx_hash_by_l1_batch.extend(
+            l1_batches
+                .iter()
+                .map(|batch| (batch.header.number, signed_tx.hash)),
+        );
+    }
+
+    for l1_batch in &l1_batches {
+        let save_actions = [
+            SaveAction::InsertBatch(l1_batch),
+            SaveAction::SaveMetadata(l1_batch),
+            SaveAction::InsertCommitTx(l1_batch.header.number),
+        ];
+        for save_action in save_actions {
+            save_action
+                .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+                .await;
+        }
+    }
+    drop(storage);
+
+    let known_transitions = if all_transitions_known {
+        transitions.to_vec()
+    } else {
+        vec![transitions[1]]
+    };
+    let (l1_batch_updates_sender, mut l1_batch_updates_receiver) = mpsc::unbounded_channel();
+    let checker = ConsistencyChecker {
+        event_handler: Box::new(l1_batch_updates_sender),
+        ..create_mock_checker(client, pool, commitment_mode)
+    }
+    .with_commitment_mode_transitions(known_transitions);
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    if !all_transitions_known {
+        // The checker must stop with an error.
+        tokio::time::timeout(Duration::from_secs(30), checker.run(stop_receiver))
+            .await
+            .expect("Timed out waiting for checker to stop")
+            .unwrap_err();
+        return;
+    }
+
+    let checker_task = tokio::spawn(checker.run(stop_receiver));
+    let mut checked_batches = vec![];
+    while checked_batches.last() != Some(&l1_batches.last().unwrap().header.number) {
+        checked_batches.push(l1_batch_updates_receiver.recv().await.unwrap());
+    }
+    // All batches, including ones on both sides of each transition, must be checked.
+    let expected_batches: Vec<_> = l1_batches.iter().map(|batch| batch.header.number).collect();
+    assert_eq!(checked_batches, expected_batches);
+
+    stop_sender.send_replace(true);
+    checker_task.await.unwrap().unwrap();
+}
+
+#[test_casing(2, [2, 3])]
+#[tokio::test]
+async fn checker_verifies_inclusion_deadline(priority_op_batch: u32) {
+    const DEADLINE_BATCHES: u32 = 2;
+
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batches: Vec<_> = (1..=3).map(create_l1_batch_with_metadata).collect();
+    let client = create_mock_ethereum();
+    let input_data = build_commit_tx_input_data(&l1_batches, L1BatchCommitmentMode::Rollup);
+    let signed_tx = client
+        .sign_prepared_tx(input_data, VALIDATOR_TIMELOCK_ADDR, Options::default())
+        .unwrap();
+    client.as_ref().send_raw_tx(signed_tx.raw_tx).await.unwrap();
+    client
+        .execute_tx(signed_tx.hash, true, 1)
+        .with_logs(l1_batches.iter().map(l1_batch_commit_log).collect());
+    let commit_tx_hash_by_l1_batch: HashMap<_, _> = l1_batches
+        .iter()
+        .map(|batch| (batch.header.number, signed_tx.hash))
+        .collect();
+    for save_action in SAVE_ACTION_MAPPERS[0].1(&l1_batches) {
+        save_action
+            .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+            .await;
+    }
+
+    // Mock L1 blocks have zero timestamp, so all non-genesis L1 batches are opened after the priority op submission.
+    let priority_op = L1Tx {
+        common_data: L1TxCommonData {
+            serial_id: PriorityOpId(0),
+            canonical_tx_hash: H256::repeat_byte(0xfe),
+            ..L1TxCommonData::default()
+        },
+        execute: Execute::default(),
+        received_timestamp_ms: 0,
+    };
+    storage
+        .transactions_dal()
+        .insert_transaction_l1(&priority_op, L1BlockNumber(1), 0)
+        .await
+        .unwrap();
+    let mut tx_result = execute_l2_transaction(create_l2_transaction(1, 1));
+    tx_result.hash = priority_op.hash();
+    tx_result.transaction = priority_op.into();
+    storage
+        .transactions_dal()
+        .mark_txs_as_executed_in_l1_batch(L1BatchNumber(priority_op_batch), &[tx_result])
+        .await
+        .unwrap();
+    drop(storage);
+
+    let (l1_batch_updates_sender, mut l1_batch_updates_receiver) = mpsc::unbounded_channel();
+    let checker = ConsistencyChecker {
+        event_handler: Box::new(l1_batch_updates_sender),
+        ..create_mock_checker(client, pool, L1BatchCommitmentMode::Rollup)
+    }
+    .with_forced_inclusion_deadline(DEADLINE_BATCHES);
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let checker_task = tokio::spawn(checker.run(stop_receiver));
+
+    if priority_op_batch > DEADLINE_BATCHES {
+        let err = tokio::time::timeout(Duration::from_secs(30), checker_task)
+            .await
+            .expect("Timed out waiting for checker to stop")
+            .unwrap()
+            .unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.contains("inclusion deadline"), "{err}");
+
+        let mut last_checked_batch = None;
+        while let Some(checked_batch) = l1_batch_updates_receiver.recv().await {
+            last_checked_batch = Some(checked_batch);
+        }
+        assert_eq!(
+            last_checked_batch,
+            Some(L1BatchNumber(priority_op_batch - 1))
+        );
+    } else {
+        loop {
+            let checked_batch = l1_batch_updates_receiver.recv().await.unwrap();
+            if checked_batch == l1_batches.last().unwrap().header.number {
+                break;
+            }
+        }
+        stop_sender.send_replace(true);
+        checker_task.await.unwrap().unwrap();
+    }
+}
+
 #[test_casing(8, Product((SAVE_ACTION_MAPPERS, COMMITMENT_MODES)))]
 #[tokio::test]
 async fn checker_processes_pre_boojum_batches(
@@ -55,6 +55,10 @@ impl RestApi {
                 "/contract_verification/info/:address",
                 axum::routing::get(Self::verification_info),
             )
+            .route(
+                "/contract_verification/sources/:address",
+                axum::routing::get(Self::verified_sources),
+            )
             .layer(CorsLayer::permissive())
             .with_state(Arc::new(self))
     }
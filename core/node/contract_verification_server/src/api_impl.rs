@@ -12,6 +12,7 @@ use zksync_types::{
     bytecode::BytecodeMarker,
     contract_verification_api::{
         CompilerVersions, VerificationIncomingRequest, VerificationInfo, VerificationRequestStatus,
+        VerifiedSourceBundle,
     },
     Address,
 };
@@ -231,4 +232,23 @@ impl RestApi {
         method_latency.observe();
         Ok(Json(info))
     }
+
+    /// Returns the full source tree, compiler settings and constructor args of a verified contract.
+    #[tracing::instrument(skip(self_))]
+    pub async fn verified_sources(
+        State(self_): State<Arc<Self>>,
+        address: Path<Address>,
+    ) -> ApiResult<VerifiedSourceBundle> {
+        let method_latency = METRICS.call[&"contract_verification_sources"].start();
+        let info = self_
+            .replica_connection_pool
+            .connection_tagged("api")
+            .await?
+            .contract_verification_dal()
+            .get_contract_verification_info(*address)
+            .await?
+            .ok_or(ApiError::VerificationInfoNotFound)?;
+        method_latency.observe();
+        Ok(Json(VerifiedSourceBundle::new(info)))
+    }
 }
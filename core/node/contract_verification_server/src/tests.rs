@@ -13,7 +13,10 @@ use zksync_dal::{Connection, Core, CoreDal};
 use zksync_node_test_utils::create_l2_block;
 use zksync_types::{
     bytecode::{BytecodeHash, BytecodeMarker},
-    contract_verification_api::CompilerVersions,
+    contract_verification_api::{
+        CompilationArtifacts, CompilerVersions, VerificationIncomingRequest, VerificationInfo,
+        VerificationRequest,
+    },
     get_code_key, Address, L2BlockNumber, ProtocolVersion, StorageLog,
 };
 
@@ -328,6 +331,63 @@ async fn querying_missing_verification_info() {
     assert_eq!(error_message, ApiError::VerificationInfoNotFound.message());
 }
 
+#[tokio::test]
+async fn querying_verified_sources() {
+    let pool = ConnectionPool::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+    let address = Address::repeat_byte(0x23);
+    mock_deploy_contract(&mut storage, address, BytecodeMarker::EraVm).await;
+
+    let verification_request = serde_json::json!({
+        "contractAddress": address,
+        "sourceCode": "contract Test {}",
+        "contractName": "Test",
+        "compilerZksolcVersion": ZKSOLC_VERSION,
+        "compilerSolcVersion": SOLC_VERSION,
+        "optimizationUsed": true,
+        "constructorArguments": "0x0123",
+    });
+    let request: VerificationIncomingRequest =
+        serde_json::from_value(verification_request).unwrap();
+    let id = storage
+        .contract_verification_dal()
+        .add_contract_verification_request(&request)
+        .await
+        .unwrap();
+    let info = VerificationInfo {
+        request: VerificationRequest { id, req: request },
+        artifacts: CompilationArtifacts {
+            bytecode: vec![0; 32],
+            deployed_bytecode: None,
+            abi: serde_json::json!([]),
+        },
+        verified_at: Default::default(),
+    };
+    storage
+        .contract_verification_dal()
+        .save_verification_info(info, None)
+        .await
+        .unwrap();
+
+    let router = RestApi::new(pool.clone(), pool).into_router();
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/contract_verification/sources/{address:?}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(req).await.unwrap();
+    let bundle = json_response(response).await;
+    assert_eq!(bundle["contractName"], "Test");
+    assert_eq!(
+        bundle["sources"],
+        serde_json::json!({ "Test.sol": "contract Test {}" })
+    );
+    assert_eq!(bundle["constructorArguments"], "0x0123");
+    assert_eq!(bundle["compilerSolcVersion"], SOLC_VERSION);
+    assert_eq!(bundle["compilerZksolcVersion"], ZKSOLC_VERSION);
+}
+
 #[tokio::test]
 async fn mismatched_compiler_type() {
     let pool = ConnectionPool::test_pool().await;
@@ -2,6 +2,7 @@ use std::{future::Future, time::Duration};
 
 use anyhow::Context;
 use chrono::Utc;
+use futures::{stream, StreamExt};
 use rand::Rng;
 use tokio::sync::watch::Receiver;
 use zksync_config::DADispatcherConfig;
@@ -10,7 +11,7 @@ use zksync_da_client::{
     DataAvailabilityClient,
 };
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::L1BatchNumber;
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
 
 use crate::metrics::METRICS;
 
@@ -34,30 +35,32 @@ impl DataAvailabilityDispatcher {
         }
     }
 
+    /// Runs the dispatcher until a stop signal is received. Errors are logged and the failed operations
+    /// are retried on the next iteration, except for retriable DA client errors (see [`Self::is_retriable_error()`])
+    /// that persisted after all retries; such errors are returned, so that the dispatcher can be restarted.
     pub async fn run(self, mut stop_receiver: Receiver<bool>) -> anyhow::Result<()> {
         loop {
             if *stop_receiver.borrow() {
                 break;
             }
 
-            let subtasks = futures::future::join(
-                async {
-                    if let Err(err) = self.dispatch().await {
-                        tracing::error!("dispatch error {err:?}");
-                    }
-                },
-                async {
-                    if let Err(err) = self.poll_for_inclusion().await {
-                        tracing::error!("poll_for_inclusion error {err:?}");
-                    }
-                },
-            );
-
-            tokio::select! {
-                _ = subtasks => {},
+            let subtasks = futures::future::join(self.dispatch(), self.poll_for_inclusion());
+            let (dispatch_result, poll_result) = tokio::select! {
+                results = subtasks => results,
                 _ = stop_receiver.changed() => {
                     break;
                 }
+            };
+            for (result, operation) in [
+                (dispatch_result, "dispatch"),
+                (poll_result, "poll_for_inclusion"),
+            ] {
+                if let Err(err) = result {
+                    if Self::is_retriable_error(&err) {
+                        return Err(err.context(operation));
+                    }
+                    tracing::error!("{operation} error {err:?}");
+                }
             }
 
             if tokio::time::timeout(self.config.polling_interval(), stop_receiver.changed())
@@ -72,7 +75,19 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
+    /// Checks whether the error returned by [`Self::run()`] is caused by a retriable DA client error.
+    pub fn is_retriable_error(err: &anyhow::Error) -> bool {
+        err.chain()
+            .filter_map(|err| err.downcast_ref::<DAError>())
+            .any(DAError::is_retriable)
+    }
+
     /// Dispatches the blobs to the data availability layer, and saves the blob_id in the database.
+    ///
+    /// Up to `max_concurrent_dispatches` blobs are dispatched concurrently. Dispatch results are persisted
+    /// in the L1 batch order and only for a contiguous prefix of batches, so that a batch is never marked as dispatched
+    /// before all preceding batches. If a dispatch fails, the failed batch and all following batches are dispatched
+    /// again on the next iteration.
     async fn dispatch(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let batches = conn
@@ -81,79 +96,115 @@ impl DataAvailabilityDispatcher {
             .await?;
         drop(conn);
 
-        for batch in batches {
-            let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+        // `buffered()` yields results in the order of the input stream, even if the DA layer responds out of order.
+        let mut dispatches = stream::iter(&batches)
+            .map(|batch| async move {
+                let dispatch_latency = METRICS.blob_dispatch_latency.start();
+                let dispatch_response =
+                    retry(self.config.max_retries(), batch.l1_batch_number, || {
+                        self.client
+                            .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
+                            batch.l1_batch_number,
+                            batch.pubdata.len()
+                        )
+                    });
+                (batch, dispatch_response, dispatch_latency.observe())
             })
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
-                    batch.l1_batch_number,
-                    batch.pubdata.len()
-                )
-            })?;
-            let dispatch_latency_duration = dispatch_latency.observe();
-
-            let sent_at = Utc::now().naive_utc();
-
-            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
-            conn.data_availability_dal()
-                .insert_l1_batch_da(
-                    batch.l1_batch_number,
-                    dispatch_response.blob_id.as_str(),
-                    sent_at,
-                )
-                .await?;
-            drop(conn);
-
-            METRICS
-                .last_dispatched_l1_batch
-                .set(batch.l1_batch_number.0 as usize);
-            METRICS.blob_size.observe(batch.pubdata.len());
-            tracing::info!(
-                "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
+            .buffered(self.config.max_concurrent_dispatches() as usize);
+
+        while let Some((batch, dispatch_response, dispatch_latency_duration)) =
+            dispatches.next().await
+        {
+            let response = dispatch_response?;
+            self.save_dispatched_blob(
                 batch.l1_batch_number,
                 batch.pubdata.len(),
-            );
+                &response.blob_id,
+                dispatch_latency_duration,
+            )
+            .await?;
         }
+        Ok(())
+    }
+
+    async fn save_dispatched_blob(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        pubdata_len: usize,
+        blob_id: &str,
+        dispatch_latency_duration: Duration,
+    ) -> anyhow::Result<()> {
+        let sent_at = Utc::now().naive_utc();
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        conn.data_availability_dal()
+            .insert_l1_batch_da(l1_batch_number, blob_id, sent_at)
+            .await?;
+        drop(conn);
 
+        METRICS
+            .last_dispatched_l1_batch
+            .set(l1_batch_number.0 as usize);
+        METRICS.blob_size.observe(pubdata_len);
+        tracing::info!(
+            "Dispatched a DA for batch_number: {l1_batch_number}, pubdata_size: {pubdata_len}, dispatch_latency: {dispatch_latency_duration:?}",
+        );
         Ok(())
     }
 
     /// Polls the data availability layer for inclusion data, and saves it in the database.
+    ///
+    /// Up to `max_rows_to_dispatch` blobs are polled per iteration, with up to `max_concurrent_dispatches`
+    /// concurrent requests to the DA layer. Similar to dispatching, inclusion data is saved only for a contiguous
+    /// prefix of batches, since L1 batches are committed in order once their inclusion data is present. Polling stops
+    /// at the first blob that is not included yet or that failed to be polled.
     async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
-        let blob_info = conn
+        let blobs = conn
             .data_availability_dal()
-            .get_first_da_blob_awaiting_inclusion()
+            .get_da_blobs_awaiting_inclusion(self.config.max_rows_to_dispatch() as usize)
             .await?;
         drop(conn);
 
-        let Some(blob_info) = blob_info else {
-            return Ok(());
-        };
+        let mut inclusions = stream::iter(&blobs)
+            .map(|blob_info| async move {
+                let inclusion_data = if self.config.use_dummy_inclusion_data() {
+                    Ok(Some(InclusionData { data: vec![] }))
+                } else {
+                    self.client
+                        .get_inclusion_data(blob_info.blob_id.as_str())
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "failed to get inclusion data for blob_id: {}, batch_number: {}",
+                                blob_info.blob_id, blob_info.l1_batch_number
+                            )
+                        })
+                };
+                (blob_info, inclusion_data)
+            })
+            .buffered(self.config.max_concurrent_dispatches() as usize);
 
-        let inclusion_data = if self.config.use_dummy_inclusion_data() {
-            Some(InclusionData { data: vec![] })
-        } else {
-            self.client
-                .get_inclusion_data(blob_info.blob_id.as_str())
-                .await
-                .with_context(|| {
-                    format!(
-                        "failed to get inclusion data for blob_id: {}, batch_number: {}",
-                        blob_info.blob_id, blob_info.l1_batch_number
-                    )
-                })?
-        };
-
-        let Some(inclusion_data) = inclusion_data else {
-            return Ok(());
-        };
+        while let Some((blob_info, inclusion_data)) = inclusions.next().await {
+            let Some(inclusion_data) = inclusion_data? else {
+                // The blob is not included yet.
+                break;
+            };
+            self.save_inclusion_data(blob_info, &inclusion_data).await?;
+        }
+        Ok(())
+    }
 
+    async fn save_inclusion_data(
+        &self,
+        blob_info: &DataAvailabilityBlob,
+        inclusion_data: &InclusionData,
+    ) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         conn.data_availability_dal()
             .save_l1_batch_inclusion_data(
@@ -176,7 +227,6 @@ impl DataAvailabilityDispatcher {
             blob_info.l1_batch_number,
             inclusion_latency.num_seconds()
         );
-
         Ok(())
     }
 }
@@ -0,0 +1,227 @@
+//! Tests for the DA dispatcher.
+
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use test_casing::test_casing;
+use tokio::sync::watch;
+use zksync_config::DADispatcherConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_node_test_utils::create_l1_batch;
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber, ProtocolVersion};
+
+use crate::DataAvailabilityDispatcher;
+
+const BATCH_COUNT: u32 = 5;
+
+#[derive(Debug, Default)]
+struct MockDAClientInner {
+    failing_batches: HashSet<u32>,
+    failures_are_retriable: bool,
+    dispatched_batches: Vec<u32>,
+    included_blobs: HashSet<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct MockDAClient(Arc<Mutex<MockDAClientInner>>);
+
+impl MockDAClient {
+    fn blob_id(batch_number: u32) -> String {
+        format!("blob{batch_number}")
+    }
+
+    fn inner(&self) -> std::sync::MutexGuard<'_, MockDAClientInner> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MockDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        _data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        // Respond to later batches faster, so that responses arrive out of order.
+        let delay = 10 * u64::from(BATCH_COUNT + 1 - batch_number);
+        tokio::time::sleep(Duration::from_millis(delay)).await;
+
+        let mut inner = self.inner();
+        if inner.failing_batches.contains(&batch_number) {
+            return Err(DAError {
+                error: anyhow::anyhow!("dispatch failed"),
+                is_retriable: inner.failures_are_retriable,
+            });
+        }
+        inner.dispatched_batches.push(batch_number);
+        Ok(Self::blob_id(batch_number).into())
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        Ok(self
+            .inner()
+            .included_blobs
+            .contains(blob_id)
+            .then(|| InclusionData {
+                data: blob_id.as_bytes().to_vec(),
+            }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        None
+    }
+}
+
+async fn setup_storage(pool: &ConnectionPool<Core>) {
+    let mut storage = pool.connection().await.unwrap();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    for number in 1..=BATCH_COUNT {
+        let mut header = create_l1_batch(number);
+        header.pubdata_input = Some(vec![number as u8; 4]);
+        storage
+            .blocks_dal()
+            .insert_mock_l1_batch(&header)
+            .await
+            .unwrap();
+    }
+}
+
+fn create_dispatcher(
+    pool: ConnectionPool<Core>,
+    client: MockDAClient,
+) -> DataAvailabilityDispatcher {
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        max_concurrent_dispatches: Some(BATCH_COUNT),
+        ..DADispatcherConfig::for_tests()
+    };
+    DataAvailabilityDispatcher::new(pool, config, Box::new(client))
+}
+
+async fn blobs_awaiting_inclusion(pool: &ConnectionPool<Core>) -> Vec<DataAvailabilityBlob> {
+    let mut storage = pool.connection().await.unwrap();
+    storage
+        .data_availability_dal()
+        .get_da_blobs_awaiting_inclusion(100)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn dispatching_and_polling_preserve_batch_order() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_storage(&pool).await;
+    let client = MockDAClient::default();
+    let dispatcher = create_dispatcher(pool.clone(), client.clone());
+
+    dispatcher.dispatch().await.unwrap();
+
+    let mut dispatched_batches = client.inner().dispatched_batches.clone();
+    dispatched_batches.sort_unstable();
+    assert_eq!(dispatched_batches, (1..=BATCH_COUNT).collect::<Vec<_>>());
+
+    let blobs = blobs_awaiting_inclusion(&pool).await;
+    assert_eq!(blobs.len(), BATCH_COUNT as usize);
+    for (blob, number) in blobs.iter().zip(1..) {
+        assert_eq!(blob.l1_batch_number, L1BatchNumber(number));
+        assert_eq!(blob.blob_id, MockDAClient::blob_id(number));
+    }
+    // Dispatch results must be persisted in the batch order even though the DA layer responds in the reverse order.
+    assert!(
+        blobs
+            .windows(2)
+            .all(|pair| pair[0].sent_at <= pair[1].sent_at),
+        "{blobs:?}"
+    );
+
+    // Inclusion data must be saved only for a contiguous prefix of batches, even though batch #4 is included.
+    client
+        .inner()
+        .included_blobs
+        .extend([1, 2, 4].map(MockDAClient::blob_id));
+    dispatcher.poll_for_inclusion().await.unwrap();
+
+    let blobs = blobs_awaiting_inclusion(&pool).await;
+    let pending_batches: Vec<_> = blobs.iter().map(|blob| blob.l1_batch_number.0).collect();
+    assert_eq!(pending_batches, [3, 4, 5]);
+
+    client
+        .inner()
+        .included_blobs
+        .insert(MockDAClient::blob_id(3));
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blobs = blobs_awaiting_inclusion(&pool).await;
+    let pending_batches: Vec<_> = blobs.iter().map(|blob| blob.l1_batch_number.0).collect();
+    assert_eq!(pending_batches, [5]);
+}
+
+#[tokio::test]
+async fn dispatches_are_persisted_up_to_first_failure() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_storage(&pool).await;
+    let client = MockDAClient::default();
+    client.inner().failing_batches.insert(2);
+    let dispatcher = create_dispatcher(pool.clone(), client.clone());
+
+    let err = dispatcher.dispatch().await.unwrap_err();
+    assert!(format!("{err:#}").contains("batch_number: 2"), "{err:#}");
+
+    // Batches following the failed one must not be persisted, so that dispatched batches form a contiguous prefix.
+    let blobs = blobs_awaiting_inclusion(&pool).await;
+    let dispatched_batches: Vec<_> = blobs.iter().map(|blob| blob.l1_batch_number.0).collect();
+    assert_eq!(dispatched_batches, [1]);
+
+    client.inner().failing_batches.clear();
+    client.inner().dispatched_batches.clear();
+    dispatcher.dispatch().await.unwrap();
+    let mut dispatched_batches = client.inner().dispatched_batches.clone();
+    dispatched_batches.sort_unstable();
+    assert_eq!(dispatched_batches, [2, 3, 4, 5]);
+    let blobs = blobs_awaiting_inclusion(&pool).await;
+    assert_eq!(blobs.len(), BATCH_COUNT as usize);
+}
+
+#[test_casing(2, [false, true])]
+#[tokio::test]
+async fn dispatcher_is_stopped_only_on_retriable_errors(failures_are_retriable: bool) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_storage(&pool).await;
+    let client = MockDAClient::default();
+    client.inner().failing_batches.insert(2);
+    client.inner().failures_are_retriable = failures_are_retriable;
+    let dispatcher = create_dispatcher(pool.clone(), client.clone());
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let dispatcher_task = tokio::spawn(dispatcher.run(stop_receiver));
+    if failures_are_retriable {
+        let err = dispatcher_task.await.unwrap().unwrap_err();
+        assert!(
+            DataAvailabilityDispatcher::is_retriable_error(&err),
+            "{err:#}"
+        );
+    } else {
+        // Non-retriable errors are logged, and the dispatcher keeps running.
+        while blobs_awaiting_inclusion(&pool).await.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!dispatcher_task.is_finished());
+        stop_sender.send_replace(true);
+        dispatcher_task.await.unwrap().unwrap();
+    }
+}
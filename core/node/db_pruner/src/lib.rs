@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::watch;
 use zksync_dal::{pruning_dal::PruningInfo, Connection, ConnectionPool, Core, CoreDal};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
-use zksync_types::{L1BatchNumber, L2BlockNumber};
+use zksync_types::{Address, L1BatchNumber, L2BlockNumber};
 
 use self::{
     metrics::{ConditionOutcome, PruneType, METRICS},
@@ -36,6 +36,9 @@ pub struct DbPrunerConfig {
     /// Minimum age of an L1 batch in order for it to be eligible for pruning. Setting this to zero
     /// will effectively disable this pruning criterion.
     pub minimum_l1_batch_age: Duration,
+    /// Addresses for which full history (events, call traces, transaction data and overwritten storage values)
+    /// is retained during hard pruning. L1 batch and L2 block headers are still pruned.
+    pub retained_addresses: Vec<Address>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -244,9 +247,10 @@ impl DbPruner {
 
         let mut dal = transaction.pruning_dal();
         let stats = tokio::select! {
-            result = dal.hard_prune_batches_range(
+            result = dal.hard_prune_batches_range_retaining(
                 last_soft_pruned_l1_batch,
                 last_soft_pruned_l2_block,
+                &self.config.retained_addresses,
             ) => result?,
 
             _ = stop_receiver.changed() => {
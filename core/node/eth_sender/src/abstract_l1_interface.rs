@@ -2,6 +2,7 @@ use std::fmt;
 
 use async_trait::async_trait;
 use vise::{EncodeLabelSet, EncodeLabelValue};
+use zksync_config::configs::eth_sender::L1FeeMarket;
 use zksync_eth_client::{
     BoundEthInterface, EnrichedClientResult, EthInterface, ExecutedTxStatus, FailureInfo, Options,
     RawTransactionBytes, SignedCallResult,
@@ -11,7 +12,7 @@ use zksync_types::web3;
 use zksync_types::{
     eth_sender::{EthTx, EthTxBlobSidecar},
     web3::{BlockId, BlockNumber},
-    Address, L1BlockNumber, Nonce, EIP_1559_TX_TYPE, EIP_4844_TX_TYPE, H256, U256,
+    Address, L1BlockNumber, Nonce, EIP_1559_TX_TYPE, EIP_4844_TX_TYPE, H256, LEGACY_TX_TYPE, U256,
 };
 
 use crate::EthSenderError;
@@ -36,6 +37,10 @@ pub(crate) struct L1BlockNumbers {
 pub(crate) enum OperatorType {
     NonBlob,
     Blob,
+    /// Dedicated operator for `PublishProofOnchain` transactions.
+    Prove,
+    /// Dedicated operator for `Execute` transactions.
+    Execute,
     Gateway,
 }
 
@@ -68,7 +73,9 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
         operator_type: OperatorType,
     ) -> EnrichedClientResult<H256>;
 
-    fn get_blobs_operator_account(&self) -> Option<Address>;
+    /// Returns the account of the operator, or `None` for the main operator
+    /// (for which `from_addr` isn't set on `eth_txs`).
+    fn get_operator_account(&self, operator_type: OperatorType) -> Option<Address>;
 
     async fn get_operator_nonce(
         &self,
@@ -96,25 +103,32 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
 pub(super) struct RealL1Interface {
     pub ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
     pub ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
+    pub ethereum_gateway_prove: Option<Box<dyn BoundEthInterface>>,
+    pub ethereum_gateway_execute: Option<Box<dyn BoundEthInterface>>,
     pub l2_gateway: Option<Box<dyn BoundEthInterface>>,
     pub wait_confirmations: Option<u64>,
+    pub l1_fee_market: L1FeeMarket,
 }
 
 impl RealL1Interface {
     fn query_client(&self, operator_type: OperatorType) -> &dyn EthInterface {
-        match operator_type {
-            OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap().as_ref(),
-            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap().as_ref(),
-            OperatorType::Gateway => self.l2_gateway.as_deref().unwrap().as_ref(),
-        }
+        self.bound_query_client(operator_type).as_ref()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the operator of the specified type is not configured. Operator types must be obtained
+    /// from [`AbstractL1Interface::supported_operator_types()`] or from transactions via `EthTxManager`,
+    /// which errors for transactions sent by operators that are not configured.
     fn bound_query_client(&self, operator_type: OperatorType) -> &dyn BoundEthInterface {
-        match operator_type {
-            OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap(),
-            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap(),
-            OperatorType::Gateway => self.l2_gateway.as_deref().unwrap(),
-        }
+        let client = match operator_type {
+            OperatorType::NonBlob => self.ethereum_gateway.as_deref(),
+            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref(),
+            OperatorType::Prove => self.ethereum_gateway_prove.as_deref(),
+            OperatorType::Execute => self.ethereum_gateway_execute.as_deref(),
+            OperatorType::Gateway => self.l2_gateway.as_deref(),
+        };
+        client.unwrap_or_else(|| panic!("{operator_type:?} operator is not configured"))
     }
 }
 
@@ -128,6 +142,12 @@ impl AbstractL1Interface for RealL1Interface {
         if self.ethereum_gateway_blobs.is_some() {
             result.push(OperatorType::Blob)
         }
+        if self.ethereum_gateway_prove.is_some() {
+            result.push(OperatorType::Prove)
+        }
+        if self.ethereum_gateway_execute.is_some() {
+            result.push(OperatorType::Execute)
+        }
         if self.ethereum_gateway.is_some() {
             result.push(OperatorType::NonBlob);
         }
@@ -175,11 +195,14 @@ impl AbstractL1Interface for RealL1Interface {
         self.query_client(operator_type).send_raw_tx(tx_bytes).await
     }
 
-    fn get_blobs_operator_account(&self) -> Option<Address> {
-        self.ethereum_gateway_blobs
-            .as_deref()
-            .as_ref()
-            .map(|s| s.sender_account())
+    fn get_operator_account(&self, operator_type: OperatorType) -> Option<Address> {
+        let client = match operator_type {
+            OperatorType::NonBlob | OperatorType::Gateway => return None,
+            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref(),
+            OperatorType::Prove => self.ethereum_gateway_prove.as_deref(),
+            OperatorType::Execute => self.ethereum_gateway_execute.as_deref(),
+        };
+        client.map(BoundEthInterface::sender_account)
     }
 
     async fn get_operator_nonce(
@@ -223,6 +246,11 @@ impl AbstractL1Interface for RealL1Interface {
                     opt.max_priority_fee_per_gas = Some(U256::from(priority_fee_per_gas));
                     opt.nonce = Some(tx.nonce.0.into());
                     opt.transaction_type = Some(EIP_1559_TX_TYPE.into());
+                    if self.l1_fee_market == L1FeeMarket::Legacy {
+                        // Legacy transactions are signed with `max_fee_per_gas` used as the gas price.
+                        opt.transaction_type = Some(LEGACY_TX_TYPE.into());
+                        opt.gas_price = opt.max_fee_per_gas;
+                    }
                     if tx.blob_sidecar.is_some() {
                         opt.transaction_type = Some(EIP_4844_TX_TYPE.into());
                         opt.max_fee_per_blob_gas = blob_gas_price;
@@ -23,6 +23,22 @@ use super::{
     },
 };
 
+/// Transaction types sent by dedicated operators rather than the main one. Each operator has its own nonces,
+/// so transactions of different types can be in flight concurrently.
+///
+/// There is at most one operator per transaction type. The L1 contract processes operations of each type
+/// in the L1 batch order, so spreading transactions of the same type across several accounts would require
+/// waiting for each transaction to be confirmed, which would serialize them again.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CustomOperators {
+    /// Commit transactions are sent by the blob operator (i.e., the node operates in the 4844 mode).
+    pub commit: bool,
+    /// Prove transactions are sent by the prove operator.
+    pub prove: bool,
+    /// Execute transactions are sent by the execute operator.
+    pub execute: bool,
+}
+
 #[derive(Debug)]
 pub struct Aggregator {
     commit_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
@@ -30,12 +46,12 @@ pub struct Aggregator {
     execute_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
     config: SenderConfig,
     blob_store: Arc<dyn ObjectStore>,
-    /// If we are operating in 4844 mode we need to wait for commit transaction
-    /// to get included before sending the respective prove and execute transactions.
-    /// In non-4844 mode of operation we operate with the single address and this
-    /// means no wait is needed: nonces will still provide the correct ordering of
-    /// transactions.
-    operate_4844_mode: bool,
+    /// If commit and prove transactions are sent by different operators, we need to wait for commit transaction
+    /// to get included before sending the respective prove transaction. If they are sent by the same operator,
+    /// no wait is needed: nonces will still provide the correct ordering of transactions.
+    wait_for_commit_confirmation: bool,
+    /// Same as `wait_for_commit_confirmation`, but for prove and execute transactions.
+    wait_for_prove_confirmation: bool,
     pubdata_da: PubdataSendingMode,
     commitment_mode: L1BatchCommitmentMode,
 }
@@ -44,7 +60,7 @@ impl Aggregator {
     pub fn new(
         config: SenderConfig,
         blob_store: Arc<dyn ObjectStore>,
-        operate_4844_mode: bool,
+        custom_operators: CustomOperators,
         commitment_mode: L1BatchCommitmentMode,
     ) -> Self {
         let pubdata_da = config.pubdata_sending_mode;
@@ -105,7 +121,8 @@ impl Aggregator {
             ],
             config,
             blob_store,
-            operate_4844_mode,
+            wait_for_commit_confirmation: custom_operators.commit || custom_operators.prove,
+            wait_for_prove_confirmation: custom_operators.prove || custom_operators.execute,
             pubdata_da,
             commitment_mode,
         }
@@ -168,11 +185,14 @@ impl Aggregator {
             .config
             .l1_batch_min_age_before_execute_seconds
             .map(|age| unix_timestamp_ms() - age * 1_000);
-        let ready_for_execute_batches = storage
+        let mut ready_for_execute_batches = storage
             .blocks_dal()
             .get_ready_for_execute_l1_batches(limit, max_l1_batch_timestamp_millis)
             .await
             .unwrap();
+        if self.wait_for_prove_confirmation {
+            retain_l1_batches_with_confirmed_proofs(storage, &mut ready_for_execute_batches).await;
+        }
         let l1_batches = extract_ready_subrange(
             storage,
             &mut self.execute_criteria,
@@ -198,7 +218,7 @@ impl Aggregator {
             .await
             .unwrap()?;
 
-        let ready_for_commit_l1_batches = if protocol_version_id.is_pre_boojum() {
+        let mut ready_for_commit_l1_batches = if protocol_version_id.is_pre_boojum() {
             blocks_dal
                 .pre_boojum_get_ready_for_commit_l1_batches(
                     limit,
@@ -221,6 +241,10 @@ impl Aggregator {
                 .unwrap()
         };
 
+        if self.config.wait_for_batch_verification && !protocol_version_id.is_pre_boojum() {
+            retain_verified_l1_batches(storage, &mut ready_for_commit_l1_batches).await;
+        }
+
         // Check that the L1 batches that are selected are sequential
         ready_for_commit_l1_batches
             .iter()
@@ -248,7 +272,7 @@ impl Aggregator {
     async fn load_dummy_proof_operations(
         storage: &mut Connection<'_, Core>,
         limit: usize,
-        is_4844_mode: bool,
+        wait_for_commit_confirmation: bool,
     ) -> Vec<L1BatchWithMetadata> {
         let mut ready_for_proof_l1_batches = storage
             .blocks_dal()
@@ -258,7 +282,7 @@ impl Aggregator {
 
         // need to find first batch with an unconfirmed commit transaction
         // and discard it and all the following ones.
-        if is_4844_mode {
+        if wait_for_commit_confirmation {
             let mut committed_batches = vec![];
 
             for batch in ready_for_proof_l1_batches.into_iter() {
@@ -293,7 +317,7 @@ impl Aggregator {
         storage: &mut Connection<'_, Core>,
         l1_verifier_config: L1VerifierConfig,
         blob_store: &dyn ObjectStore,
-        is_4844_mode: bool,
+        wait_for_commit_confirmation: bool,
     ) -> Option<ProveBatches> {
         let previous_proven_batch_number = storage
             .blocks_dal()
@@ -309,7 +333,7 @@ impl Aggregator {
             .await
             .unwrap()?;
 
-        if is_4844_mode
+        if wait_for_commit_confirmation
             && storage
                 .eth_sender_dal()
                 .get_confirmed_tx_hash_by_eth_tx_id(commit_tx_id as u32)
@@ -431,14 +455,18 @@ impl Aggregator {
                     storage,
                     l1_verifier_config,
                     &*self.blob_store,
-                    self.operate_4844_mode,
+                    self.wait_for_commit_confirmation,
                 )
                 .await
             }
 
             ProofSendingMode::SkipEveryProof => {
-                let ready_for_proof_l1_batches =
-                    Self::load_dummy_proof_operations(storage, limit, self.operate_4844_mode).await;
+                let ready_for_proof_l1_batches = Self::load_dummy_proof_operations(
+                    storage,
+                    limit,
+                    self.wait_for_commit_confirmation,
+                )
+                .await;
                 self.prepare_dummy_proof_operation(
                     storage,
                     ready_for_proof_l1_batches,
@@ -453,7 +481,7 @@ impl Aggregator {
                     storage,
                     l1_verifier_config,
                     &*self.blob_store,
-                    self.operate_4844_mode,
+                    self.wait_for_commit_confirmation,
                 )
                 .await
                 {
@@ -484,6 +512,66 @@ impl Aggregator {
     }
 }
 
+/// Truncates `l1_batches` to the longest prefix of batches with commitments confirmed by the batch verifier,
+/// i.e., independently recomputed by re-executing the batches.
+pub(crate) async fn retain_verified_l1_batches(
+    storage: &mut Connection<'_, Core>,
+    l1_batches: &mut Vec<L1BatchWithMetadata>,
+) {
+    let (Some(first), Some(last)) = (l1_batches.first(), l1_batches.last()) else {
+        return;
+    };
+    let verified_commitments = storage
+        .vm_runner_dal()
+        .get_batch_verifier_commitments(first.header.number..=last.header.number)
+        .await
+        .unwrap();
+
+    let verified_count = l1_batches
+        .iter()
+        .take_while(|batch| {
+            let number = batch.header.number;
+            match verified_commitments.get(&number) {
+                Some(commitment) if *commitment == batch.metadata.commitment => true,
+                Some(commitment) => {
+                    tracing::error!(
+                        "Commitment for L1 batch #{number} recomputed by the batch verifier ({commitment:?}) \
+                         differs from the persisted one ({:?}); the batch won't be committed",
+                        batch.metadata.commitment
+                    );
+                    false
+                }
+                None => {
+                    tracing::debug!("L1 batch #{number} is not verified yet");
+                    false
+                }
+            }
+        })
+        .count();
+    l1_batches.truncate(verified_count);
+}
+
+/// Truncates `l1_batches` to the batches with confirmed prove transactions. Used if execute transactions are sent
+/// by another operator than prove ones, so that nonces don't guarantee the correct order of transactions on L1.
+pub(crate) async fn retain_l1_batches_with_confirmed_proofs(
+    storage: &mut Connection<'_, Core>,
+    l1_batches: &mut Vec<L1BatchWithMetadata>,
+) {
+    if l1_batches.is_empty() {
+        return;
+    }
+    let last_proven_l1_batch = storage
+        .blocks_dal()
+        .get_number_of_last_l1_batch_proven_on_eth()
+        .await
+        .unwrap();
+    let Some(last_proven_l1_batch) = last_proven_l1_batch else {
+        l1_batches.clear();
+        return;
+    };
+    l1_batches.retain(|batch| batch.header.number <= last_proven_l1_batch);
+}
+
 async fn extract_ready_subrange(
     storage: &mut Connection<'_, Core>,
     publish_criteria: &mut [Box<dyn L1BatchPublishCriterion>],
@@ -1,5 +1,5 @@
 use zksync_eth_client::{ContractCallError, EnrichedClientError};
-use zksync_types::web3::contract;
+use zksync_types::{web3::contract, Address};
 
 #[derive(Debug, thiserror::Error)]
 pub enum EthSenderError {
@@ -9,6 +9,11 @@ pub enum EthSenderError {
     ContractCall(#[from] ContractCallError),
     #[error("Token parsing error: {0}")]
     Parse(#[from] contract::Error),
+    #[error(
+        "eth_tx {eth_tx_id} is sent by operator {from_addr:?}, which is not configured; \
+         the operator key must be kept in config until all its transactions are confirmed"
+    )]
+    OperatorNotConfigured { eth_tx_id: u32, from_addr: Address },
 }
 
 impl EthSenderError {
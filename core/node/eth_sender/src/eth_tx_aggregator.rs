@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use tokio::sync::watch;
 use zksync_config::configs::eth_sender::SenderConfig;
 use zksync_contracts::BaseSystemContractsHashes;
@@ -55,13 +57,23 @@ pub struct EthTxAggregator {
     pub(super) state_transition_chain_contract: Address,
     functions: ZkSyncFunctions,
     base_nonce: u64,
-    base_nonce_custom_commit_sender: Option<u64>,
+    /// Base nonces of the custom senders, keyed by their addresses.
+    base_nonces_custom_senders: HashMap<Address, u64>,
+    /// Set if the main operator key was rotated since the last transaction saved to Postgres. In this case,
+    /// the nonce for the next main operator transaction must be taken from the settlement layer rather than from Postgres.
+    main_operator_rotated: bool,
     rollup_chain_id: L2ChainId,
     /// If set to `Some` node is operating in the 4844 mode with two operator
     /// addresses at play: the main one and the custom address for sending commit
     /// transactions. The `Some` then contains the address of this custom operator
     /// address.
     custom_commit_sender_addr: Option<Address>,
+    /// If set, `PublishProofOnchain` transactions are sent by this operator with its own nonces
+    /// instead of the main operator.
+    custom_prove_sender_addr: Option<Address>,
+    /// If set, `Execute` transactions are sent by this operator with its own nonces
+    /// instead of the main operator.
+    custom_execute_sender_addr: Option<Address>,
     pool: ConnectionPool<Core>,
     settlement_mode: SettlementMode,
     sl_chain_id: SLChainId,
@@ -84,25 +96,33 @@ impl EthTxAggregator {
         state_transition_chain_contract: Address,
         rollup_chain_id: L2ChainId,
         custom_commit_sender_addr: Option<Address>,
+        custom_prove_sender_addr: Option<Address>,
+        custom_execute_sender_addr: Option<Address>,
         settlement_mode: SettlementMode,
     ) -> Self {
         let eth_client = eth_client.for_component("eth_tx_aggregator");
         let functions = ZkSyncFunctions::default();
         let base_nonce = eth_client.pending_nonce().await.unwrap().as_u64();
 
-        let base_nonce_custom_commit_sender = match custom_commit_sender_addr {
-            Some(addr) => Some(
-                (*eth_client)
-                    .as_ref()
-                    .nonce_at_for_account(addr, BlockNumber::Pending)
-                    .await
-                    .unwrap()
-                    .as_u64(),
-            ),
-            None => None,
-        };
+        let mut base_nonces_custom_senders = HashMap::new();
+        let custom_sender_addrs = [
+            custom_commit_sender_addr,
+            custom_prove_sender_addr,
+            custom_execute_sender_addr,
+        ];
+        for addr in custom_sender_addrs.into_iter().flatten() {
+            let nonce = (*eth_client)
+                .as_ref()
+                .nonce_at_for_account(addr, BlockNumber::Pending)
+                .await
+                .unwrap()
+                .as_u64();
+            base_nonces_custom_senders.insert(addr, nonce);
+        }
 
         let sl_chain_id = (*eth_client).as_ref().fetch_chain_id().await.unwrap();
+        let main_operator_rotated =
+            Self::is_main_operator_rotated(&pool, base_nonce, settlement_mode.is_gateway()).await;
 
         Self {
             config,
@@ -113,15 +133,51 @@ impl EthTxAggregator {
             state_transition_chain_contract,
             functions,
             base_nonce,
-            base_nonce_custom_commit_sender,
+            base_nonces_custom_senders,
+            main_operator_rotated,
             rollup_chain_id,
             custom_commit_sender_addr,
+            custom_prove_sender_addr,
+            custom_execute_sender_addr,
             pool,
             settlement_mode,
             sl_chain_id,
         }
     }
 
+    /// If all main operator transactions in Postgres are confirmed, the operator nonce on the settlement layer
+    /// cannot be lower than the next nonce in Postgres unless the operator key was rotated.
+    async fn is_main_operator_rotated(
+        pool: &ConnectionPool<Core>,
+        base_nonce: u64,
+        is_gateway: bool,
+    ) -> bool {
+        let mut storage = pool.connection_tagged("eth_sender").await.unwrap();
+        let db_nonce = storage
+            .eth_sender_dal()
+            .get_next_nonce(None, is_gateway)
+            .await
+            .unwrap();
+        let Some(db_nonce) = db_nonce.filter(|&nonce| nonce > base_nonce) else {
+            return false;
+        };
+        let unconfirmed_txs_count = storage
+            .eth_sender_dal()
+            .get_unconfirmed_txs_count(None, is_gateway)
+            .await
+            .unwrap();
+        if unconfirmed_txs_count > 0 {
+            return false;
+        }
+        tracing::info!(
+            db_nonce,
+            base_nonce,
+            "Main operator nonce is lower than the nonce in Postgres, while all operator transactions \
+             are confirmed; assuming that the operator key was rotated"
+        );
+        true
+    }
+
     pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let pool = self.pool.clone();
         loop {
@@ -590,7 +646,7 @@ impl EthTxAggregator {
     }
 
     pub(super) async fn save_eth_tx(
-        &self,
+        &mut self,
         storage: &mut Connection<'_, Core>,
         aggregated_op: &AggregatedOperation,
         contracts_are_pre_shared_bridge: bool,
@@ -598,12 +654,14 @@ impl EthTxAggregator {
     ) -> Result<EthTx, EthSenderError> {
         let mut transaction = storage.start_transaction().await.unwrap();
         let op_type = aggregated_op.get_action_type();
-        // We may be using a custom sender for commit transactions, so use this
-        // var whatever it actually is: a `None` for single-addr operator or `Some`
-        // for multi-addr operator in 4844 mode.
+        // We may be using custom senders for some transaction types, each with its own nonces,
+        // so use this var whatever it actually is: a `None` for the main operator or `Some`
+        // for a custom one.
         let sender_addr = match (op_type, is_gateway) {
             (AggregatedActionType::Commit, false) => self.custom_commit_sender_addr,
-            (_, _) => None,
+            (AggregatedActionType::PublishProofOnchain, false) => self.custom_prove_sender_addr,
+            (AggregatedActionType::Execute, false) => self.custom_execute_sender_addr,
+            (_, true) => None,
         };
         let nonce = self.get_next_nonce(&mut transaction, sender_addr).await?;
         let encoded_aggregated_op =
@@ -644,6 +702,10 @@ impl EthTxAggregator {
             .await
             .unwrap();
         transaction.commit().await.unwrap();
+        if sender_addr.is_none() {
+            // The main operator nonce in Postgres is now up to date.
+            self.main_operator_rotated = false;
+        }
         Ok(eth_tx)
     }
 
@@ -661,13 +723,15 @@ impl EthTxAggregator {
             .unwrap_or(0);
         // Between server starts we can execute some txs using operator account or remove some txs from the database
         // At the start we have to consider this fact and get the max nonce.
-        Ok(if from_addr.is_none() {
-            db_nonce.max(self.base_nonce)
-        } else {
-            db_nonce.max(
-                self.base_nonce_custom_commit_sender
+        Ok(match from_addr {
+            None if self.main_operator_rotated => self.base_nonce,
+            None => db_nonce.max(self.base_nonce),
+            Some(addr) => db_nonce.max(
+                *self
+                    .base_nonces_custom_senders
+                    .get(&addr)
                     .expect("custom base nonce is expected to be initialized; qed"),
-            )
+            ),
         })
     }
 }
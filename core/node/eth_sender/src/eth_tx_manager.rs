@@ -11,7 +11,10 @@ use zksync_eth_client::{
 };
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_shared_metrics::BlockL1Stage;
-use zksync_types::{eth_sender::EthTx, Address, L1BlockNumber, H256, U256};
+use zksync_types::{
+    aggregated_operations::AggregatedActionType, eth_sender::EthTx, prover_dal::ProvingStage,
+    Address, L1BlockNumber, H256, U256,
+};
 
 use super::{metrics::METRICS, EthSenderError};
 use crate::{
@@ -36,17 +39,25 @@ pub struct EthTxManager {
 }
 
 impl EthTxManager {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: ConnectionPool<Core>,
         config: SenderConfig,
         gas_adjuster: Arc<dyn TxParamsProvider>,
         ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
         ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
+        ethereum_gateway_prove: Option<Box<dyn BoundEthInterface>>,
+        ethereum_gateway_execute: Option<Box<dyn BoundEthInterface>>,
         l2_gateway: Option<Box<dyn BoundEthInterface>>,
     ) -> Self {
         let ethereum_gateway = ethereum_gateway.map(|eth| eth.for_component("eth_tx_manager"));
         let ethereum_gateway_blobs =
             ethereum_gateway_blobs.map(|eth| eth.for_component("eth_tx_manager"));
+        let ethereum_gateway_prove =
+            ethereum_gateway_prove.map(|eth| eth.for_component("eth_tx_manager"));
+        let ethereum_gateway_execute =
+            ethereum_gateway_execute.map(|eth| eth.for_component("eth_tx_manager"));
+        let l1_fee_market = gas_adjuster.l1_fee_market();
         let fees_oracle = GasAdjusterFeesOracle {
             gas_adjuster,
             max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
@@ -55,8 +66,11 @@ impl EthTxManager {
         let l1_interface = Box::new(RealL1Interface {
             ethereum_gateway,
             ethereum_gateway_blobs,
+            ethereum_gateway_prove,
+            ethereum_gateway_execute,
             l2_gateway,
             wait_confirmations: config.wait_confirmations,
+            l1_fee_market,
         });
         tracing::info!(
             "Started eth_tx_manager supporting {:?} operators",
@@ -80,6 +94,7 @@ impl EthTxManager {
         storage: &mut Connection<'_, Core>,
         op: &EthTx,
     ) -> Result<Option<ExecutedTxStatus>, EthSenderError> {
+        let operator_type = self.operator_type(op)?;
         // Checking history items, starting from most recently sent.
         for history_item in storage
             .eth_sender_dal()
@@ -92,7 +107,7 @@ impl EthTxManager {
             // which means we might miss the transaction that actually succeeded.
             match self
                 .l1_interface
-                .get_tx_status(history_item.tx_hash, self.operator_type(op))
+                .get_tx_status(history_item.tx_hash, operator_type)
                 .await
             {
                 Ok(Some(s)) => return Ok(Some(s)),
@@ -123,6 +138,7 @@ impl EthTxManager {
             .await
             .unwrap();
 
+        let operator_type = self.operator_type(tx)?;
         let EthFees {
             base_fee_per_gas,
             priority_fee_per_gas,
@@ -131,11 +147,9 @@ impl EthTxManager {
         } = self.fees_oracle.calculate_fees(
             &previous_sent_tx,
             time_in_mempool_in_l1_blocks,
-            self.operator_type(tx),
+            operator_type,
         )?;
 
-        let operator_type = self.operator_type(tx);
-
         if let Some(previous_sent_tx) = previous_sent_tx {
             METRICS.transaction_resent.inc();
             tracing::info!(
@@ -265,12 +279,9 @@ impl EthTxManager {
     }
 
     pub(crate) fn operator_address(&self, operator_type: OperatorType) -> Option<Address> {
-        if operator_type == OperatorType::Blob {
-            self.l1_interface.get_blobs_operator_account()
-        } else {
-            None
-        }
+        self.l1_interface.get_operator_account(operator_type)
     }
+
     // Monitors the in-flight transactions, marks mined ones as confirmed,
     // returns the one that has to be resent (if there is one).
     pub(super) async fn monitor_inflight_transactions_single_operator(
@@ -431,14 +442,53 @@ impl EthTxManager {
         }
     }
 
-    fn operator_type(&self, tx: &EthTx) -> OperatorType {
+    /// Returns the type of the operator that has sent `tx`. Errors if `tx` was sent by a custom operator
+    /// that is not configured anymore (e.g., its key was removed while the transaction was in flight).
+    fn operator_type(&self, tx: &EthTx) -> Result<OperatorType, EthSenderError> {
         if tx.is_gateway {
-            OperatorType::Gateway
-        } else if tx.from_addr.is_none() {
-            OperatorType::NonBlob
-        } else {
-            OperatorType::Blob
+            return Ok(OperatorType::Gateway);
+        }
+        let Some(from_addr) = tx.from_addr else {
+            return Ok(OperatorType::NonBlob);
+        };
+        const CUSTOM_OPERATOR_TYPES: [OperatorType; 3] = [
+            OperatorType::Blob,
+            OperatorType::Prove,
+            OperatorType::Execute,
+        ];
+        CUSTOM_OPERATOR_TYPES
+            .into_iter()
+            .find(|&operator_type| self.operator_address(operator_type) == Some(from_addr))
+            .ok_or(EthSenderError::OperatorNotConfigured {
+                eth_tx_id: tx.id,
+                from_addr,
+            })
+    }
+
+    /// Checks that all unconfirmed transactions are sent by configured operators. Otherwise, these transactions
+    /// would never be monitored or resent, stalling the corresponding operations.
+    pub(crate) async fn check_unconfirmed_txs_senders(
+        &self,
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<()> {
+        let senders = storage
+            .eth_sender_dal()
+            .get_unconfirmed_txs_custom_senders()
+            .await?;
+        let configured_senders: Vec<_> = self
+            .l1_interface
+            .supported_operator_types()
+            .into_iter()
+            .filter_map(|operator_type| self.operator_address(operator_type))
+            .collect();
+        for sender in senders {
+            anyhow::ensure!(
+                configured_senders.contains(&sender),
+                "there are unconfirmed eth_txs sent by operator {sender:?}, which is not configured; \
+                 the operator key must be kept in config until all its transactions are confirmed"
+            );
         }
+        Ok(())
     }
 
     pub async fn fail_tx(
@@ -452,10 +502,17 @@ impl EthTxManager {
             .mark_failed_transaction(tx.id)
             .await
             .unwrap();
-        let failure_reason = self
-            .l1_interface
-            .failure_reason(tx_status.receipt.transaction_hash, self.operator_type(tx))
-            .await;
+        let failure_reason = match self.operator_type(tx) {
+            Ok(operator_type) => {
+                self.l1_interface
+                    .failure_reason(tx_status.receipt.transaction_hash, operator_type)
+                    .await
+            }
+            Err(err) => {
+                tracing::warn!("Cannot query failure reason for eth_tx {}: {err}", tx.id);
+                None
+            }
+        };
 
         tracing::error!(
             "Eth tx failed {:?}, {:?}, failure reason {:?}",
@@ -483,6 +540,13 @@ impl EthTxManager {
             .confirm_tx(tx_status.tx_hash, gas_used)
             .await
             .unwrap();
+        if tx.tx_type == AggregatedActionType::PublishProofOnchain {
+            storage
+                .proof_latency_dal()
+                .record_stage_for_prove_tx(tx.id, ProvingStage::Verified)
+                .await
+                .unwrap();
+        }
 
         METRICS
             .track_eth_tx_metrics(storage, BlockL1Stage::Mined, tx)
@@ -523,6 +587,10 @@ impl EthTxManager {
 
     pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let pool = self.pool.clone();
+        {
+            let mut storage = pool.connection_tagged("eth_sender").await?;
+            self.check_unconfirmed_txs_senders(&mut storage).await?;
+        }
 
         loop {
             let mut storage = pool.connection_tagged("eth_sender").await.unwrap();
@@ -652,9 +720,9 @@ impl EthTxManager {
         self.assert_there_are_no_pre_gateway_txs_with_gateway_enabled(storage)
             .await;
 
-        // We can treat blob and non-blob operators independently as they have different nonces and
-        // aggregator makes sure that corresponding Commit transaction is confirmed before creating
-        // a PublishProof transaction
+        // We can treat all operators independently as they have different nonces and
+        // aggregator makes sure that corresponding Commit (Prove) transaction is confirmed before
+        // creating a PublishProof (Execute) transaction sent by another operator
         for operator_type in self.l1_interface.supported_operator_types() {
             let l1_block_numbers = self
                 .l1_interface
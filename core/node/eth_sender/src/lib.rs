@@ -18,6 +18,8 @@ mod tests;
 mod tester;
 
 pub use self::{
-    aggregator::Aggregator, error::EthSenderError, eth_tx_aggregator::EthTxAggregator,
+    aggregator::{Aggregator, CustomOperators},
+    error::EthSenderError,
+    eth_tx_aggregator::EthTxAggregator,
     eth_tx_manager::EthTxManager,
 };
@@ -20,7 +20,7 @@ use crate::{
     abstract_l1_interface::{L1BlockNumbers, OperatorType},
     aggregated_operations::AggregatedOperation,
     tests::{default_l1_batch_metadata, l1_batch_with_metadata},
-    Aggregator, EthTxAggregator, EthTxManager,
+    Aggregator, CustomOperators, EthTxAggregator, EthTxManager,
 };
 
 pub(super) const STATE_TRANSITION_CONTRACT_ADDRESS: Address = Address::repeat_byte(0xa0);
@@ -256,7 +256,10 @@ impl EthSenderTester {
             Aggregator::new(
                 aggregator_config.clone(),
                 MockObjectStore::arc(),
-                aggregator_operate_4844_mode,
+                CustomOperators {
+                    commit: aggregator_operate_4844_mode,
+                    ..CustomOperators::default()
+                },
                 commitment_mode,
             ),
             gateway.clone(),
@@ -266,6 +269,8 @@ impl EthSenderTester {
             STATE_TRANSITION_CONTRACT_ADDRESS,
             Default::default(),
             custom_commit_sender_addr,
+            None,
+            None,
             SettlementMode::SettlesToL1,
         )
         .await;
@@ -277,6 +282,8 @@ impl EthSenderTester {
             Some(gateway.clone()),
             Some(gateway_blobs.clone()),
             None,
+            None,
+            None,
         );
 
         let connection_pool_clone = connection_pool.clone();
@@ -312,6 +319,8 @@ impl EthSenderTester {
             self.gas_adjuster.clone(),
             None,
             None,
+            None,
+            None,
             Some(self.l2_gateway.clone()),
         );
         self.is_l2 = true;
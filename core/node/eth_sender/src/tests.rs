@@ -16,12 +16,13 @@ use zksync_types::{
     helpers::unix_timestamp_ms,
     web3,
     web3::contract::Error,
-    Address, ProtocolVersionId, H256,
+    Address, L1BatchNumber, ProtocolVersion, ProtocolVersionId, H256, U256,
 };
 
 use crate::{
     abstract_l1_interface::OperatorType,
     aggregated_operations::AggregatedOperation,
+    aggregator::{retain_l1_batches_with_confirmed_proofs, retain_verified_l1_batches},
     tester::{EthSenderTester, TestL1Batch, STATE_TRANSITION_CONTRACT_ADDRESS},
     zksync_functions::ZkSyncFunctions,
     EthSenderError,
@@ -507,6 +508,59 @@ async fn switching_to_gateway_while_some_transactions_were_in_flight_should_caus
     tester.run_eth_sender_tx_manager_iteration().await;
 }
 
+#[tokio::test]
+async fn transactions_of_removed_operator_cause_error() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        true,
+        true,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+    let mut storage = tester.conn.connection().await.unwrap();
+    tester
+        .manager
+        .check_unconfirmed_txs_senders(&mut storage)
+        .await
+        .unwrap();
+
+    // Emulate a prove operator that was removed from config while its transaction was in flight.
+    let removed_operator = Address::repeat_byte(0x33);
+    let tx = storage
+        .eth_sender_dal()
+        .save_eth_tx(
+            0,
+            vec![],
+            AggregatedActionType::PublishProofOnchain,
+            STATE_TRANSITION_CONTRACT_ADDRESS,
+            0,
+            Some(removed_operator),
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let err = tester
+        .manager
+        .check_unconfirmed_txs_senders(&mut storage)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not configured"), "{err}");
+
+    let block = tester.get_block_numbers().await.latest;
+    let err = tester
+        .manager
+        .send_eth_tx(&mut storage, &tx, 0, block)
+        .await
+        .unwrap_err();
+    assert_matches!(
+        err,
+        EthSenderError::OperatorNotConfigured { from_addr, .. } if from_addr == removed_operator
+    );
+}
+
 #[test_log::test(tokio::test)]
 async fn switching_to_gateway_works_for_most_basic_scenario() {
     let mut tester = EthSenderTester::new(
@@ -850,3 +904,117 @@ async fn get_multicall_data(commitment_mode: L1BatchCommitmentMode) {
     assert_eq!(data.verifier_address, Address::repeat_byte(5));
     assert_eq!(data.protocol_version_id, ProtocolVersionId::latest());
 }
+
+#[tokio::test]
+async fn only_verified_l1_batches_are_retained() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let l1_batches: Vec<_> = (1..=3)
+        .map(|number| l1_batch_with_metadata(create_l1_batch(number)))
+        .collect();
+
+    let mut batches = l1_batches.clone();
+    retain_verified_l1_batches(&mut storage, &mut batches).await;
+    assert!(batches.is_empty());
+
+    // Batch #1 is verified, batch #2 has a diverging commitment, and batch #3 is not verified yet.
+    let commitments = [H256::default(), H256::repeat_byte(1)];
+    for (number, commitment) in (1..).map(L1BatchNumber).zip(commitments) {
+        storage
+            .vm_runner_dal()
+            .mark_batch_verifier_batch_as_processing(number)
+            .await
+            .unwrap();
+        storage
+            .vm_runner_dal()
+            .save_batch_verifier_commitment(number, commitment)
+            .await
+            .unwrap();
+    }
+
+    let mut batches = l1_batches.clone();
+    retain_verified_l1_batches(&mut storage, &mut batches).await;
+    let retained_numbers: Vec<_> = batches.iter().map(|batch| batch.header.number).collect();
+    assert_eq!(retained_numbers, [L1BatchNumber(1)]);
+}
+
+#[tokio::test]
+async fn only_l1_batches_with_confirmed_proofs_are_retained() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    let l1_batches: Vec<_> = (1..=3)
+        .map(|number| l1_batch_with_metadata(create_l1_batch(number)))
+        .collect();
+    for l1_batch in &l1_batches {
+        storage
+            .blocks_dal()
+            .insert_mock_l1_batch(&l1_batch.header)
+            .await
+            .unwrap();
+    }
+
+    let mut batches = l1_batches.clone();
+    retain_l1_batches_with_confirmed_proofs(&mut storage, &mut batches).await;
+    assert!(batches.is_empty());
+
+    // Batches #1 and #2 are proven by a confirmed tx, and batch #3 by a sent but not yet confirmed one.
+    let proofs = [
+        (
+            L1BatchNumber(1)..=L1BatchNumber(2),
+            H256::repeat_byte(1),
+            true,
+        ),
+        (
+            L1BatchNumber(3)..=L1BatchNumber(3),
+            H256::repeat_byte(2),
+            false,
+        ),
+    ];
+    for (nonce, (l1_batch_range, tx_hash, is_confirmed)) in proofs.into_iter().enumerate() {
+        let eth_tx = storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                nonce as u64,
+                vec![],
+                AggregatedActionType::PublishProofOnchain,
+                STATE_TRANSITION_CONTRACT_ADDRESS,
+                0,
+                Some(Address::repeat_byte(0x22)),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        storage
+            .blocks_dal()
+            .set_eth_tx_id(
+                l1_batch_range,
+                eth_tx.id,
+                AggregatedActionType::PublishProofOnchain,
+            )
+            .await
+            .unwrap();
+        storage
+            .eth_sender_dal()
+            .insert_tx_history(eth_tx.id, 0, 0, None, tx_hash, &[], 0)
+            .await
+            .unwrap();
+        if is_confirmed {
+            storage
+                .eth_sender_dal()
+                .confirm_tx(tx_hash, U256::zero())
+                .await
+                .unwrap();
+        }
+    }
+
+    let mut batches = l1_batches.clone();
+    retain_l1_batches_with_confirmed_proofs(&mut storage, &mut batches).await;
+    let retained_numbers: Vec<_> = batches.iter().map(|batch| batch.header.number).collect();
+    assert_eq!(retained_numbers, [L1BatchNumber(1), L1BatchNumber(2)]);
+}
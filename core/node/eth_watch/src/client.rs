@@ -29,6 +29,8 @@ pub trait EthClient: 'static + fmt::Debug + Send + Sync {
     ) -> EnrichedClientResult<Vec<Log>>;
     /// Returns finalized L1 block number.
     async fn finalized_block_number(&self) -> EnrichedClientResult<u64>;
+    /// Returns the timestamp of the specified block.
+    async fn block_timestamp(&self, block_number: u64) -> EnrichedClientResult<u64>;
 
     async fn get_total_priority_txs(&self) -> Result<u64, ContractCallError>;
     /// Returns scheduler verification key hash by verifier address.
@@ -295,6 +297,18 @@ impl EthClient for EthHttpQueryClient {
         }
     }
 
+    async fn block_timestamp(&self, block_number: u64) -> EnrichedClientResult<u64> {
+        let block = self
+            .client
+            .block(BlockId::Number(BlockNumber::Number(block_number.into())))
+            .await?
+            .ok_or_else(|| {
+                let err = ClientError::Custom("Block must be present on L1".into());
+                EnrichedClientError::new(err, "block").with_arg("block_number", &block_number)
+            })?;
+        Ok(block.timestamp.as_u64())
+    }
+
     async fn get_total_priority_txs(&self) -> Result<u64, ContractCallError> {
         CallFunctionArgs::new("getTotalPriorityTxs", ())
             .for_contract(self.diamond_proxy_addr, &self.getters_facet_contract_abi)
@@ -43,17 +43,21 @@ impl EventProcessor for PriorityOpsEventProcessor {
         let events_count = events.len();
         for event in events {
             assert_eq!(event.topics[0], self.new_priority_request_signature); // guaranteed by the watcher
+            let block_timestamp = event
+                .block_timestamp
+                .context("priority op event is missing block timestamp")?
+                .as_u64();
             let tx = L1Tx::try_from(event)
                 .map_err(|err| EventProcessorError::log_parse(err, "priority op"))?;
-            priority_ops.push(tx);
+            priority_ops.push((tx, block_timestamp));
         }
 
         if priority_ops.is_empty() {
             return Ok(events_count);
         }
 
-        let first = &priority_ops[0];
-        let last = &priority_ops[priority_ops.len() - 1];
+        let (first, _) = &priority_ops[0];
+        let (last, _) = &priority_ops[priority_ops.len() - 1];
         tracing::debug!(
             "Received priority requests with serial ids: {} (block {}) - {} (block {})",
             first.serial_id(),
@@ -69,10 +73,10 @@ impl EventProcessor for PriorityOpsEventProcessor {
 
         let new_ops: Vec<_> = priority_ops
             .into_iter()
-            .skip_while(|tx| tx.serial_id() < self.next_expected_priority_id)
+            .skip_while(|(tx, _)| tx.serial_id() < self.next_expected_priority_id)
             .collect();
         let skipped_ops = events_count - new_ops.len();
-        let Some(first_new) = new_ops.first() else {
+        let Some((first_new, _)) = new_ops.first() else {
             return Ok(events_count);
         };
         assert_eq!(
@@ -85,20 +89,20 @@ impl EventProcessor for PriorityOpsEventProcessor {
         APP_METRICS.processed_txs[&TxStage::added_to_mempool()].inc();
         APP_METRICS.processed_l1_txs[&TxStage::added_to_mempool()].inc();
         let processed_priority_transactions = sl_client.get_total_priority_txs().await?;
-        let ops_to_insert: Vec<&L1Tx> = new_ops
+        let ops_to_insert: Vec<&(L1Tx, u64)> = new_ops
             .iter()
-            .take_while(|op| processed_priority_transactions > op.serial_id().0)
+            .take_while(|(op, _)| processed_priority_transactions > op.serial_id().0)
             .collect();
 
-        for new_op in &ops_to_insert {
+        for (new_op, block_timestamp) in &ops_to_insert {
             storage
                 .transactions_dal()
-                .insert_transaction_l1(new_op, new_op.eth_block())
+                .insert_transaction_l1(new_op, new_op.eth_block(), *block_timestamp)
                 .await
                 .map_err(DalError::generalize)?;
         }
         stage_latency.observe();
-        if let Some(last_op) = ops_to_insert.last() {
+        if let Some((last_op, _)) = ops_to_insert.last() {
             self.next_expected_priority_id = last_op.serial_id().next();
         }
 
@@ -2,15 +2,17 @@
 //! protocol upgrades etc.
 //! New events are accepted to the ZKsync network once they have the sufficient amount of L1 confirmations.
 
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use anyhow::Context as _;
 use tokio::sync::watch;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
 use zksync_system_constants::PRIORITY_EXPIRATION;
 use zksync_types::{
-    ethabi::Contract, protocol_version::ProtocolSemanticVersion,
-    web3::BlockNumber as Web3BlockNumber, PriorityOpId,
+    ethabi::Contract,
+    protocol_version::ProtocolSemanticVersion,
+    web3::{BlockNumber as Web3BlockNumber, Log},
+    PriorityOpId,
 };
 
 pub use self::client::EthHttpQueryClient;
@@ -126,6 +128,33 @@ impl EthWatch {
         Ok(())
     }
 
+    /// Sets block timestamps for events if they are not returned by the client.
+    async fn fill_block_timestamps(
+        client: &dyn EthClient,
+        events: &mut [Log],
+    ) -> Result<(), EventProcessorError> {
+        let mut timestamps = HashMap::new();
+        for event in events {
+            if event.block_timestamp.is_some() {
+                continue;
+            }
+            let block_number = event
+                .block_number
+                .expect("Event block number is missing")
+                .as_u64();
+            let timestamp = match timestamps.get(&block_number) {
+                Some(&timestamp) => timestamp,
+                None => {
+                    let timestamp = client.block_timestamp(block_number).await?;
+                    timestamps.insert(block_number, timestamp);
+                    timestamp
+                }
+            };
+            event.block_timestamp = Some(timestamp.into());
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(name = "EthWatch::loop_iteration", skip_all)]
     async fn loop_iteration(
         &mut self,
@@ -153,7 +182,7 @@ impl EthWatch {
             if from_block > finalized_block {
                 continue;
             }
-            let processor_events = client
+            let mut processor_events = client
                 .get_events(
                     Web3BlockNumber::Number(from_block.into()),
                     Web3BlockNumber::Number(finalized_block.into()),
@@ -162,6 +191,7 @@ impl EthWatch {
                     RETRY_LIMIT,
                 )
                 .await?;
+            Self::fill_block_timestamps(client, &mut processor_events).await?;
             let processed_events_count = processor
                 .process_events(storage, &*self.sl_client, processor_events.clone())
                 .await?;
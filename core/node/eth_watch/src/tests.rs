@@ -169,6 +169,10 @@ impl EthClient for MockEthClient {
         Ok(self.inner.read().await.last_finalized_block_number)
     }
 
+    async fn block_timestamp(&self, block_number: u64) -> EnrichedClientResult<u64> {
+        Ok(block_number)
+    }
+
     async fn diamond_cut_by_version(
         &self,
         packed_version: H256,
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use tower_http::cors::CorsLayer;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_types::L2ChainId;
+
+#[derive(Debug, Clone)]
+pub(crate) struct EtherscanApi {
+    pub(crate) connection_pool: ConnectionPool<Core>,
+    pub(crate) l2_chain_id: L2ChainId,
+    pub(crate) max_page_size: usize,
+}
+
+impl EtherscanApi {
+    pub fn new(
+        connection_pool: ConnectionPool<Core>,
+        l2_chain_id: L2ChainId,
+        max_page_size: usize,
+    ) -> Self {
+        Self {
+            connection_pool,
+            l2_chain_id,
+            max_page_size,
+        }
+    }
+
+    pub fn into_router(self) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/api", axum::routing::get(Self::handle))
+            .layer(CorsLayer::permissive())
+            .with_state(Arc::new(self))
+    }
+}
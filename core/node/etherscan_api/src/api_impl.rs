@@ -0,0 +1,257 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{rejection::QueryRejection, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use zksync_dal::{CoreDal, DalError};
+use zksync_types::{AccountTreeId, L2BlockNumber, L2_BASE_TOKEN_ADDRESS, U64};
+
+use super::{
+    api_decl::EtherscanApi,
+    metrics::METRICS,
+    types::{
+        Action, ApiQuery, ApiResponse, SortOrder, SourceCodeItem, TxListItem, TxReceiptStatus,
+        NOT_VERIFIED_MESSAGE,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) enum ApiError {
+    InvalidQuery(String),
+    UnknownAction,
+    MissingAddress,
+    MissingTxHash,
+    UnsupportedTag,
+    ResultWindowTooLarge(usize),
+    ContractNotVerified,
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::Internal(err)
+    }
+}
+
+impl From<DalError> for ApiError {
+    fn from(err: DalError) -> Self {
+        Self::Internal(err.generalize())
+    }
+}
+
+impl ApiError {
+    pub fn message(&self) -> String {
+        match self {
+            Self::InvalidQuery(err) => format!("Error! {err}"),
+            Self::UnknownAction => "Error! Missing Or invalid Module or Action name".to_owned(),
+            Self::MissingAddress => "Error! Missing Or invalid address".to_owned(),
+            Self::MissingTxHash => "Error! Missing Or invalid txhash".to_owned(),
+            Self::UnsupportedTag => "Error! Only `latest` tag is supported".to_owned(),
+            Self::ResultWindowTooLarge(max_size) => format!(
+                "Result window is too large, PageNo x Offset size must be less than or equal to {max_size}"
+            ),
+            Self::ContractNotVerified => NOT_VERIFIED_MESSAGE.to_owned(),
+            Self::Internal(_) => "Internal server error".to_owned(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        // Etherscan signals errors in the response body, so that clients expect the OK status code for them.
+        let status_code = match &self {
+            Self::Internal(err) => {
+                // Do not expose the error details to the client, but log it.
+                tracing::warn!("Internal error: {err:#}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            _ => StatusCode::OK,
+        };
+        (status_code, Json(ApiResponse::error(self.message()))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<ApiResponse<T>>, ApiError>;
+
+impl EtherscanApi {
+    /// Dispatches a request to the handler of the action specified in the query.
+    #[tracing::instrument(skip(self_))]
+    pub async fn handle(
+        State(self_): State<Arc<Self>>,
+        query: Result<Query<ApiQuery>, QueryRejection>,
+    ) -> Response {
+        let query = match query {
+            Ok(Query(query)) => query,
+            Err(err) => return ApiError::InvalidQuery(err.body_text()).into_response(),
+        };
+        let Some(action) = Action::new(&query.module, &query.action) else {
+            return ApiError::UnknownAction.into_response();
+        };
+
+        let method_latency = METRICS.call[&action].start();
+        let response = match action {
+            Action::AccountBalance => self_.balance(&query).await.into_response(),
+            Action::AccountTxList => self_.tx_list(&query).await.into_response(),
+            Action::ContractGetAbi => self_.abi(&query).await.into_response(),
+            Action::ContractGetSourceCode => self_.source_code(&query).await.into_response(),
+            Action::TransactionGetTxReceiptStatus => {
+                self_.tx_receipt_status(&query).await.into_response()
+            }
+        };
+        method_latency.observe();
+        response
+    }
+
+    async fn balance(&self, query: &ApiQuery) -> ApiResult<String> {
+        let address = query.address.ok_or(ApiError::MissingAddress)?;
+        if !matches!(query.tag.as_deref(), None | Some("latest" | "pending")) {
+            return Err(ApiError::UnsupportedTag);
+        }
+
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let latest_l2_block = storage
+            .blocks_dal()
+            .get_sealed_l2_block_number()
+            .await?
+            .unwrap_or(L2BlockNumber(0));
+        let balance = storage
+            .storage_web3_dal()
+            .standard_token_historical_balance(
+                AccountTreeId::new(L2_BASE_TOKEN_ADDRESS),
+                AccountTreeId::new(address),
+                latest_l2_block,
+            )
+            .await?;
+        Ok(Json(ApiResponse::ok(balance.to_string())))
+    }
+
+    async fn tx_list(&self, query: &ApiQuery) -> ApiResult<Vec<TxListItem>> {
+        let address = query.address.ok_or(ApiError::MissingAddress)?;
+        let page = query.page.unwrap_or(1).max(1);
+        let page_size = match query.offset {
+            None | Some(0) => self.max_page_size,
+            Some(size) => size,
+        };
+        if page.saturating_mul(page_size) > self.max_page_size {
+            return Err(ApiError::ResultWindowTooLarge(self.max_page_size));
+        }
+
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let Some(latest_l2_block) = storage.blocks_dal().get_sealed_l2_block_number().await? else {
+            return Ok(Json(no_transactions()));
+        };
+        let start_block = L2BlockNumber(query.startblock.unwrap_or(0));
+        let end_block = query.endblock.map_or(latest_l2_block, |number| {
+            L2BlockNumber(number).min(latest_l2_block)
+        });
+        if start_block > end_block {
+            return Ok(Json(no_transactions()));
+        }
+
+        let hashes = storage
+            .transactions_web3_dal()
+            .get_account_transaction_hashes(
+                address,
+                start_block..=end_block,
+                (page - 1) * page_size,
+                page_size,
+                query.sort == SortOrder::Desc,
+            )
+            .await?;
+        if hashes.is_empty() {
+            return Ok(Json(no_transactions()));
+        }
+
+        let transactions = storage
+            .transactions_web3_dal()
+            .get_transactions(&hashes, self.l2_chain_id)
+            .await?;
+        let mut transactions: HashMap<_, _> =
+            transactions.into_iter().map(|tx| (tx.hash, tx)).collect();
+        let receipts = storage
+            .transactions_web3_dal()
+            .get_transaction_receipts(&hashes)
+            .await?;
+        let mut receipts: HashMap<_, _> = receipts
+            .into_iter()
+            .map(|receipt| (receipt.transaction_hash, receipt))
+            .collect();
+
+        let mut timestamps = HashMap::new();
+        let mut items = Vec::with_capacity(hashes.len());
+        for hash in &hashes {
+            let (Some(tx), Some(receipt)) = (transactions.remove(hash), receipts.remove(hash))
+            else {
+                // The transaction may have been reverted since the hashes were loaded.
+                continue;
+            };
+            let block_number = L2BlockNumber(receipt.block_number.as_u32());
+            let timestamp = if let Some(&timestamp) = timestamps.get(&block_number) {
+                timestamp
+            } else {
+                let timestamp = storage
+                    .blocks_dal()
+                    .get_l2_block_header(block_number)
+                    .await?
+                    .map_or(0, |header| header.timestamp);
+                timestamps.insert(block_number, timestamp);
+                timestamp
+            };
+            items.push(TxListItem::new(&tx, &receipt, timestamp, latest_l2_block));
+        }
+        Ok(Json(ApiResponse::ok(items)))
+    }
+
+    async fn abi(&self, query: &ApiQuery) -> ApiResult<String> {
+        let address = query.address.ok_or(ApiError::MissingAddress)?;
+        let info = self
+            .connection_pool
+            .connection_tagged("api")
+            .await?
+            .contract_verification_dal()
+            .get_contract_verification_info(address)
+            .await?
+            .ok_or(ApiError::ContractNotVerified)?;
+        Ok(Json(ApiResponse::ok(info.artifacts.abi.to_string())))
+    }
+
+    async fn source_code(&self, query: &ApiQuery) -> ApiResult<Vec<SourceCodeItem>> {
+        let address = query.address.ok_or(ApiError::MissingAddress)?;
+        let info = self
+            .connection_pool
+            .connection_tagged("api")
+            .await?
+            .contract_verification_dal()
+            .get_contract_verification_info(address)
+            .await?;
+        // Unlike `getabi`, Etherscan returns a successful response with empty fields for unverified contracts.
+        let item = info.map_or_else(SourceCodeItem::not_verified, |info| {
+            SourceCodeItem::new(&info)
+        });
+        Ok(Json(ApiResponse::ok(vec![item])))
+    }
+
+    async fn tx_receipt_status(&self, query: &ApiQuery) -> ApiResult<TxReceiptStatus> {
+        let hash = query.txhash.ok_or(ApiError::MissingTxHash)?;
+        let receipts = self
+            .connection_pool
+            .connection_tagged("api")
+            .await?
+            .transactions_web3_dal()
+            .get_transaction_receipts(&[hash])
+            .await?;
+        let status = match receipts.first() {
+            Some(receipt) if receipt.status == U64::one() => "1",
+            Some(_) => "0",
+            None => "",
+        };
+        Ok(Json(ApiResponse::ok(TxReceiptStatus { status })))
+    }
+}
+
+fn no_transactions() -> ApiResponse<Vec<TxListItem>> {
+    ApiResponse::empty("No transactions found", vec![])
+}
@@ -0,0 +1,48 @@
+//! Etherscan-compatible REST API backed by the node storage.
+//!
+//! The API implements a subset of the [Etherscan API](https://docs.etherscan.io/) (e.g., `module=account&action=txlist`
+//! or `module=contract&action=getabi`), so that tooling built for Etherscan can be used with a ZKsync chain without
+//! deploying a separate block explorer.
+
+use std::net::SocketAddr;
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_types::L2ChainId;
+
+use self::api_decl::EtherscanApi;
+
+mod api_decl;
+mod api_impl;
+mod metrics;
+#[cfg(test)]
+mod tests;
+mod types;
+
+pub async fn start_server(
+    connection_pool: ConnectionPool<Core>,
+    l2_chain_id: L2ChainId,
+    max_page_size: usize,
+    bind_address: SocketAddr,
+    mut stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let api = EtherscanApi::new(connection_pool, l2_chain_id, max_page_size).into_router();
+
+    let listener = tokio::net::TcpListener::bind(bind_address)
+        .await
+        .context("Cannot bind to the specified address")?;
+    axum::serve(listener, api)
+        .with_graceful_shutdown(async move {
+            if stop_receiver.changed().await.is_err() {
+                tracing::warn!(
+                    "Stop signal sender for Etherscan API server was dropped without sending a signal"
+                );
+            }
+            tracing::info!("Stop signal received, Etherscan API server is shutting down");
+        })
+        .await
+        .context("Etherscan API server failed")?;
+    tracing::info!("Etherscan API server shut down");
+    Ok(())
+}
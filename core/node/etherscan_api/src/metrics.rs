@@ -0,0 +1,18 @@
+//! Metrics for the Etherscan-compatible API.
+
+use std::time::Duration;
+
+use vise::{Buckets, Histogram, LabeledFamily, Metrics};
+
+use crate::types::Action;
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_etherscan")]
+pub(super) struct EtherscanApiMetrics {
+    /// Latency of handling API calls.
+    #[metrics(buckets = Buckets::LATENCIES, labels = ["action"])]
+    pub call: LabeledFamily<Action, Histogram<Duration>>,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<EtherscanApiMetrics> = vise::Global::new();
@@ -0,0 +1,228 @@
+//! Tests for the Etherscan-compatible API server.
+
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+};
+use chrono::Utc;
+use http_body_util::BodyExt as _;
+use tower::ServiceExt;
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_node_test_utils::{create_l2_block, create_l2_transaction, execute_l2_transaction};
+use zksync_types::{
+    contract_verification_api::{
+        CompilationArtifacts, CompilerVersions, SourceCodeData, VerificationIncomingRequest,
+        VerificationInfo, VerificationRequest,
+    },
+    l2::L2Tx,
+    web3, Address, L2BlockNumber, ProtocolVersion, ProtocolVersionId, H256, U256,
+};
+use zksync_vm_interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
+
+use super::*;
+
+const CHAIN_ID: u32 = 271;
+
+async fn prepare_storage(storage: &mut Connection<'_, Core>) -> L2Tx {
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .insert_l2_block(&create_l2_block(0))
+        .await
+        .unwrap();
+
+    let tx = create_l2_transaction(10, 100);
+    storage
+        .transactions_dal()
+        .insert_transaction_l2(
+            &tx,
+            TransactionExecutionMetrics::default(),
+            ValidationTraces::default(),
+        )
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .insert_l2_block(&create_l2_block(1))
+        .await
+        .unwrap();
+    storage
+        .transactions_dal()
+        .mark_txs_as_executed_in_l2_block(
+            L2BlockNumber(1),
+            &[execute_l2_transaction(tx.clone())],
+            U256::one(),
+            ProtocolVersionId::latest(),
+            false,
+        )
+        .await
+        .unwrap();
+    tx
+}
+
+fn mock_verification_info(address: Address) -> VerificationInfo {
+    VerificationInfo {
+        request: VerificationRequest {
+            id: 1,
+            req: VerificationIncomingRequest {
+                contract_address: address,
+                source_code_data: SourceCodeData::SolSingleFile("contract Test {}".to_owned()),
+                contract_name: "Test".to_owned(),
+                compiler_versions: CompilerVersions::Solc {
+                    compiler_zksolc_version: Some("1.5.6".to_owned()),
+                    compiler_solc_version: "0.8.27".to_owned(),
+                },
+                optimization_used: true,
+                optimizer_mode: None,
+                constructor_arguments: web3::Bytes(vec![0xab, 0xcd]),
+                is_system: false,
+                force_evmla: false,
+                search_partial_matches: false,
+            },
+        },
+        artifacts: CompilationArtifacts {
+            bytecode: vec![1; 32],
+            deployed_bytecode: None,
+            abi: serde_json::json!([{ "type": "constructor", "inputs": [] }]),
+        },
+        verified_at: Utc::now(),
+    }
+}
+
+async fn api_call(pool: &ConnectionPool<Core>, query: &str) -> serde_json::Value {
+    let router = EtherscanApi::new(pool.clone(), L2ChainId::from(CHAIN_ID), 1_000).into_router();
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api?{query}"))
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&response).unwrap()
+}
+
+#[tokio::test]
+async fn unknown_action() {
+    let pool = ConnectionPool::test_pool().await;
+    let response = api_call(&pool, "module=account&action=tokennfttx").await;
+    assert_eq!(response["status"], "0");
+    assert_eq!(response["message"], "NOTOK");
+
+    let response = api_call(&pool, "module=account&action=txlist&address=bogus").await;
+    assert_eq!(response["status"], "0");
+    assert_eq!(response["message"], "NOTOK");
+}
+
+#[tokio::test]
+async fn listing_transactions() {
+    let pool = ConnectionPool::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let tx = prepare_storage(&mut storage).await;
+    let initiator = tx.initiator_account();
+
+    let query = format!("module=account&action=txlist&address={initiator:?}&sort=desc");
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["status"], "1", "{response}");
+    let items = response["result"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["hash"], format!("{:?}", tx.hash()));
+    assert_eq!(items[0]["blockNumber"], "1");
+    assert_eq!(items[0]["from"], format!("{initiator:?}"));
+    assert_eq!(items[0]["txreceipt_status"], "1");
+    assert_eq!(items[0]["isError"], "0");
+    assert_eq!(items[0]["confirmations"], "1");
+
+    let recipient = tx.recipient_account().unwrap();
+    let query = format!("module=account&action=txlist&address={recipient:?}");
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["result"].as_array().unwrap().len(), 1);
+
+    for query in [
+        format!("module=account&action=txlist&address={initiator:?}&startblock=2"),
+        format!("module=account&action=txlist&address={initiator:?}&page=2&offset=1"),
+        format!(
+            "module=account&action=txlist&address={:?}",
+            Address::repeat_byte(1)
+        ),
+    ] {
+        let response = api_call(&pool, &query).await;
+        assert_eq!(response["status"], "0", "{response}");
+        assert_eq!(response["message"], "No transactions found");
+        assert_eq!(response["result"], serde_json::json!([]));
+    }
+
+    let query = format!("module=account&action=txlist&address={initiator:?}&page=2&offset=1000");
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["status"], "0");
+    assert_eq!(response["message"], "NOTOK");
+}
+
+#[tokio::test]
+async fn getting_tx_receipt_status() {
+    let pool = ConnectionPool::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let tx = prepare_storage(&mut storage).await;
+
+    let query = format!(
+        "module=transaction&action=gettxreceiptstatus&txhash={:?}",
+        tx.hash()
+    );
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["status"], "1");
+    assert_eq!(response["result"], serde_json::json!({ "status": "1" }));
+
+    let query = format!(
+        "module=transaction&action=gettxreceiptstatus&txhash={:?}",
+        H256::repeat_byte(0xff)
+    );
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["result"], serde_json::json!({ "status": "" }));
+}
+
+#[tokio::test]
+async fn getting_contract_abi_and_source_code() {
+    let pool = ConnectionPool::test_pool().await;
+    let address = Address::repeat_byte(0x23);
+
+    let query = format!("module=contract&action=getabi&address={address:?}");
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["status"], "0");
+    assert_eq!(response["result"], types::NOT_VERIFIED_MESSAGE);
+
+    let source_query = format!("module=contract&action=getsourcecode&address={address:?}");
+    let response = api_call(&pool, &source_query).await;
+    assert_eq!(response["status"], "1");
+    assert_eq!(response["result"][0]["ABI"], types::NOT_VERIFIED_MESSAGE);
+    assert_eq!(response["result"][0]["SourceCode"], "");
+
+    let info = mock_verification_info(address);
+    let abi = info.artifacts.abi.clone();
+    pool.connection()
+        .await
+        .unwrap()
+        .contract_verification_dal()
+        .save_verification_info(info, None)
+        .await
+        .unwrap();
+
+    let response = api_call(&pool, &query).await;
+    assert_eq!(response["status"], "1");
+    let returned_abi: serde_json::Value =
+        serde_json::from_str(response["result"].as_str().unwrap()).unwrap();
+    assert_eq!(returned_abi, abi);
+
+    let response = api_call(&pool, &source_query).await;
+    assert_eq!(response["status"], "1");
+    let item = &response["result"][0];
+    assert_eq!(item["SourceCode"], "contract Test {}");
+    assert_eq!(item["ContractName"], "Test");
+    assert_eq!(item["CompilerVersion"], "0.8.27");
+    assert_eq!(item["ZkCompilerVersion"], "1.5.6");
+    assert_eq!(item["OptimizationUsed"], "1");
+    assert_eq!(item["ConstructorArguments"], "abcd");
+}
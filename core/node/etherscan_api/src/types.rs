@@ -0,0 +1,243 @@
+//! Request and response types of the Etherscan-compatible API.
+
+use serde::{Deserialize, Serialize};
+use vise::EncodeLabelValue;
+use zksync_types::{
+    api::{Transaction, TransactionReceipt},
+    contract_verification_api::{SourceCodeData, VerificationInfo},
+    Address, L2BlockNumber, H256, U64,
+};
+
+/// Message returned in the `ABI` field of unverified contracts.
+pub(crate) const NOT_VERIFIED_MESSAGE: &str = "Contract source code not verified";
+
+/// Supported API actions, identified by `module` and `action` query params.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum Action {
+    AccountBalance,
+    AccountTxList,
+    ContractGetAbi,
+    ContractGetSourceCode,
+    TransactionGetTxReceiptStatus,
+}
+
+impl Action {
+    pub fn new(module: &str, action: &str) -> Option<Self> {
+        Some(match (module, action) {
+            ("account", "balance") => Self::AccountBalance,
+            ("account", "txlist") => Self::AccountTxList,
+            ("contract", "getabi") => Self::ContractGetAbi,
+            ("contract", "getsourcecode") => Self::ContractGetSourceCode,
+            ("transaction", "gettxreceiptstatus") => Self::TransactionGetTxReceiptStatus,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Query params of an API request. Params irrelevant for the requested action (e.g., `apikey`) are ignored.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ApiQuery {
+    pub module: String,
+    pub action: String,
+    pub address: Option<Address>,
+    pub txhash: Option<H256>,
+    pub tag: Option<String>,
+    pub startblock: Option<u32>,
+    pub endblock: Option<u32>,
+    pub page: Option<usize>,
+    pub offset: Option<usize>,
+    #[serde(default)]
+    pub sort: SortOrder,
+}
+
+/// Envelope for all API responses.
+#[derive(Debug, Serialize)]
+pub(crate) struct ApiResponse<T> {
+    /// `"1"` for successful responses, `"0"` otherwise.
+    pub status: &'static str,
+    pub message: &'static str,
+    pub result: T,
+}
+
+impl<T> ApiResponse<T> {
+    pub fn ok(result: T) -> Self {
+        Self {
+            status: "1",
+            message: "OK",
+            result,
+        }
+    }
+
+    /// Response with an empty `result`; Etherscan signals it with the `"0"` status.
+    pub fn empty(message: &'static str, result: T) -> Self {
+        Self {
+            status: "0",
+            message,
+            result,
+        }
+    }
+
+    pub fn error(result: T) -> Self {
+        Self {
+            status: "0",
+            message: "NOTOK",
+            result,
+        }
+    }
+}
+
+/// Item returned by the `txlist` action. As in Etherscan, all values are encoded as strings.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TxListItem {
+    pub block_number: String,
+    #[serde(rename = "timeStamp")]
+    pub timestamp: String,
+    pub hash: String,
+    pub nonce: String,
+    pub block_hash: String,
+    pub transaction_index: String,
+    pub from: String,
+    pub to: String,
+    pub value: String,
+    pub gas: String,
+    pub gas_price: String,
+    pub is_error: String,
+    #[serde(rename = "txreceipt_status")]
+    pub tx_receipt_status: String,
+    pub input: String,
+    pub contract_address: String,
+    pub cumulative_gas_used: String,
+    pub gas_used: String,
+    pub confirmations: String,
+    pub method_id: String,
+    pub function_name: String,
+}
+
+impl TxListItem {
+    pub fn new(
+        tx: &Transaction,
+        receipt: &TransactionReceipt,
+        timestamp: u64,
+        latest_l2_block: L2BlockNumber,
+    ) -> Self {
+        let block_number = receipt.block_number.as_u64();
+        let is_success = receipt.status == U64::one();
+        let method_id = tx.input.0.get(..4).unwrap_or_default();
+        Self {
+            block_number: block_number.to_string(),
+            timestamp: timestamp.to_string(),
+            hash: format!("{:?}", tx.hash),
+            nonce: tx.nonce.to_string(),
+            block_hash: format!("{:?}", receipt.block_hash),
+            transaction_index: receipt.transaction_index.to_string(),
+            from: format!("{:?}", receipt.from),
+            to: receipt.to.map(|to| format!("{to:?}")).unwrap_or_default(),
+            value: tx.value.to_string(),
+            gas: tx.gas.to_string(),
+            gas_price: tx.gas_price.unwrap_or_default().to_string(),
+            is_error: if is_success { "0" } else { "1" }.to_owned(),
+            tx_receipt_status: if is_success { "1" } else { "0" }.to_owned(),
+            input: format!("0x{}", hex::encode(&tx.input.0)),
+            contract_address: receipt
+                .contract_address
+                .map(|address| format!("{address:?}"))
+                .unwrap_or_default(),
+            cumulative_gas_used: receipt.cumulative_gas_used.to_string(),
+            gas_used: receipt.gas_used.unwrap_or_default().to_string(),
+            confirmations: (u64::from(latest_l2_block.0).saturating_sub(block_number) + 1)
+                .to_string(),
+            method_id: format!("0x{}", hex::encode(method_id)),
+            // Decoding function names requires the contract ABI, which is not generally available.
+            function_name: String::new(),
+        }
+    }
+}
+
+/// Item returned by the `getsourcecode` action.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct SourceCodeItem {
+    pub source_code: String,
+    #[serde(rename = "ABI")]
+    pub abi: String,
+    pub contract_name: String,
+    pub compiler_version: String,
+    /// Version of `zksolc` / `zkvyper` used to compile the contract; not present in Etherscan.
+    pub zk_compiler_version: String,
+    pub optimization_used: String,
+    pub runs: String,
+    pub constructor_arguments: String,
+    #[serde(rename = "EVMVersion")]
+    pub evm_version: String,
+    pub library: String,
+    pub license_type: String,
+    pub proxy: String,
+    pub implementation: String,
+    pub swarm_source: String,
+}
+
+impl SourceCodeItem {
+    pub fn not_verified() -> Self {
+        Self {
+            abi: NOT_VERIFIED_MESSAGE.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    pub fn new(info: &VerificationInfo) -> Self {
+        let request = &info.request.req;
+        let (source_code, runs) = match &request.source_code_data {
+            SourceCodeData::SolSingleFile(code) | SourceCodeData::YulSingleFile(code) => {
+                (code.clone(), None)
+            }
+            SourceCodeData::StandardJsonInput(input) => {
+                let runs = input
+                    .get("settings")
+                    .and_then(|settings| settings.pointer("/optimizer/runs"))
+                    .map(ToString::to_string);
+                // Etherscan wraps standard JSON input in double braces to distinguish it from single-file sources.
+                let input = serde_json::Value::Object(input.clone());
+                (format!("{{{input}}}"), runs)
+            }
+            SourceCodeData::VyperMultiFile(files) => (
+                serde_json::to_string(files).expect("failed serializing source files"),
+                None,
+            ),
+        };
+
+        Self {
+            source_code,
+            abi: info.artifacts.abi.to_string(),
+            contract_name: request.contract_name.clone(),
+            compiler_version: request.compiler_versions.compiler_version().to_owned(),
+            zk_compiler_version: request
+                .compiler_versions
+                .zk_compiler_version()
+                .unwrap_or_default()
+                .to_owned(),
+            optimization_used: if request.optimization_used { "1" } else { "0" }.to_owned(),
+            runs: runs.unwrap_or_default(),
+            constructor_arguments: hex::encode(&request.constructor_arguments.0),
+            evm_version: "Default".to_owned(),
+            proxy: "0".to_owned(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Result of the `gettxreceiptstatus` action.
+#[derive(Debug, Serialize)]
+pub(crate) struct TxReceiptStatus {
+    /// `"1"` for successful transactions, `"0"` for failed ones, and an empty string for unknown / pending ones.
+    pub status: &'static str,
+}
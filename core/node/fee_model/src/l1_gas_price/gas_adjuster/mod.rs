@@ -6,8 +6,8 @@ use std::{
 };
 
 use tokio::sync::watch;
-use zksync_config::GasAdjusterConfig;
-use zksync_eth_client::EthFeeInterface;
+use zksync_config::{configs::eth_sender::L1FeeMarket, GasAdjusterConfig};
+use zksync_eth_client::{BaseFees, EthFeeInterface};
 use zksync_types::{
     commitment::L1BatchCommitmentMode, pubdata_da::PubdataSendingMode, L1_GAS_PER_PUBDATA_BYTE,
     U256,
@@ -99,6 +99,12 @@ impl GasAdjuster {
                 "Relayed L2 calldata is only available in L2 mode"
             );
         }
+        anyhow::ensure!(
+            config.l1_fee_market.supports_blobs()
+                || !matches!(pubdata_sending_mode, PubdataSendingMode::Blobs),
+            "Blobs are not supported by the {:?} fee market",
+            config.l1_fee_market
+        );
 
         // Subtracting 1 from the "latest" block number to prevent errors in case
         // the info about the latest block is not yet present on the node.
@@ -109,10 +115,13 @@ impl GasAdjuster {
             .await?
             .as_usize()
             .saturating_sub(1);
-        let fee_history = client
-            .inner
-            .base_fee_history(current_block, config.max_base_fee_samples)
-            .await?;
+        let fee_history = Self::fetch_fee_history(
+            &client,
+            config.l1_fee_market,
+            current_block,
+            config.max_base_fee_samples,
+        )
+        .await?;
 
         let base_fee_statistics = GasStatistics::new(
             config.max_base_fee_samples,
@@ -143,6 +152,33 @@ impl GasAdjuster {
         })
     }
 
+    /// Fetches fees for `block_count` blocks up to and including `upto_block`.
+    ///
+    /// Legacy fee markets don't provide fee history, so the current network gas price is used
+    /// as the base fee for all requested blocks.
+    async fn fetch_fee_history(
+        client: &GasAdjusterClient,
+        l1_fee_market: L1FeeMarket,
+        upto_block: usize,
+        block_count: usize,
+    ) -> anyhow::Result<Vec<BaseFees>> {
+        if l1_fee_market != L1FeeMarket::Legacy {
+            return Ok(client
+                .inner
+                .base_fee_history(upto_block, block_count)
+                .await?);
+        }
+
+        let gas_price = client.inner.get_gas_price().await?;
+        let gas_price = u64::try_from(gas_price)
+            .map_err(|_| anyhow::anyhow!("L1 gas price {gas_price} does not fit in u64"))?;
+        let fees = BaseFees {
+            base_fee_per_gas: gas_price,
+            ..BaseFees::default()
+        };
+        Ok(vec![fees; block_count])
+    }
+
     /// Performs an actualization routine for `GasAdjuster`.
     /// This method is intended to be invoked periodically.
     pub async fn keep_updated(&self) -> anyhow::Result<()> {
@@ -161,11 +197,13 @@ impl GasAdjuster {
 
         if current_block > last_processed_block {
             let n_blocks = current_block - last_processed_block;
-            let fee_data = self
-                .client
-                .inner
-                .base_fee_history(current_block, n_blocks)
-                .await?;
+            let fee_data = Self::fetch_fee_history(
+                &self.client,
+                self.config.l1_fee_market,
+                current_block,
+                n_blocks,
+            )
+            .await?;
 
             // We shouldn't rely on L1 provider to return consistent results, so we check that we have at least one new sample.
             if let Some(current_base_fee_per_gas) = fee_data.last().map(|fee| fee.base_fee_per_gas)
@@ -335,6 +373,10 @@ impl TxParamsProvider for GasAdjuster {
     }
 
     fn get_next_block_minimal_base_fee(&self) -> u64 {
+        if self.config.l1_fee_market == L1FeeMarket::Legacy {
+            // Gas price isn't bounded by the protocol in legacy fee markets.
+            return 0;
+        }
         let last_block_base_fee = self.base_fee_statistics.last_added_value();
 
         // The next block's base fee will decrease by a maximum of 12.5%.
@@ -348,7 +390,12 @@ impl TxParamsProvider for GasAdjuster {
     // will decrease. The EIP-1559 mechanism is designed such that
     // `base_fee` will balance out `priority_fee` in such a way that
     // `priority_fee` will be a small fraction of the overall fee.
+    //
+    // In legacy fee markets, the sampled network gas price already includes the tip.
     fn get_priority_fee(&self) -> u64 {
+        if self.config.l1_fee_market == L1FeeMarket::Legacy {
+            return 0;
+        }
         self.config.default_priority_fee_per_gas
     }
 
@@ -375,6 +422,10 @@ impl TxParamsProvider for GasAdjuster {
     fn get_gateway_tx_pubdata_price(&self) -> u64 {
         todo!()
     }
+
+    fn l1_fee_market(&self) -> L1FeeMarket {
+        self.config.l1_fee_market
+    }
 }
 
 /// Helper structure responsible for collecting the data about recent transactions,
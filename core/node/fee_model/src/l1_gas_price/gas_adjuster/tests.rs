@@ -1,7 +1,7 @@
 use std::{collections::VecDeque, sync::RwLockReadGuard};
 
 use test_casing::test_casing;
-use zksync_config::GasAdjusterConfig;
+use zksync_config::{configs::eth_sender::L1FeeMarket, GasAdjusterConfig};
 use zksync_eth_client::{clients::MockSettlementLayer, BaseFees};
 use zksync_types::{
     commitment::L1BatchCommitmentMode, pubdata_da::PubdataSendingMode, settlement::SettlementMode,
@@ -9,7 +9,7 @@ use zksync_types::{
 use zksync_web3_decl::client::L2;
 
 use super::{GasAdjuster, GasStatistics, GasStatisticsInner};
-use crate::l1_gas_price::GasAdjusterClient;
+use crate::l1_gas_price::{GasAdjusterClient, TxParamsProvider};
 
 /// Check that we compute the median correctly
 #[test]
@@ -73,6 +73,7 @@ fn test_config(settlement_mode: SettlementMode) -> GasAdjusterConfig {
         internal_pubdata_pricing_multiplier: 1.0,
         max_blob_base_fee: None,
         settlement_mode,
+        l1_fee_market: L1FeeMarket::default(),
     }
 }
 
@@ -208,3 +209,57 @@ async fn kept_updated_l2(commitment_mode: L1BatchCommitmentMode) {
         expected_median_blob_base_fee.into()
     );
 }
+
+/// Check that the gas price is sampled for L1s without fee history.
+#[tokio::test]
+async fn kept_updated_with_legacy_fee_market() {
+    // The mock returns 100 wei as the gas price; fee history is not set and must not be queried.
+    let eth_client = MockSettlementLayer::builder().build();
+    eth_client.advance_block_number(6);
+
+    let config = GasAdjusterConfig {
+        l1_fee_market: L1FeeMarket::Legacy,
+        ..test_config(SettlementMode::SettlesToL1)
+    };
+    let adjuster = GasAdjuster::new(
+        GasAdjusterClient::from_l1(Box::new(eth_client.clone().into_client())),
+        config,
+        PubdataSendingMode::Calldata,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        read(&adjuster.base_fee_statistics).samples.len(),
+        config.max_base_fee_samples
+    );
+    assert_eq!(read(&adjuster.base_fee_statistics).median(), 100);
+    assert_eq!(read(&adjuster.base_fee_statistics).last_processed_block, 5);
+    assert_eq!(adjuster.get_priority_fee(), 0);
+    assert_eq!(adjuster.l1_fee_market(), L1FeeMarket::Legacy);
+
+    eth_client.advance_block_number(3);
+    adjuster.keep_updated().await.unwrap();
+    assert_eq!(read(&adjuster.base_fee_statistics).last_processed_block, 8);
+    assert_eq!(read(&adjuster.base_fee_statistics).median(), 100);
+}
+
+#[test_casing(2, [L1FeeMarket::Eip1559, L1FeeMarket::Legacy])]
+#[tokio::test]
+async fn blobs_are_rejected_without_blob_support(l1_fee_market: L1FeeMarket) {
+    let eth_client = MockSettlementLayer::builder().build();
+    let config = GasAdjusterConfig {
+        l1_fee_market,
+        ..test_config(SettlementMode::SettlesToL1)
+    };
+    let err = GasAdjuster::new(
+        GasAdjusterClient::from_l1(Box::new(eth_client.into_client())),
+        config,
+        PubdataSendingMode::Blobs,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("not supported"), "{err}");
+}
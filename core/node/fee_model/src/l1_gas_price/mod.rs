@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use zksync_config::configs::eth_sender::L1FeeMarket;
+
 pub use self::{
     gas_adjuster::{GasAdjuster, GasAdjusterClient},
     main_node_fetcher::MainNodeFeeParamsFetcher,
@@ -38,4 +40,8 @@ pub trait TxParamsProvider: fmt::Debug + 'static + Send + Sync {
 
     /// Returns the recommended `max_fee_per_gas` value for gateway transactions.
     fn get_gateway_tx_pubdata_price(&self) -> u64;
+
+    /// Returns the fee market of the settlement layer. For [`L1FeeMarket::Legacy`], the value returned
+    /// by [`Self::get_base_fee()`] should be used as the gas price of a legacy transaction.
+    fn l1_fee_market(&self) -> L1FeeMarket;
 }
@@ -0,0 +1,217 @@
+//! Dynamic L2 base fee.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use vise::{Gauge, Metrics};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::L1BatchNumber;
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_l2_base_fee")]
+struct L2BaseFeeMetrics {
+    /// Current factor applied to the minimal L2 gas price.
+    multiplier: Gauge<f64>,
+    /// Gas utilization of the last processed L1 batch.
+    batch_utilization: Gauge<f64>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<L2BaseFeeMetrics> = vise::Global::new();
+
+/// Configuration of [`L2BaseFeeAdjuster`].
+#[derive(Debug, Clone, Copy)]
+pub struct L2BaseFeeAdjusterConfig {
+    /// Target utilization of `max_gas_per_batch`, in (0, 1].
+    pub target_utilization: f64,
+    /// Maximum relative change of the multiplier per L1 batch.
+    pub max_change_rate: f64,
+    /// Cap for the multiplier.
+    pub max_multiplier: f64,
+    /// Gas limit per L1 batch used to compute utilization.
+    pub max_gas_per_batch: u64,
+    /// Interval between polls for new sealed L1 batches.
+    pub poll_interval: Duration,
+}
+
+impl L2BaseFeeAdjusterConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.target_utilization > 0.0 && self.target_utilization <= 1.0,
+            "target utilization must be in (0, 1], got {}",
+            self.target_utilization
+        );
+        anyhow::ensure!(
+            self.max_change_rate > 0.0,
+            "max change rate must be positive, got {}",
+            self.max_change_rate
+        );
+        anyhow::ensure!(
+            self.max_multiplier >= 1.0,
+            "max multiplier must be at least 1, got {}",
+            self.max_multiplier
+        );
+        anyhow::ensure!(
+            self.max_gas_per_batch > 0,
+            "max gas per batch must be positive"
+        );
+        Ok(())
+    }
+
+    fn next_multiplier(&self, multiplier: f64, gas_used: u64) -> f64 {
+        let utilization = gas_used as f64 / self.max_gas_per_batch as f64;
+        let change = (self.max_change_rate * (utilization - self.target_utilization)
+            / self.target_utilization)
+            .clamp(-self.max_change_rate, self.max_change_rate);
+        (multiplier * (1.0 + change)).clamp(1.0, self.max_multiplier)
+    }
+}
+
+/// Adjusts the factor applied to the minimal L2 gas price based on gas usage of sealed L1 batches, similarly to EIP-1559:
+///
+/// ```text
+/// multiplier' = multiplier * (1 + max_change_rate * (utilization - target_utilization) / target_utilization)
+/// ```
+///
+/// The multiplier is clamped to `[1, max_multiplier]`, so that the L2 gas price never drops below the configured minimum.
+/// The multiplier is not persisted; after a restart, it starts from 1.
+#[derive(Debug)]
+pub struct L2BaseFeeAdjuster {
+    config: L2BaseFeeAdjusterConfig,
+    pool: ConnectionPool<Core>,
+    /// `f64` bits of the current multiplier.
+    multiplier: AtomicU64,
+}
+
+impl L2BaseFeeAdjuster {
+    pub fn new(
+        config: L2BaseFeeAdjusterConfig,
+        pool: ConnectionPool<Core>,
+    ) -> anyhow::Result<Self> {
+        config.validate().context("invalid L2 base fee config")?;
+        Ok(Self {
+            config,
+            pool,
+            multiplier: AtomicU64::new(1.0_f64.to_bits()),
+        })
+    }
+
+    /// Returns the current factor applied to the minimal L2 gas price.
+    pub fn multiplier(&self) -> f64 {
+        f64::from_bits(self.multiplier.load(Ordering::Relaxed))
+    }
+
+    fn process_batch(&self, gas_used: u64) {
+        let multiplier = self.config.next_multiplier(self.multiplier(), gas_used);
+        self.multiplier
+            .store(multiplier.to_bits(), Ordering::Relaxed);
+        METRICS.multiplier.set(multiplier);
+        METRICS
+            .batch_utilization
+            .set(gas_used as f64 / self.config.max_gas_per_batch as f64);
+    }
+
+    async fn process_new_batches(
+        &self,
+        last_processed_batch: &mut Option<L1BatchNumber>,
+    ) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("l2_base_fee_adjuster").await?;
+        let Some(sealed_batch) = storage.blocks_dal().get_sealed_l1_batch_number().await? else {
+            return Ok(());
+        };
+        let Some(last_batch) = *last_processed_batch else {
+            // Batches sealed before the start are not replayed.
+            *last_processed_batch = Some(sealed_batch);
+            return Ok(());
+        };
+
+        let mut batch_number = last_batch + 1;
+        while batch_number <= sealed_batch {
+            let stats = storage
+                .blocks_dal()
+                .get_l1_batch_execution_stats(batch_number)
+                .await?;
+            if let Some(stats) = stats {
+                self.process_batch(stats.gas_used);
+            } else {
+                tracing::warn!("L1 batch #{batch_number} has no execution stats; skipping it");
+            }
+            *last_processed_batch = Some(batch_number);
+            batch_number += 1;
+        }
+        Ok(())
+    }
+
+    pub async fn run(&self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut last_processed_batch = None;
+        while !*stop_receiver.borrow_and_update() {
+            if let Err(err) = self.process_new_batches(&mut last_processed_batch).await {
+                tracing::warn!("Cannot update L2 base fee: {err:#}");
+            }
+
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(self.config.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop signal received, L2 base fee adjuster is shutting down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: L2BaseFeeAdjusterConfig = L2BaseFeeAdjusterConfig {
+        target_utilization: 0.5,
+        max_change_rate: 0.125,
+        max_multiplier: 2.0,
+        max_gas_per_batch: 1_000,
+        poll_interval: Duration::from_secs(1),
+    };
+
+    #[test]
+    fn multiplier_follows_utilization() {
+        // Full batches increase the multiplier by `max_change_rate`.
+        let multiplier = CONFIG.next_multiplier(1.0, 1_000);
+        assert!((multiplier - 1.125).abs() < 1e-9);
+        // Batches at the target utilization don't change the multiplier.
+        let multiplier = CONFIG.next_multiplier(multiplier, 500);
+        assert!((multiplier - 1.125).abs() < 1e-9);
+        // Empty batches decrease it.
+        let multiplier = CONFIG.next_multiplier(multiplier, 0);
+        assert!((multiplier - 1.125 * 0.875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn multiplier_is_clamped() {
+        assert_eq!(CONFIG.next_multiplier(1.0, 0), 1.0);
+
+        let mut multiplier = 1.0;
+        for _ in 0..20 {
+            // Gas usage may exceed the limit because of the overhead, but the change is still capped.
+            multiplier = CONFIG.next_multiplier(multiplier, 10_000);
+        }
+        assert_eq!(multiplier, 2.0);
+    }
+
+    #[test]
+    fn validating_config() {
+        CONFIG.validate().unwrap();
+        let config = L2BaseFeeAdjusterConfig {
+            target_utilization: 0.0,
+            ..CONFIG
+        };
+        config.validate().unwrap_err();
+        let config = L2BaseFeeAdjusterConfig {
+            max_multiplier: 0.5,
+            ..CONFIG
+        };
+        config.validate().unwrap_err();
+    }
+}
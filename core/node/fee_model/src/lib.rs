@@ -7,9 +7,10 @@ use zksync_types::fee_model::{
     BaseTokenConversionRatio, BatchFeeInput, FeeModelConfig, FeeParams, FeeParamsV1, FeeParamsV2,
 };
 
-use crate::l1_gas_price::GasAdjuster;
+use crate::{l1_gas_price::GasAdjuster, l2_base_fee::L2BaseFeeAdjuster};
 
 pub mod l1_gas_price;
+pub mod l2_base_fee;
 
 /// Trait responsible for providing numerator and denominator for adjusting gas price that is denominated
 /// in a non-eth base token
@@ -51,22 +52,31 @@ pub struct MainNodeFeeInputProvider {
     provider: Arc<GasAdjuster>,
     base_token_ratio_provider: Arc<dyn BaseTokenRatioProvider>,
     config: FeeModelConfig,
+    l2_base_fee_adjuster: Option<Arc<L2BaseFeeAdjuster>>,
 }
 
 #[async_trait]
 impl BatchFeeModelInputProvider for MainNodeFeeInputProvider {
     fn get_fee_model_params(&self) -> FeeParams {
         match self.config {
-            FeeModelConfig::V1(config) => FeeParams::V1(FeeParamsV1 {
-                config,
-                l1_gas_price: self.provider.estimate_effective_gas_price(),
-            }),
-            FeeModelConfig::V2(config) => FeeParams::V2(FeeParamsV2::new(
-                config,
-                self.provider.estimate_effective_gas_price(),
-                self.provider.estimate_effective_pubdata_price(),
-                self.base_token_ratio_provider.get_conversion_ratio(),
-            )),
+            FeeModelConfig::V1(mut config) => {
+                config.minimal_l2_gas_price =
+                    self.adjust_minimal_l2_gas_price(config.minimal_l2_gas_price);
+                FeeParams::V1(FeeParamsV1 {
+                    config,
+                    l1_gas_price: self.provider.estimate_effective_gas_price(),
+                })
+            }
+            FeeModelConfig::V2(mut config) => {
+                config.minimal_l2_gas_price =
+                    self.adjust_minimal_l2_gas_price(config.minimal_l2_gas_price);
+                FeeParams::V2(FeeParamsV2::new(
+                    config,
+                    self.provider.estimate_effective_gas_price(),
+                    self.provider.estimate_effective_pubdata_price(),
+                    self.base_token_ratio_provider.get_conversion_ratio(),
+                ))
+            }
         }
     }
 }
@@ -81,6 +91,20 @@ impl MainNodeFeeInputProvider {
             provider,
             base_token_ratio_provider,
             config,
+            l2_base_fee_adjuster: None,
+        }
+    }
+
+    /// Makes the minimal L2 gas price dynamic, adjusting it based on the utilization of sealed L1 batches.
+    pub fn with_l2_base_fee_adjuster(mut self, adjuster: Arc<L2BaseFeeAdjuster>) -> Self {
+        self.l2_base_fee_adjuster = Some(adjuster);
+        self
+    }
+
+    fn adjust_minimal_l2_gas_price(&self, minimal_l2_gas_price: u64) -> u64 {
+        match &self.l2_base_fee_adjuster {
+            Some(adjuster) => (minimal_l2_gas_price as f64 * adjuster.multiplier()) as u64,
+            None => minimal_l2_gas_price,
         }
     }
 }
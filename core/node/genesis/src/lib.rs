@@ -5,16 +5,16 @@
 use std::fmt::Formatter;
 
 use anyhow::Context as _;
-use zksync_config::GenesisConfig;
+use zksync_config::{configs::CustomSystemContract, GenesisConfig};
 use zksync_contracts::{
-    hyperchain_contract, verifier_contract, BaseSystemContracts, BaseSystemContractsHashes,
-    SET_CHAIN_ID_EVENT,
+    hyperchain_contract, read_bytecode_from_path, verifier_contract, BaseSystemContracts,
+    BaseSystemContractsHashes, SET_CHAIN_ID_EVENT,
 };
 use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_eth_client::{CallFunctionArgs, EthInterface};
 use zksync_merkle_tree::{domain::ZkSyncTree, TreeInstruction};
 use zksync_multivm::utils::get_max_gas_per_pubdata_byte;
-use zksync_system_constants::PRIORITY_EXPIRATION;
+use zksync_system_constants::{MAX_KERNEL_SPACE_ADDRESS, PRIORITY_EXPIRATION};
 use zksync_types::{
     block::{BlockGasCount, DeployedContract, L1BatchHeader, L2BlockHasher, L2BlockHeader},
     bytecode::BytecodeHash,
@@ -73,6 +73,11 @@ pub enum GenesisError {
     Other(#[from] anyhow::Error),
     #[error("Field: {0} required for genesis")]
     MalformedConfig(&'static str),
+    #[error("Custom system contract at {address:?} is invalid: {reason}")]
+    CustomSystemContract {
+        address: Address,
+        reason: &'static str,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -118,6 +123,9 @@ impl GenesisParams {
         if config.protocol_version.is_none() {
             return Err(GenesisError::MalformedConfig("protocol_version"));
         }
+        for contract in &config.custom_system_contracts {
+            validate_custom_system_contract(contract, &system_contracts)?;
+        }
         Ok(GenesisParams {
             base_system_contracts,
             system_contracts,
@@ -130,7 +138,10 @@ impl GenesisParams {
         if config.evm_emulator_hash.is_some() {
             base_system_contracts = base_system_contracts.with_latest_evm_emulator();
         }
-        let system_contracts = get_system_smart_contracts(config.evm_emulator_hash.is_some());
+        let mut system_contracts = get_system_smart_contracts(config.evm_emulator_hash.is_some());
+        for contract in &config.custom_system_contracts {
+            system_contracts.push(load_custom_system_contract(contract)?);
+        }
         Self::from_genesis_config(config, base_system_contracts, system_contracts)
     }
 
@@ -185,9 +196,52 @@ pub fn mock_genesis_config() -> GenesisConfig {
         fee_account: Default::default(),
         dummy_verifier: false,
         l1_batch_commit_data_generator_mode: Default::default(),
+        custom_system_contracts: vec![],
     }
 }
 
+fn load_custom_system_contract(
+    contract: &CustomSystemContract,
+) -> Result<DeployedContract, GenesisError> {
+    let bytecode = read_bytecode_from_path(&contract.source).with_context(|| {
+        format!(
+            "artifact for custom system contract at {:?} is not found at `{}`",
+            contract.address, contract.source
+        )
+    })?;
+    Ok(DeployedContract::new(
+        AccountTreeId::new(contract.address),
+        bytecode,
+    ))
+}
+
+/// Checks that a custom system contract is in the kernel space and is deployed at genesis with the expected bytecode.
+fn validate_custom_system_contract(
+    contract: &CustomSystemContract,
+    system_contracts: &[DeployedContract],
+) -> Result<(), GenesisError> {
+    let error = |reason| GenesisError::CustomSystemContract {
+        address: contract.address,
+        reason,
+    };
+
+    if contract.address > MAX_KERNEL_SPACE_ADDRESS {
+        return Err(error("address is outside the kernel space"));
+    }
+    let mut deployed = system_contracts
+        .iter()
+        .filter(|deployed| *deployed.account_id.address() == contract.address);
+    let (Some(deployed), None) = (deployed.next(), deployed.next()) else {
+        return Err(error(
+            "address must be occupied by exactly one system contract",
+        ));
+    };
+    if BytecodeHash::for_bytecode(&deployed.bytecode).value() != contract.bytecode_hash {
+        return Err(error("bytecode hash mismatch"));
+    }
+    Ok(())
+}
+
 // Insert genesis batch into the database
 pub async fn insert_genesis_batch(
     storage: &mut Connection<'_, Core>,
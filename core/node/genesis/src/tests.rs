@@ -1,5 +1,7 @@
 use zksync_config::GenesisConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_system_constants::CONTRACT_DEPLOYER_ADDRESS;
+use zksync_types::get_code_key;
 
 use super::*;
 
@@ -64,3 +66,67 @@ async fn running_genesis_with_non_latest_protocol_version() {
     insert_genesis_batch(&mut conn, &params).await.unwrap();
     assert!(!conn.blocks_dal().is_genesis_needed().await.unwrap());
 }
+
+fn genesis_params_with_custom_contract(
+    address: Address,
+    bytecode_hash: H256,
+) -> Result<GenesisParams, GenesisError> {
+    let base_system_contracts = BaseSystemContracts::load_from_disk();
+    let mut system_contracts = get_system_smart_contracts(false);
+    let bytecode = base_system_contracts.default_aa.code.clone();
+    system_contracts.push(DeployedContract::new(AccountTreeId::new(address), bytecode));
+
+    let config = GenesisConfig {
+        custom_system_contracts: vec![CustomSystemContract {
+            address,
+            bytecode_hash,
+            source: "custom/Custom.json".to_owned(),
+        }],
+        ..mock_genesis_config()
+    };
+    GenesisParams::from_genesis_config(config, base_system_contracts, system_contracts)
+}
+
+#[tokio::test]
+async fn running_genesis_with_custom_system_contract() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    let address = Address::from_low_u64_be(0x9000);
+    let bytecode_hash = BaseSystemContracts::load_from_disk().default_aa.hash;
+    let params = genesis_params_with_custom_contract(address, bytecode_hash).unwrap();
+
+    insert_genesis_batch(&mut conn, &params).await.unwrap();
+    let deployed_hash = conn
+        .storage_web3_dal()
+        .get_value(&get_code_key(&address))
+        .await
+        .unwrap();
+    assert_eq!(deployed_hash, bytecode_hash);
+}
+
+#[test]
+fn validating_custom_system_contracts() {
+    let bytecode_hash = BaseSystemContracts::load_from_disk().default_aa.hash;
+    let err =
+        genesis_params_with_custom_contract(Address::from_low_u64_be(0x1_0000), bytecode_hash)
+            .unwrap_err();
+    assert!(
+        matches!(err, GenesisError::CustomSystemContract { reason, .. } if reason.contains("kernel space")),
+        "{err:?}"
+    );
+
+    let err = genesis_params_with_custom_contract(Address::from_low_u64_be(0x9000), H256::zero())
+        .unwrap_err();
+    assert!(
+        matches!(err, GenesisError::CustomSystemContract { reason, .. } if reason.contains("hash mismatch")),
+        "{err:?}"
+    );
+
+    // Collision with a built-in system contract
+    let err =
+        genesis_params_with_custom_contract(CONTRACT_DEPLOYER_ADDRESS, bytecode_hash).unwrap_err();
+    assert!(
+        matches!(err, GenesisError::CustomSystemContract { reason, .. } if reason.contains("exactly one")),
+        "{err:?}"
+    );
+}
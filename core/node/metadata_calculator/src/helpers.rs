@@ -254,7 +254,12 @@ impl AsyncTree {
     }
 
     pub fn pruner(&mut self) -> PruningHandles {
-        self.as_mut().pruner()
+        let (pruner, pruner_handle) = self.as_mut().pruner();
+        PruningHandles {
+            pruner,
+            pruner_handle,
+            tree_reader: self.reader(),
+        }
     }
 
     pub fn reader(&self) -> AsyncTreeReader {
@@ -350,6 +355,11 @@ impl AsyncTreeReader {
         })
     }
 
+    /// Returns the underlying RocksDB instance, e.g., to perform manual compaction.
+    pub(super) fn rocksdb(&self) -> RocksDB<MerkleTreeColumnFamily> {
+        self.inner.db().clone().into_inner()
+    }
+
     fn downgrade(&self) -> WeakAsyncTreeReader {
         WeakAsyncTreeReader {
             db: self.inner.db().clone().into_inner().downgrade(),
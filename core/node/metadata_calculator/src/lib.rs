@@ -25,7 +25,7 @@ use self::{
 };
 pub use self::{
     helpers::{AsyncTreeReader, LazyAsyncTreeReader, MerkleTreeInfo},
-    pruning::MerkleTreePruningTask,
+    pruning::{MaintenanceWindow, MerkleTreeMaintenanceConfig, MerkleTreePruningTask},
     repair::StaleKeysRepairTask,
 };
 use crate::helpers::create_readonly_db;
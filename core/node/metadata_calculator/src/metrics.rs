@@ -3,8 +3,8 @@
 use std::time::{Duration, Instant, SystemTime};
 
 use vise::{
-    Buckets, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Info,
-    LatencyObserver, Metrics, Unit,
+    Buckets, Counter, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram,
+    Info, LatencyObserver, Metrics, Unit,
 };
 use zksync_config::configs::database::MerkleTreeMode;
 use zksync_shared_metrics::{BlockStage, APP_METRICS};
@@ -260,3 +260,22 @@ pub(super) struct MetadataCalculatorRecoveryMetrics {
 #[vise::register]
 pub(super) static RECOVERY_METRICS: vise::Global<MetadataCalculatorRecoveryMetrics> =
     vise::Global::new();
+
+/// Metrics for scheduled Merkle tree maintenance (retention-based pruning and RocksDB compaction).
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_merkle_tree_maintenance")]
+pub(super) struct MerkleTreeMaintenanceMetrics {
+    /// Total size of SST files in the tree RocksDB measured after the latest compaction.
+    #[metrics(unit = Unit::Bytes)]
+    pub db_size: Gauge<u64>,
+    /// Total disk space reclaimed by compactions.
+    #[metrics(unit = Unit::Bytes)]
+    pub reclaimed_space: Counter,
+    /// Latency of a manual RocksDB compaction.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub compaction_latency: Histogram<Duration>,
+}
+
+#[vise::register]
+pub(super) static MAINTENANCE_METRICS: vise::Global<MerkleTreeMaintenanceMetrics> =
+    vise::Global::new();
@@ -1,16 +1,121 @@
 //! Merkle tree pruning logic.
 
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::Context as _;
 use serde::Serialize;
 use tokio::sync::{oneshot, watch};
+use zksync_config::configs::database::MerkleTreeConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_merkle_tree::{MerkleTreePruner, MerkleTreePrunerHandle, RocksDBWrapper};
 use zksync_types::L1BatchNumber;
 
-pub(super) type PruningHandles = (MerkleTreePruner<RocksDBWrapper>, MerkleTreePrunerHandle);
+use crate::{helpers::AsyncTreeReader, metrics::MAINTENANCE_METRICS};
+
+#[derive(Debug)]
+pub(super) struct PruningHandles {
+    pub pruner: MerkleTreePruner<RocksDBWrapper>,
+    pub pruner_handle: MerkleTreePrunerHandle,
+    pub tree_reader: AsyncTreeReader,
+}
+
+/// Daily time window (in UTC) during which heavy tree maintenance is performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceWindow {
+    start_hour: u8,
+    end_hour: u8,
+}
+
+impl MaintenanceWindow {
+    /// Creates a window from the start hour (inclusive) to the end hour (exclusive). The window may span midnight.
+    pub fn new(start_hour: u8, end_hour: u8) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            start_hour < 24 && end_hour < 24,
+            "maintenance window hours must be in 0..=23, got {start_hour}..{end_hour}"
+        );
+        anyhow::ensure!(
+            start_hour != end_hour,
+            "maintenance window must not be empty"
+        );
+        Ok(Self {
+            start_hour,
+            end_hour,
+        })
+    }
+
+    fn contains(&self, timestamp: SystemTime) -> bool {
+        let secs = timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        let hour = (secs / 3_600 % 24) as u8;
+        if self.start_hour < self.end_hour {
+            (self.start_hour..self.end_hour).contains(&hour)
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// Merkle tree maintenance that is not driven by pruning in Postgres.
+#[derive(Debug, Clone, Copy)]
+pub struct MerkleTreeMaintenanceConfig {
+    /// Number of the latest L1 batches to retain in the tree; older versions are pruned.
+    pub retained_l1_batches: Option<u64>,
+    /// If set, retention-based pruning and manual RocksDB compaction are only performed inside this window.
+    /// If not set, retention-based pruning is performed continuously, and compaction is left to RocksDB.
+    pub window: Option<MaintenanceWindow>,
+    /// Minimum number of tree versions pruned since the last manual compaction to trigger the next one.
+    pub compaction_min_pruned_versions: u64,
+}
+
+impl Default for MerkleTreeMaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            retained_l1_batches: None,
+            window: None,
+            compaction_min_pruned_versions: Self::DEFAULT_COMPACTION_MIN_PRUNED_VERSIONS,
+        }
+    }
+}
+
+impl MerkleTreeMaintenanceConfig {
+    const DEFAULT_COMPACTION_MIN_PRUNED_VERSIONS: u64 = 1_000;
+
+    pub fn new(config: &MerkleTreeConfig) -> anyhow::Result<Self> {
+        let window = match (
+            config.maintenance_window_start_hour,
+            config.maintenance_window_end_hour,
+        ) {
+            (Some(start_hour), Some(end_hour)) => {
+                Some(MaintenanceWindow::new(start_hour, end_hour)?)
+            }
+            (None, None) => None,
+            _ => anyhow::bail!(
+                "both start and end hours of the maintenance window must be specified"
+            ),
+        };
+        anyhow::ensure!(
+            config.retained_l1_batches != Some(0),
+            "number of retained L1 batches must be positive"
+        );
+        anyhow::ensure!(
+            config.maintenance_compaction_min_pruned_l1_batches != Some(0),
+            "minimum number of pruned L1 batches triggering compaction must be positive"
+        );
+        Ok(Self {
+            retained_l1_batches: config.retained_l1_batches,
+            window,
+            compaction_min_pruned_versions: config
+                .maintenance_compaction_min_pruned_l1_batches
+                .unwrap_or(Self::DEFAULT_COMPACTION_MIN_PRUNED_VERSIONS),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.retained_l1_batches.is_some() || self.window.is_some()
+    }
+}
 
 #[derive(Debug, Serialize)]
 #[serde(tag = "stage", rename_all = "snake_case")]
@@ -44,6 +149,7 @@ pub struct MerkleTreePruningTask {
     pool: ConnectionPool<Core>,
     health_updater: HealthUpdater,
     poll_interval: Duration,
+    maintenance: MerkleTreeMaintenanceConfig,
 }
 
 impl MerkleTreePruningTask {
@@ -57,9 +163,16 @@ impl MerkleTreePruningTask {
             pool,
             health_updater: ReactiveHealthCheck::new("tree_pruner").1,
             poll_interval,
+            maintenance: MerkleTreeMaintenanceConfig::default(),
         }
     }
 
+    /// Configures maintenance not driven by pruning in Postgres.
+    pub fn with_maintenance(mut self, maintenance: MerkleTreeMaintenanceConfig) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
     pub fn health_check(&self) -> ReactiveHealthCheck {
         self.health_updater.subscribe()
     }
@@ -69,11 +182,11 @@ impl MerkleTreePruningTask {
         self.health_updater
             .update(MerkleTreePruningTaskHealth::Initialization.into());
 
-        let (mut pruner, pruner_handle);
+        let handles;
         tokio::select! {
             res = self.handles => {
                 match res {
-                    Ok(handles) => (pruner, pruner_handle) = handles,
+                    Ok(res) => handles = res,
                     Err(_) => {
                         tracing::info!("Merkle tree dropped; shutting down tree pruning");
                         return Ok(());
@@ -90,19 +203,43 @@ impl MerkleTreePruningTask {
         };
         self.health_updater.update(health.into());
         tracing::info!("Obtained pruning handles; starting Merkle tree pruning");
+        let PruningHandles {
+            mut pruner,
+            pruner_handle,
+            tree_reader,
+        } = handles;
 
         // Pruner is not allocated a managed task because it is blocking; its cancellation awareness inherently
         // depends on the pruner handle (i.e., this task).
         pruner.set_poll_interval(self.poll_interval);
         let pruner_task_handle = tokio::task::spawn_blocking(|| pruner.run());
+        let mut last_compacted_version = None;
 
         while !*stop_receiver.borrow_and_update() {
             let mut storage = self.pool.connection_tagged("metadata_calculator").await?;
             let pruning_info = storage.pruning_dal().get_pruning_info().await?;
             drop(storage);
 
-            if let Some(l1_batch_number) = pruning_info.last_hard_pruned_l1_batch {
-                let target_retained_l1_batch_number = l1_batch_number + 1;
+            let in_window = self
+                .maintenance
+                .window
+                .map_or(true, |window| window.contains(SystemTime::now()));
+            let pruned_target = pruning_info
+                .last_hard_pruned_l1_batch
+                .map(|l1_batch_number| l1_batch_number + 1);
+            let retention_target = match self.maintenance.retained_l1_batches {
+                Some(retained_l1_batches) if in_window => {
+                    let next_l1_batch_number =
+                        tree_reader.clone().info().await.next_l1_batch_number;
+                    u32::try_from(retained_l1_batches)
+                        .ok()
+                        .and_then(|retained| next_l1_batch_number.0.checked_sub(retained))
+                        .map(L1BatchNumber)
+                }
+                _ => None,
+            };
+
+            if let Some(target_retained_l1_batch_number) = pruned_target.max(retention_target) {
                 let target_retained_version = u64::from(target_retained_l1_batch_number.0);
                 let Ok(prev_target_version) =
                     pruner_handle.set_target_retained_version(target_retained_version)
@@ -124,6 +261,15 @@ impl MerkleTreePruningTask {
                 }
             }
 
+            if self.maintenance.window.is_some() && in_window {
+                last_compacted_version = Self::compact_if_pruned(
+                    &tree_reader,
+                    last_compacted_version,
+                    self.maintenance.compaction_min_pruned_versions,
+                )
+                .await?;
+            }
+
             if tokio::time::timeout(self.poll_interval, stop_receiver.changed())
                 .await
                 .is_ok()
@@ -140,6 +286,54 @@ impl MerkleTreePruningTask {
             .await
             .context("Merkle tree pruning thread panicked")?
     }
+
+    /// Compacts the tree RocksDB once pruning has advanced the minimum retained version by at least
+    /// `min_pruned_versions` since the last compaction, so that space occupied by pruned nodes is reclaimed.
+    /// Returns the minimum retained version at the time of the last compaction.
+    async fn compact_if_pruned(
+        tree_reader: &AsyncTreeReader,
+        last_compacted_version: Option<u64>,
+        min_pruned_versions: u64,
+    ) -> anyhow::Result<Option<u64>> {
+        let Some(min_retained_version) = tree_reader
+            .clone()
+            .info()
+            .await
+            .min_l1_batch_number
+            .map(|number| u64::from(number.0))
+        else {
+            return Ok(last_compacted_version);
+        };
+        let Some(last_compacted_version) = last_compacted_version else {
+            // Don't compact the tree on each node start; just remember the current state.
+            return Ok(Some(min_retained_version));
+        };
+        if min_retained_version < last_compacted_version.saturating_add(min_pruned_versions) {
+            return Ok(Some(last_compacted_version));
+        }
+
+        let db = tree_reader.rocksdb();
+        tracing::info!(
+            "Compacting Merkle tree RocksDB after pruning up to version {min_retained_version:?}"
+        );
+        let latency = MAINTENANCE_METRICS.compaction_latency.start();
+        let (size_before, size_after) = tokio::task::spawn_blocking(move || {
+            let size_before = db.total_sst_size();
+            db.compact();
+            (size_before, db.total_sst_size())
+        })
+        .await
+        .context("Merkle tree compaction panicked")?;
+        let latency = latency.observe();
+
+        let reclaimed = size_before.saturating_sub(size_after);
+        MAINTENANCE_METRICS.reclaimed_space.inc_by(reclaimed);
+        MAINTENANCE_METRICS.db_size.set(size_after);
+        tracing::info!(
+            "Compacted Merkle tree RocksDB in {latency:?}, reclaiming {reclaimed} bytes; DB size is {size_after} bytes"
+        );
+        Ok(Some(min_retained_version))
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +403,114 @@ mod tests {
             .await;
     }
 
+    #[test]
+    fn maintenance_window_contains_timestamps() {
+        let at_hour = |hour: u64| UNIX_EPOCH + Duration::from_secs(86_400 * 100 + hour * 3_600 + 1);
+
+        let window = MaintenanceWindow::new(2, 5).unwrap();
+        assert!(!window.contains(at_hour(1)));
+        assert!(window.contains(at_hour(2)));
+        assert!(window.contains(at_hour(4)));
+        assert!(!window.contains(at_hour(5)));
+
+        let window = MaintenanceWindow::new(22, 4).unwrap();
+        assert!(window.contains(at_hour(23)));
+        assert!(window.contains(at_hour(0)));
+        assert!(window.contains(at_hour(3)));
+        assert!(!window.contains(at_hour(4)));
+        assert!(!window.contains(at_hour(21)));
+
+        MaintenanceWindow::new(3, 3).unwrap_err();
+        MaintenanceWindow::new(3, 24).unwrap_err();
+    }
+
+    #[test]
+    fn maintenance_config_validation() {
+        let mut config = MerkleTreeConfig::default();
+        assert!(!MerkleTreeMaintenanceConfig::new(&config)
+            .unwrap()
+            .is_enabled());
+
+        config.maintenance_window_start_hour = Some(1);
+        MerkleTreeMaintenanceConfig::new(&config).unwrap_err();
+        config.maintenance_window_end_hour = Some(3);
+        let maintenance = MerkleTreeMaintenanceConfig::new(&config).unwrap();
+        assert_eq!(
+            maintenance.window,
+            Some(MaintenanceWindow::new(1, 3).unwrap())
+        );
+
+        assert_eq!(
+            maintenance.compaction_min_pruned_versions,
+            MerkleTreeMaintenanceConfig::DEFAULT_COMPACTION_MIN_PRUNED_VERSIONS
+        );
+        config.maintenance_compaction_min_pruned_l1_batches = Some(0);
+        MerkleTreeMaintenanceConfig::new(&config).unwrap_err();
+        config.maintenance_compaction_min_pruned_l1_batches = Some(100);
+        let maintenance = MerkleTreeMaintenanceConfig::new(&config).unwrap();
+        assert_eq!(maintenance.compaction_min_pruned_versions, 100);
+
+        config.retained_l1_batches = Some(0);
+        MerkleTreeMaintenanceConfig::new(&config).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn retention_based_tree_pruning() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+        let config = mock_config(temp_dir.path());
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        reset_db_state(&pool, 5).await;
+
+        let mut calculator = MetadataCalculator::new(config, None, pool.clone())
+            .await
+            .unwrap();
+        let reader = calculator.tree_reader();
+        let maintenance = MerkleTreeMaintenanceConfig {
+            retained_l1_batches: Some(2),
+            ..MerkleTreeMaintenanceConfig::default()
+        };
+        let pruning_task = calculator
+            .pruning_task(POLL_INTERVAL)
+            .with_maintenance(maintenance);
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let calculator_handle = tokio::spawn(calculator.run(stop_receiver.clone()));
+        let pruning_task_handle = tokio::spawn(pruning_task.run(stop_receiver));
+
+        let reader = reader.wait().await.unwrap();
+        loop {
+            let info = reader.clone().info().await;
+            if info.next_l1_batch_number == L1BatchNumber(6)
+                && info.min_l1_batch_number == Some(L1BatchNumber(4))
+            {
+                break;
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        reader.verify_consistency(L1BatchNumber(5)).await.unwrap();
+
+        // Compaction should only be performed if enough versions were pruned since the last compaction.
+        let compacted_version = MerkleTreePruningTask::compact_if_pruned(&reader, None, 1)
+            .await
+            .unwrap();
+        assert_eq!(compacted_version, Some(4));
+        let compacted_version = MerkleTreePruningTask::compact_if_pruned(&reader, Some(3), 2)
+            .await
+            .unwrap();
+        assert_eq!(compacted_version, Some(3));
+        let compacted_version = MerkleTreePruningTask::compact_if_pruned(&reader, Some(2), 2)
+            .await
+            .unwrap();
+        assert_eq!(compacted_version, Some(4));
+
+        stop_sender.send_replace(true);
+        calculator_handle.await.unwrap().unwrap();
+        pruning_task_handle.await.unwrap().unwrap();
+    }
+
     #[derive(Debug)]
     enum PrematureExitScenario {
         CalculatorDrop,
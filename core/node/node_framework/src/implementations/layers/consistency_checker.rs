@@ -1,5 +1,5 @@
 use zksync_consistency_checker::ConsistencyChecker;
-use zksync_types::{commitment::L1BatchCommitmentMode, Address};
+use zksync_types::{commitment::L1BatchCommitmentMode, Address, L1BatchNumber};
 
 use crate::{
     implementations::resources::{
@@ -19,6 +19,8 @@ pub struct ConsistencyCheckerLayer {
     diamond_proxy_addr: Address,
     max_batches_to_recheck: u32,
     commitment_mode: L1BatchCommitmentMode,
+    commitment_mode_transitions: Vec<L1BatchNumber>,
+    forced_inclusion_deadline: Option<u32>,
 }
 
 #[derive(Debug, FromContext)]
@@ -47,8 +49,22 @@ impl ConsistencyCheckerLayer {
             diamond_proxy_addr,
             max_batches_to_recheck,
             commitment_mode,
+            commitment_mode_transitions: vec![],
+            forced_inclusion_deadline: None,
         }
     }
+
+    /// Sets the first L1 batches committed after each switch of the chain commitment mode.
+    pub fn with_commitment_mode_transitions(mut self, first_batches: Vec<L1BatchNumber>) -> Self {
+        self.commitment_mode_transitions = first_batches;
+        self
+    }
+
+    /// Sets the inclusion deadline for priority operations (in L1 batches) to be verified by the checker.
+    pub fn with_forced_inclusion_deadline(mut self, deadline_batches: Option<u32>) -> Self {
+        self.forced_inclusion_deadline = deadline_batches;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -66,14 +82,19 @@ impl WiringLayer for ConsistencyCheckerLayer {
 
         let singleton_pool = input.master_pool.get_singleton().await?;
 
-        let consistency_checker = ConsistencyChecker::new(
+        let mut consistency_checker = ConsistencyChecker::new(
             l1_client,
             self.max_batches_to_recheck,
             singleton_pool,
             self.commitment_mode,
         )
         .map_err(WiringError::Internal)?
-        .with_diamond_proxy_addr(self.diamond_proxy_addr);
+        .with_diamond_proxy_addr(self.diamond_proxy_addr)
+        .with_commitment_mode_transitions(self.commitment_mode_transitions);
+        if let Some(deadline_batches) = self.forced_inclusion_deadline {
+            consistency_checker =
+                consistency_checker.with_forced_inclusion_deadline(deadline_batches);
+        }
 
         input
             .app_health
@@ -7,7 +7,7 @@ use crate::{
         pools::{MasterPool, PoolResource},
     },
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{RestartPolicy, RestartableTask, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -17,6 +17,7 @@ use crate::{
 pub struct DataAvailabilityDispatcherLayer {
     state_keeper_config: StateKeeperConfig,
     da_config: DADispatcherConfig,
+    restart_policy: RestartPolicy,
 }
 
 #[derive(Debug, FromContext)]
@@ -30,7 +31,7 @@ pub struct Input {
 #[context(crate = crate)]
 pub struct Output {
     #[context(task)]
-    pub da_dispatcher_task: DataAvailabilityDispatcher,
+    pub da_dispatcher_task: RestartableTask,
 }
 
 impl DataAvailabilityDispatcherLayer {
@@ -38,8 +39,15 @@ impl DataAvailabilityDispatcherLayer {
         Self {
             state_keeper_config,
             da_config,
+            restart_policy: RestartPolicy::default(),
         }
     }
+
+    /// Sets the policy used to restart the dispatcher after it fails with a retriable DA client error.
+    pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.restart_policy = restart_policy;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -65,8 +73,18 @@ impl WiringLayer for DataAvailabilityDispatcherLayer {
             }
         }
 
-        let da_dispatcher_task =
-            DataAvailabilityDispatcher::new(master_pool, self.da_config, da_client);
+        let da_config = self.da_config;
+        let da_dispatcher_task = RestartableTask::new(
+            move || {
+                DataAvailabilityDispatcher::new(
+                    master_pool.clone(),
+                    da_config.clone(),
+                    da_client.clone(),
+                )
+            },
+            DataAvailabilityDispatcher::is_retriable_error,
+            self.restart_policy,
+        );
 
         Ok(Output { da_dispatcher_task })
     }
@@ -2,13 +2,16 @@ use anyhow::Context;
 use zksync_circuit_breaker::l1_txs::FailedL1TransactionChecker;
 use zksync_config::configs::{eth_sender::EthConfig, ContractsConfig};
 use zksync_eth_client::BoundEthInterface;
-use zksync_eth_sender::{Aggregator, EthTxAggregator};
+use zksync_eth_sender::{Aggregator, CustomOperators, EthTxAggregator};
 use zksync_types::{commitment::L1BatchCommitmentMode, settlement::SettlementMode, L2ChainId};
 
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
-        eth_interface::{BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource},
+        eth_interface::{
+            BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+            BoundEthInterfaceForProveResource, BoundEthInterfaceResource,
+        },
         object_store::ObjectStoreResource,
         pools::{MasterPool, PoolResource, ReplicaPool},
     },
@@ -30,6 +33,8 @@ use crate::{
 /// - `PoolResource<ReplicaPool>`
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
+/// - `BoundEthInterfaceForProveResource` (optional)
+/// - `BoundEthInterfaceForExecuteResource` (optional)
 /// - `ObjectStoreResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
@@ -52,6 +57,8 @@ pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
     pub eth_client: Option<BoundEthInterfaceResource>,
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    pub eth_client_prove: Option<BoundEthInterfaceForProveResource>,
+    pub eth_client_execute: Option<BoundEthInterfaceForExecuteResource>,
     pub object_store: ObjectStoreResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -103,12 +110,27 @@ impl WiringLayer for EthTxAggregatorLayer {
         let eth_client_blobs_addr = eth_client_blobs
             .as_deref()
             .map(BoundEthInterface::sender_account);
+        // Dedicated prove and execute operators aren't used when settling to Gateway.
+        let is_gateway = self.settlement_mode.is_gateway();
+        let eth_client_prove_addr = input
+            .eth_client_prove
+            .filter(|_| !is_gateway)
+            .map(|client| client.0.sender_account());
+        let eth_client_execute_addr = input
+            .eth_client_execute
+            .filter(|_| !is_gateway)
+            .map(|client| client.0.sender_account());
 
         let config = self.eth_sender_config.sender.context("sender")?;
+        let custom_operators = CustomOperators {
+            commit: eth_client_blobs_addr.is_some(),
+            prove: eth_client_prove_addr.is_some(),
+            execute: eth_client_execute_addr.is_some(),
+        };
         let aggregator = Aggregator::new(
             config.clone(),
             object_store,
-            eth_client_blobs_addr.is_some(),
+            custom_operators,
             self.l1_batch_commit_data_generator_mode,
         );
 
@@ -122,6 +144,8 @@ impl WiringLayer for EthTxAggregatorLayer {
             self.contracts_config.diamond_proxy_addr,
             self.zksync_network_id,
             eth_client_blobs_addr,
+            eth_client_prove_addr,
+            eth_client_execute_addr,
             self.settlement_mode,
         )
         .await;
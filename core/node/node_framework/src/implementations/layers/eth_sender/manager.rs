@@ -1,17 +1,20 @@
 use anyhow::Context;
 use zksync_circuit_breaker::l1_txs::FailedL1TransactionChecker;
 use zksync_config::configs::eth_sender::EthConfig;
-use zksync_eth_sender::EthTxManager;
+use zksync_eth_sender::{EthSenderError, EthTxManager};
 
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
-        eth_interface::{BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource},
+        eth_interface::{
+            BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+            BoundEthInterfaceForProveResource, BoundEthInterfaceResource,
+        },
         gas_adjuster::GasAdjusterResource,
         pools::{MasterPool, PoolResource, ReplicaPool},
     },
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{RestartPolicy, RestartableTask, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -27,15 +30,18 @@ use crate::{
 /// - `PoolResource<ReplicaPool>`
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
+/// - `BoundEthInterfaceForProveResource` (optional)
+/// - `BoundEthInterfaceForExecuteResource` (optional)
 /// - `TxParamsResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
 /// ## Adds tasks
 ///
-/// - `EthTxManager`
+/// - `EthTxManager` (restarted in place on retriable L1 client errors)
 #[derive(Debug)]
 pub struct EthTxManagerLayer {
     eth_sender_config: EthConfig,
+    restart_policy: RestartPolicy,
 }
 
 #[derive(Debug, FromContext)]
@@ -45,6 +51,8 @@ pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
     pub eth_client: BoundEthInterfaceResource,
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    pub eth_client_prove: Option<BoundEthInterfaceForProveResource>,
+    pub eth_client_execute: Option<BoundEthInterfaceForExecuteResource>,
     pub gas_adjuster: GasAdjusterResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -54,12 +62,21 @@ pub struct Input {
 #[context(crate = crate)]
 pub struct Output {
     #[context(task)]
-    pub eth_tx_manager: EthTxManager,
+    pub eth_tx_manager: RestartableTask,
 }
 
 impl EthTxManagerLayer {
     pub fn new(eth_sender_config: EthConfig) -> Self {
-        Self { eth_sender_config }
+        Self {
+            eth_sender_config,
+            restart_policy: RestartPolicy::default(),
+        }
+    }
+
+    /// Sets the policy used to restart [`EthTxManager`] after it fails with a retriable L1 client error.
+    pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.restart_policy = restart_policy;
+        self
     }
 }
 
@@ -80,31 +97,35 @@ impl WiringLayer for EthTxManagerLayer {
         let settlement_mode = self.eth_sender_config.gas_adjuster.unwrap().settlement_mode;
         let eth_client = input.eth_client.0.clone();
         let eth_client_blobs = input.eth_client_blobs.map(|c| c.0);
+        let eth_client_prove = input.eth_client_prove.map(|c| c.0);
+        let eth_client_execute = input.eth_client_execute.map(|c| c.0);
         let l2_client = input.eth_client.0;
 
         let config = self.eth_sender_config.sender.context("sender")?;
 
         let gas_adjuster = input.gas_adjuster.0;
 
-        let eth_tx_manager = EthTxManager::new(
-            master_pool,
-            config,
-            gas_adjuster,
-            if !settlement_mode.is_gateway() {
-                Some(eth_client)
-            } else {
-                None
-            },
-            if !settlement_mode.is_gateway() {
-                eth_client_blobs
-            } else {
-                None
-            },
-            if settlement_mode.is_gateway() {
-                Some(l2_client)
-            } else {
-                None
+        let eth_client = (!settlement_mode.is_gateway()).then_some(eth_client);
+        let eth_client_blobs = eth_client_blobs.filter(|_| !settlement_mode.is_gateway());
+        let eth_client_prove = eth_client_prove.filter(|_| !settlement_mode.is_gateway());
+        let eth_client_execute = eth_client_execute.filter(|_| !settlement_mode.is_gateway());
+        let l2_client = settlement_mode.is_gateway().then_some(l2_client);
+
+        let eth_tx_manager = RestartableTask::new(
+            move || {
+                EthTxManager::new(
+                    master_pool.clone(),
+                    config.clone(),
+                    gas_adjuster.clone(),
+                    eth_client.clone(),
+                    eth_client_blobs.clone(),
+                    eth_client_prove.clone(),
+                    eth_client_execute.clone(),
+                    l2_client.clone(),
+                )
             },
+            is_retriable,
+            self.restart_policy,
         );
 
         // Insert circuit breaker.
@@ -118,6 +139,12 @@ impl WiringLayer for EthTxManagerLayer {
     }
 }
 
+fn is_retriable(err: &anyhow::Error) -> bool {
+    err.chain()
+        .filter_map(|err| err.downcast_ref::<EthSenderError>())
+        .any(EthSenderError::is_retriable)
+}
+
 #[async_trait::async_trait]
 impl Task for EthTxManager {
     fn id(&self) -> TaskId {
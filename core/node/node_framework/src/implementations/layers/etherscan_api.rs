@@ -0,0 +1,87 @@
+use zksync_config::configs::api::EtherscanApiConfig;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_types::L2ChainId;
+
+use crate::{
+    implementations::resources::pools::{PoolResource, ReplicaPool},
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the Etherscan-compatible API.
+///
+/// Responsible for initialization of the Etherscan-compatible API server.
+#[derive(Debug)]
+pub struct EtherscanApiLayer {
+    config: EtherscanApiConfig,
+    l2_chain_id: L2ChainId,
+}
+
+impl EtherscanApiLayer {
+    pub fn new(config: EtherscanApiConfig, l2_chain_id: L2ChainId) -> Self {
+        Self {
+            config,
+            l2_chain_id,
+        }
+    }
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub replica_pool: PoolResource<ReplicaPool>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub etherscan_api_task: EtherscanApiTask,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for EtherscanApiLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "etherscan_api_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let replica_pool = input.replica_pool.get().await?;
+        let etherscan_api_task = EtherscanApiTask {
+            replica_pool,
+            config: self.config,
+            l2_chain_id: self.l2_chain_id,
+        };
+        Ok(Output { etherscan_api_task })
+    }
+}
+
+#[derive(Debug)]
+pub struct EtherscanApiTask {
+    replica_pool: ConnectionPool<Core>,
+    config: EtherscanApiConfig,
+    l2_chain_id: L2ChainId,
+}
+
+#[async_trait::async_trait]
+impl Task for EtherscanApiTask {
+    fn id(&self) -> TaskId {
+        "etherscan_api".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        zksync_etherscan_api::start_server(
+            self.replica_pool,
+            self.l2_chain_id,
+            self.config.max_page_size,
+            self.config.bind_addr(),
+            stop_receiver.0,
+        )
+        .await
+    }
+}
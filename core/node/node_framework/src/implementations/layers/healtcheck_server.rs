@@ -16,9 +16,28 @@ use crate::{
 ///
 /// Expects other layers to insert different components' health checks
 /// into [`AppHealthCheck`] aggregating heath using [`AppHealthCheckResource`].
-/// The added task spawns a health check server that only exposes the state provided by other tasks.
+/// The added task spawns a health check server that only exposes the state provided by other tasks
+/// and, optionally, the effective node configuration.
 #[derive(Debug)]
-pub struct HealthCheckLayer(pub HealthCheckConfig);
+pub struct HealthCheckLayer {
+    config: HealthCheckConfig,
+    effective_config: Option<serde_json::Value>,
+}
+
+impl HealthCheckLayer {
+    pub fn new(config: HealthCheckConfig) -> Self {
+        Self {
+            config,
+            effective_config: None,
+        }
+    }
+
+    /// Exposes the provided config on the `/config` endpoint of the server. The config must not contain secrets.
+    pub fn with_effective_config(mut self, config: serde_json::Value) -> Self {
+        self.effective_config = Some(config);
+        self
+    }
+}
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
@@ -45,11 +64,13 @@ impl WiringLayer for HealthCheckLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let AppHealthCheckResource(app_health_check) = input.app_health_check;
-        app_health_check.override_limits(self.0.slow_time_limit(), self.0.hard_time_limit());
+        app_health_check
+            .override_limits(self.config.slow_time_limit(), self.config.hard_time_limit());
 
         let health_check_task = HealthCheckTask {
-            config: self.0,
+            config: self.config,
             app_health_check,
+            effective_config: self.effective_config,
         };
 
         Ok(Output { health_check_task })
@@ -60,6 +81,7 @@ impl WiringLayer for HealthCheckLayer {
 pub struct HealthCheckTask {
     config: HealthCheckConfig,
     app_health_check: Arc<AppHealthCheck>,
+    effective_config: Option<serde_json::Value>,
 }
 
 #[async_trait::async_trait]
@@ -73,8 +95,11 @@ impl Task for HealthCheckTask {
     }
 
     async fn run(mut self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
-        let handle =
-            HealthCheckHandle::spawn_server(self.config.bind_addr(), self.app_health_check.clone());
+        let handle = HealthCheckHandle::spawn_server(
+            self.config.bind_addr(),
+            self.app_health_check.clone(),
+            self.effective_config.take(),
+        );
         stop_receiver.0.changed().await?;
         handle.stop().await;
 
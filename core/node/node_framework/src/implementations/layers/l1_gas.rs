@@ -1,7 +1,11 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use anyhow::Context as _;
 use zksync_config::configs::chain::{FeeModelVersion, StateKeeperConfig};
-use zksync_node_fee_model::{ApiFeeInputProvider, MainNodeFeeInputProvider};
+use zksync_node_fee_model::{
+    l2_base_fee::{L2BaseFeeAdjuster, L2BaseFeeAdjusterConfig},
+    ApiFeeInputProvider, MainNodeFeeInputProvider,
+};
 use zksync_types::fee_model::{FeeModelConfig, FeeModelConfigV1, FeeModelConfigV2};
 
 use crate::{
@@ -12,6 +16,8 @@ use crate::{
         l1_tx_params::TxParamsResource,
         pools::{PoolResource, ReplicaPool},
     },
+    service::StopReceiver,
+    task::{Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -21,6 +27,7 @@ use crate::{
 #[derive(Debug)]
 pub struct L1GasLayer {
     fee_model_config: FeeModelConfig,
+    l2_base_fee_config: Option<L2BaseFeeAdjusterConfig>,
 }
 
 #[derive(Debug, FromContext)]
@@ -39,12 +46,28 @@ pub struct Output {
     pub sequencer_fee_input: SequencerFeeInputResource,
     pub api_fee_input: ApiFeeInputResource,
     pub l1_tx_params: TxParamsResource,
+    #[context(task)]
+    pub l2_base_fee_adjuster_task: Option<L2BaseFeeAdjusterTask>,
 }
 
 impl L1GasLayer {
+    /// Interval between polls for new sealed L1 batches by the L2 base fee adjuster.
+    const L2_BASE_FEE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
     pub fn new(state_keeper_config: &StateKeeperConfig) -> Self {
+        let l2_base_fee_config =
+            state_keeper_config
+                .l2_base_fee_target_utilization
+                .map(|target_utilization| L2BaseFeeAdjusterConfig {
+                    target_utilization,
+                    max_change_rate: state_keeper_config.l2_base_fee_max_change_rate(),
+                    max_multiplier: state_keeper_config.l2_base_fee_max_multiplier(),
+                    max_gas_per_batch: state_keeper_config.max_gas_per_batch,
+                    poll_interval: Self::L2_BASE_FEE_POLL_INTERVAL,
+                });
         Self {
             fee_model_config: Self::map_config(state_keeper_config),
+            l2_base_fee_config,
         }
     }
 
@@ -76,14 +99,25 @@ impl WiringLayer for L1GasLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let ratio_provider = input.base_token_ratio_provider;
+        let replica_pool = input.replica_pool.get().await?;
 
-        let main_fee_input_provider = Arc::new(MainNodeFeeInputProvider::new(
+        let mut main_fee_input_provider = MainNodeFeeInputProvider::new(
             input.gas_adjuster.0.clone(),
             ratio_provider.0,
             self.fee_model_config,
-        ));
+        );
+        let l2_base_fee_adjuster_task = if let Some(config) = self.l2_base_fee_config {
+            let adjuster = L2BaseFeeAdjuster::new(config, replica_pool.clone())
+                .context("L2BaseFeeAdjuster::new()")?;
+            let adjuster = Arc::new(adjuster);
+            main_fee_input_provider =
+                main_fee_input_provider.with_l2_base_fee_adjuster(adjuster.clone());
+            Some(L2BaseFeeAdjusterTask { adjuster })
+        } else {
+            None
+        };
+        let main_fee_input_provider = Arc::new(main_fee_input_provider);
 
-        let replica_pool = input.replica_pool.get().await?;
         let api_fee_input_provider = Arc::new(ApiFeeInputProvider::new(
             main_fee_input_provider.clone(),
             replica_pool,
@@ -93,6 +127,23 @@ impl WiringLayer for L1GasLayer {
             sequencer_fee_input: main_fee_input_provider.into(),
             api_fee_input: api_fee_input_provider.into(),
             l1_tx_params: input.gas_adjuster.0.into(),
+            l2_base_fee_adjuster_task,
         })
     }
 }
+
+#[derive(Debug)]
+pub struct L2BaseFeeAdjusterTask {
+    adjuster: Arc<L2BaseFeeAdjuster>,
+}
+
+#[async_trait::async_trait]
+impl Task for L2BaseFeeAdjusterTask {
+    fn id(&self) -> TaskId {
+        "l2_base_fee_adjuster".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.adjuster.run(stop_receiver.0).await
+    }
+}
@@ -7,8 +7,9 @@ use std::{
 use anyhow::Context as _;
 use zksync_config::configs::{api::MerkleTreeApiConfig, database::MerkleTreeMode};
 use zksync_metadata_calculator::{
-    LazyAsyncTreeReader, MerkleTreePruningTask, MerkleTreeReaderConfig, MetadataCalculator,
-    MetadataCalculatorConfig, StaleKeysRepairTask, TreeReaderTask,
+    LazyAsyncTreeReader, MerkleTreeMaintenanceConfig, MerkleTreePruningTask,
+    MerkleTreeReaderConfig, MetadataCalculator, MetadataCalculatorConfig, StaleKeysRepairTask,
+    TreeReaderTask,
 };
 use zksync_storage::RocksDB;
 
@@ -31,6 +32,7 @@ pub struct MetadataCalculatorLayer {
     config: MetadataCalculatorConfig,
     tree_api_config: Option<MerkleTreeApiConfig>,
     pruning_config: Option<Duration>,
+    maintenance_config: MerkleTreeMaintenanceConfig,
     stale_keys_repair_enabled: bool,
 }
 
@@ -69,6 +71,7 @@ impl MetadataCalculatorLayer {
             config,
             tree_api_config: None,
             pruning_config: None,
+            maintenance_config: MerkleTreeMaintenanceConfig::default(),
             stale_keys_repair_enabled: false,
         }
     }
@@ -83,6 +86,16 @@ impl MetadataCalculatorLayer {
         self
     }
 
+    /// Enables retention-based tree pruning and / or scheduled RocksDB compaction. If pruning isn't configured
+    /// explicitly, the pruning task will use the default poll interval.
+    pub fn with_maintenance_config(
+        mut self,
+        maintenance_config: MerkleTreeMaintenanceConfig,
+    ) -> Self {
+        self.maintenance_config = maintenance_config;
+        self
+    }
+
     pub fn with_stale_keys_repair(mut self) -> Self {
         self.stale_keys_repair_enabled = true;
         self
@@ -138,11 +151,20 @@ impl WiringLayer for MetadataCalculatorLayer {
             }
         });
 
-        let pruning_task = self
-            .pruning_config
+        const DEFAULT_MAINTENANCE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+        let pruning_poll_interval = self.pruning_config.or_else(|| {
+            self.maintenance_config
+                .is_enabled()
+                .then_some(DEFAULT_MAINTENANCE_POLL_INTERVAL)
+        });
+        let maintenance_config = self.maintenance_config;
+        let pruning_task = pruning_poll_interval
             .map(
                 |pruning_removal_delay| -> Result<MerkleTreePruningTask, WiringError> {
-                    let pruning_task = metadata_calculator.pruning_task(pruning_removal_delay);
+                    let pruning_task = metadata_calculator
+                        .pruning_task(pruning_removal_delay)
+                        .with_maintenance(maintenance_config);
                     app_health
                         .insert_component(pruning_task.health_check())
                         .map_err(|err| WiringError::Internal(err.into()))?;
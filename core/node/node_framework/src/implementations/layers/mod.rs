@@ -10,6 +10,7 @@ pub mod da_clients;
 pub mod da_dispatcher;
 pub mod eth_sender;
 pub mod eth_watch;
+pub mod etherscan_api;
 pub mod external_proof_integration_api;
 pub mod gas_adjuster;
 pub mod healtcheck_server;
@@ -33,6 +34,7 @@ pub mod reorg_detector;
 pub mod sigint;
 pub mod state_keeper;
 pub mod sync_state_updater;
+pub mod transaction_decoder;
 pub mod tree_data_fetcher;
 pub mod validate_chain_ids;
 pub mod vm_runner;
@@ -1,14 +1,17 @@
+use std::collections::HashSet;
+
 use anyhow::Context as _;
 use zksync_config::{
     configs::{wallets, ContractsConfig},
     EthConfig,
 };
-use zksync_eth_client::clients::PKSigningClient;
+use zksync_eth_client::{clients::PKSigningClient, BoundEthInterface};
 use zksync_types::SLChainId;
 
 use crate::{
     implementations::resources::eth_interface::{
-        BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource, EthInterfaceResource,
+        BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+        BoundEthInterfaceForProveResource, BoundEthInterfaceResource, EthInterfaceResource,
     },
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
@@ -35,6 +38,10 @@ pub struct Output {
     pub signing_client: BoundEthInterfaceResource,
     /// Only provided if the blob operator key is provided to the layer.
     pub signing_client_for_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    /// Only provided if the prove operator key is provided to the layer.
+    pub signing_client_for_prove: Option<BoundEthInterfaceForProveResource>,
+    /// Only provided if the execute operator key is provided to the layer.
+    pub signing_client_for_execute: Option<BoundEthInterfaceForExecuteResource>,
 }
 
 impl PKSigningEthClientLayer {
@@ -63,6 +70,23 @@ impl WiringLayer for PKSigningEthClientLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        // Operators have separate nonces, so sharing an account between them would lead to nonce conflicts.
+        let operators = [
+            Some(&self.wallets.operator),
+            self.wallets.blob_operator.as_ref(),
+            self.wallets.prove_operator.as_ref(),
+            self.wallets.execute_operator.as_ref(),
+        ];
+        let mut operator_addresses = HashSet::new();
+        for wallet in operators.into_iter().flatten() {
+            if !operator_addresses.insert(wallet.address()) {
+                return Err(WiringError::Configuration(format!(
+                    "Operator account {:?} is used for several operator roles",
+                    wallet.address()
+                )));
+            }
+        }
+
         let private_key = self.wallets.operator.private_key();
         let gas_adjuster_config = self
             .eth_sender_config
@@ -80,21 +104,33 @@ impl WiringLayer for PKSigningEthClientLayer {
         );
         let signing_client = BoundEthInterfaceResource(Box::new(signing_client));
 
-        let signing_client_for_blobs = self.wallets.blob_operator.map(|blob_operator| {
-            let private_key = blob_operator.private_key();
-            let signing_client_for_blobs = PKSigningClient::new_raw(
-                private_key.clone(),
+        let create_signing_client = |wallet: wallets::Wallet| -> Box<dyn BoundEthInterface> {
+            Box::new(PKSigningClient::new_raw(
+                wallet.private_key().clone(),
                 self.contracts_config.diamond_proxy_addr,
                 gas_adjuster_config.default_priority_fee_per_gas,
                 self.sl_chain_id,
-                query_client,
-            );
-            BoundEthInterfaceForBlobsResource(Box::new(signing_client_for_blobs))
-        });
+                query_client.clone(),
+            ))
+        };
+        let signing_client_for_blobs = self
+            .wallets
+            .blob_operator
+            .map(|wallet| BoundEthInterfaceForBlobsResource(create_signing_client(wallet)));
+        let signing_client_for_prove = self
+            .wallets
+            .prove_operator
+            .map(|wallet| BoundEthInterfaceForProveResource(create_signing_client(wallet)));
+        let signing_client_for_execute = self
+            .wallets
+            .execute_operator
+            .map(|wallet| BoundEthInterfaceForExecuteResource(create_signing_client(wallet)));
 
         Ok(Output {
             signing_client,
             signing_client_for_blobs,
+            signing_client_for_prove,
+            signing_client_for_execute,
         })
     }
 }
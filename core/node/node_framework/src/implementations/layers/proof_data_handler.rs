@@ -3,10 +3,12 @@ use std::sync::Arc;
 use zksync_config::configs::ProofDataHandlerConfig;
 use zksync_dal::{ConnectionPool, Core};
 use zksync_object_store::ObjectStore;
-use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
+use zksync_proof_data_handler::{L1ProofVerifier, ProofVerifier};
+use zksync_types::{commitment::L1BatchCommitmentMode, Address, L2ChainId};
 
 use crate::{
     implementations::resources::{
+        eth_interface::EthInterfaceResource,
         object_store::ObjectStoreResource,
         pools::{MasterPool, PoolResource},
     },
@@ -22,6 +24,7 @@ pub struct ProofDataHandlerLayer {
     proof_data_handler_config: ProofDataHandlerConfig,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    proof_verifier_diamond_proxy_addr: Option<Address>,
 }
 
 #[derive(Debug, FromContext)]
@@ -29,6 +32,8 @@ pub struct ProofDataHandlerLayer {
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub object_store: ObjectStoreResource,
+    /// Required if proofs are verified, i.e. if [`ProofDataHandlerLayer::with_proof_verifier()`] is called.
+    pub eth_client: Option<EthInterfaceResource>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -48,8 +53,16 @@ impl ProofDataHandlerLayer {
             proof_data_handler_config,
             commitment_mode,
             l2_chain_id,
+            proof_verifier_diamond_proxy_addr: None,
         }
     }
+
+    /// Enables verification of proofs submitted by third-party provers using the verifier contract on L1.
+    /// Required for the proving marketplace API.
+    pub fn with_proof_verifier(mut self, diamond_proxy_addr: Address) -> Self {
+        self.proof_verifier_diamond_proxy_addr = Some(diamond_proxy_addr);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -64,6 +77,18 @@ impl WiringLayer for ProofDataHandlerLayer {
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let main_pool = input.master_pool.get().await?;
         let blob_store = input.object_store.0;
+        let proof_verifier = match (self.proof_verifier_diamond_proxy_addr, input.eth_client) {
+            (Some(diamond_proxy_addr), Some(EthInterfaceResource(client))) => {
+                let verifier = L1ProofVerifier::new(client, diamond_proxy_addr);
+                Some(Arc::new(verifier) as Arc<dyn ProofVerifier>)
+            }
+            (Some(_), None) => {
+                return Err(WiringError::Configuration(
+                    "Proof verifier requires an L1 client".to_owned(),
+                ));
+            }
+            (None, _) => None,
+        };
 
         let task = ProofDataHandlerTask {
             proof_data_handler_config: self.proof_data_handler_config,
@@ -71,6 +96,7 @@ impl WiringLayer for ProofDataHandlerLayer {
             main_pool,
             commitment_mode: self.commitment_mode,
             l2_chain_id: self.l2_chain_id,
+            proof_verifier,
         };
 
         Ok(Output { task })
@@ -84,6 +110,7 @@ pub struct ProofDataHandlerTask {
     main_pool: ConnectionPool<Core>,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    proof_verifier: Option<Arc<dyn ProofVerifier>>,
 }
 
 #[async_trait::async_trait]
@@ -99,6 +126,7 @@ impl Task for ProofDataHandlerTask {
             self.main_pool,
             self.commitment_mode,
             self.l2_chain_id,
+            self.proof_verifier,
             stop_receiver.0,
         )
         .await
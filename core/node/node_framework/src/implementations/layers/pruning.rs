@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use zksync_node_db_pruner::{DbPruner, DbPrunerConfig};
+use zksync_types::Address;
 
 use crate::{
     implementations::resources::{
@@ -19,6 +20,7 @@ pub struct PruningLayer {
     pruning_removal_delay: Duration,
     pruning_chunk_size: u32,
     minimum_l1_batch_age: Duration,
+    retained_addresses: Vec<Address>,
 }
 
 #[derive(Debug, FromContext)]
@@ -46,8 +48,15 @@ impl PruningLayer {
             pruning_removal_delay,
             pruning_chunk_size,
             minimum_l1_batch_age,
+            retained_addresses: Vec::new(),
         }
     }
+
+    /// Sets addresses for which full history is retained during pruning.
+    pub fn with_retained_addresses(mut self, retained_addresses: Vec<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -67,6 +76,7 @@ impl WiringLayer for PruningLayer {
                 removal_delay: self.pruning_removal_delay,
                 pruned_batch_chunk_size: self.pruning_chunk_size,
                 minimum_l1_batch_age: self.minimum_l1_batch_age,
+                retained_addresses: self.retained_addresses,
             },
             main_pool,
         );
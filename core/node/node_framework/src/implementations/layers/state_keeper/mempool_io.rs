@@ -1,16 +1,22 @@
+use std::sync::Arc;
+
 use anyhow::Context as _;
 use zksync_config::configs::{
-    chain::{MempoolConfig, StateKeeperConfig},
-    wallets,
+    chain::{MempoolConfig, MempoolOrderingPolicy, StateKeeperConfig},
+    wallets, ExternalBlockBuilderConfig,
+};
+use zksync_state_keeper::{
+    block_builder::{BlockBuilderApi, BlockProposals},
+    ordering::{FifoOrdering, PriorityFeeOrdering, TimestampOrdering, TxOrderingPolicy},
+    AdmissionDryRun, MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer,
 };
-use zksync_state_keeper::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
 use zksync_types::{commitment::L1BatchCommitmentMode, Address, L2ChainId};
 
 use crate::{
     implementations::resources::{
         fee_input::SequencerFeeInputResource,
         pools::{MasterPool, PoolResource},
-        state_keeper::{ConditionalSealerResource, StateKeeperIOResource},
+        state_keeper::{ConditionalSealerResource, MempoolResource, StateKeeperIOResource},
     },
     service::StopReceiver,
     task::{Task, TaskId},
@@ -29,10 +35,18 @@ use crate::{
 ///
 /// - `StateKeeperIOResource`
 /// - `ConditionalSealerResource`
+/// - `MempoolResource`
 ///
 /// ## Adds tasks
 ///
 /// - `MempoolFetcherTask`
+/// - `BlockBuilderApi` (if the external block builder is configured)
+///
+/// If the admission dry-run is enabled in the mempool config, the fetcher task pre-executes loaded transactions
+/// and provides ordering hints for `MempoolIO`.
+///
+/// The mempool uses the transaction ordering policy specified in the mempool config. The `custom` policy must be provided
+/// with [`Self::with_custom_ordering_policy()`].
 #[derive(Debug)]
 pub struct MempoolIOLayer {
     zksync_network_id: L2ChainId,
@@ -41,6 +55,8 @@ pub struct MempoolIOLayer {
     wallets: wallets::StateKeeper,
     l2_da_validator_addr: Option<Address>,
     l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    external_block_builder: Option<ExternalBlockBuilderConfig>,
+    custom_ordering_policy: Option<Arc<dyn TxOrderingPolicy>>,
 }
 
 #[derive(Debug, FromContext)]
@@ -55,8 +71,11 @@ pub struct Input {
 pub struct Output {
     pub state_keeper_io: StateKeeperIOResource,
     pub conditional_sealer: ConditionalSealerResource,
+    pub mempool: MempoolResource,
     #[context(task)]
     pub mempool_fetcher: MempoolFetcher,
+    #[context(task)]
+    pub block_builder_api: Option<BlockBuilderApi>,
 }
 
 impl MempoolIOLayer {
@@ -75,9 +94,34 @@ impl MempoolIOLayer {
             wallets,
             l2_da_validator_addr,
             l1_batch_commit_data_generator_mode,
+            external_block_builder: None,
+            custom_ordering_policy: None,
         }
     }
 
+    /// Enables the experimental API allowing an external block builder to propose transaction ordering.
+    pub fn with_external_block_builder(mut self, config: ExternalBlockBuilderConfig) -> Self {
+        self.external_block_builder = Some(config);
+        self
+    }
+
+    /// Sets the operator-defined transaction ordering policy used if the mempool config specifies the `custom` policy.
+    pub fn with_custom_ordering_policy(mut self, policy: Arc<dyn TxOrderingPolicy>) -> Self {
+        self.custom_ordering_policy = Some(policy);
+        self
+    }
+
+    fn ordering_policy(&self) -> anyhow::Result<Arc<dyn TxOrderingPolicy>> {
+        Ok(match self.mempool_config.ordering_policy {
+            MempoolOrderingPolicy::Timestamp => Arc::new(TimestampOrdering),
+            MempoolOrderingPolicy::Fifo => Arc::new(FifoOrdering),
+            MempoolOrderingPolicy::PriorityFee => Arc::new(PriorityFeeOrdering),
+            MempoolOrderingPolicy::Custom => self.custom_ordering_policy.clone().context(
+                "custom transaction ordering policy is configured, but is not provided to the node",
+            )?,
+        })
+    }
+
     async fn build_mempool_guard(
         &self,
         master_pool: &PoolResource<MasterPool>,
@@ -90,7 +134,14 @@ impl MempoolIOLayer {
             .connection()
             .await
             .context("Access storage to build mempool")?;
-        let mempool = MempoolGuard::from_storage(&mut storage, self.mempool_config.capacity).await;
+        let ordering_policy = self.ordering_policy()?;
+        tracing::info!(
+            "Using `{}` transaction ordering policy in mempool",
+            ordering_policy.name()
+        );
+        let mempool =
+            MempoolGuard::from_storage(&mut storage, self.mempool_config.capacity, ordering_policy)
+                .await;
         mempool.register_metrics();
         Ok(mempool)
     }
@@ -115,20 +166,41 @@ impl WiringLayer for MempoolIOLayer {
             .get_singleton()
             .await
             .context("Get master pool")?;
-        let mempool_fetcher = MempoolFetcher::new(
+        let mut mempool_fetcher = MempoolFetcher::new(
             mempool_guard.clone(),
             batch_fee_input_provider.clone(),
             &self.mempool_config,
             mempool_fetcher_pool,
         );
 
+        // Create admission dry-run if it's enabled.
+        let admission_dry_run = match self.mempool_config.admission_dry_run_concurrency {
+            Some(concurrency) => {
+                let dry_run_pool = master_pool
+                    .get_custom(concurrency.try_into().context("concurrency")?)
+                    .await
+                    .context("Get master pool")?;
+                let dry_run = AdmissionDryRun::new(
+                    dry_run_pool,
+                    self.zksync_network_id,
+                    self.wallets.fee_account.address(),
+                    self.state_keeper_config.validation_computational_gas_limit,
+                    concurrency,
+                )
+                .await?
+                .with_halted_txs_rejection(self.mempool_config.admission_reject_halted_txs);
+                Some(dry_run)
+            }
+            None => None,
+        };
+
         // Create mempool IO resource.
         let mempool_db_pool = master_pool
             .get_singleton()
             .await
             .context("Get master pool")?;
         let io = MempoolIO::new(
-            mempool_guard,
+            mempool_guard.clone(),
             batch_fee_input_provider,
             mempool_db_pool,
             &self.state_keeper_config,
@@ -138,6 +210,23 @@ impl WiringLayer for MempoolIOLayer {
             self.l2_da_validator_addr,
             self.l1_batch_commit_data_generator_mode,
         )?;
+        let io = match admission_dry_run {
+            Some(dry_run) => {
+                let io = io.with_admission_hints(dry_run.hints());
+                mempool_fetcher = mempool_fetcher.with_admission_dry_run(dry_run);
+                io
+            }
+            None => io,
+        };
+
+        let (io, block_builder_api) = match &self.external_block_builder {
+            Some(config) => {
+                let proposals = BlockProposals::new(config.max_transactions_per_proposal);
+                let io = io.with_block_proposals(proposals.clone(), config.proposal_timeout());
+                (io, Some(BlockBuilderApi::new(proposals, config)))
+            }
+            None => (io, None),
+        };
 
         // Create sealer.
         let sealer = SequencerSealer::new(self.state_keeper_config);
@@ -145,7 +234,9 @@ impl WiringLayer for MempoolIOLayer {
         Ok(Output {
             state_keeper_io: io.into(),
             conditional_sealer: sealer.into(),
+            mempool: mempool_guard.into(),
             mempool_fetcher,
+            block_builder_api,
         })
     }
 }
@@ -160,3 +251,14 @@ impl Task for MempoolFetcher {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for BlockBuilderApi {
+    fn id(&self) -> TaskId {
+        "state_keeper/block_builder_api".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,58 @@
+use zksync_transaction_decoder::TransactionDecoder;
+
+use crate::{
+    implementations::resources::pools::{MasterPool, PoolResource},
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the transaction decoder.
+///
+/// Responsible for initializing and running [`TransactionDecoder`] task, that decodes transactions using ABIs
+/// of verified contracts for the `zks_getDecodedTransaction` API method.
+#[derive(Debug)]
+pub struct TransactionDecoderLayer;
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub master_pool: PoolResource<MasterPool>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub transaction_decoder: TransactionDecoder,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for TransactionDecoderLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "transaction_decoder_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let pool = input.master_pool.get_singleton().await?;
+        let transaction_decoder = TransactionDecoder::new(pool);
+        Ok(Output {
+            transaction_decoder,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for TransactionDecoder {
+    fn id(&self) -> TaskId {
+        "transaction_decoder".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,98 @@
+use zksync_config::configs::vm_runner::BatchVerifierConfig;
+use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
+use zksync_vm_runner::{
+    impls::{BatchVerifier, BatchVerifierIo},
+    ConcurrentOutputHandlerFactoryTask, StorageSyncTask,
+};
+
+use crate::{
+    implementations::resources::pools::{MasterPool, PoolResource},
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the batch verifier.
+#[derive(Debug)]
+pub struct BatchVerifierLayer {
+    config: BatchVerifierConfig,
+    zksync_network_id: L2ChainId,
+    commitment_mode: L1BatchCommitmentMode,
+}
+
+impl BatchVerifierLayer {
+    pub fn new(
+        config: BatchVerifierConfig,
+        zksync_network_id: L2ChainId,
+        commitment_mode: L1BatchCommitmentMode,
+    ) -> Self {
+        Self {
+            config,
+            zksync_network_id,
+            commitment_mode,
+        }
+    }
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub master_pool: PoolResource<MasterPool>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub batch_verifier: BatchVerifier,
+    #[context(task)]
+    pub loader_task: StorageSyncTask<BatchVerifierIo>,
+    #[context(task)]
+    pub output_handler_factory_task: ConcurrentOutputHandlerFactoryTask<BatchVerifierIo>,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for BatchVerifierLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "vm_runner_batch_verifier"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        // One connection for `StorageSyncTask`, one for `ConcurrentOutputHandlerFactoryTask` / `VmRunner`,
+        // and `window_size` connections for output handlers recomputing commitments.
+        let pool = input
+            .master_pool
+            .get_custom(self.config.window_size + 2)
+            .await?;
+        let (batch_verifier, tasks) = BatchVerifier::new(
+            pool,
+            self.config.db_path,
+            self.zksync_network_id,
+            self.commitment_mode,
+            self.config.first_processed_batch,
+            self.config.window_size,
+        )
+        .await?;
+
+        Ok(Output {
+            batch_verifier,
+            loader_task: tasks.loader_task,
+            output_handler_factory_task: tasks.output_handler_factory_task,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for BatchVerifier {
+    fn id(&self) -> TaskId {
+        "vm_runner/batch_verifier".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(&stop_receiver.0).await
+    }
+}
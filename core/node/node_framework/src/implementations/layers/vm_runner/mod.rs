@@ -5,6 +5,7 @@ use crate::{
     task::{Task, TaskId},
 };
 
+pub mod batch_verifier;
 pub mod bwip;
 pub mod playground;
 pub mod protective_reads;
@@ -1,5 +1,6 @@
 pub mod caches;
 pub mod server;
+pub mod token_prices;
 pub mod tree_api_client;
 pub mod tx_sender;
 pub mod tx_sink;
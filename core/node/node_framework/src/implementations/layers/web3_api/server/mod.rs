@@ -18,8 +18,12 @@ use crate::{
             healthcheck::AppHealthCheckResource,
             main_node_client::MainNodeClientResource,
             pools::{PoolResource, ReplicaPool},
+            state_keeper::MempoolResource,
             sync_state::SyncStateResource,
-            web3_api::{MempoolCacheResource, TreeApiClientResource, TxSenderResource},
+            web3_api::{
+                MempoolCacheResource, TokenPriceCacheResource, TreeApiClientResource,
+                TxSenderResource,
+            },
         },
     },
     service::StopReceiver,
@@ -100,6 +104,7 @@ enum Transport {
 /// - `SyncStateResource` (optional)
 /// - `TreeApiClientResource` (optional)
 /// - `MempoolCacheResource`
+/// - `MempoolResource` (optional; required for the `txpool` namespace)
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 /// - `AppHealthCheckResource` (adds a health check)
 ///
@@ -123,6 +128,8 @@ pub struct Input {
     pub sync_state: Option<SyncStateResource>,
     pub tree_api_client: Option<TreeApiClientResource>,
     pub mempool_cache: MempoolCacheResource,
+    pub mempool: Option<MempoolResource>,
+    pub token_price_cache: Option<TokenPriceCacheResource>,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
@@ -192,6 +199,8 @@ impl WiringLayer for Web3ServerLayer {
         let MempoolCacheResource(mempool_cache) = input.mempool_cache;
         let sync_state = input.sync_state.map(|state| state.0);
         let tree_api_client = input.tree_api_client.map(|client| client.0);
+        let token_price_cache = input.token_price_cache.map(|cache| cache.0);
+        let mempool = input.mempool.map(|mempool| mempool.0);
 
         let sealed_l2_block_handle = SealedL2BlockNumber::default();
         let bridge_addresses_handle =
@@ -222,6 +231,12 @@ impl WiringLayer for Web3ServerLayer {
         if let Some(client) = tree_api_client {
             api_builder = api_builder.with_tree_api(client);
         }
+        if let Some(cache) = token_price_cache {
+            api_builder = api_builder.with_token_price_cache(cache);
+        }
+        if let Some(mempool) = mempool {
+            api_builder = api_builder.with_mempool(mempool);
+        }
         match self.transport {
             Transport::Http => {
                 api_builder = api_builder.http(self.port);
@@ -0,0 +1,74 @@
+use anyhow::Context as _;
+use zksync_config::configs::TokenPriceConfig;
+use zksync_external_price_api::token_price::TokenPriceCache;
+
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource, web3_api::TokenPriceCacheResource,
+    },
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer that provides the [`TokenPriceCache`] used by the `zks_getTokenPrice` API method.
+///
+/// ## Requests resources
+///
+/// - `EthInterfaceResource` (only if one of the configured providers reads prices from L1)
+///
+/// ## Adds resources
+///
+/// - `TokenPriceCacheResource`
+#[derive(Debug)]
+pub struct TokenPriceCacheLayer {
+    config: TokenPriceConfig,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub eth_client: Option<EthInterfaceResource>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub token_price_cache: TokenPriceCacheResource,
+}
+
+impl TokenPriceCacheLayer {
+    pub fn new(config: TokenPriceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for TokenPriceCacheLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "token_price_cache_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let requires_l1_client = self
+            .config
+            .providers
+            .iter()
+            .any(|provider| provider.requires_l1_client());
+        let l1_client = if requires_l1_client {
+            let client = input
+                .eth_client
+                .context("L1 client is required by token price providers")?;
+            Some(client.0)
+        } else {
+            None
+        };
+
+        let cache = TokenPriceCache::from_config(&self.config, l1_client)?;
+        Ok(Output {
+            token_price_cache: cache.into(),
+        })
+    }
+}
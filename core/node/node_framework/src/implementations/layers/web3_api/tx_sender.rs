@@ -2,8 +2,8 @@ use std::{sync::Arc, time::Duration};
 
 use tokio::sync::RwLock;
 use zksync_node_api_server::{
-    execution_sandbox::{VmConcurrencyBarrier, VmConcurrencyLimiter},
-    tx_sender::{SandboxExecutorOptions, TxSenderBuilder, TxSenderConfig},
+    execution_sandbox::{VmConcurrencyBarrier, VmConcurrencyLimiter, VmWorkloadLimits},
+    tx_sender::{ExecutionLimits, SandboxExecutorOptions, TxSenderBuilder, TxSenderConfig},
 };
 use zksync_state::{PostgresStorageCaches, PostgresStorageCachesTask};
 use zksync_types::{vm::FastVmMode, AccountTreeId, Address};
@@ -33,6 +33,7 @@ pub struct PostgresStorageCachesConfig {
     pub initial_writes_cache_size: u64,
     pub latest_values_cache_size: u64,
     pub latest_values_max_block_lag: u32,
+    pub block_state_cache_size: u64,
 }
 
 /// Wiring layer for the `TxSender`.
@@ -59,8 +60,10 @@ pub struct TxSenderLayer {
     tx_sender_config: TxSenderConfig,
     postgres_storage_caches_config: PostgresStorageCachesConfig,
     max_vm_concurrency: usize,
+    vm_workload_limits: VmWorkloadLimits,
     whitelisted_tokens_for_aa_cache: bool,
     vm_mode: FastVmMode,
+    execution_limits: ExecutionLimits,
 }
 
 #[derive(Debug, FromContext)]
@@ -95,8 +98,10 @@ impl TxSenderLayer {
             tx_sender_config,
             postgres_storage_caches_config,
             max_vm_concurrency,
+            vm_workload_limits: VmWorkloadLimits::default(),
             whitelisted_tokens_for_aa_cache: false,
             vm_mode: FastVmMode::Old,
+            execution_limits: ExecutionLimits::default(),
         }
     }
 
@@ -109,11 +114,25 @@ impl TxSenderLayer {
         self
     }
 
+    /// Sets dedicated VM concurrency limits for workload classes (calls, gas estimation, tracing).
+    /// By default, all workloads share a single pool limited by `max_vm_concurrency`.
+    pub fn with_vm_workload_limits(mut self, limits: VmWorkloadLimits) -> Self {
+        self.vm_workload_limits = limits;
+        self
+    }
+
     /// Sets the fast VM modes used for all supported operations.
     pub fn with_vm_mode(mut self, mode: FastVmMode) -> Self {
         self.vm_mode = mode;
         self
     }
+
+    /// Sets limits on VM cycles and wall-clock time for calls and gas estimation.
+    /// By default, execution is only bounded by the gas limit.
+    pub fn with_execution_limits(mut self, limits: ExecutionLimits) -> Self {
+        self.execution_limits = limits;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -140,6 +159,10 @@ impl WiringLayer for TxSenderLayer {
         let values_capacity = self.postgres_storage_caches_config.latest_values_cache_size;
         let mut storage_caches =
             PostgresStorageCaches::new(factory_deps_capacity, initial_writes_capacity);
+        let block_state_capacity = self.postgres_storage_caches_config.block_state_cache_size;
+        if block_state_capacity > 0 {
+            storage_caches.configure_block_state_cache(block_state_capacity);
+        }
 
         let postgres_storage_caches_task = if values_capacity > 0 {
             let update_task = storage_caches.configure_storage_values_cache(
@@ -155,7 +178,10 @@ impl WiringLayer for TxSenderLayer {
 
         // Initialize `VmConcurrencyLimiter`.
         let (vm_concurrency_limiter, vm_concurrency_barrier) =
-            VmConcurrencyLimiter::new(self.max_vm_concurrency);
+            VmConcurrencyLimiter::with_workload_limits(
+                self.max_vm_concurrency,
+                self.vm_workload_limits,
+            );
 
         // TODO (BFT-138): Allow to dynamically reload API contracts
         let config = self.tx_sender_config;
@@ -166,6 +192,7 @@ impl WiringLayer for TxSenderLayer {
         )
         .await?;
         executor_options.set_fast_vm_mode(self.vm_mode);
+        executor_options.set_execution_limits(self.execution_limits);
 
         // Build `TxSender`.
         let mut tx_sender = TxSenderBuilder::new(config, replica_pool, tx_sink);
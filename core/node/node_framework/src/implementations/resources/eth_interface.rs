@@ -47,6 +47,26 @@ impl Resource for BoundEthInterfaceForBlobsResource {
     }
 }
 
+/// Same as `BoundEthInterfaceResource`, but for sending prove transactions.
+#[derive(Debug, Clone)]
+pub struct BoundEthInterfaceForProveResource(pub Box<dyn BoundEthInterface>);
+
+impl Resource for BoundEthInterfaceForProveResource {
+    fn name() -> String {
+        "common/bound_eth_interface_for_prove".into()
+    }
+}
+
+/// Same as `BoundEthInterfaceResource`, but for sending execute transactions.
+#[derive(Debug, Clone)]
+pub struct BoundEthInterfaceForExecuteResource(pub Box<dyn BoundEthInterface>);
+
+impl Resource for BoundEthInterfaceForExecuteResource {
+    fn name() -> String {
+        "common/bound_eth_interface_for_execute".into()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoundEthInterfaceForL2Resource(pub Box<dyn BoundEthInterface>);
 
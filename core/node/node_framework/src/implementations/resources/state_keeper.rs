@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use zksync_state::OwnedStorage;
-use zksync_state_keeper::{seal_criteria::ConditionalSealer, OutputHandler, StateKeeperIO};
+use zksync_state_keeper::{
+    seal_criteria::ConditionalSealer, MempoolGuard, OutputHandler, StateKeeperIO,
+};
 use zksync_vm_executor::interface::BatchExecutorFactory;
 
 use crate::resource::{Resource, Unique};
@@ -78,3 +80,19 @@ where
         Self(Arc::new(sealer))
     }
 }
+
+/// A resource that provides the [`MempoolGuard`] used by the state keeper on the main node.
+#[derive(Debug, Clone)]
+pub struct MempoolResource(pub MempoolGuard);
+
+impl Resource for MempoolResource {
+    fn name() -> String {
+        "state_keeper/mempool".into()
+    }
+}
+
+impl From<MempoolGuard> for MempoolResource {
+    fn from(mempool: MempoolGuard) -> Self {
+        Self(mempool)
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use zksync_external_price_api::token_price::TokenPriceCache;
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_api_server::{
     tx_sender::{tx_sink::TxSink, TxSender},
@@ -71,3 +72,19 @@ impl From<MempoolCache> for MempoolCacheResource {
         Self(cache)
     }
 }
+
+/// A resource that provides [`TokenPriceCache`] to the service.
+#[derive(Debug, Clone)]
+pub struct TokenPriceCacheResource(pub Arc<TokenPriceCache>);
+
+impl Resource for TokenPriceCacheResource {
+    fn name() -> String {
+        "api/token_price_cache".into()
+    }
+}
+
+impl From<TokenPriceCache> for TokenPriceCacheResource {
+    fn from(cache: TokenPriceCache) -> Self {
+        Self(Arc::new(cache))
+    }
+}
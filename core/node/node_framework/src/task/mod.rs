@@ -7,9 +7,13 @@ use std::{
 
 use tokio::sync::Barrier;
 
-pub use self::types::{TaskId, TaskKind};
+pub use self::{
+    restart::{RestartPolicy, RestartableTask},
+    types::{TaskId, TaskKind},
+};
 use crate::service::StopReceiver;
 
+mod restart;
 mod types;
 
 /// A task implementation.
@@ -56,6 +60,12 @@ mod types;
 /// A task that can run without waiting for preconditions and can exit without stopping the service.
 /// Usually such tasks may be used for satisfying a precondition, for example, they can perform the database
 /// setup.
+///
+/// ## Restarting failed tasks
+///
+/// By default, a failed task causes the whole service to stop. Long-running tasks that may fail because of
+/// transient issues (e.g., L1 RPC errors) can instead be wrapped into a [`RestartableTask`], which re-creates
+/// and restarts the task in place according to a [`RestartPolicy`].
 #[async_trait::async_trait]
 pub trait Task: 'static + Send {
     /// Returns the kind of the task.
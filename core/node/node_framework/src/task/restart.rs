@@ -0,0 +1,272 @@
+//! Support for restarting failed tasks in place, without restarting the whole node.
+
+use std::{fmt, time::Duration};
+
+use tokio::time::Instant;
+
+use super::{Task, TaskId, TaskKind};
+use crate::service::StopReceiver;
+
+/// Policy that determines how a [`RestartableTask`] is restarted after it fails with a retriable error.
+///
+/// Delays between restarts grow exponentially, starting from `initial_backoff` and capped at `max_backoff`.
+/// If the task keeps running for longer than `max_backoff` after a restart, the backoff and the restart
+/// counter are reset.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    /// Delay before the first restart.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between restarts.
+    pub max_backoff: Duration,
+    /// Maximum number of consecutive restarts. Once exceeded, the task error is propagated to the service.
+    pub max_restarts: usize,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_restarts: 10,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Returns a policy that never restarts the task.
+    pub fn never() -> Self {
+        Self {
+            max_restarts: 0,
+            ..Self::default()
+        }
+    }
+
+    fn backoff(&self, restart_idx: usize) -> Duration {
+        let multiplier = 1_u32.checked_shl(restart_idx as u32).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(multiplier)
+            .min(self.max_backoff)
+    }
+}
+
+type TaskFactory = Box<dyn FnMut() -> Box<dyn Task> + Send>;
+
+/// A long-running task that is re-created and restarted when it exits with a retriable error.
+///
+/// Since [`Task::run`] consumes the task, the wrapper holds a factory that creates a fresh task instance
+/// for every run. Errors that are not classified as retriable, as well as errors occurring after
+/// [`RestartPolicy::max_restarts`] consecutive restarts, are propagated to the service as usual,
+/// causing the node to shut down.
+pub struct RestartableTask {
+    id: TaskId,
+    kind: TaskKind,
+    factory: TaskFactory,
+    is_retriable: fn(&anyhow::Error) -> bool,
+    policy: RestartPolicy,
+}
+
+impl fmt::Debug for RestartableTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RestartableTask")
+            .field("id", &self.id)
+            .field("kind", &self.kind)
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RestartableTask {
+    /// Creates a restartable task. `factory` is invoked to create a task instance for each run;
+    /// `is_retriable` decides whether a task error warrants a restart.
+    pub fn new<T: Task>(
+        mut factory: impl FnMut() -> T + Send + 'static,
+        is_retriable: fn(&anyhow::Error) -> bool,
+        policy: RestartPolicy,
+    ) -> Self {
+        // Create an instance to learn the task metadata; it will be used for the first run.
+        let first_task = factory();
+        let id = first_task.id();
+        let kind = first_task.kind();
+        let mut first_task = Some(first_task);
+        let factory = move || -> Box<dyn Task> {
+            match first_task.take() {
+                Some(task) => Box::new(task),
+                None => Box::new(factory()),
+            }
+        };
+
+        Self {
+            id,
+            kind,
+            factory: Box::new(factory),
+            is_retriable,
+            policy,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for RestartableTask {
+    fn kind(&self) -> TaskKind {
+        self.kind
+    }
+
+    fn id(&self) -> TaskId {
+        self.id.clone()
+    }
+
+    async fn run(mut self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        let mut restart_idx = 0;
+        loop {
+            let task = (self.factory)();
+            let started_at = Instant::now();
+            let err = match task.run(stop_receiver.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(err) => err,
+            };
+
+            let stop_requested = *stop_receiver.0.borrow();
+            if stop_requested || !(self.is_retriable)(&err) {
+                return Err(err);
+            }
+            if started_at.elapsed() > self.policy.max_backoff {
+                // The task has been running stably since the last restart.
+                restart_idx = 0;
+            }
+            if restart_idx >= self.policy.max_restarts {
+                tracing::error!(
+                    "Task {} failed after {restart_idx} consecutive restarts, giving up",
+                    self.id
+                );
+                return Err(err);
+            }
+
+            let backoff = self.policy.backoff(restart_idx);
+            restart_idx += 1;
+            tracing::warn!(
+                "Task {} failed with a retriable error, restarting in {backoff:?} \
+                 (restart {restart_idx}/{}): {err:#}",
+                self.id,
+                self.policy.max_restarts
+            );
+            if tokio::time::timeout(backoff, stop_receiver.0.changed())
+                .await
+                .is_ok()
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tokio::{runtime::Runtime, sync::watch};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct FailingTask {
+        runs: Arc<AtomicUsize>,
+        failures: usize,
+        retriable: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl Task for FailingTask {
+        fn id(&self) -> TaskId {
+            "failing_task".into()
+        }
+
+        async fn run(self: Box<Self>, _stop_receiver: StopReceiver) -> anyhow::Result<()> {
+            let run_idx = self.runs.fetch_add(1, Ordering::SeqCst);
+            if run_idx < self.failures {
+                anyhow::bail!(if self.retriable { "retriable" } else { "fatal" });
+            }
+            Ok(())
+        }
+    }
+
+    fn is_retriable(err: &anyhow::Error) -> bool {
+        err.to_string() == "retriable"
+    }
+
+    fn test_policy(max_restarts: usize) -> RestartPolicy {
+        RestartPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_secs(10),
+            max_restarts,
+        }
+    }
+
+    fn run_task(task: Box<dyn Task>) -> anyhow::Result<()> {
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        Runtime::new()
+            .unwrap()
+            .block_on(task.run(StopReceiver(stop_receiver)))
+    }
+
+    fn restartable_task(
+        runs: &Arc<AtomicUsize>,
+        failures: usize,
+        retriable: bool,
+        policy: RestartPolicy,
+    ) -> Box<dyn Task> {
+        let runs = runs.clone();
+        let factory = move || FailingTask {
+            runs: runs.clone(),
+            failures,
+            retriable,
+        };
+        Box::new(RestartableTask::new(factory, is_retriable, policy))
+    }
+
+    #[test]
+    fn backoff_is_exponential_and_capped() {
+        let policy = RestartPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            max_restarts: 100,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(8));
+        assert_eq!(policy.backoff(4), Duration::from_secs(10));
+        assert_eq!(policy.backoff(64), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn task_is_restarted_on_retriable_error() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let task = restartable_task(&runs, 3, true, test_policy(5));
+        assert_eq!(task.id(), TaskId::from("failing_task"));
+
+        run_task(task).unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn task_is_not_restarted_on_fatal_error() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let task = restartable_task(&runs, 3, false, test_policy(5));
+
+        let err = run_task(task).unwrap_err();
+        assert_eq!(err.to_string(), "fatal");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn restarts_are_capped() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let task = restartable_task(&runs, 10, true, test_policy(2));
+
+        let err = run_task(task).unwrap_err();
+        assert_eq!(err.to_string(), "retriable");
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+}
@@ -44,10 +44,13 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
             .create_store()
             .await?;
 
-        let config = SnapshotsApplierConfig {
+        let mut config = SnapshotsApplierConfig {
             max_concurrency: self.max_concurrency,
             ..SnapshotsApplierConfig::default()
         };
+        if let Some(max_chunks_in_memory) = self.recovery_config.max_chunks_in_memory {
+            config.max_chunks_in_memory = max_chunks_in_memory;
+        }
         let mut snapshots_applier_task = SnapshotsApplierTask::new(
             config,
             self.pool.clone(),
@@ -130,6 +133,7 @@ mod tests {
             recovery_config: SnapshotRecoveryConfig {
                 snapshot_l1_batch_override: None,
                 drop_storage_key_preimages: false,
+                max_chunks_in_memory: None,
                 object_store_config: None,
             },
             app_health,
@@ -1,4 +1,4 @@
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{future::Future, num::NonZeroUsize, sync::Arc, time::Duration};
 
 use tokio::sync::watch;
 use zksync_config::ObjectStoreConfig;
@@ -16,6 +16,8 @@ pub struct SnapshotRecoveryConfig {
     /// If not specified, the latest snapshot will be used.
     pub snapshot_l1_batch_override: Option<L1BatchNumber>,
     pub drop_storage_key_preimages: bool,
+    /// If not specified, the default value from the snapshots applier config will be used.
+    pub max_chunks_in_memory: Option<NonZeroUsize>,
     pub object_store_config: Option<ObjectStoreConfig>,
 }
 
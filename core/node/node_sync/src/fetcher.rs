@@ -4,7 +4,8 @@ use zksync_shared_metrics::{TxStage, APP_METRICS};
 use zksync_state_keeper::io::{common::IoCursor, L1BatchParams, L2BlockParams};
 use zksync_types::{
     api::en::SyncBlock, block::L2BlockHasher, commitment::PubdataParams, fee_model::BatchFeeInput,
-    helpers::unix_timestamp_ms, Address, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256,
+    helpers::unix_timestamp_ms, transaction_request::TransactionRequest, Address,
+    ExecuteTransactionCommon, L1BatchNumber, L2BlockNumber, ProtocolVersionId, H256,
 };
 
 use super::{
@@ -63,6 +64,48 @@ impl FetchedBlock {
         }
         hasher.finalize(self.protocol_version)
     }
+
+    /// Checks that initiators and hashes of L2 transactions in this block match the ones recovered from
+    /// the raw transaction bytes. Signers are recovered in a single batch on a blocking thread, so that
+    /// recovery doesn't stall the async runtime.
+    pub async fn verify_l2_transactions(&self) -> anyhow::Result<()> {
+        let l2_txs: Vec<_> = self
+            .transactions
+            .iter()
+            .filter_map(|tx| match &tx.0.common_data {
+                ExecuteTransactionCommon::L2(data) => Some((
+                    tx.hash(),
+                    data.initiator_address,
+                    data.input_data()?.to_vec(),
+                )),
+                _ => None,
+            })
+            .collect();
+        if l2_txs.is_empty() {
+            return Ok(());
+        }
+
+        tokio::task::spawn_blocking(move || {
+            let raw_txs: Vec<_> = l2_txs.iter().map(|(_, _, raw)| raw.as_slice()).collect();
+            let decoded = TransactionRequest::from_bytes_batch_unverified(&raw_txs);
+            for ((hash, initiator, _), decoded) in l2_txs.iter().zip(decoded) {
+                let (request, recovered_hash) =
+                    decoded.with_context(|| format!("failed decoding L2 transaction {hash:?}"))?;
+                anyhow::ensure!(
+                    recovered_hash == *hash,
+                    "L2 transaction hash mismatch: expected {hash:?}, recovered {recovered_hash:?}"
+                );
+                anyhow::ensure!(
+                    request.from == Some(*initiator),
+                    "L2 transaction {hash:?} initiator mismatch: expected {initiator:?}, recovered {:?}",
+                    request.from
+                );
+            }
+            Ok(())
+        })
+        .await
+        .context("panicked while recovering L2 transaction signers")?
+    }
 }
 
 impl TryFrom<SyncBlock> for FetchedBlock {
@@ -48,10 +48,17 @@ async fn create_genesis_params(
     // Load the list of addresses that are known to contain system contracts at any point in time.
     // Not every of these addresses is guaranteed to be present in the genesis state, but we'll iterate through
     // them and try to fetch the contract bytecode for each of them.
+    // Chain-specific system contracts declared in the genesis config are appended to this list.
     let system_contract_addresses: Vec<_> =
         get_system_smart_contracts(config.evm_emulator_hash.is_some())
             .into_iter()
             .map(|contract| *contract.account_id.address())
+            .chain(
+                config
+                    .custom_system_contracts
+                    .iter()
+                    .map(|contract| contract.address),
+            )
             .collect();
 
     // These have to be *initial* base contract hashes of main node
@@ -21,12 +21,17 @@ use zksync_types::{
     api,
     block::{L2BlockHasher, UnsealedL1BatchHeader},
     fee_model::{BatchFeeInput, PubdataIndependentBatchFeeModelInput},
+    l2::L2Tx,
     snapshots::SnapshotRecoveryStatus,
-    Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersionId, Transaction, H256,
+    Address, K256PrivateKey, L1BatchNumber, L2BlockNumber, L2ChainId, Nonce, ProtocolVersionId,
+    Transaction, H256, U256,
 };
 
 use super::{
-    fetcher::FetchedTransaction, sync_action::SyncAction, testonly::MockMainNodeClient, *,
+    fetcher::{FetchedBlock, FetchedTransaction},
+    sync_action::SyncAction,
+    testonly::MockMainNodeClient,
+    *,
 };
 
 const TEST_TIMEOUT: Duration = Duration::from_secs(10);
@@ -755,3 +760,49 @@ async fn external_io_empty_unsealed_batch() {
     state_keeper.wait_for_local_block(L2BlockNumber(4)).await;
     hash_task.await.unwrap();
 }
+
+#[tokio::test]
+async fn verifying_l2_transactions_in_fetched_block() {
+    let fee = create_l2_transaction(10, 100).common_data.fee;
+    let txs: Vec<_> = (0..5)
+        .map(|i| {
+            L2Tx::new_signed(
+                Some(Address::repeat_byte(2)),
+                vec![],
+                Nonce(i),
+                fee.clone(),
+                U256::zero(),
+                L2ChainId::default(),
+                &K256PrivateKey::random(),
+                vec![],
+                Default::default(),
+            )
+            .unwrap()
+        })
+        .collect();
+    let mut block = FetchedBlock {
+        number: L2BlockNumber(1),
+        l1_batch_number: L1BatchNumber(1),
+        last_in_batch: false,
+        protocol_version: ProtocolVersionId::latest(),
+        timestamp: 1,
+        reference_hash: None,
+        l1_gas_price: 2,
+        l2_fair_gas_price: 3,
+        fair_pubdata_price: Some(4),
+        virtual_blocks: 1,
+        operator_address: OPERATOR_ADDRESS,
+        transactions: txs
+            .iter()
+            .map(|tx| FetchedTransaction::new(tx.clone().into()))
+            .collect(),
+        pubdata_params: Default::default(),
+    };
+    block.verify_l2_transactions().await.unwrap();
+
+    let mut tampered_tx = txs[2].clone();
+    tampered_tx.common_data.initiator_address = Address::repeat_byte(3);
+    block.transactions[2] = FetchedTransaction::new(tampered_tx.into());
+    let err = block.verify_l2_transactions().await.unwrap_err();
+    assert!(err.to_string().contains("initiator mismatch"), "{err:#}");
+}
@@ -4,11 +4,16 @@ use axum::{
 };
 use zksync_dal::DalError;
 use zksync_object_store::ObjectStoreError;
+use zksync_types::L1BatchNumber;
 
 pub(crate) enum RequestProcessorError {
     GeneralError(String),
     ObjectStore(ObjectStoreError),
     Dal(DalError),
+    Unauthorized,
+    InvalidRequest(String),
+    InvalidProof(String),
+    BatchNotAvailable(L1BatchNumber),
 }
 
 impl From<DalError> for RequestProcessorError {
@@ -41,6 +46,19 @@ impl IntoResponse for RequestProcessorError {
                     "Failed fetching/saving from db".to_owned(),
                 )
             }
+            Self::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Missing or invalid API key".to_owned(),
+            ),
+            Self::InvalidRequest(message) => (StatusCode::BAD_REQUEST, message),
+            Self::InvalidProof(message) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Invalid proof: {message}"),
+            ),
+            Self::BatchNotAvailable(l1_batch_number) => (
+                StatusCode::CONFLICT,
+                format!("Batch {l1_batch_number} is not available for proving"),
+            ),
         };
         (status_code, message).into_response()
     }
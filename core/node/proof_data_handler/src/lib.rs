@@ -1,7 +1,14 @@
 use std::{net::SocketAddr, sync::Arc};
 
 use anyhow::Context as _;
-use axum::{extract::Path, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use marketplace_request_processor::{BatchesAwaitingProofQuery, MarketplaceRequestProcessor};
 use request_processor::RequestProcessor;
 use tee_request_processor::TeeRequestProcessor;
 use tokio::sync::watch;
@@ -10,7 +17,7 @@ use zksync_dal::{ConnectionPool, Core};
 use zksync_object_store::ObjectStore;
 use zksync_prover_interface::api::{
     ProofGenerationDataRequest, RegisterTeeAttestationRequest, SubmitProofRequest,
-    SubmitTeeProofRequest, TeeProofGenerationDataRequest,
+    SubmitProvingStagesRequest, SubmitTeeProofRequest, TeeProofGenerationDataRequest,
 };
 use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 
@@ -18,16 +25,21 @@ use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
 mod tests;
 
 mod errors;
+mod marketplace_request_processor;
 mod metrics;
+mod proof_verifier;
 mod request_processor;
 mod tee_request_processor;
 
+pub use self::proof_verifier::{L1ProofVerifier, ProofVerifier};
+
 pub async fn run_server(
     config: ProofDataHandlerConfig,
     blob_store: Arc<dyn ObjectStore>,
     connection_pool: ConnectionPool<Core>,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    proof_verifier: Option<Arc<dyn ProofVerifier>>,
     mut stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
     let bind_address = SocketAddr::from(([0, 0, 0, 0], config.http_port));
@@ -38,7 +50,8 @@ pub async fn run_server(
         config,
         commitment_mode,
         l2_chain_id,
-    );
+        proof_verifier,
+    )?;
 
     let listener = tokio::net::TcpListener::bind(bind_address)
         .await
@@ -62,7 +75,8 @@ fn create_proof_processing_router(
     config: ProofDataHandlerConfig,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
-) -> Router {
+    proof_verifier: Option<Arc<dyn ProofVerifier>>,
+) -> anyhow::Result<Router> {
     let get_proof_gen_processor = RequestProcessor::new(
         blob_store.clone(),
         connection_pool.clone(),
@@ -70,6 +84,8 @@ fn create_proof_processing_router(
         commitment_mode,
     );
     let submit_proof_processor = get_proof_gen_processor.clone();
+    let submit_proving_stages_processor = get_proof_gen_processor.clone();
+    let marketplace_inner_processor = get_proof_gen_processor.clone();
     let mut router = Router::new()
         .route(
             "/proof_generation_data",
@@ -92,7 +108,64 @@ fn create_proof_processing_router(
                         .await
                 },
             ),
+        )
+        .route(
+            "/submit_proving_stages/:l1_batch_number",
+            post(
+                move |l1_batch_number: Path<u32>,
+                      payload: Json<SubmitProvingStagesRequest>| async move {
+                    submit_proving_stages_processor
+                        .submit_proving_stages(l1_batch_number, payload)
+                        .await
+                },
+            ),
+        );
+
+    if config.marketplace_config.marketplace_support {
+        let proof_verifier =
+            proof_verifier.context("proof verifier is required for marketplace support")?;
+        let marketplace_processor = MarketplaceRequestProcessor::new(
+            marketplace_inner_processor,
+            connection_pool.clone(),
+            config.clone(),
+            proof_verifier,
         );
+        let get_batches_processor = marketplace_processor.clone();
+        let claim_batch_processor = marketplace_processor.clone();
+        let submit_marketplace_proof_processor = marketplace_processor;
+
+        router = router
+            .route(
+                "/marketplace/batches",
+                get(move |query: Query<BatchesAwaitingProofQuery>| async move {
+                    get_batches_processor
+                        .get_batches_awaiting_proof(query)
+                        .await
+                }),
+            )
+            .route(
+                "/marketplace/claim/:l1_batch_number",
+                post(
+                    move |headers: HeaderMap, l1_batch_number: Path<u32>| async move {
+                        claim_batch_processor
+                            .claim_batch(headers, l1_batch_number)
+                            .await
+                    },
+                ),
+            )
+            .route(
+                "/marketplace/submit_proof/:l1_batch_number",
+                post(
+                    move |headers: HeaderMap,
+                          l1_batch_number: Path<u32>,
+                          payload: Json<SubmitProofRequest>| async move {
+                        submit_marketplace_proof_processor
+                            .submit_proof(headers, l1_batch_number, payload)
+                            .await
+                    },
+                ),
+            );
+    }
 
     if config.tee_config.tee_support {
         let get_tee_proof_gen_processor =
@@ -138,7 +211,7 @@ fn create_proof_processing_router(
         );
     }
 
-    router
+    Ok(router
         .layer(tower_http::compression::CompressionLayer::new())
-        .layer(tower_http::decompression::RequestDecompressionLayer::new().zstd(true))
+        .layer(tower_http::decompression::RequestDecompressionLayer::new().zstd(true)))
 }
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{header, HeaderMap},
+    Json,
+};
+use serde::Deserialize;
+use zksync_config::configs::ProofDataHandlerConfig;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_prover_interface::{
+    api::{
+        BatchAwaitingProof, BatchesAwaitingProofResponse, ProofGenerationDataResponse, ProofReward,
+        SubmitProofRequest, SubmitProofResponse,
+    },
+    outputs::L1BatchProofForL1,
+};
+use zksync_types::{
+    commitment::L1BatchWithMetadata,
+    web3::{keccak256, keccak256_concat},
+    L1BatchNumber, H256, U256,
+};
+
+use crate::{
+    errors::RequestProcessorError, proof_verifier::ProofVerifier,
+    request_processor::RequestProcessor,
+};
+
+/// Default number of batches returned by the listing endpoint.
+const DEFAULT_BATCHES_LIMIT: usize = 10;
+/// Maximum number of batches returned by the listing endpoint.
+const MAX_BATCHES_LIMIT: usize = 100;
+/// Number of bits the public input of a proof is shifted by, so that it fits into the scalar field.
+/// Must match `PUBLIC_INPUT_SHIFT` in the L1 executor contract.
+const PUBLIC_INPUT_SHIFT: usize = 32;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchesAwaitingProofQuery {
+    limit: Option<usize>,
+}
+
+/// Processes requests from third-party provers, e.g. proving marketplaces.
+///
+/// Provers list batches awaiting proofs, claim a batch to receive its witness inputs and then submit
+/// a proof for the claimed batch. Claims are exclusive and expire after the proof generation timeout,
+/// same as for the batches locked by the prover gateway. Submitted proofs are verified before being persisted.
+#[derive(Clone)]
+pub(crate) struct MarketplaceRequestProcessor {
+    inner: RequestProcessor,
+    pool: ConnectionPool<Core>,
+    config: ProofDataHandlerConfig,
+    proof_verifier: Arc<dyn ProofVerifier>,
+}
+
+impl MarketplaceRequestProcessor {
+    pub(crate) fn new(
+        inner: RequestProcessor,
+        pool: ConnectionPool<Core>,
+        config: ProofDataHandlerConfig,
+        proof_verifier: Arc<dyn ProofVerifier>,
+    ) -> Self {
+        Self {
+            inner,
+            pool,
+            config,
+            proof_verifier,
+        }
+    }
+
+    /// Authenticates the prover by the API key in the `Authorization` header. Returns the key hash,
+    /// which identifies the prover.
+    fn authenticate(&self, headers: &HeaderMap) -> Result<H256, RequestProcessorError> {
+        let api_key = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(RequestProcessorError::Unauthorized)?;
+        let key_hash = H256(keccak256(api_key.trim().as_bytes()));
+        let marketplace_config = &self.config.marketplace_config;
+        if marketplace_config
+            .marketplace_api_key_hashes
+            .contains(&key_hash)
+        {
+            Ok(key_hash)
+        } else {
+            Err(RequestProcessorError::Unauthorized)
+        }
+    }
+
+    pub(crate) async fn get_batches_awaiting_proof(
+        &self,
+        Query(query): Query<BatchesAwaitingProofQuery>,
+    ) -> Result<Json<BatchesAwaitingProofResponse>, RequestProcessorError> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_BATCHES_LIMIT)
+            .min(MAX_BATCHES_LIMIT);
+        let batches = self
+            .pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .proof_generation_dal()
+            .get_batches_awaiting_proof(self.config.proof_generation_timeout(), limit)
+            .await
+            .map_err(RequestProcessorError::Dal)?;
+
+        let marketplace_config = &self.config.marketplace_config;
+        let reward = (marketplace_config.marketplace_reward_token.is_some()
+            || marketplace_config.marketplace_reward_per_batch.is_some())
+        .then(|| ProofReward {
+            token: marketplace_config.marketplace_reward_token.clone(),
+            amount_per_batch: marketplace_config.marketplace_reward_per_batch.clone(),
+        });
+        let batches = batches
+            .into_iter()
+            .map(|batch| BatchAwaitingProof {
+                l1_batch_number: batch.l1_batch_number,
+                witness_inputs_available: batch.witness_inputs_available,
+                claimed: batch.is_picked,
+            })
+            .collect();
+        Ok(Json(BatchesAwaitingProofResponse { batches, reward }))
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub(crate) async fn claim_batch(
+        &self,
+        headers: HeaderMap,
+        Path(l1_batch_number): Path<u32>,
+    ) -> Result<Json<ProofGenerationDataResponse>, RequestProcessorError> {
+        let prover = self.authenticate(&headers)?;
+        let l1_batch_number = L1BatchNumber(l1_batch_number);
+        tracing::info!("Received claim for batch {l1_batch_number} from prover {prover:?}");
+
+        let claimed = self
+            .pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .proof_generation_dal()
+            .claim_batch_for_proving(
+                l1_batch_number,
+                prover.as_bytes(),
+                self.config.proof_generation_timeout(),
+            )
+            .await
+            .map_err(RequestProcessorError::Dal)?;
+        if !claimed {
+            return Err(RequestProcessorError::BatchNotAvailable(l1_batch_number));
+        }
+
+        let proof_generation_data = self
+            .inner
+            .proof_generation_data_for_existing_batch(l1_batch_number)
+            .await;
+        // If we weren't able to fetch all the data, we should unlock the batch before returning.
+        match proof_generation_data {
+            Ok(data) => Ok(Json(ProofGenerationDataResponse::Success(Some(Box::new(
+                data,
+            ))))),
+            Err(err) => {
+                self.inner.unlock_batch(l1_batch_number).await?;
+                Err(err)
+            }
+        }
+    }
+
+    pub(crate) async fn submit_proof(
+        &self,
+        headers: HeaderMap,
+        Path(l1_batch_number): Path<u32>,
+        Json(payload): Json<SubmitProofRequest>,
+    ) -> Result<Json<SubmitProofResponse>, RequestProcessorError> {
+        let prover = self.authenticate(&headers)?;
+        let l1_batch_number = L1BatchNumber(l1_batch_number);
+        let SubmitProofRequest::Proof(proof) = payload else {
+            return Err(RequestProcessorError::InvalidRequest(
+                "Only proofs can be submitted via the marketplace API".to_owned(),
+            ));
+        };
+        tracing::info!("Received proof for batch {l1_batch_number} from prover {prover:?}");
+
+        let claimant = self
+            .pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .proof_generation_dal()
+            .get_batch_claimant(l1_batch_number)
+            .await
+            .map_err(RequestProcessorError::Dal)?;
+        if claimant.as_deref() != Some(prover.as_bytes()) {
+            // The batch wasn't claimed by this prover, or the claim has been taken over after expiration.
+            return Err(RequestProcessorError::BatchNotAvailable(l1_batch_number));
+        }
+
+        // Unlike proofs from the prover gateway, proofs from third-party provers are untrusted, so they are verified
+        // before being persisted. Otherwise, an invalid proof would be sent to L1 and stall proving.
+        let prev_l1_batch_number = l1_batch_number
+            .0
+            .checked_sub(1)
+            .map(L1BatchNumber)
+            .ok_or_else(|| {
+                RequestProcessorError::InvalidRequest(
+                    "Genesis batch doesn't have a proof".to_owned(),
+                )
+            })?;
+        let l1_batch = self.inner.load_l1_batch_metadata(l1_batch_number).await?;
+        let prev_l1_batch = self
+            .inner
+            .load_l1_batch_metadata(prev_l1_batch_number)
+            .await?;
+        RequestProcessor::check_aggregation_coords(&l1_batch, &proof)?;
+        self.verify_proof(&prev_l1_batch, &l1_batch, &proof).await?;
+        self.inner.save_proof(&l1_batch, &proof).await?;
+        Ok(Json(SubmitProofResponse::Success))
+    }
+
+    /// Verifies the SNARK proof of an L1 batch. The public input is computed in the same way as
+    /// by the L1 executor contract.
+    async fn verify_proof(
+        &self,
+        prev_l1_batch: &L1BatchWithMetadata,
+        l1_batch: &L1BatchWithMetadata,
+        proof: &L1BatchProofForL1,
+    ) -> Result<(), RequestProcessorError> {
+        let l1_batch_number = l1_batch.header.number;
+        if l1_batch.header.protocol_version != Some(proof.protocol_version.minor) {
+            return Err(RequestProcessorError::InvalidProof(format!(
+                "protocol version {} doesn't match L1 batch #{l1_batch_number}",
+                proof.protocol_version
+            )));
+        }
+
+        let hash = keccak256_concat(
+            prev_l1_batch.metadata.commitment,
+            l1_batch.metadata.commitment,
+        );
+        let public_input = U256::from_big_endian(hash.as_bytes()) >> PUBLIC_INPUT_SHIFT;
+        let is_valid = self
+            .proof_verifier
+            .verify_proof(public_input, proof)
+            .await
+            .map_err(|err| {
+                RequestProcessorError::GeneralError(format!(
+                    "failed verifying proof for L1 batch #{l1_batch_number}: {err:#}"
+                ))
+            })?;
+        if !is_valid {
+            return Err(RequestProcessorError::InvalidProof(format!(
+                "SNARK verification failed for L1 batch #{l1_batch_number}"
+            )));
+        }
+        Ok(())
+    }
+}
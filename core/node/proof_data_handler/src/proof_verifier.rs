@@ -0,0 +1,89 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use crypto_codegen::serialize_proof;
+use zksync_contracts::{getters_facet_contract, verifier_contract};
+use zksync_eth_client::{
+    clients::{DynClient, L1},
+    CallFunctionArgs, ClientError, ContractCallError,
+};
+use zksync_prover_interface::outputs::L1BatchProofForL1;
+use zksync_types::{
+    ethabi::{Contract, Token},
+    Address, U256,
+};
+
+/// Verifies final proofs submitted by untrusted provers before they are persisted.
+#[async_trait]
+pub trait ProofVerifier: 'static + fmt::Debug + Send + Sync {
+    /// Verifies the final proof against the specified public input. Returns `Ok(false)` if the proof
+    /// is invalid, and an error if it cannot be verified (e.g., because of a network error).
+    async fn verify_proof(
+        &self,
+        public_input: U256,
+        proof: &L1BatchProofForL1,
+    ) -> anyhow::Result<bool>;
+}
+
+/// Verifies proofs by calling the verifier contract on L1. Thus, proofs are verified with the same
+/// verification key that is used when they are sent to L1.
+#[derive(Debug)]
+pub struct L1ProofVerifier {
+    client: Box<DynClient<L1>>,
+    diamond_proxy_addr: Address,
+    getters_facet_contract_abi: Contract,
+    verifier_contract_abi: Contract,
+}
+
+impl L1ProofVerifier {
+    pub fn new(client: Box<DynClient<L1>>, diamond_proxy_addr: Address) -> Self {
+        Self {
+            client: client.for_component("proof_verifier"),
+            diamond_proxy_addr,
+            getters_facet_contract_abi: getters_facet_contract(),
+            verifier_contract_abi: verifier_contract(),
+        }
+    }
+}
+
+#[async_trait]
+impl ProofVerifier for L1ProofVerifier {
+    async fn verify_proof(
+        &self,
+        public_input: U256,
+        proof: &L1BatchProofForL1,
+    ) -> anyhow::Result<bool> {
+        // The verifier may change during protocol upgrades, so it's queried every time.
+        let verifier_address: Address = CallFunctionArgs::new("getVerifier", ())
+            .for_contract(self.diamond_proxy_addr, &self.getters_facet_contract_abi)
+            .call(&self.client)
+            .await?;
+
+        // Public inputs included into the proof are ignored, same as when the proof is sent to L1.
+        let (_, serialized_proof) = serialize_proof(&proof.scheduler_proof);
+        let public_inputs = Token::Array(vec![Token::Uint(public_input)]);
+        let proof = Token::Array(serialized_proof.into_iter().map(Token::Uint).collect());
+        let verify_function = self.verifier_contract_abi.function("verify")?;
+        let args = if verify_function.inputs.len() == 3 {
+            // Older verifiers additionally accept the recursive aggregation input, which is always empty.
+            CallFunctionArgs::new("verify", (public_inputs, proof, Token::Array(vec![])))
+        } else {
+            CallFunctionArgs::new("verify", (public_inputs, proof))
+        };
+        let result = args
+            .for_contract(verifier_address, &self.verifier_contract_abi)
+            .call(&self.client)
+            .await;
+        match result {
+            Ok(is_valid) => Ok(is_valid),
+            // The verifier reverts on malformed proofs.
+            Err(ContractCallError::EthereumGateway(err))
+                if matches!(err.as_ref(), ClientError::Call(_)) =>
+            {
+                tracing::info!("Verifier reverted when verifying proof: {err}");
+                Ok(false)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
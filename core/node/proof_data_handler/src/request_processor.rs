@@ -7,15 +7,17 @@ use zksync_object_store::ObjectStore;
 use zksync_prover_interface::{
     api::{
         ProofGenerationData, ProofGenerationDataRequest, ProofGenerationDataResponse,
-        SubmitProofRequest, SubmitProofResponse,
+        SubmitProofRequest, SubmitProofResponse, SubmitProvingStagesRequest,
+        SubmitProvingStagesResponse,
     },
     inputs::{
         L1BatchMetadataHashes, VMRunWitnessInputData, WitnessInputData, WitnessInputMerklePaths,
     },
+    outputs::L1BatchProofForL1,
 };
 use zksync_types::{
     basic_fri_types::Eip4844Blobs,
-    commitment::{serialize_commitments, L1BatchCommitmentMode},
+    commitment::{serialize_commitments, L1BatchCommitmentMode, L1BatchWithMetadata},
     web3::keccak256,
     L1BatchNumber, ProtocolVersionId, H256, STATE_DIFF_HASH_KEY_PRE_GATEWAY,
 };
@@ -86,7 +88,7 @@ impl RequestProcessor {
     }
 
     /// Marks the batch as 'unpicked', allowing it to be picked up by another prover.
-    async fn unlock_batch(
+    pub(crate) async fn unlock_batch(
         &self,
         l1_batch_number: L1BatchNumber,
     ) -> Result<(), RequestProcessorError> {
@@ -107,7 +109,7 @@ impl RequestProcessor {
     /// Expects all the data to be present in the database.
     /// Will panic if any of the required data is missing.
     #[tracing::instrument(skip(self))]
-    async fn proof_generation_data_for_existing_batch(
+    pub(crate) async fn proof_generation_data_for_existing_batch(
         &self,
         l1_batch_number: L1BatchNumber,
     ) -> Result<ProofGenerationData, RequestProcessorError> {
@@ -202,98 +204,14 @@ impl RequestProcessor {
         let l1_batch_number = L1BatchNumber(l1_batch_number);
         match payload {
             SubmitProofRequest::Proof(proof) => {
-                let blob_url = self
-                    .blob_store
-                    .put((l1_batch_number, proof.protocol_version), &*proof)
-                    .await
-                    .map_err(RequestProcessorError::ObjectStore)?;
-
-                let system_logs_hash_from_prover =
-                    H256::from_slice(&proof.aggregation_result_coords[0]);
-                let state_diff_hash_from_prover =
-                    H256::from_slice(&proof.aggregation_result_coords[1]);
-                let bootloader_heap_initial_content_from_prover =
-                    H256::from_slice(&proof.aggregation_result_coords[2]);
-                let events_queue_state_from_prover =
-                    H256::from_slice(&proof.aggregation_result_coords[3]);
-
-                let mut storage = self.pool.connection().await.unwrap();
-
-                let l1_batch = storage
-                    .blocks_dal()
-                    .get_l1_batch_metadata(l1_batch_number)
-                    .await
-                    .unwrap()
-                    .expect("Proved block without metadata");
-
-                let protocol_version = l1_batch
-                    .header
-                    .protocol_version
-                    .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
-
-                let events_queue_state = l1_batch
-                    .metadata
-                    .events_queue_commitment
-                    .expect("No events_queue_commitment");
-                let bootloader_heap_initial_content = l1_batch
-                    .metadata
-                    .bootloader_initial_content_commitment
-                    .expect("No bootloader_initial_content_commitment");
-
-                if events_queue_state != events_queue_state_from_prover
-                    || bootloader_heap_initial_content
-                        != bootloader_heap_initial_content_from_prover
-                {
-                    panic!(
-                        "Auxilary output doesn't match\n\
-                        server values: events_queue_state = {events_queue_state}, bootloader_heap_initial_content = {bootloader_heap_initial_content}\n\
-                        prover values: events_queue_state = {events_queue_state_from_prover}, bootloader_heap_initial_content = {bootloader_heap_initial_content_from_prover}",
-                    );
-                }
-
-                let system_logs = serialize_commitments(&l1_batch.header.system_logs);
-                let system_logs_hash = H256(keccak256(&system_logs));
-
-                let state_diff_hash = if protocol_version.is_pre_gateway() {
-                    l1_batch
-                        .header
-                        .system_logs
-                        .iter()
-                        .find_map(|log| {
-                            (log.0.key
-                                == H256::from_low_u64_be(STATE_DIFF_HASH_KEY_PRE_GATEWAY as u64))
-                            .then_some(log.0.value)
-                        })
-                        .expect("Failed to get state_diff_hash from system logs")
-                } else {
-                    l1_batch
-                        .metadata
-                        .state_diff_hash
-                        .expect("Failed to get state_diff_hash from metadata")
-                };
-
-                if state_diff_hash != state_diff_hash_from_prover
-                    || system_logs_hash != system_logs_hash_from_prover
-                {
-                    let server_values = format!("system_logs_hash = {system_logs_hash}, state_diff_hash = {state_diff_hash}");
-                    let prover_values = format!("system_logs_hash = {system_logs_hash_from_prover}, state_diff_hash = {state_diff_hash_from_prover}");
-                    panic!(
-                        "Auxilary output doesn't match, server values: {} prover values: {}",
-                        server_values, prover_values
-                    );
-                }
-
-                storage
-                    .proof_generation_dal()
-                    .save_proof_artifacts_metadata(l1_batch_number, &blob_url)
-                    .await
-                    .map_err(RequestProcessorError::Dal)?;
+                let l1_batch = self.load_l1_batch_metadata(l1_batch_number).await?;
+                self.save_proof(&l1_batch, &proof).await?;
             }
             SubmitProofRequest::SkippedProofGeneration => {
                 self.pool
                     .connection()
                     .await
-                    .unwrap()
+                    .map_err(RequestProcessorError::Dal)?
                     .proof_generation_dal()
                     .mark_proof_generation_job_as_skipped(l1_batch_number)
                     .await
@@ -303,4 +221,155 @@ impl RequestProcessor {
 
         Ok(Json(SubmitProofResponse::Success))
     }
+
+    pub(crate) async fn load_l1_batch_metadata(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<L1BatchWithMetadata, RequestProcessorError> {
+        self.pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .blocks_dal()
+            .get_l1_batch_metadata(l1_batch_number)
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .ok_or_else(|| {
+                RequestProcessorError::GeneralError(format!(
+                    "Proved L1 batch #{l1_batch_number} doesn't have metadata"
+                ))
+            })
+    }
+
+    /// Checks the auxiliary outputs of the proof and, if they match, persists the proof.
+    pub(crate) async fn save_proof(
+        &self,
+        l1_batch: &L1BatchWithMetadata,
+        proof: &L1BatchProofForL1,
+    ) -> Result<(), RequestProcessorError> {
+        let l1_batch_number = l1_batch.header.number;
+        Self::check_aggregation_coords(l1_batch, proof)?;
+
+        let blob_url = self
+            .blob_store
+            .put((l1_batch_number, proof.protocol_version), proof)
+            .await
+            .map_err(RequestProcessorError::ObjectStore)?;
+        self.pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .proof_generation_dal()
+            .save_proof_artifacts_metadata(l1_batch_number, &blob_url)
+            .await
+            .map_err(RequestProcessorError::Dal)?;
+        Ok(())
+    }
+
+    /// Checks that the auxiliary outputs of the proof (aggregation result coordinates) match the L1 batch.
+    pub(crate) fn check_aggregation_coords(
+        l1_batch: &L1BatchWithMetadata,
+        proof: &L1BatchProofForL1,
+    ) -> Result<(), RequestProcessorError> {
+        let system_logs_hash_from_prover = H256::from_slice(&proof.aggregation_result_coords[0]);
+        let state_diff_hash_from_prover = H256::from_slice(&proof.aggregation_result_coords[1]);
+        let bootloader_heap_initial_content_from_prover =
+            H256::from_slice(&proof.aggregation_result_coords[2]);
+        let events_queue_state_from_prover = H256::from_slice(&proof.aggregation_result_coords[3]);
+
+        let protocol_version = l1_batch
+            .header
+            .protocol_version
+            .unwrap_or_else(ProtocolVersionId::last_potentially_undefined);
+        let missing_metadata = |name: &str| {
+            RequestProcessorError::GeneralError(format!(
+                "L1 batch #{} doesn't have {name}",
+                l1_batch.header.number
+            ))
+        };
+
+        let events_queue_state = l1_batch
+            .metadata
+            .events_queue_commitment
+            .ok_or_else(|| missing_metadata("events_queue_commitment"))?;
+        let bootloader_heap_initial_content = l1_batch
+            .metadata
+            .bootloader_initial_content_commitment
+            .ok_or_else(|| missing_metadata("bootloader_initial_content_commitment"))?;
+
+        if events_queue_state != events_queue_state_from_prover
+            || bootloader_heap_initial_content != bootloader_heap_initial_content_from_prover
+        {
+            let server_values = format!("events_queue_state = {events_queue_state}, bootloader_heap_initial_content = {bootloader_heap_initial_content}");
+            let prover_values = format!("events_queue_state = {events_queue_state_from_prover}, bootloader_heap_initial_content = {bootloader_heap_initial_content_from_prover}");
+            tracing::error!(
+                "Auxilary output doesn't match, server values: {server_values} prover values: {prover_values}"
+            );
+            return Err(RequestProcessorError::InvalidProof(
+                "Auxiliary output doesn't match".to_owned(),
+            ));
+        }
+
+        let system_logs = serialize_commitments(&l1_batch.header.system_logs);
+        let system_logs_hash = H256(keccak256(&system_logs));
+
+        let state_diff_hash = if protocol_version.is_pre_gateway() {
+            l1_batch
+                .header
+                .system_logs
+                .iter()
+                .find_map(|log| {
+                    (log.0.key == H256::from_low_u64_be(STATE_DIFF_HASH_KEY_PRE_GATEWAY as u64))
+                        .then_some(log.0.value)
+                })
+                .ok_or_else(|| missing_metadata("state_diff_hash in system logs"))?
+        } else {
+            l1_batch
+                .metadata
+                .state_diff_hash
+                .ok_or_else(|| missing_metadata("state_diff_hash"))?
+        };
+
+        if state_diff_hash != state_diff_hash_from_prover
+            || system_logs_hash != system_logs_hash_from_prover
+        {
+            let server_values = format!(
+                "system_logs_hash = {system_logs_hash}, state_diff_hash = {state_diff_hash}"
+            );
+            let prover_values = format!("system_logs_hash = {system_logs_hash_from_prover}, state_diff_hash = {state_diff_hash_from_prover}");
+            tracing::error!(
+                "Auxilary output doesn't match, server values: {server_values} prover values: {prover_values}"
+            );
+            return Err(RequestProcessorError::InvalidProof(
+                "Auxiliary output doesn't match".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn submit_proving_stages(
+        &self,
+        Path(l1_batch_number): Path<u32>,
+        Json(payload): Json<SubmitProvingStagesRequest>,
+    ) -> Result<Json<SubmitProvingStagesResponse>, RequestProcessorError> {
+        let l1_batch_number = L1BatchNumber(l1_batch_number);
+        tracing::info!(
+            "Received {} proving stage timestamps for L1 batch #{l1_batch_number}",
+            payload.stages.len()
+        );
+        let stages: Vec<_> = payload
+            .stages
+            .into_iter()
+            .map(|stage| (stage.stage, stage.timestamp))
+            .collect();
+        self.pool
+            .connection()
+            .await
+            .map_err(RequestProcessorError::Dal)?
+            .proof_latency_dal()
+            .record_stages(l1_batch_number, &stages)
+            .await
+            .map_err(RequestProcessorError::Dal)?;
+        Ok(Json(SubmitProvingStagesResponse::Success))
+    }
 }
@@ -1,19 +1,48 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use assert_matches::assert_matches;
+use async_trait::async_trait;
 use axum::{
     body::Body,
     http::{self, Method, Request, StatusCode},
     response::Response,
     Router,
 };
+use circuit_sequencer_api_1_5_0::proof::FinalProof;
 use serde_json::json;
 use tower::ServiceExt;
 use zksync_basic_types::L2ChainId;
-use zksync_config::configs::{ProofDataHandlerConfig, TeeConfig};
+use zksync_config::configs::{ProofDataHandlerConfig, ProofMarketplaceConfig, TeeConfig};
 use zksync_dal::{ConnectionPool, CoreDal};
-use zksync_object_store::MockObjectStore;
-use zksync_prover_interface::api::SubmitTeeProofRequest;
-use zksync_types::{commitment::L1BatchCommitmentMode, tee_types::TeeType, L1BatchNumber};
+use zksync_node_test_utils::{
+    create_l1_batch, create_l1_batch_metadata, l1_batch_metadata_to_commitment_artifacts,
+};
+use zksync_object_store::{MockObjectStore, ObjectStore, ObjectStoreError};
+use zksync_prover_interface::{
+    api::{
+        BatchesAwaitingProofResponse, ProofReward, ProvingStageTimestamp, SubmitProofRequest,
+        SubmitProvingStagesRequest, SubmitTeeProofRequest,
+    },
+    outputs::L1BatchProofForL1,
+};
+use zksync_types::{
+    block::L1BatchHeader,
+    commitment::{serialize_commitments, L1BatchCommitmentMode},
+    l2_to_l1_log::{L2ToL1Log, SystemL2ToL1Log},
+    protocol_version::ProtocolSemanticVersion,
+    prover_dal::ProvingStage,
+    tee_types::TeeType,
+    web3::{keccak256, keccak256_concat},
+    L1BatchNumber, ProtocolVersion, ProtocolVersionId, H256, STATE_DIFF_HASH_KEY_PRE_GATEWAY, U256,
+};
 
-use crate::create_proof_processing_router;
+use crate::{create_proof_processing_router, ProofVerifier};
 
 #[tokio::test]
 async fn request_tee_proof_inputs() {
@@ -30,10 +59,13 @@ async fn request_tee_proof_inputs() {
                 first_tee_processed_batch: L1BatchNumber(0),
                 tee_proof_generation_timeout_in_secs: 600,
             },
+            marketplace_config: ProofMarketplaceConfig::default(),
         },
         L1BatchCommitmentMode::Rollup,
         L2ChainId::default(),
-    );
+        None,
+    )
+    .unwrap();
     let test_cases = vec![
         (json!({ "tee_type": "sgx" }), StatusCode::NO_CONTENT),
         (
@@ -89,10 +121,13 @@ async fn submit_tee_proof() {
                 first_tee_processed_batch: L1BatchNumber(0),
                 tee_proof_generation_timeout_in_secs: 600,
             },
+            marketplace_config: ProofMarketplaceConfig::default(),
         },
         L1BatchCommitmentMode::Rollup,
         L2ChainId::default(),
-    );
+        None,
+    )
+    .unwrap();
 
     // this should fail because we haven't saved the attestation for the pubkey yet
 
@@ -146,6 +181,375 @@ async fn submit_tee_proof() {
     assert_eq!(proof.pubkey.as_ref().unwrap(), &tee_proof_request.0.pubkey);
 }
 
+const MARKETPLACE_API_KEY: &str = "test-api-key";
+
+const OTHER_MARKETPLACE_API_KEY: &str = "other-test-api-key";
+
+/// Proof verifier accepting proofs with the expected public input if `is_valid` is set.
+#[derive(Debug, Default)]
+struct MockProofVerifier {
+    is_valid: AtomicBool,
+    verified_public_inputs: Mutex<Vec<U256>>,
+}
+
+#[async_trait]
+impl ProofVerifier for MockProofVerifier {
+    async fn verify_proof(
+        &self,
+        public_input: U256,
+        _proof: &L1BatchProofForL1,
+    ) -> anyhow::Result<bool> {
+        self.verified_public_inputs
+            .lock()
+            .unwrap()
+            .push(public_input);
+        Ok(self.is_valid.load(Ordering::SeqCst))
+    }
+}
+
+fn create_marketplace_router(db_conn_pool: ConnectionPool<zksync_dal::Core>) -> Router {
+    create_marketplace_router_with_verifier(
+        db_conn_pool,
+        MockObjectStore::arc(),
+        Arc::<MockProofVerifier>::default(),
+    )
+}
+
+fn create_marketplace_router_with_verifier(
+    db_conn_pool: ConnectionPool<zksync_dal::Core>,
+    blob_store: Arc<dyn ObjectStore>,
+    proof_verifier: Arc<MockProofVerifier>,
+) -> Router {
+    let api_key_hashes = [MARKETPLACE_API_KEY, OTHER_MARKETPLACE_API_KEY]
+        .map(|key| H256(keccak256(key.as_bytes())))
+        .to_vec();
+    create_proof_processing_router(
+        blob_store,
+        db_conn_pool,
+        ProofDataHandlerConfig {
+            http_port: 1337,
+            proof_generation_timeout_in_secs: 10,
+            tee_config: TeeConfig::default(),
+            marketplace_config: ProofMarketplaceConfig {
+                marketplace_support: true,
+                marketplace_api_key_hashes: api_key_hashes,
+                marketplace_reward_token: Some("ETH".to_owned()),
+                marketplace_reward_per_batch: Some("0.01".to_owned()),
+            },
+        },
+        L1BatchCommitmentMode::Rollup,
+        L2ChainId::default(),
+        Some(proof_verifier),
+    )
+    .unwrap()
+}
+
+async fn send_marketplace_request(
+    app: &Router,
+    uri: &str,
+    api_key: Option<&str>,
+    body: Body,
+) -> Response {
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(http::header::CONTENT_TYPE, "application/json");
+    if let Some(api_key) = api_key {
+        request = request.header(http::header::AUTHORIZATION, format!("Bearer {api_key}"));
+    }
+    app.clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn list_marketplace_batches() {
+    let app = create_marketplace_router(ConnectionPool::test_pool().await);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri("/marketplace/batches?limit=5")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let response: BatchesAwaitingProofResponse = serde_json::from_slice(&body).unwrap();
+    assert!(response.batches.is_empty());
+    assert_eq!(
+        response.reward,
+        Some(ProofReward {
+            token: Some("ETH".to_owned()),
+            amount_per_batch: Some("0.01".to_owned()),
+        })
+    );
+}
+
+#[tokio::test]
+async fn marketplace_requests_are_authenticated() {
+    let app = create_marketplace_router(ConnectionPool::test_pool().await);
+    let uri = "/marketplace/claim/1";
+
+    let response = send_marketplace_request(&app, uri, None, Body::empty()).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = send_marketplace_request(&app, uri, Some("wrong-key"), Body::empty()).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The batch doesn't exist, so it cannot be claimed.
+    let response =
+        send_marketplace_request(&app, uri, Some(MARKETPLACE_API_KEY), Body::empty()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Provers must not be able to skip proofs.
+    let body = Body::from(serde_json::to_vec(&SubmitProofRequest::SkippedProofGeneration).unwrap());
+    let response = send_marketplace_request(
+        &app,
+        "/marketplace/submit_proof/1",
+        Some(MARKETPLACE_API_KEY),
+        body,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+const STATE_DIFF_HASH: H256 = H256::repeat_byte(0x11);
+
+/// Inserts L1 batches #0 and #1 with metadata, and claims batch #1 by the prover with the specified API key.
+async fn prepare_claimed_batch(pool: &ConnectionPool<zksync_dal::Core>, api_key: &str) {
+    let mut conn = pool.connection().await.unwrap();
+    conn.protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    for number in [0, 1] {
+        let mut header = create_l1_batch(number);
+        header.system_logs = vec![SystemL2ToL1Log(L2ToL1Log {
+            key: H256::from_low_u64_be(STATE_DIFF_HASH_KEY_PRE_GATEWAY.into()),
+            value: STATE_DIFF_HASH,
+            ..L2ToL1Log::default()
+        })];
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&header)
+            .await
+            .unwrap();
+        let metadata = create_l1_batch_metadata(number);
+        conn.blocks_dal()
+            .save_l1_batch_tree_data(L1BatchNumber(number), &metadata.tree_data())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .save_l1_batch_commitment_artifacts(
+                L1BatchNumber(number),
+                &l1_batch_metadata_to_commitment_artifacts(&metadata),
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut dal = conn.proof_generation_dal();
+    dal.insert_proof_generation_details(L1BatchNumber(1))
+        .await
+        .unwrap();
+    dal.save_vm_runner_artifacts_metadata(L1BatchNumber(1), "vm_run_data")
+        .await
+        .unwrap();
+    dal.save_merkle_paths_artifacts_metadata(L1BatchNumber(1), "merkle_paths")
+        .await
+        .unwrap();
+    let claimed = dal
+        .claim_batch_for_proving(
+            L1BatchNumber(1),
+            &keccak256(api_key.as_bytes()),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+    assert!(claimed);
+}
+
+/// Creates a proof for batch #1 with aggregation coordinates matching the batch.
+fn create_proof() -> L1BatchProofForL1 {
+    let system_logs = [SystemL2ToL1Log(L2ToL1Log {
+        key: H256::from_low_u64_be(STATE_DIFF_HASH_KEY_PRE_GATEWAY.into()),
+        value: STATE_DIFF_HASH,
+        ..L2ToL1Log::default()
+    })];
+    let metadata = create_l1_batch_metadata(1);
+    L1BatchProofForL1 {
+        aggregation_result_coords: [
+            keccak256(&serialize_commitments(&system_logs)),
+            STATE_DIFF_HASH.0,
+            metadata.bootloader_initial_content_commitment.unwrap().0,
+            metadata.events_queue_commitment.unwrap().0,
+        ],
+        scheduler_proof: FinalProof::empty(),
+        protocol_version: ProtocolSemanticVersion {
+            minor: ProtocolVersionId::latest(),
+            patch: 0.into(),
+        },
+    }
+}
+
+async fn submit_marketplace_proof(
+    app: &Router,
+    api_key: &str,
+    proof: L1BatchProofForL1,
+) -> Response {
+    let request = SubmitProofRequest::Proof(Box::new(proof));
+    let body = Body::from(serde_json::to_vec(&request).unwrap());
+    send_marketplace_request(app, "/marketplace/submit_proof/1", Some(api_key), body).await
+}
+
+async fn assert_proof_is_not_saved(blob_store: &dyn ObjectStore, proof: &L1BatchProofForL1) {
+    let saved_proof = blob_store
+        .get::<L1BatchProofForL1>((L1BatchNumber(1), proof.protocol_version))
+        .await;
+    assert_matches!(saved_proof, Err(ObjectStoreError::KeyNotFound(_)));
+}
+
+#[tokio::test]
+async fn marketplace_proofs_are_verified_before_saving() {
+    let pool = ConnectionPool::test_pool().await;
+    prepare_claimed_batch(&pool, MARKETPLACE_API_KEY).await;
+    let blob_store = MockObjectStore::arc();
+    let verifier = Arc::<MockProofVerifier>::default();
+    let app =
+        create_marketplace_router_with_verifier(pool.clone(), blob_store.clone(), verifier.clone());
+
+    // Proof with mismatched aggregation coordinates is rejected before its SNARK is verified.
+    let mut bad_proof = create_proof();
+    bad_proof.aggregation_result_coords[1] = [0xff; 32];
+    verifier.is_valid.store(true, Ordering::SeqCst);
+    let response = submit_marketplace_proof(&app, MARKETPLACE_API_KEY, bad_proof.clone()).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_proof_is_not_saved(&*blob_store, &bad_proof).await;
+
+    // Proof with an invalid SNARK is rejected.
+    let proof = create_proof();
+    verifier.is_valid.store(false, Ordering::SeqCst);
+    let response = submit_marketplace_proof(&app, MARKETPLACE_API_KEY, proof.clone()).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_proof_is_not_saved(&*blob_store, &proof).await;
+
+    // The public input must be computed in the same way as on L1.
+    let commitments = [0, 1].map(|number| create_l1_batch_metadata(number).commitment);
+    let expected_public_input =
+        U256::from_big_endian(keccak256_concat(commitments[0], commitments[1]).as_bytes()) >> 32;
+    assert_eq!(
+        *verifier.verified_public_inputs.lock().unwrap(),
+        [expected_public_input]
+    );
+
+    // Valid proof is saved.
+    verifier.is_valid.store(true, Ordering::SeqCst);
+    let response = submit_marketplace_proof(&app, MARKETPLACE_API_KEY, proof.clone()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    blob_store
+        .get::<L1BatchProofForL1>((L1BatchNumber(1), proof.protocol_version))
+        .await
+        .unwrap();
+    let mut conn = pool.connection().await.unwrap();
+    let latest_proven_batch = conn
+        .proof_generation_dal()
+        .get_latest_proven_batch()
+        .await
+        .unwrap();
+    assert_eq!(latest_proven_batch, L1BatchNumber(1));
+}
+
+#[tokio::test]
+async fn submitting_proof_for_batch_claimed_by_another_prover() {
+    let pool = ConnectionPool::test_pool().await;
+    prepare_claimed_batch(&pool, MARKETPLACE_API_KEY).await;
+    let blob_store = MockObjectStore::arc();
+    let verifier = Arc::<MockProofVerifier>::default();
+    verifier.is_valid.store(true, Ordering::SeqCst);
+    let app =
+        create_marketplace_router_with_verifier(pool.clone(), blob_store.clone(), verifier.clone());
+
+    let proof = create_proof();
+    let response = submit_marketplace_proof(&app, OTHER_MARKETPLACE_API_KEY, proof.clone()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_proof_is_not_saved(&*blob_store, &proof).await;
+    assert!(verifier.verified_public_inputs.lock().unwrap().is_empty());
+
+    // The batch can still be proven by the prover that claimed it.
+    let response = submit_marketplace_proof(&app, MARKETPLACE_API_KEY, proof).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn submitting_proving_stages() {
+    let db_conn_pool = ConnectionPool::test_pool().await;
+    let mut conn = db_conn_pool.connection().await.unwrap();
+    conn.protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    let header = L1BatchHeader::new(
+        L1BatchNumber(1),
+        1,
+        Default::default(),
+        ProtocolVersionId::latest(),
+    );
+    conn.blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+    conn.proof_latency_dal()
+        .record_stage(L1BatchNumber(1), ProvingStage::Queued)
+        .await
+        .unwrap();
+
+    let queued_at = conn
+        .proof_latency_dal()
+        .get_stages(L1BatchNumber(1))
+        .await
+        .unwrap()[0]
+        .1;
+    let request = SubmitProvingStagesRequest {
+        stages: vec![
+            // Must not override the timestamp recorded by the server.
+            ProvingStageTimestamp {
+                stage: ProvingStage::Queued,
+                timestamp: queued_at + chrono::Duration::minutes(1),
+            },
+            ProvingStageTimestamp {
+                stage: ProvingStage::CompressionFinished,
+                timestamp: queued_at + chrono::Duration::minutes(5),
+            },
+        ],
+    };
+
+    let app = create_marketplace_router(db_conn_pool.clone());
+    let body = Body::from(serde_json::to_vec(&request).unwrap());
+    let response = send_marketplace_request(&app, "/submit_proving_stages/1", None, body).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let stages = conn
+        .proof_latency_dal()
+        .get_stages(L1BatchNumber(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        stages,
+        [
+            (ProvingStage::Queued, queued_at),
+            (
+                ProvingStage::CompressionFinished,
+                queued_at + chrono::Duration::minutes(5)
+            ),
+        ]
+    );
+}
+
 // Mock SQL db with information about the status of the TEE proof generation
 async fn mock_tee_batch_status(
     db_conn_pool: ConnectionPool<zksync_dal::Core>,
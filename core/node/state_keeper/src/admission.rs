@@ -0,0 +1,407 @@
+//! Optional admission pipeline pre-executing transactions loaded into the mempool.
+//!
+//! When transactions are loaded from Postgres into the mempool, [`AdmissionDryRun`] executes the next transaction
+//! of each initiator in parallel on top of the latest sealed state (i.e., each transaction is executed on
+//! its own snapshot). Touched storage of executed transactions is compared to estimate conflicts among them:
+//! a transaction is *conflicting* if it reads or writes a storage slot written by another pre-executed transaction,
+//! in which case its dry-run outcome may differ from its actual execution.
+//!
+//! Dry-run results are used in two ways:
+//!
+//! - Independent transactions are passed to [`MempoolIO`](crate::MempoolIO) as [`AdmissionHints`], so that
+//!   the state keeper executes them before other L2 transactions.
+//! - Optionally, independent transactions that have halted are rejected right away, without reaching the mempool.
+//!   (Halted transactions are rejected by the state keeper anyway, so this saves sequential execution time.)
+//!   Since dry-run doesn't account for transactions in the mempool or the pending L2 block, halted transactions
+//!   are only rejected if there are no other pending transactions they may depend on.
+//!
+//! Dry-run never affects correctness: all admitted transactions are still executed sequentially by the state keeper.
+//! If a dry-run fails, the transaction is admitted to the mempool without a hint.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Context as _;
+use futures::{stream, StreamExt};
+use tokio::runtime::Handle;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_multivm::interface::{
+    executor::OneshotExecutor, storage::StorageWithOverrides, ExecutionResult, Halt,
+    OneshotTracingParams, TouchedState, TxExecutionArgs,
+};
+use zksync_state::PostgresStorage;
+use zksync_types::{
+    fee_model::BatchFeeInput, l2::L2Tx, utils::storage_key_for_eth_balance, AccountTreeId, Address,
+    L2ChainId, Nonce, StorageKey, Transaction, TransactionTimeRangeConstraint, H256,
+    SYSTEM_CONTEXT_ADDRESS,
+};
+use zksync_vm_executor::oneshot::{
+    BlockInfo, CallOrExecute, MainOneshotExecutor, MultiVmBaseSystemContracts,
+    OneshotEnvParameters, ResolvedBlockInfo,
+};
+
+use crate::{
+    metrics::{AdmissionOutcome, ADMISSION_METRICS, KEEPER_METRICS},
+    seal_criteria::UnexecutableReason,
+};
+
+/// Maximum number of hints retained by [`AdmissionHints`]. If exceeded, the oldest hints are discarded.
+const MAX_HINTS: usize = 10_000;
+
+/// Ordering hints produced by [`AdmissionDryRun`]: hashes of L2 transactions that are expected to be executed
+/// independently of each other.
+#[derive(Debug, Clone, Default)]
+pub struct AdmissionHints(Arc<Mutex<VecDeque<H256>>>);
+
+impl AdmissionHints {
+    pub(crate) fn extend(&self, hashes: impl IntoIterator<Item = H256>) {
+        let mut hints = self.0.lock().expect("admission hints are poisoned");
+        hints.extend(hashes);
+        let excess = hints.len().saturating_sub(MAX_HINTS);
+        hints.drain(..excess);
+    }
+
+    pub(crate) fn pop(&self) -> Option<H256> {
+        self.0
+            .lock()
+            .expect("admission hints are poisoned")
+            .pop_front()
+    }
+}
+
+/// Result of pre-executing a single transaction.
+#[derive(Debug)]
+struct DryRunResult {
+    tx_hash: H256,
+    gas_used: u64,
+    halt: Option<Halt>,
+    touched_state: TouchedState,
+}
+
+/// Pre-executes transactions loaded into the mempool. See the [module docs](self) for details.
+#[derive(Debug)]
+pub struct AdmissionDryRun {
+    pool: ConnectionPool<Core>,
+    env_params: OneshotEnvParameters<CallOrExecute>,
+    executor: MainOneshotExecutor,
+    fee_account: Address,
+    max_concurrency: usize,
+    reject_halted_txs: bool,
+    hints: AdmissionHints,
+}
+
+impl AdmissionDryRun {
+    /// Creates a new dry-run executor. `pool` should have at least `max_concurrency` connections.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        chain_id: L2ChainId,
+        fee_account: Address,
+        validation_computational_gas_limit: u32,
+        max_concurrency: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            max_concurrency > 0,
+            "admission dry-run concurrency must be positive"
+        );
+        let base_system_contracts =
+            tokio::task::spawn_blocking(MultiVmBaseSystemContracts::load_eth_call_blocking)
+                .await
+                .context("failed loading base contracts for tx execution")?;
+        Ok(Self {
+            pool,
+            env_params: OneshotEnvParameters::new(
+                Arc::new(base_system_contracts),
+                chain_id,
+                AccountTreeId::new(fee_account),
+                validation_computational_gas_limit,
+            ),
+            executor: MainOneshotExecutor::new(usize::MAX),
+            fee_account,
+            max_concurrency,
+            reject_halted_txs: false,
+            hints: AdmissionHints::default(),
+        })
+    }
+
+    /// Makes the dry-run reject independent transactions that have halted.
+    pub fn with_halted_txs_rejection(mut self, reject_halted_txs: bool) -> Self {
+        self.reject_halted_txs = reject_halted_txs;
+        self
+    }
+
+    /// Returns hints produced by this dry-run. Hints should be passed to [`MempoolIO`](crate::MempoolIO).
+    pub fn hints(&self) -> AdmissionHints {
+        self.hints.clone()
+    }
+
+    /// Pre-executes the next transaction of each initiator among `transactions` and removes rejected transactions
+    /// from `transactions`. `nonces` must contain the committed nonce for each initiator.
+    pub(crate) async fn process(
+        &self,
+        transactions: &mut Vec<(Transaction, TransactionTimeRangeConstraint)>,
+        nonces: &HashMap<Address, Nonce>,
+        fee_input: BatchFeeInput,
+    ) -> anyhow::Result<()> {
+        let candidates: Vec<L2Tx> = transactions
+            .iter()
+            .filter(|(tx, _)| nonces.get(&tx.initiator_account()).copied() == tx.nonce())
+            .filter_map(|(tx, _)| L2Tx::try_from(tx.clone()).ok())
+            .collect();
+        if candidates.is_empty() {
+            return Ok(());
+        }
+
+        let latency = ADMISSION_METRICS.dry_run_latency.start();
+        let mut connection = self.pool.connection_tagged("state_keeper").await?;
+        let block_info = BlockInfo::pending(&mut connection).await?;
+        let resolved_block_info = block_info.resolve(&mut connection).await?;
+        drop(connection);
+
+        let candidate_hashes: Vec<_> = candidates.iter().map(L2Tx::hash).collect();
+        let results: Vec<_> = stream::iter(candidates)
+            .map(|tx| self.dry_run(tx, &resolved_block_info, fee_input))
+            .buffered(self.max_concurrency)
+            .collect()
+            .await;
+        latency.observe();
+
+        let dry_run_count = results.len();
+        let results: Vec<_> = results
+            .into_iter()
+            .filter_map(|result| {
+                result
+                    .map_err(|err| {
+                        tracing::warn!("Admission dry-run failed: {err:#}");
+                        ADMISSION_METRICS.transactions[&AdmissionOutcome::Failed].inc();
+                    })
+                    .ok()
+            })
+            .collect();
+        let ignored_keys = HashSet::from([storage_key_for_eth_balance(&self.fee_account)]);
+        let touched_states: Vec<_> = results.iter().map(|res| &res.touched_state).collect();
+        let conflicts = find_conflicts(&touched_states, &ignored_keys);
+
+        // Transactions not pre-executed in this run may affect the outcome of halted transactions (e.g., by funding
+        // the initiator), so halted transactions are only rejected if there are no such transactions.
+        let may_reject_halted_txs = self.reject_halted_txs
+            && results
+                .iter()
+                .zip(&conflicts)
+                .any(|(result, &is_conflicting)| result.halt.is_some() && !is_conflicting)
+            && !self.has_pending_transactions(&candidate_hashes).await?;
+
+        let mut independent_hashes = vec![];
+        let mut rejected = HashMap::new();
+        for (result, is_conflicting) in results.into_iter().zip(conflicts) {
+            ADMISSION_METRICS.gas_used.observe(result.gas_used);
+            let outcome = match (result.halt, is_conflicting) {
+                (Some(reason), false) if may_reject_halted_txs => {
+                    rejected.insert(result.tx_hash, UnexecutableReason::Halt(reason));
+                    AdmissionOutcome::Rejected
+                }
+                (Some(_), _) => AdmissionOutcome::Halted,
+                (None, true) => AdmissionOutcome::Conflicting,
+                (None, false) => {
+                    independent_hashes.push(result.tx_hash);
+                    AdmissionOutcome::Independent
+                }
+            };
+            ADMISSION_METRICS.transactions[&outcome].inc();
+        }
+
+        if !rejected.is_empty() {
+            let mut connection = self.pool.connection_tagged("state_keeper").await?;
+            for (tx_hash, reason) in &rejected {
+                tracing::warn!(
+                    "Transaction {tx_hash:?} is rejected at admission with error: {reason}"
+                );
+                KEEPER_METRICS.inc_rejected_txs(reason.as_metric_label());
+                connection
+                    .transactions_dal()
+                    .mark_tx_as_rejected(*tx_hash, &format!("rejected: {reason}"))
+                    .await?;
+            }
+            transactions.retain(|(tx, _)| !rejected.contains_key(&tx.hash()));
+        }
+
+        tracing::debug!(
+            "Admission dry-run has pre-executed {} transactions: {} independent, {} rejected",
+            dry_run_count,
+            independent_hashes.len(),
+            rejected.len()
+        );
+        self.hints.extend(independent_hashes);
+        Ok(())
+    }
+
+    async fn has_pending_transactions(&self, candidate_hashes: &[H256]) -> anyhow::Result<bool> {
+        let mut connection = self.pool.connection_tagged("state_keeper").await?;
+        let has_pending_txs = connection
+            .transactions_dal()
+            .has_pending_transactions_except(candidate_hashes)
+            .await?;
+        if has_pending_txs {
+            tracing::debug!(
+                "Not rejecting halted transactions at admission since there are pending transactions"
+            );
+        }
+        Ok(has_pending_txs)
+    }
+
+    async fn dry_run(
+        &self,
+        tx: L2Tx,
+        resolved_block_info: &ResolvedBlockInfo,
+        fee_input: BatchFeeInput,
+    ) -> anyhow::Result<DryRunResult> {
+        let tx_hash = tx.hash();
+        let mut connection = self.pool.connection_tagged("state_keeper").await?;
+        let env = self
+            .env_params
+            .to_execute_env(&mut connection, resolved_block_info, fee_input, &tx)
+            .await?;
+        let storage = PostgresStorage::new_async(
+            Handle::current(),
+            connection,
+            resolved_block_info.state_l2_block_number(),
+            false,
+        )
+        .await
+        .context("failed creating Postgres storage")?;
+
+        let tracing_params = OneshotTracingParams {
+            trace_calls: false,
+            trace_state: true,
+        };
+        let output = self
+            .executor
+            .inspect_transaction_with_bytecode_compression(
+                StorageWithOverrides::new(storage),
+                env,
+                TxExecutionArgs::for_validation(tx),
+                tracing_params,
+            )
+            .await
+            .with_context(|| format!("failed pre-executing transaction {tx_hash:?}"))?;
+
+        let halt = match output.tx_result.result {
+            ExecutionResult::Halt { reason } => Some(reason),
+            ExecutionResult::Success { .. }
+            | ExecutionResult::Revert { .. }
+            | ExecutionResult::Interrupted { .. } => None,
+        };
+        Ok(DryRunResult {
+            tx_hash,
+            gas_used: output.tx_result.statistics.gas_used,
+            halt,
+            touched_state: output.touched_state.unwrap_or_default(),
+        })
+    }
+}
+
+/// Returns whether each of the provided touched states conflicts with any other state, i.e., whether it has read
+/// or written a slot modified by another state. Slots in `ignored_keys` (e.g., the fee account balance, which is
+/// modified by all transactions) and system context slots are not considered.
+fn find_conflicts(states: &[&TouchedState], ignored_keys: &HashSet<StorageKey>) -> Vec<bool> {
+    let is_relevant =
+        |key: &StorageKey| *key.address() != SYSTEM_CONTEXT_ADDRESS && !ignored_keys.contains(key);
+    let written_keys: Vec<HashSet<_>> = states
+        .iter()
+        .map(|state| {
+            state
+                .final_values
+                .iter()
+                .filter(|(key, value)| state.initial_values.get(key) != Some(value))
+                .map(|(key, _)| *key)
+                .filter(is_relevant)
+                .collect()
+        })
+        .collect();
+
+    let mut writer_counts = HashMap::<_, usize>::new();
+    for key in written_keys.iter().flatten() {
+        *writer_counts.entry(*key).or_default() += 1;
+    }
+
+    states
+        .iter()
+        .zip(&written_keys)
+        .map(|(state, own_writes)| {
+            state
+                .initial_values
+                .keys()
+                .chain(own_writes)
+                .filter(|&key| is_relevant(key))
+                .any(|key| {
+                    let writer_count = writer_counts.get(key).copied().unwrap_or(0);
+                    let own_write = usize::from(own_writes.contains(key));
+                    writer_count > own_write
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::H160;
+
+    use super::*;
+
+    fn key(address: u8, slot: u8) -> StorageKey {
+        StorageKey::new(
+            AccountTreeId::new(H160::repeat_byte(address)),
+            H256::repeat_byte(slot),
+        )
+    }
+
+    fn touched_state(reads: &[StorageKey], writes: &[StorageKey]) -> TouchedState {
+        let mut state = TouchedState::default();
+        for &key in reads.iter().chain(writes) {
+            state.initial_values.insert(key, H256::zero());
+        }
+        for &key in writes {
+            state.final_values.insert(key, H256::repeat_byte(0xff));
+        }
+        state
+    }
+
+    #[test]
+    fn finding_conflicts() {
+        let ignored_key = key(0xee, 0);
+        let states = [
+            // Writes a slot read by the second state
+            touched_state(&[], &[key(1, 1), ignored_key]),
+            touched_state(&[key(1, 1)], &[key(2, 1), ignored_key]),
+            // Only touches its own slots and the ignored slot
+            touched_state(&[key(3, 0)], &[key(3, 1), ignored_key]),
+            // Writes the same slot as the fifth state
+            touched_state(&[], &[key(4, 1)]),
+            touched_state(&[], &[key(4, 1)]),
+        ];
+        let states: Vec<_> = states.iter().collect();
+        let conflicts = find_conflicts(&states, &HashSet::from([ignored_key]));
+        assert_eq!(conflicts, [true, true, false, true, true]);
+    }
+
+    #[test]
+    fn unmodified_slots_do_not_conflict() {
+        let mut noop_write = touched_state(&[], &[]);
+        noop_write.initial_values.insert(key(1, 1), H256::zero());
+        noop_write.final_values.insert(key(1, 1), H256::zero());
+        let reader = touched_state(&[key(1, 1)], &[]);
+        let system_write = touched_state(
+            &[],
+            &[StorageKey::new(
+                AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+                H256::zero(),
+            )],
+        );
+        let system_write_2 = system_write.clone();
+
+        let states = [&noop_write, &reader, &system_write, &system_write_2];
+        let conflicts = find_conflicts(&states, &HashSet::new());
+        assert_eq!(conflicts, [false; 4]);
+    }
+}
@@ -0,0 +1,377 @@
+//! Experimental API allowing an external block builder to propose transaction ordering for L2 blocks.
+//!
+//! The flow is as follows:
+//!
+//! 1. A builder observes the mempool (e.g., via the node API) and submits a [`BlockProposal`] for the next L2 block
+//!    via an authenticated HTTP endpoint served by [`BlockBuilderApi`]. A proposal is an ordered list of transaction hashes.
+//! 2. When the state keeper opens a new L2 block, it waits for a proposal for this block up to the configured timeout.
+//! 3. Proposed transactions are executed in the proposed order. A proposed transaction is only executed if it's present
+//!    in the mempool and is the next executable transaction of its initiator. If a proposed transaction is invalid,
+//!    the rest of the proposal is discarded, and the state keeper falls back to local ordering for the block.
+//!    Likewise, local ordering is used if no proposal arrives in time, or after the proposal is exhausted.
+//!
+//! Proposals never affect sealing logic; a proposed block may be split across several L2 blocks / L1 batches
+//! if it doesn't fit.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    http::{header, HeaderMap, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use zksync_config::configs::ExternalBlockBuilderConfig;
+use zksync_types::{L2BlockNumber, H256};
+
+use crate::metrics::{BlockProposalOutcome, BLOCK_BUILDER_METRICS};
+
+/// Maximum number of L2 blocks ahead of the currently awaited one for which proposals are accepted.
+const MAX_BLOCKS_AHEAD: u32 = 10;
+
+/// Ordered list of transactions proposed for inclusion into a specific L2 block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockProposal {
+    pub l2_block_number: L2BlockNumber,
+    pub transactions: Vec<H256>,
+}
+
+/// Errors that can occur when submitting a [`BlockProposal`].
+#[derive(Debug, thiserror::Error)]
+pub enum ProposalError {
+    #[error("proposal is for L2 block #{proposed}, but the next L2 block is #{next}")]
+    Outdated {
+        proposed: L2BlockNumber,
+        next: L2BlockNumber,
+    },
+    #[error(
+        "proposal is for L2 block #{proposed}, which is too far ahead of the next L2 block #{next}"
+    )]
+    TooFarAhead {
+        proposed: L2BlockNumber,
+        next: L2BlockNumber,
+    },
+    #[error("proposal contains no transactions")]
+    Empty,
+    #[error("proposal contains {len} transactions, while at most {max} are allowed")]
+    TooLarge { len: usize, max: usize },
+    #[error("transaction {0:?} is proposed more than once")]
+    DuplicateTransaction(H256),
+}
+
+#[derive(Debug, Default)]
+struct ProposalsState {
+    /// Number of the L2 block the state keeper waits for or will wait for next.
+    next_l2_block: L2BlockNumber,
+    proposals: BTreeMap<L2BlockNumber, Vec<H256>>,
+}
+
+/// Shared storage for block proposals connecting [`BlockBuilderApi`] and the state keeper I/O.
+#[derive(Debug, Clone)]
+pub struct BlockProposals {
+    state: Arc<watch::Sender<ProposalsState>>,
+    max_transactions: usize,
+}
+
+impl BlockProposals {
+    pub fn new(max_transactions: usize) -> Self {
+        Self {
+            state: Arc::new(watch::channel(ProposalsState::default()).0),
+            max_transactions,
+        }
+    }
+
+    /// Validates and stores the proposal. A proposal for the same L2 block submitted earlier is overwritten.
+    pub fn submit(&self, proposal: BlockProposal) -> Result<(), ProposalError> {
+        if proposal.transactions.is_empty() {
+            return Err(ProposalError::Empty);
+        }
+        if proposal.transactions.len() > self.max_transactions {
+            return Err(ProposalError::TooLarge {
+                len: proposal.transactions.len(),
+                max: self.max_transactions,
+            });
+        }
+        let mut seen_hashes = HashSet::with_capacity(proposal.transactions.len());
+        if let Some(&duplicate) = proposal
+            .transactions
+            .iter()
+            .find(|&&hash| !seen_hashes.insert(hash))
+        {
+            return Err(ProposalError::DuplicateTransaction(duplicate));
+        }
+
+        let mut result = Ok(());
+        self.state.send_if_modified(|state| {
+            let proposed = proposal.l2_block_number;
+            let next = state.next_l2_block;
+            if proposed < next {
+                result = Err(ProposalError::Outdated { proposed, next });
+                false
+            } else if proposed.0 - next.0 > MAX_BLOCKS_AHEAD {
+                result = Err(ProposalError::TooFarAhead { proposed, next });
+                false
+            } else {
+                state.proposals.insert(proposed, proposal.transactions);
+                true
+            }
+        });
+        result
+    }
+
+    /// Waits for a proposal for the specified L2 block up to the specified timeout. Proposals for earlier blocks
+    /// are discarded, and proposals for this block are no longer accepted once this method returns.
+    pub async fn wait_for_proposal(
+        &self,
+        l2_block: L2BlockNumber,
+        timeout: Duration,
+    ) -> Option<Vec<H256>> {
+        let latency = BLOCK_BUILDER_METRICS.wait_for_proposal.start();
+        self.state.send_modify(|state| {
+            state.next_l2_block = l2_block;
+            state.proposals = state.proposals.split_off(&l2_block);
+        });
+
+        let mut receiver = self.state.subscribe();
+        let wait = async {
+            // The sender is held by `self`, so waiting cannot fail.
+            receiver
+                .wait_for(|state| state.proposals.contains_key(&l2_block))
+                .await
+                .ok();
+        };
+        tokio::time::timeout(timeout, wait).await.ok();
+
+        let mut proposal = None;
+        self.state.send_if_modified(|state| {
+            proposal = state.proposals.remove(&l2_block);
+            state.next_l2_block = l2_block + 1;
+            false
+        });
+        latency.observe();
+
+        let outcome = if proposal.is_some() {
+            BlockProposalOutcome::Received
+        } else {
+            BlockProposalOutcome::TimedOut
+        };
+        BLOCK_BUILDER_METRICS.proposals[&outcome].inc();
+        proposal
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ApiState {
+    proposals: BlockProposals,
+    auth_token: Arc<str>,
+}
+
+impl ApiState {
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        let Some(token) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return false;
+        };
+        // Compare in constant time so that the token cannot be recovered via a timing side channel.
+        let expected = self.auth_token.as_bytes();
+        token.len() == expected.len()
+            && token
+                .bytes()
+                .zip(expected)
+                .fold(0_u8, |acc, (a, &b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+/// HTTP API accepting block proposals from an external builder. Requests must be authenticated
+/// with the configured bearer token.
+#[derive(Debug)]
+pub struct BlockBuilderApi {
+    state: ApiState,
+    port: u16,
+}
+
+impl BlockBuilderApi {
+    pub fn new(proposals: BlockProposals, config: &ExternalBlockBuilderConfig) -> Self {
+        Self {
+            state: ApiState {
+                proposals,
+                auth_token: config.auth_token.as_str().into(),
+            },
+            port: config.http_port,
+        }
+    }
+
+    async fn submit_proposal(
+        State(state): State<ApiState>,
+        headers: HeaderMap,
+        proposal: Result<Json<BlockProposal>, JsonRejection>,
+    ) -> (StatusCode, String) {
+        if !state.is_authorized(&headers) {
+            BLOCK_BUILDER_METRICS.rejected_submissions.inc();
+            return (StatusCode::UNAUTHORIZED, "invalid auth token".to_owned());
+        }
+        let Json(proposal) = match proposal {
+            Ok(proposal) => proposal,
+            Err(err) => {
+                BLOCK_BUILDER_METRICS.rejected_submissions.inc();
+                return (err.status(), err.body_text());
+            }
+        };
+
+        let l2_block_number = proposal.l2_block_number;
+        match state.proposals.submit(proposal) {
+            Ok(()) => {
+                tracing::debug!("Accepted proposal for L2 block #{l2_block_number}");
+                (StatusCode::ACCEPTED, String::new())
+            }
+            Err(err) => {
+                tracing::info!("Rejected proposal for L2 block #{l2_block_number}: {err}");
+                BLOCK_BUILDER_METRICS.rejected_submissions.inc();
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+        }
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let router = Router::new()
+            .route("/proposals", post(Self::submit_proposal))
+            .with_state(self.state);
+
+        let bind_address = SocketAddr::from(([0, 0, 0, 0], self.port));
+        tracing::info!("Starting block builder API server on {bind_address}");
+        let listener = tokio::net::TcpListener::bind(bind_address)
+            .await
+            .with_context(|| {
+                format!("Failed binding block builder API server to {bind_address}")
+            })?;
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                if stop_receiver.changed().await.is_err() {
+                    tracing::warn!("Stop signal sender for block builder API server was dropped without sending a signal");
+                }
+                tracing::info!("Stop signal received, block builder API server is shutting down");
+            })
+            .await
+            .context("Block builder API server failed")?;
+        tracing::info!("Block builder API server shut down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn proposal(number: u32, transactions: Vec<H256>) -> BlockProposal {
+        BlockProposal {
+            l2_block_number: L2BlockNumber(number),
+            transactions,
+        }
+    }
+
+    #[test]
+    fn proposal_validation() {
+        let proposals = BlockProposals::new(2);
+        let err = proposals.submit(proposal(1, vec![])).unwrap_err();
+        assert_matches!(err, ProposalError::Empty);
+        let err = proposals
+            .submit(proposal(1, vec![H256::repeat_byte(1); 3]))
+            .unwrap_err();
+        assert_matches!(err, ProposalError::TooLarge { len: 3, max: 2 });
+        let err = proposals
+            .submit(proposal(1, vec![H256::repeat_byte(1); 2]))
+            .unwrap_err();
+        assert_matches!(err, ProposalError::DuplicateTransaction(hash) if hash == H256::repeat_byte(1));
+        let err = proposals
+            .submit(proposal(MAX_BLOCKS_AHEAD + 1, vec![H256::zero()]))
+            .unwrap_err();
+        assert_matches!(err, ProposalError::TooFarAhead { .. });
+
+        proposals.submit(proposal(1, vec![H256::zero()])).unwrap();
+    }
+
+    #[tokio::test]
+    async fn waiting_for_proposals() {
+        let proposals = BlockProposals::new(10);
+        let early_txs = vec![H256::repeat_byte(1)];
+        let txs = vec![H256::repeat_byte(2), H256::repeat_byte(3)];
+        proposals.submit(proposal(1, early_txs)).unwrap();
+        proposals.submit(proposal(2, txs.clone())).unwrap();
+
+        // The proposal for block #1 should be discarded.
+        let received = proposals.wait_for_proposal(L2BlockNumber(2), TIMEOUT).await;
+        assert_eq!(received, Some(txs));
+        let err = proposals
+            .submit(proposal(2, vec![H256::zero()]))
+            .unwrap_err();
+        assert_matches!(err, ProposalError::Outdated { .. });
+
+        let received = proposals.wait_for_proposal(L2BlockNumber(3), TIMEOUT).await;
+        assert_eq!(received, None);
+    }
+
+    #[tokio::test]
+    async fn proposal_submitted_while_waiting() {
+        let proposals = BlockProposals::new(10);
+        let wait_task = tokio::spawn({
+            let proposals = proposals.clone();
+            async move {
+                proposals
+                    .wait_for_proposal(L2BlockNumber(1), Duration::from_secs(10))
+                    .await
+            }
+        });
+        // Wait until the state keeper starts waiting, so that the proposal isn't considered outdated.
+        let mut receiver = proposals.state.subscribe();
+        receiver
+            .wait_for(|state| state.next_l2_block == L2BlockNumber(1))
+            .await
+            .unwrap();
+
+        proposals
+            .submit(proposal(1, vec![H256::repeat_byte(1)]))
+            .unwrap();
+        let received = wait_task.await.unwrap();
+        assert_eq!(received, Some(vec![H256::repeat_byte(1)]));
+    }
+
+    #[test]
+    fn authorizing_requests() {
+        let state = ApiState {
+            proposals: BlockProposals::new(10),
+            auth_token: "secret".into(),
+        };
+        let mut headers = HeaderMap::new();
+        assert!(!state.is_authorized(&headers));
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("secret"));
+        assert!(!state.is_authorized(&headers));
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer secre"),
+        );
+        assert!(!state.is_authorized(&headers));
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer secret"),
+        );
+        assert!(state.is_authorized(&headers));
+    }
+}
@@ -37,7 +37,12 @@ impl TxExecutionResult {
                 reason: Halt::BootloaderOutOfGas,
             } => Self::BootloaderOutOfGasForTx,
             ExecutionResult::Halt { reason } => Self::RejectedByVm { reason },
-            _ => Self::Success {
+            // The state keeper doesn't set execution limits, but if execution is interrupted nevertheless,
+            // the transaction must not be sealed.
+            ExecutionResult::Interrupted { reason } => Self::RejectedByVm {
+                reason: Halt::TracerCustom(reason.to_string()),
+            },
+            ExecutionResult::Success { .. } | ExecutionResult::Revert { .. } => Self::Success {
                 tx_metrics: Box::new(ExecutionMetricsForCriteria::new(Some(tx), &res.tx_result)),
                 gas_remaining: res.tx_result.statistics.gas_remaining,
                 tx_result: res.tx_result.clone(),
@@ -1,6 +1,6 @@
 use std::{
     cmp,
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -18,18 +18,24 @@ use zksync_types::{
     commitment::{L1BatchCommitmentMode, PubdataParams},
     protocol_upgrade::ProtocolUpgradeTx,
     utils::display_timestamp,
-    Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersionId, Transaction, H256, U256,
+    Address, ExecuteTransactionCommon, L1BatchNumber, L2BlockNumber, L2ChainId, PriorityOpId,
+    ProtocolVersionId, Transaction, TransactionTimeRangeConstraint, H256, U256,
 };
 use zksync_vm_executor::storage::L1BatchParamsProvider;
 
 use crate::{
+    admission::AdmissionHints,
+    block_builder::BlockProposals,
     io::{
         common::{load_pending_batch, poll_iters, IoCursor},
         seal_logic::l2_block_seal_subtasks::L2BlockSealProcess,
         L1BatchParams, L2BlockParams, PendingBatchData, StateKeeperIO,
     },
-    mempool_actor::l2_tx_filter,
-    metrics::{L2BlockSealReason, AGGREGATION_METRICS, KEEPER_METRICS},
+    mempool_actor::l2_tx_filter_from_params,
+    metrics::{
+        BlockProposalOutcome, L2BlockSealReason, AGGREGATION_METRICS, BLOCK_BUILDER_METRICS,
+        KEEPER_METRICS,
+    },
     seal_criteria::{
         IoSealCriteria, L2BlockMaxPayloadSizeSealer, TimeoutSealer, UnexecutableReason,
     },
@@ -53,16 +59,32 @@ pub struct MempoolIO {
     fee_account: Address,
     validation_computational_gas_limit: u32,
     max_allowed_tx_gas_limit: U256,
+    max_l2_tx_size_bytes: Option<usize>,
+    max_l2_tx_calldata_bytes: Option<usize>,
     delay_interval: Duration,
     // Used to keep track of gas prices to set accepted price per pubdata byte in blocks.
     batch_fee_input_provider: Arc<dyn BatchFeeModelInputProvider>,
     chain_id: L2ChainId,
     l2_da_validator_address: Option<Address>,
     pubdata_type: L1BatchCommitmentMode,
+    /// Proposals from an external block builder together with the timeout to wait for a proposal.
+    block_proposals: Option<(BlockProposals, Duration)>,
+    /// Remaining transactions proposed for the current L2 block.
+    proposed_txs: VecDeque<H256>,
+    /// Ordering hints produced by the admission dry-run.
+    admission_hints: Option<AdmissionHints>,
+    /// Inclusion deadline for priority operations in L1 batches.
+    forced_inclusion_deadline_batches: Option<u32>,
+    /// Last priority operation for which the current L1 batch is the inclusion deadline.
+    forced_inclusion_due: Option<PriorityOpId>,
 }
 
 impl IoSealCriteria for MempoolIO {
     fn should_seal_l1_batch_unconditionally(&mut self, manager: &UpdatesManager) -> bool {
+        // The batch is held open until all priority operations due in it are executed.
+        if self.has_due_priority_ops() {
+            return false;
+        }
         self.timeout_sealer
             .should_seal_l1_batch_unconditionally(manager)
     }
@@ -144,6 +166,9 @@ impl StateKeeperIO for MempoolIO {
                     .into_unsealed_header(Some(pending_batch_data.system_env.version)),
             )
             .await?;
+        drop(storage);
+        self.check_forced_inclusion_deadline(cursor.l1_batch)
+            .await?;
 
         Ok((cursor, Some(pending_batch_data)))
     }
@@ -165,6 +190,9 @@ impl StateKeeperIO for MempoolIO {
             let protocol_version = unsealed_storage_batch
                 .protocol_version
                 .context("unsealed batch is missing protocol version")?;
+            self.check_forced_inclusion_deadline(cursor.l1_batch)
+                .await?;
+            self.load_block_proposal(cursor.next_l2_block).await;
             return Ok(Some(L1BatchParams {
                 protocol_version,
                 validation_computational_gas_limit: self.validation_computational_gas_limit,
@@ -210,21 +238,20 @@ impl StateKeeperIO for MempoolIO {
 
             // We create a new filter each time, since parameters may change and a previously
             // ignored transaction in the mempool may be scheduled for the execution.
-            self.filter = l2_tx_filter(
-                self.batch_fee_input_provider.as_ref(),
-                protocol_version.into(),
-            )
-            .await
-            .context("failed creating L2 transaction filter")?;
+            // The filter is derived from the same fee params snapshot that is persisted for the batch,
+            // so that the batch fee input can be reproduced from the persisted params.
+            let fee_params = self.batch_fee_input_provider.get_fee_model_params();
+            self.filter = l2_tx_filter_from_params(fee_params, protocol_version.into());
 
             if !self.mempool.has_next(&self.filter) {
                 tokio::time::sleep(self.delay_interval).await;
                 continue;
             }
 
-            self.pool
-                .connection_tagged("state_keeper")
-                .await?
+            // Fee params are persisted to allow reproducing the batch fee input via the API.
+            let mut storage = self.pool.connection_tagged("state_keeper").await?;
+            let mut transaction = storage.start_transaction().await?;
+            transaction
                 .blocks_dal()
                 .insert_l1_batch(UnsealedL1BatchHeader {
                     number: cursor.l1_batch,
@@ -234,7 +261,16 @@ impl StateKeeperIO for MempoolIO {
                     fee_input: self.filter.fee_input,
                 })
                 .await?;
+            transaction
+                .blocks_dal()
+                .save_l1_batch_fee_params(cursor.l1_batch, &fee_params)
+                .await?;
+            transaction.commit().await?;
+            drop(storage);
 
+            self.check_forced_inclusion_deadline(cursor.l1_batch)
+                .await?;
+            self.load_block_proposal(cursor.next_l2_block).await;
             return Ok(Some(L1BatchParams {
                 protocol_version,
                 validation_computational_gas_limit: self.validation_computational_gas_limit,
@@ -267,6 +303,7 @@ impl StateKeeperIO for MempoolIO {
             return Ok(None);
         };
 
+        self.load_block_proposal(cursor.next_l2_block).await;
         Ok(Some(L2BlockParams {
             timestamp,
             // This value is effectively ignored by the protocol.
@@ -282,7 +319,18 @@ impl StateKeeperIO for MempoolIO {
         let started_at = Instant::now();
         while started_at.elapsed() <= max_wait {
             let get_latency = KEEPER_METRICS.get_tx_from_mempool.start();
-            let maybe_tx = self.mempool.next_transaction(&self.filter);
+            let maybe_tx = if self.has_due_priority_ops() {
+                // Only priority operations are executed until all operations due in this batch are executed,
+                // even if they are not loaded into the mempool yet.
+                self.mempool.next_l1_transaction()
+            } else {
+                match self.next_proposed_transaction() {
+                    Some(tx) => Some(tx),
+                    None => self
+                        .next_hinted_transaction()
+                        .or_else(|| self.mempool.next_transaction(&self.filter)),
+                }
+            };
             get_latency.observe();
 
             if let Some((tx, constraint)) = maybe_tx {
@@ -298,6 +346,15 @@ impl StateKeeperIO for MempoolIO {
                         .await?;
                     continue;
                 }
+                // Same for transactions exceeding size limits.
+                if let Some(reason) = self.check_tx_size(&tx) {
+                    tracing::warn!(
+                        "Found tx exceeding size limits in state keeper, hash: {:?}: {reason}",
+                        tx.hash()
+                    );
+                    self.reject(&tx, reason).await?;
+                    continue;
+                }
 
                 // Reject transactions that violate block.timestamp constraints. Such transactions should be
                 // rejected at the API level, but we need to protect ourselves in case if a transaction
@@ -494,14 +551,153 @@ impl MempoolIO {
             fee_account,
             validation_computational_gas_limit: config.validation_computational_gas_limit,
             max_allowed_tx_gas_limit: config.max_allowed_l2_tx_gas_limit.into(),
+            max_l2_tx_size_bytes: config.max_l2_tx_size_bytes,
+            max_l2_tx_calldata_bytes: config.max_l2_tx_calldata_bytes,
             delay_interval,
             batch_fee_input_provider,
             chain_id,
             l2_da_validator_address,
             pubdata_type,
+            block_proposals: None,
+            proposed_txs: VecDeque::new(),
+            admission_hints: None,
+            forced_inclusion_deadline_batches: config.forced_inclusion_deadline_batches,
+            forced_inclusion_due: None,
         })
     }
 
+    fn check_tx_size(&self, tx: &Transaction) -> Option<UnexecutableReason> {
+        let ExecuteTransactionCommon::L2(common_data) = &tx.common_data else {
+            // L1 and upgrade transactions cannot be rejected.
+            return None;
+        };
+        let tx_size = common_data.input_data().map_or(0, <[u8]>::len);
+        if self
+            .max_l2_tx_size_bytes
+            .is_some_and(|max_size| tx_size > max_size)
+        {
+            return Some(UnexecutableReason::TxSizeLimit);
+        }
+        if self
+            .max_l2_tx_calldata_bytes
+            .is_some_and(|max_size| tx.execute.calldata.len() > max_size)
+        {
+            return Some(UnexecutableReason::CalldataSizeLimit);
+        }
+        None
+    }
+
+    /// Makes this IO execute transactions in the order proposed by an external block builder. The IO will wait
+    /// for a proposal up to `timeout` for each L2 block, falling back to local ordering afterward.
+    pub fn with_block_proposals(mut self, proposals: BlockProposals, timeout: Duration) -> Self {
+        self.block_proposals = Some((proposals, timeout));
+        self
+    }
+
+    /// Makes this IO execute transactions deemed independent by the admission dry-run before other L2 transactions.
+    pub fn with_admission_hints(mut self, hints: AdmissionHints) -> Self {
+        self.admission_hints = Some(hints);
+        self
+    }
+
+    /// Checks whether the new L1 batch is the inclusion deadline for any of the pending priority operations. If it is,
+    /// only L1 transactions are executed, and the batch is not sealed by timeout until all due operations are executed.
+    async fn check_forced_inclusion_deadline(
+        &mut self,
+        l1_batch: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        self.forced_inclusion_due = None;
+        let Some(deadline_batches) = self.forced_inclusion_deadline_batches else {
+            return Ok(());
+        };
+
+        let mut storage = self.pool.connection_tagged("state_keeper").await?;
+        let due_ops = storage
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(l1_batch, deadline_batches.saturating_sub(1))
+            .await?;
+        let overdue_ops = storage
+            .transactions_dal()
+            .get_priority_ops_past_inclusion_deadline(l1_batch, deadline_batches)
+            .await?;
+        drop(storage);
+
+        KEEPER_METRICS.overdue_priority_ops.set(overdue_ops.len());
+        if let Some((first_id, first_hash)) = overdue_ops.first() {
+            tracing::warn!(
+                "{} priority operations, starting from #{first_id} ({first_hash:?}), have missed the inclusion \
+                 deadline of {deadline_batches} L1 batches",
+                overdue_ops.len()
+            );
+        }
+        if let Some(&(last_id, _)) = due_ops.last() {
+            tracing::info!(
+                "L1 batch #{l1_batch} is the inclusion deadline for {} priority operations up to #{last_id}; \
+                 executing L1 transactions only until they are included",
+                due_ops.len()
+            );
+            self.forced_inclusion_due = Some(last_id);
+        }
+        Ok(())
+    }
+
+    /// Returns whether some priority operations due in the current L1 batch are not executed yet.
+    fn has_due_priority_ops(&self) -> bool {
+        self.forced_inclusion_due
+            .is_some_and(|last_id| self.mempool.next_priority_id() <= last_id)
+    }
+
+    async fn load_block_proposal(&mut self, l2_block: L2BlockNumber) {
+        // Proposed transactions not executed in the previous L2 block are discarded.
+        self.proposed_txs.clear();
+        let Some((proposals, timeout)) = &self.block_proposals else {
+            return;
+        };
+        if let Some(transactions) = proposals.wait_for_proposal(l2_block, *timeout).await {
+            tracing::debug!(
+                "Using proposal with {} transactions for L2 block #{l2_block}",
+                transactions.len()
+            );
+            self.proposed_txs = transactions.into();
+        }
+    }
+
+    /// Returns the next proposed transaction, if any. L1 transactions are not proposed, so they are executed
+    /// once the proposal is exhausted.
+    fn next_proposed_transaction(
+        &mut self,
+    ) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        let hash = self.proposed_txs.pop_front()?;
+        let tx = self.mempool.next_transaction_with_hash(hash, &self.filter);
+        if tx.is_none() {
+            tracing::warn!(
+                "Proposed transaction {hash:?} is not executable; falling back to local ordering for the remaining \
+                 {} proposed transactions",
+                self.proposed_txs.len()
+            );
+            BLOCK_BUILDER_METRICS.proposals[&BlockProposalOutcome::Invalid].inc();
+            self.proposed_txs.clear();
+        }
+        tx
+    }
+
+    /// Returns the next hinted transaction, if any. Hints are ignored while there are pending L1 transactions,
+    /// so that priority operations are not delayed.
+    fn next_hinted_transaction(&mut self) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        let hints = self.admission_hints.as_ref()?;
+        if self.mempool.has_l1_transactions() {
+            return None;
+        }
+        // Hinted transactions may have been already executed or replaced; such hints are skipped.
+        while let Some(hash) = hints.pop() {
+            let tx = self.mempool.next_transaction_with_hash(hash, &self.filter);
+            if tx.is_some() {
+                return tx;
+            }
+        }
+        None
+    }
+
     fn pubdata_params(&self, protocol_version: ProtocolVersionId) -> anyhow::Result<PubdataParams> {
         let pubdata_params = match (
             protocol_version.is_pre_gateway(),
@@ -10,7 +10,7 @@ use anyhow::Context as _;
 use itertools::Itertools;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_multivm::{
-    interface::{DeduplicatedWritesMetrics, TransactionExecutionResult, VmEvent},
+    interface::{DeduplicatedWritesMetrics, FinishedL1Batch, TransactionExecutionResult, VmEvent},
     utils::{
         get_max_batch_gas_limit, get_max_gas_per_pubdata_byte, ModifiedSlot,
         StorageWritesDeduplicator,
@@ -18,9 +18,10 @@ use zksync_multivm::{
 };
 use zksync_shared_metrics::{BlockStage, L2BlockStage, APP_METRICS};
 use zksync_types::{
+    api::{L1BatchExecutionStats, PubdataComposition},
     block::{build_bloom, L1BatchHeader, L2BlockHeader},
     helpers::unix_timestamp_ms,
-    l2_to_l1_log::UserL2ToL1Log,
+    l2_to_l1_log::{L2ToL1Log, UserL2ToL1Log},
     tx::IncludedTxLocation,
     u256_to_h256,
     utils::display_timestamp,
@@ -151,6 +152,13 @@ impl UpdatesManager {
                 self.pending_execution_metrics().circuit_statistic,
             )
             .await?;
+        transaction
+            .blocks_dal()
+            .save_l1_batch_execution_stats(
+                self.l1_batch.number,
+                &self.l1_batch_execution_stats(finished_batch),
+            )
+            .await?;
         progress.observe(None);
 
         let progress = L1_BATCH_METRICS.start(L1BatchSealStage::SetL1BatchNumberForL2Blocks);
@@ -277,6 +285,33 @@ impl UpdatesManager {
         L1_BATCH_METRICS.sealed_time.observe(elapsed);
         tracing::debug!("Sealed L1 batch {} in {elapsed:?}", self.l1_batch.number);
     }
+
+    fn l1_batch_execution_stats(&self, finished_batch: &FinishedL1Batch) -> L1BatchExecutionStats {
+        let metrics = self.pending_execution_metrics();
+        let l2_to_l1_logs = (metrics.l2_to_l1_logs * L2ToL1Log::SERIALIZED_SIZE) as u64;
+        let l2_to_l1_messages = metrics.l2_l1_long_messages as u64;
+        let published_bytecodes = metrics.published_bytecode_bytes as u64;
+        let total_pubdata = u64::from(metrics.pubdata_published);
+        let state_diffs = finished_batch.state_diffs.as_ref();
+
+        L1BatchExecutionStats {
+            gas_used: metrics.gas_used as u64,
+            computational_gas_used: metrics.computational_gas_used.into(),
+            events_count: finished_batch.final_execution_state.events.len() as u64,
+            initial_writes_count: state_diffs
+                .map(|diffs| diffs.iter().filter(|diff| diff.is_write_initial()).count() as u64),
+            repeated_writes_count: state_diffs
+                .map(|diffs| diffs.iter().filter(|diff| !diff.is_write_initial()).count() as u64),
+            pubdata: PubdataComposition {
+                total: total_pubdata,
+                l2_to_l1_logs,
+                l2_to_l1_messages,
+                published_bytecodes,
+                state_diffs: total_pubdata
+                    .saturating_sub(l2_to_l1_logs + l2_to_l1_messages + published_bytecodes),
+            },
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -1,6 +1,7 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use test_casing::test_casing;
+use zksync_config::configs::chain::StateKeeperConfig;
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_mempool::L2TxFilter;
@@ -12,22 +13,30 @@ use zksync_multivm::{
 };
 use zksync_node_test_utils::prepare_recovery_snapshot;
 use zksync_system_constants::KNOWN_CODES_STORAGE_ADDRESS;
+use zksync_test_contracts::Account;
 use zksync_types::{
     block::{BlockGasCount, L2BlockHasher},
     bytecode::BytecodeHash,
     commitment::{L1BatchCommitmentMode, PubdataParams},
     fee_model::{BatchFeeInput, PubdataIndependentBatchFeeModelInput},
+    l1::L1Tx,
     l2::L2Tx,
-    AccountTreeId, Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersion,
-    ProtocolVersionId, StorageKey, TransactionTimeRangeConstraint, H256, U256,
+    AccountTreeId, Address, Execute, L1BatchNumber, L1BlockNumber, L2BlockNumber, L2ChainId,
+    ProtocolVersion, ProtocolVersionId, StorageKey, TransactionTimeRangeConstraint, H256, U256,
 };
 
 use self::tester::Tester;
 use crate::{
+    admission::AdmissionHints,
+    block_builder::{BlockProposal, BlockProposals},
     io::{seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, StateKeeperIO},
     mempool_actor::l2_tx_filter,
+    seal_criteria::IoSealCriteria,
     testonly::BASE_SYSTEM_CONTRACTS,
-    tests::{create_execution_result, create_transaction, seconds_since_epoch, Query},
+    tests::{
+        create_execution_result, create_transaction, create_updates_manager, seconds_since_epoch,
+        Query,
+    },
     updates::{L2BlockSealCommand, L2BlockUpdates, UpdatesManager},
     StateKeeperOutputHandler, StateKeeperPersistence,
 };
@@ -853,6 +862,275 @@ async fn test_mempool_with_timestamp_assertion() {
     );
 }
 
+#[tokio::test]
+async fn executing_transactions_in_proposed_order() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+
+    let proposals = BlockProposals::new(10);
+    let (mempool, mut guard) = tester.create_test_mempool_io(connection_pool).await;
+    let mut mempool = mempool.with_block_proposals(proposals.clone(), Duration::from_secs(10));
+    let (io_cursor, _) = mempool.initialize().await.unwrap();
+
+    let txs: Vec<_> = (0..3)
+        .map(|_| tester.insert_tx(&mut guard, 1, 1, TransactionTimeRangeConstraint::default()))
+        .collect();
+    proposals
+        .submit(BlockProposal {
+            l2_block_number: io_cursor.next_l2_block,
+            transactions: vec![txs[2].hash(), H256::repeat_byte(1), txs[0].hash()],
+        })
+        .unwrap();
+
+    mempool
+        .wait_for_new_l2_block_params(&io_cursor, Duration::from_secs(10))
+        .await
+        .unwrap()
+        .expect("no new L2 block params");
+
+    let tx = mempool
+        .wait_for_next_tx(Duration::from_secs(2), 0)
+        .await
+        .unwrap()
+        .expect("no proposed transaction");
+    assert_eq!(tx.hash(), txs[2].hash());
+    // The second proposed transaction is unknown, so the IO should fall back to local ordering.
+    let mut remaining_hashes = vec![];
+    for _ in 0..2 {
+        let tx = mempool
+            .wait_for_next_tx(Duration::from_secs(2), 0)
+            .await
+            .unwrap()
+            .expect("no transaction");
+        remaining_hashes.push(tx.hash());
+    }
+    remaining_hashes.sort_unstable();
+    let mut expected_hashes = vec![txs[0].hash(), txs[1].hash()];
+    expected_hashes.sort_unstable();
+    assert_eq!(remaining_hashes, expected_hashes);
+}
+
+#[tokio::test]
+async fn executing_hinted_transactions_first() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+
+    let hints = AdmissionHints::default();
+    let (mempool, mut guard) = tester.create_test_mempool_io(connection_pool).await;
+    let mut mempool = mempool.with_admission_hints(hints.clone());
+    let (io_cursor, _) = mempool.initialize().await.unwrap();
+
+    let txs: Vec<_> = (0..3)
+        .map(|_| tester.insert_tx(&mut guard, 1, 1, TransactionTimeRangeConstraint::default()))
+        .collect();
+    hints.extend([txs[2].hash(), H256::repeat_byte(1), txs[1].hash()]);
+
+    mempool
+        .wait_for_new_l2_block_params(&io_cursor, Duration::from_secs(10))
+        .await
+        .unwrap()
+        .expect("no new L2 block params");
+
+    // The unknown hinted transaction should be skipped, and the non-hinted transaction should be executed last.
+    for expected_tx in [&txs[2], &txs[1], &txs[0]] {
+        let tx = mempool
+            .wait_for_next_tx(Duration::from_secs(2), 0)
+            .await
+            .unwrap()
+            .expect("no transaction");
+        assert_eq!(tx.hash(), expected_tx.hash());
+    }
+    assert_eq!(hints.pop(), None);
+}
+
+#[tokio::test]
+async fn executing_priority_ops_first_on_inclusion_deadline() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+
+    let config = StateKeeperConfig {
+        forced_inclusion_deadline_batches: Some(1),
+        ..StateKeeperConfig::for_tests()
+    };
+    let proposals = BlockProposals::new(10);
+    let (mempool, mut guard) = tester
+        .create_test_mempool_io_with_config(connection_pool.clone(), config)
+        .await;
+    let mut mempool = mempool.with_block_proposals(proposals.clone(), Duration::from_secs(10));
+    let (io_cursor, _) = mempool.initialize().await.unwrap();
+
+    let l2_tx = tester.insert_tx(&mut guard, 1, 1, TransactionTimeRangeConstraint::default());
+    let l1_tx = Account::random().get_l1_tx(Execute::transfer(Address::random(), 0.into()), 0);
+    connection_pool
+        .connection()
+        .await
+        .unwrap()
+        .transactions_dal()
+        .insert_transaction_l1(&L1Tx::try_from(l1_tx.clone()).unwrap(), L1BlockNumber(1), 0)
+        .await
+        .unwrap();
+    guard.insert(
+        vec![(l1_tx.clone(), TransactionTimeRangeConstraint::default())],
+        Default::default(),
+    );
+    proposals
+        .submit(BlockProposal {
+            l2_block_number: io_cursor.next_l2_block,
+            transactions: vec![l2_tx.hash()],
+        })
+        .unwrap();
+
+    mempool
+        .wait_for_new_batch_params(&io_cursor, Duration::from_secs(10))
+        .await
+        .unwrap()
+        .expect("no new L1 batch params");
+
+    // The priority operation must be included into the new L1 batch, so it should be executed before the proposal.
+    for expected_hash in [l1_tx.hash(), l2_tx.hash()] {
+        let tx = mempool
+            .wait_for_next_tx(Duration::from_secs(2), 0)
+            .await
+            .unwrap()
+            .expect("no transaction");
+        assert_eq!(tx.hash(), expected_hash);
+    }
+}
+
+#[tokio::test]
+async fn holding_l1_batch_until_due_priority_ops_are_executed() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+
+    let config = StateKeeperConfig {
+        forced_inclusion_deadline_batches: Some(1),
+        block_commit_deadline_ms: 0,
+        ..StateKeeperConfig::for_tests()
+    };
+    let (mut mempool, mut guard) = tester
+        .create_test_mempool_io_with_config(connection_pool.clone(), config)
+        .await;
+    let (io_cursor, _) = mempool.initialize().await.unwrap();
+
+    let l2_tx = tester.insert_tx(&mut guard, 1, 1, TransactionTimeRangeConstraint::default());
+    // The priority operation is persisted, but is not loaded into the mempool yet.
+    let l1_tx = Account::random().get_l1_tx(Execute::transfer(Address::random(), 0.into()), 0);
+    connection_pool
+        .connection()
+        .await
+        .unwrap()
+        .transactions_dal()
+        .insert_transaction_l1(&L1Tx::try_from(l1_tx.clone()).unwrap(), L1BlockNumber(1), 0)
+        .await
+        .unwrap();
+
+    mempool
+        .wait_for_new_batch_params(&io_cursor, Duration::from_secs(10))
+        .await
+        .unwrap()
+        .expect("no new L1 batch params");
+
+    let tx = mempool
+        .wait_for_next_tx(Duration::from_millis(100), 0)
+        .await
+        .unwrap();
+    assert!(tx.is_none(), "{tx:?}");
+    // The batch must not be sealed by timeout before the due operation is executed.
+    let mut updates = create_updates_manager();
+    updates.extend_from_executed_transaction(
+        l2_tx.clone().into(),
+        create_execution_result([]),
+        vec![],
+        BlockGasCount::default(),
+        VmExecutionMetrics::default(),
+        vec![],
+    );
+    assert!(!mempool.should_seal_l1_batch_unconditionally(&updates));
+
+    guard.insert(
+        vec![(l1_tx.clone(), TransactionTimeRangeConstraint::default())],
+        Default::default(),
+    );
+    for expected_hash in [l1_tx.hash(), l2_tx.hash()] {
+        let tx = mempool
+            .wait_for_next_tx(Duration::from_secs(2), 0)
+            .await
+            .unwrap()
+            .expect("no transaction");
+        assert_eq!(tx.hash(), expected_hash);
+    }
+    assert!(mempool.should_seal_l1_batch_unconditionally(&updates));
+}
+
+#[tokio::test]
+async fn rejecting_transactions_exceeding_size_limits() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&connection_pool).await;
+
+    let config = StateKeeperConfig {
+        max_l2_tx_size_bytes: Some(64),
+        max_l2_tx_calldata_bytes: Some(16),
+        ..StateKeeperConfig::for_tests()
+    };
+    let (mut mempool, mut guard) = tester
+        .create_test_mempool_io_with_config(connection_pool.clone(), config)
+        .await;
+    mempool.initialize().await.unwrap();
+
+    // Inserting 3 transactions: one exceeding the size limit, one exceeding the calldata limit
+    // and a good one. Only the good one should be returned by `wait_for_next_tx`.
+    let mut oversized_tx = create_l2_transaction(1, 1);
+    oversized_tx.set_input(vec![1; 65], H256::random());
+    let mut oversized_calldata_tx = create_l2_transaction(1, 1);
+    oversized_calldata_tx.execute.calldata = vec![1; 17];
+    let expected_tx = create_l2_transaction(1, 1);
+
+    let mut storage = connection_pool.connection().await.unwrap();
+    for tx in [&oversized_tx, &oversized_calldata_tx, &expected_tx] {
+        insert_l2_transaction(&mut storage, tx).await;
+        guard.insert(
+            vec![(tx.clone().into(), TransactionTimeRangeConstraint::default())],
+            Default::default(),
+        );
+    }
+
+    let tx = mempool
+        .wait_for_next_tx(Duration::from_secs(2), 0)
+        .await
+        .unwrap()
+        .expect("No expected transaction in the mempool");
+    assert_eq!(tx.hash(), expected_tx.hash());
+    let next_tx = mempool
+        .wait_for_next_tx(Duration::from_secs(2), 0)
+        .await
+        .unwrap();
+    assert!(next_tx.is_none());
+
+    for (tx, expected_error) in [
+        (
+            &oversized_tx,
+            "rejected: Transaction size exceeds the configured limit",
+        ),
+        (
+            &oversized_calldata_tx,
+            "rejected: Transaction calldata size exceeds the configured limit",
+        ),
+    ] {
+        let storage_tx = storage
+            .transactions_dal()
+            .get_storage_tx_by_hash(tx.hash())
+            .await
+            .unwrap()
+            .expect("Failed to find transaction");
+        assert_eq!(storage_tx.error.unwrap(), expected_error);
+    }
+}
+
 async fn insert_l2_transaction(storage: &mut Connection<'_, Core>, tx: &L2Tx) {
     storage
         .transactions_dal()
@@ -83,6 +83,7 @@ impl Tester {
             internal_pubdata_pricing_multiplier: 1.0,
             max_blob_base_fee: None,
             settlement_mode: Default::default(),
+            l1_fee_market: Default::default(),
         };
 
         GasAdjuster::new(
@@ -120,6 +121,16 @@ impl Tester {
     pub(super) async fn create_test_mempool_io(
         &self,
         pool: ConnectionPool<Core>,
+    ) -> (MempoolIO, MempoolGuard) {
+        self.create_test_mempool_io_with_config(pool, StateKeeperConfig::for_tests())
+            .await
+    }
+
+    /// Creates an IO with the specified config. Fee-related params in the config are overridden.
+    pub(super) async fn create_test_mempool_io_with_config(
+        &self,
+        pool: ConnectionPool<Core>,
+        config: StateKeeperConfig,
     ) -> (MempoolIO, MempoolGuard) {
         let gas_adjuster = Arc::new(self.create_gas_adjuster().await);
         let batch_fee_input_provider = MainNodeFeeInputProvider::new(
@@ -139,7 +150,7 @@ impl Tester {
         let config = StateKeeperConfig {
             minimal_l2_gas_price: self.minimal_l2_gas_price(),
             validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
-            ..StateKeeperConfig::for_tests()
+            ..config
         };
         let wallets = Wallets::for_tests();
         let io = MempoolIO::new(
@@ -1,4 +1,5 @@
 pub use self::{
+    admission::{AdmissionDryRun, AdmissionHints},
     io::{
         mempool::MempoolIO, L2BlockParams, L2BlockSealerTask, OutputHandler, StateKeeperIO,
         StateKeeperOutputHandler, StateKeeperPersistence, TreeWritesPersistence,
@@ -10,7 +11,10 @@ pub use self::{
     types::{ExecutionMetricsForCriteria, MempoolGuard},
     updates::UpdatesManager,
 };
+pub use zksync_mempool::ordering;
 
+mod admission;
+pub mod block_builder;
 pub mod executor;
 pub mod io;
 mod keeper;
@@ -11,9 +11,14 @@ use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
 use zksync_node_fee_model::BatchFeeModelInputProvider;
 #[cfg(test)]
 use zksync_types::H256;
-use zksync_types::{get_nonce_key, vm::VmVersion, Address, Nonce, Transaction};
+use zksync_types::{
+    fee_model::{BatchFeeInput, FeeParams},
+    get_nonce_key,
+    vm::VmVersion,
+    Address, Nonce, Transaction,
+};
 
-use super::{metrics::KEEPER_METRICS, types::MempoolGuard};
+use super::{admission::AdmissionDryRun, metrics::KEEPER_METRICS, types::MempoolGuard};
 
 /// Creates a mempool filter for L2 transactions based on the current L1 gas price.
 /// The filter is used to filter out transactions from the mempool that do not cover expenses
@@ -23,12 +28,22 @@ pub async fn l2_tx_filter(
     vm_version: VmVersion,
 ) -> anyhow::Result<L2TxFilter> {
     let fee_input = batch_fee_input_provider.get_batch_fee_input().await?;
+    Ok(l2_tx_filter_from_fee_input(fee_input, vm_version))
+}
+
+/// Same as [`l2_tx_filter()`], but derives the filter from the provided fee model params. Useful if the params
+/// need to be consistent with the filter, e.g. to persist them.
+pub fn l2_tx_filter_from_params(fee_params: FeeParams, vm_version: VmVersion) -> L2TxFilter {
+    l2_tx_filter_from_fee_input(fee_params.scale(1.0, 1.0), vm_version)
+}
+
+fn l2_tx_filter_from_fee_input(fee_input: BatchFeeInput, vm_version: VmVersion) -> L2TxFilter {
     let (base_fee, gas_per_pubdata) = derive_base_fee_and_gas_per_pubdata(fee_input, vm_version);
-    Ok(L2TxFilter {
+    L2TxFilter {
         fee_input,
         fee_per_gas: base_fee,
         gas_per_pubdata: gas_per_pubdata as u32,
-    })
+    }
 }
 
 #[derive(Debug)]
@@ -39,6 +54,7 @@ pub struct MempoolFetcher {
     sync_interval: Duration,
     sync_batch_size: usize,
     stuck_tx_timeout: Option<Duration>,
+    admission_dry_run: Option<AdmissionDryRun>,
     #[cfg(test)]
     transaction_hashes_sender: mpsc::UnboundedSender<Vec<H256>>,
 }
@@ -57,11 +73,18 @@ impl MempoolFetcher {
             sync_interval: config.sync_interval(),
             sync_batch_size: config.sync_batch_size,
             stuck_tx_timeout: config.remove_stuck_txs.then(|| config.stuck_tx_timeout()),
+            admission_dry_run: None,
             #[cfg(test)]
             transaction_hashes_sender: mpsc::unbounded_channel().0,
         }
     }
 
+    /// Makes this fetcher pre-execute loaded transactions before inserting them into the mempool.
+    pub fn with_admission_dry_run(mut self, dry_run: AdmissionDryRun) -> Self {
+        self.admission_dry_run = Some(dry_run);
+        self
+    }
+
     pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let mut storage = self.pool.connection_tagged("state_keeper").await?;
         if let Some(stuck_tx_timeout) = self.stuck_tx_timeout {
@@ -89,7 +112,7 @@ impl MempoolFetcher {
                 .await
                 .context("failed getting pending protocol version")?;
 
-            let (fee_per_gas, gas_per_pubdata) = if let Some(unsealed_batch) = storage
+            let (fee_input, fee_per_gas, gas_per_pubdata) = if let Some(unsealed_batch) = storage
                 .blocks_dal()
                 .get_unsealed_l1_batch()
                 .await
@@ -99,7 +122,11 @@ impl MempoolFetcher {
                     unsealed_batch.fee_input,
                     protocol_version.into(),
                 );
-                (fee_per_gas, gas_per_pubdata as u32)
+                (
+                    unsealed_batch.fee_input,
+                    fee_per_gas,
+                    gas_per_pubdata as u32,
+                )
             } else {
                 let filter = l2_tx_filter(
                     self.batch_fee_input_provider.as_ref(),
@@ -108,10 +135,10 @@ impl MempoolFetcher {
                 .await
                 .context("failed creating L2 transaction filter")?;
 
-                (filter.fee_per_gas, filter.gas_per_pubdata)
+                (filter.fee_input, filter.fee_per_gas, filter.gas_per_pubdata)
             };
 
-            let transactions_with_constraints = storage
+            let mut transactions_with_constraints = storage
                 .transactions_dal()
                 .sync_mempool(
                     &mempool_info.stashed_accounts,
@@ -137,6 +164,15 @@ impl MempoolFetcher {
                 self.transaction_hashes_sender.send(transaction_hashes).ok();
             }
             let all_transactions_loaded = transactions.len() < self.sync_batch_size;
+            if let Some(dry_run) = &self.admission_dry_run {
+                // Admission dry-run is an optimization, so its errors shouldn't stop the mempool fetcher.
+                if let Err(err) = dry_run
+                    .process(&mut transactions_with_constraints, &nonces, fee_input)
+                    .await
+                {
+                    tracing::warn!("Failed pre-executing transactions at admission: {err:#}");
+                }
+            }
             self.mempool.insert(transactions_with_constraints, nonces);
             latency.observe();
 
@@ -181,6 +217,7 @@ async fn get_transaction_nonces(
 
 #[cfg(test)]
 mod tests {
+    use zksync_config::configs::chain::MempoolOrderingPolicy;
     use zksync_multivm::interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
     use zksync_node_fee_model::MockBatchFeeParamsProvider;
     use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
@@ -198,6 +235,9 @@ mod tests {
         stuck_tx_timeout: 0,
         remove_stuck_txs: false,
         delay_interval: 10,
+        admission_dry_run_concurrency: None,
+        admission_reject_halted_txs: false,
+        ordering_policy: MempoolOrderingPolicy::Timestamp,
     };
 
     #[tokio::test]
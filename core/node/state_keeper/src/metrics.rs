@@ -96,6 +96,8 @@ pub struct StateKeeperMetrics {
     /// The time it takes to wait for new L2 block parameters
     #[metrics(buckets = Buckets::LATENCIES)]
     pub wait_for_l2_block_params: Histogram<Duration>,
+    /// Number of pending priority operations that have missed the inclusion deadline, as of the last opened L1 batch.
+    pub overdue_priority_ops: Gauge<usize>,
 }
 
 fn vm_revert_reason_as_metric_label(reason: &VmRevertReason) -> &'static str {
@@ -473,3 +475,63 @@ pub struct UpdatesManagerMetrics {
 #[vise::register]
 pub(crate) static UPDATES_MANAGER_METRICS: vise::Global<UpdatesManagerMetrics> =
     vise::Global::new();
+
+/// Outcome of waiting for a block proposal from an external block builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "outcome", rename_all = "snake_case")]
+pub(crate) enum BlockProposalOutcome {
+    /// Proposal was received in time.
+    Received,
+    /// No proposal was received in time; local ordering was used.
+    TimedOut,
+    /// Proposal contained a transaction that could not be executed; the remainder of the block used local ordering.
+    Invalid,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_state_keeper_block_builder")]
+pub(crate) struct BlockBuilderMetrics {
+    /// Number of L2 blocks for which the state keeper has waited for a proposal, grouped by the outcome.
+    pub proposals: Family<BlockProposalOutcome, Counter>,
+    /// Latency of waiting for a block proposal.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub wait_for_proposal: Histogram<Duration>,
+    /// Number of proposal submissions rejected by the API (e.g., because of failed authentication or validation).
+    pub rejected_submissions: Counter,
+}
+
+#[vise::register]
+pub(crate) static BLOCK_BUILDER_METRICS: vise::Global<BlockBuilderMetrics> = vise::Global::new();
+
+/// Outcome of pre-executing a transaction during the admission dry-run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "outcome", rename_all = "snake_case")]
+pub(crate) enum AdmissionOutcome {
+    /// Transaction doesn't conflict with other pre-executed transactions.
+    Independent,
+    /// Transaction touches storage written by other pre-executed transactions.
+    Conflicting,
+    /// Transaction has halted and was rejected before reaching the mempool.
+    Rejected,
+    /// Transaction has halted, but was admitted to the mempool (e.g., because it's conflicting, or there are
+    /// pending transactions it may depend on).
+    Halted,
+    /// Dry-run has failed; the transaction was admitted to the mempool without a hint.
+    Failed,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_state_keeper_admission")]
+pub(crate) struct AdmissionMetrics {
+    /// Number of pre-executed transactions grouped by the outcome.
+    pub transactions: Family<AdmissionOutcome, Counter>,
+    /// Gas used by pre-executed transactions.
+    #[metrics(buckets = Buckets::exponential(1_000.0..=100_000_000.0, 4.0))]
+    pub gas_used: Histogram<u64>,
+    /// Latency of pre-executing a chunk of transactions loaded into the mempool.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub dry_run_latency: Histogram<Duration>,
+}
+
+#[vise::register]
+pub(crate) static ADMISSION_METRICS: vise::Global<AdmissionMetrics> = vise::Global::new();
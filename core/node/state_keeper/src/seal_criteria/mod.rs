@@ -68,6 +68,8 @@ pub enum UnexecutableReason {
     OutOfGasForBatchTip,
     BootloaderOutOfGas,
     NotEnoughGasProvided,
+    TxSizeLimit,
+    CalldataSizeLimit,
 }
 
 impl UnexecutableReason {
@@ -82,6 +84,8 @@ impl UnexecutableReason {
             UnexecutableReason::OutOfGasForBatchTip => "OutOfGasForBatchTip",
             UnexecutableReason::BootloaderOutOfGas => "BootloaderOutOfGas",
             UnexecutableReason::NotEnoughGasProvided => "NotEnoughGasProvided",
+            UnexecutableReason::TxSizeLimit => "TxSizeLimit",
+            UnexecutableReason::CalldataSizeLimit => "CalldataSizeLimit",
         }
     }
 }
@@ -106,6 +110,12 @@ impl fmt::Display for UnexecutableReason {
             UnexecutableReason::OutOfGasForBatchTip => write!(f, "Out of gas for batch tip"),
             UnexecutableReason::BootloaderOutOfGas => write!(f, "Bootloader out of gas"),
             UnexecutableReason::NotEnoughGasProvided => write!(f, "Not enough gas provided"),
+            UnexecutableReason::TxSizeLimit => {
+                write!(f, "Transaction size exceeds the configured limit")
+            }
+            UnexecutableReason::CalldataSizeLimit => {
+                write!(f, "Transaction calldata size exceeds the configured limit")
+            }
         }
     }
 }
@@ -185,6 +195,24 @@ impl SealData {
             gas_remaining: tx_metrics.gas_remaining,
         }
     }
+
+    /// Creates sealing data for a `transaction` that wasn't executed yet. Only the properties known before execution
+    /// are taken into account: the encoding size and `min_pubdata`, a lower bound on the pubdata published
+    /// by the transaction. This allows to reject transactions that cannot fit into an L1 batch without executing them.
+    pub fn for_unexecuted_transaction(transaction: &Transaction, min_pubdata: u32) -> Self {
+        let execution_metrics = VmExecutionMetrics {
+            pubdata_published: min_pubdata,
+            ..VmExecutionMetrics::default()
+        };
+        Self {
+            execution_metrics,
+            gas_count: gas_count_from_tx_and_metrics(transaction, &execution_metrics),
+            cumulative_size: transaction.bootloader_encoding_size(),
+            writes_metrics: DeduplicatedWritesMetrics::default(),
+            // Gas isn't known before execution, so it shouldn't influence the decision.
+            gas_remaining: u32::MAX,
+        }
+    }
 }
 
 pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
@@ -45,6 +45,8 @@ use crate::{
     ZkSyncStateKeeper,
 };
 
+mod simulation;
+
 pub(crate) fn seconds_since_epoch() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
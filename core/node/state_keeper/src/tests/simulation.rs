@@ -0,0 +1,843 @@
+//! Deterministic simulation tests for the state keeper.
+//!
+//! A simulation drives the state keeper in a single thread with a virtual clock, a scripted mempool generated
+//! from a seed and randomly injected I/O failures. After an injected failure, the state keeper is restarted
+//! from the persisted state, same as it would be on a node restart. Seal criteria invariants are checked
+//! for every sealed L2 block and L1 batch, so that a failing seed can be replayed to reproduce the failure.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio::sync::watch;
+use zksync_config::configs::chain::StateKeeperConfig;
+use zksync_contracts::BaseSystemContracts;
+use zksync_multivm::{
+    interface::{
+        executor::{BatchExecutor, BatchExecutorFactory},
+        storage::{InMemoryStorage, StorageView},
+        BatchTransactionExecutionResult, FinishedL1Batch, L1BatchEnv, L2BlockEnv, SystemEnv,
+        VmExecutionLogs, VmExecutionResultAndLogs,
+    },
+    utils::execution_metrics_bootloader_batch_tip_overhead,
+    vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT,
+};
+use zksync_state::OwnedStorage;
+use zksync_types::{
+    block::L2BlockExecutionData,
+    commitment::PubdataParams,
+    fee_model::BatchFeeInput,
+    l2_to_l1_log::{L2ToL1Log, UserL2ToL1Log},
+    protocol_upgrade::ProtocolUpgradeTx,
+    L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersionId, Transaction, H256,
+};
+
+use crate::{
+    io::{IoCursor, L1BatchParams, L2BlockParams, PendingBatchData, StateKeeperIO},
+    seal_criteria::{
+        criteria::{PubDataBytesCriterion, SlotsCriterion},
+        IoSealCriteria, SequencerSealer, UnexecutableReason,
+    },
+    testonly::{
+        test_batch_executor::{random_tx, MockReadStorageFactory, FEE_ACCOUNT},
+        BASE_SYSTEM_CONTRACTS,
+    },
+    updates::UpdatesManager,
+    OutputHandler, StateKeeperOutputHandler, ZkSyncStateKeeper,
+};
+
+/// Number of schedules checked by the randomized test.
+const SIMULATION_COUNT: u64 = 1_000;
+/// Maximum number of I/O failures injected during a single simulation.
+const MAX_INJECTED_FAILURES: usize = 5;
+/// Virtual time passing between a failure and the state keeper restart.
+const RESTART_DELAY_MS: u64 = 1_000;
+/// Upper bound on the virtual time the state keeper may spend after the last transaction arrives.
+/// Exceeding it means that some transactions are stuck.
+const IDLE_TIME_LIMIT_MS: u64 = 120_000;
+
+fn simulation_config() -> StateKeeperConfig {
+    StateKeeperConfig {
+        transaction_slots: 10,
+        // Leaves 2,000 bytes for transactions on top of the batch tip overhead, i.e. ~21 L2-to-L1 logs.
+        max_pubdata_per_batch: 4_000,
+        reject_tx_at_eth_params_percentage: 0.95,
+        close_block_at_eth_params_percentage: 0.9,
+        block_commit_deadline_ms: 2_500,
+        l2_block_commit_deadline_ms: 1_000,
+        l2_block_max_payload_size: 2_000,
+        ..StateKeeperConfig::for_tests()
+    }
+}
+
+fn simulation_sealer(config: &StateKeeperConfig) -> SequencerSealer {
+    SequencerSealer::with_sealers(
+        config.clone(),
+        vec![
+            Box::new(SlotsCriterion),
+            Box::new(PubDataBytesCriterion {
+                max_pubdata_per_batch: config.max_pubdata_per_batch,
+            }),
+        ],
+    )
+}
+
+/// Pubdata published by a transaction emitting the specified number of user L2-to-L1 logs,
+/// including the batch tip overhead.
+fn tx_pubdata_with_overhead(l2_to_l1_logs: usize) -> usize {
+    // Each log is accompanied by a 4-byte length prefix; see `VmExecutionMetrics::size()`.
+    l2_to_l1_logs * (L2ToL1Log::SERIALIZED_SIZE + 4)
+        + execution_metrics_bootloader_batch_tip_overhead(ProtocolVersionId::latest().into())
+}
+
+fn exceeds_reject_bound(config: &StateKeeperConfig, l2_to_l1_logs: usize) -> bool {
+    let reject_bound =
+        (config.max_pubdata_per_batch as f64 * config.reject_tx_at_eth_params_percentage).round();
+    tx_pubdata_with_overhead(l2_to_l1_logs) > reject_bound as usize
+}
+
+fn tx_payload_size(tx: &Transaction) -> usize {
+    zksync_protobuf::repr::encode::<zksync_dal::consensus::proto::Transaction>(tx).len()
+}
+
+#[derive(Debug, Clone)]
+struct ScheduledTx {
+    arrival_ms: u64,
+    tx: Transaction,
+    l2_to_l1_logs: usize,
+}
+
+/// Scripted input for a simulation: transaction arrivals and I/O failure rate.
+#[derive(Debug, Clone)]
+struct Schedule {
+    seed: u64,
+    txs: Vec<ScheduledTx>,
+    failure_probability: f64,
+}
+
+impl Schedule {
+    fn generate(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let tx_count = rng.gen_range(1..=60);
+        let mut arrival_ms = 0;
+        let txs = (0..tx_count)
+            .map(|i| {
+                // Mostly bursts of transactions with occasional idle periods triggering timeout sealing.
+                arrival_ms += if rng.gen_bool(0.9) {
+                    rng.gen_range(0..200)
+                } else {
+                    rng.gen_range(1_000..5_000)
+                };
+                // Occasional transactions exceed the pubdata limit for a single transaction and must be rejected.
+                let l2_to_l1_logs = if rng.gen_bool(0.05) {
+                    rng.gen_range(20..=30)
+                } else {
+                    rng.gen_range(0..=8)
+                };
+                ScheduledTx {
+                    arrival_ms,
+                    tx: random_tx(i),
+                    l2_to_l1_logs,
+                }
+            })
+            .collect();
+        let failure_probability = if rng.gen_bool(0.5) {
+            0.0
+        } else {
+            rng.gen_range(0.005..0.05)
+        };
+
+        Self {
+            seed,
+            txs,
+            failure_probability,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("injected failure in `{0}`")]
+struct InjectedFailure(&'static str);
+
+#[derive(Debug, Clone)]
+struct SealedL2Block {
+    l1_batch: L1BatchNumber,
+    hash: H256,
+    data: L2BlockExecutionData,
+}
+
+/// Summary of a finished simulation.
+#[derive(Debug, PartialEq)]
+struct SimulationOutcome {
+    /// Sealed L2 blocks as `(L1 batch, L2 block, timestamp, tx hashes)` tuples.
+    l2_blocks: Vec<(L1BatchNumber, L2BlockNumber, u64, Vec<H256>)>,
+    rejected_txs: usize,
+    rolled_back_txs: usize,
+    restarts: usize,
+}
+
+/// State shared by the simulated I/O and persistence. It survives state keeper restarts,
+/// similarly to the mempool and Postgres for the real state keeper.
+#[derive(Debug)]
+struct SimState {
+    config: StateKeeperConfig,
+    rng: StdRng,
+    clock_ms: u64,
+    time_limit_ms: u64,
+    stop_sender: watch::Sender<bool>,
+    // Mempool
+    arrivals: VecDeque<ScheduledTx>,
+    arrived_txs: Vec<Transaction>,
+    mempool: VecDeque<Transaction>,
+    l2_to_l1_logs: Arc<HashMap<H256, usize>>,
+    rolled_back_txs: usize,
+    rejected_txs: HashSet<H256>,
+    // Failure injection
+    failure_probability: f64,
+    failures_left: usize,
+    // Persisted state
+    opened_batch: Option<(L1BatchEnv, SystemEnv, PubdataParams)>,
+    sealed_l2_blocks: Vec<SealedL2Block>,
+    sealed_txs: HashSet<H256>,
+    last_sealed_l1_batch: L1BatchNumber,
+    finalized_tx_count: usize,
+}
+
+impl SimState {
+    fn new(
+        schedule: &Schedule,
+        config: StateKeeperConfig,
+        stop_sender: watch::Sender<bool>,
+    ) -> Self {
+        let l2_to_l1_logs = schedule
+            .txs
+            .iter()
+            .map(|scheduled| (scheduled.tx.hash(), scheduled.l2_to_l1_logs))
+            .collect();
+        let last_arrival_ms = schedule
+            .txs
+            .last()
+            .map_or(0, |scheduled| scheduled.arrival_ms);
+
+        Self {
+            config,
+            // Use a different stream than the one used to generate the schedule.
+            rng: StdRng::seed_from_u64(!schedule.seed),
+            clock_ms: 0,
+            time_limit_ms: last_arrival_ms + IDLE_TIME_LIMIT_MS,
+            stop_sender,
+            arrivals: schedule.txs.iter().cloned().collect(),
+            arrived_txs: vec![],
+            mempool: VecDeque::new(),
+            l2_to_l1_logs: Arc::new(l2_to_l1_logs),
+            rolled_back_txs: 0,
+            rejected_txs: HashSet::new(),
+            failure_probability: schedule.failure_probability,
+            failures_left: MAX_INJECTED_FAILURES,
+            opened_batch: None,
+            sealed_l2_blocks: vec![],
+            sealed_txs: HashSet::new(),
+            last_sealed_l1_batch: L1BatchNumber(0),
+            finalized_tx_count: 0,
+        }
+    }
+
+    fn maybe_fail(&mut self, operation: &'static str) -> anyhow::Result<()> {
+        if self.failures_left > 0 && self.rng.gen_bool(self.failure_probability) {
+            self.failures_left -= 1;
+            return Err(InjectedFailure(operation).into());
+        }
+        Ok(())
+    }
+
+    fn receive_arrived_txs(&mut self) {
+        while let Some(scheduled) = self.arrivals.front() {
+            if scheduled.arrival_ms > self.clock_ms {
+                break;
+            }
+            let scheduled = self.arrivals.pop_front().unwrap();
+            self.arrived_txs.push(scheduled.tx.clone());
+            self.mempool.push_back(scheduled.tx);
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        let all_processed = self.arrivals.is_empty()
+            && self.mempool.is_empty()
+            && self.finalized_tx_count + self.rejected_txs.len() == self.l2_to_l1_logs.len();
+        all_processed || self.clock_ms > self.time_limit_ms
+    }
+
+    /// Returns a timestamp for the next L2 block. Like the real I/O, waits until the timestamp becomes
+    /// greater than the timestamp of the previous L2 block.
+    fn next_l2_block_timestamp(&mut self, cursor: &IoCursor) -> u64 {
+        let timestamp = (self.clock_ms / 1_000).max(cursor.prev_l2_block_timestamp + 1);
+        self.clock_ms = self.clock_ms.max(timestamp * 1_000);
+        timestamp
+    }
+
+    /// Restores the state keeper state from the persisted data. Transactions that were executed,
+    /// but not persisted, are returned to the mempool.
+    fn initialize(&mut self) -> anyhow::Result<(IoCursor, Option<PendingBatchData>)> {
+        self.mempool = self
+            .arrived_txs
+            .iter()
+            .filter(|tx| {
+                let hash = tx.hash();
+                !self.sealed_txs.contains(&hash) && !self.rejected_txs.contains(&hash)
+            })
+            .cloned()
+            .collect();
+
+        let cursor = match self.sealed_l2_blocks.last() {
+            Some(block) => IoCursor {
+                next_l2_block: block.data.number + 1,
+                prev_l2_block_hash: block.hash,
+                prev_l2_block_timestamp: block.data.timestamp,
+                l1_batch: self.last_sealed_l1_batch + 1,
+            },
+            None => IoCursor {
+                next_l2_block: L2BlockNumber(1),
+                prev_l2_block_hash: H256::zero(),
+                prev_l2_block_timestamp: 0,
+                l1_batch: L1BatchNumber(1),
+            },
+        };
+
+        let pending_l2_blocks: Vec<_> = self
+            .sealed_l2_blocks
+            .iter()
+            .filter(|block| block.l1_batch == cursor.l1_batch)
+            .map(|block| block.data.clone())
+            .collect();
+        if pending_l2_blocks.is_empty() {
+            return Ok((cursor, None));
+        }
+        let (l1_batch_env, system_env, pubdata_params) = self
+            .opened_batch
+            .clone()
+            .context("no params for the pending batch")?;
+        anyhow::ensure!(
+            l1_batch_env.number == cursor.l1_batch,
+            "params for the pending batch #{} are for batch #{}",
+            cursor.l1_batch,
+            l1_batch_env.number
+        );
+        let pending_batch = PendingBatchData {
+            l1_batch_env,
+            system_env,
+            pubdata_params,
+            pending_l2_blocks,
+        };
+        Ok((cursor, Some(pending_batch)))
+    }
+
+    fn check_l2_block(&self, updates: &UpdatesManager) -> anyhow::Result<()> {
+        let l2_block = &updates.l2_block;
+        let (expected_number, expected_prev_hash, prev_timestamp) =
+            match self.sealed_l2_blocks.last() {
+                Some(block) => (block.data.number + 1, block.hash, block.data.timestamp),
+                None => (L2BlockNumber(1), H256::zero(), 0),
+            };
+        anyhow::ensure!(
+            l2_block.number == expected_number,
+            "sealed L2 block #{}, expected #{expected_number}",
+            l2_block.number
+        );
+        anyhow::ensure!(
+            l2_block.prev_block_hash == expected_prev_hash,
+            "L2 block #{} doesn't reference the previous L2 block",
+            l2_block.number
+        );
+        anyhow::ensure!(
+            l2_block.timestamp > prev_timestamp,
+            "L2 block #{} has timestamp {}, which isn't greater than the previous one ({prev_timestamp})",
+            l2_block.number,
+            l2_block.timestamp
+        );
+
+        for tx in &l2_block.executed_transactions {
+            anyhow::ensure!(
+                !self.sealed_txs.contains(&tx.hash),
+                "transaction {:?} is sealed twice",
+                tx.hash
+            );
+            let l2_to_l1_logs = self.l2_to_l1_logs[&tx.hash];
+            anyhow::ensure!(
+                !exceeds_reject_bound(&self.config, l2_to_l1_logs),
+                "transaction {:?} exceeding the pubdata limit is sealed in L2 block #{}",
+                tx.hash,
+                l2_block.number
+            );
+        }
+
+        if let Some(last_tx) = l2_block.executed_transactions.last() {
+            // The L2 block must be sealed as soon as its payload reaches the limit.
+            let payload_before_last_tx =
+                l2_block.payload_encoding_size - tx_payload_size(&last_tx.transaction);
+            anyhow::ensure!(
+                payload_before_last_tx < self.config.l2_block_max_payload_size,
+                "L2 block #{} wasn't sealed after reaching the payload limit: {payload_before_last_tx} bytes \
+                 before the last transaction",
+                l2_block.number
+            );
+        }
+        Ok(())
+    }
+
+    fn check_l1_batch(&self, updates: &UpdatesManager) -> anyhow::Result<()> {
+        let number = updates.l1_batch.number;
+        anyhow::ensure!(
+            number == self.last_sealed_l1_batch + 1,
+            "sealed L1 batch #{number}, expected #{}",
+            self.last_sealed_l1_batch + 1
+        );
+        anyhow::ensure!(
+            updates.l2_block.executed_transactions.is_empty(),
+            "fictive L2 block in L1 batch #{number} contains transactions"
+        );
+
+        let tx_count = updates.pending_executed_transactions_len();
+        anyhow::ensure!(tx_count > 0, "L1 batch #{number} is empty");
+        anyhow::ensure!(
+            tx_count <= self.config.transaction_slots,
+            "L1 batch #{number} has {tx_count} transactions, more than {} slots",
+            self.config.transaction_slots
+        );
+
+        let protocol_version = updates.protocol_version();
+        let pubdata = updates.pending_execution_metrics().size()
+            + updates
+                .storage_writes_deduplicator
+                .metrics()
+                .size(protocol_version)
+            + execution_metrics_bootloader_batch_tip_overhead(protocol_version.into());
+        anyhow::ensure!(
+            pubdata <= self.config.max_pubdata_per_batch as usize,
+            "L1 batch #{number} publishes {pubdata} bytes of pubdata, more than the limit ({})",
+            self.config.max_pubdata_per_batch
+        );
+        Ok(())
+    }
+
+    fn record_l2_block(&mut self, updates: &UpdatesManager) {
+        let l2_block = &updates.l2_block;
+        let txs: Vec<_> = l2_block
+            .executed_transactions
+            .iter()
+            .map(|tx| tx.transaction.clone())
+            .collect();
+        self.sealed_txs.extend(txs.iter().map(Transaction::hash));
+        self.sealed_l2_blocks.push(SealedL2Block {
+            l1_batch: updates.l1_batch.number,
+            hash: l2_block.get_l2_block_hash(),
+            data: L2BlockExecutionData {
+                number: l2_block.number,
+                timestamp: l2_block.timestamp,
+                prev_block_hash: l2_block.prev_block_hash,
+                virtual_blocks: l2_block.virtual_blocks,
+                txs,
+            },
+        });
+    }
+
+    /// Checks that every transaction is either included into a sealed L1 batch, or rejected
+    /// because of exceeding the pubdata limit.
+    fn check_final_state(&self, schedule: &Schedule) -> anyhow::Result<()> {
+        let finalized_txs: HashSet<_> = self
+            .sealed_l2_blocks
+            .iter()
+            .filter(|block| block.l1_batch <= self.last_sealed_l1_batch)
+            .flat_map(|block| block.data.txs.iter().map(Transaction::hash))
+            .collect();
+        for scheduled in &schedule.txs {
+            let hash = scheduled.tx.hash();
+            if exceeds_reject_bound(&self.config, scheduled.l2_to_l1_logs) {
+                anyhow::ensure!(
+                    self.rejected_txs.contains(&hash),
+                    "transaction {hash:?} exceeding the pubdata limit wasn't rejected"
+                );
+            } else {
+                anyhow::ensure!(
+                    finalized_txs.contains(&hash),
+                    "transaction {hash:?} isn't included into a sealed L1 batch (virtual time: {}ms)",
+                    self.clock_ms
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct SimIO {
+    state: Arc<Mutex<SimState>>,
+}
+
+impl SimIO {
+    fn state(&self) -> std::sync::MutexGuard<'_, SimState> {
+        self.state.lock().expect("simulation state is poisoned")
+    }
+}
+
+impl IoSealCriteria for SimIO {
+    fn should_seal_l1_batch_unconditionally(&mut self, manager: &UpdatesManager) -> bool {
+        let state = self.state();
+        manager.pending_executed_transactions_len() > 0
+            && state
+                .clock_ms
+                .saturating_sub(manager.batch_timestamp() * 1_000)
+                > state.config.block_commit_deadline_ms
+    }
+
+    fn should_seal_l2_block(&mut self, manager: &UpdatesManager) -> bool {
+        let state = self.state();
+        let l2_block = &manager.l2_block;
+        let is_timed_out = !l2_block.executed_transactions.is_empty()
+            && state.clock_ms.saturating_sub(l2_block.timestamp * 1_000)
+                > state.config.l2_block_commit_deadline_ms;
+        is_timed_out || l2_block.payload_encoding_size >= state.config.l2_block_max_payload_size
+    }
+}
+
+#[async_trait]
+impl StateKeeperIO for SimIO {
+    fn chain_id(&self) -> L2ChainId {
+        L2ChainId::default()
+    }
+
+    async fn initialize(&mut self) -> anyhow::Result<(IoCursor, Option<PendingBatchData>)> {
+        self.state().initialize()
+    }
+
+    async fn wait_for_new_batch_params(
+        &mut self,
+        cursor: &IoCursor,
+        _max_wait: Duration,
+    ) -> anyhow::Result<Option<L1BatchParams>> {
+        let mut state = self.state();
+        state.maybe_fail("wait_for_new_batch_params")?;
+
+        let params = L1BatchParams {
+            protocol_version: ProtocolVersionId::latest(),
+            validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
+            operator_address: FEE_ACCOUNT,
+            fee_input: BatchFeeInput::default(),
+            first_l2_block: L2BlockParams {
+                timestamp: state.next_l2_block_timestamp(cursor),
+                virtual_blocks: 1,
+            },
+            pubdata_params: PubdataParams::default(),
+        };
+        // Remember the batch environment to be able to restore the batch after a restart.
+        let (system_env, l1_batch_env, pubdata_params) = params.clone().into_env(
+            self.chain_id(),
+            BASE_SYSTEM_CONTRACTS.clone(),
+            cursor,
+            H256::zero(),
+        );
+        state.opened_batch = Some((l1_batch_env, system_env, pubdata_params));
+        Ok(Some(params))
+    }
+
+    async fn wait_for_new_l2_block_params(
+        &mut self,
+        cursor: &IoCursor,
+        _max_wait: Duration,
+    ) -> anyhow::Result<Option<L2BlockParams>> {
+        let mut state = self.state();
+        state.maybe_fail("wait_for_new_l2_block_params")?;
+        Ok(Some(L2BlockParams {
+            timestamp: state.next_l2_block_timestamp(cursor),
+            virtual_blocks: 1,
+        }))
+    }
+
+    async fn wait_for_next_tx(
+        &mut self,
+        max_wait: Duration,
+        _l2_block_timestamp: u64,
+    ) -> anyhow::Result<Option<Transaction>> {
+        let mut state = self.state();
+        state.maybe_fail("wait_for_next_tx")?;
+
+        state.receive_arrived_txs();
+        if state.mempool.is_empty() {
+            // Wait until the next transaction arrives, but no longer than `max_wait`.
+            let wait_until = state.clock_ms + max_wait.as_millis() as u64;
+            let next_arrival_ms = state.arrivals.front().map(|scheduled| scheduled.arrival_ms);
+            state.clock_ms = next_arrival_ms.map_or(wait_until, |ms| ms.min(wait_until));
+            state.receive_arrived_txs();
+        }
+
+        let Some(tx) = state.mempool.pop_front() else {
+            if state.is_finished() {
+                state.stop_sender.send_replace(true);
+            }
+            return Ok(None);
+        };
+        // Account for the transaction execution time.
+        let execution_time_ms = state.rng.gen_range(1..=50);
+        state.clock_ms += execution_time_ms;
+        Ok(Some(tx))
+    }
+
+    async fn rollback(&mut self, tx: Transaction) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.maybe_fail("rollback")?;
+        anyhow::ensure!(
+            !state.sealed_txs.contains(&tx.hash()),
+            "rolled back transaction {:?} is sealed",
+            tx.hash()
+        );
+        state.rolled_back_txs += 1;
+        state.mempool.push_front(tx);
+        Ok(())
+    }
+
+    async fn reject(&mut self, tx: &Transaction, reason: UnexecutableReason) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.maybe_fail("reject")?;
+        let hash = tx.hash();
+        anyhow::ensure!(
+            reason == UnexecutableReason::PubdataLimit,
+            "transaction {hash:?} is rejected with unexpected reason: {reason}"
+        );
+        let l2_to_l1_logs = state.l2_to_l1_logs[&hash];
+        anyhow::ensure!(
+            exceeds_reject_bound(&state.config, l2_to_l1_logs),
+            "transaction {hash:?} with {l2_to_l1_logs} L2-to-L1 logs is rejected, although it fits into the pubdata limit"
+        );
+        state.rejected_txs.insert(hash);
+        Ok(())
+    }
+
+    async fn load_base_system_contracts(
+        &self,
+        _protocol_version: ProtocolVersionId,
+        _cursor: &IoCursor,
+    ) -> anyhow::Result<BaseSystemContracts> {
+        Ok(BASE_SYSTEM_CONTRACTS.clone())
+    }
+
+    async fn load_batch_version_id(
+        &self,
+        _number: L1BatchNumber,
+    ) -> anyhow::Result<ProtocolVersionId> {
+        Ok(ProtocolVersionId::latest())
+    }
+
+    async fn load_upgrade_tx(
+        &self,
+        _version_id: ProtocolVersionId,
+    ) -> anyhow::Result<Option<ProtocolUpgradeTx>> {
+        Ok(None)
+    }
+
+    async fn load_batch_state_hash(&self, _number: L1BatchNumber) -> anyhow::Result<H256> {
+        Ok(H256::zero())
+    }
+}
+
+#[derive(Debug)]
+struct SimPersistence {
+    state: Arc<Mutex<SimState>>,
+}
+
+#[async_trait]
+impl StateKeeperOutputHandler for SimPersistence {
+    async fn handle_l2_block(&mut self, updates_manager: &UpdatesManager) -> anyhow::Result<()> {
+        let mut state = self.state.lock().expect("simulation state is poisoned");
+        state.maybe_fail("handle_l2_block")?;
+        anyhow::ensure!(
+            !updates_manager.l2_block.executed_transactions.is_empty(),
+            "sealed empty L2 block #{}",
+            updates_manager.l2_block.number
+        );
+        state.check_l2_block(updates_manager)?;
+        state.record_l2_block(updates_manager);
+        Ok(())
+    }
+
+    async fn handle_l1_batch(
+        &mut self,
+        updates_manager: Arc<UpdatesManager>,
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.lock().expect("simulation state is poisoned");
+        state.maybe_fail("handle_l1_batch")?;
+        // The fictive L2 block is persisted together with the batch.
+        state.check_l2_block(&updates_manager)?;
+        state.check_l1_batch(&updates_manager)?;
+        state.record_l2_block(&updates_manager);
+        state.last_sealed_l1_batch = updates_manager.l1_batch.number;
+        state.finalized_tx_count += updates_manager.pending_executed_transactions_len();
+        Ok(())
+    }
+}
+
+/// Batch executor returning deterministic results based on the transaction hash.
+#[derive(Debug)]
+struct SimBatchExecutor {
+    l2_to_l1_logs: Arc<HashMap<H256, usize>>,
+    executed_txs: Vec<H256>,
+}
+
+impl BatchExecutorFactory<OwnedStorage> for SimBatchExecutor {
+    fn init_batch(
+        &mut self,
+        _storage: OwnedStorage,
+        _l1_batch_env: L1BatchEnv,
+        _system_env: SystemEnv,
+        _pubdata_params: PubdataParams,
+    ) -> Box<dyn BatchExecutor<OwnedStorage>> {
+        Box::new(Self {
+            l2_to_l1_logs: self.l2_to_l1_logs.clone(),
+            executed_txs: vec![],
+        })
+    }
+}
+
+#[async_trait]
+impl BatchExecutor<OwnedStorage> for SimBatchExecutor {
+    async fn execute_tx(
+        &mut self,
+        tx: Transaction,
+    ) -> anyhow::Result<BatchTransactionExecutionResult> {
+        let hash = tx.hash();
+        anyhow::ensure!(
+            !self.executed_txs.contains(&hash),
+            "transaction {hash:?} is executed twice in the same L1 batch"
+        );
+        let l2_to_l1_logs = *self
+            .l2_to_l1_logs
+            .get(&hash)
+            .with_context(|| format!("unknown transaction {hash:?}"))?;
+        self.executed_txs.push(hash);
+
+        Ok(BatchTransactionExecutionResult {
+            tx_result: Box::new(VmExecutionResultAndLogs {
+                logs: VmExecutionLogs {
+                    user_l2_to_l1_logs: vec![UserL2ToL1Log::default(); l2_to_l1_logs],
+                    ..VmExecutionLogs::default()
+                },
+                ..VmExecutionResultAndLogs::mock_success()
+            }),
+            compressed_bytecodes: vec![],
+            call_traces: vec![],
+        })
+    }
+
+    async fn rollback_last_tx(&mut self) -> anyhow::Result<()> {
+        self.executed_txs
+            .pop()
+            .context("no transaction to roll back")?;
+        Ok(())
+    }
+
+    async fn start_next_l2_block(&mut self, _env: L2BlockEnv) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn finish_batch(
+        self: Box<Self>,
+    ) -> anyhow::Result<(FinishedL1Batch, StorageView<OwnedStorage>)> {
+        let storage = OwnedStorage::boxed(InMemoryStorage::default());
+        Ok((FinishedL1Batch::mock(), StorageView::new(storage)))
+    }
+}
+
+/// Runs the state keeper according to the schedule, restarting it after each injected failure.
+async fn simulate(schedule: &Schedule) -> anyhow::Result<SimulationOutcome> {
+    let config = simulation_config();
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let state = SimState::new(schedule, config.clone(), stop_sender);
+    let l2_to_l1_logs = state.l2_to_l1_logs.clone();
+    let state = Arc::new(Mutex::new(state));
+
+    let mut restarts = 0;
+    loop {
+        let io = SimIO {
+            state: state.clone(),
+        };
+        let persistence = SimPersistence {
+            state: state.clone(),
+        };
+        let batch_executor = SimBatchExecutor {
+            l2_to_l1_logs: l2_to_l1_logs.clone(),
+            executed_txs: vec![],
+        };
+        let state_keeper = ZkSyncStateKeeper::new(
+            stop_receiver.clone(),
+            Box::new(io),
+            Box::new(batch_executor),
+            OutputHandler::new(Box::new(persistence)),
+            Arc::new(simulation_sealer(&config)),
+            Arc::new(MockReadStorageFactory),
+        );
+
+        match state_keeper.run().await {
+            Ok(()) => break,
+            Err(err) if err.root_cause().is::<InjectedFailure>() => {
+                restarts += 1;
+                state.lock().unwrap().clock_ms += RESTART_DELAY_MS;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    let state = state.lock().unwrap();
+    state.check_final_state(schedule)?;
+    Ok(SimulationOutcome {
+        l2_blocks: state
+            .sealed_l2_blocks
+            .iter()
+            .map(|block| {
+                let tx_hashes = block.data.txs.iter().map(Transaction::hash).collect();
+                (
+                    block.l1_batch,
+                    block.data.number,
+                    block.data.timestamp,
+                    tx_hashes,
+                )
+            })
+            .collect(),
+        rejected_txs: state.rejected_txs.len(),
+        rolled_back_txs: state.rolled_back_txs,
+        restarts,
+    })
+}
+
+#[tokio::test]
+async fn seal_criteria_invariants_hold_for_random_schedules() {
+    let (mut rejected_txs, mut rolled_back_txs, mut restarts) = (0, 0, 0);
+    for seed in 0..SIMULATION_COUNT {
+        let schedule = Schedule::generate(seed);
+        let outcome = simulate(&schedule)
+            .await
+            .unwrap_or_else(|err| panic!("simulation with seed {seed} failed: {err:#}"));
+        rejected_txs += outcome.rejected_txs;
+        rolled_back_txs += outcome.rolled_back_txs;
+        restarts += outcome.restarts;
+    }
+
+    // Sanity check: the generated schedules must cover all interesting code paths.
+    assert!(rejected_txs > 0, "no rejected transactions");
+    assert!(rolled_back_txs > 0, "no rolled back transactions");
+    assert!(restarts > 0, "no state keeper restarts");
+}
+
+#[tokio::test]
+async fn simulation_is_deterministic() {
+    for seed in 0..10 {
+        let schedule = Schedule::generate(seed);
+        let outcome = simulate(&schedule).await.unwrap();
+        assert!(!outcome.l2_blocks.is_empty());
+        let replayed_outcome = simulate(&schedule).await.unwrap();
+        assert_eq!(outcome, replayed_outcome, "seed: {seed}");
+    }
+}
@@ -4,10 +4,13 @@ use std::{
 };
 
 use zksync_dal::{Connection, Core, CoreDal};
-use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
+use zksync_mempool::{
+    ordering::TxOrderingPolicy, AccountMempoolContents, L2TxFilter, MempoolInfo, MempoolStore,
+};
 use zksync_multivm::interface::{VmExecutionMetrics, VmExecutionResultAndLogs};
 use zksync_types::{
-    block::BlockGasCount, Address, Nonce, PriorityOpId, Transaction, TransactionTimeRangeConstraint,
+    block::BlockGasCount, Address, Nonce, PriorityOpId, Transaction,
+    TransactionTimeRangeConstraint, H256,
 };
 
 use super::{
@@ -19,12 +22,18 @@ use super::{
 pub struct MempoolGuard(Arc<Mutex<MempoolStore>>);
 
 impl MempoolGuard {
-    pub async fn from_storage(storage_processor: &mut Connection<'_, Core>, capacity: u64) -> Self {
+    pub async fn from_storage(
+        storage_processor: &mut Connection<'_, Core>,
+        capacity: u64,
+        ordering_policy: Arc<dyn TxOrderingPolicy>,
+    ) -> Self {
         let next_priority_id = storage_processor
             .transactions_dal()
             .next_priority_id()
             .await;
-        Self::new(next_priority_id, capacity)
+        let store =
+            MempoolStore::new(next_priority_id, capacity).with_ordering_policy(ordering_policy);
+        Self(Arc::new(Mutex::new(store)))
     }
 
     pub(super) fn new(next_priority_id: PriorityOpId, capacity: u64) -> Self {
@@ -75,6 +84,24 @@ impl MempoolGuard {
             .next_transaction(filter)
     }
 
+    pub fn next_l1_transaction(&mut self) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .next_l1_transaction()
+    }
+
+    pub fn next_transaction_with_hash(
+        &mut self,
+        hash: H256,
+        filter: &L2TxFilter,
+    ) -> Option<(Transaction, TransactionTimeRangeConstraint)> {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .next_transaction_with_hash(hash, filter)
+    }
+
     pub fn rollback(&mut self, rejected: &Transaction) -> TransactionTimeRangeConstraint {
         self.0
             .lock()
@@ -89,6 +116,52 @@ impl MempoolGuard {
             .get_mempool_info()
     }
 
+    /// Returns whether there are L1 transactions in the mempool.
+    pub fn has_l1_transactions(&self) -> bool {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .stats()
+            .l1_transaction_count
+            > 0
+    }
+
+    pub fn pending_and_queued_counts(&self) -> (usize, usize) {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .pending_and_queued_counts()
+    }
+
+    pub fn contents(&self) -> HashMap<Address, AccountMempoolContents> {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .contents()
+    }
+
+    pub fn account_contents(&self, account: Address) -> AccountMempoolContents {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .account_contents(account)
+    }
+
+    pub fn ordering_policy(&self) -> Arc<dyn TxOrderingPolicy> {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .ordering_policy()
+            .clone()
+    }
+
+    pub fn next_priority_id(&self) -> PriorityOpId {
+        self.0
+            .lock()
+            .expect("failed to acquire mempool lock")
+            .next_priority_id()
+    }
+
     #[cfg(test)]
     pub fn stats(&self) -> zksync_mempool::MempoolStats {
         self.0
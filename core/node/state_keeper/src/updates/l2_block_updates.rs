@@ -113,6 +113,9 @@ impl L2BlockUpdates {
             ExecutionResult::Halt { .. } => {
                 unreachable!("Tx that is added to `UpdatesManager` must not have Halted status")
             }
+            ExecutionResult::Interrupted { .. } => {
+                unreachable!("Tx that is added to `UpdatesManager` must not be interrupted")
+            }
         };
 
         // Get transaction factory deps
@@ -0,0 +1,199 @@
+//! ABI-based decoding of transaction calldata and logs.
+
+use std::collections::HashMap;
+
+use zksync_dal::decoded_transactions_dal::TransactionToDecode;
+use zksync_types::{
+    api::{DecodedCall, DecodedLog, DecodedParam, DecodedTransaction, Log},
+    ethabi::{Contract, Function},
+    Address,
+};
+
+/// Decodes a transaction using the provided contract ABIs. Returns `None` if neither the call nor any of the logs
+/// could be decoded.
+pub(crate) fn decode_transaction(
+    tx: &TransactionToDecode,
+    abis: &HashMap<Address, Contract>,
+) -> Option<DecodedTransaction> {
+    let call = tx.contract_address.and_then(|address| {
+        let abi = abis.get(&address)?;
+        decode_call(address, abi, &tx.calldata)
+    });
+    let logs: Vec<_> = tx
+        .logs
+        .iter()
+        .filter_map(|log| decode_log(abis.get(&log.address)?, log))
+        .collect();
+
+    if call.is_none() && logs.is_empty() {
+        return None;
+    }
+    Some(DecodedTransaction {
+        transaction_hash: tx.hash,
+        call,
+        logs,
+    })
+}
+
+fn decode_call(contract_address: Address, abi: &Contract, calldata: &[u8]) -> Option<DecodedCall> {
+    let (selector, encoded_args) = calldata.split_first_chunk::<4>()?;
+    let function = abi
+        .functions()
+        .find(|function| function.short_signature() == *selector)?;
+    let args = function.decode_input(encoded_args).ok()?;
+
+    let params = function
+        .inputs
+        .iter()
+        .zip(&args)
+        .map(|(input, value)| DecodedParam::new(input.name.clone(), &input.kind, value, None))
+        .collect();
+    Some(DecodedCall {
+        contract_address,
+        name: function.name.clone(),
+        signature: function_signature(function),
+        params,
+    })
+}
+
+fn decode_log(abi: &Contract, log: &Log) -> Option<DecodedLog> {
+    let topic = *log.topics.first()?;
+    let event = abi
+        .events()
+        .find(|event| !event.anonymous && event.signature() == topic)?;
+    DecodedLog::decode(event, log)
+}
+
+fn function_signature(function: &Function) -> String {
+    let types: Vec<_> = function
+        .inputs
+        .iter()
+        .map(|input| input.kind.to_string())
+        .collect();
+    format!("{}({})", function.name, types.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{
+        ethabi::{self, Token},
+        web3::Bytes,
+        L2BlockNumber, H256,
+    };
+
+    use super::*;
+
+    const ERC20_ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "stateMutability": "nonpayable",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }]
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        }
+    ]"#;
+
+    fn erc20_abi() -> Contract {
+        serde_json::from_str(ERC20_ABI).unwrap()
+    }
+
+    fn mock_transaction(contract_address: Address, abi: &Contract) -> TransactionToDecode {
+        let to = Address::repeat_byte(2);
+        let from = Address::repeat_byte(3);
+        let calldata = abi
+            .function("transfer")
+            .unwrap()
+            .encode_input(&[Token::Address(to), Token::Uint(1_000.into())])
+            .unwrap();
+        let transfer_event = abi.event("Transfer").unwrap();
+        let log = Log {
+            address: contract_address,
+            topics: vec![transfer_event.signature(), H256::from(from), H256::from(to)],
+            data: Bytes(ethabi::encode(&[Token::Uint(1_000.into())])),
+            block_hash: None,
+            block_number: None,
+            l1_batch_number: None,
+            transaction_hash: None,
+            transaction_index: None,
+            log_index: Some(5.into()),
+            transaction_log_index: None,
+            log_type: None,
+            removed: None,
+            block_timestamp: None,
+            decoded_args: None,
+        };
+
+        TransactionToDecode {
+            hash: H256::repeat_byte(1),
+            l2_block_number: L2BlockNumber(1),
+            contract_address: Some(contract_address),
+            calldata,
+            logs: vec![log],
+        }
+    }
+
+    #[test]
+    fn decoding_erc20_transfer() {
+        let contract_address = Address::repeat_byte(1);
+        let abi = erc20_abi();
+        let tx = mock_transaction(contract_address, &abi);
+        let abis = HashMap::from([(contract_address, abi)]);
+
+        let decoded = decode_transaction(&tx, &abis).unwrap();
+        assert_eq!(decoded.transaction_hash, tx.hash);
+        let call = decoded.call.unwrap();
+        assert_eq!(call.contract_address, contract_address);
+        assert_eq!(call.name, "transfer");
+        assert_eq!(call.signature, "transfer(address,uint256)");
+        assert_eq!(call.params.len(), 2);
+        assert_eq!(call.params[0].name, "to");
+        assert_eq!(call.params[0].param_type, "address");
+        assert_eq!(
+            call.params[0].value,
+            format!("{:?}", Address::repeat_byte(2))
+        );
+        assert_eq!(call.params[1].value, "1000");
+        assert_eq!(call.params[1].indexed, None);
+
+        assert_eq!(decoded.logs.len(), 1);
+        let log = &decoded.logs[0];
+        assert_eq!(log.log_index, 5.into());
+        assert_eq!(log.name, "Transfer");
+        assert_eq!(log.signature, "Transfer(address,address,uint256)");
+        let param_names: Vec<_> = log.params.iter().map(|param| param.name.as_str()).collect();
+        assert_eq!(param_names, ["from", "to", "value"]);
+        assert_eq!(log.params[0].indexed, Some(true));
+        assert_eq!(log.params[2].indexed, Some(false));
+        assert_eq!(log.params[2].value, "1000");
+    }
+
+    #[test]
+    fn transactions_without_verified_contracts_are_not_decoded() {
+        let contract_address = Address::repeat_byte(1);
+        let abi = erc20_abi();
+        let tx = mock_transaction(contract_address, &abi);
+
+        assert_eq!(decode_transaction(&tx, &HashMap::new()), None);
+
+        // Calldata not matching the ABI should not prevent decoding logs.
+        let mut tx = tx;
+        tx.calldata = vec![0xff; 36];
+        let abis = HashMap::from([(contract_address, abi)]);
+        let decoded = decode_transaction(&tx, &abis).unwrap();
+        assert_eq!(decoded.call, None);
+        assert_eq!(decoded.logs.len(), 1);
+    }
+}
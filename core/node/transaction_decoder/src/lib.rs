@@ -0,0 +1,165 @@
+//! Decoding of transaction calldata and logs using ABIs of verified contracts.
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_types::{ethabi::Contract, Address, L2BlockNumber};
+
+use self::{decoder::decode_transaction, metrics::METRICS};
+
+mod decoder;
+mod metrics;
+
+/// Background task decoding transactions in sealed L2 blocks using ABIs of verified contracts
+/// and persisting decoded data in Postgres.
+///
+/// Transactions are decoded using ABIs available at the time of processing; transactions interacting with contracts
+/// verified afterwards are not re-decoded. Decoded data is removed together with the L2 block the transaction belongs to
+/// (e.g., on pruning or a block revert).
+#[derive(Debug)]
+pub struct TransactionDecoder {
+    pool: ConnectionPool<Core>,
+    poll_interval: Duration,
+    max_l2_blocks_per_iteration: u32,
+}
+
+impl TransactionDecoder {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+    const DEFAULT_MAX_L2_BLOCKS_PER_ITERATION: u32 = 100;
+
+    pub fn new(pool: ConnectionPool<Core>) -> Self {
+        Self {
+            pool,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            max_l2_blocks_per_iteration: Self::DEFAULT_MAX_L2_BLOCKS_PER_ITERATION,
+        }
+    }
+
+    /// Decoding resumes after the last L2 block with decoded transactions. This may re-process some L2 blocks
+    /// after a restart, which is fine since decoding is idempotent.
+    async fn next_l2_block_to_decode(
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<Option<L2BlockNumber>> {
+        if let Some(last_decoded) = storage
+            .decoded_transactions_dal()
+            .get_last_decoded_l2_block()
+            .await?
+        {
+            return Ok(Some(last_decoded + 1));
+        }
+        Ok(storage.blocks_dal().get_earliest_l2_block_number().await?)
+    }
+
+    async fn load_abis(
+        storage: &mut Connection<'_, Core>,
+        addresses: HashSet<Address>,
+    ) -> anyhow::Result<HashMap<Address, Contract>> {
+        let mut abis = HashMap::new();
+        for address in addresses {
+            let Some(info) = storage
+                .contract_verification_dal()
+                .get_contract_verification_info(address)
+                .await?
+            else {
+                continue;
+            };
+            match serde_json::from_value::<Contract>(info.artifacts.abi) {
+                Ok(abi) => {
+                    abis.insert(address, abi);
+                }
+                Err(err) => {
+                    tracing::warn!("Failed parsing ABI of verified contract {address:?}: {err}");
+                }
+            }
+        }
+        Ok(abis)
+    }
+
+    /// Decodes transactions in the specified L2 block range. Returns the number of decoded transactions.
+    async fn decode_l2_blocks(
+        &self,
+        l2_blocks: std::ops::RangeInclusive<L2BlockNumber>,
+    ) -> anyhow::Result<usize> {
+        let mut storage = self.pool.connection_tagged("transaction_decoder").await?;
+        let transactions = storage
+            .decoded_transactions_dal()
+            .get_transactions_to_decode(l2_blocks.clone())
+            .await?;
+
+        let addresses = transactions
+            .iter()
+            .flat_map(|tx| {
+                let log_addresses = tx.logs.iter().map(|log| log.address);
+                tx.contract_address.into_iter().chain(log_addresses)
+            })
+            .collect();
+        let abis = Self::load_abis(&mut storage, addresses).await?;
+
+        let decoded: Vec<_> = transactions
+            .iter()
+            .filter_map(|tx| Some((tx.l2_block_number, decode_transaction(tx, &abis)?)))
+            .collect();
+        if !decoded.is_empty() {
+            storage
+                .decoded_transactions_dal()
+                .insert_decoded_transactions(&decoded)
+                .await?;
+        }
+
+        tracing::debug!(
+            "Decoded {} / {} transactions in L2 blocks {l2_blocks:?}",
+            decoded.len(),
+            transactions.len()
+        );
+        METRICS
+            .processed_transactions
+            .inc_by(transactions.len() as u64);
+        METRICS.decoded_transactions.inc_by(decoded.len() as u64);
+        METRICS
+            .last_processed_l2_block
+            .set(l2_blocks.end().0.into());
+        Ok(decoded.len())
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut next_l2_block = None;
+        while !*stop_receiver.borrow_and_update() {
+            let mut storage = self.pool.connection_tagged("transaction_decoder").await?;
+            if next_l2_block.is_none() {
+                next_l2_block = Self::next_l2_block_to_decode(&mut storage).await?;
+                if let Some(number) = next_l2_block {
+                    tracing::info!("Starting decoding transactions from L2 block #{number}");
+                }
+            }
+            let sealed_l2_block = storage.blocks_dal().get_sealed_l2_block_number().await?;
+            drop(storage);
+
+            let range = next_l2_block
+                .zip(sealed_l2_block)
+                .and_then(|(start, sealed)| {
+                    let end = sealed.min(start + (self.max_l2_blocks_per_iteration - 1));
+                    (start <= end).then_some(start..=end)
+                });
+            let Some(range) = range else {
+                // No new L2 blocks to process.
+                tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                    .await
+                    .ok();
+                continue;
+            };
+
+            self.decode_l2_blocks(range.clone())
+                .await
+                .with_context(|| format!("failed decoding transactions in L2 blocks {range:?}"))?;
+            next_l2_block = Some(*range.end() + 1);
+        }
+
+        tracing::info!("Stop signal received, transaction decoder is shutting down");
+        Ok(())
+    }
+}
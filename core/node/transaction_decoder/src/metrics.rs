@@ -0,0 +1,17 @@
+//! Metrics for the transaction decoder.
+
+use vise::{Counter, Gauge, Metrics};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "transaction_decoder")]
+pub(crate) struct TransactionDecoderMetrics {
+    /// Number of processed transactions.
+    pub processed_transactions: Counter,
+    /// Number of transactions with a decoded call or at least one decoded log.
+    pub decoded_transactions: Counter,
+    /// Number of the last processed L2 block.
+    pub last_processed_l2_block: Gauge<u64>,
+}
+
+#[vise::register]
+pub(crate) static METRICS: vise::Global<TransactionDecoderMetrics> = vise::Global::new();
@@ -0,0 +1,266 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tokio::sync::watch;
+use zksync_commitment_generator::{CommitmentGenerator, L1BatchExecutionOutputs};
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_types::{commitment::L1BatchCommitmentMode, vm::FastVmMode, L1BatchNumber, L2ChainId};
+use zksync_vm_executor::batch::MainBatchExecutorFactory;
+use zksync_vm_interface::{L1BatchEnv, L2BlockEnv, SystemEnv};
+
+use crate::{
+    metrics::BATCH_VERIFIER_METRICS, storage::StorageSyncTask, ConcurrentOutputHandlerFactory,
+    ConcurrentOutputHandlerFactoryTask, L1BatchOutput, L2BlockOutput, OutputHandler,
+    OutputHandlerFactory, VmRunner, VmRunnerIo, VmRunnerStorage,
+};
+
+/// Interval between checks whether Merkle tree data is available for a re-executed batch.
+const TREE_DATA_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A standalone component that independently re-executes batches using the fast VM and recomputes their commitments.
+/// Recomputed commitments are persisted in Postgres, so that the ETH sender can refuse committing batches
+/// with diverging commitments.
+#[derive(Debug)]
+pub struct BatchVerifier {
+    vm_runner: VmRunner,
+}
+
+impl BatchVerifier {
+    /// Creates a new batch verifier from the provided DB parameters and window size which
+    /// regulates how many batches this component can handle at the same time.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        rocksdb_path: String,
+        chain_id: L2ChainId,
+        commitment_mode: L1BatchCommitmentMode,
+        first_processed_batch: L1BatchNumber,
+        window_size: u32,
+    ) -> anyhow::Result<(Self, BatchVerifierTasks)> {
+        let io = BatchVerifierIo {
+            first_processed_batch,
+            window_size,
+        };
+        let (loader, loader_task) =
+            VmRunnerStorage::new(pool.clone(), rocksdb_path, io.clone(), chain_id).await?;
+        let output_handler_factory = BatchVerifierOutputHandlerFactory {
+            pool: pool.clone(),
+            commitment_generator: Arc::new(CommitmentGenerator::new(pool.clone(), commitment_mode)),
+        };
+        let (output_handler_factory, output_handler_factory_task) =
+            ConcurrentOutputHandlerFactory::new(pool.clone(), io.clone(), output_handler_factory);
+        // Batches are sealed by the state keeper using the legacy VM, so re-executing them with the fast VM
+        // catches nondeterminism and implementation bugs specific to either of the VMs.
+        let mut batch_processor = MainBatchExecutorFactory::<()>::new(false);
+        batch_processor.set_fast_vm_mode(FastVmMode::New);
+        let vm_runner = VmRunner::new(
+            pool,
+            Arc::new(io),
+            Arc::new(loader),
+            Arc::new(output_handler_factory),
+            Box::new(batch_processor),
+        );
+        Ok((
+            Self { vm_runner },
+            BatchVerifierTasks {
+                loader_task,
+                output_handler_factory_task,
+            },
+        ))
+    }
+
+    /// Continuously loads new available batches, re-executes them and persists recomputed commitments.
+    ///
+    /// # Errors
+    ///
+    /// Propagates RocksDB and Postgres errors.
+    pub async fn run(self, stop_receiver: &watch::Receiver<bool>) -> anyhow::Result<()> {
+        self.vm_runner.run(stop_receiver).await
+    }
+}
+
+/// A collections of tasks that need to be run in order for batch verifier to work as intended.
+#[derive(Debug)]
+pub struct BatchVerifierTasks {
+    /// Task that synchronizes storage with new available batches.
+    pub loader_task: StorageSyncTask<BatchVerifierIo>,
+    /// Task that handles output from processed batches.
+    pub output_handler_factory_task: ConcurrentOutputHandlerFactoryTask<BatchVerifierIo>,
+}
+
+/// `VmRunnerIo` implementation for batch verifier.
+#[derive(Debug, Clone)]
+pub struct BatchVerifierIo {
+    first_processed_batch: L1BatchNumber,
+    window_size: u32,
+}
+
+#[async_trait]
+impl VmRunnerIo for BatchVerifierIo {
+    fn name(&self) -> &'static str {
+        "batch_verifier"
+    }
+
+    async fn latest_processed_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_batch_verifier_latest_processed_batch()
+            .await?
+            .unwrap_or(self.first_processed_batch))
+    }
+
+    async fn last_ready_to_be_loaded_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_batch_verifier_last_ready_batch(self.first_processed_batch, self.window_size)
+            .await?)
+    }
+
+    async fn mark_l1_batch_as_processing(
+        &self,
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        Ok(conn
+            .vm_runner_dal()
+            .mark_batch_verifier_batch_as_processing(l1_batch_number)
+            .await?)
+    }
+
+    async fn mark_l1_batch_as_completed(
+        &self,
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        conn.vm_runner_dal()
+            .mark_batch_verifier_batch_as_completed(l1_batch_number)
+            .await
+    }
+}
+
+#[derive(Debug)]
+struct BatchVerifierOutputHandler {
+    l1_batch_number: L1BatchNumber,
+    pool: ConnectionPool<Core>,
+    commitment_generator: Arc<CommitmentGenerator>,
+}
+
+impl BatchVerifierOutputHandler {
+    /// Waits until the Merkle tree processes the batch; the tree root hash is a part of the commitment input.
+    async fn wait_for_tree_data(&self) -> anyhow::Result<()> {
+        loop {
+            let mut connection = self.pool.connection_tagged("batch_verifier").await?;
+            let tree_data = connection
+                .blocks_dal()
+                .get_l1_batch_tree_data(self.l1_batch_number)
+                .await?;
+            drop(connection);
+            if tree_data.is_some() {
+                return Ok(());
+            }
+            tokio::time::sleep(TREE_DATA_POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[async_trait]
+impl OutputHandler for BatchVerifierOutputHandler {
+    async fn handle_l2_block(
+        &mut self,
+        _env: L2BlockEnv,
+        _output: &L2BlockOutput,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        name = "BatchVerifierOutputHandler::handle_l1_batch",
+        skip_all,
+        fields(l1_batch = %self.l1_batch_number)
+    )]
+    async fn handle_l1_batch(self: Box<Self>, output: Arc<L1BatchOutput>) -> anyhow::Result<()> {
+        let l1_batch_number = self.l1_batch_number;
+        let batch = &output.batch;
+        let execution_state = &batch.final_execution_state;
+        let outputs = L1BatchExecutionOutputs {
+            l2_to_l1_logs: execution_state.user_l2_to_l1_logs.clone(),
+            system_logs: execution_state.system_logs.clone(),
+            events: execution_state.events.clone(),
+            state_diffs: batch.state_diffs.clone().unwrap_or_default(),
+            initial_bootloader_contents: batch.final_bootloader_memory.clone().unwrap_or_default(),
+            pubdata_input: batch.pubdata_input.clone(),
+        };
+
+        self.wait_for_tree_data().await?;
+        let Some(commitment) = self
+            .commitment_generator
+            .recompute_commitment(l1_batch_number, outputs)
+            .await?
+        else {
+            tracing::warn!(
+                l1_batch_number = %l1_batch_number,
+                "Verifying pre-boojum batches is not supported"
+            );
+            return Ok(());
+        };
+
+        let mut connection = self.pool.connection_tagged("batch_verifier").await?;
+        connection
+            .vm_runner_dal()
+            .save_batch_verifier_commitment(l1_batch_number, commitment)
+            .await?;
+
+        // The commitment generator may not have processed the batch yet; in this case, the commitments
+        // will be compared by the ETH sender.
+        let expected_commitment = connection
+            .blocks_dal()
+            .get_l1_batch_metadata(l1_batch_number)
+            .await?
+            .map(|batch| batch.metadata.commitment);
+        match expected_commitment {
+            Some(expected) if expected != commitment => {
+                BATCH_VERIFIER_METRICS.diverged_batches.inc();
+                tracing::error!(
+                    l1_batch_number = %l1_batch_number,
+                    expected = ?expected,
+                    recomputed = ?commitment,
+                    "Commitment recomputed by re-executing batch differs from the persisted one"
+                );
+            }
+            Some(_) => {
+                BATCH_VERIFIER_METRICS
+                    .last_verified_batch
+                    .set(l1_batch_number.0.into());
+                tracing::debug!(l1_batch_number = %l1_batch_number, "Verified batch commitment");
+            }
+            None => { /* Will be checked by the ETH sender */ }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct BatchVerifierOutputHandlerFactory {
+    pool: ConnectionPool<Core>,
+    commitment_generator: Arc<CommitmentGenerator>,
+}
+
+#[async_trait]
+impl OutputHandlerFactory for BatchVerifierOutputHandlerFactory {
+    async fn create_handler(
+        &self,
+        _system_env: SystemEnv,
+        l1_batch_env: L1BatchEnv,
+    ) -> anyhow::Result<Box<dyn OutputHandler>> {
+        Ok(Box::new(BatchVerifierOutputHandler {
+            l1_batch_number: l1_batch_env.number,
+            pool: self.pool.clone(),
+            commitment_generator: self.commitment_generator.clone(),
+        }))
+    }
+}
@@ -1,10 +1,12 @@
 //! Components powered by a VM runner.
 
+mod batch_verifier;
 mod bwip;
 mod playground;
 mod protective_reads;
 
 pub use self::{
+    batch_verifier::{BatchVerifier, BatchVerifierIo, BatchVerifierTasks},
     bwip::{
         BasicWitnessInputProducer, BasicWitnessInputProducerIo, BasicWitnessInputProducerTasks,
     },
@@ -2,7 +2,9 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit};
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit,
+};
 use zksync_state::OwnedStorage;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
@@ -50,3 +52,16 @@ pub(super) struct VmRunnerMetrics {
 
 #[vise::register]
 pub(super) static METRICS: vise::Global<VmRunnerMetrics> = vise::Global::new();
+
+/// Metrics for the batch verifier.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "vm_runner_batch_verifier")]
+pub(super) struct BatchVerifierMetrics {
+    /// Last batch with the recomputed commitment matching the persisted one.
+    pub last_verified_batch: Gauge<u64>,
+    /// Number of batches with the recomputed commitment differing from the persisted one.
+    pub diverged_batches: Counter,
+}
+
+#[vise::register]
+pub(super) static BATCH_VERIFIER_METRICS: vise::Global<BatchVerifierMetrics> = vise::Global::new();
@@ -0,0 +1,97 @@
+//! Differential fuzzing of the legacy and fast VMs. Each iteration generates a workload from a seed,
+//! runs it on both VMs and compares their outputs. Failing workloads are dumped to the artifacts directory.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+use vm_benchmark::fuzz::{FuzzConfig, FuzzFailure, Workload};
+
+#[derive(Debug, Parser)]
+#[command(about = "Differential fuzzing of the legacy and fast VMs")]
+struct Cli {
+    /// Seed of the first workload; workload `i` uses `seed + i`. If not specified, a random seed is used.
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Number of workloads to execute.
+    #[arg(long, default_value_t = 100)]
+    iterations: u64,
+    /// Number of transactions in each workload.
+    #[arg(long, default_value_t = 20)]
+    txs_per_batch: usize,
+    /// Enable the EVM emulator in the base system contracts.
+    #[arg(long)]
+    evm_emulator: bool,
+    /// Directory to write failing workloads to.
+    #[arg(long, default_value = "vm_fuzz_artifacts")]
+    artifacts_dir: PathBuf,
+    /// Continue fuzzing after a failure instead of stopping.
+    #[arg(long)]
+    keep_going: bool,
+}
+
+impl Cli {
+    fn dump_failure(&self, workload: &Workload, failure: &FuzzFailure) -> anyhow::Result<PathBuf> {
+        fs::create_dir_all(&self.artifacts_dir).with_context(|| {
+            format!(
+                "failed creating artifacts dir {}",
+                self.artifacts_dir.display()
+            )
+        })?;
+        let path = self
+            .artifacts_dir
+            .join(format!("seed-{}.json", workload.seed));
+        let artifact = serde_json::json!({
+            "workload": workload,
+            "failure": failure,
+        });
+        let artifact = serde_json::to_string_pretty(&artifact)?;
+        fs::write(&path, artifact)
+            .with_context(|| format!("failed writing artifact to {}", path.display()))?;
+        Ok(path)
+    }
+
+    fn run(self) -> anyhow::Result<()> {
+        let first_seed = self.seed.unwrap_or_else(rand::random);
+        let config = FuzzConfig {
+            txs_per_batch: self.txs_per_batch,
+            evm_emulator: self.evm_emulator,
+        };
+        println!(
+            "Fuzzing VMs with {} workloads starting from seed {first_seed}, config: {config:?}",
+            self.iterations
+        );
+
+        let mut failed_seeds = vec![];
+        for i in 0..self.iterations {
+            let seed = first_seed.wrapping_add(i);
+            let workload = Workload::generate(seed, config);
+            let Err(failure) = workload.execute() else {
+                continue;
+            };
+
+            let path = self.dump_failure(&workload, &failure)?;
+            println!(
+                "Seed {seed}: {:?}: {}\nWorkload is dumped to {}; reproduce with `--seed {seed} --iterations 1`",
+                failure.kind,
+                failure.message,
+                path.display()
+            );
+            failed_seeds.push(seed);
+            if !self.keep_going {
+                break;
+            }
+        }
+
+        anyhow::ensure!(
+            failed_seeds.is_empty(),
+            "VM fuzzing failed for seeds {failed_seeds:?}"
+        );
+        println!("No divergences detected");
+        Ok(())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    Cli::parse().run()
+}
@@ -0,0 +1,355 @@
+//! Differential fuzzing of the legacy and fast VMs.
+//!
+//! Workloads are generated deterministically from a seed and are executed on [`ShadowVm`] with the legacy VM
+//! as the main VM and the fast VM as the shadow one. The shadow VM compares execution results (incl. storage logs,
+//! events and gas stats) after each operation and reports divergences together with a [`VmDump`].
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use zksync_contracts::BaseSystemContracts;
+use zksync_multivm::{
+    interface::{
+        storage::{ImmutableStorageView, InMemoryStorage, StorageView},
+        utils::{DivergenceHandler, ShadowVm, VmDump},
+        ExecutionResult, L1BatchEnv, L2BlockEnv, SystemEnv, TxExecutionMode, VmFactory,
+        VmInterface, VmInterfaceHistoryEnabled,
+    },
+    pubdata_builders::RollupPubdataBuilder,
+    vm_fast,
+    vm_latest::{self, constants::BATCH_COMPUTATIONAL_GAS_LIMIT, HistoryEnabled},
+};
+use zksync_test_contracts::{Account, TestContract, TxType};
+use zksync_types::{
+    block::L2BlockHasher, ethabi::Token, fee_model::BatchFeeInput, u256_to_h256,
+    utils::storage_key_for_eth_balance, Address, Execute, L1BatchNumber, L2BlockNumber, L2ChainId,
+    ProtocolVersionId, Transaction, H256, U256,
+};
+
+use crate::{transaction::tx_fee, LoadTestParams, BYTECODES};
+
+/// Legacy VM shadowed by the fast VM.
+type FuzzedVm = ShadowVm<
+    InMemoryStorage,
+    vm_latest::Vm<StorageView<InMemoryStorage>, HistoryEnabled>,
+    vm_fast::Vm<ImmutableStorageView<InMemoryStorage>>,
+>;
+
+const ACCOUNT_COUNT: usize = 4;
+const LOAD_TEST_MAX_READS: usize = 100;
+/// Fixed timestamp of the fuzzed batch, so that workloads are reproducible.
+const BATCH_TIMESTAMP: u64 = 1_700_000_000;
+
+/// Configuration of generated workloads.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FuzzConfig {
+    /// Number of transactions in each workload.
+    pub txs_per_batch: usize,
+    /// Whether the EVM emulator is enabled in the base system contracts.
+    pub evm_emulator: bool,
+}
+
+/// Transactions executed in a single L1 batch, generated from a seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workload {
+    pub seed: u64,
+    pub config: FuzzConfig,
+    /// Rich accounts sending transactions.
+    pub accounts: Vec<Address>,
+    /// Transactions split into L2 blocks.
+    pub l2_blocks: Vec<Vec<Transaction>>,
+}
+
+/// Kind of [`FuzzFailure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FuzzFailureKind {
+    /// VMs produced different outputs.
+    Divergence,
+    /// One of the VMs panicked.
+    Crash,
+}
+
+/// Failure detected when executing a [`Workload`].
+#[derive(Debug, Serialize)]
+pub struct FuzzFailure {
+    pub kind: FuzzFailureKind,
+    pub message: String,
+    /// State of the main VM at the moment of divergence. Not available for crashes.
+    pub dump: Option<VmDump>,
+}
+
+impl Workload {
+    /// Deterministically generates a workload from the provided seed.
+    pub fn generate(seed: u64, config: FuzzConfig) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut accounts: Vec<_> = (0..ACCOUNT_COUNT)
+            .map(|_| Account::random_using(&mut rng))
+            .collect();
+        let account_addresses = accounts.iter().map(|account| account.address).collect();
+
+        let load_test_deployment = accounts[0].get_deploy_tx(
+            TestContract::load_test().bytecode,
+            Some(&[Token::Uint(LOAD_TEST_MAX_READS.into())]),
+            TxType::L2,
+        );
+        let load_test_address = load_test_deployment.address;
+        let mut l2_blocks = vec![vec![load_test_deployment.tx]];
+
+        for _ in 0..config.txs_per_batch {
+            if rng.gen_ratio(1, 4) {
+                l2_blocks.push(vec![]);
+            }
+
+            let sender_idx = rng.gen_range(0..accounts.len());
+            let tx = match rng.gen_range(0..3) {
+                0 => {
+                    let recipient = if rng.gen() {
+                        accounts.choose(&mut rng).unwrap().address
+                    } else {
+                        Address::random_using(&mut rng)
+                    };
+                    let execute = Execute {
+                        contract_address: Some(recipient),
+                        calldata: vec![],
+                        value: U256::from(rng.gen_range(0..1_000_000_000_000_u64)),
+                        factory_deps: vec![],
+                    };
+                    let gas_limit = Self::gen_gas_limit(&mut rng, 1_000_000);
+                    accounts[sender_idx].get_l2_tx_for_execute(execute, Some(tx_fee(gas_limit)))
+                }
+                1 => {
+                    let bytecode = BYTECODES.choose(&mut rng).unwrap();
+                    let execute = Execute::for_deploy(
+                        H256::random_using(&mut rng),
+                        bytecode.bytecode().to_vec(),
+                        &[],
+                    );
+                    let gas_limit = Self::gen_gas_limit(&mut rng, 30_000_000);
+                    accounts[sender_idx].get_l2_tx_for_execute(execute, Some(tx_fee(gas_limit)))
+                }
+                _ => {
+                    let params = LoadTestParams {
+                        reads: rng.gen_range(0..=LOAD_TEST_MAX_READS),
+                        initial_writes: rng.gen_range(0..=20),
+                        repeated_writes: rng.gen_range(0..=20),
+                        events: rng.gen_range(0..=20),
+                        hashes: rng.gen_range(0..=20),
+                        recursive_calls: rng.gen_range(0..=5),
+                        deploys: rng.gen_range(0..=3),
+                    };
+                    let execute = Execute {
+                        contract_address: Some(load_test_address),
+                        calldata: params.to_bytes(),
+                        value: U256::zero(),
+                        factory_deps: vec![],
+                    };
+                    let gas_limit = Self::gen_gas_limit(&mut rng, 50_000_000);
+                    accounts[sender_idx].get_l2_tx_for_execute(execute, Some(tx_fee(gas_limit)))
+                }
+            };
+            l2_blocks.last_mut().unwrap().push(tx);
+        }
+
+        Self {
+            seed,
+            config,
+            accounts: account_addresses,
+            l2_blocks,
+        }
+    }
+
+    /// Most transactions get a sufficient gas limit, but some are deliberately starved to exercise out-of-gas paths.
+    fn gen_gas_limit(rng: &mut impl Rng, sufficient_limit: u32) -> u32 {
+        if rng.gen_ratio(1, 5) {
+            rng.gen_range(300_000..sufficient_limit)
+        } else {
+            sufficient_limit
+        }
+    }
+
+    fn storage(&self) -> InMemoryStorage {
+        let mut storage = InMemoryStorage::with_system_contracts();
+        let balance = U256::from(10_u32).pow(U256::from(32));
+        for address in &self.accounts {
+            let key = storage_key_for_eth_balance(address);
+            storage.set_value(key, u256_to_h256(balance));
+        }
+        storage
+    }
+
+    fn env(&self) -> (SystemEnv, L1BatchEnv) {
+        let mut base_system_smart_contracts = BaseSystemContracts::load_from_disk();
+        if self.config.evm_emulator {
+            base_system_smart_contracts = base_system_smart_contracts.with_latest_evm_emulator();
+        }
+        let system_env = SystemEnv {
+            zk_porter_available: false,
+            version: ProtocolVersionId::latest(),
+            base_system_smart_contracts,
+            bootloader_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
+            execution_mode: TxExecutionMode::VerifyExecute,
+            default_validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
+            chain_id: L2ChainId::from(270),
+        };
+        let l1_batch_env = L1BatchEnv {
+            previous_batch_hash: None,
+            number: L1BatchNumber(1),
+            timestamp: BATCH_TIMESTAMP,
+            fee_input: BatchFeeInput::l1_pegged(
+                50_000_000_000, // 50 gwei
+                250_000_000,    // 0.25 gwei
+            ),
+            fee_account: Address::repeat_byte(0xfe),
+            enforced_base_fee: None,
+            first_l2_block: L2BlockEnv {
+                number: 1,
+                timestamp: BATCH_TIMESTAMP,
+                prev_block_hash: L2BlockHasher::legacy_hash(L2BlockNumber(0)),
+                max_virtual_blocks_to_create: 100,
+            },
+        };
+        (system_env, l1_batch_env)
+    }
+
+    /// Executes this workload on both VMs, returning the first detected failure.
+    pub fn execute(&self) -> Result<(), FuzzFailure> {
+        let divergence = Arc::new(Mutex::new(None));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| self.execute_inner(&divergence)));
+        if let Err(panic) = result {
+            return Err(FuzzFailure {
+                kind: FuzzFailureKind::Crash,
+                message: panic_message(panic),
+                dump: None,
+            });
+        }
+
+        match divergence.lock().unwrap().take() {
+            Some((message, dump)) => Err(FuzzFailure {
+                kind: FuzzFailureKind::Divergence,
+                message,
+                dump: Some(dump),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn execute_inner(&self, divergence: &Arc<Mutex<Option<(String, VmDump)>>>) {
+        let (system_env, l1_batch_env) = self.env();
+        let mut current_block = l1_batch_env.first_l2_block;
+        let storage = StorageView::new(self.storage()).to_rc_ptr();
+        let mut vm = FuzzedVm::new(l1_batch_env, system_env, storage);
+        let divergence_sink = divergence.clone();
+        vm.set_divergence_handler(DivergenceHandler::new(move |err, dump| {
+            divergence_sink
+                .lock()
+                .unwrap()
+                .get_or_insert((err.to_string(), dump));
+        }));
+        let has_diverged = || divergence.lock().unwrap().is_some();
+
+        let mut executed_tx_hashes = vec![];
+        for (i, txs) in self.l2_blocks.iter().enumerate() {
+            if i > 0 {
+                current_block = L2BlockEnv {
+                    number: current_block.number + 1,
+                    timestamp: current_block.timestamp + 1,
+                    prev_block_hash: hash_block(current_block, &executed_tx_hashes),
+                    max_virtual_blocks_to_create: current_block.max_virtual_blocks_to_create,
+                };
+                vm.start_new_l2_block(current_block);
+                executed_tx_hashes.clear();
+            }
+
+            for tx in txs {
+                vm.make_snapshot();
+                let (compression_result, tx_result) =
+                    vm.execute_transaction_with_bytecode_compression(tx.clone(), true);
+                // Mimic the state keeper, which excludes halted transactions from the batch.
+                if compression_result.is_err()
+                    || matches!(tx_result.result, ExecutionResult::Halt { .. })
+                {
+                    vm.rollback_to_the_latest_snapshot();
+                } else {
+                    vm.pop_snapshot_no_rollback();
+                    executed_tx_hashes.push(tx.hash());
+                }
+                if has_diverged() {
+                    return;
+                }
+            }
+        }
+
+        vm.finish_batch(Rc::new(RollupPubdataBuilder::new(Address::zero())));
+    }
+}
+
+fn hash_block(block_env: L2BlockEnv, tx_hashes: &[H256]) -> H256 {
+    let mut hasher = L2BlockHasher::new(
+        L2BlockNumber(block_env.number),
+        block_env.timestamp,
+        block_env.prev_block_hash,
+    );
+    for &tx_hash in tx_hashes {
+        hasher.push_tx_hash(tx_hash);
+    }
+    hasher.finalize(ProtocolVersionId::latest())
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "VM panicked with a non-string payload".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: FuzzConfig = FuzzConfig {
+        txs_per_batch: 10,
+        evm_emulator: false,
+    };
+
+    #[test]
+    fn workload_generation_is_deterministic() {
+        let workload = Workload::generate(42, CONFIG);
+        let tx_count: usize = workload.l2_blocks.iter().map(Vec::len).sum();
+        assert_eq!(tx_count, CONFIG.txs_per_batch + 1);
+
+        let other_workload = Workload::generate(42, CONFIG);
+        let tx_hashes = |workload: &Workload| -> Vec<_> {
+            workload
+                .l2_blocks
+                .iter()
+                .flatten()
+                .map(Transaction::hash)
+                .collect()
+        };
+        assert_eq!(tx_hashes(&workload), tx_hashes(&other_workload));
+        assert_ne!(
+            tx_hashes(&workload),
+            tx_hashes(&Workload::generate(43, CONFIG))
+        );
+    }
+
+    #[test]
+    fn fuzzed_vms_do_not_diverge() {
+        for seed in 0..3 {
+            let workload = Workload::generate(seed, CONFIG);
+            if let Err(failure) = workload.execute() {
+                panic!("seed {seed}: {:?}: {}", failure.kind, failure.message);
+            }
+        }
+    }
+}
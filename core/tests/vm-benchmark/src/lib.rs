@@ -10,6 +10,7 @@ pub use crate::{
 };
 
 pub mod criterion;
+pub mod fuzz;
 mod instruction_counter;
 mod transaction;
 mod vm;
@@ -27,7 +27,7 @@ pub fn get_deploy_tx_with_gas_limit(code: &[u8], gas_limit: u32, nonce: u32) ->
     account.get_l2_tx_for_execute(execute, Some(tx_fee(gas_limit)))
 }
 
-fn tx_fee(gas_limit: u32) -> Fee {
+pub(crate) fn tx_fee(gas_limit: u32) -> Fee {
     Fee {
         gas_limit: U256::from(gas_limit),
         max_fee_per_gas: U256::from(250_000_000),
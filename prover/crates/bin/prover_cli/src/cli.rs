@@ -3,7 +3,7 @@ use zksync_types::url::SensitiveUrl;
 
 use crate::commands::{
     config, debug_proof, delete, get_file_info, insert_batch, insert_version, requeue, restart,
-    stats, status::StatusCommand,
+    stats, status::StatusCommand, verify_proof,
 };
 
 pub const VERSION_STRING: &str = env!("CARGO_PKG_VERSION");
@@ -30,6 +30,7 @@ impl ProverCLI {
             ProverCommand::Stats(args) => stats::run(args, self.config).await?,
             ProverCommand::InsertVersion(args) => insert_version::run(args, self.config).await?,
             ProverCommand::InsertBatch(args) => insert_batch::run(args, self.config).await?,
+            ProverCommand::VerifyProof(args) => verify_proof::run(args).await?,
         };
         Ok(())
     }
@@ -62,4 +63,8 @@ pub enum ProverCommand {
     Stats(stats::Options),
     InsertVersion(insert_version::Args),
     InsertBatch(insert_batch::Args),
+    #[command(
+        about = "Verifies the final proof of an L1 batch against the local verification key"
+    )]
+    VerifyProof(verify_proof::Args),
 }
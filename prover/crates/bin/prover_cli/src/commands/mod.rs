@@ -8,3 +8,4 @@ pub(crate) mod requeue;
 pub(crate) mod restart;
 pub(crate) mod stats;
 pub mod status;
+pub(crate) mod verify_proof;
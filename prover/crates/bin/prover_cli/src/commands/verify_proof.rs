@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+use zksync_basic_types::{web3::keccak256_concat, L1BatchNumber, H256, U256};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_prover_fri_types::circuit_definitions::{
+    circuit_definitions::aux_layer::ZkSyncSnarkWrapperCircuit,
+    snark_wrapper::franklin_crypto::bellman::{
+        pairing::bn256::{Bn256, Fr},
+        plonk::{
+            better_better_cs::{proof::Proof, setup::VerificationKey, verifier::verify},
+            commitments::transcript::keccak_transcript::RollingKeccakTranscript,
+        },
+        PrimeField, PrimeFieldRepr,
+    },
+};
+use zksync_prover_interface::outputs::L1BatchProofForL1;
+use zksync_prover_keystore::{keystore::Keystore, utils::calculate_snark_vk_hash};
+use zksync_types::url::SensitiveUrl;
+
+/// Number of bits the public input is shifted by, so that it fits into the scalar field.
+/// Must match `PUBLIC_INPUT_SHIFT` in the L1 `Executor` contract.
+const PUBLIC_INPUT_SHIFT: usize = 32;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Batch the proof is for.
+    #[clap(short, long)]
+    batch: L1BatchNumber,
+    /// File with the final proof of the batch, as stored in the object store
+    /// (`proofs_fri/l1_batch_proof_<batch>_<version>.bin`).
+    #[clap(short, long)]
+    file: PathBuf,
+    /// URL of the core database to load batch commitments from.
+    #[clap(long, env("PLI__CORE_DB_URL"))]
+    core_db_url: SensitiveUrl,
+    /// Directory with the verification keys. Defaults to `prover/data/keys`.
+    #[clap(long)]
+    keystore: Option<PathBuf>,
+}
+
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    let l1_batch_proof: L1BatchProofForL1 = bincode::deserialize(
+        &std::fs::read(&args.file)
+            .with_context(|| format!("failed reading proof from {:?}", args.file))?,
+    )
+    .context("failed deserializing proof")?;
+
+    let keystore = args.keystore.map_or_else(Keystore::locate, Keystore::new);
+    let vk: VerificationKey<Bn256, ZkSyncSnarkWrapperCircuit> =
+        serde_json::from_str(&keystore.load_snark_verification_key()?)
+            .context("failed parsing snark verification key")?;
+    let vk_hash = calculate_snark_vk_hash(&keystore)?;
+
+    // `FinalProof` is compatible with the snark wrapper proof on the serialization level
+    // (see the proof compressor).
+    let proof: Proof<Bn256, ZkSyncSnarkWrapperCircuit> =
+        bincode::deserialize(&bincode::serialize(&l1_batch_proof.scheduler_proof)?)
+            .context("failed converting final proof")?;
+
+    println!(" ====== Proof of batch {} ====== ", args.batch);
+    println!("Protocol version: {}", l1_batch_proof.protocol_version);
+    println!("Verification key hash: {vk_hash:?}");
+
+    let is_valid =
+        verify::<Bn256, ZkSyncSnarkWrapperCircuit, RollingKeccakTranscript<Fr>>(&vk, &proof, None)
+            .map_err(|err| anyhow::anyhow!("failed verifying proof: {err:?}"))?;
+    pretty_print_check("Proof", is_valid);
+
+    let public_input = match proof.inputs.as_slice() {
+        [input] => fr_to_u256(input)?,
+        inputs => anyhow::bail!("expected 1 public input, got {}", inputs.len()),
+    };
+    let expected_public_input = expected_public_input(args.core_db_url, args.batch).await?;
+    println!("Public input: {public_input:#x}");
+    println!("Expected public input: {expected_public_input:#x}");
+    let is_input_valid = public_input == expected_public_input;
+    pretty_print_check("Public input", is_input_valid);
+
+    anyhow::ensure!(
+        is_valid && is_input_valid,
+        "proof of batch {} is invalid",
+        args.batch
+    );
+    Ok(())
+}
+
+/// Computes the public input the same way as the L1 `Executor` contract:
+/// `keccak256(prev_batch_commitment ++ batch_commitment) >> PUBLIC_INPUT_SHIFT`.
+async fn expected_public_input(
+    core_db_url: SensitiveUrl,
+    batch: L1BatchNumber,
+) -> anyhow::Result<U256> {
+    anyhow::ensure!(batch.0 > 0, "genesis batch doesn't have a proof");
+    let pool = ConnectionPool::<Core>::singleton(core_db_url)
+        .build()
+        .await
+        .context("failed to build a core connection pool")?;
+    let mut conn = pool.connection().await?;
+
+    let mut commitments = [H256::zero(); 2];
+    for (commitment, number) in commitments.iter_mut().zip([batch - 1, batch]) {
+        *commitment = conn
+            .blocks_dal()
+            .get_l1_batch_metadata(number)
+            .await?
+            .with_context(|| format!("metadata for batch {number} is not available"))?
+            .metadata
+            .commitment;
+    }
+
+    let hash = keccak256_concat(commitments[0], commitments[1]);
+    Ok(U256::from_big_endian(hash.as_bytes()) >> PUBLIC_INPUT_SHIFT)
+}
+
+fn fr_to_u256(value: &Fr) -> anyhow::Result<U256> {
+    let mut bytes = Vec::with_capacity(32);
+    value.into_repr().write_be(&mut bytes)?;
+    Ok(U256::from_big_endian(&bytes))
+}
+
+fn pretty_print_check(name: &str, is_valid: bool) {
+    if is_valid {
+        println!("{name}: {}", "valid".green().bold());
+    } else {
+        println!("{name}: {}", "INVALID".red().bold());
+    }
+}
@@ -28,6 +28,8 @@ use zksync_types::{
 use zksync_utils::wait_for_tasks::ManagedTasks;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
+use crate::utils::fetch_missing_setup_data;
+
 mod gpu_prover_availability_checker;
 mod gpu_prover_job_processor;
 mod metrics;
@@ -193,12 +195,20 @@ async fn get_prover_tasks(
         protocol_version
     );
 
+    let blob_store = store_factory.create_store().await?;
     let keystore =
         Keystore::locate().with_setup_path(Some(prover_config.setup_data_path.clone().into()));
+    fetch_missing_setup_data(
+        &keystore,
+        blob_store.as_ref(),
+        &circuit_ids_for_round_to_be_proven,
+    )
+    .await
+    .context("fetch_missing_setup_data()")?;
     let setup_load_mode =
         load_setup_data_cache(&keystore, &prover_config).context("load_setup_data_cache()")?;
     let prover = Prover::new(
-        store_factory.create_store().await?,
+        blob_store,
         public_blob_store,
         prover_config,
         keystore,
@@ -229,8 +239,16 @@ async fn get_prover_tasks(
     use zksync_prover_fri_types::queue::FixedSizeQueue;
     use zksync_prover_keystore::keystore::Keystore;
 
+    let blob_store = store_factory.create_store().await?;
     let keystore =
         Keystore::locate().with_setup_path(Some(prover_config.setup_data_path.clone().into()));
+    fetch_missing_setup_data(
+        &keystore,
+        blob_store.as_ref(),
+        &circuit_ids_for_round_to_be_proven,
+    )
+    .await
+    .context("fetch_missing_setup_data()")?;
     let setup_load_mode = gpu_prover::load_setup_data_cache(
         &keystore,
         prover_config.setup_load_mode,
@@ -253,7 +271,7 @@ async fn get_prover_tasks(
 
     let prover = gpu_prover::Prover::new(
         keystore,
-        store_factory.create_store().await?,
+        blob_store,
         public_blob_store,
         prover_config.clone(),
         pool.clone(),
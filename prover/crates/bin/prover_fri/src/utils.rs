@@ -1,10 +1,11 @@
 #![cfg_attr(not(feature = "gpu"), allow(unused_imports))]
 
-use std::{sync::Arc, time::Instant};
+use std::{collections::HashSet, sync::Arc, time::Instant};
 
+use anyhow::Context as _;
 use tokio::sync::Mutex;
 use zkevm_test_harness::prover_utils::{verify_base_layer_proof, verify_recursion_layer_proof};
-use zksync_object_store::ObjectStore;
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError};
 use zksync_prover_dal::{Connection, Prover, ProverDal};
 use zksync_prover_fri_types::{
     circuit_definitions::{
@@ -22,6 +23,7 @@ use zksync_prover_fri_types::{
     queue::FixedSizeQueue,
     CircuitWrapper, FriProofWrapper, ProverServiceDataKey, WitnessVectorArtifacts,
 };
+use zksync_prover_keystore::keystore::Keystore;
 use zksync_types::{
     basic_fri_types::{AggregationRound, CircuitIdRoundTuple},
     protocol_version::ProtocolSemanticVersion,
@@ -159,6 +161,54 @@ pub fn setup_metadata_to_setup_data_key(
     }
 }
 
+/// Downloads setup data missing on the local disk from the object store. If the store has a local mirror,
+/// setup data is cached in the mirror, so that co-located provers don't re-download it.
+pub async fn fetch_missing_setup_data(
+    keystore: &Keystore,
+    blob_store: &dyn ObjectStore,
+    setup_metadata: &[CircuitIdRoundTuple],
+) -> anyhow::Result<()> {
+    let keys: HashSet<_> = setup_metadata
+        .iter()
+        .map(setup_metadata_to_setup_data_key)
+        .collect();
+
+    for key in keys {
+        if keystore.is_setup_data_present(&key) {
+            continue;
+        }
+        let path = keystore.setup_data_file_path(key);
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .with_context(|| format!("invalid setup data path: {path:?}"))?;
+        let data = match blob_store.get_raw(Bucket::SetupData, file_name).await {
+            Ok(data) => data,
+            Err(ObjectStoreError::KeyNotFound(_)) => {
+                tracing::warn!(
+                    "Setup data for {key:?} is missing both locally and in object store"
+                );
+                continue;
+            }
+            Err(err) => {
+                return Err(anyhow::Error::from(err))
+                    .with_context(|| format!("failed fetching setup data for {key:?}"));
+            }
+        };
+
+        // Write data to a temporary file first, so that co-located provers never observe partially written setup data.
+        let tmp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        tokio::fs::write(&tmp_path, data)
+            .await
+            .with_context(|| format!("failed writing setup data to {tmp_path:?}"))?;
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .with_context(|| format!("failed moving setup data to {path:?}"))?;
+        tracing::info!("Fetched setup data for {key:?} from object store to {path:?}");
+    }
+    Ok(())
+}
+
 pub fn get_setup_data_key(key: ProverServiceDataKey) -> ProverServiceDataKey {
     match key.round {
         AggregationRound::NodeAggregation => {
@@ -3,18 +3,26 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
-use zksync_prover_interface::api::{SubmitProofRequest, SubmitProofResponse};
+use zksync_prover_interface::api::{
+    ProvingStageTimestamp, SubmitProofRequest, SubmitProofResponse, SubmitProvingStagesRequest,
+    SubmitProvingStagesResponse,
+};
 use zksync_types::{prover_dal::ProofCompressionJobStatus, L1BatchNumber};
 
 use crate::{client::ProverApiClient, traits::PeriodicApi};
 
 /// The path to the API endpoint that submits the proof.
 const SUBMIT_PROOF_PATH: &str = "/submit_proof";
+/// The path to the API endpoint that accepts timestamps of proving stages.
+const SUBMIT_PROVING_STAGES_PATH: &str = "/submit_proving_stages";
 
 /// Poller structure that will periodically check the database for new proofs to submit.
-/// Once a new proof is detected, it will be sent to the prover API.
+/// Once a new proof is detected, it will be sent to the prover API together with timestamps of proving stages.
 #[derive(Debug)]
-pub struct ProofSubmitter(ProverApiClient);
+pub struct ProofSubmitter {
+    client: ProverApiClient,
+    proving_stages_url: String,
+}
 
 impl ProofSubmitter {
     pub(crate) fn new(
@@ -23,15 +31,18 @@ impl ProofSubmitter {
         pool: ConnectionPool<Prover>,
     ) -> Self {
         let api_url = format!("{base_url}{SUBMIT_PROOF_PATH}");
-        let inner = ProverApiClient::new(blob_store, pool, api_url);
-        Self(inner)
+        let client = ProverApiClient::new(blob_store, pool, api_url);
+        Self {
+            client,
+            proving_stages_url: format!("{base_url}{SUBMIT_PROVING_STAGES_PATH}"),
+        }
     }
 }
 
 impl ProofSubmitter {
     async fn next_submit_proof_request(&self) -> Option<(L1BatchNumber, SubmitProofRequest)> {
         let (l1_batch_number, protocol_version, status) = self
-            .0
+            .client
             .pool
             .connection()
             .await
@@ -43,7 +54,7 @@ impl ProofSubmitter {
         let request = match status {
             ProofCompressionJobStatus::Successful => {
                 let proof = self
-                    .0
+                    .client
                     .blob_store
                     .get((l1_batch_number, protocol_version))
                     .await
@@ -60,8 +71,46 @@ impl ProofSubmitter {
         Some((l1_batch_number, request))
     }
 
+    /// Reports timestamps of proving stages to the server. Errors are logged and otherwise ignored
+    /// since they don't influence proof generation.
+    async fn submit_proving_stages(&self, l1_batch_number: L1BatchNumber) {
+        let stages = self
+            .client
+            .pool
+            .connection()
+            .await
+            .unwrap()
+            .fri_proof_latency_dal()
+            .get_stage_timestamps(l1_batch_number)
+            .await;
+        let stages = match stages {
+            Ok(stages) => stages,
+            Err(err) => {
+                tracing::warn!(
+                    "Failed getting proving stage timestamps for L1 batch #{l1_batch_number}: {err}"
+                );
+                return;
+            }
+        };
+
+        let request = SubmitProvingStagesRequest {
+            stages: stages
+                .into_iter()
+                .map(|(stage, timestamp)| ProvingStageTimestamp { stage, timestamp })
+                .collect(),
+        };
+        let endpoint = format!("{}/{l1_batch_number}", self.proving_stages_url);
+        let response: reqwest::Result<SubmitProvingStagesResponse> =
+            self.client.send_http_request(request, &endpoint).await;
+        if let Err(err) = response {
+            tracing::warn!(
+                "Failed submitting proving stage timestamps for L1 batch #{l1_batch_number}: {err}"
+            );
+        }
+    }
+
     async fn save_successful_sent_proof(&self, l1_batch_number: L1BatchNumber) {
-        self.0
+        self.client
             .pool
             .connection()
             .await
@@ -89,12 +138,14 @@ impl PeriodicApi for ProofSubmitter {
         job_id: Self::JobId,
         request: SubmitProofRequest,
     ) -> reqwest::Result<Self::Response> {
-        let endpoint = format!("{}/{job_id}", self.0.api_url);
-        self.0.send_http_request(request, &endpoint).await
+        let endpoint = format!("{}/{job_id}", self.client.api_url);
+        self.client.send_http_request(request, &endpoint).await
     }
 
     async fn handle_response(&self, job_id: L1BatchNumber, response: Self::Response) {
         tracing::info!("Received response: {:?}", response);
+        // Stage timestamps must be collected before marking the proof as sent, since it updates the compression job.
+        self.submit_proving_stages(job_id).await;
         self.save_successful_sent_proof(job_id).await;
     }
 }
@@ -0,0 +1,127 @@
+use std::{sync::Arc, time::Duration};
+
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError};
+use zksync_prover_dal::{fri_artifacts_gc_dal::BatchArtifactKeys, Connection, Prover, ProverDal};
+
+use crate::{metrics::ARTIFACTS_GC_METRICS, task_wiring::Task};
+
+/// Maximum number of batches processed during a single run of the collector.
+const MAX_BATCHES_PER_RUN: usize = 10;
+
+/// `ArtifactsCollector` is a task that removes intermediate artifacts (witness inputs, circuits, intermediate proofs etc.)
+/// of batches proven on L1 from the object store, together with per-circuit and aggregation jobs from the prover DB.
+/// Artifacts are retained for a certain amount of time after the final proof of a batch was sent to the server.
+/// Note: Without this component, the object store and the prover DB grow indefinitely.
+#[derive(Debug)]
+pub struct ArtifactsCollector {
+    core_pool: ConnectionPool<Core>,
+    object_store: Arc<dyn ObjectStore>,
+    /// duration after the final proof was sent to the server after which artifacts can be removed
+    retention: Duration,
+}
+
+impl ArtifactsCollector {
+    pub fn new(
+        core_pool: ConnectionPool<Core>,
+        object_store: Arc<dyn ObjectStore>,
+        retention: Duration,
+    ) -> Self {
+        Self {
+            core_pool,
+            object_store,
+            retention,
+        }
+    }
+
+    /// Removes all artifacts of a batch, returning the number of reclaimed bytes.
+    async fn remove_artifacts(&self, keys: &BatchArtifactKeys) -> anyhow::Result<u64> {
+        let keys_by_bucket = [
+            (Bucket::WitnessInput, &keys.witness_inputs),
+            (Bucket::ProverJobsFri, &keys.circuits),
+            (Bucket::ProofsFri, &keys.proofs),
+            (
+                Bucket::LeafAggregationWitnessJobsFri,
+                &keys.closed_form_inputs,
+            ),
+            (Bucket::NodeAggregationWitnessJobsFri, &keys.aggregations),
+            (
+                Bucket::SchedulerWitnessJobsFri,
+                &keys.scheduler_partial_inputs,
+            ),
+        ];
+
+        let mut reclaimed_bytes = 0;
+        for (bucket, keys) in keys_by_bucket {
+            for key in keys {
+                let Some(size) = self.remove_object(bucket, key).await? else {
+                    continue;
+                };
+                let bucket = bucket.to_string();
+                ARTIFACTS_GC_METRICS.removed_objects[&bucket].inc();
+                ARTIFACTS_GC_METRICS.reclaimed_bytes[&bucket].inc_by(size);
+                reclaimed_bytes += size;
+            }
+        }
+        Ok(reclaimed_bytes)
+    }
+
+    /// Removes an object returning its size, or `None` if the object doesn't exist.
+    async fn remove_object(&self, bucket: Bucket, key: &str) -> anyhow::Result<Option<u64>> {
+        let size = match self.object_store.object_size_raw(bucket, key).await {
+            Ok(size) => size,
+            // The object may have been removed by a previous run that was interrupted before updating the DB.
+            Err(ObjectStoreError::KeyNotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        self.object_store.remove_raw(bucket, key).await?;
+        Ok(Some(size))
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ArtifactsCollector {
+    async fn invoke(&self, connection: &mut Connection<Prover>) -> anyhow::Result<()> {
+        let last_proven_batch = self
+            .core_pool
+            .connection_tagged("prover_job_monitor")
+            .await?
+            .blocks_dal()
+            .get_number_of_last_l1_batch_proven_on_eth()
+            .await?;
+        let Some(last_proven_batch) = last_proven_batch else {
+            return Ok(());
+        };
+
+        let batches = connection
+            .fri_artifacts_gc_dal()
+            .get_batches_for_artifacts_removal(
+                last_proven_batch,
+                self.retention,
+                MAX_BATCHES_PER_RUN,
+            )
+            .await?;
+        for l1_batch_number in batches {
+            let keys = connection
+                .fri_artifacts_gc_dal()
+                .get_batch_artifact_keys(l1_batch_number)
+                .await?;
+            let reclaimed_bytes = self.remove_artifacts(&keys).await?;
+            // Jobs are removed only after the artifacts, so that artifacts don't leak if the task is interrupted.
+            connection
+                .fri_artifacts_gc_dal()
+                .mark_batch_artifacts_as_removed(l1_batch_number)
+                .await?;
+
+            tracing::info!(
+                "Removed {} artifacts ({reclaimed_bytes} bytes) for batch {l1_batch_number}",
+                keys.len()
+            );
+            ARTIFACTS_GC_METRICS.removed_batches.inc();
+            ARTIFACTS_GC_METRICS
+                .last_removed_batch
+                .set(l1_batch_number.0.into());
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 pub mod archiver;
+pub mod artifacts_collector;
 pub mod autoscaler_queue_reporter;
 pub mod job_requeuer;
 pub(crate) mod metrics;
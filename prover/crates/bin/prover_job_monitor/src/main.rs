@@ -11,9 +11,12 @@ use zksync_config::configs::{
     FriWitnessGeneratorConfig, ProverJobMonitorConfig,
 };
 use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_general_config};
+use zksync_dal::Core;
+use zksync_object_store::ObjectStoreFactory;
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_job_monitor::{
     archiver::{GpuProverArchiver, ProverJobsArchiver},
+    artifacts_collector::ArtifactsCollector,
     autoscaler_queue_reporter::get_queue_reporter_router,
     job_requeuer::{ProofCompressorJobRequeuer, ProverJobRequeuer, WitnessGeneratorJobRequeuer},
     queue_reporter::{
@@ -85,6 +88,25 @@ async fn main() -> anyhow::Result<()> {
 
     let graceful_shutdown_timeout = prover_job_monitor_config.graceful_shutdown_timeout();
 
+    let artifacts_collector = match prover_job_monitor_config.artifacts_gc_retention() {
+        Some(retention) => {
+            let core_pool =
+                zksync_dal::ConnectionPool::<Core>::singleton(database_secrets.master_url()?)
+                    .build()
+                    .await
+                    .context("failed to build a core connection pool")?;
+            let object_store_config = prover_config
+                .prover_object_store
+                .clone()
+                .context("prover_object_store")?;
+            let object_store = ObjectStoreFactory::new(object_store_config)
+                .create_store()
+                .await?;
+            Some(ArtifactsCollector::new(core_pool, object_store, retention))
+        }
+        None => None,
+    };
+
     let mut tasks = vec![tokio::spawn(exporter_config.run(stop_receiver.clone()))];
 
     tasks.extend(get_tasks(
@@ -94,6 +116,7 @@ async fn main() -> anyhow::Result<()> {
         prover_config,
         witness_generator_config,
         prover_group_config,
+        artifacts_collector,
         stop_receiver.clone(),
     )?);
     let mut tasks = ManagedTasks::new(tasks);
@@ -131,6 +154,7 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_tasks(
     connection_pool: ConnectionPool<Prover>,
     prover_job_monitor_config: ProverJobMonitorConfig,
@@ -138,6 +162,7 @@ fn get_tasks(
     prover_config: FriProverConfig,
     witness_generator_config: FriWitnessGeneratorConfig,
     prover_group_config: FriProverGroupConfig,
+    artifacts_collector: Option<ArtifactsCollector>,
     stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<Vec<JoinHandle<anyhow::Result<()>>>> {
     let mut task_runner = TaskRunner::new(connection_pool);
@@ -220,5 +245,14 @@ fn get_tasks(
         witness_job_queuer,
     );
 
+    // artifacts garbage collector
+    if let Some(artifacts_collector) = artifacts_collector {
+        task_runner.add(
+            "ArtifactsCollector",
+            prover_job_monitor_config.artifacts_gc_run_interval(),
+            artifacts_collector,
+        );
+    }
+
     Ok(task_runner.spawn(stop_receiver))
 }
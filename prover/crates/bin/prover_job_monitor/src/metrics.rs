@@ -1,4 +1,6 @@
-use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, LabeledFamily, Metrics};
+use vise::{
+    Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, LabeledFamily, Metrics, Unit,
+};
 use zksync_types::protocol_version::ProtocolSemanticVersion;
 
 #[derive(Debug, Metrics)]
@@ -122,3 +124,21 @@ pub(crate) struct ServerMetrics {
 
 #[vise::register]
 pub(crate) static SERVER_METRICS: vise::Global<ServerMetrics> = vise::Global::new();
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "prover_artifacts_gc")]
+pub(crate) struct ArtifactsGcMetrics {
+    /// Number of batches with removed artifacts.
+    pub removed_batches: Counter,
+    /// Number of removed objects per object store bucket.
+    #[metrics(labels = ["bucket"])]
+    pub removed_objects: LabeledFamily<String, Counter>,
+    /// Size of removed objects per object store bucket.
+    #[metrics(unit = Unit::Bytes, labels = ["bucket"])]
+    pub reclaimed_bytes: LabeledFamily<String, Counter>,
+    /// Latest batch with removed artifacts.
+    pub last_removed_batch: Gauge<u64>,
+}
+
+#[vise::register]
+pub(crate) static ARTIFACTS_GC_METRICS: vise::Global<ArtifactsGcMetrics> = vise::Global::new();
@@ -16,13 +16,17 @@ use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_fri_types::PROVER_PROTOCOL_SEMANTIC_VERSION;
 use zksync_prover_keystore::keystore::Keystore;
 use zksync_queued_job_processor::JobProcessor;
-use zksync_types::{basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion};
+use zksync_types::{
+    basic_fri_types::{AggregationRound, WeightedRound},
+    protocol_version::ProtocolSemanticVersion,
+};
 use zksync_utils::wait_for_tasks::ManagedTasks;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 use zksync_witness_generator::{
     metrics::SERVER_METRICS,
     rounds::{
-        BasicCircuits, LeafAggregation, NodeAggregation, RecursionTip, Scheduler, WitnessGenerator,
+        round_worker, BasicCircuits, LeafAggregation, MultiRoundWitnessGenerator, NodeAggregation,
+        RecursionTip, Scheduler, WitnessGenerator,
     },
 };
 
@@ -47,6 +51,10 @@ struct Opt {
     /// Start all aggregation rounds for the witness generator.
     #[structopt(short = "a", long = "all_rounds")]
     all_rounds: bool,
+    /// Serve several aggregation rounds by a single worker processing one job at a time.
+    /// Rounds are polled according to their weights, e.g. `basic_circuits:4,leaf_aggregation:2,scheduler`.
+    #[structopt(long = "rounds", use_delimiter = true)]
+    rounds: Vec<WeightedRound>,
     /// Path to the configuration file.
     #[structopt(long)]
     config_path: Option<std::path::PathBuf>,
@@ -144,23 +152,25 @@ async fn main() -> anyhow::Result<()> {
         .await
         .unwrap_or_else(|err| panic!("Protocol alignment check failed: {:?}", err));
 
-    let rounds = match (opt.round, opt.all_rounds) {
-        (Some(round), false) => vec![round],
-        (None, true) => vec![
+    let rounds = match (opt.round, opt.all_rounds, opt.rounds.is_empty()) {
+        (Some(round), false, true) => vec![round],
+        (None, true, true) => vec![
             AggregationRound::BasicCircuits,
             AggregationRound::LeafAggregation,
             AggregationRound::NodeAggregation,
             AggregationRound::RecursionTip,
             AggregationRound::Scheduler,
         ],
-        (Some(_), true) => {
+        // Rounds are served by a single multi-round worker initialized below.
+        (None, false, false) => vec![],
+        (None, false, true) => {
             return Err(anyhow!(
-                "Cannot set both the --all_rounds and --round flags. Choose one or the other."
+                "Expected one of --all_rounds, --round or --rounds flags to be present"
             ));
         }
-        (None, false) => {
+        _ => {
             return Err(anyhow!(
-                "Expected --all_rounds flag with no --round flag present"
+                "Flags --all_rounds, --round and --rounds are mutually exclusive. Choose one of them."
             ));
         }
     };
@@ -273,6 +283,46 @@ async fn main() -> anyhow::Result<()> {
         SERVER_METRICS.init_latency[&round.into()].set(started_at.elapsed());
     }
 
+    if !opt.rounds.is_empty() {
+        let public_blob_store = match config.shall_save_to_public_bucket {
+            false => None,
+            true => Some(
+                ObjectStoreFactory::new(
+                    prover_config
+                        .public_object_store
+                        .clone()
+                        .expect("public_object_store"),
+                )
+                .create_store()
+                .await?,
+            ),
+        };
+        let mut workers = Vec::with_capacity(opt.rounds.len());
+        for weighted_round in &opt.rounds {
+            let worker = round_worker(
+                weighted_round.round,
+                config.clone(),
+                store_factory.create_store().await?,
+                public_blob_store.clone(),
+                connection_pool.clone(),
+                protocol_version,
+                keystore.clone(),
+            );
+            workers.push((worker, weighted_round.weight));
+            SERVER_METRICS.init_latency[&weighted_round.round.into()].set(started_at.elapsed());
+        }
+        tracing::info!(
+            "initialized multi-round witness generator for {:?}, batch size: {:?} with protocol_version: {:?}",
+            opt.rounds,
+            opt.batch_size,
+            &protocol_version
+        );
+        let generator = MultiRoundWitnessGenerator::new(workers);
+        tasks.push(tokio::spawn(
+            generator.run(stop_receiver.clone(), opt.batch_size),
+        ));
+    }
+
     let (mut stop_signal_sender, mut stop_signal_receiver) = mpsc::channel(256);
     ctrlc::set_handler(move || {
         block_on(stop_signal_sender.send(true)).expect("Ctrl+C signal send");
@@ -14,12 +14,14 @@ use crate::artifacts::ArtifactsManager;
 
 mod basic_circuits;
 mod leaf_aggregation;
+mod multi_round;
 mod node_aggregation;
 mod recursion_tip;
 mod scheduler;
 
 pub use basic_circuits::BasicCircuits;
 pub use leaf_aggregation::LeafAggregation;
+pub use multi_round::{round_worker, MultiRoundWitnessGenerator, RoundWorker};
 pub use node_aggregation::NodeAggregation;
 pub use recursion_tip::RecursionTip;
 pub use scheduler::Scheduler;
@@ -0,0 +1,218 @@
+//! Witness generator serving several aggregation rounds from a single process.
+//!
+//! Unlike running one [`JobProcessor`] per round (as `--all_rounds` does), a multi-round worker processes
+//! at most one job at a time and polls the configured rounds according to their weights. This trades
+//! per-round scalability for a smaller resource footprint, which suits small deployments.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use tokio::sync::watch;
+use zksync_config::configs::FriWitnessGeneratorConfig;
+use zksync_object_store::ObjectStore;
+use zksync_prover_dal::{ConnectionPool, Prover};
+use zksync_prover_keystore::keystore::Keystore;
+use zksync_queued_job_processor::JobProcessor;
+use zksync_types::{basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion};
+
+use super::{
+    BasicCircuits, JobManager, LeafAggregation, NodeAggregation, RecursionTip, Scheduler,
+    WitnessGenerator,
+};
+use crate::artifacts::ArtifactsManager;
+
+const POLLING_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Type-erased witness generator for a single round.
+#[async_trait]
+pub trait RoundWorker: Send + Sync {
+    fn round(&self) -> AggregationRound;
+
+    /// Picks and processes a single job for the round. Returns `false` if there were no pending jobs.
+    async fn process_next_job(
+        &self,
+        stop_receiver: &mut watch::Receiver<bool>,
+    ) -> anyhow::Result<bool>;
+}
+
+#[async_trait]
+impl<R> RoundWorker for WitnessGenerator<R>
+where
+    R: JobManager + ArtifactsManager + Send + Sync,
+{
+    fn round(&self) -> AggregationRound {
+        R::ROUND
+    }
+
+    async fn process_next_job(
+        &self,
+        stop_receiver: &mut watch::Receiver<bool>,
+    ) -> anyhow::Result<bool> {
+        let Some((job_id, job)) = self.get_next_job().await.context("get_next_job()")? else {
+            return Ok(false);
+        };
+        let started_at = Instant::now();
+        let task = JobProcessor::process_job(self, &job_id, job, started_at).await;
+        self.wait_for_task(job_id, started_at, task, stop_receiver)
+            .await
+            .context("wait_for_task")?;
+        Ok(true)
+    }
+}
+
+/// Creates a type-erased witness generator for the specified `round`.
+pub fn round_worker(
+    round: AggregationRound,
+    config: FriWitnessGeneratorConfig,
+    object_store: Arc<dyn ObjectStore>,
+    public_blob_store: Option<Arc<dyn ObjectStore>>,
+    connection_pool: ConnectionPool<Prover>,
+    protocol_version: ProtocolSemanticVersion,
+    keystore: Keystore,
+) -> Box<dyn RoundWorker> {
+    macro_rules! worker {
+        ($round:ty) => {
+            Box::new(WitnessGenerator::<$round>::new(
+                config,
+                object_store,
+                public_blob_store,
+                connection_pool,
+                protocol_version,
+                keystore,
+            ))
+        };
+    }
+
+    match round {
+        AggregationRound::BasicCircuits => worker!(BasicCircuits),
+        AggregationRound::LeafAggregation => worker!(LeafAggregation),
+        AggregationRound::NodeAggregation => worker!(NodeAggregation),
+        AggregationRound::RecursionTip => worker!(RecursionTip),
+        AggregationRound::Scheduler => worker!(Scheduler),
+    }
+}
+
+/// Witness generator polling several rounds with weighted priorities.
+pub struct MultiRoundWitnessGenerator {
+    workers: Vec<Box<dyn RoundWorker>>,
+    /// Indices of `workers` in the order they are polled during a single polling cycle.
+    schedule: Vec<usize>,
+}
+
+impl std::fmt::Debug for MultiRoundWitnessGenerator {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rounds: Vec<_> = self.workers.iter().map(|worker| worker.round()).collect();
+        formatter
+            .debug_struct("MultiRoundWitnessGenerator")
+            .field("rounds", &rounds)
+            .field("schedule", &self.schedule)
+            .finish()
+    }
+}
+
+impl MultiRoundWitnessGenerator {
+    /// Creates a generator from workers with their polling weights.
+    pub fn new(workers: Vec<(Box<dyn RoundWorker>, usize)>) -> Self {
+        let weights: Vec<_> = workers.iter().map(|(_, weight)| *weight).collect();
+        Self {
+            schedule: Self::polling_schedule(&weights),
+            workers: workers.into_iter().map(|(worker, _)| worker).collect(),
+        }
+    }
+
+    /// Builds a polling schedule using smooth weighted round-robin, so that rounds with large weights
+    /// don't starve other rounds within a cycle.
+    fn polling_schedule(weights: &[usize]) -> Vec<usize> {
+        let total_weight: usize = weights.iter().sum();
+        let mut current = vec![0_isize; weights.len()];
+        let mut schedule = Vec::with_capacity(total_weight);
+        for _ in 0..total_weight {
+            for (current, &weight) in current.iter_mut().zip(weights) {
+                *current += weight as isize;
+            }
+            let (selected, _) = current
+                .iter()
+                .enumerate()
+                .max_by_key(|&(idx, &value)| (value, std::cmp::Reverse(idx)))
+                .expect("no rounds");
+            current[selected] -= total_weight as isize;
+            schedule.push(selected);
+        }
+        schedule
+    }
+
+    /// Runs the generator. Semantics of `iterations_left` are the same as for [`JobProcessor::run()`].
+    pub async fn run(
+        self,
+        mut stop_receiver: watch::Receiver<bool>,
+        mut iterations_left: Option<usize>,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(!self.schedule.is_empty(), "no rounds to process");
+        tracing::info!("Starting multi-round witness generator: {self:?}");
+
+        let mut backoff = POLLING_INTERVAL;
+        let mut idle_polls = 0;
+        for &worker_idx in self.schedule.iter().cycle() {
+            if iterations_left == Some(0) {
+                tracing::info!("Requested number of jobs is processed. Server can stop now.");
+                return Ok(());
+            }
+            if *stop_receiver.borrow() {
+                tracing::warn!("Stop signal received, shutting down multi-round witness generator");
+                return Ok(());
+            }
+
+            let worker = &self.workers[worker_idx];
+            let processed = worker
+                .process_next_job(&mut stop_receiver)
+                .await
+                .with_context(|| format!("failed processing {:?} job", worker.round()))?;
+            if processed {
+                backoff = POLLING_INTERVAL;
+                idle_polls = 0;
+                iterations_left = iterations_left.map(|i| i - 1);
+                continue;
+            }
+
+            idle_polls += 1;
+            if idle_polls < self.schedule.len() {
+                continue;
+            }
+            // All rounds were polled without finding a job.
+            idle_polls = 0;
+            if iterations_left.is_some() {
+                tracing::info!("No more jobs to process. Server can stop now.");
+                return Ok(());
+            }
+            tracing::trace!("Backing off for {backoff:?}");
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(backoff, stop_receiver.changed())
+                .await
+                .ok();
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+        unreachable!("polling schedule is non-empty and cycled")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polling_schedule_respects_weights() {
+        let schedule = MultiRoundWitnessGenerator::polling_schedule(&[3, 1]);
+        assert_eq!(schedule, [0, 0, 1, 0]);
+
+        let schedule = MultiRoundWitnessGenerator::polling_schedule(&[2, 2, 1]);
+        assert_eq!(schedule.len(), 5);
+        for (idx, weight) in [2, 2, 1].into_iter().enumerate() {
+            assert_eq!(schedule.iter().filter(|&&i| i == idx).count(), weight);
+        }
+    }
+}
@@ -44,6 +44,8 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        local_mirror_max_size_mb: None,
+        replication: None,
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -67,6 +69,8 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        local_mirror_max_size_mb: None,
+        replication: None,
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -119,6 +123,8 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        local_mirror_max_size_mb: None,
+        replication: None,
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -153,6 +159,8 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        local_mirror_max_size_mb: None,
+        replication: None,
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -303,6 +303,12 @@ impl Keystore {
         })
     }
 
+    /// Returns the path of the setup data file for the specified key. The file name is also used as the key
+    /// of setup data in object stores.
+    pub fn setup_data_file_path(&self, key: ProverServiceDataKey) -> PathBuf {
+        self.get_file_path(key, ProverServiceDataType::SetupData)
+    }
+
     pub fn is_setup_data_present(&self, key: &ProverServiceDataKey) -> bool {
         Path::new(&self.get_file_path(*key, ProverServiceDataType::SetupData)).exists()
     }
@@ -0,0 +1,306 @@
+use std::time::Duration;
+
+use zksync_basic_types::L1BatchNumber;
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+
+use crate::{pg_interval_from_duration, Prover};
+
+/// Object store keys of intermediate artifacts produced while proving an L1 batch.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BatchArtifactKeys {
+    /// Witness inputs for the basic witness generator.
+    pub witness_inputs: Vec<String>,
+    /// Circuits generated by witness generators for all aggregation rounds.
+    pub circuits: Vec<String>,
+    /// FRI proofs for all aggregation rounds, including the scheduler proof.
+    pub proofs: Vec<String>,
+    /// Closed form inputs for leaf aggregation.
+    pub closed_form_inputs: Vec<String>,
+    /// Aggregation outputs for node aggregation.
+    pub aggregations: Vec<String>,
+    /// Partial inputs for the scheduler.
+    pub scheduler_partial_inputs: Vec<String>,
+}
+
+impl BatchArtifactKeys {
+    pub fn len(&self) -> usize {
+        self.witness_inputs.len()
+            + self.circuits.len()
+            + self.proofs.len()
+            + self.closed_form_inputs.len()
+            + self.aggregations.len()
+            + self.scheduler_partial_inputs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug)]
+pub struct FriArtifactsGcDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Prover>,
+}
+
+impl FriArtifactsGcDal<'_, '_> {
+    /// Returns batches with artifacts eligible for removal in ascending order. A batch is eligible if its final proof
+    /// was sent to the server more than `retention` ago, it's not greater than `last_proven_batch`,
+    /// and its artifacts were not removed yet.
+    pub async fn get_batches_for_artifacts_removal(
+        &mut self,
+        last_proven_batch: L1BatchNumber,
+        retention: Duration,
+        limit: usize,
+    ) -> DalResult<Vec<L1BatchNumber>> {
+        let retention = pg_interval_from_duration(retention);
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number
+            FROM
+                proof_compression_jobs_fri
+            WHERE
+                status = 'sent_to_server'
+                AND artifacts_removed_at IS NULL
+                AND l1_batch_number <= $1
+                AND updated_at < NOW() - $2::INTERVAL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                $3
+            "#,
+            i64::from(last_proven_batch.0),
+            &retention,
+            limit as i64
+        )
+        .instrument("get_batches_for_artifacts_removal")
+        .with_arg("last_proven_batch", &last_proven_batch)
+        .with_arg("retention", &retention)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+            .collect())
+    }
+
+    /// Returns object store keys of intermediate artifacts for the specified batch.
+    pub async fn get_batch_artifact_keys(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<BatchArtifactKeys> {
+        let batch_number = i64::from(l1_batch_number.0);
+        let mut keys = BatchArtifactKeys::default();
+
+        keys.witness_inputs = sqlx::query_scalar!(
+            r#"
+            SELECT
+                witness_inputs_blob_url
+            FROM
+                witness_inputs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("get_batch_artifact_keys#witness_inputs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let prover_jobs = sqlx::query!(
+            r#"
+            SELECT
+                circuit_blob_url AS "circuit_blob_url?",
+                proof_blob_url AS "proof_blob_url?"
+            FROM
+                prover_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            UNION ALL
+            SELECT
+                circuit_blob_url,
+                proof_blob_url
+            FROM
+                prover_jobs_fri_archive
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("get_batch_artifact_keys#prover_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+        for job in prover_jobs {
+            keys.circuits.extend(job.circuit_blob_url);
+            keys.proofs.extend(job.proof_blob_url);
+        }
+
+        keys.proofs.extend(
+            sqlx::query_scalar!(
+                r#"
+                SELECT
+                    fri_proof_blob_url
+                FROM
+                    proof_compression_jobs_fri
+                WHERE
+                    l1_batch_number = $1
+                "#,
+                batch_number
+            )
+            .instrument("get_batch_artifact_keys#compression_job")
+            .with_arg("l1_batch_number", &l1_batch_number)
+            .fetch_optional(self.storage)
+            .await?
+            .flatten(),
+        );
+
+        keys.closed_form_inputs = sqlx::query_scalar!(
+            r#"
+            SELECT
+                closed_form_inputs_blob_url
+            FROM
+                leaf_aggregation_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("get_batch_artifact_keys#leaf_aggregation_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        keys.aggregations = sqlx::query_scalar!(
+            r#"
+            SELECT
+                aggregations_url
+            FROM
+                node_aggregation_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("get_batch_artifact_keys#node_aggregation_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        keys.scheduler_partial_inputs = sqlx::query_scalar!(
+            r#"
+            SELECT
+                scheduler_partial_input_blob_url
+            FROM
+                scheduler_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("get_batch_artifact_keys#scheduler_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(keys)
+    }
+
+    /// Removes per-circuit and aggregation jobs for the specified batch and marks its artifacts as removed.
+    /// Batch-level rows (witness inputs, scheduler and compression jobs) are retained.
+    pub async fn mark_batch_artifacts_as_removed(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<()> {
+        let batch_number = i64::from(l1_batch_number.0);
+        let mut transaction = self.storage.start_transaction().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM prover_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#prover_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM prover_jobs_fri_archive
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#prover_jobs_archive")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM leaf_aggregation_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#leaf_aggregation_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM node_aggregation_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#node_aggregation_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM recursion_tip_witness_jobs_fri
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#recursion_tip_jobs")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query!(
+            r#"
+            UPDATE proof_compression_jobs_fri
+            SET
+                artifacts_removed_at = NOW()
+            WHERE
+                l1_batch_number = $1
+            "#,
+            batch_number
+        )
+        .instrument("mark_batch_artifacts_as_removed#proof_compression_job")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await
+    }
+}
@@ -0,0 +1,200 @@
+use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
+use zksync_basic_types::{prover_dal::ProvingStage, L1BatchNumber};
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+
+use crate::Prover;
+
+#[derive(Debug)]
+pub struct FriProofLatencyDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Prover>,
+}
+
+impl FriProofLatencyDal<'_, '_> {
+    /// Returns timestamps of the specified L1 batch reaching proving stages tracked by the prover subsystem,
+    /// ordered by stage. Stages that weren't reached are omitted.
+    ///
+    /// Timestamps are derived from job tables, so they are only accurate if the jobs weren't retried;
+    /// "finished" timestamps for stages with multiple jobs are only meaningful once all jobs are successful.
+    pub async fn get_stage_timestamps(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<(ProvingStage, DateTime<Utc>)>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                (
+                    SELECT
+                        processing_started_at
+                    FROM
+                        witness_inputs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS basic_circuits_started_at,
+                (
+                    SELECT
+                        updated_at
+                    FROM
+                        witness_inputs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS basic_circuits_finished_at,
+                (
+                    SELECT
+                        MIN(processing_started_at)
+                    FROM
+                        leaf_aggregation_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS leaf_aggregation_started_at,
+                (
+                    SELECT
+                        MAX(updated_at)
+                    FROM
+                        leaf_aggregation_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS leaf_aggregation_finished_at,
+                (
+                    SELECT
+                        MIN(processing_started_at)
+                    FROM
+                        node_aggregation_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS node_aggregation_started_at,
+                (
+                    SELECT
+                        MAX(updated_at)
+                    FROM
+                        node_aggregation_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS node_aggregation_finished_at,
+                (
+                    SELECT
+                        processing_started_at
+                    FROM
+                        recursion_tip_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS recursion_tip_started_at,
+                (
+                    SELECT
+                        updated_at
+                    FROM
+                        recursion_tip_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS recursion_tip_finished_at,
+                (
+                    SELECT
+                        processing_started_at
+                    FROM
+                        scheduler_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS scheduler_started_at,
+                (
+                    SELECT
+                        updated_at
+                    FROM
+                        scheduler_witness_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS scheduler_finished_at,
+                (
+                    SELECT
+                        MIN(processing_started_at)
+                    FROM
+                        prover_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS proving_started_at,
+                (
+                    SELECT
+                        MAX(updated_at)
+                    FROM
+                        prover_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS proving_finished_at,
+                (
+                    SELECT
+                        processing_started_at
+                    FROM
+                        proof_compression_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                ) AS compression_started_at,
+                (
+                    SELECT
+                        updated_at
+                    FROM
+                        proof_compression_jobs_fri
+                    WHERE
+                        l1_batch_number = $1
+                        AND status = 'successful'
+                ) AS compression_finished_at
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_stage_timestamps")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_one(self.storage)
+        .await?;
+
+        let stages: [(ProvingStage, Option<NaiveDateTime>); 14] = [
+            (
+                ProvingStage::BasicCircuitsStarted,
+                row.basic_circuits_started_at,
+            ),
+            (
+                ProvingStage::BasicCircuitsFinished,
+                row.basic_circuits_finished_at,
+            ),
+            (
+                ProvingStage::LeafAggregationStarted,
+                row.leaf_aggregation_started_at,
+            ),
+            (
+                ProvingStage::LeafAggregationFinished,
+                row.leaf_aggregation_finished_at,
+            ),
+            (
+                ProvingStage::NodeAggregationStarted,
+                row.node_aggregation_started_at,
+            ),
+            (
+                ProvingStage::NodeAggregationFinished,
+                row.node_aggregation_finished_at,
+            ),
+            (
+                ProvingStage::RecursionTipStarted,
+                row.recursion_tip_started_at,
+            ),
+            (
+                ProvingStage::RecursionTipFinished,
+                row.recursion_tip_finished_at,
+            ),
+            (ProvingStage::SchedulerStarted, row.scheduler_started_at),
+            (ProvingStage::SchedulerFinished, row.scheduler_finished_at),
+            (ProvingStage::ProvingStarted, row.proving_started_at),
+            (ProvingStage::ProvingFinished, row.proving_finished_at),
+            (ProvingStage::CompressionStarted, row.compression_started_at),
+            (
+                ProvingStage::CompressionFinished,
+                row.compression_finished_at,
+            ),
+        ];
+        Ok(stages
+            .into_iter()
+            .filter_map(|(stage, timestamp)| Some((stage, timestamp?.and_utc())))
+            .collect())
+    }
+}
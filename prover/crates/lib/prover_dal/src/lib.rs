@@ -6,15 +6,18 @@ pub use zksync_db_connection::{
 };
 
 use crate::{
-    cli_test_dal::CliTestDal, fri_gpu_prover_queue_dal::FriGpuProverQueueDal,
-    fri_proof_compressor_dal::FriProofCompressorDal,
+    cli_test_dal::CliTestDal, fri_artifacts_gc_dal::FriArtifactsGcDal,
+    fri_gpu_prover_queue_dal::FriGpuProverQueueDal,
+    fri_proof_compressor_dal::FriProofCompressorDal, fri_proof_latency_dal::FriProofLatencyDal,
     fri_protocol_versions_dal::FriProtocolVersionsDal, fri_prover_dal::FriProverDal,
     fri_witness_generator_dal::FriWitnessGeneratorDal,
 };
 
 pub mod cli_test_dal;
+pub mod fri_artifacts_gc_dal;
 pub mod fri_gpu_prover_queue_dal;
 pub mod fri_proof_compressor_dal;
+pub mod fri_proof_latency_dal;
 pub mod fri_protocol_versions_dal;
 pub mod fri_prover_dal;
 pub mod fri_witness_generator_dal;
@@ -41,6 +44,10 @@ where
     fn fri_protocol_versions_dal(&mut self) -> FriProtocolVersionsDal<'_, 'a>;
 
     fn fri_proof_compressor_dal(&mut self) -> FriProofCompressorDal<'_, 'a>;
+
+    fn fri_artifacts_gc_dal(&mut self) -> FriArtifactsGcDal<'_, 'a>;
+
+    fn fri_proof_latency_dal(&mut self) -> FriProofLatencyDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -71,6 +78,15 @@ impl<'a> ProverDal<'a> for Connection<'a, Prover> {
     fn fri_proof_compressor_dal(&mut self) -> FriProofCompressorDal<'_, 'a> {
         FriProofCompressorDal { storage: self }
     }
+
+    fn fri_artifacts_gc_dal(&mut self) -> FriArtifactsGcDal<'_, 'a> {
+        FriArtifactsGcDal { storage: self }
+    }
+
+    fn fri_proof_latency_dal(&mut self) -> FriProofLatencyDal<'_, 'a> {
+        FriProofLatencyDal { storage: self }
+    }
+
     fn cli_test_dal(&mut self) -> CliTestDal<'_, 'a> {
         CliTestDal { storage: self }
     }
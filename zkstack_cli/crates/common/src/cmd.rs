@@ -9,7 +9,7 @@ use std::{
 use console::style;
 
 use crate::{
-    config::global_config,
+    config::{global_config, OutputFormat},
     logger::{self},
 };
 
@@ -158,7 +158,12 @@ fn check_output_status(command_text: &str, output: &std::process::Output) -> Cmd
 }
 
 fn run_low_level_process_command(mut command: Command, piped_std_err: bool) -> io::Result<Output> {
-    command.stdout(Stdio::inherit());
+    // Stdout is reserved for the command result in the JSON output mode.
+    if global_config().output == OutputFormat::Json {
+        command.stdout(io::stderr());
+    } else {
+        command.stdout(Stdio::inherit());
+    }
     if piped_std_err {
         command.stderr(Stdio::piped());
     } else {
@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
 use once_cell::sync::OnceCell;
 
 static CONFIG: OnceCell<GlobalConfig> = OnceCell::new();
@@ -17,4 +20,18 @@ pub struct GlobalConfig {
     pub verbose: bool,
     pub chain_name: Option<String>,
     pub ignore_prerequisites: bool,
+    pub output: OutputFormat,
+    /// If set, L1 transactions of forge scripts aren't broadcast, but appended to the bundle in this directory
+    /// for offline signing.
+    pub unsigned_txs_dir: Option<PathBuf>,
+}
+
+/// Format of command results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable logs only.
+    #[default]
+    Human,
+    /// Human-readable logs are written to stderr, and the command result is printed to stdout as JSON.
+    Json,
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, path::PathBuf};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context as _};
 use serde::{Deserialize, Serialize};
 use sqlx::{
     migrate::{Migrate, MigrateError, Migrator},
@@ -148,6 +148,101 @@ pub async fn migrate_db(
     Ok(())
 }
 
+/// Returns the version of the latest applied migration, or `None` if no migrations are applied.
+pub async fn latest_migration_version(db_url: &Url) -> anyhow::Result<Option<i64>> {
+    let mut conn = PgConnection::connect(db_url.as_str()).await?;
+    conn.ensure_migrations_table().await?;
+    let version = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .max();
+    let _ = conn.close().await;
+    Ok(version)
+}
+
+/// Reverts migrations applied after `target_version` in the reverse order, using down migrations from
+/// `migrations_folder`. Returns the number of reverted migrations.
+pub async fn revert_migrations(
+    shell: &Shell,
+    migrations_folder: PathBuf,
+    db_url: &Url,
+    target_version: i64,
+) -> anyhow::Result<usize> {
+    if !shell.path_exists(&migrations_folder) {
+        anyhow::bail!("Migrations folder {migrations_folder:?} doesn't exist");
+    }
+    let migrator = Migrator::new(migrations_folder).await?;
+
+    let mut conn = PgConnection::connect(db_url.as_str()).await?;
+    conn.ensure_migrations_table().await?;
+    if let Some(version) = conn.dirty_version().await? {
+        anyhow::bail!(MigrateError::Dirty(version));
+    }
+
+    let mut versions: Vec<_> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .filter(|&version| version > target_version)
+        .collect();
+    versions.sort_unstable_by(|a, b| b.cmp(a));
+
+    for &version in &versions {
+        let migration = migrator
+            .iter()
+            .find(|m| m.version == version && m.migration_type.is_down_migration())
+            .with_context(|| format!("down migration {version} not found"))?;
+        let elapsed = conn.revert(migration).await?;
+        if global_config().verbose {
+            logger::step(format!(
+                "    Reverted {}/{} {} ({elapsed:?})",
+                migration.version,
+                migration.migration_type.label(),
+                migration.description,
+            ));
+        }
+    }
+
+    let _ = conn.close().await;
+    Ok(versions.len())
+}
+
+/// Returns versions of migrations applied after `target_version` that have no down migration in `migrations_folder`,
+/// i.e., cannot be reverted by [`revert_migrations()`].
+pub async fn irreversible_migrations(
+    shell: &Shell,
+    migrations_folder: PathBuf,
+    db_url: &Url,
+    target_version: i64,
+) -> anyhow::Result<Vec<i64>> {
+    if !shell.path_exists(&migrations_folder) {
+        anyhow::bail!("Migrations folder {migrations_folder:?} doesn't exist");
+    }
+    let migrator = Migrator::new(migrations_folder).await?;
+
+    let mut conn = PgConnection::connect(db_url.as_str()).await?;
+    conn.ensure_migrations_table().await?;
+    let mut versions: Vec<_> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .filter(|&version| {
+            version > target_version
+                && !migrator
+                    .iter()
+                    .any(|m| m.version == version && m.migration_type.is_down_migration())
+        })
+        .collect();
+    versions.sort_unstable();
+
+    let _ = conn.close().await;
+    Ok(versions)
+}
+
 pub async fn wait_for_db(db_url: &Url, tries: u32) -> anyhow::Result<()> {
     for i in 0..tries {
         if PgConnection::connect(db_url.as_str()).await.is_ok() {
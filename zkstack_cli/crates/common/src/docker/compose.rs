@@ -0,0 +1,459 @@
+//! Subset of the compose file format needed to run compose files without `docker compose`.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use serde::Deserialize;
+use xshell::Shell;
+
+#[derive(Debug)]
+pub(super) struct ComposeFile {
+    /// Directory of the compose file. Relative bind mounts are resolved against it.
+    pub dir: PathBuf,
+    pub spec: ComposeSpec,
+}
+
+impl ComposeFile {
+    pub fn read(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<Self> {
+        let path = shell.current_dir().join(docker_compose_file);
+        let contents = shell
+            .read_file(&path)
+            .with_context(|| format!("failed reading {path:?}"))?;
+        let spec = serde_yaml::from_str(&contents)
+            .with_context(|| format!("failed parsing compose file {path:?}"))?;
+        let dir = path
+            .parent()
+            .map_or_else(|| shell.current_dir(), Path::to_path_buf);
+        Ok(Self { dir, spec })
+    }
+
+    /// Returns the compose project name: the top-level `name` or, by default, the name of the compose file directory.
+    pub fn project_name(&self) -> String {
+        let name = self.spec.name.clone().unwrap_or_else(|| {
+            self.dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+        name.to_lowercase()
+            .chars()
+            .filter(|ch| ch.is_ascii_alphanumeric() || *ch == '_' || *ch == '-')
+            .collect()
+    }
+
+    /// Returns the name of a named volume as created by compose tools.
+    pub fn volume_name(&self, volume: &str) -> anyhow::Result<String> {
+        let spec = self.volume(volume)?;
+        Ok(match spec.and_then(|spec| spec.name.clone()) {
+            Some(name) => name,
+            None => format!("{}_{volume}", self.project_name()),
+        })
+    }
+
+    pub fn volume(&self, volume: &str) -> anyhow::Result<Option<&VolumeSpec>> {
+        self.spec
+            .volumes
+            .get(volume)
+            .map(Option::as_ref)
+            .with_context(|| format!("volume `{volume}` is not declared in the compose file"))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ComposeSpec {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub services: BTreeMap<String, Service>,
+    #[serde(default)]
+    pub volumes: BTreeMap<String, Option<VolumeSpec>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct VolumeSpec {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct Service {
+    #[serde(default)]
+    pub image: Option<String>,
+    #[serde(default)]
+    pub entrypoint: Option<StringOrList>,
+    #[serde(default)]
+    pub command: Option<StringOrList>,
+    #[serde(default)]
+    pub environment: Environment,
+    #[serde(default)]
+    pub ports: Vec<Port>,
+    #[serde(default)]
+    pub volumes: Vec<Volume>,
+    #[serde(default)]
+    pub restart: Option<String>,
+    /// Services with profiles are only started if one of their profiles is enabled.
+    #[serde(default)]
+    pub profiles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+impl StringOrList {
+    /// Returns the list of arguments, splitting a string the same way as compose tools do.
+    pub fn to_args(&self) -> anyhow::Result<Vec<String>> {
+        match self {
+            Self::String(s) => split_command(s),
+            Self::List(args) => Ok(args.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(untagged)]
+pub(super) enum Environment {
+    #[default]
+    Empty,
+    List(Vec<String>),
+    Map(BTreeMap<String, Option<serde_yaml::Value>>),
+}
+
+impl Environment {
+    pub fn to_vars(&self) -> Vec<(String, String)> {
+        match self {
+            Self::Empty => vec![],
+            Self::List(vars) => vars
+                .iter()
+                .map(|var| match var.split_once('=') {
+                    Some((name, value)) => (name.to_owned(), value.to_owned()),
+                    None => (var.clone(), String::new()),
+                })
+                .collect(),
+            Self::Map(vars) => vars
+                .iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        None => String::new(),
+                        Some(serde_yaml::Value::String(s)) => s.clone(),
+                        Some(value) => serde_yaml::to_string(value)
+                            .unwrap_or_default()
+                            .trim()
+                            .to_owned(),
+                    };
+                    (name.clone(), value)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum Port {
+    Number(u16),
+    Short(String),
+    Long {
+        target: u16,
+        #[serde(default)]
+        published: Option<serde_yaml::Value>,
+        #[serde(default)]
+        host_ip: Option<String>,
+        #[serde(default)]
+        protocol: Option<String>,
+    },
+}
+
+/// Port mapping in the normalized form.
+#[derive(Debug, PartialEq)]
+pub(super) struct PortMapping {
+    pub host_ip: Option<String>,
+    pub published: Option<u16>,
+    pub target: u16,
+    pub protocol: String,
+}
+
+impl Port {
+    pub fn to_mapping(&self) -> anyhow::Result<PortMapping> {
+        match self {
+            Self::Number(target) => Ok(PortMapping {
+                host_ip: None,
+                published: None,
+                target: *target,
+                protocol: "tcp".to_owned(),
+            }),
+            Self::Short(spec) => {
+                let (ports, protocol) = spec.split_once('/').unwrap_or((spec, "tcp"));
+                let parts: Vec<_> = ports.split(':').collect();
+                let (host_ip, published, target) = match parts.as_slice() {
+                    [target] => (None, None, *target),
+                    [published, target] => (None, Some(*published), *target),
+                    [host_ip, published, target] => (Some(*host_ip), Some(*published), *target),
+                    _ => anyhow::bail!("unsupported port mapping `{spec}`"),
+                };
+                let parse_port = |port: &str| {
+                    port.parse::<u16>()
+                        .with_context(|| format!("unsupported port mapping `{spec}`"))
+                };
+                Ok(PortMapping {
+                    host_ip: host_ip.map(str::to_owned),
+                    published: published.map(parse_port).transpose()?,
+                    target: parse_port(target)?,
+                    protocol: protocol.to_owned(),
+                })
+            }
+            Self::Long {
+                target,
+                published,
+                host_ip,
+                protocol,
+            } => {
+                let published = match published {
+                    None => None,
+                    Some(serde_yaml::Value::Number(port)) => port.as_u64(),
+                    Some(serde_yaml::Value::String(port)) => Some(port.parse()?),
+                    Some(other) => anyhow::bail!("unsupported published port {other:?}"),
+                };
+                Ok(PortMapping {
+                    host_ip: host_ip.clone(),
+                    published: published.map(u16::try_from).transpose()?,
+                    target: *target,
+                    protocol: protocol.clone().unwrap_or_else(|| "tcp".to_owned()),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum Volume {
+    Short(String),
+    Long {
+        #[serde(rename = "type")]
+        kind: String,
+        #[serde(default)]
+        source: Option<String>,
+        target: String,
+        #[serde(default)]
+        read_only: bool,
+    },
+}
+
+/// Volume mount in the normalized form.
+#[derive(Debug, PartialEq)]
+pub(super) enum VolumeMount {
+    Named {
+        volume: String,
+        target: String,
+        read_only: bool,
+    },
+    Bind {
+        source: PathBuf,
+        target: String,
+        read_only: bool,
+    },
+    Anonymous {
+        target: String,
+    },
+}
+
+impl Volume {
+    /// Normalizes the volume mount, resolving relative bind mount sources against `dir`.
+    pub fn to_mount(&self, dir: &Path) -> anyhow::Result<VolumeMount> {
+        match self {
+            Self::Short(spec) => {
+                let parts: Vec<_> = spec.split(':').collect();
+                let (source, target, read_only) = match parts.as_slice() {
+                    [target] => {
+                        return Ok(VolumeMount::Anonymous {
+                            target: target.to_string(),
+                        })
+                    }
+                    [source, target] => (*source, *target, false),
+                    [source, target, mode] => {
+                        (*source, *target, mode.split(',').any(|m| m == "ro"))
+                    }
+                    _ => anyhow::bail!("unsupported volume `{spec}`"),
+                };
+                let is_path =
+                    source.starts_with('.') || source.starts_with('/') || source.starts_with('~');
+                Ok(if is_path {
+                    VolumeMount::Bind {
+                        source: resolve_path(dir, source),
+                        target: target.to_owned(),
+                        read_only,
+                    }
+                } else {
+                    VolumeMount::Named {
+                        volume: source.to_owned(),
+                        target: target.to_owned(),
+                        read_only,
+                    }
+                })
+            }
+            Self::Long {
+                kind,
+                source,
+                target,
+                read_only,
+            } => Ok(match (kind.as_str(), source) {
+                ("volume", Some(source)) => VolumeMount::Named {
+                    volume: source.clone(),
+                    target: target.clone(),
+                    read_only: *read_only,
+                },
+                ("volume", None) => VolumeMount::Anonymous {
+                    target: target.clone(),
+                },
+                ("bind", Some(source)) => VolumeMount::Bind {
+                    source: resolve_path(dir, source),
+                    target: target.clone(),
+                    read_only: *read_only,
+                },
+                _ => anyhow::bail!("unsupported volume of type `{kind}` mounted to `{target}`"),
+            }),
+        }
+    }
+}
+
+fn resolve_path(dir: &Path, source: &str) -> PathBuf {
+    if let Some(relative) = source.strip_prefix("~/") {
+        if let Some(home) = std::env::var_os("HOME") {
+            return PathBuf::from(home).join(relative);
+        }
+    }
+    dir.join(source)
+}
+
+/// Splits a command into arguments, handling quotes like a POSIX shell.
+fn split_command(command: &str) -> anyhow::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = command.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => {
+                args.extend(current.take());
+            }
+            '\'' => {
+                let arg = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(ch) => arg.push(ch),
+                        None => anyhow::bail!("unterminated quote in `{command}`"),
+                    }
+                }
+            }
+            '"' => {
+                let arg = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch @ ('"' | '\\' | '$' | '`')) => arg.push(ch),
+                            Some(ch) => {
+                                arg.push('\\');
+                                arg.push(ch);
+                            }
+                            None => anyhow::bail!("unterminated quote in `{command}`"),
+                        },
+                        Some(ch) => arg.push(ch),
+                        None => anyhow::bail!("unterminated quote in `{command}`"),
+                    }
+                }
+            }
+            '\\' => {
+                let arg = current.get_or_insert_with(String::new);
+                arg.extend(chars.next());
+            }
+            ch => current.get_or_insert_with(String::new).push(ch),
+        }
+    }
+    args.extend(current);
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting_commands() {
+        assert_eq!(
+            split_command(r#"node --http.corsdomain "*" --chain /chaindata/reth_config"#).unwrap(),
+            [
+                "node",
+                "--http.corsdomain",
+                "*",
+                "--chain",
+                "/chaindata/reth_config"
+            ]
+        );
+        assert_eq!(
+            split_command("postgres -c 'max_connections=1000'  ''").unwrap(),
+            ["postgres", "-c", "max_connections=1000", ""]
+        );
+        assert_eq!(
+            split_command(r#"sh -c "echo \"a b\"""#).unwrap(),
+            ["sh", "-c", r#"echo "a b""#]
+        );
+        split_command("echo 'a").unwrap_err();
+    }
+
+    #[test]
+    fn normalizing_ports() {
+        let port: Port = serde_yaml::from_str("127.0.0.1:8545:8545").unwrap();
+        assert_eq!(
+            port.to_mapping().unwrap(),
+            PortMapping {
+                host_ip: Some("127.0.0.1".to_owned()),
+                published: Some(8545),
+                target: 8545,
+                protocol: "tcp".to_owned(),
+            }
+        );
+        let port: Port = serde_yaml::from_str("3000:3001/udp").unwrap();
+        let mapping = port.to_mapping().unwrap();
+        assert_eq!((mapping.published, mapping.target), (Some(3000), 3001));
+        assert_eq!(mapping.protocol, "udp");
+        let port: Port = serde_yaml::from_str("{ target: 80, published: '8080' }").unwrap();
+        assert_eq!(port.to_mapping().unwrap().published, Some(8080));
+        let port: Port = serde_yaml::from_str("8000-8010:8000-8010").unwrap();
+        port.to_mapping().unwrap_err();
+    }
+
+    #[test]
+    fn normalizing_volumes() {
+        let dir = Path::new("/ecosystem");
+        let volume: Volume =
+            serde_yaml::from_str("postgres-data:/var/lib/postgresql/data").unwrap();
+        assert_eq!(
+            volume.to_mount(dir).unwrap(),
+            VolumeMount::Named {
+                volume: "postgres-data".to_owned(),
+                target: "/var/lib/postgresql/data".to_owned(),
+                read_only: false,
+            }
+        );
+        let volume: Volume = serde_yaml::from_str(
+            "{ type: bind, source: ./etc/chaindata, target: /chaindata, read_only: true }",
+        )
+        .unwrap();
+        assert_eq!(
+            volume.to_mount(dir).unwrap(),
+            VolumeMount::Bind {
+                source: PathBuf::from("/ecosystem/./etc/chaindata"),
+                target: "/chaindata".to_owned(),
+                read_only: true,
+            }
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Running compose files with `podman play kube`. Compose services are converted to containers of a single pod,
+//! so that they share the network namespace and can reach each other via `localhost`.
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+use xshell::{cmd, Shell};
+
+use super::compose::{ComposeFile, VolumeMount};
+use crate::cmd::Cmd;
+
+/// Pod converted from a compose file.
+#[derive(Debug)]
+struct Pod {
+    name: String,
+    /// Names of the persistent volume claims used by the pod.
+    claims: Vec<String>,
+    manifest: Value,
+}
+
+impl Pod {
+    fn from_compose(compose: &ComposeFile) -> anyhow::Result<Self> {
+        let name = pod_name(compose);
+        let mut containers = vec![];
+        let mut volumes = vec![];
+        let mut claims = vec![];
+        let mut restart_policies = vec![];
+
+        for (service_name, service) in &compose.spec.services {
+            if !service.profiles.is_empty() {
+                continue;
+            }
+            let image = service.image.as_ref().ok_or_else(|| {
+                anyhow::anyhow!("service `{service_name}` has no image; building images is not supported with `podman play kube`")
+            })?;
+            let mut container = json!({ "name": service_name, "image": image });
+            if let Some(entrypoint) = &service.entrypoint {
+                container["command"] = json!(entrypoint.to_args()?);
+            }
+            if let Some(command) = &service.command {
+                container["args"] = json!(command.to_args()?);
+            }
+
+            let env: Vec<_> = service
+                .environment
+                .to_vars()
+                .into_iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect();
+            if !env.is_empty() {
+                container["env"] = json!(env);
+            }
+
+            let mut ports = vec![];
+            for port in &service.ports {
+                let mapping = port.to_mapping()?;
+                let mut port = json!({
+                    "containerPort": mapping.target,
+                    "protocol": mapping.protocol.to_uppercase(),
+                });
+                if let Some(published) = mapping.published {
+                    port["hostPort"] = json!(published);
+                }
+                if let Some(host_ip) = mapping.host_ip {
+                    port["hostIP"] = json!(host_ip);
+                }
+                ports.push(port);
+            }
+            if !ports.is_empty() {
+                container["ports"] = json!(ports);
+            }
+
+            let mut volume_mounts = vec![];
+            for (i, volume) in service.volumes.iter().enumerate() {
+                let (volume_name, target, read_only) = match volume.to_mount(&compose.dir)? {
+                    VolumeMount::Named {
+                        volume,
+                        target,
+                        read_only,
+                    } => {
+                        let claim = claim_name(compose, &name, &volume)?;
+                        let volume_name = dns_label(&volume);
+                        if !claims.contains(&claim) {
+                            claims.push(claim.clone());
+                            volumes.push(json!({
+                                "name": volume_name,
+                                "persistentVolumeClaim": { "claimName": claim },
+                            }));
+                        }
+                        (volume_name, target, read_only)
+                    }
+                    VolumeMount::Bind {
+                        source,
+                        target,
+                        read_only,
+                    } => {
+                        let volume_name = format!("{}-bind-{i}", dns_label(service_name));
+                        let kind = if source.is_file() {
+                            "File"
+                        } else {
+                            "DirectoryOrCreate"
+                        };
+                        volumes.push(json!({
+                            "name": volume_name,
+                            "hostPath": { "path": source, "type": kind },
+                        }));
+                        (volume_name, target, read_only)
+                    }
+                    VolumeMount::Anonymous { target } => {
+                        let volume_name = format!("{}-tmp-{i}", dns_label(service_name));
+                        volumes.push(json!({ "name": volume_name, "emptyDir": {} }));
+                        (volume_name, target, false)
+                    }
+                };
+                volume_mounts.push(json!({
+                    "name": volume_name,
+                    "mountPath": target,
+                    "readOnly": read_only,
+                }));
+            }
+            if !volume_mounts.is_empty() {
+                container["volumeMounts"] = json!(volume_mounts);
+            }
+
+            restart_policies.extend(service.restart.clone());
+            containers.push(container);
+        }
+        anyhow::ensure!(
+            !containers.is_empty(),
+            "compose file has no services to run"
+        );
+
+        // Restart policy is defined for the entire pod, so the most permissive policy of the services is used.
+        let restart_policy = if restart_policies
+            .iter()
+            .any(|policy| policy == "always" || policy == "unless-stopped")
+        {
+            "Always"
+        } else if restart_policies
+            .iter()
+            .any(|policy| policy.starts_with("on-failure"))
+        {
+            "OnFailure"
+        } else {
+            "Never"
+        };
+
+        let manifest = json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": name },
+            "spec": {
+                "restartPolicy": restart_policy,
+                "containers": containers,
+                "volumes": volumes,
+            },
+        });
+        Ok(Self {
+            name,
+            claims,
+            manifest,
+        })
+    }
+
+    /// Writes the pod manifest next to the compose file and returns its path.
+    fn write_manifest(&self, shell: &Shell, docker_compose_file: &str) -> anyhow::Result<PathBuf> {
+        let path = shell
+            .current_dir()
+            .join(docker_compose_file)
+            .with_extension("kube.yml");
+        shell.write_file(&path, serde_yaml::to_string(&self.manifest)?)?;
+        Ok(path)
+    }
+}
+
+fn pod_name(compose: &ComposeFile) -> String {
+    dns_label(&compose.project_name())
+}
+
+fn claim_name(compose: &ComposeFile, pod_name: &str, volume: &str) -> anyhow::Result<String> {
+    let spec = compose.volume(volume)?;
+    Ok(match spec.and_then(|spec| spec.name.clone()) {
+        Some(name) => name,
+        None => dns_label(&format!("{pod_name}-{volume}")),
+    })
+}
+
+/// Converts a compose name to a valid Kubernetes object name.
+fn dns_label(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_owned()
+}
+
+pub(super) fn volume_name(compose: &ComposeFile, volume: &str) -> anyhow::Result<String> {
+    claim_name(compose, &pod_name(compose), volume)
+}
+
+pub(super) fn up(shell: &Shell, docker_compose_file: &str, detach: bool) -> anyhow::Result<()> {
+    let pod = Pod::from_compose(&ComposeFile::read(shell, docker_compose_file)?)?;
+    let manifest_path = pod.write_manifest(shell, docker_compose_file)?;
+    Cmd::new(cmd!(shell, "podman play kube --replace {manifest_path}")).run()?;
+    if !detach {
+        let name = &pod.name;
+        Cmd::new(cmd!(shell, "podman pod logs --follow {name}"))
+            .with_force_run()
+            .run()?;
+    }
+    Ok(())
+}
+
+pub(super) fn down(
+    shell: &Shell,
+    docker_compose_file: &str,
+    remove_volumes: bool,
+) -> anyhow::Result<()> {
+    let pod = Pod::from_compose(&ComposeFile::read(shell, docker_compose_file)?)?;
+    let manifest_path = pod.write_manifest(shell, docker_compose_file)?;
+    Cmd::new(cmd!(shell, "podman play kube --down {manifest_path}")).run()?;
+    if remove_volumes {
+        for claim in &pod.claims {
+            Cmd::new(cmd!(shell, "podman volume rm --force {claim}")).run()?;
+        }
+    }
+    Ok(())
+}
+
+/// Pauses or unpauses the container of a service.
+pub(super) fn pause(
+    shell: &Shell,
+    docker_compose_file: &str,
+    service: &str,
+    pause: bool,
+) -> anyhow::Result<()> {
+    let pod_name = pod_name(&ComposeFile::read(shell, docker_compose_file)?);
+    let container = format!("{pod_name}-{service}");
+    let action = if pause { "pause" } else { "unpause" };
+    Ok(Cmd::new(cmd!(shell, "podman {action} {container}")).run()?)
+}
+
+pub(super) fn running_services(
+    shell: &Shell,
+    docker_compose_file: &str,
+) -> anyhow::Result<Vec<String>> {
+    let pod_name = pod_name(&ComposeFile::read(shell, docker_compose_file)?);
+    let ps = shell.cmd("podman").args([
+        "ps".to_owned(),
+        format!("--filter=pod={pod_name}"),
+        "--filter=status=running".to_owned(),
+        "--format={{.Names}}".to_owned(),
+    ]);
+    let output = Cmd::new(ps).run_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    // Containers are named `{pod}-{container}`; the infra container doesn't have the pod name prefix.
+    let prefix = format!("{pod_name}-");
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(&prefix))
+        .map(str::to_owned)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::compose::ComposeSpec;
+
+    const COMPOSE: &str = r#"
+name: zksync_era
+services:
+  reth:
+    restart: always
+    image: "ghcr.io/paradigmxyz/reth:v1.3.7"
+    volumes:
+      - type: bind
+        source: ./volumes/reth/data
+        target: /rethdata
+      - ./etc/reth/chaindata:/chaindata:ro
+    command: node --dev --http.addr 0.0.0.0 --http.corsdomain "*"
+    ports:
+      - 127.0.0.1:8545:8545
+  postgres:
+    image: "postgres:14"
+    command: postgres -c 'max_connections=1000'
+    ports:
+      - 127.0.0.1:5432:5432
+    volumes:
+      - postgres-data:/var/lib/postgresql/data
+    environment:
+      - POSTGRES_PASSWORD=notsecurepassword
+      - PGPORT=5432
+  observability:
+    image: grafana/grafana
+    profiles: [observability]
+volumes:
+  postgres-data:
+"#;
+
+    fn compose() -> ComposeFile {
+        ComposeFile {
+            dir: PathBuf::from("/ecosystem"),
+            spec: serde_yaml::from_str::<ComposeSpec>(COMPOSE).unwrap(),
+        }
+    }
+
+    #[test]
+    fn converting_compose_file_to_pod() {
+        let compose = compose();
+        let pod = Pod::from_compose(&compose).unwrap();
+        assert_eq!(pod.name, "zksync-era");
+        assert_eq!(pod.claims, ["zksync-era-postgres-data"]);
+        assert_eq!(
+            volume_name(&compose, "postgres-data").unwrap(),
+            pod.claims[0]
+        );
+        volume_name(&compose, "reth-data").unwrap_err();
+
+        let spec = &pod.manifest["spec"];
+        assert_eq!(spec["restartPolicy"], "Always");
+        let containers = spec["containers"].as_array().unwrap();
+        let names: Vec<_> = containers
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["postgres", "reth"]);
+
+        let postgres = &containers[0];
+        assert_eq!(
+            postgres["args"],
+            json!(["postgres", "-c", "max_connections=1000"])
+        );
+        assert_eq!(
+            postgres["env"],
+            json!([
+                { "name": "POSTGRES_PASSWORD", "value": "notsecurepassword" },
+                { "name": "PGPORT", "value": "5432" },
+            ])
+        );
+        assert_eq!(
+            postgres["ports"],
+            json!([{ "containerPort": 5432, "hostPort": 5432, "hostIP": "127.0.0.1", "protocol": "TCP" }])
+        );
+        assert_eq!(postgres["volumeMounts"][0]["name"], "postgres-data");
+
+        let reth = &containers[1];
+        assert_eq!(reth["args"][5], "*");
+        assert_eq!(reth["volumeMounts"][1]["readOnly"], true);
+        let volumes = spec["volumes"].as_array().unwrap();
+        assert_eq!(
+            volumes[0]["persistentVolumeClaim"]["claimName"],
+            "zksync-era-postgres-data"
+        );
+        assert_eq!(
+            volumes[1]["hostPath"]["path"],
+            "/ecosystem/./volumes/reth/data"
+        );
+    }
+
+    #[test]
+    fn rejecting_services_with_build() {
+        let compose = ComposeFile {
+            dir: PathBuf::from("/ecosystem"),
+            spec: serde_yaml::from_str("services:\n  app:\n    build: .\n").unwrap(),
+        };
+        let err = Pod::from_compose(&compose).unwrap_err();
+        assert!(err.to_string().contains("building images"), "{err}");
+    }
+}
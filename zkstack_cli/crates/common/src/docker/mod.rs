@@ -0,0 +1,256 @@
+//! Container management for the local setup. Containers are run either with Docker or with Podman (including rootless
+//! Podman). Compose files are run with `docker compose`, `podman-compose`, or, if the latter is not installed,
+//! converted to a Kubernetes pod and run with `podman play kube`.
+
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::Context as _;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use url::Url;
+use xshell::{cmd, Shell};
+
+use self::compose::ComposeFile;
+use crate::cmd::Cmd;
+
+mod compose;
+mod kube;
+
+/// Container runtime used for the local setup.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ContainerRuntime {
+    #[default]
+    Docker,
+    Podman,
+}
+
+impl ContainerRuntime {
+    fn program(self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+}
+
+/// Tool running compose files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComposeBackend {
+    DockerCompose,
+    PodmanCompose,
+    /// Compose files are converted to Kubernetes pods run with `podman play kube`.
+    PodmanKube,
+}
+
+impl ComposeBackend {
+    /// Returns the command to run compose subcommands with, or `None` for [`Self::PodmanKube`].
+    fn command<'a>(self, shell: &'a Shell, docker_compose_file: &str) -> Option<xshell::Cmd<'a>> {
+        match self {
+            Self::DockerCompose => Some(cmd!(shell, "docker compose -f {docker_compose_file}")),
+            Self::PodmanCompose => Some(cmd!(shell, "podman-compose -f {docker_compose_file}")),
+            Self::PodmanKube => None,
+        }
+    }
+}
+
+static SELECTED_RUNTIME: Mutex<Option<ContainerRuntime>> = Mutex::new(None);
+static HAS_PODMAN_COMPOSE: OnceLock<bool> = OnceLock::new();
+
+/// Selects the container runtime for all subsequent container commands instead of detecting it.
+pub fn set_runtime(runtime: ContainerRuntime) {
+    *SELECTED_RUNTIME.lock().unwrap() = Some(runtime);
+}
+
+/// Returns the selected container runtime. If no runtime was selected, it is detected on the first call.
+pub fn runtime(shell: &Shell) -> ContainerRuntime {
+    *SELECTED_RUNTIME
+        .lock()
+        .unwrap()
+        .get_or_insert_with(|| detect_runtime(shell).unwrap_or_default())
+}
+
+/// Detects an installed container runtime. Docker with the compose plugin is preferred over Podman.
+pub fn detect_runtime(shell: &Shell) -> Option<ContainerRuntime> {
+    if succeeds(cmd!(shell, "docker compose version")) {
+        Some(ContainerRuntime::Docker)
+    } else if succeeds(cmd!(shell, "podman --version")) {
+        Some(ContainerRuntime::Podman)
+    } else {
+        None
+    }
+}
+
+/// Checks whether Podman runs in the rootless mode.
+pub fn is_rootless_podman(shell: &Shell) -> bool {
+    let info = shell
+        .cmd("podman")
+        .args(["info", "--format", "{{.Host.Security.Rootless}}"]);
+    let Ok(output) = Cmd::new(info).run_with_output() else {
+        return false;
+    };
+    output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "true"
+}
+
+fn succeeds(cmd: xshell::Cmd<'_>) -> bool {
+    Cmd::new(cmd)
+        .run_with_output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn compose_backend(shell: &Shell) -> ComposeBackend {
+    match runtime(shell) {
+        ContainerRuntime::Docker => ComposeBackend::DockerCompose,
+        ContainerRuntime::Podman => {
+            let has_podman_compose =
+                *HAS_PODMAN_COMPOSE.get_or_init(|| succeeds(cmd!(shell, "podman-compose version")));
+            if has_podman_compose {
+                ComposeBackend::PodmanCompose
+            } else {
+                ComposeBackend::PodmanKube
+            }
+        }
+    }
+}
+
+pub fn up(shell: &Shell, docker_compose_file: &str, detach: bool) -> anyhow::Result<()> {
+    let Some(compose) = compose_backend(shell).command(shell, docker_compose_file) else {
+        return kube::up(shell, docker_compose_file, detach);
+    };
+    let args = if detach { vec!["-d"] } else { vec![] };
+    let mut cmd = Cmd::new(compose.arg("up").args(args));
+    cmd = if !detach { cmd.with_force_run() } else { cmd };
+    Ok(cmd.run()?)
+}
+
+pub fn down(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<()> {
+    let Some(compose) = compose_backend(shell).command(shell, docker_compose_file) else {
+        return kube::down(shell, docker_compose_file, true);
+    };
+    Ok(Cmd::new(compose.args(["down", "-v"])).run()?)
+}
+
+/// Stops and removes containers, keeping named volumes intact.
+pub fn stop(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<()> {
+    let Some(compose) = compose_backend(shell).command(shell, docker_compose_file) else {
+        return kube::down(shell, docker_compose_file, false);
+    };
+    Ok(Cmd::new(compose.arg("down")).run()?)
+}
+
+/// Removes a named volume declared in the docker compose file. The containers using the volume must be stopped.
+pub fn remove_volume(shell: &Shell, docker_compose_file: &str, volume: &str) -> anyhow::Result<()> {
+    let name = match compose_backend(shell) {
+        ComposeBackend::DockerCompose => {
+            docker_compose_volume_name(shell, docker_compose_file, volume)?
+        }
+        ComposeBackend::PodmanCompose => {
+            ComposeFile::read(shell, docker_compose_file)?.volume_name(volume)?
+        }
+        ComposeBackend::PodmanKube => {
+            kube::volume_name(&ComposeFile::read(shell, docker_compose_file)?, volume)?
+        }
+    };
+    let program = runtime(shell).program();
+    Ok(Cmd::new(cmd!(shell, "{program} volume rm --force {name}")).run()?)
+}
+
+fn docker_compose_volume_name(
+    shell: &Shell,
+    docker_compose_file: &str,
+    volume: &str,
+) -> anyhow::Result<String> {
+    // Volume names are prefixed with the compose project name, so the resolved name is taken from the compose config.
+    let output = Cmd::new(cmd!(
+        shell,
+        "docker compose -f {docker_compose_file} config --format json"
+    ))
+    .run_with_output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "failed reading docker compose config from {docker_compose_file}"
+    );
+    let config: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let name = config["volumes"][volume]["name"]
+        .as_str()
+        .with_context(|| format!("volume `{volume}` is not declared in {docker_compose_file}"))?;
+    Ok(name.to_owned())
+}
+
+/// Returns names of the services from the docker compose file that are started by default, i.e., don't have profiles.
+pub fn services(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<Vec<String>> {
+    let compose = ComposeFile::read(shell, docker_compose_file)?;
+    Ok(compose
+        .spec
+        .services
+        .into_iter()
+        .filter(|(_, service)| service.profiles.is_empty())
+        .map(|(name, _)| name)
+        .collect())
+}
+
+/// Returns names of the running services from the docker compose file.
+pub fn running_services(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<Vec<String>> {
+    let backend = compose_backend(shell);
+    let cmd = match backend {
+        ComposeBackend::DockerCompose => cmd!(
+            shell,
+            "docker compose -f {docker_compose_file} ps --services --filter status=running"
+        ),
+        ComposeBackend::PodmanCompose => {
+            let project = ComposeFile::read(shell, docker_compose_file)?.project_name();
+            shell.cmd("podman").args([
+                "ps".to_owned(),
+                format!("--filter=label=io.podman.compose.project={project}"),
+                "--filter=status=running".to_owned(),
+                r#"--format={{index .Labels "com.docker.compose.service"}}"#.to_owned(),
+            ])
+        }
+        ComposeBackend::PodmanKube => {
+            return kube::running_services(shell, docker_compose_file);
+        }
+    };
+    let output = Cmd::new(cmd).run_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.trim().to_owned())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+pub fn pause(shell: &Shell, docker_compose_file: &str, service: &str) -> anyhow::Result<()> {
+    let Some(compose) = compose_backend(shell).command(shell, docker_compose_file) else {
+        return kube::pause(shell, docker_compose_file, service, true);
+    };
+    Ok(Cmd::new(compose.args(["pause", service])).run()?)
+}
+
+pub fn unpause(shell: &Shell, docker_compose_file: &str, service: &str) -> anyhow::Result<()> {
+    let Some(compose) = compose_backend(shell).command(shell, docker_compose_file) else {
+        return kube::pause(shell, docker_compose_file, service, false);
+    };
+    Ok(Cmd::new(compose.args(["unpause", service])).run()?)
+}
+
+pub fn run(shell: &Shell, docker_image: &str, docker_args: Vec<String>) -> anyhow::Result<()> {
+    let program = runtime(shell).program();
+    Ok(Cmd::new(cmd!(shell, "{program} run {docker_args...} {docker_image}")).run()?)
+}
+
+pub fn adjust_localhost_for_docker(mut url: Url) -> anyhow::Result<Url> {
+    if let Some(host) = url.host_str() {
+        if host == "localhost" || host == "127.0.0.1" {
+            url.set_host(Some("host.docker.internal"))?;
+        }
+    } else {
+        anyhow::bail!("Failed to parse: no host");
+    }
+    Ok(url)
+}
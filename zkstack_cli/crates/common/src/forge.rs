@@ -17,7 +17,9 @@ use xshell::{cmd, Shell};
 
 use crate::{
     cmd::{Cmd, CmdResult},
+    config::global_config,
     ethereum::create_ethers_client,
+    logger, tx_bundle,
 };
 
 /// Forge is a wrapper around the forge binary.
@@ -55,7 +57,43 @@ pub struct ForgeScript {
 
 impl ForgeScript {
     /// Run the forge script command.
+    ///
+    /// If the unsigned transactions directory is set in the global config, the script is only simulated,
+    /// and the transactions it would broadcast are appended to the bundle in this directory.
     pub fn run(mut self, shell: &Shell) -> anyhow::Result<()> {
+        let unsigned_txs_dir = global_config().unsigned_txs_dir.as_ref();
+        let export_to = match unsigned_txs_dir {
+            Some(dir) if self.args.args.contains(&ForgeScriptArg::Broadcast) => {
+                self.args
+                    .args
+                    .retain(|arg| *arg != ForgeScriptArg::Broadcast);
+                Some(shell.current_dir().join(dir))
+            }
+            _ => None,
+        };
+        let dry_run_files = match export_to {
+            Some(_) => tx_bundle::dry_run_files(&self.base_path, &self.script_path)?,
+            None => Default::default(),
+        };
+        self.run_script(shell)?;
+
+        if let Some(out_dir) = export_to {
+            let count = tx_bundle::export_dry_run(
+                shell,
+                &self.base_path,
+                &self.script_path,
+                &dry_run_files,
+                &out_dir,
+            )?;
+            logger::info(format!(
+                "Added {count} unsigned transaction(s) of {:?} to {out_dir:?}",
+                self.script_path
+            ));
+        }
+        Ok(())
+    }
+
+    fn run_script(&mut self, shell: &Shell) -> anyhow::Result<()> {
         let _dir_guard = shell.push_dir(&self.base_path);
         let script_path = self.script_path.as_os_str();
         let args_no_resume = self.args.build();
@@ -38,3 +38,37 @@ pub fn pull(shell: &Shell, link_to_code: PathBuf) -> anyhow::Result<()> {
     Cmd::new(cmd!(shell, "git pull origin {current_branch}")).run()?;
     Ok(())
 }
+
+/// Returns the checked out commit and branch; the branch is `None` if HEAD is detached.
+pub fn head(shell: &Shell, link_to_code: PathBuf) -> anyhow::Result<(String, Option<String>)> {
+    let _dir_guard = shell.push_dir(link_to_code);
+    let res = Cmd::new(cmd!(shell, "git rev-parse HEAD")).run_with_output()?;
+    let revision = String::from_utf8(res.stdout)?.trim_end().to_owned();
+    let res = Cmd::new(cmd!(shell, "git rev-parse --abbrev-ref HEAD")).run_with_output()?;
+    let branch = String::from_utf8(res.stdout)?.trim_end().to_owned();
+    let branch = (branch != "HEAD").then_some(branch);
+    Ok((revision, branch))
+}
+
+/// Fetches the remote and checks out the specified branch, tag or commit.
+pub fn checkout(shell: &Shell, link_to_code: PathBuf, git_ref: &str) -> anyhow::Result<()> {
+    let _dir_guard = shell.push_dir(link_to_code);
+    Cmd::new(cmd!(shell, "git fetch origin --tags")).run()?;
+    Cmd::new(cmd!(shell, "git checkout {git_ref}")).run()?;
+    Ok(())
+}
+
+/// Checks out the revision, resetting the branch to it if specified.
+pub fn checkout_revision(
+    shell: &Shell,
+    link_to_code: PathBuf,
+    revision: &str,
+    branch: Option<&str>,
+) -> anyhow::Result<()> {
+    let _dir_guard = shell.push_dir(link_to_code);
+    match branch {
+        Some(branch) => Cmd::new(cmd!(shell, "git checkout -B {branch} {revision}")).run()?,
+        None => Cmd::new(cmd!(shell, "git checkout {revision}")).run()?,
+    }
+    Ok(())
+}
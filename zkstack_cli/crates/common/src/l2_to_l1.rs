@@ -0,0 +1,425 @@
+//! Export of L2→L1 logs and reconstruction of per-batch L2→L1 logs Merkle roots.
+//!
+//! Exports are stored as JSON Lines files with one [`BatchL2ToL1Logs`] record per L1 batch. Files are
+//! append-only: batches are written in ascending order and existing records are never rewritten, so that
+//! an export can be incrementally extended and audited.
+//!
+//! Starting from the gateway protocol version, the root stored on L1 is `keccak(local_root, aggregation_root)`,
+//! where the local root is the root of the Merkle tree over the batch logs, and the aggregation root
+//! is read from the `MessageRoot` L2 contract at the end of the batch.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::Context;
+use ethers::{
+    contract::abigen,
+    providers::{Http, Provider},
+    types::Address as EthAddress,
+};
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::{
+    protocol_version::ProtocolVersionId, web3::keccak256, Address, L1BatchNumber, L2BlockNumber,
+    H256, U256, U64,
+};
+use zksync_system_constants::{
+    message_root::{AGG_TREE_HEIGHT_KEY, AGG_TREE_NODES_KEY},
+    L2_MESSAGE_ROOT_ADDRESS,
+};
+use zksync_types::l2_to_l1_log::l2_to_l1_logs_tree_size;
+
+/// Size of a serialized L2→L1 log, i.e. a leaf of the L2→L1 logs Merkle tree.
+pub const L2_TO_L1_LOG_SERIALIZED_SIZE: usize = 88;
+
+/// L2→L1 log as returned in the `l2ToL1Logs` field of transaction receipts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1LogEntry {
+    pub block_number: U64,
+    pub transaction_hash: H256,
+    pub tx_index_in_l1_batch: U64,
+    pub shard_id: U64,
+    pub is_service: bool,
+    pub sender: Address,
+    pub key: H256,
+    pub value: H256,
+}
+
+impl L2ToL1LogEntry {
+    /// Serializes the log in the same way as it is committed to L1.
+    pub fn to_bytes(&self) -> [u8; L2_TO_L1_LOG_SERIALIZED_SIZE] {
+        let mut buffer = [0_u8; L2_TO_L1_LOG_SERIALIZED_SIZE];
+        buffer[0] = self.shard_id.as_u64() as u8;
+        buffer[1] = self.is_service as u8;
+        buffer[2..4].copy_from_slice(&(self.tx_index_in_l1_batch.as_u64() as u16).to_be_bytes());
+        buffer[4..24].copy_from_slice(self.sender.as_bytes());
+        buffer[24..56].copy_from_slice(self.key.as_bytes());
+        buffer[56..88].copy_from_slice(self.value.as_bytes());
+        buffer
+    }
+}
+
+/// All L2→L1 logs emitted in an L1 batch together with the reconstructed Merkle root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchL2ToL1Logs {
+    pub l1_batch_number: L1BatchNumber,
+    pub protocol_version: Option<ProtocolVersionId>,
+    pub logs: Vec<L2ToL1LogEntry>,
+    /// Aggregation root of the batch; only set for batches with the gateway protocol version or newer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregation_root: Option<H256>,
+    /// Root hash in the form stored on L1.
+    pub root_hash: H256,
+}
+
+impl BatchL2ToL1Logs {
+    pub fn new(
+        l1_batch_number: L1BatchNumber,
+        protocol_version: Option<ProtocolVersionId>,
+        logs: Vec<L2ToL1LogEntry>,
+        aggregation_root: Option<H256>,
+    ) -> anyhow::Result<Self> {
+        let mut this = Self {
+            l1_batch_number,
+            protocol_version,
+            logs,
+            aggregation_root,
+            root_hash: H256::zero(),
+        };
+        this.root_hash = this
+            .reconstruct_root_hash()
+            .with_context(|| format!("failed computing logs root for batch {l1_batch_number}"))?;
+        Ok(this)
+    }
+
+    fn reconstruct_root_hash(&self) -> anyhow::Result<H256> {
+        let protocol_version = self
+            .protocol_version
+            .context("protocol version is unknown")?;
+        let local_root =
+            l2_to_l1_logs_root_hash(&self.logs, l2_to_l1_logs_tree_size(protocol_version))?;
+        if protocol_version.is_pre_gateway() {
+            return Ok(local_root);
+        }
+        let aggregation_root = self
+            .aggregation_root
+            .context("aggregation root is missing for a post-gateway batch")?;
+        Ok(hash_pair(local_root, aggregation_root))
+    }
+
+    /// Checks that the stored root hash matches the one reconstructed from the stored logs.
+    pub fn check_root_hash(&self) -> anyhow::Result<()> {
+        let root_hash = self.reconstruct_root_hash().with_context(|| {
+            format!(
+                "failed computing logs root for batch {}",
+                self.l1_batch_number
+            )
+        })?;
+        anyhow::ensure!(
+            root_hash == self.root_hash,
+            "root hash mismatch for batch {}: stored {:?}, reconstructed {root_hash:?}",
+            self.l1_batch_number,
+            self.root_hash
+        );
+        Ok(())
+    }
+}
+
+fn hash_pair(lhs: H256, rhs: H256) -> H256 {
+    let mut buffer = [0_u8; 64];
+    buffer[..32].copy_from_slice(lhs.as_bytes());
+    buffer[32..].copy_from_slice(rhs.as_bytes());
+    H256(keccak256(&buffer))
+}
+
+/// Computes the root of the Merkle tree with the specified minimum size over serialized `logs`.
+/// Empty leaves are hashes of zero-filled serialized logs.
+pub fn l2_to_l1_logs_root_hash(logs: &[L2ToL1LogEntry], tree_size: usize) -> anyhow::Result<H256> {
+    anyhow::ensure!(
+        logs.len() <= tree_size,
+        "number of logs ({}) exceeds tree size ({tree_size})",
+        logs.len()
+    );
+    let tree_size = tree_size.next_power_of_two();
+    let empty_leaf_hash = H256(keccak256(&[0; L2_TO_L1_LOG_SERIALIZED_SIZE]));
+    let mut hashes: Vec<_> = logs
+        .iter()
+        .map(|log| H256(keccak256(&log.to_bytes())))
+        .collect();
+    hashes.resize(tree_size, empty_leaf_hash);
+
+    while hashes.len() > 1 {
+        hashes = hashes
+            .chunks(2)
+            .map(|pair| hash_pair(pair[0], pair[1]))
+            .collect();
+    }
+    Ok(hashes[0])
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReceiptL2ToL1Logs {
+    l2_to_l1_logs: Vec<L2ToL1LogEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockProtocolVersion {
+    protocol_version: Option<ProtocolVersionId>,
+}
+
+/// Fetches all L2→L1 logs for the specified L1 batch from an L2 node. Returns `None` if the batch
+/// is not sealed yet.
+pub async fn fetch_batch_l2_to_l1_logs(
+    l2_provider: &Provider<Http>,
+    l1_batch_number: L1BatchNumber,
+) -> anyhow::Result<Option<BatchL2ToL1Logs>> {
+    let block_range: Option<(U64, U64)> = l2_provider
+        .request("zks_getL1BatchBlockRange", [l1_batch_number])
+        .await
+        .context("zks_getL1BatchBlockRange")?;
+    let Some((first_block, last_block)) = block_range else {
+        return Ok(None);
+    };
+
+    let first_block = L2BlockNumber(first_block.as_u32());
+    let block_details: Option<BlockProtocolVersion> = l2_provider
+        .request("zks_getBlockDetails", [first_block])
+        .await
+        .context("zks_getBlockDetails")?;
+    let protocol_version = block_details
+        .and_then(|details| details.protocol_version)
+        .with_context(|| format!("protocol version is unknown for batch {l1_batch_number}"))?;
+
+    let mut logs = vec![];
+    for block_number in first_block.0..=last_block.as_u32() {
+        let receipts: Vec<ReceiptL2ToL1Logs> = l2_provider
+            .request("eth_getBlockReceipts", [U64::from(block_number)])
+            .await
+            .with_context(|| format!("eth_getBlockReceipts({block_number})"))?;
+        logs.extend(
+            receipts
+                .into_iter()
+                .flat_map(|receipt| receipt.l2_to_l1_logs),
+        );
+    }
+    let aggregation_root = if protocol_version.is_pre_gateway() {
+        None
+    } else {
+        Some(fetch_aggregation_root(l2_provider, last_block).await?)
+    };
+    BatchL2ToL1Logs::new(
+        l1_batch_number,
+        Some(protocol_version),
+        logs,
+        aggregation_root,
+    )
+    .map(Some)
+}
+
+/// Reads the aggregation root (i.e., `nodes[height][0]` of the aggregation tree) from the `MessageRoot`
+/// L2 contract storage as of the specified L2 block.
+async fn fetch_aggregation_root(
+    l2_provider: &Provider<Http>,
+    l2_block_number: U64,
+) -> anyhow::Result<H256> {
+    let height_slot = H256::from_low_u64_be(AGG_TREE_HEIGHT_KEY as u64);
+    let height: H256 = l2_provider
+        .request(
+            "eth_getStorageAt",
+            (L2_MESSAGE_ROOT_ADDRESS, height_slot, l2_block_number),
+        )
+        .await
+        .context("eth_getStorageAt(MessageRoot height)")?;
+    let height = U256::from_big_endian(height.as_bytes());
+
+    let root_slot = array_element_slot(
+        array_element_slot(H256::from_low_u64_be(AGG_TREE_NODES_KEY as u64), height),
+        U256::zero(),
+    );
+    l2_provider
+        .request(
+            "eth_getStorageAt",
+            (L2_MESSAGE_ROOT_ADDRESS, root_slot, l2_block_number),
+        )
+        .await
+        .context("eth_getStorageAt(MessageRoot root)")
+}
+
+/// Returns the storage slot of an element of a dynamic array stored at `array_slot`.
+fn array_element_slot(array_slot: H256, index: U256) -> H256 {
+    let data_start = U256::from_big_endian(&keccak256(array_slot.as_bytes()));
+    let mut slot = H256::zero();
+    data_start
+        .overflowing_add(index)
+        .0
+        .to_big_endian(slot.as_bytes_mut());
+    slot
+}
+
+abigen!(
+    L2LogsRootGetter,
+    r"[
+    function l2LogsRootHash(uint256 _batchNumber) external view returns (bytes32)
+    ]"
+);
+
+/// Returns the L2→L1 logs root hash stored on L1 for the specified batch. The root is set once the batch
+/// is executed; for batches that are not executed yet, zero hash is returned.
+pub async fn l1_logs_root_hash(
+    l1_provider: Arc<Provider<Http>>,
+    diamond_proxy_addr: EthAddress,
+    l1_batch_number: L1BatchNumber,
+) -> anyhow::Result<H256> {
+    let contract = L2LogsRootGetter::new(diamond_proxy_addr, l1_provider);
+    let root_hash = contract
+        .l_2_logs_root_hash(l1_batch_number.0.into())
+        .call()
+        .await
+        .with_context(|| format!("l2LogsRootHash({l1_batch_number})"))?;
+    Ok(H256(root_hash))
+}
+
+/// Reads all batches from an export file. Returns an empty list if the file doesn't exist.
+pub fn read_export(path: &Path) -> anyhow::Result<Vec<BatchL2ToL1Logs>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let file = File::open(path).with_context(|| format!("failed opening {path:?}"))?;
+    let mut batches = Vec::<BatchL2ToL1Logs>::new();
+    for (line_idx, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.with_context(|| format!("failed reading {path:?}"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let batch: BatchL2ToL1Logs = serde_json::from_str(&line)
+            .with_context(|| format!("invalid record at {path:?}:{}", line_idx + 1))?;
+        if let Some(prev_batch) = batches.last() {
+            anyhow::ensure!(
+                batch.l1_batch_number == prev_batch.l1_batch_number + 1,
+                "non-consecutive batches in {path:?}: {} follows {}",
+                batch.l1_batch_number,
+                prev_batch.l1_batch_number
+            );
+        }
+        batches.push(batch);
+    }
+    Ok(batches)
+}
+
+/// Appends a batch to an export file, creating the file if necessary.
+pub fn append_to_export(path: &Path, batch: &BatchL2ToL1Logs) -> anyhow::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed opening {path:?}"))?;
+    let mut line = serde_json::to_string(batch)?;
+    line.push('\n');
+    file.write_all(line.as_bytes())
+        .with_context(|| format!("failed writing to {path:?}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(tx_index: u64, value: u64) -> L2ToL1LogEntry {
+        L2ToL1LogEntry {
+            block_number: 1.into(),
+            transaction_hash: H256::repeat_byte(1),
+            tx_index_in_l1_batch: tx_index.into(),
+            shard_id: 0.into(),
+            is_service: true,
+            sender: Address::repeat_byte(0x80),
+            key: H256::repeat_byte(2),
+            value: H256::from_low_u64_be(value),
+        }
+    }
+
+    #[test]
+    fn log_serialization() {
+        let bytes = log(0x0102, 3).to_bytes();
+        assert_eq!(bytes[..4], [0, 1, 1, 2]);
+        assert_eq!(bytes[4..24], [0x80; 20]);
+        assert_eq!(bytes[87], 3);
+    }
+
+    #[test]
+    fn reconstructing_root_hash() {
+        let logs = [log(0, 1), log(1, 2), log(1, 3)];
+        let leaves: Vec<_> = logs
+            .iter()
+            .map(|log| H256(keccak256(&log.to_bytes())))
+            .collect();
+        let empty_leaf = H256(keccak256(&[0; L2_TO_L1_LOG_SERIALIZED_SIZE]));
+        let expected = hash_pair(
+            hash_pair(leaves[0], leaves[1]),
+            hash_pair(leaves[2], empty_leaf),
+        );
+        assert_eq!(l2_to_l1_logs_root_hash(&logs, 4).unwrap(), expected);
+
+        let empty_root = hash_pair(
+            hash_pair(empty_leaf, empty_leaf),
+            hash_pair(empty_leaf, empty_leaf),
+        );
+        assert_eq!(l2_to_l1_logs_root_hash(&[], 4).unwrap(), empty_root);
+        l2_to_l1_logs_root_hash(&logs, 2).unwrap_err();
+    }
+
+    #[test]
+    fn export_roundtrip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("export.jsonl");
+
+        let batches: Vec<_> = (1..=2)
+            .map(|number| {
+                BatchL2ToL1Logs::new(
+                    L1BatchNumber(number),
+                    Some(ProtocolVersionId::Version26),
+                    vec![log(0, number.into())],
+                    None,
+                )
+                .unwrap()
+            })
+            .collect();
+        for batch in &batches {
+            append_to_export(&path, batch).unwrap();
+        }
+        let exported = read_export(&path).unwrap();
+        assert_eq!(exported, batches);
+        for batch in &exported {
+            batch.check_root_hash().unwrap();
+        }
+    }
+
+    #[test]
+    fn root_hash_for_gateway_batches() {
+        let logs = vec![log(0, 1)];
+        let protocol_version = ProtocolVersionId::Version27;
+        let local_root =
+            l2_to_l1_logs_root_hash(&logs, l2_to_l1_logs_tree_size(protocol_version)).unwrap();
+        let aggregation_root = H256::repeat_byte(0xaa);
+
+        let batch = BatchL2ToL1Logs::new(
+            L1BatchNumber(1),
+            Some(protocol_version),
+            logs.clone(),
+            Some(aggregation_root),
+        )
+        .unwrap();
+        assert_eq!(batch.root_hash, hash_pair(local_root, aggregation_root));
+        batch.check_root_hash().unwrap();
+
+        BatchL2ToL1Logs::new(L1BatchNumber(1), Some(protocol_version), logs.clone(), None)
+            .unwrap_err();
+        BatchL2ToL1Logs::new(L1BatchNumber(1), None, logs, None).unwrap_err();
+    }
+}
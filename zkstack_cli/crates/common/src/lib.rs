@@ -12,14 +12,16 @@ pub mod external_node;
 pub mod files;
 pub mod forge;
 pub mod git;
+pub mod l2_to_l1;
 pub mod server;
+pub mod tx_bundle;
 pub mod version;
 pub mod wallets;
 pub mod yaml;
 
 pub use prerequisites::{
-    check_general_prerequisites, check_prerequisites, GCLOUD_PREREQUISITE, GPU_PREREQUISITES,
-    PROVER_CLI_PREREQUISITE,
+    check_general_prerequisites, check_prerequisites, missing_general_prerequisites,
+    GCLOUD_PREREQUISITE, GPU_PREREQUISITES, POSTGRES_CLIENT_PREREQUISITES, PROVER_CLI_PREREQUISITE,
 };
-pub use prompt::{init_prompt_theme, Prompt, PromptConfirm, PromptSelect};
+pub use prompt::{disable_prompts, init_prompt_theme, Prompt, PromptConfirm, PromptSelect};
 pub use term::{error, logger, spinner};
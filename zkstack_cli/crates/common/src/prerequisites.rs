@@ -1,19 +1,14 @@
 use xshell::{cmd, Shell};
 
-use crate::{cmd::Cmd, logger};
+use crate::{cmd::Cmd, docker, logger};
 
-fn prerequisites() -> [Prerequisite; 5] {
+fn prerequisites() -> [Prerequisite; 4] {
     [
         Prerequisite {
             name: "git",
             download_link: "https://git-scm.com/book/en/v2/Getting-Started-Installing-Git",
             custom_validator: None,
         },
-        Prerequisite {
-            name: "docker",
-            download_link: "https://docs.docker.com/get-docker/",
-            custom_validator: None,
-        },
         Prerequisite {
             name: "forge",
             download_link:
@@ -43,8 +38,8 @@ fn prerequisites() -> [Prerequisite; 5] {
     ]
 }
 
-const DOCKER_COMPOSE_PREREQUISITE: Prerequisite = Prerequisite {
-    name: "docker compose",
+const CONTAINER_RUNTIME_PREREQUISITE: Prerequisite = Prerequisite {
+    name: "docker compose (or podman)",
     download_link: "https://docs.docker.com/compose/install/",
     custom_validator: None,
 };
@@ -80,6 +75,19 @@ pub const PROVER_CLI_PREREQUISITE: [Prerequisite; 1] = [Prerequisite {
     custom_validator: None,
 }];
 
+pub const POSTGRES_CLIENT_PREREQUISITES: [Prerequisite; 2] = [
+    Prerequisite {
+        name: "pg_dump",
+        download_link: "https://www.postgresql.org/download/",
+        custom_validator: None,
+    },
+    Prerequisite {
+        name: "pg_restore",
+        download_link: "https://www.postgresql.org/download/",
+        custom_validator: None,
+    },
+];
+
 pub struct Prerequisite {
     name: &'static str,
     download_link: &'static str,
@@ -90,18 +98,16 @@ pub fn check_general_prerequisites(shell: &Shell) {
     check_prerequisites(shell, &prerequisites(), true);
 }
 
-pub fn check_prerequisites(shell: &Shell, prerequisites: &[Prerequisite], check_compose: bool) {
-    let mut missing_prerequisites = vec![];
-
-    for prerequisite in prerequisites {
-        if !check_prerequisite(shell, prerequisite) {
-            missing_prerequisites.push(prerequisite);
-        }
-    }
+/// Returns names and download links of the missing general prerequisites without terminating the process.
+pub fn missing_general_prerequisites(shell: &Shell) -> Vec<(&'static str, &'static str)> {
+    find_missing_prerequisites(shell, &prerequisites(), true)
+        .into_iter()
+        .map(|prerequisite| (prerequisite.name, prerequisite.download_link))
+        .collect()
+}
 
-    if check_compose && !check_docker_compose_prerequisite(shell) {
-        missing_prerequisites.push(&DOCKER_COMPOSE_PREREQUISITE);
-    }
+pub fn check_prerequisites(shell: &Shell, prerequisites: &[Prerequisite], check_compose: bool) {
+    let missing_prerequisites = find_missing_prerequisites(shell, prerequisites, check_compose);
 
     if !missing_prerequisites.is_empty() {
         logger::error("Prerequisite check has failed");
@@ -120,6 +126,25 @@ pub fn check_prerequisites(shell: &Shell, prerequisites: &[Prerequisite], check_
     }
 }
 
+fn find_missing_prerequisites<'a>(
+    shell: &Shell,
+    prerequisites: &'a [Prerequisite],
+    check_compose: bool,
+) -> Vec<&'a Prerequisite> {
+    let mut missing_prerequisites = vec![];
+
+    for prerequisite in prerequisites {
+        if !check_prerequisite(shell, prerequisite) {
+            missing_prerequisites.push(prerequisite);
+        }
+    }
+
+    if check_compose && docker::detect_runtime(shell).is_none() {
+        missing_prerequisites.push(&CONTAINER_RUNTIME_PREREQUISITE);
+    }
+    missing_prerequisites
+}
+
 fn check_prerequisite(shell: &Shell, prerequisite: &Prerequisite) -> bool {
     let name = prerequisite.name;
     if Cmd::new(cmd!(shell, "which {name}")).run().is_err() {
@@ -130,9 +155,3 @@ fn check_prerequisite(shell: &Shell, prerequisite: &Prerequisite) -> bool {
     };
     custom()
 }
-
-fn check_docker_compose_prerequisite(shell: &Shell) -> bool {
-    Cmd::new(cmd!(shell, "docker compose version"))
-        .run()
-        .is_ok()
-}
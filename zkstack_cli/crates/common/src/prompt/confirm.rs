@@ -2,24 +2,31 @@ use std::fmt::Display;
 
 use cliclack::Confirm;
 
+use super::ensure_prompts_enabled;
+
 pub struct PromptConfirm {
+    question: String,
     inner: Confirm,
 }
 
 impl PromptConfirm {
     pub fn new(question: impl Display) -> Self {
+        let question = question.to_string();
         Self {
-            inner: Confirm::new(question),
+            inner: Confirm::new(&question),
+            question,
         }
     }
 
     pub fn default(self, default: bool) -> Self {
         Self {
             inner: self.inner.initial_value(default),
+            ..self
         }
     }
 
     pub fn ask(mut self) -> bool {
+        ensure_prompts_enabled(&self.question);
         self.inner.interact().unwrap()
     }
 }
@@ -2,13 +2,17 @@ use std::str::FromStr;
 
 use cliclack::{Input, Validate};
 
+use super::ensure_prompts_enabled;
+
 pub struct Prompt {
+    question: String,
     inner: Input,
 }
 
 impl Prompt {
     pub fn new(question: &str) -> Self {
         Self {
+            question: question.to_owned(),
             inner: Input::new(question),
         }
     }
@@ -36,6 +40,7 @@ impl Prompt {
     where
         T: FromStr,
     {
+        ensure_prompts_enabled(&self.question);
         self.inner.interact().unwrap()
     }
 }
@@ -2,12 +2,18 @@ mod confirm;
 mod input;
 mod select;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use cliclack::{Theme, ThemeState};
 pub use confirm::PromptConfirm;
 use console::Style;
 pub use input::Prompt;
 pub use select::PromptSelect;
 
+use crate::error::log_error;
+
+static PROMPTS_DISABLED: AtomicBool = AtomicBool::new(false);
+
 pub struct CliclackTheme;
 
 impl Theme for CliclackTheme {
@@ -23,3 +29,18 @@ impl Theme for CliclackTheme {
 pub fn init_prompt_theme() {
     cliclack::set_theme(CliclackTheme);
 }
+
+/// Disables interactive prompts. Any prompt shown afterwards fails the command instead of waiting for input;
+/// used when all values are expected to be provided upfront (e.g., via an answers file).
+pub fn disable_prompts() {
+    PROMPTS_DISABLED.store(true, Ordering::Relaxed);
+}
+
+fn ensure_prompts_enabled(question: &str) {
+    if PROMPTS_DISABLED.load(Ordering::Relaxed) {
+        log_error(anyhow::anyhow!(
+            "No value provided for prompt \"{question}\" while prompts are disabled"
+        ));
+        std::process::exit(1);
+    }
+}
@@ -1,6 +1,9 @@
 use cliclack::Select;
 
+use super::ensure_prompts_enabled;
+
 pub struct PromptSelect<T> {
+    question: String,
     inner: Select<T>,
 }
 
@@ -22,11 +25,13 @@ where
             })
             .collect::<Vec<_>>();
         Self {
+            question: question.to_owned(),
             inner: Select::new(question).items(&items),
         }
     }
 
     pub fn ask(mut self) -> T {
+        ensure_prompts_enabled(&self.question);
         self.inner.interact().unwrap()
     }
 }
@@ -4,7 +4,10 @@ use cliclack::{intro as cliclak_intro, log, outro as cliclak_outro, Theme, Theme
 use console::{style, Emoji, Term};
 use serde::Serialize;
 
-use crate::prompt::CliclackTheme;
+use crate::{
+    config::{global_config, OutputFormat},
+    prompt::CliclackTheme,
+};
 
 const S_BAR: Emoji = Emoji("│", "|");
 
@@ -62,6 +65,14 @@ pub fn error_note(msg: &str, content: &str) {
     term_write(note);
 }
 
+/// Prints the machine-readable result of a command to stdout if JSON output is enabled.
+/// Logs are written to stderr, so stdout contains only the result.
+pub fn result(result: impl Serialize) {
+    if global_config().output == OutputFormat::Json {
+        println!("{}", serde_json::to_string(&result).unwrap());
+    }
+}
+
 pub fn object_to_string(obj: impl Serialize) -> String {
     let json = serde_json::to_value(obj).unwrap();
 
@@ -0,0 +1,316 @@
+//! Bundles of unsigned L1 transactions for offline signing.
+//!
+//! If the unsigned transactions directory is set in the global config, forge scripts aren't broadcast; instead,
+//! the transactions simulated by forge are appended to the bundle in this directory. The bundle can then be signed
+//! on an air-gapped machine and broadcast later.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use anyhow::Context as _;
+use ethers::{
+    prelude::{LocalWallet, Signer},
+    types::{
+        transaction::eip2718::TypedTransaction, Address, Bytes, TransactionRequest, H256, U256, U64,
+    },
+    utils::keccak256,
+};
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+
+/// Name of the bundle file in the unsigned transactions directory.
+pub const BUNDLE_FILE_NAME: &str = "transactions.json";
+
+/// Ordered list of L1 transactions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TransactionBundle {
+    pub transactions: Vec<BundledTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundledTransaction {
+    /// Forge script that produced the transaction.
+    pub script: String,
+    /// Called contract and function, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Unsigned transaction.
+    pub transaction: TransactionRequest,
+    /// RLP-encoded signed transaction; set by signing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed: Option<Bytes>,
+}
+
+impl BundledTransaction {
+    /// Returns the hash of the signed transaction.
+    pub fn signed_hash(&self) -> Option<H256> {
+        self.signed.as_ref().map(|signed| H256(keccak256(signed)))
+    }
+
+    /// Signs the transaction, filling in the gas price if it's not set.
+    fn sign(&mut self, wallet: &LocalWallet, gas_price: Option<U256>) -> anyhow::Result<()> {
+        if let Some(gas_price) = gas_price {
+            self.transaction.gas_price = Some(gas_price);
+        }
+        anyhow::ensure!(
+            self.transaction.gas_price.is_some(),
+            "transaction has no gas price; specify it for signing"
+        );
+        let chain_id = self
+            .transaction
+            .chain_id
+            .context("transaction has no chain ID")?;
+        let wallet = wallet.clone().with_chain_id(chain_id.as_u64());
+        let tx = TypedTransaction::Legacy(self.transaction.clone());
+        let signature = wallet.sign_transaction_sync(&tx)?;
+        self.signed = Some(tx.rlp_signed(&signature));
+        Ok(())
+    }
+}
+
+impl TransactionBundle {
+    pub fn read(shell: &Shell, path: &Path) -> anyhow::Result<Self> {
+        let contents = shell.read_file(path)?;
+        serde_json::from_str(&contents).with_context(|| format!("invalid bundle {path:?}"))
+    }
+
+    pub fn save(&self, shell: &Shell, path: &Path) -> anyhow::Result<()> {
+        shell.write_file(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Signs all transactions sent from the wallet address. Returns the number of signed transactions.
+    pub fn sign(&mut self, wallet: &LocalWallet, gas_price: Option<U256>) -> anyhow::Result<usize> {
+        let mut count = 0;
+        for (i, tx) in self.transactions.iter_mut().enumerate() {
+            if tx.transaction.from == Some(wallet.address()) {
+                tx.sign(wallet, gas_price)
+                    .with_context(|| format!("failed signing transaction #{}", i + 1))?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns senders of the transactions that aren't signed yet.
+    pub fn unsigned_senders(&self) -> Vec<Address> {
+        let mut senders: Vec<_> = self
+            .transactions
+            .iter()
+            .filter(|tx| tx.signed.is_none())
+            .filter_map(|tx| tx.transaction.from)
+            .collect();
+        senders.sort_unstable();
+        senders.dedup();
+        senders
+    }
+}
+
+/// Forge broadcast file, e.g. `broadcast/DeployL1.s.sol/9/dry-run/run-latest.json`.
+#[derive(Debug, Deserialize)]
+struct ForgeBroadcast {
+    transactions: Vec<ForgeTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForgeTransaction {
+    contract_name: Option<String>,
+    function: Option<String>,
+    transaction: ForgeTransactionRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForgeTransactionRequest {
+    from: Address,
+    to: Option<Address>,
+    gas: Option<U256>,
+    gas_price: Option<U256>,
+    value: Option<U256>,
+    // Older forge versions use `data`
+    #[serde(alias = "data")]
+    input: Bytes,
+    nonce: Option<U256>,
+    chain_id: Option<U64>,
+}
+
+impl ForgeTransaction {
+    fn into_bundled(self, script: &str) -> BundledTransaction {
+        let description = match (self.contract_name, self.function) {
+            (Some(contract), Some(function)) => Some(format!("{contract}.{function}")),
+            (contract, function) => contract.or(function),
+        };
+        let tx = self.transaction;
+        let mut transaction = TransactionRequest::new().from(tx.from).data(tx.input);
+        transaction.to = tx.to.map(Into::into);
+        transaction.gas = tx.gas;
+        transaction.gas_price = tx.gas_price;
+        transaction.value = tx.value;
+        transaction.nonce = tx.nonce;
+        transaction.chain_id = tx.chain_id;
+        BundledTransaction {
+            script: script.to_owned(),
+            description,
+            transaction,
+            signed: None,
+        }
+    }
+}
+
+/// Modification times of the dry-run broadcast files of a forge script.
+pub(crate) type DryRunFiles = HashMap<PathBuf, SystemTime>;
+
+fn broadcast_dir(foundry_path: &Path, script_path: &Path) -> anyhow::Result<PathBuf> {
+    let script = script_path
+        .file_name()
+        .context("script path has no file name")?;
+    Ok(foundry_path.join("broadcast").join(script))
+}
+
+/// Lists dry-run broadcast files of the script, e.g. `broadcast/DeployL1.s.sol/9/dry-run/run-latest.json`.
+pub(crate) fn dry_run_files(
+    foundry_path: &Path,
+    script_path: &Path,
+) -> anyhow::Result<DryRunFiles> {
+    let broadcast_dir = broadcast_dir(foundry_path, script_path)?;
+    let mut files = HashMap::new();
+    if !broadcast_dir.exists() {
+        return Ok(files);
+    }
+    for chain_dir in fs::read_dir(broadcast_dir)? {
+        let dry_run_dir = chain_dir?.path().join("dry-run");
+        if !dry_run_dir.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(dry_run_dir)? {
+            let path = file?.path();
+            let is_latest = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with("-latest.json"));
+            if !is_latest {
+                continue;
+            }
+            let modified = fs::metadata(&path)?.modified()?;
+            files.insert(path, modified);
+        }
+    }
+    Ok(files)
+}
+
+/// Appends transactions simulated by the forge script to the bundle in `out_dir`. `files_before` are the dry-run
+/// files before running the script. Returns the number of appended transactions.
+pub(crate) fn export_dry_run(
+    shell: &Shell,
+    foundry_path: &Path,
+    script_path: &Path,
+    files_before: &DryRunFiles,
+    out_dir: &Path,
+) -> anyhow::Result<usize> {
+    let script = script_path
+        .file_name()
+        .context("script path has no file name")?
+        .to_string_lossy();
+    // Forge doesn't write the broadcast file if the script doesn't send transactions.
+    let Some(dry_run_file) = dry_run_files(foundry_path, script_path)?
+        .into_iter()
+        .filter(|(path, modified)| files_before.get(path) != Some(modified))
+        .max_by_key(|(_, modified)| *modified)
+        .map(|(path, _)| path)
+    else {
+        return Ok(0);
+    };
+    let broadcast: ForgeBroadcast = serde_json::from_str(&shell.read_file(&dry_run_file)?)
+        .with_context(|| format!("invalid forge broadcast file {dry_run_file:?}"))?;
+
+    shell.create_dir(out_dir)?;
+    let bundle_path = out_dir.join(BUNDLE_FILE_NAME);
+    let mut bundle = if shell.path_exists(&bundle_path) {
+        TransactionBundle::read(shell, &bundle_path)?
+    } else {
+        TransactionBundle::default()
+    };
+    let count = broadcast.transactions.len();
+    bundle.transactions.extend(
+        broadcast
+            .transactions
+            .into_iter()
+            .map(|tx| tx.into_bundled(&script)),
+    );
+    bundle.save(shell, &bundle_path)?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORGE_BROADCAST: &str = r#"{
+        "transactions": [
+            {
+                "hash": null,
+                "transactionType": "CALL",
+                "contractName": "ChainAdmin",
+                "contractAddress": "0x0000000000000000000000000000000000000a00",
+                "function": "multicall((address,uint256,bytes)[],bool)",
+                "transaction": {
+                    "from": "0x36615cf349d7f6344891b1e7ca7c72883f5dc049",
+                    "to": "0x0000000000000000000000000000000000000a00",
+                    "gas": "0x186a0",
+                    "value": "0x0",
+                    "input": "0x1234",
+                    "nonce": "0x5",
+                    "chainId": "0x9"
+                }
+            }
+        ],
+        "chain": 9
+    }"#;
+
+    #[test]
+    fn signing_forge_transactions() {
+        let broadcast: ForgeBroadcast = serde_json::from_str(FORGE_BROADCAST).unwrap();
+        let mut bundle = TransactionBundle {
+            transactions: broadcast
+                .transactions
+                .into_iter()
+                .map(|tx| tx.into_bundled("AdminFunctions.s.sol"))
+                .collect(),
+        };
+        let tx = &bundle.transactions[0];
+        assert_eq!(
+            tx.description.as_deref(),
+            Some("ChainAdmin.multicall((address,uint256,bytes)[],bool)")
+        );
+        assert_eq!(tx.transaction.nonce, Some(5.into()));
+        assert_eq!(tx.transaction.chain_id, Some(9.into()));
+
+        let wallet: LocalWallet =
+            "0x7726827caac94a7f9e1b160f7ea819f172f7b6f9d2a97f992c38edeab82d4110"
+                .parse()
+                .unwrap();
+        assert_eq!(bundle.unsigned_senders(), [wallet.address()]);
+        let other_wallet: LocalWallet =
+            "0xac1e735be8536c6534bb4f17f06f6afc73b2b5ba84ac2cfb12f7461b20c0bbe3"
+                .parse()
+                .unwrap();
+        assert_eq!(bundle.sign(&other_wallet, None).unwrap(), 0);
+        bundle.sign(&wallet, None).unwrap_err(); // no gas price
+        assert_eq!(bundle.sign(&wallet, Some(1_000_000_000.into())).unwrap(), 1);
+        assert!(bundle.unsigned_senders().is_empty());
+
+        let signed = bundle.transactions[0].signed.clone().unwrap();
+        let (decoded, signature) =
+            TypedTransaction::decode_signed(&ethers::utils::rlp::Rlp::new(&signed)).unwrap();
+        assert_eq!(
+            signature.recover(decoded.sighash()).unwrap(),
+            wallet.address()
+        );
+    }
+}
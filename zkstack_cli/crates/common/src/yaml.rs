@@ -88,6 +88,50 @@ pub fn merge_yaml(
     Ok(diff)
 }
 
+fn remove_new_fields_internal(
+    a: &mut serde_yaml::Value,
+    b: &serde_yaml::Value,
+    current_key: String,
+    removed: &mut serde_yaml::Mapping,
+) -> anyhow::Result<()> {
+    let (serde_yaml::Value::Mapping(a), serde_yaml::Value::Mapping(b)) = (a, b) else {
+        return Ok(());
+    };
+
+    let keys: Vec<_> = a.keys().cloned().collect();
+    for key in keys {
+        let k = key.as_str().context(MSG_INVALID_KEY_TYPE_ERR)?.to_string();
+        let current_key = if current_key.is_empty() {
+            k
+        } else {
+            format!("{}.{}", current_key, k)
+        };
+
+        match b.get(&key) {
+            Some(b_value) => {
+                let a_value = a.get_mut(&key).unwrap();
+                remove_new_fields_internal(a_value, b_value, current_key, removed)?;
+            }
+            None => {
+                let value = a.remove(&key).unwrap();
+                removed.insert(current_key.into(), value);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes fields that are present in `a` but not in `b`, i.e. reverts fields added to `b` by [`merge_yaml`].
+/// Values of the fields present in both configurations are left intact. Returns the removed fields.
+pub fn remove_new_fields(
+    a: &mut serde_yaml::Value,
+    b: &serde_yaml::Value,
+) -> anyhow::Result<serde_yaml::Mapping> {
+    let mut removed = serde_yaml::Mapping::new();
+    remove_new_fields_internal(a, b, "".into(), &mut removed)?;
+    Ok(removed)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -472,4 +516,46 @@ mod tests {
         );
         assert_eq!(a, expected);
     }
+
+    #[test]
+    fn test_remove_new_fields_reverts_merge() {
+        let original: serde_yaml::Value = serde_yaml::from_str(
+            r#"
+            key1: value1
+            key3:
+                key4: value4
+            "#,
+        )
+        .unwrap();
+        let mut a = original.clone();
+        let b: serde_yaml::Value = serde_yaml::from_str(
+            r#"
+            key1: value1
+            key2: value2
+            key3:
+                key4: value4
+                key5: value5
+            "#,
+        )
+        .unwrap();
+        super::merge_yaml(&mut a, b, false).unwrap();
+        // Values changed after the merge must be preserved.
+        a["key1"] = "changed".into();
+
+        let removed = super::remove_new_fields(&mut a, &original).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            removed.get::<serde_yaml::Value>("key3.key5".into()),
+            Some(&"value5".into())
+        );
+        let expected: serde_yaml::Value = serde_yaml::from_str(
+            r#"
+            key1: changed
+            key3:
+                key4: value4
+            "#,
+        )
+        .unwrap();
+        assert_eq!(a, expected);
+    }
 }
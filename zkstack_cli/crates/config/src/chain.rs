@@ -1,12 +1,13 @@
 use std::{
     cell::OnceCell,
+    collections::BTreeMap,
     path::{Path, PathBuf},
 };
 
 use serde::{Deserialize, Serialize, Serializer};
 use types::{BaseToken, L1BatchCommitmentMode, L1Network, ProverMode, WalletCreation};
 use xshell::Shell;
-use zksync_basic_types::L2ChainId;
+use zksync_basic_types::{L1BatchNumber, L2ChainId};
 
 use crate::{
     consts::{
@@ -14,6 +15,7 @@ use crate::{
         L1_CONTRACTS_FOUNDRY, SECRETS_FILE, WALLETS_FILE,
     },
     create_localhost_wallets,
+    migrations::CURRENT_CONFIG_VERSION,
     traits::{
         FileConfigWithDefaultName, ReadConfig, ReadConfigWithBasePath, SaveConfig,
         SaveConfigWithBasePath, ZkStackConfig,
@@ -25,6 +27,9 @@ use crate::{
 /// directory before network initialization.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChainConfigInternal {
+    /// Version of the config layout, see [`crate::migrations`].
+    #[serde(default)]
+    pub config_version: u32,
     // The id of chain on this machine allows to easily setup multiple chains,
     // needs for local setups only
     pub id: u32,
@@ -36,12 +41,19 @@ pub struct ChainConfigInternal {
     pub external_node_config_path: Option<PathBuf>,
     pub artifacts_path: Option<PathBuf>,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    /// First L1 batches committed after each conversion of the chain between rollup and validium,
+    /// in ascending order. Batches after the last transition use `l1_batch_commit_data_generator_mode`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commitment_mode_transition_batches: Vec<L1BatchNumber>,
     pub base_token: BaseToken,
     pub wallet_creation: WalletCreation,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub legacy_bridge: Option<bool>,
     #[serde(default)] // for backward compatibility
     pub evm_emulator: bool,
+    /// Custom presets for `zkstack server --preset`, mapping preset names to server components.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub server_presets: BTreeMap<String, Vec<String>>,
 }
 
 /// Chain configuration file. This file is created in the chain
@@ -59,11 +71,13 @@ pub struct ChainConfig {
     pub configs: PathBuf,
     pub external_node_config_path: Option<PathBuf>,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    pub commitment_mode_transition_batches: Vec<L1BatchNumber>,
     pub base_token: BaseToken,
     pub wallet_creation: WalletCreation,
     pub shell: OnceCell<Shell>,
     pub legacy_bridge: Option<bool>,
     pub evm_emulator: bool,
+    pub server_presets: BTreeMap<String, Vec<String>>,
 }
 
 impl Serialize for ChainConfig {
@@ -148,6 +162,7 @@ impl ChainConfig {
 
     fn get_internal(&self) -> ChainConfigInternal {
         ChainConfigInternal {
+            config_version: CURRENT_CONFIG_VERSION,
             id: self.id,
             name: self.name.clone(),
             chain_id: self.chain_id,
@@ -157,10 +172,12 @@ impl ChainConfig {
             external_node_config_path: self.external_node_config_path.clone(),
             artifacts_path: Some(self.artifacts.clone()),
             l1_batch_commit_data_generator_mode: self.l1_batch_commit_data_generator_mode,
+            commitment_mode_transition_batches: self.commitment_mode_transition_batches.clone(),
             base_token: self.base_token.clone(),
             wallet_creation: self.wallet_creation,
             legacy_bridge: self.legacy_bridge,
             evm_emulator: self.evm_emulator,
+            server_presets: self.server_presets.clone(),
         }
     }
 }
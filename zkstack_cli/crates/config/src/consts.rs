@@ -13,7 +13,8 @@ pub const GENESIS_FILE: &str = "genesis.yaml";
 pub const EN_CONFIG_FILE: &str = "external_node.yaml";
 // Name of consensus config
 pub const CONSENSUS_CONFIG_FILE: &str = "consensus_config.yaml";
-pub(crate) const ERC20_CONFIGS_FILE: &str = "erc20.yaml";
+/// Name of the file with deployed ERC20 tokens
+pub const ERC20_CONFIGS_FILE: &str = "erc20.yaml";
 /// Name of the initial deployments config file
 pub(crate) const INITIAL_DEPLOYMENT_FILE: &str = "initial_deployments.yaml";
 /// Name of the erc20 deployments config file
@@ -41,6 +42,8 @@ pub(crate) const LOCAL_ARTIFACTS_PATH: &str = "artifacts/";
 
 /// Name of apps config file
 pub const APPS_CONFIG_FILE: &str = "apps.yaml";
+/// Name of the registry of externally operated chains
+pub const REMOTE_CHAINS_FILE: &str = "remote_chains.yaml";
 /// Name of portal runtime config file (auto-generated)
 pub const PORTAL_JS_CONFIG_FILE: &str = "portal.config.js";
 /// Name of portal config JSON file
@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use common::{config::global_config, logger};
+use common::{config::global_config, docker::ContainerRuntime, logger};
 use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
 use types::{L1Network, ProverMode, WalletCreation};
@@ -21,6 +21,7 @@ use crate::{
         input::{Erc20DeploymentConfig, InitialDeploymentConfig},
         output::{ERC20Tokens, Erc20Token},
     },
+    migrations::{migrate_config_file, CURRENT_CONFIG_VERSION},
     traits::{FileConfigWithDefaultName, ReadConfig, SaveConfig, ZkStackConfig},
     ChainConfig, ChainConfigInternal, ContractsConfig, WalletsConfig,
 };
@@ -29,6 +30,9 @@ use crate::{
 /// directory before network initialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct EcosystemConfigInternal {
+    /// Version of the config layout, see [`crate::migrations`].
+    #[serde(default)]
+    pub config_version: u32,
     pub name: String,
     pub l1_network: L1Network,
     pub link_to_code: PathBuf,
@@ -39,6 +43,8 @@ struct EcosystemConfigInternal {
     pub era_chain_id: L2ChainId,
     pub prover_version: ProverMode,
     pub wallet_creation: WalletCreation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_runtime: Option<ContainerRuntime>,
 }
 
 /// Ecosystem configuration file. This file is created in the chain
@@ -55,6 +61,8 @@ pub struct EcosystemConfig {
     pub era_chain_id: L2ChainId,
     pub prover_version: ProverMode,
     pub wallet_creation: WalletCreation,
+    /// Container runtime used for the local setup; detected automatically if not set.
+    pub container_runtime: Option<ContainerRuntime>,
     pub shell: OnceCell<Shell>,
 }
 
@@ -69,6 +77,7 @@ impl Serialize for EcosystemConfig {
 
 impl ReadConfig for EcosystemConfig {
     fn read(shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        migrate_config_file(shell, &path)?;
         let config: EcosystemConfigInternal = EcosystemConfigInternal::read(shell, path)?;
 
         let bellman_cuda_dir = config
@@ -85,6 +94,7 @@ impl ReadConfig for EcosystemConfig {
             era_chain_id: config.era_chain_id,
             prover_version: config.prover_version,
             wallet_creation: config.wallet_creation,
+            container_runtime: config.container_runtime,
             shell: Default::default(),
         })
     }
@@ -157,6 +167,7 @@ impl EcosystemConfig {
 
     fn load_chain_inner(&self, name: &str) -> anyhow::Result<ChainConfig> {
         let path = self.chains.join(name).join(CONFIG_NAME);
+        migrate_config_file(self.get_shell(), &path)?;
         let config = ChainConfigInternal::read(self.get_shell(), path.clone())?;
 
         Ok(ChainConfig {
@@ -167,6 +178,7 @@ impl EcosystemConfig {
             configs: config.configs,
             external_node_config_path: config.external_node_config_path,
             l1_batch_commit_data_generator_mode: config.l1_batch_commit_data_generator_mode,
+            commitment_mode_transition_batches: config.commitment_mode_transition_batches,
             l1_network: self.l1_network,
             link_to_code: self.get_shell().current_dir().join(&self.link_to_code),
             base_token: config.base_token,
@@ -179,6 +191,7 @@ impl EcosystemConfig {
                 .unwrap_or_else(|| self.get_chain_artifacts_path(name)),
             legacy_bridge: config.legacy_bridge,
             evm_emulator: config.evm_emulator,
+            server_presets: config.server_presets,
         })
     }
 
@@ -259,6 +272,7 @@ impl EcosystemConfig {
             .clone()
             .map(|dir| self.get_shell().current_dir().join(dir));
         EcosystemConfigInternal {
+            config_version: CURRENT_CONFIG_VERSION,
             name: self.name.clone(),
             l1_network: self.l1_network,
             link_to_code: self.get_shell().current_dir().join(&self.link_to_code),
@@ -269,6 +283,7 @@ impl EcosystemConfig {
             era_chain_id: self.era_chain_id,
             prover_version: self.prover_version,
             wallet_creation: self.wallet_creation,
+            container_runtime: self.container_runtime,
         }
     }
 }
@@ -7,6 +7,7 @@ pub use file_config::*;
 pub use general::*;
 pub use genesis::*;
 pub use manipulations::*;
+pub use remote_chains::*;
 pub use secrets::*;
 pub use wallet_creation::*;
 pub use wallets::*;
@@ -21,6 +22,7 @@ mod file_config;
 mod general;
 mod genesis;
 mod manipulations;
+mod remote_chains;
 mod secrets;
 mod wallet_creation;
 mod wallets;
@@ -32,5 +34,6 @@ pub mod explorer;
 pub mod explorer_compose;
 pub mod external_node;
 pub mod forge_interface;
+pub mod migrations;
 pub mod portal;
 pub mod traits;
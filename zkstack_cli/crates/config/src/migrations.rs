@@ -0,0 +1,264 @@
+//! Versioning and automatic migration of ZK Stack CLI config files.
+//!
+//! Every `ZkStack.yaml` (both ecosystem and chain flavors) carries a `config_version` field.
+//! Files without this field are considered to be of version 0. When a file with an outdated
+//! layout is loaded, all the necessary migrations are applied sequentially. If migrations change
+//! the file contents (other than the version), the original file is backed up next to it,
+//! and the upgraded layout is written back to disk.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use common::logger;
+use serde_yaml::{Mapping, Value};
+use xshell::Shell;
+
+/// Current version of the `ZkStack.yaml` layout. Must be bumped together with adding
+/// a new migration to [`ECOSYSTEM_MIGRATIONS`] and [`CHAIN_MIGRATIONS`].
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// Name of the field storing the config layout version.
+pub(crate) const CONFIG_VERSION_FIELD: &str = "config_version";
+
+/// Migration upgrading a config from version `N` to version `N + 1`, where `N` is its index
+/// in the corresponding migrations list.
+type Migration = fn(&mut Mapping) -> anyhow::Result<()>;
+
+const ECOSYSTEM_MIGRATIONS: [Migration; CURRENT_CONFIG_VERSION as usize] = [ecosystem_v0_to_v1];
+const CHAIN_MIGRATIONS: [Migration; CURRENT_CONFIG_VERSION as usize] = [chain_v0_to_v1];
+
+/// Kind of `ZkStack.yaml` file. Both kinds share the file name, so the kind is inferred
+/// from the file contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigKind {
+    Ecosystem,
+    Chain,
+}
+
+impl ConfigKind {
+    fn detect(config: &Mapping) -> Self {
+        // Only ecosystem configs reference the default chain.
+        if config.contains_key("default_chain") {
+            Self::Ecosystem
+        } else {
+            Self::Chain
+        }
+    }
+
+    fn migrations(self) -> &'static [Migration] {
+        match self {
+            Self::Ecosystem => &ECOSYSTEM_MIGRATIONS,
+            Self::Chain => &CHAIN_MIGRATIONS,
+        }
+    }
+}
+
+/// Outcome of migrating a single config.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MigrationOutcome {
+    pub kind: ConfigKind,
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Whether migrations have changed anything besides the config version.
+    pub is_changed: bool,
+}
+
+/// Upgrades the config in place to [`CURRENT_CONFIG_VERSION`]. Returns `None` if the config
+/// is already up to date.
+pub fn migrate_config(config: &mut Value) -> anyhow::Result<Option<MigrationOutcome>> {
+    let config = config
+        .as_mapping_mut()
+        .context("Config must be a YAML mapping")?;
+    let kind = ConfigKind::detect(config);
+    let from_version = config_version(config)?;
+    anyhow::ensure!(
+        from_version <= CURRENT_CONFIG_VERSION,
+        "Config version {from_version} is newer than the latest version supported by this \
+         zkstack release ({CURRENT_CONFIG_VERSION}). Please update zkstack"
+    );
+    if from_version == CURRENT_CONFIG_VERSION {
+        return Ok(None);
+    }
+
+    let original = config.clone();
+    for (version, migration) in kind
+        .migrations()
+        .iter()
+        .enumerate()
+        .skip(from_version as usize)
+    {
+        migration(config)
+            .with_context(|| format!("Failed migrating {kind:?} config from v{version}"))?;
+        config.insert(CONFIG_VERSION_FIELD.into(), (version as u32 + 1).into());
+    }
+    let is_changed = without_version(config) != without_version(&original);
+    Ok(Some(MigrationOutcome {
+        kind,
+        from_version,
+        to_version: CURRENT_CONFIG_VERSION,
+        is_changed,
+    }))
+}
+
+fn without_version(config: &Mapping) -> Mapping {
+    let mut config = config.clone();
+    config.remove(CONFIG_VERSION_FIELD);
+    config
+}
+
+/// Migrates the YAML config file at `path` if it has an outdated layout. The original file is
+/// preserved as `<path>.v<old_version>.bak`. The file is left intact if migrations don't change
+/// its contents (e.g., if they only bump the config version).
+///
+/// Does nothing if the file doesn't exist or doesn't have a `.yaml` / `.yml` extension.
+/// Returns an error if the file cannot be parsed as YAML.
+pub fn migrate_config_file(shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let is_yaml = matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml") | Some("yml")
+    );
+    if !is_yaml || !shell.path_exists(path) {
+        return Ok(());
+    }
+
+    let raw = shell.read_file(path)?;
+    let mut config: Value = serde_yaml::from_str(&raw)
+        .with_context(|| format!("Failed to parse config file {path:?}"))?;
+    let Some(outcome) = migrate_config(&mut config)? else {
+        return Ok(());
+    };
+    if !outcome.is_changed {
+        return Ok(());
+    }
+
+    let backup_path = backup_path(path, outcome.from_version);
+    shell
+        .write_file(&backup_path, raw)
+        .with_context(|| format!("Failed to write config backup {backup_path:?}"))?;
+    shell.write_file(path, serde_yaml::to_string(&config)?)?;
+    logger::info(format!(
+        "Migrated {:?} config {path:?} from v{} to v{} (backup saved to {backup_path:?})",
+        outcome.kind, outcome.from_version, outcome.to_version
+    ));
+    Ok(())
+}
+
+fn backup_path(path: &Path, version: u32) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".v{version}.bak"));
+    path.with_file_name(file_name)
+}
+
+fn config_version(config: &Mapping) -> anyhow::Result<u32> {
+    let Some(version) = config.get(CONFIG_VERSION_FIELD) else {
+        return Ok(0);
+    };
+    version
+        .as_u64()
+        .and_then(|version| u32::try_from(version).ok())
+        .with_context(|| format!("Invalid `{CONFIG_VERSION_FIELD}`: {version:?}"))
+}
+
+/// v1 only introduces the config version. Fields missing from v0 ecosystem configs
+/// (e.g., `bellman_cuda_dir`) are covered by serde defaults.
+fn ecosystem_v0_to_v1(_config: &mut Mapping) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// v1 only introduces the config version. Fields missing from v0 chain configs
+/// (e.g., `evm_emulator` or `artifacts_path`) are covered by serde defaults.
+fn chain_v0_to_v1(_config: &mut Mapping) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_CHAIN_CONFIG: &str = r#"
+id: 1
+name: era
+chain_id: 271
+prover_version: NoProofs
+configs: ./chains/era/configs/
+rocks_db_path: ./chains/era/db/
+l1_batch_commit_data_generator_mode: Rollup
+base_token:
+  address: '0x0000000000000000000000000000000000000001'
+  nominator: 1
+  denominator: 1
+wallet_creation: Localhost
+"#;
+
+    const LEGACY_ECOSYSTEM_CONFIG: &str = r#"
+name: zk
+l1_network: Localhost
+link_to_code: ./zksync-era
+chains: ./chains
+config: ./configs/
+default_chain: era
+era_chain_id: 270
+prover_version: NoProofs
+wallet_creation: Localhost
+"#;
+
+    #[test]
+    fn migrating_legacy_chain_config() {
+        let mut config: Value = serde_yaml::from_str(LEGACY_CHAIN_CONFIG).unwrap();
+        let outcome = migrate_config(&mut config).unwrap().unwrap();
+        assert_eq!(
+            outcome,
+            MigrationOutcome {
+                kind: ConfigKind::Chain,
+                from_version: 0,
+                to_version: CURRENT_CONFIG_VERSION,
+                is_changed: false,
+            }
+        );
+        assert_eq!(config[CONFIG_VERSION_FIELD], CURRENT_CONFIG_VERSION);
+
+        // Migration is idempotent.
+        assert_eq!(migrate_config(&mut config).unwrap(), None);
+    }
+
+    #[test]
+    fn migrating_legacy_ecosystem_config() {
+        let mut config: Value = serde_yaml::from_str(LEGACY_ECOSYSTEM_CONFIG).unwrap();
+        let outcome = migrate_config(&mut config).unwrap().unwrap();
+        assert_eq!(outcome.kind, ConfigKind::Ecosystem);
+        assert!(!outcome.is_changed);
+        assert_eq!(config[CONFIG_VERSION_FIELD], CURRENT_CONFIG_VERSION);
+    }
+
+    #[test]
+    fn rejecting_config_from_newer_release() {
+        let mut config: Value = serde_yaml::from_str(LEGACY_CHAIN_CONFIG).unwrap();
+        config[CONFIG_VERSION_FIELD] = (CURRENT_CONFIG_VERSION + 1).into();
+        let err = migrate_config(&mut config).unwrap_err().to_string();
+        assert!(err.contains("newer"), "{err}");
+    }
+
+    #[test]
+    fn config_file_is_not_rewritten_if_migrations_change_nothing() {
+        let shell = Shell::new().unwrap();
+        let dir = shell.create_temp_dir().unwrap();
+        let path = dir.path().join("ZkStack.yaml");
+        shell.write_file(&path, LEGACY_CHAIN_CONFIG).unwrap();
+
+        migrate_config_file(&shell, &path).unwrap();
+
+        assert_eq!(shell.read_file(&path).unwrap(), LEGACY_CHAIN_CONFIG);
+        assert!(!shell.path_exists(dir.path().join("ZkStack.yaml.v0.bak")));
+    }
+
+    #[test]
+    fn migrating_config_file_errors_on_invalid_yaml() {
+        let shell = Shell::new().unwrap();
+        let dir = shell.create_temp_dir().unwrap();
+        let path = dir.path().join("ZkStack.yaml");
+        shell.write_file(&path, "name: [unclosed").unwrap();
+
+        migrate_config_file(&shell, &path).unwrap_err();
+    }
+}
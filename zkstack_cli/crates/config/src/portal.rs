@@ -1,5 +1,9 @@
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
+use anyhow::Context as _;
 use serde::{Deserialize, Serialize};
 use types::TokenInfo;
 use xshell::Shell;
@@ -162,6 +166,105 @@ impl PortalConfig {
     }
 }
 
+/// Overrides applied on top of the generated portal configuration, e.g. custom tokens, bridges or branding.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct PortalConfigOverride {
+    /// Per-chain overrides keyed by the chain name.
+    #[serde(default)]
+    pub chains: HashMap<String, PortalChainConfigOverride>,
+    /// Top-level fields of the portal runtime config (e.g., branding) merged into the generated config.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct PortalChainConfigOverride {
+    /// Network fields (e.g., display name, block explorer or bridge URLs) merged into the generated network config.
+    #[serde(default)]
+    pub network: serde_json::Map<String, serde_json::Value>,
+    /// Tokens added to the chain. A token with the same L2 address as a generated one replaces it.
+    #[serde(default)]
+    pub tokens: Vec<TokenConfig>,
+}
+
+impl PortalConfigOverride {
+    /// Reads overrides from a JSON file.
+    pub fn read(shell: &Shell, path: &Path) -> anyhow::Result<Self> {
+        let contents = shell.read_file(path)?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed parsing portal config override {}", path.display()))
+    }
+
+    /// Applies overrides to the given config. Fails if an override refers to a chain missing from the config.
+    pub fn apply(&self, config: &mut PortalConfig) -> anyhow::Result<()> {
+        for (chain_name, chain_override) in &self.chains {
+            let chain_config = config
+                .hyperchains_config
+                .iter_mut()
+                .find(|config| &config.network.key == chain_name)
+                .with_context(|| {
+                    format!("portal config override refers to unknown chain `{chain_name}`")
+                })?;
+            chain_override.apply(chain_config)?;
+        }
+
+        if !self.other.is_empty() {
+            let mut value = serde_json::to_value(&*config)?;
+            merge_json(&mut value, serde_json::Value::Object(self.other.clone()));
+            *config = serde_json::from_value(value).context("invalid portal config override")?;
+        }
+        Ok(())
+    }
+}
+
+impl PortalChainConfigOverride {
+    fn apply(&self, config: &mut PortalChainConfig) -> anyhow::Result<()> {
+        if !self.network.is_empty() {
+            let mut value = serde_json::to_value(&config.network)?;
+            merge_json(&mut value, serde_json::Value::Object(self.network.clone()));
+            config.network = serde_json::from_value(value).with_context(|| {
+                format!(
+                    "invalid network override for chain `{}`",
+                    config.network.key
+                )
+            })?;
+        }
+
+        for token in &self.tokens {
+            let existing = config
+                .tokens
+                .iter_mut()
+                .find(|existing| existing.address.eq_ignore_ascii_case(&token.address));
+            if let Some(existing) = existing {
+                *existing = token.clone();
+            } else {
+                config.tokens.push(token.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Merges `patch` into `target` similarly to JSON merge patch (RFC 7396): objects are merged recursively,
+/// `null` values remove the corresponding fields, and all other values replace existing ones.
+fn merge_json(target: &mut serde_json::Value, patch: serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let target = target.as_object_mut().unwrap();
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(&key);
+        } else {
+            merge_json(target.entry(key).or_insert(serde_json::Value::Null), value);
+        }
+    }
+}
+
 impl Default for PortalConfig {
     fn default() -> Self {
         PortalConfig {
@@ -173,3 +276,86 @@ impl Default for PortalConfig {
 }
 
 impl ZkStackConfig for PortalConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_config(name: &str) -> PortalChainConfig {
+        PortalChainConfig {
+            network: NetworkConfig {
+                id: 271,
+                key: name.to_owned(),
+                name: name.to_owned(),
+                rpc_url: "http://127.0.0.1:3050".to_owned(),
+                hidden: None,
+                block_explorer_url: None,
+                block_explorer_api: None,
+                public_l1_network_id: None,
+                l1_network: None,
+                other: serde_json::Value::Null,
+            },
+            tokens: vec![TokenConfig {
+                address: "0x000000000000000000000000000000000000800A".to_owned(),
+                symbol: "ETH".to_owned(),
+                decimals: 18,
+                l1_address: None,
+                name: Some("Ether".to_owned()),
+            }],
+        }
+    }
+
+    #[test]
+    fn applying_config_override() {
+        let mut config = PortalConfig::default();
+        config.add_chain_config(&chain_config("era"));
+        config.add_chain_config(&chain_config("validium"));
+
+        let config_override: PortalConfigOverride = serde_json::from_value(serde_json::json!({
+            "chains": {
+                "era": {
+                    "network": {
+                        "name": "Era Local",
+                        "blockExplorerUrl": "http://localhost:3010",
+                        "bridgeUrl": "http://localhost:3030"
+                    },
+                    "tokens": [
+                        {
+                            "address": "0x000000000000000000000000000000000000800a",
+                            "symbol": "WEI",
+                            "decimals": 18
+                        },
+                        {
+                            "address": "0x0000000000000000000000000000000000000001",
+                            "symbol": "TEST",
+                            "decimals": 6
+                        }
+                    ]
+                }
+            },
+            "branding": { "title": "Local Portal" }
+        }))
+        .unwrap();
+        config_override.apply(&mut config).unwrap();
+
+        let era = &config.hyperchains_config[0];
+        assert_eq!(era.network.name, "Era Local");
+        assert_eq!(
+            era.network.block_explorer_url.as_deref(),
+            Some("http://localhost:3010")
+        );
+        assert_eq!(era.network.other["bridgeUrl"], "http://localhost:3030");
+        let symbols: Vec<_> = era
+            .tokens
+            .iter()
+            .map(|token| token.symbol.as_str())
+            .collect();
+        assert_eq!(symbols, ["WEI", "TEST"]);
+        assert_eq!(config.hyperchains_config[1].network.name, "validium");
+        assert_eq!(config.other["branding"]["title"], "Local Portal");
+
+        let config_override: PortalConfigOverride =
+            serde_json::from_value(serde_json::json!({ "chains": { "unknown": {} } })).unwrap();
+        config_override.apply(&mut config).unwrap_err();
+    }
+}
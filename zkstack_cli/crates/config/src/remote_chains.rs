@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use xshell::Shell;
+use zksync_basic_types::L2ChainId;
+
+use crate::{
+    consts::{LOCAL_CONFIGS_PATH, REMOTE_CHAINS_FILE},
+    traits::{FileConfigWithDefaultName, ReadConfig, SaveConfig, ZkStackConfig},
+};
+
+/// Ecosystem level registry of chains operated outside of the ecosystem. Such chains are not created
+/// or initialized locally, but can be targeted by commands like `portal` and `explorer`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteChainsConfig {
+    #[serde(default)]
+    pub chains: Vec<RemoteChainConfig>,
+}
+
+/// Externally operated chain. The chain is assumed to settle on the same L1 network as the ecosystem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteChainConfig {
+    pub name: String,
+    pub chain_id: L2ChainId,
+    pub l2_rpc_url: Url,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_rpc_url: Option<Url>,
+    /// L1 address of the base token; `0x00..01` for ETH.
+    pub base_token_address: Address,
+    #[serde(default)]
+    pub bridges: RemoteChainBridges,
+    /// URL of the block explorer for the chain, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explorer_url: Option<Url>,
+    /// URL of the contract verification API for the chain, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_api_url: Option<Url>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteChainBridges {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridgehub: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_shared_bridge: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l2_shared_bridge: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_erc20_bridge: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l2_erc20_bridge: Option<Address>,
+}
+
+impl ZkStackConfig for RemoteChainsConfig {}
+impl FileConfigWithDefaultName for RemoteChainsConfig {
+    const FILE_NAME: &'static str = REMOTE_CHAINS_FILE;
+}
+
+impl RemoteChainsConfig {
+    pub fn get_config_path(ecosystem_base_path: &Path) -> PathBuf {
+        ecosystem_base_path
+            .join(LOCAL_CONFIGS_PATH)
+            .join(REMOTE_CHAINS_FILE)
+    }
+
+    /// Reads the registry, returning an empty one if the registry file doesn't exist.
+    pub fn read_or_default(shell: &Shell) -> anyhow::Result<Self> {
+        let config_path = Self::get_config_path(&shell.current_dir());
+        if !shell.path_exists(&config_path) {
+            return Ok(Self::default());
+        }
+        Self::read(shell, &config_path)
+    }
+
+    pub fn save_to_ecosystem(&self, shell: &Shell) -> anyhow::Result<()> {
+        let config_path = Self::get_config_path(&shell.current_dir());
+        self.save(shell, config_path)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&RemoteChainConfig> {
+        self.chains.iter().find(|chain| chain.name == name)
+    }
+
+    pub fn list_of_chains(&self) -> Vec<String> {
+        self.chains.iter().map(|chain| chain.name.clone()).collect()
+    }
+
+    /// Adds a chain to the registry, replacing a previously registered chain with the same name.
+    pub fn add_chain(&mut self, chain: RemoteChainConfig) {
+        if let Some(existing) = self.chains.iter_mut().find(|c| c.name == chain.name) {
+            *existing = chain;
+        } else {
+            self.chains.push(chain);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remote_chains_config_format() {
+        let yaml = r#"
+chains:
+  - name: partner
+    chain_id: 324
+    l2_rpc_url: https://mainnet.era.zksync.io/
+    base_token_address: "0x0000000000000000000000000000000000000001"
+    bridges:
+      bridgehub: "0x303a465b659cbb0ab36ee643ea362c509eeb5213"
+      l1_shared_bridge: "0xd7f9f54194c633f36ccd5f3da84ad4a1c38cb2cb"
+  - name: minimal
+    chain_id: 300
+    l2_rpc_url: https://sepolia.era.zksync.dev/
+    base_token_address: "0x0000000000000000000000000000000000000001"
+"#;
+        let config: RemoteChainsConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.list_of_chains(), ["partner", "minimal"]);
+        let partner = config.get("partner").unwrap();
+        assert_eq!(partner.chain_id, L2ChainId::from(324));
+        assert!(partner.bridges.bridgehub.is_some());
+        assert_eq!(partner.bridges.l2_shared_bridge, None);
+        assert_eq!(
+            config.get("minimal").unwrap().bridges,
+            RemoteChainBridges::default()
+        );
+
+        let serialized = serde_yaml::to_string(&config).unwrap();
+        let restored: RemoteChainsConfig = serde_yaml::from_str(&serialized).unwrap();
+        assert_eq!(restored, config);
+    }
+}
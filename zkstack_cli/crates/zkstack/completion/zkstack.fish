@@ -1,6 +1,6 @@
 # Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
 function __fish_zkstack_global_optspecs
-	string join \n v/verbose chain= ignore-prerequisites h/help V/version
+	string join \n v/verbose chain= ignore-prerequisites output= unsigned-txs-dir= h/help V/version
 end
 
 function __fish_zkstack_needs_command
@@ -25,6 +25,8 @@ function __fish_zkstack_using_subcommand
 end
 
 complete -c zkstack -n "__fish_zkstack_needs_command" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_needs_command" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_needs_command" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_needs_command" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_needs_command" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_needs_command" -s h -l help -d 'Print help'
@@ -36,30 +38,41 @@ complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "dev" -d 'Supervisor
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "prover" -d 'Prover related commands'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "server" -d 'Run server'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "external-node" -d 'External Node related commands'
+complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "run-all" -d 'Run multiple long-lived services as supervised child processes with multiplexed logs'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "containers" -d 'Run containers for local development'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "contract-verifier" -d 'Run contract verifier'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "portal" -d 'Run dapp-portal'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "explorer" -d 'Run block-explorer'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "consensus" -d 'Consensus utilities'
+complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "interop" -d 'Cross-chain interop utilities'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "update" -d 'Update ZKsync'
+complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "doctor" -d 'Check the local setup and print a report with remediation hints'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "markdown" -d 'Print markdown help'
 complete -c zkstack -n "__fish_zkstack_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -l generate -d 'The shell to generate the autocomplete script for' -r -f -a "{bash\t'',elvish\t'',fish\t'',powershell\t'',zsh\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -s o -l out -d 'The out directory to write the autocomplete script to' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand autocomplete" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "create" -d 'Create a new ecosystem and chain, setting necessary configurations for later initialization'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "build-transactions" -d 'Create transactions to build ecosystem contracts'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "init" -d 'Initialize ecosystem and chain, deploying necessary contracts and performing on-chain operations'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "change-default-chain" -d 'Change the default chain'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "setup-observability" -d 'Setup observability for the ecosystem, downloading Grafana dashboards from the era-observability repo'
-complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions init change-default-chain setup-observability help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "create" -d 'Create a new ecosystem and chain, setting necessary configurations for later initialization'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "build-transactions" -d 'Create transactions to build ecosystem contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "sign-transactions" -d 'Sign the unsigned transactions bundle built with `--unsigned-txs-dir`; works offline'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "broadcast" -d 'Broadcast the signed transactions bundle to L1 in order'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "init" -d 'Initialize ecosystem and chain, deploying necessary contracts and performing on-chain operations'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "change-default-chain" -d 'Change the default chain'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "setup-observability" -d 'Setup observability for the ecosystem, downloading Grafana dashboards from the era-observability repo'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "teardown" -d 'Tear down the ecosystem: drop chain databases, remove deployment artifacts and stop containers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "status" -d 'Live dashboard of the ecosystem: container states and, for each chain, server health, latest L2 block, last committed / proven / executed L1 batch and prover queue depth'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and not __fish_seen_subcommand_from create build-transactions sign-transactions broadcast init change-default-chain setup-observability teardown status help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l ecosystem-name -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l l1-network -d 'L1 Network' -r -f -a "{localhost\t'',sepolia\t'',holesky\t'',mainnet\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l link-to-code -d 'Code link' -r -f -a "(__fish_complete_directories)"
@@ -74,8 +87,11 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_se
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l base-token-price-denominator -d 'Base token denominator' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l set-as-default -d 'Set as default chain' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l evm-emulator -d 'Enable EVM emulator' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l answers -d 'Path to a YAML or TOML file with values of the command arguments, keyed by argument names. Prompts are disabled: the command fails if a value is neither specified in the file nor on the command line' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l start-containers -d 'Start reth and postgres containers after creation' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l legacy-bridge
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from create" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -89,10 +105,29 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_se
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from build-transactions" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l bundle -d 'Path to the transactions bundle' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l private-key -d 'Private key of the signer; transactions sent from other addresses are left unsigned' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l gas-price-gwei -d 'Gas price in gwei; required if the transactions have no gas price' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from sign-transactions" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l bundle -d 'Path to the signed transactions bundle' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l l1-rpc-url -d 'L1 RPC URL' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from broadcast" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l deploy-erc20 -d 'Deploy ERC20 contracts' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l deploy-ecosystem -d 'Deploy ecosystem contracts' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l ecosystem-contracts-path -d 'Path to ecosystem contracts' -r -F
@@ -106,7 +141,10 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_se
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l server-db-url -d 'Server database url without database name' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l server-db-name -d 'Server database name' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -s o -l observability -d 'Enable Grafana' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l validium-type -l validium -d 'DA layer of validium chains, either for all chains (e.g., avail) or for a single chain (e.g., my_chain=no-da). Can be specified multiple times' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -s d -l dont-drop
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l ecosystem-only -d 'Initialize ecosystem only and skip chain initialization (chain can be initialized later with `chain init` subcommand)'
@@ -116,38 +154,75 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_se
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from change-default-chain" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from setup-observability" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -l wipe-l1 -d 'Remove the L1 (reth) data volume as well, resetting the L1 state'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -s y -l yes -d 'Do not ask for confirmation'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from teardown" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l refresh -d 'Refresh interval of the dashboard, in seconds' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l once -d 'Print the dashboard once and exit instead of refreshing it in place'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "create" -d 'Create a new ecosystem and chain, setting necessary configurations for later initialization'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "build-transactions" -d 'Create transactions to build ecosystem contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "sign-transactions" -d 'Sign the unsigned transactions bundle built with `--unsigned-txs-dir`; works offline'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "broadcast" -d 'Broadcast the signed transactions bundle to L1 in order'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "init" -d 'Initialize ecosystem and chain, deploying necessary contracts and performing on-chain operations'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "change-default-chain" -d 'Change the default chain'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "setup-observability" -d 'Setup observability for the ecosystem, downloading Grafana dashboards from the era-observability repo'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "teardown" -d 'Tear down the ecosystem: drop chain databases, remove deployment artifacts and stop containers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "status" -d 'Live dashboard of the ecosystem: container states and, for each chain, server health, latest L2 block, last committed / proven / executed L1 batch and prover queue depth'
 complete -c zkstack -n "__fish_zkstack_using_subcommand ecosystem; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "create" -d 'Create a new chain, setting the necessary configurations for later initialization'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "build-transactions" -d 'Create unsigned transactions for chain deployment'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "init" -d 'Initialize chain, deploying necessary contracts and performing on-chain operations'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "genesis" -d 'Run server genesis'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "register-chain" -d 'Register a new chain on L1 (executed by L1 governor). This command deploys and configures Governance, ChainAdmin, and DiamondProxy contracts, registers chain with BridgeHub and sets pending admin for DiamondProxy. Note: After completion, L2 governor can accept ownership by running `accept-chain-ownership`'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-l2-contracts" -d 'Deploy all L2 contracts (executed by L1 governor)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "accept-chain-ownership" -d 'Accept ownership of L2 chain (executed by L2 governor). This command should be run after `register-chain` to accept ownership of newly created DiamondProxy contract'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "initialize-bridges" -d 'Initialize bridges on L2'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-consensus-registry" -d 'Deploy L2 consensus registry'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-multicall3" -d 'Deploy L2 multicall3'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-timestamp-asserter" -d 'Deploy L2 TimestampAsserter'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-upgrader" -d 'Deploy Default Upgrader'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "deploy-paymaster" -d 'Deploy paymaster smart contract'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "update-token-multiplier-setter" -d 'Update Token Multiplier Setter address on L1'
-complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "create" -d 'Create a new chain, setting the necessary configurations for later initialization'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "build-transactions" -d 'Create unsigned transactions for chain deployment'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "init" -d 'Initialize chain, deploying necessary contracts and performing on-chain operations'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "genesis" -d 'Run server genesis'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "register-chain" -d 'Register a new chain on L1 (executed by L1 governor). This command deploys and configures Governance, ChainAdmin, and DiamondProxy contracts, registers chain with BridgeHub and sets pending admin for DiamondProxy. Note: After completion, L2 governor can accept ownership by running `accept-chain-ownership`'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-l2-contracts" -d 'Deploy all L2 contracts (executed by L1 governor)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "accept-chain-ownership" -d 'Accept ownership of L2 chain (executed by L2 governor). This command should be run after `register-chain` to accept ownership of newly created DiamondProxy contract'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "initialize-bridges" -d 'Initialize bridges on L2'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-consensus-registry" -d 'Deploy L2 consensus registry'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-multicall3" -d 'Deploy L2 multicall3'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-timestamp-asserter" -d 'Deploy L2 TimestampAsserter'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-upgrader" -d 'Deploy Default Upgrader'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "deploy-paymaster" -d 'Deploy paymaster smart contract'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "update-token-multiplier-setter" -d 'Update Token Multiplier Setter address on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "convert-to-validium" -d 'Switch an existing rollup chain to validium (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "convert-to-rollup" -d 'Switch an existing validium chain to rollup (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "set-da" -d 'Switch the DA mode of the chain: rollup with calldata or blobs, or validium without DA or with Avail / Celestia / EigenDA (executed by L1 governor). Updates the DA validator pair on L1 if necessary, and the commitment mode and DA client in the chain configs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "diff-config" -d 'Compare the config loaded by the running server with the on-disk general config (secrets and contracts configs are not compared). The server has no hot-reloadable values, so all differences require a restart to be applied'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "add-remote" -d 'Register an externally operated chain in the ecosystem, so that commands like `portal` and `explorer` can target it. Chain parameters are fetched from its L2 RPC'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "backup" -d 'Back up Postgres databases, RocksDB state and configs of the chain into a single archive. The server should be stopped while the backup is created'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "restore" -d 'Restore a chain from an archive created by `backup`, e.g. on another machine'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "upgrade" -d 'Upgrade the chain to a new protocol version: publish the upgrade via the ecosystem governance if necessary, upgrade the chain via its admin, wait for the upgrade batch and verify the new versions'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "monitor" -d 'Live dashboard of the chain: latest L2 block and L1 batch, commit / prove / execute lag, mempool size, L1 operator balances and component health'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "expose" -d 'Expose the chain RPC (HTTP and WebSocket) on a public domain via a reverse proxy container with automatic TLS certificates and per-IP rate limits'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "wallet" -d 'Manage chain wallets'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and not __fish_seen_subcommand_from create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter convert-to-validium convert-to-rollup set-da diff-config add-remote backup restore upgrade monitor expose wallet help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l chain-name -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l chain-id -d 'Chain ID' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l prover-mode -d 'Prover options' -r -f -a "{no-proofs\t'',gpu\t''}"
@@ -159,7 +234,10 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l base-token-price-denominator -d 'Base token denominator' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l set-as-default -d 'Set as default chain' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l evm-emulator -d 'Enable EVM emulator' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l answers -d 'Path to a YAML or TOML file with values of the command arguments, keyed by argument names. Prompts are disabled: the command fails if a value is neither specified in the file nor on the command line' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l legacy-bridge
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from create" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -172,6 +250,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l l1-rpc-url -d 'L1 RPC URL' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from build-transactions" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -185,7 +265,16 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l server-db-name -d 'Server database name' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l deploy-paymaster -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l l1-rpc-url -d 'L1 RPC URL' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l validium-type -d 'DA layer of the validium chain' -r -f -a "{no-da\t'',avail\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l avail-api-node-url -d 'URL of the Avail API node' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l avail-app-id -d 'Avail application ID of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l avail-bridge-api-url -d 'URL of the Avail bridge API' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l avail-seed-phrase -d 'Seed phrase of the Avail account' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l l1-da-validator -d 'Address of the L1 DA validator contract; required for Avail' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l l2-da-validator -d 'Address of the L2 DA validator contract; required for Avail' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -s d -l dont-drop
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -l no-port-reallocation -d 'Do not reallocate ports'
@@ -197,14 +286,20 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from init" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l server-db-url -d 'Server database url without database name' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l server-db-name -d 'Server database name' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l chains -d 'Comma-separated list of chains to run the command for concurrently' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -s d -l dev -d 'Use default database urls and names'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -s d -l dont-drop
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l all-chains -d 'Run the command for all chains of the ecosystem concurrently'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -f -a "init-database" -d 'Initialize databases'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -f -a "server" -d 'Runs server genesis'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -f -a "export" -d 'Export genesis config, storage logs and factory deps of the chain into a portable bundle'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -f -a "import" -d 'Run genesis from a bundle created by `export`, reproducing the exported genesis state'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from genesis" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l verify -d 'Verify deployed contracts' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l verifier -d 'Verifier to use' -r -f -a "{etherscan\t'',sourcify\t'',blockscout\t'',oklink\t''}"
@@ -212,6 +307,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from register-chain" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -222,6 +319,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-l2-contracts" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -232,6 +331,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from accept-chain-ownership" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -242,6 +343,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from initialize-bridges" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -252,6 +355,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-consensus-registry" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -262,6 +367,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-multicall3" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -272,6 +379,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-timestamp-asserter" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -282,6 +391,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-upgrader" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -292,6 +403,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from deploy-paymaster" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -302,10 +415,145 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l verifier-api-key -d 'Verifier API key' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l resume
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from update-token-multiplier-setter" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l verify -d 'Verify deployed contracts' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l verifier -d 'Verifier to use' -r -f -a "{etherscan\t'',sourcify\t'',blockscout\t'',oklink\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l verifier-url -d 'Verifier URL, if using a custom provider' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l verifier-api-key -d 'Verifier API key' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l l1-da-validator -d 'Address of the L1 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l l2-da-validator -d 'Address of the L2 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l resume
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-validium" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l verify -d 'Verify deployed contracts' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l verifier -d 'Verifier to use' -r -f -a "{etherscan\t'',sourcify\t'',blockscout\t'',oklink\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l verifier-url -d 'Verifier URL, if using a custom provider' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l verifier-api-key -d 'Verifier API key' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l l1-da-validator -d 'Address of the L1 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l l2-da-validator -d 'Address of the L2 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l resume
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from convert-to-rollup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l mode -d 'DA mode to switch the chain to' -r -f -a "{calldata\t'Rollup publishing pubdata in L1 transaction calldata',blobs\t'Rollup publishing pubdata in EIP-4844 blobs',no-da\t'Validium not publishing pubdata anywhere',avail\t'Validium publishing pubdata to Avail',celestia\t'Validium publishing pubdata to Celestia',eigen\t'Validium publishing pubdata to EigenDA'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l verify -d 'Verify deployed contracts' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l verifier -d 'Verifier to use' -r -f -a "{etherscan\t'',sourcify\t'',blockscout\t'',oklink\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l verifier-url -d 'Verifier URL, if using a custom provider' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l verifier-api-key -d 'Verifier API key' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -s a -l additional-args -d 'List of additional arguments that can be passed through the CLI' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l l1-da-validator -d 'Address of the L1 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l l2-da-validator -d 'Address of the L2 DA validator contract to use after the conversion' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l avail-api-node-url -d 'URL of the Avail API node' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l avail-app-id -d 'Avail application ID of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l avail-bridge-api-url -d 'URL of the Avail bridge API' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l avail-seed-phrase -d 'Seed phrase of the Avail account' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l celestia-api-node-url -d 'URL of the Celestia light node API' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l celestia-namespace -d 'Celestia namespace of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l celestia-chain-id -d 'Celestia network chain ID' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l celestia-private-key -d 'Private key of the Celestia account' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l eigen-rpc-node-url -d 'URL of the EigenDA disperser RPC node' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l eigen-private-key -d 'Private key of the EigenDA account' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l resume
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l restart -d 'Stop the running server and external nodes of the chain, so that their supervisor (e.g., `zkstack run-all --restart always`) restarts them with the updated configs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from set-da" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -s u -l url -d 'URL of the server config endpoint (defaults to the `/config` endpoint of the healthcheck server)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from diff-config" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l name -d 'Name to register the remote chain under' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l l2-rpc-url -d 'L2 JSON-RPC URL of the remote chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l l1-rpc-url -d 'L1 RPC URL used by apps (e.g., portal) to interact with L1 on behalf of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l chain-id -d 'Expected chain ID; must match the one reported by the L2 RPC unless --skip-rpc-check is set' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l bridgehub -d 'Bridgehub address (fetched from the L2 RPC if not specified)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l l1-shared-bridge -d 'L1 shared bridge address (fetched from the L2 RPC if not specified)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l l2-shared-bridge -d 'L2 shared bridge address (fetched from the L2 RPC if not specified)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l base-token-address -d 'L1 address of the base token (fetched from the L2 RPC if not specified)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l explorer-url -d 'Block explorer URL of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l verification-api-url -d 'Contract verification API URL of the chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l skip-rpc-check -d 'Don\'t query the L2 RPC; all chain parameters must be specified explicitly'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from add-remote" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -s o -l out -d 'Path to the created archive. Defaults to `<chain>-backup-<timestamp>.tar.gz` in the current directory' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l skip-prover-db -d 'Don\'t include the prover database'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l skip-rocks-db -d 'Don\'t include RocksDB state; it will be recovered from Postgres by the server after restore'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from backup" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l server-db-url -d 'Full server database URL (incl. database name) to restore into. Defaults to the URL in the backed up secrets' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l prover-db-url -d 'Full prover database URL (incl. database name) to restore into. Defaults to the URL in the backed up secrets' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l force -d 'Overwrite the chain configs, RocksDB state and databases if they already exist'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from restore" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l protocol-version -d 'Target protocol version in the `0.<minor>.<patch>` format; defaults to the latest version known to the chain type manager' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l upgrade-data -d 'Path to a JSON file with the diamond cut data of the upgrade (e.g., `transactions.json` produced by the protocol upgrade tool). Required if the upgrade is not yet published in the chain type manager; otherwise, the published cut data is used' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l timeout -d 'Timeout for waiting for the upgrade batch, in seconds' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l calldata-only -d 'Only print the calldata of governance and chain admin transactions without sending them (e.g., to submit them via a multisig)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from upgrade" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l refresh -d 'Refresh interval of the dashboard, in seconds' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l once -d 'Print the dashboard once and exit instead of refreshing it in place'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from monitor" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l domain -d 'Public domain name of the RPC endpoint, e.g. `rpc.example.com`. Its DNS record must point to this machine' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l email -d 'Contact email for the ACME account used to issue certificates, e.g. for expiry notices' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l rate-limit -d 'Maximum number of requests per second from a single IP address' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l staging -d 'Use the staging environment of the certificate authority, e.g. to check the setup without hitting production rate limits'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l config-only -d 'Only generate the reverse proxy config without starting the proxy container'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from expose" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -f -a "rotate" -d 'Rotate operator, blob operator and / or fee account keys of the chain. Updates validator registrations on L1 and the chain wallets config; the server must be restarted afterwards'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from wallet" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "create" -d 'Create a new chain, setting the necessary configurations for later initialization'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "build-transactions" -d 'Create unsigned transactions for chain deployment'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "init" -d 'Initialize chain, deploying necessary contracts and performing on-chain operations'
@@ -320,25 +568,49 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "deploy-upgrader" -d 'Deploy Default Upgrader'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "deploy-paymaster" -d 'Deploy paymaster smart contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "update-token-multiplier-setter" -d 'Update Token Multiplier Setter address on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "convert-to-validium" -d 'Switch an existing rollup chain to validium (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "convert-to-rollup" -d 'Switch an existing validium chain to rollup (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "set-da" -d 'Switch the DA mode of the chain: rollup with calldata or blobs, or validium without DA or with Avail / Celestia / EigenDA (executed by L1 governor). Updates the DA validator pair on L1 if necessary, and the commitment mode and DA client in the chain configs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "diff-config" -d 'Compare the config loaded by the running server with the on-disk general config (secrets and contracts configs are not compared). The server has no hot-reloadable values, so all differences require a restart to be applied'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "add-remote" -d 'Register an externally operated chain in the ecosystem, so that commands like `portal` and `explorer` can target it. Chain parameters are fetched from its L2 RPC'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "backup" -d 'Back up Postgres databases, RocksDB state and configs of the chain into a single archive. The server should be stopped while the backup is created'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "restore" -d 'Restore a chain from an archive created by `backup`, e.g. on another machine'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "upgrade" -d 'Upgrade the chain to a new protocol version: publish the upgrade via the ecosystem governance if necessary, upgrade the chain via its admin, wait for the upgrade batch and verify the new versions'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "monitor" -d 'Live dashboard of the chain: latest L2 block and L1 batch, commit / prove / execute lag, mempool size, L1 operator balances and component health'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "expose" -d 'Expose the chain RPC (HTTP and WebSocket) on a public domain via a reverse proxy container with automatic TLS certificates and per-IP rate limits'
+complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "wallet" -d 'Manage chain wallets'
 complete -c zkstack -n "__fish_zkstack_using_subcommand chain; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "database" -d 'Database related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "test" -d 'Run tests'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "clean" -d 'Clean artifacts'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "snapshot" -d 'Snapshots creator'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "lint" -d 'Lint code'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "fmt" -d 'Format code'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "prover" -d 'Protocol version used by provers'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "contracts" -d 'Build contracts'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "config-writer" -d 'Overwrite general config'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "send-transactions" -d 'Send transactions from file'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "status" -d 'Get status of the server'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "generate-genesis" -d 'Generate new genesis file based on current contracts'
-complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "database" -d 'Database related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "test" -d 'Run tests'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "clean" -d 'Clean artifacts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "snapshot" -d 'Snapshots creator'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "lint" -d 'Lint code'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "fmt" -d 'Format code'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "prover" -d 'Protocol version used by provers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "contracts" -d 'Build contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "config-writer" -d 'Overwrite general config'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "send-transactions" -d 'Send transactions from file'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "status" -d 'Get status of the server'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "generate-genesis" -d 'Generate new genesis file based on current contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "export-l2-to-l1" -d 'Export L2→L1 logs per L1 batch and verify their Merkle roots against L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "verify-proof" -d 'Verify the final proof of an L1 batch locally, including its public input'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "compute-create2" -d 'Compute CREATE2 addresses of planned deployments and mine vanity salts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "audit-upgrades" -d 'Build a chronological report of governance and upgrade events of the ecosystem and chain on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "chaos" -d 'Inject failures into a local chain on a schedule and check that it recovers without losing batches'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "fuzz" -d 'Fuzz core components'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "debug-tx" -d 'Execute a transaction in the VM step by step with an interactive debugger'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "replay-batch" -d 'Re-execute a sealed L1 batch in the VM and compare its outputs with the persisted ones'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "fetch-sources" -d 'Download the verified source tree, compiler settings and constructor args of a contract'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and not __fish_seen_subcommand_from database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis export-l2-to-l1 verify-proof compute-create2 audit-upgrades chaos fuzz debug-tx replay-batch fetch-sources help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -s h -l help -d 'Print help'
@@ -351,6 +623,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -f -a "setup" -d 'Setup databases. If no databases are selected, all databases will be setup.'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from database" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -s h -l help -d 'Print help'
@@ -363,10 +637,13 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "rust" -d 'Run unit-tests, accepts optional cargo test flags'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "l1-contracts" -d 'Run L1 contracts tests'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "prover" -d 'Run prover tests'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "prover-e2e" -d 'Run a full proving cycle for a single batch on a local chain'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "wallet" -d 'Print test wallets information'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "loadtest" -d 'Run loadtest'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from test" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -s h -l help -d 'Print help'
@@ -375,6 +652,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -f -a "contracts-cache" -d 'Remove contracts caches'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from clean" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -s h -l help -d 'Print help'
@@ -382,11 +661,15 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from snapshot" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -s t -l targets -r -f -a "{md\t'',sol\t'',js\t'',ts\t'',rs\t'',contracts\t'',autocompletion\t'',rust-toolchain\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -s c -l check
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from lint" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -s c -l check
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -396,6 +679,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -f -a "prettier"
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fmt" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from prover" -s h -l help -d 'Print help'
@@ -407,11 +692,15 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l l2-contracts -d 'Build L2 contracts' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l system-contracts -d 'Build system contracts' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from contracts" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -s p -l path -d 'Path to the config file to override' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from config-writer" -s h -l help -d 'Print help'
@@ -420,20 +709,117 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l l1-rpc-url -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l confirmations -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from send-transactions" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -s u -l url -d 'URL of the health check endpoint' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -f -a "ports" -d 'Show used ports'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from status" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from generate-genesis" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l out -d 'Path to the append-only export file (JSON Lines). Defaults to `l2_to_l1_logs_<chain>.jsonl`' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l from-batch -d 'First L1 batch to export. Ignored if the export file already contains batches' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l to-batch -d 'Last L1 batch to export. Defaults to the latest sealed batch' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l no-verify -d 'Do not verify reconstructed Merkle roots against L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from export-l2-to-l1" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l batch -d 'L1 batch to verify the proof of' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l proof-path -d 'Path to the final proof of the batch. Defaults to the proof in the prover object store' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from verify-proof" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l deployer -d 'Address of the deploying contract (e.g., a CREATE2 factory)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l kind -d 'Address derivation scheme, `zkevm` for EraVM contracts or `evm` for EVM contracts (incl. the EVM emulator)' -r -f -a "{zkevm\t'EraVM contracts deployed via ContractDeployer',evm\t'EVM contracts on L1 or deployed via the EVM emulator'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l bytecode -d 'Path to the contract bytecode, either a hex file or a compiled artifact with the `bytecode` field' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l bytecode-hash -d 'Bytecode hash to use instead of --bytecode (the versioned EraVM bytecode hash for `zkevm`, or keccak256 of the init code for `evm`)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l constructor-args -d 'ABI-encoded constructor arguments (hex)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l salt -d 'Salt to compute the address for. Defaults to zero' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l prefix -d 'Hex prefix of the address to mine a salt for (conflicts with --salt)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l threads -d 'Number of threads used for mining. Defaults to the number of CPUs' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l save -d 'Record the salt as `create2_factory_salt` in the ecosystem initial deployments config'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from compute-create2" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l from-block -d 'First L1 block to scan' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l to-block -d 'Last L1 block to scan. Defaults to the latest block' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l block-range -d 'Maximum number of L1 blocks queried by a single `eth_getLogs` request' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l out -d 'Path to the JSON report. Defaults to `upgrades_audit_<chain>.json`; the human-readable report is written next to it with the `.txt` extension' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from audit-upgrades" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l faults -d 'Comma-separated faults to inject in a round-robin order. Defaults to all applicable faults' -r -f -a "{kill-server\t'Kills the server with `SIGKILL` and restarts it after the fault duration',restart-server\t'Gracefully stops the server with `SIGTERM` and restarts it immediately',delay-postgres\t'Pauses the Postgres container, so that all DB queries are delayed for the fault duration',drop-l1-rpc\t'Pauses the L1 node container, so that L1 RPC requests time out for the fault duration',pause-da\t'Pauses the DA client container for the fault duration'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l duration -d 'Total duration of the chaos run' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l interval -d 'Interval between injected faults' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l fault-duration -d 'How long a component is kept down or paused by a single fault' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l recovery-timeout -d 'Maximum time for the chain to recover after a fault' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l da-service -d 'Docker Compose service running the DA client; required for the `pause-da` fault' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l logs-dir -d 'Directory to write server logs to' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l require-progress -d 'Require a new L1 batch to be sealed after each fault; needs transaction load, e.g. from `zkstack dev send-transactions`'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from chaos" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -f -a "vm" -d 'Run randomized transaction workloads on the legacy and fast VMs and compare their outputs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fuzz" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l tx-hash -d 'Hash of the transaction. Loaded from the chain database unless `--dump` is specified' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l dump -d 'Path to a VM dump or a VM fuzzing artifact to load the transaction from' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -s b -l break -d 'Breakpoint: `opcode:<name>`, `address:<address>` or `storage:<key>`. Can be specified multiple times' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from debug-tx" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l l1-batch-number -d 'Number of the L1 batch. Loaded from the chain database unless `--dump` is specified' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l dump -d 'Path to a batch dump to load the batch from instead of the chain database' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l save-dump -d 'Path to save a self-contained batch dump to, e.g. to replay the batch on another machine' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from replay-batch" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l url -d 'URL of the contract verification API. Defaults to the API of the current chain' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l out-dir -d 'Directory to save the sources to. Defaults to a directory named after the contract address' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from fetch-sources" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "database" -d 'Database related commands'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "test" -d 'Run tests'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "clean" -d 'Clean artifacts'
@@ -446,8 +832,19 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_sub
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "send-transactions" -d 'Send transactions from file'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "status" -d 'Get status of the server'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "generate-genesis" -d 'Generate new genesis file based on current contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "export-l2-to-l1" -d 'Export L2→L1 logs per L1 batch and verify their Merkle roots against L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "verify-proof" -d 'Verify the final proof of an L1 batch locally, including its public input'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "compute-create2" -d 'Compute CREATE2 addresses of planned deployments and mine vanity salts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "audit-upgrades" -d 'Build a chronological report of governance and upgrade events of the ecosystem and chain on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "chaos" -d 'Inject failures into a local chain on a schedule and check that it recovers without losing batches'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "fuzz" -d 'Fuzz core components'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "debug-tx" -d 'Execute a transaction in the VM step by step with an interactive debugger'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "replay-batch" -d 'Re-execute a sealed L1 batch in the VM and compare its outputs with the persisted ones'
+complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "fetch-sources" -d 'Download the verified source tree, compiler settings and constructor args of a contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand dev; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and not __fish_seen_subcommand_from init setup-keys run init-bellman-cuda compressor-keys help" -s h -l help -d 'Print help'
@@ -476,6 +873,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l path -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l region -r -f -a "{us\t'',europe\t'',asia\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l mode -r -f -a "{download\t'',generate\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l circuits -d 'Comma-separated circuits to generate setup keys for, e.g. `basic_1,leaf_3,node,recursion_tip,scheduler`; implies `--mode generate`. Existing keys are not regenerated' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l jobs -d 'Number of setup keys generated in parallel when `--circuits` is specified' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l setup-keys -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l setup-database -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l prover-db-url -d 'Prover database url without database name' -r
@@ -483,7 +882,10 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -s u -l use-default -d 'Use default database urls and names' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -s d -l dont-drop -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l cloud-type -r -f -a "{gcp\t'',local\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l answers -d 'Path to a YAML or TOML file with values of the command arguments, keyed by argument names. Prompts are disabled: the command fails if a value is neither specified in the file nor on the command line' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l dev
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -l clone
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -s v -l verbose -d 'Verbose mode'
@@ -491,12 +893,17 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l region -r -f -a "{us\t'',europe\t'',asia\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l mode -r -f -a "{download\t'',generate\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l circuits -d 'Comma-separated circuits to generate setup keys for, e.g. `basic_1,leaf_3,node,recursion_tip,scheduler`; implies `--mode generate`. Existing keys are not regenerated' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l jobs -d 'Number of setup keys generated in parallel when `--circuits` is specified' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from setup-keys" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l component -r -f -a "{gateway\t'',witness-generator\t'',witness-vector-generator\t'',prover\t'',circuit-prover\t'',compressor\t'',prover-job-monitor\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l round -r -f -a "{all-rounds\t'',basic-circuits\t'',leaf-aggregation\t'',node-aggregation\t'',recursion-tip\t'',scheduler\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l rounds -d 'Rounds served by a single witness generator process with optional polling weights, e.g. `basic_circuits:4,leaf_aggregation:2,scheduler`' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l threads -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l max-allocation -d 'Memory allocation limit in bytes (for prover component)' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -s l -l light-wvg-count -r
@@ -505,17 +912,23 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l docker -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l tag -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from run" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l bellman-cuda-dir -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l clone
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from init-bellman-cuda" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -l path -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from compressor-keys" -s h -l help -d 'Print help'
@@ -526,10 +939,15 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from help" -f -a "compressor-keys" -d 'Download compressor keys'
 complete -c zkstack -n "__fish_zkstack_using_subcommand prover; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l components -d 'Components of server to run' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l preset -d 'Named set of components to run: `full`, `sequencer`, `api-only`, `prover` or a custom preset from the chain config' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -s a -l additional-args -d 'Additional arguments that can be passed through the CLI' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l chains -d 'Comma-separated list of chains to run the command for concurrently' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l genesis -d 'Run server in genesis mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l uring -d 'Enables uring support for RocksDB'
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l all-chains -d 'Run the command for all chains of the ecosystem concurrently'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -s h -l help -d 'Print help'
@@ -538,12 +956,17 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_s
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -f -a "wait" -d 'Waits for server to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and not __fish_seen_subcommand_from build run wait help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from build" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l components -d 'Components of server to run' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l preset -d 'Named set of components to run: `full`, `sequencer`, `api-only`, `prover` or a custom preset from the chain config' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -s a -l additional-args -d 'Additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l genesis -d 'Run server in genesis mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -l uring -d 'Enables uring support for RocksDB'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
@@ -552,6 +975,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -s t -l timeout -d 'Wait timeout in seconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -l poll-interval -d 'Poll interval in milliseconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from wait" -s h -l help -d 'Print help'
@@ -559,29 +984,53 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from help" -f -a "run" -d 'Runs server'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from help" -f -a "wait" -d 'Waits for server to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand server; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "configs" -d 'Prepare configs for EN'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "init" -d 'Init databases'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "build" -d 'Build external node'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "run" -d 'Run external node'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "wait" -d 'Wait for external node to start'
-complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs init build run wait help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "configs" -d 'Prepare configs for EN'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "gen-fleet" -d 'Prepare configs and a Docker Compose / Kubernetes manifest for a fleet of ENs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "init" -d 'Init databases'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "build" -d 'Build external node'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "run" -d 'Run external node'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "wait" -d 'Wait for external node to start'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and not __fish_seen_subcommand_from configs gen-fleet init build run wait help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l db-url -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l db-name -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l l1-rpc-url -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -s u -l use-default -d 'Use default database urls and names'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from configs" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l count -d 'Number of external nodes in the fleet' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l db-url -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l db-name -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l l1-rpc-url -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l pruning-data-retention-sec -d 'Retention period of pruned data in seconds' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l manifest -d 'Kind of the manifest to generate' -r -f -a "{compose\t'Docker Compose file',k8s\t'Kubernetes manifest'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l image -d 'Docker image of the external node' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -s u -l use-default -d 'Use default database urls and names'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l pruning -d 'Enable pruning for all nodes in the fleet'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from gen-fleet" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from build" -s h -l help -d 'Print help'
@@ -589,6 +1038,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fis
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l enable-consensus -d 'Enable consensus' -r -f -a "{true\t'',false\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -s a -l additional-args -d 'Additional arguments that can be passed through the CLI' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l reinit
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from run" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -596,21 +1047,41 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fis
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -s t -l timeout -d 'Wait timeout in seconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -l poll-interval -d 'Poll interval in milliseconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from wait" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "configs" -d 'Prepare configs for EN'
+complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "gen-fleet" -d 'Prepare configs and a Docker Compose / Kubernetes manifest for a fleet of ENs'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "init" -d 'Init databases'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "build" -d 'Build external node'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "run" -d 'Run external node'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "wait" -d 'Wait for external node to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand external-node; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l services -d 'Comma-separated list of services to run' -r -f -a "{server\t'',external-node\t'',prover-gateway\t'',witness-generator\t'',witness-vector-generator\t'',prover\t'',circuit-prover\t'',compressor\t'',prover-job-monitor\t'',contract-verifier\t'',explorer\t'',explorer-backend\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l restart -d 'Restart policy for exited services' -r -f -a "{never\t'Never restart exited services',on-failure\t'Restart services exiting with a non-zero status',always\t'Restart services regardless of their exit status'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l max-restarts -d 'Maximum number of restarts of a single service before giving up' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l restart-delay-ms -d 'Delay before the first restart of a service, doubled with each subsequent restart' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l log-format -d 'Format of the multiplexed logs' -r -f -a "{text\t'Lines prefixed with the service name',json\t'A JSON object per line'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l no-color -d 'Disable colored service prefixes'
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand run-all" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -s o -l observability -d 'Enable Grafana' -r -f -a "{true\t'',false\t''}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -l runtime -d 'Container runtime to use; saved in the ecosystem config for subsequent commands. Detected automatically by default' -r -f -a "{docker\t'',podman\t''}"
 complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand containers" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -s h -l help -d 'Print help'
@@ -620,16 +1091,22 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and n
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -f -a "init" -d 'Download required binaries for contract verifier'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and not __fish_seen_subcommand_from build run wait init help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from build" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from run" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -s t -l timeout -d 'Wait timeout in seconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -l poll-interval -d 'Poll interval in milliseconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from wait" -s h -l help -d 'Print help'
@@ -639,6 +1116,8 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and _
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l era-vm-solc-version -d 'Version of era vm solc to install' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l vyper-version -d 'Version of vyper to install' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l only -d 'Install only provided compilers'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from init" -l ignore-prerequisites -d 'Ignores prerequisites checks'
@@ -648,11 +1127,31 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and _
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from help" -f -a "wait" -d 'Wait for contract verifier to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from help" -f -a "init" -d 'Download required binaries for contract verifier'
 complete -c zkstack -n "__fish_zkstack_using_subcommand contract-verifier; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand portal" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand portal" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand portal" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand portal" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l chains -d 'Comma-separated list of chains to serve in portal (defaults to all chains)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l config-override -d 'Path to a JSON file overriding tokens, bridges and branding in the generated portal config' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l config-only -d 'Only regenerate the portal runtime config without starting the container'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -f -a "run" -d 'Run dapp-portal (default)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and not __fish_seen_subcommand_from run help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l chains -d 'Comma-separated list of chains to serve in portal (defaults to all chains)' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l config-override -d 'Path to a JSON file overriding tokens, bridges and branding in the generated portal config' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l config-only -d 'Only regenerate the portal runtime config without starting the container'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from run" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from help" -f -a "run" -d 'Run dapp-portal (default)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand portal; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -s h -l help -d 'Print help'
@@ -661,14 +1160,20 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -f -a "run" -d 'Run explorer app'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and not __fish_seen_subcommand_from init run-backend run help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run-backend" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from run" -s h -l help -d 'Print help'
@@ -676,63 +1181,129 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_see
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from help" -f -a "run-backend" -d 'Start explorer backend services (api, data_fetcher, worker) for a given chain. Uses default chain, unless --chain is passed'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from help" -f -a "run" -d 'Run explorer app'
 complete -c zkstack -n "__fish_zkstack_using_subcommand explorer; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -f -a "set-attester-committee" -d 'Sets the attester committee in the consensus registry contract to `consensus.genesis_spec.attesters` in general.yaml'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -f -a "get-attester-committee" -d 'Fetches the attester committee from the consensus registry contract'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -f -a "wait-for-registry" -d 'Wait until the consensus registry contract is deployed to L2'
-complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -f -a "set-attester-committee" -d 'Sets the attester committee in the consensus registry contract to `consensus.genesis_spec.attesters` in general.yaml'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -f -a "get-attester-committee" -d 'Fetches the attester committee from the consensus registry contract'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -f -a "wait-for-registry" -d 'Wait until the consensus registry contract is deployed to L2'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -f -a "status" -d 'Shows the consensus view of the node: current view and leader, last certificates, committee weights, and connectivity to consensus peers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and not __fish_seen_subcommand_from set-attester-committee get-attester-committee wait-for-registry status help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l from-file -d 'Sets the attester committee in the consensus registry contract to the committee in the yaml file. File format is definied in `commands/consensus/proto/mod.proto`' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l from-genesis -d 'Sets the attester committee in the consensus registry contract to `consensus.genesis_spec.attesters` in general.yaml'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from set-attester-committee" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from get-attester-committee" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -s t -l timeout -d 'Wait timeout in seconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -l poll-interval -d 'Poll interval in milliseconds' -r
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from wait-for-registry" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -l probe-timeout -d 'Timeout for probing connectivity to each consensus peer, in seconds' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from help" -f -a "set-attester-committee" -d 'Sets the attester committee in the consensus registry contract to `consensus.genesis_spec.attesters` in general.yaml'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from help" -f -a "get-attester-committee" -d 'Fetches the attester committee from the consensus registry contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from help" -f -a "wait-for-registry" -d 'Wait until the consensus registry contract is deployed to L2'
+complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from help" -f -a "status" -d 'Shows the consensus view of the node: current view and leader, last certificates, committee weights, and connectivity to consensus peers'
 complete -c zkstack -n "__fish_zkstack_using_subcommand consensus; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
-complete -c zkstack -n "__fish_zkstack_using_subcommand update" -l chain -d 'Chain to use' -r
-complete -c zkstack -n "__fish_zkstack_using_subcommand update" -s c -l only-config -d 'Update only the config files'
-complete -c zkstack -n "__fish_zkstack_using_subcommand update" -s v -l verbose -d 'Verbose mode'
-complete -c zkstack -n "__fish_zkstack_using_subcommand update" -l ignore-prerequisites -d 'Ignores prerequisites checks'
-complete -c zkstack -n "__fish_zkstack_using_subcommand update" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -f -a "relayer" -d 'Relayer delivering messages sent by a chain to other chains of the ecosystem'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and not __fish_seen_subcommand_from relayer help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -f -a "run" -d 'Run the relayer for the chain selected with `--chain`, delivering its messages to target chains'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from relayer" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from help" -f -a "relayer" -d 'Relayer delivering messages sent by a chain to other chains of the ecosystem'
+complete -c zkstack -n "__fish_zkstack_using_subcommand interop; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l to -d 'Check out the specified branch, tag or commit instead of pulling the current branch' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l chains -d 'Comma-separated list of chains to run the command for concurrently' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -s c -l only-config -d 'Update only the config files'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l rollback -d 'Restore the code revision, binaries, chain configs and DB migrations recorded before the last update'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l all-chains -d 'Run the command for all chains of the ecosystem concurrently'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -f -a "rollback" -d 'Restore the code revision, binaries, chain configs and DB migrations recorded before the last update'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and not __fish_seen_subcommand_from rollback help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from rollback" -s h -l help -d 'Print help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from help" -f -a "rollback" -d 'Restore the code revision, binaries, chain configs and DB migrations recorded before the last update'
+complete -c zkstack -n "__fish_zkstack_using_subcommand update; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -s v -l verbose -d 'Verbose mode'
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -l ignore-prerequisites -d 'Ignores prerequisites checks'
+complete -c zkstack -n "__fish_zkstack_using_subcommand doctor" -s h -l help -d 'Print help'
 complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -l chain -d 'Chain to use' -r
+complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -l output -d 'Output format of command results' -r -f -a "{human\t'Human-readable logs only',json\t'Human-readable logs are written to stderr, and the command result is printed to stdout as JSON'}"
+complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -l unsigned-txs-dir -d 'Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this directory for offline signing' -r -F
 complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -s v -l verbose -d 'Verbose mode'
 complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -l ignore-prerequisites -d 'Ignores prerequisites checks'
 complete -c zkstack -n "__fish_zkstack_using_subcommand markdown" -s h -l help -d 'Print help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "autocomplete" -d 'Create shell autocompletion files'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "ecosystem" -d 'Ecosystem related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "chain" -d 'Chain related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "dev" -d 'Supervisor related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "prover" -d 'Prover related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "server" -d 'Run server'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "external-node" -d 'External Node related commands'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "containers" -d 'Run containers for local development'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "contract-verifier" -d 'Run contract verifier'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "portal" -d 'Run dapp-portal'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "explorer" -d 'Run block-explorer'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "consensus" -d 'Consensus utilities'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "update" -d 'Update ZKsync'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "markdown" -d 'Print markdown help'
-complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "autocomplete" -d 'Create shell autocompletion files'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "ecosystem" -d 'Ecosystem related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "chain" -d 'Chain related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "dev" -d 'Supervisor related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "prover" -d 'Prover related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "server" -d 'Run server'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "external-node" -d 'External Node related commands'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "run-all" -d 'Run multiple long-lived services as supervised child processes with multiplexed logs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "containers" -d 'Run containers for local development'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "contract-verifier" -d 'Run contract verifier'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "portal" -d 'Run dapp-portal'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "explorer" -d 'Run block-explorer'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "consensus" -d 'Consensus utilities'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "interop" -d 'Cross-chain interop utilities'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "update" -d 'Update ZKsync'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "doctor" -d 'Check the local setup and print a report with remediation hints'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "markdown" -d 'Print markdown help'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and not __fish_seen_subcommand_from autocomplete ecosystem chain dev prover server external-node run-all containers contract-verifier portal explorer consensus interop update doctor markdown help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "create" -d 'Create a new ecosystem and chain, setting necessary configurations for later initialization'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "build-transactions" -d 'Create transactions to build ecosystem contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "sign-transactions" -d 'Sign the unsigned transactions bundle built with `--unsigned-txs-dir`; works offline'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "broadcast" -d 'Broadcast the signed transactions bundle to L1 in order'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "init" -d 'Initialize ecosystem and chain, deploying necessary contracts and performing on-chain operations'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "change-default-chain" -d 'Change the default chain'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "setup-observability" -d 'Setup observability for the ecosystem, downloading Grafana dashboards from the era-observability repo'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "teardown" -d 'Tear down the ecosystem: drop chain databases, remove deployment artifacts and stop containers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from ecosystem" -f -a "status" -d 'Live dashboard of the ecosystem: container states and, for each chain, server health, latest L2 block, last committed / proven / executed L1 batch and prover queue depth'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "create" -d 'Create a new chain, setting the necessary configurations for later initialization'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "build-transactions" -d 'Create unsigned transactions for chain deployment'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "init" -d 'Initialize chain, deploying necessary contracts and performing on-chain operations'
@@ -747,6 +1318,17 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_su
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "deploy-upgrader" -d 'Deploy Default Upgrader'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "deploy-paymaster" -d 'Deploy paymaster smart contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "update-token-multiplier-setter" -d 'Update Token Multiplier Setter address on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "convert-to-validium" -d 'Switch an existing rollup chain to validium (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "convert-to-rollup" -d 'Switch an existing validium chain to rollup (executed by L1 governor). Requires all sealed batches to be committed; updates the DA validator pair on L1 and records the transition batch for the consistency checker'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "set-da" -d 'Switch the DA mode of the chain: rollup with calldata or blobs, or validium without DA or with Avail / Celestia / EigenDA (executed by L1 governor). Updates the DA validator pair on L1 if necessary, and the commitment mode and DA client in the chain configs'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "diff-config" -d 'Compare the config loaded by the running server with the on-disk general config (secrets and contracts configs are not compared). The server has no hot-reloadable values, so all differences require a restart to be applied'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "add-remote" -d 'Register an externally operated chain in the ecosystem, so that commands like `portal` and `explorer` can target it. Chain parameters are fetched from its L2 RPC'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "backup" -d 'Back up Postgres databases, RocksDB state and configs of the chain into a single archive. The server should be stopped while the backup is created'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "restore" -d 'Restore a chain from an archive created by `backup`, e.g. on another machine'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "upgrade" -d 'Upgrade the chain to a new protocol version: publish the upgrade via the ecosystem governance if necessary, upgrade the chain via its admin, wait for the upgrade batch and verify the new versions'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "monitor" -d 'Live dashboard of the chain: latest L2 block and L1 batch, commit / prove / execute lag, mempool size, L1 operator balances and component health'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "expose" -d 'Expose the chain RPC (HTTP and WebSocket) on a public domain via a reverse proxy container with automatic TLS certificates and per-IP rate limits'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from chain" -f -a "wallet" -d 'Manage chain wallets'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "database" -d 'Database related commands'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "test" -d 'Run tests'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "clean" -d 'Clean artifacts'
@@ -759,6 +1341,15 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_su
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "send-transactions" -d 'Send transactions from file'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "status" -d 'Get status of the server'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "generate-genesis" -d 'Generate new genesis file based on current contracts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "export-l2-to-l1" -d 'Export L2→L1 logs per L1 batch and verify their Merkle roots against L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "verify-proof" -d 'Verify the final proof of an L1 batch locally, including its public input'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "compute-create2" -d 'Compute CREATE2 addresses of planned deployments and mine vanity salts'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "audit-upgrades" -d 'Build a chronological report of governance and upgrade events of the ecosystem and chain on L1'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "chaos" -d 'Inject failures into a local chain on a schedule and check that it recovers without losing batches'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "fuzz" -d 'Fuzz core components'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "debug-tx" -d 'Execute a transaction in the VM step by step with an interactive debugger'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "replay-batch" -d 'Re-execute a sealed L1 batch in the VM and compare its outputs with the persisted ones'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from dev" -f -a "fetch-sources" -d 'Download the verified source tree, compiler settings and constructor args of a contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from prover" -f -a "init" -d 'Initialize prover'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from prover" -f -a "setup-keys" -d 'Generate setup keys'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from prover" -f -a "run" -d 'Run prover'
@@ -768,6 +1359,7 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_su
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from server" -f -a "run" -d 'Runs server'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from server" -f -a "wait" -d 'Waits for server to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from external-node" -f -a "configs" -d 'Prepare configs for EN'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from external-node" -f -a "gen-fleet" -d 'Prepare configs and a Docker Compose / Kubernetes manifest for a fleet of ENs'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from external-node" -f -a "init" -d 'Init databases'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from external-node" -f -a "build" -d 'Build external node'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from external-node" -f -a "run" -d 'Run external node'
@@ -776,9 +1368,13 @@ complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_su
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from contract-verifier" -f -a "run" -d 'Run contract verifier'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from contract-verifier" -f -a "wait" -d 'Wait for contract verifier to start'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from contract-verifier" -f -a "init" -d 'Download required binaries for contract verifier'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from portal" -f -a "run" -d 'Run dapp-portal (default)'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from explorer" -f -a "init" -d 'Initialize explorer (create database to store explorer data and generate docker compose file with explorer services). Runs for all chains, unless --chain is passed'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from explorer" -f -a "run-backend" -d 'Start explorer backend services (api, data_fetcher, worker) for a given chain. Uses default chain, unless --chain is passed'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from explorer" -f -a "run" -d 'Run explorer app'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from consensus" -f -a "set-attester-committee" -d 'Sets the attester committee in the consensus registry contract to `consensus.genesis_spec.attesters` in general.yaml'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from consensus" -f -a "get-attester-committee" -d 'Fetches the attester committee from the consensus registry contract'
 complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from consensus" -f -a "wait-for-registry" -d 'Wait until the consensus registry contract is deployed to L2'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from consensus" -f -a "status" -d 'Shows the consensus view of the node: current view and leader, last certificates, committee weights, and connectivity to consensus peers'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from interop" -f -a "relayer" -d 'Relayer delivering messages sent by a chain to other chains of the ecosystem'
+complete -c zkstack -n "__fish_zkstack_using_subcommand help; and __fish_seen_subcommand_from update" -f -a "rollback" -d 'Restore the code revision, binaries, chain configs and DB migrations recorded before the last update'
@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, ffi::OsString, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::{parser::ValueSource, Arg, ArgAction, Command, Parser, ValueHint};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::messages::{
+    msg_answers_invalid_value_err, msg_answers_unknown_key_err, MSG_ANSWERS_HELP,
+};
+
+/// ID of the `--answers` argument.
+const ANSWERS_ARG_ID: &str = "answers";
+
+/// Answers file argument. Commands flattening these args can be run non-interactively.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Parser)]
+pub struct AnswersArgs {
+    #[clap(long, help = MSG_ANSWERS_HELP, value_hint = ValueHint::FilePath)]
+    pub answers: Option<PathBuf>,
+}
+
+/// Extends command-line arguments with values from the answers file if the invoked command has `--answers` specified.
+/// Answers are keyed by the long names of the command arguments (with either dashes or underscores);
+/// values specified on the command line take precedence. Returns `None` if no answers file is used.
+pub(crate) fn args_with_answers(
+    mut command: Command,
+    args: &[OsString],
+) -> anyhow::Result<Option<Vec<OsString>>> {
+    command.build();
+    // Errors (including `--help`) are reported by the main parser.
+    let Ok(matches) = command
+        .clone()
+        .ignore_errors(true)
+        .try_get_matches_from(args)
+    else {
+        return Ok(None);
+    };
+
+    let mut command = &command;
+    let mut matches = &matches;
+    let mut command_path = vec![command.get_name().to_owned()];
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        command = command
+            .find_subcommand(name)
+            .context("unknown subcommand")?;
+        matches = sub_matches;
+        command_path.push(name.to_owned());
+    }
+
+    let has_answers_arg = command
+        .get_arguments()
+        .any(|arg| arg.get_id() == ANSWERS_ARG_ID);
+    if !has_answers_arg {
+        return Ok(None);
+    }
+    let Some(path) = matches.get_one::<PathBuf>(ANSWERS_ARG_ID) else {
+        return Ok(None);
+    };
+
+    let contents =
+        std::fs::read_to_string(path).with_context(|| format!("failed reading {path:?}"))?;
+    let answers: BTreeMap<String, Value> = if path.extension().is_some_and(|ext| ext == "toml") {
+        toml::from_str(&contents).with_context(|| format!("invalid answers file {path:?}"))?
+    } else {
+        serde_yaml::from_str(&contents).with_context(|| format!("invalid answers file {path:?}"))?
+    };
+
+    let command_path = command_path.join(" ");
+    let mut args = args.to_vec();
+    for (key, value) in answers {
+        let long = key.replace('_', "-");
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(long.as_str()))
+            .with_context(|| msg_answers_unknown_key_err(&key, &command_path))?;
+        if matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine) {
+            continue;
+        }
+        let answer_args = answer_to_args(arg, &long, value)
+            .with_context(|| msg_answers_invalid_value_err(&key))?;
+        args.extend(answer_args.into_iter().map(OsString::from));
+    }
+    Ok(Some(args))
+}
+
+fn answer_to_args(arg: &Arg, long: &str, value: Value) -> anyhow::Result<Vec<String>> {
+    match (arg.get_action(), value) {
+        (ArgAction::SetTrue, Value::Bool(flag)) | (ArgAction::SetFalse, Value::Bool(flag)) => {
+            let is_set = flag == matches!(arg.get_action(), ArgAction::SetTrue);
+            Ok(if is_set {
+                vec![format!("--{long}")]
+            } else {
+                vec![]
+            })
+        }
+        (ArgAction::Set, value) => Ok(vec![format!("--{long}={}", scalar_to_string(value)?)]),
+        (ArgAction::Append, Value::Array(values)) => values
+            .into_iter()
+            .map(|value| Ok(format!("--{long}={}", scalar_to_string(value)?)))
+            .collect(),
+        (ArgAction::Append, value) => Ok(vec![format!("--{long}={}", scalar_to_string(value)?)]),
+        (action, value) => {
+            anyhow::bail!("unsupported value {value} for argument with action {action:?}")
+        }
+    }
+}
+
+fn scalar_to_string(value: Value) -> anyhow::Result<String> {
+    Ok(match value {
+        Value::Bool(flag) => flag.to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(s) => s,
+        Value::Null | Value::Array(_) | Value::Object(_) => {
+            anyhow::bail!("expected a boolean, number or string, got {value}")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, Subcommand};
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: TestCommand,
+    }
+
+    #[derive(Debug, Subcommand)]
+    enum TestCommand {
+        Create {
+            #[clap(long)]
+            chain_name: Option<String>,
+            #[clap(long)]
+            chain_id: Option<u32>,
+            #[clap(long, default_missing_value = "true", num_args = 0..=1)]
+            evm_emulator: Option<bool>,
+            #[clap(long)]
+            legacy_bridge: bool,
+            #[clap(long, value_delimiter = ',')]
+            targets: Vec<String>,
+            #[clap(flatten)]
+            answers: AnswersArgs,
+        },
+        Run {
+            #[clap(long)]
+            chain_name: Option<String>,
+        },
+    }
+
+    fn write_answers(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}_{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn os_args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn applying_yaml_answers() {
+        let path = write_answers(
+            "answers.yaml",
+            "chain_name: era\nchain-id: 271\nevm_emulator: false\nlegacy_bridge: true\ntargets: [a, b]\n",
+        );
+        let args = os_args(&["test", "create", "--chain-id", "272", "--answers"]);
+        let args = [args, vec![path.clone().into_os_string()]].concat();
+        let args = args_with_answers(TestCli::command(), &args)
+            .unwrap()
+            .unwrap();
+
+        let TestCommand::Create {
+            chain_name,
+            chain_id,
+            evm_emulator,
+            legacy_bridge,
+            targets,
+            ..
+        } = TestCli::parse_from(args).command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(chain_name.as_deref(), Some("era"));
+        // The command line takes precedence over the answers file.
+        assert_eq!(chain_id, Some(272));
+        assert_eq!(evm_emulator, Some(false));
+        assert!(legacy_bridge);
+        assert_eq!(targets, ["a", "b"]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn applying_toml_answers() {
+        let path = write_answers("answers.toml", "chain_name = \"era\"\nchain_id = 271\n");
+        let args = [
+            os_args(&["test", "create", "--answers"]),
+            vec![path.clone().into_os_string()],
+        ]
+        .concat();
+        let args = args_with_answers(TestCli::command(), &args)
+            .unwrap()
+            .unwrap();
+        let TestCommand::Create { chain_id, .. } = TestCli::parse_from(args).command else {
+            panic!("unexpected command");
+        };
+        assert_eq!(chain_id, Some(271));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn rejecting_unknown_answers() {
+        let path = write_answers("unknown.yaml", "chain_nam: era\n");
+        let args = [
+            os_args(&["test", "create", "--answers"]),
+            vec![path.clone().into_os_string()],
+        ]
+        .concat();
+        let err = args_with_answers(TestCli::command(), &args).unwrap_err();
+        assert!(err.to_string().contains("chain_nam"), "{err}");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn ignoring_commands_without_answers() {
+        let args = os_args(&["test", "run", "--chain-name", "era"]);
+        assert!(args_with_answers(TestCli::command(), &args)
+            .unwrap()
+            .is_none());
+        let args = os_args(&["test", "create"]);
+        assert!(args_with_answers(TestCli::command(), &args)
+            .unwrap()
+            .is_none());
+    }
+}
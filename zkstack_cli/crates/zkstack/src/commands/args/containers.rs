@@ -1,16 +1,22 @@
 use clap::Parser;
+use common::docker::ContainerRuntime;
 
-use crate::messages::{MSG_OBSERVABILITY_HELP, MSG_OBSERVABILITY_RUN_PROMPT};
+use crate::messages::{
+    MSG_CONTAINER_RUNTIME_HELP, MSG_OBSERVABILITY_HELP, MSG_OBSERVABILITY_RUN_PROMPT,
+};
 
 #[derive(Debug, Parser)]
 pub struct ContainersArgs {
     #[clap(long, short = 'o', help = MSG_OBSERVABILITY_HELP, default_missing_value = "true", num_args = 0..=1)]
     pub observability: Option<bool>,
+    #[clap(long, help = MSG_CONTAINER_RUNTIME_HELP, value_enum)]
+    pub runtime: Option<ContainerRuntime>,
 }
 
 #[derive(Debug)]
 pub struct ContainersArgsFinal {
     pub observability: bool,
+    pub runtime: Option<ContainerRuntime>,
 }
 
 impl ContainersArgs {
@@ -21,6 +27,9 @@ impl ContainersArgs {
                 .ask()
         });
 
-        ContainersArgsFinal { observability }
+        ContainersArgsFinal {
+            observability,
+            runtime: self.runtime,
+        }
     }
 }
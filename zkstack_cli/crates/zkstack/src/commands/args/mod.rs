@@ -1,7 +1,14 @@
-pub use self::{autocomplete::*, containers::*, run_server::*, update::*, wait::*};
+pub use self::{
+    answers::*, autocomplete::*, containers::*, multi_chain::*, portal::*, run_all::*,
+    run_server::*, update::*, wait::*,
+};
 
+mod answers;
 mod autocomplete;
 mod containers;
+mod multi_chain;
+mod portal;
+mod run_all;
 mod run_server;
 mod update;
 mod wait;
@@ -0,0 +1,56 @@
+use anyhow::bail;
+use clap::Parser;
+use common::config::global_config;
+use config::EcosystemConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::messages::{
+    msg_multi_chain_unknown_chain_err, MSG_MULTI_CHAIN_ALL_CHAINS_HELP,
+    MSG_MULTI_CHAIN_CHAINS_HELP, MSG_MULTI_CHAIN_CHAIN_CONFLICT_ERR, MSG_MULTI_CHAIN_NO_CHAINS_ERR,
+};
+
+/// Arguments selecting chains for commands that can run for multiple chains concurrently.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Parser)]
+pub struct MultiChainArgs {
+    #[clap(long, conflicts_with = "chains", help = MSG_MULTI_CHAIN_ALL_CHAINS_HELP)]
+    pub all_chains: bool,
+    #[clap(long, value_delimiter = ',', help = MSG_MULTI_CHAIN_CHAINS_HELP)]
+    pub chains: Option<Vec<String>>,
+}
+
+impl MultiChainArgs {
+    pub fn is_enabled(&self) -> bool {
+        self.all_chains || self.chains.is_some()
+    }
+
+    /// Returns the selected chains in the order they should be run, without duplicates.
+    pub fn select_chains(&self, ecosystem: &EcosystemConfig) -> anyhow::Result<Vec<String>> {
+        if global_config().chain_name.is_some() {
+            bail!(MSG_MULTI_CHAIN_CHAIN_CONFLICT_ERR);
+        }
+
+        let available = ecosystem.list_of_chains();
+        let mut selected = match &self.chains {
+            Some(chains) => {
+                let mut selected: Vec<String> = Vec::with_capacity(chains.len());
+                for chain in chains {
+                    if !available.contains(chain) {
+                        bail!(msg_multi_chain_unknown_chain_err(chain, &available));
+                    }
+                    if !selected.contains(chain) {
+                        selected.push(chain.clone());
+                    }
+                }
+                selected
+            }
+            None => available,
+        };
+        if selected.is_empty() {
+            bail!(MSG_MULTI_CHAIN_NO_CHAINS_ERR);
+        }
+        if self.chains.is_none() {
+            selected.sort();
+        }
+        Ok(selected)
+    }
+}
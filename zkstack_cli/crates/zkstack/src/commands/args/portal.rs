@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::messages::{
+    MSG_PORTAL_CHAINS_HELP, MSG_PORTAL_CONFIG_ONLY_HELP, MSG_PORTAL_CONFIG_OVERRIDE_HELP,
+};
+
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct PortalArgs {
+    #[command(subcommand)]
+    pub command: Option<PortalCommands>,
+    /// Arguments used if no subcommand is specified (equivalent to `portal run`).
+    #[command(flatten)]
+    pub run: PortalRunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PortalCommands {
+    /// Run dapp-portal (default)
+    Run(PortalRunArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct PortalRunArgs {
+    #[clap(long, value_delimiter = ',', help = MSG_PORTAL_CHAINS_HELP)]
+    pub chains: Vec<String>,
+    #[clap(long, help = MSG_PORTAL_CONFIG_OVERRIDE_HELP)]
+    pub config_override: Option<PathBuf>,
+    #[clap(long, help = MSG_PORTAL_CONFIG_ONLY_HELP)]
+    pub config_only: bool,
+}
+
+impl PortalArgs {
+    pub fn into_run_args(self) -> PortalRunArgs {
+        match self.command {
+            Some(PortalCommands::Run(args)) => args,
+            None => self.run,
+        }
+    }
+}
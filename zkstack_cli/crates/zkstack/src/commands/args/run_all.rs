@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::messages::{
+    MSG_RUN_ALL_LOG_FORMAT_HELP, MSG_RUN_ALL_MAX_RESTARTS_HELP, MSG_RUN_ALL_NO_COLOR_HELP,
+    MSG_RUN_ALL_RESTART_DELAY_HELP, MSG_RUN_ALL_RESTART_HELP, MSG_RUN_ALL_SERVICES_HELP,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display, ValueEnum)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RunAllService {
+    Server,
+    ExternalNode,
+    ProverGateway,
+    WitnessGenerator,
+    WitnessVectorGenerator,
+    Prover,
+    CircuitProver,
+    Compressor,
+    ProverJobMonitor,
+    ContractVerifier,
+    Explorer,
+    ExplorerBackend,
+}
+
+impl RunAllService {
+    /// Arguments of the `zkstack` subcommand running the service in the foreground without prompts.
+    pub fn zkstack_args(self) -> &'static [&'static str] {
+        match self {
+            Self::Server => &["server"],
+            Self::ExternalNode => &["external-node", "run"],
+            Self::ProverGateway => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "gateway",
+            ],
+            Self::WitnessGenerator => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "witness-generator",
+                "--round",
+                "all-rounds",
+            ],
+            Self::WitnessVectorGenerator => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "witness-vector-generator",
+                "--threads",
+                "1",
+            ],
+            Self::Prover => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "prover",
+            ],
+            Self::CircuitProver => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "circuit-prover",
+                "--light-wvg-count",
+                "8",
+                "--heavy-wvg-count",
+                "2",
+            ],
+            Self::Compressor => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "compressor",
+            ],
+            Self::ProverJobMonitor => &[
+                "prover",
+                "run",
+                "--docker",
+                "false",
+                "--component",
+                "prover-job-monitor",
+            ],
+            Self::ContractVerifier => &["contract-verifier", "run"],
+            Self::Explorer => &["explorer", "run"],
+            Self::ExplorerBackend => &["explorer", "run-backend"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, ValueEnum)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RestartPolicy {
+    /// Never restart exited services.
+    Never,
+    /// Restart services exiting with a non-zero status.
+    OnFailure,
+    /// Restart services regardless of their exit status.
+    Always,
+}
+
+impl RestartPolicy {
+    pub fn should_restart(self, success: bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure => !success,
+            Self::Always => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+pub enum LogFormat {
+    /// Lines prefixed with the service name.
+    Text,
+    /// A JSON object per line.
+    Json,
+}
+
+#[derive(Debug, Parser, Serialize, Deserialize)]
+pub struct RunAllArgs {
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        num_args = 1..,
+        default_value = "server",
+        help = MSG_RUN_ALL_SERVICES_HELP
+    )]
+    pub services: Vec<RunAllService>,
+    #[arg(long, value_enum, default_value_t = RestartPolicy::OnFailure, help = MSG_RUN_ALL_RESTART_HELP)]
+    pub restart: RestartPolicy,
+    #[arg(long, default_value_t = 5, help = MSG_RUN_ALL_MAX_RESTARTS_HELP)]
+    pub max_restarts: usize,
+    #[arg(long, value_name = "MILLIS", default_value_t = 1_000, help = MSG_RUN_ALL_RESTART_DELAY_HELP)]
+    pub restart_delay_ms: u64,
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, help = MSG_RUN_ALL_LOG_FORMAT_HELP)]
+    pub log_format: LogFormat,
+    #[arg(long, help = MSG_RUN_ALL_NO_COLOR_HELP)]
+    pub no_color: bool,
+}
+
+impl RunAllArgs {
+    /// Delay before the `restart`-th restart (1-based), doubling with each attempt and capped at one minute.
+    pub fn restart_delay(&self, restart: usize) -> Duration {
+        const MAX_DELAY: Duration = Duration::from_secs(60);
+
+        let exponent = restart.saturating_sub(1).min(16) as u32;
+        Duration::from_millis(self.restart_delay_ms)
+            .saturating_mul(1 << exponent)
+            .min(MAX_DELAY)
+    }
+}
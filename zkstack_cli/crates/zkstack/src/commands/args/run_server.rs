@@ -1,14 +1,62 @@
+use std::collections::BTreeMap;
+
 use clap::{Parser, Subcommand};
+use config::ChainConfig;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    commands::args::WaitArgs,
+    commands::args::{MultiChainArgs, WaitArgs},
     messages::{
-        MSG_SERVER_ADDITIONAL_ARGS_HELP, MSG_SERVER_COMPONENTS_HELP, MSG_SERVER_GENESIS_HELP,
-        MSG_SERVER_URING_HELP,
+        msg_server_unknown_preset_err, MSG_SERVER_ADDITIONAL_ARGS_HELP, MSG_SERVER_COMPONENTS_HELP,
+        MSG_SERVER_GENESIS_HELP, MSG_SERVER_PRESET_HELP, MSG_SERVER_URING_HELP,
     },
 };
 
+/// Components run by the server by default.
+const FULL_COMPONENTS: &[&str] = &[
+    "api",
+    "tree",
+    "eth",
+    "state_keeper",
+    "housekeeper",
+    "commitment_generator",
+    "da_dispatcher",
+    "vm_runner_protective_reads",
+];
+
+/// Built-in server presets; custom presets in the chain config take precedence over them.
+const BUILTIN_PRESETS: &[(&str, &[&str])] = &[
+    ("full", FULL_COMPONENTS),
+    (
+        "sequencer",
+        &[
+            "tree",
+            "eth",
+            "state_keeper",
+            "housekeeper",
+            "commitment_generator",
+            "da_dispatcher",
+            "vm_runner_protective_reads",
+        ],
+    ),
+    ("api-only", &["api"]),
+    (
+        "prover",
+        &[
+            "api",
+            "tree",
+            "eth",
+            "state_keeper",
+            "housekeeper",
+            "commitment_generator",
+            "da_dispatcher",
+            "vm_runner_protective_reads",
+            "proof_data_handler",
+            "vm_runner_bwip",
+        ],
+    ),
+];
+
 #[derive(Debug, Parser)]
 #[command(args_conflicts_with_subcommands = true, flatten_help = true)]
 pub struct ServerArgs {
@@ -16,6 +64,8 @@ pub struct ServerArgs {
     command: Option<ServerCommand>,
     #[command(flatten)]
     run: RunServerArgs,
+    #[command(flatten)]
+    pub multi_chain: MultiChainArgs,
 }
 
 #[derive(Debug, Subcommand)]
@@ -38,6 +88,8 @@ impl From<ServerArgs> for ServerCommand {
 pub struct RunServerArgs {
     #[arg(long, help = MSG_SERVER_COMPONENTS_HELP)]
     pub components: Option<Vec<String>>,
+    #[arg(long, conflicts_with = "components", help = MSG_SERVER_PRESET_HELP)]
+    pub preset: Option<String>,
     #[arg(long, help = MSG_SERVER_GENESIS_HELP)]
     pub genesis: bool,
     #[arg(
@@ -51,3 +103,81 @@ pub struct RunServerArgs {
     #[clap(help = MSG_SERVER_URING_HELP, long, default_missing_value = "true")]
     pub uring: bool,
 }
+
+impl RunServerArgs {
+    /// Returns the components to run, resolving `--preset` if it's specified.
+    pub fn resolve_components(
+        &self,
+        chain_config: &ChainConfig,
+    ) -> anyhow::Result<Option<Vec<String>>> {
+        match &self.preset {
+            Some(preset) => resolve_preset(preset, &chain_config.server_presets).map(Some),
+            None => Ok(self.components.clone()),
+        }
+    }
+}
+
+fn resolve_preset(
+    preset: &str,
+    custom_presets: &BTreeMap<String, Vec<String>>,
+) -> anyhow::Result<Vec<String>> {
+    if let Some(components) = custom_presets.get(preset) {
+        return Ok(components.clone());
+    }
+    if let Some((_, components)) = BUILTIN_PRESETS.iter().find(|(name, _)| *name == preset) {
+        return Ok(components
+            .iter()
+            .map(|&component| component.to_owned())
+            .collect());
+    }
+
+    let is_builtin = |name: &str| BUILTIN_PRESETS.iter().any(|(builtin, _)| *builtin == name);
+    let available: Vec<_> = BUILTIN_PRESETS
+        .iter()
+        .map(|(name, _)| (*name).to_owned())
+        .chain(
+            custom_presets
+                .keys()
+                .filter(|name| !is_builtin(name.as_str()))
+                .cloned(),
+        )
+        .collect();
+    anyhow::bail!(msg_server_unknown_preset_err(preset, &available))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolving_presets() {
+        let mut custom_presets = BTreeMap::new();
+        custom_presets.insert(
+            "light".to_owned(),
+            vec!["api".to_owned(), "state_keeper".to_owned()],
+        );
+        custom_presets.insert("api-only".to_owned(), vec!["http_api".to_owned()]);
+
+        assert_eq!(
+            resolve_preset("full", &custom_presets).unwrap(),
+            FULL_COMPONENTS
+        );
+        assert_eq!(
+            resolve_preset("light", &custom_presets).unwrap(),
+            ["api", "state_keeper"]
+        );
+        // Custom presets override built-in ones.
+        assert_eq!(
+            resolve_preset("api-only", &custom_presets).unwrap(),
+            ["http_api"]
+        );
+
+        let err = resolve_preset("unknown", &custom_presets)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("full, sequencer, api-only, prover, light"),
+            "{err}"
+        );
+    }
+}
@@ -1,9 +1,30 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
-use crate::messages::MSG_UPDATE_ONLY_CONFIG_HELP;
+use crate::{
+    commands::args::MultiChainArgs,
+    messages::{MSG_UPDATE_ONLY_CONFIG_HELP, MSG_UPDATE_ROLLBACK_HELP, MSG_UPDATE_TO_HELP},
+};
 
 #[derive(Debug, Parser)]
 pub struct UpdateArgs {
     #[clap(long, short = 'c', help = MSG_UPDATE_ONLY_CONFIG_HELP)]
     pub only_config: bool,
+    #[clap(long, conflicts_with = "only_config", help = MSG_UPDATE_TO_HELP)]
+    pub to: Option<String>,
+    // Same as the `rollback` subcommand.
+    #[clap(long, conflicts_with_all = ["only_config", "to"], help = MSG_UPDATE_ROLLBACK_HELP)]
+    pub rollback: bool,
+    // Set when updating chains in separate processes, which shouldn't overwrite the rollback point.
+    #[clap(long, hide = true)]
+    pub no_rollback_point: bool,
+    #[clap(flatten)]
+    pub multi_chain: MultiChainArgs,
+    #[command(subcommand)]
+    pub command: Option<UpdateCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UpdateCommands {
+    #[command(about = MSG_UPDATE_ROLLBACK_HELP)]
+    Rollback,
 }
@@ -0,0 +1,129 @@
+use anyhow::Context as _;
+use common::logger;
+use config::{EcosystemConfig, RemoteChainBridges, RemoteChainConfig, RemoteChainsConfig};
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::Address,
+};
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zksync_basic_types::L2ChainId;
+
+use crate::{
+    commands::chain::args::add_remote::{AddRemoteChainArgs, AddRemoteChainArgsFinal},
+    messages::{
+        msg_fetching_remote_chain_info, msg_remote_chain_added, msg_remote_chain_id_mismatch_err,
+        msg_remote_chain_name_taken_err, MSG_REMOTE_CHAIN_BASE_TOKEN_REQUIRED_ERR,
+        MSG_REMOTE_CHAIN_ID_REQUIRED_ERR,
+    },
+};
+
+/// Subset of the `zks_getBridgeContracts` response.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeContracts {
+    l1_shared_default_bridge: Option<Address>,
+    l2_shared_default_bridge: Option<Address>,
+    l1_erc20_default_bridge: Option<Address>,
+    l2_erc20_default_bridge: Option<Address>,
+}
+
+pub async fn run(args: AddRemoteChainArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let args = args.fill_values_with_prompt();
+    if ecosystem_config.list_of_chains().contains(&args.name) {
+        anyhow::bail!(msg_remote_chain_name_taken_err(&args.name));
+    }
+
+    let chain = if args.skip_rpc_check {
+        remote_chain_from_args(args)?
+    } else {
+        fetch_remote_chain(args).await?
+    };
+
+    let mut remote_chains = RemoteChainsConfig::read_or_default(shell)?;
+    let name = chain.name.clone();
+    remote_chains.add_chain(chain);
+    remote_chains.save_to_ecosystem(shell)?;
+    logger::outro(msg_remote_chain_added(
+        &name,
+        &RemoteChainsConfig::get_config_path(&shell.current_dir()),
+    ));
+    Ok(())
+}
+
+fn remote_chain_from_args(args: AddRemoteChainArgsFinal) -> anyhow::Result<RemoteChainConfig> {
+    let chain_id = args.chain_id.context(MSG_REMOTE_CHAIN_ID_REQUIRED_ERR)?;
+    let base_token_address = args
+        .base_token_address
+        .context(MSG_REMOTE_CHAIN_BASE_TOKEN_REQUIRED_ERR)?;
+    Ok(RemoteChainConfig {
+        name: args.name,
+        chain_id: L2ChainId::try_from(chain_id).map_err(anyhow::Error::msg)?,
+        l2_rpc_url: args.l2_rpc_url,
+        l1_rpc_url: args.l1_rpc_url,
+        base_token_address,
+        bridges: RemoteChainBridges {
+            bridgehub: args.bridgehub,
+            l1_shared_bridge: args.l1_shared_bridge,
+            l2_shared_bridge: args.l2_shared_bridge,
+            l1_erc20_bridge: None,
+            l2_erc20_bridge: None,
+        },
+        explorer_url: args.explorer_url,
+        verification_api_url: args.verification_api_url,
+    })
+}
+
+/// Fetches chain parameters from the chain L2 RPC. Parameters explicitly specified in `args` take precedence.
+async fn fetch_remote_chain(args: AddRemoteChainArgsFinal) -> anyhow::Result<RemoteChainConfig> {
+    logger::info(msg_fetching_remote_chain_info(&args.l2_rpc_url));
+    let provider = Provider::<Http>::try_from(args.l2_rpc_url.as_str())?;
+
+    let chain_id = provider
+        .get_chainid()
+        .await
+        .context("eth_chainId")?
+        .as_u64();
+    if let Some(expected) = args.chain_id {
+        anyhow::ensure!(
+            expected == chain_id,
+            msg_remote_chain_id_mismatch_err(expected, chain_id)
+        );
+    }
+    let bridges = provider
+        .request::<_, BridgeContracts>("zks_getBridgeContracts", ())
+        .await
+        .context("zks_getBridgeContracts")?;
+    let bridgehub = match args.bridgehub {
+        Some(address) => Some(address),
+        None => provider
+            .request::<_, Option<Address>>("zks_getBridgehubContract", ())
+            .await
+            .context("zks_getBridgehubContract")?,
+    };
+    let base_token_address = match args.base_token_address {
+        Some(address) => address,
+        None => provider
+            .request::<_, Address>("zks_getBaseTokenL1Address", ())
+            .await
+            .context("zks_getBaseTokenL1Address")?,
+    };
+
+    Ok(RemoteChainConfig {
+        name: args.name,
+        chain_id: L2ChainId::try_from(chain_id).map_err(anyhow::Error::msg)?,
+        l2_rpc_url: args.l2_rpc_url,
+        l1_rpc_url: args.l1_rpc_url,
+        base_token_address,
+        bridges: RemoteChainBridges {
+            bridgehub,
+            l1_shared_bridge: args.l1_shared_bridge.or(bridges.l1_shared_default_bridge),
+            l2_shared_bridge: args.l2_shared_bridge.or(bridges.l2_shared_default_bridge),
+            l1_erc20_bridge: bridges.l1_erc20_default_bridge,
+            l2_erc20_bridge: bridges.l2_erc20_default_bridge,
+        },
+        explorer_url: args.explorer_url,
+        verification_api_url: args.verification_api_url,
+    })
+}
@@ -0,0 +1,86 @@
+use clap::Parser;
+use common::Prompt;
+use ethers::types::Address;
+use serde::{Deserialize, Serialize};
+use slugify_rs::slugify;
+use url::Url;
+
+use crate::messages::{
+    MSG_CHAIN_NAME_PROMPT, MSG_REMOTE_CHAIN_BASE_TOKEN_HELP, MSG_REMOTE_CHAIN_BRIDGEHUB_HELP,
+    MSG_REMOTE_CHAIN_EXPLORER_URL_HELP, MSG_REMOTE_CHAIN_ID_HELP, MSG_REMOTE_CHAIN_L1_RPC_URL_HELP,
+    MSG_REMOTE_CHAIN_L1_SHARED_BRIDGE_HELP, MSG_REMOTE_CHAIN_L2_RPC_URL_HELP,
+    MSG_REMOTE_CHAIN_L2_RPC_URL_PROMPT, MSG_REMOTE_CHAIN_L2_SHARED_BRIDGE_HELP,
+    MSG_REMOTE_CHAIN_NAME_HELP, MSG_REMOTE_CHAIN_SKIP_RPC_CHECK_HELP,
+    MSG_REMOTE_CHAIN_VERIFICATION_API_URL_HELP,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct AddRemoteChainArgs {
+    #[clap(long, help = MSG_REMOTE_CHAIN_NAME_HELP)]
+    pub name: Option<String>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_L2_RPC_URL_HELP)]
+    pub l2_rpc_url: Option<Url>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_L1_RPC_URL_HELP)]
+    pub l1_rpc_url: Option<Url>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_ID_HELP)]
+    pub chain_id: Option<u64>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_BRIDGEHUB_HELP)]
+    pub bridgehub: Option<Address>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_L1_SHARED_BRIDGE_HELP)]
+    pub l1_shared_bridge: Option<Address>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_L2_SHARED_BRIDGE_HELP)]
+    pub l2_shared_bridge: Option<Address>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_BASE_TOKEN_HELP)]
+    pub base_token_address: Option<Address>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_EXPLORER_URL_HELP)]
+    pub explorer_url: Option<Url>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_VERIFICATION_API_URL_HELP)]
+    pub verification_api_url: Option<Url>,
+    #[clap(long, help = MSG_REMOTE_CHAIN_SKIP_RPC_CHECK_HELP)]
+    pub skip_rpc_check: bool,
+}
+
+impl AddRemoteChainArgs {
+    pub fn fill_values_with_prompt(self) -> AddRemoteChainArgsFinal {
+        let name = self
+            .name
+            .unwrap_or_else(|| Prompt::new(MSG_CHAIN_NAME_PROMPT).ask());
+        let name = slugify!(&name, separator = "_");
+        let l2_rpc_url = self.l2_rpc_url.unwrap_or_else(|| {
+            Prompt::new(MSG_REMOTE_CHAIN_L2_RPC_URL_PROMPT)
+                .validate_with(|val: &String| -> Result<(), String> {
+                    Url::parse(val).map(drop).map_err(|err| err.to_string())
+                })
+                .ask()
+        });
+
+        AddRemoteChainArgsFinal {
+            name,
+            l2_rpc_url,
+            l1_rpc_url: self.l1_rpc_url,
+            chain_id: self.chain_id,
+            bridgehub: self.bridgehub,
+            l1_shared_bridge: self.l1_shared_bridge,
+            l2_shared_bridge: self.l2_shared_bridge,
+            base_token_address: self.base_token_address,
+            explorer_url: self.explorer_url,
+            verification_api_url: self.verification_api_url,
+            skip_rpc_check: self.skip_rpc_check,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddRemoteChainArgsFinal {
+    pub name: String,
+    pub l2_rpc_url: Url,
+    pub l1_rpc_url: Option<Url>,
+    pub chain_id: Option<u64>,
+    pub bridgehub: Option<Address>,
+    pub l1_shared_bridge: Option<Address>,
+    pub l2_shared_bridge: Option<Address>,
+    pub base_token_address: Option<Address>,
+    pub explorer_url: Option<Url>,
+    pub verification_api_url: Option<Url>,
+    pub skip_rpc_check: bool,
+}
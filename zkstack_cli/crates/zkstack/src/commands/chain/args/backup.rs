@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use url::Url;
+
+use crate::messages::{
+    MSG_BACKUP_OUTPUT_HELP, MSG_BACKUP_SKIP_PROVER_DB_HELP, MSG_BACKUP_SKIP_ROCKS_DB_HELP,
+    MSG_RESTORE_ARCHIVE_HELP, MSG_RESTORE_FORCE_HELP, MSG_RESTORE_PROVER_DB_URL_HELP,
+    MSG_RESTORE_SERVER_DB_URL_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct BackupArgs {
+    #[clap(long = "out", short, help = MSG_BACKUP_OUTPUT_HELP)]
+    pub output: Option<PathBuf>,
+    #[clap(long, help = MSG_BACKUP_SKIP_PROVER_DB_HELP)]
+    pub skip_prover_db: bool,
+    #[clap(long, help = MSG_BACKUP_SKIP_ROCKS_DB_HELP)]
+    pub skip_rocks_db: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct RestoreArgs {
+    #[clap(help = MSG_RESTORE_ARCHIVE_HELP)]
+    pub archive: PathBuf,
+    #[clap(long, help = MSG_RESTORE_SERVER_DB_URL_HELP)]
+    pub server_db_url: Option<Url>,
+    #[clap(long, help = MSG_RESTORE_PROVER_DB_URL_HELP)]
+    pub prover_db_url: Option<Url>,
+    #[clap(long, help = MSG_RESTORE_FORCE_HELP)]
+    pub force: bool,
+}
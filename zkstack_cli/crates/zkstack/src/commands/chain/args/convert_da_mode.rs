@@ -0,0 +1,45 @@
+use clap::Parser;
+use common::{forge::ForgeScriptArgs, Prompt};
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::Address;
+
+use crate::messages::{
+    MSG_L1_DA_VALIDATOR_HELP, MSG_L1_DA_VALIDATOR_PROMPT, MSG_L2_DA_VALIDATOR_HELP,
+    MSG_L2_DA_VALIDATOR_PROMPT,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct ConvertDaModeArgs {
+    /// All ethereum environment related arguments
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub forge_args: ForgeScriptArgs,
+    #[clap(long, help = MSG_L1_DA_VALIDATOR_HELP)]
+    pub l1_da_validator: Option<Address>,
+    #[clap(long, help = MSG_L2_DA_VALIDATOR_HELP)]
+    pub l2_da_validator: Option<Address>,
+}
+
+impl ConvertDaModeArgs {
+    pub fn fill_values_with_prompt(self) -> ConvertDaModeArgsFinal {
+        let l1_da_validator = self
+            .l1_da_validator
+            .unwrap_or_else(|| Prompt::new(MSG_L1_DA_VALIDATOR_PROMPT).ask());
+        let l2_da_validator = self
+            .l2_da_validator
+            .unwrap_or_else(|| Prompt::new(MSG_L2_DA_VALIDATOR_PROMPT).ask());
+
+        ConvertDaModeArgsFinal {
+            forge_args: self.forge_args,
+            l1_da_validator,
+            l2_da_validator,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertDaModeArgsFinal {
+    pub forge_args: ForgeScriptArgs,
+    pub l1_da_validator: Address,
+    pub l2_da_validator: Address,
+}
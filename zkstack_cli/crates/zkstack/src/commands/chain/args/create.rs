@@ -11,6 +11,7 @@ use types::{BaseToken, L1BatchCommitmentMode, L1Network, ProverMode, WalletCreat
 use zksync_basic_types::H160;
 
 use crate::{
+    commands::args::AnswersArgs,
     defaults::L2_CHAIN_ID,
     messages::{
         MSG_BASE_TOKEN_ADDRESS_HELP, MSG_BASE_TOKEN_ADDRESS_PROMPT,
@@ -70,6 +71,9 @@ pub struct ChainCreateArgs {
     pub(crate) legacy_bridge: bool,
     #[arg(long, help = MSG_EVM_EMULATOR_HELP, default_missing_value = "true", num_args = 0..=1)]
     evm_emulator: Option<bool>,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub(crate) answers: AnswersArgs,
 }
 
 impl ChainCreateArgs {
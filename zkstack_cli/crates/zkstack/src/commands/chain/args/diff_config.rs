@@ -0,0 +1,9 @@
+use clap::Parser;
+
+use crate::messages::MSG_DIFF_CONFIG_URL_HELP;
+
+#[derive(Debug, Parser)]
+pub struct DiffConfigArgs {
+    #[clap(long, short = 'u', help = MSG_DIFF_CONFIG_URL_HELP)]
+    pub url: Option<String>,
+}
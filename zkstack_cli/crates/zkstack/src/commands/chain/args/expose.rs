@@ -0,0 +1,65 @@
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::messages::{
+    MSG_EXPOSE_CONFIG_ONLY_HELP, MSG_EXPOSE_DOMAIN_HELP, MSG_EXPOSE_EMAIL_HELP,
+    MSG_EXPOSE_INVALID_DOMAIN_ERR, MSG_EXPOSE_RATE_LIMIT_HELP, MSG_EXPOSE_STAGING_HELP,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct ExposeArgs {
+    #[clap(long, value_parser = parse_domain, help = MSG_EXPOSE_DOMAIN_HELP)]
+    pub domain: String,
+    #[clap(long, help = MSG_EXPOSE_EMAIL_HELP)]
+    pub email: String,
+    #[clap(long, default_value_t = 50, value_parser = clap::value_parser!(u32).range(1..), help = MSG_EXPOSE_RATE_LIMIT_HELP)]
+    pub rate_limit: u32,
+    #[clap(long, help = MSG_EXPOSE_STAGING_HELP)]
+    pub staging: bool,
+    #[clap(long, help = MSG_EXPOSE_CONFIG_ONLY_HELP)]
+    pub config_only: bool,
+}
+
+/// Checks that the domain is a bare hostname (no scheme, port or path), so that it can be used
+/// both in the proxy config and for requesting certificates.
+fn parse_domain(domain: &str) -> Result<String, String> {
+    let is_valid = !domain.is_empty()
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+        });
+    if is_valid {
+        Ok(domain.to_ascii_lowercase())
+    } else {
+        Err(MSG_EXPOSE_INVALID_DOMAIN_ERR.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_domain() {
+        assert_eq!(parse_domain("rpc.example.com").unwrap(), "rpc.example.com");
+        assert_eq!(parse_domain("RPC.Example.com").unwrap(), "rpc.example.com");
+        assert_eq!(parse_domain("my-chain.xyz").unwrap(), "my-chain.xyz");
+
+        for invalid in [
+            "",
+            "localhost",
+            "https://rpc.example.com",
+            "rpc.example.com:443",
+            "rpc.example.com/",
+            "-rpc.example.com",
+            "rpc..example.com",
+        ] {
+            parse_domain(invalid).unwrap_err();
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use anyhow::Context;
 use clap::Parser;
 use common::{db::DatabaseConfig, Prompt};
@@ -9,8 +11,9 @@ use url::Url;
 use crate::{
     defaults::{generate_db_names, DBNames, DATABASE_SERVER_URL},
     messages::{
-        msg_server_db_name_prompt, msg_server_db_url_prompt, MSG_SERVER_DB_NAME_HELP,
-        MSG_SERVER_DB_URL_HELP, MSG_USE_DEFAULT_DATABASES_HELP,
+        msg_server_db_name_prompt, msg_server_db_url_prompt, MSG_GENESIS_EXPORT_OUTPUT_HELP,
+        MSG_GENESIS_IMPORT_BUNDLE_HELP, MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP,
+        MSG_USE_DEFAULT_DATABASES_HELP,
     },
 };
 
@@ -90,3 +93,17 @@ pub struct GenesisArgsFinal {
     pub server_db: DatabaseConfig,
     pub dont_drop: bool,
 }
+
+#[derive(Debug, Clone, Parser)]
+pub struct GenesisExportArgs {
+    #[clap(long = "out", short, help = MSG_GENESIS_EXPORT_OUTPUT_HELP)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct GenesisImportArgs {
+    #[clap(help = MSG_GENESIS_IMPORT_BUNDLE_HELP)]
+    pub bundle: PathBuf,
+    #[clap(flatten)]
+    pub genesis: GenesisArgs,
+}
@@ -8,7 +8,10 @@ use url::Url;
 use crate::{
     commands::chain::args::{
         genesis::{GenesisArgs, GenesisArgsFinal},
-        init::InitArgsFinal,
+        init::{
+            da_configs::{ValidiumType, ValidiumTypeArgs},
+            InitArgsFinal,
+        },
     },
     defaults::LOCAL_RPC_URL,
     messages::{
@@ -26,6 +29,9 @@ pub struct InitConfigsArgs {
     pub l1_rpc_url: Option<String>,
     #[clap(long, help = MSG_NO_PORT_REALLOCATION_HELP)]
     pub no_port_reallocation: bool,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub validium_args: ValidiumTypeArgs,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -33,6 +39,9 @@ pub struct InitConfigsArgsFinal {
     pub genesis_args: GenesisArgsFinal,
     pub l1_rpc_url: String,
     pub no_port_reallocation: bool,
+    /// `None` for rollup chains. Not serialized since it contains secrets.
+    #[serde(skip)]
+    pub validium_config: Option<ValidiumType>,
 }
 
 impl InitConfigsArgs {
@@ -55,6 +64,7 @@ impl InitConfigsArgs {
             genesis_args: self.genesis_args.fill_values_with_prompt(config),
             l1_rpc_url,
             no_port_reallocation: self.no_port_reallocation,
+            validium_config: self.validium_args.fill_values_with_prompt(config),
         }
     }
 }
@@ -65,6 +75,7 @@ impl InitConfigsArgsFinal {
             genesis_args: init_args.genesis_args.clone(),
             l1_rpc_url: init_args.l1_rpc_url.clone(),
             no_port_reallocation: init_args.no_port_reallocation,
+            validium_config: init_args.validium_config.clone(),
         }
     }
 }
@@ -0,0 +1,205 @@
+use std::str::FromStr;
+
+use clap::{Parser, ValueEnum};
+use common::{logger, Prompt, PromptSelect};
+use config::ChainConfig;
+use secrecy::Secret;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+use types::L1BatchCommitmentMode;
+use url::Url;
+use zksync_basic_types::{secrets::SeedPhrase, Address};
+use zksync_config::{
+    configs::da_client::avail::{AvailClientConfig, AvailDefaultConfig, AvailSecrets},
+    AvailConfig,
+};
+
+use crate::{
+    defaults::AVAIL_DEFAULT_TIMEOUT_MS,
+    messages::{
+        msg_validium_type_ignored_for_rollup, msg_validium_type_prompt,
+        MSG_AVAIL_API_NODE_URL_HELP, MSG_AVAIL_API_NODE_URL_PROMPT, MSG_AVAIL_APP_ID_HELP,
+        MSG_AVAIL_APP_ID_PROMPT, MSG_AVAIL_BRIDGE_API_URL_HELP, MSG_AVAIL_BRIDGE_API_URL_PROMPT,
+        MSG_AVAIL_SEED_PHRASE_HELP, MSG_AVAIL_SEED_PHRASE_PROMPT, MSG_INVALID_URL_ERR,
+        MSG_L1_DA_VALIDATOR_PROMPT, MSG_L2_DA_VALIDATOR_PROMPT, MSG_VALIDIUM_L1_DA_VALIDATOR_HELP,
+        MSG_VALIDIUM_L2_DA_VALIDATOR_HELP, MSG_VALIDIUM_TYPE_HELP,
+    },
+};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, Display, ValueEnum,
+)]
+pub enum ValidiumTypeInternal {
+    NoDA,
+    Avail,
+}
+
+#[derive(Debug, Clone)]
+pub enum ValidiumType {
+    /// Pubdata is not published anywhere; the chain doesn't use a DA client.
+    NoDA,
+    Avail(AvailDAConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct AvailDAConfig {
+    pub client: AvailConfig,
+    pub secrets: AvailSecrets,
+    pub l1_da_validator: Address,
+    pub l2_da_validator: Address,
+}
+
+/// DA layer of a validium chain.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Parser)]
+pub struct ValidiumTypeArgs {
+    #[clap(long, help = MSG_VALIDIUM_TYPE_HELP, value_enum)]
+    pub validium_type: Option<ValidiumTypeInternal>,
+    #[clap(long, help = MSG_AVAIL_API_NODE_URL_HELP)]
+    pub avail_api_node_url: Option<Url>,
+    #[clap(long, help = MSG_AVAIL_APP_ID_HELP)]
+    pub avail_app_id: Option<u32>,
+    #[clap(long, help = MSG_AVAIL_BRIDGE_API_URL_HELP)]
+    pub avail_bridge_api_url: Option<Url>,
+    #[clap(long, help = MSG_AVAIL_SEED_PHRASE_HELP)]
+    pub avail_seed_phrase: Option<String>,
+    #[clap(long, help = MSG_VALIDIUM_L1_DA_VALIDATOR_HELP)]
+    pub l1_da_validator: Option<Address>,
+    #[clap(long, help = MSG_VALIDIUM_L2_DA_VALIDATOR_HELP)]
+    pub l2_da_validator: Option<Address>,
+}
+
+impl ValidiumTypeArgs {
+    /// Returns `None` for rollup chains.
+    pub fn fill_values_with_prompt(self, config: &ChainConfig) -> Option<ValidiumType> {
+        if config.l1_batch_commit_data_generator_mode == L1BatchCommitmentMode::Rollup {
+            if self.validium_type.is_some() {
+                logger::warn(msg_validium_type_ignored_for_rollup(&config.name));
+            }
+            return None;
+        }
+
+        let validium_type = self.validium_type.unwrap_or_else(|| {
+            PromptSelect::new(
+                &msg_validium_type_prompt(&config.name),
+                ValidiumTypeInternal::iter(),
+            )
+            .ask()
+        });
+
+        Some(match validium_type {
+            ValidiumTypeInternal::NoDA => ValidiumType::NoDA,
+            ValidiumTypeInternal::Avail => {
+                let (client, secrets) = fill_avail_config(
+                    self.avail_api_node_url,
+                    self.avail_app_id,
+                    self.avail_bridge_api_url,
+                    self.avail_seed_phrase,
+                );
+                // Avail attestations are verified by dedicated DA validators, so they must be set explicitly.
+                let l1_da_validator = self
+                    .l1_da_validator
+                    .unwrap_or_else(|| Prompt::new(MSG_L1_DA_VALIDATOR_PROMPT).ask());
+                let l2_da_validator = self
+                    .l2_da_validator
+                    .unwrap_or_else(|| Prompt::new(MSG_L2_DA_VALIDATOR_PROMPT).ask());
+
+                ValidiumType::Avail(AvailDAConfig {
+                    client,
+                    secrets,
+                    l1_da_validator,
+                    l2_da_validator,
+                })
+            }
+        })
+    }
+}
+
+/// Builds the Avail full client config, prompting for the values that are not provided.
+pub fn fill_avail_config(
+    api_node_url: Option<Url>,
+    app_id: Option<u32>,
+    bridge_api_url: Option<Url>,
+    seed_phrase: Option<String>,
+) -> (AvailConfig, AvailSecrets) {
+    let api_node_url = api_node_url.unwrap_or_else(|| prompt_url(MSG_AVAIL_API_NODE_URL_PROMPT));
+    let app_id = app_id.unwrap_or_else(|| Prompt::new(MSG_AVAIL_APP_ID_PROMPT).ask());
+    let bridge_api_url =
+        bridge_api_url.unwrap_or_else(|| prompt_url(MSG_AVAIL_BRIDGE_API_URL_PROMPT));
+    let seed_phrase =
+        seed_phrase.unwrap_or_else(|| Prompt::new(MSG_AVAIL_SEED_PHRASE_PROMPT).ask());
+
+    let client = AvailConfig {
+        bridge_api_url: bridge_api_url.to_string(),
+        timeout_ms: AVAIL_DEFAULT_TIMEOUT_MS,
+        config: AvailClientConfig::FullClient(AvailDefaultConfig {
+            api_node_url: api_node_url.to_string(),
+            app_id,
+        }),
+    };
+    let secrets = AvailSecrets {
+        seed_phrase: Some(SeedPhrase(Secret::new(seed_phrase))),
+        gas_relay_api_key: None,
+    };
+    (client, secrets)
+}
+
+pub(crate) fn prompt_url(prompt: &str) -> Url {
+    Prompt::new(prompt)
+        .validate_with(|val: &String| -> Result<(), String> {
+            Url::parse(val)
+                .map(|_| ())
+                .map_err(|_| MSG_INVALID_URL_ERR.to_string())
+        })
+        .ask()
+}
+
+/// Validium type specified on the ecosystem level, either for all validium chains or for a single chain
+/// (in the `<chain>=<type>` form).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainValidiumType {
+    pub chain_name: Option<String>,
+    pub validium_type: ValidiumTypeInternal,
+}
+
+impl FromStr for ChainValidiumType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (chain_name, validium_type) = match s.split_once('=') {
+            Some((chain_name, validium_type)) => {
+                (Some(chain_name.trim().to_owned()), validium_type)
+            }
+            None => (None, s),
+        };
+        Ok(Self {
+            chain_name,
+            validium_type: ValidiumTypeInternal::from_str(validium_type.trim(), true)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_chain_validium_type() {
+        assert_eq!(
+            "avail".parse::<ChainValidiumType>().unwrap(),
+            ChainValidiumType {
+                chain_name: None,
+                validium_type: ValidiumTypeInternal::Avail,
+            }
+        );
+        assert_eq!(
+            "era_test=no-da".parse::<ChainValidiumType>().unwrap(),
+            ChainValidiumType {
+                chain_name: Some("era_test".to_owned()),
+                validium_type: ValidiumTypeInternal::NoDA,
+            }
+        );
+        "era_test=celestia"
+            .parse::<ChainValidiumType>()
+            .unwrap_err();
+    }
+}
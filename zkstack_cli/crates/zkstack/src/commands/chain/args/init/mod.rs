@@ -6,7 +6,10 @@ use types::L1Network;
 use url::Url;
 
 use crate::{
-    commands::chain::args::genesis::{GenesisArgs, GenesisArgsFinal},
+    commands::chain::args::{
+        genesis::{GenesisArgs, GenesisArgsFinal},
+        init::da_configs::{ValidiumType, ValidiumTypeArgs, ValidiumTypeInternal},
+    },
     defaults::LOCAL_RPC_URL,
     messages::{
         MSG_DEPLOY_PAYMASTER_PROMPT, MSG_DEV_ARG_HELP, MSG_L1_RPC_URL_HELP,
@@ -16,6 +19,7 @@ use crate::{
 };
 
 pub mod configs;
+pub mod da_configs;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Parser)]
 pub struct InitArgs {
@@ -37,6 +41,9 @@ pub struct InitArgs {
     pub no_port_reallocation: bool,
     #[clap(long, help = MSG_DEV_ARG_HELP)]
     pub dev: bool,
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub validium_args: ValidiumTypeArgs,
 }
 
 impl InitArgs {
@@ -80,6 +87,13 @@ impl InitArgs {
             })
         };
 
+        let mut validium_args = self.validium_args;
+        if self.dev {
+            validium_args
+                .validium_type
+                .get_or_insert(ValidiumTypeInternal::NoDA);
+        }
+
         InitArgsFinal {
             forge_args: self.forge_args,
             genesis_args: genesis.fill_values_with_prompt(config),
@@ -87,6 +101,7 @@ impl InitArgs {
             l1_rpc_url,
             no_port_reallocation: self.no_port_reallocation,
             dev: self.dev,
+            validium_config: validium_args.fill_values_with_prompt(config),
         }
     }
 }
@@ -99,4 +114,7 @@ pub struct InitArgsFinal {
     pub l1_rpc_url: String,
     pub no_port_reallocation: bool,
     pub dev: bool,
+    /// `None` for rollup chains. Not serialized since it contains secrets.
+    #[serde(skip)]
+    pub validium_config: Option<ValidiumType>,
 }
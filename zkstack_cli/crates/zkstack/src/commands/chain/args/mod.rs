@@ -1,4 +1,13 @@
+pub mod add_remote;
+pub mod backup;
 pub mod build_transactions;
+pub mod convert_da_mode;
 pub mod create;
+pub mod diff_config;
+pub mod expose;
 pub mod genesis;
 pub mod init;
+pub mod monitor;
+pub mod rotate_wallets;
+pub mod set_da;
+pub mod upgrade;
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::messages::{MSG_MONITOR_ONCE_HELP, MSG_MONITOR_REFRESH_HELP};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct MonitorArgs {
+    #[clap(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..), help = MSG_MONITOR_REFRESH_HELP)]
+    pub refresh: u64,
+    #[clap(long, help = MSG_MONITOR_ONCE_HELP)]
+    pub once: bool,
+}
+
+impl MonitorArgs {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh)
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use clap::Parser;
+use common::{Prompt, PromptConfirm};
+use ethers::{signers::LocalWallet, types::H256};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::messages::{
+    msg_rotate_wallet_prompt, MSG_ROTATE_BLOB_OPERATOR_HELP, MSG_ROTATE_FEE_ACCOUNT_HELP,
+    MSG_ROTATE_GENERATE_KEY_PROMPT, MSG_ROTATE_NOTHING_SELECTED_ERR, MSG_ROTATE_OPERATOR_HELP,
+    MSG_ROTATE_PRIVATE_KEY_HELP, MSG_ROTATE_PRIVATE_KEY_PROMPT,
+    MSG_ROTATE_SKIP_VALIDATOR_UPDATE_HELP, MSG_ROTATE_VERIFY_HELP, MSG_ROTATE_VERIFY_TIMEOUT_HELP,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum WalletRole {
+    #[strum(serialize = "operator")]
+    Operator,
+    #[strum(serialize = "blob operator")]
+    BlobOperator,
+    #[strum(serialize = "fee account")]
+    FeeAccount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct RotateWalletsArgs {
+    #[clap(long, help = MSG_ROTATE_OPERATOR_HELP)]
+    pub operator: bool,
+    #[clap(long, help = MSG_ROTATE_BLOB_OPERATOR_HELP)]
+    pub blob_operator: bool,
+    #[clap(long, help = MSG_ROTATE_FEE_ACCOUNT_HELP)]
+    pub fee_account: bool,
+    #[clap(long, help = MSG_ROTATE_PRIVATE_KEY_HELP)]
+    pub operator_private_key: Option<H256>,
+    #[clap(long, help = MSG_ROTATE_PRIVATE_KEY_HELP)]
+    pub blob_operator_private_key: Option<H256>,
+    #[clap(long, help = MSG_ROTATE_PRIVATE_KEY_HELP)]
+    pub fee_account_private_key: Option<H256>,
+    #[clap(long, help = MSG_ROTATE_SKIP_VALIDATOR_UPDATE_HELP)]
+    pub skip_validator_update: bool,
+    #[clap(long, help = MSG_ROTATE_VERIFY_HELP)]
+    pub verify: bool,
+    #[clap(long, default_value_t = 600, help = MSG_ROTATE_VERIFY_TIMEOUT_HELP)]
+    pub verify_timeout: u64,
+}
+
+impl RotateWalletsArgs {
+    fn selected(&self, role: WalletRole) -> bool {
+        match role {
+            WalletRole::Operator => self.operator || self.operator_private_key.is_some(),
+            WalletRole::BlobOperator => {
+                self.blob_operator || self.blob_operator_private_key.is_some()
+            }
+            WalletRole::FeeAccount => self.fee_account || self.fee_account_private_key.is_some(),
+        }
+    }
+
+    fn private_key(&self, role: WalletRole) -> Option<H256> {
+        match role {
+            WalletRole::Operator => self.operator_private_key,
+            WalletRole::BlobOperator => self.blob_operator_private_key,
+            WalletRole::FeeAccount => self.fee_account_private_key,
+        }
+    }
+
+    pub fn fill_values_with_prompt(self) -> anyhow::Result<RotateWalletsArgsFinal> {
+        // If no wallets are selected explicitly, ask about each of them.
+        let ask_for_roles = WalletRole::iter().all(|role| !self.selected(role));
+        let mut wallets = vec![];
+        for role in WalletRole::iter() {
+            let selected = if ask_for_roles {
+                PromptConfirm::new(msg_rotate_wallet_prompt(role))
+                    .default(role != WalletRole::FeeAccount)
+                    .ask()
+            } else {
+                self.selected(role)
+            };
+            if !selected {
+                continue;
+            }
+
+            let private_key = match self.private_key(role) {
+                Some(key) => Some(key),
+                None if ask_for_roles
+                    && !PromptConfirm::new(MSG_ROTATE_GENERATE_KEY_PROMPT)
+                        .default(true)
+                        .ask() =>
+                {
+                    Some(Prompt::new(MSG_ROTATE_PRIVATE_KEY_PROMPT).ask())
+                }
+                None => None,
+            };
+            let private_key = private_key
+                .map(|key| LocalWallet::from_bytes(key.as_bytes()))
+                .transpose()?;
+            wallets.push((role, private_key));
+        }
+        anyhow::ensure!(!wallets.is_empty(), MSG_ROTATE_NOTHING_SELECTED_ERR);
+
+        Ok(RotateWalletsArgsFinal {
+            wallets,
+            skip_validator_update: self.skip_validator_update,
+            verify_timeout: self
+                .verify
+                .then(|| Duration::from_secs(self.verify_timeout)),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RotateWalletsArgsFinal {
+    /// Rotated wallets together with imported private keys. If a key is not specified, it is generated.
+    pub wallets: Vec<(WalletRole, Option<LocalWallet>)>,
+    pub skip_validator_update: bool,
+    /// Set if the command should wait until the server uses the rotated wallets.
+    pub verify_timeout: Option<Duration>,
+}
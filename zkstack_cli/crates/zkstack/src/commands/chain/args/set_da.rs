@@ -0,0 +1,294 @@
+use clap::{Parser, ValueEnum};
+use common::{forge::ForgeScriptArgs, Prompt, PromptSelect};
+use secrecy::Secret;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+use types::L1BatchCommitmentMode;
+use url::Url;
+use zksync_basic_types::{pubdata_da::PubdataSendingMode, secrets::PrivateKey, Address};
+use zksync_config::{
+    configs::{
+        da_client::{celestia::CelestiaSecrets, eigen::EigenSecrets},
+        secrets::DataAvailabilitySecrets,
+    },
+    CelestiaConfig, DAClientConfig, EigenConfig,
+};
+
+use crate::{
+    commands::chain::args::init::da_configs::{fill_avail_config, prompt_url},
+    defaults::{CELESTIA_DEFAULT_TIMEOUT_MS, EIGEN_DEFAULT_INCLUSION_POLLING_INTERVAL_MS},
+    messages::{
+        msg_chain_already_uses_da_mode_err, MSG_AVAIL_API_NODE_URL_HELP, MSG_AVAIL_APP_ID_HELP,
+        MSG_AVAIL_BRIDGE_API_URL_HELP, MSG_AVAIL_SEED_PHRASE_HELP, MSG_CELESTIA_API_NODE_URL_HELP,
+        MSG_CELESTIA_API_NODE_URL_PROMPT, MSG_CELESTIA_CHAIN_ID_HELP, MSG_CELESTIA_CHAIN_ID_PROMPT,
+        MSG_CELESTIA_NAMESPACE_HELP, MSG_CELESTIA_NAMESPACE_PROMPT, MSG_CELESTIA_PRIVATE_KEY_HELP,
+        MSG_CELESTIA_PRIVATE_KEY_PROMPT, MSG_EIGEN_PRIVATE_KEY_HELP, MSG_EIGEN_PRIVATE_KEY_PROMPT,
+        MSG_EIGEN_RPC_NODE_URL_HELP, MSG_EIGEN_RPC_NODE_URL_PROMPT, MSG_L1_DA_VALIDATOR_HELP,
+        MSG_L1_DA_VALIDATOR_PROMPT, MSG_L2_DA_VALIDATOR_HELP, MSG_L2_DA_VALIDATOR_PROMPT,
+        MSG_SET_DA_MODE_HELP, MSG_SET_DA_MODE_PROMPT, MSG_SET_DA_RESTART_HELP,
+    },
+};
+
+/// Where the chain publishes its pubdata.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, Display, ValueEnum,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DaMode {
+    /// Rollup publishing pubdata in L1 transaction calldata.
+    Calldata,
+    /// Rollup publishing pubdata in EIP-4844 blobs.
+    Blobs,
+    /// Validium not publishing pubdata anywhere.
+    NoDa,
+    /// Validium publishing pubdata to Avail.
+    Avail,
+    /// Validium publishing pubdata to Celestia.
+    Celestia,
+    /// Validium publishing pubdata to EigenDA.
+    Eigen,
+}
+
+impl DaMode {
+    /// Determines the DA mode from the chain configs. Returns `None` for modes that cannot be set
+    /// by `set-da`, e.g. the object store DA client.
+    pub fn from_configs(
+        commitment_mode: L1BatchCommitmentMode,
+        pubdata_sending_mode: Option<PubdataSendingMode>,
+        da_client: Option<&DAClientConfig>,
+    ) -> Option<Self> {
+        match commitment_mode {
+            L1BatchCommitmentMode::Rollup => match pubdata_sending_mode {
+                Some(PubdataSendingMode::Calldata) => Some(Self::Calldata),
+                Some(PubdataSendingMode::Blobs) => Some(Self::Blobs),
+                _ => None,
+            },
+            L1BatchCommitmentMode::Validium => match da_client {
+                None => Some(Self::NoDa),
+                Some(DAClientConfig::Avail(_)) => Some(Self::Avail),
+                Some(DAClientConfig::Celestia(_)) => Some(Self::Celestia),
+                Some(DAClientConfig::Eigen(_)) => Some(Self::Eigen),
+                Some(DAClientConfig::ObjectStore(_)) => None,
+            },
+        }
+    }
+
+    pub fn commitment_mode(self) -> L1BatchCommitmentMode {
+        match self {
+            Self::Calldata | Self::Blobs => L1BatchCommitmentMode::Rollup,
+            Self::NoDa | Self::Avail | Self::Celestia | Self::Eigen => {
+                L1BatchCommitmentMode::Validium
+            }
+        }
+    }
+
+    pub fn pubdata_sending_mode(self) -> PubdataSendingMode {
+        match self {
+            Self::Calldata => PubdataSendingMode::Calldata,
+            Self::Blobs => PubdataSendingMode::Blobs,
+            Self::NoDa | Self::Avail | Self::Celestia | Self::Eigen => PubdataSendingMode::Custom,
+        }
+    }
+
+    /// Checks whether switching between the modes requires changing the DA validator pair on L1.
+    /// Rollup modes share the validators, since the L1 rollup DA validator accepts both calldata and blobs.
+    pub fn requires_new_da_validators(self, target: Self) -> bool {
+        let is_rollup = |mode: Self| mode.commitment_mode() == L1BatchCommitmentMode::Rollup;
+        !(self == target || (is_rollup(self) && is_rollup(target)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct SetDaArgs {
+    #[clap(long, value_enum, help = MSG_SET_DA_MODE_HELP)]
+    pub mode: Option<DaMode>,
+    /// All ethereum environment related arguments
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub forge_args: ForgeScriptArgs,
+    #[clap(long, help = MSG_L1_DA_VALIDATOR_HELP)]
+    pub l1_da_validator: Option<Address>,
+    #[clap(long, help = MSG_L2_DA_VALIDATOR_HELP)]
+    pub l2_da_validator: Option<Address>,
+    #[clap(long, help = MSG_AVAIL_API_NODE_URL_HELP)]
+    pub avail_api_node_url: Option<Url>,
+    #[clap(long, help = MSG_AVAIL_APP_ID_HELP)]
+    pub avail_app_id: Option<u32>,
+    #[clap(long, help = MSG_AVAIL_BRIDGE_API_URL_HELP)]
+    pub avail_bridge_api_url: Option<Url>,
+    #[clap(long, help = MSG_AVAIL_SEED_PHRASE_HELP)]
+    pub avail_seed_phrase: Option<String>,
+    #[clap(long, help = MSG_CELESTIA_API_NODE_URL_HELP)]
+    pub celestia_api_node_url: Option<Url>,
+    #[clap(long, help = MSG_CELESTIA_NAMESPACE_HELP)]
+    pub celestia_namespace: Option<String>,
+    #[clap(long, help = MSG_CELESTIA_CHAIN_ID_HELP)]
+    pub celestia_chain_id: Option<String>,
+    #[clap(long, help = MSG_CELESTIA_PRIVATE_KEY_HELP)]
+    pub celestia_private_key: Option<String>,
+    #[clap(long, help = MSG_EIGEN_RPC_NODE_URL_HELP)]
+    pub eigen_rpc_node_url: Option<Url>,
+    #[clap(long, help = MSG_EIGEN_PRIVATE_KEY_HELP)]
+    pub eigen_private_key: Option<String>,
+    #[clap(long, help = MSG_SET_DA_RESTART_HELP)]
+    pub restart: bool,
+}
+
+impl SetDaArgs {
+    /// `current_mode` is `None` if the current DA mode of the chain is unknown; in this case,
+    /// the DA validators are always updated.
+    pub fn fill_values_with_prompt(
+        self,
+        current_mode: Option<DaMode>,
+    ) -> anyhow::Result<SetDaArgsFinal> {
+        let mode = self
+            .mode
+            .unwrap_or_else(|| PromptSelect::new(MSG_SET_DA_MODE_PROMPT, DaMode::iter()).ask());
+        if current_mode == Some(mode) {
+            anyhow::bail!(msg_chain_already_uses_da_mode_err(mode));
+        }
+
+        let requires_new_da_validators = match current_mode {
+            Some(current) => current.requires_new_da_validators(mode),
+            None => true,
+        };
+        let da_validators = requires_new_da_validators.then(|| {
+            let l1_da_validator = self
+                .l1_da_validator
+                .unwrap_or_else(|| Prompt::new(MSG_L1_DA_VALIDATOR_PROMPT).ask());
+            let l2_da_validator = self
+                .l2_da_validator
+                .unwrap_or_else(|| Prompt::new(MSG_L2_DA_VALIDATOR_PROMPT).ask());
+            (l1_da_validator, l2_da_validator)
+        });
+
+        let (client, secrets) = match mode {
+            DaMode::Calldata | DaMode::Blobs | DaMode::NoDa => (None, None),
+            DaMode::Avail => {
+                let (client, secrets) = fill_avail_config(
+                    self.avail_api_node_url,
+                    self.avail_app_id,
+                    self.avail_bridge_api_url,
+                    self.avail_seed_phrase,
+                );
+                (
+                    Some(DAClientConfig::Avail(client)),
+                    Some(DataAvailabilitySecrets::Avail(secrets)),
+                )
+            }
+            DaMode::Celestia => {
+                let api_node_url = self
+                    .celestia_api_node_url
+                    .unwrap_or_else(|| prompt_url(MSG_CELESTIA_API_NODE_URL_PROMPT));
+                let namespace = self
+                    .celestia_namespace
+                    .unwrap_or_else(|| Prompt::new(MSG_CELESTIA_NAMESPACE_PROMPT).ask());
+                let chain_id = self
+                    .celestia_chain_id
+                    .unwrap_or_else(|| Prompt::new(MSG_CELESTIA_CHAIN_ID_PROMPT).ask());
+                let private_key = self
+                    .celestia_private_key
+                    .unwrap_or_else(|| Prompt::new(MSG_CELESTIA_PRIVATE_KEY_PROMPT).ask());
+                let client = CelestiaConfig {
+                    api_node_url: api_node_url.to_string(),
+                    namespace,
+                    chain_id,
+                    timeout_ms: CELESTIA_DEFAULT_TIMEOUT_MS,
+                };
+                let secrets = CelestiaSecrets {
+                    private_key: PrivateKey(Secret::new(private_key)),
+                };
+                (
+                    Some(DAClientConfig::Celestia(client)),
+                    Some(DataAvailabilitySecrets::Celestia(secrets)),
+                )
+            }
+            DaMode::Eigen => {
+                let rpc_node_url = self
+                    .eigen_rpc_node_url
+                    .unwrap_or_else(|| prompt_url(MSG_EIGEN_RPC_NODE_URL_PROMPT));
+                let private_key = self
+                    .eigen_private_key
+                    .unwrap_or_else(|| Prompt::new(MSG_EIGEN_PRIVATE_KEY_PROMPT).ask());
+                let client = EigenConfig {
+                    rpc_node_url: rpc_node_url.to_string(),
+                    inclusion_polling_interval_ms: EIGEN_DEFAULT_INCLUSION_POLLING_INTERVAL_MS,
+                };
+                let secrets = EigenSecrets {
+                    private_key: PrivateKey(Secret::new(private_key)),
+                };
+                (
+                    Some(DAClientConfig::Eigen(client)),
+                    Some(DataAvailabilitySecrets::Eigen(secrets)),
+                )
+            }
+        };
+
+        Ok(SetDaArgsFinal {
+            mode,
+            forge_args: self.forge_args,
+            da_validators,
+            client,
+            secrets,
+            restart: self.restart,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetDaArgsFinal {
+    pub mode: DaMode,
+    pub forge_args: ForgeScriptArgs,
+    /// L1 and L2 DA validators; `None` if the validators don't need to be changed.
+    pub da_validators: Option<(Address, Address)>,
+    pub client: Option<DAClientConfig>,
+    pub secrets: Option<DataAvailabilitySecrets>,
+    pub restart: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determining_da_mode_from_configs() {
+        assert_eq!(
+            DaMode::from_configs(
+                L1BatchCommitmentMode::Rollup,
+                Some(PubdataSendingMode::Blobs),
+                None
+            ),
+            Some(DaMode::Blobs)
+        );
+        assert_eq!(
+            DaMode::from_configs(
+                L1BatchCommitmentMode::Validium,
+                Some(PubdataSendingMode::Custom),
+                None
+            ),
+            Some(DaMode::NoDa)
+        );
+        let celestia = DAClientConfig::Celestia(CelestiaConfig::default());
+        assert_eq!(
+            DaMode::from_configs(
+                L1BatchCommitmentMode::Validium,
+                Some(PubdataSendingMode::Custom),
+                Some(&celestia)
+            ),
+            Some(DaMode::Celestia)
+        );
+        assert_eq!(
+            DaMode::from_configs(L1BatchCommitmentMode::Rollup, None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn da_validators_are_only_changed_between_da_layers() {
+        assert!(!DaMode::Calldata.requires_new_da_validators(DaMode::Blobs));
+        assert!(DaMode::Blobs.requires_new_da_validators(DaMode::NoDa));
+        assert!(DaMode::NoDa.requires_new_da_validators(DaMode::Avail));
+        assert!(DaMode::Avail.requires_new_da_validators(DaMode::Eigen));
+        assert!(!DaMode::Eigen.requires_new_da_validators(DaMode::Eigen));
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::protocol_version::ProtocolSemanticVersion;
+
+use crate::messages::{
+    MSG_UPGRADE_CALLDATA_ONLY_HELP, MSG_UPGRADE_DATA_HELP, MSG_UPGRADE_PROTOCOL_VERSION_HELP,
+    MSG_UPGRADE_TIMEOUT_HELP,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct ChainUpgradeArgs {
+    #[clap(long, help = MSG_UPGRADE_PROTOCOL_VERSION_HELP)]
+    pub protocol_version: Option<ProtocolSemanticVersion>,
+    #[clap(long, help = MSG_UPGRADE_DATA_HELP)]
+    pub upgrade_data: Option<PathBuf>,
+    #[clap(long, help = MSG_UPGRADE_CALLDATA_ONLY_HELP)]
+    pub calldata_only: bool,
+    #[clap(long, default_value_t = 600, help = MSG_UPGRADE_TIMEOUT_HELP)]
+    pub timeout: u64,
+}
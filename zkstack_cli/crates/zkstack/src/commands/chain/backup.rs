@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use chrono::Utc;
+use common::{
+    check_prerequisites,
+    cmd::Cmd,
+    db::{drop_db_if_exists, init_db, DatabaseConfig},
+    logger, POSTGRES_CLIENT_PREREQUISITES,
+};
+use config::{
+    set_prover_database, set_server_database, traits::SaveConfigWithBasePath, EcosystemConfig,
+    SecretsConfig, CONFIG_NAME,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use xshell::{cmd, Shell};
+use zksync_basic_types::L2ChainId;
+
+use crate::{
+    commands::chain::args::backup::{BackupArgs, RestoreArgs},
+    messages::{
+        msg_chain_backup_created, msg_chain_restored, msg_database_missing_in_secrets_err,
+        msg_dumping_database, msg_restore_chain_exists_err, msg_restore_database_exists_err,
+        msg_restoring_database, MSG_BACKUP_COPYING_FILES, MSG_BACKUP_INVALID_ARCHIVE_ERR,
+        MSG_BACKUP_SERVER_RUNNING_WARNING, MSG_CHAIN_NOT_INITIALIZED,
+    },
+};
+
+/// Name of the manifest file in the backup archive.
+const MANIFEST_FILE: &str = "backup.json";
+/// Directory in the backup archive containing chain configs.
+const CONFIGS_DIR: &str = "configs";
+/// Directory in the backup archive containing RocksDB state.
+const ROCKS_DB_DIR: &str = "db";
+/// Directory in the backup archive containing Postgres dumps.
+const POSTGRES_DIR: &str = "postgres";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+enum DatabaseKind {
+    Server,
+    Prover,
+}
+
+impl DatabaseKind {
+    fn dump_path(self) -> PathBuf {
+        Path::new(POSTGRES_DIR).join(format!("{self}.dump"))
+    }
+
+    fn url_from_secrets(self, secrets: &SecretsConfig) -> anyhow::Result<Option<Url>> {
+        let database = secrets
+            .database
+            .as_ref()
+            .with_context(|| msg_database_missing_in_secrets_err(self))?;
+        let url = match self {
+            Self::Server => Some(database.master_url()?),
+            Self::Prover => database.prover_url.clone(),
+        };
+        Ok(url.map(|url| url.expose_url().clone()))
+    }
+}
+
+/// Describes the contents of a backup archive.
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    chain_name: String,
+    chain_id: L2ChainId,
+    /// Creation time in the RFC 3339 format.
+    created_at: String,
+    databases: Vec<DatabaseKind>,
+    rocks_db: bool,
+}
+
+pub async fn run_backup(args: BackupArgs, shell: &Shell) -> anyhow::Result<()> {
+    check_prerequisites(shell, &POSTGRES_CLIENT_PREREQUISITES, false);
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let secrets = chain_config.get_secrets_config()?;
+    let created_at = Utc::now();
+    let output = args.output.unwrap_or_else(|| {
+        let timestamp = created_at.format("%Y%m%d%H%M%S");
+        PathBuf::from(format!("{}-backup-{timestamp}.tar.gz", chain_config.name))
+    });
+    let output = shell.current_dir().join(output);
+
+    logger::warn(MSG_BACKUP_SERVER_RUNNING_WARNING);
+    let staging_dir = shell.create_temp_dir()?;
+    let staging_path = staging_dir.path();
+
+    logger::info(MSG_BACKUP_COPYING_FILES);
+    shell.copy_file(
+        ecosystem_config
+            .chains
+            .join(&chain_config.name)
+            .join(CONFIG_NAME),
+        staging_path.join(CONFIG_NAME),
+    )?;
+    copy_dir(
+        shell,
+        &chain_config.configs,
+        &staging_path.join(CONFIGS_DIR),
+    )?;
+    let rocks_db = !args.skip_rocks_db && shell.path_exists(&chain_config.rocks_db_path);
+    if rocks_db {
+        copy_dir(
+            shell,
+            &chain_config.rocks_db_path,
+            &staging_path.join(ROCKS_DB_DIR),
+        )?;
+    }
+
+    let mut databases = vec![];
+    shell.create_dir(staging_path.join(POSTGRES_DIR))?;
+    for kind in [DatabaseKind::Server, DatabaseKind::Prover] {
+        if kind == DatabaseKind::Prover && args.skip_prover_db {
+            continue;
+        }
+        let Some(url) = kind.url_from_secrets(&secrets)? else {
+            continue;
+        };
+        let db = DatabaseConfig::from_url(&url)?;
+        logger::info(msg_dumping_database(kind, &db.name));
+        let dump_path = staging_path.join(kind.dump_path());
+        Cmd::new(cmd!(
+            shell,
+            "pg_dump --format=custom --no-owner --file={dump_path} --dbname={url}"
+        ))
+        .run()?;
+        databases.push(kind);
+    }
+
+    let manifest = BackupManifest {
+        chain_name: chain_config.name.clone(),
+        chain_id: chain_config.chain_id,
+        created_at: created_at.to_rfc3339(),
+        databases,
+        rocks_db,
+    };
+    shell.write_file(
+        staging_path.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    Cmd::new(cmd!(shell, "tar -czf {output} -C {staging_path} .")).run()?;
+
+    logger::outro(msg_chain_backup_created(&chain_config.name, &output));
+    Ok(())
+}
+
+pub async fn run_restore(args: RestoreArgs, shell: &Shell) -> anyhow::Result<()> {
+    check_prerequisites(shell, &POSTGRES_CLIENT_PREREQUISITES, false);
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let archive = shell.current_dir().join(&args.archive);
+    let staging_dir = shell.create_temp_dir()?;
+    let staging_path = staging_dir.path();
+    Cmd::new(cmd!(shell, "tar -xzf {archive} -C {staging_path}")).run()?;
+
+    let manifest = shell
+        .read_file(staging_path.join(MANIFEST_FILE))
+        .context(MSG_BACKUP_INVALID_ARCHIVE_ERR)?;
+    let manifest: BackupManifest =
+        serde_json::from_str(&manifest).context(MSG_BACKUP_INVALID_ARCHIVE_ERR)?;
+    let chain_name = manifest.chain_name;
+    let chain_dir = ecosystem_config.chains.join(&chain_name);
+    if shell.path_exists(&chain_dir) && !args.force {
+        anyhow::bail!(msg_restore_chain_exists_err(&chain_name));
+    }
+
+    // Chain config is restored first, so that other files are placed according to the paths it specifies.
+    shell.create_dir(&chain_dir)?;
+    shell.copy_file(staging_path.join(CONFIG_NAME), chain_dir.join(CONFIG_NAME))?;
+    let chain_config = ecosystem_config.load_chain(Some(chain_name.clone()))?;
+
+    logger::info(MSG_BACKUP_COPYING_FILES);
+    shell.remove_path(&chain_config.configs)?;
+    copy_dir(
+        shell,
+        &staging_path.join(CONFIGS_DIR),
+        &chain_config.configs,
+    )?;
+    if manifest.rocks_db {
+        shell.remove_path(&chain_config.rocks_db_path)?;
+        copy_dir(
+            shell,
+            &staging_path.join(ROCKS_DB_DIR),
+            &chain_config.rocks_db_path,
+        )?;
+    }
+
+    let mut secrets = chain_config.get_secrets_config()?;
+    if let Some(url) = &args.server_db_url {
+        set_server_database(&mut secrets, &DatabaseConfig::from_url(url)?)?;
+    }
+    if let Some(url) = &args.prover_db_url {
+        set_prover_database(&mut secrets, &DatabaseConfig::from_url(url)?)?;
+    }
+    secrets.save_with_base_path(shell, &chain_config.configs)?;
+
+    for kind in manifest.databases {
+        let url = kind
+            .url_from_secrets(&secrets)?
+            .with_context(|| msg_database_missing_in_secrets_err(kind))?;
+        let db = DatabaseConfig::from_url(&url)?;
+        logger::info(msg_restoring_database(kind, &db.name));
+        if args.force {
+            drop_db_if_exists(&db).await?;
+        }
+        init_db(&db)
+            .await
+            .with_context(|| msg_restore_database_exists_err(&db.name))?;
+        let dump_path = staging_path.join(kind.dump_path());
+        Cmd::new(cmd!(
+            shell,
+            "pg_restore --no-owner --dbname={url} {dump_path}"
+        ))
+        .run()?;
+    }
+
+    logger::outro(msg_chain_restored(&chain_name));
+    Ok(())
+}
+
+fn copy_dir(shell: &Shell, from: &Path, to: &Path) -> anyhow::Result<()> {
+    Cmd::new(cmd!(shell, "cp -R {from} {to}")).run()?;
+    Ok(())
+}
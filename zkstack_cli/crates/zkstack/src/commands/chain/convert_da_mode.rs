@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use common::{cmd::Cmd, forge::ForgeScriptArgs, logger, spinner::Spinner};
+use config::{
+    external_node::ENConfig,
+    override_config,
+    traits::{
+        ConfigWithL2RpcUrl, FileConfigWithDefaultName, ReadConfigWithBasePath,
+        SaveConfigWithBasePath,
+    },
+    ChainConfig, EcosystemConfig, GeneralConfig,
+};
+use ethers::{
+    contract::abigen,
+    providers::{Http, Provider},
+};
+use types::L1BatchCommitmentMode;
+use xshell::{cmd, Shell};
+use zksync_basic_types::{Address, L1BatchNumber, U64};
+
+use crate::{
+    accept_ownership::set_da_validator_pair,
+    commands::chain::args::convert_da_mode::ConvertDaModeArgs,
+    consts::{PATH_TO_ROLLUP_OVERRIDE_CONFIG, PATH_TO_VALIDIUM_OVERRIDE_CONFIG},
+    messages::{
+        msg_chain_already_in_da_mode_err, msg_chain_converted_to_da_mode,
+        msg_stopped_chain_components, msg_uncommitted_batches_err, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_CHECKING_COMMITTED_BATCHES_SPINNER, MSG_L1_SECRETS_MUST_BE_PRESENTED,
+        MSG_NO_RUNNING_CHAIN_COMPONENTS, MSG_START_CHAIN_COMPONENTS_NOTE,
+        MSG_UPDATING_DA_VALIDATORS_SPINNER,
+    },
+};
+
+abigen!(
+    ZkChainGetters,
+    r"[
+    function getTotalBatchesCommitted() external view returns (uint256)
+    ]"
+);
+
+pub async fn run(
+    args: ConvertDaModeArgs,
+    mode: L1BatchCommitmentMode,
+    shell: &Shell,
+) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let mut chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    if chain_config.l1_batch_commit_data_generator_mode == mode {
+        anyhow::bail!(msg_chain_already_in_da_mode_err(mode));
+    }
+    let args = args.fill_values_with_prompt();
+
+    let transition_batch = update_da_validators(
+        shell,
+        &ecosystem_config,
+        &chain_config,
+        args.l1_da_validator,
+        args.l2_da_validator,
+        &args.forge_args,
+    )
+    .await?;
+    update_commitment_mode(shell, &mut chain_config, mode, transition_batch)?;
+    let chain_path = ecosystem_config.chains.join(&chain_config.name);
+    chain_config.save_with_base_path(shell, chain_path)?;
+
+    // Batches sealed by the running components would use the old commitment mode and fail L1 commit,
+    // so the components are stopped right away. Configs are updated beforehand, so that a supervisor
+    // restarting the components (e.g., `zkstack run-all --restart always`) picks up the new mode.
+    logger::info(msg_chain_converted_to_da_mode(mode, transition_batch));
+    match stop_chain_components(shell, &chain_config)? {
+        0 => logger::warn(MSG_NO_RUNNING_CHAIN_COMPONENTS),
+        count => logger::info(msg_stopped_chain_components(count)),
+    }
+    logger::outro(MSG_START_CHAIN_COMPONENTS_NOTE);
+    Ok(())
+}
+
+/// Sends SIGTERM to the server and external nodes of the chain, i.e. to the processes launched with
+/// one of the chain general configs. Returns the number of signaled processes.
+pub(super) fn stop_chain_components(
+    shell: &Shell,
+    chain_config: &ChainConfig,
+) -> anyhow::Result<usize> {
+    let mut general_config_paths = vec![chain_config.path_to_general_config()];
+    if let Some(en_configs_path) = &chain_config.external_node_config_path {
+        general_config_paths.push(GeneralConfig::get_path_with_base_path(en_configs_path));
+    }
+
+    let mut pids = vec![];
+    for path in general_config_paths {
+        let pattern = format!("--config-path {}", path.display());
+        // `pgrep` exits with a non-zero status if there are no matching processes.
+        let output = cmd!(shell, "pgrep -f -- {pattern}")
+            .ignore_status()
+            .read()?;
+        pids.extend(output.lines().map(str::to_owned));
+    }
+    if !pids.is_empty() {
+        Cmd::new(cmd!(shell, "kill -TERM {pids...}")).run()?;
+    }
+    Ok(pids.len())
+}
+
+/// Sets the DA validator pair of the chain on L1. Returns the first L1 batch that will be committed
+/// using the new validators, as observed on L1 after the validator pair is updated.
+pub(super) async fn update_da_validators(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+    chain_config: &ChainConfig,
+    l1_da_validator: Address,
+    l2_da_validator: Address,
+    forge_args: &ForgeScriptArgs,
+) -> anyhow::Result<L1BatchNumber> {
+    let contracts_config = chain_config.get_contracts_config()?;
+    let l1_url = chain_config
+        .get_secrets_config()?
+        .l1
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_string();
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+
+    // Batch commitments depend on the DA mode, so a batch sealed before the switch must be committed before
+    // the DA validators change; otherwise, it would be committed in a mode that doesn't match its contents.
+    let spinner = Spinner::new(MSG_CHECKING_COMMITTED_BATCHES_SPINNER);
+    let l1_provider = Arc::new(Provider::<Http>::try_from(l1_url.as_str())?);
+    let zk_chain = ZkChainGetters::new(contracts_config.l1.diamond_proxy_addr, l1_provider);
+    let l2_provider = Provider::<Http>::try_from(l2_rpc_url.as_str())?;
+    ensure_all_batches_committed(&zk_chain, &l2_provider).await?;
+    spinner.finish();
+
+    let spinner = Spinner::new(MSG_UPDATING_DA_VALIDATORS_SPINNER);
+    set_da_validator_pair(
+        shell,
+        ecosystem_config,
+        contracts_config.l1.chain_admin_addr,
+        &chain_config.get_wallets_config()?.governor,
+        contracts_config.l1.diamond_proxy_addr,
+        l1_da_validator,
+        l2_da_validator,
+        forge_args,
+        l1_url,
+    )
+    .await?;
+    spinner.finish();
+
+    // The server may have sealed and committed more batches while the validator pair was being updated,
+    // so the transition batch is determined from the L1 state after the update rather than before it.
+    // If a batch was sealed but not yet committed during the update, it's impossible to tell which mode
+    // it will be committed in, so we bail and let the operator retry once the server has caught up.
+    let last_committed_batch = ensure_all_batches_committed(&zk_chain, &l2_provider).await?;
+    Ok(L1BatchNumber(last_committed_batch + 1))
+}
+
+/// Checks that all L1 batches sealed by the server are committed on L1. Returns the last committed batch.
+async fn ensure_all_batches_committed(
+    zk_chain: &ZkChainGetters<Provider<Http>>,
+    l2_provider: &Provider<Http>,
+) -> anyhow::Result<u32> {
+    let last_committed_batch = zk_chain
+        .get_total_batches_committed()
+        .call()
+        .await
+        .context("getTotalBatchesCommitted()")?
+        .as_u32();
+    let last_sealed_batch: U64 = l2_provider
+        .request("zks_L1BatchNumber", ())
+        .await
+        .context("zks_L1BatchNumber")?;
+    let last_sealed_batch = last_sealed_batch.as_u32();
+    if last_sealed_batch > last_committed_batch {
+        anyhow::bail!(msg_uncommitted_batches_err(
+            last_sealed_batch,
+            last_committed_batch
+        ));
+    }
+    Ok(last_committed_batch)
+}
+
+/// Updates the commitment mode in the genesis and external node configs and applies the corresponding general
+/// config overrides. The chain config is only updated in memory; it's the caller's responsibility to save it.
+pub(super) fn update_commitment_mode(
+    shell: &Shell,
+    chain_config: &mut ChainConfig,
+    mode: L1BatchCommitmentMode,
+    transition_batch: L1BatchNumber,
+) -> anyhow::Result<()> {
+    chain_config.l1_batch_commit_data_generator_mode = mode;
+    push_commitment_mode_transition(
+        &mut chain_config.commitment_mode_transition_batches,
+        transition_batch,
+    );
+
+    let mut genesis_config = chain_config.get_genesis_config()?;
+    genesis_config.l1_batch_commit_data_generator_mode = mode;
+    genesis_config.save_with_base_path(shell, &chain_config.configs)?;
+
+    let override_config_path = match mode {
+        L1BatchCommitmentMode::Rollup => PATH_TO_ROLLUP_OVERRIDE_CONFIG,
+        L1BatchCommitmentMode::Validium => PATH_TO_VALIDIUM_OVERRIDE_CONFIG,
+    };
+    override_config(
+        shell,
+        chain_config.link_to_code.join(override_config_path),
+        chain_config,
+    )?;
+
+    if let Some(en_configs_path) = &chain_config.external_node_config_path {
+        if shell.path_exists(en_configs_path.join(ENConfig::FILE_NAME)) {
+            let mut en_config = ENConfig::read_with_base_path(shell, en_configs_path)?;
+            en_config.l1_batch_commit_data_generator_mode = mode;
+            push_commitment_mode_transition(
+                &mut en_config.commitment_mode_transition_batches,
+                transition_batch,
+            );
+            en_config.save_with_base_path(shell, en_configs_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Records a commitment mode transition, keeping transitions of all previous conversions.
+fn push_commitment_mode_transition(transitions: &mut Vec<L1BatchNumber>, batch: L1BatchNumber) {
+    // A conversion without any batches committed in between doesn't produce a new transition;
+    // instead, it reverts the previous one.
+    if transitions.last() == Some(&batch) {
+        transitions.pop();
+    } else {
+        transitions.push(batch);
+    }
+}
@@ -1,4 +1,4 @@
-use std::cell::OnceCell;
+use std::{cell::OnceCell, path::PathBuf};
 
 use anyhow::Context;
 use common::{logger, spinner::Spinner};
@@ -7,6 +7,8 @@ use config::{
     traits::{ReadConfigWithBasePath, SaveConfigWithBasePath},
     ChainConfig, EcosystemConfig, GenesisConfig,
 };
+use ethers::types::Address;
+use serde::Serialize;
 use xshell::Shell;
 use zksync_basic_types::L2ChainId;
 
@@ -20,6 +22,19 @@ use crate::{
     utils::link_to_code::resolve_link_to_code,
 };
 
+/// Result of the command printed in the JSON output mode.
+#[derive(Debug, Serialize)]
+struct ChainCreateOutput {
+    name: String,
+    chain_id: L2ChainId,
+    chain_path: PathBuf,
+    configs_path: PathBuf,
+    operator: Address,
+    blob_operator: Address,
+    fee_account: Address,
+    governor: Address,
+}
+
 pub fn run(args: ChainCreateArgs, shell: &Shell) -> anyhow::Result<()> {
     let mut ecosystem_config = EcosystemConfig::from_file(shell)?;
     create(args, &mut ecosystem_config, shell)
@@ -48,13 +63,25 @@ fn create(
     let set_as_default = args.set_as_default;
     create_chain_inner(args, ecosystem_config, shell)?;
     if set_as_default {
-        ecosystem_config.default_chain = name;
+        ecosystem_config.default_chain = name.clone();
         ecosystem_config.save_with_base_path(shell, ".")?;
     }
     spinner.finish();
 
     logger::success(MSG_CHAIN_CREATED);
 
+    let chain_config = ecosystem_config.load_chain(Some(name.clone()))?;
+    let wallets = chain_config.get_wallets_config()?;
+    logger::result(ChainCreateOutput {
+        chain_path: ecosystem_config.chains.join(&name),
+        name,
+        chain_id: chain_config.chain_id,
+        configs_path: chain_config.configs.clone(),
+        operator: wallets.operator.address,
+        blob_operator: wallets.blob_operator.address,
+        fee_account: wallets.fee_account.address,
+        governor: wallets.governor.address,
+    });
     Ok(())
 }
 
@@ -98,11 +125,13 @@ pub(crate) fn create_chain_inner(
         configs: chain_configs_path.clone(),
         external_node_config_path: None,
         l1_batch_commit_data_generator_mode: args.l1_batch_commit_data_generator_mode,
+        commitment_mode_transition_batches: vec![],
         base_token: args.base_token,
         wallet_creation: args.wallet_creation,
         shell: OnceCell::from(shell.clone()),
         legacy_bridge,
         evm_emulator: args.evm_emulator,
+        server_presets: Default::default(),
     };
 
     create_wallets(
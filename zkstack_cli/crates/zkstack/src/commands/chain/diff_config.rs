@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use common::logger;
+use config::EcosystemConfig;
+use serde_json::Value;
+use xshell::Shell;
+use zksync_protobuf_config::{encode_json_repr, proto};
+
+use crate::{
+    commands::chain::args::diff_config::DiffConfigArgs,
+    messages::{
+        msg_config_drift_detected, msg_config_value_added, msg_config_value_changed,
+        msg_config_value_removed, msg_fetching_effective_config, msg_fetching_effective_config_err,
+        MSG_API_CONFIG_MISSING, MSG_CHAIN_NOT_INITIALIZED, MSG_CONFIG_IN_SYNC,
+        MSG_CONFIG_RESTART_REQUIRED,
+    },
+};
+
+/// Difference between the config loaded by the running server and the on-disk config for a single value.
+#[derive(Debug, PartialEq)]
+enum ValueDiff<'a> {
+    Changed {
+        running: &'a Value,
+        on_disk: &'a Value,
+    },
+    Added {
+        on_disk: &'a Value,
+    },
+    Removed {
+        running: &'a Value,
+    },
+}
+
+pub async fn run(args: DiffConfigArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let mut general_config = chain_config.get_general_config()?;
+
+    let url = match args.url {
+        Some(url) => url,
+        None => {
+            let health_check_port = general_config
+                .api_config
+                .as_ref()
+                .context(MSG_API_CONFIG_MISSING)?
+                .healthcheck
+                .port;
+            format!("http://localhost:{health_check_port}/config")
+        }
+    };
+    logger::info(msg_fetching_effective_config(&url));
+    let response = reqwest::get(&url)
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .with_context(|| msg_fetching_effective_config_err(&url))?
+        .text()
+        .await?;
+    let running_config: Value =
+        serde_json::from_str(&response).context("failed parsing effective config")?;
+
+    // The server redacts secrets, so they need to be redacted on our side as well to be comparable.
+    general_config.redact_secrets();
+    let on_disk_config = encode_json_repr::<proto::general::GeneralConfig>(&general_config)?;
+
+    let running_values = flatten(&running_config);
+    let on_disk_values = flatten(&on_disk_config);
+    let diffs = diff_values(&running_values, &on_disk_values);
+    if diffs.is_empty() {
+        logger::success(MSG_CONFIG_IN_SYNC);
+        return Ok(());
+    }
+
+    let diff_lines: Vec<_> = diffs
+        .iter()
+        .map(|(path, diff)| match diff {
+            ValueDiff::Changed { running, on_disk } => {
+                msg_config_value_changed(path, running, on_disk)
+            }
+            ValueDiff::Added { on_disk } => msg_config_value_added(path, on_disk),
+            ValueDiff::Removed { running } => msg_config_value_removed(path, running),
+        })
+        .collect();
+    logger::note(
+        msg_config_drift_detected(diffs.len(), &chain_config.path_to_general_config()),
+        diff_lines.join("\n"),
+    );
+    // The server reads its config only on startup, so all drifted values require a restart to apply.
+    logger::warn(MSG_CONFIG_RESTART_REQUIRED);
+    Ok(())
+}
+
+/// Flattens a JSON object into a map from dot-separated paths to leaf values. Arrays are treated as leaves.
+fn flatten(value: &Value) -> BTreeMap<String, &Value> {
+    fn flatten_inner<'a>(prefix: &str, value: &'a Value, output: &mut BTreeMap<String, &'a Value>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    flatten_inner(&path, value, output);
+                }
+            }
+            _ => {
+                output.insert(prefix.to_owned(), value);
+            }
+        }
+    }
+
+    let mut output = BTreeMap::new();
+    flatten_inner("", value, &mut output);
+    output
+}
+
+fn diff_values<'a>(
+    running: &BTreeMap<String, &'a Value>,
+    on_disk: &BTreeMap<String, &'a Value>,
+) -> BTreeMap<String, ValueDiff<'a>> {
+    let mut diffs = BTreeMap::new();
+    for (path, &running_value) in running {
+        match on_disk.get(path) {
+            Some(&on_disk_value) if on_disk_value == running_value => { /* no diff */ }
+            Some(&on_disk_value) => {
+                let diff = ValueDiff::Changed {
+                    running: running_value,
+                    on_disk: on_disk_value,
+                };
+                diffs.insert(path.clone(), diff);
+            }
+            None => {
+                let diff = ValueDiff::Removed {
+                    running: running_value,
+                };
+                diffs.insert(path.clone(), diff);
+            }
+        }
+    }
+    for (path, &on_disk_value) in on_disk {
+        if !running.contains_key(path) {
+            let diff = ValueDiff::Added {
+                on_disk: on_disk_value,
+            };
+            diffs.insert(path.clone(), diff);
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn diffing_configs() {
+        let running = json!({
+            "api": { "web3_json_rpc": { "http_port": 3050, "filters_limit": 10000 } },
+            "state_keeper": { "block_commit_deadline_ms": 2500, "fee_account_addr": "0x01" },
+            "observability": { "log_format": "plain" },
+        });
+        let on_disk = json!({
+            "api": { "web3_json_rpc": { "http_port": 3060, "filters_limit": 10000 } },
+            "state_keeper": { "block_commit_deadline_ms": 2500, "fee_account_addr": "0x01" },
+            "pruning": { "enabled": true },
+        });
+
+        let running_values = flatten(&running);
+        let on_disk_values = flatten(&on_disk);
+        let diffs = diff_values(&running_values, &on_disk_values);
+        assert_eq!(diffs.len(), 3, "{diffs:?}");
+        assert_eq!(
+            diffs["api.web3_json_rpc.http_port"],
+            ValueDiff::Changed {
+                running: &json!(3050),
+                on_disk: &json!(3060)
+            }
+        );
+        assert_eq!(
+            diffs["observability.log_format"],
+            ValueDiff::Removed {
+                running: &json!("plain")
+            }
+        );
+        assert_eq!(
+            diffs["pruning.enabled"],
+            ValueDiff::Added {
+                on_disk: &json!(true)
+            }
+        );
+
+        assert!(diff_values(&running_values, &running_values).is_empty());
+    }
+}
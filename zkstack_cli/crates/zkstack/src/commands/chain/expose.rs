@@ -0,0 +1,197 @@
+use std::path::Path;
+
+use anyhow::Context;
+use common::{docker, logger};
+use config::EcosystemConfig;
+use xshell::Shell;
+
+use crate::{
+    commands::chain::args::expose::ExposeArgs,
+    consts::{
+        EXPOSE_PROXY_DOCKER_CERTS_DIR, EXPOSE_PROXY_DOCKER_CONFIG_DIR, EXPOSE_PROXY_DOCKER_IMAGE,
+    },
+    messages::{
+        msg_expose_config_saved, msg_expose_starting, MSG_API_CONFIG_MISSING,
+        MSG_CHAIN_NOT_INITIALIZED, MSG_EXPOSE_FAILED_TO_RUN_DOCKER_ERR,
+    },
+};
+
+/// Directory in the chain configs where the reverse proxy config is stored.
+const EXPOSE_CONFIG_DIR: &str = "expose";
+
+/// Parameters of the generated reverse proxy config.
+#[derive(Debug)]
+struct ProxyConfig<'a> {
+    domain: &'a str,
+    http_port: u16,
+    ws_port: u16,
+    rate_limit: u32,
+}
+
+impl ProxyConfig<'_> {
+    /// Renders an nginx server config. Certificate paths follow the layout used by certbot, which issues
+    /// certificates for each `server_name` in the config on the container start.
+    fn render(&self) -> String {
+        let Self {
+            domain,
+            http_port,
+            ws_port,
+            rate_limit,
+        } = self;
+        // Allow short bursts, e.g. for wallets sending several requests on page load.
+        let burst = rate_limit * 2;
+        format!(
+            r#"# Generated by `zkstack chain expose`; re-run the command to update it.
+limit_req_zone $binary_remote_addr zone=zksync_rpc:10m rate={rate_limit}r/s;
+
+upstream zksync_http {{
+    server host.docker.internal:{http_port};
+}}
+
+upstream zksync_ws {{
+    server host.docker.internal:{ws_port};
+}}
+
+# WebSocket connections are proxied to the WS server, all other requests to the HTTP server.
+map $http_upgrade $zksync_upstream {{
+    default zksync_http;
+    ~*^websocket$ zksync_ws;
+}}
+
+map $http_upgrade $connection_upgrade {{
+    default upgrade;
+    '' close;
+}}
+
+server {{
+    listen 443 ssl;
+    listen [::]:443 ssl;
+    server_name {domain};
+
+    ssl_certificate /etc/letsencrypt/live/{domain}/fullchain.pem;
+    ssl_certificate_key /etc/letsencrypt/live/{domain}/privkey.pem;
+    ssl_trusted_certificate /etc/letsencrypt/live/{domain}/chain.pem;
+
+    client_max_body_size 10m;
+
+    location / {{
+        limit_req zone=zksync_rpc burst={burst} nodelay;
+        limit_req_status 429;
+
+        proxy_pass http://$zksync_upstream;
+        proxy_http_version 1.1;
+        proxy_set_header Upgrade $http_upgrade;
+        proxy_set_header Connection $connection_upgrade;
+        proxy_set_header Host $host;
+        proxy_set_header X-Real-IP $remote_addr;
+        proxy_set_header X-Forwarded-For $proxy_add_x_forwarded_for;
+        proxy_set_header X-Forwarded-Proto $scheme;
+        # Keep idle WebSocket subscriptions open.
+        proxy_read_timeout 1h;
+    }}
+}}
+"#
+        )
+    }
+}
+
+pub fn run(args: ExposeArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let general_config = chain_config.get_general_config()?;
+    let web3_json_rpc = &general_config
+        .api_config
+        .as_ref()
+        .context(MSG_API_CONFIG_MISSING)?
+        .web3_json_rpc;
+
+    let proxy_config = ProxyConfig {
+        domain: &args.domain,
+        http_port: web3_json_rpc.http_port,
+        ws_port: web3_json_rpc.ws_port,
+        rate_limit: args.rate_limit,
+    };
+    let config_dir = chain_config.configs.join(EXPOSE_CONFIG_DIR);
+    shell.create_dir(&config_dir)?;
+    // The proxy container loads all `*.conf` files from the mounted directory.
+    let config_path = config_dir.join(format!("{}.conf", chain_config.name));
+    shell.write_file(&config_path, proxy_config.render())?;
+    logger::info(msg_expose_config_saved(&config_path));
+    if args.config_only {
+        return Ok(());
+    }
+
+    logger::info(msg_expose_starting(&chain_config.name, &args.domain));
+    let name = expose_proxy_name(&ecosystem_config.name, &chain_config.name);
+    run_proxy(shell, &config_dir, &name, &args)
+}
+
+fn run_proxy(
+    shell: &Shell,
+    config_dir: &Path,
+    name: &str,
+    args: &ExposeArgs,
+) -> anyhow::Result<()> {
+    let mut docker_args: Vec<String> = vec![
+        "--name".to_string(),
+        name.to_string(),
+        "-p".to_string(),
+        "80:80".to_string(),
+        "-p".to_string(),
+        "443:443".to_string(),
+        // Makes the server ports on the host reachable from the container on Linux as well.
+        "--add-host".to_string(),
+        "host.docker.internal:host-gateway".to_string(),
+        "-v".to_string(),
+        format!(
+            "{}:{EXPOSE_PROXY_DOCKER_CONFIG_DIR}:ro",
+            config_dir.display()
+        ),
+        // Certificates are kept in a named volume, so that they aren't re-issued on each start.
+        "-v".to_string(),
+        format!("{name}-certs:{EXPOSE_PROXY_DOCKER_CERTS_DIR}"),
+        "-e".to_string(),
+        format!("CERTBOT_EMAIL={}", args.email),
+        "--rm".to_string(),
+    ];
+    if args.staging {
+        docker_args.extend(["-e".to_string(), "STAGING=1".to_string()]);
+    }
+
+    docker::run(shell, EXPOSE_PROXY_DOCKER_IMAGE, docker_args)
+        .context(MSG_EXPOSE_FAILED_TO_RUN_DOCKER_ERR)?;
+    Ok(())
+}
+
+/// Generates a name for the reverse proxy Docker container.
+/// Will be passed as `--name` argument to `docker run`.
+fn expose_proxy_name(ecosystem_name: &str, chain_name: &str) -> String {
+    format!("{ecosystem_name}-{chain_name}-expose")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendering_proxy_config() {
+        let config = ProxyConfig {
+            domain: "rpc.example.com",
+            http_port: 3050,
+            ws_port: 3051,
+            rate_limit: 20,
+        }
+        .render();
+
+        assert!(config.contains("rate=20r/s;"));
+        assert!(config.contains("burst=40 nodelay;"));
+        assert!(config.contains("server host.docker.internal:3050;"));
+        assert!(config.contains("server host.docker.internal:3051;"));
+        assert!(config.contains("server_name rpc.example.com;"));
+        assert!(config.contains("/etc/letsencrypt/live/rpc.example.com/fullchain.pem;"));
+        // Braces must be balanced after formatting.
+        assert_eq!(config.matches('{').count(), config.matches('}').count());
+    }
+}
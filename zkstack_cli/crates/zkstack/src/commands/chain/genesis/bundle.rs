@@ -0,0 +1,293 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Context;
+use common::{logger, spinner::Spinner};
+use config::{traits::SaveConfigWithBasePath, ChainConfig, EcosystemConfig, GenesisConfig};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, PgConnection};
+use xshell::Shell;
+use zksync_basic_types::{web3::Bytes, Address, H256};
+
+use crate::{
+    commands::chain::{
+        args::genesis::{GenesisExportArgs, GenesisImportArgs},
+        genesis::genesis,
+    },
+    messages::{
+        msg_genesis_export_root_hash_mismatch_err, msg_genesis_exported,
+        msg_genesis_import_applying, msg_genesis_import_state_mismatch_err,
+        msg_genesis_import_unsupported_version_err, msg_genesis_imported,
+        MSG_CHAIN_NOT_INITIALIZED, MSG_DATABASE_MUST_BE_PRESENTED,
+        MSG_GENESIS_EXPORT_NO_GENESIS_ERR, MSG_GENESIS_EXPORT_SPINNER,
+        MSG_GENESIS_IMPORT_EVM_EMULATOR_MISMATCH_ERR, MSG_GENESIS_IMPORT_INVALID_BUNDLE_ERR,
+        MSG_GENESIS_IMPORT_SERVER_GENESIS_ERR, MSG_GENESIS_IMPORT_VERIFYING_SPINNER,
+    },
+};
+
+/// Version of the bundle format; bumped on incompatible changes.
+const BUNDLE_VERSION: u32 = 1;
+
+/// Portable genesis state of a chain.
+#[derive(Debug, Serialize, Deserialize)]
+struct GenesisBundle {
+    version: u32,
+    /// Name of the chain the bundle was exported from.
+    chain_name: String,
+    genesis: GenesisConfig,
+    #[serde(flatten)]
+    state: GenesisState,
+}
+
+/// Storage logs and factory deps inserted by the genesis L2 block.
+#[derive(Debug, Serialize, Deserialize)]
+struct GenesisState {
+    storage_logs: Vec<GenesisStorageLog>,
+    factory_deps: Vec<GenesisFactoryDep>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GenesisStorageLog {
+    hashed_key: H256,
+    address: Option<Address>,
+    key: Option<H256>,
+    value: H256,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GenesisFactoryDep {
+    bytecode_hash: H256,
+    bytecode: Bytes,
+}
+
+impl GenesisState {
+    async fn load(db: &mut PgConnection) -> anyhow::Result<Self> {
+        let storage_logs: Vec<(Vec<u8>, Option<Vec<u8>>, Option<Vec<u8>>, Vec<u8>)> =
+            sqlx::query_as(
+                "SELECT hashed_key, address, key, value FROM storage_logs \
+                 WHERE miniblock_number = 0 ORDER BY hashed_key",
+            )
+            .fetch_all(&mut *db)
+            .await
+            .context("failed querying genesis storage logs")?;
+        let factory_deps: Vec<(Vec<u8>, Vec<u8>)> = sqlx::query_as(
+            "SELECT bytecode_hash, bytecode FROM factory_deps \
+             WHERE miniblock_number = 0 ORDER BY bytecode_hash",
+        )
+        .fetch_all(&mut *db)
+        .await
+        .context("failed querying genesis factory deps")?;
+
+        Ok(Self {
+            storage_logs: storage_logs
+                .into_iter()
+                .map(|(hashed_key, address, key, value)| GenesisStorageLog {
+                    hashed_key: H256::from_slice(&hashed_key),
+                    address: address.map(|address| Address::from_slice(&address)),
+                    key: key.map(|key| H256::from_slice(&key)),
+                    value: H256::from_slice(&value),
+                })
+                .collect(),
+            factory_deps: factory_deps
+                .into_iter()
+                .map(|(bytecode_hash, bytecode)| GenesisFactoryDep {
+                    bytecode_hash: H256::from_slice(&bytecode_hash),
+                    bytecode: bytecode.into(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the number of mismatched storage logs and factory deps compared to `expected`.
+    fn mismatches(&self, expected: &Self) -> (usize, usize) {
+        let storage_logs = count_mismatches(
+            expected
+                .storage_logs
+                .iter()
+                .map(|log| (log.hashed_key, log.value)),
+            self.storage_logs
+                .iter()
+                .map(|log| (log.hashed_key, log.value)),
+        );
+        let factory_deps = count_mismatches(
+            expected
+                .factory_deps
+                .iter()
+                .map(|dep| (dep.bytecode_hash, &dep.bytecode)),
+            self.factory_deps
+                .iter()
+                .map(|dep| (dep.bytecode_hash, &dep.bytecode)),
+        );
+        (storage_logs, factory_deps)
+    }
+}
+
+/// Counts entries that are missing in `actual`, have a different value there or aren't present in `expected`.
+fn count_mismatches<K: Ord, V: PartialEq>(
+    expected: impl IntoIterator<Item = (K, V)>,
+    actual: impl IntoIterator<Item = (K, V)>,
+) -> usize {
+    let mut actual: BTreeMap<K, V> = actual.into_iter().collect();
+    let mut mismatches = 0;
+    for (key, value) in expected {
+        if actual.remove(&key).as_ref() != Some(&value) {
+            mismatches += 1;
+        }
+    }
+    mismatches + actual.len()
+}
+
+/// Copies the fields defining the genesis state from the bundle, keeping the chain-specific ones
+/// (chain IDs, fee account, commitment mode etc.) intact.
+fn apply_bundle_genesis(genesis: &mut GenesisConfig, bundle: &GenesisConfig) {
+    genesis.protocol_version = bundle.protocol_version;
+    genesis.genesis_root_hash = bundle.genesis_root_hash;
+    genesis.rollup_last_leaf_index = bundle.rollup_last_leaf_index;
+    genesis.genesis_commitment = bundle.genesis_commitment;
+    genesis.bootloader_hash = bundle.bootloader_hash;
+    genesis.default_aa_hash = bundle.default_aa_hash;
+    genesis.evm_emulator_hash = bundle.evm_emulator_hash;
+    genesis.snark_wrapper_vk_hash = bundle.snark_wrapper_vk_hash;
+}
+
+async fn connect_to_server_db(chain_config: &ChainConfig) -> anyhow::Result<PgConnection> {
+    let db_url = chain_config
+        .get_secrets_config()?
+        .database
+        .context(MSG_DATABASE_MUST_BE_PRESENTED)?
+        .master_url()?;
+    PgConnection::connect(db_url.expose_str())
+        .await
+        .context("failed connecting to server DB")
+}
+
+pub async fn run_export(args: GenesisExportArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let genesis = chain_config.get_genesis_config()?;
+    let output = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(format!("{}-genesis.json", chain_config.name)));
+    let output = shell.current_dir().join(output);
+
+    let spinner = Spinner::new(MSG_GENESIS_EXPORT_SPINNER);
+    let mut db = connect_to_server_db(&chain_config).await?;
+    let root_hash: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT hash FROM l1_batches WHERE number = 0")
+            .fetch_optional(&mut db)
+            .await
+            .context("failed querying genesis batch")?
+            .flatten();
+    let root_hash = H256::from_slice(&root_hash.context(MSG_GENESIS_EXPORT_NO_GENESIS_ERR)?);
+    if let Some(config_hash) = genesis.genesis_root_hash {
+        if config_hash != root_hash {
+            anyhow::bail!(msg_genesis_export_root_hash_mismatch_err(
+                root_hash,
+                config_hash
+            ));
+        }
+    }
+    let state = GenesisState::load(&mut db).await?;
+    spinner.finish();
+
+    let (storage_logs, factory_deps) = (state.storage_logs.len(), state.factory_deps.len());
+    let bundle = GenesisBundle {
+        version: BUNDLE_VERSION,
+        chain_name: chain_config.name.clone(),
+        genesis,
+        state,
+    };
+    shell.write_file(&output, serde_json::to_string_pretty(&bundle)?)?;
+
+    logger::outro(msg_genesis_exported(
+        &chain_config.name,
+        &output,
+        storage_logs,
+        factory_deps,
+    ));
+    Ok(())
+}
+
+pub async fn run_import(args: GenesisImportArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let bundle = shell
+        .read_file(shell.current_dir().join(&args.bundle))
+        .context(MSG_GENESIS_IMPORT_INVALID_BUNDLE_ERR)?;
+    let bundle: GenesisBundle =
+        serde_json::from_str(&bundle).context(MSG_GENESIS_IMPORT_INVALID_BUNDLE_ERR)?;
+    if bundle.version != BUNDLE_VERSION {
+        anyhow::bail!(msg_genesis_import_unsupported_version_err(bundle.version));
+    }
+    if bundle.genesis.evm_emulator_hash.is_some() != chain_config.evm_emulator {
+        anyhow::bail!(MSG_GENESIS_IMPORT_EVM_EMULATOR_MISMATCH_ERR);
+    }
+
+    logger::info(msg_genesis_import_applying(&bundle.chain_name));
+    let mut genesis_config = chain_config.get_genesis_config()?;
+    apply_bundle_genesis(&mut genesis_config, &bundle.genesis);
+    genesis_config.save_with_base_path(shell, &chain_config.configs)?;
+
+    // The server checks the root hash and commitment of the produced genesis against the config.
+    let genesis_args = args.genesis.fill_values_with_prompt(&chain_config);
+    genesis(genesis_args, shell, &chain_config)
+        .await
+        .context(MSG_GENESIS_IMPORT_SERVER_GENESIS_ERR)?;
+
+    let spinner = Spinner::new(MSG_GENESIS_IMPORT_VERIFYING_SPINNER);
+    let mut db = connect_to_server_db(&chain_config).await?;
+    let state = GenesisState::load(&mut db).await?;
+    spinner.finish();
+    let (storage_logs, factory_deps) = state.mismatches(&bundle.state);
+    if storage_logs > 0 || factory_deps > 0 {
+        anyhow::bail!(msg_genesis_import_state_mismatch_err(
+            storage_logs,
+            factory_deps
+        ));
+    }
+
+    logger::outro(msg_genesis_imported(&chain_config.name));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_basic_types::{L1ChainId, L2ChainId};
+
+    use super::*;
+
+    #[test]
+    fn applying_bundle_genesis_keeps_chain_specific_fields() {
+        let mut genesis = GenesisConfig::for_tests();
+        genesis.l2_chain_id = L2ChainId::from(271);
+        genesis.fee_account = Address::repeat_byte(0xfe);
+        let mut bundle = GenesisConfig::for_tests();
+        bundle.l1_chain_id = L1ChainId(1);
+        bundle.l2_chain_id = L2ChainId::from(505);
+        bundle.genesis_root_hash = Some(H256::repeat_byte(0xaa));
+        bundle.genesis_commitment = Some(H256::repeat_byte(0xbb));
+        bundle.rollup_last_leaf_index = Some(100);
+
+        apply_bundle_genesis(&mut genesis, &bundle);
+        assert_eq!(genesis.genesis_root_hash, bundle.genesis_root_hash);
+        assert_eq!(genesis.genesis_commitment, bundle.genesis_commitment);
+        assert_eq!(genesis.rollup_last_leaf_index, Some(100));
+        assert_eq!(genesis.l1_chain_id, GenesisConfig::for_tests().l1_chain_id);
+        assert_eq!(genesis.l2_chain_id, L2ChainId::from(271));
+        assert_eq!(genesis.fee_account, Address::repeat_byte(0xfe));
+    }
+
+    #[test]
+    fn mismatches_are_counted() {
+        let expected = [(1, "a"), (2, "b"), (3, "c")];
+        assert_eq!(count_mismatches(expected, expected), 0);
+        assert_eq!(count_mismatches(expected, [(1, "a"), (2, "x")]), 2);
+        assert_eq!(
+            count_mismatches(expected, [(1, "a"), (2, "b"), (3, "c"), (4, "d")]),
+            1
+        );
+    }
+}
@@ -1,21 +1,28 @@
-use anyhow::Context;
+use anyhow::{bail, Context};
 use clap::{command, Parser, Subcommand};
 use common::{logger, spinner::Spinner};
 use config::{ChainConfig, EcosystemConfig};
 use xshell::Shell;
 
 use crate::{
-    commands::chain::{
-        args::genesis::{GenesisArgs, GenesisArgsFinal},
-        genesis::{self, database::initialize_server_database, server::run_server_genesis},
+    commands::{
+        args::MultiChainArgs,
+        chain::{
+            args::genesis::{GenesisArgs, GenesisArgsFinal, GenesisExportArgs, GenesisImportArgs},
+            genesis::{self, database::initialize_server_database, server::run_server_genesis},
+        },
+        multi_chain,
     },
+    defaults::generate_db_names,
     messages::{
-        MSG_CHAIN_NOT_INITIALIZED, MSG_GENESIS_COMPLETED, MSG_INITIALIZING_DATABASES_SPINNER,
+        MSG_CHAIN_NOT_INITIALIZED, MSG_GENESIS_COMPLETED, MSG_GENESIS_MULTI_CHAIN_DB_NAME_ERR,
+        MSG_GENESIS_MULTI_CHAIN_DB_URL_ERR, MSG_INITIALIZING_DATABASES_SPINNER,
         MSG_SELECTED_CONFIG, MSG_STARTING_GENESIS, MSG_STARTING_GENESIS_SPINNER,
     },
 };
 
 // Genesis subcommands
+pub mod bundle;
 pub mod database;
 pub mod server;
 
@@ -26,6 +33,10 @@ pub enum GenesisSubcommands {
     InitDatabase(Box<GenesisArgs>),
     /// Runs server genesis
     Server,
+    /// Export genesis config, storage logs and factory deps of the chain into a portable bundle
+    Export(GenesisExportArgs),
+    /// Run genesis from a bundle created by `export`, reproducing the exported genesis state
+    Import(Box<GenesisImportArgs>),
 }
 
 #[derive(Parser, Debug)]
@@ -35,16 +46,58 @@ pub struct GenesisCommand {
     command: Option<GenesisSubcommands>,
     #[clap(flatten)]
     args: GenesisArgs,
+    #[clap(flatten)]
+    multi_chain: MultiChainArgs,
 }
 
 pub(crate) async fn run(args: GenesisCommand, shell: &Shell) -> anyhow::Result<()> {
+    if args.multi_chain.is_enabled() {
+        return run_for_chains(args, shell).await;
+    }
+
     match args.command {
         Some(GenesisSubcommands::InitDatabase(args)) => database::run(*args, shell).await,
         Some(GenesisSubcommands::Server) => server::run(shell).await,
+        Some(GenesisSubcommands::Export(args)) => bundle::run_export(args, shell).await,
+        Some(GenesisSubcommands::Import(args)) => bundle::run_import(*args, shell).await,
         None => run_genesis(args.args, shell).await,
     }
 }
 
+async fn run_for_chains(args: GenesisCommand, shell: &Shell) -> anyhow::Result<()> {
+    let genesis_args = match &args.command {
+        Some(GenesisSubcommands::InitDatabase(args)) => Some(args.as_ref()),
+        Some(GenesisSubcommands::Import(args)) => Some(&args.genesis),
+        Some(GenesisSubcommands::Server | GenesisSubcommands::Export(_)) => None,
+        None => Some(&args.args),
+    };
+    // Chains run without prompts, so every chain is given its default database name explicitly.
+    let pass_db_name = match genesis_args {
+        Some(genesis_args) if !genesis_args.dev => {
+            if genesis_args.server_db_name.is_some() {
+                bail!(MSG_GENESIS_MULTI_CHAIN_DB_NAME_ERR);
+            }
+            if genesis_args.server_db_url.is_none() {
+                bail!(MSG_GENESIS_MULTI_CHAIN_DB_URL_ERR);
+            }
+            true
+        }
+        _ => false,
+    };
+
+    multi_chain::run(shell, &args.multi_chain, |chain_config| {
+        Ok(if pass_db_name {
+            vec![
+                "--server-db-name".to_owned(),
+                generate_db_names(chain_config).server_name,
+            ]
+        } else {
+            vec![]
+        })
+    })
+    .await
+}
+
 pub async fn run_genesis(args: GenesisArgs, shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
     let chain_config = ecosystem_config
@@ -10,9 +10,12 @@ use config::{
 };
 use xshell::Shell;
 
-use crate::messages::{
-    MSG_CHAIN_NOT_INITIALIZED, MSG_FAILED_TO_RUN_SERVER_ERR, MSG_GENESIS_COMPLETED,
-    MSG_STARTING_GENESIS_SPINNER,
+use crate::{
+    messages::{
+        MSG_CHAIN_NOT_INITIALIZED, MSG_FAILED_TO_RUN_SERVER_ERR, MSG_GENESIS_COMPLETED,
+        MSG_STARTING_GENESIS_SPINNER,
+    },
+    utils::secrets::resolve_secret_references,
 };
 
 pub async fn run(shell: &Shell) -> anyhow::Result<()> {
@@ -30,16 +33,25 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
 }
 
 pub fn run_server_genesis(chain_config: &ChainConfig, shell: &Shell) -> anyhow::Result<()> {
+    let wallets = resolve_secret_references(
+        shell,
+        &WalletsConfig::get_path_with_base_path(&chain_config.configs),
+    )?;
+    let secrets = resolve_secret_references(
+        shell,
+        &SecretsConfig::get_path_with_base_path(&chain_config.configs),
+    )?;
+
     let server = Server::new(None, chain_config.link_to_code.clone(), false);
     server
         .run(
             shell,
             ServerMode::Genesis,
-            GenesisConfig::get_path_with_base_path(&chain_config.configs),
-            WalletsConfig::get_path_with_base_path(&chain_config.configs),
-            GeneralConfig::get_path_with_base_path(&chain_config.configs),
-            SecretsConfig::get_path_with_base_path(&chain_config.configs),
-            ContractsConfig::get_path_with_base_path(&chain_config.configs),
+            GenesisConfig::get_path_with_base_path(&chain_config.configs).as_path(),
+            wallets.path(),
+            GeneralConfig::get_path_with_base_path(&chain_config.configs).as_path(),
+            secrets.path(),
+            ContractsConfig::get_path_with_base_path(&chain_config.configs).as_path(),
             vec![],
         )
         .context(MSG_FAILED_TO_RUN_SERVER_ERR)
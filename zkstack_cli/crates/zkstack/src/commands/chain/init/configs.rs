@@ -6,11 +6,15 @@ use config::{
 };
 use ethers::types::Address;
 use xshell::Shell;
+use zksync_config::configs::{da_client::DAClientConfig, secrets::DataAvailabilitySecrets};
 
 use crate::{
     commands::{
         chain::{
-            args::init::configs::{InitConfigsArgs, InitConfigsArgsFinal},
+            args::init::{
+                configs::{InitConfigsArgs, InitConfigsArgsFinal},
+                da_configs::ValidiumType,
+            },
             genesis,
         },
         portal::update_portal_config,
@@ -77,6 +81,11 @@ pub async fn init_configs(
     consensus_config.genesis_spec = Some(get_genesis_specs(chain_config, &consensus_keys));
 
     general_config.consensus_config = Some(consensus_config);
+    // Chains without a DA client config use the no-DA client
+    general_config.da_client_config = match &init_args.validium_config {
+        Some(ValidiumType::Avail(config)) => Some(DAClientConfig::Avail(config.client.clone())),
+        Some(ValidiumType::NoDA) | None => None,
+    };
     general_config.save_with_base_path(shell, &chain_config.configs)?;
 
     // Initialize genesis config
@@ -96,6 +105,12 @@ pub async fn init_configs(
     let mut secrets = chain_config.get_secrets_config()?;
     set_l1_rpc_url(&mut secrets, init_args.l1_rpc_url.clone())?;
     secrets.consensus = Some(get_consensus_secrets(&consensus_keys));
+    secrets.data_availability = match &init_args.validium_config {
+        Some(ValidiumType::Avail(config)) => {
+            Some(DataAvailabilitySecrets::Avail(config.secrets.clone()))
+        }
+        Some(ValidiumType::NoDA) | None => None,
+    };
     secrets.save_with_base_path(shell, &chain_config.configs)?;
 
     genesis::database::update_configs(init_args.genesis_args.clone(), shell, chain_config)?;
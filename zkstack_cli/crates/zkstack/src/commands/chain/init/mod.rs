@@ -1,15 +1,20 @@
+use std::collections::BTreeMap;
+
 use anyhow::Context;
 use clap::{command, Parser, Subcommand};
 use common::{git, logger, spinner::Spinner};
-use config::{traits::SaveConfigWithBasePath, ChainConfig, EcosystemConfig};
+use config::{traits::SaveConfigWithBasePath, ChainConfig, ContractsConfig, EcosystemConfig};
+use serde::Serialize;
 use types::BaseToken;
 use xshell::Shell;
+use zksync_basic_types::L2ChainId;
 
 use crate::{
-    accept_ownership::accept_admin,
+    accept_ownership::{accept_admin, set_da_validator_pair},
     commands::chain::{
         args::init::{
             configs::{InitConfigsArgs, InitConfigsArgsFinal},
+            da_configs::ValidiumType,
             InitArgs, InitArgsFinal,
         },
         common::{distribute_eth, mint_base_token},
@@ -23,9 +28,10 @@ use crate::{
     messages::{
         msg_initializing_chain, MSG_ACCEPTING_ADMIN_SPINNER, MSG_CHAIN_INITIALIZED,
         MSG_CHAIN_NOT_FOUND_ERR, MSG_DEPLOYING_PAYMASTER, MSG_GENESIS_DATABASE_ERR,
-        MSG_REGISTERING_CHAIN_SPINNER, MSG_SELECTED_CONFIG,
+        MSG_REGISTERING_CHAIN_SPINNER, MSG_SELECTED_CONFIG, MSG_UPDATING_DA_VALIDATORS_SPINNER,
         MSG_UPDATING_TOKEN_MULTIPLIER_SETTER_SPINNER, MSG_WALLET_TOKEN_MULTIPLIER_SETTER_NOT_FOUND,
     },
+    utils::ports::EcosystemPortsScanner,
 };
 
 // Init subcommands
@@ -46,6 +52,28 @@ pub struct ChainInitCommand {
     args: InitArgs,
 }
 
+/// Result of chain initialization printed in the JSON output mode.
+#[derive(Debug, Serialize)]
+pub(crate) struct ChainInitOutput {
+    name: String,
+    chain_id: L2ChainId,
+    contracts: ContractsConfig,
+    /// Ports assigned to the chain components, grouped by config file.
+    ports: BTreeMap<String, BTreeMap<String, u16>>,
+}
+
+impl ChainInitOutput {
+    pub(crate) fn new(shell: &Shell, chain_config: &ChainConfig) -> anyhow::Result<Self> {
+        let ports = EcosystemPortsScanner::scan(shell)?.ports_in_dir(&chain_config.configs);
+        Ok(Self {
+            name: chain_config.name.clone(),
+            chain_id: chain_config.chain_id,
+            contracts: chain_config.get_contracts_config()?,
+            ports,
+        })
+    }
+}
+
 pub(crate) async fn run(args: ChainInitCommand, shell: &Shell) -> anyhow::Result<()> {
     match args.command {
         Some(ChainInitSubcommands::Configs(args)) => configs::run(args, shell).await,
@@ -67,6 +95,7 @@ async fn run_init(args: InitArgs, shell: &Shell) -> anyhow::Result<()> {
     init(&args, shell, &config, &chain_config).await?;
 
     logger::success(MSG_CHAIN_INITIALIZED);
+    logger::result(ChainInitOutput::new(shell, &chain_config)?);
     Ok(())
 }
 
@@ -115,6 +144,24 @@ pub async fn init(
     .await?;
     spinner.finish();
 
+    // Set DA validators of the chain's DA layer (run by L2 Governor)
+    if let Some(ValidiumType::Avail(config)) = &init_args.validium_config {
+        let spinner = Spinner::new(MSG_UPDATING_DA_VALIDATORS_SPINNER);
+        set_da_validator_pair(
+            shell,
+            ecosystem_config,
+            contracts_config.l1.chain_admin_addr,
+            &chain_config.get_wallets_config()?.governor,
+            contracts_config.l1.diamond_proxy_addr,
+            config.l1_da_validator,
+            config.l2_da_validator,
+            &init_args.forge_args,
+            init_args.l1_rpc_url.clone(),
+        )
+        .await?;
+        spinner.finish();
+    }
+
     // Set token multiplier setter address (run by L2 Governor)
     if chain_config.base_token != BaseToken::eth() {
         let spinner = Spinner::new(MSG_UPDATING_TOKEN_MULTIPLIER_SETTER_SPINNER);
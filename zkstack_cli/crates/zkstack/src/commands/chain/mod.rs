@@ -3,25 +3,48 @@ use args::build_transactions::BuildTransactionsArgs;
 pub(crate) use args::create::ChainCreateArgsFinal;
 use clap::{command, Subcommand};
 pub(crate) use create::create_chain_inner;
+use types::L1BatchCommitmentMode;
 use xshell::Shell;
 
 use crate::commands::chain::{
-    args::create::ChainCreateArgs, deploy_l2_contracts::Deploy2ContractsOption,
-    genesis::GenesisCommand, init::ChainInitCommand,
+    args::{
+        add_remote::AddRemoteChainArgs,
+        backup::{BackupArgs, RestoreArgs},
+        convert_da_mode::ConvertDaModeArgs,
+        create::ChainCreateArgs,
+        diff_config::DiffConfigArgs,
+        expose::ExposeArgs,
+        monitor::MonitorArgs,
+        set_da::SetDaArgs,
+        upgrade::ChainUpgradeArgs,
+    },
+    deploy_l2_contracts::Deploy2ContractsOption,
+    genesis::GenesisCommand,
+    init::ChainInitCommand,
+    wallet::WalletCommands,
 };
 
 mod accept_chain_ownership;
+mod add_remote;
 pub(crate) mod args;
+mod backup;
 mod build_transactions;
 mod common;
+mod convert_da_mode;
 mod create;
 pub mod deploy_l2_contracts;
 pub mod deploy_paymaster;
+mod diff_config;
+mod expose;
 pub mod genesis;
 pub mod init;
+pub(crate) mod monitor;
 pub mod register_chain;
+mod set_da;
 mod set_token_multiplier_setter;
 mod setup_legacy_bridge;
+mod upgrade;
+mod wallet;
 
 #[derive(Subcommand, Debug)]
 pub enum ChainCommands {
@@ -67,6 +90,42 @@ pub enum ChainCommands {
     DeployPaymaster(ForgeScriptArgs),
     /// Update Token Multiplier Setter address on L1
     UpdateTokenMultiplierSetter(ForgeScriptArgs),
+    /// Switch an existing rollup chain to validium (executed by L1 governor).
+    /// Requires all sealed batches to be committed; updates the DA validator pair on L1
+    /// and records the transition batch for the consistency checker.
+    ConvertToValidium(ConvertDaModeArgs),
+    /// Switch an existing validium chain to rollup (executed by L1 governor).
+    /// Requires all sealed batches to be committed; updates the DA validator pair on L1
+    /// and records the transition batch for the consistency checker.
+    ConvertToRollup(ConvertDaModeArgs),
+    /// Switch the DA mode of the chain: rollup with calldata or blobs, or validium without DA or with
+    /// Avail / Celestia / EigenDA (executed by L1 governor). Updates the DA validator pair on L1 if necessary,
+    /// and the commitment mode and DA client in the chain configs.
+    SetDa(SetDaArgs),
+    /// Compare the config loaded by the running server with the on-disk general config
+    /// (secrets and contracts configs are not compared). The server has no hot-reloadable values,
+    /// so all differences require a restart to be applied.
+    DiffConfig(DiffConfigArgs),
+    /// Register an externally operated chain in the ecosystem, so that commands like `portal`
+    /// and `explorer` can target it. Chain parameters are fetched from its L2 RPC.
+    AddRemote(Box<AddRemoteChainArgs>),
+    /// Back up Postgres databases, RocksDB state and configs of the chain into a single archive.
+    /// The server should be stopped while the backup is created.
+    Backup(BackupArgs),
+    /// Restore a chain from an archive created by `backup`, e.g. on another machine
+    Restore(RestoreArgs),
+    /// Upgrade the chain to a new protocol version: publish the upgrade via the ecosystem governance if necessary,
+    /// upgrade the chain via its admin, wait for the upgrade batch and verify the new versions
+    Upgrade(ChainUpgradeArgs),
+    /// Live dashboard of the chain: latest L2 block and L1 batch, commit / prove / execute lag,
+    /// mempool size, L1 operator balances and component health
+    Monitor(MonitorArgs),
+    /// Expose the chain RPC (HTTP and WebSocket) on a public domain via a reverse proxy container
+    /// with automatic TLS certificates and per-IP rate limits
+    Expose(ExposeArgs),
+    /// Manage chain wallets
+    #[command(subcommand)]
+    Wallet(WalletCommands),
 }
 
 pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()> {
@@ -99,5 +158,20 @@ pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()
         ChainCommands::UpdateTokenMultiplierSetter(args) => {
             set_token_multiplier_setter::run(args, shell).await
         }
+        ChainCommands::ConvertToValidium(args) => {
+            convert_da_mode::run(args, L1BatchCommitmentMode::Validium, shell).await
+        }
+        ChainCommands::ConvertToRollup(args) => {
+            convert_da_mode::run(args, L1BatchCommitmentMode::Rollup, shell).await
+        }
+        ChainCommands::SetDa(args) => set_da::run(args, shell).await,
+        ChainCommands::DiffConfig(args) => diff_config::run(args, shell).await,
+        ChainCommands::AddRemote(args) => add_remote::run(*args, shell).await,
+        ChainCommands::Backup(args) => backup::run_backup(args, shell).await,
+        ChainCommands::Restore(args) => backup::run_restore(args, shell).await,
+        ChainCommands::Upgrade(args) => upgrade::run(args, shell).await,
+        ChainCommands::Monitor(args) => monitor::run(args, shell).await,
+        ChainCommands::Expose(args) => expose::run(args, shell),
+        ChainCommands::Wallet(args) => wallet::run(shell, args).await,
     }
 }
@@ -0,0 +1,418 @@
+use std::{collections::BTreeMap, fmt, future::Future, sync::Arc};
+
+use anyhow::Context;
+use chrono::Utc;
+use common::logger;
+use config::{traits::ConfigWithL2RpcUrl, ChainConfig, EcosystemConfig};
+use console::{style, Term};
+use ethers::{
+    contract::abigen,
+    providers::{Http, Middleware, Provider},
+    types::{Address, U256, U64},
+    utils::format_ether,
+};
+use serde::Deserialize;
+use tokio::time::MissedTickBehavior;
+use xshell::Shell;
+
+use crate::{
+    commands::chain::args::monitor::MonitorArgs,
+    messages::{
+        msg_monitor_header, MSG_CHAIN_NOT_INITIALIZED, MSG_L1_SECRETS_MUST_BE_PRESENTED,
+        MSG_MONITOR_EXIT_HINT, MSG_MONITOR_UNAVAILABLE,
+    },
+};
+
+abigen!(
+    ZkChainGetters,
+    r"[
+    function getTotalBatchesCommitted() external view returns (uint256)
+    function getTotalBatchesVerified() external view returns (uint256)
+    function getTotalBatchesExecuted() external view returns (uint256)
+    ]"
+);
+
+const STATUS_READY: &str = "ready";
+
+/// Progress of L1 batches on L1.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct L1Progress {
+    pub committed: u64,
+    pub proven: u64,
+    pub executed: u64,
+}
+
+/// Response of `txpool_status`.
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct TxpoolStatus {
+    pending: U64,
+    queued: U64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct HealthResponse {
+    pub status: String,
+    #[serde(default)]
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ComponentHealth {
+    pub status: String,
+}
+
+impl ComponentHealth {
+    pub fn is_ready(&self) -> bool {
+        self.status.eq_ignore_ascii_case(STATUS_READY)
+    }
+}
+
+/// State of the chain at a certain moment. Each part is fetched independently, so that the dashboard stays usable
+/// if some of the sources (e.g., the server) are unavailable.
+#[derive(Debug)]
+pub(crate) struct Snapshot {
+    pub l2_block: anyhow::Result<u64>,
+    sealed_batch: anyhow::Result<u64>,
+    pub l1_progress: anyhow::Result<L1Progress>,
+    mempool: anyhow::Result<TxpoolStatus>,
+    balances: Vec<(&'static str, Address, anyhow::Result<U256>)>,
+    pub health: anyhow::Result<HealthResponse>,
+}
+
+pub(crate) struct ChainMonitor {
+    l1_provider: Arc<Provider<Http>>,
+    l2_provider: Provider<Http>,
+    diamond_proxy: ZkChainGetters<Provider<Http>>,
+    operators: Vec<(&'static str, Address)>,
+    health_url: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl ChainMonitor {
+    pub fn new(chain_config: &ChainConfig) -> anyhow::Result<Self> {
+        let contracts_config = chain_config.get_contracts_config()?;
+        let general_config = chain_config.get_general_config()?;
+        let l1_rpc_url = chain_config
+            .get_secrets_config()?
+            .l1
+            .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+            .l1_rpc_url
+            .expose_str()
+            .to_owned();
+        let l2_rpc_url = general_config.get_l2_rpc_url()?;
+        let wallets = chain_config.get_wallets_config()?;
+
+        let l1_provider = Arc::new(Provider::<Http>::try_from(l1_rpc_url.as_str())?);
+        Ok(Self {
+            diamond_proxy: ZkChainGetters::new(
+                contracts_config.l1.diamond_proxy_addr,
+                l1_provider.clone(),
+            ),
+            l1_provider,
+            l2_provider: Provider::<Http>::try_from(l2_rpc_url.as_str())?,
+            operators: vec![
+                ("Operator", wallets.operator.address),
+                ("Blob operator", wallets.blob_operator.address),
+            ],
+            health_url: general_config
+                .api_config
+                .as_ref()
+                .map(|api| format!("http://localhost:{}/health", api.healthcheck.port)),
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    pub async fn snapshot(&self) -> Snapshot {
+        let mut balances = vec![];
+        for &(role, address) in &self.operators {
+            let balance = self
+                .l1_provider
+                .get_balance(address, None)
+                .await
+                .context("eth_getBalance");
+            balances.push((role, address, balance));
+        }
+
+        Snapshot {
+            l2_block: self.l2_block().await,
+            sealed_batch: self.sealed_batch().await,
+            l1_progress: self.l1_progress().await,
+            mempool: self.mempool().await,
+            balances,
+            health: self.health().await,
+        }
+    }
+
+    async fn l2_block(&self) -> anyhow::Result<u64> {
+        let number = self
+            .l2_provider
+            .get_block_number()
+            .await
+            .context("eth_blockNumber")?;
+        Ok(number.as_u64())
+    }
+
+    async fn sealed_batch(&self) -> anyhow::Result<u64> {
+        let number: U64 = self
+            .l2_provider
+            .request("zks_L1BatchNumber", ())
+            .await
+            .context("zks_L1BatchNumber")?;
+        Ok(number.as_u64())
+    }
+
+    async fn l1_progress(&self) -> anyhow::Result<L1Progress> {
+        let committed = self
+            .diamond_proxy
+            .get_total_batches_committed()
+            .call()
+            .await
+            .context("getTotalBatchesCommitted()")?;
+        let proven = self
+            .diamond_proxy
+            .get_total_batches_verified()
+            .call()
+            .await
+            .context("getTotalBatchesVerified()")?;
+        let executed = self
+            .diamond_proxy
+            .get_total_batches_executed()
+            .call()
+            .await
+            .context("getTotalBatchesExecuted()")?;
+        Ok(L1Progress {
+            committed: committed.as_u64(),
+            proven: proven.as_u64(),
+            executed: executed.as_u64(),
+        })
+    }
+
+    async fn mempool(&self) -> anyhow::Result<TxpoolStatus> {
+        self.l2_provider
+            .request("txpool_status", ())
+            .await
+            .context("txpool_status")
+    }
+
+    async fn health(&self) -> anyhow::Result<HealthResponse> {
+        let url = self
+            .health_url
+            .as_deref()
+            .context("healthcheck is not configured")?;
+        // The health check endpoint responds with 503 if some components are not ready, so the status code is ignored.
+        let response = self.http_client.get(url).send().await?;
+        Ok(response.json().await?)
+    }
+}
+
+pub(crate) fn format_or_unavailable<T>(
+    value: &anyhow::Result<T>,
+    format: impl FnOnce(&T) -> String,
+) -> String {
+    match value {
+        Ok(value) => format(value),
+        Err(_) => style(MSG_MONITOR_UNAVAILABLE).dim().to_string(),
+    }
+}
+
+fn format_lag(lag: u64) -> String {
+    let lag_str = format!("(lag {lag})");
+    if lag == 0 {
+        style(lag_str).green().to_string()
+    } else {
+        style(lag_str).yellow().to_string()
+    }
+}
+
+pub(crate) fn format_status(status: &str) -> String {
+    if status.eq_ignore_ascii_case(STATUS_READY) {
+        style(status).green().to_string()
+    } else {
+        style(status).red().to_string()
+    }
+}
+
+fn render(chain_name: &str, timestamp: impl fmt::Display, snapshot: &Snapshot) -> Vec<String> {
+    let mut lines = vec![
+        style(msg_monitor_header(chain_name, timestamp))
+            .bold()
+            .to_string(),
+        String::new(),
+        style("Blocks").bold().underlined().to_string(),
+        format!(
+            "  Latest L2 block      {}",
+            format_or_unavailable(&snapshot.l2_block, |number| format!("#{number}"))
+        ),
+        format!(
+            "  Latest sealed batch  {}",
+            format_or_unavailable(&snapshot.sealed_batch, |number| format!("#{number}"))
+        ),
+    ];
+
+    let sealed_batch = snapshot.sealed_batch.as_ref().ok().copied();
+    let progress = snapshot.l1_progress.as_ref().ok();
+    let stages = [
+        ("Committed", progress.map(|p| p.committed), sealed_batch),
+        (
+            "Proven",
+            progress.map(|p| p.proven),
+            progress.map(|p| p.committed),
+        ),
+        (
+            "Executed",
+            progress.map(|p| p.executed),
+            progress.map(|p| p.proven),
+        ),
+    ];
+    for (name, number, previous) in stages {
+        let value = match (number, previous) {
+            (Some(number), Some(previous)) => {
+                format!("#{number} {}", format_lag(previous.saturating_sub(number)))
+            }
+            (Some(number), None) => format!("#{number}"),
+            (None, _) => style(MSG_MONITOR_UNAVAILABLE).dim().to_string(),
+        };
+        lines.push(format!("  {name:<21}{value}"));
+    }
+
+    lines.push(String::new());
+    lines.push(style("Mempool").bold().underlined().to_string());
+    lines.push(format!(
+        "  Pending / queued     {}",
+        format_or_unavailable(&snapshot.mempool, |status| format!(
+            "{} / {}",
+            status.pending, status.queued
+        ))
+    ));
+
+    lines.push(String::new());
+    lines.push(
+        style("L1 operator balances")
+            .bold()
+            .underlined()
+            .to_string(),
+    );
+    for (role, address, balance) in &snapshot.balances {
+        let balance = format_or_unavailable(balance, |balance| {
+            let formatted = format!("{} ETH", format_ether(*balance));
+            if balance.is_zero() {
+                style(formatted).red().to_string()
+            } else {
+                formatted
+            }
+        });
+        lines.push(format!("  {role:<21}{balance} ({address:#x})"));
+    }
+
+    lines.push(String::new());
+    lines.push(style("Health").bold().underlined().to_string());
+    match &snapshot.health {
+        Ok(health) => {
+            lines.push(format!(
+                "  {:<21}{}",
+                "Overall",
+                format_status(&health.status)
+            ));
+            for (name, component) in &health.components {
+                lines.push(format!("  {name:<21}{}", format_status(&component.status)));
+            }
+        }
+        Err(err) => lines.push(format!(
+            "  {}",
+            style(format!("{MSG_MONITOR_UNAVAILABLE}: {err:#}")).dim()
+        )),
+    }
+    lines
+}
+
+pub(super) async fn run(args: MonitorArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let monitor = ChainMonitor::new(&chain_config)?;
+
+    let (monitor, chain_name) = (&monitor, chain_config.name.as_str());
+    show_dashboard(&args, move || async move {
+        let snapshot = monitor.snapshot().await;
+        let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
+        render(chain_name, timestamp, &snapshot)
+    })
+    .await
+}
+
+/// Prints the lines produced by `frame` once or, unless `args.once` is set, keeps refreshing them in place
+/// until Ctrl+C is pressed.
+pub(crate) async fn show_dashboard<F>(
+    args: &MonitorArgs,
+    frame: impl Fn() -> F,
+) -> anyhow::Result<()>
+where
+    F: Future<Output = Vec<String>>,
+{
+    if args.once {
+        logger::raw(frame().await.join("\n"));
+        return Ok(());
+    }
+
+    let term = Term::stdout();
+    term.hide_cursor()?;
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut interval = tokio::time::interval(args.refresh_interval());
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let result = loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = &mut ctrl_c => break Ok(()),
+        }
+        let mut lines = frame().await;
+        lines.push(String::new());
+        lines.push(style(MSG_MONITOR_EXIT_HINT).dim().to_string());
+        // Clearing the screen right before writing keeps flickering to a minimum.
+        if let Err(err) = term
+            .clear_screen()
+            .and_then(|()| term.write_line(&lines.join("\n")))
+        {
+            break Err(err);
+        }
+    };
+    term.show_cursor()?;
+    result?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendering_snapshot_with_unavailable_sources() {
+        console::set_colors_enabled(false);
+        let snapshot = Snapshot {
+            l2_block: Ok(100),
+            sealed_batch: Ok(12),
+            l1_progress: Ok(L1Progress {
+                committed: 10,
+                proven: 9,
+                executed: 9,
+            }),
+            mempool: Err(anyhow::anyhow!("txpool namespace is disabled")),
+            balances: vec![("Operator", Address::repeat_byte(1), Ok(U256::exp10(18)))],
+            health: Err(anyhow::anyhow!("connection refused")),
+        };
+        let lines = render("era", "now", &snapshot);
+
+        assert_eq!(lines[0], "Chain `era` at now");
+        assert!(lines.contains(&"  Latest L2 block      #100".to_owned()));
+        assert!(lines.contains(&"  Committed            #10 (lag 2)".to_owned()));
+        assert!(lines.contains(&"  Proven               #9 (lag 1)".to_owned()));
+        assert!(lines.contains(&"  Executed             #9 (lag 0)".to_owned()));
+        assert!(lines.contains(&"  Pending / queued     n/a".to_owned()));
+        assert!(lines.contains(
+            &"  Operator             1.000000000000000000 ETH (0x0101010101010101010101010101010101010101)"
+                .to_owned()
+        ));
+        assert_eq!(lines.last().unwrap(), "  n/a: connection refused");
+    }
+}
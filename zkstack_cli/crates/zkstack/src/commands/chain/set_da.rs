@@ -0,0 +1,82 @@
+use anyhow::Context;
+use common::logger;
+use config::{traits::SaveConfigWithBasePath, EcosystemConfig};
+use xshell::Shell;
+
+use crate::{
+    commands::chain::{
+        args::set_da::{DaMode, SetDaArgs},
+        convert_da_mode::{stop_chain_components, update_commitment_mode, update_da_validators},
+    },
+    messages::{
+        msg_chain_da_mode_set, msg_stopped_chain_components, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_DA_MODE_CONVERSION_NOTE, MSG_NO_RUNNING_CHAIN_COMPONENTS,
+    },
+};
+
+pub async fn run(args: SetDaArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let mut chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let general_config = chain_config.get_general_config()?;
+    let current_mode = DaMode::from_configs(
+        chain_config.l1_batch_commit_data_generator_mode,
+        general_config
+            .eth
+            .as_ref()
+            .and_then(|eth| eth.sender.as_ref())
+            .map(|sender| sender.pubdata_sending_mode),
+        general_config.da_client_config.as_ref(),
+    );
+    let args = args.fill_values_with_prompt(current_mode)?;
+
+    let mut transition_batch = None;
+    if let Some((l1_da_validator, l2_da_validator)) = args.da_validators {
+        let batch = update_da_validators(
+            shell,
+            &ecosystem_config,
+            &chain_config,
+            l1_da_validator,
+            l2_da_validator,
+            &args.forge_args,
+        )
+        .await?;
+        let commitment_mode = args.mode.commitment_mode();
+        if chain_config.l1_batch_commit_data_generator_mode != commitment_mode {
+            update_commitment_mode(shell, &mut chain_config, commitment_mode, batch)?;
+        }
+        transition_batch = Some(batch);
+    }
+
+    // Loaded again since switching the commitment mode applies general config overrides.
+    let mut general_config = chain_config.get_general_config()?;
+    general_config.da_client_config = args.client;
+    if let Some(sender) = general_config
+        .eth
+        .as_mut()
+        .and_then(|eth| eth.sender.as_mut())
+    {
+        sender.pubdata_sending_mode = args.mode.pubdata_sending_mode();
+    }
+    general_config.save_with_base_path(shell, &chain_config.configs)?;
+
+    let mut secrets = chain_config.get_secrets_config()?;
+    secrets.data_availability = args.secrets;
+    secrets.save_with_base_path(shell, &chain_config.configs)?;
+
+    let chain_path = ecosystem_config.chains.join(&chain_config.name);
+    chain_config.save_with_base_path(shell, chain_path)?;
+
+    let message = msg_chain_da_mode_set(args.mode, transition_batch);
+    if args.restart {
+        logger::info(message);
+        match stop_chain_components(shell, &chain_config)? {
+            0 => logger::warn(MSG_NO_RUNNING_CHAIN_COMPONENTS),
+            count => logger::outro(msg_stopped_chain_components(count)),
+        }
+    } else {
+        logger::note(message, MSG_DA_MODE_CONVERSION_NOTE);
+    }
+    Ok(())
+}
@@ -0,0 +1,592 @@
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use common::{config::global_config, logger, spinner::Spinner, wallets::Wallet, PromptConfirm};
+use config::{traits::ConfigWithL2RpcUrl, EcosystemConfig};
+use ethers::{
+    abi::{encode, parse_abi, RawLog, Token},
+    contract::{abigen, BaseContract},
+    middleware::SignerMiddleware,
+    providers::{Http, Middleware, Provider},
+    signers::Signer,
+    types::{Address, Bytes, Filter, TransactionRequest, H256, U256, U64},
+    utils::keccak256,
+};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use serde_json::json;
+use xshell::Shell;
+use zksync_basic_types::{
+    protocol_version::{ProtocolSemanticVersion, ProtocolVersionId},
+    vm::VmVersion,
+    L1BatchNumber,
+};
+
+use crate::{
+    commands::chain::args::upgrade::ChainUpgradeArgs,
+    messages::{
+        msg_upgrade_base_contract_mismatch_err, msg_upgrade_batch_sealed,
+        msg_upgrade_batch_timeout_err, msg_upgrade_completed, msg_upgrade_cut_hash_mismatch_err,
+        msg_upgrade_not_newer_err, msg_upgrade_not_published_err, msg_upgrade_plan,
+        msg_upgrade_version_mismatch_err, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_GOVERNOR_PRIVATE_KEY_NOT_SET, MSG_L1_SECRETS_MUST_BE_PRESENTED, MSG_RECEIPT_MISSING,
+        MSG_TRANSACTION_FAILED, MSG_UNSIGNED_TXS_UNSUPPORTED_ERR, MSG_UPGRADE_ABORTED,
+        MSG_UPGRADE_CONFIRM_PROMPT, MSG_UPGRADE_CUT_DATA_NOT_FOUND_ERR,
+        MSG_UPGRADE_EXECUTING_CHAIN_SPINNER, MSG_UPGRADE_EXECUTING_GOVERNANCE_SPINNER,
+        MSG_UPGRADE_PREPARING_SPINNER, MSG_UPGRADE_SCHEDULING_SPINNER,
+        MSG_UPGRADE_VERIFYING_SPINNER, MSG_UPGRADE_WAITING_FOR_BATCH_SPINNER,
+    },
+};
+
+abigen!(
+    ZkChainGetters,
+    r"[
+    function getProtocolVersion() external view returns (uint256)
+    function getL2BootloaderBytecodeHash() external view returns (bytes32)
+    function getL2DefaultAccountBytecodeHash() external view returns (bytes32)
+    ]"
+);
+
+abigen!(
+    ChainTypeManager,
+    r"[
+    function protocolVersion() external view returns (uint256)
+    function upgradeCutHash(uint256 _protocolVersion) external view returns (bytes32)
+    ]"
+);
+
+abigen!(
+    Governance,
+    r"[
+    function minDelay() external view returns (uint256)
+    function isOperationReady(bytes32 _id) external view returns (bool)
+    ]"
+);
+
+lazy_static! {
+    /// Functions and events involving the diamond cut data, which cannot be conveniently expressed with `abigen!`.
+    static ref UPGRADE_ABI: BaseContract = BaseContract::from(
+        parse_abi(&[
+            "function upgradeChainFromVersion(uint256 _protocolVersion, ((address,uint8,bool,bytes4[])[],address,bytes) _cutData) external",
+            "function setNewVersionUpgrade(((address,uint8,bool,bytes4[])[],address,bytes) _cutData, uint256 _oldProtocolVersion, uint256 _oldProtocolVersionDeadline, uint256 _newProtocolVersion) external",
+            "function multicall((address,uint256,bytes)[] _calls, bool _requireSuccess) external payable",
+            "function scheduleTransparent(((address,uint256,bytes)[],bytes32,bytes32) _operation, uint256 _delay) external",
+            "function execute(((address,uint256,bytes)[],bytes32,bytes32) _operation) external payable",
+            "event NewUpgradeCutData(uint256 indexed protocolVersion, ((address,uint8,bool,bytes4[])[],address,bytes) diamondCutData)",
+        ])
+        .unwrap(),
+    );
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Diamond cut data in the format used by the protocol upgrade tool.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiamondCutData {
+    facet_cuts: Vec<FacetCut>,
+    init_address: Address,
+    init_calldata: Bytes,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FacetCut {
+    facet: Address,
+    action: u8,
+    is_freezable: bool,
+    selectors: Vec<Bytes>,
+}
+
+impl DiamondCutData {
+    fn into_token(self) -> anyhow::Result<Token> {
+        let facet_cuts = self
+            .facet_cuts
+            .into_iter()
+            .map(|cut| {
+                let selectors = cut
+                    .selectors
+                    .into_iter()
+                    .map(|selector| {
+                        anyhow::ensure!(selector.len() == 4, "invalid selector {selector}");
+                        Ok(Token::FixedBytes(selector.to_vec()))
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+                Ok(Token::Tuple(vec![
+                    Token::Address(cut.facet),
+                    Token::Uint(cut.action.into()),
+                    Token::Bool(cut.is_freezable),
+                    Token::Array(selectors),
+                ]))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Token::Tuple(vec![
+            Token::Array(facet_cuts),
+            Token::Address(self.init_address),
+            Token::Bytes(self.init_calldata.to_vec()),
+        ]))
+    }
+}
+
+/// Subset of L2 block details returned by `zks_getBlockDetails`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct L2BlockDetails {
+    l1_batch_number: L1BatchNumber,
+    protocol_version: Option<ProtocolVersionId>,
+}
+
+/// Subset of protocol version info returned by `zks_getProtocolVersion`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct L2ProtocolVersion {
+    bootloader_code_hash: Option<H256>,
+    default_account_code_hash: Option<H256>,
+}
+
+/// Transaction sent to an L1 contract.
+#[derive(Debug)]
+struct L1Call {
+    target: Address,
+    calldata: Bytes,
+}
+
+pub(super) async fn run(args: ChainUpgradeArgs, shell: &Shell) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        args.calldata_only || global_config().unsigned_txs_dir.is_none(),
+        MSG_UNSIGNED_TXS_UNSUPPORTED_ERR
+    );
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let contracts_config = chain_config.get_contracts_config()?;
+    let l1_rpc_url = chain_config
+        .get_secrets_config()?
+        .l1
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_owned();
+    let l1_provider = Arc::new(Provider::<Http>::try_from(l1_rpc_url.as_str())?);
+    let diamond_proxy_addr = contracts_config.l1.diamond_proxy_addr;
+    let ctm_addr = contracts_config
+        .ecosystem_contracts
+        .state_transition_proxy_addr;
+    let governance_addr = contracts_config.l1.governance_addr;
+    let chain_admin_addr = contracts_config.l1.chain_admin_addr;
+    let diamond_proxy = ZkChainGetters::new(diamond_proxy_addr, l1_provider.clone());
+    let ctm = ChainTypeManager::new(ctm_addr, l1_provider.clone());
+
+    let spinner = Spinner::new(MSG_UPGRADE_PREPARING_SPINNER);
+    let old_version = unpack_version(
+        diamond_proxy
+            .get_protocol_version()
+            .call()
+            .await
+            .context("getProtocolVersion()")?,
+    )?;
+    let ctm_version = unpack_version(
+        ctm.protocol_version()
+            .call()
+            .await
+            .context("ChainTypeManager.protocolVersion()")?,
+    )?;
+    let new_version = args.protocol_version.unwrap_or(ctm_version);
+    anyhow::ensure!(
+        new_version > old_version,
+        msg_upgrade_not_newer_err(old_version, new_version)
+    );
+    let is_published = new_version <= ctm_version;
+
+    let cut_data = match &args.upgrade_data {
+        Some(path) => load_diamond_cut(shell, path)?,
+        None if is_published => fetch_diamond_cut(&l1_provider, ctm_addr, new_version).await?,
+        None => anyhow::bail!(msg_upgrade_not_published_err(new_version)),
+    };
+    if is_published {
+        check_cut_hash(&ctm, old_version, &cut_data).await?;
+    }
+
+    // Publishing the upgrade in the chain type manager requires an ecosystem governance operation.
+    let governance_operation = (!is_published).then(|| {
+        let calldata = UPGRADE_ABI
+            .encode(
+                "setNewVersionUpgrade",
+                (
+                    cut_data.clone(),
+                    pack_version(old_version),
+                    // The old protocol version has no deadline.
+                    U256::MAX,
+                    pack_version(new_version),
+                ),
+            )
+            .unwrap();
+        Token::Tuple(vec![
+            Token::Array(vec![call_token(ctm_addr, calldata)]),
+            Token::FixedBytes(H256::zero().0.to_vec()),
+            Token::FixedBytes(H256::zero().0.to_vec()),
+        ])
+    });
+    let upgrade_calldata = UPGRADE_ABI
+        .encode(
+            "upgradeChainFromVersion",
+            (pack_version(old_version), cut_data.clone()),
+        )
+        .unwrap();
+    let chain_admin_call = L1Call {
+        target: chain_admin_addr,
+        calldata: UPGRADE_ABI
+            .encode(
+                "multicall",
+                (vec![call_token(diamond_proxy_addr, upgrade_calldata)], true),
+            )
+            .unwrap(),
+    };
+    spinner.finish();
+
+    let governance = Governance::new(governance_addr, l1_provider.clone());
+    let governance_operation = match governance_operation {
+        Some(operation) => {
+            let min_delay = governance
+                .min_delay()
+                .call()
+                .await
+                .context("Governance.minDelay()")?;
+            Some((operation, min_delay))
+        }
+        None => None,
+    };
+
+    if args.calldata_only {
+        let governance = governance_operation.map(|(operation, min_delay)| {
+            json!({
+                "address": governance_addr,
+                "schedule_calldata": schedule_call(governance_addr, operation.clone(), min_delay).calldata,
+                "execute_calldata": execute_call(governance_addr, operation).calldata,
+            })
+        });
+        let output = json!({
+            "old_protocol_version": old_version,
+            "new_protocol_version": new_version,
+            "governance": governance,
+            "chain_admin": {
+                "address": chain_admin_call.target,
+                "calldata": chain_admin_call.calldata,
+            },
+        });
+        logger::raw(serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    logger::info(msg_upgrade_plan(old_version, new_version));
+    if !PromptConfirm::new(MSG_UPGRADE_CONFIRM_PROMPT)
+        .default(true)
+        .ask()
+    {
+        logger::outro(MSG_UPGRADE_ABORTED);
+        return Ok(());
+    }
+
+    let l1_chain_id = l1_provider.get_chainid().await?.as_u64();
+    if let Some((operation, min_delay)) = governance_operation {
+        let ecosystem_governor = ecosystem_config.get_wallets()?.governor;
+        let spinner = Spinner::new(MSG_UPGRADE_SCHEDULING_SPINNER);
+        let schedule = schedule_call(governance_addr, operation.clone(), min_delay);
+        send_call(&l1_provider, l1_chain_id, &ecosystem_governor, schedule).await?;
+        // `Governance.hashOperation()` is the hash of the ABI-encoded operation.
+        let operation_id = keccak256(encode(&[operation.clone()]));
+        while !governance
+            .is_operation_ready(operation_id)
+            .call()
+            .await
+            .context("Governance.isOperationReady()")?
+        {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        spinner.finish();
+
+        let spinner = Spinner::new(MSG_UPGRADE_EXECUTING_GOVERNANCE_SPINNER);
+        let execute = execute_call(governance_addr, operation);
+        send_call(&l1_provider, l1_chain_id, &ecosystem_governor, execute).await?;
+        check_cut_hash(&ctm, old_version, &cut_data).await?;
+        spinner.finish();
+    }
+
+    let spinner = Spinner::new(MSG_UPGRADE_EXECUTING_CHAIN_SPINNER);
+    let chain_governor = chain_config.get_wallets_config()?.governor;
+    send_call(&l1_provider, l1_chain_id, &chain_governor, chain_admin_call).await?;
+    let l1_version = unpack_version(
+        diamond_proxy
+            .get_protocol_version()
+            .call()
+            .await
+            .context("getProtocolVersion()")?,
+    )?;
+    anyhow::ensure!(
+        l1_version == new_version,
+        msg_upgrade_version_mismatch_err("diamond proxy", new_version, l1_version)
+    );
+    spinner.finish();
+
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+    let l2_provider = Provider::<Http>::try_from(l2_rpc_url.as_str())?;
+    let spinner = Spinner::new(MSG_UPGRADE_WAITING_FOR_BATCH_SPINNER);
+    let upgrade_batch = wait_for_upgrade_batch(
+        &l2_provider,
+        new_version.minor,
+        Duration::from_secs(args.timeout),
+    )
+    .await?
+    .with_context(|| msg_upgrade_batch_timeout_err(new_version))?;
+    spinner.finish();
+    logger::info(msg_upgrade_batch_sealed(upgrade_batch));
+
+    let spinner = Spinner::new(MSG_UPGRADE_VERIFYING_SPINNER);
+    let l2_version: Option<L2ProtocolVersion> = l2_provider
+        .request("zks_getProtocolVersion", [new_version.minor as u16])
+        .await
+        .context("zks_getProtocolVersion")?;
+    let l2_version = l2_version
+        .with_context(|| msg_upgrade_version_mismatch_err("server", new_version, "no version"))?;
+    let bootloader_hash = diamond_proxy
+        .get_l2_bootloader_bytecode_hash()
+        .call()
+        .await
+        .context("getL2BootloaderBytecodeHash()")?;
+    anyhow::ensure!(
+        l2_version.bootloader_code_hash == Some(bootloader_hash.into()),
+        msg_upgrade_base_contract_mismatch_err(
+            "Bootloader",
+            H256::from(bootloader_hash),
+            l2_version.bootloader_code_hash
+        )
+    );
+    let default_account_hash = diamond_proxy
+        .get_l2_default_account_bytecode_hash()
+        .call()
+        .await
+        .context("getL2DefaultAccountBytecodeHash()")?;
+    anyhow::ensure!(
+        l2_version.default_account_code_hash == Some(default_account_hash.into()),
+        msg_upgrade_base_contract_mismatch_err(
+            "Default account",
+            H256::from(default_account_hash),
+            l2_version.default_account_code_hash
+        )
+    );
+    spinner.finish();
+
+    logger::outro(msg_upgrade_completed(
+        new_version,
+        VmVersion::from(new_version.minor),
+    ));
+    Ok(())
+}
+
+fn unpack_version(packed: U256) -> anyhow::Result<ProtocolSemanticVersion> {
+    ProtocolSemanticVersion::try_from_packed(zksync_basic_types::U256(packed.0))
+        .map_err(|err| anyhow::anyhow!("invalid packed protocol version {packed}: {err}"))
+}
+
+fn pack_version(version: ProtocolSemanticVersion) -> U256 {
+    U256(version.pack().0)
+}
+
+fn call_token(target: Address, calldata: Bytes) -> Token {
+    Token::Tuple(vec![
+        Token::Address(target),
+        Token::Uint(U256::zero()),
+        Token::Bytes(calldata.to_vec()),
+    ])
+}
+
+fn schedule_call(governance_addr: Address, operation: Token, delay: U256) -> L1Call {
+    L1Call {
+        target: governance_addr,
+        calldata: UPGRADE_ABI
+            .encode("scheduleTransparent", (operation, delay))
+            .unwrap(),
+    }
+}
+
+fn execute_call(governance_addr: Address, operation: Token) -> L1Call {
+    L1Call {
+        target: governance_addr,
+        calldata: UPGRADE_ABI.encode("execute", (operation,)).unwrap(),
+    }
+}
+
+/// Loads the diamond cut data either from a bare JSON object, or from the `diamondCut` field of the upgrade transactions
+/// file produced by the protocol upgrade tool.
+fn load_diamond_cut(shell: &Shell, path: &Path) -> anyhow::Result<Token> {
+    let contents = shell.read_file(path)?;
+    parse_diamond_cut(&contents)
+        .with_context(|| format!("invalid upgrade data in {}", path.display()))
+}
+
+fn parse_diamond_cut(contents: &str) -> anyhow::Result<Token> {
+    let value: serde_json::Value = serde_json::from_str(contents)?;
+    let value = value.get("diamondCut").cloned().unwrap_or(value);
+    let cut_data: DiamondCutData = serde_json::from_value(value)?;
+    cut_data.into_token()
+}
+
+/// Fetches the diamond cut data published in the chain type manager for the specified protocol version.
+async fn fetch_diamond_cut(
+    l1_provider: &Provider<Http>,
+    ctm_addr: Address,
+    version: ProtocolSemanticVersion,
+) -> anyhow::Result<Token> {
+    let event = UPGRADE_ABI.abi().event("NewUpgradeCutData")?;
+    let filter = Filter::new()
+        .address(ctm_addr)
+        .topic0(event.signature())
+        .topic1(H256::from_uint(&pack_version(version)))
+        .from_block(0);
+    let logs = l1_provider
+        .get_logs(&filter)
+        .await
+        .context("failed fetching NewUpgradeCutData events")?;
+    // If the upgrade was re-published, the latest cut data is used.
+    let log = logs.last().context(MSG_UPGRADE_CUT_DATA_NOT_FOUND_ERR)?;
+    let log = event.parse_log(RawLog {
+        topics: log.topics.clone(),
+        data: log.data.to_vec(),
+    })?;
+    log.params
+        .into_iter()
+        .find(|param| param.name == "diamondCutData")
+        .map(|param| param.value)
+        .context("NewUpgradeCutData event has no cut data")
+}
+
+/// Checks that the cut data matches the upgrade registered in the chain type manager, so that the chain upgrade
+/// transaction doesn't revert.
+async fn check_cut_hash(
+    ctm: &ChainTypeManager<Provider<Http>>,
+    old_version: ProtocolSemanticVersion,
+    cut_data: &Token,
+) -> anyhow::Result<()> {
+    let expected_hash = ctm
+        .upgrade_cut_hash(pack_version(old_version))
+        .call()
+        .await
+        .context("ChainTypeManager.upgradeCutHash()")?;
+    anyhow::ensure!(
+        expected_hash == keccak256(encode(&[cut_data.clone()])),
+        msg_upgrade_cut_hash_mismatch_err(old_version)
+    );
+    Ok(())
+}
+
+async fn send_call(
+    l1_provider: &Arc<Provider<Http>>,
+    l1_chain_id: u64,
+    wallet: &Wallet,
+    call: L1Call,
+) -> anyhow::Result<()> {
+    let private_key = wallet
+        .private_key
+        .clone()
+        .context(MSG_GOVERNOR_PRIVATE_KEY_NOT_SET)?;
+    let signer = SignerMiddleware::new(l1_provider.clone(), private_key.with_chain_id(l1_chain_id));
+    let tx = TransactionRequest::new()
+        .to(call.target)
+        .data(call.calldata);
+    let receipt = signer
+        .send_transaction(tx, None)
+        .await
+        .with_context(|| format!("failed sending transaction to {:#x}", call.target))?
+        .await?
+        .context(MSG_RECEIPT_MISSING)?;
+    anyhow::ensure!(receipt.status == Some(U64::one()), MSG_TRANSACTION_FAILED);
+    Ok(())
+}
+
+/// Waits until the server seals an L2 block with the new protocol version and returns the L1 batch containing it.
+/// Returns `None` on timeout.
+async fn wait_for_upgrade_batch(
+    l2_provider: &Provider<Http>,
+    new_version: ProtocolVersionId,
+    timeout: Duration,
+) -> anyhow::Result<Option<L1BatchNumber>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let block_number = l2_provider.get_block_number().await?;
+        let details: Option<L2BlockDetails> = l2_provider
+            .request("zks_getBlockDetails", [block_number.as_u32()])
+            .await
+            .context("zks_getBlockDetails")?;
+        if let Some(details) = details {
+            if details.protocol_version >= Some(new_version) {
+                return Ok(Some(details.l1_batch_number));
+            }
+        }
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::utils::hex;
+
+    use super::*;
+
+    #[test]
+    fn parsing_diamond_cut_from_upgrade_transactions() {
+        let contents = r#"{
+            "chainAdminUpgradeCalldata": "0x",
+            "diamondCut": {
+                "facetCuts": [{
+                    "facet": "0xfafafafafafafafafafafafafafafafafafafafa",
+                    "action": 1,
+                    "isFreezable": true,
+                    "selectors": ["0x12345678"]
+                }],
+                "initAddress": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "initCalldata": "0xdeadbeef"
+            }
+        }"#;
+        let cut_data = parse_diamond_cut(contents).unwrap();
+
+        let facet_cut = Token::Tuple(vec![
+            Token::Address(Address::repeat_byte(0xfa)),
+            Token::Uint(1.into()),
+            Token::Bool(true),
+            Token::Array(vec![Token::FixedBytes(vec![0x12, 0x34, 0x56, 0x78])]),
+        ]);
+        let expected = Token::Tuple(vec![
+            Token::Array(vec![facet_cut]),
+            Token::Address(Address::repeat_byte(0xaa)),
+            Token::Bytes(hex::decode("deadbeef").unwrap()),
+        ]);
+        assert_eq!(cut_data, expected);
+        // Cut data must be encodable as an argument of the upgrade functions.
+        UPGRADE_ABI
+            .encode("upgradeChainFromVersion", (U256::zero(), cut_data))
+            .unwrap();
+    }
+
+    #[test]
+    fn rejecting_invalid_selectors() {
+        let contents = r#"{
+            "facetCuts": [{
+                "facet": "0xfafafafafafafafafafafafafafafafafafafafa",
+                "action": 0,
+                "isFreezable": false,
+                "selectors": ["0x1234"]
+            }],
+            "initAddress": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "initCalldata": "0x"
+        }"#;
+        parse_diamond_cut(contents).unwrap_err();
+    }
+}
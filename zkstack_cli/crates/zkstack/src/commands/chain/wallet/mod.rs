@@ -0,0 +1,20 @@
+use clap::Subcommand;
+use xshell::Shell;
+
+use crate::commands::chain::args::rotate_wallets::RotateWalletsArgs;
+
+mod rotate;
+
+#[derive(Subcommand, Debug)]
+pub enum WalletCommands {
+    /// Rotate operator, blob operator and / or fee account keys of the chain.
+    /// Updates validator registrations on L1 and the chain wallets config;
+    /// the server must be restarted afterwards.
+    Rotate(RotateWalletsArgs),
+}
+
+pub(crate) async fn run(shell: &Shell, args: WalletCommands) -> anyhow::Result<()> {
+    match args {
+        WalletCommands::Rotate(args) => rotate::run(args, shell).await,
+    }
+}
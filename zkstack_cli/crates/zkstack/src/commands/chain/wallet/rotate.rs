@@ -0,0 +1,390 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use common::{config::global_config, logger, spinner::Spinner, wallets::Wallet, PromptConfirm};
+use config::{
+    traits::{ConfigWithL2RpcUrl, FileConfigWithDefaultName, SaveConfig},
+    ChainConfig, EcosystemConfig, WalletsConfig,
+};
+use ethers::{
+    contract::abigen,
+    middleware::SignerMiddleware,
+    providers::{Http, Middleware, Provider},
+    signers::Signer,
+    types::{Address, Bytes, U256, U64},
+};
+use rand::thread_rng;
+use sqlx::{Connection, PgConnection};
+use types::{L1Network, WalletCreation};
+use xshell::Shell;
+
+use crate::{
+    commands::chain::args::rotate_wallets::{
+        RotateWalletsArgs, RotateWalletsArgsFinal, WalletRole,
+    },
+    consts::AMOUNT_FOR_DISTRIBUTION_TO_WALLETS,
+    messages::{
+        msg_rotate_unconfirmed_eth_txs_err, msg_rotate_unfunded_wallet_warning,
+        msg_rotate_validator_not_registered_err, msg_rotate_verify_timeout_err, msg_rotate_wallet,
+        msg_rotate_wallets_saved, MSG_CHAIN_NOT_INITIALIZED, MSG_DATABASE_MUST_BE_PRESENTED,
+        MSG_DISTRIBUTING_ETH_SPINNER, MSG_GOVERNOR_PRIVATE_KEY_NOT_SET,
+        MSG_L1_SECRETS_MUST_BE_PRESENTED, MSG_RECEIPT_MISSING, MSG_ROTATE_ABORTED,
+        MSG_ROTATE_CHECKING_ETH_TXS_SPINNER, MSG_ROTATE_CONFIRM_PROMPT, MSG_ROTATE_RESTART_NOTE,
+        MSG_ROTATE_UPDATING_VALIDATORS_SPINNER, MSG_ROTATE_VERIFIED,
+        MSG_ROTATE_WAITING_FOR_SERVER_SPINNER, MSG_TRANSACTION_FAILED,
+        MSG_UNSIGNED_TXS_UNSUPPORTED_ERR,
+    },
+};
+
+abigen!(
+    ValidatorTimelock,
+    r"[
+    function addValidator(uint256 _chainId, address _newValidator) external
+    function removeValidator(uint256 _chainId, address _validator) external
+    function validators(uint256 _chainId, address _validator) external view returns (bool)
+    ]"
+);
+
+abigen!(
+    ChainAdmin,
+    r"[
+    function multicall((address,uint256,bytes)[] _calls, bool _requireSuccess) external payable
+    ]"
+);
+
+const VERIFY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Wallet being rotated.
+#[derive(Debug)]
+struct RotatedWallet {
+    role: WalletRole,
+    old: Wallet,
+    new: Wallet,
+}
+
+pub(super) async fn run(args: RotateWalletsArgs, shell: &Shell) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        global_config().unsigned_txs_dir.is_none(),
+        MSG_UNSIGNED_TXS_UNSUPPORTED_ERR
+    );
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let args = args.fill_values_with_prompt()?;
+
+    let secrets = chain_config.get_secrets_config()?;
+    let l1_rpc_url = secrets
+        .l1
+        .as_ref()
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_owned();
+    let db_url = secrets
+        .database
+        .as_ref()
+        .context(MSG_DATABASE_MUST_BE_PRESENTED)?
+        .master_url()?;
+
+    // Transactions already created by the eth sender are signed with the current keys (and, for the blob operator,
+    // have nonces specific to the current key), so they must be confirmed before the keys change.
+    let spinner = Spinner::new(MSG_ROTATE_CHECKING_ETH_TXS_SPINNER);
+    let mut db = PgConnection::connect(db_url.expose_str())
+        .await
+        .context("failed connecting to server DB")?;
+    let unconfirmed_txs_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM eth_txs WHERE confirmed_eth_tx_history_id IS NULL AND NOT has_failed",
+    )
+    .fetch_one(&mut db)
+    .await
+    .context("failed querying unconfirmed L1 transactions")?;
+    spinner.finish();
+    if unconfirmed_txs_count > 0 {
+        anyhow::bail!(msg_rotate_unconfirmed_eth_txs_err(unconfirmed_txs_count));
+    }
+
+    let mut wallets = chain_config.get_wallets_config()?;
+    let rotated = generate_wallets(&wallets, &args);
+    for wallet in &rotated {
+        logger::info(msg_rotate_wallet(
+            wallet.role,
+            wallet.old.address,
+            wallet.new.address,
+        ));
+    }
+    if !PromptConfirm::new(MSG_ROTATE_CONFIRM_PROMPT)
+        .default(true)
+        .ask()
+    {
+        logger::outro(MSG_ROTATE_ABORTED);
+        return Ok(());
+    }
+
+    let l1_provider = Provider::<Http>::try_from(l1_rpc_url.as_str())?;
+    fund_operators(&ecosystem_config, &chain_config, &rotated, &l1_rpc_url).await?;
+    for wallet in &rotated {
+        if wallet.role != WalletRole::FeeAccount
+            && l1_provider
+                .get_balance(wallet.new.address, None)
+                .await?
+                .is_zero()
+        {
+            logger::warn(msg_rotate_unfunded_wallet_warning(
+                wallet.role,
+                wallet.new.address,
+            ));
+        }
+    }
+
+    if !args.skip_validator_update {
+        let spinner = Spinner::new(MSG_ROTATE_UPDATING_VALIDATORS_SPINNER);
+        update_validators(&chain_config, &wallets, &rotated, l1_provider.clone()).await?;
+        spinner.finish();
+    }
+
+    let wallets_path = WalletsConfig::get_path_with_base_path(&chain_config.configs);
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let backup_path = chain_config
+        .configs
+        .join(format!("wallets.{timestamp}.backup.yaml"));
+    wallets.save(shell, &backup_path)?;
+    // Record L1 nonces before the server is restarted so that verification can detect new transactions.
+    let mut initial_nonces = vec![];
+    for wallet in &rotated {
+        let nonce = l1_provider
+            .get_transaction_count(wallet.new.address, None)
+            .await?;
+        initial_nonces.push(nonce);
+        match wallet.role {
+            WalletRole::Operator => wallets.operator = wallet.new.clone(),
+            WalletRole::BlobOperator => wallets.blob_operator = wallet.new.clone(),
+            WalletRole::FeeAccount => wallets.fee_account = wallet.new.clone(),
+        }
+    }
+    wallets.save(shell, &wallets_path)?;
+    logger::info(msg_rotate_wallets_saved(&wallets_path, &backup_path));
+
+    let Some(verify_timeout) = args.verify_timeout else {
+        logger::outro(MSG_ROTATE_RESTART_NOTE);
+        return Ok(());
+    };
+    logger::info(MSG_ROTATE_RESTART_NOTE);
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+    let l2_provider = Provider::<Http>::try_from(l2_rpc_url.as_str())?;
+
+    let spinner = Spinner::new(MSG_ROTATE_WAITING_FOR_SERVER_SPINNER);
+    let deadline = Instant::now() + verify_timeout;
+    let mut pending: Vec<_> = rotated.iter().zip(initial_nonces).collect();
+    while !pending.is_empty() {
+        let mut still_pending = vec![];
+        for (wallet, initial_nonce) in pending {
+            if !is_wallet_used(wallet, initial_nonce, &l1_provider, &l2_provider).await? {
+                still_pending.push((wallet, initial_nonce));
+            }
+        }
+        pending = still_pending;
+        if pending.is_empty() {
+            break;
+        }
+        if Instant::now() >= deadline {
+            let pending: Vec<_> = pending
+                .iter()
+                .map(|(wallet, _)| wallet.role.to_string())
+                .collect();
+            anyhow::bail!(msg_rotate_verify_timeout_err(&pending));
+        }
+        tokio::time::sleep(VERIFY_POLL_INTERVAL).await;
+    }
+    spinner.finish();
+    logger::outro(MSG_ROTATE_VERIFIED);
+    Ok(())
+}
+
+fn generate_wallets(wallets: &WalletsConfig, args: &RotateWalletsArgsFinal) -> Vec<RotatedWallet> {
+    let mut rng = thread_rng();
+    args.wallets
+        .iter()
+        .map(|(role, private_key)| {
+            let old = match role {
+                WalletRole::Operator => &wallets.operator,
+                WalletRole::BlobOperator => &wallets.blob_operator,
+                WalletRole::FeeAccount => &wallets.fee_account,
+            };
+            let new = match private_key {
+                Some(key) => Wallet::new(key.clone()),
+                None => Wallet::random(&mut rng),
+            };
+            RotatedWallet {
+                role: *role,
+                old: old.clone(),
+                new,
+            }
+        })
+        .collect()
+}
+
+/// Funds new operator wallets for localhost environments, similarly to `chain init`.
+async fn fund_operators(
+    ecosystem_config: &EcosystemConfig,
+    chain_config: &ChainConfig,
+    rotated: &[RotatedWallet],
+    l1_rpc_url: &str,
+) -> anyhow::Result<()> {
+    if chain_config.wallet_creation != WalletCreation::Localhost
+        || ecosystem_config.l1_network != L1Network::Localhost
+    {
+        return Ok(());
+    }
+    let addresses: Vec<_> = rotated
+        .iter()
+        .filter(|wallet| wallet.role != WalletRole::FeeAccount)
+        .map(|wallet| wallet.new.address)
+        .collect();
+    if addresses.is_empty() {
+        return Ok(());
+    }
+
+    let spinner = Spinner::new(MSG_DISTRIBUTING_ETH_SPINNER);
+    common::ethereum::distribute_eth(
+        ecosystem_config.get_wallets()?.operator,
+        addresses,
+        l1_rpc_url.to_owned(),
+        ecosystem_config.l1_network.chain_id(),
+        AMOUNT_FOR_DISTRIBUTION_TO_WALLETS,
+    )
+    .await?;
+    spinner.finish();
+    Ok(())
+}
+
+/// Registers new operators as validators and unregisters the old ones in a single `ChainAdmin` multicall
+/// sent by the chain governor.
+async fn update_validators(
+    chain_config: &ChainConfig,
+    wallets: &WalletsConfig,
+    rotated: &[RotatedWallet],
+    l1_provider: Provider<Http>,
+) -> anyhow::Result<()> {
+    let contracts_config = chain_config.get_contracts_config()?;
+    let chain_id = U256::from(chain_config.chain_id.as_u64());
+    let validator_timelock_addr = contracts_config.l1.validator_timelock_addr;
+    let validator_timelock =
+        ValidatorTimelock::new(validator_timelock_addr, Arc::new(l1_provider.clone()));
+
+    // Addresses of operators that are not rotated must stay registered.
+    let retained_validators: Vec<_> = [
+        (WalletRole::Operator, wallets.operator.address),
+        (WalletRole::BlobOperator, wallets.blob_operator.address),
+    ]
+    .into_iter()
+    .filter(|(role, _)| rotated.iter().all(|wallet| wallet.role != *role))
+    .map(|(_, address)| address)
+    .collect();
+    let mut new_validators = vec![];
+    let mut old_validators = vec![];
+    for wallet in rotated {
+        if wallet.role == WalletRole::FeeAccount {
+            continue;
+        }
+        if !new_validators.contains(&wallet.new.address) {
+            new_validators.push(wallet.new.address);
+        }
+        if !old_validators.contains(&wallet.old.address) {
+            old_validators.push(wallet.old.address);
+        }
+    }
+    old_validators.retain(|address| {
+        !new_validators.contains(address) && !retained_validators.contains(address)
+    });
+
+    let mut calls = vec![];
+    for &address in &new_validators {
+        if !is_validator(&validator_timelock, chain_id, address).await? {
+            let call = validator_timelock.add_validator(chain_id, address);
+            calls.push(call_tuple(validator_timelock_addr, call.calldata()));
+        }
+    }
+    for &address in &old_validators {
+        if is_validator(&validator_timelock, chain_id, address).await? {
+            let call = validator_timelock.remove_validator(chain_id, address);
+            calls.push(call_tuple(validator_timelock_addr, call.calldata()));
+        }
+    }
+    if calls.is_empty() {
+        return Ok(());
+    }
+
+    let governor = chain_config
+        .get_wallets_config()?
+        .governor
+        .private_key
+        .context(MSG_GOVERNOR_PRIVATE_KEY_NOT_SET)?;
+    let l1_chain_id = l1_provider.get_chainid().await?.as_u64();
+    let signer = Arc::new(SignerMiddleware::new(
+        l1_provider,
+        governor.with_chain_id(l1_chain_id),
+    ));
+    let chain_admin = ChainAdmin::new(contracts_config.l1.chain_admin_addr, signer);
+    let receipt = chain_admin
+        .multicall(calls, true)
+        .send()
+        .await
+        .context("ChainAdmin.multicall()")?
+        .await?
+        .context(MSG_RECEIPT_MISSING)?;
+    anyhow::ensure!(receipt.status == Some(U64::one()), MSG_TRANSACTION_FAILED);
+
+    for address in new_validators {
+        anyhow::ensure!(
+            is_validator(&validator_timelock, chain_id, address).await?,
+            msg_rotate_validator_not_registered_err(address)
+        );
+    }
+    Ok(())
+}
+
+async fn is_validator(
+    validator_timelock: &ValidatorTimelock<Provider<Http>>,
+    chain_id: U256,
+    address: Address,
+) -> anyhow::Result<bool> {
+    validator_timelock
+        .validators(chain_id, address)
+        .call()
+        .await
+        .context("ValidatorTimelock.validators()")
+}
+
+fn call_tuple(target: Address, calldata: Option<Bytes>) -> (Address, U256, Bytes) {
+    (target, U256::zero(), calldata.unwrap_or_default())
+}
+
+/// Checks whether the server uses the rotated wallet: operators must send an L1 transaction, and the fee account
+/// must be used as the operator address of the latest L2 block.
+async fn is_wallet_used(
+    wallet: &RotatedWallet,
+    initial_nonce: U256,
+    l1_provider: &Provider<Http>,
+    l2_provider: &Provider<Http>,
+) -> anyhow::Result<bool> {
+    if wallet.role != WalletRole::FeeAccount {
+        let nonce = l1_provider
+            .get_transaction_count(wallet.new.address, None)
+            .await?;
+        return Ok(nonce > initial_nonce);
+    }
+
+    let block_number = l2_provider.get_block_number().await?;
+    let details: Option<serde_json::Value> = l2_provider
+        .request("zks_getBlockDetails", [block_number.as_u32()])
+        .await
+        .context("zks_getBlockDetails")?;
+    let operator_address = details.and_then(|details| {
+        serde_json::from_value::<Address>(details["operatorAddress"].clone()).ok()
+    });
+    Ok(operator_address == Some(wallet.new.address))
+}
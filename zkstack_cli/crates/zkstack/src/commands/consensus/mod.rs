@@ -14,15 +14,17 @@ use ethers::{
     signers::{LocalWallet, Signer as _},
     types::{Address, BlockId, H256},
 };
+use serde::Serialize;
 use tokio::time::MissedTickBehavior;
 use xshell::Shell;
-use zksync_consensus_crypto::ByteFmt;
+use zksync_consensus_crypto::{ByteFmt, TextFmt};
 use zksync_consensus_roles::{attester, validator};
 
 use crate::{commands::args::WaitArgs, messages, utils::consensus::parse_attester_committee};
 
 mod conv;
 mod proto;
+mod status;
 #[cfg(test)]
 mod tests;
 
@@ -95,6 +97,9 @@ pub enum Command {
     GetAttesterCommittee,
     /// Wait until the consensus registry contract is deployed to L2.
     WaitForRegistry(WaitArgs),
+    /// Shows the consensus view of the node: current view and leader, last certificates,
+    /// committee weights, and connectivity to consensus peers.
+    Status(status::StatusArgs),
 }
 
 /// Collection of sent transactions.
@@ -113,8 +118,9 @@ impl TxSet {
         Ok(())
     }
 
-    /// Waits for all stored transactions to complete.
-    pub async fn wait<P: JsonRpcClient>(self, provider: &Provider<P>) -> anyhow::Result<()> {
+    /// Waits for all stored transactions to complete. Returns hashes of the transactions.
+    pub async fn wait<P: JsonRpcClient>(self, provider: &Provider<P>) -> anyhow::Result<Vec<H256>> {
+        let mut hashes = Vec::with_capacity(self.0.len());
         for (h, name) in self.0 {
             async {
                 let status = PendingTransaction::new(h, provider)
@@ -128,12 +134,28 @@ impl TxSet {
             }
             .await
             .context(name)?;
+            hashes.push(h);
         }
-        Ok(())
+        Ok(hashes)
     }
 }
 
-fn print_attesters(committee: &attester::Committee) {
+/// Attester committee printed in the JSON output mode.
+#[derive(Debug, Serialize)]
+struct AttesterCommitteeOutput {
+    attesters: Vec<AttesterOutput>,
+    /// Hashes of the transactions sent to update the committee.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tx_hashes: Vec<H256>,
+}
+
+#[derive(Debug, Serialize)]
+struct AttesterOutput {
+    key: String,
+    weight: u64,
+}
+
+fn print_attesters(committee: &attester::Committee, tx_hashes: Vec<H256>) {
     logger::success(
         committee
             .iter()
@@ -141,6 +163,16 @@ fn print_attesters(committee: &attester::Committee) {
             .collect::<Vec<_>>()
             .join("\n"),
     );
+    logger::result(AttesterCommitteeOutput {
+        attesters: committee
+            .iter()
+            .map(|a| AttesterOutput {
+                key: a.key.encode(),
+                weight: a.weight,
+            })
+            .collect(),
+        tx_hashes,
+    });
 }
 
 struct Setup {
@@ -334,7 +366,10 @@ impl Setup {
         .await
     }
 
-    async fn set_attester_committee(&self, want: &attester::Committee) -> anyhow::Result<()> {
+    async fn set_attester_committee(
+        &self,
+        want: &attester::Committee,
+    ) -> anyhow::Result<Vec<H256>> {
         let provider = self.provider().context("provider()")?;
         let block_id = self.last_block(&provider).await.context("last_block()")?;
         let governor = self.governor().context("governor()")?;
@@ -443,8 +478,7 @@ impl Setup {
             consensus_registry.commit_attester_committee(),
         )
         .await?;
-        txs.wait(&provider).await.context("wait()")?;
-        Ok(())
+        txs.wait(&provider).await.context("wait()")
     }
 }
 
@@ -456,22 +490,25 @@ impl Command {
                 let want = setup
                     .read_attester_committee(&opts)
                     .context("read_attester_committee()")?;
-                setup.set_attester_committee(&want).await?;
+                let tx_hashes = setup.set_attester_committee(&want).await?;
                 let got = setup.get_attester_committee().await?;
                 anyhow::ensure!(
                     got == want,
                     messages::msg_setting_attester_committee_failed(&got, &want)
                 );
-                print_attesters(&got);
+                print_attesters(&got, tx_hashes);
             }
             Self::GetAttesterCommittee => {
                 let got = setup.get_attester_committee().await?;
-                print_attesters(&got);
+                print_attesters(&got, vec![]);
             }
             Self::WaitForRegistry(args) => {
                 let verbose = global_config().verbose;
                 setup.wait_for_registry_contract(&args, verbose).await?;
             }
+            Self::Status(args) => {
+                status::run(&setup, &args).await?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,158 @@
+//! Consensus diagnostics.
+
+use std::{fmt::Write as _, time::Duration};
+
+use anyhow::Context as _;
+use common::logger;
+use serde::Deserialize;
+use zksync_basic_types::{L1BatchNumber, L2BlockNumber};
+
+use super::Setup;
+use crate::messages;
+
+/// Member of a consensus committee.
+/// Duplicated from `zksync_types::api::en::ConsensusCommitteeMember`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitteeMember {
+    key: String,
+    weight: u64,
+}
+
+/// Consensus state of a node returned by `en_consensusStatus`.
+/// Duplicated from `zksync_types::api::en::ConsensusStatus`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConsensusStatus {
+    view_number: u64,
+    phase: String,
+    leader: String,
+    last_certified_block: Option<L2BlockNumber>,
+    last_certificate_view: Option<u64>,
+    validators: Vec<CommitteeMember>,
+    last_certified_batch: Option<L1BatchNumber>,
+    next_batch_to_attest: Option<L1BatchNumber>,
+    attesters: Vec<CommitteeMember>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct StatusArgs {
+    /// Timeout for probing connectivity to each consensus peer, in seconds.
+    #[clap(long, default_value_t = 5)]
+    probe_timeout: u64,
+}
+
+fn format_committee(members: &[CommitteeMember]) -> String {
+    let total_weight: u64 = members.iter().map(|member| member.weight).sum();
+    let mut output = String::new();
+    for member in members {
+        writeln!(
+            output,
+            "{} (weight {}/{total_weight})",
+            member.key, member.weight
+        )
+        .unwrap();
+    }
+    output
+}
+
+fn format_optional(value: Option<impl ToString>) -> String {
+    value.map_or_else(|| "none".to_owned(), |value| value.to_string())
+}
+
+/// Checks whether a TCP connection to `addr` can be established.
+async fn probe_peer(addr: &str, timeout: Duration) -> anyhow::Result<()> {
+    tokio::time::timeout(timeout, tokio::net::TcpStream::connect(addr))
+        .await
+        .context(messages::MSG_CONSENSUS_PEER_PROBE_TIMEOUT)?
+        .context(messages::MSG_CONSENSUS_PEER_PROBE_FAILED)?;
+    Ok(())
+}
+
+impl Setup {
+    async fn consensus_status(&self) -> anyhow::Result<Option<ConsensusStatus>> {
+        let provider = self.provider().context("provider()")?;
+        provider
+            .request("en_consensusStatus", ())
+            .await
+            .context("en_consensusStatus")
+    }
+
+    async fn print_peer_connectivity(&self, timeout: Duration) -> anyhow::Result<()> {
+        let consensus = self
+            .general
+            .consensus_config
+            .as_ref()
+            .context(messages::MSG_CONSENSUS_CONFIG_MISSING_ERR)?;
+
+        let mut output = String::new();
+        writeln!(
+            output,
+            "public address: {} (listening on {})",
+            consensus.public_addr.0, consensus.server_addr
+        )?;
+        match probe_peer(&consensus.server_addr.to_string(), timeout).await {
+            Ok(()) => writeln!(output, "  local consensus port is reachable")?,
+            Err(err) => writeln!(output, "  local consensus port is unreachable: {err:#}")?,
+        }
+
+        writeln!(
+            output,
+            "static inbound peers: {}, dynamic inbound limit: {}",
+            consensus.gossip_static_inbound.len(),
+            consensus.gossip_dynamic_inbound_limit
+        )?;
+        if consensus.gossip_static_outbound.is_empty() {
+            writeln!(output, "no static outbound peers")?;
+        }
+        for (node_key, addr) in &consensus.gossip_static_outbound {
+            match probe_peer(&addr.0, timeout).await {
+                Ok(()) => writeln!(output, "{} at {}: reachable", node_key.0, addr.0)?,
+                Err(err) => writeln!(output, "{} at {}: {err:#}", node_key.0, addr.0)?,
+            }
+        }
+        if let Some(addr) = consensus.debug_page_addr {
+            writeln!(
+                output,
+                "live gossip connections are listed on the debug page at http://{addr}"
+            )?;
+        }
+        logger::note(messages::MSG_CONSENSUS_PEERS, output);
+        Ok(())
+    }
+}
+
+pub(super) async fn run(setup: &Setup, args: &StatusArgs) -> anyhow::Result<()> {
+    let status = setup
+        .consensus_status()
+        .await?
+        .context(messages::MSG_CONSENSUS_NOT_ENABLED)?;
+
+    logger::note(
+        messages::MSG_CONSENSUS_VIEW,
+        format!(
+            "view: {} (phase: {})\nleader: {}\nlast certified L2 block: {} (view {})",
+            status.view_number,
+            status.phase,
+            status.leader,
+            format_optional(status.last_certified_block),
+            format_optional(status.last_certificate_view),
+        ),
+    );
+    logger::note(
+        messages::MSG_CONSENSUS_VALIDATORS,
+        format_committee(&status.validators),
+    );
+    logger::note(
+        messages::MSG_CONSENSUS_ATTESTATION,
+        format!(
+            "last certified L1 batch: {}\nnext L1 batch to attest: {}\n{}",
+            format_optional(status.last_certified_batch),
+            format_optional(status.next_batch_to_attest),
+            format_committee(&status.attesters),
+        ),
+    );
+    setup
+        .print_peer_connectivity(Duration::from_secs(args.probe_timeout))
+        .await
+}
@@ -1,15 +1,22 @@
 use std::path::PathBuf;
 
 use anyhow::{anyhow, Context};
-use common::{docker, logger, spinner::Spinner};
-use config::{EcosystemConfig, DOCKER_COMPOSE_FILE, ERA_OBSERVABILITY_COMPOSE_FILE};
+use common::{
+    docker::{self, ContainerRuntime},
+    logger,
+    spinner::Spinner,
+};
+use config::{
+    traits::SaveConfigWithBasePath, EcosystemConfig, DOCKER_COMPOSE_FILE,
+    ERA_OBSERVABILITY_COMPOSE_FILE,
+};
 use xshell::Shell;
 
 use super::args::ContainersArgs;
 use crate::{
     commands::ecosystem::setup_observability,
     messages::{
-        MSG_CONTAINERS_STARTED, MSG_FAILED_TO_FIND_ECOSYSTEM_ERR,
+        msg_using_container_runtime, MSG_CONTAINERS_STARTED, MSG_FAILED_TO_FIND_ECOSYSTEM_ERR,
         MSG_RETRY_START_CONTAINERS_PROMPT, MSG_STARTING_CONTAINERS,
         MSG_STARTING_DOCKER_CONTAINERS_SPINNER,
     },
@@ -17,7 +24,19 @@ use crate::{
 
 pub fn run(shell: &Shell, args: ContainersArgs) -> anyhow::Result<()> {
     let args = args.fill_values_with_prompt();
-    let ecosystem = EcosystemConfig::from_file(shell).context(MSG_FAILED_TO_FIND_ECOSYSTEM_ERR)?;
+    let mut ecosystem =
+        EcosystemConfig::from_file(shell).context(MSG_FAILED_TO_FIND_ECOSYSTEM_ERR)?;
+
+    if let Some(runtime) = args.runtime {
+        docker::set_runtime(runtime);
+        if ecosystem.container_runtime != Some(runtime) {
+            ecosystem.container_runtime = Some(runtime);
+            ecosystem.save_with_base_path(shell, ".")?;
+        }
+    }
+    let runtime = docker::runtime(shell);
+    let rootless = runtime == ContainerRuntime::Podman && docker::is_rootless_podman(shell);
+    logger::info(msg_using_container_runtime(runtime, rootless));
 
     initialize_docker(shell, &ecosystem)?;
 
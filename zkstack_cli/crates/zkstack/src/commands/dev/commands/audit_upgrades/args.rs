@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_AUDIT_UPGRADES_BLOCK_RANGE_HELP, MSG_AUDIT_UPGRADES_FROM_BLOCK_HELP,
+    MSG_AUDIT_UPGRADES_OUTPUT_HELP, MSG_AUDIT_UPGRADES_TO_BLOCK_HELP,
+};
+
+/// Default number of L1 blocks queried by a single `eth_getLogs` call. Most providers limit the range of queried blocks.
+const DEFAULT_BLOCK_RANGE: u64 = 50_000;
+
+#[derive(Debug, Parser)]
+pub struct AuditUpgradesArgs {
+    #[clap(long, default_value_t = 0, help = MSG_AUDIT_UPGRADES_FROM_BLOCK_HELP)]
+    pub from_block: u64,
+    #[clap(long, help = MSG_AUDIT_UPGRADES_TO_BLOCK_HELP)]
+    pub to_block: Option<u64>,
+    #[clap(long, default_value_t = DEFAULT_BLOCK_RANGE, help = MSG_AUDIT_UPGRADES_BLOCK_RANGE_HELP)]
+    pub block_range: u64,
+    #[clap(long = "out", help = MSG_AUDIT_UPGRADES_OUTPUT_HELP)]
+    pub output: Option<PathBuf>,
+}
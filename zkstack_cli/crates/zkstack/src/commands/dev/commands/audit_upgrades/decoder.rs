@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use ethers::{
+    abi::{parse_abi, Event, Function, RawLog, Token},
+    types::{Address, Log, H256, U256},
+    utils::{hex, keccak256},
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use strum::Display;
+use zksync_basic_types::protocol_version::ProtocolSemanticVersion;
+
+/// `Diamond.DiamondCutData` struct as an ABI tuple.
+macro_rules! diamond_cut_data_abi {
+    () => {
+        "((address,uint8,bool,bytes4[])[],address,bytes)"
+    };
+}
+
+/// Governance and upgrade-related events emitted by the ecosystem and chain contracts on L1.
+const EVENTS: &[&str] = &[
+    // `Governance`
+    "event TransparentOperationScheduled(bytes32 indexed _id, uint256 delay, ((address,uint256,bytes)[],bytes32,bytes32) _operation)",
+    "event ShadowOperationScheduled(bytes32 indexed _id, uint256 delay)",
+    "event OperationExecuted(bytes32 indexed _id)",
+    "event OperationCancelled(bytes32 indexed _id)",
+    "event ChangeSecurityCouncil(address _securityCouncilBefore, address _securityCouncilAfter)",
+    "event ChangeMinDelay(uint256 _delayBefore, uint256 _delayAfter)",
+    // `ChainAdmin`
+    "event UpdateUpgradeTimestamp(uint256 indexed protocolVersion, uint256 upgradeTimestamp)",
+    // `StateTransitionManager`
+    concat!(
+        "event NewUpgradeCutData(uint256 indexed protocolVersion, ",
+        diamond_cut_data_abi!(),
+        " diamondCutData)"
+    ),
+    "event NewUpgradeCutHash(uint256 indexed protocolVersion, bytes32 indexed upgradeCutHash)",
+    "event NewProtocolVersion(uint256 indexed oldProtocolVersion, uint256 indexed newProtocolVersion)",
+    "event NewProtocolVersionDeadline(uint256 indexed protocolVersion, uint256 deadline)",
+    "event NewValidatorTimelock(address indexed oldValidatorTimelock, address indexed newValidatorTimelock)",
+    // Diamond proxy of the chain
+    "event DiamondCut((address,uint8,bool,bytes4[])[] facetCuts, address initAddress, bytes initCalldata)",
+    concat!("event ExecuteUpgrade(", diamond_cut_data_abi!(), " diamondCut)"),
+    "event NewPendingAdmin(address indexed oldPendingAdmin, address indexed newPendingAdmin)",
+    "event NewAdmin(address indexed oldAdmin, address indexed newAdmin)",
+    "event ValidatorStatusUpdate(address indexed validatorAddress, bool isActive)",
+    "event NewPriorityTxMaxGasLimit(uint256 oldPriorityTxMaxGasLimit, uint256 newPriorityTxMaxGasLimit)",
+    "event NewFeeParams((uint8,uint32,uint32,uint32,uint32,uint64) oldFeeParams, (uint8,uint32,uint32,uint32,uint32,uint64) newFeeParams)",
+    "event NewBaseTokenMultiplier(uint128 oldNominator, uint128 oldDenominator, uint128 newNominator, uint128 newDenominator)",
+    "event NewTransactionFilterer(address oldTransactionFilterer, address newTransactionFilterer)",
+    "event Freeze()",
+    "event Unfreeze()",
+    // Common to all ownable contracts
+    "event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner)",
+    "event OwnershipTransferred(address indexed previousOwner, address indexed newOwner)",
+];
+
+/// Functions that are commonly called by governance operations.
+const FUNCTIONS: &[&str] = &[
+    concat!(
+        "function setNewVersionUpgrade(",
+        diamond_cut_data_abi!(),
+        " _cutData, uint256 _oldProtocolVersion, uint256 _oldProtocolVersionDeadline, uint256 _newProtocolVersion)"
+    ),
+    concat!(
+        "function setUpgradeDiamondCut(",
+        diamond_cut_data_abi!(),
+        " _cutData, uint256 _oldProtocolVersion)"
+    ),
+    concat!(
+        "function upgradeChainFromVersion(uint256 _protocolVersion, ",
+        diamond_cut_data_abi!(),
+        " _cutData)"
+    ),
+    concat!("function executeUpgrade(", diamond_cut_data_abi!(), " _diamondCut)"),
+    "function setProtocolVersionDeadline(uint256 _protocolVersion, uint256 _timestamp)",
+    "function setUpgradeTimestamp(uint256 _protocolVersion, uint256 _upgradeTimestamp)",
+    "function setValidatorTimelock(address _validatorTimelock)",
+    "function setValidator(address _validator, bool _active)",
+    "function setPriorityTxMaxGasLimit(uint256 _newPriorityTxMaxGasLimit)",
+    "function setTokenMultiplier(uint128 _nominator, uint128 _denominator)",
+    "function setPendingAdmin(address _newPendingAdmin)",
+    "function acceptAdmin()",
+    "function freezeChain(uint256 _chainId)",
+    "function unfreezeChain(uint256 _chainId)",
+    "function transferOwnership(address newOwner)",
+    "function acceptOwnership()",
+    "function upgrade(address proxy, address implementation)",
+    "function upgradeAndCall(address proxy, address implementation, bytes data)",
+];
+
+/// Role of a watched L1 contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub(super) enum ContractKind {
+    Governance,
+    ChainAdmin,
+    Bridgehub,
+    StateTransitionManager,
+    DiamondProxy,
+}
+
+/// Governance or upgrade event with decoded parameters.
+#[derive(Debug, Serialize)]
+pub(super) struct DecodedEvent {
+    pub contract: ContractKind,
+    pub address: Address,
+    pub event: String,
+    pub params: Map<String, Value>,
+}
+
+#[derive(Debug)]
+pub(super) struct UpgradeEventDecoder {
+    events: HashMap<H256, Event>,
+    functions: HashMap<[u8; 4], Function>,
+    contracts: HashMap<Address, ContractKind>,
+}
+
+impl UpgradeEventDecoder {
+    pub fn new(contracts: HashMap<Address, ContractKind>) -> anyhow::Result<Self> {
+        let events = parse_abi(EVENTS)
+            .context("invalid events ABI")?
+            .events()
+            .map(|event| (event.signature(), event.clone()))
+            .collect();
+        let functions = parse_abi(FUNCTIONS)
+            .context("invalid functions ABI")?
+            .functions()
+            .map(|function| (function.short_signature(), function.clone()))
+            .collect();
+        Ok(Self {
+            events,
+            functions,
+            contracts,
+        })
+    }
+
+    pub fn addresses(&self) -> Vec<Address> {
+        self.contracts.keys().copied().collect()
+    }
+
+    pub fn topics(&self) -> Vec<H256> {
+        self.events.keys().copied().collect()
+    }
+
+    /// Decodes a log emitted by one of the watched contracts. Returns `None` for logs of unknown events.
+    pub fn decode(&self, log: &Log) -> anyhow::Result<Option<DecodedEvent>> {
+        let Some(&contract) = self.contracts.get(&log.address) else {
+            return Ok(None);
+        };
+        let Some(event) = log.topics.first().and_then(|topic| self.events.get(topic)) else {
+            return Ok(None);
+        };
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        };
+        let parsed = event
+            .parse_log(raw_log)
+            .with_context(|| format!("failed decoding `{}` event", event.name))?;
+        let params = parsed
+            .params
+            .into_iter()
+            .map(|param| {
+                let value = self.describe(&param.name, &param.value);
+                (param.name, value)
+            })
+            .collect();
+        Ok(Some(DecodedEvent {
+            contract,
+            address: log.address,
+            event: event.name.clone(),
+            params,
+        }))
+    }
+
+    /// Converts a token into JSON, recognizing diamond cuts, governance operations and protocol versions.
+    fn describe(&self, name: &str, token: &Token) -> Value {
+        match token {
+            Token::Uint(value) if name.to_lowercase().contains("protocolversion") => json!({
+                "packed": value.to_string(),
+                "semver": protocol_semver(*value),
+            }),
+            Token::Bytes(calldata) if name.to_lowercase().contains("calldata") => {
+                describe_calldata(calldata)
+            }
+            Token::Tuple(fields) => self
+                .describe_tuple(fields)
+                .unwrap_or_else(|| self.describe_all(fields)),
+            Token::Array(items) | Token::FixedArray(items) => self.describe_all(items),
+            _ => token_to_json(token),
+        }
+    }
+
+    fn describe_all(&self, tokens: &[Token]) -> Value {
+        Value::Array(
+            tokens
+                .iter()
+                .map(|token| self.describe("", token))
+                .collect(),
+        )
+    }
+
+    fn describe_tuple(&self, fields: &[Token]) -> Option<Value> {
+        Some(match fields {
+            // `Diamond.FacetCut`
+            [Token::Address(facet), Token::Uint(action), Token::Bool(is_freezable), Token::Array(selectors)] =>
+            {
+                json!({
+                    "facet": facet,
+                    "action": facet_cut_action(*action),
+                    "is_freezable": is_freezable,
+                    "selectors": selectors.iter().map(token_to_json).collect::<Vec<_>>(),
+                })
+            }
+            // `Diamond.DiamondCutData`
+            [Token::Array(facet_cuts), Token::Address(init_address), Token::Bytes(init_calldata)] =>
+            {
+                json!({
+                    "facet_cuts": self.describe_all(facet_cuts),
+                    "init_address": init_address,
+                    "init_calldata": describe_calldata(init_calldata),
+                })
+            }
+            // `IGovernance.Operation`
+            [Token::Array(calls), Token::FixedBytes(predecessor), Token::FixedBytes(salt)] => {
+                json!({
+                    "calls": self.describe_all(calls),
+                    "predecessor": token_to_json(&Token::FixedBytes(predecessor.clone())),
+                    "salt": token_to_json(&Token::FixedBytes(salt.clone())),
+                })
+            }
+            // `IGovernance.Call`
+            [Token::Address(target), Token::Uint(value), Token::Bytes(data)] => {
+                self.describe_call(*target, *value, data)
+            }
+            _ => return None,
+        })
+    }
+
+    fn describe_call(&self, target: Address, value: U256, data: &[u8]) -> Value {
+        let mut call = json!({
+            "target": target,
+            "target_contract": self.contracts.get(&target),
+            "value": value.to_string(),
+        });
+        let function = data
+            .get(..4)
+            .and_then(|selector| self.functions.get(selector))
+            .and_then(|function| Some((function, function.decode_input(&data[4..]).ok()?)));
+        match function {
+            Some((function, args)) => {
+                let args: Map<_, _> = function
+                    .inputs
+                    .iter()
+                    .zip(&args)
+                    .map(|(input, arg)| (input.name.clone(), self.describe(&input.name, arg)))
+                    .collect();
+                call["function"] = function.name.clone().into();
+                call["args"] = args.into();
+            }
+            None => call["calldata"] = format!("0x{}", hex::encode(data)).into(),
+        }
+        call
+    }
+}
+
+/// Converts an ABI token into JSON. Integers are represented as decimal strings, since they may not fit into JSON numbers.
+fn token_to_json(token: &Token) -> Value {
+    match token {
+        Token::Address(address) => json!(address),
+        Token::FixedBytes(bytes) | Token::Bytes(bytes) => {
+            format!("0x{}", hex::encode(bytes)).into()
+        }
+        Token::Int(value) | Token::Uint(value) => value.to_string().into(),
+        Token::Bool(value) => (*value).into(),
+        Token::String(value) => value.clone().into(),
+        Token::Array(items) | Token::FixedArray(items) | Token::Tuple(items) => {
+            Value::Array(items.iter().map(token_to_json).collect())
+        }
+    }
+}
+
+/// Summarizes potentially large calldata (e.g., the `ProposedUpgrade` passed to the upgrade contract).
+fn describe_calldata(calldata: &[u8]) -> Value {
+    json!({
+        "selector": calldata.get(..4).map(|selector| format!("0x{}", hex::encode(selector))),
+        "length": calldata.len(),
+        "keccak256": format!("0x{}", hex::encode(keccak256(calldata))),
+    })
+}
+
+fn facet_cut_action(action: U256) -> String {
+    match action.as_u64() {
+        0 => "add".to_owned(),
+        1 => "replace".to_owned(),
+        2 => "remove".to_owned(),
+        other => format!("unknown ({other})"),
+    }
+}
+
+fn protocol_semver(packed: U256) -> Option<String> {
+    ProtocolSemanticVersion::try_from_packed(zksync_basic_types::U256(packed.0))
+        .ok()
+        .map(|version| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::abi::encode;
+
+    use super::*;
+
+    fn diamond_proxy() -> Address {
+        Address::repeat_byte(1)
+    }
+
+    fn decoder() -> UpgradeEventDecoder {
+        let contracts = HashMap::from([
+            (Address::repeat_byte(2), ContractKind::Governance),
+            (
+                Address::repeat_byte(3),
+                ContractKind::StateTransitionManager,
+            ),
+            (diamond_proxy(), ContractKind::DiamondProxy),
+        ]);
+        UpgradeEventDecoder::new(contracts).unwrap()
+    }
+
+    fn event_log(decoder: &UpgradeEventDecoder, name: &str, address: Address) -> Log {
+        let event = decoder.events.values().find(|event| event.name == name);
+        Log {
+            address,
+            topics: vec![event.unwrap().signature()],
+            ..Log::default()
+        }
+    }
+
+    fn diamond_cut_data() -> Token {
+        let facet_cut = Token::Tuple(vec![
+            Token::Address(Address::repeat_byte(0xfa)),
+            Token::Uint(1.into()),
+            Token::Bool(true),
+            Token::Array(vec![Token::FixedBytes(vec![0x12, 0x34, 0x56, 0x78])]),
+        ]);
+        Token::Tuple(vec![
+            Token::Array(vec![facet_cut]),
+            Token::Address(Address::repeat_byte(0xaa)),
+            Token::Bytes(vec![0xde, 0xad, 0xbe, 0xef, 0]),
+        ])
+    }
+
+    #[test]
+    fn decoding_upgrade_cut_data() {
+        let decoder = decoder();
+        let mut log = event_log(&decoder, "NewUpgradeCutData", Address::repeat_byte(3));
+        let packed_version = U256::from(25) << 32;
+        log.topics.push(H256::from_uint(&packed_version));
+        log.data = encode(&[diamond_cut_data()]).into();
+
+        let event = decoder.decode(&log).unwrap().unwrap();
+        assert_eq!(event.contract, ContractKind::StateTransitionManager);
+        assert_eq!(event.params["protocolVersion"]["semver"], "0.25.0");
+        let cut = &event.params["diamondCutData"];
+        assert_eq!(cut["facet_cuts"][0]["action"], "replace");
+        assert_eq!(cut["facet_cuts"][0]["selectors"][0], "0x12345678");
+        assert_eq!(cut["init_calldata"]["selector"], "0xdeadbeef");
+        assert_eq!(cut["init_calldata"]["length"], 5);
+    }
+
+    #[test]
+    fn decoding_governance_operation() {
+        let decoder = decoder();
+        let mut log = event_log(
+            &decoder,
+            "TransparentOperationScheduled",
+            Address::repeat_byte(2),
+        );
+        log.topics.push(H256::repeat_byte(0x11));
+
+        let upgrade_call = decoder
+            .functions
+            .values()
+            .find(|function| function.name == "upgradeChainFromVersion")
+            .unwrap()
+            .encode_input(&[Token::Uint(U256::from(24) << 32), diamond_cut_data()])
+            .unwrap();
+        let calls = vec![
+            Token::Tuple(vec![
+                Token::Address(diamond_proxy()),
+                Token::Uint(0.into()),
+                Token::Bytes(upgrade_call),
+            ]),
+            Token::Tuple(vec![
+                Token::Address(Address::repeat_byte(0xee)),
+                Token::Uint(0.into()),
+                Token::Bytes(vec![0xff; 6]),
+            ]),
+        ];
+        let operation = Token::Tuple(vec![
+            Token::Array(calls),
+            Token::FixedBytes(vec![0; 32]),
+            Token::FixedBytes(vec![0; 32]),
+        ]);
+        log.data = encode(&[Token::Uint(0.into()), operation]).into();
+
+        let event = decoder.decode(&log).unwrap().unwrap();
+        let calls = &event.params["_operation"]["calls"];
+        assert_eq!(calls[0]["target_contract"], "diamond_proxy");
+        assert_eq!(calls[0]["function"], "upgradeChainFromVersion");
+        assert_eq!(calls[0]["args"]["_protocolVersion"]["semver"], "0.24.0");
+        assert_eq!(
+            calls[0]["args"]["_cutData"]["init_address"],
+            json!(Address::repeat_byte(0xaa))
+        );
+        assert_eq!(calls[1]["target_contract"], Value::Null);
+        assert_eq!(calls[1]["calldata"], "0xffffffffffff");
+    }
+
+    #[test]
+    fn skipping_unknown_logs() {
+        let decoder = decoder();
+        let log = Log {
+            address: diamond_proxy(),
+            topics: vec![H256::repeat_byte(0x42)],
+            ..Log::default()
+        };
+        assert!(decoder.decode(&log).unwrap().is_none());
+
+        let mut log = event_log(&decoder, "Freeze", diamond_proxy());
+        log.address = Address::repeat_byte(0x42);
+        assert!(decoder.decode(&log).unwrap().is_none());
+    }
+}
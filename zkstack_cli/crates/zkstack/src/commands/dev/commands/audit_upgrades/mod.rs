@@ -0,0 +1,210 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    fmt::Write as _,
+};
+
+use anyhow::Context;
+use args::AuditUpgradesArgs;
+use chrono::DateTime;
+use common::{logger, spinner::Spinner};
+use config::{ContractsConfig, EcosystemConfig};
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::{Address, Filter, ValueOrArray, H256},
+};
+use serde::Serialize;
+use xshell::Shell;
+
+use self::decoder::{ContractKind, DecodedEvent, UpgradeEventDecoder};
+use crate::{
+    commands::dev::messages::{
+        msg_audit_upgrades_fetching_events, msg_audit_upgrades_outro,
+        MSG_AUDIT_UPGRADES_BLOCK_RANGE_ERR, MSG_AUDIT_UPGRADES_INVALID_RANGE_ERR,
+    },
+    messages::{MSG_CHAIN_NOT_FOUND_ERR, MSG_L1_SECRETS_MUST_BE_PRESENTED},
+};
+
+pub mod args;
+mod decoder;
+
+/// Chronological report of governance and upgrade events.
+#[derive(Debug, Serialize)]
+struct AuditReport {
+    ecosystem: String,
+    chain: String,
+    from_block: u64,
+    to_block: u64,
+    contracts: BTreeMap<ContractKind, Address>,
+    events: Vec<ReportEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportEntry {
+    block_number: u64,
+    /// Timestamp of the L1 block in RFC 3339 format.
+    timestamp: String,
+    transaction_hash: H256,
+    log_index: u64,
+    #[serde(flatten)]
+    event: DecodedEvent,
+}
+
+pub async fn run(shell: &Shell, args: AuditUpgradesArgs) -> anyhow::Result<()> {
+    anyhow::ensure!(args.block_range > 0, MSG_AUDIT_UPGRADES_BLOCK_RANGE_ERR);
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let output = args.output.unwrap_or_else(|| {
+        shell
+            .current_dir()
+            .join(format!("upgrades_audit_{}.json", chain_config.name))
+    });
+
+    let l1_rpc_url = chain_config
+        .get_secrets_config()?
+        .l1
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_string();
+    let provider = Provider::<Http>::try_from(l1_rpc_url)?;
+    let to_block = match args.to_block {
+        Some(to_block) => to_block,
+        None => provider.get_block_number().await?.as_u64(),
+    };
+    anyhow::ensure!(
+        args.from_block <= to_block,
+        MSG_AUDIT_UPGRADES_INVALID_RANGE_ERR
+    );
+
+    let contracts = watched_contracts(&chain_config.get_contracts_config()?);
+    let decoder = UpgradeEventDecoder::new(
+        contracts
+            .iter()
+            .map(|(&kind, &address)| (address, kind))
+            .collect(),
+    )?;
+
+    let spinner = Spinner::new(&msg_audit_upgrades_fetching_events(
+        args.from_block,
+        to_block,
+    ));
+    let mut events = vec![];
+    let mut timestamps = HashMap::new();
+    let mut range_start = args.from_block;
+    while range_start <= to_block {
+        let range_end = to_block.min(range_start.saturating_add(args.block_range - 1));
+        let filter = Filter::new()
+            .address(ValueOrArray::Array(decoder.addresses()))
+            .topic0(ValueOrArray::Array(decoder.topics()))
+            .from_block(range_start)
+            .to_block(range_end);
+        let logs = provider
+            .get_logs(&filter)
+            .await
+            .with_context(|| format!("eth_getLogs({range_start}..={range_end})"))?;
+
+        for log in logs {
+            if log.removed == Some(true) {
+                continue;
+            }
+            let Some(event) = decoder.decode(&log)? else {
+                continue;
+            };
+            let block_number = log.block_number.context("missing block number")?.as_u64();
+            let timestamp = match timestamps.entry(block_number) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let block = provider
+                        .get_block(block_number)
+                        .await?
+                        .with_context(|| format!("L1 block {block_number} is missing"))?;
+                    *entry.insert(block.timestamp.as_u64())
+                }
+            };
+            events.push(ReportEntry {
+                block_number,
+                timestamp: DateTime::from_timestamp(timestamp as i64, 0)
+                    .context("invalid block timestamp")?
+                    .to_rfc3339(),
+                transaction_hash: log.transaction_hash.context("missing transaction hash")?,
+                log_index: log.log_index.context("missing log index")?.as_u64(),
+                event,
+            });
+        }
+        range_start = range_end + 1;
+    }
+    spinner.finish();
+    events.sort_by_key(|entry| (entry.block_number, entry.log_index));
+
+    let report = AuditReport {
+        ecosystem: ecosystem_config.name.clone(),
+        chain: chain_config.name.clone(),
+        from_block: args.from_block,
+        to_block,
+        contracts,
+        events,
+    };
+    let text_output = output.with_extension("txt");
+    shell.write_file(&output, serde_json::to_string_pretty(&report)?)?;
+    shell.write_file(&text_output, format_report(&report)?)?;
+    logger::outro(msg_audit_upgrades_outro(
+        report.events.len(),
+        &output,
+        &text_output,
+    ));
+    Ok(())
+}
+
+/// Returns L1 contracts of the ecosystem and the current chain emitting governance and upgrade events.
+fn watched_contracts(contracts: &ContractsConfig) -> BTreeMap<ContractKind, Address> {
+    [
+        (ContractKind::Governance, contracts.l1.governance_addr),
+        (ContractKind::ChainAdmin, contracts.l1.chain_admin_addr),
+        (
+            ContractKind::Bridgehub,
+            contracts.ecosystem_contracts.bridgehub_proxy_addr,
+        ),
+        (
+            ContractKind::StateTransitionManager,
+            contracts.ecosystem_contracts.state_transition_proxy_addr,
+        ),
+        (ContractKind::DiamondProxy, contracts.l1.diamond_proxy_addr),
+    ]
+    .into_iter()
+    // Chain admin is absent for chains deployed by older versions of the tool.
+    .filter(|(_, address)| !address.is_zero())
+    .collect()
+}
+
+/// Formats the report as human-readable text, with event parameters rendered as YAML.
+fn format_report(report: &AuditReport) -> anyhow::Result<String> {
+    let mut text = String::new();
+    writeln!(
+        text,
+        "Governance and upgrade events of chain `{}` (ecosystem `{}`), L1 blocks {}..={}",
+        report.chain, report.ecosystem, report.from_block, report.to_block
+    )?;
+    for entry in &report.events {
+        let event = &entry.event;
+        writeln!(
+            text,
+            "\n[{}] block {}, tx {:?}: {} ({:?}) emitted {}",
+            entry.timestamp,
+            entry.block_number,
+            entry.transaction_hash,
+            event.contract,
+            event.address,
+            event.event
+        )?;
+        if event.params.is_empty() {
+            continue;
+        }
+        let params = serde_yaml::to_string(&event.params)?;
+        for line in params.lines() {
+            writeln!(text, "    {line}")?;
+        }
+    }
+    Ok(text)
+}
@@ -0,0 +1,64 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::{Parser, ValueEnum};
+use strum::EnumIter;
+
+use crate::commands::dev::messages::{
+    MSG_CHAOS_DA_SERVICE_HELP, MSG_CHAOS_DURATION_HELP, MSG_CHAOS_FAULTS_HELP,
+    MSG_CHAOS_FAULT_DURATION_HELP, MSG_CHAOS_INTERVAL_HELP, MSG_CHAOS_LOGS_DIR_HELP,
+    MSG_CHAOS_RECOVERY_TIMEOUT_HELP, MSG_CHAOS_REQUIRE_PROGRESS_HELP,
+};
+
+/// Failure injected into the local stack.
+#[derive(Debug, ValueEnum, EnumIter, strum::Display, PartialEq, Eq, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ChaosFault {
+    /// Kills the server with `SIGKILL` and restarts it after the fault duration.
+    KillServer,
+    /// Gracefully stops the server with `SIGTERM` and restarts it immediately.
+    RestartServer,
+    /// Pauses the Postgres container, so that all DB queries are delayed for the fault duration.
+    DelayPostgres,
+    /// Pauses the L1 node container, so that L1 RPC requests time out for the fault duration.
+    DropL1Rpc,
+    /// Pauses the DA client container for the fault duration.
+    PauseDa,
+}
+
+#[derive(Debug, Parser)]
+pub struct ChaosArgs {
+    #[clap(long, value_enum, value_delimiter = ',', help = MSG_CHAOS_FAULTS_HELP)]
+    pub faults: Vec<ChaosFault>,
+    #[clap(long, value_name = "SECONDS", default_value_t = 600, help = MSG_CHAOS_DURATION_HELP)]
+    pub duration: u64,
+    #[clap(long, value_name = "SECONDS", default_value_t = 60, help = MSG_CHAOS_INTERVAL_HELP)]
+    pub interval: u64,
+    #[clap(long, value_name = "SECONDS", default_value_t = 20, help = MSG_CHAOS_FAULT_DURATION_HELP)]
+    pub fault_duration: u64,
+    #[clap(long, value_name = "SECONDS", default_value_t = 300, help = MSG_CHAOS_RECOVERY_TIMEOUT_HELP)]
+    pub recovery_timeout: u64,
+    #[clap(long, help = MSG_CHAOS_REQUIRE_PROGRESS_HELP)]
+    pub require_progress: bool,
+    #[clap(long, help = MSG_CHAOS_DA_SERVICE_HELP)]
+    pub da_service: Option<String>,
+    #[clap(long, default_value = "chaos_logs", help = MSG_CHAOS_LOGS_DIR_HELP)]
+    pub logs_dir: PathBuf,
+}
+
+impl ChaosArgs {
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.duration)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval)
+    }
+
+    pub fn fault_duration(&self) -> Duration {
+        Duration::from_secs(self.fault_duration)
+    }
+
+    pub fn recovery_timeout(&self) -> Duration {
+        Duration::from_secs(self.recovery_timeout)
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    fs::OpenOptions,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use args::{ChaosArgs, ChaosFault};
+use common::{cmd::Cmd, docker, logger};
+use config::{traits::ConfigWithL2RpcUrl, EcosystemConfig, DOCKER_COMPOSE_FILE};
+use ethers::providers::{Http, Provider};
+use strum::IntoEnumIterator;
+use tokio::process::{Child, Command};
+use xshell::{cmd, Shell};
+use zksync_basic_types::U64;
+
+use crate::commands::dev::messages::{
+    msg_chaos_batch_lost_err, msg_chaos_failed, msg_chaos_injecting_fault,
+    msg_chaos_missing_batch_err, msg_chaos_recovered, msg_chaos_recovery_timeout_err,
+    msg_chaos_server_exited_err, msg_chaos_success, MSG_CHAIN_NOT_FOUND_ERR,
+    MSG_CHAOS_DA_SERVICE_REQUIRED_ERR, MSG_CHAOS_INTERRUPTED_ERR, MSG_CHAOS_STARTING_SERVER,
+};
+
+pub mod args;
+
+/// Docker Compose service running Postgres in the local setup.
+const POSTGRES_SERVICE: &str = "postgres";
+/// Docker Compose service running the L1 node in the local setup.
+const L1_SERVICE: &str = "reth";
+/// Interval between polls of the server API while waiting for recovery.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Time given to the server to shut down gracefully before it's killed.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `zkstack server` spawned in a separate process group, so that it can be signalled together with the binary it runs.
+#[derive(Debug)]
+struct ServerProcess {
+    chain_arg: String,
+    log_path: PathBuf,
+    child: Child,
+}
+
+impl ServerProcess {
+    fn spawn(shell: &Shell, chain_name: &str, logs_dir: &Path) -> anyhow::Result<Self> {
+        let chain_arg = format!("--chain={chain_name}");
+        let log_path = logs_dir.join("server.log");
+        let child = Self::spawn_child(shell, &chain_arg, &log_path)?;
+        Ok(Self {
+            chain_arg,
+            log_path,
+            child,
+        })
+    }
+
+    fn spawn_child(shell: &Shell, chain_arg: &str, log_path: &Path) -> anyhow::Result<Child> {
+        logger::step(MSG_CHAOS_STARTING_SERVER);
+        // Logs of all server runs are appended to the same file, so that restarts can be correlated with failures.
+        let log_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_path)
+            .with_context(|| format!("failed opening log file {log_path:?}"))?;
+        Command::new(std::env::current_exe()?)
+            .args(["server", chain_arg])
+            .current_dir(shell.current_dir())
+            .stdin(Stdio::null())
+            .stdout(log_file.try_clone()?)
+            .stderr(log_file)
+            .process_group(0)
+            .kill_on_drop(true)
+            .spawn()
+            .context("failed spawning server")
+    }
+
+    fn restart(&mut self, shell: &Shell) -> anyhow::Result<()> {
+        self.child = Self::spawn_child(shell, &self.chain_arg, &self.log_path)?;
+        Ok(())
+    }
+
+    fn signal(&self, shell: &Shell, signal: &str) {
+        if let Some(pid) = self.child.id() {
+            let signal = format!("-{signal}");
+            let group = format!("-{pid}");
+            Cmd::new(cmd!(shell, "kill {signal} -- {group}")).run().ok();
+        }
+    }
+
+    /// Stops the server with the specified signal, falling back to `SIGKILL` if it doesn't exit in time.
+    async fn stop(&mut self, shell: &Shell, signal: &str) -> anyhow::Result<()> {
+        self.signal(shell, signal);
+        if tokio::time::timeout(GRACEFUL_SHUTDOWN_TIMEOUT, self.child.wait())
+            .await
+            .is_err()
+        {
+            self.signal(shell, "KILL");
+            self.child.wait().await?;
+        }
+        Ok(())
+    }
+
+    fn ensure_running(&mut self) -> anyhow::Result<()> {
+        if let Some(status) = self.child.try_wait()? {
+            anyhow::bail!(msg_chaos_server_exited_err(status));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ServerProcess {
+    fn drop(&mut self) {
+        if let Some(pid) = self.child.id() {
+            // `Shell` isn't available here, so the signal is sent directly.
+            std::process::Command::new("kill")
+                .args(["-TERM", "--", &format!("-{pid}")])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .ok();
+        }
+    }
+}
+
+/// Docker Compose service paused until this guard is dropped.
+struct PausedService<'a> {
+    shell: &'a Shell,
+    service: &'a str,
+}
+
+impl<'a> PausedService<'a> {
+    fn new(shell: &'a Shell, service: &'a str) -> anyhow::Result<Self> {
+        docker::pause(shell, DOCKER_COMPOSE_FILE, service)?;
+        Ok(Self { shell, service })
+    }
+}
+
+impl Drop for PausedService<'_> {
+    fn drop(&mut self) {
+        if let Err(err) = docker::unpause(self.shell, DOCKER_COMPOSE_FILE, self.service) {
+            logger::error(format!("Failed unpausing `{}`: {err:#}", self.service));
+        }
+    }
+}
+
+/// Tracks sealed L1 batches reported by the server API.
+struct BatchWatcher {
+    provider: Provider<Http>,
+    max_sealed_batch: u32,
+}
+
+impl BatchWatcher {
+    async fn sealed_batch(&self) -> anyhow::Result<u32> {
+        let number: U64 = self
+            .provider
+            .request("zks_L1BatchNumber", ())
+            .await
+            .context("zks_L1BatchNumber")?;
+        Ok(number.as_u32())
+    }
+
+    /// Waits until the server API is available and reports no fewer sealed batches than before the fault.
+    /// If `require_progress` is set, additionally waits until a new batch is sealed.
+    async fn wait_for_recovery(
+        &mut self,
+        server: &mut ServerProcess,
+        require_progress: bool,
+        timeout: Duration,
+    ) -> anyhow::Result<Duration> {
+        let started_at = Instant::now();
+        let expected_batch = self.max_sealed_batch;
+        loop {
+            server.ensure_running()?;
+            if let Ok(sealed_batch) = self.sealed_batch().await {
+                anyhow::ensure!(
+                    sealed_batch >= expected_batch,
+                    msg_chaos_batch_lost_err(sealed_batch, expected_batch)
+                );
+                self.max_sealed_batch = sealed_batch;
+                if !require_progress || sealed_batch > expected_batch {
+                    return Ok(started_at.elapsed());
+                }
+            }
+
+            if started_at.elapsed() >= timeout {
+                anyhow::bail!(msg_chaos_recovery_timeout_err(timeout));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Checks that details are available for all batches in the range, i.e. no batch was lost.
+    async fn check_batches(&self, first_batch: u32, last_batch: u32) -> anyhow::Result<()> {
+        for batch_number in first_batch..=last_batch {
+            let details: Option<serde_json::Value> = self
+                .provider
+                .request("zks_getL1BatchDetails", [batch_number])
+                .await
+                .context("zks_getL1BatchDetails")?;
+            anyhow::ensure!(details.is_some(), msg_chaos_missing_batch_err(batch_number));
+        }
+        Ok(())
+    }
+}
+
+pub async fn run(shell: &Shell, args: ChaosArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+
+    let faults = if args.faults.is_empty() {
+        // `pause-da` is only applicable if the DA client runs in a container.
+        ChaosFault::iter()
+            .filter(|&fault| fault != ChaosFault::PauseDa || args.da_service.is_some())
+            .collect()
+    } else {
+        args.faults.clone()
+    };
+    anyhow::ensure!(
+        !faults.contains(&ChaosFault::PauseDa) || args.da_service.is_some(),
+        MSG_CHAOS_DA_SERVICE_REQUIRED_ERR
+    );
+
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+    let mut watcher = BatchWatcher {
+        provider: Provider::<Http>::try_from(l2_rpc_url.as_str())?,
+        max_sealed_batch: 0,
+    };
+    let logs_dir = shell.current_dir().join(&args.logs_dir);
+    shell.create_dir(&logs_dir)?;
+    let mut server = ServerProcess::spawn(shell, &chain_config.name, &logs_dir)?;
+
+    let scenario = run_scenario(shell, &args, &faults, &mut server, &mut watcher);
+    let result = tokio::select! {
+        res = scenario => res,
+        _ = tokio::signal::ctrl_c() => Err(anyhow::anyhow!(MSG_CHAOS_INTERRUPTED_ERR)),
+    };
+    result.with_context(|| msg_chaos_failed(&logs_dir))
+}
+
+async fn run_scenario(
+    shell: &Shell,
+    args: &ChaosArgs,
+    faults: &[ChaosFault],
+    server: &mut ServerProcess,
+    watcher: &mut BatchWatcher,
+) -> anyhow::Result<()> {
+    watcher
+        .wait_for_recovery(server, false, args.recovery_timeout())
+        .await?;
+    let first_batch = watcher.max_sealed_batch;
+
+    let deadline = Instant::now() + args.duration();
+    let mut injected_faults = 0;
+    for &fault in faults.iter().cycle() {
+        if Instant::now() + args.interval() >= deadline {
+            break;
+        }
+        tokio::time::sleep(args.interval()).await;
+
+        injected_faults += 1;
+        logger::step(msg_chaos_injecting_fault(fault, injected_faults));
+        inject_fault(shell, args, fault, server).await?;
+        let elapsed = watcher
+            .wait_for_recovery(server, args.require_progress, args.recovery_timeout())
+            .await?;
+        logger::info(msg_chaos_recovered(fault, elapsed));
+    }
+
+    let last_batch = watcher.sealed_batch().await?;
+    anyhow::ensure!(
+        last_batch >= watcher.max_sealed_batch,
+        msg_chaos_batch_lost_err(last_batch, watcher.max_sealed_batch)
+    );
+    watcher.check_batches(first_batch, last_batch).await?;
+    logger::outro(msg_chaos_success(injected_faults, first_batch, last_batch));
+    Ok(())
+}
+
+async fn inject_fault(
+    shell: &Shell,
+    args: &ChaosArgs,
+    fault: ChaosFault,
+    server: &mut ServerProcess,
+) -> anyhow::Result<()> {
+    let service = match fault {
+        ChaosFault::KillServer => {
+            server.stop(shell, "KILL").await?;
+            tokio::time::sleep(args.fault_duration()).await;
+            return server.restart(shell);
+        }
+        ChaosFault::RestartServer => {
+            server.stop(shell, "TERM").await?;
+            return server.restart(shell);
+        }
+        ChaosFault::DelayPostgres => POSTGRES_SERVICE,
+        ChaosFault::DropL1Rpc => L1_SERVICE,
+        ChaosFault::PauseDa => args
+            .da_service
+            .as_deref()
+            .context(MSG_CHAOS_DA_SERVICE_REQUIRED_ERR)?,
+    };
+
+    let _paused = PausedService::new(shell, service)?;
+    tokio::time::sleep(args.fault_duration()).await;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use ethers::types::{Address, Bytes, H256};
+use strum::Display;
+
+use crate::commands::dev::messages::{
+    MSG_CREATE2_BYTECODE_HASH_HELP, MSG_CREATE2_BYTECODE_HELP, MSG_CREATE2_CONSTRUCTOR_ARGS_HELP,
+    MSG_CREATE2_DEPLOYER_HELP, MSG_CREATE2_DEPLOYER_PROMPT, MSG_CREATE2_KIND_HELP,
+    MSG_CREATE2_PREFIX_HELP, MSG_CREATE2_SALT_HELP, MSG_CREATE2_SAVE_HELP,
+    MSG_CREATE2_THREADS_HELP,
+};
+
+/// Scheme used to derive CREATE2 addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, ValueEnum)]
+#[strum(serialize_all = "lowercase")]
+pub enum Create2Kind {
+    /// EraVM contracts deployed via ContractDeployer.
+    Zkevm,
+    /// EVM contracts on L1 or deployed via the EVM emulator.
+    Evm,
+}
+
+#[derive(Debug, Parser)]
+pub struct ComputeCreate2Args {
+    #[clap(long, help = MSG_CREATE2_DEPLOYER_HELP)]
+    pub deployer: Option<Address>,
+    #[clap(long, value_enum, default_value_t = Create2Kind::Zkevm, help = MSG_CREATE2_KIND_HELP)]
+    pub kind: Create2Kind,
+    #[clap(long, conflicts_with = "bytecode_hash", help = MSG_CREATE2_BYTECODE_HELP)]
+    pub bytecode: Option<PathBuf>,
+    #[clap(long, help = MSG_CREATE2_BYTECODE_HASH_HELP)]
+    pub bytecode_hash: Option<H256>,
+    #[clap(long, default_value = "0x", help = MSG_CREATE2_CONSTRUCTOR_ARGS_HELP)]
+    pub constructor_args: Bytes,
+    #[clap(long, conflicts_with = "prefix", help = MSG_CREATE2_SALT_HELP)]
+    pub salt: Option<H256>,
+    #[clap(long, help = MSG_CREATE2_PREFIX_HELP)]
+    pub prefix: Option<String>,
+    #[clap(long, help = MSG_CREATE2_THREADS_HELP)]
+    pub threads: Option<usize>,
+    #[clap(long, help = MSG_CREATE2_SAVE_HELP)]
+    pub save: bool,
+}
+
+impl ComputeCreate2Args {
+    pub fn deployer(&self) -> Address {
+        self.deployer
+            .unwrap_or_else(|| common::Prompt::new(MSG_CREATE2_DEPLOYER_PROMPT).ask())
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+            .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+            .unwrap_or(1)
+            .max(1)
+    }
+}
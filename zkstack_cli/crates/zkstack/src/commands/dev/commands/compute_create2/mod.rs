@@ -0,0 +1,283 @@
+use std::{
+    path::Path,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use anyhow::Context;
+use args::{ComputeCreate2Args, Create2Kind};
+use common::logger;
+use config::{
+    forge_interface::deploy_ecosystem::input::InitialDeploymentConfig,
+    traits::{ReadConfigWithBasePath, SaveConfigWithCommentAndBasePath},
+    EcosystemConfig,
+};
+use ethers::{
+    types::{Address, Bytes, H256},
+    utils::{keccak256, to_checksum},
+};
+use serde::Serialize;
+use xshell::Shell;
+use zksync_basic_types::bytecode::{validate_bytecode, BytecodeHash};
+
+use crate::{
+    commands::dev::messages::{
+        msg_create2_mined, msg_create2_mining, MSG_CREATE2_BYTECODE_MISSING_ERR,
+        MSG_CREATE2_FAILED_TO_READ_BYTECODE_ERR, MSG_CREATE2_INVALID_BYTECODE_ERR,
+        MSG_CREATE2_INVALID_PREFIX_ERR, MSG_CREATE2_RESULT, MSG_CREATE2_SALT_SAVED,
+    },
+    messages::MSG_SAVE_INITIAL_CONFIG_ATTENTION,
+};
+
+pub mod args;
+
+/// Number of salts checked by a mining thread between checks whether another thread has found a salt.
+const MINING_BATCH_SIZE: u64 = 1_024;
+
+#[derive(Debug, Serialize)]
+struct Create2Output {
+    kind: String,
+    deployer: String,
+    code_hash: H256,
+    salt: H256,
+    address: String,
+}
+
+pub fn run(shell: &Shell, args: ComputeCreate2Args) -> anyhow::Result<()> {
+    let prefix = args.prefix.as_deref().map(parse_prefix).transpose()?;
+    let deployer = args.deployer();
+    let code_hash = code_hash(&args)?;
+    let preimage = match args.kind {
+        Create2Kind::Zkevm => Create2Preimage::zkevm(deployer, code_hash, &args.constructor_args),
+        Create2Kind::Evm => Create2Preimage::evm(deployer, code_hash),
+    };
+
+    let (salt, address) = if let Some(prefix) = &prefix {
+        let threads = args.threads();
+        logger::info(msg_create2_mining(args.prefix.as_deref().unwrap(), threads));
+        let started_at = Instant::now();
+        let (salt, address, attempts) = mine_salt(&preimage, prefix, threads);
+        logger::info(msg_create2_mined(attempts, started_at.elapsed()));
+        (salt, address)
+    } else {
+        let salt = args.salt.unwrap_or_default();
+        (salt, preimage.clone().address(&salt))
+    };
+
+    let output = Create2Output {
+        kind: args.kind.to_string(),
+        deployer: to_checksum(&deployer, None),
+        code_hash,
+        salt,
+        address: to_checksum(&address, None),
+    };
+    logger::note(MSG_CREATE2_RESULT, logger::object_to_string(&output));
+
+    if args.save {
+        let ecosystem_config = EcosystemConfig::from_file(shell)?;
+        let mut initial_deployment_config =
+            InitialDeploymentConfig::read_with_base_path(shell, &ecosystem_config.config)?;
+        initial_deployment_config.create2_factory_salt = salt;
+        initial_deployment_config.save_with_comment_and_base_path(
+            shell,
+            &ecosystem_config.config,
+            MSG_SAVE_INITIAL_CONFIG_ATTENTION,
+        )?;
+        logger::outro(MSG_CREATE2_SALT_SAVED);
+    }
+    Ok(())
+}
+
+/// Returns the code hash participating in address derivation: the versioned bytecode hash for EraVM contracts,
+/// or the keccak256 hash of the init code (i.e., bytecode with appended constructor args) for EVM contracts.
+fn code_hash(args: &ComputeCreate2Args) -> anyhow::Result<H256> {
+    if let Some(hash) = args.bytecode_hash {
+        return Ok(hash);
+    }
+    let path = args
+        .bytecode
+        .as_deref()
+        .context(MSG_CREATE2_BYTECODE_MISSING_ERR)?;
+    let bytecode = read_bytecode(path).context(MSG_CREATE2_FAILED_TO_READ_BYTECODE_ERR)?;
+    Ok(match args.kind {
+        Create2Kind::Zkevm => {
+            validate_bytecode(&bytecode).context(MSG_CREATE2_INVALID_BYTECODE_ERR)?;
+            H256(BytecodeHash::for_bytecode(&bytecode).value().0)
+        }
+        Create2Kind::Evm => H256(keccak256([&bytecode[..], &args.constructor_args].concat())),
+    })
+}
+
+/// Reads bytecode from a file containing either hex bytecode, or a compiled artifact (Hardhat / Foundry)
+/// with the bytecode in the `bytecode` or `bytecode.object` field.
+fn read_bytecode(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let contents = std::fs::read_to_string(path)?;
+    let contents = contents.trim();
+    let hex = if contents.starts_with('{') {
+        let artifact: serde_json::Value = serde_json::from_str(contents)?;
+        let bytecode = &artifact["bytecode"];
+        bytecode
+            .as_str()
+            .or_else(|| bytecode["object"].as_str())
+            .context("artifact doesn't contain `bytecode`")?
+            .to_owned()
+    } else {
+        contents.to_owned()
+    };
+    Ok(Bytes::from_str(&hex)?.to_vec())
+}
+
+/// Parses a hex address prefix into nibbles.
+fn parse_prefix(prefix: &str) -> anyhow::Result<Vec<u8>> {
+    let prefix = prefix.strip_prefix("0x").unwrap_or(prefix);
+    anyhow::ensure!(prefix.len() <= 40, MSG_CREATE2_INVALID_PREFIX_ERR);
+    prefix
+        .chars()
+        .map(|ch| ch.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<_>>()
+        .context(MSG_CREATE2_INVALID_PREFIX_ERR)
+}
+
+fn has_prefix(address: &Address, prefix: &[u8]) -> bool {
+    prefix.iter().enumerate().all(|(i, &nibble)| {
+        let byte = address.as_bytes()[i / 2];
+        let address_nibble = if i % 2 == 0 { byte >> 4 } else { byte & 0xf };
+        address_nibble == nibble
+    })
+}
+
+/// Data hashed to obtain a CREATE2 address, with a placeholder for the salt so that salts can be cheaply substituted.
+#[derive(Debug, Clone)]
+struct Create2Preimage {
+    bytes: Vec<u8>,
+    salt_offset: usize,
+}
+
+impl Create2Preimage {
+    /// Preimage used by the `ContractDeployer` system contract for EraVM contracts:
+    /// `keccak256("zksyncCreate2") ++ deployer ++ salt ++ bytecode_hash ++ keccak256(constructor_args)`,
+    /// with the deployer address left-padded to 32 bytes.
+    fn zkevm(deployer: Address, bytecode_hash: H256, constructor_args: &[u8]) -> Self {
+        let mut bytes = Vec::with_capacity(160);
+        bytes.extend_from_slice(&keccak256(b"zksyncCreate2"));
+        bytes.extend_from_slice(H256::from(deployer).as_bytes());
+        let salt_offset = bytes.len();
+        bytes.extend_from_slice(&[0; 32]);
+        bytes.extend_from_slice(bytecode_hash.as_bytes());
+        bytes.extend_from_slice(&keccak256(constructor_args));
+        Self { bytes, salt_offset }
+    }
+
+    /// Preimage defined by EIP-1014 (also used for EVM contracts deployed via the EVM emulator):
+    /// `0xff ++ deployer ++ salt ++ keccak256(init_code)`.
+    fn evm(deployer: Address, init_code_hash: H256) -> Self {
+        let mut bytes = Vec::with_capacity(85);
+        bytes.push(0xff);
+        bytes.extend_from_slice(deployer.as_bytes());
+        let salt_offset = bytes.len();
+        bytes.extend_from_slice(&[0; 32]);
+        bytes.extend_from_slice(init_code_hash.as_bytes());
+        Self { bytes, salt_offset }
+    }
+
+    fn address(&mut self, salt: &H256) -> Address {
+        self.bytes[self.salt_offset..self.salt_offset + 32].copy_from_slice(salt.as_bytes());
+        Address::from_slice(&keccak256(&self.bytes)[12..])
+    }
+}
+
+/// Searches for a salt producing an address with the specified prefix. Each thread starts from a random salt
+/// and increments it. Returns the salt, the corresponding address and the total number of checked salts.
+fn mine_salt(preimage: &Create2Preimage, prefix: &[u8], threads: usize) -> (H256, Address, u64) {
+    let found = Mutex::new(None);
+    let is_found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                let mut preimage = preimage.clone();
+                let mut salt = H256::random();
+                while !is_found.load(Ordering::Relaxed) {
+                    for _ in 0..MINING_BATCH_SIZE {
+                        increment_salt(&mut salt);
+                        let address = preimage.address(&salt);
+                        if has_prefix(&address, prefix) {
+                            is_found.store(true, Ordering::Relaxed);
+                            let mut found = found.lock().unwrap();
+                            if found.is_none() {
+                                *found = Some((salt, address));
+                            }
+                            break;
+                        }
+                    }
+                    attempts.fetch_add(MINING_BATCH_SIZE, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+
+    let (salt, address) = found.into_inner().unwrap().expect("mining threads exited");
+    (salt, address, attempts.into_inner())
+}
+
+fn increment_salt(salt: &mut H256) {
+    let counter = &mut salt.as_bytes_mut()[24..];
+    let value = u64::from_be_bytes(counter[..].try_into().unwrap()).wrapping_add(1);
+    counter.copy_from_slice(&value.to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computing_evm_create2_address() {
+        // Example 0 from EIP-1014
+        let mut preimage = Create2Preimage::evm(Address::zero(), H256(keccak256([0])));
+        assert_eq!(
+            preimage.address(&H256::zero()),
+            "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"
+                .parse()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn computing_zkevm_create2_address() {
+        let deployer: Address = "0x0000000000000000000000000000000000008006"
+            .parse()
+            .unwrap();
+        let bytecode_hash: H256 =
+            "0x0100000f248e111a1b587fef850dc4585c39af2dd505bc8a0d5cc6d3fcc7ed3c"
+                .parse()
+                .unwrap();
+        let mut preimage = Create2Preimage::zkevm(deployer, bytecode_hash, &[]);
+        assert_eq!(
+            preimage.address(&H256::zero()),
+            "0xc8764bc4e16e0d1430f77800408917e35badd2f6"
+                .parse()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn mining_salt_with_prefix() {
+        let preimage = Create2Preimage::evm(Address::repeat_byte(1), H256::repeat_byte(2));
+        let prefix = parse_prefix("0xab").unwrap();
+        let (salt, address, _) = mine_salt(&preimage, &prefix, 2);
+        assert!(has_prefix(&address, &prefix), "{address:?}");
+        assert_eq!(preimage.clone().address(&salt), address);
+    }
+
+    #[test]
+    fn parsing_prefix() {
+        assert_eq!(parse_prefix("0x0aF").unwrap(), [0, 0xa, 0xf]);
+        parse_prefix("0xg").unwrap_err();
+        parse_prefix(&"0".repeat(41)).unwrap_err();
+    }
+}
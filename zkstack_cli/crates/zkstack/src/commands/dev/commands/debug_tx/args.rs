@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_DEBUG_TX_BREAK_HELP, MSG_DEBUG_TX_DUMP_HELP, MSG_DEBUG_TX_HASH_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct DebugTxArgs {
+    #[clap(long, required_unless_present = "dump", help = MSG_DEBUG_TX_HASH_HELP)]
+    pub tx_hash: Option<String>,
+    #[clap(long, help = MSG_DEBUG_TX_DUMP_HELP)]
+    pub dump: Option<PathBuf>,
+    #[clap(long = "break", short = 'b', help = MSG_DEBUG_TX_BREAK_HELP)]
+    pub breakpoints: Vec<String>,
+}
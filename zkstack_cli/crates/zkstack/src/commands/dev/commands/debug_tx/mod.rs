@@ -0,0 +1,48 @@
+use anyhow::Context as _;
+use args::DebugTxArgs;
+use common::{cmd::Cmd, logger};
+use config::EcosystemConfig;
+use xshell::{cmd, Shell};
+
+use crate::commands::dev::{
+    dals::get_core_dal,
+    messages::{MSG_CHAIN_NOT_FOUND_ERR, MSG_DEBUG_TX_FAILED, MSG_DEBUG_TX_LOADING},
+};
+
+pub mod args;
+
+pub fn run(shell: &Shell, args: DebugTxArgs) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+
+    let mut debugger_args = vec![];
+    if let Some(dump) = &args.dump {
+        // Resolve the dump path before changing the working dir, so that relative paths work as expected.
+        let dump = shell.current_dir().join(dump);
+        debugger_args.push(format!("--dump={}", dump.display()));
+    } else {
+        let chain = ecosystem
+            .load_current_chain()
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        let database_url = get_core_dal(shell, None)?.url;
+        debugger_args.push(format!("--database-url={database_url}"));
+        debugger_args.push(format!("--chain-id={}", chain.chain_id.as_u64()));
+    }
+    if let Some(tx_hash) = &args.tx_hash {
+        debugger_args.push(format!("--tx-hash={tx_hash}"));
+    }
+    for breakpoint in &args.breakpoints {
+        debugger_args.push(format!("--break={breakpoint}"));
+    }
+
+    let _dir_guard = shell.push_dir(&ecosystem.link_to_code);
+    logger::info(MSG_DEBUG_TX_LOADING);
+    let cmd = cmd!(
+        shell,
+        "cargo run --release -p vm_debugger -- {debugger_args...}"
+    );
+    Cmd::new(cmd)
+        .with_force_run()
+        .run()
+        .context(MSG_DEBUG_TX_FAILED)?;
+    Ok(())
+}
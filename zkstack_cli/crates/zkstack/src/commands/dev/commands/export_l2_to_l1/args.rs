@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_EXPORT_L2_TO_L1_FROM_BATCH_HELP, MSG_EXPORT_L2_TO_L1_NO_VERIFY_HELP,
+    MSG_EXPORT_L2_TO_L1_OUTPUT_HELP, MSG_EXPORT_L2_TO_L1_TO_BATCH_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct ExportL2ToL1Args {
+    #[clap(long = "out", help = MSG_EXPORT_L2_TO_L1_OUTPUT_HELP)]
+    pub output: Option<PathBuf>,
+    #[clap(long, help = MSG_EXPORT_L2_TO_L1_FROM_BATCH_HELP)]
+    pub from_batch: Option<u32>,
+    #[clap(long, help = MSG_EXPORT_L2_TO_L1_TO_BATCH_HELP)]
+    pub to_batch: Option<u32>,
+    #[clap(long, help = MSG_EXPORT_L2_TO_L1_NO_VERIFY_HELP)]
+    pub no_verify: bool,
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use args::ExportL2ToL1Args;
+use common::{
+    l2_to_l1::{
+        append_to_export, fetch_batch_l2_to_l1_logs, l1_logs_root_hash, read_export,
+        BatchL2ToL1Logs,
+    },
+    logger,
+    spinner::Spinner,
+};
+use config::{traits::ConfigWithL2RpcUrl, ChainConfig, EcosystemConfig};
+use ethers::providers::{Http, Provider};
+use xshell::Shell;
+use zksync_basic_types::{L1BatchNumber, U64};
+
+use crate::{
+    commands::dev::messages::{
+        msg_export_l2_to_l1_outro, msg_export_l2_to_l1_resuming, msg_exporting_l2_to_l1_logs,
+        msg_l1_batch_not_sealed_err, msg_l2_to_l1_roots_mismatch_err, msg_l2_to_l1_roots_verified,
+        MSG_EXPORT_L2_TO_L1_UP_TO_DATE, MSG_VERIFYING_L2_TO_L1_ROOTS_SPINNER,
+    },
+    messages::{MSG_CHAIN_NOT_FOUND_ERR, MSG_L1_SECRETS_MUST_BE_PRESENTED},
+};
+
+pub mod args;
+
+pub async fn run(shell: &Shell, args: ExportL2ToL1Args) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let output = args.output.unwrap_or_else(|| {
+        shell
+            .current_dir()
+            .join(format!("l2_to_l1_logs_{}.jsonl", chain_config.name))
+    });
+
+    // Existing records are never rewritten; we only check their integrity and continue after the last one.
+    let mut batches = read_export(&output)?;
+    for batch in &batches {
+        batch.check_root_hash()?;
+    }
+    let first_batch = match batches.last() {
+        Some(last_batch) => {
+            let next_batch = last_batch.l1_batch_number + 1;
+            if args.from_batch.is_some_and(|from| from != next_batch.0) {
+                logger::warn(msg_export_l2_to_l1_resuming(&output, next_batch));
+            }
+            next_batch
+        }
+        None => L1BatchNumber(args.from_batch.unwrap_or(1)),
+    };
+
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+    let l2_provider = Provider::<Http>::try_from(l2_rpc_url.as_str())?;
+    let last_batch = match args.to_batch {
+        Some(to_batch) => L1BatchNumber(to_batch),
+        None => {
+            let last_batch: U64 = l2_provider
+                .request("zks_L1BatchNumber", ())
+                .await
+                .context("zks_L1BatchNumber")?;
+            L1BatchNumber(last_batch.as_u32())
+        }
+    };
+
+    if first_batch <= last_batch {
+        let spinner = Spinner::new(&msg_exporting_l2_to_l1_logs(first_batch, last_batch));
+        for number in first_batch.0..=last_batch.0 {
+            let batch = fetch_batch_l2_to_l1_logs(&l2_provider, L1BatchNumber(number))
+                .await?
+                .with_context(|| msg_l1_batch_not_sealed_err(number))?;
+            append_to_export(&output, &batch)?;
+            batches.push(batch);
+        }
+        spinner.finish();
+    } else {
+        logger::info(MSG_EXPORT_L2_TO_L1_UP_TO_DATE);
+    }
+
+    if !args.no_verify {
+        verify_roots(&chain_config, &batches).await?;
+    }
+    logger::outro(msg_export_l2_to_l1_outro(&output, batches.len()));
+    Ok(())
+}
+
+/// Compares reconstructed roots with the ones stored on L1. Batches that are not executed on L1 yet
+/// are reported as pending.
+async fn verify_roots(
+    chain_config: &ChainConfig,
+    batches: &[BatchL2ToL1Logs],
+) -> anyhow::Result<()> {
+    let l1_rpc_url = chain_config
+        .get_secrets_config()?
+        .l1
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_string();
+    let diamond_proxy_addr = chain_config.get_contracts_config()?.l1.diamond_proxy_addr;
+    let l1_provider = Arc::new(Provider::<Http>::try_from(l1_rpc_url)?);
+
+    let spinner = Spinner::new(MSG_VERIFYING_L2_TO_L1_ROOTS_SPINNER);
+    let mut verified_count = 0;
+    let mut pending_count = 0;
+    let mut mismatched_batches = vec![];
+    for batch in batches {
+        let l1_root_hash = l1_logs_root_hash(
+            l1_provider.clone(),
+            diamond_proxy_addr,
+            batch.l1_batch_number,
+        )
+        .await?;
+        if l1_root_hash.is_zero() {
+            pending_count += 1;
+        } else if l1_root_hash == batch.root_hash {
+            verified_count += 1;
+        } else {
+            mismatched_batches.push(batch.l1_batch_number);
+        }
+    }
+    spinner.finish();
+
+    logger::info(msg_l2_to_l1_roots_verified(verified_count, pending_count));
+    anyhow::ensure!(
+        mismatched_batches.is_empty(),
+        msg_l2_to_l1_roots_mismatch_err(&mismatched_batches)
+    );
+    Ok(())
+}
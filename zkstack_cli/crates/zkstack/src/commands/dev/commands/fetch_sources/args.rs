@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use ethers::types::Address;
+
+use crate::commands::dev::messages::{
+    MSG_FETCH_SOURCES_ADDRESS_HELP, MSG_FETCH_SOURCES_OUT_DIR_HELP, MSG_FETCH_SOURCES_URL_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct FetchSourcesArgs {
+    #[clap(help = MSG_FETCH_SOURCES_ADDRESS_HELP)]
+    pub address: Address,
+    #[clap(long, help = MSG_FETCH_SOURCES_URL_HELP)]
+    pub url: Option<String>,
+    #[clap(long, help = MSG_FETCH_SOURCES_OUT_DIR_HELP)]
+    pub out_dir: Option<PathBuf>,
+}
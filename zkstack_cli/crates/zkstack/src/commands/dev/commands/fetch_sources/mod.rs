@@ -0,0 +1,117 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Context as _;
+use args::FetchSourcesArgs;
+use common::logger;
+use config::EcosystemConfig;
+use reqwest::StatusCode;
+use serde_json::Value;
+use xshell::Shell;
+
+use crate::commands::dev::messages::{
+    msg_contract_not_verified, msg_fetching_sources, msg_sources_saved, msg_unsafe_source_path_err,
+    MSG_CHAIN_NOT_FOUND_ERR, MSG_FETCH_SOURCES_VERIFIER_CONFIG_ERR,
+};
+
+pub mod args;
+
+pub async fn run(shell: &Shell, args: FetchSourcesArgs) -> anyhow::Result<()> {
+    let base_url = match args.url {
+        Some(url) => url,
+        None => {
+            let ecosystem = EcosystemConfig::from_file(shell)?;
+            let chain = ecosystem
+                .load_current_chain()
+                .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+            let port = chain
+                .get_general_config()?
+                .contract_verifier
+                .as_ref()
+                .context(MSG_FETCH_SOURCES_VERIFIER_CONFIG_ERR)?
+                .port;
+            format!("http://127.0.0.1:{port}")
+        }
+    };
+    let url = format!(
+        "{}/contract_verification/sources/{:?}",
+        base_url.trim_end_matches('/'),
+        args.address
+    );
+
+    logger::info(msg_fetching_sources(&url));
+    let response = reqwest::get(&url)
+        .await
+        .with_context(|| format!("failed fetching verified sources from {url}"))?;
+    if response.status() == StatusCode::NOT_FOUND {
+        anyhow::bail!(msg_contract_not_verified(args.address));
+    }
+    let response = response
+        .error_for_status()
+        .with_context(|| format!("failed fetching verified sources from {url}"))?
+        .text()
+        .await?;
+    let bundle: Value =
+        serde_json::from_str(&response).context("failed parsing verified sources bundle")?;
+
+    let out_dir = args
+        .out_dir
+        .unwrap_or_else(|| PathBuf::from(format!("{:?}", args.address)));
+    let out_dir = shell.current_dir().join(out_dir);
+    save_bundle(shell, &out_dir, &bundle)?;
+    logger::success(msg_sources_saved(&out_dir));
+    logger::result(bundle);
+    Ok(())
+}
+
+/// Saves the bundle as `bundle.json` and unpacks its source files into the `sources` subdirectory.
+fn save_bundle(shell: &Shell, out_dir: &Path, bundle: &Value) -> anyhow::Result<()> {
+    shell.create_dir(out_dir)?;
+    shell.write_file(
+        out_dir.join("bundle.json"),
+        serde_json::to_string_pretty(bundle)?,
+    )?;
+
+    let sources = bundle["sources"]
+        .as_object()
+        .context("verified sources bundle doesn't contain sources")?;
+    let sources_dir = out_dir.join("sources");
+    for (path, content) in sources {
+        let relative_path =
+            sanitize_source_path(path).with_context(|| msg_unsafe_source_path_err(path))?;
+        let content = content
+            .as_str()
+            .with_context(|| format!("content of source file `{path}` is not a string"))?;
+        shell.write_file(sources_dir.join(relative_path), content)?;
+    }
+    Ok(())
+}
+
+/// Source paths are provided by the users verifying contracts, so they must not escape the output directory.
+fn sanitize_source_path(path: &str) -> Option<PathBuf> {
+    let path = Path::new(path);
+    let is_safe = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    (is_safe && path.components().next().is_some()).then(|| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitizing_source_paths() {
+        assert_eq!(
+            sanitize_source_path("contracts/Counter.sol"),
+            Some(PathBuf::from("contracts/Counter.sol"))
+        );
+        assert_eq!(
+            sanitize_source_path("./Counter.sol"),
+            Some(PathBuf::from("./Counter.sol"))
+        );
+        assert_eq!(sanitize_source_path(""), None);
+        assert_eq!(sanitize_source_path("/etc/passwd"), None);
+        assert_eq!(sanitize_source_path("../Counter.sol"), None);
+        assert_eq!(sanitize_source_path("contracts/../../Counter.sol"), None);
+    }
+}
@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_FUZZ_VM_ARTIFACTS_DIR_HELP, MSG_FUZZ_VM_EVM_EMULATOR_HELP, MSG_FUZZ_VM_ITERATIONS_HELP,
+    MSG_FUZZ_VM_KEEP_GOING_HELP, MSG_FUZZ_VM_SEED_HELP, MSG_FUZZ_VM_TXS_PER_BATCH_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct FuzzVmArgs {
+    #[clap(long, help = MSG_FUZZ_VM_SEED_HELP)]
+    pub seed: Option<u64>,
+    #[clap(long, default_value_t = 100, help = MSG_FUZZ_VM_ITERATIONS_HELP)]
+    pub iterations: u64,
+    #[clap(long, default_value_t = 20, help = MSG_FUZZ_VM_TXS_PER_BATCH_HELP)]
+    pub txs_per_batch: usize,
+    #[clap(long, help = MSG_FUZZ_VM_EVM_EMULATOR_HELP)]
+    pub evm_emulator: bool,
+    #[clap(long, default_value = "vm_fuzz_artifacts", help = MSG_FUZZ_VM_ARTIFACTS_DIR_HELP)]
+    pub artifacts_dir: PathBuf,
+    #[clap(long, help = MSG_FUZZ_VM_KEEP_GOING_HELP)]
+    pub keep_going: bool,
+}
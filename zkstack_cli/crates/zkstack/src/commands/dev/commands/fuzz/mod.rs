@@ -0,0 +1,59 @@
+use anyhow::Context as _;
+use args::FuzzVmArgs;
+use clap::Subcommand;
+use common::{cmd::Cmd, logger};
+use config::EcosystemConfig;
+use xshell::{cmd, Shell};
+
+use crate::commands::dev::messages::{
+    msg_fuzz_vm_failed, MSG_FUZZ_VM_ABOUT, MSG_FUZZ_VM_RUNNING, MSG_FUZZ_VM_SUCCESS,
+};
+
+pub mod args;
+
+#[derive(Subcommand, Debug)]
+pub enum FuzzCommands {
+    #[clap(about = MSG_FUZZ_VM_ABOUT)]
+    Vm(FuzzVmArgs),
+}
+
+pub fn run(shell: &Shell, args: FuzzCommands) -> anyhow::Result<()> {
+    match args {
+        FuzzCommands::Vm(args) => fuzz_vm(shell, args),
+    }
+}
+
+fn fuzz_vm(shell: &Shell, args: FuzzVmArgs) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    // Resolve the artifacts dir before changing the working dir, so that relative paths work as expected.
+    let artifacts_dir = shell.current_dir().join(&args.artifacts_dir);
+    let _dir_guard = shell.push_dir(&ecosystem.link_to_code);
+
+    let mut fuzz_args = vec![
+        format!("--iterations={}", args.iterations),
+        format!("--txs-per-batch={}", args.txs_per_batch),
+        format!("--artifacts-dir={}", artifacts_dir.display()),
+    ];
+    if let Some(seed) = args.seed {
+        fuzz_args.push(format!("--seed={seed}"));
+    }
+    if args.evm_emulator {
+        fuzz_args.push("--evm-emulator".to_owned());
+    }
+    if args.keep_going {
+        fuzz_args.push("--keep-going".to_owned());
+    }
+
+    logger::info(MSG_FUZZ_VM_RUNNING);
+    let cmd = cmd!(
+        shell,
+        "cargo run --release -p vm-benchmark --bin vm_fuzz -- {fuzz_args...}"
+    );
+    Cmd::new(cmd)
+        .with_force_run()
+        .run()
+        .with_context(|| msg_fuzz_vm_failed(&artifacts_dir))?;
+
+    logger::outro(MSG_FUZZ_VM_SUCCESS);
+    Ok(())
+}
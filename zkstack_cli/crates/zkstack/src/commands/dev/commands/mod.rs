@@ -1,14 +1,23 @@
+pub mod audit_upgrades;
+pub mod chaos;
 pub mod clean;
+pub mod compute_create2;
 pub mod config_writer;
 pub mod contracts;
 pub mod database;
+pub mod debug_tx;
+pub mod export_l2_to_l1;
+pub mod fetch_sources;
 pub mod fmt;
+pub mod fuzz;
 pub mod genesis;
 pub mod lint;
 pub(crate) mod lint_utils;
 pub mod prover;
+pub mod replay_batch;
 pub mod send_transactions;
 pub mod snapshot;
 pub(crate) mod sql_fmt;
 pub mod status;
 pub mod test;
+pub mod verify_proof;
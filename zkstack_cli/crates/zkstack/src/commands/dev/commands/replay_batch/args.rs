@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_REPLAY_BATCH_DUMP_HELP, MSG_REPLAY_BATCH_NUMBER_HELP, MSG_REPLAY_BATCH_SAVE_DUMP_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct ReplayBatchArgs {
+    #[clap(long, required_unless_present = "dump", help = MSG_REPLAY_BATCH_NUMBER_HELP)]
+    pub l1_batch_number: Option<u32>,
+    #[clap(long, conflicts_with_all = ["l1_batch_number", "save_dump"], help = MSG_REPLAY_BATCH_DUMP_HELP)]
+    pub dump: Option<PathBuf>,
+    #[clap(long, help = MSG_REPLAY_BATCH_SAVE_DUMP_HELP)]
+    pub save_dump: Option<PathBuf>,
+}
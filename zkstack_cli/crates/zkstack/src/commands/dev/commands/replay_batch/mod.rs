@@ -0,0 +1,49 @@
+use anyhow::Context as _;
+use args::ReplayBatchArgs;
+use common::{cmd::Cmd, logger};
+use config::EcosystemConfig;
+use xshell::{cmd, Shell};
+
+use crate::commands::dev::{
+    dals::get_core_dal,
+    messages::{MSG_CHAIN_NOT_FOUND_ERR, MSG_REPLAY_BATCH_FAILED, MSG_REPLAY_BATCH_LOADING},
+};
+
+pub mod args;
+
+pub fn run(shell: &Shell, args: ReplayBatchArgs) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+
+    let mut replayer_args = vec![];
+    if let Some(dump) = &args.dump {
+        // Resolve the dump path before changing the working dir, so that relative paths work as expected.
+        let dump = shell.current_dir().join(dump);
+        replayer_args.push(format!("--dump={}", dump.display()));
+    } else {
+        let chain = ecosystem
+            .load_current_chain()
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        let database_url = get_core_dal(shell, None)?.url;
+        replayer_args.push(format!("--database-url={database_url}"));
+        replayer_args.push(format!("--chain-id={}", chain.chain_id.as_u64()));
+    }
+    if let Some(l1_batch_number) = args.l1_batch_number {
+        replayer_args.push(format!("--l1-batch-number={l1_batch_number}"));
+    }
+    if let Some(save_dump) = &args.save_dump {
+        let save_dump = shell.current_dir().join(save_dump);
+        replayer_args.push(format!("--save-dump={}", save_dump.display()));
+    }
+
+    let _dir_guard = shell.push_dir(&ecosystem.link_to_code);
+    logger::info(MSG_REPLAY_BATCH_LOADING);
+    let cmd = cmd!(
+        shell,
+        "cargo run --release -p batch_replayer -- {replayer_args...}"
+    );
+    Cmd::new(cmd)
+        .with_force_run()
+        .run()
+        .context(MSG_REPLAY_BATCH_FAILED)?;
+    Ok(())
+}
@@ -1,5 +1,6 @@
 pub mod fees;
 pub mod integration;
+pub mod prover_e2e;
 pub mod recovery;
 pub mod revert;
 pub mod rust;
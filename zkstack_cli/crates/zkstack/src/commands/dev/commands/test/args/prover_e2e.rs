@@ -0,0 +1,32 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_PROVER_E2E_BATCH_NUMBER_HELP, MSG_PROVER_E2E_LOGS_DIR_HELP, MSG_PROVER_E2E_NO_SERVER_HELP,
+    MSG_PROVER_E2E_POLL_INTERVAL_HELP, MSG_PROVER_E2E_TIMEOUT_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct ProverE2eArgs {
+    #[clap(long, help = MSG_PROVER_E2E_BATCH_NUMBER_HELP)]
+    pub batch_number: Option<u32>,
+    #[clap(long, value_name = "SECONDS", default_value_t = 3_600, help = MSG_PROVER_E2E_TIMEOUT_HELP)]
+    pub timeout: u64,
+    #[clap(long, value_name = "SECONDS", default_value_t = 10, help = MSG_PROVER_E2E_POLL_INTERVAL_HELP)]
+    pub poll_interval: u64,
+    #[clap(long, default_value = "prover_logs", help = MSG_PROVER_E2E_LOGS_DIR_HELP)]
+    pub logs_dir: PathBuf,
+    #[clap(long, help = MSG_PROVER_E2E_NO_SERVER_HELP)]
+    pub no_server: bool,
+}
+
+impl ProverE2eArgs {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval)
+    }
+}
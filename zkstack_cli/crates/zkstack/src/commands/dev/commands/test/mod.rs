@@ -1,14 +1,14 @@
 use args::{
-    fees::FeesArgs, integration::IntegrationArgs, recovery::RecoveryArgs, revert::RevertArgs,
-    rust::RustArgs, upgrade::UpgradeArgs,
+    fees::FeesArgs, integration::IntegrationArgs, prover_e2e::ProverE2eArgs,
+    recovery::RecoveryArgs, revert::RevertArgs, rust::RustArgs, upgrade::UpgradeArgs,
 };
 use clap::Subcommand;
 use xshell::Shell;
 
 use crate::commands::dev::messages::{
     MSG_BUILD_ABOUT, MSG_INTEGRATION_TESTS_ABOUT, MSG_L1_CONTRACTS_ABOUT, MSG_LOADTEST_ABOUT,
-    MSG_PROVER_TEST_ABOUT, MSG_RECOVERY_TEST_ABOUT, MSG_REVERT_TEST_ABOUT, MSG_RUST_TEST_ABOUT,
-    MSG_TEST_WALLETS_INFO, MSG_UPGRADE_TEST_ABOUT,
+    MSG_PROVER_E2E_TEST_ABOUT, MSG_PROVER_TEST_ABOUT, MSG_RECOVERY_TEST_ABOUT,
+    MSG_REVERT_TEST_ABOUT, MSG_RUST_TEST_ABOUT, MSG_TEST_WALLETS_INFO, MSG_UPGRADE_TEST_ABOUT,
 };
 
 mod args;
@@ -19,6 +19,7 @@ mod integration;
 mod l1_contracts;
 mod loadtest;
 mod prover;
+mod prover_e2e;
 mod recovery;
 mod revert;
 mod rust;
@@ -46,6 +47,8 @@ pub enum TestCommands {
     L1Contracts,
     #[clap(about = MSG_PROVER_TEST_ABOUT, alias = "p")]
     Prover,
+    #[clap(about = MSG_PROVER_E2E_TEST_ABOUT, alias = "pe2e")]
+    ProverE2e(ProverE2eArgs),
     #[clap(about = MSG_TEST_WALLETS_INFO)]
     Wallet,
     #[clap(about = MSG_LOADTEST_ABOUT)]
@@ -63,6 +66,7 @@ pub async fn run(shell: &Shell, args: TestCommands) -> anyhow::Result<()> {
         TestCommands::Rust(args) => rust::run(shell, args).await,
         TestCommands::L1Contracts => l1_contracts::run(shell),
         TestCommands::Prover => prover::run(shell).await,
+        TestCommands::ProverE2e(args) => prover_e2e::run(shell, args).await,
         TestCommands::Wallet => wallet::run(shell),
         TestCommands::Loadtest => loadtest::run(shell),
     }
@@ -0,0 +1,328 @@
+use std::{
+    fs::File,
+    path::Path,
+    process::Stdio,
+    time::Instant,
+};
+
+use anyhow::Context as _;
+use common::{cmd::Cmd, logger};
+use config::{traits::ConfigWithL2RpcUrl, ChainConfig, EcosystemConfig};
+use ethers::providers::{Http, Provider};
+use sqlx::{Connection, PgConnection};
+use tokio::process::{Child, Command};
+use types::ProverMode;
+use xshell::{cmd, Shell};
+use zksync_basic_types::U64;
+
+use super::args::prover_e2e::ProverE2eArgs;
+use crate::commands::dev::messages::{
+    msg_prover_e2e_component_exited_err, msg_prover_e2e_failed, msg_prover_e2e_stage_done,
+    msg_prover_e2e_stage_timeout, msg_prover_e2e_starting_component, msg_prover_e2e_success,
+    msg_prover_e2e_target_batch, MSG_CHAIN_NOT_FOUND_ERR, MSG_DATABASE_MUST_BE_PRESENTED,
+    MSG_PROVER_E2E_NO_PROOFS_ERR, MSG_PROVER_E2E_NO_SEALED_BATCHES_ERR,
+};
+
+/// Server components required to feed batches into the prover subsystem and to submit proofs to L1.
+const SERVER_COMPONENTS: &str = "api,tree,eth,state_keeper,commitment_generator,proof_data_handler,vm_runner_protective_reads,vm_runner_bwip";
+
+#[derive(Debug, Clone, Copy)]
+enum Stage {
+    Gateway,
+    Proving,
+    Compression,
+    L1Verification,
+}
+
+impl Stage {
+    const ALL: [Self; 4] = [
+        Self::Gateway,
+        Self::Proving,
+        Self::Compression,
+        Self::L1Verification,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Gateway => "batch picked up by prover gateway",
+            Self::Proving => "witness generation & circuit proving",
+            Self::Compression => "proof compression",
+            Self::L1Verification => "proof verification on L1",
+        }
+    }
+}
+
+/// Background process (`zkstack server` or `zkstack prover run`) spawned in a separate process group,
+/// so that it can be terminated together with the binaries it runs.
+#[derive(Debug)]
+struct BackgroundComponent {
+    name: &'static str,
+    child: Child,
+}
+
+impl BackgroundComponent {
+    fn spawn(
+        shell: &Shell,
+        name: &'static str,
+        args: &[&str],
+        logs_dir: &Path,
+    ) -> anyhow::Result<Self> {
+        logger::step(msg_prover_e2e_starting_component(name));
+        let log_path = logs_dir.join(format!("{name}.log"));
+        let log_file = File::create(&log_path)
+            .with_context(|| format!("failed creating log file {log_path:?}"))?;
+        let child = Command::new(std::env::current_exe()?)
+            .args(args)
+            .current_dir(shell.current_dir())
+            .stdin(Stdio::null())
+            .stdout(log_file.try_clone()?)
+            .stderr(log_file)
+            .process_group(0)
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("failed spawning {name}"))?;
+        Ok(Self { name, child })
+    }
+
+    fn ensure_running(&mut self) -> anyhow::Result<()> {
+        if let Some(status) = self.child.try_wait()? {
+            anyhow::bail!(msg_prover_e2e_component_exited_err(self.name, status));
+        }
+        Ok(())
+    }
+
+    fn terminate(&mut self, shell: &Shell) {
+        if let Some(pid) = self.child.id() {
+            let group = format!("-{pid}");
+            Cmd::new(cmd!(shell, "kill -TERM -- {group}")).run().ok();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Components<'a> {
+    shell: &'a Shell,
+    running: Vec<BackgroundComponent>,
+}
+
+impl Components<'_> {
+    fn spawn(&mut self, name: &'static str, args: &[&str], logs_dir: &Path) -> anyhow::Result<()> {
+        let component = BackgroundComponent::spawn(self.shell, name, args, logs_dir)?;
+        self.running.push(component);
+        Ok(())
+    }
+
+    fn stop(&mut self, name: &str) {
+        self.running.retain_mut(|component| {
+            if component.name == name {
+                component.terminate(self.shell);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    fn ensure_running(&mut self) -> anyhow::Result<()> {
+        self.running
+            .iter_mut()
+            .try_for_each(BackgroundComponent::ensure_running)
+    }
+}
+
+impl Drop for Components<'_> {
+    fn drop(&mut self) {
+        for component in &mut self.running {
+            component.terminate(self.shell);
+        }
+    }
+}
+
+pub async fn run(shell: &Shell, args: ProverE2eArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    anyhow::ensure!(
+        chain_config.prover_version != ProverMode::NoProofs,
+        MSG_PROVER_E2E_NO_PROOFS_ERR
+    );
+
+    let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+    let l2_provider = Provider::<Http>::try_from(l2_rpc_url.as_str())?;
+    let prover_db_url = prover_db_url(&chain_config)?;
+
+    let logs_dir = shell.current_dir().join(&args.logs_dir);
+    shell.create_dir(&logs_dir)?;
+    let mut components = Components {
+        shell,
+        running: vec![],
+    };
+    let chain_arg = format!("--chain={}", chain_config.name);
+    if !args.no_server {
+        components.spawn(
+            "server",
+            &[
+                "server",
+                &chain_arg,
+                "--uring",
+                &format!("--components={SERVER_COMPONENTS}"),
+            ],
+            &logs_dir,
+        )?;
+    }
+    for (name, component_args) in [
+        ("gateway", &["--component=gateway"][..]),
+        ("prover-job-monitor", &["--component=prover-job-monitor"]),
+        (
+            "witness-generator",
+            &["--component=witness-generator", "--round=all-rounds"],
+        ),
+        (
+            "circuit-prover",
+            &["--component=circuit-prover", "-l=23", "-h=3"],
+        ),
+    ] {
+        let mut prover_args = vec!["prover", "run", &chain_arg, "--docker=false"];
+        prover_args.extend_from_slice(component_args);
+        components.spawn(name, &prover_args, &logs_dir)?;
+    }
+
+    let started_at = Instant::now();
+    let deadline = started_at + args.timeout();
+    let batch_number = pick_batch(
+        &l2_provider,
+        args.batch_number,
+        &mut components,
+        &args,
+        deadline,
+    )
+    .await?;
+    logger::info(msg_prover_e2e_target_batch(batch_number));
+
+    let mut prover_db = PgConnection::connect(&prover_db_url)
+        .await
+        .context("failed connecting to prover DB")?;
+    let mut timings = vec![];
+    let mut stage_started_at = Instant::now();
+    for stage in Stage::ALL {
+        if let Stage::Compression = stage {
+            // The circuit prover and compressor may not fit into GPU memory simultaneously.
+            components.stop("circuit-prover");
+            let compressor_args = [
+                "prover",
+                "run",
+                &chain_arg,
+                "--docker=false",
+                "--component=compressor",
+            ];
+            components.spawn("compressor", &compressor_args, &logs_dir)?;
+        }
+
+        loop {
+            components.ensure_running().inspect_err(|_| {
+                logger::error(msg_prover_e2e_failed(stage.name(), &logs_dir));
+            })?;
+            if is_stage_completed(stage, batch_number, &mut prover_db, &l2_provider).await? {
+                break;
+            }
+            if Instant::now() >= deadline {
+                logger::error(msg_prover_e2e_failed(stage.name(), &logs_dir));
+                anyhow::bail!(msg_prover_e2e_stage_timeout(stage.name(), args.timeout()));
+            }
+            tokio::time::sleep(args.poll_interval()).await;
+        }
+
+        let elapsed = stage_started_at.elapsed();
+        logger::success(msg_prover_e2e_stage_done(stage.name(), elapsed));
+        timings.push((stage.name(), elapsed));
+        stage_started_at = Instant::now();
+    }
+
+    logger::outro(msg_prover_e2e_success(
+        batch_number,
+        &timings,
+        started_at.elapsed(),
+    ));
+    Ok(())
+}
+
+fn prover_db_url(chain_config: &ChainConfig) -> anyhow::Result<String> {
+    let secrets = chain_config.get_secrets_config()?;
+    let url = secrets
+        .database
+        .as_ref()
+        .context(MSG_DATABASE_MUST_BE_PRESENTED)?
+        .prover_url()?;
+    Ok(url.expose_str().to_owned())
+}
+
+/// Picks the earliest sealed batch not yet proven on L1, since batches are proven strictly in order.
+async fn pick_batch(
+    l2_provider: &Provider<Http>,
+    requested_batch: Option<u32>,
+    components: &mut Components<'_>,
+    args: &ProverE2eArgs,
+    deadline: Instant,
+) -> anyhow::Result<u32> {
+    // Wait until the server API is available.
+    let latest_batch = loop {
+        components.ensure_running()?;
+        match l2_provider.request::<_, U64>("zks_L1BatchNumber", ()).await {
+            Ok(number) if number.as_u32() > 0 => break number.as_u32(),
+            Ok(_) if Instant::now() >= deadline => {
+                anyhow::bail!(MSG_PROVER_E2E_NO_SEALED_BATCHES_ERR)
+            }
+            Err(err) if Instant::now() >= deadline => {
+                return Err(anyhow::Error::new(err).context("zks_L1BatchNumber"))
+            }
+            _ => tokio::time::sleep(args.poll_interval()).await,
+        }
+    };
+
+    if let Some(batch) = requested_batch {
+        return Ok(batch);
+    }
+    if is_proven_on_l1(l2_provider, latest_batch).await? {
+        // All sealed batches are already proven; wait for the next one.
+        return Ok(latest_batch + 1);
+    }
+    let mut batch = latest_batch;
+    while batch > 1 && !is_proven_on_l1(l2_provider, batch - 1).await? {
+        batch -= 1;
+    }
+    Ok(batch)
+}
+
+async fn is_proven_on_l1(l2_provider: &Provider<Http>, batch_number: u32) -> anyhow::Result<bool> {
+    let details: Option<serde_json::Value> = l2_provider
+        .request("zks_getL1BatchDetails", [batch_number])
+        .await
+        .context("zks_getL1BatchDetails")?;
+    Ok(details.is_some_and(|details| !details["provenAt"].is_null()))
+}
+
+async fn is_stage_completed(
+    stage: Stage,
+    batch_number: u32,
+    prover_db: &mut PgConnection,
+    l2_provider: &Provider<Http>,
+) -> anyhow::Result<bool> {
+    let query = match stage {
+        Stage::Gateway => "SELECT COUNT(*) FROM witness_inputs_fri WHERE l1_batch_number = $1",
+        Stage::Proving => {
+            "SELECT COUNT(*) FROM proof_compression_jobs_fri WHERE l1_batch_number = $1"
+        }
+        Stage::Compression => {
+            "SELECT COUNT(*) FROM proof_compression_jobs_fri \
+             WHERE l1_batch_number = $1 AND status IN ('successful', 'sent_to_server')"
+        }
+        Stage::L1Verification => return is_proven_on_l1(l2_provider, batch_number).await,
+    };
+    let count: i64 = sqlx::query_scalar(query)
+        .bind(i64::from(batch_number))
+        .fetch_one(prover_db)
+        .await
+        .with_context(|| format!("failed querying prover DB for stage `{}`", stage.name()))?;
+    Ok(count > 0)
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_VERIFY_PROOF_BATCH_HELP, MSG_VERIFY_PROOF_BATCH_PROMPT, MSG_VERIFY_PROOF_PATH_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct VerifyProofArgs {
+    #[clap(long, help = MSG_VERIFY_PROOF_BATCH_HELP)]
+    pub batch: Option<u32>,
+    #[clap(long, help = MSG_VERIFY_PROOF_PATH_HELP)]
+    pub proof_path: Option<PathBuf>,
+}
+
+impl VerifyProofArgs {
+    pub fn batch(&self) -> u32 {
+        self.batch
+            .unwrap_or_else(|| common::Prompt::new(MSG_VERIFY_PROOF_BATCH_PROMPT).ask())
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use args::VerifyProofArgs;
+use common::{check_prerequisites, cmd::Cmd, logger, PROVER_CLI_PREREQUISITE};
+use config::{get_link_to_prover, EcosystemConfig};
+use xshell::{cmd, Shell};
+use zksync_config::configs::object_store::ObjectStoreMode;
+
+use crate::{
+    commands::dev::{
+        dals::{get_core_dal, get_prover_dal},
+        messages::{
+            msg_proof_not_found_err, msg_verified_proof_of_batch, msg_verifying_proof_of_batch,
+            MSG_CHAIN_NOT_FOUND_ERR, MSG_PROOF_STORE_NOT_FILE_BACKED_ERR,
+            MSG_VERIFY_PROOF_FAILED_ERR,
+        },
+    },
+    messages::MSG_PROVER_CONFIG_NOT_FOUND_ERR,
+};
+
+pub mod args;
+
+/// Directory of the final proofs within the object store.
+const PROOFS_BUCKET: &str = "proofs_fri";
+
+pub async fn run(shell: &Shell, args: VerifyProofArgs) -> anyhow::Result<()> {
+    check_prerequisites(shell, &PROVER_CLI_PREREQUISITE, false);
+
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let link_to_prover = get_link_to_prover(&ecosystem_config);
+    let batch = args.batch();
+
+    let proof_path = match args.proof_path {
+        Some(path) => path,
+        None => {
+            let prover_config = chain_config
+                .get_general_config()?
+                .prover_config
+                .context(MSG_PROVER_CONFIG_NOT_FOUND_ERR)?;
+            let Some(ObjectStoreMode::FileBacked {
+                file_backed_base_path,
+            }) = prover_config.prover_object_store.map(|config| config.mode)
+            else {
+                anyhow::bail!(MSG_PROOF_STORE_NOT_FILE_BACKED_ERR);
+            };
+            // The base path is relative to the prover workspace, which prover components are run from.
+            find_proof(&link_to_prover.join(file_backed_base_path), batch)?
+        }
+    };
+
+    let prover_url = get_prover_dal(shell, None)?.url.to_string();
+    let core_url = get_core_dal(shell, None)?.url.to_string();
+    let keystore = link_to_prover.join("data/keys");
+
+    logger::info(msg_verifying_proof_of_batch(batch, &proof_path));
+    let batch_number = batch.to_string();
+    Cmd::new(cmd!(
+        shell,
+        "prover_cli {prover_url} verify-proof --batch {batch_number} --file {proof_path} --core-db-url {core_url} --keystore {keystore}"
+    ))
+    .with_force_run()
+    .run()
+    .context(MSG_VERIFY_PROOF_FAILED_ERR)?;
+
+    logger::outro(msg_verified_proof_of_batch(batch));
+    Ok(())
+}
+
+/// Finds the final proof of the batch in a file-backed object store. Proofs are keyed by the batch number
+/// and the protocol version (`l1_batch_proof_<batch>_<version>.bin`), so the latest version is used
+/// if the batch was proven several times.
+fn find_proof(object_store_path: &Path, batch: u32) -> anyhow::Result<PathBuf> {
+    let proofs_dir = object_store_path.join(PROOFS_BUCKET);
+    let prefix = format!("l1_batch_proof_{batch}_");
+    let mut proofs: Vec<_> = fs::read_dir(&proofs_dir)
+        .with_context(|| msg_proof_not_found_err(batch, &proofs_dir))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let file_name = path.file_name()?.to_str()?;
+            let version = file_name.strip_prefix(&prefix)?.strip_suffix(".bin")?;
+            let version: Vec<u32> = version
+                .split('_')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            Some((version, path))
+        })
+        .collect();
+    proofs.sort_unstable();
+    proofs
+        .pop()
+        .map(|(_, path)| path)
+        .with_context(|| msg_proof_not_found_err(batch, &proofs_dir))
+}
@@ -1,3 +1,8 @@
+use std::{fmt, path::Path, process::ExitStatus, time::Duration};
+
+use ethers::types::Address;
+use zksync_basic_types::L1BatchNumber;
+
 use super::commands::lint_utils::Target;
 
 // Ecosystem related messages
@@ -102,6 +107,61 @@ pub(super) const MSG_L1_CONTRACTS_ABOUT: &str = "Run L1 contracts tests";
 pub(super) const MSG_L1_CONTRACTS_TEST_SUCCESS: &str = "L1 contracts tests ran successfully";
 pub(super) const MSG_PROVER_TEST_ABOUT: &str = "Run prover tests";
 pub(super) const MSG_PROVER_TEST_SUCCESS: &str = "Prover tests ran successfully";
+pub(super) const MSG_PROVER_E2E_TEST_ABOUT: &str =
+    "Run a full proving cycle for a single batch on a local chain";
+pub(super) const MSG_PROVER_E2E_BATCH_NUMBER_HELP: &str =
+    "L1 batch to prove. Defaults to the earliest sealed batch not proven on L1 yet";
+pub(super) const MSG_PROVER_E2E_TIMEOUT_HELP: &str = "Timeout for the entire proving cycle";
+pub(super) const MSG_PROVER_E2E_POLL_INTERVAL_HELP: &str =
+    "Interval between checks of the proving progress";
+pub(super) const MSG_PROVER_E2E_LOGS_DIR_HELP: &str =
+    "Directory to write logs of the started components to";
+pub(super) const MSG_PROVER_E2E_NO_SERVER_HELP: &str =
+    "Do not start the server; use an already running one";
+pub(super) const MSG_PROVER_E2E_NO_PROOFS_ERR: &str =
+    "Chain is configured without real proofs; create it with `--prover-mode gpu`";
+pub(super) const MSG_PROVER_E2E_NO_SEALED_BATCHES_ERR: &str =
+    "Timed out waiting for the server to seal an L1 batch";
+
+pub(super) fn msg_prover_e2e_starting_component(name: &str) -> String {
+    format!("Starting {name}")
+}
+
+pub(super) fn msg_prover_e2e_component_exited_err(name: &str, status: ExitStatus) -> String {
+    format!("Component `{name}` exited unexpectedly with {status}")
+}
+
+pub(super) fn msg_prover_e2e_target_batch(batch_number: u32) -> String {
+    format!("Proving L1 batch #{batch_number}")
+}
+
+pub(super) fn msg_prover_e2e_stage_done(stage: &str, elapsed: Duration) -> String {
+    format!("Stage `{stage}` completed in {elapsed:.1?}")
+}
+
+pub(super) fn msg_prover_e2e_stage_timeout(stage: &str, timeout: Duration) -> String {
+    format!("Proving cycle did not complete in {timeout:?}; stuck at stage `{stage}`")
+}
+
+pub(super) fn msg_prover_e2e_failed(stage: &str, logs_dir: &Path) -> String {
+    format!(
+        "Prover e2e test failed at stage `{stage}`; see component logs in {}",
+        logs_dir.display()
+    )
+}
+
+pub(super) fn msg_prover_e2e_success(
+    batch_number: u32,
+    timings: &[(&str, Duration)],
+    total: Duration,
+) -> String {
+    let stages = timings
+        .iter()
+        .map(|(stage, elapsed)| format!("  {stage}: {elapsed:.1?}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("L1 batch #{batch_number} proven and verified on L1 in {total:.1?}\n{stages}")
+}
 pub(super) const MSG_POSTGRES_CONFIG_NOT_FOUND_ERR: &str = "Postgres config not found";
 pub(super) const MSG_RESETTING_TEST_DATABASES: &str = "Resetting test databases";
 
@@ -253,3 +313,267 @@ pub(super) fn msg_not_ready_components(components: &str) -> String {
 
 // Genesis
 pub(super) const MSG_GENESIS_FILE_GENERATION_STARTED: &str = "Regenerate genesis file";
+
+// Export L2→L1 logs related messages
+pub(super) const MSG_EXPORT_L2_TO_L1_ABOUT: &str =
+    "Export L2→L1 logs per L1 batch and verify their Merkle roots against L1";
+pub(super) const MSG_EXPORT_L2_TO_L1_OUTPUT_HELP: &str =
+    "Path to the append-only export file (JSON Lines). Defaults to `l2_to_l1_logs_<chain>.jsonl`";
+pub(super) const MSG_EXPORT_L2_TO_L1_FROM_BATCH_HELP: &str =
+    "First L1 batch to export. Ignored if the export file already contains batches";
+pub(super) const MSG_EXPORT_L2_TO_L1_TO_BATCH_HELP: &str =
+    "Last L1 batch to export. Defaults to the latest sealed batch";
+pub(super) const MSG_EXPORT_L2_TO_L1_NO_VERIFY_HELP: &str =
+    "Do not verify reconstructed Merkle roots against L1";
+pub(super) const MSG_EXPORT_L2_TO_L1_UP_TO_DATE: &str = "Export is up to date";
+pub(super) const MSG_VERIFYING_L2_TO_L1_ROOTS_SPINNER: &str =
+    "Verifying L2→L1 logs Merkle roots against L1...";
+
+pub(super) fn msg_exporting_l2_to_l1_logs(
+    first_batch: L1BatchNumber,
+    last_batch: L1BatchNumber,
+) -> String {
+    format!("Exporting L2→L1 logs for batches {first_batch}..={last_batch}...")
+}
+
+pub(super) fn msg_export_l2_to_l1_resuming(output: &Path, next_batch: L1BatchNumber) -> String {
+    format!("{output:?} already contains exported batches; resuming from batch {next_batch}")
+}
+
+pub(super) fn msg_l1_batch_not_sealed_err(l1_batch_number: u32) -> String {
+    format!("L1 batch {l1_batch_number} is not sealed yet")
+}
+
+pub(super) fn msg_l2_to_l1_roots_verified(verified_count: usize, pending_count: usize) -> String {
+    format!("Roots verified against L1: {verified_count}; batches not executed on L1 yet: {pending_count}")
+}
+
+pub(super) fn msg_l2_to_l1_roots_mismatch_err(batches: &[L1BatchNumber]) -> String {
+    format!("Reconstructed L2→L1 logs roots don't match L1 for batches: {batches:?}")
+}
+
+pub(super) fn msg_export_l2_to_l1_outro(output: &Path, batch_count: usize) -> String {
+    format!("Export at {output:?} contains {batch_count} batches")
+}
+
+// Verify proof related messages
+pub(super) const MSG_VERIFY_PROOF_ABOUT: &str =
+    "Verify the final proof of an L1 batch locally, including its public input";
+pub(super) const MSG_VERIFY_PROOF_BATCH_HELP: &str = "L1 batch to verify the proof of";
+pub(super) const MSG_VERIFY_PROOF_BATCH_PROMPT: &str = "Enter the number of the batch to verify";
+pub(super) const MSG_VERIFY_PROOF_PATH_HELP: &str =
+    "Path to the final proof of the batch. Defaults to the proof in the prover object store";
+pub(super) const MSG_PROOF_STORE_NOT_FILE_BACKED_ERR: &str =
+    "Prover object store is not file-backed; download the proof and pass it with --proof-path";
+pub(super) const MSG_VERIFY_PROOF_FAILED_ERR: &str = "Proof verification failed";
+
+pub(super) fn msg_proof_not_found_err(batch: u32, proofs_dir: &Path) -> String {
+    format!("Final proof of batch {batch} not found in {proofs_dir:?}")
+}
+
+pub(super) fn msg_verifying_proof_of_batch(batch: u32, proof_path: &Path) -> String {
+    format!("Verifying proof of batch {batch} from {proof_path:?}")
+}
+
+pub(super) fn msg_verified_proof_of_batch(batch: u32) -> String {
+    format!("Proof of batch {batch} is valid")
+}
+
+// Compute CREATE2 related messages
+pub(super) const MSG_COMPUTE_CREATE2_ABOUT: &str =
+    "Compute CREATE2 addresses of planned deployments and mine vanity salts";
+pub(super) const MSG_CREATE2_DEPLOYER_HELP: &str =
+    "Address of the deploying contract (e.g., a CREATE2 factory)";
+pub(super) const MSG_CREATE2_DEPLOYER_PROMPT: &str = "Enter the address of the deploying contract";
+pub(super) const MSG_CREATE2_KIND_HELP: &str =
+    "Address derivation scheme, `zkevm` for EraVM contracts or `evm` for EVM contracts (incl. the EVM emulator)";
+pub(super) const MSG_CREATE2_BYTECODE_HELP: &str =
+    "Path to the contract bytecode, either a hex file or a compiled artifact with the `bytecode` field";
+pub(super) const MSG_CREATE2_BYTECODE_HASH_HELP: &str =
+    "Bytecode hash to use instead of --bytecode (the versioned EraVM bytecode hash for `zkevm`, or keccak256 of the init code for `evm`)";
+pub(super) const MSG_CREATE2_CONSTRUCTOR_ARGS_HELP: &str =
+    "ABI-encoded constructor arguments (hex)";
+pub(super) const MSG_CREATE2_SALT_HELP: &str = "Salt to compute the address for. Defaults to zero";
+pub(super) const MSG_CREATE2_PREFIX_HELP: &str =
+    "Hex prefix of the address to mine a salt for (conflicts with --salt)";
+pub(super) const MSG_CREATE2_THREADS_HELP: &str =
+    "Number of threads used for mining. Defaults to the number of CPUs";
+pub(super) const MSG_CREATE2_SAVE_HELP: &str =
+    "Record the salt as `create2_factory_salt` in the ecosystem initial deployments config";
+pub(super) const MSG_CREATE2_BYTECODE_MISSING_ERR: &str =
+    "Either --bytecode or --bytecode-hash must be specified";
+pub(super) const MSG_CREATE2_INVALID_PREFIX_ERR: &str =
+    "Address prefix must consist of at most 40 hex digits";
+pub(super) const MSG_CREATE2_FAILED_TO_READ_BYTECODE_ERR: &str = "Failed to read bytecode";
+pub(super) const MSG_CREATE2_INVALID_BYTECODE_ERR: &str = "Invalid contract bytecode";
+pub(super) const MSG_CREATE2_RESULT: &str = "CREATE2 deployment";
+pub(super) const MSG_CREATE2_SALT_SAVED: &str =
+    "Salt was saved as `create2_factory_salt` to the initial deployments config";
+
+pub(super) fn msg_create2_mining(prefix: &str, threads: usize) -> String {
+    format!("Mining a salt for address prefix 0x{prefix} using {threads} threads")
+}
+
+pub(super) fn msg_create2_mined(attempts: u64, elapsed: Duration) -> String {
+    let rate = attempts as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+    format!("Found a salt after {attempts} attempts in {elapsed:?} ({rate:.0} salts/s)")
+}
+
+// Audit upgrades related messages
+pub(super) const MSG_AUDIT_UPGRADES_ABOUT: &str =
+    "Build a chronological report of governance and upgrade events of the ecosystem and chain on L1";
+pub(super) const MSG_AUDIT_UPGRADES_FROM_BLOCK_HELP: &str = "First L1 block to scan";
+pub(super) const MSG_AUDIT_UPGRADES_TO_BLOCK_HELP: &str =
+    "Last L1 block to scan. Defaults to the latest block";
+pub(super) const MSG_AUDIT_UPGRADES_BLOCK_RANGE_HELP: &str =
+    "Maximum number of L1 blocks queried by a single `eth_getLogs` request";
+pub(super) const MSG_AUDIT_UPGRADES_OUTPUT_HELP: &str =
+    "Path to the JSON report. Defaults to `upgrades_audit_<chain>.json`; the human-readable report is written next to it with the `.txt` extension";
+pub(super) const MSG_AUDIT_UPGRADES_BLOCK_RANGE_ERR: &str = "Block range must be positive";
+pub(super) const MSG_AUDIT_UPGRADES_INVALID_RANGE_ERR: &str =
+    "First L1 block must not be greater than the last one";
+
+pub(super) fn msg_audit_upgrades_fetching_events(from_block: u64, to_block: u64) -> String {
+    format!("Fetching governance and upgrade events from L1 blocks {from_block}..={to_block}...")
+}
+
+pub(super) fn msg_audit_upgrades_outro(
+    event_count: usize,
+    json_report: &Path,
+    text_report: &Path,
+) -> String {
+    format!(
+        "Found {event_count} events; reports are written to {json_report:?} and {text_report:?}"
+    )
+}
+
+// Chaos related messages
+pub(super) const MSG_CHAOS_ABOUT: &str =
+    "Inject failures into a local chain on a schedule and check that it recovers without losing batches";
+pub(super) const MSG_CHAOS_FAULTS_HELP: &str =
+    "Comma-separated faults to inject in a round-robin order. Defaults to all applicable faults";
+pub(super) const MSG_CHAOS_DURATION_HELP: &str = "Total duration of the chaos run";
+pub(super) const MSG_CHAOS_INTERVAL_HELP: &str = "Interval between injected faults";
+pub(super) const MSG_CHAOS_FAULT_DURATION_HELP: &str =
+    "How long a component is kept down or paused by a single fault";
+pub(super) const MSG_CHAOS_RECOVERY_TIMEOUT_HELP: &str =
+    "Maximum time for the chain to recover after a fault";
+pub(super) const MSG_CHAOS_REQUIRE_PROGRESS_HELP: &str =
+    "Require a new L1 batch to be sealed after each fault; needs transaction load, e.g. from `zkstack dev send-transactions`";
+pub(super) const MSG_CHAOS_DA_SERVICE_HELP: &str =
+    "Docker Compose service running the DA client; required for the `pause-da` fault";
+pub(super) const MSG_CHAOS_LOGS_DIR_HELP: &str = "Directory to write server logs to";
+pub(super) const MSG_CHAOS_DA_SERVICE_REQUIRED_ERR: &str =
+    "`pause-da` fault requires the `--da-service` argument";
+pub(super) const MSG_CHAOS_STARTING_SERVER: &str = "Starting server";
+pub(super) const MSG_CHAOS_INTERRUPTED_ERR: &str = "Chaos run was interrupted";
+
+pub(super) fn msg_chaos_injecting_fault(fault: impl fmt::Display, index: usize) -> String {
+    format!("Injecting fault #{index}: `{fault}`")
+}
+
+pub(super) fn msg_chaos_recovered(fault: impl fmt::Display, elapsed: Duration) -> String {
+    format!("Chain recovered from `{fault}` in {elapsed:.1?}")
+}
+
+pub(super) fn msg_chaos_server_exited_err(status: ExitStatus) -> String {
+    format!("Server exited unexpectedly with {status}")
+}
+
+pub(super) fn msg_chaos_recovery_timeout_err(timeout: Duration) -> String {
+    format!("Chain did not recover in {timeout:?}")
+}
+
+pub(super) fn msg_chaos_batch_lost_err(sealed_batch: u32, expected_batch: u32) -> String {
+    format!("Server reports L1 batch #{sealed_batch} as the last sealed one, although #{expected_batch} was sealed before")
+}
+
+pub(super) fn msg_chaos_missing_batch_err(batch_number: u32) -> String {
+    format!("L1 batch #{batch_number} is missing")
+}
+
+pub(super) fn msg_chaos_failed(logs_dir: &Path) -> String {
+    format!(
+        "Chaos run failed; see server logs in {}",
+        logs_dir.display()
+    )
+}
+
+pub(super) fn msg_chaos_success(fault_count: usize, first_batch: u32, last_batch: u32) -> String {
+    format!("Chain recovered from {fault_count} faults; L1 batches #{first_batch}..=#{last_batch} are intact")
+}
+
+// VM fuzzing related messages
+pub(super) const MSG_FUZZ_ABOUT: &str = "Fuzz core components";
+pub(super) const MSG_FUZZ_VM_ABOUT: &str =
+    "Run randomized transaction workloads on the legacy and fast VMs and compare their outputs";
+pub(super) const MSG_FUZZ_VM_SEED_HELP: &str =
+    "Seed of the first workload; workload `i` uses `seed + i`. Defaults to a random seed";
+pub(super) const MSG_FUZZ_VM_ITERATIONS_HELP: &str = "Number of workloads to execute";
+pub(super) const MSG_FUZZ_VM_TXS_PER_BATCH_HELP: &str = "Number of transactions in each workload";
+pub(super) const MSG_FUZZ_VM_EVM_EMULATOR_HELP: &str =
+    "Enable the EVM emulator in the base system contracts";
+pub(super) const MSG_FUZZ_VM_ARTIFACTS_DIR_HELP: &str = "Directory to write failing workloads to";
+pub(super) const MSG_FUZZ_VM_KEEP_GOING_HELP: &str =
+    "Continue fuzzing after a failure instead of stopping";
+pub(super) const MSG_FUZZ_VM_RUNNING: &str = "Running VM fuzzing";
+pub(super) const MSG_FUZZ_VM_SUCCESS: &str = "No VM divergences detected";
+
+pub(super) fn msg_fuzz_vm_failed(artifacts_dir: &Path) -> String {
+    format!(
+        "VM fuzzing failed; failing workloads are dumped to {}",
+        artifacts_dir.display()
+    )
+}
+
+// Transaction debugger related messages
+pub(super) const MSG_DEBUG_TX_ABOUT: &str =
+    "Execute a transaction in the VM step by step with an interactive debugger";
+pub(super) const MSG_DEBUG_TX_HASH_HELP: &str =
+    "Hash of the transaction. Loaded from the chain database unless `--dump` is specified";
+pub(super) const MSG_DEBUG_TX_DUMP_HELP: &str =
+    "Path to a VM dump or a VM fuzzing artifact to load the transaction from";
+pub(super) const MSG_DEBUG_TX_BREAK_HELP: &str =
+    "Breakpoint: `opcode:<name>`, `address:<address>` or `storage:<key>`. Can be specified multiple times";
+pub(super) const MSG_DEBUG_TX_LOADING: &str = "Building the debugger and loading the transaction";
+pub(super) const MSG_DEBUG_TX_FAILED: &str = "Transaction debugger failed";
+
+// Batch replay related messages
+pub(super) const MSG_REPLAY_BATCH_ABOUT: &str =
+    "Re-execute a sealed L1 batch in the VM and compare its outputs with the persisted ones";
+pub(super) const MSG_REPLAY_BATCH_NUMBER_HELP: &str =
+    "Number of the L1 batch. Loaded from the chain database unless `--dump` is specified";
+pub(super) const MSG_REPLAY_BATCH_DUMP_HELP: &str =
+    "Path to a batch dump to load the batch from instead of the chain database";
+pub(super) const MSG_REPLAY_BATCH_SAVE_DUMP_HELP: &str =
+    "Path to save a self-contained batch dump to, e.g. to replay the batch on another machine";
+pub(super) const MSG_REPLAY_BATCH_LOADING: &str =
+    "Building the batch replayer and loading the batch";
+pub(super) const MSG_REPLAY_BATCH_FAILED: &str = "Batch replay failed or diverged";
+
+// Fetch sources related messages
+pub(super) const MSG_FETCH_SOURCES_ABOUT: &str =
+    "Download the verified source tree, compiler settings and constructor args of a contract";
+pub(super) const MSG_FETCH_SOURCES_ADDRESS_HELP: &str = "Address of the verified contract";
+pub(super) const MSG_FETCH_SOURCES_URL_HELP: &str =
+    "URL of the contract verification API. Defaults to the API of the current chain";
+pub(super) const MSG_FETCH_SOURCES_OUT_DIR_HELP: &str =
+    "Directory to save the sources to. Defaults to a directory named after the contract address";
+pub(super) const MSG_FETCH_SOURCES_VERIFIER_CONFIG_ERR: &str =
+    "Contract verifier config is missing for the current chain; specify the API URL with `--url`";
+
+pub(super) fn msg_fetching_sources(url: &str) -> String {
+    format!("Fetching verified sources from {url}")
+}
+
+pub(super) fn msg_contract_not_verified(address: Address) -> String {
+    format!("Contract {address:?} is not verified")
+}
+
+pub(super) fn msg_unsafe_source_path_err(path: &str) -> String {
+    format!("Source file path `{path}` points outside of the output directory")
+}
+
+pub(super) fn msg_sources_saved(out_dir: &Path) -> String {
+    format!("Verified sources saved to {}", out_dir.display())
+}
@@ -4,15 +4,21 @@ use messages::MSG_STATUS_ABOUT;
 use xshell::Shell;
 
 use self::commands::{
-    clean::CleanCommands, config_writer::ConfigWriterArgs, contracts::ContractsArgs,
-    database::DatabaseCommands, fmt::FmtArgs, lint::LintArgs, prover::ProverCommands,
-    send_transactions::args::SendTransactionsArgs, snapshot::SnapshotCommands, test::TestCommands,
+    audit_upgrades::args::AuditUpgradesArgs, chaos::args::ChaosArgs, clean::CleanCommands,
+    compute_create2::args::ComputeCreate2Args, config_writer::ConfigWriterArgs,
+    contracts::ContractsArgs, database::DatabaseCommands, debug_tx::args::DebugTxArgs,
+    export_l2_to_l1::args::ExportL2ToL1Args, fetch_sources::args::FetchSourcesArgs, fmt::FmtArgs,
+    fuzz::FuzzCommands, lint::LintArgs, prover::ProverCommands,
+    replay_batch::args::ReplayBatchArgs, send_transactions::args::SendTransactionsArgs,
+    snapshot::SnapshotCommands, test::TestCommands, verify_proof::args::VerifyProofArgs,
 };
 use crate::commands::dev::messages::{
-    MSG_CONFIG_WRITER_ABOUT, MSG_CONTRACTS_ABOUT, MSG_GENERATE_GENESIS_ABOUT,
-    MSG_PROVER_VERSION_ABOUT, MSG_SEND_TXNS_ABOUT, MSG_SUBCOMMAND_CLEAN,
-    MSG_SUBCOMMAND_DATABASE_ABOUT, MSG_SUBCOMMAND_FMT_ABOUT, MSG_SUBCOMMAND_LINT_ABOUT,
-    MSG_SUBCOMMAND_SNAPSHOTS_CREATOR_ABOUT, MSG_SUBCOMMAND_TESTS_ABOUT,
+    MSG_AUDIT_UPGRADES_ABOUT, MSG_CHAOS_ABOUT, MSG_COMPUTE_CREATE2_ABOUT, MSG_CONFIG_WRITER_ABOUT,
+    MSG_CONTRACTS_ABOUT, MSG_DEBUG_TX_ABOUT, MSG_EXPORT_L2_TO_L1_ABOUT, MSG_FETCH_SOURCES_ABOUT,
+    MSG_FUZZ_ABOUT, MSG_GENERATE_GENESIS_ABOUT, MSG_PROVER_VERSION_ABOUT, MSG_REPLAY_BATCH_ABOUT,
+    MSG_SEND_TXNS_ABOUT, MSG_SUBCOMMAND_CLEAN, MSG_SUBCOMMAND_DATABASE_ABOUT,
+    MSG_SUBCOMMAND_FMT_ABOUT, MSG_SUBCOMMAND_LINT_ABOUT, MSG_SUBCOMMAND_SNAPSHOTS_CREATOR_ABOUT,
+    MSG_SUBCOMMAND_TESTS_ABOUT, MSG_VERIFY_PROOF_ABOUT,
 };
 
 mod commands;
@@ -47,6 +53,24 @@ pub enum DevCommands {
     Status(StatusArgs),
     #[command(about = MSG_GENERATE_GENESIS_ABOUT, alias = "genesis")]
     GenerateGenesis,
+    #[command(about = MSG_EXPORT_L2_TO_L1_ABOUT)]
+    ExportL2ToL1(ExportL2ToL1Args),
+    #[command(about = MSG_VERIFY_PROOF_ABOUT)]
+    VerifyProof(VerifyProofArgs),
+    #[command(about = MSG_COMPUTE_CREATE2_ABOUT)]
+    ComputeCreate2(ComputeCreate2Args),
+    #[command(about = MSG_AUDIT_UPGRADES_ABOUT)]
+    AuditUpgrades(AuditUpgradesArgs),
+    #[command(about = MSG_CHAOS_ABOUT)]
+    Chaos(ChaosArgs),
+    #[command(subcommand, about = MSG_FUZZ_ABOUT)]
+    Fuzz(FuzzCommands),
+    #[command(about = MSG_DEBUG_TX_ABOUT)]
+    DebugTx(DebugTxArgs),
+    #[command(about = MSG_REPLAY_BATCH_ABOUT)]
+    ReplayBatch(ReplayBatchArgs),
+    #[command(about = MSG_FETCH_SOURCES_ABOUT)]
+    FetchSources(FetchSourcesArgs),
 }
 
 pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
@@ -65,6 +89,15 @@ pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
         }
         DevCommands::Status(args) => commands::status::run(shell, args).await?,
         DevCommands::GenerateGenesis => commands::genesis::run(shell).await?,
+        DevCommands::ExportL2ToL1(args) => commands::export_l2_to_l1::run(shell, args).await?,
+        DevCommands::VerifyProof(args) => commands::verify_proof::run(shell, args).await?,
+        DevCommands::ComputeCreate2(args) => commands::compute_create2::run(shell, args)?,
+        DevCommands::AuditUpgrades(args) => commands::audit_upgrades::run(shell, args).await?,
+        DevCommands::Chaos(args) => commands::chaos::run(shell, args).await?,
+        DevCommands::Fuzz(command) => commands::fuzz::run(shell, command)?,
+        DevCommands::DebugTx(args) => commands::debug_tx::run(shell, args)?,
+        DevCommands::ReplayBatch(args) => commands::replay_batch::run(shell, args)?,
+        DevCommands::FetchSources(args) => commands::fetch_sources::run(shell, args).await?,
     }
     Ok(())
 }
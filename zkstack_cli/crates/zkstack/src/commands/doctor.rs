@@ -0,0 +1,558 @@
+use std::{future::Future, net::TcpListener, path::Path, time::Duration};
+
+use anyhow::Context;
+use common::{docker, logger, missing_general_prerequisites};
+use config::{get_link_to_prover, ChainConfig, EcosystemConfig, DOCKER_COMPOSE_FILE};
+use console::style;
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::Address,
+};
+use sqlx::{Connection, PgConnection};
+use types::L1Network;
+use xshell::Shell;
+use zksync_basic_types::H256;
+use zksync_config::configs::object_store::ObjectStoreMode;
+
+use crate::{
+    messages::{
+        msg_doctor_containers_not_running, msg_doctor_containers_running,
+        msg_doctor_contract_not_deployed, msg_doctor_genesis_matches, msg_doctor_genesis_mismatch,
+        msg_doctor_install_prerequisites_hint, msg_doctor_l1_chain_id_mismatch,
+        msg_doctor_l1_connected, msg_doctor_missing_prerequisites, msg_doctor_path_exists,
+        msg_doctor_path_missing, msg_doctor_port_conflict, msg_doctor_ports_in_use,
+        msg_doctor_reachable, msg_doctor_summary, MSG_DOCTOR_ALL_INSTALLED,
+        MSG_DOCTOR_CHAIN_CONFIG, MSG_DOCTOR_CHAIN_INIT_HINT, MSG_DOCTOR_CHECK_TIMEOUT,
+        MSG_DOCTOR_COMPOSE_FILE_MISSING, MSG_DOCTOR_CONTAINERS, MSG_DOCTOR_CONTRACTS_DEPLOYED,
+        MSG_DOCTOR_CREATE_CHAIN_HINT, MSG_DOCTOR_CREATE_ECOSYSTEM_HINT,
+        MSG_DOCTOR_DATABASE_CONNECTED, MSG_DOCTOR_DATABASE_HINT,
+        MSG_DOCTOR_DATABASE_SECRETS_MISSING, MSG_DOCTOR_ECOSYSTEM_CONFIG, MSG_DOCTOR_GCS_HINT,
+        MSG_DOCTOR_GENESIS, MSG_DOCTOR_GENESIS_HASH_MISSING, MSG_DOCTOR_GENESIS_HINT,
+        MSG_DOCTOR_GENESIS_MISMATCH_HINT, MSG_DOCTOR_GENESIS_MISSING, MSG_DOCTOR_INTRO,
+        MSG_DOCTOR_L1_CONTRACTS, MSG_DOCTOR_L1_RPC, MSG_DOCTOR_L1_RPC_HINT, MSG_DOCTOR_PORTS,
+        MSG_DOCTOR_PORTS_FREE, MSG_DOCTOR_PORTS_IN_USE_HINT, MSG_DOCTOR_PORT_CONFLICTS_HINT,
+        MSG_DOCTOR_PREREQUISITES, MSG_DOCTOR_PROVER_DATABASE, MSG_DOCTOR_PROVER_DATABASE_HINT,
+        MSG_DOCTOR_PROVER_INIT_HINT, MSG_DOCTOR_PROVER_NOT_CONFIGURED,
+        MSG_DOCTOR_PROVER_OBJECT_STORE, MSG_DOCTOR_SERVER_DATABASE,
+        MSG_DOCTOR_START_CONTAINERS_HINT, MSG_L1_SECRETS_MUST_BE_PRESENTED,
+    },
+    utils::ports::EcosystemPortsScanner,
+};
+
+/// Timeout for each network request made by the checks.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Endpoint used to check reachability of Google Cloud Storage.
+const GCS_ENDPOINT: &str = "https://storage.googleapis.com";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// Outcome of a single diagnostic check.
+#[derive(Debug)]
+struct CheckResult {
+    name: &'static str,
+    status: CheckStatus,
+    details: String,
+    /// Remediation hint; only relevant for failed checks.
+    hint: Option<String>,
+}
+
+impl CheckResult {
+    fn ok(name: &'static str, details: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            details: details.into(),
+            hint: None,
+        }
+    }
+
+    fn warning(name: &'static str, details: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            details: details.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn error(name: &'static str, details: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Error,
+            details: details.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn print(&self) {
+        let mut msg = format!("{}: {}", style(self.name).bold(), self.details);
+        if let Some(hint) = &self.hint {
+            msg += &format!("\n{}", style(format!("hint: {hint}")).dim());
+        }
+        match self.status {
+            CheckStatus::Ok => logger::success(msg),
+            CheckStatus::Warning => logger::warn(msg),
+            CheckStatus::Error => logger::error(msg),
+        }
+    }
+}
+
+pub async fn run(shell: &Shell) -> anyhow::Result<()> {
+    logger::info(MSG_DOCTOR_INTRO);
+    let results = run_checks(shell).await;
+    for result in &results {
+        result.print();
+    }
+
+    let count = |status| results.iter().filter(|res| res.status == status).count();
+    let (errors, warnings) = (count(CheckStatus::Error), count(CheckStatus::Warning));
+    let summary = msg_doctor_summary(errors, warnings);
+    if errors > 0 {
+        anyhow::bail!(summary);
+    }
+    logger::outro(summary);
+    Ok(())
+}
+
+async fn run_checks(shell: &Shell) -> Vec<CheckResult> {
+    let mut results = vec![check_prerequisites(shell)];
+
+    let ecosystem_config = match EcosystemConfig::from_file(shell) {
+        Ok(config) => config,
+        Err(err) => {
+            results.push(CheckResult::error(
+                MSG_DOCTOR_ECOSYSTEM_CONFIG,
+                err.to_string(),
+                MSG_DOCTOR_CREATE_ECOSYSTEM_HINT,
+            ));
+            return results;
+        }
+    };
+    results.push(CheckResult::ok(
+        MSG_DOCTOR_ECOSYSTEM_CONFIG,
+        ecosystem_config.name.clone(),
+    ));
+    results.push(check_containers(shell, &ecosystem_config));
+
+    let chain_config = match ecosystem_config.load_current_chain() {
+        Ok(config) => config,
+        Err(err) => {
+            results.push(CheckResult::error(
+                MSG_DOCTOR_CHAIN_CONFIG,
+                format!("{err:#}"),
+                MSG_DOCTOR_CREATE_CHAIN_HINT,
+            ));
+            return results;
+        }
+    };
+    results.push(CheckResult::ok(
+        MSG_DOCTOR_CHAIN_CONFIG,
+        chain_config.name.clone(),
+    ));
+    results.extend(check_ports(shell, &chain_config));
+    results.extend(check_l1(&ecosystem_config, &chain_config).await);
+    results.extend(check_databases(&chain_config).await);
+    results.push(check_prover_object_store(&ecosystem_config, &chain_config).await);
+    results
+}
+
+fn check_prerequisites(shell: &Shell) -> CheckResult {
+    let missing = missing_general_prerequisites(shell);
+    if missing.is_empty() {
+        return CheckResult::ok(MSG_DOCTOR_PREREQUISITES, MSG_DOCTOR_ALL_INSTALLED);
+    }
+    let names: Vec<_> = missing.iter().map(|(name, _)| *name).collect();
+    let links: Vec<_> = missing
+        .iter()
+        .map(|(name, link)| format!("{name} ({link})"))
+        .collect();
+    CheckResult::error(
+        MSG_DOCTOR_PREREQUISITES,
+        msg_doctor_missing_prerequisites(&names),
+        msg_doctor_install_prerequisites_hint(&links),
+    )
+}
+
+fn check_containers(shell: &Shell, ecosystem_config: &EcosystemConfig) -> CheckResult {
+    if !shell.path_exists(DOCKER_COMPOSE_FILE) {
+        return CheckResult::warning(
+            MSG_DOCTOR_CONTAINERS,
+            MSG_DOCTOR_COMPOSE_FILE_MISSING,
+            MSG_DOCTOR_START_CONTAINERS_HINT,
+        );
+    }
+    let mut required = vec!["postgres"];
+    // Non-local setups use external L1 nodes.
+    if ecosystem_config.l1_network == L1Network::Localhost {
+        required.push("reth");
+    }
+
+    let running = match docker::running_services(shell, DOCKER_COMPOSE_FILE) {
+        Ok(running) => running,
+        Err(err) => {
+            return CheckResult::error(
+                MSG_DOCTOR_CONTAINERS,
+                err.to_string(),
+                MSG_DOCTOR_START_CONTAINERS_HINT,
+            )
+        }
+    };
+    let not_running: Vec<_> = required
+        .iter()
+        .copied()
+        .filter(|service| !running.iter().any(|running| running == service))
+        .collect();
+    if not_running.is_empty() {
+        CheckResult::ok(
+            MSG_DOCTOR_CONTAINERS,
+            msg_doctor_containers_running(&required),
+        )
+    } else {
+        CheckResult::error(
+            MSG_DOCTOR_CONTAINERS,
+            msg_doctor_containers_not_running(&not_running),
+            MSG_DOCTOR_START_CONTAINERS_HINT,
+        )
+    }
+}
+
+/// Checks that ports assigned to the chain are not occupied and aren't assigned elsewhere in the ecosystem.
+fn check_ports(shell: &Shell, chain_config: &ChainConfig) -> Vec<CheckResult> {
+    let ecosystem_ports = match EcosystemPortsScanner::scan(shell) {
+        Ok(ports) => ports,
+        Err(err) => {
+            return vec![CheckResult::error(
+                MSG_DOCTOR_PORTS,
+                format!("{err:#}"),
+                MSG_DOCTOR_CREATE_CHAIN_HINT,
+            )]
+        }
+    };
+
+    let is_chain_file = |file_path: &str| Path::new(file_path).starts_with(&chain_config.configs);
+    let mut ports_in_use = vec![];
+    let mut conflicts = vec![];
+    for (&port, infos) in &ecosystem_ports.ports {
+        if !infos.iter().any(|info| is_chain_file(&info.file_path)) {
+            continue;
+        }
+        if TcpListener::bind(("127.0.0.1", port)).is_err() {
+            ports_in_use.push(port);
+        }
+        conflicts.extend(
+            infos
+                .iter()
+                .filter(|info| !is_chain_file(&info.file_path))
+                .map(|info| msg_doctor_port_conflict(port, &info.file_path)),
+        );
+    }
+    ports_in_use.sort_unstable();
+    conflicts.sort();
+
+    let mut results = vec![];
+    if !ports_in_use.is_empty() {
+        results.push(CheckResult::warning(
+            MSG_DOCTOR_PORTS,
+            msg_doctor_ports_in_use(&ports_in_use),
+            MSG_DOCTOR_PORTS_IN_USE_HINT,
+        ));
+    }
+    if !conflicts.is_empty() {
+        results.push(CheckResult::error(
+            MSG_DOCTOR_PORTS,
+            conflicts.join("\n"),
+            MSG_DOCTOR_PORT_CONFLICTS_HINT,
+        ));
+    }
+    if results.is_empty() {
+        results.push(CheckResult::ok(MSG_DOCTOR_PORTS, MSG_DOCTOR_PORTS_FREE));
+    }
+    results
+}
+
+async fn check_l1(
+    ecosystem_config: &EcosystemConfig,
+    chain_config: &ChainConfig,
+) -> Vec<CheckResult> {
+    let provider = match l1_provider(chain_config) {
+        Ok(provider) => provider,
+        Err(err) => {
+            return vec![CheckResult::error(
+                MSG_DOCTOR_L1_RPC,
+                format!("{err:#}"),
+                MSG_DOCTOR_L1_RPC_HINT,
+            )]
+        }
+    };
+    let expected_chain_id = ecosystem_config.l1_network.chain_id();
+    let rpc_result = match with_timeout(provider.get_chainid()).await {
+        Ok(chain_id) if chain_id.as_u64() == expected_chain_id => CheckResult::ok(
+            MSG_DOCTOR_L1_RPC,
+            msg_doctor_l1_connected(expected_chain_id),
+        ),
+        Ok(chain_id) => CheckResult::error(
+            MSG_DOCTOR_L1_RPC,
+            msg_doctor_l1_chain_id_mismatch(chain_id.as_u64(), expected_chain_id),
+            MSG_DOCTOR_L1_RPC_HINT,
+        ),
+        Err(err) => {
+            // Contract checks are meaningless without a working RPC.
+            return vec![CheckResult::error(
+                MSG_DOCTOR_L1_RPC,
+                format!("{err:#}"),
+                MSG_DOCTOR_L1_RPC_HINT,
+            )];
+        }
+    };
+    vec![
+        rpc_result,
+        check_l1_contracts(&provider, chain_config).await,
+    ]
+}
+
+fn l1_provider(chain_config: &ChainConfig) -> anyhow::Result<Provider<Http>> {
+    let secrets = chain_config.get_secrets_config()?;
+    let l1_rpc_url = secrets
+        .l1
+        .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+        .l1_rpc_url
+        .expose_str()
+        .to_owned();
+    Ok(Provider::<Http>::try_from(l1_rpc_url)?)
+}
+
+async fn check_l1_contracts(provider: &Provider<Http>, chain_config: &ChainConfig) -> CheckResult {
+    let contracts_config = match chain_config.get_contracts_config() {
+        Ok(config) => config,
+        Err(err) => {
+            return CheckResult::error(
+                MSG_DOCTOR_L1_CONTRACTS,
+                format!("{err:#}"),
+                MSG_DOCTOR_CHAIN_INIT_HINT,
+            )
+        }
+    };
+    let contracts = [
+        (
+            "bridgehub",
+            contracts_config.ecosystem_contracts.bridgehub_proxy_addr,
+        ),
+        ("diamond proxy", contracts_config.l1.diamond_proxy_addr),
+    ];
+    for (name, address) in contracts {
+        match contract_has_code(provider, address).await {
+            Ok(true) => {}
+            Ok(false) => {
+                return CheckResult::error(
+                    MSG_DOCTOR_L1_CONTRACTS,
+                    msg_doctor_contract_not_deployed(name, address),
+                    MSG_DOCTOR_CHAIN_INIT_HINT,
+                )
+            }
+            Err(err) => {
+                return CheckResult::error(
+                    MSG_DOCTOR_L1_CONTRACTS,
+                    format!("{err:#}"),
+                    MSG_DOCTOR_L1_RPC_HINT,
+                )
+            }
+        }
+    }
+    CheckResult::ok(MSG_DOCTOR_L1_CONTRACTS, MSG_DOCTOR_CONTRACTS_DEPLOYED)
+}
+
+async fn contract_has_code(provider: &Provider<Http>, address: Address) -> anyhow::Result<bool> {
+    let code = with_timeout(provider.get_code(address, None)).await?;
+    Ok(!code.is_empty())
+}
+
+async fn check_databases(chain_config: &ChainConfig) -> Vec<CheckResult> {
+    let database = match chain_config.get_secrets_config() {
+        Ok(secrets) => secrets.database,
+        Err(err) => {
+            return vec![CheckResult::error(
+                MSG_DOCTOR_SERVER_DATABASE,
+                format!("{err:#}"),
+                MSG_DOCTOR_DATABASE_HINT,
+            )]
+        }
+    };
+    let Some(database) = database else {
+        return vec![CheckResult::error(
+            MSG_DOCTOR_SERVER_DATABASE,
+            MSG_DOCTOR_DATABASE_SECRETS_MISSING,
+            MSG_DOCTOR_DATABASE_HINT,
+        )];
+    };
+
+    let mut results = vec![];
+    let server_connection = match database.master_url() {
+        Ok(url) => connect(url.expose_str()).await,
+        Err(err) => Err(err),
+    };
+    match server_connection {
+        Ok(mut connection) => {
+            results.push(CheckResult::ok(
+                MSG_DOCTOR_SERVER_DATABASE,
+                MSG_DOCTOR_DATABASE_CONNECTED,
+            ));
+            results.push(check_genesis(chain_config, &mut connection).await);
+        }
+        Err(err) => results.push(CheckResult::error(
+            MSG_DOCTOR_SERVER_DATABASE,
+            format!("{err:#}"),
+            MSG_DOCTOR_DATABASE_HINT,
+        )),
+    }
+
+    if let Some(prover_url) = &database.prover_url {
+        let result = match connect(prover_url.expose_str()).await {
+            Ok(_) => CheckResult::ok(MSG_DOCTOR_PROVER_DATABASE, MSG_DOCTOR_DATABASE_CONNECTED),
+            Err(err) => CheckResult::error(
+                MSG_DOCTOR_PROVER_DATABASE,
+                format!("{err:#}"),
+                MSG_DOCTOR_PROVER_DATABASE_HINT,
+            ),
+        };
+        results.push(result);
+    }
+    results
+}
+
+async fn connect(url: &str) -> anyhow::Result<PgConnection> {
+    with_timeout(PgConnection::connect(url)).await
+}
+
+/// Compares the genesis root hash from the chain config with the one of the genesis L1 batch in Postgres.
+async fn check_genesis(chain_config: &ChainConfig, connection: &mut PgConnection) -> CheckResult {
+    let config_hash = match chain_config.get_genesis_config() {
+        Ok(genesis) => genesis.genesis_root_hash,
+        Err(err) => {
+            return CheckResult::error(
+                MSG_DOCTOR_GENESIS,
+                format!("{err:#}"),
+                MSG_DOCTOR_CREATE_CHAIN_HINT,
+            )
+        }
+    };
+    let db_hash: Option<Option<Vec<u8>>> =
+        match sqlx::query_scalar("SELECT hash FROM l1_batches WHERE number = 0")
+            .fetch_optional(connection)
+            .await
+        {
+            Ok(hash) => hash,
+            Err(err) => {
+                return CheckResult::error(
+                    MSG_DOCTOR_GENESIS,
+                    err.to_string(),
+                    MSG_DOCTOR_GENESIS_HINT,
+                )
+            }
+        };
+
+    match (config_hash, db_hash.flatten()) {
+        (Some(config_hash), Some(db_hash)) => {
+            let db_hash = H256::from_slice(&db_hash);
+            if config_hash == db_hash {
+                CheckResult::ok(MSG_DOCTOR_GENESIS, msg_doctor_genesis_matches(db_hash))
+            } else {
+                CheckResult::error(
+                    MSG_DOCTOR_GENESIS,
+                    msg_doctor_genesis_mismatch(config_hash, db_hash),
+                    MSG_DOCTOR_GENESIS_MISMATCH_HINT,
+                )
+            }
+        }
+        (None, _) => CheckResult::error(
+            MSG_DOCTOR_GENESIS,
+            MSG_DOCTOR_GENESIS_HASH_MISSING,
+            MSG_DOCTOR_CHAIN_INIT_HINT,
+        ),
+        (_, None) => CheckResult::warning(
+            MSG_DOCTOR_GENESIS,
+            MSG_DOCTOR_GENESIS_MISSING,
+            MSG_DOCTOR_GENESIS_HINT,
+        ),
+    }
+}
+
+async fn check_prover_object_store(
+    ecosystem_config: &EcosystemConfig,
+    chain_config: &ChainConfig,
+) -> CheckResult {
+    let object_store = chain_config
+        .get_general_config()
+        .ok()
+        .and_then(|config| config.prover_config)
+        .and_then(|config| config.prover_object_store);
+    let Some(object_store) = object_store else {
+        return CheckResult::ok(
+            MSG_DOCTOR_PROVER_OBJECT_STORE,
+            MSG_DOCTOR_PROVER_NOT_CONFIGURED,
+        );
+    };
+
+    match object_store.mode {
+        ObjectStoreMode::FileBacked {
+            file_backed_base_path,
+        } => {
+            // The base path is relative to the prover workspace, which prover components are run from.
+            let path = get_link_to_prover(ecosystem_config).join(file_backed_base_path);
+            if path.is_dir() {
+                CheckResult::ok(
+                    MSG_DOCTOR_PROVER_OBJECT_STORE,
+                    msg_doctor_path_exists(&path),
+                )
+            } else {
+                CheckResult::warning(
+                    MSG_DOCTOR_PROVER_OBJECT_STORE,
+                    msg_doctor_path_missing(&path),
+                    MSG_DOCTOR_PROVER_INIT_HINT,
+                )
+            }
+        }
+        ObjectStoreMode::GCSWithCredentialFile {
+            gcs_credential_file_path,
+            ..
+        } if !Path::new(&gcs_credential_file_path).exists() => CheckResult::error(
+            MSG_DOCTOR_PROVER_OBJECT_STORE,
+            msg_doctor_path_missing(Path::new(&gcs_credential_file_path)),
+            MSG_DOCTOR_PROVER_INIT_HINT,
+        ),
+        ObjectStoreMode::GCS { .. }
+        | ObjectStoreMode::GCSAnonymousReadOnly { .. }
+        | ObjectStoreMode::GCSWithCredentialFile { .. } => match check_gcs_reachable().await {
+            Ok(()) => CheckResult::ok(
+                MSG_DOCTOR_PROVER_OBJECT_STORE,
+                msg_doctor_reachable(GCS_ENDPOINT),
+            ),
+            Err(err) => CheckResult::error(
+                MSG_DOCTOR_PROVER_OBJECT_STORE,
+                format!("{err:#}"),
+                MSG_DOCTOR_GCS_HINT,
+            ),
+        },
+    }
+}
+
+/// Checks that GCS responds to requests; authentication and bucket access are checked by prover components.
+async fn check_gcs_reachable() -> anyhow::Result<()> {
+    let client = reqwest::Client::builder().timeout(CHECK_TIMEOUT).build()?;
+    client.get(GCS_ENDPOINT).send().await?;
+    Ok(())
+}
+
+async fn with_timeout<T, E>(fut: impl Future<Output = Result<T, E>>) -> anyhow::Result<T>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    tokio::time::timeout(CHECK_TIMEOUT, fut)
+        .await
+        .map_err(|_| anyhow::anyhow!(MSG_DOCTOR_CHECK_TIMEOUT))?
+        .map_err(Into::into)
+}
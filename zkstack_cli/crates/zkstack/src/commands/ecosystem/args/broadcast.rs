@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use common::Prompt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::sign_transactions::default_bundle_path;
+use crate::{
+    defaults::LOCAL_RPC_URL,
+    messages::{MSG_L1_RPC_URL_HELP, MSG_L1_RPC_URL_INVALID_ERR, MSG_L1_RPC_URL_PROMPT},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct BroadcastArgs {
+    /// Path to the signed transactions bundle.
+    #[clap(long)]
+    pub bundle: Option<PathBuf>,
+    #[clap(long, help = MSG_L1_RPC_URL_HELP)]
+    pub l1_rpc_url: Option<String>,
+}
+
+impl BroadcastArgs {
+    pub fn fill_values_with_prompt(self) -> BroadcastArgsFinal {
+        let l1_rpc_url = self.l1_rpc_url.unwrap_or_else(|| {
+            Prompt::new(MSG_L1_RPC_URL_PROMPT)
+                .default(LOCAL_RPC_URL)
+                .validate_with(|val: &String| -> Result<(), String> {
+                    Url::parse(val)
+                        .map(|_| ())
+                        .map_err(|_| MSG_L1_RPC_URL_INVALID_ERR.to_string())
+                })
+                .ask()
+        });
+
+        BroadcastArgsFinal {
+            bundle: self.bundle.unwrap_or_else(default_bundle_path),
+            l1_rpc_url,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BroadcastArgsFinal {
+    pub bundle: PathBuf,
+    pub l1_rpc_url: String,
+}
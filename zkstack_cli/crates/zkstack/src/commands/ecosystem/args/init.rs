@@ -7,13 +7,13 @@ use types::L1Network;
 use url::Url;
 
 use crate::{
-    commands::chain::args::genesis::GenesisArgs,
+    commands::chain::args::{genesis::GenesisArgs, init::da_configs::ChainValidiumType},
     defaults::LOCAL_RPC_URL,
     messages::{
         MSG_DEPLOY_ECOSYSTEM_PROMPT, MSG_DEPLOY_ERC20_PROMPT, MSG_DEV_ARG_HELP,
-        MSG_L1_RPC_URL_HELP, MSG_L1_RPC_URL_INVALID_ERR, MSG_L1_RPC_URL_PROMPT,
-        MSG_NO_PORT_REALLOCATION_HELP, MSG_OBSERVABILITY_HELP, MSG_OBSERVABILITY_PROMPT,
-        MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP,
+        MSG_ECOSYSTEM_VALIDIUM_TYPE_HELP, MSG_L1_RPC_URL_HELP, MSG_L1_RPC_URL_INVALID_ERR,
+        MSG_L1_RPC_URL_PROMPT, MSG_NO_PORT_REALLOCATION_HELP, MSG_OBSERVABILITY_HELP,
+        MSG_OBSERVABILITY_PROMPT, MSG_SERVER_DB_NAME_HELP, MSG_SERVER_DB_URL_HELP,
     },
 };
 
@@ -101,6 +101,9 @@ pub struct EcosystemInitArgs {
     pub observability: Option<bool>,
     #[clap(long, help = MSG_NO_PORT_REALLOCATION_HELP)]
     pub no_port_reallocation: bool,
+    #[clap(long = "validium-type", visible_alias = "validium", help = MSG_ECOSYSTEM_VALIDIUM_TYPE_HELP)]
+    #[serde(default)]
+    pub validium_types: Vec<ChainValidiumType>,
 }
 
 impl EcosystemInitArgs {
@@ -1,4 +1,7 @@
+pub mod broadcast;
 pub mod build_transactions;
 pub mod change_default;
 pub mod create;
 pub mod init;
+pub mod sign_transactions;
+pub mod teardown;
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use common::{tx_bundle::BUNDLE_FILE_NAME, Prompt};
+use ethers::{prelude::LocalWallet, types::U256};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts::DEFAULT_UNSIGNED_TRANSACTIONS_DIR,
+    messages::{MSG_SIGNER_PRIVATE_KEY_INVALID_ERR, MSG_SIGNER_PRIVATE_KEY_PROMPT},
+};
+
+pub(crate) fn default_bundle_path() -> PathBuf {
+    PathBuf::from(DEFAULT_UNSIGNED_TRANSACTIONS_DIR).join(BUNDLE_FILE_NAME)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct SignTransactionsArgs {
+    /// Path to the transactions bundle.
+    #[clap(long)]
+    pub bundle: Option<PathBuf>,
+    /// Private key of the signer; transactions sent from other addresses are left unsigned.
+    #[clap(long)]
+    pub private_key: Option<String>,
+    /// Gas price in gwei; required if the transactions have no gas price.
+    #[clap(long)]
+    pub gas_price_gwei: Option<u64>,
+}
+
+impl SignTransactionsArgs {
+    pub fn fill_values_with_prompt(self) -> anyhow::Result<SignTransactionsArgsFinal> {
+        let private_key = self.private_key.unwrap_or_else(|| {
+            Prompt::new(MSG_SIGNER_PRIVATE_KEY_PROMPT)
+                .validate_with(|val: &String| -> Result<(), String> {
+                    val.parse::<LocalWallet>()
+                        .map(|_| ())
+                        .map_err(|_| MSG_SIGNER_PRIVATE_KEY_INVALID_ERR.to_string())
+                })
+                .ask()
+        });
+        let wallet = private_key
+            .parse()
+            .map_err(|_| anyhow::anyhow!(MSG_SIGNER_PRIVATE_KEY_INVALID_ERR))?;
+        let gas_price = self
+            .gas_price_gwei
+            .map(|gwei| U256::from(gwei) * U256::exp10(9));
+
+        Ok(SignTransactionsArgsFinal {
+            bundle: self.bundle.unwrap_or_else(default_bundle_path),
+            wallet,
+            gas_price,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct SignTransactionsArgsFinal {
+    pub bundle: PathBuf,
+    pub wallet: LocalWallet,
+    pub gas_price: Option<U256>,
+}
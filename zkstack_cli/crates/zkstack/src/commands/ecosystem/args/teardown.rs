@@ -0,0 +1,11 @@
+use clap::Parser;
+
+use crate::messages::{MSG_TEARDOWN_WIPE_L1_HELP, MSG_TEARDOWN_YES_HELP};
+
+#[derive(Debug, Parser)]
+pub struct EcosystemTeardownArgs {
+    #[clap(long, help = MSG_TEARDOWN_WIPE_L1_HELP)]
+    pub wipe_l1: bool,
+    #[clap(long, short, help = MSG_TEARDOWN_YES_HELP)]
+    pub yes: bool,
+}
@@ -0,0 +1,60 @@
+use anyhow::Context as _;
+use common::{logger, spinner::Spinner, tx_bundle::TransactionBundle};
+use ethers::{
+    providers::{Http, Middleware, Provider},
+    types::U64,
+};
+use xshell::Shell;
+
+use super::args::broadcast::BroadcastArgs;
+use crate::messages::{
+    msg_broadcasting_transaction, msg_bundle_awaits_signatures, msg_bundle_broadcast,
+    msg_bundle_chain_id_mismatch_err, msg_bundled_transaction_failed_err, MSG_RECEIPT_MISSING,
+};
+
+pub(super) async fn run(args: BroadcastArgs, shell: &Shell) -> anyhow::Result<()> {
+    let args = args.fill_values_with_prompt();
+    let bundle = TransactionBundle::read(shell, &args.bundle)?;
+    let unsigned_senders = bundle.unsigned_senders();
+    anyhow::ensure!(
+        unsigned_senders.is_empty(),
+        msg_bundle_awaits_signatures(&unsigned_senders)
+    );
+
+    let provider = Provider::<Http>::try_from(args.l1_rpc_url)?;
+    let l1_chain_id = provider.get_chainid().await?.as_u64();
+    let total = bundle.transactions.len();
+    for (i, tx) in bundle.transactions.iter().enumerate() {
+        let number = i + 1;
+        if let Some(chain_id) = tx.transaction.chain_id {
+            anyhow::ensure!(
+                chain_id.as_u64() == l1_chain_id,
+                msg_bundle_chain_id_mismatch_err(number, chain_id.as_u64(), l1_chain_id)
+            );
+        }
+        let (Some(signed), Some(hash)) = (&tx.signed, tx.signed_hash()) else {
+            unreachable!("all transactions are signed");
+        };
+
+        let description = tx.description.as_deref().unwrap_or(&tx.script);
+        let spinner = Spinner::new(&msg_broadcasting_transaction(number, total, description));
+        // The transaction may have been sent by a previous run that was interrupted.
+        let receipt = match provider.get_transaction_receipt(hash).await? {
+            Some(receipt) => receipt,
+            None => provider
+                .send_raw_transaction(signed.clone())
+                .await
+                .with_context(|| format!("failed sending transaction #{number}"))?
+                .await?
+                .context(MSG_RECEIPT_MISSING)?,
+        };
+        anyhow::ensure!(
+            receipt.status == Some(U64::one()),
+            msg_bundled_transaction_failed_err(number, hash)
+        );
+        spinner.finish();
+    }
+
+    logger::outro(msg_bundle_broadcast(total));
+    Ok(())
+}
@@ -75,6 +75,7 @@ fn create(args: EcosystemCreateArgs, shell: &Shell) -> anyhow::Result<()> {
         default_chain: default_chain_name.clone(),
         prover_version: chain_config.prover_version,
         wallet_creation: args.wallet_creation,
+        container_runtime: None,
         shell: shell.clone().into(),
     };
 
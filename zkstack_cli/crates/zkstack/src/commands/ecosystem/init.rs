@@ -1,4 +1,4 @@
-use std::{path::PathBuf, str::FromStr};
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 use anyhow::Context;
 use common::{
@@ -20,6 +20,7 @@ use config::{
     traits::{FileConfigWithDefaultName, ReadConfig, SaveConfig, SaveConfigWithBasePath},
     ContractsConfig, EcosystemConfig,
 };
+use serde::Serialize;
 use types::L1Network;
 use xshell::Shell;
 
@@ -31,14 +32,15 @@ use super::{
 use crate::{
     accept_ownership::{accept_admin, accept_owner},
     commands::{
-        chain::{self},
+        chain::{self, args::init::da_configs::ValidiumTypeArgs, init::ChainInitOutput},
         ecosystem::create_configs::{
             create_erc20_deployment_config, create_initial_deployments_config,
         },
     },
     messages::{
         msg_chain_load_err, msg_ecosystem_initialized, msg_ecosystem_no_found_preexisting_contract,
-        msg_initializing_chain, MSG_DEPLOYING_ECOSYSTEM_CONTRACTS_SPINNER, MSG_DEPLOYING_ERC20,
+        msg_initializing_chain, msg_validium_type_for_unknown_chain_err,
+        MSG_DEPLOYING_ECOSYSTEM_CONTRACTS_SPINNER, MSG_DEPLOYING_ERC20,
         MSG_DEPLOYING_ERC20_SPINNER, MSG_ECOSYSTEM_CONTRACTS_PATH_INVALID_ERR,
         MSG_ECOSYSTEM_CONTRACTS_PATH_PROMPT, MSG_INITIALIZING_ECOSYSTEM,
         MSG_INTALLING_DEPS_SPINNER,
@@ -46,6 +48,13 @@ use crate::{
     utils::forge::{check_the_balance, fill_forge_private_key},
 };
 
+/// Result of the command printed in the JSON output mode.
+#[derive(Debug, Serialize)]
+struct EcosystemInitOutput {
+    contracts: ContractsConfig,
+    chains: Vec<ChainInitOutput>,
+}
+
 pub async fn run(args: EcosystemInitArgs, shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
 
@@ -98,6 +107,17 @@ pub async fn run(args: EcosystemInitArgs, shell: &Shell) -> anyhow::Result<()> {
     }
     logger::outro(msg_ecosystem_initialized(&chains.join(",")));
 
+    let chains = chains
+        .iter()
+        .map(|name| {
+            let chain_config = ecosystem_config.load_chain(Some(name.clone()))?;
+            ChainInitOutput::new(shell, &chain_config)
+        })
+        .collect::<anyhow::Result<_>>()?;
+    logger::result(EcosystemInitOutput {
+        contracts: contracts_config,
+        chains,
+    });
     Ok(())
 }
 
@@ -350,9 +370,24 @@ async fn init_chains(
     if list_of_chains.len() > 1 {
         genesis_args.reset_db_names();
     }
-    // Initialize chains
+    // Per-chain validium types take precedence over the ecosystem-wide one
+    let mut validium_types = HashMap::new();
+    let mut default_validium_type = None;
+    for validium_type in &init_args.validium_types {
+        match &validium_type.chain_name {
+            Some(chain_name) => {
+                if !list_of_chains.contains(chain_name) {
+                    anyhow::bail!(msg_validium_type_for_unknown_chain_err(chain_name));
+                }
+                validium_types.insert(chain_name.as_str(), validium_type.validium_type);
+            }
+            None => default_validium_type = Some(validium_type.validium_type),
+        }
+    }
+
+    // Collect arguments for all chains before initializing them, so that prompts are not interleaved with deployments
+    let mut chains = vec![];
     for chain_name in &list_of_chains {
-        logger::info(msg_initializing_chain(chain_name));
         let chain_config = ecosystem_config
             .load_chain(Some(chain_name.clone()))
             .context(msg_chain_load_err(chain_name))?;
@@ -366,16 +401,22 @@ async fn init_chains(
             l1_rpc_url: Some(final_init_args.ecosystem.l1_rpc_url.clone()),
             no_port_reallocation: final_init_args.no_port_reallocation,
             dev: final_init_args.dev,
+            validium_args: ValidiumTypeArgs {
+                validium_type: validium_types
+                    .get(chain_name.as_str())
+                    .copied()
+                    .or(default_validium_type),
+                ..ValidiumTypeArgs::default()
+            },
         };
         let final_chain_init_args = chain_init_args.fill_values_with_prompt(&chain_config);
+        chains.push((chain_config, final_chain_init_args));
+    }
 
-        chain::init::init(
-            &final_chain_init_args,
-            shell,
-            ecosystem_config,
-            &chain_config,
-        )
-        .await?;
+    // Initialize chains
+    for (chain_config, final_chain_init_args) in &chains {
+        logger::info(msg_initializing_chain(&chain_config.name));
+        chain::init::init(final_chain_init_args, shell, ecosystem_config, chain_config).await?;
     }
     Ok(list_of_chains)
 }
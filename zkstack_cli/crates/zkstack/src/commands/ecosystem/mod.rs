@@ -2,11 +2,17 @@ use args::build_transactions::BuildTransactionsArgs;
 use clap::Subcommand;
 use xshell::Shell;
 
-use crate::commands::ecosystem::args::{
-    change_default::ChangeDefaultChain, create::EcosystemCreateArgs, init::EcosystemInitArgs,
+use crate::commands::{
+    chain::args::monitor::MonitorArgs,
+    ecosystem::args::{
+        broadcast::BroadcastArgs, change_default::ChangeDefaultChain, create::EcosystemCreateArgs,
+        init::EcosystemInitArgs, sign_transactions::SignTransactionsArgs,
+        teardown::EcosystemTeardownArgs,
+    },
 };
 
 mod args;
+mod broadcast;
 pub(crate) mod build_transactions;
 mod change_default;
 mod common;
@@ -14,6 +20,9 @@ mod create;
 pub mod create_configs;
 pub(crate) mod init;
 pub(crate) mod setup_observability;
+mod sign_transactions;
+mod status;
+mod teardown;
 mod utils;
 
 #[derive(Subcommand, Debug)]
@@ -24,6 +33,10 @@ pub enum EcosystemCommands {
     Create(EcosystemCreateArgs),
     /// Create transactions to build ecosystem contracts
     BuildTransactions(BuildTransactionsArgs),
+    /// Sign the unsigned transactions bundle built with `--unsigned-txs-dir`; works offline
+    SignTransactions(SignTransactionsArgs),
+    /// Broadcast the signed transactions bundle to L1 in order
+    Broadcast(BroadcastArgs),
     /// Initialize ecosystem and chain,
     /// deploying necessary contracts and performing on-chain operations
     Init(EcosystemInitArgs),
@@ -34,14 +47,24 @@ pub enum EcosystemCommands {
     /// downloading Grafana dashboards from the era-observability repo
     #[command(alias = "obs")]
     SetupObservability,
+    /// Tear down the ecosystem: drop chain databases, remove deployment artifacts
+    /// and stop containers
+    Teardown(EcosystemTeardownArgs),
+    /// Live dashboard of the ecosystem: container states and, for each chain, server health,
+    /// latest L2 block, last committed / proven / executed L1 batch and prover queue depth
+    Status(MonitorArgs),
 }
 
 pub(crate) async fn run(shell: &Shell, args: EcosystemCommands) -> anyhow::Result<()> {
     match args {
         EcosystemCommands::Create(args) => create::run(args, shell),
         EcosystemCommands::BuildTransactions(args) => build_transactions::run(args, shell).await,
+        EcosystemCommands::SignTransactions(args) => sign_transactions::run(args, shell),
+        EcosystemCommands::Broadcast(args) => broadcast::run(args, shell).await,
         EcosystemCommands::Init(args) => init::run(args, shell).await,
         EcosystemCommands::ChangeDefaultChain(args) => change_default::run(args, shell),
         EcosystemCommands::SetupObservability => setup_observability::run(shell),
+        EcosystemCommands::Teardown(args) => teardown::run(args, shell).await,
+        EcosystemCommands::Status(args) => status::run(args, shell).await,
     }
 }
@@ -0,0 +1,27 @@
+use common::{logger, tx_bundle::TransactionBundle};
+use ethers::prelude::Signer;
+use xshell::Shell;
+
+use super::args::sign_transactions::SignTransactionsArgs;
+use crate::messages::{
+    msg_bundle_awaits_signatures, msg_no_transactions_from_signer_err, msg_signed_transactions,
+    MSG_BUNDLE_SIGNED,
+};
+
+pub(super) fn run(args: SignTransactionsArgs, shell: &Shell) -> anyhow::Result<()> {
+    let args = args.fill_values_with_prompt()?;
+    let address = args.wallet.address();
+    let mut bundle = TransactionBundle::read(shell, &args.bundle)?;
+    let count = bundle.sign(&args.wallet, args.gas_price)?;
+    anyhow::ensure!(count > 0, msg_no_transactions_from_signer_err(address));
+    bundle.save(shell, &args.bundle)?;
+    logger::info(msg_signed_transactions(count, address));
+
+    let unsigned_senders = bundle.unsigned_senders();
+    if unsigned_senders.is_empty() {
+        logger::outro(MSG_BUNDLE_SIGNED);
+    } else {
+        logger::outro(msg_bundle_awaits_signatures(&unsigned_senders));
+    }
+    Ok(())
+}
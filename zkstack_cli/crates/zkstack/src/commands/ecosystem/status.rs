@@ -0,0 +1,280 @@
+use std::{fmt, time::Duration};
+
+use anyhow::Context;
+use chrono::Utc;
+use common::docker;
+use config::{EcosystemConfig, DOCKER_COMPOSE_FILE};
+use console::style;
+use futures::future;
+use sqlx::{Connection, PgConnection};
+use xshell::Shell;
+
+use crate::{
+    commands::chain::{
+        args::monitor::MonitorArgs,
+        monitor::{format_or_unavailable, format_status, show_dashboard, ChainMonitor, Snapshot},
+    },
+    messages::{
+        msg_ecosystem_status_chain_header, msg_ecosystem_status_header, MSG_CHAIN_NOT_FOUND_ERR,
+        MSG_ECOSYSTEM_STATUS_NO_SERVICES, MSG_ECOSYSTEM_STATUS_PROVER_NOT_CONFIGURED,
+        MSG_MONITOR_UNAVAILABLE,
+    },
+};
+
+/// Timeout for connecting to and querying the prover database.
+const PROVER_DB_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of prover jobs by status.
+#[derive(Debug, Clone, Copy)]
+struct ProverQueue {
+    queued: i64,
+    in_progress: i64,
+}
+
+/// State of a chain at a certain moment.
+#[derive(Debug)]
+struct ChainStatus {
+    name: String,
+    /// `Err` if the chain cannot be monitored at all, e.g. because it's not initialized.
+    snapshot: anyhow::Result<Snapshot>,
+    /// `None` if the prover database is not configured for the chain.
+    prover_queue: Option<anyhow::Result<ProverQueue>>,
+}
+
+/// Service names from the compose file together with whether they are running.
+type ContainerStates = anyhow::Result<Vec<(String, bool)>>;
+
+struct ChainSource {
+    name: String,
+    monitor: anyhow::Result<ChainMonitor>,
+    prover_url: Option<String>,
+}
+
+impl ChainSource {
+    async fn status(&self) -> ChainStatus {
+        let snapshot = match &self.monitor {
+            Ok(monitor) => Ok(monitor.snapshot().await),
+            Err(err) => Err(anyhow::anyhow!("{err:#}")),
+        };
+        let prover_queue = match &self.prover_url {
+            Some(url) => Some(prover_queue(url).await),
+            None => None,
+        };
+        ChainStatus {
+            name: self.name.clone(),
+            snapshot,
+            prover_queue,
+        }
+    }
+}
+
+async fn prover_queue(url: &str) -> anyhow::Result<ProverQueue> {
+    let query = async {
+        let mut connection = PgConnection::connect(url).await?;
+        sqlx::query_as::<_, (i64, i64)>(
+            "SELECT COUNT(*) FILTER (WHERE status = 'queued'), \
+             COUNT(*) FILTER (WHERE status = 'in_progress') FROM prover_jobs_fri",
+        )
+        .fetch_one(&mut connection)
+        .await
+    };
+    let (queued, in_progress) = tokio::time::timeout(PROVER_DB_TIMEOUT, query)
+        .await
+        .context("prover database timed out")?
+        .context("failed querying prover jobs")?;
+    Ok(ProverQueue {
+        queued,
+        in_progress,
+    })
+}
+
+fn container_states(shell: &Shell) -> ContainerStates {
+    let services = docker::services(shell, DOCKER_COMPOSE_FILE)?;
+    let running = docker::running_services(shell, DOCKER_COMPOSE_FILE)?;
+    Ok(services
+        .into_iter()
+        .map(|service| {
+            let is_running = running.contains(&service);
+            (service, is_running)
+        })
+        .collect())
+}
+
+fn render_chain(lines: &mut Vec<String>, status: &ChainStatus) {
+    lines.push(String::new());
+    lines.push(
+        style(msg_ecosystem_status_chain_header(&status.name))
+            .bold()
+            .underlined()
+            .to_string(),
+    );
+    match &status.snapshot {
+        Ok(snapshot) => render_snapshot(lines, snapshot),
+        Err(err) => lines.push(format!(
+            "  {}",
+            style(format!("{MSG_MONITOR_UNAVAILABLE}: {err:#}")).dim()
+        )),
+    }
+
+    let prover_queue = match &status.prover_queue {
+        Some(queue) => format_or_unavailable(queue, |queue| {
+            format!(
+                "{} queued / {} in progress",
+                queue.queued, queue.in_progress
+            )
+        }),
+        None => style(MSG_ECOSYSTEM_STATUS_PROVER_NOT_CONFIGURED)
+            .dim()
+            .to_string(),
+    };
+    lines.push(format!("  Prover jobs          {prover_queue}"));
+}
+
+fn render_snapshot(lines: &mut Vec<String>, snapshot: &Snapshot) {
+    let health = format_or_unavailable(&snapshot.health, |health| {
+        let not_ready: Vec<_> = health
+            .components
+            .iter()
+            .filter(|(_, component)| !component.is_ready())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if not_ready.is_empty() {
+            format_status(&health.status)
+        } else {
+            format!(
+                "{} ({})",
+                format_status(&health.status),
+                not_ready.join(", ")
+            )
+        }
+    });
+    lines.push(format!("  Health               {health}"));
+    lines.push(format!(
+        "  Latest L2 block      {}",
+        format_or_unavailable(&snapshot.l2_block, |number| format!("#{number}"))
+    ));
+    lines.push(format!(
+        "  L1 batches           {}",
+        format_or_unavailable(&snapshot.l1_progress, |progress| format!(
+            "#{} committed / #{} proven / #{} executed",
+            progress.committed, progress.proven, progress.executed
+        ))
+    ));
+}
+
+fn render(
+    ecosystem_name: &str,
+    timestamp: impl fmt::Display,
+    containers: &ContainerStates,
+    chains: &[ChainStatus],
+) -> Vec<String> {
+    let mut lines = vec![
+        style(msg_ecosystem_status_header(ecosystem_name, timestamp))
+            .bold()
+            .to_string(),
+        String::new(),
+        style("Containers").bold().underlined().to_string(),
+    ];
+    match containers {
+        Ok(containers) if containers.is_empty() => {
+            lines.push(format!(
+                "  {}",
+                style(MSG_ECOSYSTEM_STATUS_NO_SERVICES).dim()
+            ));
+        }
+        Ok(containers) => {
+            for (service, is_running) in containers {
+                let state = if *is_running {
+                    style("running").green()
+                } else {
+                    style("stopped").red()
+                };
+                lines.push(format!("  {service:<21}{state}"));
+            }
+        }
+        Err(err) => lines.push(format!(
+            "  {}",
+            style(format!("{MSG_MONITOR_UNAVAILABLE}: {err:#}")).dim()
+        )),
+    }
+
+    for chain in chains {
+        render_chain(&mut lines, chain);
+    }
+    lines
+}
+
+pub(super) async fn run(args: MonitorArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let mut chain_names = ecosystem_config.list_of_chains();
+    chain_names.sort();
+
+    let mut chains = Vec::with_capacity(chain_names.len());
+    for name in chain_names {
+        let chain_config = ecosystem_config
+            .load_chain(Some(name.clone()))
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        let prover_url = chain_config
+            .get_secrets_config()
+            .ok()
+            .and_then(|secrets| secrets.database)
+            .and_then(|database| database.prover_url)
+            .map(|url| url.expose_str().to_owned());
+        chains.push(ChainSource {
+            name,
+            monitor: ChainMonitor::new(&chain_config),
+            prover_url,
+        });
+    }
+
+    let (chains, ecosystem_name) = (&chains, ecosystem_config.name.as_str());
+    show_dashboard(&args, move || async move {
+        let containers = container_states(shell);
+        let statuses = future::join_all(chains.iter().map(ChainSource::status)).await;
+        let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
+        render(ecosystem_name, timestamp, &containers, &statuses)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendering_status_with_unavailable_sources() {
+        console::set_colors_enabled(false);
+        let containers = Ok(vec![
+            ("postgres".to_owned(), true),
+            ("reth".to_owned(), false),
+        ]);
+        let chains = [
+            ChainStatus {
+                name: "era".to_owned(),
+                snapshot: Err(anyhow::anyhow!("contracts.yaml is missing")),
+                prover_queue: None,
+            },
+            ChainStatus {
+                name: "validium".to_owned(),
+                snapshot: Err(anyhow::anyhow!("connection refused")),
+                prover_queue: Some(Ok(ProverQueue {
+                    queued: 5,
+                    in_progress: 2,
+                })),
+            },
+        ];
+        let lines = render("local", "now", &containers, &chains);
+
+        assert_eq!(lines[0], "Ecosystem `local` at now");
+        assert!(lines.contains(&"  postgres             running".to_owned()));
+        assert!(lines.contains(&"  reth                 stopped".to_owned()));
+        assert!(lines.contains(&"Chain `era`".to_owned()));
+        assert!(lines.contains(&"  n/a: contracts.yaml is missing".to_owned()));
+        assert!(lines.contains(&"  Prover jobs          prover DB is not configured".to_owned()));
+        assert!(lines.contains(&"  n/a: connection refused".to_owned()));
+        assert_eq!(
+            lines.last().unwrap(),
+            "  Prover jobs          5 queued / 2 in progress"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::Context as _;
+use common::{
+    db::{drop_db_if_exists, DatabaseConfig},
+    docker, logger, PromptConfirm,
+};
+use config::{
+    ChainConfig, EcosystemConfig, CONTRACTS_FILE, DOCKER_COMPOSE_FILE,
+    ERA_OBSERVABILITY_COMPOSE_FILE, ERC20_CONFIGS_FILE,
+};
+use xshell::Shell;
+
+use super::args::teardown::EcosystemTeardownArgs;
+use crate::messages::{
+    msg_chain_load_err, msg_ecosystem_torn_down, msg_teardown_chain_err,
+    msg_teardown_confirm_prompt, msg_teardown_dropping_database, MSG_DATABASE_MUST_BE_PRESENTED,
+    MSG_TEARDOWN_ABORTED, MSG_TEARDOWN_FAILED_ERR, MSG_TEARDOWN_REMOVING_ARTIFACTS,
+    MSG_TEARDOWN_STOPPING_CONTAINERS, MSG_TEARDOWN_WIPING_L1,
+};
+
+/// Name of the L1 node data volume in the docker compose file.
+const L1_DATA_VOLUME: &str = "reth-data";
+
+pub async fn run(args: EcosystemTeardownArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    if !args.yes
+        && !PromptConfirm::new(msg_teardown_confirm_prompt(
+            &ecosystem_config.name,
+            args.wipe_l1,
+        ))
+        .default(false)
+        .ask()
+    {
+        logger::outro(MSG_TEARDOWN_ABORTED);
+        return Ok(());
+    }
+
+    // Chains are torn down while containers are still running, since their databases may live
+    // in the ecosystem Postgres container. A failure for one chain doesn't prevent cleaning up the others.
+    let mut has_errors = false;
+    for chain_name in ecosystem_config.list_of_chains() {
+        if let Err(err) = teardown_chain(shell, &ecosystem_config, &chain_name).await {
+            logger::warn(msg_teardown_chain_err(&chain_name, &err));
+            has_errors = true;
+        }
+    }
+
+    logger::info(MSG_TEARDOWN_REMOVING_ARTIFACTS);
+    shell.remove_path(ecosystem_config.config.join(CONTRACTS_FILE))?;
+    shell.remove_path(ecosystem_config.config.join(ERC20_CONFIGS_FILE))?;
+
+    logger::info(MSG_TEARDOWN_STOPPING_CONTAINERS);
+    if shell.path_exists(ERA_OBSERVABILITY_COMPOSE_FILE) {
+        docker::stop(shell, ERA_OBSERVABILITY_COMPOSE_FILE)?;
+    }
+    if shell.path_exists(DOCKER_COMPOSE_FILE) {
+        docker::stop(shell, DOCKER_COMPOSE_FILE)?;
+        if args.wipe_l1 {
+            logger::info(MSG_TEARDOWN_WIPING_L1);
+            docker::remove_volume(shell, DOCKER_COMPOSE_FILE, L1_DATA_VOLUME)?;
+        }
+    }
+
+    anyhow::ensure!(!has_errors, MSG_TEARDOWN_FAILED_ERR);
+    logger::outro(msg_ecosystem_torn_down(&ecosystem_config.name));
+    Ok(())
+}
+
+async fn teardown_chain(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+    chain_name: &str,
+) -> anyhow::Result<()> {
+    let chain_config = ecosystem_config
+        .load_chain(Some(chain_name.to_owned()))
+        .with_context(|| msg_chain_load_err(chain_name))?;
+    drop_chain_databases(&chain_config).await?;
+
+    shell.remove_path(chain_config.path_to_contracts_config())?;
+    shell.remove_path(&chain_config.rocks_db_path)?;
+    shell.remove_path(&chain_config.artifacts)?;
+    if let Some(path) = &chain_config.external_node_config_path {
+        shell.remove_path(path)?;
+    }
+    Ok(())
+}
+
+async fn drop_chain_databases(chain_config: &ChainConfig) -> anyhow::Result<()> {
+    let secrets = chain_config.get_secrets_config()?;
+    let Some(database) = &secrets.database else {
+        // The chain was never initialized.
+        return Ok(());
+    };
+    let server_url = database
+        .master_url()
+        .context(MSG_DATABASE_MUST_BE_PRESENTED)?;
+    let urls = [Some(server_url), database.prover_url.clone()];
+    for url in urls.into_iter().flatten() {
+        let db = DatabaseConfig::from_url(url.expose_url())?;
+        logger::step(msg_teardown_dropping_database(&chain_config.name, &db.name));
+        drop_db_if_exists(&db).await?;
+    }
+    Ok(())
+}
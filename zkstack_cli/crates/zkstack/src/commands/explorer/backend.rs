@@ -2,7 +2,7 @@ use std::path::Path;
 
 use anyhow::Context;
 use common::docker;
-use config::{explorer_compose::ExplorerBackendComposeConfig, EcosystemConfig};
+use config::{explorer_compose::ExplorerBackendComposeConfig, EcosystemConfig, RemoteChainsConfig};
 use xshell::Shell;
 
 use crate::messages::{
@@ -12,14 +12,19 @@ use crate::messages::{
 
 pub(crate) fn run(shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
-    let chain_config = ecosystem_config
-        .load_current_chain()
-        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
-    let chain_name = chain_config.name.clone();
+    let chain_name = ecosystem_config.current_chain().to_owned();
+    let is_remote = RemoteChainsConfig::read_or_default(shell)?
+        .get(&chain_name)
+        .is_some();
+    if !is_remote {
+        ecosystem_config
+            .load_current_chain()
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    }
     // Read chain-level explorer backend docker compose file
     let ecosystem_path = shell.current_dir();
     let backend_config_path =
-        ExplorerBackendComposeConfig::get_config_path(&ecosystem_path, &chain_config.name);
+        ExplorerBackendComposeConfig::get_config_path(&ecosystem_path, &chain_name);
     if !backend_config_path.exists() {
         anyhow::bail!(msg_explorer_chain_not_initialized(&chain_name));
     }
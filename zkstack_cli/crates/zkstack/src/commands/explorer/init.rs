@@ -4,7 +4,7 @@ use config::{
     explorer::{ExplorerChainConfig, ExplorerConfig},
     explorer_compose::{ExplorerBackendComposeConfig, ExplorerBackendConfig, ExplorerBackendPorts},
     traits::{ConfigWithL2RpcUrl, SaveConfig},
-    ChainConfig, EcosystemConfig,
+    ChainConfig, EcosystemConfig, RemoteChainConfig, RemoteChainsConfig,
 };
 use slugify_rs::slugify;
 use url::Url;
@@ -23,32 +23,50 @@ use crate::{
 
 pub(crate) async fn run(shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let remote_chains = RemoteChainsConfig::read_or_default(shell)?;
+    let local_chains = ecosystem_config.list_of_chains();
     // If specific chain is provided, initialize only that chain; otherwise, initialize all chains
     let chains_enabled = match global_config().chain_name {
         Some(ref chain_name) => vec![chain_name.clone()],
-        None => ecosystem_config.list_of_chains(),
+        None => local_chains
+            .iter()
+            .cloned()
+            .chain(remote_chains.list_of_chains())
+            .collect(),
     };
     let mut ports = EcosystemPortsScanner::scan(shell)?;
     // Initialize chains one by one
     let mut explorer_config = ExplorerConfig::read_or_create_default(shell)?;
     for chain_name in chains_enabled.iter() {
-        // Load chain config
-        let chain_config = ecosystem_config
-            .load_chain(Some(chain_name.clone()))
-            .context(msg_chain_load_err(chain_name))?;
-        // Build backend config - parameters required to create explorer backend services
-        let backend_config = build_backend_config(&mut ports, &chain_config)?;
-        // Initialize explorer database
-        initialize_explorer_database(&backend_config.database_url).await?;
-        // Create explorer backend docker compose file
-        let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
-        let backend_compose_config =
-            ExplorerBackendComposeConfig::new(chain_name, l2_rpc_url, &backend_config)?;
-        let backend_compose_config_path =
-            ExplorerBackendComposeConfig::get_config_path(&shell.current_dir(), chain_name);
-        backend_compose_config.save(shell, &backend_compose_config_path)?;
+        let remote_position = remote_chains
+            .chains
+            .iter()
+            .position(|chain| chain.name == *chain_name);
+        let explorer_chain_config = if let Some(position) = remote_position {
+            let remote_chain = &remote_chains.chains[position];
+            // Remote chains are numbered after local ones to get non-overlapping default ports
+            let chain_number = (local_chains.len() + position + 1) as u32;
+            let default_db_name =
+                generate_explorer_db_name(ecosystem_config.l1_network, chain_name);
+            let backend_config =
+                build_backend_config(&mut ports, chain_name, &default_db_name, chain_number)?;
+            init_backend(shell, chain_name, &remote_chain.l2_rpc_url, &backend_config).await?;
+            build_remote_explorer_chain_config(remote_chain, &backend_config)
+        } else {
+            // Load chain config
+            let chain_config = ecosystem_config
+                .load_chain(Some(chain_name.clone()))
+                .context(msg_chain_load_err(chain_name))?;
+            // Build backend config - parameters required to create explorer backend services
+            let default_db_name =
+                generate_explorer_db_name(chain_config.l1_network, &chain_config.name);
+            let backend_config =
+                build_backend_config(&mut ports, chain_name, &default_db_name, chain_config.id)?;
+            let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+            init_backend(shell, chain_name, &l2_rpc_url, &backend_config).await?;
+            build_explorer_chain_config(&chain_config, &backend_config)?
+        };
         // Add chain to explorer.json
-        let explorer_chain_config = build_explorer_chain_config(&chain_config, &backend_config)?;
         explorer_config.add_chain_config(&explorer_chain_config);
     }
     // Save explorer config
@@ -59,17 +77,35 @@ pub(crate) async fn run(shell: &Shell) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Initializes explorer database and creates explorer backend docker compose file for the chain.
+async fn init_backend(
+    shell: &Shell,
+    chain_name: &str,
+    l2_rpc_url: &Url,
+    backend_config: &ExplorerBackendConfig,
+) -> anyhow::Result<()> {
+    initialize_explorer_database(&backend_config.database_url).await?;
+    let backend_compose_config =
+        ExplorerBackendComposeConfig::new(chain_name, l2_rpc_url.clone(), backend_config)?;
+    let backend_compose_config_path =
+        ExplorerBackendComposeConfig::get_config_path(&shell.current_dir(), chain_name);
+    backend_compose_config.save(shell, &backend_compose_config_path)?;
+    Ok(())
+}
+
 fn build_backend_config(
     ports: &mut EcosystemPorts,
-    chain_config: &ChainConfig,
+    chain_name: &str,
+    default_db_name: &str,
+    chain_number: u32,
 ) -> anyhow::Result<ExplorerBackendConfig> {
     // Prompt explorer database name
-    logger::info(msg_explorer_initializing_database_for(&chain_config.name));
-    let db_config = fill_database_values_with_prompt(chain_config);
+    logger::info(msg_explorer_initializing_database_for(chain_name));
+    let db_config = fill_database_values_with_prompt(chain_name, default_db_name);
 
     // Allocate ports for backend services
     let mut backend_ports = ExplorerBackendPorts::default();
-    ports.allocate_ports_with_offset_from_defaults(&mut backend_ports, chain_config.id)?;
+    ports.allocate_ports_with_offset_from_defaults(&mut backend_ports, chain_number)?;
 
     // Build explorer backend config
     Ok(ExplorerBackendConfig::new(
@@ -87,14 +123,12 @@ async fn initialize_explorer_database(db_url: &Url) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn fill_database_values_with_prompt(config: &ChainConfig) -> db::DatabaseConfig {
-    let defaul_db_name: String = generate_explorer_db_name(config);
-    let chain_name = config.name.clone();
-    let explorer_db_url = Prompt::new(&msg_explorer_db_url_prompt(&chain_name))
+fn fill_database_values_with_prompt(chain_name: &str, default_db_name: &str) -> db::DatabaseConfig {
+    let explorer_db_url = Prompt::new(&msg_explorer_db_url_prompt(chain_name))
         .default(DATABASE_EXPLORER_URL.as_str())
         .ask();
-    let explorer_db_name: String = Prompt::new(&msg_explorer_db_name_prompt(&chain_name))
-        .default(&defaul_db_name)
+    let explorer_db_name: String = Prompt::new(&msg_explorer_db_name_prompt(chain_name))
+        .default(default_db_name)
         .ask();
     let explorer_db_name = slugify!(&explorer_db_name, separator = "_");
     db::DatabaseConfig::new(explorer_db_url, explorer_db_name)
@@ -136,3 +170,26 @@ fn build_explorer_chain_config(
         other: serde_json::Value::Null,
     })
 }
+
+fn build_remote_explorer_chain_config(
+    chain: &RemoteChainConfig,
+    backend_config: &ExplorerBackendConfig,
+) -> ExplorerChainConfig {
+    let api_port = backend_config.ports.api_http_port;
+    ExplorerChainConfig {
+        name: chain.name.clone(),
+        l2_network_name: chain.name.clone(),
+        l2_chain_id: chain.chain_id.as_u64(),
+        rpc_url: chain.l2_rpc_url.to_string(),
+        api_url: format!("http://127.0.0.1:{api_port}"),
+        base_token_address: L2_BASE_TOKEN_ADDRESS.to_string(),
+        hostnames: Vec::new(),
+        icon: "/images/icons/zksync-arrows.svg".to_string(),
+        maintenance: false,
+        published: true,
+        bridge_url: None,
+        l1_explorer_url: None,
+        verification_api_url: chain.verification_api_url.as_ref().map(Url::to_string),
+        other: serde_json::Value::Null,
+    }
+}
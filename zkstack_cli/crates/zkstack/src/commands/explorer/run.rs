@@ -2,7 +2,9 @@ use std::path::Path;
 
 use anyhow::Context;
 use common::{config::global_config, docker, logger};
-use config::{explorer::*, traits::SaveConfig, AppsEcosystemConfig, EcosystemConfig};
+use config::{
+    explorer::*, traits::SaveConfig, AppsEcosystemConfig, EcosystemConfig, RemoteChainsConfig,
+};
 use xshell::Shell;
 
 use crate::{
@@ -20,9 +22,11 @@ pub(crate) fn run(shell: &Shell) -> anyhow::Result<()> {
     // Get ecosystem level apps.yaml config
     let apps_config = AppsEcosystemConfig::read_or_create_default(shell)?;
     // If specific_chain is provided, run only with that chain; otherwise, run with all chains
+    let mut known_chains = ecosystem_config.list_of_chains();
+    known_chains.extend(RemoteChainsConfig::read_or_default(shell)?.list_of_chains());
     let chains_enabled = match global_config().chain_name {
         Some(ref chain_name) => vec![chain_name.clone()],
-        None => ecosystem_config.list_of_chains(),
+        None => known_chains.clone(),
     };
 
     // Read explorer config
@@ -31,7 +35,7 @@ pub(crate) fn run(shell: &Shell) -> anyhow::Result<()> {
         .context(MSG_EXPLORER_FAILED_TO_CREATE_CONFIG_ERR)?;
 
     // Validate and update explorer config
-    explorer_config.filter(&ecosystem_config.list_of_chains());
+    explorer_config.filter(&known_chains);
     explorer_config.hide_except(&chains_enabled);
     if explorer_config.is_empty() {
         anyhow::bail!(MSG_EXPLORER_FAILED_TO_FIND_ANY_CHAIN_ERR);
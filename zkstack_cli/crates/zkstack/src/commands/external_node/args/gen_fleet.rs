@@ -0,0 +1,65 @@
+use clap::{Parser, ValueEnum};
+use config::ChainConfig;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use zksync_config::configs::PruningConfig;
+
+use crate::{
+    commands::external_node::args::prepare_configs::{PrepareConfigArgs, PrepareConfigFinal},
+    messages::{
+        MSG_EN_FLEET_COUNT_HELP, MSG_EN_FLEET_IMAGE_HELP, MSG_EN_FLEET_MANIFEST_HELP,
+        MSG_EN_FLEET_PRUNING_HELP, MSG_EN_FLEET_PRUNING_RETENTION_HELP,
+    },
+};
+
+/// Default image used for external nodes in generated manifests.
+const DEFAULT_EN_IMAGE: &str = "matterlabs/external-node:latest";
+
+/// Kind of the manifest generated for an external node fleet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, ValueEnum, Serialize, Deserialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum FleetManifest {
+    /// Docker Compose file.
+    Compose,
+    /// Kubernetes manifest.
+    K8s,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct GenFleetArgs {
+    #[clap(long, help = MSG_EN_FLEET_COUNT_HELP)]
+    pub count: u16,
+    #[clap(flatten)]
+    pub configs: PrepareConfigArgs,
+    #[clap(long, help = MSG_EN_FLEET_PRUNING_HELP)]
+    pub pruning: bool,
+    #[clap(long, requires = "pruning", help = MSG_EN_FLEET_PRUNING_RETENTION_HELP)]
+    pub pruning_data_retention_sec: Option<u64>,
+    #[clap(long, value_enum, default_value_t = FleetManifest::Compose, help = MSG_EN_FLEET_MANIFEST_HELP)]
+    pub manifest: FleetManifest,
+    #[clap(long, default_value = DEFAULT_EN_IMAGE, help = MSG_EN_FLEET_IMAGE_HELP)]
+    pub image: String,
+}
+
+impl GenFleetArgs {
+    /// Returns per-node config parameters. Each node gets a distinct database suffixed with the node index.
+    pub fn node_configs(&self, config: &ChainConfig) -> Vec<PrepareConfigFinal> {
+        let base = self.configs.clone().fill_values_with_prompt(config);
+        let pruning = self.pruning.then(|| PruningConfig {
+            enabled: true,
+            chunk_size: None,
+            removal_delay_sec: None,
+            data_retention_sec: self.pruning_data_retention_sec,
+            retained_addresses: vec![],
+        });
+
+        (0..self.count)
+            .map(|i| {
+                let mut node = base.clone();
+                node.db.name = format!("{}_{i}", base.db.name);
+                node.pruning = pruning.clone();
+                node
+            })
+            .collect()
+    }
+}
@@ -1,2 +1,3 @@
+pub mod gen_fleet;
 pub mod prepare_configs;
 pub mod run;
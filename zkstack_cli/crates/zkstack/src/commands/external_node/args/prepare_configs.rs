@@ -4,6 +4,7 @@ use config::ChainConfig;
 use serde::{Deserialize, Serialize};
 use slugify_rs::slugify;
 use url::Url;
+use zksync_config::configs::PruningConfig;
 
 use crate::{
     defaults::{generate_external_node_db_name, DATABASE_SERVER_URL, LOCAL_RPC_URL},
@@ -33,6 +34,7 @@ impl PrepareConfigArgs {
             PrepareConfigFinal {
                 db: DatabaseConfig::new(DATABASE_SERVER_URL.clone(), db_name),
                 l1_rpc_url: LOCAL_RPC_URL.to_string(),
+                pruning: None,
             }
         } else {
             let db_url = self.db_url.unwrap_or_else(|| {
@@ -57,6 +59,7 @@ impl PrepareConfigArgs {
             PrepareConfigFinal {
                 db: DatabaseConfig::new(db_url, db_name),
                 l1_rpc_url,
+                pruning: None,
             }
         }
     }
@@ -66,4 +69,7 @@ impl PrepareConfigArgs {
 pub struct PrepareConfigFinal {
     pub db: DatabaseConfig,
     pub l1_rpc_url: String,
+    /// Pruning settings overriding the ones inherited from the main node config.
+    #[serde(skip)]
+    pub pruning: Option<PruningConfig>,
 }
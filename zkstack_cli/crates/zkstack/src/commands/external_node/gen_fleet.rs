@@ -0,0 +1,296 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use common::logger;
+use config::{
+    docker_compose::{DockerComposeConfig, DockerComposeService},
+    external_node::ENConfig,
+    traits::{FileConfigWithDefaultName, ReadConfigWithBasePath, SaveConfig},
+    EcosystemConfig, GeneralConfig, SecretsConfig,
+};
+use serde_json::json;
+use slugify_rs::slugify;
+use xshell::Shell;
+use zksync_config::configs::consensus::ConsensusConfig;
+
+use super::prepare_configs::prepare_configs;
+use crate::{
+    commands::external_node::args::gen_fleet::{FleetManifest, GenFleetArgs},
+    messages::{
+        msg_en_fleet_generated, msg_preparing_en_fleet_node_config, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_EN_FLEET_EMPTY_ERR, MSG_EN_FLEET_K8S_DATABASE_ATTENTION,
+    },
+    utils::{
+        ports::EcosystemPortsScanner,
+        rocks_db::{recreate_rocksdb_dirs, RocksDBDirOption},
+    },
+};
+
+const FLEET_DIR: &str = "external_node_fleet";
+const COMPOSE_FILE_NAME: &str = "docker-compose.yml";
+const K8S_FILE_NAME: &str = "k8s.yaml";
+/// Mount path of configs inside Kubernetes pods.
+const K8S_CONFIGS_PATH: &str = "/configs";
+/// Storage requested for RocksDB volumes in Kubernetes.
+const K8S_ROCKS_DB_STORAGE: &str = "100Gi";
+
+/// Single node of the generated fleet.
+#[derive(Debug)]
+struct FleetNode {
+    name: String,
+    configs_path: PathBuf,
+    rocks_db_path: PathBuf,
+    database_url: String,
+    ports: FleetNodePorts,
+}
+
+#[derive(Debug)]
+struct FleetNodePorts {
+    http: u16,
+    ws: u16,
+    healthcheck: u16,
+    prometheus: u16,
+}
+
+impl FleetNodePorts {
+    fn read(shell: &Shell, configs_path: &Path) -> anyhow::Result<Self> {
+        let general = GeneralConfig::read_with_base_path(shell, configs_path)?;
+        let api = general.api_config.context("api_config")?;
+        Ok(Self {
+            http: api.web3_json_rpc.http_port,
+            ws: api.web3_json_rpc.ws_port,
+            healthcheck: api.healthcheck.port,
+            prometheus: api.prometheus.listener_port,
+        })
+    }
+
+    fn all(&self) -> [(&'static str, u16); 4] {
+        [
+            ("http", self.http),
+            ("ws", self.ws),
+            ("healthcheck", self.healthcheck),
+            ("prometheus", self.prometheus),
+        ]
+    }
+}
+
+pub fn run(shell: &Shell, args: GenFleetArgs) -> anyhow::Result<()> {
+    anyhow::ensure!(args.count > 0, MSG_EN_FLEET_EMPTY_ERR);
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+
+    let fleet_path = chain_config.configs.join(FLEET_DIR);
+    let fleet_rocks_db_path = chain_config.rocks_db_path.join(FLEET_DIR);
+    // A single scanner is shared by all nodes, so that each node gets its own set of ports.
+    let mut ports = EcosystemPortsScanner::scan(shell)?;
+
+    let mut nodes = vec![];
+    for (i, node_args) in args.node_configs(&chain_config).into_iter().enumerate() {
+        let node_dir = format!("en-{i}");
+        logger::info(msg_preparing_en_fleet_node_config(&node_dir));
+        let configs_path = fleet_path.join(&node_dir);
+        shell.create_dir(&configs_path)?;
+        let rocks_db_path = fleet_rocks_db_path.join(&node_dir);
+        let rocks_db_dirs =
+            recreate_rocksdb_dirs(shell, &rocks_db_path, RocksDBDirOption::ExternalNode)?;
+        let database_url = node_args.db.full_url().to_string();
+
+        prepare_configs(
+            shell,
+            &chain_config,
+            &configs_path,
+            node_args,
+            rocks_db_dirs,
+            &mut ports,
+        )?;
+        nodes.push(FleetNode {
+            name: slugify!(&format!("{}-en-{i}", chain_config.name)),
+            ports: FleetNodePorts::read(shell, &configs_path)?,
+            configs_path,
+            rocks_db_path,
+            database_url,
+        });
+    }
+
+    let manifest_path = match args.manifest {
+        FleetManifest::Compose => {
+            let path = fleet_path.join(COMPOSE_FILE_NAME);
+            compose_manifest(&chain_config.name, &args.image, &nodes).save(shell, &path)?;
+            path
+        }
+        FleetManifest::K8s => {
+            let path = fleet_path.join(K8S_FILE_NAME);
+            shell.write_file(&path, k8s_manifest(shell, &args.image, &nodes)?)?;
+            logger::warn(MSG_EN_FLEET_K8S_DATABASE_ATTENTION);
+            path
+        }
+    };
+    logger::info(msg_en_fleet_generated(nodes.len(), &manifest_path));
+    Ok(())
+}
+
+/// Returns command-line arguments of the external node binary reading configs from `configs_path`.
+fn node_command(configs_path: &Path) -> Vec<String> {
+    let config_arg = |name: &str, path: PathBuf| [name.to_owned(), path.display().to_string()];
+    [
+        config_arg(
+            "--config-path",
+            GeneralConfig::get_path_with_base_path(configs_path),
+        ),
+        config_arg(
+            "--secrets-path",
+            SecretsConfig::get_path_with_base_path(configs_path),
+        ),
+        config_arg(
+            "--external-node-config-path",
+            ENConfig::get_path_with_base_path(configs_path),
+        ),
+        config_arg(
+            "--consensus-path",
+            ConsensusConfig::get_path_with_base_path(configs_path),
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(["--enable-consensus".to_owned()])
+    .collect()
+}
+
+/// Generates a Docker Compose file running the fleet. Nodes use the host network, since generated configs
+/// reference ports and databases on the host; config and RocksDB dirs are mounted at the same paths as on the host.
+fn compose_manifest(chain_name: &str, image: &str, nodes: &[FleetNode]) -> DockerComposeConfig {
+    let mut compose = DockerComposeConfig {
+        name: Some(slugify!(&format!("{chain_name}-external-node-fleet"))),
+        ..Default::default()
+    };
+    for node in nodes {
+        let volumes = [&node.configs_path, &node.rocks_db_path]
+            .into_iter()
+            .map(|path| format!("{0}:{0}", path.display()))
+            .collect();
+        let service = DockerComposeService {
+            image: image.to_owned(),
+            platform: None,
+            ports: None,
+            environment: Some(HashMap::from([(
+                "DATABASE_URL".to_owned(),
+                node.database_url.clone(),
+            )])),
+            volumes: Some(volumes),
+            depends_on: None,
+            restart: Some("unless-stopped".to_owned()),
+            extra_hosts: None,
+            other: json!({
+                "network_mode": "host",
+                "command": node_command(&node.configs_path),
+            }),
+        };
+        compose.add_service(&node.name, service);
+    }
+    compose
+}
+
+/// Generates a multi-document Kubernetes manifest with a secret holding configs, a stateful set
+/// and a service for each node of the fleet.
+fn k8s_manifest(shell: &Shell, image: &str, nodes: &[FleetNode]) -> anyhow::Result<String> {
+    let mut documents = vec![];
+    for node in nodes {
+        let labels = json!({ "app": node.name });
+        let mut config_files = serde_json::Map::new();
+        for file_name in [
+            GeneralConfig::FILE_NAME,
+            SecretsConfig::FILE_NAME,
+            ENConfig::FILE_NAME,
+            ConsensusConfig::FILE_NAME,
+        ] {
+            let contents = shell.read_file(node.configs_path.join(file_name))?;
+            config_files.insert(file_name.to_owned(), contents.into());
+        }
+        config_files.insert("database_url".to_owned(), node.database_url.clone().into());
+        let configs_secret = format!("{}-configs", node.name);
+
+        documents.push(json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": { "name": configs_secret, "labels": labels },
+            "type": "Opaque",
+            "stringData": config_files,
+        }));
+
+        let container_ports: Vec<_> = node
+            .ports
+            .all()
+            .into_iter()
+            .map(|(name, port)| json!({ "name": name, "containerPort": port }))
+            .collect();
+        documents.push(json!({
+            "apiVersion": "apps/v1",
+            "kind": "StatefulSet",
+            "metadata": { "name": node.name, "labels": labels },
+            "spec": {
+                "serviceName": node.name,
+                "replicas": 1,
+                "selector": { "matchLabels": labels },
+                "template": {
+                    "metadata": { "labels": labels },
+                    "spec": {
+                        "containers": [{
+                            "name": "external-node",
+                            "image": image,
+                            "args": node_command(Path::new(K8S_CONFIGS_PATH)),
+                            "env": [{
+                                "name": "DATABASE_URL",
+                                "valueFrom": {
+                                    "secretKeyRef": { "name": configs_secret, "key": "database_url" },
+                                },
+                            }],
+                            "ports": container_ports,
+                            "readinessProbe": {
+                                "httpGet": { "path": "/health", "port": "healthcheck" },
+                            },
+                            "volumeMounts": [
+                                { "name": "configs", "mountPath": K8S_CONFIGS_PATH, "readOnly": true },
+                                { "name": "rocksdb", "mountPath": node.rocks_db_path },
+                            ],
+                        }],
+                        "volumes": [{
+                            "name": "configs",
+                            "secret": { "secretName": configs_secret },
+                        }],
+                    },
+                },
+                "volumeClaimTemplates": [{
+                    "metadata": { "name": "rocksdb" },
+                    "spec": {
+                        "accessModes": ["ReadWriteOnce"],
+                        "resources": { "requests": { "storage": K8S_ROCKS_DB_STORAGE } },
+                    },
+                }],
+            },
+        }));
+
+        let service_ports: Vec<_> = node
+            .ports
+            .all()
+            .into_iter()
+            .map(|(name, port)| json!({ "name": name, "port": port, "targetPort": name }))
+            .collect();
+        documents.push(json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": node.name, "labels": labels },
+            "spec": { "selector": labels, "ports": service_ports },
+        }));
+    }
+
+    let documents = documents
+        .iter()
+        .map(serde_yaml::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(documents.join("---\n"))
+}
@@ -2,11 +2,14 @@ use clap::Parser;
 use serde::{Deserialize, Serialize};
 use xshell::Shell;
 
-use self::args::{prepare_configs::PrepareConfigArgs, run::RunExternalNodeArgs};
+use self::args::{
+    gen_fleet::GenFleetArgs, prepare_configs::PrepareConfigArgs, run::RunExternalNodeArgs,
+};
 use crate::commands::args::WaitArgs;
 
 mod args;
 mod build;
+mod gen_fleet;
 mod init;
 mod prepare_configs;
 mod run;
@@ -16,6 +19,8 @@ mod wait;
 pub enum ExternalNodeCommands {
     /// Prepare configs for EN
     Configs(PrepareConfigArgs),
+    /// Prepare configs and a Docker Compose / Kubernetes manifest for a fleet of ENs
+    GenFleet(GenFleetArgs),
     /// Init databases
     Init,
     /// Build external node
@@ -29,6 +34,7 @@ pub enum ExternalNodeCommands {
 pub async fn run(shell: &Shell, commands: ExternalNodeCommands) -> anyhow::Result<()> {
     match commands {
         ExternalNodeCommands::Configs(args) => prepare_configs::run(shell, args),
+        ExternalNodeCommands::GenFleet(args) => gen_fleet::run(shell, args),
         ExternalNodeCommands::Init => init::run(shell).await,
         ExternalNodeCommands::Build => build::build(shell).await,
         ExternalNodeCommands::Run(args) => run::run(shell, args).await,
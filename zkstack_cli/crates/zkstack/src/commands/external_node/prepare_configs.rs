@@ -6,7 +6,7 @@ use config::{
     external_node::ENConfig,
     set_rocks_db_config,
     traits::{FileConfigWithDefaultName, SaveConfigWithBasePath},
-    ChainConfig, EcosystemConfig, GeneralConfig, SecretsConfig,
+    ChainConfig, EcosystemConfig, GeneralConfig, RocksDbs, SecretsConfig,
 };
 use xshell::Shell;
 use zksync_basic_types::url::SensitiveUrl;
@@ -26,7 +26,7 @@ use crate::{
     },
     utils::{
         consensus::node_public_key,
-        ports::EcosystemPortsScanner,
+        ports::{EcosystemPorts, EcosystemPortsScanner},
         rocks_db::{recreate_rocksdb_dirs, RocksDBDirOption},
     },
 };
@@ -44,20 +44,36 @@ pub fn run(shell: &Shell, args: PrepareConfigArgs) -> anyhow::Result<()> {
         .unwrap_or_else(|| chain_config.configs.join("external_node"));
     shell.create_dir(&external_node_config_path)?;
     chain_config.external_node_config_path = Some(external_node_config_path.clone());
-    prepare_configs(shell, &chain_config, &external_node_config_path, args)?;
+    let mut ports = EcosystemPortsScanner::scan(shell)?;
+    let rocks_db_dirs = recreate_rocksdb_dirs(
+        shell,
+        &chain_config.rocks_db_path,
+        RocksDBDirOption::ExternalNode,
+    )?;
+    prepare_configs(
+        shell,
+        &chain_config,
+        &external_node_config_path,
+        args,
+        rocks_db_dirs,
+        &mut ports,
+    )?;
     let chain_path = ecosystem_config.chains.join(&chain_config.name);
     chain_config.save_with_base_path(shell, chain_path)?;
     logger::info(msg_preparing_en_config_is_done(&external_node_config_path));
     Ok(())
 }
 
-fn prepare_configs(
+/// Generates configs for a single external node in `en_configs_path`. Allocated ports are registered in `ports`,
+/// so that configs for several nodes can be generated without port clashes.
+pub(super) fn prepare_configs(
     shell: &Shell,
     config: &ChainConfig,
     en_configs_path: &Path,
     args: PrepareConfigFinal,
+    rocks_db_dirs: RocksDbs,
+    ports: &mut EcosystemPorts,
 ) -> anyhow::Result<()> {
-    let mut ports = EcosystemPortsScanner::scan(shell)?;
     let genesis = config.get_genesis_config()?;
     let general = config.get_general_config()?;
     let en_config = ENConfig {
@@ -65,6 +81,11 @@ fn prepare_configs(
         l1_chain_id: genesis.l1_chain_id,
         sl_chain_id: genesis.sl_chain_id,
         l1_batch_commit_data_generator_mode: genesis.l1_batch_commit_data_generator_mode,
+        commitment_mode_transition_batches: config.commitment_mode_transition_batches.clone(),
+        forced_inclusion_deadline_batches: general
+            .state_keeper_config
+            .as_ref()
+            .and_then(|config| config.forced_inclusion_deadline_batches),
         main_node_url: SensitiveUrl::from_str(
             &general
                 .api_config
@@ -79,6 +100,9 @@ fn prepare_configs(
     };
     let mut general_en = general.clone();
     general_en.consensus_config = None;
+    if let Some(pruning) = args.pruning {
+        general_en.pruning = Some(pruning);
+    }
 
     let main_node_consensus_config = general
         .consensus_config
@@ -116,8 +140,7 @@ fn prepare_configs(
         data_availability: None,
     };
 
-    let dirs = recreate_rocksdb_dirs(shell, &config.rocks_db_path, RocksDBDirOption::ExternalNode)?;
-    set_rocks_db_config(&mut general_en, dirs)?;
+    set_rocks_db_config(&mut general_en, rocks_db_dirs)?;
 
     general_en.save_with_base_path(shell, en_configs_path)?;
     en_config.save_with_base_path(shell, en_configs_path)?;
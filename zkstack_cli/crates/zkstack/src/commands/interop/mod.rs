@@ -0,0 +1,19 @@
+use clap::Subcommand;
+use xshell::Shell;
+
+use self::relayer::RelayerCommands;
+
+mod relayer;
+
+#[derive(Subcommand, Debug)]
+pub enum InteropCommands {
+    /// Relayer delivering messages sent by a chain to other chains of the ecosystem
+    #[command(subcommand)]
+    Relayer(RelayerCommands),
+}
+
+pub(crate) async fn run(shell: &Shell, args: InteropCommands) -> anyhow::Result<()> {
+    match args {
+        InteropCommands::Relayer(args) => relayer::run(shell, args).await,
+    }
+}
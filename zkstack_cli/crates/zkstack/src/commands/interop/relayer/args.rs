@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::messages::{
+    MSG_RELAYER_FROM_BATCH_HELP, MSG_RELAYER_L2_GAS_LIMIT_HELP, MSG_RELAYER_MAX_ATTEMPTS_HELP,
+    MSG_RELAYER_ONCE_HELP, MSG_RELAYER_POLL_INTERVAL_HELP, MSG_RELAYER_STATE_HELP,
+    MSG_RELAYER_TARGETS_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct RelayerRunArgs {
+    #[clap(long, value_delimiter = ',', help = MSG_RELAYER_TARGETS_HELP)]
+    pub targets: Vec<String>,
+    #[clap(long, help = MSG_RELAYER_FROM_BATCH_HELP)]
+    pub from_batch: Option<u32>,
+    #[clap(long, help = MSG_RELAYER_STATE_HELP)]
+    pub state: Option<PathBuf>,
+    #[clap(long, default_value_t = 5, help = MSG_RELAYER_POLL_INTERVAL_HELP)]
+    pub poll_interval: u64,
+    #[clap(long, default_value_t = 5, help = MSG_RELAYER_MAX_ATTEMPTS_HELP)]
+    pub max_attempts: u32,
+    #[clap(long, default_value_t = 2_000_000, help = MSG_RELAYER_L2_GAS_LIMIT_HELP)]
+    pub l2_gas_limit: u64,
+    #[clap(long, help = MSG_RELAYER_ONCE_HELP)]
+    pub once: bool,
+}
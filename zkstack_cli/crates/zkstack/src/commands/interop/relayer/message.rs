@@ -0,0 +1,270 @@
+//! Format of interop messages and their extraction from source chain receipts.
+//!
+//! An interop message is an L2→L1 message sent via `L1Messenger.sendToL1()` with the body ABI-encoded
+//! as a call of `interopMessage(uint256 targetChainId, address recipient, bytes payload)`. Other L2→L1 messages
+//! (e.g., withdrawals) are ignored by the relayer.
+
+use ethers::{
+    abi::{self, ParamType, Token},
+    types::{Address, Bytes, H160, H256, U256, U64},
+    utils::{id, keccak256},
+};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+/// Address of the `L1Messenger` system contract.
+const L1_MESSENGER_ADDRESS: Address = H160([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80, 0x08,
+]);
+const INTEROP_MESSAGE_SIGNATURE: &str = "interopMessage(uint256,address,bytes)";
+const RECEIVE_INTEROP_MESSAGE_SIGNATURE: &str =
+    "receiveInteropMessage(uint256,uint256,uint256,address,bytes)";
+
+lazy_static! {
+    static ref L1_MESSAGE_SENT_TOPIC: H256 =
+        H256(keccak256("L1MessageSent(address,bytes32,bytes)"));
+}
+
+/// Interop message decoded from an L2→L1 message body.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct InteropMessage {
+    pub target_chain_id: u64,
+    pub recipient: Address,
+    pub payload: Bytes,
+}
+
+impl InteropMessage {
+    /// Decodes an L2→L1 message body. Returns `None` if the body doesn't have the interop message format.
+    pub fn decode(body: &[u8]) -> Option<Self> {
+        if body.len() < 4 || body[..4] != id(INTEROP_MESSAGE_SIGNATURE) {
+            return None;
+        }
+        let params = [ParamType::Uint(256), ParamType::Address, ParamType::Bytes];
+        let tokens = abi::decode(&params, &body[4..]).ok()?;
+        let [Token::Uint(target_chain_id), Token::Address(recipient), Token::Bytes(payload)] =
+            tokens.as_slice()
+        else {
+            return None;
+        };
+        Some(Self {
+            target_chain_id: u64::try_from(*target_chain_id).ok()?,
+            recipient: *recipient,
+            payload: payload.clone().into(),
+        })
+    }
+
+    #[cfg(test)]
+    fn encode(&self) -> Vec<u8> {
+        let tokens = [
+            Token::Uint(self.target_chain_id.into()),
+            Token::Address(self.recipient),
+            Token::Bytes(self.payload.to_vec()),
+        ];
+        [
+            id(INTEROP_MESSAGE_SIGNATURE).as_slice(),
+            abi::encode(&tokens).as_slice(),
+        ]
+        .concat()
+    }
+
+    /// Returns calldata of the `receiveInteropMessage()` call delivering the message to the recipient.
+    /// The source batch number and message index allow recipients to deduplicate deliveries.
+    pub fn delivery_calldata(
+        &self,
+        source_chain_id: u64,
+        source_batch_number: u32,
+        message_index: u32,
+        sender: Address,
+    ) -> Bytes {
+        let tokens = [
+            Token::Uint(source_chain_id.into()),
+            Token::Uint(source_batch_number.into()),
+            Token::Uint(message_index.into()),
+            Token::Address(sender),
+            Token::Bytes(self.payload.to_vec()),
+        ];
+        [
+            id(RECEIVE_INTEROP_MESSAGE_SIGNATURE).as_slice(),
+            abi::encode(&tokens).as_slice(),
+        ]
+        .concat()
+        .into()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct L2ToL1Log {
+    pub sender: Address,
+    pub key: H256,
+    pub value: H256,
+    pub tx_index_in_l1_batch: U64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct EventLog {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+}
+
+/// Subset of transaction receipt fields used by the relayer.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Receipt {
+    pub transaction_hash: H256,
+    pub logs: Vec<EventLog>,
+    pub l2_to_l1_logs: Vec<L2ToL1Log>,
+}
+
+/// Interop message found in a source chain receipt.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct SentMessage {
+    pub tx_hash: H256,
+    /// Position of the message among L2→L1 logs of the transaction.
+    pub log_position: usize,
+    pub tx_number_in_batch: u16,
+    pub sender: Address,
+    pub body: Bytes,
+    pub message: InteropMessage,
+}
+
+/// Extracts interop messages sent in a transaction.
+pub(super) fn extract_messages(receipt: &Receipt) -> Vec<SentMessage> {
+    let mut messages = vec![];
+    for (log_position, log) in receipt.l2_to_l1_logs.iter().enumerate() {
+        if log.sender != L1_MESSENGER_ADDRESS {
+            continue;
+        }
+        // The L2→L1 log only contains the message hash; the body is emitted in the `L1MessageSent` event.
+        let body = receipt.logs.iter().find_map(|event| {
+            let is_matching = event.address == L1_MESSENGER_ADDRESS
+                && event.topics.len() == 3
+                && event.topics[0] == *L1_MESSAGE_SENT_TOPIC
+                && event.topics[2] == log.value;
+            if !is_matching {
+                return None;
+            }
+            match abi::decode(&[ParamType::Bytes], &event.data).ok()?.pop()? {
+                Token::Bytes(body) => Some(body),
+                _ => None,
+            }
+        });
+        let Some(body) = body else {
+            continue;
+        };
+        if H256(keccak256(&body)) != log.value {
+            continue;
+        }
+        let Some(message) = InteropMessage::decode(&body) else {
+            continue;
+        };
+        messages.push(SentMessage {
+            tx_hash: receipt.transaction_hash,
+            log_position,
+            tx_number_in_batch: log.tx_index_in_l1_batch.as_u64() as u16,
+            sender: Address::from(log.key),
+            body: body.into(),
+            message,
+        });
+    }
+    messages
+}
+
+/// Proof of L2→L1 log inclusion returned by `zks_getL2ToL1LogProof`.
+#[derive(Debug, Deserialize)]
+pub(super) struct L2ToL1LogProof {
+    pub proof: Vec<H256>,
+    /// Index of the log in the L2→L1 logs Merkle tree of the batch.
+    pub id: u32,
+}
+
+/// Returns an `L2Message` struct accepted by `Bridgehub.proveL2MessageInclusion()`.
+pub(super) fn l2_message_token(tx_number_in_batch: u16, sender: Address, body: &Bytes) -> Token {
+    Token::Tuple(vec![
+        Token::Uint(U256::from(tx_number_in_batch)),
+        Token::Address(sender),
+        Token::Bytes(body.to_vec()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_message() -> InteropMessage {
+        InteropMessage {
+            target_chain_id: 272,
+            recipient: Address::repeat_byte(0x42),
+            payload: vec![1, 2, 3].into(),
+        }
+    }
+
+    fn mock_receipt(sender: Address, bodies: &[Vec<u8>]) -> Receipt {
+        let mut sender_key = H256::zero();
+        sender_key.0[12..].copy_from_slice(sender.as_bytes());
+        let hashes: Vec<_> = bodies.iter().map(|body| H256(keccak256(body))).collect();
+        Receipt {
+            transaction_hash: H256::repeat_byte(1),
+            logs: bodies
+                .iter()
+                .zip(&hashes)
+                .map(|(body, hash)| EventLog {
+                    address: L1_MESSENGER_ADDRESS,
+                    topics: vec![*L1_MESSAGE_SENT_TOPIC, sender_key, *hash],
+                    data: abi::encode(&[Token::Bytes(body.clone())]).into(),
+                })
+                .collect(),
+            l2_to_l1_logs: hashes
+                .iter()
+                .map(|hash| L2ToL1Log {
+                    sender: L1_MESSENGER_ADDRESS,
+                    key: sender_key,
+                    value: *hash,
+                    tx_index_in_l1_batch: 3.into(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn decoding_interop_message() {
+        let message = mock_message();
+        assert_eq!(
+            InteropMessage::decode(&message.encode()),
+            Some(message.clone())
+        );
+
+        let mut body = message.encode();
+        body[0] ^= 1;
+        assert_eq!(InteropMessage::decode(&body), None);
+        assert_eq!(InteropMessage::decode(&message.encode()[..40]), None);
+        assert_eq!(InteropMessage::decode(b"withdrawal"), None);
+    }
+
+    #[test]
+    fn extracting_messages_from_receipt() {
+        let sender = Address::repeat_byte(0x23);
+        let message = mock_message();
+        let receipt = mock_receipt(
+            sender,
+            &[b"not an interop message".to_vec(), message.encode()],
+        );
+
+        let messages = extract_messages(&receipt);
+        assert_eq!(messages.len(), 1);
+        let sent = &messages[0];
+        assert_eq!(sent.log_position, 1);
+        assert_eq!(sent.tx_number_in_batch, 3);
+        assert_eq!(sent.sender, sender);
+        assert_eq!(sent.body.to_vec(), message.encode());
+        assert_eq!(sent.message, message);
+    }
+
+    #[test]
+    fn skipping_messages_with_mismatched_hash() {
+        let mut receipt = mock_receipt(Address::repeat_byte(0x23), &[mock_message().encode()]);
+        receipt.logs[0].data = abi::encode(&[Token::Bytes(vec![0; 4])]).into();
+        assert!(extract_messages(&receipt).is_empty());
+    }
+}
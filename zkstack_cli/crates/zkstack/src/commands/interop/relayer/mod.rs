@@ -0,0 +1,476 @@
+//! Relayer delivering interop messages between chains of the ecosystem.
+//!
+//! The relayer scans L1 batches of the source chain executed on L1, extracts interop messages (see [`message`])
+//! together with their inclusion proofs, checks the proofs with `Bridgehub.proveL2MessageInclusion()`, and delivers
+//! messages to target chains as L1→L2 transactions requested via `Bridgehub.requestL2TransactionDirect()`.
+
+use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use common::{ethereum::create_ethers_client, l2_to_l1::l1_logs_root_hash, logger};
+use config::{traits::ConfigWithL2RpcUrl, ChainConfig, EcosystemConfig};
+use ethers::{
+    abi::{parse_abi, Detokenize, Token, Tokenize},
+    contract::BaseContract,
+    core::k256::ecdsa::SigningKey,
+    middleware::SignerMiddleware,
+    providers::{Http, Middleware, Provider},
+    signers::Wallet,
+    types::{Address, TransactionRequest, H160, H256, U256, U64},
+};
+use lazy_static::lazy_static;
+use xshell::Shell;
+use zksync_basic_types::L1BatchNumber;
+
+use self::{
+    args::RelayerRunArgs,
+    message::{extract_messages, l2_message_token, InteropMessage, L2ToL1LogProof, Receipt},
+    state::{unix_timestamp, DeliveryStatus, MessageRecord, RelayerState, RetryPolicy},
+};
+use crate::messages::{
+    msg_chain_load_err, msg_relayer_batch_not_sealed_err, msg_relayer_delivery_retry,
+    msg_relayer_iteration_err, msg_relayer_message_delivered, msg_relayer_message_failed,
+    msg_relayer_message_found, msg_relayer_missing_proof_err, msg_relayer_resuming,
+    msg_relayer_started, msg_relayer_state_chain_mismatch_err, msg_relayer_summary,
+    msg_relayer_target_not_eth_based, msg_relayer_target_not_registered,
+    msg_relayer_tx_dropped_err, msg_relayer_tx_reverted_err, msg_relayer_unknown_target,
+    MSG_CHAIN_NOT_FOUND_ERR, MSG_L1_SECRETS_MUST_BE_PRESENTED,
+    MSG_RELAYER_DEPLOYER_WALLET_MISSING_ERR, MSG_RELAYER_INCLUSION_REJECTED,
+    MSG_RELAYER_INVALID_MESSAGE, MSG_RELAYER_NO_TARGETS_ERR,
+};
+
+pub mod args;
+mod message;
+mod state;
+
+/// Name of the relayer state file in the source chain artifacts directory.
+const STATE_FILE: &str = "interop_relayer.json";
+/// Gas per pubdata byte limit of delivery transactions.
+const L2_GAS_PER_PUBDATA_BYTE_LIMIT: u64 = 800;
+/// Base token address of ETH-based chains in Bridgehub.
+const ETH_TOKEN_ADDRESS: Address =
+    H160([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+lazy_static! {
+    static ref BRIDGEHUB: BaseContract = BaseContract::from(
+        parse_abi(&[
+            "function getHyperchain(uint256 _chainId) external view returns (address)",
+            "function baseToken(uint256 _chainId) external view returns (address)",
+            "function l2TransactionBaseCost(uint256 _chainId, uint256 _gasPrice, uint256 _l2GasLimit, uint256 _l2GasPerPubdataByteLimit) external view returns (uint256)",
+            "function proveL2MessageInclusion(uint256 _chainId, uint256 _batchNumber, uint256 _index, (uint16,address,bytes) _message, bytes32[] _proof) external view returns (bool)",
+            "function requestL2TransactionDirect((uint256,uint256,address,uint256,bytes,uint256,uint256,bytes[],address) _request) external payable returns (bytes32)",
+        ])
+        .unwrap(),
+    );
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RelayerCommands {
+    /// Run the relayer for the chain selected with `--chain`, delivering its messages to target chains
+    Run(RelayerRunArgs),
+}
+
+pub(super) async fn run(shell: &Shell, args: RelayerCommands) -> anyhow::Result<()> {
+    match args {
+        RelayerCommands::Run(args) => run_relayer(shell, args).await,
+    }
+}
+
+async fn run_relayer(shell: &Shell, args: RelayerRunArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let mut relayer = Relayer::new(&ecosystem_config, &chain_config, &args).await?;
+
+    loop {
+        let result = relayer.process().await;
+        if args.once {
+            result?;
+            break;
+        }
+        // Errors are usually caused by unavailable RPC nodes, so the relayer keeps running.
+        if let Err(err) = result {
+            logger::warn(msg_relayer_iteration_err(&err));
+        }
+        tokio::time::sleep(relayer.poll_interval).await;
+    }
+
+    let state = &relayer.state;
+    logger::outro(msg_relayer_summary(
+        state.count(DeliveryStatus::Delivered),
+        state.count(DeliveryStatus::Pending),
+        state.count(DeliveryStatus::Failed),
+    ));
+    Ok(())
+}
+
+/// Error delivering a message.
+#[derive(Debug)]
+enum DeliveryError {
+    /// The message can never be delivered, e.g. because it targets an unknown chain.
+    Rejected(String),
+    /// Delivery can succeed on retry.
+    Transient(anyhow::Error),
+}
+
+impl From<anyhow::Error> for DeliveryError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::Transient(err)
+    }
+}
+
+struct Relayer {
+    source_chain_id: u64,
+    diamond_proxy_addr: Address,
+    bridgehub_addr: Address,
+    l2_provider: Provider<Http>,
+    l1_provider: Arc<Provider<Http>>,
+    l1_client: SignerMiddleware<Provider<Http>, Wallet<SigningKey>>,
+    /// Names of target chains keyed by chain ID.
+    targets: HashMap<u64, String>,
+    state: RelayerState,
+    state_path: PathBuf,
+    retry_policy: RetryPolicy,
+    poll_interval: Duration,
+    l2_gas_limit: U256,
+}
+
+impl Relayer {
+    async fn new(
+        ecosystem_config: &EcosystemConfig,
+        chain_config: &ChainConfig,
+        args: &RelayerRunArgs,
+    ) -> anyhow::Result<Self> {
+        let l1_rpc_url = chain_config
+            .get_secrets_config()?
+            .l1
+            .context(MSG_L1_SECRETS_MUST_BE_PRESENTED)?
+            .l1_rpc_url
+            .expose_str()
+            .to_string();
+        let l2_rpc_url = chain_config.get_general_config()?.get_l2_rpc_url()?;
+        let contracts_config = chain_config.get_contracts_config()?;
+        let wallet = chain_config
+            .get_wallets_config()?
+            .deployer
+            .and_then(|wallet| wallet.private_key)
+            .context(MSG_RELAYER_DEPLOYER_WALLET_MISSING_ERR)?;
+
+        let source_chain_id = chain_config.chain_id.as_u64();
+        let state_path = args
+            .state
+            .clone()
+            .unwrap_or_else(|| chain_config.artifacts.join(STATE_FILE));
+        let state = match RelayerState::load(&state_path)? {
+            Some(state) => {
+                anyhow::ensure!(
+                    state.source_chain_id == source_chain_id,
+                    msg_relayer_state_chain_mismatch_err(&state_path, state.source_chain_id)
+                );
+                if args.from_batch.is_some() {
+                    logger::warn(msg_relayer_resuming(&state_path, state.next_batch));
+                }
+                state
+            }
+            None => RelayerState::new(source_chain_id, L1BatchNumber(args.from_batch.unwrap_or(1))),
+        };
+
+        let mut relayer = Self {
+            source_chain_id,
+            diamond_proxy_addr: contracts_config.l1.diamond_proxy_addr,
+            bridgehub_addr: contracts_config.ecosystem_contracts.bridgehub_proxy_addr,
+            l2_provider: Provider::try_from(l2_rpc_url.as_str())?,
+            l1_provider: Arc::new(Provider::try_from(l1_rpc_url.as_str())?),
+            l1_client: create_ethers_client(
+                wallet,
+                l1_rpc_url,
+                Some(ecosystem_config.l1_network.chain_id()),
+            )?,
+            targets: HashMap::new(),
+            state,
+            state_path,
+            retry_policy: RetryPolicy {
+                max_attempts: args.max_attempts.max(1),
+                base_delay: Duration::from_secs(args.poll_interval),
+            },
+            poll_interval: Duration::from_secs(args.poll_interval),
+            l2_gas_limit: args.l2_gas_limit.into(),
+        };
+        relayer
+            .resolve_targets(ecosystem_config, &chain_config.name, &args.targets)
+            .await?;
+
+        let target_names: Vec<_> = relayer.targets.values().map(String::as_str).collect();
+        logger::info(msg_relayer_started(
+            &chain_config.name,
+            &target_names,
+            &relayer.state_path,
+        ));
+        Ok(relayer)
+    }
+
+    /// Selects target chains; by default, these are all other chains of the ecosystem. Only chains registered
+    /// in Bridgehub and using ETH as the base token are supported.
+    async fn resolve_targets(
+        &mut self,
+        ecosystem_config: &EcosystemConfig,
+        source_chain_name: &str,
+        target_names: &[String],
+    ) -> anyhow::Result<()> {
+        let target_names = if target_names.is_empty() {
+            ecosystem_config
+                .list_of_chains()
+                .into_iter()
+                .filter(|name| name != source_chain_name)
+                .collect()
+        } else {
+            target_names.to_vec()
+        };
+
+        for name in target_names {
+            let chain_config = ecosystem_config
+                .load_chain(Some(name.clone()))
+                .with_context(|| msg_chain_load_err(&name))?;
+            let chain_id = U256::from(chain_config.chain_id.as_u64());
+            let diamond_proxy: Address = self.call_bridgehub("getHyperchain", chain_id).await?;
+            if diamond_proxy.is_zero() {
+                logger::warn(msg_relayer_target_not_registered(&name));
+                continue;
+            }
+            let base_token: Address = self.call_bridgehub("baseToken", chain_id).await?;
+            if base_token != ETH_TOKEN_ADDRESS {
+                logger::warn(msg_relayer_target_not_eth_based(&name));
+                continue;
+            }
+            self.targets.insert(chain_config.chain_id.as_u64(), name);
+        }
+        anyhow::ensure!(!self.targets.is_empty(), MSG_RELAYER_NO_TARGETS_ERR);
+        Ok(())
+    }
+
+    async fn call_bridgehub<D: Detokenize>(
+        &self,
+        name: &str,
+        args: impl Tokenize,
+    ) -> anyhow::Result<D> {
+        let data = BRIDGEHUB.encode(name, args)?;
+        let tx = TransactionRequest::new().to(self.bridgehub_addr).data(data);
+        let output = self
+            .l1_provider
+            .call(&tx.into(), None)
+            .await
+            .with_context(|| format!("Bridgehub.{name}()"))?;
+        Ok(BRIDGEHUB.decode_output(name, output)?)
+    }
+
+    async fn process(&mut self) -> anyhow::Result<()> {
+        self.scan_source_chain().await?;
+        self.deliver_due_messages().await
+    }
+
+    /// Scans source chain batches executed on L1 for new messages.
+    async fn scan_source_chain(&mut self) -> anyhow::Result<()> {
+        let last_sealed_batch: U64 = self
+            .l2_provider
+            .request("zks_L1BatchNumber", ())
+            .await
+            .context("zks_L1BatchNumber")?;
+        while self.state.next_batch.0 <= last_sealed_batch.as_u32() {
+            let batch = self.state.next_batch;
+            // Messages can only be proven once the batch is executed, and batches are executed in order.
+            let root_hash =
+                l1_logs_root_hash(self.l1_provider.clone(), self.diamond_proxy_addr, batch).await?;
+            if root_hash.is_zero() {
+                break;
+            }
+            for record in self.fetch_batch_messages(batch).await? {
+                logger::info(msg_relayer_message_found(
+                    batch,
+                    record.index,
+                    record.target_chain_id,
+                ));
+                self.state.insert(record);
+            }
+            self.state.next_batch = batch + 1;
+            self.state.save(&self.state_path)?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_batch_messages(
+        &self,
+        batch: L1BatchNumber,
+    ) -> anyhow::Result<Vec<MessageRecord>> {
+        let block_range: Option<(U64, U64)> = self
+            .l2_provider
+            .request("zks_getL1BatchBlockRange", [batch])
+            .await
+            .context("zks_getL1BatchBlockRange")?;
+        let (first_block, last_block) =
+            block_range.with_context(|| msg_relayer_batch_not_sealed_err(batch))?;
+
+        let mut records = vec![];
+        for block_number in first_block.as_u64()..=last_block.as_u64() {
+            let receipts: Vec<Receipt> = self
+                .l2_provider
+                .request("eth_getBlockReceipts", [U64::from(block_number)])
+                .await
+                .with_context(|| format!("eth_getBlockReceipts({block_number})"))?;
+            for sent in receipts.iter().flat_map(extract_messages) {
+                let proof: Option<L2ToL1LogProof> = self
+                    .l2_provider
+                    .request("zks_getL2ToL1LogProof", (sent.tx_hash, sent.log_position))
+                    .await
+                    .context("zks_getL2ToL1LogProof")?;
+                let proof = proof.with_context(|| msg_relayer_missing_proof_err(sent.tx_hash))?;
+                records.push(MessageRecord::new(batch, proof.id, proof.proof, sent));
+            }
+        }
+        Ok(records)
+    }
+
+    async fn deliver_due_messages(&mut self) -> anyhow::Result<()> {
+        let now = unix_timestamp();
+        for idx in self.state.due_messages(now) {
+            let result = self.deliver(&self.state.messages[idx]).await;
+            let record = &mut self.state.messages[idx];
+            match result {
+                Ok(l1_tx_hash) => {
+                    record.mark_delivered(l1_tx_hash);
+                    let target_name = &self.targets[&record.target_chain_id];
+                    logger::success(msg_relayer_message_delivered(
+                        record.source_batch,
+                        record.index,
+                        target_name,
+                        l1_tx_hash,
+                    ));
+                }
+                Err(DeliveryError::Rejected(reason)) => {
+                    logger::warn(msg_relayer_message_failed(
+                        record.source_batch,
+                        record.index,
+                        &reason,
+                    ));
+                    record.mark_failed(reason);
+                }
+                Err(DeliveryError::Transient(err)) => {
+                    let err = format!("{err:#}");
+                    record.record_failed_attempt(err.clone(), now, &self.retry_policy);
+                    if record.status == DeliveryStatus::Failed {
+                        logger::warn(msg_relayer_message_failed(
+                            record.source_batch,
+                            record.index,
+                            &err,
+                        ));
+                    } else {
+                        logger::warn(msg_relayer_delivery_retry(
+                            record.source_batch,
+                            record.index,
+                            record.attempts,
+                            &err,
+                        ));
+                    }
+                }
+            }
+            self.state.save(&self.state_path)?;
+        }
+        Ok(())
+    }
+
+    /// Delivers a message to the target chain. Returns the hash of the L1 transaction requesting
+    /// the delivery transaction on the target chain.
+    async fn deliver(&self, record: &MessageRecord) -> Result<H256, DeliveryError> {
+        if !self.targets.contains_key(&record.target_chain_id) {
+            return Err(DeliveryError::Rejected(msg_relayer_unknown_target(
+                record.target_chain_id,
+            )));
+        }
+        let message = InteropMessage::decode(&record.body)
+            .ok_or_else(|| DeliveryError::Rejected(MSG_RELAYER_INVALID_MESSAGE.to_owned()))?;
+
+        let proof = record
+            .proof
+            .iter()
+            .map(|hash| Token::FixedBytes(hash.as_bytes().to_vec()))
+            .collect();
+        let is_included: bool = self
+            .call_bridgehub(
+                "proveL2MessageInclusion",
+                (
+                    U256::from(self.source_chain_id),
+                    U256::from(record.source_batch.0),
+                    U256::from(record.index),
+                    l2_message_token(record.tx_number_in_batch, record.sender, &record.body),
+                    Token::Array(proof),
+                ),
+            )
+            .await?;
+        if !is_included {
+            return Err(DeliveryError::Rejected(
+                MSG_RELAYER_INCLUSION_REJECTED.to_owned(),
+            ));
+        }
+
+        // Legacy transactions are used, so that the gas price used to compute the base cost matches `tx.gasprice`.
+        let gas_price = self
+            .l1_client
+            .get_gas_price()
+            .await
+            .context("eth_gasPrice")?;
+        let target_chain_id = U256::from(record.target_chain_id);
+        let base_cost: U256 = self
+            .call_bridgehub(
+                "l2TransactionBaseCost",
+                (
+                    target_chain_id,
+                    gas_price,
+                    self.l2_gas_limit,
+                    U256::from(L2_GAS_PER_PUBDATA_BYTE_LIMIT),
+                ),
+            )
+            .await?;
+        let calldata = message.delivery_calldata(
+            self.source_chain_id,
+            record.source_batch.0,
+            record.index,
+            record.sender,
+        );
+        let request = Token::Tuple(vec![
+            Token::Uint(target_chain_id),
+            Token::Uint(base_cost),
+            Token::Address(record.recipient),
+            Token::Uint(U256::zero()),
+            Token::Bytes(calldata.to_vec()),
+            Token::Uint(self.l2_gas_limit),
+            Token::Uint(L2_GAS_PER_PUBDATA_BYTE_LIMIT.into()),
+            Token::Array(vec![]),
+            Token::Address(self.l1_client.address()),
+        ]);
+        let data = BRIDGEHUB
+            .encode("requestL2TransactionDirect", (request,))
+            .context("failed encoding delivery transaction")?;
+        let tx = TransactionRequest::new()
+            .to(self.bridgehub_addr)
+            .data(data)
+            .value(base_cost)
+            .gas_price(gas_price);
+
+        let pending_tx = self
+            .l1_client
+            .send_transaction(tx, None)
+            .await
+            .context("failed sending delivery transaction")?;
+        let tx_hash = pending_tx.tx_hash();
+        let receipt = pending_tx
+            .confirmations(1)
+            .await
+            .context("failed waiting for delivery transaction")?
+            .with_context(|| msg_relayer_tx_dropped_err(tx_hash))?;
+        if receipt.status != Some(1.into()) {
+            return Err(anyhow::anyhow!(msg_relayer_tx_reverted_err(tx_hash)).into());
+        }
+        Ok(tx_hash)
+    }
+}
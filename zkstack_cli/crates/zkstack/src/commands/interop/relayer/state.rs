@@ -0,0 +1,274 @@
+//! Persistent relayer state.
+//!
+//! The state is stored as a JSON file and saved after each processed batch and each delivery attempt,
+//! so that the relayer can be restarted at any time. Delivery is at-least-once: if the relayer is stopped
+//! after sending a delivery transaction but before saving its outcome, the message will be delivered again.
+
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use ethers::types::{Address, Bytes, H256};
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::L1BatchNumber;
+
+use super::message::SentMessage;
+
+/// Maximum delay between delivery attempts.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(3_600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+/// Interop message tracked by the relayer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct MessageRecord {
+    pub source_batch: L1BatchNumber,
+    /// Index of the message in the L2→L1 logs Merkle tree of the source batch.
+    pub index: u32,
+    pub tx_hash: H256,
+    pub tx_number_in_batch: u16,
+    pub sender: Address,
+    pub target_chain_id: u64,
+    pub recipient: Address,
+    pub body: Bytes,
+    pub proof: Vec<H256>,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    /// UNIX timestamp (in seconds) after which the next delivery attempt can be made.
+    pub next_attempt_at: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_tx_hash: Option<H256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl MessageRecord {
+    pub fn new(
+        source_batch: L1BatchNumber,
+        index: u32,
+        proof: Vec<H256>,
+        sent: SentMessage,
+    ) -> Self {
+        Self {
+            source_batch,
+            index,
+            tx_hash: sent.tx_hash,
+            tx_number_in_batch: sent.tx_number_in_batch,
+            sender: sent.sender,
+            target_chain_id: sent.message.target_chain_id,
+            recipient: sent.message.recipient,
+            body: sent.body,
+            proof,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: 0,
+            l1_tx_hash: None,
+            last_error: None,
+        }
+    }
+
+    pub fn mark_delivered(&mut self, l1_tx_hash: H256) {
+        self.status = DeliveryStatus::Delivered;
+        self.attempts += 1;
+        self.l1_tx_hash = Some(l1_tx_hash);
+        self.last_error = None;
+    }
+
+    /// Marks the message as failed without further retries.
+    pub fn mark_failed(&mut self, reason: String) {
+        self.status = DeliveryStatus::Failed;
+        self.attempts += 1;
+        self.last_error = Some(reason);
+    }
+
+    /// Records a failed delivery attempt and schedules a retry with exponential backoff. Once the maximum
+    /// number of attempts is reached, the message is marked as failed.
+    pub fn record_failed_attempt(&mut self, error: String, now: u64, policy: &RetryPolicy) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+        if self.attempts >= policy.max_attempts {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = now + policy.delay(self.attempts).as_secs();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns the delay after the specified number of failed attempts.
+    fn delay(&self, attempts: u32) -> Duration {
+        let factor = 1_u32 << attempts.saturating_sub(1).min(16);
+        self.base_delay.saturating_mul(factor).min(MAX_RETRY_DELAY)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RelayerState {
+    pub source_chain_id: u64,
+    /// Next source chain L1 batch to scan for messages.
+    pub next_batch: L1BatchNumber,
+    pub messages: Vec<MessageRecord>,
+}
+
+impl RelayerState {
+    pub fn new(source_chain_id: u64, first_batch: L1BatchNumber) -> Self {
+        Self {
+            source_chain_id,
+            next_batch: first_batch,
+            messages: vec![],
+        }
+    }
+
+    /// Loads the state from the specified file. Returns `None` if the file doesn't exist.
+    pub fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("failed reading {path:?}"))?;
+        let state = serde_json::from_str(&contents)
+            .with_context(|| format!("invalid relayer state in {path:?}"))?;
+        Ok(Some(state))
+    }
+
+    /// Atomically saves the state to the specified file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("failed creating {parent:?}"))?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed writing {tmp_path:?}"))?;
+        fs::rename(&tmp_path, path).with_context(|| format!("failed writing {path:?}"))?;
+        Ok(())
+    }
+
+    /// Adds a message unless it's already tracked. Returns whether the message was added.
+    pub fn insert(&mut self, record: MessageRecord) -> bool {
+        let is_tracked = self.messages.iter().any(|existing| {
+            existing.source_batch == record.source_batch && existing.index == record.index
+        });
+        if !is_tracked {
+            self.messages.push(record);
+        }
+        !is_tracked
+    }
+
+    /// Returns indices of pending messages which can be delivered at the specified time.
+    pub fn due_messages(&self, now: u64) -> Vec<usize> {
+        self.messages
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| {
+                record.status == DeliveryStatus::Pending && record.next_attempt_at <= now
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    pub fn count(&self, status: DeliveryStatus) -> usize {
+        self.messages
+            .iter()
+            .filter(|record| record.status == status)
+            .count()
+    }
+}
+
+pub(super) fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before UNIX epoch")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::interop::relayer::message::InteropMessage;
+
+    fn mock_record(source_batch: u32, index: u32) -> MessageRecord {
+        let sent = SentMessage {
+            tx_hash: H256::repeat_byte(1),
+            log_position: 0,
+            tx_number_in_batch: 2,
+            sender: Address::repeat_byte(0x23),
+            body: vec![0xaa; 8].into(),
+            message: InteropMessage {
+                target_chain_id: 272,
+                recipient: Address::repeat_byte(0x42),
+                payload: vec![1, 2, 3].into(),
+            },
+        };
+        MessageRecord::new(L1BatchNumber(source_batch), index, vec![H256::zero()], sent)
+    }
+
+    #[test]
+    fn retrying_delivery() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(5),
+        };
+        let mut state = RelayerState::new(271, L1BatchNumber(1));
+        assert!(state.insert(mock_record(1, 0)));
+        assert!(!state.insert(mock_record(1, 0)));
+        assert!(state.insert(mock_record(1, 1)));
+        assert_eq!(state.due_messages(100), [0, 1]);
+
+        state.messages[0].record_failed_attempt("error".to_owned(), 100, &policy);
+        assert_eq!(state.messages[0].next_attempt_at, 105);
+        state.messages[0].record_failed_attempt("error".to_owned(), 105, &policy);
+        assert_eq!(state.messages[0].next_attempt_at, 115);
+        assert_eq!(state.due_messages(110), [1]);
+        state.messages[0].record_failed_attempt("error".to_owned(), 115, &policy);
+        assert_eq!(state.messages[0].status, DeliveryStatus::Failed);
+
+        state.messages[1].mark_delivered(H256::repeat_byte(2));
+        assert!(state.due_messages(u64::MAX).is_empty());
+        assert_eq!(state.count(DeliveryStatus::Delivered), 1);
+        assert_eq!(state.count(DeliveryStatus::Failed), 1);
+    }
+
+    #[test]
+    fn retry_delay_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 100,
+            base_delay: Duration::from_secs(5),
+        };
+        assert_eq!(policy.delay(1), Duration::from_secs(5));
+        assert_eq!(policy.delay(4), Duration::from_secs(40));
+        assert_eq!(policy.delay(50), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn state_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("interop_relayer_{}", std::process::id()));
+        let path = dir.join("state.json");
+        assert!(RelayerState::load(&path).unwrap().is_none());
+
+        let mut state = RelayerState::new(271, L1BatchNumber(5));
+        state.insert(mock_record(5, 3));
+        state.save(&path).unwrap();
+        let loaded = RelayerState::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.source_chain_id, 271);
+        assert_eq!(loaded.next_batch, L1BatchNumber(5));
+        assert_eq!(loaded.messages, state.messages);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
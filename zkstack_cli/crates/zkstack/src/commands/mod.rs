@@ -5,10 +5,14 @@ pub mod consensus;
 pub mod containers;
 pub mod contract_verifier;
 pub mod dev;
+pub mod doctor;
 pub mod ecosystem;
 pub mod explorer;
 pub mod external_node;
+pub mod interop;
+pub mod multi_chain;
 pub mod portal;
 pub mod prover;
+pub mod run_all;
 pub mod server;
 pub mod update;
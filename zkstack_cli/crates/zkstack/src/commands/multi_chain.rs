@@ -0,0 +1,320 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsString,
+    os::unix::process::CommandExt as _,
+    path::PathBuf,
+    process::{ExitStatus, Stdio},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use common::logger;
+use config::{ChainConfig, EcosystemConfig};
+use console::Color;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::Command,
+    sync::watch,
+    task::{JoinHandle, JoinSet},
+};
+use xshell::Shell;
+
+use crate::{
+    commands::{
+        args::{LogFormat, MultiChainArgs},
+        run_all::{terminate, LogMultiplexer, Stream, PREFIX_COLORS},
+    },
+    messages::{
+        msg_multi_chain_chain_failed, msg_multi_chain_chain_stopped,
+        msg_multi_chain_chain_succeeded, msg_multi_chain_failed_err, msg_multi_chain_last_lines,
+        msg_multi_chain_running, msg_run_all_failed_to_spawn, MSG_CHAIN_NOT_FOUND_ERR,
+        MSG_MULTI_CHAIN_ALL_SUCCEEDED, MSG_MULTI_CHAIN_RUNNER_PANICKED_ERR,
+        MSG_MULTI_CHAIN_SHUTTING_DOWN, MSG_MULTI_CHAIN_STOPPED,
+        MSG_RUN_ALL_FAILED_TO_GET_CURRENT_EXE_ERR,
+    },
+};
+
+/// Number of last output lines of a failed chain shown in the summary.
+const TAIL_LINES: usize = 20;
+
+/// Re-runs the current `zkstack` command for each selected chain as a child process, with the
+/// output of every chain prefixed by its name, and prints a summary once all of them exit.
+///
+/// `chain_args` returns additional arguments passed to the command of a specific chain.
+pub(crate) async fn run(
+    shell: &Shell,
+    args: &MultiChainArgs,
+    chain_args: impl Fn(&ChainConfig) -> anyhow::Result<Vec<String>>,
+) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chains = args.select_chains(&ecosystem_config)?;
+    let zkstack = std::env::current_exe().context(MSG_RUN_ALL_FAILED_TO_GET_CURRENT_EXE_ERR)?;
+    let command_args = strip_multi_chain_args(std::env::args_os().skip(1));
+
+    let mut runners = Vec::with_capacity(chains.len());
+    let output = LogMultiplexer {
+        format: LogFormat::Text,
+        prefix_width: chains.iter().map(String::len).max().unwrap_or(0) + 2,
+    };
+    for (i, chain) in chains.iter().enumerate() {
+        let chain_config = ecosystem_config
+            .load_chain(Some(chain.clone()))
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        let mut args: Vec<OsString> = vec!["--chain".into(), chain.into()];
+        // Prerequisites were already checked by this command.
+        if !command_args
+            .iter()
+            .any(|arg| arg == "--ignore-prerequisites")
+        {
+            args.push("--ignore-prerequisites".into());
+        }
+        args.extend(command_args.iter().cloned());
+        args.extend(chain_args(&chain_config)?.into_iter().map(OsString::from));
+
+        runners.push(ChainRunner {
+            chain: chain.clone(),
+            color: PREFIX_COLORS[i % PREFIX_COLORS.len()],
+            zkstack: zkstack.clone(),
+            args,
+            output: output.clone(),
+            tail: Arc::default(),
+        });
+    }
+
+    logger::info(msg_multi_chain_running(&chains));
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let mut tasks = JoinSet::new();
+    for runner in runners {
+        tasks.spawn(runner.run(stop_receiver.clone()));
+    }
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut outcomes = Vec::with_capacity(chains.len());
+    loop {
+        tokio::select! {
+            res = tasks.join_next() => match res {
+                Some(res) => outcomes.push(res.context(MSG_MULTI_CHAIN_RUNNER_PANICKED_ERR)?),
+                None => break,
+            },
+            _ = &mut ctrl_c, if !*stop_sender.borrow() => {
+                logger::info(MSG_MULTI_CHAIN_SHUTTING_DOWN);
+                stop_sender.send_replace(true);
+            }
+        }
+    }
+
+    outcomes.sort_by_key(|outcome| chains.iter().position(|chain| *chain == outcome.chain));
+    print_summary(&outcomes)
+}
+
+fn print_summary(outcomes: &[ChainOutcome]) -> anyhow::Result<()> {
+    let mut failed = vec![];
+    let mut stopped = false;
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(ChainStatus::Exited(status)) if status.success() => {
+                logger::success(msg_multi_chain_chain_succeeded(
+                    &outcome.chain,
+                    outcome.elapsed,
+                ));
+            }
+            Ok(ChainStatus::Stopped) => {
+                stopped = true;
+                logger::warn(msg_multi_chain_chain_stopped(
+                    &outcome.chain,
+                    outcome.elapsed,
+                ));
+            }
+            Ok(ChainStatus::Exited(status)) => {
+                logger::error(msg_multi_chain_chain_failed(
+                    &outcome.chain,
+                    status,
+                    outcome.elapsed,
+                ));
+                failed.push(outcome);
+            }
+            Err(err) => {
+                logger::error(msg_multi_chain_chain_failed(
+                    &outcome.chain,
+                    format!("{err:#}"),
+                    outcome.elapsed,
+                ));
+                failed.push(outcome);
+            }
+        }
+    }
+
+    for outcome in &failed {
+        if !outcome.tail.is_empty() {
+            logger::note(
+                msg_multi_chain_last_lines(&outcome.chain),
+                outcome.tail.join("\n"),
+            );
+        }
+    }
+
+    if !failed.is_empty() {
+        let failed: Vec<_> = failed.iter().map(|outcome| outcome.chain.clone()).collect();
+        anyhow::bail!(msg_multi_chain_failed_err(&failed));
+    }
+    logger::outro(if stopped {
+        MSG_MULTI_CHAIN_STOPPED
+    } else {
+        MSG_MULTI_CHAIN_ALL_SUCCEEDED
+    });
+    Ok(())
+}
+
+/// Removes the chain selection arguments, so that the command can be re-run for a single chain.
+fn strip_multi_chain_args(args: impl IntoIterator<Item = OsString>) -> Vec<OsString> {
+    let mut stripped = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("--") => {
+                stripped.push(arg);
+                stripped.extend(args);
+                break;
+            }
+            Some("--all-chains") => {}
+            Some("--chains") => {
+                args.next();
+            }
+            Some(arg) if arg.starts_with("--chains=") => {}
+            _ => stripped.push(arg),
+        }
+    }
+    stripped
+}
+
+#[derive(Debug)]
+enum ChainStatus {
+    Exited(ExitStatus),
+    /// The command was stopped because of Ctrl+C.
+    Stopped,
+}
+
+#[derive(Debug)]
+struct ChainOutcome {
+    chain: String,
+    result: anyhow::Result<ChainStatus>,
+    elapsed: Duration,
+    tail: Vec<String>,
+}
+
+struct ChainRunner {
+    chain: String,
+    color: Color,
+    zkstack: PathBuf,
+    args: Vec<OsString>,
+    output: LogMultiplexer,
+    tail: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl ChainRunner {
+    async fn run(self, stop_receiver: watch::Receiver<bool>) -> ChainOutcome {
+        let started = Instant::now();
+        let result = self
+            .run_command(stop_receiver)
+            .await
+            .with_context(|| msg_run_all_failed_to_spawn(&self.chain));
+        let tail = self.tail.lock().unwrap().drain(..).collect();
+        ChainOutcome {
+            chain: self.chain,
+            result,
+            elapsed: started.elapsed(),
+            tail,
+        }
+    }
+
+    async fn run_command(
+        &self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<ChainStatus> {
+        let mut command = std::process::Command::new(&self.zkstack);
+        command.args(&self.args);
+        // Each chain gets its own process group, so that Ctrl+C is handled by this command
+        // and the chain command can be stopped together with the processes it spawns.
+        command.process_group(0);
+
+        let mut child = Command::from(command)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        let mut forwarders = vec![];
+        if let Some(stdout) = child.stdout.take() {
+            forwarders.push(self.forward_lines(stdout, Stream::Stdout));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            forwarders.push(self.forward_lines(stderr, Stream::Stderr));
+        }
+
+        let status = tokio::select! {
+            status = child.wait() => ChainStatus::Exited(status?),
+            _ = stop_receiver.wait_for(|&stop| stop) => {
+                terminate(&mut child).await?;
+                ChainStatus::Stopped
+            }
+        };
+        // Make sure that the output is complete before it's summarized.
+        for forwarder in forwarders {
+            forwarder.await.ok();
+        }
+        Ok(status)
+    }
+
+    fn forward_lines(
+        &self,
+        reader: impl AsyncRead + Unpin + Send + 'static,
+        stream: Stream,
+    ) -> JoinHandle<()> {
+        let (chain, color, output) = (self.chain.clone(), self.color, self.output.clone());
+        let tail = self.tail.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(reader).split(b'\n');
+            while let Ok(Some(line)) = lines.next_segment().await {
+                let line = String::from_utf8_lossy(&line);
+                let line = line.trim_end_matches('\r');
+                output.write(&chain, color, stream, line);
+
+                let mut tail = tail.lock().unwrap();
+                if tail.len() == TAIL_LINES {
+                    tail.pop_front();
+                }
+                tail.push_back(console::strip_ansi_codes(line).into_owned());
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip(args: &[&str]) -> Vec<String> {
+        strip_multi_chain_args(args.iter().map(OsString::from))
+            .into_iter()
+            .map(|arg| arg.into_string().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn multi_chain_args_are_stripped() {
+        assert_eq!(
+            strip(&["chain", "genesis", "--all-chains", "--dev"]),
+            ["chain", "genesis", "--dev"]
+        );
+        assert_eq!(
+            strip(&["update", "--chains", "era,validium", "-c"]),
+            ["update", "-c"]
+        );
+        assert_eq!(
+            strip(&["server", "--chains=era", "--", "--chains"]),
+            ["server", "--", "--chains"]
+        );
+    }
+}
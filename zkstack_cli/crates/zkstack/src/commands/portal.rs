@@ -5,18 +5,22 @@ use common::{config::global_config, docker, ethereum, logger};
 use config::{
     portal::*,
     traits::{ConfigWithL2RpcUrl, SaveConfig},
-    AppsEcosystemConfig, ChainConfig, EcosystemConfig,
+    AppsEcosystemConfig, ChainConfig, EcosystemConfig, RemoteChainConfig, RemoteChainsConfig,
 };
 use ethers::types::Address;
-use types::{BaseToken, TokenInfo};
+use types::{BaseToken, L1Network, TokenInfo};
+use url::Url;
 use xshell::Shell;
 
 use crate::{
+    commands::args::PortalArgs,
     consts::{L2_BASE_TOKEN_ADDRESS, PORTAL_DOCKER_CONFIG_PATH, PORTAL_DOCKER_IMAGE},
     messages::{
+        msg_portal_chain_not_found, msg_portal_config_regenerated, msg_portal_remote_chain_skipped,
         msg_portal_running_with_config, msg_portal_starting_on,
-        MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR, MSG_PORTAL_FAILED_TO_FIND_ANY_CHAIN_ERR,
-        MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR,
+        MSG_PORTAL_FAILED_TO_APPLY_OVERRIDE_ERR, MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR,
+        MSG_PORTAL_FAILED_TO_FIND_ANY_CHAIN_ERR, MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR,
+        MSG_PORTAL_L1_RPC_URL_REQUIRED_ERR,
     },
 };
 
@@ -32,11 +36,48 @@ async fn build_portal_chain_config(
         .as_ref()
         .map(|l1| l1.l1_rpc_url.expose_str())
         .context("l1")?;
+    build_portal_chain_config_inner(
+        &chain_config.name,
+        chain_config.chain_id.as_u64(),
+        chain_config.l1_network,
+        l2_rpc_url.as_str(),
+        Some(l1_rpc_url),
+        chain_config.base_token.address,
+        None,
+    )
+    .await
+}
+
+async fn build_remote_portal_chain_config(
+    chain: &RemoteChainConfig,
+    l1_network: L1Network,
+) -> anyhow::Result<PortalChainConfig> {
+    build_portal_chain_config_inner(
+        &chain.name,
+        chain.chain_id.as_u64(),
+        l1_network,
+        chain.l2_rpc_url.as_str(),
+        chain.l1_rpc_url.as_ref().map(Url::as_str),
+        chain.base_token_address,
+        chain.explorer_url.as_ref().map(Url::to_string),
+    )
+    .await
+}
+
+async fn build_portal_chain_config_inner(
+    chain_name: &str,
+    chain_id: u64,
+    l1_network: L1Network,
+    l2_rpc_url: &str,
+    l1_rpc_url: Option<&str>,
+    base_token_address: Address,
+    block_explorer_url: Option<String>,
+) -> anyhow::Result<PortalChainConfig> {
     // Build L1 network config
-    let l1_network = Some(L1NetworkConfig {
-        id: chain_config.l1_network.chain_id(),
-        name: chain_config.l1_network.to_string(),
-        network: chain_config.l1_network.to_string().to_lowercase(),
+    let l1_network_config = l1_rpc_url.map(|l1_rpc_url| L1NetworkConfig {
+        id: l1_network.chain_id(),
+        name: l1_network.to_string(),
+        network: l1_network.to_string().to_lowercase(),
         native_currency: TokenInfo::eth(),
         rpc_urls: RpcUrls {
             default: RpcUrlConfig {
@@ -48,13 +89,13 @@ async fn build_portal_chain_config(
         },
     });
     // Base token:
-    let (base_token_addr, base_token_info) = if chain_config.base_token == BaseToken::eth() {
+    let (base_token_addr, base_token_info) = if base_token_address == BaseToken::eth().address {
         (format!("{:?}", Address::zero()), TokenInfo::eth())
     } else {
+        let l1_rpc_url = l1_rpc_url.context(MSG_PORTAL_L1_RPC_URL_REQUIRED_ERR)?;
         (
-            format!("{:?}", chain_config.base_token.address),
-            ethereum::get_token_info(chain_config.base_token.address, l1_rpc_url.to_string())
-                .await?,
+            format!("{:?}", base_token_address),
+            ethereum::get_token_info(base_token_address, l1_rpc_url.to_string()).await?,
         )
     };
     let tokens = vec![TokenConfig {
@@ -67,13 +108,13 @@ async fn build_portal_chain_config(
     // Build hyperchain config
     Ok(PortalChainConfig {
         network: NetworkConfig {
-            id: chain_config.chain_id.as_u64(),
-            key: chain_config.name.clone(),
-            name: chain_config.name.clone(),
+            id: chain_id,
+            key: chain_name.to_owned(),
+            name: chain_name.to_owned(),
             rpc_url: l2_rpc_url.to_string(),
-            l1_network,
+            l1_network: l1_network_config,
             public_l1_network_id: None,
-            block_explorer_url: None,
+            block_explorer_url,
             block_explorer_api: None,
             hidden: None,
             other: serde_json::Value::Null,
@@ -100,6 +141,7 @@ pub async fn update_portal_config(
 async fn validate_portal_config(
     portal_config: &mut PortalConfig,
     ecosystem_config: &EcosystemConfig,
+    remote_chains: &RemoteChainsConfig,
 ) -> anyhow::Result<()> {
     let chain_names = ecosystem_config.list_of_chains();
     for chain_name in &chain_names {
@@ -113,19 +155,41 @@ async fn validate_portal_config(
             }
         }
     }
-    portal_config.filter(&chain_names);
+    // Remote chains are always rebuilt, since the registry might have been updated
+    for chain in &remote_chains.chains {
+        match build_remote_portal_chain_config(chain, ecosystem_config.l1_network).await {
+            Ok(portal_chain_config) => portal_config.add_chain_config(&portal_chain_config),
+            Err(err) => logger::warn(msg_portal_remote_chain_skipped(&chain.name, &err)),
+        }
+    }
+    let mut known_chains = chain_names;
+    known_chains.extend(remote_chains.list_of_chains());
+    portal_config.filter(&known_chains);
     Ok(())
 }
 
-pub async fn run(shell: &Shell) -> anyhow::Result<()> {
+pub async fn run(shell: &Shell, args: PortalArgs) -> anyhow::Result<()> {
+    let args = args.into_run_args();
     let ecosystem_config: EcosystemConfig = EcosystemConfig::from_file(shell)?;
     // Get ecosystem level apps.yaml config
     let apps_config = AppsEcosystemConfig::read_or_create_default(shell)?;
-    // Display all chains, unless --chain is passed
-    let chains_enabled = match global_config().chain_name {
-        Some(ref chain_name) => vec![chain_name.clone()],
-        None => ecosystem_config.list_of_chains(),
+    // Display all chains, unless --chains or --chain is passed
+    let remote_chains = RemoteChainsConfig::read_or_default(shell)?;
+    let mut known_chains = ecosystem_config.list_of_chains();
+    known_chains.extend(remote_chains.list_of_chains());
+    let chains_enabled = if !args.chains.is_empty() {
+        args.chains
+    } else if let Some(chain_name) = &global_config().chain_name {
+        vec![chain_name.clone()]
+    } else {
+        known_chains.clone()
     };
+    if let Some(chain_name) = chains_enabled
+        .iter()
+        .find(|chain_name| !known_chains.contains(chain_name))
+    {
+        anyhow::bail!(msg_portal_chain_not_found(chain_name));
+    }
 
     // Read portal config
     let config_path = PortalConfig::get_config_path(&shell.current_dir());
@@ -133,11 +197,16 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
         .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?;
 
     // Validate and update portal config
-    validate_portal_config(&mut portal_config, &ecosystem_config).await?;
+    validate_portal_config(&mut portal_config, &ecosystem_config, &remote_chains).await?;
     portal_config.hide_except(&chains_enabled);
     if portal_config.is_empty() {
         anyhow::bail!(MSG_PORTAL_FAILED_TO_FIND_ANY_CHAIN_ERR);
     }
+    if let Some(override_path) = &args.config_override {
+        PortalConfigOverride::read(shell, override_path)
+            .and_then(|config_override| config_override.apply(&mut portal_config))
+            .context(MSG_PORTAL_FAILED_TO_APPLY_OVERRIDE_ERR)?;
+    }
 
     // Save portal config
     portal_config.save(shell, &config_path)?;
@@ -145,6 +214,11 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
     let config_js_path = portal_config
         .save_as_js(shell)
         .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?;
+    if args.config_only {
+        // The runtime config is mounted into the container, so a running portal picks it up on reload
+        logger::info(msg_portal_config_regenerated(&config_js_path));
+        return Ok(());
+    }
 
     logger::info(msg_portal_running_with_config(&config_path));
     logger::info(msg_portal_starting_on(
@@ -13,7 +13,7 @@ use super::{
     setup_keys::SetupKeysArgs,
 };
 use crate::{
-    commands::prover::gcs::get_project_ids,
+    commands::{args::AnswersArgs, prover::gcs::get_project_ids},
     consts::{DEFAULT_CREDENTIALS_FILE, DEFAULT_PROOF_STORE_DIR},
     defaults::{generate_db_names, DBNames, DATABASE_PROVER_URL},
     messages::{
@@ -83,6 +83,9 @@ pub struct ProverInitArgs {
 
     #[clap(long)]
     cloud_type: Option<InternalCloudConnectionMode>,
+
+    #[clap(flatten)]
+    pub answers: AnswersArgs,
 }
 
 #[derive(Debug, Clone, ValueEnum, EnumIter, strum::Display, PartialEq, Eq)]
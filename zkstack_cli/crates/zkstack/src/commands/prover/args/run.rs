@@ -5,6 +5,7 @@ use clap::{Parser, ValueEnum};
 use common::{Prompt, PromptSelect};
 use config::ChainConfig;
 use strum::{EnumIter, IntoEnumIterator};
+use zksync_basic_types::basic_fri_types::WeightedRound;
 
 use crate::{
     consts::{
@@ -138,6 +139,15 @@ impl ProverComponent {
         }
 
         match self {
+            Self::WitnessGenerator if !args.witness_generator_args.rounds.is_empty() => {
+                let rounds: Vec<_> = args
+                    .witness_generator_args
+                    .rounds
+                    .iter()
+                    .map(WeightedRound::to_string)
+                    .collect();
+                additional_args.push(format!("--rounds={}", rounds.join(",")));
+            }
             Self::WitnessGenerator => {
                 additional_args.push(
                     match args
@@ -200,6 +210,10 @@ impl ProverComponent {
 pub struct WitnessGeneratorArgs {
     #[clap(long)]
     pub round: Option<WitnessGeneratorRound>,
+    /// Rounds served by a single witness generator process with optional polling weights,
+    /// e.g. `basic_circuits:4,leaf_aggregation:2,scheduler`
+    #[clap(long, value_delimiter = ',', conflicts_with = "round")]
+    pub rounds: Vec<WeightedRound>,
 }
 
 #[derive(Debug, Clone, ValueEnum, strum::EnumString, EnumIter, PartialEq, Eq, strum::Display)]
@@ -332,11 +346,17 @@ impl WitnessGeneratorArgs {
         if component != ProverComponent::WitnessGenerator {
             return Ok(Self::default());
         }
+        if !self.rounds.is_empty() {
+            return Ok(self.clone());
+        }
 
         let round = self.round.clone().unwrap_or_else(|| {
             PromptSelect::new(MSG_ROUND_SELECT_PROMPT, WitnessGeneratorRound::iter()).ask()
         });
 
-        Ok(WitnessGeneratorArgs { round: Some(round) })
+        Ok(WitnessGeneratorArgs {
+            round: Some(round),
+            rounds: vec![],
+        })
     }
 }
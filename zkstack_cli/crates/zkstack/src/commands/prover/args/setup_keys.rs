@@ -1,8 +1,15 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Context as _;
 use clap::{Parser, ValueEnum};
 use common::PromptSelect;
 use strum::{EnumIter, IntoEnumIterator};
 
-use crate::messages::{MSG_SETUP_KEYS_DOWNLOAD_SELECTION_PROMPT, MSG_SETUP_KEYS_REGION_PROMPT};
+use crate::messages::{
+    msg_setup_key_circuit_parse_err, MSG_SETUP_KEYS_CIRCUITS_HELP,
+    MSG_SETUP_KEYS_DOWNLOAD_SELECTION_PROMPT, MSG_SETUP_KEYS_JOBS_HELP,
+    MSG_SETUP_KEYS_REGION_PROMPT,
+};
 
 #[derive(Debug, Clone, Parser, Default)]
 pub struct SetupKeysArgs {
@@ -10,12 +17,20 @@ pub struct SetupKeysArgs {
     pub region: Option<Region>,
     #[clap(long)]
     pub mode: Option<Mode>,
+    #[clap(long, value_delimiter = ',', help = MSG_SETUP_KEYS_CIRCUITS_HELP)]
+    pub circuits: Vec<SetupKeyCircuit>,
+    #[clap(long, default_value_t = 1, help = MSG_SETUP_KEYS_JOBS_HELP)]
+    pub jobs: usize,
 }
 
 #[derive(Debug, Clone)]
 pub struct SetupKeysArgsFinal {
     pub region: Option<Region>,
     pub mode: Mode,
+    /// Circuits to generate setup keys for; empty means all circuits.
+    pub circuits: Vec<SetupKeyCircuit>,
+    /// Number of setup keys generated in parallel.
+    pub jobs: usize,
 }
 
 #[derive(Debug, Clone, ValueEnum, strum::EnumString, EnumIter, PartialEq, Eq, strum::Display)]
@@ -31,11 +46,89 @@ pub enum Region {
     Asia,
 }
 
+/// Circuit identified by the name of its setup key, e.g. `basic_1`, `leaf_3`, `node`, `recursion_tip` or `scheduler`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetupKeyCircuit {
+    Basic(u8),
+    Leaf(u8),
+    Node,
+    RecursionTip,
+    Scheduler,
+}
+
+impl SetupKeyCircuit {
+    /// Recursive circuit IDs that don't correspond to leaf circuits.
+    const SCHEDULER_ID: u8 = 1;
+    const NODE_ID: u8 = 2;
+    const RECURSION_TIP_ID: u8 = 255;
+
+    /// Returns the circuit group and the numeric circuit ID as accepted by `key_generator`.
+    pub fn key_generator_args(self) -> (&'static str, u8) {
+        match self {
+            Self::Basic(id) => ("basic", id),
+            Self::Leaf(id) => ("recursive", id),
+            Self::Node => ("recursive", Self::NODE_ID),
+            Self::RecursionTip => ("recursive", Self::RECURSION_TIP_ID),
+            Self::Scheduler => ("recursive", Self::SCHEDULER_ID),
+        }
+    }
+
+    /// Name of the file with GPU setup data for the circuit in the keystore.
+    pub fn setup_data_file_name(self) -> String {
+        format!("setup_{self}_data.bin")
+    }
+}
+
+impl fmt::Display for SetupKeyCircuit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic(id) => write!(formatter, "basic_{id}"),
+            Self::Leaf(id) => write!(formatter, "leaf_{id}"),
+            Self::Node => formatter.write_str("node"),
+            Self::RecursionTip => formatter.write_str("recursion_tip"),
+            Self::Scheduler => formatter.write_str("scheduler"),
+        }
+    }
+}
+
+impl FromStr for SetupKeyCircuit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_id = |id: &str| {
+            id.parse::<u8>()
+                .with_context(|| msg_setup_key_circuit_parse_err(s))
+        };
+        match s {
+            "node" => return Ok(Self::Node),
+            "recursion_tip" => return Ok(Self::RecursionTip),
+            "scheduler" => return Ok(Self::Scheduler),
+            _ => {}
+        }
+        if let Some(id) = s.strip_prefix("basic_") {
+            return Ok(Self::Basic(parse_id(id)?));
+        }
+        if let Some(id) = s.strip_prefix("leaf_") {
+            let id = parse_id(id)?;
+            anyhow::ensure!(
+                ![Self::SCHEDULER_ID, Self::NODE_ID, Self::RECURSION_TIP_ID].contains(&id),
+                msg_setup_key_circuit_parse_err(s)
+            );
+            return Ok(Self::Leaf(id));
+        }
+        anyhow::bail!(msg_setup_key_circuit_parse_err(s))
+    }
+}
+
 impl SetupKeysArgs {
     pub fn fill_values_with_prompt(self) -> SetupKeysArgsFinal {
-        let mode = self.mode.unwrap_or_else(|| {
-            PromptSelect::new(MSG_SETUP_KEYS_DOWNLOAD_SELECTION_PROMPT, Mode::iter()).ask()
-        });
+        let mode = if self.circuits.is_empty() {
+            self.mode.unwrap_or_else(|| {
+                PromptSelect::new(MSG_SETUP_KEYS_DOWNLOAD_SELECTION_PROMPT, Mode::iter()).ask()
+            })
+        } else {
+            Mode::Generate
+        };
 
         if mode == Mode::Download {
             let region = self.region.unwrap_or_else(|| {
@@ -45,9 +138,16 @@ impl SetupKeysArgs {
             SetupKeysArgsFinal {
                 region: Some(region),
                 mode,
+                circuits: vec![],
+                jobs: 1,
             }
         } else {
-            SetupKeysArgsFinal { region: None, mode }
+            SetupKeysArgsFinal {
+                region: None,
+                mode,
+                circuits: self.circuits,
+                jobs: self.jobs.max(1),
+            }
         }
     }
 }
@@ -122,6 +122,8 @@ fn get_object_store_config(
             },
             max_retries: PROVER_STORE_MAX_RETRIES,
             local_mirror_path: None,
+            local_mirror_max_size_mb: None,
+            replication: None,
         }),
         Some(ProofStorageConfig::GCSCreateBucket(config)) => {
             Some(create_gcs_bucket(shell, config)?)
@@ -177,6 +179,8 @@ fn init_file_backed_proof_storage(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        local_mirror_max_size_mb: None,
+        replication: None,
     };
 
     Ok(object_store_config)
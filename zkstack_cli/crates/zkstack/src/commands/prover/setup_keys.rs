@@ -1,15 +1,28 @@
-use anyhow::Ok;
+use std::{collections::HashSet, path::Path, process::Stdio};
+
+use anyhow::{Context, Ok};
 use common::{
     check_prerequisites, cmd::Cmd, logger, spinner::Spinner, GCLOUD_PREREQUISITE, GPU_PREREQUISITES,
 };
 use config::{get_link_to_prover, EcosystemConfig};
+use futures::{stream, StreamExt};
+use tokio::process::Command;
 use xshell::{cmd, Shell};
 
 use crate::{
-    commands::prover::args::setup_keys::{Mode, Region, SetupKeysArgs},
-    messages::{MSG_GENERATING_SK_SPINNER, MSG_SK_GENERATED},
+    commands::prover::args::setup_keys::{Mode, Region, SetupKeyCircuit, SetupKeysArgs},
+    messages::{
+        msg_generating_sk_for_circuit, msg_not_enough_disk_space_err, msg_sk_generated_for_circuit,
+        msg_sk_generation_failed, MSG_BUILDING_KEY_GENERATOR_SPINNER, MSG_GENERATING_SK_SPINNER,
+        MSG_SK_ALREADY_PRESENT, MSG_SK_GENERATED,
+    },
 };
 
+/// Directory with setup keys relative to the prover workspace.
+const KEYS_PATH: &str = "data/keys";
+/// Conservative upper bound on the size of a single GPU setup key, used for the disk space preflight check.
+const MAX_SETUP_KEY_SIZE_BYTES: u64 = 3 << 30;
+
 pub(crate) async fn run(args: SetupKeysArgs, shell: &Shell) -> anyhow::Result<()> {
     let args = args.fill_values_with_prompt();
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
@@ -18,6 +31,9 @@ pub(crate) async fn run(args: SetupKeysArgs, shell: &Shell) -> anyhow::Result<()
         check_prerequisites(shell, &GPU_PREREQUISITES, false);
         let link_to_prover = get_link_to_prover(&ecosystem_config);
         shell.change_dir(&link_to_prover);
+        if !args.circuits.is_empty() {
+            return generate_selected_keys(shell, &link_to_prover, &args.circuits, args.jobs).await;
+        }
 
         let spinner = Spinner::new(MSG_GENERATING_SK_SPINNER);
         let cmd = Cmd::new(cmd!(
@@ -79,3 +95,114 @@ pub(crate) async fn run(args: SetupKeysArgs, shell: &Shell) -> anyhow::Result<()
 
     Ok(())
 }
+
+/// Generates setup keys for the specified circuits that are missing in the keystore, running up to `jobs`
+/// key generator processes in parallel.
+async fn generate_selected_keys(
+    shell: &Shell,
+    link_to_prover: &Path,
+    circuits: &[SetupKeyCircuit],
+    jobs: usize,
+) -> anyhow::Result<()> {
+    let keys_path = link_to_prover.join(KEYS_PATH);
+    let mut seen = HashSet::new();
+    let missing: Vec<_> = circuits
+        .iter()
+        .copied()
+        .filter(|&circuit| seen.insert(circuit))
+        .filter(|circuit| !shell.path_exists(keys_path.join(circuit.setup_data_file_name())))
+        .collect();
+    if missing.is_empty() {
+        logger::outro(MSG_SK_ALREADY_PRESENT);
+        return Ok(());
+    }
+
+    shell.create_dir(&keys_path)?;
+    let required = MAX_SETUP_KEY_SIZE_BYTES * missing.len() as u64;
+    let available = available_disk_space(shell, &keys_path)?;
+    anyhow::ensure!(
+        available >= required,
+        msg_not_enough_disk_space_err(&keys_path, required, available)
+    );
+
+    // Build the generator once, so that parallel jobs don't compete for the cargo lock.
+    let spinner = Spinner::new(MSG_BUILDING_KEY_GENERATOR_SPINNER);
+    Cmd::new(cmd!(
+        shell,
+        "cargo build --features gpu --release --bin key_generator"
+    ))
+    .run()?;
+    spinner.finish();
+
+    let key_generator = link_to_prover.join("target/release/key_generator");
+    let key_generator = &key_generator;
+    let results: Vec<_> = stream::iter(missing.iter().map(|&circuit| async move {
+        let result = generate_key(key_generator, link_to_prover, circuit).await;
+        (circuit, result)
+    }))
+    .buffer_unordered(jobs)
+    .collect()
+    .await;
+
+    let mut failed = vec![];
+    for (circuit, result) in results {
+        if let Err(err) = result {
+            logger::error(format!("{circuit}: {err:#}"));
+            failed.push(circuit.to_string());
+        }
+    }
+    anyhow::ensure!(failed.is_empty(), msg_sk_generation_failed(&failed));
+    logger::outro(MSG_SK_GENERATED);
+    Ok(())
+}
+
+async fn generate_key(
+    key_generator: &Path,
+    link_to_prover: &Path,
+    circuit: SetupKeyCircuit,
+) -> anyhow::Result<()> {
+    logger::step(msg_generating_sk_for_circuit(circuit));
+    let (circuits_type, numeric_circuit) = circuit.key_generator_args();
+    let output = Command::new(key_generator)
+        .arg("generate-sk-gpu")
+        .arg(circuits_type)
+        .arg(format!("--numeric-circuit={numeric_circuit}"))
+        .arg("--recompute-if-missing")
+        .arg(format!("--setup-path={KEYS_PATH}"))
+        .arg(format!(
+            "--path={}",
+            link_to_prover.join(KEYS_PATH).display()
+        ))
+        .current_dir(link_to_prover)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .context("failed running key generator")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{}: {}", output.status, stderr.trim());
+    }
+    logger::success(msg_sk_generated_for_circuit(circuit));
+    Ok(())
+}
+
+/// Returns the available disk space (in bytes) on the file system containing `path`.
+fn available_disk_space(shell: &Shell, path: &Path) -> anyhow::Result<u64> {
+    let output = Cmd::new(cmd!(shell, "df -Pk {path}")).run_with_output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "`df` failed for {}",
+        path.display()
+    );
+    let stdout = String::from_utf8(output.stdout)?;
+    // Output has a header line and a line with the file system info; the 4th column is available space in KiB.
+    let available_kib: u64 = stdout
+        .lines()
+        .nth(1)
+        .and_then(|line| line.split_whitespace().nth(3))
+        .context("unexpected `df` output")?
+        .parse()
+        .context("unexpected `df` output")?;
+    Ok(available_kib << 10)
+}
@@ -0,0 +1,299 @@
+use std::{
+    io::Write as _,
+    os::unix::process::CommandExt as _,
+    path::PathBuf,
+    process::{ExitStatus, Stdio},
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::Context;
+use common::{config::global_config, logger};
+use config::EcosystemConfig;
+use console::{style, Color};
+use serde::Serialize;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::{Child, Command},
+    sync::watch,
+    task::JoinSet,
+};
+use xshell::Shell;
+
+use crate::{
+    commands::args::{LogFormat, RunAllArgs, RunAllService},
+    messages::{
+        msg_run_all_failed_to_spawn, msg_run_all_giving_up, msg_run_all_restarting_service,
+        msg_run_all_service_exited, msg_run_all_starting_services, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_RUN_ALL_FAILED_TO_GET_CURRENT_EXE_ERR, MSG_RUN_ALL_SERVICES_STOPPED,
+        MSG_RUN_ALL_SHUTTING_DOWN, MSG_RUN_ALL_SUPERVISOR_PANICKED_ERR,
+    },
+};
+
+/// Time given to a service to shut down gracefully before it's killed.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+pub(super) const PREFIX_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
+pub async fn run(shell: &Shell, args: RunAllArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    let zkstack = std::env::current_exe().context(MSG_RUN_ALL_FAILED_TO_GET_CURRENT_EXE_ERR)?;
+
+    if args.no_color {
+        console::set_colors_enabled(false);
+    }
+
+    let mut services = Vec::with_capacity(args.services.len());
+    for &service in &args.services {
+        if !services.contains(&service) {
+            services.push(service);
+        }
+    }
+
+    let names: Vec<_> = services.iter().map(ToString::to_string).collect();
+    logger::info(msg_run_all_starting_services(&names));
+
+    let output = LogMultiplexer {
+        format: args.log_format,
+        prefix_width: names.iter().map(String::len).max().unwrap_or(0) + 2,
+    };
+    let args = Arc::new(args);
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let mut supervisors = JoinSet::new();
+    for (i, service) in services.into_iter().enumerate() {
+        let supervisor = ServiceSupervisor {
+            service,
+            color: PREFIX_COLORS[i % PREFIX_COLORS.len()],
+            zkstack: zkstack.clone(),
+            args: args.clone(),
+            output: output.clone(),
+        };
+        supervisors.spawn(supervisor.run(stop_receiver.clone()));
+    }
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut stopping = false;
+    let mut result = Ok(());
+    loop {
+        tokio::select! {
+            res = supervisors.join_next() => match res {
+                Some(res) => {
+                    if let Err(err) = res.context(MSG_RUN_ALL_SUPERVISOR_PANICKED_ERR).and_then(|res| res) {
+                        // Stop the remaining services so that they don't outlive `zkstack`.
+                        stop_sender.send_replace(true);
+                        if result.is_ok() {
+                            result = Err(err);
+                        }
+                    }
+                }
+                None => break,
+            },
+            _ = &mut ctrl_c, if !stopping => {
+                logger::info(MSG_RUN_ALL_SHUTTING_DOWN);
+                stopping = true;
+                stop_sender.send_replace(true);
+            }
+        }
+    }
+
+    logger::outro(MSG_RUN_ALL_SERVICES_STOPPED);
+    result
+}
+
+struct ServiceSupervisor {
+    service: RunAllService,
+    color: Color,
+    zkstack: PathBuf,
+    args: Arc<RunAllArgs>,
+    output: LogMultiplexer,
+}
+
+impl ServiceSupervisor {
+    async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let name = self.service.to_string();
+        let mut restarts = 0;
+        loop {
+            let mut child = self
+                .spawn()
+                .with_context(|| msg_run_all_failed_to_spawn(&name))?;
+
+            let status = tokio::select! {
+                status = child.wait() => status?,
+                _ = stop_receiver.wait_for(|&stop| stop) => {
+                    let status = terminate(&mut child).await?;
+                    self.log(&msg_run_all_service_exited(&name, status));
+                    return Ok(());
+                }
+            };
+            self.log(&msg_run_all_service_exited(&name, status));
+
+            if *stop_receiver.borrow() || !self.args.restart.should_restart(status.success()) {
+                return Ok(());
+            }
+            if restarts == self.args.max_restarts {
+                self.log(&msg_run_all_giving_up(&name, self.args.max_restarts));
+                return Ok(());
+            }
+
+            restarts += 1;
+            let delay = self.args.restart_delay(restarts);
+            self.log(&msg_run_all_restarting_service(
+                &name,
+                delay,
+                restarts,
+                self.args.max_restarts,
+            ));
+            tokio::select! {
+                () = tokio::time::sleep(delay) => {}
+                _ = stop_receiver.wait_for(|&stop| stop) => return Ok(()),
+            }
+        }
+    }
+
+    fn spawn(&self) -> anyhow::Result<Child> {
+        let mut command = std::process::Command::new(&self.zkstack);
+        command.args(self.service.zkstack_args());
+        if let Some(chain_name) = &global_config().chain_name {
+            command.args(["--chain", chain_name]);
+        }
+        if global_config().verbose {
+            command.arg("--verbose");
+        }
+        // Prerequisites were already checked by this command.
+        command.arg("--ignore-prerequisites");
+        // Each service gets its own process group, so that Ctrl+C is handled by the supervisor
+        // and the service can be stopped together with the processes it spawns.
+        command.process_group(0);
+
+        let mut child = Command::from(command)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        if let Some(stdout) = child.stdout.take() {
+            self.forward_lines(stdout, Stream::Stdout);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            self.forward_lines(stderr, Stream::Stderr);
+        }
+        Ok(child)
+    }
+
+    fn forward_lines(&self, reader: impl AsyncRead + Unpin + Send + 'static, stream: Stream) {
+        let (service, color, output) = (self.service, self.color, self.output.clone());
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(reader).split(b'\n');
+            while let Ok(Some(line)) = lines.next_segment().await {
+                let line = String::from_utf8_lossy(&line);
+                output.write(
+                    &service.to_string(),
+                    color,
+                    stream,
+                    line.trim_end_matches('\r'),
+                );
+            }
+        });
+    }
+
+    fn log(&self, line: &str) {
+        self.output.write(
+            &self.service.to_string(),
+            self.color,
+            Stream::Supervisor,
+            line,
+        );
+    }
+}
+
+/// Sends `SIGTERM` to the process group of the service, falling back to `SIGKILL`
+/// if it doesn't exit in time.
+pub(super) async fn terminate(child: &mut Child) -> anyhow::Result<ExitStatus> {
+    let Some(pid) = child.id() else {
+        // The process has already exited.
+        return Ok(child.wait().await?);
+    };
+
+    signal_process_group(pid, "TERM").await?;
+    match tokio::time::timeout(GRACEFUL_SHUTDOWN_TIMEOUT, child.wait()).await {
+        Ok(status) => Ok(status?),
+        Err(_) => {
+            signal_process_group(pid, "KILL").await?;
+            Ok(child.wait().await?)
+        }
+    }
+}
+
+async fn signal_process_group(pid: u32, signal: &str) -> anyhow::Result<()> {
+    Command::new("kill")
+        .args([format!("-{signal}"), "--".to_owned(), format!("-{pid}")])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum Stream {
+    Stdout,
+    Stderr,
+    Supervisor,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLogLine<'a> {
+    timestamp: String,
+    service: String,
+    stream: Stream,
+    line: &'a str,
+}
+
+/// Writes lines produced by the services to stdout.
+#[derive(Debug, Clone)]
+pub(super) struct LogMultiplexer {
+    pub(super) format: LogFormat,
+    pub(super) prefix_width: usize,
+}
+
+impl LogMultiplexer {
+    pub(super) fn write(&self, service: &str, color: Color, stream: Stream, line: &str) {
+        let line = match self.format {
+            LogFormat::Text => {
+                let prefix = format!(
+                    "{:<width$}",
+                    format!("[{service}]"),
+                    width = self.prefix_width
+                );
+                let prefix = style(prefix).fg(color).bold();
+                match stream {
+                    Stream::Supervisor => format!("{prefix} {}", style(line).italic()),
+                    Stream::Stdout | Stream::Stderr => format!("{prefix} {line}"),
+                }
+            }
+            LogFormat::Json => {
+                let line = console::strip_ansi_codes(line);
+                let json_line = JsonLogLine {
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                    service: service.to_owned(),
+                    stream,
+                    line: &line,
+                };
+                serde_json::to_string(&json_line).expect("failed serializing log line")
+            }
+        };
+        // A failed write to stdout shouldn't bring down the services.
+        writeln!(std::io::stdout().lock(), "{line}").ok();
+    }
+}
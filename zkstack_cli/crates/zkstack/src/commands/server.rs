@@ -12,15 +12,23 @@ use config::{
 use xshell::{cmd, Shell};
 
 use crate::{
-    commands::args::{RunServerArgs, ServerArgs, ServerCommand, WaitArgs},
+    commands::{
+        args::{RunServerArgs, ServerArgs, ServerCommand, WaitArgs},
+        multi_chain,
+    },
     messages::{
-        msg_waiting_for_server_success, MSG_BUILDING_SERVER, MSG_CHAIN_NOT_INITIALIZED,
-        MSG_FAILED_TO_BUILD_SERVER_ERR, MSG_FAILED_TO_RUN_SERVER_ERR, MSG_STARTING_SERVER,
-        MSG_WAITING_FOR_SERVER,
+        msg_server_preset_components, msg_waiting_for_server_success, MSG_BUILDING_SERVER,
+        MSG_CHAIN_NOT_INITIALIZED, MSG_FAILED_TO_BUILD_SERVER_ERR, MSG_FAILED_TO_RUN_SERVER_ERR,
+        MSG_STARTING_SERVER, MSG_WAITING_FOR_SERVER,
     },
+    utils::secrets::resolve_secret_references,
 };
 
 pub async fn run(shell: &Shell, args: ServerArgs) -> anyhow::Result<()> {
+    if args.multi_chain.is_enabled() {
+        return multi_chain::run(shell, &args.multi_chain, |_| Ok(vec![])).await;
+    }
+
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
     let chain_config = ecosystem_config
         .load_current_chain()
@@ -48,12 +56,22 @@ fn run_server(
     chain_config: &ChainConfig,
     shell: &Shell,
 ) -> anyhow::Result<()> {
+    let components = args.resolve_components(chain_config)?;
+    if let (Some(preset), Some(components)) = (&args.preset, &components) {
+        logger::info(msg_server_preset_components(preset, components));
+    }
+
+    let wallets = resolve_secret_references(
+        shell,
+        &WalletsConfig::get_path_with_base_path(&chain_config.configs),
+    )?;
+    let secrets = resolve_secret_references(
+        shell,
+        &SecretsConfig::get_path_with_base_path(&chain_config.configs),
+    )?;
+
     logger::info(MSG_STARTING_SERVER);
-    let server = Server::new(
-        args.components.clone(),
-        chain_config.link_to_code.clone(),
-        args.uring,
-    );
+    let server = Server::new(components, chain_config.link_to_code.clone(), args.uring);
 
     let mode = if args.genesis {
         ServerMode::Genesis
@@ -64,11 +82,11 @@ fn run_server(
         .run(
             shell,
             mode,
-            GenesisConfig::get_path_with_base_path(&chain_config.configs),
-            WalletsConfig::get_path_with_base_path(&chain_config.configs),
-            GeneralConfig::get_path_with_base_path(&chain_config.configs),
-            SecretsConfig::get_path_with_base_path(&chain_config.configs),
-            ContractsConfig::get_path_with_base_path(&chain_config.configs),
+            GenesisConfig::get_path_with_base_path(&chain_config.configs).as_path(),
+            wallets.path(),
+            GeneralConfig::get_path_with_base_path(&chain_config.configs).as_path(),
+            secrets.path(),
+            ContractsConfig::get_path_with_base_path(&chain_config.configs).as_path(),
             vec![],
         )
         .context(MSG_FAILED_TO_RUN_SERVER_ERR)
@@ -2,6 +2,7 @@ use std::path::Path;
 
 use anyhow::{Context, Ok};
 use common::{
+    config::global_config,
     db::migrate_db,
     git, logger,
     spinner::Spinner,
@@ -13,24 +14,71 @@ use config::{
 };
 use xshell::Shell;
 
-use super::args::UpdateArgs;
+use super::{
+    args::{UpdateArgs, UpdateCommands},
+    multi_chain,
+};
 use crate::{
     consts::{PROVER_MIGRATIONS, SERVER_MIGRATIONS},
     messages::{
         msg_diff_contracts_config, msg_diff_genesis_config, msg_diff_secrets, msg_updating_chain,
         MSG_CHAIN_NOT_FOUND_ERR, MSG_DIFF_EN_CONFIG, MSG_DIFF_EN_GENERAL_CONFIG,
-        MSG_DIFF_GENERAL_CONFIG, MSG_PULLING_ZKSYNC_CODE_SPINNER,
-        MSG_UPDATING_ERA_OBSERVABILITY_SPINNER, MSG_UPDATING_SUBMODULES_SPINNER,
-        MSG_UPDATING_ZKSYNC, MSG_ZKSYNC_UPDATED,
+        MSG_DIFF_GENERAL_CONFIG, MSG_PULLING_ZKSYNC_CODE_SPINNER, MSG_UPDATE_FAILED_ROLLBACK_HINT,
+        MSG_UPDATE_ROLLBACK_NOTE, MSG_UPDATING_ERA_OBSERVABILITY_SPINNER,
+        MSG_UPDATING_SUBMODULES_SPINNER, MSG_UPDATING_ZKSYNC, MSG_ZKSYNC_UPDATED,
     },
 };
 
+mod rollback;
+
 pub async fn run(shell: &Shell, args: UpdateArgs) -> anyhow::Result<()> {
+    if args.rollback || matches!(args.command, Some(UpdateCommands::Rollback)) {
+        return rollback::run(shell).await;
+    }
+
     logger::info(MSG_UPDATING_ZKSYNC);
     let ecosystem = EcosystemConfig::from_file(shell)?;
+    let chains = if args.multi_chain.is_enabled() {
+        // Reject an invalid selection of chains before pulling the code.
+        args.multi_chain.select_chains(&ecosystem)?
+    } else {
+        match &global_config().chain_name {
+            Some(chain) => vec![chain.clone()],
+            None => ecosystem.list_of_chains(),
+        }
+    };
+    if !args.no_rollback_point {
+        rollback::record(shell, &ecosystem, &chains).await?;
+    }
 
+    let result = update(shell, &args, &ecosystem, chains).await;
+    if result.is_err() && !args.no_rollback_point {
+        logger::warn(MSG_UPDATE_FAILED_ROLLBACK_HINT);
+    }
+    result
+}
+
+async fn update(
+    shell: &Shell,
+    args: &UpdateArgs,
+    ecosystem: &EcosystemConfig,
+    chains: Vec<String>,
+) -> anyhow::Result<()> {
     if !args.only_config {
-        update_repo(shell, &ecosystem)?;
+        update_repo(shell, ecosystem, args.to.as_deref())?;
+    }
+
+    if args.multi_chain.is_enabled() {
+        update_era_observability(shell)?;
+        // The code is pulled only once, so the chains are left to update their configs.
+        // The rollback point covering all chains is already recorded.
+        let mut chain_args = vec!["--no-rollback-point".to_owned()];
+        if !args.only_config {
+            chain_args.push("--only-config".to_owned());
+        }
+        multi_chain::run(shell, &args.multi_chain, |_| Ok(chain_args.clone())).await?;
+        logger::info(MSG_UPDATE_ROLLBACK_NOTE);
+        return Ok(());
     }
 
     let general_config_path = ecosystem.get_default_configs_path().join(GENERAL_FILE);
@@ -39,7 +87,7 @@ pub async fn run(shell: &Shell, args: UpdateArgs) -> anyhow::Result<()> {
     let contracts_config_path = ecosystem.get_default_configs_path().join(CONTRACTS_FILE);
     let secrets_path = ecosystem.get_default_configs_path().join(SECRETS_FILE);
 
-    for chain in ecosystem.list_of_chains() {
+    for chain in chains {
         logger::step(msg_updating_chain(&chain));
         let chain = ecosystem
             .load_chain(Some(chain))
@@ -56,6 +104,20 @@ pub async fn run(shell: &Shell, args: UpdateArgs) -> anyhow::Result<()> {
         .await?;
     }
 
+    // Observability is shared by the whole ecosystem, so it's skipped when updating a single chain.
+    if global_config().chain_name.is_none() {
+        update_era_observability(shell)?;
+    }
+
+    if !args.no_rollback_point {
+        logger::info(MSG_UPDATE_ROLLBACK_NOTE);
+    }
+    logger::outro(MSG_ZKSYNC_UPDATED);
+
+    Ok(())
+}
+
+fn update_era_observability(shell: &Shell) -> anyhow::Result<()> {
     let path_to_era_observability = shell.current_dir().join(ERA_OBSERBAVILITY_DIR);
     if shell.path_exists(path_to_era_observability.clone()) {
         let spinner = Spinner::new(MSG_UPDATING_ERA_OBSERVABILITY_SPINNER);
@@ -63,16 +125,17 @@ pub async fn run(shell: &Shell, args: UpdateArgs) -> anyhow::Result<()> {
         spinner.finish();
     }
 
-    logger::outro(MSG_ZKSYNC_UPDATED);
-
     Ok(())
 }
 
-fn update_repo(shell: &Shell, ecosystem: &EcosystemConfig) -> anyhow::Result<()> {
+fn update_repo(shell: &Shell, ecosystem: &EcosystemConfig, to: Option<&str>) -> anyhow::Result<()> {
     let link_to_code = ecosystem.link_to_code.clone();
 
     let spinner = Spinner::new(MSG_PULLING_ZKSYNC_CODE_SPINNER);
-    git::pull(shell, link_to_code.clone())?;
+    match to {
+        Some(git_ref) => git::checkout(shell, link_to_code.clone(), git_ref)?,
+        None => git::pull(shell, link_to_code.clone())?,
+    }
     spinner.finish();
     let spinner = Spinner::new(MSG_UPDATING_SUBMODULES_SPINNER);
     git::submodule_update(shell, link_to_code.clone())?;
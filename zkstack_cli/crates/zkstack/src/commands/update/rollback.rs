@@ -0,0 +1,350 @@
+//! Rollback points recorded before updating, and restoring them with `zkstack update rollback`.
+//!
+//! Rolling back reverts DB migrations and config fields added by the update, and restores the code revision
+//! together with the binaries built from it. It's refused if the update has applied irreversible DB migrations.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use chrono::Utc;
+use common::{
+    cmd::Cmd,
+    db::{irreversible_migrations, latest_migration_version, revert_migrations},
+    files::{read_json_file, save_json_file},
+    git, logger,
+    spinner::Spinner,
+    yaml::remove_new_fields,
+};
+use config::{ChainConfig, EcosystemConfig, SecretsConfig};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use xshell::{cmd, Shell};
+
+use crate::{
+    consts::{PROVER_MIGRATIONS, SERVER_MIGRATIONS},
+    messages::{
+        msg_update_irreversible_migrations, msg_update_reverted_config_fields,
+        msg_update_reverted_migrations, msg_update_rollback_completed,
+        msg_update_rollback_point_recorded, msg_update_rolling_back_chain, MSG_CHAIN_NOT_FOUND_ERR,
+        MSG_UPDATE_NO_ROLLBACK_POINT_ERR, MSG_UPDATE_RECORDING_ROLLBACK_POINT_SPINNER,
+        MSG_UPDATE_RESTORING_BINARIES_SPINNER, MSG_UPDATE_RESTORING_CODE_SPINNER,
+        MSG_UPDATING_SUBMODULES_SPINNER,
+    },
+};
+
+/// Directory in the ecosystem containing the rollback point.
+const ROLLBACK_DIR: &str = ".update_rollback";
+/// Name of the manifest file in the rollback directory.
+const MANIFEST_FILE: &str = "rollback.json";
+/// Directory in the rollback directory containing chain configs.
+const CHAINS_DIR: &str = "chains";
+const CONFIGS_DIR: &str = "configs";
+const EXTERNAL_NODE_CONFIGS_DIR: &str = "external_node";
+/// Directory in the rollback directory containing binaries built from the recorded revision.
+const BINARIES_DIR: &str = "bin";
+/// Directory in the `zksync-era` repo containing release binaries.
+const RELEASE_BINARIES_DIR: &str = "target/release";
+/// Binaries restored on rollback, so that they match the restored code even if they are run without rebuilding.
+const BINARIES: &[&str] = &[
+    "zksync_server",
+    "zksync_external_node",
+    "zksync_contract_verifier",
+];
+
+/// State of the ecosystem before an update.
+#[derive(Debug, Serialize, Deserialize)]
+struct RollbackPoint {
+    /// Creation time in the RFC 3339 format.
+    created_at: String,
+    /// Checked out commit of the `zksync-era` repo.
+    revision: String,
+    /// Checked out branch; `None` if HEAD was detached.
+    branch: Option<String>,
+    /// Names of the binaries copied from the `zksync-era` repo.
+    #[serde(default)]
+    binaries: Vec<String>,
+    chains: Vec<ChainRollbackPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ChainRollbackPoint {
+    name: String,
+    /// Version of the latest applied server DB migration.
+    server_migration: Option<i64>,
+    /// Version of the latest applied prover DB migration.
+    prover_migration: Option<i64>,
+    external_node_configs: bool,
+}
+
+fn rollback_dir(shell: &Shell) -> PathBuf {
+    shell.current_dir().join(ROLLBACK_DIR)
+}
+
+fn chain_dir(shell: &Shell, chain_name: &str) -> PathBuf {
+    rollback_dir(shell).join(CHAINS_DIR).join(chain_name)
+}
+
+fn copy_dir(shell: &Shell, from: &Path, to: &Path) -> anyhow::Result<()> {
+    Cmd::new(cmd!(shell, "cp -R {from} {to}")).run()?;
+    Ok(())
+}
+
+/// Copies release binaries built from the checked out revision to `to`. Returns the names of the copied binaries.
+fn copy_binaries(shell: &Shell, link_to_code: &Path, to: &Path) -> anyhow::Result<Vec<String>> {
+    let release_dir = link_to_code.join(RELEASE_BINARIES_DIR);
+    let mut binaries = vec![];
+    for &name in BINARIES {
+        let path = release_dir.join(name);
+        if shell.path_exists(&path) {
+            shell.copy_file(&path, to)?;
+            binaries.push(name.to_owned());
+        }
+    }
+    Ok(binaries)
+}
+
+/// Returns server and prover DB URLs of the chain.
+fn database_urls(secrets: &SecretsConfig) -> (Option<Url>, Option<Url>) {
+    let Some(database) = &secrets.database else {
+        return (None, None);
+    };
+    let server_url = database
+        .server_url
+        .as_ref()
+        .map(|url| url.expose_url().clone());
+    let prover_url = database
+        .prover_url
+        .as_ref()
+        .map(|url| url.expose_url().clone());
+    (server_url, prover_url)
+}
+
+/// Returns databases of the chain with the migration versions recorded in the rollback point, together with
+/// the migrations folder. Databases without applied migrations at the rollback point were created by the update,
+/// so they are left intact.
+fn chain_databases(
+    chain: &ChainConfig,
+    point: &ChainRollbackPoint,
+) -> anyhow::Result<Vec<(Url, i64, PathBuf)>> {
+    let (server_url, prover_url) = database_urls(&chain.get_secrets_config()?);
+    let databases = [
+        (server_url, point.server_migration, SERVER_MIGRATIONS),
+        (prover_url, point.prover_migration, PROVER_MIGRATIONS),
+    ];
+    Ok(databases
+        .into_iter()
+        .filter_map(|(url, target_version, migrations)| {
+            Some((url?, target_version?, chain.link_to_code.join(migrations)))
+        })
+        .collect())
+}
+
+async fn migration_version(url: Option<&Url>) -> anyhow::Result<Option<i64>> {
+    match url {
+        Some(url) => latest_migration_version(url).await,
+        None => Ok(None),
+    }
+}
+
+/// Records the checked out revision, chain configs and DB migration levels, replacing the previous rollback point.
+pub(super) async fn record(
+    shell: &Shell,
+    ecosystem: &EcosystemConfig,
+    chains: &[String],
+) -> anyhow::Result<()> {
+    let spinner = Spinner::new(MSG_UPDATE_RECORDING_ROLLBACK_POINT_SPINNER);
+    let (revision, branch) = git::head(shell, ecosystem.link_to_code.clone())?;
+    let rollback_dir = rollback_dir(shell);
+    shell.remove_path(&rollback_dir)?;
+    let binaries_dir = rollback_dir.join(BINARIES_DIR);
+    shell.create_dir(&binaries_dir)?;
+    let binaries = copy_binaries(shell, &ecosystem.link_to_code, &binaries_dir)?;
+
+    let mut chain_points = Vec::with_capacity(chains.len());
+    for name in chains {
+        let chain = ecosystem
+            .load_chain(Some(name.clone()))
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        let (server_url, prover_url) = database_urls(&chain.get_secrets_config()?);
+        let server_migration = migration_version(server_url.as_ref())
+            .await
+            .context("failed querying server DB migrations")?;
+        let prover_migration = migration_version(prover_url.as_ref())
+            .await
+            .context("failed querying prover DB migrations")?;
+
+        let chain_dir = chain_dir(shell, name);
+        shell.create_dir(&chain_dir)?;
+        copy_dir(shell, &chain.configs, &chain_dir.join(CONFIGS_DIR))?;
+        let external_node_configs = match &chain.external_node_config_path {
+            Some(path) if shell.path_exists(path) => {
+                copy_dir(shell, path, &chain_dir.join(EXTERNAL_NODE_CONFIGS_DIR))?;
+                true
+            }
+            _ => false,
+        };
+        chain_points.push(ChainRollbackPoint {
+            name: name.clone(),
+            server_migration,
+            prover_migration,
+            external_node_configs,
+        });
+    }
+
+    let point = RollbackPoint {
+        created_at: Utc::now().to_rfc3339(),
+        revision,
+        branch,
+        binaries,
+        chains: chain_points,
+    };
+    save_json_file(shell, rollback_dir.join(MANIFEST_FILE), &point)?;
+    spinner.finish();
+    logger::info(msg_update_rollback_point_recorded(&point.revision));
+    Ok(())
+}
+
+/// Checks that all DB migrations applied after the rollback point can be reverted. Must be called before
+/// changing anything, so that a refused rollback doesn't leave the chain in a partially reverted state.
+async fn check_chain_migrations(
+    shell: &Shell,
+    chain: &ChainConfig,
+    point: &ChainRollbackPoint,
+) -> anyhow::Result<()> {
+    for (url, target_version, migrations) in chain_databases(chain, point)? {
+        let versions = irreversible_migrations(shell, migrations, &url, target_version).await?;
+        if !versions.is_empty() {
+            anyhow::bail!(msg_update_irreversible_migrations(
+                &point.name,
+                url.path().trim_start_matches('/'),
+                &versions,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reverts DB migrations applied after the rollback point. Must be called before restoring the code,
+/// since down migrations of the new version are used.
+async fn revert_chain_migrations(
+    shell: &Shell,
+    chain: &ChainConfig,
+    point: &ChainRollbackPoint,
+) -> anyhow::Result<()> {
+    for (url, target_version, migrations) in chain_databases(chain, point)? {
+        let count = revert_migrations(shell, migrations, &url, target_version).await?;
+        logger::step(msg_update_reverted_migrations(
+            count,
+            url.path().trim_start_matches('/'),
+        ));
+    }
+    Ok(())
+}
+
+/// Reverts config migrations applied by the update, i.e., removes the fields added to YAML configs after
+/// the rollback point. Values changed since the update are preserved. Other files, and files removed since
+/// the update, are restored from the rollback point.
+fn revert_config_dir(shell: &Shell, recorded_dir: &Path, dir: &Path) -> anyhow::Result<()> {
+    for recorded_path in shell.read_dir(recorded_dir)? {
+        let file_name = recorded_path
+            .file_name()
+            .context("recorded config path has no file name")?;
+        let path = dir.join(file_name);
+        if recorded_path.is_dir() {
+            shell.create_dir(&path)?;
+            revert_config_dir(shell, &recorded_path, &path)?;
+            continue;
+        }
+
+        let is_yaml = recorded_path
+            .extension()
+            .is_some_and(|ext| ext == "yaml" || ext == "yml");
+        if !is_yaml || !shell.path_exists(&path) {
+            shell.copy_file(&recorded_path, &path)?;
+            continue;
+        }
+        let recorded_config: serde_yaml::Value =
+            serde_yaml::from_str(&shell.read_file(&recorded_path)?)?;
+        let mut config: serde_yaml::Value = serde_yaml::from_str(&shell.read_file(&path)?)?;
+        let removed = remove_new_fields(&mut config, &recorded_config)
+            .with_context(|| format!("failed reverting config {path:?}"))?;
+        if !removed.is_empty() {
+            logger::info(msg_update_reverted_config_fields(&path));
+            logger::info(logger::object_to_string(&removed));
+            shell.write_file(&path, serde_yaml::to_string(&config)?)?;
+        }
+    }
+    Ok(())
+}
+
+fn revert_chain_configs(
+    shell: &Shell,
+    chain: &ChainConfig,
+    point: &ChainRollbackPoint,
+) -> anyhow::Result<()> {
+    let chain_dir = chain_dir(shell, &point.name);
+    revert_config_dir(shell, &chain_dir.join(CONFIGS_DIR), &chain.configs)?;
+    if let (true, Some(path)) = (
+        point.external_node_configs,
+        &chain.external_node_config_path,
+    ) {
+        shell.create_dir(path)?;
+        revert_config_dir(shell, &chain_dir.join(EXTERNAL_NODE_CONFIGS_DIR), path)?;
+    }
+    Ok(())
+}
+
+pub(super) async fn run(shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    let manifest_path = rollback_dir(shell).join(MANIFEST_FILE);
+    if !shell.path_exists(&manifest_path) {
+        anyhow::bail!(MSG_UPDATE_NO_ROLLBACK_POINT_ERR);
+    }
+    let point: RollbackPoint = read_json_file(shell, &manifest_path)?;
+
+    let mut chains = Vec::with_capacity(point.chains.len());
+    for chain_point in &point.chains {
+        let chain = ecosystem
+            .load_chain(Some(chain_point.name.clone()))
+            .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+        check_chain_migrations(shell, &chain, chain_point).await?;
+        chains.push(chain);
+    }
+    for (chain, chain_point) in chains.iter().zip(&point.chains) {
+        logger::step(msg_update_rolling_back_chain(&chain_point.name));
+        revert_chain_migrations(shell, chain, chain_point).await?;
+    }
+
+    let spinner = Spinner::new(MSG_UPDATE_RESTORING_CODE_SPINNER);
+    git::checkout_revision(
+        shell,
+        ecosystem.link_to_code.clone(),
+        &point.revision,
+        point.branch.as_deref(),
+    )?;
+    spinner.finish();
+    let spinner = Spinner::new(MSG_UPDATING_SUBMODULES_SPINNER);
+    git::submodule_update(shell, ecosystem.link_to_code.clone())?;
+    spinner.finish();
+
+    if !point.binaries.is_empty() {
+        let spinner = Spinner::new(MSG_UPDATE_RESTORING_BINARIES_SPINNER);
+        let release_dir = ecosystem.link_to_code.join(RELEASE_BINARIES_DIR);
+        shell.create_dir(&release_dir)?;
+        let binaries_dir = rollback_dir(shell).join(BINARIES_DIR);
+        for name in &point.binaries {
+            shell.copy_file(binaries_dir.join(name), &release_dir)?;
+        }
+        spinner.finish();
+    }
+
+    for (chain, chain_point) in chains.iter().zip(&point.chains) {
+        revert_chain_configs(shell, chain, chain_point)?;
+    }
+
+    logger::outro(msg_update_rollback_completed(
+        &point.revision,
+        &point.created_at,
+    ));
+    Ok(())
+}
@@ -16,6 +16,12 @@ pub const EXPLORER_APP_DOCKER_IMAGE: &str = "matterlabs/block-explorer-app";
 /// Path to the JS runtime config for the dapp-portal docker container to be mounted to
 pub const PORTAL_DOCKER_CONFIG_PATH: &str = "/usr/src/app/dist/config.js";
 pub const PORTAL_DOCKER_IMAGE: &str = "matterlabs/dapp-portal";
+/// Reverse proxy exposing the chain RPC: nginx with automatic Let's Encrypt certificates
+pub const EXPOSE_PROXY_DOCKER_IMAGE: &str = "jonasal/nginx-certbot:5";
+/// Directory with nginx server configs in the reverse proxy docker container
+pub const EXPOSE_PROXY_DOCKER_CONFIG_DIR: &str = "/etc/nginx/user_conf.d";
+/// Directory with issued certificates in the reverse proxy docker container
+pub const EXPOSE_PROXY_DOCKER_CERTS_DIR: &str = "/etc/letsencrypt";
 
 pub const PROVER_GATEWAY_DOCKER_IMAGE: &str = "matterlabs/prover-fri-gateway";
 pub const WITNESS_GENERATOR_DOCKER_IMAGE: &str = "matterlabs/witness-generator";
@@ -36,3 +42,4 @@ pub const PROVER_JOB_MONITOR_BINARY_NAME: &str = "zksync_prover_job_monitor";
 pub const PATH_TO_ONLY_REAL_PROOFS_OVERRIDE_CONFIG: &str =
     "etc/env/file_based/overrides/only_real_proofs.yaml";
 pub const PATH_TO_VALIDIUM_OVERRIDE_CONFIG: &str = "etc/env/file_based/overrides/validium.yaml";
+pub const PATH_TO_ROLLUP_OVERRIDE_CONFIG: &str = "etc/env/file_based/overrides/rollup.yaml";
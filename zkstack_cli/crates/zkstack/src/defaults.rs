@@ -1,5 +1,6 @@
 use config::ChainConfig;
 use lazy_static::lazy_static;
+use types::L1Network;
 use url::Url;
 
 lazy_static! {
@@ -25,6 +26,12 @@ pub const EN_ROCKS_DB_PREFIX: &str = "en";
 pub const MAIN_ROCKS_DB_PREFIX: &str = "main";
 
 pub const L2_CHAIN_ID: u32 = 271;
+/// Default timeout of Avail bridge API requests
+pub const AVAIL_DEFAULT_TIMEOUT_MS: usize = 10_000;
+/// Default timeout of Celestia light node requests
+pub const CELESTIA_DEFAULT_TIMEOUT_MS: u64 = 30_000;
+/// Default interval between EigenDA blob inclusion checks
+pub const EIGEN_DEFAULT_INCLUSION_POLLING_INTERVAL_MS: u64 = 1_000;
 /// Path to base chain configuration inside zksync-era
 /// Local RPC url
 pub(super) const LOCAL_RPC_URL: &str = "http://127.0.0.1:8545";
@@ -49,11 +56,11 @@ pub fn generate_db_names(config: &ChainConfig) -> DBNames {
     }
 }
 
-pub fn generate_explorer_db_name(config: &ChainConfig) -> String {
+pub fn generate_explorer_db_name(l1_network: L1Network, chain_name: &str) -> String {
     format!(
         "zksync_explorer_{}_{}",
-        config.l1_network.to_string().to_ascii_lowercase(),
-        config.name
+        l1_network.to_string().to_ascii_lowercase(),
+        chain_name
     )
 }
 
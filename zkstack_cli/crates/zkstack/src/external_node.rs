@@ -8,7 +8,7 @@ use config::{
 use xshell::Shell;
 use zksync_config::configs::consensus::ConsensusConfig;
 
-use crate::messages::MSG_FAILED_TO_RUN_SERVER_ERR;
+use crate::{messages::MSG_FAILED_TO_RUN_SERVER_ERR, utils::secrets::resolve_secret_references};
 
 pub struct RunExternalNode {
     components: Option<Vec<String>>,
@@ -52,7 +52,8 @@ impl RunExternalNode {
         let code_path = self.code_path.to_str().unwrap();
         let config_general_config = &self.general_config.to_str().unwrap();
         let en_config = &self.en_config.to_str().unwrap();
-        let secrets = &self.secrets.to_str().unwrap();
+        let resolved_secrets = resolve_secret_references(shell, &self.secrets)?;
+        let secrets = resolved_secrets.path().to_str().unwrap();
         let consensus_config = &self.consensus_config.to_str().unwrap();
         if let Some(components) = self.components() {
             additional_args.push(format!("--components={}", components))
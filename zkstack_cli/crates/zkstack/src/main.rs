@@ -1,12 +1,17 @@
-use clap::{command, Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{command, CommandFactory, Parser, Subcommand};
 use commands::{
-    args::{AutocompleteArgs, ContainersArgs, UpdateArgs},
+    args::{
+        args_with_answers, AutocompleteArgs, ContainersArgs, PortalArgs, RunAllArgs, UpdateArgs,
+    },
     contract_verifier::ContractVerifierCommands,
     dev::DevCommands,
 };
 use common::{
     check_general_prerequisites,
-    config::{global_config, init_global_config, GlobalConfig},
+    config::{global_config, init_global_config, GlobalConfig, OutputFormat},
+    disable_prompts, docker,
     error::log_error,
     init_prompt_theme, logger,
     version::version_message,
@@ -16,7 +21,8 @@ use xshell::Shell;
 
 use crate::commands::{
     args::ServerArgs, chain::ChainCommands, consensus, ecosystem::EcosystemCommands,
-    explorer::ExplorerCommands, external_node::ExternalNodeCommands, prover::ProverCommands,
+    explorer::ExplorerCommands, external_node::ExternalNodeCommands, interop::InteropCommands,
+    prover::ProverCommands,
 };
 
 pub mod accept_ownership;
@@ -61,6 +67,8 @@ pub enum ZkStackSubcommands {
     /// External Node related commands
     #[command(subcommand, alias = "en")]
     ExternalNode(ExternalNodeCommands),
+    /// Run multiple long-lived services as supervised child processes with multiplexed logs
+    RunAll(RunAllArgs),
     /// Run containers for local development
     #[command(alias = "up")]
     Containers(ContainersArgs),
@@ -68,16 +76,21 @@ pub enum ZkStackSubcommands {
     #[command(subcommand)]
     ContractVerifier(ContractVerifierCommands),
     /// Run dapp-portal
-    Portal,
+    Portal(PortalArgs),
     /// Run block-explorer
     #[command(subcommand)]
     Explorer(ExplorerCommands),
     /// Consensus utilities
     #[command(subcommand)]
     Consensus(consensus::Command),
+    /// Cross-chain interop utilities
+    #[command(subcommand)]
+    Interop(InteropCommands),
     /// Update ZKsync
     #[command(alias = "u")]
     Update(UpdateArgs),
+    /// Check the local setup and print a report with remediation hints
+    Doctor,
     /// Print markdown help
     #[command(hide = true)]
     Markdown,
@@ -95,6 +108,13 @@ struct ZkStackGlobalArgs {
     /// Ignores prerequisites checks
     #[clap(long, global = true)]
     ignore_prerequisites: bool,
+    /// Output format; with `json`, supported commands print their result to stdout as JSON
+    #[clap(long, global = true, value_enum, default_value_t)]
+    output: OutputFormat,
+    /// Instead of broadcasting L1 transactions, append them to the unsigned transactions bundle in this
+    /// directory for offline signing
+    #[clap(long, global = true)]
+    unsigned_txs_dir: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -103,7 +123,19 @@ async fn main() -> anyhow::Result<()> {
 
     // We must parse arguments before printing the intro, because some autogenerated
     // Clap commands (like `--version` would look odd otherwise).
-    let zkstack_args = ZkStack::parse();
+    let mut args: Vec<_> = std::env::args_os().collect();
+    match args_with_answers(ZkStack::command(), &args) {
+        Ok(Some(args_with_answers)) => {
+            args = args_with_answers;
+            disable_prompts();
+        }
+        Ok(None) => {}
+        Err(error) => {
+            log_error(error);
+            std::process::exit(1);
+        }
+    }
+    let zkstack_args = ZkStack::parse_from(args);
 
     match run_subcommand(zkstack_args).await {
         Ok(_) => {}
@@ -126,7 +158,9 @@ async fn run_subcommand(zkstack_args: ZkStack) -> anyhow::Result<()> {
 
     init_global_config_inner(&shell, &zkstack_args.global)?;
 
-    if !global_config().ignore_prerequisites {
+    // Doctor reports missing prerequisites itself instead of exiting early.
+    let is_doctor = matches!(zkstack_args.command, ZkStackSubcommands::Doctor);
+    if !global_config().ignore_prerequisites && !is_doctor {
         check_general_prerequisites(&shell);
     }
 
@@ -137,6 +171,7 @@ async fn run_subcommand(zkstack_args: ZkStack) -> anyhow::Result<()> {
         ZkStackSubcommands::Dev(args) => commands::dev::run(&shell, args).await?,
         ZkStackSubcommands::Prover(args) => commands::prover::run(&shell, args).await?,
         ZkStackSubcommands::Server(args) => commands::server::run(&shell, args).await?,
+        ZkStackSubcommands::RunAll(args) => commands::run_all::run(&shell, args).await?,
         ZkStackSubcommands::Containers(args) => commands::containers::run(&shell, args)?,
         ZkStackSubcommands::ExternalNode(args) => {
             commands::external_node::run(&shell, args).await?
@@ -146,8 +181,10 @@ async fn run_subcommand(zkstack_args: ZkStack) -> anyhow::Result<()> {
         }
         ZkStackSubcommands::Explorer(args) => commands::explorer::run(&shell, args).await?,
         ZkStackSubcommands::Consensus(cmd) => cmd.run(&shell).await?,
-        ZkStackSubcommands::Portal => commands::portal::run(&shell).await?,
+        ZkStackSubcommands::Interop(args) => commands::interop::run(&shell, args).await?,
+        ZkStackSubcommands::Portal(args) => commands::portal::run(&shell, args).await?,
         ZkStackSubcommands::Update(args) => commands::update::run(&shell, args).await?,
+        ZkStackSubcommands::Doctor => commands::doctor::run(&shell).await?,
         ZkStackSubcommands::Markdown => {
             clap_markdown::print_help_markdown::<ZkStack>();
         }
@@ -172,6 +209,15 @@ fn init_global_config_inner(shell: &Shell, zkstack_args: &ZkStackGlobalArgs) ->
         verbose: zkstack_args.verbose,
         chain_name: zkstack_args.chain.clone(),
         ignore_prerequisites: zkstack_args.ignore_prerequisites,
+        output: zkstack_args.output,
+        unsigned_txs_dir: zkstack_args.unsigned_txs_dir.clone(),
     });
+
+    // A separate shell is used, so that the working directory isn't changed for commands running outside the ecosystem.
+    if let Ok(config) = EcosystemConfig::from_file(&Shell::new()?) {
+        if let Some(runtime) = config.container_runtime {
+            docker::set_runtime(runtime);
+        }
+    }
     Ok(())
 }
@@ -1,10 +1,14 @@
 use std::{fmt, path::Path, time::Duration};
 
+use common::docker::ContainerRuntime;
 use ethers::{
     types::{Address, H160, U256},
     utils::format_ether,
 };
+use serde_json::Value;
+use types::L1BatchCommitmentMode;
 use url::Url;
+use zksync_basic_types::{L1BatchNumber, H256};
 use zksync_consensus_roles::attester;
 
 pub(super) const MSG_SETUP_KEYS_DOWNLOAD_SELECTION_PROMPT: &str =
@@ -75,6 +79,24 @@ pub(super) const MSG_ECOSYSTEM_CONTRACTS_PATH_PROMPT: &str = "Provide the path t
 For using this config, you need to have governance wallet";
 pub(super) const MSG_L1_RPC_URL_INVALID_ERR: &str = "Invalid RPC URL";
 pub(super) const MSG_ECOSYSTEM_CONTRACTS_PATH_INVALID_ERR: &str = "Invalid path";
+pub(super) const MSG_INVALID_URL_ERR: &str = "Invalid URL";
+pub(super) const MSG_VALIDIUM_TYPE_HELP: &str = "DA layer of the validium chain";
+pub(super) const MSG_ECOSYSTEM_VALIDIUM_TYPE_HELP: &str =
+    "DA layer of validium chains, either for all chains \
+(e.g., avail) or for a single chain (e.g., my_chain=no-da). Can be specified multiple times";
+pub(super) const MSG_AVAIL_API_NODE_URL_HELP: &str = "URL of the Avail API node";
+pub(super) const MSG_AVAIL_APP_ID_HELP: &str = "Avail application ID of the chain";
+pub(super) const MSG_AVAIL_BRIDGE_API_URL_HELP: &str = "URL of the Avail bridge API";
+pub(super) const MSG_AVAIL_SEED_PHRASE_HELP: &str = "Seed phrase of the Avail account";
+pub(super) const MSG_VALIDIUM_L1_DA_VALIDATOR_HELP: &str =
+    "Address of the L1 DA validator contract; required for Avail";
+pub(super) const MSG_VALIDIUM_L2_DA_VALIDATOR_HELP: &str =
+    "Address of the L2 DA validator contract; required for Avail";
+pub(super) const MSG_AVAIL_API_NODE_URL_PROMPT: &str = "What is the URL of the Avail API node?";
+pub(super) const MSG_AVAIL_APP_ID_PROMPT: &str = "What is the Avail application ID of the chain?";
+pub(super) const MSG_AVAIL_BRIDGE_API_URL_PROMPT: &str = "What is the URL of the Avail bridge API?";
+pub(super) const MSG_AVAIL_SEED_PHRASE_PROMPT: &str =
+    "What is the seed phrase of the Avail account?";
 pub(super) const MSG_GENESIS_DATABASE_ERR: &str = "Unable to perform genesis on the database";
 pub(super) const MSG_CHAIN_NOT_FOUND_ERR: &str = "Chain not found";
 pub(super) const MSG_INITIALIZING_ECOSYSTEM: &str = "Initializing ecosystem";
@@ -111,6 +133,18 @@ pub(super) fn msg_initializing_chain(chain_name: &str) -> String {
     format!("Initializing chain {chain_name}")
 }
 
+pub(super) fn msg_validium_type_prompt(chain_name: &str) -> String {
+    format!("Select the DA layer of validium chain {chain_name}")
+}
+
+pub(super) fn msg_validium_type_for_unknown_chain_err(chain_name: &str) -> String {
+    format!("Validium type is specified for chain {chain_name}, which is not initialized")
+}
+
+pub(super) fn msg_validium_type_ignored_for_rollup(chain_name: &str) -> String {
+    format!("Chain {chain_name} is a rollup; the specified validium type is ignored")
+}
+
 pub(super) fn msg_ecosystem_initialized(chains: &str) -> String {
     if chains.is_empty() {
         "Ecosystem initialized successfully. You can initialize chain with `chain init`".to_string()
@@ -147,6 +181,91 @@ pub(super) const MSG_WRITING_OUTPUT_FILES_SPINNER: &str = "Writing output files.
 pub(super) const MSG_ECOSYSTEM_TXN_OUTRO: &str = "Transactions successfully built";
 pub(super) const MSG_ECOSYSTEM_TXN_OUT_PATH_INVALID_ERR: &str = "Invalid path";
 
+/// Offline signing related messages
+pub(super) const MSG_SIGNER_PRIVATE_KEY_PROMPT: &str = "What is the private key of the signer?";
+pub(super) const MSG_SIGNER_PRIVATE_KEY_INVALID_ERR: &str = "Invalid private key";
+pub(super) const MSG_BUNDLE_SIGNED: &str = "All transactions in the bundle are signed";
+
+pub(super) fn msg_no_transactions_from_signer_err(address: Address) -> String {
+    format!("Bundle has no transactions sent from {address:#x}")
+}
+
+pub(super) fn msg_signed_transactions(count: usize, address: Address) -> String {
+    format!("Signed {count} transaction(s) sent from {address:#x}")
+}
+
+pub(super) fn msg_bundle_awaits_signatures(senders: &[Address]) -> String {
+    let senders: Vec<_> = senders
+        .iter()
+        .map(|address| format!("{address:#x}"))
+        .collect();
+    format!(
+        "Bundle has unsigned transactions sent from {}",
+        senders.join(", ")
+    )
+}
+
+pub(super) fn msg_bundle_chain_id_mismatch_err(
+    index: usize,
+    tx_chain_id: u64,
+    l1_chain_id: u64,
+) -> String {
+    format!(
+        "Transaction #{index} is built for chain {tx_chain_id}, but L1 chain ID is {l1_chain_id}"
+    )
+}
+
+pub(super) fn msg_broadcasting_transaction(
+    index: usize,
+    total: usize,
+    description: &str,
+) -> String {
+    format!("Broadcasting transaction #{index} of {total}: {description}...")
+}
+
+pub(super) fn msg_bundled_transaction_failed_err(index: usize, hash: H256) -> String {
+    format!("Transaction #{index} ({hash:?}) failed")
+}
+
+pub(super) fn msg_bundle_broadcast(count: usize) -> String {
+    format!("Broadcast {count} transaction(s)")
+}
+
+pub(super) const MSG_UNSIGNED_TXS_UNSUPPORTED_ERR: &str =
+    "This command sends L1 transactions directly and doesn't support building unsigned transactions";
+
+/// Ecosystem teardown related messages
+pub(super) const MSG_TEARDOWN_WIPE_L1_HELP: &str =
+    "Remove the L1 (reth) data volume as well, resetting the L1 state";
+pub(super) const MSG_TEARDOWN_YES_HELP: &str = "Do not ask for confirmation";
+pub(super) const MSG_TEARDOWN_ABORTED: &str = "Ecosystem teardown aborted";
+pub(super) const MSG_TEARDOWN_REMOVING_ARTIFACTS: &str = "Removing deployment artifacts";
+pub(super) const MSG_TEARDOWN_STOPPING_CONTAINERS: &str = "Stopping containers";
+pub(super) const MSG_TEARDOWN_WIPING_L1: &str = "Removing L1 data volume";
+pub(super) const MSG_TEARDOWN_FAILED_ERR: &str =
+    "Ecosystem teardown finished with errors, see the warnings above";
+
+pub(super) fn msg_teardown_confirm_prompt(ecosystem_name: &str, wipe_l1: bool) -> String {
+    let l1_note = if wipe_l1 { " and the L1 state" } else { "" };
+    format!(
+        "This will drop databases and deployment artifacts of all chains in ecosystem `{ecosystem_name}`{l1_note}. Continue?"
+    )
+}
+
+pub(super) fn msg_teardown_dropping_database(chain_name: &str, db_name: &str) -> String {
+    format!("Dropping database `{db_name}` of chain `{chain_name}`")
+}
+
+pub(super) fn msg_teardown_chain_err(chain_name: &str, err: &anyhow::Error) -> String {
+    format!("Failed tearing down chain `{chain_name}`: {err:#}")
+}
+
+pub(super) fn msg_ecosystem_torn_down(ecosystem_name: &str) -> String {
+    format!(
+        "Ecosystem `{ecosystem_name}` was torn down; run `zkstack ecosystem init` to initialize it again"
+    )
+}
+
 /// Chain create related messages
 pub(super) const MSG_PROVER_MODE_HELP: &str = "Prover options";
 pub(super) const MSG_CHAIN_ID_HELP: &str = "Chain ID";
@@ -209,6 +328,68 @@ pub(super) const MSG_FAILED_TO_DROP_SERVER_DATABASE_ERR: &str = "Failed to drop
 pub(super) const MSG_INITIALIZING_PROVER_DATABASE: &str = "Initializing prover database";
 pub(super) const MSG_FAILED_TO_DROP_PROVER_DATABASE_ERR: &str = "Failed to drop prover database";
 pub(super) const MSG_GENESIS_DATABASES_INITIALIZED: &str = "Databases initialized successfully";
+pub(super) const MSG_GENESIS_MULTI_CHAIN_DB_NAME_ERR: &str =
+    "`--server-db-name` can't be used for multiple chains, each chain uses its default database name";
+pub(super) const MSG_GENESIS_MULTI_CHAIN_DB_URL_ERR: &str =
+    "Running genesis for multiple chains requires either `--dev` or `--server-db-url`";
+pub(super) const MSG_GENESIS_EXPORT_OUTPUT_HELP: &str =
+    "Path to the created bundle. Defaults to `<chain>-genesis.json` in the current directory";
+pub(super) const MSG_GENESIS_IMPORT_BUNDLE_HELP: &str =
+    "Path to the bundle created by `zkstack chain genesis export`";
+pub(super) const MSG_GENESIS_EXPORT_SPINNER: &str =
+    "Reading genesis state from the server database...";
+pub(super) const MSG_GENESIS_EXPORT_NO_GENESIS_ERR: &str =
+    "Server database doesn't contain the genesis batch; run `zkstack chain genesis` first";
+pub(super) const MSG_GENESIS_IMPORT_INVALID_BUNDLE_ERR: &str =
+    "File is not a genesis bundle created by `zkstack chain genesis export`";
+pub(super) const MSG_GENESIS_IMPORT_EVM_EMULATOR_MISMATCH_ERR: &str =
+    "EVM emulator must be enabled either both for the chain and in the bundle or for neither of them";
+pub(super) const MSG_GENESIS_IMPORT_SERVER_GENESIS_ERR: &str =
+    "Server genesis doesn't match the bundle; make sure that the chain uses the same contracts version as the exported one";
+pub(super) const MSG_GENESIS_IMPORT_VERIFYING_SPINNER: &str =
+    "Verifying genesis state against the bundle...";
+
+pub(super) fn msg_genesis_export_root_hash_mismatch_err(
+    db_hash: H256,
+    config_hash: H256,
+) -> String {
+    format!(
+        "Genesis root hash in the server database ({db_hash:?}) differs from the one in genesis config ({config_hash:?})"
+    )
+}
+
+pub(super) fn msg_genesis_exported(
+    chain_name: &str,
+    path: &Path,
+    storage_logs: usize,
+    factory_deps: usize,
+) -> String {
+    format!(
+        "Genesis of chain `{chain_name}` ({storage_logs} storage logs, {factory_deps} factory deps) exported to {}",
+        path.display()
+    )
+}
+
+pub(super) fn msg_genesis_import_unsupported_version_err(version: u32) -> String {
+    format!("Unsupported genesis bundle version: {version}")
+}
+
+pub(super) fn msg_genesis_import_applying(chain_name: &str) -> String {
+    format!("Applying genesis config exported from chain `{chain_name}`")
+}
+
+pub(super) fn msg_genesis_import_state_mismatch_err(
+    storage_logs: usize,
+    factory_deps: usize,
+) -> String {
+    format!(
+        "Genesis state differs from the bundle: {storage_logs} mismatched storage logs, {factory_deps} mismatched factory deps"
+    )
+}
+
+pub(super) fn msg_genesis_imported(chain_name: &str) -> String {
+    format!("Genesis state of chain `{chain_name}` imported and verified")
+}
 
 /// Chain update related messages
 pub(super) const MSG_WALLETS_CONFIG_MUST_BE_PRESENT: &str = "Wallets configuration must be present";
@@ -251,6 +432,427 @@ pub(super) const MSG_DEPLOYING_L2_CONTRACT_SPINNER: &str = "Deploying l2 contrac
 /// Chain deploy paymaster related messages
 pub(super) const MSG_DEPLOYING_PAYMASTER: &str = "Deploying paymaster";
 
+/// Chain DA mode conversion related messages
+pub(super) const MSG_L1_DA_VALIDATOR_HELP: &str =
+    "Address of the L1 DA validator contract to use after the conversion";
+pub(super) const MSG_L2_DA_VALIDATOR_HELP: &str =
+    "Address of the L2 DA validator contract to use after the conversion";
+pub(super) const MSG_L1_DA_VALIDATOR_PROMPT: &str =
+    "What is the address of the L1 DA validator contract?";
+pub(super) const MSG_L2_DA_VALIDATOR_PROMPT: &str =
+    "What is the address of the L2 DA validator contract?";
+pub(super) const MSG_CHECKING_COMMITTED_BATCHES_SPINNER: &str =
+    "Checking that all sealed batches are committed...";
+pub(super) const MSG_UPDATING_DA_VALIDATORS_SPINNER: &str = "Updating DA validator pair...";
+pub(super) const MSG_DA_MODE_CONVERSION_NOTE: &str =
+    "Restart the server and external nodes so that they pick up the updated configs";
+pub(super) const MSG_START_CHAIN_COMPONENTS_NOTE: &str =
+    "Start the server and external nodes of the chain so that they run with the updated configs";
+
+pub(super) fn msg_chain_already_in_da_mode_err(mode: L1BatchCommitmentMode) -> String {
+    format!("Chain is already using {mode} commitment mode")
+}
+
+pub(super) fn msg_uncommitted_batches_err(sealed: u32, committed: u32) -> String {
+    format!(
+        "Chain has sealed L1 batches that are not committed on L1 (last sealed: #{sealed}, last committed: #{committed}). \
+         Wait until all batches are committed and stop batch sealing before switching DA mode; \
+         the command can be safely re-run"
+    )
+}
+
+pub(super) fn msg_chain_converted_to_da_mode(
+    mode: L1BatchCommitmentMode,
+    transition_batch: L1BatchNumber,
+) -> String {
+    format!("Chain switched to {mode} commitment mode starting from L1 batch #{transition_batch}")
+}
+
+/// Chain DA switch related messages
+pub(super) const MSG_SET_DA_MODE_HELP: &str = "DA mode to switch the chain to";
+pub(super) const MSG_SET_DA_MODE_PROMPT: &str = "Which DA mode should the chain use?";
+pub(super) const MSG_SET_DA_RESTART_HELP: &str =
+    "Stop the running server and external nodes of the chain, so that their supervisor \
+     (e.g., `zkstack run-all --restart always`) restarts them with the updated configs";
+pub(super) const MSG_CELESTIA_API_NODE_URL_HELP: &str = "URL of the Celestia light node API";
+pub(super) const MSG_CELESTIA_NAMESPACE_HELP: &str = "Celestia namespace of the chain";
+pub(super) const MSG_CELESTIA_CHAIN_ID_HELP: &str = "Celestia network chain ID";
+pub(super) const MSG_CELESTIA_PRIVATE_KEY_HELP: &str = "Private key of the Celestia account";
+pub(super) const MSG_CELESTIA_API_NODE_URL_PROMPT: &str =
+    "What is the URL of the Celestia light node API?";
+pub(super) const MSG_CELESTIA_NAMESPACE_PROMPT: &str =
+    "What is the Celestia namespace of the chain?";
+pub(super) const MSG_CELESTIA_CHAIN_ID_PROMPT: &str = "What is the Celestia network chain ID?";
+pub(super) const MSG_CELESTIA_PRIVATE_KEY_PROMPT: &str =
+    "What is the private key of the Celestia account?";
+pub(super) const MSG_EIGEN_RPC_NODE_URL_HELP: &str = "URL of the EigenDA disperser RPC node";
+pub(super) const MSG_EIGEN_PRIVATE_KEY_HELP: &str = "Private key of the EigenDA account";
+pub(super) const MSG_EIGEN_RPC_NODE_URL_PROMPT: &str =
+    "What is the URL of the EigenDA disperser RPC node?";
+pub(super) const MSG_EIGEN_PRIVATE_KEY_PROMPT: &str =
+    "What is the private key of the EigenDA account?";
+pub(super) const MSG_NO_RUNNING_CHAIN_COMPONENTS: &str =
+    "No running server or external node of the chain was found";
+
+pub(super) fn msg_chain_already_uses_da_mode_err(mode: impl fmt::Display) -> String {
+    format!("Chain is already using the `{mode}` DA mode")
+}
+
+pub(super) fn msg_chain_da_mode_set(
+    mode: impl fmt::Display,
+    transition_batch: Option<L1BatchNumber>,
+) -> String {
+    match transition_batch {
+        Some(batch) => {
+            format!("Chain switched to the `{mode}` DA mode starting from L1 batch #{batch}")
+        }
+        None => format!("Chain switched to the `{mode}` DA mode"),
+    }
+}
+
+pub(super) fn msg_stopped_chain_components(count: usize) -> String {
+    format!("Sent SIGTERM to {count} process(es) of the chain server and external nodes")
+}
+
+/// Chain config diff related messages
+pub(super) const MSG_DIFF_CONFIG_URL_HELP: &str =
+    "URL of the server config endpoint (defaults to the `/config` endpoint of the healthcheck server)";
+pub(super) const MSG_CONFIG_IN_SYNC: &str =
+    "Config loaded by the running server matches the on-disk general config";
+pub(super) const MSG_CONFIG_RESTART_REQUIRED: &str =
+    "Server reads its config only on startup; restart it to apply the changes above";
+
+pub(super) fn msg_fetching_effective_config(url: &str) -> String {
+    format!("Fetching effective config from {url}")
+}
+
+pub(super) fn msg_fetching_effective_config_err(url: &str) -> String {
+    format!(
+        "Failed fetching effective config from {url}; make sure that the server runs with \
+         `api.healthcheck.expose_config` enabled"
+    )
+}
+
+pub(super) fn msg_config_drift_detected(count: usize, path: &Path) -> String {
+    format!(
+        "{count} value(s) in {} differ from the config loaded by the running server",
+        path.display()
+    )
+}
+
+pub(super) fn msg_config_value_changed(path: &str, running: &Value, on_disk: &Value) -> String {
+    format!("~ {path}: {running} (running) -> {on_disk} (on disk)")
+}
+
+pub(super) fn msg_config_value_added(path: &str, on_disk: &Value) -> String {
+    format!("+ {path}: {on_disk} (only on disk)")
+}
+
+pub(super) fn msg_config_value_removed(path: &str, running: &Value) -> String {
+    format!("- {path}: {running} (only in running server)")
+}
+
+/// Remote chain registration related messages
+pub(super) const MSG_REMOTE_CHAIN_NAME_HELP: &str = "Name to register the remote chain under";
+pub(super) const MSG_REMOTE_CHAIN_L2_RPC_URL_HELP: &str = "L2 JSON-RPC URL of the remote chain";
+pub(super) const MSG_REMOTE_CHAIN_L2_RPC_URL_PROMPT: &str = "What's the L2 RPC URL of the chain?";
+pub(super) const MSG_REMOTE_CHAIN_L1_RPC_URL_HELP: &str =
+    "L1 RPC URL used by apps (e.g., portal) to interact with L1 on behalf of the chain";
+pub(super) const MSG_REMOTE_CHAIN_ID_HELP: &str =
+    "Expected chain ID; must match the one reported by the L2 RPC unless --skip-rpc-check is set";
+pub(super) const MSG_REMOTE_CHAIN_BRIDGEHUB_HELP: &str =
+    "Bridgehub address (fetched from the L2 RPC if not specified)";
+pub(super) const MSG_REMOTE_CHAIN_L1_SHARED_BRIDGE_HELP: &str =
+    "L1 shared bridge address (fetched from the L2 RPC if not specified)";
+pub(super) const MSG_REMOTE_CHAIN_L2_SHARED_BRIDGE_HELP: &str =
+    "L2 shared bridge address (fetched from the L2 RPC if not specified)";
+pub(super) const MSG_REMOTE_CHAIN_BASE_TOKEN_HELP: &str =
+    "L1 address of the base token (fetched from the L2 RPC if not specified)";
+pub(super) const MSG_REMOTE_CHAIN_EXPLORER_URL_HELP: &str = "Block explorer URL of the chain";
+pub(super) const MSG_REMOTE_CHAIN_VERIFICATION_API_URL_HELP: &str =
+    "Contract verification API URL of the chain";
+pub(super) const MSG_REMOTE_CHAIN_SKIP_RPC_CHECK_HELP: &str =
+    "Don't query the L2 RPC; all chain parameters must be specified explicitly";
+pub(super) const MSG_REMOTE_CHAIN_ID_REQUIRED_ERR: &str =
+    "--chain-id must be specified if --skip-rpc-check is set";
+pub(super) const MSG_REMOTE_CHAIN_BASE_TOKEN_REQUIRED_ERR: &str =
+    "--base-token-address must be specified if --skip-rpc-check is set";
+
+pub(super) fn msg_remote_chain_name_taken_err(name: &str) -> String {
+    format!("Chain `{name}` already exists in the ecosystem; remote chains must have unique names")
+}
+
+pub(super) fn msg_remote_chain_id_mismatch_err(expected: u64, actual: u64) -> String {
+    format!("Chain ID reported by the L2 RPC ({actual}) doesn't match the specified chain ID ({expected})")
+}
+
+pub(super) fn msg_fetching_remote_chain_info(url: &Url) -> String {
+    format!("Fetching chain info from {url}")
+}
+
+pub(super) fn msg_remote_chain_added(name: &str, path: &Path) -> String {
+    format!("Remote chain `{name}` saved to {}", path.display())
+}
+
+/// Chain backup related messages
+pub(super) const MSG_BACKUP_OUTPUT_HELP: &str =
+    "Path to the created archive. Defaults to `<chain>-backup-<timestamp>.tar.gz` in the current directory";
+pub(super) const MSG_BACKUP_SKIP_PROVER_DB_HELP: &str = "Don't include the prover database";
+pub(super) const MSG_BACKUP_SKIP_ROCKS_DB_HELP: &str =
+    "Don't include RocksDB state; it will be recovered from Postgres by the server after restore";
+pub(super) const MSG_RESTORE_ARCHIVE_HELP: &str =
+    "Path to the archive created by `zkstack chain backup`";
+pub(super) const MSG_RESTORE_SERVER_DB_URL_HELP: &str =
+    "Full server database URL (incl. database name) to restore into. Defaults to the URL in the backed up secrets";
+pub(super) const MSG_RESTORE_PROVER_DB_URL_HELP: &str =
+    "Full prover database URL (incl. database name) to restore into. Defaults to the URL in the backed up secrets";
+pub(super) const MSG_RESTORE_FORCE_HELP: &str =
+    "Overwrite the chain configs, RocksDB state and databases if they already exist";
+pub(super) const MSG_BACKUP_COPYING_FILES: &str = "Copying chain configs and RocksDB state";
+pub(super) const MSG_BACKUP_INVALID_ARCHIVE_ERR: &str =
+    "Archive is not a chain backup created by `zkstack chain backup`";
+pub(super) const MSG_BACKUP_SERVER_RUNNING_WARNING: &str =
+    "Make sure that the server is stopped; otherwise, the backed up RocksDB state may be inconsistent";
+
+pub(super) fn msg_dumping_database(kind: impl fmt::Display, name: &str) -> String {
+    format!("Dumping {kind} database `{name}`")
+}
+
+pub(super) fn msg_restoring_database(kind: impl fmt::Display, name: &str) -> String {
+    format!("Restoring {kind} database `{name}`")
+}
+
+pub(super) fn msg_database_missing_in_secrets_err(kind: impl fmt::Display) -> String {
+    format!("{kind} database URL is not specified in secrets")
+}
+
+pub(super) fn msg_chain_backup_created(chain_name: &str, path: &Path) -> String {
+    format!("Chain `{chain_name}` is backed up to {}", path.display())
+}
+
+pub(super) fn msg_restore_chain_exists_err(chain_name: &str) -> String {
+    format!("Chain `{chain_name}` already exists in the ecosystem; use --force to overwrite it")
+}
+
+pub(super) fn msg_restore_database_exists_err(name: &str) -> String {
+    format!("Failed creating database `{name}`; if it already exists, use --force to overwrite it")
+}
+
+pub(super) fn msg_chain_restored(chain_name: &str) -> String {
+    format!("Chain `{chain_name}` is restored")
+}
+
+/// Chain wallet rotation related messages
+pub(super) const MSG_ROTATE_OPERATOR_HELP: &str =
+    "Rotate the operator key (used to send prove and execute transactions, and commit transactions if no blob operator is used)";
+pub(super) const MSG_ROTATE_BLOB_OPERATOR_HELP: &str =
+    "Rotate the blob operator key (used to send commit transactions)";
+pub(super) const MSG_ROTATE_FEE_ACCOUNT_HELP: &str =
+    "Rotate the fee account receiving L2 transaction fees";
+pub(super) const MSG_ROTATE_PRIVATE_KEY_HELP: &str =
+    "Private key to import for the rotated wallet; a new key is generated if not specified";
+pub(super) const MSG_ROTATE_SKIP_VALIDATOR_UPDATE_HELP: &str =
+    "Don't update validator registrations in ValidatorTimelock (e.g., if they are managed by a multisig)";
+pub(super) const MSG_ROTATE_VERIFY_HELP: &str =
+    "After rotating keys, wait until the restarted server uses them";
+pub(super) const MSG_ROTATE_VERIFY_TIMEOUT_HELP: &str = "Timeout for --verify, in seconds";
+pub(super) const MSG_ROTATE_NOTHING_SELECTED_ERR: &str = "No wallets were selected for rotation";
+pub(super) const MSG_ROTATE_GENERATE_KEY_PROMPT: &str = "Generate a new key?";
+pub(super) const MSG_ROTATE_PRIVATE_KEY_PROMPT: &str = "What's the private key to import?";
+pub(super) const MSG_ROTATE_CONFIRM_PROMPT: &str = "Proceed with the rotation?";
+pub(super) const MSG_ROTATE_ABORTED: &str = "Wallet rotation aborted";
+pub(super) const MSG_ROTATE_CHECKING_ETH_TXS_SPINNER: &str =
+    "Checking that there are no unconfirmed L1 transactions...";
+pub(super) const MSG_ROTATE_UPDATING_VALIDATORS_SPINNER: &str =
+    "Updating validator registrations in ValidatorTimelock...";
+pub(super) const MSG_ROTATE_WAITING_FOR_SERVER_SPINNER: &str =
+    "Waiting for the server to use the rotated wallets...";
+pub(super) const MSG_ROTATE_RESTART_NOTE: &str =
+    "Restart the server so that the eth sender and state keeper pick up the rotated wallets";
+pub(super) const MSG_ROTATE_VERIFIED: &str = "Server uses the rotated wallets";
+
+pub(super) fn msg_rotate_wallet_prompt(role: impl fmt::Display) -> String {
+    format!("Rotate the {role} key?")
+}
+
+pub(super) fn msg_rotate_unconfirmed_eth_txs_err(count: i64) -> String {
+    format!(
+        "There are {count} unconfirmed L1 transactions; they are signed with the current operator keys \
+         and would be broken by the rotation. Pause the eth sender (e.g., set `eth.sender.tx_aggregation_paused`) \
+         and wait until all transactions are confirmed"
+    )
+}
+
+pub(super) fn msg_rotate_wallet(role: impl fmt::Display, old: Address, new: Address) -> String {
+    format!("{role}: {old:#x} -> {new:#x}")
+}
+
+pub(super) fn msg_rotate_unfunded_wallet_warning(
+    role: impl fmt::Display,
+    address: Address,
+) -> String {
+    format!("New {role} {address:#x} has no funds on L1; fund it before restarting the server")
+}
+
+pub(super) fn msg_rotate_validator_not_registered_err(address: Address) -> String {
+    format!("{address:#x} is not registered as a validator in ValidatorTimelock after the update")
+}
+
+pub(super) fn msg_rotate_wallets_saved(path: &Path, backup_path: &Path) -> String {
+    format!(
+        "Rotated wallets saved to {}; previous wallets are backed up to {}",
+        path.display(),
+        backup_path.display()
+    )
+}
+
+pub(super) fn msg_rotate_verify_timeout_err(pending: &[String]) -> String {
+    format!(
+        "Timed out waiting for the server to use the rotated wallets: {}",
+        pending.join(", ")
+    )
+}
+
+/// Chain protocol upgrade related messages
+pub(super) const MSG_UPGRADE_PROTOCOL_VERSION_HELP: &str =
+    "Target protocol version in the `0.<minor>.<patch>` format; defaults to the latest version known to the chain type manager";
+pub(super) const MSG_UPGRADE_DATA_HELP: &str =
+    "Path to a JSON file with the diamond cut data of the upgrade (e.g., `transactions.json` produced by the protocol upgrade tool). \
+     Required if the upgrade is not yet published in the chain type manager; otherwise, the published cut data is used";
+pub(super) const MSG_UPGRADE_CALLDATA_ONLY_HELP: &str =
+    "Only print the calldata of governance and chain admin transactions without sending them (e.g., to submit them via a multisig)";
+pub(super) const MSG_UPGRADE_TIMEOUT_HELP: &str =
+    "Timeout for waiting for the upgrade batch, in seconds";
+pub(super) const MSG_UPGRADE_CONFIRM_PROMPT: &str = "Proceed with the upgrade?";
+pub(super) const MSG_UPGRADE_ABORTED: &str = "Protocol upgrade aborted";
+pub(super) const MSG_UPGRADE_PREPARING_SPINNER: &str = "Preparing upgrade calldata...";
+pub(super) const MSG_UPGRADE_SCHEDULING_SPINNER: &str =
+    "Scheduling the upgrade in the ecosystem governance...";
+pub(super) const MSG_UPGRADE_EXECUTING_GOVERNANCE_SPINNER: &str =
+    "Executing the governance operation publishing the upgrade...";
+pub(super) const MSG_UPGRADE_EXECUTING_CHAIN_SPINNER: &str =
+    "Upgrading the chain diamond proxy via chain admin...";
+pub(super) const MSG_UPGRADE_WAITING_FOR_BATCH_SPINNER: &str =
+    "Waiting for the server to seal the upgrade batch...";
+pub(super) const MSG_UPGRADE_VERIFYING_SPINNER: &str = "Verifying upgraded versions...";
+pub(super) const MSG_UPGRADE_CUT_DATA_NOT_FOUND_ERR: &str =
+    "Diamond cut data of the upgrade is not found in the chain type manager events; specify it with --upgrade-data";
+
+pub(super) fn msg_upgrade_plan(
+    old_version: impl fmt::Display,
+    new_version: impl fmt::Display,
+) -> String {
+    format!("Upgrading the chain from protocol version {old_version} to {new_version}")
+}
+
+pub(super) fn msg_upgrade_not_newer_err(
+    old_version: impl fmt::Display,
+    new_version: impl fmt::Display,
+) -> String {
+    format!(
+        "Chain already uses protocol version {old_version}, which is not older than {new_version}"
+    )
+}
+
+pub(super) fn msg_upgrade_not_published_err(new_version: impl fmt::Display) -> String {
+    format!(
+        "Protocol version {new_version} is not published in the chain type manager; \
+         specify the upgrade data with --upgrade-data to publish it via the ecosystem governance"
+    )
+}
+
+pub(super) fn msg_upgrade_cut_hash_mismatch_err(old_version: impl fmt::Display) -> String {
+    format!(
+        "Diamond cut data doesn't match the upgrade registered in the chain type manager for protocol version {old_version}"
+    )
+}
+
+pub(super) fn msg_upgrade_version_mismatch_err(
+    source: &str,
+    expected: impl fmt::Display,
+    actual: impl fmt::Display,
+) -> String {
+    format!("Unexpected protocol version reported by {source}: expected {expected}, got {actual}")
+}
+
+pub(super) fn msg_upgrade_base_contract_mismatch_err(
+    contract: &str,
+    l1_hash: impl fmt::Debug,
+    l2_hash: impl fmt::Debug,
+) -> String {
+    format!("{contract} hash on L1 ({l1_hash:?}) doesn't match the one used by the server ({l2_hash:?})")
+}
+
+pub(super) fn msg_upgrade_batch_timeout_err(new_version: impl fmt::Display) -> String {
+    format!(
+        "Timed out waiting for the server to seal an L1 batch with protocol version {new_version}"
+    )
+}
+
+pub(super) fn msg_upgrade_batch_sealed(batch_number: L1BatchNumber) -> String {
+    format!("Upgrade is applied in L1 batch #{batch_number}")
+}
+
+pub(super) fn msg_upgrade_completed(
+    new_version: impl fmt::Display,
+    vm_version: impl fmt::Debug,
+) -> String {
+    format!("Chain is upgraded to protocol version {new_version} (VM version: {vm_version:?})")
+}
+
+/// Chain monitor related messages
+pub(super) const MSG_MONITOR_REFRESH_HELP: &str = "Refresh interval of the dashboard, in seconds";
+pub(super) const MSG_MONITOR_ONCE_HELP: &str =
+    "Print the dashboard once and exit instead of refreshing it in place";
+pub(super) const MSG_MONITOR_EXIT_HINT: &str = "Press Ctrl+C to exit";
+pub(super) const MSG_MONITOR_UNAVAILABLE: &str = "n/a";
+
+pub(super) fn msg_monitor_header(chain_name: &str, timestamp: impl fmt::Display) -> String {
+    format!("Chain `{chain_name}` at {timestamp}")
+}
+
+/// Chain expose related messages
+pub(super) const MSG_EXPOSE_DOMAIN_HELP: &str =
+    "Public domain name of the RPC endpoint, e.g. `rpc.example.com`. Its DNS record must point to this machine";
+pub(super) const MSG_EXPOSE_EMAIL_HELP: &str =
+    "Contact email for the ACME account used to issue certificates, e.g. for expiry notices";
+pub(super) const MSG_EXPOSE_RATE_LIMIT_HELP: &str =
+    "Maximum number of requests per second from a single IP address";
+pub(super) const MSG_EXPOSE_STAGING_HELP: &str =
+    "Use the staging environment of the certificate authority, e.g. to check the setup without hitting production rate limits";
+pub(super) const MSG_EXPOSE_CONFIG_ONLY_HELP: &str =
+    "Only generate the reverse proxy config without starting the proxy container";
+pub(super) const MSG_EXPOSE_INVALID_DOMAIN_ERR: &str =
+    "expected a domain name without scheme, port or path, e.g. `rpc.example.com`";
+pub(super) const MSG_EXPOSE_FAILED_TO_RUN_DOCKER_ERR: &str =
+    "Failed to run the reverse proxy docker container";
+
+pub(super) fn msg_expose_config_saved(path: &Path) -> String {
+    format!("Reverse proxy config saved to {}", path.display())
+}
+
+pub(super) fn msg_expose_starting(chain_name: &str, domain: &str) -> String {
+    format!(
+        "Exposing chain `{chain_name}` at https://{domain} (WebSocket: wss://{domain}). \
+         Ports 80 and 443 must be reachable from the internet to obtain certificates"
+    )
+}
+
+/// Ecosystem status related messages
+pub(super) const MSG_ECOSYSTEM_STATUS_NO_SERVICES: &str = "No services in the compose file";
+pub(super) const MSG_ECOSYSTEM_STATUS_PROVER_NOT_CONFIGURED: &str = "prover DB is not configured";
+
+pub(super) fn msg_ecosystem_status_header(
+    ecosystem_name: &str,
+    timestamp: impl fmt::Display,
+) -> String {
+    format!("Ecosystem `{ecosystem_name}` at {timestamp}")
+}
+
+pub(super) fn msg_ecosystem_status_chain_header(chain_name: &str) -> String {
+    format!("Chain `{chain_name}`")
+}
+
 /// Chain build related messages
 pub(super) const MSG_BUILDING_CHAIN_REGISTRATION_TXNS_SPINNER: &str =
     "Building chain registration transactions...";
@@ -266,6 +868,22 @@ pub(super) const MSG_SERVER_GENESIS_HELP: &str = "Run server in genesis mode";
 pub(super) const MSG_SERVER_ADDITIONAL_ARGS_HELP: &str =
     "Additional arguments that can be passed through the CLI";
 pub(super) const MSG_SERVER_URING_HELP: &str = "Enables uring support for RocksDB";
+pub(super) const MSG_SERVER_PRESET_HELP: &str =
+    "Named set of components to run: `full`, `sequencer`, `api-only`, `prover` or a custom preset from the chain config";
+
+pub(super) fn msg_server_unknown_preset_err(preset: &str, available: &[String]) -> String {
+    format!(
+        "Unknown server preset `{preset}`; available presets: {}",
+        available.join(", ")
+    )
+}
+
+pub(super) fn msg_server_preset_components(preset: &str, components: &[String]) -> String {
+    format!(
+        "Running components of preset `{preset}`: {}",
+        components.join(",")
+    )
+}
 
 /// Accept ownership related messages
 pub(super) const MSG_ACCEPTING_GOVERNANCE_SPINNER: &str = "Accepting governance...";
@@ -279,6 +897,13 @@ pub(super) const MSG_RETRY_START_CONTAINERS_PROMPT: &str =
     "Failed to start containers. Make sure there is nothing running on default ports for Ethereum node l1 and postgres. Want to try again?";
 pub(super) const MSG_FAILED_TO_FIND_ECOSYSTEM_ERR: &str = "Failed to find ecosystem folder.";
 pub(super) const MSG_OBSERVABILITY_RUN_PROMPT: &str = "Do you want to run observability?";
+pub(super) const MSG_CONTAINER_RUNTIME_HELP: &str =
+    "Container runtime to use; saved in the ecosystem config for subsequent commands. Detected automatically by default";
+
+pub(super) fn msg_using_container_runtime(runtime: ContainerRuntime, rootless: bool) -> String {
+    let mode = if rootless { " (rootless)" } else { "" };
+    format!("Using {runtime} container runtime{mode}")
+}
 
 /// Server related messages
 pub(super) const MSG_STARTING_SERVER: &str = "Starting server";
@@ -292,6 +917,106 @@ pub(super) fn msg_waiting_for_server_success(health_check_port: u16) -> String {
     format!("Server is alive with health check server on :{health_check_port}")
 }
 
+/// Secret references related messages
+pub(super) fn msg_secret_reference_resolution_err(reference: &str) -> String {
+    format!("Failed to resolve secret reference `{reference}`")
+}
+
+pub(super) fn msg_resolved_secret_references(count: usize, path: &Path) -> String {
+    format!("Resolved {count} secret reference(s) in {path:?}")
+}
+
+/// Run all related messages
+pub(super) const MSG_RUN_ALL_SERVICES_HELP: &str = "Comma-separated list of services to run";
+pub(super) const MSG_RUN_ALL_RESTART_HELP: &str = "Restart policy for exited services";
+pub(super) const MSG_RUN_ALL_MAX_RESTARTS_HELP: &str =
+    "Maximum number of restarts of a single service before giving up";
+pub(super) const MSG_RUN_ALL_RESTART_DELAY_HELP: &str =
+    "Delay before the first restart of a service, doubled with each subsequent restart";
+pub(super) const MSG_RUN_ALL_LOG_FORMAT_HELP: &str = "Format of the multiplexed logs";
+pub(super) const MSG_RUN_ALL_NO_COLOR_HELP: &str = "Disable colored service prefixes";
+pub(super) const MSG_RUN_ALL_SHUTTING_DOWN: &str = "Received Ctrl+C, stopping services";
+pub(super) const MSG_RUN_ALL_SERVICES_STOPPED: &str = "All services stopped";
+pub(super) const MSG_RUN_ALL_SUPERVISOR_PANICKED_ERR: &str = "Service supervisor panicked";
+pub(super) const MSG_RUN_ALL_FAILED_TO_GET_CURRENT_EXE_ERR: &str =
+    "Failed to locate the zkstack executable";
+
+pub(super) fn msg_run_all_starting_services(services: &[String]) -> String {
+    format!("Starting services: {}", services.join(", "))
+}
+
+pub(super) fn msg_run_all_failed_to_spawn(service: &str) -> String {
+    format!("Failed to spawn {service}")
+}
+
+pub(super) fn msg_run_all_service_exited(service: &str, status: impl std::fmt::Display) -> String {
+    format!("{service} exited with {status}")
+}
+
+pub(super) fn msg_run_all_restarting_service(
+    service: &str,
+    delay: std::time::Duration,
+    restart: usize,
+    max_restarts: usize,
+) -> String {
+    format!("Restarting {service} in {delay:?} (restart {restart}/{max_restarts})")
+}
+
+pub(super) fn msg_run_all_giving_up(service: &str, max_restarts: usize) -> String {
+    format!("{service} was restarted {max_restarts} times, giving up")
+}
+
+/// Multi-chain related messages
+pub(super) const MSG_MULTI_CHAIN_ALL_CHAINS_HELP: &str =
+    "Run the command for all chains of the ecosystem concurrently";
+pub(super) const MSG_MULTI_CHAIN_CHAINS_HELP: &str =
+    "Comma-separated list of chains to run the command for concurrently";
+pub(super) const MSG_MULTI_CHAIN_CHAIN_CONFLICT_ERR: &str =
+    "`--chain` can't be combined with `--all-chains` or `--chains`";
+pub(super) const MSG_MULTI_CHAIN_NO_CHAINS_ERR: &str = "Ecosystem has no chains";
+pub(super) const MSG_MULTI_CHAIN_SHUTTING_DOWN: &str = "Received Ctrl+C, stopping chains";
+pub(super) const MSG_MULTI_CHAIN_RUNNER_PANICKED_ERR: &str = "Chain runner panicked";
+pub(super) const MSG_MULTI_CHAIN_ALL_SUCCEEDED: &str = "Command succeeded for all chains";
+pub(super) const MSG_MULTI_CHAIN_STOPPED: &str = "Command was stopped";
+
+pub(super) fn msg_multi_chain_unknown_chain_err(chain: &str, available: &[String]) -> String {
+    format!(
+        "Chain {chain} doesn't exist, available chains: {}",
+        available.join(", ")
+    )
+}
+
+pub(super) fn msg_multi_chain_running(chains: &[String]) -> String {
+    format!("Running for chains: {}", chains.join(", "))
+}
+
+pub(super) fn msg_multi_chain_chain_succeeded(chain: &str, elapsed: Duration) -> String {
+    format!("{chain}: succeeded in {:.1}s", elapsed.as_secs_f64())
+}
+
+pub(super) fn msg_multi_chain_chain_failed(
+    chain: &str,
+    reason: impl fmt::Display,
+    elapsed: Duration,
+) -> String {
+    format!(
+        "{chain}: failed in {:.1}s ({reason})",
+        elapsed.as_secs_f64()
+    )
+}
+
+pub(super) fn msg_multi_chain_chain_stopped(chain: &str, elapsed: Duration) -> String {
+    format!("{chain}: stopped after {:.1}s", elapsed.as_secs_f64())
+}
+
+pub(super) fn msg_multi_chain_last_lines(chain: &str) -> String {
+    format!("Last output lines of {chain}")
+}
+
+pub(super) fn msg_multi_chain_failed_err(chains: &[String]) -> String {
+    format!("Command failed for chains: {}", chains.join(", "))
+}
+
 /// Portal related messages
 pub(super) const MSG_PORTAL_FAILED_TO_FIND_ANY_CHAIN_ERR: &str =
     "Failed to find any valid chain to run portal for";
@@ -304,6 +1029,25 @@ pub(super) fn msg_portal_running_with_config(path: &Path) -> String {
 pub(super) fn msg_portal_starting_on(host: &str, port: u16) -> String {
     format!("Starting portal on http://{host}:{port}")
 }
+pub(super) const MSG_PORTAL_L1_RPC_URL_REQUIRED_ERR: &str =
+    "L1 RPC URL is required to fetch info about a custom base token";
+pub(super) fn msg_portal_remote_chain_skipped(chain_name: &str, err: &anyhow::Error) -> String {
+    format!("Skipping remote chain `{chain_name}` in portal: {err:#}")
+}
+pub(super) const MSG_PORTAL_CHAINS_HELP: &str =
+    "Comma-separated list of chains to serve in portal (defaults to all chains)";
+pub(super) const MSG_PORTAL_CONFIG_OVERRIDE_HELP: &str =
+    "Path to a JSON file overriding tokens, bridges and branding in the generated portal config";
+pub(super) const MSG_PORTAL_CONFIG_ONLY_HELP: &str =
+    "Only regenerate the portal runtime config without starting the container";
+pub(super) const MSG_PORTAL_FAILED_TO_APPLY_OVERRIDE_ERR: &str =
+    "Failed to apply portal config override";
+pub(super) fn msg_portal_chain_not_found(chain_name: &str) -> String {
+    format!("Chain `{chain_name}` is not found in the ecosystem or remote chains config")
+}
+pub(super) fn msg_portal_config_regenerated(path: &Path) -> String {
+    format!("Portal runtime config regenerated at: {}", path.display())
+}
 
 /// Explorer related messages
 pub(super) const MSG_EXPLORER_FAILED_TO_DROP_DATABASE_ERR: &str =
@@ -352,6 +1096,24 @@ pub(super) fn msg_preparing_en_config_is_done(path: &Path) -> String {
     format!("External nodes configs could be found in: {path:?}")
 }
 
+pub(super) const MSG_EN_FLEET_COUNT_HELP: &str = "Number of external nodes in the fleet";
+pub(super) const MSG_EN_FLEET_PRUNING_HELP: &str = "Enable pruning for all nodes in the fleet";
+pub(super) const MSG_EN_FLEET_PRUNING_RETENTION_HELP: &str =
+    "Retention period of pruned data in seconds";
+pub(super) const MSG_EN_FLEET_MANIFEST_HELP: &str = "Kind of the manifest to generate";
+pub(super) const MSG_EN_FLEET_IMAGE_HELP: &str = "Docker image of the external node";
+pub(super) const MSG_EN_FLEET_EMPTY_ERR: &str = "Fleet must contain at least one external node";
+pub(super) const MSG_EN_FLEET_K8S_DATABASE_ATTENTION: &str =
+    "ATTENTION: Database URLs in the generated manifest point to the local Postgres. Please update them to the database reachable from the cluster.";
+
+pub(super) fn msg_preparing_en_fleet_node_config(node: &str) -> String {
+    format!("Preparing configs for external node {node}")
+}
+
+pub(super) fn msg_en_fleet_generated(count: usize, manifest_path: &Path) -> String {
+    format!("Configs for {count} external nodes are generated, manifest could be found in: {manifest_path:?}")
+}
+
 pub(super) const MSG_EXTERNAL_NODE_CONFIG_NOT_INITIALIZED: &str =
     "External node is not initialized";
 pub(super) const MSG_CONSENSUS_CONFIG_MISSING_ERR: &str = "Consensus config is missing";
@@ -370,6 +1132,12 @@ pub(super) fn msg_waiting_for_en_success(health_check_port: u16) -> String {
 /// Prover related messages
 pub(super) const MSG_GENERATING_SK_SPINNER: &str = "Generating setup keys...";
 pub(super) const MSG_SK_GENERATED: &str = "Setup keys generated successfully";
+pub(super) const MSG_SETUP_KEYS_CIRCUITS_HELP: &str =
+    "Comma-separated circuits to generate setup keys for, e.g. `basic_1,leaf_3,node,recursion_tip,scheduler`; implies `--mode generate`. Existing keys are not regenerated";
+pub(super) const MSG_SETUP_KEYS_JOBS_HELP: &str =
+    "Number of setup keys generated in parallel when `--circuits` is specified";
+pub(super) const MSG_BUILDING_KEY_GENERATOR_SPINNER: &str = "Building key generator...";
+pub(super) const MSG_SK_ALREADY_PRESENT: &str = "Setup keys for all selected circuits are present";
 pub(super) const MSG_MISSING_COMPONENT_ERR: &str = "Missing component";
 pub(super) const MSG_RUNNING_PROVER_GATEWAY: &str = "Running gateway";
 pub(super) const MSG_RUNNING_PROVER_JOB_MONITOR_ERR: &str = "Failed to run prover job monitor";
@@ -438,6 +1206,31 @@ pub(super) const MSG_CLOUD_TYPE_PROMPT: &str = "Select the cloud connection mode
 pub(super) const MSG_THREADS_PROMPT: &str = "Provide the number of threads:";
 pub(super) const MSG_SETUP_KEYS_PROMPT: &str = "Do you want to setup keys?";
 
+pub(super) fn msg_setup_key_circuit_parse_err(circuit: &str) -> String {
+    format!("Invalid circuit `{circuit}`; expected `basic_<id>`, `leaf_<id>`, `node`, `recursion_tip` or `scheduler`")
+}
+
+pub(super) fn msg_not_enough_disk_space_err(path: &Path, required: u64, available: u64) -> String {
+    format!(
+        "Not enough disk space in {}: setup keys require up to {} GiB, but only {} GiB is available",
+        path.display(),
+        required >> 30,
+        available >> 30
+    )
+}
+
+pub(super) fn msg_generating_sk_for_circuit(circuit: impl fmt::Display) -> String {
+    format!("Generating setup key for {circuit}")
+}
+
+pub(super) fn msg_sk_generated_for_circuit(circuit: impl fmt::Display) -> String {
+    format!("Setup key for {circuit} generated")
+}
+
+pub(super) fn msg_sk_generation_failed(circuits: &[String]) -> String {
+    format!("Failed generating setup keys for: {}", circuits.join(", "))
+}
+
 pub(super) fn msg_bucket_created(bucket_name: &str) -> String {
     format!("Bucket created successfully with url: gs://{bucket_name}")
 }
@@ -494,6 +1287,52 @@ pub(super) const MSG_DIFF_EN_CONFIG: &str =
 pub(super) const MSG_DIFF_EN_GENERAL_CONFIG: &str =
     "Added the following fields to the external node generalconfig:";
 pub(super) const MSG_UPDATING_ERA_OBSERVABILITY_SPINNER: &str = "Updating era observability...";
+pub(super) const MSG_UPDATE_TO_HELP: &str =
+    "Check out the specified branch, tag or commit instead of pulling the current branch";
+pub(super) const MSG_UPDATE_ROLLBACK_HELP: &str =
+    "Restore the code revision, binaries, chain configs and DB migrations recorded before the last update";
+pub(super) const MSG_UPDATE_RECORDING_ROLLBACK_POINT_SPINNER: &str = "Recording rollback point...";
+pub(super) const MSG_UPDATE_ROLLBACK_NOTE: &str =
+    "If the new version fails to start, run `zkstack update rollback` \
+     to restore the previous code, configs and DB migrations";
+pub(super) const MSG_UPDATE_FAILED_ROLLBACK_HINT: &str =
+    "Update failed; run `zkstack update rollback` to restore the state before the update";
+pub(super) const MSG_UPDATE_NO_ROLLBACK_POINT_ERR: &str =
+    "No rollback point found; it's recorded by `zkstack update`";
+pub(super) const MSG_UPDATE_RESTORING_CODE_SPINNER: &str = "Restoring zksync-era repo...";
+pub(super) const MSG_UPDATE_RESTORING_BINARIES_SPINNER: &str = "Restoring binaries...";
+
+pub(super) fn msg_update_rollback_point_recorded(revision: &str) -> String {
+    format!("Recorded rollback point at revision {revision}")
+}
+
+pub(super) fn msg_update_rolling_back_chain(chain: &str) -> String {
+    format!("Rolling back chain: {chain}")
+}
+
+pub(super) fn msg_update_reverted_migrations(count: usize, database: &str) -> String {
+    format!("Reverted {count} migration(s) in database `{database}`")
+}
+
+pub(super) fn msg_update_irreversible_migrations(
+    chain: &str,
+    database: &str,
+    versions: &[i64],
+) -> String {
+    let versions: Vec<_> = versions.iter().map(i64::to_string).collect();
+    format!(
+        "Cannot roll back chain `{chain}`: migrations {} in database `{database}` have no down migrations",
+        versions.join(", ")
+    )
+}
+
+pub(super) fn msg_update_reverted_config_fields(path: &Path) -> String {
+    format!("Removed the following fields added by the update from {path:?}:")
+}
+
+pub(super) fn msg_update_rollback_completed(revision: &str, created_at: &str) -> String {
+    format!("Rolled back to revision {revision} recorded at {created_at}")
+}
 
 /// Wait-related messages
 pub(super) const MSG_WAIT_TIMEOUT_HELP: &str = "Wait timeout in seconds";
@@ -563,6 +1402,13 @@ pub(super) const MSG_CONSENSUS_GENESIS_SPEC_ATTESTERS_MISSING_IN_GENERAL_YAML: &
     "consensus.genesis_spec.attesters missing in general.yaml";
 pub(super) const MSG_CONSENSUS_REGISTRY_POLL_ERROR: &str = "failed querying L2 node";
 pub(super) const MSG_CONSENSUS_REGISTRY_WAIT_COMPONENT: &str = "main node HTTP RPC";
+pub(super) const MSG_CONSENSUS_NOT_ENABLED: &str = "consensus is not enabled on the node";
+pub(super) const MSG_CONSENSUS_VIEW: &str = "Consensus view";
+pub(super) const MSG_CONSENSUS_VALIDATORS: &str = "Validator committee";
+pub(super) const MSG_CONSENSUS_ATTESTATION: &str = "Attestation";
+pub(super) const MSG_CONSENSUS_PEERS: &str = "Peer connectivity";
+pub(super) const MSG_CONSENSUS_PEER_PROBE_TIMEOUT: &str = "connection timed out";
+pub(super) const MSG_CONSENSUS_PEER_PROBE_FAILED: &str = "connection failed";
 
 pub(super) fn msg_setting_attester_committee_failed(
     got: &attester::Committee,
@@ -578,3 +1424,233 @@ pub(super) fn msg_wait_consensus_registry_started_polling(addr: Address, url: &U
 pub(super) fn msg_consensus_registry_wait_success(addr: Address, code_len: usize) -> String {
     format!("Consensus registry is deployed at {addr:?}: {code_len} bytes")
 }
+
+/// Interop relayer related messages
+pub(super) const MSG_RELAYER_TARGETS_HELP: &str =
+    "Names of chains to deliver messages to, comma-separated. Defaults to all other chains of the ecosystem";
+pub(super) const MSG_RELAYER_FROM_BATCH_HELP: &str =
+    "Source chain L1 batch to start scanning from if there is no saved relayer state";
+pub(super) const MSG_RELAYER_STATE_HELP: &str =
+    "Path to the relayer state file. Defaults to `interop_relayer.json` in the chain artifacts directory";
+pub(super) const MSG_RELAYER_POLL_INTERVAL_HELP: &str =
+    "Interval between source chain polls in seconds; also used as the base delay between delivery retries";
+pub(super) const MSG_RELAYER_MAX_ATTEMPTS_HELP: &str =
+    "Number of delivery attempts after which a message is marked as failed";
+pub(super) const MSG_RELAYER_L2_GAS_LIMIT_HELP: &str =
+    "L2 gas limit of delivery transactions on target chains";
+pub(super) const MSG_RELAYER_ONCE_HELP: &str =
+    "Process available messages once and exit instead of running continuously";
+pub(super) const MSG_RELAYER_DEPLOYER_WALLET_MISSING_ERR: &str =
+    "Deployer wallet with a private key is required to pay for message delivery";
+pub(super) const MSG_RELAYER_NO_TARGETS_ERR: &str = "No target chains to deliver messages to";
+pub(super) const MSG_RELAYER_INCLUSION_REJECTED: &str =
+    "message inclusion proof is rejected by Bridgehub";
+pub(super) const MSG_RELAYER_INVALID_MESSAGE: &str = "message body is not an interop message";
+
+pub(super) fn msg_relayer_started(
+    source_chain: &str,
+    targets: &[&str],
+    state_path: &Path,
+) -> String {
+    format!(
+        "Relaying messages from chain `{source_chain}` to {}; state is saved to {state_path:?}",
+        targets.join(", ")
+    )
+}
+
+pub(super) fn msg_relayer_resuming(state_path: &Path, next_batch: L1BatchNumber) -> String {
+    format!("Relayer state {state_path:?} exists; resuming from batch {next_batch} and ignoring `--from-batch`")
+}
+
+pub(super) fn msg_relayer_state_chain_mismatch_err(state_path: &Path, chain_id: u64) -> String {
+    format!("Relayer state {state_path:?} belongs to chain {chain_id}")
+}
+
+pub(super) fn msg_relayer_target_not_registered(chain_name: &str) -> String {
+    format!("Chain `{chain_name}` is not registered in Bridgehub; skipping it as a target")
+}
+
+pub(super) fn msg_relayer_target_not_eth_based(chain_name: &str) -> String {
+    format!("Chain `{chain_name}` doesn't use ETH as the base token, which is not supported yet; skipping it as a target")
+}
+
+pub(super) fn msg_relayer_batch_not_sealed_err(batch: L1BatchNumber) -> String {
+    format!("L1 batch {batch} is not sealed")
+}
+
+pub(super) fn msg_relayer_missing_proof_err(tx_hash: H256) -> String {
+    format!("No L2->L1 log proof for transaction {tx_hash:?}")
+}
+
+pub(super) fn msg_relayer_message_found(
+    batch: L1BatchNumber,
+    index: u32,
+    target_chain_id: u64,
+) -> String {
+    format!("Found message {batch}:{index} to chain {target_chain_id}")
+}
+
+pub(super) fn msg_relayer_message_delivered(
+    batch: L1BatchNumber,
+    index: u32,
+    target_chain: &str,
+    l1_tx_hash: H256,
+) -> String {
+    format!("Delivered message {batch}:{index} to chain `{target_chain}` in L1 transaction {l1_tx_hash:?}")
+}
+
+pub(super) fn msg_relayer_message_failed(batch: L1BatchNumber, index: u32, reason: &str) -> String {
+    format!("Message {batch}:{index} cannot be delivered: {reason}")
+}
+
+pub(super) fn msg_relayer_delivery_retry(
+    batch: L1BatchNumber,
+    index: u32,
+    attempts: u32,
+    err: &str,
+) -> String {
+    format!("Delivery attempt {attempts} of message {batch}:{index} failed, will retry: {err}")
+}
+
+pub(super) fn msg_relayer_unknown_target(chain_id: u64) -> String {
+    format!("target chain {chain_id} is not served by the relayer")
+}
+
+pub(super) fn msg_relayer_tx_dropped_err(tx_hash: H256) -> String {
+    format!("delivery transaction {tx_hash:?} was dropped")
+}
+
+pub(super) fn msg_relayer_tx_reverted_err(tx_hash: H256) -> String {
+    format!("delivery transaction {tx_hash:?} was reverted")
+}
+
+pub(super) fn msg_relayer_iteration_err(err: &anyhow::Error) -> String {
+    format!("Relayer iteration failed, will retry: {err:#}")
+}
+
+pub(super) fn msg_relayer_summary(delivered: usize, pending: usize, failed: usize) -> String {
+    format!(
+        "Relayer finished: {delivered} message(s) delivered, {pending} pending, {failed} failed"
+    )
+}
+
+/// Answers file related messages
+pub(super) const MSG_ANSWERS_HELP: &str = "Path to a YAML or TOML file with values of the command arguments, \
+    keyed by argument names. Prompts are disabled: the command fails if a value is neither specified \
+    in the file nor on the command line";
+
+pub(super) fn msg_answers_unknown_key_err(key: &str, command: &str) -> String {
+    format!("Answers file contains `{key}`, which is not an argument of `{command}`")
+}
+
+pub(super) fn msg_answers_invalid_value_err(key: &str) -> String {
+    format!("Invalid value of `{key}` in the answers file")
+}
+
+/// Doctor related messages
+pub(super) const MSG_DOCTOR_INTRO: &str = "Checking the local setup";
+pub(super) const MSG_DOCTOR_PREREQUISITES: &str = "Prerequisites";
+pub(super) const MSG_DOCTOR_ECOSYSTEM_CONFIG: &str = "Ecosystem config";
+pub(super) const MSG_DOCTOR_CHAIN_CONFIG: &str = "Chain config";
+pub(super) const MSG_DOCTOR_CONTAINERS: &str = "Containers";
+pub(super) const MSG_DOCTOR_PORTS: &str = "Ports";
+pub(super) const MSG_DOCTOR_L1_RPC: &str = "L1 RPC";
+pub(super) const MSG_DOCTOR_L1_CONTRACTS: &str = "L1 contracts";
+pub(super) const MSG_DOCTOR_SERVER_DATABASE: &str = "Server database";
+pub(super) const MSG_DOCTOR_PROVER_DATABASE: &str = "Prover database";
+pub(super) const MSG_DOCTOR_GENESIS: &str = "Genesis";
+pub(super) const MSG_DOCTOR_PROVER_OBJECT_STORE: &str = "Prover object store";
+pub(super) const MSG_DOCTOR_ALL_INSTALLED: &str = "all prerequisites are installed";
+pub(super) const MSG_DOCTOR_CREATE_ECOSYSTEM_HINT: &str =
+    "Run `zkstack ecosystem create`, or run the command from the ecosystem directory";
+pub(super) const MSG_DOCTOR_CREATE_CHAIN_HINT: &str =
+    "Run `zkstack chain create`, or select an existing chain with `--chain`";
+pub(super) const MSG_DOCTOR_START_CONTAINERS_HINT: &str = "Run `zkstack containers`";
+pub(super) const MSG_DOCTOR_COMPOSE_FILE_MISSING: &str = "docker-compose.yml is not found";
+pub(super) const MSG_DOCTOR_PORTS_FREE: &str = "all ports assigned to the chain are free";
+pub(super) const MSG_DOCTOR_PORTS_IN_USE_HINT: &str =
+    "This is expected if the chain components are running; otherwise, stop the processes occupying these ports";
+pub(super) const MSG_DOCTOR_PORT_CONFLICTS_HINT: &str =
+    "Change the conflicting ports in the chain configs so that they are unique across the ecosystem";
+pub(super) const MSG_DOCTOR_L1_RPC_HINT: &str =
+    "Check `l1.l1_rpc_url` in the chain secrets; for local setups, run `zkstack containers`";
+pub(super) const MSG_DOCTOR_CHAIN_INIT_HINT: &str =
+    "Run `zkstack ecosystem init` or `zkstack chain init`";
+pub(super) const MSG_DOCTOR_CONTRACTS_DEPLOYED: &str = "bridgehub and diamond proxy are deployed";
+pub(super) const MSG_DOCTOR_DATABASE_HINT: &str =
+    "Make sure Postgres is running (`zkstack containers`) and the database URL in the chain secrets is correct; create the database with `zkstack chain init`";
+pub(super) const MSG_DOCTOR_PROVER_DATABASE_HINT: &str =
+    "Make sure Postgres is running and initialize the prover database with `zkstack prover init`";
+pub(super) const MSG_DOCTOR_DATABASE_CONNECTED: &str = "connected";
+pub(super) const MSG_DOCTOR_DATABASE_SECRETS_MISSING: &str = "database secrets are not set";
+pub(super) const MSG_DOCTOR_GENESIS_HASH_MISSING: &str =
+    "genesis root hash is not set in the genesis config";
+pub(super) const MSG_DOCTOR_GENESIS_MISSING: &str = "genesis L1 batch is not found in the database";
+pub(super) const MSG_DOCTOR_GENESIS_HINT: &str = "Run `zkstack chain genesis`";
+pub(super) const MSG_DOCTOR_GENESIS_MISMATCH_HINT: &str =
+    "The database was initialized for a different genesis; re-run `zkstack chain genesis`";
+pub(super) const MSG_DOCTOR_PROVER_NOT_CONFIGURED: &str = "not configured; skipped";
+pub(super) const MSG_DOCTOR_PROVER_INIT_HINT: &str = "Run `zkstack prover init`";
+pub(super) const MSG_DOCTOR_GCS_HINT: &str =
+    "Check the network connection and the bucket URL configured via `zkstack prover init`";
+pub(super) const MSG_DOCTOR_CHECK_TIMEOUT: &str = "timed out";
+
+pub(super) fn msg_doctor_missing_prerequisites(names: &[&str]) -> String {
+    format!("missing: {}", names.join(", "))
+}
+
+pub(super) fn msg_doctor_install_prerequisites_hint(links: &[String]) -> String {
+    format!("Install the missing prerequisites: {}", links.join(", "))
+}
+
+pub(super) fn msg_doctor_containers_not_running(services: &[&str]) -> String {
+    format!("services are not running: {}", services.join(", "))
+}
+
+pub(super) fn msg_doctor_containers_running(services: &[&str]) -> String {
+    format!("running: {}", services.join(", "))
+}
+
+pub(super) fn msg_doctor_ports_in_use(ports: &[u16]) -> String {
+    format!("ports are in use: {ports:?}")
+}
+
+pub(super) fn msg_doctor_port_conflict(port: u16, file_path: &str) -> String {
+    format!("port {port} is also assigned in {file_path}")
+}
+
+pub(super) fn msg_doctor_l1_chain_id_mismatch(actual: u64, expected: u64) -> String {
+    format!("L1 chain ID is {actual}, but the ecosystem expects {expected}")
+}
+
+pub(super) fn msg_doctor_l1_connected(chain_id: u64) -> String {
+    format!("connected, chain ID {chain_id}")
+}
+
+pub(super) fn msg_doctor_contract_not_deployed(name: &str, address: Address) -> String {
+    format!("{name} has no code at {address:?}")
+}
+
+pub(super) fn msg_doctor_genesis_matches(hash: H256) -> String {
+    format!("genesis root hash {hash:?} matches the database")
+}
+
+pub(super) fn msg_doctor_genesis_mismatch(config_hash: H256, db_hash: H256) -> String {
+    format!("genesis root hash in config ({config_hash:?}) differs from the database ({db_hash:?})")
+}
+
+pub(super) fn msg_doctor_path_missing(path: &Path) -> String {
+    format!("{} doesn't exist", path.display())
+}
+
+pub(super) fn msg_doctor_path_exists(path: &Path) -> String {
+    format!("{} exists", path.display())
+}
+
+pub(super) fn msg_doctor_reachable(url: &str) -> String {
+    format!("{url} is reachable")
+}
+
+pub(super) fn msg_doctor_summary(errors: usize, warnings: usize) -> String {
+    format!("Doctor found {errors} error(s) and {warnings} warning(s)")
+}
@@ -1,5 +1,5 @@
 use anyhow::Context as _;
-use common::{forge::ForgeScript, wallets::Wallet};
+use common::{config::global_config, forge::ForgeScript, wallets::Wallet};
 use ethers::types::U256;
 
 use crate::{
@@ -8,17 +8,23 @@ use crate::{
 };
 
 pub fn fill_forge_private_key(
-    mut forge: ForgeScript,
+    forge: ForgeScript,
     wallet: Option<&Wallet>,
 ) -> anyhow::Result<ForgeScript> {
-    if !forge.wallet_args_passed() {
-        forge = forge.with_private_key(
-            wallet
-                .and_then(|w| w.private_key_h256())
-                .context(MSG_DEPLOYER_PK_NOT_SET_ERR)?,
-        );
+    if forge.wallet_args_passed() {
+        return Ok(forge);
     }
-    Ok(forge)
+    // Transactions are only simulated when building unsigned transactions, so the private key may be kept offline.
+    if global_config().unsigned_txs_dir.is_some() {
+        if let Some(wallet) = wallet.filter(|w| w.private_key.is_none()) {
+            return Ok(forge.with_sender(format!("{:#x}", wallet.address)));
+        }
+    }
+    Ok(forge.with_private_key(
+        wallet
+            .and_then(|w| w.private_key_h256())
+            .context(MSG_DEPLOYER_PK_NOT_SET_ERR)?,
+    ))
 }
 
 pub async fn check_the_balance(forge: &ForgeScript) -> anyhow::Result<()> {
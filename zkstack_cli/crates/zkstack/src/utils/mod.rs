@@ -3,3 +3,4 @@ pub mod forge;
 pub mod link_to_code;
 pub mod ports;
 pub mod rocks_db;
+pub mod secrets;
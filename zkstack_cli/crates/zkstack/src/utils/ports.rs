@@ -1,4 +1,10 @@
-use std::{collections::HashMap, fmt, net::SocketAddr, ops::Range, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    net::SocketAddr,
+    ops::Range,
+    path::Path,
+};
 
 use anyhow::{bail, Context, Result};
 use config::{
@@ -172,6 +178,27 @@ impl EcosystemPorts {
         }
         grouped_ports
     }
+
+    /// Returns ports assigned in the config files located in `dir`, grouped by the file name and keyed by description.
+    pub fn ports_in_dir(&self, dir: &Path) -> BTreeMap<String, BTreeMap<String, u16>> {
+        let mut ports: BTreeMap<String, BTreeMap<_, _>> = BTreeMap::new();
+        for port_info in self.ports.values().flatten() {
+            let file_path = Path::new(&port_info.file_path);
+            if !file_path.starts_with(dir) {
+                continue;
+            }
+            let file_name = file_path
+                .strip_prefix(dir)
+                .unwrap_or(file_path)
+                .display()
+                .to_string();
+            ports
+                .entry(file_name)
+                .or_default()
+                .insert(port_info.description.clone(), port_info.port);
+        }
+        ports
+    }
 }
 
 impl fmt::Display for EcosystemPorts {
@@ -406,7 +433,7 @@ impl ConfigWithChainPorts for ExplorerBackendPorts {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
     use crate::utils::ports::{EcosystemPorts, EcosystemPortsScanner, PortInfo};
 
@@ -639,4 +666,39 @@ mod tests {
             "http://127.0.0.1:9091"
         );
     }
+
+    #[test]
+    fn test_ports_in_dir() {
+        let mut ecosystem_ports = EcosystemPorts::default();
+        for (port, file_path, description) in [
+            (
+                3050,
+                "./chains/era/configs/general.yaml",
+                "api:web3_json_rpc:http_port",
+            ),
+            (3054, "./chains/era/configs/general.yaml", "consensus:port"),
+            (
+                3150,
+                "./chains/other/configs/general.yaml",
+                "api:web3_json_rpc:http_port",
+            ),
+            (5432, "./docker-compose.yml", "postgres:ports"),
+        ] {
+            ecosystem_ports.add_port_info(
+                port,
+                PortInfo {
+                    port,
+                    file_path: file_path.to_string(),
+                    description: description.to_string(),
+                },
+            );
+        }
+
+        let ports = ecosystem_ports.ports_in_dir(Path::new("./chains/era/configs"));
+        assert_eq!(ports.len(), 1);
+        let general_ports = &ports["general.yaml"];
+        assert_eq!(general_ports.len(), 2);
+        assert_eq!(general_ports["api:web3_json_rpc:http_port"], 3050);
+        assert_eq!(general_ports["consensus:port"], 3054);
+    }
 }
@@ -0,0 +1,280 @@
+//! Resolution of secret references in `secrets.yaml` and `wallets.yaml`.
+//!
+//! Instead of a plaintext value, any string in these configs may reference a secret stored in an external backend:
+//!
+//! - `vault://<path>#<field>`: field of a HashiCorp Vault KV secret, read with `vault kv get`.
+//! - `aws-sm://<secret-id>[#<field>]`: AWS Secrets Manager secret, read with `aws secretsmanager get-secret-value`.
+//!   If a field is specified, the secret string is parsed as a JSON object.
+//! - `sops://<file>[#<key.path>]`: value from a file encrypted with SOPS; the key path is dot-separated.
+//!
+//! References are resolved right before launching the server or the external node; the resolved configs only exist
+//! in a temporary directory while the process runs.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write as _,
+    os::unix::fs::OpenOptionsExt as _,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use common::logger;
+use serde_yaml::Value;
+use xshell::{cmd, Shell, TempDir};
+
+use crate::messages::{msg_resolved_secret_references, msg_secret_reference_resolution_err};
+
+const VAULT_SCHEME: &str = "vault";
+const AWS_SECRETS_MANAGER_SCHEME: &str = "aws-sm";
+const SOPS_SCHEME: &str = "sops";
+
+#[derive(Debug, PartialEq)]
+enum SecretReference {
+    Vault {
+        path: String,
+        field: String,
+    },
+    AwsSecretsManager {
+        secret_id: String,
+        field: Option<String>,
+    },
+    Sops {
+        file: PathBuf,
+        key_path: Option<String>,
+    },
+}
+
+impl SecretReference {
+    /// Parses a reference from a config value. Returns `None` if the value isn't a reference.
+    fn parse(value: &str) -> anyhow::Result<Option<Self>> {
+        let Some((scheme, reference)) = value.split_once("://") else {
+            return Ok(None);
+        };
+        let (location, field) = match reference.split_once('#') {
+            Some((location, field)) => (location, Some(field.to_owned())),
+            None => (reference, None),
+        };
+        let reference = match scheme {
+            VAULT_SCHEME => Self::Vault {
+                path: location.to_owned(),
+                field: field.context("Vault secret reference must specify a field after `#`")?,
+            },
+            AWS_SECRETS_MANAGER_SCHEME => Self::AwsSecretsManager {
+                secret_id: location.to_owned(),
+                field,
+            },
+            SOPS_SCHEME => Self::Sops {
+                file: location.into(),
+                key_path: field,
+            },
+            // Other URLs, e.g. database or RPC ones
+            _ => return Ok(None),
+        };
+        anyhow::ensure!(!location.is_empty(), "secret location is empty");
+        Ok(Some(reference))
+    }
+
+    /// Fetches the secret from the backend. The output of backend CLIs isn't logged even in the verbose mode,
+    /// so [`common::cmd::Cmd`] isn't used.
+    fn fetch(&self, shell: &Shell) -> anyhow::Result<String> {
+        Ok(match self {
+            Self::Vault { path, field } => cmd!(shell, "vault kv get -field={field} {path}")
+                .quiet()
+                .read()?,
+            Self::AwsSecretsManager { secret_id, field } => {
+                let secret = cmd!(
+                    shell,
+                    "aws secretsmanager get-secret-value --secret-id {secret_id} --query SecretString --output text"
+                )
+                .quiet()
+                .read()?;
+                let Some(field) = field else {
+                    return Ok(secret);
+                };
+                let secret: serde_json::Value =
+                    serde_json::from_str(&secret).context("secret is not a JSON object")?;
+                match &secret[field] {
+                    serde_json::Value::String(value) => value.clone(),
+                    serde_json::Value::Null => anyhow::bail!("secret has no field `{field}`"),
+                    value => value.to_string(),
+                }
+            }
+            Self::Sops { file, key_path } => {
+                let mut cmd = cmd!(shell, "sops --decrypt");
+                if let Some(key_path) = key_path {
+                    let extract: String = key_path
+                        .split('.')
+                        .map(|key| format!("[{key:?}]"))
+                        .collect();
+                    cmd = cmd.arg("--extract").arg(extract);
+                }
+                cmd.arg(file).quiet().read()?
+            }
+        })
+    }
+}
+
+/// Replaces secret references in string values of `value` with secrets returned by `fetch`. Each distinct reference
+/// is fetched once. Returns the number of replaced values.
+fn resolve_references(
+    value: &mut Value,
+    cache: &mut HashMap<String, String>,
+    fetch: &mut impl FnMut(&SecretReference) -> anyhow::Result<String>,
+) -> anyhow::Result<usize> {
+    match value {
+        Value::String(s) => {
+            let Some(reference) = SecretReference::parse(s)
+                .with_context(|| msg_secret_reference_resolution_err(s))?
+            else {
+                return Ok(0);
+            };
+            let secret = match cache.get(s.as_str()) {
+                Some(secret) => secret.clone(),
+                None => {
+                    let secret = fetch(&reference)
+                        .with_context(|| msg_secret_reference_resolution_err(s))?;
+                    cache.insert(s.clone(), secret.clone());
+                    secret
+                }
+            };
+            *s = secret;
+            Ok(1)
+        }
+        Value::Sequence(values) => values.iter_mut().try_fold(0, |count, value| {
+            Ok(count + resolve_references(value, cache, fetch)?)
+        }),
+        Value::Mapping(mapping) => mapping.values_mut().try_fold(0, |count, value| {
+            Ok(count + resolve_references(value, cache, fetch)?)
+        }),
+        Value::Tagged(tagged) => resolve_references(&mut tagged.value, cache, fetch),
+        Value::Null | Value::Bool(_) | Value::Number(_) => Ok(0),
+    }
+}
+
+/// Config file with resolved secret references.
+#[derive(Debug)]
+pub struct ResolvedConfig {
+    path: PathBuf,
+    /// Directory with the resolved config; removed on drop. `None` if the config has no references.
+    _temp_dir: Option<TempDir>,
+}
+
+impl ResolvedConfig {
+    /// Returns the path to pass to the launched process.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Resolves secret references in the YAML config at `path`. If there are no references, the original config is used.
+pub fn resolve_secret_references(shell: &Shell, path: &Path) -> anyhow::Result<ResolvedConfig> {
+    let contents = shell.read_file(path)?;
+    let mut config: Value =
+        serde_yaml::from_str(&contents).with_context(|| format!("failed parsing {path:?}"))?;
+    let count = resolve_references(&mut config, &mut HashMap::new(), &mut |reference| {
+        reference.fetch(shell)
+    })?;
+    if count == 0 {
+        return Ok(ResolvedConfig {
+            path: path.to_owned(),
+            _temp_dir: None,
+        });
+    }
+
+    let temp_dir = shell.create_temp_dir()?;
+    let resolved_path = temp_dir
+        .path()
+        .join(path.file_name().context("config path has no file name")?);
+    // The resolved config contains plaintext secrets, so it's only readable by the current user.
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&resolved_path)
+        .with_context(|| format!("failed creating {resolved_path:?}"))?;
+    file.write_all(serde_yaml::to_string(&config)?.as_bytes())?;
+    logger::info(msg_resolved_secret_references(count, path));
+
+    Ok(ResolvedConfig {
+        path: resolved_path,
+        _temp_dir: Some(temp_dir),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_references() {
+        assert_eq!(
+            SecretReference::parse("vault://secret/era#operator").unwrap(),
+            Some(SecretReference::Vault {
+                path: "secret/era".to_owned(),
+                field: "operator".to_owned(),
+            })
+        );
+        assert_eq!(
+            SecretReference::parse("aws-sm://era/db").unwrap(),
+            Some(SecretReference::AwsSecretsManager {
+                secret_id: "era/db".to_owned(),
+                field: None,
+            })
+        );
+        assert_eq!(
+            SecretReference::parse("sops://secrets.enc.yaml#database.server_url").unwrap(),
+            Some(SecretReference::Sops {
+                file: "secrets.enc.yaml".into(),
+                key_path: Some("database.server_url".to_owned()),
+            })
+        );
+        assert_eq!(
+            SecretReference::parse("postgres://postgres@localhost/zksync").unwrap(),
+            None
+        );
+        assert_eq!(SecretReference::parse("0x0123").unwrap(), None);
+        SecretReference::parse("vault://secret/era").unwrap_err();
+        SecretReference::parse("aws-sm://#field").unwrap_err();
+    }
+
+    #[test]
+    fn resolving_references_in_config() {
+        let mut config: Value = serde_yaml::from_str(
+            r#"
+            database:
+              server_url: aws-sm://era/db#server_url
+              prover_url: aws-sm://era/db#server_url
+            l1:
+              l1_rpc_url: http://localhost:8545
+            operator:
+              private_key: vault://secret/era#operator
+              ports: [3050, 3051]
+            "#,
+        )
+        .unwrap();
+
+        let mut fetched = vec![];
+        let count = resolve_references(&mut config, &mut HashMap::new(), &mut |reference| {
+            fetched.push(format!("{reference:?}"));
+            Ok(match reference {
+                SecretReference::Vault { .. } => "0xdead".to_owned(),
+                _ => "postgres://postgres@db/era".to_owned(),
+            })
+        })
+        .unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(fetched.len(), 2, "{fetched:?}");
+        assert_eq!(
+            config["database"]["server_url"],
+            "postgres://postgres@db/era"
+        );
+        assert_eq!(
+            config["database"]["prover_url"],
+            "postgres://postgres@db/era"
+        );
+        assert_eq!(config["l1"]["l1_rpc_url"], "http://localhost:8545");
+        assert_eq!(config["operator"]["private_key"], "0xdead");
+    }
+}